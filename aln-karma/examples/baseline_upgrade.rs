@@ -0,0 +1,99 @@
+// path: aln-karma/examples/baseline_upgrade.rs
+
+//! Example: how much of a mid-year change in AU.ET is the new baseline (run with `--features test-util`).
+//! - Four epochs of a Phoenix controller are shadow-run under last year's baseline and a
+//!   stricter one that also asks for a 5% improvement; under the new one each epoch avoids
+//!   half a tonne less
+//! - The third epoch saved only kWh: eligible under the old baseline, not the new one, and
+//!   both `rebaseline` and `compare_chains` say so
+//! - The reports are counterfactual: marked as such, with no hash chain, and the manifests
+//!   they read still verify unchanged
+//! - Chains over different vNode logs are not compared
+
+use aln_karma::builders::{smart_city_mobility_pricing, ManifestBuilder, FIXTURE_EPOCH_SECONDS, FIXTURE_START_MS};
+use aln_karma::compare::{compare_chains, rebaseline};
+use aln_karma::{BaselineModel, EpochChain, ImpactMetrics, ReportKind, SafetyEpochManifest};
+
+const VNODE: &str = "city:phoenix:traffic:controller-01";
+
+fn baseline(description: &str, min_improvement_ratio: f64, method: &str) -> BaselineModel {
+    BaselineModel {
+        description: description.into(),
+        additionality_certified: true,
+        min_improvement_ratio,
+        method: Some(method.into()),
+    }
+}
+
+/// One manifest per `(t_co2e_avoided, kwh_reduced)`, consecutive and linked, all over the same logs.
+fn chain(baseline: &BaselineModel, epochs: &[(f64, f64)], seed: u64) -> Vec<SafetyEpochManifest> {
+    let mut chain: Vec<SafetyEpochManifest> = Vec::new();
+    for (i, &(t_co2e_avoided, kwh_reduced)) in epochs.iter().enumerate() {
+        let mut builder = ManifestBuilder::new(VNODE)
+            .baseline(baseline.clone())
+            .metrics(ImpactMetrics { t_co2e_avoided, kwh_reduced, ..ImpactMetrics::default() })
+            .at_ms(FIXTURE_START_MS + i as u64 * FIXTURE_EPOCH_SECONDS * 1_000)
+            .seed(seed + i as u64);
+        if let Some(prev) = chain.last() {
+            builder = builder.follows(prev);
+        }
+        chain.push(builder.build());
+    }
+    chain
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn main() -> Result<(), String> {
+    let pricing = smart_city_mobility_pricing();
+    let old = baseline("Phoenix SOV baseline, 2018–2020 average", 0.0, "sov-average");
+    let new = baseline("Phoenix SOV baseline, 2025 regression", 0.05, "sov-regression");
+    let a = chain(&old, &[(2.0, 0.0), (1.5, 0.0), (0.0, 400.0), (1.0, 0.0)], 10);
+    let b = chain(&new, &[(1.5, 0.0), (1.0, 0.0), (0.0, 400.0), (0.5, 0.0)], 20);
+
+    // 1. Rebaselining the kWh-only epoch: eligible before, not after, and worth nothing after.
+    let kwh_only = &a[2];
+    let report = rebaseline(kwh_only, &new, &pricing);
+    assert_eq!(report.kind, ReportKind::Counterfactual);
+    assert!(report.eligible_before && !report.eligible_after);
+    assert!(close(report.au_et_before, 4.0) && report.au_et_after == 0.0);
+    assert!(close(report.au_et_change, -4.0));
+    assert_eq!(report.manifest_hash, kwh_only.self_hash);
+
+    // 2. An epoch with avoided CO₂e stays eligible; rebaselining alone leaves its metrics as measured.
+    let first = rebaseline(&a[0], &new, &pricing);
+    assert!(first.eligible_before && first.eligible_after);
+    assert!(close(first.au_et_change, 0.0));
+
+    // 3. Nothing was converted or changed: the manifests verify under their old baseline.
+    assert!(a.iter().all(|m| m.verify_hash() && m.baseline.method.as_deref() == Some("sov-average")));
+
+    // 4. The shadow run: 0.5 t less each epoch at 10 AU.ET a tonne, and the kWh epoch lost.
+    let comparison = compare_chains(&EpochChain::new(a.clone())?, &EpochChain::new(b)?, &pricing)?;
+    assert_eq!(comparison.kind, ReportKind::Counterfactual);
+    let third = &comparison.epochs[2];
+    assert!(third.eligible_a && !third.eligible_b);
+    assert!(close(third.divergence, -4.0));
+    let divergences: Vec<f64> = comparison.epochs.iter().map(|e| e.divergence).collect();
+    assert!(divergences.iter().zip([-5.0, -5.0, -4.0, -5.0]).all(|(d, want)| close(*d, want)), "{divergences:?}");
+    assert!(close(comparison.epochs[1].cumulative_divergence, -10.0));
+    assert!(close(comparison.total_au_et_a, 49.0) && close(comparison.total_au_et_b, 30.0));
+    assert!(close(comparison.cumulative_divergence, -19.0));
+    assert!(comparison.relative_divergence.is_some_and(|r| close(r, -19.0 / 49.0)));
+    assert!(close(comparison.mean_absolute_divergence, 4.75) && close(comparison.max_absolute_divergence, 5.0));
+    assert_eq!(comparison.eligibility_changes, 1);
+
+    // 5. Serialized, the report says what it is and carries no chain hashes.
+    let json = serde_json::to_value(&comparison).expect("json");
+    assert_eq!(json["kind"], "counterfactual");
+    assert!(json.get("self_hash").is_none() && json.get("prev_hash").is_none());
+
+    // 6. A chain over other logs is not a shadow run of this one.
+    let elsewhere = ManifestBuilder::new(VNODE).baseline(new).vnode_log_root("merkle-root:other").seed(30).build();
+    let one = EpochChain::new(a.into_iter().take(1))?;
+    assert!(compare_chains(&one, &EpochChain::new([elsewhere])?, &pricing).is_err());
+    println!("{}", serde_json::to_string_pretty(&report).expect("json"));
+    Ok(())
+}
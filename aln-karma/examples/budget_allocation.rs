@@ -0,0 +1,131 @@
+// path: aln-karma/examples/budget_allocation.rs
+
+//! Example: a traffic controller spending its earned AU.ET on maintenance and new sensors.
+//! - Allocations draw on the cited allowances in order; a partly used allowance keeps its remainder
+//! - Asking for more than the cited allowances have left, or citing a used-up one, records nothing
+//! - A neighbouring controller's allowance cannot be cited: the book does not contain it
+//! - Invalidated and expired allowances cannot be drawn on
+//! - Allocation records are hash-chained; an edited record no longer verifies
+
+use aln_karma::{
+    BaselineModel, BudgetPurpose, ImpactMetrics, JusticeConstraints, KarmaAllowance, KarmaLedger, PricingTable,
+    SafetyEpochManifest, ShardPolicy, VNodeId, VintageRule,
+};
+use steward_runtime_support::SequentialIdGenerator;
+
+const SHARD: &str = "policy:aln:mobility:v1";
+/// 2026-01-01T00:00:00Z, in seconds (epoch bounds are seconds).
+const EPOCH0: u64 = 1_767_225_600;
+const DAY_MS: u64 = 86_400_000;
+const MONTH_S: u64 = 30 * 86_400;
+const YEAR_MS: u64 = 365 * DAY_MS;
+
+fn vnode(id: &str) -> VNodeId {
+    VNodeId { vnode_id: id.into(), policy_shard_id: SHARD.into() }
+}
+
+/// Monthly allowances worth 10 AU.ET per tCO₂e, usable for a year after each epoch.
+fn earn(ledger: &mut KarmaLedger, ids: &SequentialIdGenerator, node: &VNodeId, t_co2e: &[f64]) -> Vec<KarmaAllowance> {
+    let policy = ShardPolicy {
+        policy_shard_id: SHARD.into(),
+        vintage: Some(VintageRule { validity_ms: YEAR_MS, decay: Default::default() }),
+        ..ShardPolicy::default()
+    };
+    let pricing = PricingTable::new(10.0, 0.0, 0.0);
+    let mut prev = None;
+    let mut earned = Vec::new();
+    for (i, t) in t_co2e.iter().enumerate() {
+        let i = i as u64;
+        let manifest = SafetyEpochManifest::new_with_ids(
+            ids,
+            node.clone(),
+            EPOCH0 + i * MONTH_S,
+            EPOCH0 + (i + 1) * MONTH_S,
+            ImpactMetrics { t_co2e_avoided: *t, ..ImpactMetrics::default() },
+            BaselineModel {
+                description: "Phoenix SOV baseline, peak hour".into(),
+                additionality_certified: true,
+                min_improvement_ratio: 0.05,
+                method: None,
+            },
+            JusticeConstraints { forbid_burden_shifting: true, require_opt_out_respected: true },
+            format!("merkle-root-{}-{i}", node.vnode_id),
+            vec![],
+            None,
+        );
+        let allowance =
+            manifest.to_karma_allowance_with_pricing(&policy, prev, &pricing).expect("eligible");
+        prev = Some(allowance.self_hash.clone());
+        ledger.append(allowance.clone()).expect("links to the previous allowance");
+        earned.push(allowance);
+    }
+    earned
+}
+
+fn maintenance(what: &str) -> BudgetPurpose {
+    BudgetPurpose::MaintenanceWindow { description: what.into() }
+}
+
+fn main() {
+    let ids = SequentialIdGenerator::new(1);
+    let (north, south) = (vnode("city:phoenix:traffic:controller-01"), vnode("city:phoenix:traffic:controller-02"));
+    let mut ledger = KarmaLedger::new();
+    let a = earn(&mut ledger, &ids, &north, &[1.0, 2.0, 3.0]);
+    let b = earn(&mut ledger, &ids, &south, &[5.0]);
+    let now = (EPOCH0 + 3 * MONTH_S) * 1_000;
+    assert_eq!(ledger.available_budget(&north, now), 60.0);
+    assert_eq!(ledger.available_budget(&south, now), 50.0);
+
+    // 1. A firmware window costs 25: all of the first allowance, three quarters of the second.
+    let mut book = ledger.budget_book(&north);
+    let firmware = book.allocate(&[a[0].id, a[1].id], maintenance("Signal firmware 4.2"), 25.0, now).expect("covered");
+    assert_eq!(firmware.draws.len(), 2);
+    assert_eq!((firmware.draws[0].au_et, firmware.draws[0].fraction), (10.0, 1.0));
+    assert_eq!((firmware.draws[1].au_et, firmware.draws[1].fraction), (15.0, 0.75));
+    assert_eq!(book.available(now), 35.0);
+
+    // 2. Over-allocation: the second allowance has 5 left, the first none. Nothing is recorded.
+    let sensors = BudgetPurpose::SensorCoverage { description: "Two more loop detectors on 7th Ave".into() };
+    let over = book.allocate(&[a[1].id], sensors.clone(), 20.0, now).expect_err("only 5 left");
+    assert_eq!(over, "Allocation of 20 AU.ET exceeds the 5 AU.ET left on the cited allowances");
+    let used_up = book.allocate(&[a[0].id, a[2].id], sensors.clone(), 20.0, now).expect_err("first is used up");
+    assert_eq!(used_up, format!("Allowance {} is fully allocated", a[0].id));
+    assert_eq!(book.budget().allocations().len(), 1);
+    assert_eq!(book.available(now), 35.0);
+
+    // 3. The south controller's allowance is not in the north controller's book.
+    let foreign = book.allocate(&[b[0].id], sensors.clone(), 10.0, now).expect_err("another vNode's");
+    assert_eq!(foreign, format!("Allowance {} is not recorded for vNode {}", b[0].id, north.vnode_id));
+    let sensors_record = book.allocate(&[a[1].id, a[2].id], sensors, 20.0, now + DAY_MS).expect("covered");
+    assert_eq!(sensors_record.prev_hash.as_ref(), Some(&firmware.self_hash));
+    assert_eq!(ledger.available_budget(&north, now + DAY_MS), 15.0);
+    assert_eq!(ledger.available_budget(&south, now + DAY_MS), 50.0, "untouched");
+    assert!(ledger.allocation_history(&south).is_empty());
+
+    // 4. The third month's manifest fails audit: its remainder can no longer be drawn on.
+    ledger.invalidate(a[2].id, "Detector calibration drift in month 3", now + 2 * DAY_MS).expect("recorded");
+    assert_eq!(ledger.available_budget(&north, now + 2 * DAY_MS), 0.0);
+    let mut book = ledger.budget_book(&north);
+    let refused = book.allocate(&[a[2].id], maintenance("Cabinet cooling"), 1.0, now + 2 * DAY_MS).unwrap_err();
+    assert_eq!(refused, format!("Allowance {} was invalidated: Detector calibration drift in month 3", a[2].id));
+
+    // 5. A year after its epoch, the south controller's allowance has expired.
+    let late = (EPOCH0 + MONTH_S) * 1_000 + YEAR_MS;
+    let mut south_book = ledger.budget_book(&south);
+    let expired = south_book.allocate(&[b[0].id], maintenance("Pole repainting"), 5.0, late).expect_err("expired");
+    assert_eq!(expired, format!("Allowance {} stopped counting at {late}", b[0].id));
+
+    // 6. The history is hash-chained; an edited amount breaks it.
+    assert_eq!(ledger.allocation_history(&north).len(), 2);
+    ledger.budget_book(&north).budget().verify_chain().expect("intact");
+    let json = serde_json::to_string(&ledger).expect("ledger json");
+    let edited = json.replacen("\"amount_au_et\":25.0", "\"amount_au_et\":2.5", 1);
+    assert_ne!(json, edited);
+    let mut tampered: KarmaLedger = serde_json::from_str(&edited).expect("ledger loads");
+    let broken = tampered.budget_book(&north).budget().verify_chain().expect_err("edited");
+    assert_eq!(broken, "Allocation 0 fails hash verification");
+
+    for record in ledger.allocation_history(&north) {
+        println!("{}", serde_json::to_string(record).expect("record json"));
+    }
+}
@@ -0,0 +1,159 @@
+// path: aln-karma/examples/budget_shortfalls.rs
+
+//! Example: an allowance decertified after the budget was spent against it.
+//! - Two allocations draw on the month-2 allowance; its baseline is then decertified
+//! - Until reconciled the book looks healthy; `reconcile_invalidations` marks both allocations
+//!   underfunded and the available budget goes negative by the shortfall
+//! - New allocations are refused while a shortfall is open; each is cured from a replacement
+//!   allowance by a new record in the hash chain, and the history keeps every record
+//! - A cure moves AU.ET from the replacement to the shortfall, so it leaves the available
+//!   budget where reconciliation put it
+//! - A ledger under `ShortfallPolicy::AllowAllocations` keeps allocating past an expired
+//!   allowance's shortfall
+
+use aln_karma::{
+    AllocationStatus, BaselineModel, BudgetPurpose, ImpactMetrics, JusticeConstraints, KarmaAllowance, KarmaLedger,
+    PricingTable, SafetyEpochManifest, ShardPolicy, ShortfallPolicy, VNodeId, VintageRule,
+};
+use steward_runtime_support::SequentialIdGenerator;
+
+const SHARD: &str = "policy:aln:mobility:v1";
+/// 2026-01-01T00:00:00Z, in seconds (epoch bounds are seconds).
+const EPOCH0: u64 = 1_767_225_600;
+const DAY_MS: u64 = 86_400_000;
+const MONTH_S: u64 = 30 * 86_400;
+const YEAR_MS: u64 = 365 * DAY_MS;
+
+fn vnode(id: &str) -> VNodeId {
+    VNodeId { vnode_id: id.into(), policy_shard_id: SHARD.into() }
+}
+
+/// Monthly allowances from `first_month` on, worth 10 AU.ET per tCO₂e and usable for a year
+/// after each epoch.
+fn earn(
+    ledger: &mut KarmaLedger,
+    ids: &SequentialIdGenerator,
+    node: &VNodeId,
+    first_month: u64,
+    t_co2e: &[f64],
+) -> Vec<KarmaAllowance> {
+    let policy = ShardPolicy {
+        policy_shard_id: SHARD.into(),
+        vintage: Some(VintageRule { validity_ms: YEAR_MS, decay: Default::default() }),
+        ..ShardPolicy::default()
+    };
+    let pricing = PricingTable::new(10.0, 0.0, 0.0);
+    let latest = ledger.allowances().iter().rev().find(|a| a.vnode.vnode_id == node.vnode_id);
+    let mut prev = latest.map(|a| a.self_hash.clone());
+    let mut earned = Vec::new();
+    for (i, t) in t_co2e.iter().enumerate() {
+        let month = first_month + i as u64;
+        let manifest = SafetyEpochManifest::new_with_ids(
+            ids,
+            node.clone(),
+            EPOCH0 + month * MONTH_S,
+            EPOCH0 + (month + 1) * MONTH_S,
+            ImpactMetrics { t_co2e_avoided: *t, ..ImpactMetrics::default() },
+            BaselineModel {
+                description: "Phoenix SOV baseline, peak hour".into(),
+                additionality_certified: true,
+                min_improvement_ratio: 0.05,
+                method: None,
+            },
+            JusticeConstraints { forbid_burden_shifting: true, require_opt_out_respected: true },
+            format!("merkle-root-{}-{month}", node.vnode_id),
+            vec![],
+            None,
+        );
+        let allowance =
+            manifest.to_karma_allowance_with_pricing(&policy, prev, &pricing).expect("eligible");
+        prev = Some(allowance.self_hash.clone());
+        ledger.append(allowance.clone()).expect("links to the previous allowance");
+        earned.push(allowance);
+    }
+    earned
+}
+
+fn assert_au_et(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} AU.ET, expected {expected}");
+}
+
+fn main() {
+    let ids = SequentialIdGenerator::new(1);
+    let north = vnode("city:phoenix:traffic:controller-01");
+    let mut ledger = KarmaLedger::new();
+    assert_eq!(ledger.shortfall_policy(), ShortfallPolicy::BlockAllocations);
+    let a = earn(&mut ledger, &ids, &north, 0, &[1.0, 2.0, 3.0]);
+    let now = (EPOCH0 + 3 * MONTH_S) * 1_000;
+
+    // 1. Allocate: firmware takes 10 + 15, sensors 5 + 15; 15 AU.ET is left, all on month 3.
+    let firmware = BudgetPurpose::MaintenanceWindow { description: "Signal firmware 4.2".into() };
+    let sensors = BudgetPurpose::SensorCoverage { description: "Two more loop detectors on 7th Ave".into() };
+    let mut book = ledger.budget_book(&north);
+    book.allocate(&[a[0].id, a[1].id], firmware.clone(), 25.0, now).expect("covered");
+    book.allocate(&[a[1].id, a[2].id], sensors, 20.0, now).expect("covered");
+    assert_au_et(ledger.available_budget(&north, now), 15.0);
+
+    // 2. Month 2's baseline is decertified. Its 20 AU.ET are already spent, so the book still
+    //    shows 15 available: the overdraft is silent until reconciled.
+    let reason = "Baseline decertified: SOV counts double-counted a detour";
+    ledger.invalidate(a[1].id, reason, now + DAY_MS).expect("recorded");
+    assert_au_et(ledger.available_budget(&north, now + DAY_MS), 15.0);
+
+    // 3. Reconcile: both allocations are short what they drew from month 2, 15 and 5.
+    let mut book = ledger.budget_book(&north);
+    let shortfalls = book.reconcile_invalidations(now + DAY_MS);
+    let found: Vec<(u64, f64)> = shortfalls.iter().map(|s| (s.seq, s.shortfall_au_et)).collect();
+    assert_eq!(found, [(0, 15.0), (1, 5.0)]);
+    assert!(shortfalls.iter().all(|s| s.allowance_ids == [a[1].id]));
+    assert_eq!(book.reconcile_invalidations(now + DAY_MS), shortfalls, "reconciling again changes nothing");
+    assert_eq!(
+        book.budget().status(0),
+        AllocationStatus::Underfunded { shortfall_au_et: 15.0, allowance_ids: vec![a[1].id] }
+    );
+    assert_au_et(book.available(now + DAY_MS), -5.0);
+    let blocked = book.allocate(&[a[2].id], firmware.clone(), 1.0, now + DAY_MS).unwrap_err();
+    assert_eq!(blocked, format!("vNode {} has an unresolved shortfall on allocation 0; cure it first", north.vnode_id));
+    assert_au_et(ledger.available_budget(&north, now + DAY_MS), -5.0);
+
+    // 4. Cure: month 4's allowance covers the firmware shortfall, month 3's the sensors'.
+    let d = earn(&mut ledger, &ids, &north, 3, &[4.0]);
+    let later = now + 30 * DAY_MS;
+    assert_au_et(ledger.available_budget(&north, later), 35.0);
+    let mut book = ledger.budget_book(&north);
+    assert!(book.cure_shortfall(0, &[a[1].id], later).unwrap_err().contains("was invalidated"));
+    let cure = book.cure_shortfall(0, &[d[0].id], later).expect("covered");
+    assert_eq!((cure.seq, cure.cures, cure.amount_au_et, &cure.purpose), (2, Some(0), 15.0, &firmware));
+    assert_eq!(book.budget().status(0), AllocationStatus::Cured { cure_seq: 2 });
+    assert_au_et(book.available(later), 35.0);
+    assert!(book.allocate(&[d[0].id], firmware.clone(), 1.0, later).unwrap_err().contains("allocation 1"));
+    book.cure_shortfall(1, &[a[2].id], later).expect("covered");
+    assert!(book.cure_shortfall(1, &[d[0].id], later).unwrap_err().contains("no unresolved shortfall"));
+    assert!(book.reconcile_invalidations(later).is_empty());
+    assert_au_et(book.available(later), 35.0);
+    book.allocate(&[d[0].id], firmware, 5.0, later).expect("no open shortfall");
+    assert_au_et(ledger.available_budget(&north, later), 30.0);
+
+    // 5. Nothing was rewritten: the original records are kept and the chain verifies.
+    let history = ledger.allocation_history(&north);
+    let amounts: Vec<(f64, Option<u64>)> = history.iter().map(|r| (r.amount_au_et, r.cures)).collect();
+    assert_eq!(amounts, [(25.0, None), (20.0, None), (15.0, Some(0)), (5.0, Some(1)), (5.0, None)]);
+    ledger.budget_book(&north).budget().verify_chain().expect("intact");
+
+    // 6. Expiry under `AllowAllocations`: the shortfall is reported but allocation goes on.
+    let south = vnode("city:phoenix:traffic:controller-02");
+    let mut lenient = KarmaLedger::new().with_shortfall_policy(ShortfallPolicy::AllowAllocations);
+    let b = earn(&mut lenient, &ids, &south, 0, &[5.0, 1.0]);
+    let upkeep = BudgetPurpose::Other { description: "Cabinet cooling".into() };
+    lenient.budget_book(&south).allocate(&[b[0].id], upkeep.clone(), 30.0, now).expect("covered");
+    let expired_at = (EPOCH0 + MONTH_S) * 1_000 + YEAR_MS;
+    let mut book = lenient.budget_book(&south);
+    let shortfalls = book.reconcile_invalidations(expired_at);
+    assert_eq!((shortfalls.len(), shortfalls[0].shortfall_au_et), (1, 30.0));
+    book.allocate(&[b[1].id], upkeep, 5.0, expired_at).expect("allowed under the lenient policy");
+    assert_au_et(lenient.available_budget(&south, expired_at), -25.0);
+
+    for record in ledger.allocation_history(&north) {
+        println!("{}", serde_json::to_string(record).expect("record json"));
+    }
+}
@@ -0,0 +1,147 @@
+// path: aln-karma/examples/canonical_fixtures.rs
+
+//! Example: canonical-encoding stability check.
+//! - Rebuilds a fixed manifest and allowance
+//! - Compares their canonical bytes and hashes to the checked-in fixtures
+//! - Shows that legacy (v1) hashes still verify and CBOR round-trips
+//! - A hash version this build does not know never verifies, whatever its `self_hash`
+//!
+//! A mismatch means a dependency or code change altered the preimage of
+//! already-sealed records; bump the hash version rather than the fixtures.
+
+use aln_karma::{
+    BaselineModel, CanonicalEncoding, ImpactMetrics, JusticeConstraints, KarmaAllowance,
+    LeakageEstimate, SafetyEpochManifest, ShardPolicy, VNodeId, HASH_VERSION_CANONICAL,
+    HASH_VERSION_LEGACY_JSON,
+};
+use steward_runtime_support::SequentialIdGenerator;
+use uuid::Uuid;
+
+const MANIFEST_HEX: &str = include_str!("../fixtures/canonical_v2/manifest.hex");
+const MANIFEST_SHA256: &str = include_str!("../fixtures/canonical_v2/manifest.sha256");
+const ALLOWANCE_HEX: &str = include_str!("../fixtures/canonical_v2/allowance.hex");
+const ALLOWANCE_SHA256: &str = include_str!("../fixtures/canonical_v2/allowance.sha256");
+
+fn fixture_manifest() -> SafetyEpochManifest {
+    SafetyEpochManifest::new_with_ids(
+        &SequentialIdGenerator::new(7),
+        VNodeId {
+            vnode_id: "city:phoenix:traffic:controller-01".into(),
+            policy_shard_id: "policy:aln:mobility:v1".into(),
+        },
+        1_767_225_600,
+        1_767_226_500,
+        ImpactMetrics {
+            t_co2e_avoided: 2.7,
+            kwh_reduced: 0.0,
+            pollution_exposure_delta: -1_500.0,
+            near_misses_blocked: 7,
+            biosafety_delta: 0.12,
+            custom: Default::default(),
+        },
+        BaselineModel {
+            description: "Phoenix SOV baseline, 2018–2020 average, peak hour".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: None,
+        },
+        JusticeConstraints {
+            forbid_burden_shifting: true,
+            require_opt_out_respected: true,
+        },
+        "merkle-root-vnode-log-0xabc".into(),
+        vec!["city_sensors://phoenix/pm25".into(), "grid://srp/emissions_factors".into()],
+        None,
+    )
+    .with_leakage(LeakageEstimate {
+        displaced_t_co2e: 0.3,
+        boundary_description: "Phoenix city limits; Tempe arterials".into(),
+        estimation_method: "default 10% factor".into(),
+    })
+}
+
+fn fixture_allowance(manifest: &SafetyEpochManifest) -> KarmaAllowance {
+    let policy = ShardPolicy {
+        policy_shard_id: "policy:aln:mobility:v1".into(),
+        allow_near_miss_summary: true,
+        ..ShardPolicy::default()
+    };
+    let mut allowance = manifest
+        .to_karma_allowance(&policy, None, 10.0, 0.01, 2.5)
+        .expect("fixture manifest is eligible");
+    allowance.id = Uuid::from_u128(0x0000_0000_0000_4000_8000_0000_0000_0001);
+    allowance.self_hash = allowance.compute_hash().expect("known hash version");
+    allowance
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn check(name: &str, bytes: &[u8], hash: &str, expected_hex: &str, expected_hash: &str) {
+    let actual_hex = hex(bytes);
+    if actual_hex != expected_hex.trim() || hash != expected_hash.trim() {
+        eprintln!("{name} canonical bytes: {actual_hex}");
+        eprintln!("{name} hash:            {hash}");
+        panic!("{name} no longer matches its canonical_v2 fixture");
+    }
+    println!("{name}: {} canonical bytes, hash {hash}", bytes.len());
+}
+
+fn main() {
+    let manifest = fixture_manifest();
+    assert!(manifest.verify_hash());
+    check(
+        "manifest",
+        &manifest.to_canonical_bytes(),
+        &manifest.self_hash,
+        MANIFEST_HEX,
+        MANIFEST_SHA256,
+    );
+
+    let allowance = fixture_allowance(&manifest);
+    assert!(allowance.verify_hash());
+    check(
+        "allowance",
+        &allowance.to_canonical_bytes(),
+        &allowance.self_hash,
+        ALLOWANCE_HEX,
+        ALLOWANCE_SHA256,
+    );
+
+    // A record sealed before canonical hashing still verifies as version 1.
+    let mut legacy = manifest.clone();
+    legacy.hash_version = HASH_VERSION_LEGACY_JSON;
+    legacy.self_hash = legacy.compute_hash().expect("known hash version");
+    assert!(legacy.verify_hash());
+    assert_ne!(legacy.self_hash, manifest.self_hash);
+
+    // Tampering is caught under either version.
+    let mut tampered = manifest.clone();
+    tampered.metrics.near_misses_blocked += 1;
+    assert!(!tampered.verify_hash());
+
+    // An unknown hash version has no preimage: nothing verifies under it.
+    for version in [0, HASH_VERSION_CANONICAL + 1, u8::MAX] {
+        let mut future = manifest.clone();
+        future.hash_version = version;
+        assert_eq!(future.compute_hash(), None);
+        assert!(!future.verify_hash(), "manifest under hash version {version}");
+        let mut future = allowance.clone();
+        future.hash_version = version;
+        assert!(future.compute_hash().is_none() && !future.verify_hash(), "allowance under {version}");
+    }
+    let resealed = {
+        let mut future = manifest.clone();
+        future.hash_version = HASH_VERSION_CANONICAL + 1;
+        future.with_leakage(manifest.leakage.clone().expect("fixture has leakage"))
+    };
+    assert!(resealed.self_hash.is_empty() && !resealed.verify_hash());
+
+    // Compact CBOR round-trip.
+    let cbor = manifest.to_cbor().expect("encode");
+    let decoded = SafetyEpochManifest::from_cbor(&cbor).expect("decode");
+    assert!(decoded.verify_hash());
+    let json_len = serde_json::to_vec(&manifest).expect("json").len();
+    println!("CBOR {} bytes vs JSON {json_len} bytes; legacy v1 hash {}", cbor.len(), legacy.self_hash);
+}
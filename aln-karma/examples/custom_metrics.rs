@@ -0,0 +1,130 @@
+// path: aln-karma/examples/custom_metrics.rs
+
+//! Example: a water-quality shard with a custom impact dimension.
+//! - The shard policy registers `water:nitrate_kg_reduced` (kg, 0..=5000)
+//! - Manifests carrying it are priced at the pricing table's per-kg rate
+//! - Unregistered keys, wrong units and out-of-range values make a manifest ineligible
+//! - The manifest hash does not depend on the order custom metrics were inserted in
+
+use aln_karma::{
+    reconcile, BaselineModel, CanonicalEncoding, CustomMetricSpec, CustomMetrics, EpochForecast, ImpactMetrics,
+    JusticeConstraints, MetricKey, MetricValue, PricingTable, SafetyEpochManifest, ShardPolicy, VNodeId,
+};
+use steward_runtime_support::SequentialIdGenerator;
+
+const SHARD: &str = "policy:aln:water:v1";
+
+fn key(s: &str) -> MetricKey {
+    MetricKey::new(s).expect("valid metric key")
+}
+
+fn vnode() -> VNodeId {
+    VNodeId {
+        vnode_id: "basin:verde:wetland-03".into(),
+        policy_shard_id: SHARD.into(),
+    }
+}
+
+fn manifest(custom: &[(&str, f64, &str)]) -> SafetyEpochManifest {
+    let mut metrics = ImpactMetrics {
+        t_co2e_avoided: 0.4,
+        ..ImpactMetrics::default()
+    };
+    for (k, value, unit) in custom {
+        metrics.custom.insert(key(k), MetricValue::new(*value, *unit));
+    }
+    SafetyEpochManifest::new_with_ids(
+        &SequentialIdGenerator::new(3),
+        vnode(),
+        1_767_225_600,
+        1_769_904_000,
+        metrics,
+        BaselineModel {
+            description: "Verde basin agricultural runoff, 2021-2024 monthly mean".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: None,
+        },
+        JusticeConstraints {
+            forbid_burden_shifting: true,
+            require_opt_out_respected: true,
+        },
+        "merkle-root-wetland-03-0x51".into(),
+        vec!["lab://verde/nitrate-panel".into()],
+        None,
+    )
+}
+
+fn main() {
+    let mut policy = ShardPolicy {
+        policy_shard_id: SHARD.into(),
+        require_leakage_estimate: false,
+        custom_metrics: Default::default(),
+        vintage: None,
+        allow_near_miss_summary: false,
+        ..ShardPolicy::default()
+    };
+    let nitrate = key("water:nitrate_kg_reduced");
+    policy
+        .register_custom_metric(
+            nitrate.clone(),
+            CustomMetricSpec {
+                unit: "kg".into(),
+                min: 0.0,
+                max: 5_000.0,
+                description: "Nitrate load removed before the river intake".into(),
+            },
+        )
+        .expect("valid spec");
+    policy
+        .register_custom_metric(
+            key("water:phosphate_kg_reduced"),
+            CustomMetricSpec { unit: "kg".into(), min: 0.0, max: 500.0, description: String::new() },
+        )
+        .expect("valid spec");
+    assert!(policy
+        .register_custom_metric(nitrate.clone(), CustomMetricSpec { unit: "t".into(), min: 0.0, max: 5.0, description: String::new() })
+        .is_err());
+    assert!(MetricKey::new("nitrate").is_err(), "keys are namespaced");
+
+    // 1. Insertion order does not reach the hash: BTreeMap, then sorted canonical CBOR.
+    let forward = manifest(&[("water:nitrate_kg_reduced", 840.0, "kg"), ("water:phosphate_kg_reduced", 35.0, "kg")]);
+    let reverse = manifest(&[("water:phosphate_kg_reduced", 35.0, "kg"), ("water:nitrate_kg_reduced", 840.0, "kg")]);
+    assert_eq!(forward.self_hash, reverse.self_hash);
+    assert_eq!(forward.to_canonical_bytes(), reverse.to_canonical_bytes());
+    assert!(forward.verify_hash());
+    println!("manifest hash: {}", forward.self_hash);
+
+    // 2. The custom dimension is part of the preimage.
+    let other = manifest(&[("water:nitrate_kg_reduced", 841.0, "kg"), ("water:phosphate_kg_reduced", 35.0, "kg")]);
+    assert_ne!(other.self_hash, forward.self_hash);
+
+    // 3. Priced per kg; phosphate has no rate, so it earns nothing.
+    let pricing = PricingTable::new(10.0, 0.01, 2.5).with_custom_rate(nitrate.clone(), 0.02);
+    let allowance = forward
+        .to_karma_allowance_with_pricing(&policy, None, &pricing)
+        .expect("eligible");
+    assert!((allowance.au_et_delta - (0.4 * 10.0 + 840.0 * 0.02)).abs() < 1e-9);
+    assert_eq!(allowance.metrics.custom[&nitrate].value, 840.0);
+    assert!(allowance.verify_hash());
+    println!("au_et_delta: {:.2}", allowance.au_et_delta);
+
+    // 4. Keys the shard never registered, wrong units and out-of-range values are ineligible.
+    for bad in [
+        manifest(&[("water:turbidity_ntu_reduced", 3.0, "NTU")]),
+        manifest(&[("water:nitrate_kg_reduced", 0.84, "t")]),
+        manifest(&[("water:nitrate_kg_reduced", 9_000.0, "kg")]),
+    ] {
+        assert!(!bad.is_eligible_under_policy(&policy));
+        assert!(bad.to_karma_allowance_with_pricing(&policy, None, &pricing).is_none());
+    }
+
+    // 5. Reconciliation compares custom dimensions alongside the built-in ones.
+    let mut forecast_metrics = ImpactMetrics { t_co2e_avoided: 0.5, ..ImpactMetrics::default() };
+    forecast_metrics.custom = CustomMetrics::from([(nitrate.clone(), MetricValue::new(2_000.0, "kg"))]);
+    let forecast = EpochForecast::new(vnode(), forward.epoch_start, forward.epoch_end, forecast_metrics, "runoff-model v2".into());
+    let record = reconcile(&forecast, &forward, 0.5).expect("same vNode and epoch");
+    let delta = record.deltas.iter().find(|d| d.metric == nitrate.as_str()).expect("nitrate reconciled");
+    assert!(delta.below_shortfall_threshold);
+    println!("nitrate forecast {} kg, realized {} kg", delta.forecast, delta.realized);
+}
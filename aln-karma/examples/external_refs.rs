@@ -0,0 +1,88 @@
+// path: aln-karma/examples/external_refs.rs
+
+//! Example: checking what a manifest's external refs held (run with `--features test-util`).
+//! - The Phoenix controller's shard requires snapshots of `city_sensors://` refs: a manifest
+//!   without one is rejected, and one carrying it (sealed into its hash) is accepted
+//! - An audit re-resolves every ref; once the sensor feed is rewritten, the captured hash no
+//!   longer matches and the audit says so
+//! - A feed briefly unavailable is retried; a scheme nobody resolves is reported, not skipped
+
+use std::sync::Arc;
+
+use aln_karma::builders::{ManifestBuilder, FIXTURE_START_MS};
+use aln_karma::refs::ref_scheme;
+use aln_karma::{InMemoryResolver, RefError, RefStatus, ResolverRegistry, ShardPolicy};
+use steward_runtime_support::FixedClock;
+
+const PM25: &str = "city_sensors://phoenix/pm25";
+const COUNTS: &str = "city_sensors://phoenix/traffic-counts";
+const MRV: &str = "mrv_registry://verra/vm0038/1742";
+
+fn main() -> Result<(), String> {
+    let clock = Arc::new(FixedClock::new(FIXTURE_START_MS));
+    let sensors = Arc::new(InMemoryResolver::new(clock.clone()));
+    sensors.publish(PM25, b"pm25,hourly,24,[8.1,7.9,...]", "PM2.5 hourly means, 24 readings");
+    sensors.publish(COUNTS, b"counts,15min,96,[412,398,...]", "Vehicle counts, 96 intervals");
+    let registry = ResolverRegistry::new().with_resolver("city_sensors", sensors.clone());
+    let policy = ShardPolicy {
+        policy_shard_id: "policy:aln:mobility:v1".into(),
+        required_ref_schemes: ["city_sensors".to_string()].into(),
+        allow_near_miss_summary: true,
+        ..ShardPolicy::default()
+    };
+
+    // 1. A manifest citing the feeds but carrying no snapshots is rejected by the shard.
+    let bare = ManifestBuilder::new("city:phoenix:traffic:controller-01").external_refs(&[PM25, COUNTS]).build();
+    let err = policy.check_manifest(&bare).expect_err("snapshots required");
+    println!("rejected: {err}");
+    assert!(err.contains(PM25));
+    assert!(!bare.is_eligible_under_policy(&policy));
+
+    // 2. The vNode resolves them at creation; the snapshots are sealed into the hash.
+    let snapshots = bare
+        .resolve_refs(&registry)
+        .into_iter()
+        .map(|(uri, result)| result.map(|snapshot| (uri, snapshot)))
+        .collect::<Result<Vec<_>, RefError>>()
+        .map_err(|e| e.to_string())?;
+    let manifest = bare.clone().with_ref_snapshots(snapshots)?;
+    assert!(manifest.verify_hash());
+    assert_ne!(manifest.self_hash, bare.self_hash);
+    policy.check_manifest(&manifest)?;
+    assert!(manifest.is_eligible_under_policy(&policy));
+    let other = ("city_sensors://tucson/pm25".to_string(), manifest.ref_snapshots[PM25].clone());
+    assert!(manifest.clone().with_ref_snapshots([other]).is_err(), "not one of its refs");
+
+    // 3. An audit the same day: every ref still holds what was captured.
+    clock.advance(3_600_000);
+    let audit = manifest.audit_refs(&registry);
+    assert!(audit.is_consistent());
+    assert!(audit.refs.iter().all(|r| r.status == RefStatus::Matches));
+
+    // 4. The PM2.5 feed is rewritten afterwards: the audit reports the mismatch.
+    sensors.publish(PM25, b"pm25,hourly,24,[5.0,5.0,...]", "PM2.5 hourly means, 24 readings");
+    let audit = manifest.audit_refs(&registry);
+    assert!(!audit.is_consistent());
+    let mismatched: Vec<_> = audit.mismatches().map(|r| r.uri.as_str()).collect();
+    assert_eq!(mismatched, [PM25]);
+    println!("{}", serde_json::to_string_pretty(&audit).expect("audit json"));
+
+    // 5. Two failed reads are retried through; four exhaust the registry's three attempts.
+    sensors.fail_next(COUNTS, 2);
+    assert!(registry.resolve(COUNTS).is_ok());
+    sensors.fail_next(COUNTS, 4);
+    assert!(matches!(registry.resolve(COUNTS), Err(RefError::Unavailable { .. })));
+    assert!(registry.resolve(COUNTS).is_ok(), "the fourth outage, then back");
+
+    // 6. Refs of a scheme without a resolver are reported unresolved; other schemes are not required.
+    let cited = ManifestBuilder::new("city:phoenix:traffic:controller-01")
+        .external_refs(&[MRV])
+        .seed(2)
+        .build();
+    policy.check_manifest(&cited)?;
+    assert_eq!(ref_scheme(MRV), Some("mrv_registry"));
+    let audit = cited.audit_refs(&registry);
+    let expected = RefError::NoResolver { scheme: "mrv_registry".into() };
+    assert_eq!(audit.refs[0].status, RefStatus::Unresolved { error: expected });
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// path: aln-karma/examples/fixture_builders.rs
+
+//! Example: deterministic manifests for downstream tests (run with `--features test-util`).
+//! - The same builder calls and seed give the same manifest id and hash; another seed does not
+//! - `build_valid` refuses a manifest that would not earn karma; `build` keeps it for tests
+//!   that expect the refusal
+//! - The `smart_city_mobility` fixture prices exactly as the example of that name
+
+use aln_karma::builders::{
+    smart_city_mobility, smart_city_mobility_policy, smart_city_mobility_pricing, ManifestBuilder, FIXTURE_START_MS,
+};
+
+fn main() {
+    // 1. Seeded ids and a fixed epoch: byte-identical manifests.
+    let a = ManifestBuilder::new("city:phoenix:grid:feeder-07").seed(7).build_valid();
+    let b = ManifestBuilder::new("city:phoenix:grid:feeder-07").seed(7).build_valid();
+    assert_eq!((a.id, &a.self_hash), (b.id, &b.self_hash));
+    assert_eq!(serde_json::to_string(&a).expect("json"), serde_json::to_string(&b).expect("json"));
+    let other = ManifestBuilder::new("city:phoenix:grid:feeder-07").seed(8).build_valid();
+    assert_ne!(a.id, other.id);
+    assert_eq!((a.epoch_start, a.epoch_end), (FIXTURE_START_MS / 1000, FIXTURE_START_MS / 1000 + 900));
+
+    // 2. A chain of epochs, each following the last.
+    let next = ManifestBuilder::new("city:phoenix:grid:feeder-07")
+        .seed(7)
+        .at_ms(FIXTURE_START_MS + 900_000)
+        .follows(&a)
+        .build_valid();
+    assert_eq!(next.prev_hash.as_deref(), Some(a.self_hash.as_str()));
+    assert_eq!(next.epoch_start, a.epoch_end);
+
+    // 3. An uncertified baseline builds, but not as a valid manifest.
+    let uncertified = ManifestBuilder::new("city:phoenix:grid:feeder-07").uncertified().build();
+    assert!(!uncertified.is_eligible_for_karma());
+    let refused = std::panic::catch_unwind(|| {
+        ManifestBuilder::new("city:phoenix:grid:feeder-07").uncertified().build_valid()
+    });
+    assert!(refused.is_err(), "build_valid checks eligibility");
+
+    // 4. The smart-city fixture earns what the example prints: 2.7 × 10 + 7 × 2.5 AU.ET.
+    let manifest = smart_city_mobility(1);
+    let allowance = manifest
+        .to_karma_allowance_with_pricing(&smart_city_mobility_policy(), None, &smart_city_mobility_pricing())
+        .expect("eligible");
+    assert!((allowance.au_et_delta - 44.5).abs() < 1e-9, "{}", allowance.au_et_delta);
+    assert_eq!(manifest.external_refs, ["city_sensors://phoenix/pm25", "grid://srp/emissions_factors"]);
+
+    println!("{}", serde_json::to_string_pretty(&manifest).expect("manifest json"));
+}
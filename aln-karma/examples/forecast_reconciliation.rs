@@ -0,0 +1,93 @@
+// path: aln-karma/examples/forecast_reconciliation.rs
+
+//! Example: an operator's forecasts reconciled against what the epochs realized
+//! (run with `--features test-util`).
+//! - The first epoch promised 5 tCO₂e and 100 kWh and realized 2.7 tCO₂e and none: the kWh
+//!   shortfall is flagged and the forecast was optimistic
+//! - The second epoch realized more than forecast: no shortfall, pessimistic bias
+//! - A manifest is reconciled once, and only against a forecast for its own vNode and epoch
+//! - Editing a forecast's metrics or a record's deltas breaks its hash, legacy records included;
+//!   a hash version this build does not know never verifies
+//! - An edited forecast, or an edited manifest, is refused by `reconcile` before any delta is
+//!   computed
+
+use aln_karma::builders::{ManifestBuilder, FIXTURE_EPOCH_SECONDS, FIXTURE_START_MS};
+use aln_karma::{
+    reconcile, EpochForecast, ForecastRegistry, ImpactMetrics, SafetyEpochManifest, HASH_VERSION_CANONICAL,
+    HASH_VERSION_LEGACY_JSON,
+};
+
+const VNODE: &str = "city:phoenix:grid:feeder-07";
+const EPOCH_MS: u64 = FIXTURE_EPOCH_SECONDS * 1000;
+
+fn forecast_for(manifest: &SafetyEpochManifest, t_co2e_avoided: f64, kwh_reduced: f64) -> EpochForecast {
+    let metrics = ImpactMetrics { t_co2e_avoided, kwh_reduced, ..manifest.metrics.clone() };
+    let (start, end) = (manifest.epoch_start, manifest.epoch_end);
+    EpochForecast::new(manifest.vnode.clone(), start, end, metrics, "grid-model v3".into())
+}
+
+fn main() {
+    let first = ManifestBuilder::new(VNODE).seed(1).build_valid();
+    let second = ManifestBuilder::new(VNODE).seed(2).at_ms(FIXTURE_START_MS + EPOCH_MS).follows(&first).build_valid();
+    let mut registry = ForecastRegistry::new(0.5);
+
+    // 1. An optimistic forecast: the kWh it promised never came.
+    registry.publish(forecast_for(&first, 5.0, 100.0)).expect("first forecast");
+    let again = forecast_for(&first, 4.0, 80.0);
+    assert!(registry.publish(again).is_err(), "one forecast per vNode and epoch");
+    let record = registry.reconcile_manifest(&first).expect("forecast published");
+    assert!(record.shortfall_flagged);
+    let flagged: Vec<&str> =
+        record.deltas.iter().filter(|d| d.below_shortfall_threshold).map(|d| d.metric.as_str()).collect();
+    assert_eq!(flagged, ["kwh_reduced"], "2.7 of 5 tCO₂e is above half, 0 of 100 kWh is not");
+    let co2 = record.deltas.iter().find(|d| d.metric == "t_co2e_avoided").expect("reconciled");
+    assert!((co2.delta + 2.3).abs() < 1e-9);
+    assert!(record.bias > 0.0, "optimistic");
+    assert!(registry.reconcile_manifest(&first).is_err(), "reconciled once");
+
+    // 2. A pessimistic forecast: more was realized than promised.
+    registry.publish(forecast_for(&second, 2.0, 0.0)).expect("second forecast");
+    let record = registry.reconcile_manifest(&second).expect("forecast published");
+    assert!(!record.shortfall_flagged);
+    assert!(record.bias < 0.0, "pessimistic");
+
+    let vnode = first.vnode.clone();
+    let summary = registry.calibration_summary(&vnode, (first.epoch_start, second.epoch_end));
+    println!("{}", serde_json::to_string_pretty(&summary).expect("summary json"));
+    assert_eq!((summary.epochs_reconciled, summary.optimistic_epochs, summary.pessimistic_epochs), (2, 1, 1));
+    assert_eq!(summary.shortfall_epochs, 1);
+
+    // 3. A forecast for another epoch does not reconcile.
+    let late = ManifestBuilder::new(VNODE).seed(3).at_ms(FIXTURE_START_MS + 2 * EPOCH_MS).build_valid();
+    assert!(reconcile(&forecast_for(&first, 5.0, 100.0), &late, 0.5).is_err());
+    assert!(registry.reconcile_manifest(&late).is_err(), "no forecast published");
+
+    // 4. The hashes cover the numbers, under the legacy version too.
+    let mut forecast = forecast_for(&first, 5.0, 100.0);
+    let mut record = reconcile(&forecast, &first, 0.5).expect("same vNode and epoch");
+    assert!(forecast.verify_hash() && record.verify_hash());
+    for version in [forecast.hash_version, HASH_VERSION_LEGACY_JSON] {
+        forecast.hash_version = version;
+        forecast.forecast_metrics.t_co2e_avoided = 5.0;
+        forecast.self_hash = forecast.compute_hash().expect("known hash version");
+        forecast.forecast_metrics.t_co2e_avoided = 2.7;
+        assert!(!forecast.verify_hash(), "edited forecast under hash version {version}");
+
+        record.hash_version = version;
+        record.deltas[0].realized = 2.7;
+        record.self_hash = record.compute_hash().expect("known hash version");
+        record.deltas[0].realized = 5.0;
+        assert!(!record.verify_hash(), "edited deltas under hash version {version}");
+        record.deltas[0].realized = 2.7;
+    }
+    let err = reconcile(&forecast, &first, 0.5).expect_err("edited forecast");
+    assert_eq!(err, format!("Forecast {} fails hash verification", forecast.id));
+    let mut edited = first.clone();
+    edited.metrics.t_co2e_avoided = 5.0;
+    let err = reconcile(&forecast_for(&first, 5.0, 100.0), &edited, 0.5).expect_err("edited manifest");
+    assert_eq!(err, format!("Manifest {} fails hash verification", first.id));
+    forecast.hash_version = HASH_VERSION_CANONICAL + 1;
+    record.hash_version = HASH_VERSION_CANONICAL + 1;
+    assert!(forecast.compute_hash().is_none() && !forecast.verify_hash(), "unknown hash version");
+    assert!(record.compute_hash().is_none() && !record.verify_hash(), "unknown hash version");
+}
@@ -0,0 +1,75 @@
+// path: aln-karma/examples/leakage_accounting.rs
+
+//! Example: leakage on a shard that requires an estimate of it.
+//! - Without a `LeakageEstimate` the manifest is ineligible, and no conversion gets around it:
+//!   `to_karma_allowance` takes the shard policy
+//! - 0.4 t displaced out of 1.5 t avoided: the allowance records gross, leakage and net, and
+//!   is priced on the net
+//! - Leakage beyond the gross avoidance nets to zero: the allowance earns exactly 0 AU.ET,
+//!   never a negative amount
+
+use aln_karma::{
+    BaselineModel, ImpactMetrics, JusticeConstraints, LeakageEstimate, SafetyEpochManifest, ShardPolicy, VNodeId,
+};
+use steward_runtime_support::SequentialIdGenerator;
+
+const SHARD: &str = "policy:aln:mobility:v1";
+const EPOCH0: u64 = 1_767_225_600;
+
+fn manifest(ids: &SequentialIdGenerator, t_co2e_avoided: f64, min_improvement_ratio: f64) -> SafetyEpochManifest {
+    SafetyEpochManifest::new_with_ids(
+        ids,
+        VNodeId { vnode_id: "city:phoenix:traffic:controller-01".into(), policy_shard_id: SHARD.into() },
+        EPOCH0,
+        EPOCH0 + 900,
+        ImpactMetrics { t_co2e_avoided, ..ImpactMetrics::default() },
+        BaselineModel {
+            description: "Phoenix SOV baseline, peak hour".into(),
+            additionality_certified: true,
+            min_improvement_ratio,
+            method: None,
+        },
+        JusticeConstraints { forbid_burden_shifting: true, require_opt_out_respected: true },
+        "merkle-root-controller-01".into(),
+        vec![],
+        None,
+    )
+}
+
+fn rerouted(displaced_t_co2e: f64) -> LeakageEstimate {
+    LeakageEstimate {
+        displaced_t_co2e,
+        boundary_description: "Traffic rerouted onto Tempe arterials".into(),
+        estimation_method: "Loop-detector counts, 2025 weekday mean".into(),
+    }
+}
+
+fn main() {
+    let policy =
+        ShardPolicy { policy_shard_id: SHARD.into(), require_leakage_estimate: true, ..ShardPolicy::default() };
+    let ids = SequentialIdGenerator::new(1);
+
+    // 1. No estimate: ineligible under the shard, and the conversion says so too.
+    let unestimated = manifest(&ids, 1.5, 0.05);
+    assert!(unestimated.is_eligible_for_karma(), "fine on its own");
+    assert!(!unestimated.is_eligible_under_policy(&policy));
+    assert!(unestimated.to_karma_allowance(&policy, None, 10.0, 0.0, 0.0).is_none());
+
+    // 2. Partial leakage: priced on the net, with every figure recorded.
+    let partial = manifest(&ids, 1.5, 0.05).with_leakage(rerouted(0.4));
+    let allowance = partial.to_karma_allowance(&policy, None, 10.0, 0.0, 0.0).expect("eligible");
+    let carbon = &allowance.carbon;
+    assert_eq!((carbon.gross_t_co2e_avoided, carbon.leakage_t_co2e), (1.5, 0.4));
+    assert!((carbon.net_t_co2e_avoided - 1.1).abs() < 1e-9);
+    assert!((allowance.au_et_delta - 11.0).abs() < 1e-9);
+    assert!(allowance.verify_hash());
+
+    // 3. Leakage beyond the gross: the net is zero, and so is the allowance.
+    let swamped = manifest(&ids, 1.5, 0.0).with_leakage(rerouted(3.0));
+    assert_eq!(swamped.net_t_co2e_avoided(), 0.0);
+    let allowance = swamped.to_karma_allowance(&policy, None, 10.0, 0.0, 0.0).expect("eligible, worth nothing");
+    println!("{}", serde_json::to_string_pretty(&allowance.carbon).expect("carbon json"));
+    assert_eq!(allowance.au_et_delta, 0.0, "zero, not negative");
+    assert_eq!(allowance.carbon.leakage_t_co2e, 3.0);
+    assert_eq!(allowance.carbon.net_t_co2e_avoided, 0.0);
+}
@@ -0,0 +1,118 @@
+// path: aln-karma/examples/near_miss_events.rs
+
+//! Example: itemizing the near-misses a traffic manifest counts.
+//! - The vNode log is hashed into a Merkle root; each near-miss event carries its log leaf
+//!   and an inclusion proof against `vnode_log_root`
+//! - Pricing weights events by severity instead of a flat rate per near-miss
+//! - A list shorter than the counter, or a leaf that does not prove into the root, is rejected
+//! - A bare counter passes only under a shard that allows summary-only reporting
+
+use aln_karma::merkle::{inclusion_proof, leaf_hash, merkle_root};
+use aln_karma::{
+    BaselineModel, ImpactMetrics, JusticeConstraints, NearMissCategory, NearMissEvent, NearMissSeverity,
+    PricingTable, SafetyEpochManifest, ShardPolicy, VNodeId,
+};
+use steward_runtime_support::SequentialIdGenerator;
+
+const SHARD: &str = "policy:aln:mobility:v1";
+const EPOCH_START: u64 = 1_767_225_600;
+
+fn manifest(near_misses_blocked: u64, vnode_log_root: String) -> SafetyEpochManifest {
+    SafetyEpochManifest::new_with_ids(
+        &SequentialIdGenerator::new(9),
+        VNodeId { vnode_id: "city:phoenix:traffic:controller-01".into(), policy_shard_id: SHARD.into() },
+        EPOCH_START,
+        EPOCH_START + 900,
+        ImpactMetrics { t_co2e_avoided: 0.8, near_misses_blocked, ..ImpactMetrics::default() },
+        BaselineModel {
+            description: "Phoenix SOV baseline, peak hour".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: None,
+        },
+        JusticeConstraints { forbid_burden_shifting: true, require_opt_out_respected: true },
+        vnode_log_root,
+        vec![],
+        None,
+    )
+}
+
+fn policy(allow_near_miss_summary: bool) -> ShardPolicy {
+    ShardPolicy {
+        policy_shard_id: SHARD.into(),
+        require_leakage_estimate: false,
+        custom_metrics: Default::default(),
+        vintage: None,
+        allow_near_miss_summary,
+        ..ShardPolicy::default()
+    }
+}
+
+fn main() {
+    // 1. The controller's epoch log: routine entries with three near-misses among them.
+    let log: Vec<String> = (0..10)
+        .map(|i| match i {
+            2 => "t=120 pedestrian conflict held, signal 14".to_string(),
+            5 => "t=340 red-light runner, all-red extended, signal 3".to_string(),
+            9 => "t=610 PM2.5 over limit, school-zone reroute".to_string(),
+            _ => format!("t={} routine phase change", i * 60),
+        })
+        .collect();
+    let leaves: Vec<String> = log.iter().map(|entry| leaf_hash(entry.as_bytes())).collect();
+    let root = merkle_root(&leaves).expect("non-empty log");
+
+    let event = |index: usize, category: NearMissCategory, severity: NearMissSeverity| NearMissEvent {
+        event_id: format!("nm-{index}"),
+        category,
+        detected_at_ms: (EPOCH_START + index as u64 * 60) * 1000,
+        severity,
+        log_leaf_hash: leaves[index].clone(),
+        inclusion_proof: inclusion_proof(&leaves, index).expect("index in log"),
+    };
+    let events = vec![
+        event(2, NearMissCategory::Collision, NearMissSeverity::High),
+        event(5, NearMissCategory::Collision, NearMissSeverity::Critical),
+        event(9, NearMissCategory::OverExposure, NearMissSeverity::Low),
+    ];
+
+    let itemized = manifest(3, root.clone()).with_near_miss_events(events.clone());
+    assert!(itemized.verify_hash());
+    itemized.verify_near_miss_events().expect("count matches and every leaf proves in");
+    assert!(itemized.is_eligible_under_policy(&policy(false)));
+    println!("{}", serde_json::to_string_pretty(&itemized.near_miss_events).expect("events json"));
+
+    // 2. Severity weighting: 2.0 + 4.0 + 0.5 near-miss rates, against a flat 3.
+    let flat = PricingTable::new(0.0, 0.0, 10.0);
+    let weighted = flat
+        .clone()
+        .with_severity_weight(NearMissSeverity::Low, 0.5)
+        .with_severity_weight(NearMissSeverity::High, 2.0)
+        .with_severity_weight(NearMissSeverity::Critical, 4.0);
+    let priced = |pricing| itemized.to_karma_allowance_with_pricing(&policy(false), None, pricing).expect("eligible");
+    let (flat_delta, weighted_delta) = (priced(&flat).au_et_delta, priced(&weighted).au_et_delta);
+    assert_eq!((flat_delta, weighted_delta), (30.0, 65.0));
+
+    // 3. Claiming seven near-misses while listing three is rejected.
+    let inflated = manifest(7, root.clone()).with_near_miss_events(events.clone());
+    let err = inflated.verify_near_miss_events().expect_err("count mismatch");
+    println!("rejected: {err}");
+    assert!(!inflated.is_eligible_for_karma());
+
+    // 4. An event whose leaf is not in the log fails its inclusion proof.
+    let mut forged = events.clone();
+    forged[1].log_leaf_hash = leaf_hash(b"t=345 fabricated near-miss");
+    let forged = manifest(3, root.clone()).with_near_miss_events(forged);
+    let err = forged.verify_near_miss_events().expect_err("leaf not included");
+    println!("rejected: {err}");
+    assert!(err.contains("nm-5"));
+    assert!(forged.to_karma_allowance_with_pricing(&policy(false), None, &flat).is_none());
+
+    // 5. A bare counter: accepted only where the shard allows summary-only reporting.
+    let summary = manifest(3, root);
+    assert!(summary.near_miss_events.is_none());
+    assert!(!summary.is_eligible_under_policy(&policy(false)));
+    let allowance = summary
+        .to_karma_allowance_with_pricing(&policy(true), None, &weighted)
+        .expect("summary-only shard");
+    assert_eq!(allowance.au_et_delta, 30.0, "unlisted near-misses are priced flat");
+}
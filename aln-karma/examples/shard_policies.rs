@@ -0,0 +1,135 @@
+// path: aln-karma/examples/shard_policies.rs
+
+//! Example: two versions of a shard policy governing a vNode's daily manifests.
+//! - v2 tightens the justice constraints and requires leakage estimates from mid-way
+//!   through day 1; day 1 stays under v1, day 2 falls under v2
+//! - A manifest claiming laxer justice constraints, another baseline method or another
+//!   cadence than its shard's version is rejected, and converts to nothing
+//! - The ledger holding the registry refuses allowances converted outside it
+
+use aln_karma::{
+    BaselineModel, ImpactMetrics, JusticeConstraints, KarmaLedger, LeakageEstimate, PricingTable,
+    SafetyEpochManifest, ShardPolicy, ShardPolicyRegistry, VNodeId,
+};
+use steward_runtime_support::SequentialIdGenerator;
+
+const SHARD: &str = "policy:aln:mobility:v1";
+/// 2026-01-01T00:00:00Z, in seconds (epoch bounds are seconds).
+const EPOCH0: u64 = 1_767_225_600;
+const DAY_S: u64 = 86_400;
+
+fn justice(forbid_burden_shifting: bool, require_opt_out_respected: bool) -> JusticeConstraints {
+    JusticeConstraints { forbid_burden_shifting, require_opt_out_respected }
+}
+
+fn manifest(ids: &SequentialIdGenerator, start: u64, end: u64, method: &str, justice: JusticeConstraints) -> SafetyEpochManifest {
+    SafetyEpochManifest::new_with_ids(
+        ids,
+        VNodeId {
+            vnode_id: "city:phoenix:traffic:controller-01".into(),
+            policy_shard_id: SHARD.into(),
+        },
+        start,
+        end,
+        ImpactMetrics { t_co2e_avoided: 1.5, ..ImpactMetrics::default() },
+        BaselineModel {
+            description: "Phoenix SOV baseline, peak hour".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: Some(method.into()),
+        },
+        justice,
+        format!("merkle-root-{start}"),
+        vec![],
+        None,
+    )
+}
+
+fn main() {
+    let v1 = ShardPolicy {
+        policy_shard_id: SHARD.into(),
+        version: 1,
+        effective_from: EPOCH0,
+        baseline_methods: ["sov-peak-hour".to_string()].into(),
+        min_improvement_ratio: 0.05,
+        justice: justice(true, false),
+        epoch_seconds: Some(DAY_S),
+        pricing_schedule: Some("pricing:mobility:2026".into()),
+        ..ShardPolicy::default()
+    };
+    let v2 = ShardPolicy {
+        version: 2,
+        effective_from: EPOCH0 + DAY_S + DAY_S / 2,
+        justice: justice(true, true),
+        require_leakage_estimate: true,
+        ..v1.clone()
+    };
+    let mut registry = ShardPolicyRegistry::new();
+    registry.register(v1.clone()).expect("first version");
+    registry.register(v2.clone()).expect("later version");
+
+    // Versions only move forward, in number and in time.
+    let stale = ShardPolicy { version: 2, effective_from: EPOCH0 + 9 * DAY_S, ..v1.clone() };
+    registry.register(stale).expect_err("version 2 exists");
+    let backdated = ShardPolicy { version: 3, effective_from: EPOCH0, ..v1.clone() };
+    registry.register(backdated).expect_err("takes effect before v2");
+
+    let ids = SequentialIdGenerator::new(1);
+    let pricing = PricingTable::new(10.0, 0.0, 0.0);
+    let day = |n: u64| (EPOCH0 + n * DAY_S, EPOCH0 + (n + 1) * DAY_S);
+
+    // 1. Day 0 under v1: compatible, and the allowance records the version.
+    let (start, end) = day(0);
+    let day0 = manifest(&ids, start, end, "sov-peak-hour", justice(true, false));
+    let allowance0 = day0.to_karma_allowance_in_registry(&registry, None, &pricing).expect("compatible");
+    assert_eq!(allowance0.policy_version, Some(1));
+
+    // 2. Laxer justice than the shard: rejected, and converts to nothing.
+    let lax = manifest(&ids, start, end, "sov-peak-hour", justice(false, false));
+    let reason = registry.check_manifest(&lax).expect_err("allows burden shifting");
+    assert_eq!(reason, format!("Shard {SHARD} v1 forbids burden shifting; the justice constraints allow it"));
+    assert_eq!(lax.to_karma_allowance_in_registry(&registry, None, &pricing).err(), Some(reason));
+
+    // 3. Another baseline method, or none, and another cadence are rejected too.
+    let modeled = manifest(&ids, start, end, "regional-travel-model", justice(true, false));
+    registry.check_manifest(&modeled).expect_err("method not accepted");
+    let hourly = manifest(&ids, start, start + 3_600, "sov-peak-hour", justice(true, false));
+    registry.check_manifest(&hourly).expect_err("not a daily epoch");
+
+    // 4. v2 takes effect mid-way through day 1: the version in force at epoch start wins.
+    let (start, end) = day(1);
+    let day1 = manifest(&ids, start, end, "sov-peak-hour", justice(true, false));
+    let allowance1 = day1
+        .to_karma_allowance_in_registry(&registry, Some(allowance0.self_hash.clone()), &pricing)
+        .expect("still under v1");
+    assert_eq!(allowance1.policy_version, Some(1));
+
+    // 5. Day 2 is under v2: the same settings are now too lax, and a leakage estimate is due.
+    let (start, end) = day(2);
+    let day2_lax = manifest(&ids, start, end, "sov-peak-hour", justice(true, false));
+    registry.check_manifest(&day2_lax).expect_err("v2 requires opt-outs respected");
+    let day2 = manifest(&ids, start, end, "sov-peak-hour", justice(true, true));
+    registry.check_manifest(&day2).expect_err("v2 requires a leakage estimate");
+    let day2 = day2.with_leakage(LeakageEstimate {
+        displaced_t_co2e: 0.1,
+        boundary_description: "Phoenix city limits".into(),
+        estimation_method: "default 5% factor".into(),
+    });
+    let allowance2 = day2
+        .to_karma_allowance_in_registry(&registry, Some(allowance1.self_hash.clone()), &pricing)
+        .expect("compatible with v2");
+    assert_eq!(allowance2.policy_version, Some(2));
+
+    // 6. The ledger checks allowances against the same registry.
+    let mut ledger = KarmaLedger::new().with_shard_policies(registry);
+    let unchecked = day0.to_karma_allowance_with_pricing(&v1, None, &pricing).expect("eligible under v1");
+    let refused = ledger.append(unchecked).expect_err("converted outside the registry");
+    assert!(refused.contains("no policy version"), "{refused}");
+    for allowance in [allowance0, allowance1, allowance2] {
+        ledger.append(allowance).expect("checked against the version in force");
+    }
+    let restored: KarmaLedger =
+        serde_json::from_str(&serde_json::to_string(&ledger).expect("ledger json")).expect("ledger round trip");
+    assert_eq!(restored.shard_policies().map(|r| r.versions(SHARD).len()), Some(2));
+    println!("{} allowances under shard {SHARD}, versions 1, 1, 2", ledger.allowances().len());
+}
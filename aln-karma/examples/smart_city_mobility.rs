@@ -7,7 +7,7 @@
 
 use aln_karma::{
     VNodeId, ImpactMetrics, BaselineModel, JusticeConstraints,
-    SafetyEpochManifest, current_epoch_window,
+    SafetyEpochManifest, ShardPolicy, current_epoch_window,
 };
 
 fn main() {
@@ -22,6 +22,7 @@ fn main() {
         description: "Phoenix SOV baseline, 2018–2020 average, peak hour".into(),
         additionality_certified: true,
         min_improvement_ratio: 0.05,
+        method: None,
     };
 
     let justice = JusticeConstraints {
@@ -45,6 +46,7 @@ fn main() {
         pollution_exposure_delta: -1_500.0, // negative => exposure reduced
         near_misses_blocked: 7,
         biosafety_delta: 0.12,
+        custom: Default::default(),
     };
 
     let manifest = SafetyEpochManifest::new(
@@ -62,8 +64,16 @@ fn main() {
         None,
     );
 
+    // The mobility shard accepts near-miss counts without itemized events.
+    let policy = ShardPolicy {
+        policy_shard_id: "policy:aln:mobility:v1".into(),
+        allow_near_miss_summary: true,
+        ..ShardPolicy::default()
+    };
+
     // Convert to AU.ET karma allowance; no mint/transfer semantics. [web:0][web:3]
     let allowance = manifest.to_karma_allowance(
+        &policy,
         None,
         10.0,  // AU.ET per tCO₂e
         0.01,  // AU.ET per kWh
@@ -0,0 +1,56 @@
+// path: aln-karma/examples/streamed_export.rs
+
+//! Example: manifests and allowances streamed to an auditor and back
+//! (run with `--features test-util`, gzip-compressed with `--features flate2` too).
+//! - `write_manifests` / `read_manifests` carry a day of 15-minute manifests, each checked
+//!   against its own hash on the way in
+//! - A ledger's allowances restore into an empty ledger with the same budget
+//! - Restoring them again is refused, since the chain would not link, and appends nothing
+
+use aln_karma::builders::{smart_city_mobility_policy, smart_city_mobility_pricing, ManifestBuilder, FIXTURE_START_MS};
+use aln_karma::{read_manifests, write_manifests, Compression, KarmaLedger, SafetyEpochManifest, StreamError};
+
+const EPOCHS: u64 = 96;
+
+fn main() {
+    let compression = if Compression::Gzip.available() { Compression::Gzip } else { Compression::None };
+    let mut manifests: Vec<SafetyEpochManifest> = Vec::new();
+    for i in 0..EPOCHS {
+        let builder = ManifestBuilder::new("city:phoenix:traffic:controller-01").seed(i);
+        let builder = builder.at_ms(FIXTURE_START_MS + i * 900_000);
+        let builder = match manifests.last() {
+            Some(prev) => builder.follows(prev),
+            None => builder,
+        };
+        manifests.push(builder.build_valid());
+    }
+
+    // 1. Manifests out and back, one at a time.
+    let mut exported = Vec::new();
+    assert_eq!(write_manifests(&mut exported, &manifests, compression).expect("written").records, EPOCHS);
+    let read: Vec<SafetyEpochManifest> =
+        read_manifests(exported.as_slice()).expect("header").collect::<Result<_, _>>().expect("all verify");
+    let json = |value: &[SafetyEpochManifest]| serde_json::to_string(value).expect("json");
+    assert_eq!(json(&read), json(&manifests));
+    println!("{EPOCHS} manifests in {} bytes ({compression:?})", exported.len());
+
+    // 2. The allowances they earned, restored into an empty ledger.
+    let policy = smart_city_mobility_policy();
+    let mut ledger = KarmaLedger::new();
+    for manifest in &manifests {
+        let prev = ledger.allowances().last().map(|a| a.self_hash.clone());
+        let allowance = manifest.to_karma_allowance_with_pricing(&policy, prev, &smart_city_mobility_pricing());
+        ledger.append(allowance.expect("eligible")).expect("links");
+    }
+    let mut allowances = Vec::new();
+    ledger.export_allowances(&mut allowances, compression).expect("written");
+    let mut restored = KarmaLedger::new();
+    assert_eq!(restored.restore_allowances(allowances.as_slice()).expect("restored"), EPOCHS as usize);
+    assert_eq!(restored.total_au_et(FIXTURE_START_MS), ledger.total_au_et(FIXTURE_START_MS));
+
+    // 3. Again: the first allowance no longer links, and nothing is appended.
+    let again = restored.restore_allowances(allowances.as_slice()).expect_err("chain does not link");
+    assert!(matches!(again, StreamError::Rejected { index: 0, .. }), "{again}");
+    assert_eq!(restored.allowances().len(), EPOCHS as usize);
+    println!("{again}");
+}
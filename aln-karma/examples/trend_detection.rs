@@ -0,0 +1,103 @@
+// path: aln-karma/examples/trend_detection.rs
+
+//! Example: regressions across Phoenix vNode epochs (run with `--features test-util`).
+//! - A controller's avoidance declining six epochs running, a near-miss count jumping tenfold
+//!   and a sensor stuck on the same readings for twelve epochs each make one finding
+//! - Window edges: five declines, eleven identical epochs, or a spike with fewer than
+//!   `spike_window` epochs before it find nothing; neither does an empty chain
+//! - A shard requiring it holds manifests after a flatline until one carries a data-quality
+//!   annotation; the same manifests pass under a shard that does not
+//! - Findings serialize, and a chain refuses an epoch that does not follow its last
+
+use aln_karma::builders::{epoch_chain, ManifestBuilder, FIXTURE_EPOCH_SECONDS, FIXTURE_START_MS};
+use aln_karma::{
+    DataQualityAnnotation, EpochChain, ImpactMetrics, SafetyEpochManifest, ShardPolicy, TrendConfig, TrendDetector,
+    TrendFinding, TrendKind, TrendMetric,
+};
+
+const VNODE: &str = "city:phoenix:traffic:controller-01";
+
+fn metrics(t_co2e_avoided: f64, near_misses_blocked: u64) -> ImpactMetrics {
+    ImpactMetrics { t_co2e_avoided, near_misses_blocked, ..ImpactMetrics::default() }
+}
+
+fn analyze(metrics: Vec<ImpactMetrics>) -> Vec<TrendFinding> {
+    let chain = EpochChain::new(epoch_chain(VNODE, 1, metrics)).expect("linked fixture chain");
+    TrendDetector::analyze(&chain, TrendConfig::default())
+}
+
+fn main() {
+    // 1. Six declines in a row: one finding over the seven epochs.
+    let declining: Vec<ImpactMetrics> =
+        [3.0, 3.0, 2.8, 2.6, 2.4, 2.2, 2.0, 1.8].iter().zip(1..).map(|(t, n)| metrics(*t, n)).collect();
+    let findings = analyze(declining.clone());
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].metric, Some(TrendMetric::NetTCo2eAvoided));
+    assert_eq!(findings[0].kind, TrendKind::ConsecutiveDecline { declines: 6, first: 3.0, last: 1.8 });
+    let epoch_s = FIXTURE_EPOCH_SECONDS;
+    assert_eq!(findings[0].epoch_end - findings[0].epoch_start, 7 * epoch_s);
+    assert!(analyze(declining[..7].to_vec()).is_empty(), "five declines");
+
+    // 2. Near-misses: 2 or 3 an epoch, then 30. Against a flat window the z-score is undefined.
+    let mut noisy: Vec<ImpactMetrics> = (0..8).map(|i| metrics(2.5 + i as f64, 2 + i % 2)).collect();
+    noisy.push(metrics(11.0, 30));
+    let findings = analyze(noisy.clone());
+    assert_eq!(findings.len(), 1);
+    let TrendKind::Spike { value, trailing_mean, trailing_std_dev, z_score } = findings[0].kind else {
+        panic!("a spike: {findings:?}");
+    };
+    assert_eq!((value, trailing_mean, trailing_std_dev), (30.0, 2.5, 0.5));
+    assert_eq!(z_score, Some(55.0));
+    assert!(analyze(noisy[3..].to_vec()).is_empty(), "five epochs before the jump, window is eight");
+    let mut quiet: Vec<ImpactMetrics> = (0..8).map(|i| metrics(2.0 + i as f64, 2)).collect();
+    quiet.push(metrics(11.0, 25));
+    assert!(matches!(analyze(quiet)[0].kind, TrendKind::Spike { z_score: None, .. }));
+    quiet = (0..8).map(|i| metrics(2.0 + i as f64, 2)).chain([metrics(11.0, 5)]).collect();
+    assert!(analyze(quiet).is_empty(), "above a flat window, but not tenfold");
+
+    // 3. A stuck sensor: twelve identical epochs flatline, eleven do not.
+    let stuck = vec![metrics(2.7, 7); 12];
+    let findings = analyze(stuck.clone());
+    assert_eq!(findings.len(), 1);
+    assert_eq!((findings[0].metric.clone(), findings[0].kind.clone()), (None, TrendKind::Flatline { epochs: 12 }));
+    assert!(analyze(stuck[1..].to_vec()).is_empty());
+    assert!(TrendDetector::analyze(&EpochChain::default(), TrendConfig::default()).is_empty());
+
+    // 4. After the flatline: held under a shard requiring annotation until one is attached.
+    let mut chain = EpochChain::new(epoch_chain(VNODE, 1, stuck)).expect("linked");
+    let findings = TrendDetector::analyze(&chain, TrendConfig::default());
+    let next = |seed: u64, epoch: u64, prev: &SafetyEpochManifest| {
+        ManifestBuilder::new(VNODE)
+            .follows(prev)
+            .at_ms(FIXTURE_START_MS + epoch * epoch_s * 1_000)
+            .seed(seed)
+            .build()
+    };
+    let unannotated = next(100, 12, chain.manifests().last().expect("stuck epochs"));
+    let strict = ShardPolicy {
+        policy_shard_id: "policy:aln:mobility:v1".into(),
+        allow_near_miss_summary: true,
+        require_data_quality_after_flatline: true,
+        ..ShardPolicy::default()
+    };
+    let lenient = ShardPolicy { require_data_quality_after_flatline: false, ..strict.clone() };
+    assert!(!chain.is_eligible_under_policy(&unannotated, &strict, &findings));
+    assert!(chain.is_eligible_under_policy(&unannotated, &lenient, &findings));
+    let annotated = unannotated.with_data_quality(DataQualityAnnotation {
+        note: "PM2.5 sensor 14 replaced; the twelve epochs before read a stuck value".into(),
+        annotated_by: "did:psv:city:phoenix:traffic-ops".into(),
+    });
+    assert!(annotated.verify_hash());
+    assert!(chain.is_eligible_under_policy(&annotated, &strict, &findings));
+    chain.push(annotated.clone()).expect("follows the stuck epochs");
+    let after = next(101, 13, &annotated);
+    assert!(chain.is_eligible_under_policy(&after, &strict, &findings), "resolved by the annotated epoch");
+
+    // 5. Findings round-trip; an epoch that skips the chain's last is refused.
+    let json = serde_json::to_string_pretty(&findings).expect("json");
+    assert_eq!(serde_json::from_str::<Vec<TrendFinding>>(&json).expect("findings"), findings);
+    println!("{json}");
+    let stray = ManifestBuilder::new(VNODE).at_ms(FIXTURE_START_MS + 20 * epoch_s * 1_000).seed(102).build();
+    let refused = chain.push(stray).expect_err("no prev_hash");
+    assert!(refused.contains("does not follow"), "{refused}");
+}
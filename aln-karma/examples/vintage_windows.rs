@@ -0,0 +1,124 @@
+// path: aln-karma/examples/vintage_windows.rs
+
+//! Example: allowance vintage under a hard-cutoff and a linear-decay shard policy.
+//! - The same four monthly manifests are converted under each policy (twelve-month validity)
+//! - Budgets are read at several dates; expired allowances count for nothing
+//! - `expiring_within` shows the cliffs ahead
+//! - Reading never touches the stored allowances: their hashes verify at every date
+
+use aln_karma::{
+    BaselineModel, ImpactMetrics, JusticeConstraints, KarmaLedger, PricingTable, SafetyEpochManifest, ShardPolicy,
+    VNodeId, VintageDecay, VintageRule,
+};
+use steward_runtime_support::SequentialIdGenerator;
+
+const SHARD: &str = "policy:aln:mobility:v1";
+/// 2026-01-01T00:00:00Z, in seconds (epoch bounds are seconds).
+const EPOCH0: u64 = 1_767_225_600;
+const DAY_MS: u64 = 86_400_000;
+const MONTH_S: u64 = 30 * 86_400;
+const YEAR_MS: u64 = 365 * DAY_MS;
+
+/// Four consecutive monthly epochs, each avoiding 1 tCO₂e more than the last.
+fn fixture_chain() -> Vec<SafetyEpochManifest> {
+    let ids = SequentialIdGenerator::new(11);
+    (0..4)
+        .map(|i| {
+            SafetyEpochManifest::new_with_ids(
+                &ids,
+                VNodeId {
+                    vnode_id: "city:phoenix:traffic:controller-01".into(),
+                    policy_shard_id: SHARD.into(),
+                },
+                EPOCH0 + i * MONTH_S,
+                EPOCH0 + (i + 1) * MONTH_S,
+                ImpactMetrics { t_co2e_avoided: (i + 1) as f64, ..ImpactMetrics::default() },
+                BaselineModel {
+                    description: "Phoenix SOV baseline, peak hour".into(),
+                    additionality_certified: true,
+                    min_improvement_ratio: 0.05,
+                    method: None,
+                },
+                JusticeConstraints {
+                    forbid_burden_shifting: true,
+                    require_opt_out_respected: true,
+                },
+                format!("merkle-root-month-{i}"),
+                vec![],
+                None,
+            )
+        })
+        .collect()
+}
+
+fn ledger_under(decay: VintageDecay) -> KarmaLedger {
+    let policy = ShardPolicy {
+        policy_shard_id: SHARD.into(),
+        require_leakage_estimate: false,
+        custom_metrics: Default::default(),
+        vintage: Some(VintageRule { validity_ms: YEAR_MS, decay }),
+        allow_near_miss_summary: false,
+        ..ShardPolicy::default()
+    };
+    let pricing = PricingTable::new(10.0, 0.0, 0.0);
+    let mut ledger = KarmaLedger::new();
+    let mut prev = None;
+    for manifest in fixture_chain() {
+        let allowance = manifest
+            .to_karma_allowance_with_pricing(&policy, prev.clone(), &pricing)
+            .expect("eligible");
+        prev = Some(allowance.self_hash.clone());
+        ledger.append(allowance).expect("links to the previous allowance");
+    }
+    ledger
+}
+
+fn main() {
+    let hard = ledger_under(VintageDecay::HardCutoff);
+    let linear = ledger_under(VintageDecay::Linear);
+    let hashes = |l: &KarmaLedger| l.allowances().iter().map(|a| a.self_hash.clone()).collect::<Vec<_>>();
+    let (hard_hashes, linear_hashes) = (hashes(&hard), hashes(&linear));
+    assert_ne!(hard_hashes, linear_hashes, "the window is part of the sealed allowance");
+
+    // Epoch k (0-based) ends at month k+1 and is usable until a year later.
+    let end_of = |k: u64| (EPOCH0 + (k + 1) * MONTH_S) * 1_000;
+    let dates = [
+        ("last epoch end", end_of(3)),
+        ("first window closes", end_of(0) + YEAR_MS),
+        ("two windows closed", end_of(1) + YEAR_MS),
+        ("all expired", end_of(3) + YEAR_MS),
+    ];
+    for (label, as_of) in dates {
+        println!("{label:>20}: hard {:>6.2} AU.ET, linear {:>6.2} AU.ET", hard.total_au_et(as_of), linear.total_au_et(as_of));
+    }
+
+    // Hard cutoff: full value until the window closes, then nothing.
+    assert_eq!(hard.total_au_et(end_of(3)), 100.0);
+    assert_eq!(hard.total_au_et(end_of(0) + YEAR_MS), 90.0);
+    assert_eq!(hard.total_au_et(end_of(1) + YEAR_MS), 70.0);
+    assert_eq!(hard.total_au_et(end_of(3) + YEAR_MS), 0.0);
+
+    // Linear: already below full value, and never below zero.
+    let at = end_of(3);
+    let expected: f64 = (0..4)
+        .map(|k| 10.0 * (k + 1) as f64 * (1.0 - (at - end_of(k)) as f64 / YEAR_MS as f64))
+        .sum();
+    assert!((linear.total_au_et(at) - expected).abs() < 1e-9);
+    assert!(linear.total_au_et(at) < hard.total_au_et(at));
+    assert_eq!(linear.total_au_et(end_of(3) + YEAR_MS), 0.0);
+    assert_eq!(linear.total_au_et(u64::MAX), 0.0);
+
+    // Cliffs in the next 45 days, seen from just before the first window closes.
+    let from = end_of(0) + YEAR_MS - DAY_MS;
+    let cliffs = hard.expiring_within(45 * DAY_MS, from);
+    assert_eq!(cliffs.len(), 2);
+    assert_eq!(cliffs[0].metrics.t_co2e_avoided, 1.0);
+    for a in &cliffs {
+        let until = a.vintage.as_ref().expect("vintage window").usable_until_ms;
+        println!("expires in {} days: {:.2} AU.ET", (until - from) / DAY_MS, a.au_et_delta);
+    }
+
+    // Nothing stored changed along the way.
+    assert_eq!(hashes(&hard), hard_hashes);
+    assert!(hard.allowances().iter().chain(linear.allowances()).all(|a| a.verify_hash()));
+}
@@ -0,0 +1,299 @@
+// path: aln-karma/src/analysis.rs
+
+//! Trends across a vNode's epochs, for shard operators watching for regressions.
+//! - `EpochChain` is one vNode's manifests in epoch order, each linked to the last by `prev_hash`
+//! - `TrendDetector::analyze` looks for three things, per `TrendConfig`:
+//!   - a metric declining epoch over epoch for at least `decline_epochs` epochs in a row
+//!   - an epoch whose value sits `spike_z_score` standard deviations above the trailing
+//!     `spike_window` epochs' mean, and at least `spike_min_ratio` times it
+//!   - the same metrics, every field, for at least `flatline_epochs` epochs: usually a stuck sensor
+//! - Window edges: a spike is only looked for in epochs with a full trailing window before
+//!   them, so a chain no longer than `spike_window` has no spike findings; runs shorter than
+//!   their minimum, including whole chains that are, find nothing
+//! - An epoch missing a custom metric breaks that metric's runs and windows
+//! - Findings are advisory. The one thing they gate is opt-in: under a shard with
+//!   `require_data_quality_after_flatline`, a manifest after a flatline began is ineligible
+//!   until it, or one between, carries a `DataQualityAnnotation`
+
+use serde::{Serialize, Deserialize};
+
+use crate::{MetricKey, SafetyEpochManifest, ShardPolicy};
+
+/// A per-epoch number trends are tracked over.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TrendMetric {
+    /// Net of leakage, as allowances are priced.
+    NetTCo2eAvoided,
+    KwhReduced,
+    PollutionExposureDelta,
+    NearMissesBlocked,
+    BiosafetyDelta,
+    Custom(MetricKey),
+}
+
+impl TrendMetric {
+    pub fn value(&self, manifest: &SafetyEpochManifest) -> Option<f64> {
+        let metrics = &manifest.metrics;
+        match self {
+            TrendMetric::NetTCo2eAvoided => Some(manifest.net_t_co2e_avoided()),
+            TrendMetric::KwhReduced => Some(metrics.kwh_reduced),
+            TrendMetric::PollutionExposureDelta => Some(metrics.pollution_exposure_delta),
+            TrendMetric::NearMissesBlocked => Some(metrics.near_misses_blocked as f64),
+            TrendMetric::BiosafetyDelta => Some(metrics.biosafety_delta),
+            TrendMetric::Custom(key) => metrics.custom.get(key).map(|v| v.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TrendConfig {
+    /// Metrics watched for a run of declines.
+    pub decline_metrics: Vec<TrendMetric>,
+    /// Epoch-over-epoch declines in a row that make a finding.
+    pub decline_epochs: usize,
+    /// Metrics watched for spikes.
+    pub spike_metrics: Vec<TrendMetric>,
+    /// Trailing epochs a spike is measured against.
+    pub spike_window: usize,
+    pub spike_z_score: f64,
+    /// Floor on value / trailing mean, so a quiet window's small wobble is no spike.
+    pub spike_min_ratio: f64,
+    /// Identical epochs in a row that make a flatline.
+    pub flatline_epochs: usize,
+}
+
+impl Default for TrendConfig {
+    fn default() -> Self {
+        Self {
+            decline_metrics: vec![TrendMetric::NetTCo2eAvoided],
+            decline_epochs: 6,
+            spike_metrics: vec![TrendMetric::NearMissesBlocked],
+            spike_window: 8,
+            spike_z_score: 3.0,
+            spike_min_ratio: 10.0,
+            flatline_epochs: 12,
+        }
+    }
+}
+
+/// What was found, with the statistics behind it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TrendKind {
+    ConsecutiveDecline { declines: usize, first: f64, last: f64 },
+    /// `z_score` is `None` when the trailing window did not vary.
+    Spike { value: f64, trailing_mean: f64, trailing_std_dev: f64, z_score: Option<f64> },
+    Flatline { epochs: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TrendFinding {
+    pub vnode_id: String,
+    /// `None` for a flatline, which covers every metric.
+    pub metric: Option<TrendMetric>,
+    /// Start of the first epoch the finding covers.
+    pub epoch_start: u64,
+    /// End of the last.
+    pub epoch_end: u64,
+    pub kind: TrendKind,
+}
+
+/// Why a manifest's data may be off, attached by the shard operator; see
+/// `SafetyEpochManifest::with_data_quality`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DataQualityAnnotation {
+    /// e.g. "PM2.5 sensor 14 replaced 2026-03-02; epochs before read a stuck value".
+    pub note: String,
+    pub annotated_by: String,
+}
+
+/// One vNode's manifests, oldest first, each following the one before.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EpochChain {
+    manifests: Vec<SafetyEpochManifest>,
+}
+
+impl EpochChain {
+    pub fn new(manifests: impl IntoIterator<Item = SafetyEpochManifest>) -> Result<Self, String> {
+        let mut chain = Self::default();
+        for manifest in manifests {
+            chain.push(manifest)?;
+        }
+        Ok(chain)
+    }
+
+    /// Append the next epoch: same vNode, starting no earlier than the last ended, linked to it.
+    pub fn push(&mut self, manifest: SafetyEpochManifest) -> Result<(), String> {
+        if !manifest.verify_hash() {
+            return Err(format!("Manifest {} does not verify", manifest.id));
+        }
+        if let Some(last) = self.manifests.last() {
+            if manifest.vnode.vnode_id != last.vnode.vnode_id {
+                return Err(format!(
+                    "Manifest {} is for vNode {}, not {}",
+                    manifest.id, manifest.vnode.vnode_id, last.vnode.vnode_id
+                ));
+            }
+            if manifest.epoch_start < last.epoch_end {
+                return Err(format!(
+                    "Manifest {} starts at {}, before the last epoch ends at {}",
+                    manifest.id, manifest.epoch_start, last.epoch_end
+                ));
+            }
+            if manifest.prev_hash.as_deref() != Some(last.self_hash.as_str()) {
+                return Err(format!("Manifest {} does not follow {}", manifest.id, last.id));
+            }
+        }
+        self.manifests.push(manifest);
+        Ok(())
+    }
+
+    pub fn manifests(&self) -> &[SafetyEpochManifest] {
+        &self.manifests
+    }
+
+    /// A flatline `findings` report that began before `manifest`'s epoch, with no annotated
+    /// manifest in the chain from after its start up to `manifest`.
+    pub fn unresolved_flatline<'a>(
+        &self,
+        manifest: &SafetyEpochManifest,
+        findings: &'a [TrendFinding],
+    ) -> Option<&'a TrendFinding> {
+        findings.iter().find(|finding| {
+            matches!(finding.kind, TrendKind::Flatline { .. })
+                && finding.vnode_id == manifest.vnode.vnode_id
+                && finding.epoch_start < manifest.epoch_start
+                && manifest.data_quality.is_none()
+                && !self.manifests.iter().any(|m| {
+                    m.data_quality.is_some()
+                        && m.epoch_start > finding.epoch_start
+                        && m.epoch_start <= manifest.epoch_start
+                })
+        })
+    }
+
+    /// `is_eligible_under_policy`, and, when the shard asks for it, no unresolved flatline.
+    pub fn is_eligible_under_policy(
+        &self,
+        manifest: &SafetyEpochManifest,
+        policy: &ShardPolicy,
+        findings: &[TrendFinding],
+    ) -> bool {
+        if policy.require_data_quality_after_flatline && self.unresolved_flatline(manifest, findings).is_some() {
+            return false;
+        }
+        manifest.is_eligible_under_policy(policy)
+    }
+}
+
+/// Looks for declines, spikes and flatlines in an `EpochChain`.
+pub struct TrendDetector;
+
+impl TrendDetector {
+    /// Every finding, declines then spikes then flatlines, each in epoch order.
+    pub fn analyze(chain: &EpochChain, config: TrendConfig) -> Vec<TrendFinding> {
+        let manifests = chain.manifests();
+        let mut findings = Vec::new();
+        for metric in &config.decline_metrics {
+            findings.extend(declines(manifests, metric, config.decline_epochs));
+        }
+        for metric in &config.spike_metrics {
+            findings.extend(spikes(manifests, metric, &config));
+        }
+        findings.extend(flatlines(manifests, config.flatline_epochs));
+        findings
+    }
+}
+
+fn finding(run: &[SafetyEpochManifest], metric: Option<&TrendMetric>, kind: TrendKind) -> TrendFinding {
+    TrendFinding {
+        vnode_id: run[0].vnode.vnode_id.clone(),
+        metric: metric.cloned(),
+        epoch_start: run[0].epoch_start,
+        epoch_end: run[run.len() - 1].epoch_end,
+        kind,
+    }
+}
+
+/// Maximal runs of strict declines at least `min_declines` long.
+fn declines(manifests: &[SafetyEpochManifest], metric: &TrendMetric, min_declines: usize) -> Vec<TrendFinding> {
+    let values: Vec<Option<f64>> = manifests.iter().map(|m| metric.value(m)).collect();
+    let mut findings = Vec::new();
+    let mut start = 0;
+    for end in 1..=values.len() {
+        let declined = end < values.len() && matches!((values[end - 1], values[end]), (Some(a), Some(b)) if b < a);
+        if declined {
+            continue;
+        }
+        let declines = end - 1 - start;
+        if declines >= min_declines.max(1) {
+            let (first, last) = (values[start].unwrap_or_default(), values[end - 1].unwrap_or_default());
+            findings.push(finding(
+                &manifests[start..end],
+                Some(metric),
+                TrendKind::ConsecutiveDecline { declines, first, last },
+            ));
+        }
+        start = end;
+    }
+    findings
+}
+
+/// Epochs with a full trailing window that they spike above.
+fn spikes(manifests: &[SafetyEpochManifest], metric: &TrendMetric, config: &TrendConfig) -> Vec<TrendFinding> {
+    let window = config.spike_window.max(1);
+    let values: Vec<Option<f64>> = manifests.iter().map(|m| metric.value(m)).collect();
+    let mut findings = Vec::new();
+    for i in window..values.len() {
+        let trailing: Option<Vec<f64>> = values[i - window..i].iter().copied().collect();
+        let (Some(value), Some(trailing)) = (values[i], trailing) else {
+            continue;
+        };
+        let mean = trailing.iter().sum::<f64>() / window as f64;
+        let std_dev = (trailing.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / window as f64).sqrt();
+        let z_score = (std_dev > 0.0).then(|| (value - mean) / std_dev);
+        let beyond = z_score.map_or(value > mean, |z| z >= config.spike_z_score);
+        let ratio_ok = if mean > 0.0 { value / mean >= config.spike_min_ratio } else { value > mean };
+        if beyond && ratio_ok {
+            findings.push(finding(
+                &manifests[i..=i],
+                Some(metric),
+                TrendKind::Spike { value, trailing_mean: mean, trailing_std_dev: std_dev, z_score },
+            ));
+        }
+    }
+    findings
+}
+
+/// Maximal runs of identical metrics at least `min_epochs` long.
+fn flatlines(manifests: &[SafetyEpochManifest], min_epochs: usize) -> Vec<TrendFinding> {
+    let metrics: Vec<serde_json::Value> =
+        manifests.iter().map(|m| serde_json::to_value(&m.metrics).expect("metrics serialization")).collect();
+    let mut findings = Vec::new();
+    let mut start = 0;
+    for end in 1..=metrics.len() {
+        if end < metrics.len() && metrics[end] == metrics[start] {
+            continue;
+        }
+        let epochs = end - start;
+        if epochs >= min_epochs.max(2) {
+            findings.push(finding(&manifests[start..end], None, TrendKind::Flatline { epochs }));
+        }
+        start = end;
+    }
+    findings
+}
+
+impl SafetyEpochManifest {
+    /// Attach a data-quality annotation and re-seal the manifest hash.
+    pub fn with_data_quality(mut self, annotation: DataQualityAnnotation) -> Self {
+        self.data_quality = Some(annotation);
+        self.self_hash = self.compute_hash().unwrap_or_default();
+        self
+    }
+}
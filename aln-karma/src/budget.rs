@@ -0,0 +1,428 @@
+// path: aln-karma/src/budget.rs
+
+//! Internal budget accounting: allocating a vNode's earned AU.ET to planned expenditures.
+//! - `KarmaLedger::budget_book(vnode)` opens the vNode's `BudgetBook`, which holds that vNode's
+//!   allowances and no others: another vNode's allowance cannot be resolved in it, so budgets
+//!   stay non-transferable by construction
+//! - Every cited allowance must be recorded, not invalidated and inside its vintage window at
+//!   allocation time; they are drawn from in the order cited
+//! - Allowances are consumed by fraction, so an unallocated remainder keeps decaying with its
+//!   allowance's vintage
+//! - Allocation records are hash-chained per vNode; `VNodeBudget::verify_chain` re-checks them
+//! - An allowance invalidated or expired after allocations drew on it leaves them unbacked:
+//!   `BudgetBook::reconcile_invalidations` marks them `Underfunded` and counts the shortfall
+//!   against the vNode's available budget; `cure_shortfall` draws it from replacement
+//!   allowances in a new chained record. Records are never changed or removed
+//! - Under `ShortfallPolicy::BlockAllocations` (the default) nothing new is allocated while a
+//!   shortfall is unresolved
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+use crate::canonical::CanonicalEncoding;
+use crate::{hash_bytes, AllowanceInvalidation, KarmaAllowance, KarmaLedger, VNodeId};
+
+/// Rounding slack when comparing AU.ET amounts and fractions.
+const EPSILON: f64 = 1e-9;
+
+/// What the AU.ET is set aside for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BudgetPurpose {
+    /// Planned downtime, e.g. controller firmware or actuator servicing.
+    MaintenanceWindow { description: String },
+    /// New or denser sensing.
+    SensorCoverage { description: String },
+    Other { description: String },
+}
+
+/// AU.ET one allocation took from one allowance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AllocationDraw {
+    pub allowance_id: Uuid,
+    pub au_et: f64,
+    /// Share of the allowance this draw consumed.
+    pub fraction: f64,
+}
+
+/// One allocation, linked to the vNode's previous one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AllocationRecord {
+    pub vnode_id: String,
+    /// Position in the vNode's allocation history, from 0.
+    pub seq: u64,
+    pub purpose: BudgetPurpose,
+    pub amount_au_et: f64,
+    /// In the order the allowances were cited.
+    pub draws: Vec<AllocationDraw>,
+    pub allocated_ms: u64,
+    /// `seq` of the underfunded allocation this record cures.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cures: Option<u64>,
+    pub prev_hash: Option<String>,
+    pub self_hash: String,
+}
+
+impl AllocationRecord {
+    pub fn compute_hash(&self) -> String {
+        hash_bytes(&self.to_canonical_bytes())
+    }
+
+    /// Whether `self_hash` matches the record's contents.
+    pub fn verify_hash(&self) -> bool {
+        self.self_hash == self.compute_hash()
+    }
+}
+
+impl CanonicalEncoding for AllocationRecord {}
+
+/// Whether an allocation is still backed by the allowances it drew on.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AllocationStatus {
+    #[default]
+    Funded,
+    /// AU.ET drawn from allowances since invalidated or expired, and which those were.
+    Underfunded { shortfall_au_et: f64, allowance_ids: Vec<Uuid> },
+    /// Made whole by the record at `cure_seq`.
+    Cured { cure_seq: u64 },
+}
+
+/// What reconciliation found unbacked on one allocation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BudgetShortfall {
+    pub vnode_id: String,
+    pub seq: u64,
+    pub shortfall_au_et: f64,
+    pub allowance_ids: Vec<Uuid>,
+}
+
+/// Whether a vNode may allocate while one of its allocations is underfunded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ShortfallPolicy {
+    /// Only `cure_shortfall` records anything until every shortfall is cured.
+    #[default]
+    BlockAllocations,
+    AllowAllocations,
+}
+
+/// A vNode's allocations and what they consumed of each allowance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VNodeBudget {
+    vnode: VNodeId,
+    allocations: Vec<AllocationRecord>,
+    /// Consumed share of each drawn allowance, in [0, 1].
+    consumed: BTreeMap<Uuid, f64>,
+    /// By `seq`; allocations not listed are funded.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    statuses: BTreeMap<u64, AllocationStatus>,
+}
+
+impl VNodeBudget {
+    fn new(vnode: VNodeId) -> Self {
+        Self { vnode, allocations: Vec::new(), consumed: BTreeMap::new(), statuses: BTreeMap::new() }
+    }
+
+    pub fn vnode(&self) -> &VNodeId {
+        &self.vnode
+    }
+
+    /// Oldest first.
+    pub fn allocations(&self) -> &[AllocationRecord] {
+        &self.allocations
+    }
+
+    pub fn consumed_fraction(&self, allowance_id: &Uuid) -> f64 {
+        self.consumed.get(allowance_id).copied().unwrap_or(0.0)
+    }
+
+    /// Status of the allocation at `seq`, as of the last reconciliation or cure.
+    pub fn status(&self, seq: u64) -> AllocationStatus {
+        self.statuses.get(&seq).cloned().unwrap_or_default()
+    }
+
+    /// Underfunded allocations, oldest first.
+    pub fn shortfalls(&self) -> Vec<BudgetShortfall> {
+        self.statuses
+            .iter()
+            .filter_map(|(seq, status)| match status {
+                AllocationStatus::Underfunded { shortfall_au_et, allowance_ids } => Some(BudgetShortfall {
+                    vnode_id: self.vnode.vnode_id.clone(),
+                    seq: *seq,
+                    shortfall_au_et: *shortfall_au_et,
+                    allowance_ids: allowance_ids.clone(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// AU.ET allocated but no longer backed, across uncured allocations.
+    pub fn unresolved_shortfall(&self) -> f64 {
+        self.shortfalls().iter().map(|s| s.shortfall_au_et).sum()
+    }
+
+    /// Check every record's hash and its link to the one before.
+    pub fn verify_chain(&self) -> Result<(), String> {
+        let mut prev: Option<&String> = None;
+        for (index, record) in self.allocations.iter().enumerate() {
+            if record.seq != index as u64 || record.vnode_id != self.vnode.vnode_id {
+                return Err(format!("Allocation {index} is out of place in vNode {}'s history", self.vnode.vnode_id));
+            }
+            if record.prev_hash.as_ref() != prev {
+                return Err(format!("Allocation {index} does not link to the previous allocation"));
+            }
+            if !record.verify_hash() {
+                return Err(format!("Allocation {index} fails hash verification"));
+            }
+            prev = Some(&record.self_hash);
+        }
+        Ok(())
+    }
+
+    /// AU.ET of `allowance` not yet allocated, as it counts at `as_of_ms`.
+    fn remaining(&self, allowance: &KarmaAllowance, as_of_ms: u64) -> f64 {
+        allowance.usable_au_et(as_of_ms) * (1.0 - self.consumed_fraction(&allowance.id)).max(0.0)
+    }
+}
+
+/// One vNode's view of the ledger for allocating its own AU.ET.
+pub struct BudgetBook<'a> {
+    allowances: BTreeMap<Uuid, &'a KarmaAllowance>,
+    invalidations: &'a BTreeMap<Uuid, AllowanceInvalidation>,
+    shortfall_policy: ShortfallPolicy,
+    budget: &'a mut VNodeBudget,
+}
+
+impl BudgetBook<'_> {
+    pub fn vnode(&self) -> &VNodeId {
+        &self.budget.vnode
+    }
+
+    /// Set aside `amount` AU.ET for `purpose`, drawn from `allowance_ids` in order. Nothing is
+    /// recorded unless the cited allowances cover the whole amount, nor while the vNode has an
+    /// unresolved shortfall under `ShortfallPolicy::BlockAllocations`.
+    pub fn allocate(
+        &mut self,
+        allowance_ids: &[Uuid],
+        purpose: BudgetPurpose,
+        amount: f64,
+        now_ms: u64,
+    ) -> Result<AllocationRecord, String> {
+        if self.shortfall_policy == ShortfallPolicy::BlockAllocations {
+            if let Some(first) = self.budget.shortfalls().first() {
+                return Err(format!(
+                    "vNode {} has an unresolved shortfall on allocation {}; cure it first",
+                    self.budget.vnode.vnode_id, first.seq
+                ));
+            }
+        }
+        let draws = self.draw(allowance_ids, amount, now_ms)?;
+        Ok(self.seal(purpose, amount, draws, now_ms, None))
+    }
+
+    /// Mark allocations whose draws cite allowances invalidated, or expired at `now_ms`, as
+    /// `Underfunded` by the AU.ET those draws took, and return every unresolved shortfall.
+    /// Decay inside a window is not a shortfall: draws are fixed in AU.ET when allocated.
+    /// A shortfall only grows; cured allocations are checked through their cure records.
+    pub fn reconcile_invalidations(&mut self, now_ms: u64) -> Vec<BudgetShortfall> {
+        for record in &self.budget.allocations {
+            if matches!(self.budget.statuses.get(&record.seq), Some(AllocationStatus::Cured { .. })) {
+                continue;
+            }
+            let unbacked: Vec<&AllocationDraw> = record
+                .draws
+                .iter()
+                .filter(|draw| {
+                    self.invalidations.contains_key(&draw.allowance_id)
+                        || self
+                            .allowances
+                            .get(&draw.allowance_id)
+                            .and_then(|a| a.vintage.as_ref())
+                            .is_some_and(|v| v.is_expired(now_ms))
+                })
+                .collect();
+            if unbacked.is_empty() {
+                continue;
+            }
+            let shortfall_au_et: f64 = unbacked.iter().map(|d| d.au_et).sum();
+            let allowance_ids = unbacked.iter().map(|d| d.allowance_id).collect();
+            let previous = match self.budget.statuses.get(&record.seq) {
+                Some(AllocationStatus::Underfunded { shortfall_au_et, .. }) => *shortfall_au_et,
+                _ => 0.0,
+            };
+            if shortfall_au_et > previous + EPSILON {
+                let status = AllocationStatus::Underfunded { shortfall_au_et, allowance_ids };
+                self.budget.statuses.insert(record.seq, status);
+            }
+        }
+        self.budget.shortfalls()
+    }
+
+    /// Make underfunded allocation `seq` whole: its shortfall is drawn from `replacement_ids`
+    /// in order, as a new record with the same purpose that cites `seq` in `cures`.
+    pub fn cure_shortfall(
+        &mut self,
+        seq: u64,
+        replacement_ids: &[Uuid],
+        now_ms: u64,
+    ) -> Result<AllocationRecord, String> {
+        let vnode_id = &self.budget.vnode.vnode_id;
+        let Some(AllocationStatus::Underfunded { shortfall_au_et, .. }) = self.budget.statuses.get(&seq) else {
+            return Err(format!("Allocation {seq} of vNode {vnode_id} has no unresolved shortfall"));
+        };
+        let (amount, purpose) = (*shortfall_au_et, self.budget.allocations[seq as usize].purpose.clone());
+        let draws = self.draw(replacement_ids, amount, now_ms)?;
+        let cure = self.seal(purpose, amount, draws, now_ms, Some(seq));
+        self.budget.statuses.insert(seq, AllocationStatus::Cured { cure_seq: cure.seq });
+        Ok(cure)
+    }
+
+    /// Draw `amount` from the cited allowances in order, if they cover all of it.
+    fn draw(&mut self, allowance_ids: &[Uuid], amount: f64, now_ms: u64) -> Result<Vec<AllocationDraw>, String> {
+        let vnode_id = &self.budget.vnode.vnode_id;
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(format!("Allocation amount must be positive, got {amount}"));
+        }
+        if allowance_ids.is_empty() {
+            return Err("An allocation must cite at least one allowance".into());
+        }
+        let mut seen = BTreeSet::new();
+        if let Some(dup) = allowance_ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(format!("Allowance {dup} is cited twice"));
+        }
+        if let Some(last) = self.budget.allocations.last() {
+            if now_ms < last.allocated_ms {
+                return Err(format!("Allocation time {now_ms} is before vNode {vnode_id}'s last allocation"));
+            }
+        }
+
+        let mut cited = Vec::with_capacity(allowance_ids.len());
+        for id in allowance_ids {
+            let allowance = self
+                .allowances
+                .get(id)
+                .ok_or_else(|| format!("Allowance {id} is not recorded for vNode {vnode_id}"))?;
+            if let Some(invalidation) = self.invalidations.get(id) {
+                return Err(format!("Allowance {id} was invalidated: {}", invalidation.reason));
+            }
+            if let Some(window) = allowance.vintage.as_ref().filter(|v| v.is_expired(now_ms)) {
+                return Err(format!("Allowance {id} stopped counting at {}", window.usable_until_ms));
+            }
+            let remaining = self.budget.remaining(allowance, now_ms);
+            if remaining <= EPSILON {
+                return Err(format!("Allowance {id} is fully allocated"));
+            }
+            cited.push((*allowance, remaining));
+        }
+        let available: f64 = cited.iter().map(|(_, remaining)| remaining).sum();
+        if amount > available + EPSILON {
+            return Err(format!(
+                "Allocation of {amount} AU.ET exceeds the {available} AU.ET left on the cited allowances"
+            ));
+        }
+
+        let mut left = amount;
+        let mut draws = Vec::new();
+        for (allowance, remaining) in cited {
+            if left <= EPSILON {
+                break;
+            }
+            let au_et = remaining.min(left);
+            let fraction = au_et / allowance.usable_au_et(now_ms);
+            let consumed = self.budget.consumed.entry(allowance.id).or_insert(0.0);
+            *consumed = (*consumed + fraction).min(1.0);
+            if 1.0 - *consumed <= EPSILON {
+                *consumed = 1.0;
+            }
+            draws.push(AllocationDraw { allowance_id: allowance.id, au_et, fraction });
+            left -= au_et;
+        }
+        Ok(draws)
+    }
+
+    /// Chain a record of `draws` onto the vNode's history.
+    fn seal(
+        &mut self,
+        purpose: BudgetPurpose,
+        amount: f64,
+        draws: Vec<AllocationDraw>,
+        now_ms: u64,
+        cures: Option<u64>,
+    ) -> AllocationRecord {
+        let mut record = AllocationRecord {
+            vnode_id: self.budget.vnode.vnode_id.clone(),
+            seq: self.budget.allocations.len() as u64,
+            purpose,
+            amount_au_et: amount,
+            draws,
+            allocated_ms: now_ms,
+            cures,
+            prev_hash: self.budget.allocations.last().map(|r| r.self_hash.clone()),
+            self_hash: String::new(),
+        };
+        record.self_hash = record.compute_hash();
+        self.budget.allocations.push(record.clone());
+        record
+    }
+
+    /// Unallocated AU.ET left on the vNode's valid allowances at `as_of_ms`, less unresolved
+    /// shortfalls; negative when the book is overdrawn.
+    pub fn available(&self, as_of_ms: u64) -> f64 {
+        let remaining: f64 = self
+            .allowances
+            .values()
+            .filter(|a| !self.invalidations.contains_key(&a.id))
+            .map(|a| self.budget.remaining(a, as_of_ms))
+            .sum();
+        remaining - self.budget.unresolved_shortfall()
+    }
+
+    pub fn budget(&self) -> &VNodeBudget {
+        self.budget
+    }
+}
+
+impl KarmaLedger {
+    /// Open `vnode`'s budget book over its own allowances.
+    pub fn budget_book(&mut self, vnode: &VNodeId) -> BudgetBook<'_> {
+        let budget = self
+            .budgets
+            .entry(vnode.vnode_id.clone())
+            .or_insert_with(|| VNodeBudget::new(vnode.clone()));
+        let allowances = self
+            .allowances
+            .iter()
+            .filter(|a| a.vnode.vnode_id == vnode.vnode_id)
+            .map(|a| (a.id, a))
+            .collect();
+        BudgetBook { allowances, invalidations: &self.invalidations, shortfall_policy: self.shortfall_policy, budget }
+    }
+
+    /// AU.ET `vnode` can still allocate at `as_of_ms`: its valid allowances as they count
+    /// then, less what earlier allocations consumed and the shortfalls last reconciled.
+    pub fn available_budget(&self, vnode: &VNodeId, as_of_ms: u64) -> f64 {
+        let budget = self.budgets.get(&vnode.vnode_id);
+        let remaining: f64 = self
+            .allowances
+            .iter()
+            .filter(|a| a.vnode.vnode_id == vnode.vnode_id && !self.invalidations.contains_key(&a.id))
+            .map(|a| match budget {
+                Some(b) => b.remaining(a, as_of_ms),
+                None => a.usable_au_et(as_of_ms),
+            })
+            .sum();
+        remaining - budget.map_or(0.0, VNodeBudget::unresolved_shortfall)
+    }
+
+    /// `vnode`'s allocations, oldest first.
+    pub fn allocation_history(&self, vnode: &VNodeId) -> &[AllocationRecord] {
+        self.budgets.get(&vnode.vnode_id).map(|b| b.allocations()).unwrap_or(&[])
+    }
+}
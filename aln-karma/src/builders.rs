@@ -0,0 +1,230 @@
+// path: aln-karma/src/builders.rs
+
+//! Fluent builders and fixtures for tests, behind the `test-util` feature.
+//! - Every builder starts from a certified, karma-eligible value; setters change one thing
+//! - Ids come from a `SequentialIdGenerator` seeded by `seed` and epochs from `at_ms`, so the
+//!   same builder calls produce byte-identical manifests
+//! - `build_valid` panics unless the result verifies and is eligible for karma; `build` does
+//!   not check, for fixtures meant to fail
+//! - `smart_city_mobility` reproduces the manifest, rates and shard policy of the example of
+//!   that name; the policy is the builders' default shard's
+//! - `epoch_chain` lays out consecutive linked epochs, one per metrics value, for `analysis`
+
+use steward_runtime_support::{FixedClock, IdGenerator, SequentialIdGenerator};
+
+use crate::{
+    epoch_window_at, BaselineModel, ImpactMetrics, JusticeConstraints, LeakageEstimate, PricingTable,
+    SafetyEpochManifest, ShardPolicy, VNodeId,
+};
+
+/// 2026-01-01T00:00:00Z; fixtures are stamped here unless told otherwise.
+pub const FIXTURE_START_MS: u64 = 1_767_225_600_000;
+
+/// 15-minute epochs, as deployed on the Phoenix vNodes.
+pub const FIXTURE_EPOCH_SECONDS: u64 = 900;
+
+/// Builds a manifest for the epoch containing `at_ms`.
+#[derive(Debug, Clone)]
+pub struct ManifestBuilder {
+    vnode: VNodeId,
+    metrics: ImpactMetrics,
+    baseline: BaselineModel,
+    justice: JusticeConstraints,
+    vnode_log_root: String,
+    external_refs: Vec<String>,
+    leakage: Option<LeakageEstimate>,
+    prev_hash: Option<String>,
+    at_ms: u64,
+    epoch_seconds: u64,
+    seed: u64,
+}
+
+impl ManifestBuilder {
+    pub fn new(vnode_id: &str) -> Self {
+        Self {
+            vnode: VNodeId {
+                vnode_id: vnode_id.into(),
+                policy_shard_id: "policy:aln:mobility:v1".into(),
+            },
+            metrics: ImpactMetrics {
+                t_co2e_avoided: 2.7,
+                kwh_reduced: 0.0,
+                pollution_exposure_delta: -1_500.0,
+                near_misses_blocked: 7,
+                biosafety_delta: 0.12,
+                custom: Default::default(),
+            },
+            baseline: BaselineModel {
+                description: "Conservative SOV baseline, peak hour".into(),
+                additionality_certified: true,
+                min_improvement_ratio: 0.05,
+                method: None,
+            },
+            justice: JusticeConstraints {
+                forbid_burden_shifting: true,
+                require_opt_out_respected: true,
+            },
+            vnode_log_root: format!("merkle-root:{vnode_id}"),
+            external_refs: vec!["city_sensors://phoenix/pm25".into()],
+            leakage: None,
+            prev_hash: None,
+            at_ms: FIXTURE_START_MS,
+            epoch_seconds: FIXTURE_EPOCH_SECONDS,
+            seed: 1,
+        }
+    }
+
+    pub fn policy_shard(mut self, shard: &str) -> Self {
+        self.vnode.policy_shard_id = shard.into();
+        self
+    }
+
+    pub fn metrics(mut self, metrics: ImpactMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn t_co2e_avoided(mut self, tonnes: f64) -> Self {
+        self.metrics.t_co2e_avoided = tonnes;
+        self
+    }
+
+    pub fn baseline(mut self, baseline: BaselineModel) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    pub fn uncertified(mut self) -> Self {
+        self.baseline.additionality_certified = false;
+        self
+    }
+
+    pub fn justice(mut self, justice: JusticeConstraints) -> Self {
+        self.justice = justice;
+        self
+    }
+
+    pub fn vnode_log_root(mut self, root: &str) -> Self {
+        self.vnode_log_root = root.into();
+        self
+    }
+
+    pub fn external_refs(mut self, refs: &[&str]) -> Self {
+        self.external_refs = refs.iter().map(|r| r.to_string()).collect();
+        self
+    }
+
+    pub fn leakage(mut self, leakage: LeakageEstimate) -> Self {
+        self.leakage = Some(leakage);
+        self
+    }
+
+    pub fn follows(mut self, prev: &SafetyEpochManifest) -> Self {
+        self.prev_hash = Some(prev.self_hash.clone());
+        self
+    }
+
+    /// Any instant inside the epoch; the window is aligned to `epoch_seconds`.
+    pub fn at_ms(mut self, at_ms: u64) -> Self {
+        self.at_ms = at_ms;
+        self
+    }
+
+    pub fn epoch_seconds(mut self, epoch_seconds: u64) -> Self {
+        self.epoch_seconds = epoch_seconds;
+        self
+    }
+
+    /// Seed of the id source `build` and `build_valid` draw the manifest id from.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn build(self) -> SafetyEpochManifest {
+        let ids = SequentialIdGenerator::new(self.seed);
+        self.build_with_ids(&ids)
+    }
+
+    /// `build`, drawing the manifest id from `ids` (e.g. one shared by a whole scenario).
+    pub fn build_with_ids(self, ids: &dyn IdGenerator) -> SafetyEpochManifest {
+        let (epoch_start, epoch_end) = epoch_window_at(&FixedClock::new(self.at_ms), self.epoch_seconds);
+        let manifest = SafetyEpochManifest::new_with_ids(
+            ids,
+            self.vnode,
+            epoch_start,
+            epoch_end,
+            self.metrics,
+            self.baseline,
+            self.justice,
+            self.vnode_log_root,
+            self.external_refs,
+            self.prev_hash,
+        );
+        match self.leakage {
+            Some(leakage) => manifest.with_leakage(leakage),
+            None => manifest,
+        }
+    }
+
+    /// `build`, panicking unless the manifest verifies and is eligible for karma.
+    pub fn build_valid(self) -> SafetyEpochManifest {
+        let manifest = self.build();
+        assert!(manifest.verify_hash(), "manifest {} does not verify", manifest.id);
+        assert!(manifest.is_eligible_for_karma(), "manifest {} is not eligible for karma", manifest.id);
+        manifest
+    }
+}
+
+/// The manifest of the `smart_city_mobility` example: the Phoenix traffic controller's
+/// 2.7 tCO₂e over the fixture epoch.
+pub fn smart_city_mobility(seed: u64) -> SafetyEpochManifest {
+    ManifestBuilder::new("city:phoenix:traffic:controller-01")
+        .baseline(BaselineModel {
+            description: "Phoenix SOV baseline, 2018–2020 average, peak hour".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: None,
+        })
+        .t_co2e_avoided((12.5_f64 - 9.8).max(0.0))
+        .vnode_log_root("merkle-root-vnode-log-0xabc...")
+        .external_refs(&["city_sensors://phoenix/pm25", "grid://srp/emissions_factors"])
+        .seed(seed)
+        .build_valid()
+}
+
+/// Consecutive fixture epochs of `vnode_id` from `FIXTURE_START_MS`, one per `metrics` entry,
+/// each following the last; ids are drawn from one generator seeded by `seed`.
+pub fn epoch_chain(
+    vnode_id: &str,
+    seed: u64,
+    metrics: impl IntoIterator<Item = ImpactMetrics>,
+) -> Vec<SafetyEpochManifest> {
+    let ids = SequentialIdGenerator::new(seed);
+    let mut chain: Vec<SafetyEpochManifest> = Vec::new();
+    for (i, metrics) in metrics.into_iter().enumerate() {
+        let mut builder = ManifestBuilder::new(vnode_id)
+            .metrics(metrics)
+            .at_ms(FIXTURE_START_MS + i as u64 * FIXTURE_EPOCH_SECONDS * 1_000);
+        if let Some(prev) = chain.last() {
+            builder = builder.follows(prev);
+        }
+        chain.push(builder.build_with_ids(&ids));
+    }
+    chain
+}
+
+/// AU.ET rates the `smart_city_mobility` example converts its manifest at.
+pub fn smart_city_mobility_pricing() -> PricingTable {
+    PricingTable::new(10.0, 0.01, 2.5)
+}
+
+/// The shard policy builders' manifests fall under (`policy:aln:mobility:v1`): no leakage
+/// estimate required, and near-miss counts accepted without itemized events.
+pub fn smart_city_mobility_policy() -> ShardPolicy {
+    ShardPolicy {
+        policy_shard_id: "policy:aln:mobility:v1".into(),
+        allow_near_miss_summary: true,
+        ..ShardPolicy::default()
+    }
+}
@@ -0,0 +1,137 @@
+// path: aln-karma/src/canonical.rs
+
+//! Canonical binary encoding for hash-bearing records.
+//! - Deterministic CBOR subset (RFC 8949 §4.2): definite lengths, shortest
+//!   integer heads, map keys sorted by their encoded bytes
+//! - Floats are always 64-bit, with `-0.0` folded into `0.0`
+//! - Independent of serde_json's text formatting and map ordering
+//! - `hash_version` on each record says which preimage its `self_hash` covers; a version
+//!   this build does not know has no hash, re-sealing leaves `self_hash` empty, and the
+//!   record never verifies
+//!
+//! Version 1 hashes (JSON over a hand-picked field subset) still verify so
+//! records sealed before the canonical encoding stay auditable.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// `self_hash` = SHA-256 over JSON of a selected field subset.
+pub const HASH_VERSION_LEGACY_JSON: u8 = 1;
+/// `self_hash` = SHA-256 over `to_canonical_bytes()` (every field but `self_hash`).
+pub const HASH_VERSION_CANONICAL: u8 = 2;
+/// Version used for newly sealed records.
+pub const CURRENT_HASH_VERSION: u8 = HASH_VERSION_CANONICAL;
+
+/// Serde default for records stored before `hash_version` existed.
+pub(crate) fn legacy_hash_version() -> u8 {
+    HASH_VERSION_LEGACY_JSON
+}
+
+/// Canonical and compact encodings for a hash-bearing record.
+pub trait CanonicalEncoding: Serialize + DeserializeOwned {
+    /// Deterministic CBOR of every field except `self_hash`.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut value = serde_json::to_value(self).expect("canonical serialization");
+        if let Value::Object(map) = &mut value {
+            map.remove("self_hash");
+        }
+        let mut out = Vec::new();
+        encode_value(&value, &mut out);
+        out
+    }
+
+    /// Compact CBOR of the full record, for storage and transfer.
+    fn to_cbor(&self) -> Result<Vec<u8>, String> {
+        let mut out = Vec::new();
+        ciborium::into_writer(self, &mut out).map_err(|e| format!("CBOR encoding failed: {e}"))?;
+        Ok(out)
+    }
+
+    fn from_cbor(bytes: &[u8]) -> Result<Self, String> {
+        ciborium::from_reader(bytes).map_err(|e| format!("Invalid CBOR: {e}"))
+    }
+}
+
+// ---------------------------------------------------------------------
+// ENCODER
+// ---------------------------------------------------------------------
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+
+const FALSE: u8 = 0xf4;
+const TRUE: u8 = 0xf5;
+const NULL: u8 = 0xf6;
+const FLOAT64: u8 = 0xfb;
+
+fn encode_head(major: u8, n: u64, out: &mut Vec<u8>) {
+    let major = major << 5;
+    if n < 24 {
+        out.push(major | n as u8);
+    } else if n <= u8::MAX as u64 {
+        out.push(major | 24);
+        out.push(n as u8);
+    } else if n <= u16::MAX as u64 {
+        out.push(major | 25);
+        out.extend_from_slice(&(n as u16).to_be_bytes());
+    } else if n <= u32::MAX as u64 {
+        out.push(major | 26);
+        out.extend_from_slice(&(n as u32).to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&n.to_be_bytes());
+    }
+}
+
+fn encode_text(s: &str, out: &mut Vec<u8>) {
+    encode_head(MAJOR_TEXT, s.len() as u64, out);
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn encode_value(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Null => out.push(NULL),
+        Value::Bool(false) => out.push(FALSE),
+        Value::Bool(true) => out.push(TRUE),
+        Value::Number(n) => {
+            if let Some(u) = n.as_u64() {
+                encode_head(MAJOR_UNSIGNED, u, out);
+            } else if let Some(i) = n.as_i64() {
+                // CBOR negative integers encode -1 - n.
+                encode_head(MAJOR_NEGATIVE, (-1 - i) as u64, out);
+            } else {
+                let f = n.as_f64().unwrap_or(0.0);
+                let f = if f == 0.0 { 0.0 } else { f };
+                out.push(FLOAT64);
+                out.extend_from_slice(&f.to_bits().to_be_bytes());
+            }
+        }
+        Value::String(s) => encode_text(s, out),
+        Value::Array(items) => {
+            encode_head(MAJOR_ARRAY, items.len() as u64, out);
+            for item in items {
+                encode_value(item, out);
+            }
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(Vec<u8>, &Value)> = map
+                .iter()
+                .map(|(k, v)| {
+                    let mut key = Vec::new();
+                    encode_text(k, &mut key);
+                    (key, v)
+                })
+                .collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            encode_head(MAJOR_MAP, entries.len() as u64, out);
+            for (key, v) in entries {
+                out.extend_from_slice(&key);
+                encode_value(v, out);
+            }
+        }
+    }
+}
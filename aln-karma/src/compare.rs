@@ -0,0 +1,187 @@
+// path: aln-karma/src/compare.rs
+
+//! Counterfactual comparisons of baselines, for shards upgrading their baseline model mid-year.
+//! - `rebaseline` re-checks one manifest's eligibility and prices it as if it had been made
+//!   under another baseline, next to what it is worth under its own
+//! - `compare_chains` walks two chains shadow-run over the same raw vNode logs (epoch by epoch,
+//!   the same `vnode_log_root`) under different baselines, and reports the AU.ET divergence per
+//!   epoch and in total
+//! - Reports are analyses, not accounting: distinct types with no id, `prev_hash` or
+//!   `self_hash`, marked `ReportKind::Counterfactual` when serialized, and nothing in this
+//!   module creates or changes an allowance
+//! - An ineligible epoch implies no AU.ET; the figures are otherwise priced as
+//!   `to_karma_allowance_with_pricing` would price them
+
+use serde::{Serialize, Deserialize};
+
+use crate::{BaselineModel, EpochChain, PricingTable, SafetyEpochManifest, VNodeId};
+
+/// Marks a report as a what-if, never an allowance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ReportKind {
+    #[default]
+    Counterfactual,
+}
+
+/// One manifest, under its own baseline and under `new_baseline`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RebaselineReport {
+    pub kind: ReportKind,
+    pub vnode: VNodeId,
+    pub epoch_start: u64,
+    pub epoch_end: u64,
+    /// The manifest analyzed, which is left as it was.
+    pub manifest_hash: String,
+    pub old_baseline: BaselineModel,
+    pub new_baseline: BaselineModel,
+    pub eligible_before: bool,
+    pub eligible_after: bool,
+    /// AU.ET the manifest implies under its own baseline; 0 when ineligible.
+    pub au_et_before: f64,
+    pub au_et_after: f64,
+    /// `au_et_after - au_et_before`: the part of the change that is methodological.
+    pub au_et_change: f64,
+}
+
+/// One epoch of two shadow-run chains.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EpochDivergence {
+    pub epoch_start: u64,
+    pub epoch_end: u64,
+    pub manifest_hash_a: String,
+    pub manifest_hash_b: String,
+    pub eligible_a: bool,
+    pub eligible_b: bool,
+    /// Implied AU.ET; 0 when ineligible.
+    pub au_et_a: f64,
+    pub au_et_b: f64,
+    /// `au_et_b - au_et_a`.
+    pub divergence: f64,
+    /// Sum of `divergence` up to and including this epoch.
+    pub cumulative_divergence: f64,
+}
+
+/// Two baseline configurations shadow-run over the same epochs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChainComparison {
+    pub kind: ReportKind,
+    pub vnode_id: String,
+    pub epochs: Vec<EpochDivergence>,
+    pub total_au_et_a: f64,
+    pub total_au_et_b: f64,
+    /// `total_au_et_b - total_au_et_a`.
+    pub cumulative_divergence: f64,
+    /// `cumulative_divergence / total_au_et_a`; `None` when chain A implies nothing.
+    pub relative_divergence: Option<f64>,
+    /// Mean of `|divergence|` per epoch; 0 for empty chains.
+    pub mean_absolute_divergence: f64,
+    pub max_absolute_divergence: f64,
+    /// Epochs eligible under one baseline and not the other.
+    pub eligibility_changes: usize,
+}
+
+/// Eligibility and the AU.ET it implies, without converting anything.
+fn implied(manifest: &SafetyEpochManifest, pricing: &PricingTable) -> (bool, f64) {
+    if manifest.is_eligible_for_karma() {
+        (true, manifest.priced_au_et(pricing))
+    } else {
+        (false, 0.0)
+    }
+}
+
+/// What `manifest` would imply had it been made under `new_baseline`, metrics unchanged,
+/// both sides priced at `pricing`.
+pub fn rebaseline(
+    manifest: &SafetyEpochManifest,
+    new_baseline: &BaselineModel,
+    pricing: &PricingTable,
+) -> RebaselineReport {
+    let (eligible_before, au_et_before) = implied(manifest, pricing);
+    // A working copy only: its hash is not resealed and it is dropped here.
+    let shadow = SafetyEpochManifest { baseline: new_baseline.clone(), ..manifest.clone() };
+    let (eligible_after, au_et_after) = implied(&shadow, pricing);
+    RebaselineReport {
+        kind: ReportKind::Counterfactual,
+        vnode: manifest.vnode.clone(),
+        epoch_start: manifest.epoch_start,
+        epoch_end: manifest.epoch_end,
+        manifest_hash: manifest.self_hash.clone(),
+        old_baseline: manifest.baseline.clone(),
+        new_baseline: new_baseline.clone(),
+        eligible_before,
+        eligible_after,
+        au_et_before,
+        au_et_after,
+        au_et_change: au_et_after - au_et_before,
+    }
+}
+
+/// Compare `chain_b` against `chain_a`, both priced at `pricing`. The chains must cover the
+/// same vNode's epochs over the same raw logs; their metrics may differ, being measured
+/// against different baselines.
+pub fn compare_chains(
+    chain_a: &EpochChain,
+    chain_b: &EpochChain,
+    pricing: &PricingTable,
+) -> Result<ChainComparison, String> {
+    let (a, b) = (chain_a.manifests(), chain_b.manifests());
+    if a.len() != b.len() {
+        return Err(format!("Chains cover {} and {} epochs", a.len(), b.len()));
+    }
+    let mut comparison = ChainComparison {
+        kind: ReportKind::Counterfactual,
+        vnode_id: a.first().map(|m| m.vnode.vnode_id.clone()).unwrap_or_default(),
+        epochs: Vec::with_capacity(a.len()),
+        total_au_et_a: 0.0,
+        total_au_et_b: 0.0,
+        cumulative_divergence: 0.0,
+        relative_divergence: None,
+        mean_absolute_divergence: 0.0,
+        max_absolute_divergence: 0.0,
+        eligibility_changes: 0,
+    };
+    for (ma, mb) in a.iter().zip(b) {
+        if ma.vnode.vnode_id != mb.vnode.vnode_id {
+            return Err(format!("Manifest {} is for vNode {}, not {}", mb.id, mb.vnode.vnode_id, ma.vnode.vnode_id));
+        }
+        if (ma.epoch_start, ma.epoch_end) != (mb.epoch_start, mb.epoch_end) {
+            return Err(format!("Manifests {} and {} cover different epochs", ma.id, mb.id));
+        }
+        if ma.vnode_log_root != mb.vnode_log_root {
+            return Err(format!("Manifests {} and {} are not over the same vNode logs", ma.id, mb.id));
+        }
+        let (eligible_a, au_et_a) = implied(ma, pricing);
+        let (eligible_b, au_et_b) = implied(mb, pricing);
+        let divergence = au_et_b - au_et_a;
+        comparison.total_au_et_a += au_et_a;
+        comparison.total_au_et_b += au_et_b;
+        comparison.cumulative_divergence += divergence;
+        comparison.mean_absolute_divergence += divergence.abs();
+        comparison.max_absolute_divergence = comparison.max_absolute_divergence.max(divergence.abs());
+        comparison.eligibility_changes += usize::from(eligible_a != eligible_b);
+        comparison.epochs.push(EpochDivergence {
+            epoch_start: ma.epoch_start,
+            epoch_end: ma.epoch_end,
+            manifest_hash_a: ma.self_hash.clone(),
+            manifest_hash_b: mb.self_hash.clone(),
+            eligible_a,
+            eligible_b,
+            au_et_a,
+            au_et_b,
+            divergence,
+            cumulative_divergence: comparison.cumulative_divergence,
+        });
+    }
+    if !a.is_empty() {
+        comparison.mean_absolute_divergence /= a.len() as f64;
+    }
+    if comparison.total_au_et_a != 0.0 {
+        comparison.relative_divergence = Some(comparison.cumulative_divergence / comparison.total_au_et_a);
+    }
+    Ok(comparison)
+}
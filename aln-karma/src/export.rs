@@ -0,0 +1,63 @@
+// path: aln-karma/src/export.rs
+
+//! Streamed export and import of manifests and allowances, as `steward_ids::stream`s.
+//! - `write_manifests` writes any number of manifests one at a time; `read_manifests` yields
+//!   them back one at a time, each checked against its own hash
+//! - `KarmaLedger::export_allowances` writes a ledger's allowances in append order;
+//!   `restore_allowances` appends them as `append` would, once the whole stream has verified
+//!   against its manifest, and appends none if any is refused
+//! - Invalidations and budget books are the ledger's own and do not travel
+
+use std::io::{self, BufRead, Write};
+use steward_ids::stream::{self, Compression, StreamError, StreamManifest, StreamReader};
+
+use crate::{KarmaAllowance, KarmaLedger, SafetyEpochManifest};
+
+/// `steward_ids::stream` kind of `write_manifests`.
+pub const MANIFEST_STREAM_KIND: &str = "aln-karma.manifests";
+/// `steward_ids::stream` kind of `KarmaLedger::export_allowances`.
+pub const ALLOWANCE_STREAM_KIND: &str = "aln-karma.allowances";
+
+pub fn write_manifests<'a, W: Write>(
+    sink: W,
+    manifests: impl IntoIterator<Item = &'a SafetyEpochManifest>,
+    compression: Compression,
+) -> io::Result<StreamManifest> {
+    stream::write_stream(sink, MANIFEST_STREAM_KIND, compression, manifests)
+}
+
+/// The manifests `write_manifests` wrote, one at a time; a manifest failing its hash is
+/// `Rejected`. The stream's own manifest is checked after the last one.
+pub fn read_manifests<'r, R: BufRead + 'r>(
+    source: R,
+) -> Result<impl Iterator<Item = Result<SafetyEpochManifest, StreamError>> + 'r, StreamError> {
+    let records = StreamReader::open(source, MANIFEST_STREAM_KIND)?.records::<SafetyEpochManifest>();
+    Ok(records.enumerate().map(|(index, manifest)| {
+        let manifest = manifest?;
+        if !manifest.verify_hash() {
+            let reason = format!("Manifest {} fails hash verification", manifest.id);
+            return Err(StreamError::Rejected { index: index as u64, reason });
+        }
+        Ok(manifest)
+    }))
+}
+
+impl KarmaLedger {
+    pub fn export_allowances<W: Write>(&self, sink: W, compression: Compression) -> io::Result<StreamManifest> {
+        stream::write_stream(sink, ALLOWANCE_STREAM_KIND, compression, &self.allowances)
+    }
+
+    /// Append the allowances `export_allowances` wrote; the number appended.
+    pub fn restore_allowances<R: BufRead>(&mut self, source: R) -> Result<usize, StreamError> {
+        let records = StreamReader::open(source, ALLOWANCE_STREAM_KIND)?.records::<KarmaAllowance>();
+        let staged = records.collect::<Result<Vec<_>, _>>()?;
+        let (before, count) = (self.allowances.len(), staged.len());
+        for (index, allowance) in staged.into_iter().enumerate() {
+            if let Err(reason) = self.append(allowance) {
+                self.allowances.truncate(before);
+                return Err(StreamError::Rejected { index: index as u64, reason });
+            }
+        }
+        Ok(count)
+    }
+}
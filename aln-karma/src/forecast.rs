@@ -0,0 +1,381 @@
+// path: aln-karma/src/forecast.rs
+
+//! Forecast-versus-realized reconciliation for epochs.
+//! - Shard operators publish an `EpochForecast` before the epoch starts
+//! - After the epoch, the forecast is reconciled against the realized manifest
+//! - Bias aggregated over time makes chronically optimistic forecasts visible
+//! - Both records' hashes cover their numbers: a forecast's metrics and a reconciliation's
+//!   deltas cannot be edited without `verify_hash` failing, under either hash version, and
+//!   `reconcile` refuses a forecast or manifest that fails it
+//!
+//! Reconciliation has no eligibility impact in v1; the shortfall flag is
+//! advisory metadata for downstream policy.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use uuid::Uuid;
+use serde::{Serialize, Deserialize};
+use steward_runtime_support::{IdGenerator, UuidV4Generator};
+
+use crate::canonical::{
+    self, CanonicalEncoding, CURRENT_HASH_VERSION, HASH_VERSION_CANONICAL, HASH_VERSION_LEGACY_JSON,
+};
+use crate::{hash_bytes, ImpactMetrics, MetricKey, SafetyEpochManifest, VNodeId};
+
+/// Impact forecast published by a shard operator ahead of an epoch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EpochForecast {
+    pub id: Uuid,
+    pub vnode: VNodeId,
+    pub epoch_start: u64,
+    pub epoch_end: u64,
+    pub forecast_metrics: ImpactMetrics,
+    /// Reference to the forecasting model (name + version, or a content hash).
+    pub model_ref: String,
+    /// Which preimage `self_hash` covers; see `canonical`. Absent (pre-canonical) records are version 1.
+    #[serde(default = "canonical::legacy_hash_version")]
+    pub hash_version: u8,
+    pub self_hash: String,
+}
+
+impl EpochForecast {
+    /// Recompute `self_hash` under this record's `hash_version`; `None` for a version this
+    /// build does not know.
+    pub fn compute_hash(&self) -> Option<String> {
+        match self.hash_version {
+            HASH_VERSION_LEGACY_JSON => Some(self.legacy_json_hash()),
+            HASH_VERSION_CANONICAL => Some(hash_bytes(&self.to_canonical_bytes())),
+            _ => None,
+        }
+    }
+
+    /// Whether `self_hash` matches the record's contents; never under an unknown `hash_version`.
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash().is_some_and(|hash| hash == self.self_hash)
+    }
+
+    pub fn new(
+        vnode: VNodeId,
+        epoch_start: u64,
+        epoch_end: u64,
+        forecast_metrics: ImpactMetrics,
+        model_ref: String,
+    ) -> Self {
+        Self::new_with_ids(&UuidV4Generator, vnode, epoch_start, epoch_end, forecast_metrics, model_ref)
+    }
+
+    /// Like `new`, drawing the forecast id from `ids` (deterministic replays).
+    pub fn new_with_ids(
+        ids: &dyn IdGenerator,
+        vnode: VNodeId,
+        epoch_start: u64,
+        epoch_end: u64,
+        forecast_metrics: ImpactMetrics,
+        model_ref: String,
+    ) -> Self {
+        let mut forecast = EpochForecast {
+            id: ids.next_uuid(),
+            vnode,
+            epoch_start,
+            epoch_end,
+            forecast_metrics,
+            model_ref,
+            hash_version: CURRENT_HASH_VERSION,
+            self_hash: String::new(),
+        };
+        forecast.self_hash = forecast.compute_hash().unwrap_or_default();
+        forecast
+    }
+
+    fn legacy_json_hash(&self) -> String {
+        let mut map = BTreeMap::new();
+        map.insert("id", self.id.to_string());
+        map.insert("vnode_id", self.vnode.vnode_id.clone());
+        map.insert("policy_shard_id", self.vnode.policy_shard_id.clone());
+        map.insert("epoch_start", self.epoch_start.to_string());
+        map.insert("epoch_end", self.epoch_end.to_string());
+        map.insert("model_ref", self.model_ref.clone());
+        let metrics = serde_json::to_string(&self.forecast_metrics).expect("hash serialization");
+        map.insert("forecast_metrics", metrics);
+        let payload = serde_json::to_vec(&map).expect("hash serialization");
+        hash_bytes(&payload)
+    }
+}
+
+/// Forecast vs. realized value for a single metric.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricDelta {
+    pub metric: String,
+    pub forecast: f64,
+    pub realized: f64,
+    /// realized - forecast, in the metric's own units.
+    pub delta: f64,
+    /// Signed optimism of the forecast relative to its own magnitude:
+    /// positive => forecast promised more benefit than was realized.
+    /// `None` when the forecast was zero (no meaningful ratio).
+    pub optimism: Option<f64>,
+    /// Realized benefit fell below `shortfall_fraction` of the forecast benefit.
+    pub below_shortfall_threshold: bool,
+}
+
+/// Hash-linked record of one forecast reconciled against one manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReconciliationRecord {
+    pub id: Uuid,
+    pub vnode: VNodeId,
+    pub epoch_start: u64,
+    pub epoch_end: u64,
+    pub forecast_hash: String,
+    pub manifest_hash: String,
+    pub deltas: Vec<MetricDelta>,
+    /// Mean optimism across metrics with a nonzero forecast (0.0 if none).
+    pub bias: f64,
+    /// True if any benefit metric fell below the configured shortfall fraction.
+    pub shortfall_flagged: bool,
+    /// Which preimage `self_hash` covers; see `canonical`. Absent (pre-canonical) records are version 1.
+    #[serde(default = "canonical::legacy_hash_version")]
+    pub hash_version: u8,
+    pub self_hash: String,
+}
+
+impl ReconciliationRecord {
+    /// Recompute `self_hash` under this record's `hash_version`; `None` for a version this
+    /// build does not know.
+    pub fn compute_hash(&self) -> Option<String> {
+        match self.hash_version {
+            HASH_VERSION_LEGACY_JSON => Some(self.legacy_json_hash()),
+            HASH_VERSION_CANONICAL => Some(hash_bytes(&self.to_canonical_bytes())),
+            _ => None,
+        }
+    }
+
+    /// Whether `self_hash` matches the record's contents; never under an unknown `hash_version`.
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash().is_some_and(|hash| hash == self.self_hash)
+    }
+
+    fn legacy_json_hash(&self) -> String {
+        let mut map = BTreeMap::new();
+        map.insert("id", self.id.to_string());
+        map.insert("vnode_id", self.vnode.vnode_id.clone());
+        map.insert("epoch_start", self.epoch_start.to_string());
+        map.insert("epoch_end", self.epoch_end.to_string());
+        map.insert("forecast_hash", self.forecast_hash.clone());
+        map.insert("manifest_hash", self.manifest_hash.clone());
+        map.insert("bias", self.bias.to_string());
+        map.insert("shortfall_flagged", self.shortfall_flagged.to_string());
+        map.insert("deltas", serde_json::to_string(&self.deltas).expect("hash serialization"));
+        let payload = serde_json::to_vec(&map).expect("hash serialization");
+        hash_bytes(&payload)
+    }
+}
+
+impl CanonicalEncoding for EpochForecast {}
+impl CanonicalEncoding for ReconciliationRecord {}
+
+/// Bias aggregated over a window of reconciled epochs for one vNode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CalibrationSummary {
+    pub vnode_id: String,
+    pub window: (u64, u64),
+    pub epochs_reconciled: usize,
+    pub mean_bias: f64,
+    pub optimistic_epochs: usize,
+    pub pessimistic_epochs: usize,
+    pub shortfall_epochs: usize,
+}
+
+/// Reconcile a forecast against the realized manifest for the same vNode and epoch.
+/// `shortfall_fraction` (e.g. 0.5) sets the realized/forecast ratio under which a
+/// benefit metric is flagged. Either record failing hash verification is refused.
+pub fn reconcile(
+    forecast: &EpochForecast,
+    manifest: &SafetyEpochManifest,
+    shortfall_fraction: f64,
+) -> Result<ReconciliationRecord, String> {
+    reconcile_with_ids(&UuidV4Generator, forecast, manifest, shortfall_fraction)
+}
+
+/// Like `reconcile`, drawing the record id from `ids`.
+pub fn reconcile_with_ids(
+    ids: &dyn IdGenerator,
+    forecast: &EpochForecast,
+    manifest: &SafetyEpochManifest,
+    shortfall_fraction: f64,
+) -> Result<ReconciliationRecord, String> {
+    if !forecast.verify_hash() {
+        return Err(format!("Forecast {} fails hash verification", forecast.id));
+    }
+    if !manifest.verify_hash() {
+        return Err(format!("Manifest {} fails hash verification", manifest.id));
+    }
+    if forecast.vnode.vnode_id != manifest.vnode.vnode_id {
+        return Err("Forecast and manifest belong to different vNodes".into());
+    }
+    if forecast.epoch_start != manifest.epoch_start || forecast.epoch_end != manifest.epoch_end {
+        return Err("Forecast and manifest cover different epochs".into());
+    }
+
+    let f = &forecast.forecast_metrics;
+    let r = &manifest.metrics;
+    // (name, forecast, realized, higher_is_better)
+    let mut metrics: Vec<(String, f64, f64, bool)> = [
+        ("t_co2e_avoided", f.t_co2e_avoided, r.t_co2e_avoided, true),
+        ("kwh_reduced", f.kwh_reduced, r.kwh_reduced, true),
+        ("pollution_exposure_delta", f.pollution_exposure_delta, r.pollution_exposure_delta, false),
+        ("near_misses_blocked", f.near_misses_blocked as f64, r.near_misses_blocked as f64, true),
+        ("biosafety_delta", f.biosafety_delta, r.biosafety_delta, true),
+    ]
+    .into_iter()
+    .map(|(name, fv, rv, higher)| (name.to_string(), fv, rv, higher))
+    .collect();
+    // Custom dimensions named on either side, in key order; a missing side counts as 0.
+    let custom_keys: BTreeSet<&MetricKey> = f.custom.keys().chain(r.custom.keys()).collect();
+    for key in custom_keys {
+        let value = |m: &ImpactMetrics| m.custom.get(key).map(|v| v.value).unwrap_or(0.0);
+        metrics.push((key.to_string(), value(f), value(r), true));
+    }
+
+    let mut deltas = Vec::new();
+    for (metric, forecast_v, realized_v, higher_is_better) in metrics {
+        // Orient both values so that "more" always means "more benefit".
+        let (fb, rb) = if higher_is_better {
+            (forecast_v, realized_v)
+        } else {
+            (-forecast_v, -realized_v)
+        };
+        let optimism = if fb == 0.0 {
+            None
+        } else {
+            Some((fb - rb) / fb.abs())
+        };
+        let below_shortfall_threshold = fb > 0.0 && rb < fb * shortfall_fraction;
+        deltas.push(MetricDelta {
+            metric,
+            forecast: forecast_v,
+            realized: realized_v,
+            delta: realized_v - forecast_v,
+            optimism,
+            below_shortfall_threshold,
+        });
+    }
+
+    let scored: Vec<f64> = deltas.iter().filter_map(|d| d.optimism).collect();
+    let bias = if scored.is_empty() {
+        0.0
+    } else {
+        scored.iter().sum::<f64>() / scored.len() as f64
+    };
+    let shortfall_flagged = deltas.iter().any(|d| d.below_shortfall_threshold);
+
+    let mut record = ReconciliationRecord {
+        id: ids.next_uuid(),
+        vnode: manifest.vnode.clone(),
+        epoch_start: manifest.epoch_start,
+        epoch_end: manifest.epoch_end,
+        forecast_hash: forecast.self_hash.clone(),
+        manifest_hash: manifest.self_hash.clone(),
+        deltas,
+        bias,
+        shortfall_flagged,
+        hash_version: CURRENT_HASH_VERSION,
+        self_hash: String::new(),
+    };
+    record.self_hash = record.compute_hash().unwrap_or_default();
+    Ok(record)
+}
+
+/// Registry of published forecasts and their reconciliations.
+pub struct ForecastRegistry {
+    /// Realized/forecast ratio under which a benefit metric is flagged.
+    pub shortfall_fraction: f64,
+    /// Forecasts indexed by (vnode_id, epoch_start).
+    forecasts: HashMap<(String, u64), EpochForecast>,
+    records: Vec<ReconciliationRecord>,
+}
+
+impl ForecastRegistry {
+    pub fn new(shortfall_fraction: f64) -> Self {
+        Self {
+            shortfall_fraction,
+            forecasts: HashMap::new(),
+            records: Vec::new(),
+        }
+    }
+
+    /// Publish a forecast; one forecast per vNode and epoch, and only before it is reconciled.
+    pub fn publish(&mut self, forecast: EpochForecast) -> Result<(), String> {
+        let key = (forecast.vnode.vnode_id.clone(), forecast.epoch_start);
+        if self.forecasts.contains_key(&key) {
+            return Err("Forecast already published for this vNode and epoch".into());
+        }
+        self.forecasts.insert(key, forecast);
+        Ok(())
+    }
+
+    pub fn get_forecast(&self, vnode: &VNodeId, epoch_start: u64) -> Option<&EpochForecast> {
+        self.forecasts.get(&(vnode.vnode_id.clone(), epoch_start))
+    }
+
+    /// Reconcile the published forecast for the manifest's vNode/epoch and store the record.
+    pub fn reconcile_manifest(
+        &mut self,
+        manifest: &SafetyEpochManifest,
+    ) -> Result<ReconciliationRecord, String> {
+        self.reconcile_manifest_with_ids(&UuidV4Generator, manifest)
+    }
+
+    /// Like `reconcile_manifest`, drawing the record id from `ids`.
+    pub fn reconcile_manifest_with_ids(
+        &mut self,
+        ids: &dyn IdGenerator,
+        manifest: &SafetyEpochManifest,
+    ) -> Result<ReconciliationRecord, String> {
+        if self.records.iter().any(|r| r.manifest_hash == manifest.self_hash) {
+            return Err("Manifest already reconciled".into());
+        }
+        let forecast = self
+            .get_forecast(&manifest.vnode, manifest.epoch_start)
+            .ok_or_else(|| "No forecast published for this vNode and epoch".to_string())?;
+        let record = reconcile_with_ids(ids, forecast, manifest, self.shortfall_fraction)?;
+        self.records.push(record.clone());
+        Ok(record)
+    }
+
+    pub fn records_for(&self, vnode: &VNodeId) -> Vec<&ReconciliationRecord> {
+        self.records
+            .iter()
+            .filter(|r| r.vnode.vnode_id == vnode.vnode_id)
+            .collect()
+    }
+
+    /// Aggregate bias for epochs of `vnode` starting within `[window.0, window.1)`.
+    pub fn calibration_summary(&self, vnode: &VNodeId, window: (u64, u64)) -> CalibrationSummary {
+        let in_window: Vec<&ReconciliationRecord> = self
+            .records
+            .iter()
+            .filter(|r| r.vnode.vnode_id == vnode.vnode_id)
+            .filter(|r| r.epoch_start >= window.0 && r.epoch_start < window.1)
+            .collect();
+
+        let epochs_reconciled = in_window.len();
+        let mean_bias = if epochs_reconciled == 0 {
+            0.0
+        } else {
+            in_window.iter().map(|r| r.bias).sum::<f64>() / epochs_reconciled as f64
+        };
+
+        CalibrationSummary {
+            vnode_id: vnode.vnode_id.clone(),
+            window,
+            epochs_reconciled,
+            mean_bias,
+            optimistic_epochs: in_window.iter().filter(|r| r.bias > 0.0).count(),
+            pessimistic_epochs: in_window.iter().filter(|r| r.bias < 0.0).count(),
+            shortfall_epochs: in_window.iter().filter(|r| r.shortfall_flagged).count(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// path: aln-karma/src/invariants.rs
+
+//! State checks for generated operation sequences (feature `test-util`).
+//! - `karma.hash_verifies`: every recorded allowance still verifies against its `self_hash`
+//! - `karma.chain_links`: each vNode's first allowance has no `prev_hash`, and every later one
+//!   links to the `self_hash` of the one before it
+//! - `karma.unique_ids`: no allowance id is recorded twice
+//! - `karma.invalidation_recorded`: invalidations only name recorded allowances
+//! - `karma.total_finite`: `total_au_et` is finite and never negative
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use steward_ids::invariants::InvariantViolation;
+
+use crate::KarmaLedger;
+
+impl KarmaLedger {
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let mut latest: BTreeMap<&str, &str> = BTreeMap::new();
+        let mut ids = BTreeSet::new();
+        for allowance in &self.allowances {
+            if !allowance.verify_hash() {
+                let detail = format!("allowance {} fails hash verification", allowance.id);
+                return Err(InvariantViolation::new("karma.hash_verifies", detail));
+            }
+            let vnode = allowance.vnode.vnode_id.as_str();
+            let expected = latest.get(vnode).copied();
+            if allowance.prev_hash.as_deref() != expected {
+                let detail = format!(
+                    "allowance {} of vNode {vnode} links to {:?}, expected {expected:?}",
+                    allowance.id, allowance.prev_hash
+                );
+                return Err(InvariantViolation::new("karma.chain_links", detail));
+            }
+            latest.insert(vnode, &allowance.self_hash);
+            if !ids.insert(allowance.id) {
+                return Err(InvariantViolation::new("karma.unique_ids", format!("{} recorded twice", allowance.id)));
+            }
+        }
+        if let Some(id) = self.invalidations.keys().find(|id| !ids.contains(*id)) {
+            let detail = format!("invalidation of {id}, which is not recorded");
+            return Err(InvariantViolation::new("karma.invalidation_recorded", detail));
+        }
+        let total = self.total_au_et(u64::MAX);
+        if !total.is_finite() || total < 0.0 {
+            return Err(InvariantViolation::new("karma.total_finite", format!("total_au_et is {total}")));
+        }
+        Ok(())
+    }
+}
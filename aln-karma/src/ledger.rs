@@ -0,0 +1,218 @@
+// path: aln-karma/src/ledger.rs
+
+//! Allowance ledger and vintage windows.
+//! - A shard's `VintageRule` stamps each allowance with a `VintageWindow` at conversion time
+//! - `KarmaLedger::total_au_et(as_of_ms)` counts an allowance in full, decayed, or not at all,
+//!   depending on where `as_of_ms` falls in its window
+//! - Decay is a read-time view: stored allowances and their hashes never change
+//! - Allowances without a window (converted outside any policy) never expire
+//! - A ledger holding a `ShardPolicyRegistry` appends only allowances checked against the
+//!   shard policy version in force at their epoch start
+//! - Invalidating an allowance (e.g. its manifest failed audit) leaves it stored but makes it
+//!   count for nothing from then on
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::budget::{ShortfallPolicy, VNodeBudget};
+use crate::{KarmaAllowance, ShardPolicyRegistry};
+
+/// How an allowance loses value across its window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VintageDecay {
+    /// Full value until `usable_until_ms`, nothing after.
+    #[default]
+    HardCutoff,
+    /// Falls linearly from full value at the epoch end to zero at `usable_until_ms`.
+    Linear,
+}
+
+/// Shard-policy rule for how long epoch impact counts toward AU.ET budgets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VintageRule {
+    /// Validity after the epoch ends, e.g. twelve months.
+    pub validity_ms: u64,
+    #[serde(default)]
+    pub decay: VintageDecay,
+}
+
+/// When an allowance was earned and how long it counts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VintageWindow {
+    /// `epoch_end` of the source manifest, in seconds.
+    pub earned_epoch_end: u64,
+    pub usable_until_ms: u64,
+    #[serde(default)]
+    pub decay: VintageDecay,
+}
+
+impl VintageWindow {
+    pub fn from_rule(epoch_end: u64, rule: &VintageRule) -> Self {
+        Self {
+            earned_epoch_end: epoch_end,
+            usable_until_ms: (epoch_end * 1_000).saturating_add(rule.validity_ms),
+            decay: rule.decay,
+        }
+    }
+
+    /// Share of the allowance still usable at `as_of_ms`, in [0, 1].
+    pub fn factor(&self, as_of_ms: u64) -> f64 {
+        if as_of_ms >= self.usable_until_ms {
+            return 0.0;
+        }
+        match self.decay {
+            VintageDecay::HardCutoff => 1.0,
+            VintageDecay::Linear => {
+                let start = self.earned_epoch_end * 1_000;
+                if as_of_ms <= start || self.usable_until_ms <= start {
+                    return 1.0;
+                }
+                let remaining = (self.usable_until_ms - as_of_ms) as f64 / (self.usable_until_ms - start) as f64;
+                remaining.clamp(0.0, 1.0)
+            }
+        }
+    }
+
+    pub fn is_expired(&self, as_of_ms: u64) -> bool {
+        as_of_ms >= self.usable_until_ms
+    }
+}
+
+impl KarmaAllowance {
+    /// `au_et_delta` as it counts at `as_of_ms`; never negative.
+    pub fn usable_au_et(&self, as_of_ms: u64) -> f64 {
+        let factor = self.vintage.as_ref().map(|v| v.factor(as_of_ms)).unwrap_or(1.0);
+        (self.au_et_delta * factor).max(0.0)
+    }
+}
+
+/// Why and when an allowance stopped counting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AllowanceInvalidation {
+    pub reason: String,
+    pub invalidated_ms: u64,
+}
+
+/// Hash-chained allowances, per vNode.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KarmaLedger {
+    pub(crate) allowances: Vec<KarmaAllowance>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shard_policies: Option<ShardPolicyRegistry>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) invalidations: BTreeMap<Uuid, AllowanceInvalidation>,
+    /// Allocations against each vNode's allowances, by `vnode_id`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) budgets: BTreeMap<String, VNodeBudget>,
+    #[serde(default)]
+    pub(crate) shortfall_policy: ShortfallPolicy,
+}
+
+impl KarmaLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check every appended allowance against `registry`'s shard policies.
+    pub fn with_shard_policies(mut self, registry: ShardPolicyRegistry) -> Self {
+        self.shard_policies = Some(registry);
+        self
+    }
+
+    pub fn shard_policies(&self) -> Option<&ShardPolicyRegistry> {
+        self.shard_policies.as_ref()
+    }
+
+    /// Whether budget books allocate while a shortfall is unresolved.
+    pub fn with_shortfall_policy(mut self, policy: ShortfallPolicy) -> Self {
+        self.shortfall_policy = policy;
+        self
+    }
+
+    pub fn shortfall_policy(&self) -> ShortfallPolicy {
+        self.shortfall_policy
+    }
+
+    /// Appends `allowance` if its hash verifies and its `prev_hash` links to the
+    /// vNode's latest allowance (`None` for the vNode's first). With shard policies, it
+    /// must also record the version in force at its epoch start and satisfy it.
+    pub fn append(&mut self, allowance: KarmaAllowance) -> Result<(), String> {
+        if !allowance.verify_hash() {
+            return Err(format!("Allowance {} fails hash verification", allowance.id));
+        }
+        if let Some(registry) = &self.shard_policies {
+            registry.check_allowance(&allowance)?;
+        }
+        let latest = self
+            .allowances
+            .iter()
+            .rev()
+            .find(|a| a.vnode.vnode_id == allowance.vnode.vnode_id)
+            .map(|a| &a.self_hash);
+        if latest != allowance.prev_hash.as_ref() {
+            return Err(format!(
+                "Allowance {} does not link to the latest allowance of vNode {}",
+                allowance.id, allowance.vnode.vnode_id
+            ));
+        }
+        self.allowances.push(allowance);
+        Ok(())
+    }
+
+    /// In append order.
+    pub fn allowances(&self) -> &[KarmaAllowance] {
+        &self.allowances
+    }
+
+    /// Stop counting a recorded allowance. The allowance itself is kept unchanged.
+    pub fn invalidate(&mut self, allowance_id: Uuid, reason: impl Into<String>, now_ms: u64) -> Result<(), String> {
+        if !self.allowances.iter().any(|a| a.id == allowance_id) {
+            return Err(format!("Allowance {allowance_id} is not recorded"));
+        }
+        if self.invalidations.contains_key(&allowance_id) {
+            return Err(format!("Allowance {allowance_id} is already invalidated"));
+        }
+        let invalidation = AllowanceInvalidation { reason: reason.into(), invalidated_ms: now_ms };
+        self.invalidations.insert(allowance_id, invalidation);
+        Ok(())
+    }
+
+    pub fn invalidation(&self, allowance_id: &Uuid) -> Option<&AllowanceInvalidation> {
+        self.invalidations.get(allowance_id)
+    }
+
+    /// AU.ET budget at `as_of_ms`, each allowance counted per its vintage window and
+    /// invalidated allowances not at all.
+    pub fn total_au_et(&self, as_of_ms: u64) -> f64 {
+        self.allowances
+            .iter()
+            .filter(|a| !self.invalidations.contains_key(&a.id))
+            .map(|a| a.usable_au_et(as_of_ms))
+            .sum::<f64>()
+            .max(0.0)
+    }
+
+    /// Allowances still usable at `as_of_ms` whose window closes within `window_ms`,
+    /// soonest first: the budget cliffs ahead.
+    pub fn expiring_within(&self, window_ms: u64, as_of_ms: u64) -> Vec<&KarmaAllowance> {
+        let horizon = as_of_ms.saturating_add(window_ms);
+        let mut expiring: Vec<&KarmaAllowance> = self
+            .allowances
+            .iter()
+            .filter(|a| {
+                !self.invalidations.contains_key(&a.id)
+                    && a.vintage
+                        .as_ref()
+                        .is_some_and(|v| !v.is_expired(as_of_ms) && v.usable_until_ms <= horizon)
+            })
+            .collect();
+        expiring.sort_by_key(|a| a.vintage.as_ref().map(|v| v.usable_until_ms));
+        expiring
+    }
+}
@@ -3,16 +3,76 @@
 //! ALN-compliant “karma-increasing” primitives for AU.ET / CSP
 //! - Non-mintable, non-transferable impact allowances
 //! - Backed only by SafetyEpochManifests derived from vNode logs
+//! - Near-miss counts can be itemized, each event proven into the vNode log root (`near_miss`)
 //! - Baseline/additionality aware
+//! - Shard policies versioned in a registry shared by manifests, allowances and the ledger (`shard`)
+//! - Earned AU.ET allocated to a vNode's own planned expenditures, never another's (`budget`)
+//! - Hash-chained records sealed over a canonical CBOR encoding (`canonical`)
+//! - Manifests and allowances streamed out and back in constant memory (`export`)
+//! - Declines, spikes and stuck sensors found across a vNode's epochs (`analysis`)
+//! - Counterfactual reports of what another baseline would have changed (`compare`)
+//! - External refs resolved by scheme and snapshotted into manifests for audit (`refs`)
+//! - Deterministic builders and fixtures for downstream tests (`builders`, feature `test-util`)
+//! - `KarmaLedger::check_invariants` for generated operation sequences (feature `test-util`)
 //! - Ready to plug into ALN/CEM runtimes as a Rust crate
 
-use std::time::{SystemTime, UNIX_EPOCH};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
 use uuid::Uuid;
 use serde::{Serialize, Deserialize};
+use steward_runtime_support::{Clock, IdGenerator, SystemClock, UuidV4Generator};
+
+/// Custom impact dimensions, shared with the planetary ledger.
+pub use steward_ids::MetricKey;
+pub use steward_ids::metrics::{CustomMetricRegistry, CustomMetricSpec, CustomMetrics, MetricValue};
+pub use steward_ids::stream::{Compression, StreamError, StreamManifest};
+
+/// Crate version stamped into exported JSON Schemas.
+#[cfg(feature = "schema")]
+pub const SCHEMA_CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub mod analysis;
+pub use analysis::{
+    DataQualityAnnotation, EpochChain, TrendConfig, TrendDetector, TrendFinding, TrendKind, TrendMetric,
+};
+pub mod budget;
+#[cfg(feature = "test-util")]
+pub mod builders;
+pub use budget::{
+    AllocationDraw, AllocationRecord, AllocationStatus, BudgetBook, BudgetPurpose, BudgetShortfall, ShortfallPolicy,
+    VNodeBudget,
+};
+pub mod canonical;
+pub use canonical::{
+    CanonicalEncoding, CURRENT_HASH_VERSION, HASH_VERSION_CANONICAL, HASH_VERSION_LEGACY_JSON,
+};
+pub mod compare;
+pub use compare::{ChainComparison, EpochDivergence, RebaselineReport, ReportKind};
+pub mod export;
+pub use export::{read_manifests, write_manifests, ALLOWANCE_STREAM_KIND, MANIFEST_STREAM_KIND};
+pub mod forecast;
+#[cfg(feature = "test-util")]
+mod invariants;
+pub use forecast::{
+    reconcile, reconcile_with_ids, CalibrationSummary, EpochForecast, ForecastRegistry, MetricDelta,
+    ReconciliationRecord,
+};
+pub mod ledger;
+pub use ledger::{AllowanceInvalidation, KarmaLedger, VintageDecay, VintageRule, VintageWindow};
+pub mod merkle;
+pub use merkle::{MerkleProof, ProofStep, SiblingSide};
+pub mod near_miss;
+pub use near_miss::{NearMissCategory, NearMissEvent, NearMissSeverity};
+pub mod refs;
+pub use refs::{
+    ExternalRefResolver, InMemoryResolver, RefAudit, RefAuditReport, RefError, RefSnapshot, RefStatus,
+    ResolverRegistry,
+};
+pub mod shard;
+pub use shard::ShardPolicyRegistry;
 
 /// vNode identity & policy shard binding (traffic, grid, habitat, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VNodeId {
     pub vnode_id: String,
     pub policy_shard_id: String,
@@ -21,6 +81,7 @@ pub struct VNodeId {
 /// Core physical metrics we allow as “impact substrate”.
 /// Each field is *measured* or derived from measured data – no symbolic scores.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ImpactMetrics {
     /// Tons CO₂e avoided in this epoch vs. conservative baseline.
     pub t_co2e_avoided: f64,
@@ -32,11 +93,16 @@ pub struct ImpactMetrics {
     pub near_misses_blocked: u64,
     /// Qualitative biosafety index delta, normalized to [-1, +1].
     pub biosafety_delta: f64,
+    /// Measured dimensions the shard policy registers (`ShardPolicy::custom_metrics`),
+    /// e.g. `water:nitrate_kg_reduced`. Omitted when empty, so existing hashes still verify.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: CustomMetrics,
 }
 
 /// Baseline model configuration: defines the conservative counterfactual.
 /// This is where additionality/baseline logic is enforced per policy shard. [web:0][web:1][web:2]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BaselineModel {
     /// Human-readable description of the baseline assumption.
     pub description: String,
@@ -45,11 +111,16 @@ pub struct BaselineModel {
     pub additionality_certified: bool,
     /// Minimal acceptable improvement factor (e.g. 0.05 => 5% better than baseline).
     pub min_improvement_ratio: f64,
+    /// Baseline methodology, matched against `ShardPolicy::baseline_methods`. Omitted when
+    /// absent, so existing hashes still verify.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
 }
 
 /// Justice & equity constraints attached to the policy shard.
 /// Ensures “positive karma” cannot be claimed by burden-shifting harms. [web:0]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JusticeConstraints {
     /// If true, disallow counting when pollution is shifted to more vulnerable tracts.
     pub forbid_burden_shifting: bool,
@@ -57,9 +128,127 @@ pub struct JusticeConstraints {
     pub require_opt_out_respected: bool,
 }
 
+/// Emissions displaced outside the measured boundary (e.g. traffic rerouted
+/// into a neighboring city). Deducted from gross avoidance before any use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LeakageEstimate {
+    /// Tons CO₂e estimated to be displaced outside the boundary (>= 0).
+    pub displaced_t_co2e: f64,
+    /// What the measured boundary is, and where displaced emissions land.
+    pub boundary_description: String,
+    /// Method used to estimate leakage (model name, survey, default factor).
+    pub estimation_method: String,
+}
+
+/// Policy attached to a policy shard (`VNodeId::policy_shard_id`), one version of it
+/// when kept in a `ShardPolicyRegistry`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ShardPolicy {
+    pub policy_shard_id: String,
+    /// Registry version; later versions of a shard have higher numbers.
+    #[serde(default)]
+    pub version: u32,
+    /// Epoch start (seconds) from which this version is in force.
+    #[serde(default)]
+    pub effective_from: u64,
+    /// Baseline methods manifests may use (`BaselineModel::method`); empty accepts any.
+    #[serde(default)]
+    pub baseline_methods: BTreeSet<String>,
+    /// Floor on manifests' `BaselineModel::min_improvement_ratio`.
+    #[serde(default)]
+    pub min_improvement_ratio: f64,
+    /// Justice constraints manifests must match or tighten.
+    #[serde(default)]
+    pub justice: JusticeConstraints,
+    /// Epoch length in seconds, with starts aligned to it; `None` accepts any epoch.
+    #[serde(default)]
+    pub epoch_seconds: Option<u64>,
+    /// Pricing schedule the shard's allowances are priced under, by reference.
+    #[serde(default)]
+    pub pricing_schedule: Option<String>,
+    /// If true, manifests without a `LeakageEstimate` are ineligible for karma.
+    #[serde(default)]
+    pub require_leakage_estimate: bool,
+    /// Custom dimensions manifests under this shard may carry; any other key makes them ineligible.
+    #[serde(default)]
+    pub custom_metrics: CustomMetricRegistry,
+    /// How long allowances converted under this shard count toward AU.ET budgets; `None` is forever.
+    #[serde(default)]
+    pub vintage: Option<VintageRule>,
+    /// Accept a nonzero `near_misses_blocked` without `near_miss_events`.
+    #[serde(default)]
+    pub allow_near_miss_summary: bool,
+    /// Hold manifests after an unresolved flatline until one carries a data-quality
+    /// annotation; see `EpochChain::is_eligible_under_policy`.
+    #[serde(default)]
+    pub require_data_quality_after_flatline: bool,
+    /// URI schemes (`city_sensors`) whose external refs manifests must carry a snapshot of
+    /// (`SafetyEpochManifest::ref_snapshots`).
+    #[serde(default)]
+    pub required_ref_schemes: BTreeSet<String>,
+}
+
+impl ShardPolicy {
+    /// Allow manifests to carry `key`, measured in `spec.unit` and within its range.
+    pub fn register_custom_metric(&mut self, key: MetricKey, spec: CustomMetricSpec) -> Result<(), String> {
+        spec.validate()?;
+        if self.custom_metrics.contains_key(&key) {
+            return Err(format!("Custom metric {key} is already registered"));
+        }
+        self.custom_metrics.insert(key, spec);
+        Ok(())
+    }
+}
+
+/// AU.ET rates applied when converting a manifest into an allowance.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PricingTable {
+    pub per_tco2e: f64,
+    pub per_kwh: f64,
+    pub per_near_miss: f64,
+    /// AU.ET per unit of a custom dimension; dimensions without a rate earn nothing.
+    #[serde(default)]
+    pub per_custom: BTreeMap<MetricKey, f64>,
+    /// Multiplies `per_near_miss` per listed event; empty prices every near-miss flat.
+    #[serde(default)]
+    pub near_miss_severity_weights: BTreeMap<NearMissSeverity, f64>,
+}
+
+impl PricingTable {
+    pub fn new(per_tco2e: f64, per_kwh: f64, per_near_miss: f64) -> Self {
+        Self {
+            per_tco2e,
+            per_kwh,
+            per_near_miss,
+            per_custom: BTreeMap::new(),
+            near_miss_severity_weights: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_custom_rate(mut self, key: MetricKey, per_unit: f64) -> Self {
+        self.per_custom.insert(key, per_unit);
+        self
+    }
+}
+
+/// Gross / leakage / net CO₂e figures behind an allowance, kept separately
+/// so the leakage deduction stays auditable.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CarbonAccounting {
+    pub gross_t_co2e_avoided: f64,
+    pub leakage_t_co2e: f64,
+    /// max(gross - leakage, 0); this is the figure priced into AU.ET.
+    pub net_t_co2e_avoided: f64,
+}
+
 /// AU.ET-linked, non-mintable “karma allowance” for a single epoch.
 /// This is *not* a token, credit, or transferable asset.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct KarmaAllowance {
     pub id: Uuid,
     pub vnode: VNodeId,
@@ -69,6 +258,9 @@ pub struct KarmaAllowance {
     pub au_et_delta: f64,
     /// Underlying physical impact metrics.
     pub metrics: ImpactMetrics,
+    /// Gross, leakage, and net CO₂e used for pricing.
+    #[serde(default)]
+    pub carbon: CarbonAccounting,
     /// Baseline model used to compute the allowance.
     pub baseline: BaselineModel,
     /// Policy shard & justice constraints in force.
@@ -77,11 +269,22 @@ pub struct KarmaAllowance {
     pub manifest_hash: String,
     /// Local hash-chain anchor for auditability. [web:0]
     pub prev_hash: Option<String>,
+    /// Validity stamped from the shard's `VintageRule`; absent means the allowance never expires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vintage: Option<VintageWindow>,
+    /// `ShardPolicy::version` the manifest was checked against, when converted through a
+    /// `ShardPolicyRegistry`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_version: Option<u32>,
+    /// Which preimage `self_hash` covers; see `canonical`. Absent (pre-canonical) records are version 1.
+    #[serde(default = "canonical::legacy_hash_version")]
+    pub hash_version: u8,
     pub self_hash: String,
 }
 
 /// SafetyEpochManifest: hash-chained, audit-ready log of one epoch’s impact. [web:0]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SafetyEpochManifest {
     pub id: Uuid,
     pub vnode: VNodeId,
@@ -91,8 +294,26 @@ pub struct SafetyEpochManifest {
     pub baseline: BaselineModel,
     pub justice: JusticeConstraints,
     pub vnode_log_root: String,     // Merkle-root over raw vNode logs.
-    pub external_refs: Vec<String>, // MRV systems, sensors, etc.
+    pub external_refs: Vec<String>, // MRV systems, sensors, etc.; sorted, unique.
+    /// Emissions displaced outside the measured boundary, if estimated.
+    #[serde(default)]
+    pub leakage: Option<LeakageEstimate>,
+    /// The events `metrics.near_misses_blocked` counts, if itemized. Omitted when absent,
+    /// so existing hashes still verify.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub near_miss_events: Option<Vec<NearMissEvent>>,
+    /// Operator's note on suspect data, e.g. after a flatline (`analysis`). Omitted when
+    /// absent, so existing hashes still verify.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_quality: Option<DataQualityAnnotation>,
+    /// What external refs held when the vNode created the manifest, keyed by URI (`refs`).
+    /// Omitted when empty, so existing hashes still verify.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ref_snapshots: BTreeMap<String, RefSnapshot>,
     pub prev_hash: Option<String>,
+    /// Which preimage `self_hash` covers; see `canonical`. Absent (pre-canonical) records are version 1.
+    #[serde(default = "canonical::legacy_hash_version")]
+    pub hash_version: u8,
     pub self_hash: String,
 }
 
@@ -105,6 +326,7 @@ fn hash_bytes(data: &[u8]) -> String {
 }
 
 impl SafetyEpochManifest {
+    /// Unchecked against any shard policy; see `ShardPolicyRegistry::check_manifest`.
     pub fn new(
         vnode: VNodeId,
         epoch_start: u64,
@@ -116,7 +338,38 @@ impl SafetyEpochManifest {
         external_refs: Vec<String>,
         prev_hash: Option<String>,
     ) -> Self {
-        let id = Uuid::new_v4();
+        Self::new_with_ids(
+            &UuidV4Generator,
+            vnode,
+            epoch_start,
+            epoch_end,
+            metrics,
+            baseline,
+            justice,
+            vnode_log_root,
+            external_refs,
+            prev_hash,
+        )
+    }
+
+    /// Like `new`, drawing the manifest id from `ids` (deterministic replays).
+    /// `external_refs` is a set: stored sorted and de-duplicated.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_ids(
+        ids: &dyn IdGenerator,
+        vnode: VNodeId,
+        epoch_start: u64,
+        epoch_end: u64,
+        metrics: ImpactMetrics,
+        baseline: BaselineModel,
+        justice: JusticeConstraints,
+        vnode_log_root: String,
+        mut external_refs: Vec<String>,
+        prev_hash: Option<String>,
+    ) -> Self {
+        external_refs.sort();
+        external_refs.dedup();
+        let id = ids.next_uuid();
         let mut manifest = SafetyEpochManifest {
             id,
             vnode,
@@ -127,21 +380,77 @@ impl SafetyEpochManifest {
             justice,
             vnode_log_root,
             external_refs,
+            leakage: None,
+            near_miss_events: None,
+            data_quality: None,
+            ref_snapshots: BTreeMap::new(),
             prev_hash,
+            hash_version: CURRENT_HASH_VERSION,
             self_hash: String::new(),
         };
-        manifest.self_hash = manifest.compute_hash();
+        manifest.self_hash = manifest.compute_hash().unwrap_or_default();
         manifest
     }
 
-    fn compute_hash(&self) -> String {
-        let mut map = HashMap::new();
+    /// Attach a leakage estimate and re-seal the manifest hash.
+    pub fn with_leakage(mut self, leakage: LeakageEstimate) -> Self {
+        self.leakage = Some(leakage);
+        self.self_hash = self.compute_hash().unwrap_or_default();
+        self
+    }
+
+    /// Leakage deduction in tCO₂e (0 when no estimate is attached).
+    pub fn leakage_t_co2e(&self) -> f64 {
+        self.leakage
+            .as_ref()
+            .map(|l| l.displaced_t_co2e.max(0.0))
+            .unwrap_or(0.0)
+    }
+
+    /// Gross avoidance minus leakage, never below zero.
+    pub fn net_t_co2e_avoided(&self) -> f64 {
+        (self.metrics.t_co2e_avoided - self.leakage_t_co2e()).max(0.0)
+    }
+
+    /// Recompute `self_hash` under this manifest's `hash_version`; `None` for a version this
+    /// build does not know.
+    pub fn compute_hash(&self) -> Option<String> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let hash = match self.hash_version {
+            HASH_VERSION_LEGACY_JSON => Some(self.legacy_json_hash()),
+            HASH_VERSION_CANONICAL => Some(hash_bytes(&self.to_canonical_bytes())),
+            _ => None,
+        };
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            manifest_id = %self.id,
+            vnode_id = %self.vnode.vnode_id,
+            hash_version = self.hash_version,
+            duration_us = started.elapsed().as_micros() as u64,
+            "manifest hashed"
+        );
+        hash
+    }
+
+    /// Whether `self_hash` matches the manifest's contents; never under an unknown `hash_version`.
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash().is_some_and(|hash| hash == self.self_hash)
+    }
+
+    fn legacy_json_hash(&self) -> String {
+        // Ordered keys, so the same manifest always hashes the same.
+        let mut map = BTreeMap::new();
         map.insert("id", self.id.to_string());
         map.insert("vnode_id", self.vnode.vnode_id.clone());
         map.insert("policy_shard_id", self.vnode.policy_shard_id.clone());
         map.insert("epoch_start", self.epoch_start.to_string());
         map.insert("epoch_end", self.epoch_end.to_string());
         map.insert("vnode_log_root", self.vnode_log_root.clone());
+        if let Some(leakage) = &self.leakage {
+            map.insert("leakage_t_co2e", leakage.displaced_t_co2e.to_string());
+        }
         if let Some(prev) = &self.prev_hash {
             map.insert("prev_hash", prev.clone());
         }
@@ -154,10 +463,13 @@ impl SafetyEpochManifest {
         if !self.baseline.additionality_certified {
             return false;
         }
-        // Simple additionality check on CO₂e and kWh reductions.
+        if self.verify_near_miss_events().is_err() {
+            return false;
+        }
+        // Simple additionality check on CO₂e (net of leakage) and kWh reductions.
         let ratio = if self.baseline.min_improvement_ratio <= 0.0 {
             1.0
-        } else if self.metrics.t_co2e_avoided > 0.0 {
+        } else if self.net_t_co2e_avoided() > 0.0 {
             // In a real system, compute relative improvement vs. modeled baseline.
             1.0
         } else {
@@ -177,25 +489,95 @@ impl SafetyEpochManifest {
         true
     }
 
-    /// Convert this manifest into a non-transferable KarmaAllowance.
+    /// Eligibility under the shard's policy: the manifest must belong to the
+    /// shard, carry a leakage estimate when the shard requires one, carry only
+    /// custom metrics the shard registers (each within range), itemize its
+    /// near-misses unless the shard allows summaries, snapshot the external refs
+    /// the shard requires, and pass the base checks.
+    pub fn is_eligible_under_policy(&self, policy: &ShardPolicy) -> bool {
+        if self.vnode.policy_shard_id != policy.policy_shard_id {
+            return false;
+        }
+        if policy.require_leakage_estimate && self.leakage.is_none() {
+            return false;
+        }
+        if self.metrics.near_misses_blocked > 0 && self.near_miss_events.is_none() && !policy.allow_near_miss_summary {
+            return false;
+        }
+        if steward_ids::metrics::check_custom(&policy.custom_metrics, &self.metrics.custom).is_err() {
+            return false;
+        }
+        if policy.check_ref_snapshots(self).is_err() {
+            return false;
+        }
+        self.is_eligible_for_karma()
+    }
+
+    /// Convert this manifest into a non-transferable KarmaAllowance, if it is eligible under
+    /// `policy`, the shard's policy; there is no conversion without one.
     /// No mint, no transfer; this only “earns” AU.ET internally. [web:0][web:3]
     pub fn to_karma_allowance(
         &self,
+        policy: &ShardPolicy,
         prev_hash: Option<String>,
         au_et_price_per_tco2e: f64,
         au_et_price_per_kwh: f64,
         au_et_price_per_near_miss: f64,
+    ) -> Option<KarmaAllowance> {
+        self.to_karma_allowance_with_pricing(
+            policy,
+            prev_hash,
+            &PricingTable::new(au_et_price_per_tco2e, au_et_price_per_kwh, au_et_price_per_near_miss),
+        )
+    }
+
+    /// Like `to_karma_allowance`, priced from `pricing`, custom dimensions and
+    /// severity-weighted near-misses included. The allowance carries the shard's vintage
+    /// window, if it sets one.
+    pub fn to_karma_allowance_with_pricing(
+        &self,
+        policy: &ShardPolicy,
+        prev_hash: Option<String>,
+        pricing: &PricingTable,
+    ) -> Option<KarmaAllowance> {
+        self.to_karma_allowance_with_ids(&UuidV4Generator, policy, prev_hash, pricing)
+    }
+
+    /// Like `to_karma_allowance_with_pricing`, drawing the allowance id from `ids`.
+    pub fn to_karma_allowance_with_ids(
+        &self,
+        ids: &dyn IdGenerator,
+        policy: &ShardPolicy,
+        prev_hash: Option<String>,
+        pricing: &PricingTable,
+    ) -> Option<KarmaAllowance> {
+        if !self.is_eligible_under_policy(policy) {
+            return None;
+        }
+        let vintage = policy.vintage.as_ref().map(|rule| VintageWindow::from_rule(self.epoch_end, rule));
+        self.allowance(ids, prev_hash, pricing, vintage, None)
+    }
+
+    fn allowance(
+        &self,
+        ids: &dyn IdGenerator,
+        prev_hash: Option<String>,
+        pricing: &PricingTable,
+        vintage: Option<VintageWindow>,
+        policy_version: Option<u32>,
     ) -> Option<KarmaAllowance> {
         if !self.is_eligible_for_karma() {
             return None;
         }
 
-        let mut au_et_delta = 0.0;
-        au_et_delta += self.metrics.t_co2e_avoided * au_et_price_per_tco2e;
-        au_et_delta += self.metrics.kwh_reduced * au_et_price_per_kwh;
-        au_et_delta += (self.metrics.near_misses_blocked as f64) * au_et_price_per_near_miss;
+        let carbon = CarbonAccounting {
+            gross_t_co2e_avoided: self.metrics.t_co2e_avoided,
+            leakage_t_co2e: self.leakage_t_co2e(),
+            net_t_co2e_avoided: self.net_t_co2e_avoided(),
+        };
 
-        let id = Uuid::new_v4();
+        let au_et_delta = self.priced_au_et(pricing);
+        let id = ids.next_uuid();
         let mut allowance = KarmaAllowance {
             id,
             vnode: self.vnode.clone(),
@@ -203,26 +585,92 @@ impl SafetyEpochManifest {
             epoch_end: self.epoch_end,
             au_et_delta,
             metrics: self.metrics.clone(),
+            carbon,
             baseline: self.baseline.clone(),
             justice: self.justice.clone(),
             manifest_hash: self.self_hash.clone(),
             prev_hash,
+            vintage,
+            policy_version,
+            hash_version: CURRENT_HASH_VERSION,
             self_hash: String::new(),
         };
-        allowance.self_hash = allowance.compute_hash();
+        allowance.self_hash = allowance.compute_hash().unwrap_or_default();
         Some(allowance)
     }
+
+    /// AU.ET the metrics are worth at `pricing`, eligible or not.
+    pub(crate) fn priced_au_et(&self, pricing: &PricingTable) -> f64 {
+        let mut au_et_delta = 0.0;
+        au_et_delta += self.net_t_co2e_avoided() * pricing.per_tco2e;
+        au_et_delta += self.metrics.kwh_reduced * pricing.per_kwh;
+        au_et_delta += pricing.near_miss_au_et(self);
+        for (key, value) in &self.metrics.custom {
+            au_et_delta += value.value * pricing.per_custom.get(key).copied().unwrap_or(0.0);
+        }
+        au_et_delta
+    }
+
+    /// Like `to_karma_allowance_with_pricing`, under the shard policy version
+    /// `registry` has in force at `epoch_start`, which the allowance records. Fails with the
+    /// reason when the manifest's settings are laxer than that version or it is ineligible.
+    pub fn to_karma_allowance_in_registry(
+        &self,
+        registry: &ShardPolicyRegistry,
+        prev_hash: Option<String>,
+        pricing: &PricingTable,
+    ) -> Result<KarmaAllowance, String> {
+        self.to_karma_allowance_in_registry_with_ids(&UuidV4Generator, registry, prev_hash, pricing)
+    }
+
+    /// Like `to_karma_allowance_in_registry`, drawing the allowance id from `ids`.
+    pub fn to_karma_allowance_in_registry_with_ids(
+        &self,
+        ids: &dyn IdGenerator,
+        registry: &ShardPolicyRegistry,
+        prev_hash: Option<String>,
+        pricing: &PricingTable,
+    ) -> Result<KarmaAllowance, String> {
+        let policy = registry.check_manifest(self)?;
+        if !self.is_eligible_under_policy(policy) {
+            return Err(format!(
+                "Manifest {} is not eligible for karma under shard {} v{}",
+                self.id, policy.policy_shard_id, policy.version
+            ));
+        }
+        let vintage = policy.vintage.as_ref().map(|rule| VintageWindow::from_rule(self.epoch_end, rule));
+        self.allowance(ids, prev_hash, pricing, vintage, Some(policy.version))
+            .ok_or_else(|| format!("Manifest {} is not eligible for karma", self.id))
+    }
 }
 
 impl KarmaAllowance {
-    fn compute_hash(&self) -> String {
-        let mut map = HashMap::new();
+    /// Recompute `self_hash` under this allowance's `hash_version`; `None` for a version this
+    /// build does not know.
+    pub fn compute_hash(&self) -> Option<String> {
+        match self.hash_version {
+            HASH_VERSION_LEGACY_JSON => Some(self.legacy_json_hash()),
+            HASH_VERSION_CANONICAL => Some(hash_bytes(&self.to_canonical_bytes())),
+            _ => None,
+        }
+    }
+
+    /// Whether `self_hash` matches the allowance's contents; never under an unknown `hash_version`.
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash().is_some_and(|hash| hash == self.self_hash)
+    }
+
+    fn legacy_json_hash(&self) -> String {
+        let mut map = BTreeMap::new();
         map.insert("id", self.id.to_string());
         map.insert("vnode_id", self.vnode.vnode_id.clone());
         map.insert("policy_shard_id", self.vnode.policy_shard_id.clone());
         map.insert("epoch_start", self.epoch_start.to_string());
         map.insert("epoch_end", self.epoch_end.to_string());
         map.insert("manifest_hash", self.manifest_hash.clone());
+        map.insert("gross_t_co2e_avoided", self.carbon.gross_t_co2e_avoided.to_string());
+        map.insert("leakage_t_co2e", self.carbon.leakage_t_co2e.to_string());
+        map.insert("net_t_co2e_avoided", self.carbon.net_t_co2e_avoided.to_string());
         if let Some(prev) = &self.prev_hash {
             map.insert("prev_hash", prev.clone());
         }
@@ -237,12 +685,17 @@ impl KarmaAllowance {
     }
 }
 
+impl CanonicalEncoding for SafetyEpochManifest {}
+impl CanonicalEncoding for KarmaAllowance {}
+
 /// Convenience helper for creating an epoch window around “now”.
 pub fn current_epoch_window(epoch_seconds: u64) -> (u64, u64) {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("time went backwards")
-        .as_secs();
+    epoch_window_at(&SystemClock, epoch_seconds)
+}
+
+/// Epoch window around `clock`'s current time.
+pub fn epoch_window_at(clock: &dyn Clock, epoch_seconds: u64) -> (u64, u64) {
+    let now = clock.now_secs();
     let start = now - (now % epoch_seconds);
     (start, start + epoch_seconds)
 }
@@ -0,0 +1,104 @@
+// path: aln-karma/src/merkle.rs
+
+//! Merkle inclusion proofs against `SafetyEpochManifest::vnode_log_root`.
+//! - Leaves are `leaf_hash(entry)`: SHA-256 over `0x00 || entry`
+//! - Interior nodes are SHA-256 over `0x01 || left || right`, the children as lowercase hex
+//! - An unpaired node is carried up a level unchanged (no duplication), so a proof has no
+//!   step for that level
+//! - `merkle_root` / `inclusion_proof` are for log producers; auditors only need `MerkleProof::verify`
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+/// Which side of the running hash a proof step's sibling sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SiblingSide {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProofStep {
+    pub sibling: String,
+    pub side: SiblingSide,
+}
+
+/// Path from a leaf to the root, leaf level first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MerkleProof {
+    #[serde(default)]
+    pub steps: Vec<ProofStep>,
+}
+
+impl MerkleProof {
+    /// Root reached by hashing `leaf` up the proof.
+    pub fn root_from(&self, leaf: &str) -> String {
+        self.steps.iter().fold(leaf.to_string(), |acc, step| match step.side {
+            SiblingSide::Left => node_hash(&step.sibling, &acc),
+            SiblingSide::Right => node_hash(&acc, &step.sibling),
+        })
+    }
+
+    /// Whether `leaf` is included under `root`.
+    pub fn verify(&self, leaf: &str, root: &str) -> bool {
+        self.root_from(leaf) == root
+    }
+}
+
+/// Hash of one raw log entry, as it appears at the bottom of the tree.
+pub fn leaf_hash(entry: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(entry);
+    format!("{:x}", hasher.finalize())
+}
+
+fn node_hash(left: &str, right: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left.as_bytes());
+    hasher.update(right.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn next_level(level: &[String]) -> Vec<String> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => single.clone(),
+            _ => unreachable!("chunks(2)"),
+        })
+        .collect()
+}
+
+/// Root over `leaves` (already `leaf_hash`ed), in log order; `None` for an empty log.
+pub fn merkle_root(leaves: &[String]) -> Option<String> {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.pop()
+}
+
+/// Proof that `leaves[index]` is included in `merkle_root(leaves)`.
+pub fn inclusion_proof(leaves: &[String], mut index: usize) -> Option<MerkleProof> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut proof = MerkleProof::default();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        if let Some(hash) = level.get(sibling) {
+            let side = if sibling < index { SiblingSide::Left } else { SiblingSide::Right };
+            proof.steps.push(ProofStep { sibling: hash.clone(), side });
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
@@ -0,0 +1,108 @@
+// path: aln-karma/src/near_miss.rs
+
+//! Per-event records behind `ImpactMetrics::near_misses_blocked`.
+//! - A manifest may list the events it counts; the list must match the counter, and each
+//!   event's `log_leaf_hash` must prove into `vnode_log_root` (`merkle`)
+//! - Without a list, a nonzero counter is accepted only under a shard that allows
+//!   summary-only reporting (`ShardPolicy::allow_near_miss_summary`)
+//! - `PricingTable::near_miss_severity_weights` prices listed events by severity instead of
+//!   a flat rate per near-miss
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+
+use crate::merkle::MerkleProof;
+use crate::{PricingTable, SafetyEpochManifest};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum NearMissCategory {
+    /// Pollution, heat or radiation exposure kept under its limit.
+    OverExposure,
+    Collision,
+    GridOverload,
+    Biosafety,
+    Other(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum NearMissSeverity {
+    Low,
+    Moderate,
+    High,
+    Critical,
+}
+
+/// One blocked near-miss, traceable to its vNode log entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NearMissEvent {
+    pub event_id: String,
+    pub category: NearMissCategory,
+    pub detected_at_ms: u64,
+    pub severity: NearMissSeverity,
+    /// `merkle::leaf_hash` of the log entry recording the event.
+    pub log_leaf_hash: String,
+    /// Path from `log_leaf_hash` to the manifest's `vnode_log_root`.
+    #[serde(default)]
+    pub inclusion_proof: MerkleProof,
+}
+
+impl SafetyEpochManifest {
+    /// Attach per-event near-miss records and re-seal the manifest hash.
+    pub fn with_near_miss_events(mut self, events: Vec<NearMissEvent>) -> Self {
+        self.near_miss_events = Some(events);
+        self.self_hash = self.compute_hash().unwrap_or_default();
+        self
+    }
+
+    /// Listed events must match `near_misses_blocked`, have unique ids, and each
+    /// prove into `vnode_log_root`. Passes when no events are listed.
+    pub fn verify_near_miss_events(&self) -> Result<(), String> {
+        let Some(events) = &self.near_miss_events else {
+            return Ok(());
+        };
+        if events.len() as u64 != self.metrics.near_misses_blocked {
+            return Err(format!(
+                "Manifest counts {} near-misses but lists {} events",
+                self.metrics.near_misses_blocked,
+                events.len()
+            ));
+        }
+        let mut seen = BTreeSet::new();
+        for event in events {
+            if !seen.insert(event.event_id.as_str()) {
+                return Err(format!("Near-miss event {} is listed twice", event.event_id));
+            }
+            if !event.inclusion_proof.verify(&event.log_leaf_hash, &self.vnode_log_root) {
+                return Err(format!(
+                    "Near-miss event {} log leaf {} is not included in {}",
+                    event.event_id, event.log_leaf_hash, self.vnode_log_root
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PricingTable {
+    /// Weight listed near-misses of `severity` by `weight` (times `per_near_miss`).
+    pub fn with_severity_weight(mut self, severity: NearMissSeverity, weight: f64) -> Self {
+        self.near_miss_severity_weights.insert(severity, weight);
+        self
+    }
+
+    /// AU.ET for the manifest's near-misses: per listed event by severity weight when
+    /// weights are configured and events are listed, otherwise the flat rate per count.
+    /// Severities without a weight count as 1.0.
+    pub fn near_miss_au_et(&self, manifest: &SafetyEpochManifest) -> f64 {
+        match &manifest.near_miss_events {
+            Some(events) if !self.near_miss_severity_weights.is_empty() => events
+                .iter()
+                .map(|e| self.per_near_miss * self.near_miss_severity_weights.get(&e.severity).copied().unwrap_or(1.0))
+                .sum(),
+            _ => manifest.metrics.near_misses_blocked as f64 * self.per_near_miss,
+        }
+    }
+}
@@ -0,0 +1,322 @@
+// path: aln-karma/src/refs.rs
+
+//! Resolution of manifests' `external_refs`, and snapshots of what they held.
+//! - An `ExternalRefResolver` reads one URI scheme (`city_sensors://...`) and reports a
+//!   `RefSnapshot`: a hash of the content, when it was read, and a short summary
+//! - `ResolverRegistry` maps schemes to resolvers. Resolution only reads, so the registry
+//!   retries transient failures (`RefError::Unavailable`) up to `max_attempts` times
+//! - A vNode captures snapshots at creation (`with_ref_snapshots`); they are part of the
+//!   canonical hash. A shard may require them for designated schemes
+//!   (`ShardPolicy::required_ref_schemes`): manifests missing one are rejected
+//! - `audit_refs` re-resolves every ref and reports, per ref, whether the content still
+//!   matches what was captured
+//! - This crate ships `InMemoryResolver` only; network resolvers live downstream
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use steward_runtime_support::Clock;
+use uuid::Uuid;
+
+use crate::{SafetyEpochManifest, ShardPolicy};
+
+/// Attempts `ResolverRegistry` makes per ref unless configured otherwise.
+pub const DEFAULT_RESOLVE_ATTEMPTS: u32 = 3;
+
+/// What an external ref held when it was resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RefSnapshot {
+    /// e.g. `sha256:<hex>` of the content.
+    pub content_hash: String,
+    pub resolved_at_ms: u64,
+    /// Human-readable gist, e.g. "PM2.5 hourly means, 24 readings".
+    pub summary: String,
+}
+
+/// Why a ref did not resolve.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RefError {
+    /// The URI has no `<scheme>://` prefix.
+    NoScheme { uri: String },
+    /// No resolver is registered for the scheme.
+    NoResolver { scheme: String },
+    /// The resolver knows nothing at the URI.
+    NotFound { uri: String },
+    /// The source could not be reached; trying again may succeed.
+    Unavailable { uri: String, detail: String },
+}
+
+impl RefError {
+    /// Whether a retry may succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, RefError::Unavailable { .. })
+    }
+}
+
+impl fmt::Display for RefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RefError::NoScheme { uri } => write!(f, "External ref {uri} has no scheme"),
+            RefError::NoResolver { scheme } => write!(f, "No resolver for {scheme}:// refs"),
+            RefError::NotFound { uri } => write!(f, "External ref {uri} was not found"),
+            RefError::Unavailable { uri, detail } => write!(f, "External ref {uri} is unavailable: {detail}"),
+        }
+    }
+}
+
+impl std::error::Error for RefError {}
+
+/// The scheme of `uri`, e.g. `city_sensors` for `city_sensors://phoenix/pm25`.
+pub fn ref_scheme(uri: &str) -> Option<&str> {
+    uri.split_once("://").map(|(scheme, _)| scheme).filter(|s| !s.is_empty())
+}
+
+/// `sha256:<hex>` of `content`, as `RefSnapshot::content_hash` is written.
+pub fn content_hash(content: &[u8]) -> String {
+    format!("sha256:{:x}", Sha256::digest(content))
+}
+
+/// Reads refs of one scheme. Resolving must not change anything, so it can be retried.
+pub trait ExternalRefResolver: Send + Sync {
+    fn resolve(&self, uri: &str) -> Result<RefSnapshot, RefError>;
+}
+
+/// Resolvers by URI scheme.
+#[derive(Clone)]
+pub struct ResolverRegistry {
+    resolvers: BTreeMap<String, Arc<dyn ExternalRefResolver>>,
+    max_attempts: u32,
+}
+
+impl Default for ResolverRegistry {
+    fn default() -> Self {
+        Self { resolvers: BTreeMap::new(), max_attempts: DEFAULT_RESOLVE_ATTEMPTS }
+    }
+}
+
+impl fmt::Debug for ResolverRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResolverRegistry")
+            .field("schemes", &self.resolvers.keys().collect::<Vec<_>>())
+            .field("max_attempts", &self.max_attempts)
+            .finish()
+    }
+}
+
+impl ResolverRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve `scheme://` refs with `resolver`, replacing any registered before.
+    pub fn with_resolver(mut self, scheme: &str, resolver: Arc<dyn ExternalRefResolver>) -> Self {
+        self.register(scheme, resolver);
+        self
+    }
+
+    pub fn register(&mut self, scheme: &str, resolver: Arc<dyn ExternalRefResolver>) {
+        self.resolvers.insert(scheme.to_string(), resolver);
+    }
+
+    /// Try each ref up to `attempts` times (at least once) while it fails transiently.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    pub fn schemes(&self) -> impl Iterator<Item = &str> {
+        self.resolvers.keys().map(String::as_str)
+    }
+
+    /// `uri` through its scheme's resolver, retried while unavailable.
+    pub fn resolve(&self, uri: &str) -> Result<RefSnapshot, RefError> {
+        let scheme = ref_scheme(uri).ok_or_else(|| RefError::NoScheme { uri: uri.to_string() })?;
+        let resolver = self
+            .resolvers
+            .get(scheme)
+            .ok_or_else(|| RefError::NoResolver { scheme: scheme.to_string() })?;
+        let mut attempt = 1;
+        loop {
+            match resolver.resolve(uri) {
+                Err(e) if e.is_transient() && attempt < self.max_attempts => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Published {
+    content_hash: String,
+    summary: String,
+    /// Resolutions still to fail as unavailable.
+    outages: u32,
+}
+
+/// Refs served from memory, for tests and fixtures; snapshots are stamped by `clock`.
+pub struct InMemoryResolver {
+    clock: Arc<dyn Clock>,
+    published: Mutex<BTreeMap<String, Published>>,
+}
+
+impl InMemoryResolver {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock, published: Mutex::new(BTreeMap::new()) }
+    }
+
+    /// Serve `content` at `uri`, replacing what was there.
+    pub fn publish(&self, uri: &str, content: &[u8], summary: &str) {
+        let published = Published { content_hash: content_hash(content), summary: summary.to_string(), outages: 0 };
+        self.lock().insert(uri.to_string(), published);
+    }
+
+    pub fn unpublish(&self, uri: &str) {
+        self.lock().remove(uri);
+    }
+
+    /// Fail the next `times` resolutions of `uri` as unavailable.
+    pub fn fail_next(&self, uri: &str, times: u32) {
+        if let Some(published) = self.lock().get_mut(uri) {
+            published.outages = times;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, Published>> {
+        self.published.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ExternalRefResolver for InMemoryResolver {
+    fn resolve(&self, uri: &str) -> Result<RefSnapshot, RefError> {
+        let mut published = self.lock();
+        let entry = published.get_mut(uri).ok_or_else(|| RefError::NotFound { uri: uri.to_string() })?;
+        if entry.outages > 0 {
+            entry.outages -= 1;
+            return Err(RefError::Unavailable { uri: uri.to_string(), detail: "simulated outage".into() });
+        }
+        Ok(RefSnapshot {
+            content_hash: entry.content_hash.clone(),
+            resolved_at_ms: self.clock.now_ms(),
+            summary: entry.summary.clone(),
+        })
+    }
+}
+
+/// How one ref compares with what the manifest captured.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RefStatus {
+    /// Resolved to the captured content.
+    Matches,
+    /// Resolved to other content than was captured.
+    Mismatch { captured: String, resolved: String },
+    /// Resolved, with nothing captured to compare against.
+    Uncaptured,
+    Unresolved { error: RefError },
+}
+
+/// One ref of an audited manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RefAudit {
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub captured: Option<RefSnapshot>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<RefSnapshot>,
+    pub status: RefStatus,
+}
+
+/// A manifest's external refs, re-resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RefAuditReport {
+    pub manifest_id: Uuid,
+    pub manifest_hash: String,
+    /// In `external_refs` order.
+    pub refs: Vec<RefAudit>,
+}
+
+impl RefAuditReport {
+    /// Every ref resolved to what was captured, or had nothing captured.
+    pub fn is_consistent(&self) -> bool {
+        self.refs.iter().all(|r| matches!(r.status, RefStatus::Matches | RefStatus::Uncaptured))
+    }
+
+    pub fn mismatches(&self) -> impl Iterator<Item = &RefAudit> {
+        self.refs.iter().filter(|r| matches!(r.status, RefStatus::Mismatch { .. }))
+    }
+}
+
+impl SafetyEpochManifest {
+    /// Attach snapshots of external refs, keyed by URI, and re-seal the manifest hash.
+    /// Each must name one of `external_refs`.
+    pub fn with_ref_snapshots(
+        mut self,
+        snapshots: impl IntoIterator<Item = (String, RefSnapshot)>,
+    ) -> Result<Self, String> {
+        for (uri, snapshot) in snapshots {
+            if self.external_refs.binary_search(&uri).is_err() {
+                return Err(format!("Manifest {} has no external ref {uri} to snapshot", self.id));
+            }
+            self.ref_snapshots.insert(uri, snapshot);
+        }
+        self.self_hash = self.compute_hash().unwrap_or_default();
+        Ok(self)
+    }
+
+    /// Each external ref through `registry`, in `external_refs` order.
+    pub fn resolve_refs(&self, registry: &ResolverRegistry) -> Vec<(String, Result<RefSnapshot, RefError>)> {
+        self.external_refs.iter().map(|uri| (uri.clone(), registry.resolve(uri))).collect()
+    }
+
+    /// `resolve_refs`, each result set against the snapshot captured for it.
+    pub fn audit_refs(&self, registry: &ResolverRegistry) -> RefAuditReport {
+        let refs = self
+            .resolve_refs(registry)
+            .into_iter()
+            .map(|(uri, result)| {
+                let captured = self.ref_snapshots.get(&uri).cloned();
+                let (resolved, status) = match result {
+                    Err(error) => (None, RefStatus::Unresolved { error }),
+                    Ok(resolved) => {
+                        let status = match &captured {
+                            None => RefStatus::Uncaptured,
+                            Some(c) if c.content_hash == resolved.content_hash => RefStatus::Matches,
+                            Some(c) => RefStatus::Mismatch {
+                                captured: c.content_hash.clone(),
+                                resolved: resolved.content_hash.clone(),
+                            },
+                        };
+                        (Some(resolved), status)
+                    }
+                };
+                RefAudit { uri, captured, resolved, status }
+            })
+            .collect();
+        RefAuditReport { manifest_id: self.id, manifest_hash: self.self_hash.clone(), refs }
+    }
+}
+
+impl ShardPolicy {
+    /// Every external ref of a required scheme carries a snapshot.
+    pub fn check_ref_snapshots(&self, manifest: &SafetyEpochManifest) -> Result<(), String> {
+        let missing = manifest.external_refs.iter().find(|uri| {
+            ref_scheme(uri).is_some_and(|s| self.required_ref_schemes.contains(s))
+                && !manifest.ref_snapshots.contains_key(*uri)
+        });
+        match missing {
+            Some(uri) => Err(format!(
+                "Manifest {} carries no snapshot of {uri}, required by shard {} v{}",
+                manifest.id, self.policy_shard_id, self.version
+            )),
+            None => Ok(()),
+        }
+    }
+}
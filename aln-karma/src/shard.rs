@@ -0,0 +1,151 @@
+// path: aln-karma/src/shard.rs
+
+//! Versioned shard policies, shared by every manifest and allowance of a shard.
+//! - `ShardPolicyRegistry` keeps each shard's `ShardPolicy` versions; versions only move
+//!   forward, each in force from its `effective_from` until the next one's
+//! - The version in force at a manifest's `epoch_start` governs the whole epoch: a version
+//!   taking effect mid-epoch applies from the next epoch on
+//! - A manifest's own baseline and justice settings may be stricter than its shard's, never
+//!   laxer; its cadence and leakage estimate must match what the shard requires
+//! - Allowances converted through the registry record the policy version they were checked
+//!   against, and a ledger holding the registry only appends allowances that match it
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::{BaselineModel, JusticeConstraints, KarmaAllowance, SafetyEpochManifest, ShardPolicy};
+
+impl ShardPolicy {
+    /// Whether `baseline` and `justice` are at least as strict as this policy.
+    pub fn check_settings(&self, baseline: &BaselineModel, justice: &JusticeConstraints) -> Result<(), String> {
+        if !self.baseline_methods.is_empty()
+            && !baseline.method.as_ref().is_some_and(|m| self.baseline_methods.contains(m))
+        {
+            return Err(format!(
+                "Baseline method {} is not accepted by shard {} v{}",
+                baseline.method.as_deref().unwrap_or("(none)"),
+                self.policy_shard_id,
+                self.version
+            ));
+        }
+        if baseline.min_improvement_ratio < self.min_improvement_ratio {
+            return Err(format!(
+                "Baseline min_improvement_ratio {} is below shard {} v{}'s {}",
+                baseline.min_improvement_ratio, self.policy_shard_id, self.version, self.min_improvement_ratio
+            ));
+        }
+        if self.justice.forbid_burden_shifting && !justice.forbid_burden_shifting {
+            return Err(format!(
+                "Shard {} v{} forbids burden shifting; the justice constraints allow it",
+                self.policy_shard_id, self.version
+            ));
+        }
+        if self.justice.require_opt_out_respected && !justice.require_opt_out_respected {
+            return Err(format!(
+                "Shard {} v{} requires opt-outs respected; the justice constraints do not",
+                self.policy_shard_id, self.version
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether `manifest` belongs to this shard and is compatible with this version.
+    pub fn check_manifest(&self, manifest: &SafetyEpochManifest) -> Result<(), String> {
+        if manifest.vnode.policy_shard_id != self.policy_shard_id {
+            return Err(format!(
+                "Manifest {} is in shard {}, not {}",
+                manifest.id, manifest.vnode.policy_shard_id, self.policy_shard_id
+            ));
+        }
+        self.check_settings(&manifest.baseline, &manifest.justice)?;
+        if let Some(epoch_seconds) = self.epoch_seconds {
+            let length = manifest.epoch_end.saturating_sub(manifest.epoch_start);
+            if length != epoch_seconds || !manifest.epoch_start.is_multiple_of(epoch_seconds) {
+                return Err(format!(
+                    "Epoch [{}, {}) does not follow shard {} v{}'s {epoch_seconds} s cadence",
+                    manifest.epoch_start, manifest.epoch_end, self.policy_shard_id, self.version
+                ));
+            }
+        }
+        if self.require_leakage_estimate && manifest.leakage.is_none() {
+            return Err(format!(
+                "Shard {} v{} requires a leakage estimate",
+                self.policy_shard_id, self.version
+            ));
+        }
+        self.check_ref_snapshots(manifest)
+    }
+}
+
+/// Every shard's policy versions, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ShardPolicyRegistry {
+    shards: BTreeMap<String, Vec<ShardPolicy>>,
+}
+
+impl ShardPolicyRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a version of `policy.policy_shard_id`'s policy. It must have a higher `version`
+    /// and a later `effective_from` than the shard's latest; registered versions never change.
+    pub fn register(&mut self, policy: ShardPolicy) -> Result<(), String> {
+        let versions = self.shards.entry(policy.policy_shard_id.clone()).or_default();
+        if let Some(latest) = versions.last() {
+            if policy.version <= latest.version {
+                return Err(format!(
+                    "Shard {} already has version {}; new versions must be higher",
+                    policy.policy_shard_id, latest.version
+                ));
+            }
+            if policy.effective_from <= latest.effective_from {
+                return Err(format!(
+                    "Shard {} v{} must take effect after v{} (from {})",
+                    policy.policy_shard_id, policy.version, latest.version, latest.effective_from
+                ));
+            }
+        }
+        versions.push(policy);
+        Ok(())
+    }
+
+    /// `shard_id`'s versions, oldest first.
+    pub fn versions(&self, shard_id: &str) -> &[ShardPolicy] {
+        self.shards.get(shard_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The version of `shard_id`'s policy in force at `at` (seconds, like epoch bounds).
+    pub fn in_force(&self, shard_id: &str, at: u64) -> Option<&ShardPolicy> {
+        self.versions(shard_id).iter().rev().find(|p| p.effective_from <= at)
+    }
+
+    /// The policy governing `manifest`'s epoch, if the manifest is compatible with it.
+    pub fn check_manifest(&self, manifest: &SafetyEpochManifest) -> Result<&ShardPolicy, String> {
+        let policy = self.governing(&manifest.vnode.policy_shard_id, manifest.epoch_start)?;
+        policy.check_manifest(manifest)?;
+        Ok(policy)
+    }
+
+    /// The policy governing `allowance`'s epoch, if the allowance was checked against it.
+    pub fn check_allowance(&self, allowance: &KarmaAllowance) -> Result<&ShardPolicy, String> {
+        let policy = self.governing(&allowance.vnode.policy_shard_id, allowance.epoch_start)?;
+        if allowance.policy_version != Some(policy.version) {
+            return Err(format!(
+                "Allowance {} was checked against {}, not shard {} v{} in force at its epoch start",
+                allowance.id,
+                allowance.policy_version.map_or("no policy version".to_string(), |v| format!("v{v}")),
+                policy.policy_shard_id,
+                policy.version
+            ));
+        }
+        policy.check_settings(&allowance.baseline, &allowance.justice)?;
+        Ok(policy)
+    }
+
+    fn governing(&self, shard_id: &str, epoch_start: u64) -> Result<&ShardPolicy, String> {
+        self.in_force(shard_id, epoch_start)
+            .ok_or_else(|| format!("Shard {shard_id} has no policy in force at {epoch_start}"))
+    }
+}
@@ -0,0 +1,111 @@
+// path: cybernetic-governance/examples/amendment_timelock.rs
+
+//! Example: amending an arena's constitution (run with `--features test-util`).
+//! - Raising the global floor needs strictly more than 0.67 + 0.05: 72% stays proposed, 80%
+//!   approves; the amendment waits out a 100-height time-lock in `pending_amendments` and
+//!   then applies, with before/after hashes in the history
+//! - A counter-proposal cancels a pending amendment, but only with its own vote and only
+//!   inside the time-lock; votes naming another proposal are refused either way
+//! - Removing a non-restrictable capability weakens hard protections: 90% is needed, and the
+//!   assessment flags it as an irreversible risk
+//! - An approved amendment that the arena no longer satisfies when it falls due (a floor
+//!   above what is enabled) is rejected, and the constitution is left as it was
+
+use cybernetic_governance::builders::{cap, vote, ConstitutionBuilder, DomainBuilder, ProposalBuilder};
+use cybernetic_governance::{
+    AmendmentCancellation, AmendmentRules, AmendmentStatus, CapabilityGovernance, CommitOutcome,
+    ConstitutionAmendment, ConstitutionChange, GovernanceEvent,
+};
+
+const ARENA: &str = "arena:phoenix:xr_sprint";
+const TIMELOCK: u64 = 100;
+
+fn amendment(id: &str, change: ConstitutionChange) -> ConstitutionAmendment {
+    ConstitutionAmendment { amendment_id: id.into(), changes: vec![change], rationale: format!("{id} rationale") }
+}
+
+fn main() {
+    let rules = AmendmentRules { timelock_heights: TIMELOCK, ..AmendmentRules::default() };
+    let mut gov = CapabilityGovernance::new(ConstitutionBuilder::new().amendment_rules(rules).build());
+    let domain = DomainBuilder::new(ARENA)
+        .allow(&["safety:emergency_stop", "move:walk", "move:dash", "move:jump", "move:slide", "move:shield"])
+        .build_valid();
+    gov.upsert_domain(domain).expect("valid domain");
+    let trim = ProposalBuilder::new("trim", ARENA).restrict(&["move:slide", "move:shield"]).activation_height(100);
+    let committed = gov.commit_proposal(&trim.build_valid_under(&gov), &vote("trim", 80, 20, 100), 100);
+    assert!(matches!(committed, Ok(CommitOutcome::Applied(_))), "four of six stay enabled");
+
+    // 1. Raise the floor from 3 to 4: strictly above 0.72, then a time-lock.
+    let floor4 = amendment("floor-4", ConstitutionChange::SetGlobalMinCapabilityFloor(4));
+    let assessment = gov.propose_amendment(floor4).expect("proposed");
+    assert!(!assessment.weakens_protections && (assessment.required_yes_ratio - 0.72).abs() < 1e-9);
+    let other = gov.approve_amendment("floor-4", &vote("floor-5", 80, 20, 1_000), 1_000);
+    assert_eq!(other, Err("Vote outcome is for floor-5, not amendment floor-4".into()));
+    let at_margin = gov.approve_amendment("floor-4", &vote("floor-4", 72, 28, 1_000), 1_000);
+    assert_eq!(at_margin, Ok(AmendmentStatus::Proposed), "0.72 is not strictly above");
+    let status = gov.approve_amendment("floor-4", &vote("floor-4", 80, 20, 1_000), 1_000).expect("voted");
+    assert_eq!(status, AmendmentStatus::Approved { approved_height: 1_000, effective_height: 1_000 + TIMELOCK });
+    assert_eq!(gov.pending_amendments().len(), 1);
+    assert!(gov.apply_due_amendments(1_000 + TIMELOCK - 1).is_empty(), "still time-locked");
+    let before = gov.constitution().canonical_hash();
+    let applied = gov.apply_due_amendments(1_000 + TIMELOCK);
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].before_hash, before);
+    assert_eq!(applied[0].after_hash, gov.constitution().canonical_hash());
+    assert_eq!(gov.constitution().global_min_capability_floor, 4);
+    assert!(gov.pending_amendments().is_empty());
+
+    // 2. A counter-proposal cancels a pending amendment with its own vote, inside the time-lock.
+    let stop = amendment("protect-stop-more", ConstitutionChange::SetProtectedOverrideSupermajority(0.95));
+    gov.propose_amendment(stop).expect("proposed");
+    gov.approve_amendment("protect-stop-more", &vote("protect-stop-more", 80, 20, 2_000), 2_000).expect("voted");
+    let cancellation = AmendmentCancellation {
+        counter_proposal_id: "keep-0.90".into(),
+        cancels_amendment_id: "protect-stop-more".into(),
+    };
+    let borrowed = gov.cancel_amendment(&cancellation, &vote("protect-stop-more", 80, 20, 2_050), 2_050);
+    assert_eq!(borrowed, Err("Vote outcome is for protect-stop-more, not counter-proposal keep-0.90".into()));
+    let weak = gov.cancel_amendment(&cancellation, &vote("keep-0.90", 60, 40, 2_050), 2_050);
+    assert!(weak.is_err(), "below the supermajority floor");
+    let cancelled = gov.cancel_amendment(&cancellation, &vote("keep-0.90", 70, 30, 2_050), 2_050).expect("cancelled");
+    assert_eq!(cancelled, AmendmentStatus::Cancelled { counter_proposal_id: "keep-0.90".into(), height: 2_050 });
+    assert!(gov.apply_due_amendments(2_000 + TIMELOCK).is_empty());
+    assert_eq!(gov.constitution().protected_override_supermajority, 0.90);
+
+    // 3. Removing a non-restrictable capability weakens hard protections: 90%, flagged.
+    let unlock = amendment("unlock-stop", ConstitutionChange::RemoveNonrestrictable(cap("safety:emergency_stop")));
+    let assessment = gov.propose_amendment(unlock).expect("proposed");
+    assert!(assessment.weakens_protections && assessment.irreversible_risk);
+    assert_eq!(assessment.required_yes_ratio, 0.9);
+    let short = gov.approve_amendment("unlock-stop", &vote("unlock-stop", 85, 15, 3_000), 3_000);
+    assert_eq!(short, Ok(AmendmentStatus::Proposed));
+    let status = gov.approve_amendment("unlock-stop", &vote("unlock-stop", 90, 10, 3_000), 3_000).expect("voted");
+    assert!(matches!(status, AmendmentStatus::Approved { .. }));
+    assert_eq!(gov.apply_due_amendments(3_000 + TIMELOCK).len(), 1);
+    let late =
+        AmendmentCancellation { counter_proposal_id: "too-late".into(), cancels_amendment_id: "unlock-stop".into() };
+    assert!(gov.cancel_amendment(&late, &vote("too-late", 90, 10, 3_200), 3_200).is_err());
+
+    // 4. A floor of 5 approved while four are enabled: rejected when due, nothing changes.
+    let floor5 = amendment("floor-5", ConstitutionChange::SetGlobalMinCapabilityFloor(5));
+    gov.propose_amendment(floor5).expect("proposed");
+    gov.approve_amendment("floor-5", &vote("floor-5", 80, 20, 4_000), 4_000).expect("voted");
+    let before = gov.constitution().canonical_hash();
+    assert!(gov.apply_due_amendments(4_000 + TIMELOCK).is_empty());
+    assert_eq!(gov.constitution().canonical_hash(), before);
+    assert_eq!(gov.amendment_history().len(), 2);
+    assert!(gov.pending_amendments().is_empty(), "no longer pending");
+    match &gov.event_log().entries().last().expect("logged").event {
+        GovernanceEvent::AmendmentStatusChanged { amendment_id, status } => {
+            assert_eq!(amendment_id, "floor-5");
+            assert!(matches!(status, AmendmentStatus::Rejected { height: 4_100, .. }), "{status:?}");
+        }
+        other => panic!("the rejection should be logged: {other:?}"),
+    }
+    let mut amended = gov.constitution().clone();
+    amended.global_min_capability_floor = 5;
+    let reason = gov.validate_amended(&amended).expect_err("four enabled");
+    println!("rejected: {reason}");
+    assert_eq!(reason, format!("Domain {ARENA} has 4 capabilities enabled, below the amended global floor of 5"));
+    gov.check_invariants().expect("the arena still satisfies the constitution in force");
+}
@@ -0,0 +1,140 @@
+// path: cybernetic-governance/examples/arena_to_element.rs
+
+//! Example: arena-level restrictions flowing into `the_element` agent profiles.
+//! - A season-long restriction disables `move:bci_pull` for every athlete
+//! - A restriction on an element baseline right is skipped, never applied
+//! - When the sunset passes, the move is unlocked again
+//! - With six-second blocks mapped from genesis, each turn carries its change's time
+//!
+//! Run with `--features the_element`.
+
+#[cfg(feature = "the_element")]
+fn main() {
+    use cybernetic_governance::element_bridge::apply_domain_to_agents;
+    use cybernetic_governance::*;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Arc;
+    use the_element::{
+        default_element, AgentId, CapabilityClass, CapabilityDomain, CyberneticAbility, Requirement, RiskTier,
+    };
+
+    fn cap(id: &str) -> CapabilityId { CapabilityId(id.to_string()) }
+
+    let constitution = GovernanceConstitution {
+        global_min_capability_floor: 3,
+        max_restriction_fraction_per_turn: 0.40,
+        max_cumulative_disabled_fraction: None,
+        min_supermajority_floor: 0.67,
+        hard_protect_safety_capabilities: true,
+        globally_nonrestrictable: vec![cap("safety:emergency_stop")].into_iter().collect(),
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.90,
+        max_outcome_age_heights: None,
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: HashMap::new(),
+        threshold_mode: ThresholdMode::default(),
+        referees: HashSet::new(),
+        max_match_override_heights: 0,
+        amendment_rules: AmendmentRules::default(),
+        require_impact_statement: false,
+        impact_estimate_factor: 2.0,
+        appeal_panel: HashSet::new(),
+        appeal_window_heights: 0,
+    };
+    let mut gov = CapabilityGovernance::new(constitution);
+    const GENESIS_MS: u64 = 1_767_225_600_000;
+    gov.set_height_time_mapper(Arc::new(LinearHeightTime::new(GENESIS_MS, 6_000)));
+    let domain_id = "arena:phoenix:bci_xr_championship";
+    gov.upsert_domain(CompetitiveDomain {
+        id: domain_id.into(),
+        description: "Phoenix BCI/XR competitive cybernetic arena".into(),
+        allowed_capabilities: vec![
+            cap("safety:emergency_stop"),
+            cap("meta:pause_augmentation"),
+            cap("move:bci_push"),
+            cap("move:bci_pull"),
+            cap("move:bci_shield"),
+        ]
+        .into_iter()
+        .collect(),
+        min_capability_count: 3,
+        capability_categories: HashMap::new(),
+        category_floors: HashMap::new(),
+    })
+    .expect("valid domain");
+
+    // Element side: default library plus the arena moves, enabled by two athletes.
+    let mut element = default_element();
+    let athletes = vec![
+        AgentId::new("did:arena:athlete:ana").expect("valid DID"),
+        AgentId::new("did:arena:athlete:kofi").expect("valid DID"),
+    ];
+    for id in ["move:bci_push", "move:bci_pull", "move:bci_shield"] {
+        element.upsert_ability(CyberneticAbility {
+            id: the_element::CapabilityId(id.into()),
+            name: id.into(),
+            domain: CapabilityDomain::Motor,
+            class_: CapabilityClass::Enhancement,
+            risk_tier: RiskTier::Low,
+            description: "Arena move".into(),
+            requires: Requirement::default(),
+            ai_delegable: false,
+            require_explicit_opt_in: true,
+        });
+        for athlete in &athletes {
+            element
+                .request_enable(athlete, &the_element::CapabilityId(id.into()), true)
+                .expect("move is enableable");
+        }
+    }
+
+    // Season restriction: pull (until height 2_000) and the element's pause right.
+    let season = GovernanceProposal {
+        proposal_id: "prop-2026-season-pull".into(),
+        domain_id: domain_id.into(),
+        target: None,
+        restrict_capabilities: vec![cap("move:bci_pull"), cap("meta:pause_augmentation")]
+            .into_iter()
+            .collect(),
+        protect_capabilities: HashSet::new(),
+        required_supermajority: 0.75,
+        activation_height: 1_000,
+        sunset_height: Some(2_000),
+        capability_sunsets: HashMap::new(),
+        impact_statement: None,
+    };
+    let outcome = GovernanceVoteOutcome {
+        proposal_id: "prop-2026-season-pull".into(),
+        yes_weight: 800,
+        no_weight: 200,
+        finalized_height: 1_005,
+        participation: None,
+    };
+    gov.commit_proposal(&season, &outcome, 1_010).expect("constitutional");
+
+    let pull = the_element::CapabilityId("move:bci_pull".into());
+    let report = apply_domain_to_agents(&gov, domain_id, &mut element, &athletes).expect("domain exists");
+    assert!(report.turns.iter().all(|t| t.timestamp_ms == Some(GENESIS_MS + 1_010 * 6_000)));
+    println!(
+        "Season start: {} turns, baseline skipped: {:?}, ana can pull: {}",
+        report.turns.len(),
+        report.skipped_baseline,
+        element.can_use(&athletes[0], &pull)
+    );
+
+    gov.advance_height(2_000);
+    let report = apply_domain_to_agents(&gov, domain_id, &mut element, &athletes).expect("domain exists");
+    assert!(report.turns.iter().all(|t| t.timestamp_ms == Some(GENESIS_MS + 2_000 * 6_000)));
+    println!(
+        "Season over: {} unlock turns, ana can pull: {}, kofi can pull: {}",
+        report.turns.len(),
+        element.can_use(&athletes[0], &pull),
+        element.can_use(&athletes[1], &pull)
+    );
+}
+
+#[cfg(not(feature = "the_element"))]
+fn main() {
+    println!("Enable the `the_element` feature to run this example.");
+}
@@ -5,7 +5,7 @@
 //! - Governance turns cannot fully disable experimentation or safe-exit moves.
 
 use cybernetic_governance::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 fn cap(id: &str) -> CapabilityId { CapabilityId(id.to_string()) }
 
@@ -20,9 +20,24 @@ fn main() {
     let constitution = GovernanceConstitution {
         global_min_capability_floor: 4,
         max_restriction_fraction_per_turn: 0.40,
+        max_cumulative_disabled_fraction: None,
         min_supermajority_floor: 0.67,
         hard_protect_safety_capabilities: true,
         globally_nonrestrictable: nonrestrictable,
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.90,
+        max_outcome_age_heights: Some(5_000),
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: [("research:".to_string(), 0.90)].into_iter().collect(),
+        threshold_mode: ThresholdMode::default(),
+        referees: ["referee:phoenix-01".to_string()].into_iter().collect(),
+        max_match_override_heights: 300,
+        amendment_rules: AmendmentRules::default(),
+        require_impact_statement: false,
+        impact_estimate_factor: 2.0,
+        appeal_panel: HashSet::new(),
+        appeal_window_heights: 0,
     };
 
     let mut gov = CapabilityGovernance::new(constitution);
@@ -42,14 +57,17 @@ fn main() {
         description: "Phoenix BCI/XR competitive cybernetic arena".into(),
         allowed_capabilities: allowed,
         min_capability_count: 5,
+        capability_categories: HashMap::new(),
+        category_floors: HashMap::new(),
     };
 
-    gov.upsert_domain(domain);
+    gov.upsert_domain(domain).expect("valid domain");
 
     // Governance proposal tries to heavily restrict gameplay.
     let proposal = GovernanceProposal {
         proposal_id: "prop-2026-01-lockdown".into(),
         domain_id: "arena:phoenix:bci_xr_championship".into(),
+        target: None,
         restrict_capabilities: vec![
             cap("move:bci_push"),
             cap("move:bci_pull"),
@@ -59,6 +77,9 @@ fn main() {
         protect_capabilities: HashSet::new(),
         required_supermajority: 0.75,
         activation_height: 1_000,
+        sunset_height: None,
+        capability_sunsets: HashMap::new(),
+        impact_statement: None,
     };
 
     let outcome = GovernanceVoteOutcome {
@@ -66,6 +87,7 @@ fn main() {
         yes_weight: 800,
         no_weight: 200,
         finalized_height: 1_005,
+        participation: None,
     };
 
     match gov.evaluate_proposal(&proposal, &outcome, 1_010) {
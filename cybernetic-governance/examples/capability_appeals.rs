@@ -0,0 +1,165 @@
+// path: cybernetic-governance/examples/capability_appeals.rs
+
+//! Example: athletes appealing a restriction that landed on their arena.
+//! - Only capabilities the domain actually disabled can be appealed, within the window after the
+//!   restricting change
+//! - Filing twice against the same change merges into one appeal
+//! - Only the constitution's review panel decides; upholding with `reinstate` re-enables the
+//!   capability and a denial leaves it disabled
+//! - The whole lifecycle is in the hash-chained event log and survives a snapshot
+
+use cybernetic_governance::*;
+use std::collections::{HashMap, HashSet};
+
+const DOMAIN: &str = "arena:phoenix:bci_xr_championship";
+
+fn cap(id: &str) -> CapabilityId {
+    CapabilityId(id.to_string())
+}
+
+fn constitution(appeal_window_heights: u64) -> GovernanceConstitution {
+    GovernanceConstitution {
+        global_min_capability_floor: 3,
+        max_restriction_fraction_per_turn: 0.40,
+        max_cumulative_disabled_fraction: None,
+        min_supermajority_floor: 0.67,
+        hard_protect_safety_capabilities: true,
+        globally_nonrestrictable: [cap("safety:emergency_stop")].into(),
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.90,
+        max_outcome_age_heights: None,
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: HashMap::new(),
+        threshold_mode: ThresholdMode::default(),
+        referees: ["ref:lee".to_string()].into(),
+        max_match_override_heights: 0,
+        amendment_rules: AmendmentRules::default(),
+        require_impact_statement: false,
+        impact_estimate_factor: 2.0,
+        appeal_panel: ["panel:okafor".to_string(), "panel:ruiz".to_string()].into(),
+        appeal_window_heights,
+    }
+}
+
+/// An arena where `prop-1` disabled `move:bci_pull` and `move:dash` at height 110.
+fn engine(appeal_window_heights: u64) -> CapabilityGovernance {
+    let mut gov = CapabilityGovernance::new(constitution(appeal_window_heights));
+    gov.upsert_domain(CompetitiveDomain {
+        id: DOMAIN.into(),
+        description: "Phoenix BCI/XR competitive cybernetic arena".into(),
+        allowed_capabilities: [
+            "safety:emergency_stop",
+            "move:bci_push",
+            "move:bci_pull",
+            "move:bci_shield",
+            "move:dash",
+        ]
+        .into_iter()
+        .map(cap)
+        .collect(),
+        min_capability_count: 3,
+        capability_categories: HashMap::new(),
+        category_floors: HashMap::new(),
+    })
+    .expect("valid domain");
+    let proposal = GovernanceProposal {
+        proposal_id: "prop-1".into(),
+        domain_id: DOMAIN.into(),
+        target: None,
+        restrict_capabilities: [cap("move:bci_pull"), cap("move:dash")].into(),
+        protect_capabilities: HashSet::new(),
+        required_supermajority: 0.75,
+        activation_height: 100,
+        sunset_height: None,
+        capability_sunsets: HashMap::new(),
+        impact_statement: None,
+    };
+    let outcome = GovernanceVoteOutcome {
+        proposal_id: "prop-1".into(),
+        yes_weight: 80,
+        no_weight: 20,
+        finalized_height: 105,
+        participation: None,
+    };
+    assert!(matches!(gov.commit_proposal(&proposal, &outcome, 110), Ok(CommitOutcome::Applied(_))));
+    gov
+}
+
+fn disabled(gov: &CapabilityGovernance, id: &str) -> bool {
+    gov.get_domain_state(DOMAIN).expect("domain").disabled_capabilities.contains(&cap(id))
+}
+
+fn main() {
+    let (ana, kofi, lea) = ("did:arena:athlete:ana", "did:arena:athlete:kofi", "did:arena:athlete:lea");
+    let (pull, dash) = (cap("move:bci_pull"), cap("move:dash"));
+
+    // 0. A constitution without a window takes no appeals.
+    let mut closed = engine(0);
+    let refused = closed.file_appeal(DOMAIN, &pull, ana, AppealGrounds::Procedural, vec![], 120);
+    assert_eq!(refused.unwrap_err(), "Constitution does not allow appeals");
+
+    let mut gov = engine(50);
+
+    // 1. Ana appeals the pull restriction, then files again: one appeal, both grounds.
+    let note = vec!["ipfs://ana-medical-note".to_string()];
+    let first = gov.file_appeal(DOMAIN, &pull, ana, AppealGrounds::AccessibilityNeed, note, 120).expect("in window");
+    let appeal_id = first.appeal_id.clone();
+    assert_eq!((first.proposal_id.as_str(), first.change_height), ("prop-1", 110));
+    let more = vec!["ipfs://ana-medical-note".to_string(), "ipfs://ana-match-stats".to_string()];
+    let merged = gov.file_appeal(DOMAIN, &pull, ana, AppealGrounds::DisproportionateImpact, more, 125).expect("merges");
+    assert_eq!(merged.appeal_id, appeal_id);
+    assert_eq!(merged.filings, 2);
+    assert_eq!(merged.grounds, [AppealGrounds::AccessibilityNeed, AppealGrounds::DisproportionateImpact]);
+    assert_eq!(merged.evidence_refs.len(), 2, "repeated evidence is kept once");
+
+    // 2. Only disabled capabilities can be appealed, and only inside the window.
+    let push = gov.file_appeal(DOMAIN, &cap("move:bci_push"), kofi, AppealGrounds::Procedural, vec![], 126);
+    assert_eq!(push.unwrap_err(), format!("Capability move:bci_push is not disabled in domain {DOMAIN}"));
+    let filed = gov.file_appeal(DOMAIN, &dash, kofi, AppealGrounds::Procedural, vec![], 126).expect("in window");
+    let kofi_id = filed.appeal_id.clone();
+    let late = gov.file_appeal(DOMAIN, &dash, lea, AppealGrounds::Procedural, vec![], 200).expect_err("window closed");
+    assert_eq!(late, "Appeal window for prop-1 closed at height 160");
+
+    // 3. A referee is not on the review panel; the appeal stays pending.
+    let upheld = AppealVerdict::Upheld { reinstate: true };
+    let outsider = gov.review_appeal(&appeal_id, "ref:lee", upheld.clone(), 130).expect_err("not on the panel");
+    assert_eq!(outsider, "ref:lee is not on the appeal review panel");
+    assert!(gov.appeals().get(&appeal_id).expect("filed").is_pending());
+
+    // 4. The panel upholds ana's appeal: the pull is playable again.
+    let decided = gov.review_appeal(&appeal_id, "panel:okafor", upheld, 131).expect("panel member");
+    assert!(decided.decision.as_ref().expect("decided").reinstated);
+    assert!(!disabled(&gov, "move:bci_pull"));
+    let change = gov.change_history(DOMAIN).last().expect("recorded");
+    assert_eq!(change.kind, ChangeKind::AppealReinstated);
+    assert_eq!(change.re_enabled, [pull.clone()].into());
+
+    // 5. Kofi's appeal is denied: the dash stays disabled, and the decision is final.
+    let denied = AppealVerdict::Denied { rationale: "Dash restriction was within the turn's limits".into() };
+    gov.review_appeal(&kofi_id, "panel:ruiz", denied, 140).expect("panel member");
+    assert!(disabled(&gov, "move:dash"));
+    let again = gov.file_appeal(DOMAIN, &dash, kofi, AppealGrounds::Procedural, vec![], 141).expect_err("decided");
+    assert_eq!(again, format!("Appeal {kofi_id} against this change was already decided"));
+    assert_eq!(gov.appeals().pending().count(), 0);
+
+    // 6. Every step is in the event log, which still verifies, and the appeals survive a snapshot.
+    let lifecycle: Vec<&str> = gov
+        .event_log()
+        .entries()
+        .iter()
+        .filter_map(|e| match &e.event {
+            GovernanceEvent::AppealFiled { .. } => Some("filed"),
+            GovernanceEvent::AppealMerged { .. } => Some("merged"),
+            GovernanceEvent::AppealReviewed { reinstatement: Some(_), .. } => Some("reinstated"),
+            GovernanceEvent::AppealReviewed { reinstatement: None, .. } => Some("reviewed"),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(lifecycle, ["filed", "merged", "filed", "reinstated", "reviewed"]);
+    gov.verify_log().expect("hash chain intact");
+    let restored = CapabilityGovernance::restore(gov.snapshot()).expect("restores");
+    assert_eq!(restored.appeals().appeals(), gov.appeals().appeals());
+
+    println!("{}", serde_json::to_string_pretty(gov.appeals()).expect("appeals json"));
+}
@@ -0,0 +1,146 @@
+// path: cybernetic-governance/examples/capability_categories.rs
+
+//! Example: category floors keeping an arena playable.
+//! - The arena has four safety functions and three moves; `min_capability_count` is met by the
+//!   safety functions alone, so only the `move` floor stops a vote from disabling every move
+//! - The rejection names the category, and the preview shows it before anyone votes
+//! - Effective capabilities report enabled counts per category
+//! - Domains without categories fall in the catch-all category; a domain categorizing only some
+//!   capabilities, or with a floor it can never meet, is refused
+
+use cybernetic_governance::*;
+use std::collections::{HashMap, HashSet};
+
+const DOMAIN: &str = "arena:phoenix:bci_xr_championship";
+
+fn cap(id: &str) -> CapabilityId {
+    CapabilityId(id.to_string())
+}
+
+fn category(id: &str) -> CategoryId {
+    CategoryId(id.to_string())
+}
+
+fn constitution() -> GovernanceConstitution {
+    GovernanceConstitution {
+        global_min_capability_floor: 2,
+        max_restriction_fraction_per_turn: 0.50,
+        max_cumulative_disabled_fraction: None,
+        min_supermajority_floor: 0.67,
+        hard_protect_safety_capabilities: true,
+        globally_nonrestrictable: [cap("safety:emergency_stop")].into(),
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.90,
+        max_outcome_age_heights: None,
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: HashMap::new(),
+        threshold_mode: ThresholdMode::default(),
+        referees: HashSet::new(),
+        max_match_override_heights: 0,
+        amendment_rules: AmendmentRules::default(),
+        require_impact_statement: false,
+        impact_estimate_factor: 2.0,
+        appeal_panel: HashSet::new(),
+        appeal_window_heights: 0,
+    }
+}
+
+const SAFETY: [&str; 4] = ["safety:emergency_stop", "safety:safe_exit", "safety:pause", "safety:haptic_limit"];
+const MOVES: [&str; 3] = ["move:bci_push", "move:bci_pull", "move:dash"];
+
+fn arena() -> CompetitiveDomain {
+    let categorized = SAFETY.iter().map(|c| (cap(c), category("safety")));
+    CompetitiveDomain {
+        id: DOMAIN.into(),
+        description: "Phoenix BCI/XR competitive cybernetic arena".into(),
+        allowed_capabilities: SAFETY.iter().chain(&MOVES).map(|c| cap(c)).collect(),
+        min_capability_count: 4,
+        capability_categories: categorized.chain(MOVES.iter().map(|c| (cap(c), category("move")))).collect(),
+        category_floors: [(category("move"), 1), (category("safety"), 4)].into(),
+    }
+}
+
+fn restrict(id: &str, capabilities: &[&str]) -> GovernanceProposal {
+    GovernanceProposal {
+        proposal_id: id.into(),
+        domain_id: DOMAIN.into(),
+        target: None,
+        restrict_capabilities: capabilities.iter().map(|c| cap(c)).collect(),
+        protect_capabilities: HashSet::new(),
+        required_supermajority: 0.75,
+        activation_height: 100,
+        sunset_height: None,
+        capability_sunsets: HashMap::new(),
+        impact_statement: None,
+    }
+}
+
+fn passed(id: &str) -> GovernanceVoteOutcome {
+    GovernanceVoteOutcome {
+        proposal_id: id.into(),
+        yes_weight: 80,
+        no_weight: 20,
+        finalized_height: 105,
+        participation: None,
+    }
+}
+
+fn main() {
+    let mut gov = CapabilityGovernance::new(constitution());
+    gov.upsert_domain(arena()).expect("valid domain");
+
+    // 1. Gutting the moves keeps four capabilities (the domain floor) but empties `move`.
+    let gut = restrict("gut-moves", &MOVES);
+    let preview = gov.preview(&gut).expect("previewable");
+    let domain = &preview.domains[0];
+    assert_eq!((domain.enabled_after, domain.domain_floor), (4, 4), "the overall floor alone would pass");
+    let moves = domain.categories_after.iter().find(|c| c.category == category("move")).expect("move");
+    assert_eq!((moves.enabled, moves.floor), (0, 1));
+    let rejection = domain.coded_rejection.as_ref().expect("rejected");
+    assert_eq!(rejection.code, ReasonCode::CapabilityCategoryFloor);
+    assert_eq!(rejection.params["category"], "move");
+    let err = gov.commit_proposal(&gut, &passed("gut-moves"), 110).expect_err("move floor");
+    let expected = "Proposal would leave 0 enabled in category move, below its floor of 1; rejected";
+    assert_eq!(err, format!("Domain {DOMAIN}: {expected}"));
+    assert!(gov.get_domain_state(DOMAIN).expect("domain").disabled_capabilities.is_empty());
+
+    // 2. Disabling two of the three moves leaves one and goes through.
+    let trim = restrict("trim-moves", &["move:bci_pull", "move:dash"]);
+    assert!(matches!(gov.commit_proposal(&trim, &passed("trim-moves"), 110), Ok(CommitOutcome::Applied(_))));
+    let effective = gov.effective_capabilities(DOMAIN, 120, None).expect("known domain");
+    let counts: Vec<(&str, usize, usize, usize)> =
+        effective.categories.iter().map(|c| (c.category.0.as_str(), c.allowed, c.enabled, c.floor)).collect();
+    assert_eq!(counts, [("move", 3, 1, 1), ("safety", 4, 4, 4)]);
+
+    // 3. Legacy domains count as one catch-all category, which floors may name.
+    let mut legacy = arena();
+    legacy.id = "arena:legacy".into();
+    legacy.capability_categories.clear();
+    legacy.category_floors.clear();
+    let mut catch_all = CapabilityGovernance::new(constitution());
+    catch_all.set_catch_all_category(category("general"));
+    catch_all.upsert_domain(legacy.clone()).expect("legacy domain");
+    let effective = catch_all.effective_capabilities("arena:legacy", 120, None).expect("known domain");
+    assert_eq!(effective.categories.len(), 1);
+    assert_eq!((effective.categories[0].category.0.as_str(), effective.categories[0].enabled), ("general", 7));
+    legacy.category_floors.insert(category("general"), 8);
+    let err = catch_all.upsert_domain(legacy).expect_err("unsatisfiable");
+    assert_eq!(err, "Domain arena:legacy sets a floor of 8 for category general, which has only 7 capabilities");
+
+    // 4. Partly categorized domains are refused, naming what is missing.
+    let mut partial = arena();
+    partial.capability_categories.remove(&cap("move:dash"));
+    assert_eq!(gov.upsert_domain(partial).unwrap_err(), format!("Domain {DOMAIN} leaves uncategorized: move:dash"));
+
+    // 5. Categories and the catch-all survive a snapshot.
+    let restored = CapabilityGovernance::restore(catch_all.snapshot()).expect("restores");
+    assert_eq!(restored.catch_all_category(), &category("general"));
+    let mut snapshot = gov.snapshot();
+    let state = snapshot.domains.get_mut(DOMAIN).expect("domain");
+    state.disabled_capabilities.insert(cap("move:bci_push"));
+    let err = CapabilityGovernance::restore(snapshot).err().expect("move floor violated");
+    assert_eq!(err.to_string(), format!("Domain {DOMAIN} has 0 enabled in category move, below floor 1"));
+
+    println!("{}", serde_json::to_string_pretty(&preview).expect("preview json"));
+}
@@ -0,0 +1,42 @@
+// path: cybernetic-governance/examples/capability_protections.rs
+
+//! Example: protections on the arena fixture (run with `--features test-util`).
+//! - A proposal restricting `bci_pull` and `bci_shield` while protecting `bci_shield`: the
+//!   pull is disabled, the shield stays enabled, and the change records the conflict
+//! - A later 80% vote to restrict the shield skips it: lifting a protection needs the
+//!   constitution's 90%
+
+use cybernetic_governance::builders::{bci_xr_arena, cap, caps, vote, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{AppliedChange, CapabilityGovernance, CommitOutcome};
+
+fn commit(gov: &mut CapabilityGovernance, builder: ProposalBuilder, height: u64) -> AppliedChange {
+    let proposal = builder.activation_height(1_000).build_valid_under(gov);
+    let outcome = vote(&proposal.proposal_id, 800, 200, height);
+    match gov.commit_proposal(&proposal, &outcome, height) {
+        Ok(CommitOutcome::Applied(mut changes)) => changes.remove(0),
+        other => panic!("{} should apply: {other:?}", proposal.proposal_id),
+    }
+}
+
+fn main() {
+    let mut gov = bci_xr_arena();
+
+    // 1. Restricted and protected in one proposal: the protection wins and is recorded.
+    let both = ProposalBuilder::new("prop-2026-02-protect-shield", BCI_XR_ARENA)
+        .restrict(&["move:bci_pull", "move:bci_shield"])
+        .protect(&["move:bci_shield"]);
+    let change = commit(&mut gov, both, 1_030);
+    assert_eq!(change.newly_disabled, caps(&["move:bci_pull"]));
+    assert_eq!(change.protection_conflicts, caps(&["move:bci_shield"]));
+    let state = gov.get_domain_state(BCI_XR_ARENA).expect("arena");
+    assert_eq!(state.disabled_capabilities, caps(&["move:bci_pull"]));
+    println!("{} kept {:?}", change.proposal_id, change.protection_conflicts);
+
+    // 2. 80% does not lift the protection; the change says why the shield stayed.
+    let lift = ProposalBuilder::new("prop-2026-03-restrict-shield", BCI_XR_ARENA).restrict(&["move:bci_shield"]);
+    let change = commit(&mut gov, lift, 1_050);
+    assert!(change.newly_disabled.is_empty());
+    assert_eq!(change.skipped_due_to_protection, caps(&["move:bci_shield"]));
+    let state = gov.get_domain_state(BCI_XR_ARENA).expect("arena");
+    assert!(!state.disabled_capabilities.contains(&cap("move:bci_shield")));
+}
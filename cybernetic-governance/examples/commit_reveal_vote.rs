@@ -0,0 +1,118 @@
+// path: cybernetic-governance/examples/commit_reveal_vote.rs
+
+//! Example: a sealed arena vote with commit-reveal (run with `--features test-util`).
+//! - Athletes commit during the first window and reveal in the second, both read off the
+//!   height source; a second commit, a commit after the window, a reveal before or after its
+//!   window and a reveal under the wrong salt are each refused with their own error
+//! - Only revealed ballots are counted; the vote cannot close while reveals are open
+//! - Under `UnrevealedPolicy::Abstain` an unrevealed commitment adds to turnout but not to
+//!   the yes/no ratio; under `Ignore` it counts for nothing. Both record participation
+//! - With a 60% quorum, one yes ballot and one unrevealed commitment pass under `Abstain`
+//!   and fall short under `Ignore`; a quorum outside 0..=1 is refused
+//! - A revealed ballot's receipt verifies against the published leaves; direct `cast` is
+//!   refused on a commit-reveal vote
+
+use std::sync::Arc;
+
+use cybernetic_governance::builders::{bci_xr_arena, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{
+    commitment_hash, verify_receipt_inclusion, CapabilityGovernance, CommitOutcome, CommitRevealError,
+    CommitRevealRules, HeightSource, ManualHeightSource, UnrevealedPolicy, VoteParticipation, VoterId, VoterRegistry,
+};
+
+fn engine(height: &ManualHeightSource) -> (CapabilityGovernance, VoterRegistry) {
+    let mut gov = bci_xr_arena();
+    gov.set_height_source(Arc::new(height.clone()));
+    let mut voters = VoterRegistry::new();
+    for (voter, weight) in [("did:psv:athlete:ana", 50), ("did:psv:athlete:ben", 30), ("did:psv:athlete:kofi", 20)] {
+        voters.register(VoterId(voter.into()), weight);
+        voters.set_eligibility(&VoterId(voter.into()), BCI_XR_ARENA, true).expect("registered");
+    }
+    (gov, voters)
+}
+
+fn main() {
+    let [ana, ben, kofi] = ["ana", "ben", "kofi"].map(|n| VoterId(format!("did:psv:athlete:{n}")));
+    let height = ManualHeightSource::new(100);
+    let (mut gov, voters) = engine(&height);
+    let rules = CommitRevealRules {
+        commit_heights: 10,
+        reveal_heights: 5,
+        unrevealed: UnrevealedPolicy::Abstain,
+        min_turnout: Some(0.6),
+    };
+    let trim = ProposalBuilder::new("trim-pull", BCI_XR_ARENA).restrict(&["move:bci_pull"]).build();
+
+    // 1. Commits, heights 100..110; one each, and no direct ballots.
+    let window = gov.open_commit_reveal_vote(&trim, voters.snapshot(BCI_XR_ARENA), rules).expect("opened");
+    assert_eq!((window.commit_until, window.reveal_until, window.quorum_weight), (110, 115, 60));
+    let commit = |voter: &VoterId, support: bool| {
+        commitment_hash("trim-pull", voter, support, &format!("{}-salt", voter.0))
+    };
+    gov.commit_vote("trim-pull", &ana, &commit(&ana, true)).expect("in window");
+    gov.commit_vote("trim-pull", &ben, &commit(&ben, false)).expect("in window");
+    gov.commit_vote("trim-pull", &kofi, &commit(&kofi, true)).expect("in window");
+    let again = gov.commit_vote("trim-pull", &ana, &commit(&ana, false));
+    assert_eq!(again, Err(CommitRevealError::DoubleCommit(ana.clone())));
+    assert!(gov.cast("trim-pull", &ana, true).is_err(), "no plain ballots on a sealed vote");
+    let early = gov.reveal_vote("trim-pull", &ana, true, "did:psv:athlete:ana-salt");
+    assert_eq!(early.err(), Some(CommitRevealError::RevealTooEarly { commit_until: 110, height: 100 }));
+
+    // 2. Reveals, heights 110..115: ben's wrong salt is refused, kofi never reveals.
+    height.set(110);
+    let late = gov.commit_vote("trim-pull", &kofi, &commit(&kofi, false));
+    assert_eq!(late, Err(CommitRevealError::CommitWindowClosed { commit_until: 110, height: 110 }));
+    let receipt = gov.reveal_vote("trim-pull", &ana, true, "did:psv:athlete:ana-salt").expect("matches");
+    let wrong = gov.reveal_vote("trim-pull", &ben, false, "ben-salt");
+    assert_eq!(wrong.err(), Some(CommitRevealError::CommitmentMismatch(ben.clone())));
+    gov.reveal_vote("trim-pull", &ben, false, "did:psv:athlete:ben-salt").expect("matches");
+    assert!(gov.close_vote("trim-pull", 112).is_err(), "reveals still open");
+
+    // 3. Closed at 115: kofi's 20 abstain, counting toward turnout only.
+    height.set(115);
+    let too_late = gov.reveal_vote("trim-pull", &kofi, true, "did:psv:athlete:kofi-salt");
+    assert_eq!(too_late.err(), Some(CommitRevealError::RevealTooLate { reveal_until: 115, height: 115 }));
+    let outcome = gov.close_vote("trim-pull", 115).expect("reveals over");
+    assert_eq!((outcome.yes_weight, outcome.no_weight), (50, 30));
+    let participation = VoteParticipation { committed: 3, revealed: 2, abstain_weight: 20, quorum_weight: 60 };
+    assert_eq!(outcome.participation, Some(participation));
+    assert_eq!((outcome.turnout_weight(), outcome.meets_quorum()), (Some(100), Some(true)));
+    let counted = gov.counted_ballots("trim-pull").expect("closed");
+    assert!(verify_receipt_inclusion(&receipt, counted));
+    println!("{}", serde_json::to_string(&outcome).expect("json"));
+
+    // 4. One yes revealed, one commitment left unrevealed, against a 60% quorum: 70 turn out
+    //    when it abstains, 50 when it is ignored.
+    let sealed = |unrevealed: UnrevealedPolicy| {
+        let (mut gov, voters) = engine(&height);
+        let rules = CommitRevealRules { unrevealed, ..rules };
+        gov.open_commit_reveal_vote(&trim, voters.snapshot(BCI_XR_ARENA), rules).expect("opened");
+        gov.commit_vote("trim-pull", &ana, &commit(&ana, true)).expect("in window");
+        gov.commit_vote("trim-pull", &kofi, &commit(&kofi, true)).expect("in window");
+        height.advance(10);
+        gov.reveal_vote("trim-pull", &ana, true, "did:psv:athlete:ana-salt").expect("matches");
+        height.advance(5);
+        let finalized = height.current_height();
+        let outcome = gov.close_vote("trim-pull", finalized).expect("reveals over");
+        let committed = gov.commit_tallied_proposal(&trim, finalized).expect("tallied");
+        (gov, outcome, committed)
+    };
+    let (_, outcome, committed) = sealed(UnrevealedPolicy::Abstain);
+    assert_eq!((outcome.turnout_weight(), outcome.meets_quorum()), (Some(70), Some(true)));
+    assert!(matches!(committed, CommitOutcome::Applied(_)), "{committed:?}");
+    let (gov, outcome, committed) = sealed(UnrevealedPolicy::Ignore);
+    let participation = VoteParticipation { committed: 2, revealed: 1, abstain_weight: 0, quorum_weight: 60 };
+    assert_eq!(outcome.participation, Some(participation));
+    assert_eq!((outcome.turnout_weight(), outcome.meets_quorum()), (Some(50), Some(false)));
+    assert!(matches!(committed, CommitOutcome::NotPassed), "unanimous, but short of the quorum: {committed:?}");
+    let (mut fresh, voters) = engine(&height);
+    let lax = CommitRevealRules { min_turnout: Some(1.5), ..rules };
+    assert!(fresh.open_commit_reveal_vote(&trim, voters.snapshot(BCI_XR_ARENA), lax).is_err());
+
+    // 5. Sessions, windows and commitments travel in snapshots.
+    let json = serde_json::to_string(&gov.snapshot()).expect("snapshot json");
+    let restored = CapabilityGovernance::restore(serde_json::from_str(&json).expect("snapshot")).expect("restores");
+    let session = restored.vote_session("trim-pull").expect("restored session");
+    assert_eq!(session.commit_reveal.map(|w| w.unrevealed), Some(UnrevealedPolicy::Ignore));
+    assert_eq!(session.commitments.len(), 2);
+}
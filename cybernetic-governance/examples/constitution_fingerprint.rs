@@ -0,0 +1,191 @@
+// path: cybernetic-governance/examples/constitution_fingerprint.rs
+
+//! Example: an auditor checking which constitution an arena's operators actually run.
+//! - The exported document carries the constitution, its fingerprint and the amendment history,
+//!   signed by the operator
+//! - Editing one constitutional field after export no longer matches the fingerprint
+//! - Every change record and log entry names the constitution it was made under; an amendment
+//!   changes the fingerprint of later records
+//! - A log entry claiming a constitution the amendment history never had is reported
+//! - The log also streams out with a manifest, so a copy cut short in transit is told apart
+
+use cybernetic_governance::*;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+
+const DOMAIN: &str = "arena:phoenix:bci_xr_championship";
+
+fn cap(id: &str) -> CapabilityId {
+    CapabilityId(id.to_string())
+}
+
+/// Stand-in for a real key: the "signature" is SHA-256 over a shared secret and the message.
+struct DemoKey {
+    id: &'static str,
+    secret: &'static str,
+}
+
+fn demo_signature(secret: &str, message: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(secret.as_bytes());
+    hasher.update(message);
+    format!("{:x}", hasher.finalize())
+}
+
+impl Signer for DemoKey {
+    fn signer_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn sign(&self, message: &[u8]) -> String {
+        demo_signature(self.secret, message)
+    }
+}
+
+impl SignatureVerifier for DemoKey {
+    fn verify(&self, signer_id: &str, message: &[u8], signature: &str) -> bool {
+        signer_id == self.id && demo_signature(self.secret, message) == signature
+    }
+}
+
+fn constitution() -> GovernanceConstitution {
+    GovernanceConstitution {
+        global_min_capability_floor: 2,
+        max_restriction_fraction_per_turn: 0.50,
+        max_cumulative_disabled_fraction: None,
+        min_supermajority_floor: 0.67,
+        hard_protect_safety_capabilities: true,
+        globally_nonrestrictable: [cap("safety:emergency_stop")].into(),
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.90,
+        max_outcome_age_heights: None,
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: HashMap::new(),
+        threshold_mode: ThresholdMode::default(),
+        referees: HashSet::new(),
+        max_match_override_heights: 0,
+        amendment_rules: AmendmentRules { timelock_heights: 10, ..AmendmentRules::default() },
+        require_impact_statement: false,
+        impact_estimate_factor: 2.0,
+        appeal_panel: HashSet::new(),
+        appeal_window_heights: 0,
+    }
+}
+
+fn restrict(gov: &mut CapabilityGovernance, id: &str, capability: &str, height: u64) -> AppliedChange {
+    let proposal = GovernanceProposal {
+        proposal_id: id.into(),
+        domain_id: DOMAIN.into(),
+        target: None,
+        restrict_capabilities: [cap(capability)].into(),
+        protect_capabilities: HashSet::new(),
+        required_supermajority: 0.75,
+        activation_height: height - 10,
+        sunset_height: None,
+        capability_sunsets: HashMap::new(),
+        impact_statement: None,
+    };
+    let outcome = GovernanceVoteOutcome {
+        proposal_id: id.into(),
+        yes_weight: 80,
+        no_weight: 20,
+        finalized_height: height - 5,
+        participation: None,
+    };
+    match gov.commit_proposal(&proposal, &outcome, height) {
+        Ok(CommitOutcome::Applied(mut changes)) => changes.remove(0),
+        other => panic!("{id} should apply: {other:?}"),
+    }
+}
+
+fn main() {
+    let operator = DemoKey { id: "did:arena:operator", secret: "operator-secret" };
+    let mut gov = CapabilityGovernance::new(constitution());
+    gov.upsert_domain(CompetitiveDomain {
+        id: DOMAIN.into(),
+        description: "Phoenix BCI/XR competitive cybernetic arena".into(),
+        allowed_capabilities: ["safety:emergency_stop", "move:bci_push", "move:bci_pull", "move:bci_shield", "move:dash"]
+            .into_iter()
+            .map(cap)
+            .collect(),
+        min_capability_count: 2,
+        capability_categories: HashMap::new(),
+        category_floors: HashMap::new(),
+    })
+    .expect("valid domain");
+    let original = gov.constitution_fingerprint();
+    assert_eq!(original, gov.constitution().canonical_hash());
+
+    // 1. Changes are stamped with the constitution in force.
+    let first = restrict(&mut gov, "prop-1", "move:dash", 110);
+    assert_eq!(first.constitution_fingerprint.as_deref(), Some(original.as_str()));
+
+    // 2. An amendment gives later records a new fingerprint.
+    let amendment = ConstitutionAmendment {
+        amendment_id: "amend-1".into(),
+        changes: vec![ConstitutionChange::AddNonrestrictable(cap("move:bci_shield"))],
+        rationale: "Shields protect against neural feedback".into(),
+    };
+    gov.propose_amendment(amendment).expect("proposed");
+    let vote = GovernanceVoteOutcome {
+        proposal_id: "amend-1".into(),
+        yes_weight: 90,
+        no_weight: 10,
+        finalized_height: 118,
+        participation: None,
+    };
+    gov.approve_amendment("amend-1", &vote, 120).expect("approved");
+    let applied = gov.apply_due_amendments(130);
+    assert_eq!(applied[0].before_hash, original);
+    let amended = gov.constitution_fingerprint();
+    assert_ne!(amended, original);
+    assert_eq!(applied[0].after_hash, amended);
+    let second = restrict(&mut gov, "prop-2", "move:bci_pull", 140);
+    assert_eq!(second.constitution_fingerprint.as_deref(), Some(amended.as_str()));
+    let stamped: Vec<_> = gov.event_log().entries().iter().map(|e| e.constitution_fingerprint.clone()).collect();
+    assert!(stamped.iter().all(Option::is_some), "every entry is stamped");
+
+    // 3. The signed export verifies, and the exported log checks out against it.
+    let document = gov.export_constitution(Some(&operator));
+    document.verify().expect("constitution matches its fingerprint");
+    document.verify_signature(&operator).expect("signed by the operator");
+    assert_eq!(document.versions(), [original.as_str(), amended.as_str()]);
+    let exported = GovernanceEventLog::from_jsonl(&gov.export_log_jsonl()).expect("log parses");
+    exported.verify().expect("hash chain intact");
+    exported.verify_fingerprints(&document).expect("every entry under a known constitution");
+    let mut streamed = Vec::new();
+    let manifest = gov.export_log(&mut streamed, Compression::None).expect("log streamed");
+    assert_eq!(manifest.records, gov.event_log().entries().len() as u64);
+    let read = GovernanceEventLog::read_stream(streamed.as_slice()).expect("stream verifies");
+    assert_eq!(read.to_jsonl(), gov.export_log_jsonl());
+    let cut = GovernanceEventLog::read_stream(&streamed[..streamed.len() - 10]).expect_err("cut short");
+    assert!(matches!(cut, StreamError::Interrupted { .. }), "{cut}");
+
+    // 4. Tampering with one constitutional field after export is caught.
+    let json = serde_json::to_string(&document).expect("document json");
+    let edited = json.replacen("\"min_supermajority_floor\":0.67", "\"min_supermajority_floor\":0.51", 1);
+    assert_ne!(json, edited);
+    let tampered: SignedConstitutionDocument = serde_json::from_str(&edited).expect("document loads");
+    let mismatch = tampered.verify().expect_err("fingerprint mismatch");
+    assert!(mismatch.starts_with("Constitution fingerprint mismatch"), "{mismatch}");
+    assert_eq!(tampered.verify_signature(&operator).unwrap_err(), "Signature by did:arena:operator does not verify");
+
+    // 5. A log entry claiming a constitution the amendment history never had is reported.
+    let bogus = "0".repeat(64);
+    let jsonl = gov.export_log_jsonl();
+    let second_line = jsonl.lines().nth(1).expect("two entries");
+    let rewritten = jsonl.replacen(second_line, &second_line.replacen(&original, &bogus, 1), 1);
+    let rewritten = GovernanceEventLog::from_jsonl(&rewritten).expect("log parses");
+    let err = rewritten.verify_fingerprints(&document).expect_err("unknown constitution");
+    assert_eq!(err.index, 1);
+    assert!(err.reason.contains(&bogus), "{err}");
+    // So is a log checked against an export that leaves out the amendment history.
+    let mut withheld = document.clone();
+    withheld.amendment_history.clear();
+    let err = exported.verify_fingerprints(&withheld).expect_err("entries under the original constitution");
+    assert_eq!(err.index, 0);
+    assert!(err.reason.contains(&original), "{err}");
+
+    println!("{}", serde_json::to_string_pretty(&document).expect("document json"));
+}
@@ -0,0 +1,47 @@
+// path: cybernetic-governance/examples/effective_capabilities.rs
+
+//! Example: what players can do right now on the arena fixture (run with `--features test-util`).
+//! - After `bci_pull` is restricted until height 2,000 and `bci_shield` protected, six moves
+//!   are enabled: the view names the proposal behind the restriction, its sunset and the
+//!   protection, and the headroom above the domain floor
+//! - At the sunset the pull counts as enabled again, before `advance_height` has run
+//! - The view serializes as served to spectators
+
+use cybernetic_governance::builders::{bci_xr_arena, cap, vote, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::CommitOutcome;
+
+fn main() {
+    let mut gov = bci_xr_arena();
+    let trim = ProposalBuilder::new("trim-pull", BCI_XR_ARENA)
+        .restrict(&["move:bci_pull"])
+        .protect(&["move:bci_shield"])
+        .capability_sunset("move:bci_pull", 2_000)
+        .activation_height(1_000)
+        .build_valid_under(&gov);
+    let outcome = vote("trim-pull", 800, 200, 1_030);
+    assert!(matches!(gov.commit_proposal(&trim, &outcome, 1_030), Ok(CommitOutcome::Applied(_))));
+
+    // 1. Six enabled; the restriction and the protection say where they came from.
+    let effective = gov.effective_capabilities(BCI_XR_ARENA, 1_070, None).expect("domain exists");
+    println!(
+        "Effective: {} enabled (floor {}), {}",
+        effective.enabled_count,
+        effective.domain_floor.max(effective.global_floor),
+        serde_json::to_string(&effective.disabled).expect("serializes")
+    );
+    assert_eq!((effective.enabled_count, effective.headroom()), (6, 1));
+    let [pull] = &effective.disabled[..] else { panic!("only the pull is disabled") };
+    assert_eq!(pull.capability, &cap("move:bci_pull"));
+    assert_eq!((pull.disabled_by, pull.sunset_height), (Some("trim-pull"), Some(2_000)));
+    let shield = effective.enabled.iter().find(|e| e.capability == &cap("move:bci_shield")).expect("enabled");
+    assert_eq!(shield.protected_by, Some("trim-pull"));
+
+    // 2. At the sunset the pull is back, whether or not the height has been advanced.
+    let at_sunset = gov.effective_capabilities(BCI_XR_ARENA, 2_000, None).expect("domain exists");
+    assert_eq!(at_sunset.enabled_count, 7);
+    assert!(at_sunset.disabled.is_empty());
+
+    // 3. Spectator APIs serve the view as it is.
+    let json = serde_json::to_value(&effective).expect("serializes");
+    assert_eq!(json["disabled"][0]["capability"], "move:bci_pull");
+}
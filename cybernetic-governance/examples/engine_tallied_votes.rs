@@ -0,0 +1,86 @@
+// path: cybernetic-governance/examples/engine_tallied_votes.rs
+
+//! Example: votes the engine collects and tallies itself (run with `--features test-util`).
+//! - A second `cast` by the same voter and a ballot from outside the snapshot are refused;
+//!   a voter registered after the vote opened is outside it
+//! - Weights near `u128::MAX` sum exactly; a tally that would overflow is refused at close
+//! - The vote is bound to the proposal it was opened for: an edited proposal under the same
+//!   id, or one moved to another domain, does not commit with its tally; the original does,
+//!   and the change records the tally as engine-computed
+
+use cybernetic_governance::builders::{bci_xr_arena, ConstitutionBuilder, DomainBuilder, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{CapabilityGovernance, CommitOutcome, TallySource, VoterId, VoterRegistry};
+
+const HEIGHT: u64 = 1_000;
+const QUARTER: u128 = u128::MAX / 4;
+
+fn voter(name: &str) -> VoterId {
+    VoterId(format!("did:psv:athlete:{name}"))
+}
+
+fn registry(weights: &[(&str, u128)]) -> VoterRegistry {
+    let mut voters = VoterRegistry::new();
+    for (name, weight) in weights {
+        voters.register(voter(name), *weight);
+        voters.set_eligibility(&voter(name), BCI_XR_ARENA, true).expect("registered");
+    }
+    voters
+}
+
+fn main() {
+    let trim = ProposalBuilder::new("trim-pull", BCI_XR_ARENA)
+        .restrict(&["move:bci_pull"])
+        .activation_height(HEIGHT)
+        .build_valid_under(&bci_xr_arena());
+    let mut gov = bci_xr_arena();
+    let mut voters = registry(&[("ana", QUARTER), ("ben", QUARTER), ("kofi", QUARTER / 2)]);
+    gov.open_vote(&trim, voters.snapshot(BCI_XR_ARENA)).expect("first vote on trim-pull");
+    assert!(gov.open_vote(&trim, voters.snapshot(BCI_XR_ARENA)).is_err(), "one vote per proposal");
+
+    // 1. One ballot each; nobody outside the snapshot, even if registered since.
+    gov.cast("trim-pull", &voter("ana"), true).expect("in snapshot");
+    let twice = gov.cast("trim-pull", &voter("ana"), false).expect_err("already voted");
+    assert_eq!(twice, "Voter did:psv:athlete:ana has already voted");
+    voters.register(voter("zed"), 1);
+    voters.set_eligibility(&voter("zed"), BCI_XR_ARENA, true).expect("registered");
+    let outsider = gov.cast("trim-pull", &voter("zed"), true).expect_err("not in snapshot");
+    assert_eq!(outsider, "Voter did:psv:athlete:zed is not in the vote snapshot");
+    gov.cast("trim-pull", &voter("ben"), true).expect("in snapshot");
+    gov.cast("trim-pull", &voter("kofi"), false).expect("in snapshot");
+
+    // 2. Five eighths of u128::MAX, counted without loss: 80% yes.
+    let outcome = gov.close_vote("trim-pull", HEIGHT + 5).expect("open vote");
+    assert_eq!((outcome.yes_weight, outcome.no_weight), (2 * QUARTER, QUARTER / 2));
+    assert!(gov.cast("trim-pull", &voter("ben"), false).is_err(), "closed");
+
+    // 3. The tally commits only the proposal it was opened for.
+    let mut edited = trim.clone();
+    edited.restrict_capabilities.insert("move:bci_shield".parse().expect("valid id"));
+    let err = gov.commit_tallied_proposal(&edited, HEIGHT + 10).expect_err("different proposal");
+    assert_eq!(err, "Proposal differs from the one the vote was opened for");
+    let moved = ProposalBuilder::new("trim-pull", "arena:phoenix:practice").restrict(&["move:bci_pull"]).build();
+    let err = gov.commit_tallied_proposal(&moved, HEIGHT + 10).expect_err("different domain");
+    assert!(err.starts_with("Vote was opened in domain arena:phoenix:bci_xr_championship"), "{err}");
+    assert!(gov.get_domain_state(BCI_XR_ARENA).expect("arena").disabled_capabilities.is_empty());
+    match gov.commit_tallied_proposal(&trim, HEIGHT + 10) {
+        Ok(CommitOutcome::Applied(changes)) => assert_eq!(changes[0].tally_source, TallySource::EngineTallied),
+        other => panic!("the tallied proposal should apply: {other:?}"),
+    }
+
+    // 4. Weights whose sum exceeds u128::MAX: refused at close, never wrapped.
+    let mut practice = CapabilityGovernance::new(ConstitutionBuilder::new().build());
+    let domain = DomainBuilder::new(BCI_XR_ARENA).allow(&["move:a", "move:b", "move:c", "move:d"]).build_valid();
+    practice.upsert_domain(domain).expect("valid domain");
+    let heavy = registry(&[("ana", u128::MAX / 2 + 1), ("ben", u128::MAX / 2 + 1)]);
+    let proposal = ProposalBuilder::new("heavy", BCI_XR_ARENA).restrict(&["move:a"]).build();
+    practice.open_vote(&proposal, heavy.snapshot(BCI_XR_ARENA)).expect("fresh proposal");
+    practice.cast("heavy", &voter("ana"), true).expect("in snapshot");
+    practice.cast("heavy", &voter("ben"), true).expect("in snapshot");
+    let overflow = practice.close_vote("heavy", HEIGHT).expect_err("overflow");
+    println!("refused: {overflow}");
+    assert_eq!(overflow, "Vote tally overflowed u128");
+
+    // 5. A snapshot of another domain cannot open a vote on this one.
+    let other = ProposalBuilder::new("other", BCI_XR_ARENA).restrict(&["move:b"]).build();
+    assert!(practice.open_vote(&other, heavy.snapshot("arena:phoenix:practice")).is_err());
+}
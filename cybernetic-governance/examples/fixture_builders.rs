@@ -0,0 +1,55 @@
+// path: cybernetic-governance/examples/fixture_builders.rs
+
+//! Example: arena fixtures for downstream tests (run with `--features test-util`).
+//! - `bci_xr_arena` is the example engine before its first vote, and its lockdown proposal
+//!   is refused the same way
+//! - `build_valid` refuses domains and proposals the engine would refuse; `build` keeps them
+//!   for tests that expect the refusal, and `build_valid_under` checks against another engine
+
+use cybernetic_governance::builders::{
+    bci_xr_arena, bci_xr_lockdown, cap, vote, ConstitutionBuilder, DomainBuilder, ProposalBuilder, BCI_XR_ARENA,
+};
+use cybernetic_governance::{CapabilityGovernance, CommitOutcome, ReasonCode};
+
+fn main() {
+    // 1. The arena fixture matches the example: seven capabilities, nothing disabled.
+    let mut gov = bci_xr_arena();
+    let enabled = gov.effective_capabilities(BCI_XR_ARENA, 1_000, None).expect("known domain").enabled_count;
+    assert_eq!(enabled, 7);
+    let (lockdown, outcome) = bci_xr_lockdown();
+    let preview = gov.preview(&lockdown).expect("previewable");
+    assert_eq!(preview.domains[0].skipped_due_to_constitution, [cap("research:noninvasive_bci")].into());
+    let refused = gov.commit_proposal(&lockdown, &outcome, 1_010);
+    assert!(refused.is_err(), "three moves leave four, below the floor of 5");
+
+    // 2. Built proposals commit like hand-written ones.
+    let trim = ProposalBuilder::new("trim", BCI_XR_ARENA).restrict(&["move:bci_pull"]).activation_height(1_000);
+    let committed = gov.commit_proposal(&trim.build_valid_under(&gov), &vote("trim", 80, 20, 1_005), 1_010);
+    assert!(matches!(committed, Ok(CommitOutcome::Applied(_))));
+
+    // 3. Categorized domains validate as a whole.
+    let arena = DomainBuilder::new("arena:fixture")
+        .category("safety", &["safety:emergency_stop", "safety:safe_exit"])
+        .category("move", &["move:dash", "move:jump"])
+        .category_floor("move", 1)
+        .min_capability_count(3)
+        .build_valid();
+    let mut categorized = CapabilityGovernance::new(ConstitutionBuilder::new().global_min_capability_floor(2).build());
+    categorized.upsert_domain(arena).expect("valid domain");
+    let too_high = DomainBuilder::new("arena:fixture").category("move", &["move:dash"]).category_floor("move", 2);
+    assert!(std::panic::catch_unwind(|| too_high.build_valid()).is_err());
+
+    // 4. A sunset before activation builds, but not as a valid proposal.
+    let backwards = ProposalBuilder::new("backwards", BCI_XR_ARENA).restrict(&["move:bci_push"]).sunset_height(50);
+    let reason = gov.validate_proposal(&backwards.clone().build()).expect_err("sunset first");
+    assert_eq!(reason.code, ReasonCode::CapabilitySunsetOrder);
+    assert!(std::panic::catch_unwind(|| backwards.build_valid()).is_err());
+
+    // 5. A constitution requiring impact statements refuses a bare restriction.
+    let strict = CapabilityGovernance::new(ConstitutionBuilder::new().require_impact_statement().build());
+    let bare = ProposalBuilder::new("bare", BCI_XR_ARENA).restrict(&["move:bci_push"]);
+    let reason = strict.validate_proposal(&bare.build()).expect_err("statement required");
+    assert_eq!(reason.code, ReasonCode::CapabilityImpactStatementMissing);
+
+    println!("{}", serde_json::to_string_pretty(&preview).expect("preview json"));
+}
@@ -0,0 +1,88 @@
+// path: cybernetic-governance/examples/height_time_mapping.rs
+
+//! Example: governance heights against wall-clock time (run with `--features test-util`).
+//! - Checkpoints from a chain whose blocks slow from 6 s to 6.5 s interpolate to within a
+//!   block of the true times, and every height maps to a time and back to itself
+//! - Past the horizon, and before the first checkpoint, mapping is refused, not guessed
+//! - Checkpoints out of order, in height or time, are rejected and change nothing
+//! - A restriction meant to end at a wall-clock time gets the first height reached by then,
+//!   and appeal windows get a closing time
+
+use std::sync::Arc;
+
+use cybernetic_governance::builders::{bci_xr_arena, cap, vote, ConstitutionBuilder, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{
+    CapabilityGovernance, CheckpointHeightTime, HeightCheckpoint, HeightTimeMapper, LinearHeightTime, MappingError,
+};
+
+const GENESIS_MS: u64 = 1_767_225_600_000;
+
+/// When the simulated chain reaches `height`: 6 s blocks, each 0.25 ms slower than the last.
+fn true_time(height: u64) -> u64 {
+    GENESIS_MS + 6_000 * height + height * height / 8
+}
+
+fn main() {
+    // 1. A checkpoint every 100 blocks; interpolation stays within one block of the truth.
+    let checkpoints = (0..=20).map(|i| HeightCheckpoint { height: i * 100, timestamp_ms: true_time(i * 100) });
+    let mapper = Arc::new(CheckpointHeightTime::from_checkpoints(checkpoints, 500).expect("increasing"));
+    let mut worst_ms = 0;
+    for height in 0..=2_000 {
+        let mapped = mapper.time_at(height).expect("within checkpoints");
+        worst_ms = worst_ms.max(mapped.abs_diff(true_time(height)));
+        assert_eq!(mapper.height_at(mapped), Ok(height), "round trip at {height}");
+    }
+    println!("worst interpolation error: {worst_ms} ms");
+    assert!(worst_ms < 6_000);
+    assert_eq!(mapper.time_at(1_000), Ok(true_time(1_000)));
+    assert_eq!(mapper.height_at(true_time(1_000) - 1), Ok(999));
+
+    // 2. Extrapolation at the average rate, up to 500 heights past the last checkpoint.
+    let horizon_ms = mapper.time_at(2_500).expect("inside the horizon");
+    assert_eq!(mapper.height_at(mapper.time_at(2_300).expect("inside")), Ok(2_300));
+    assert_eq!(mapper.time_at(2_501), Err(MappingError::HeightBeyondHorizon { height: 2_501, max_height: 2_500 }));
+    assert_eq!(
+        mapper.height_at(horizon_ms + 1),
+        Err(MappingError::TimeBeyondHorizon { time_ms: horizon_ms + 1, max_time_ms: horizon_ms })
+    );
+    assert_eq!(
+        mapper.height_at(GENESIS_MS - 1),
+        Err(MappingError::BeforeStart { time_ms: GENESIS_MS - 1, start_ms: GENESIS_MS })
+    );
+    let linear = LinearHeightTime::new(GENESIS_MS, 6_000).with_max_height(10_000);
+    assert_eq!(linear.height_at(linear.time_at(7_777).expect("mapped")), Ok(7_777));
+    assert!(matches!(linear.time_at(10_001), Err(MappingError::HeightBeyondHorizon { .. })));
+
+    // 3. Checkpoints that go back in height or stand still in time are rejected.
+    let last = HeightCheckpoint { height: 2_000, timestamp_ms: true_time(2_000) };
+    let stale = HeightCheckpoint { height: 1_950, timestamp_ms: true_time(2_100) };
+    assert_eq!(mapper.observe(stale), Err(MappingError::NonMonotonicCheckpoint { checkpoint: stale, last }));
+    let frozen = HeightCheckpoint { height: 2_100, timestamp_ms: last.timestamp_ms };
+    assert!(mapper.observe(frozen).is_err());
+    assert_eq!(mapper.checkpoints().len(), 21);
+    mapper.observe(HeightCheckpoint { height: 2_100, timestamp_ms: true_time(2_100) }).expect("next block");
+
+    // 4. A restriction that ends an hour after it starts, on the engine's mapper.
+    let mut gov = bci_xr_arena();
+    gov.set_height_time_mapper(mapper.clone());
+    let start_ms = gov.time_at_height(1_010).expect("mapped");
+    let mut proposal = ProposalBuilder::new("one-hour-pull-ban", BCI_XR_ARENA)
+        .restrict(&["move:bci_pull"])
+        .activation_height(1_000)
+        .build();
+    let sunset = proposal.set_sunset_at_ms(mapper.as_ref(), start_ms + 3_600_000).expect("inside the horizon");
+    assert!(mapper.time_at(sunset).expect("mapped") >= start_ms + 3_600_000);
+    assert!(mapper.time_at(sunset - 1).expect("mapped") < start_ms + 3_600_000);
+    gov.commit_proposal(&proposal, &vote("one-hour-pull-ban", 80, 20, 1_005), 1_010).expect("constitutional");
+    gov.advance_height(sunset);
+    let state = gov.get_domain_state(BCI_XR_ARENA).expect("known domain");
+    assert!(!state.disabled_capabilities.contains(&cap("move:bci_pull")));
+
+    // 5. Appeal windows close at a time; without a mapper, there is none to give.
+    let mut appeals = CapabilityGovernance::new(ConstitutionBuilder::new().appeal_panel(&["panel"], 100).build());
+    assert_eq!(appeals.appeal_window_closes_at_ms(1_010), Err(MappingError::NoMapper));
+    appeals.set_height_time_mapper(mapper.clone());
+    assert_eq!(appeals.appeal_window_closes_at_ms(1_010), Ok(Some(mapper.time_at(1_110).expect("mapped"))));
+    let lifted_after_ms = mapper.time_at(sunset).expect("mapped") - start_ms;
+    println!("restriction lifts at height {sunset}, {lifted_after_ms} ms after it started");
+}
@@ -0,0 +1,178 @@
+// path: cybernetic-governance/examples/impact_statements.rs
+
+//! Example: impact statements on restriction proposals, with the constitutional toggle off and on.
+//! - Off: a restriction without a statement opens for voting and commits as before
+//! - On: the same proposal is refused before its vote opens; an incomplete statement too
+//! - A complete statement shows in the preview and is kept in the domain's history
+//! - With `the_element`, an affected-agent estimate far from what the bridge sees is flagged
+//!
+//! Run with `--features the_element` for the last part.
+
+use cybernetic_governance::*;
+use std::collections::{HashMap, HashSet};
+
+fn cap(id: &str) -> CapabilityId {
+    CapabilityId(id.to_string())
+}
+
+fn constitution(require_impact_statement: bool) -> GovernanceConstitution {
+    GovernanceConstitution {
+        global_min_capability_floor: 3,
+        max_restriction_fraction_per_turn: 0.40,
+        max_cumulative_disabled_fraction: None,
+        min_supermajority_floor: 0.67,
+        hard_protect_safety_capabilities: true,
+        globally_nonrestrictable: [cap("safety:emergency_stop")].into(),
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.90,
+        max_outcome_age_heights: None,
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: HashMap::new(),
+        threshold_mode: ThresholdMode::default(),
+        referees: HashSet::new(),
+        max_match_override_heights: 0,
+        amendment_rules: AmendmentRules::default(),
+        require_impact_statement,
+        impact_estimate_factor: 2.0,
+        appeal_panel: HashSet::new(),
+        appeal_window_heights: 0,
+    }
+}
+
+const DOMAIN: &str = "arena:phoenix:bci_xr_championship";
+
+fn engine(require_impact_statement: bool) -> CapabilityGovernance {
+    let mut gov = CapabilityGovernance::new(constitution(require_impact_statement));
+    gov.upsert_domain(CompetitiveDomain {
+        id: DOMAIN.into(),
+        description: "Phoenix BCI/XR competitive cybernetic arena".into(),
+        allowed_capabilities: ["safety:emergency_stop", "move:bci_push", "move:bci_pull", "move:bci_shield", "move:dash"]
+            .into_iter()
+            .map(cap)
+            .collect(),
+        min_capability_count: 3,
+        capability_categories: HashMap::new(),
+        category_floors: HashMap::new(),
+    })
+    .expect("valid domain");
+    gov
+}
+
+fn proposal(id: &str, impact_statement: Option<ImpactStatement>) -> GovernanceProposal {
+    GovernanceProposal {
+        proposal_id: id.into(),
+        domain_id: DOMAIN.into(),
+        target: None,
+        restrict_capabilities: [cap("move:bci_pull")].into(),
+        protect_capabilities: HashSet::new(),
+        required_supermajority: 0.75,
+        activation_height: 100,
+        sunset_height: Some(2_000),
+        capability_sunsets: HashMap::new(),
+        impact_statement,
+    }
+}
+
+fn statement(estimated_affected_agents: Option<u32>) -> ImpactStatement {
+    ImpactStatement {
+        affected_capability_count: 1,
+        estimated_affected_agents,
+        alternatives_considered: vec!["Cap pull strength instead of disabling it".into()],
+        reversibility_note: "Lapses at height 2000; a protecting proposal can lift it sooner".into(),
+    }
+}
+
+fn outcome(id: &str) -> GovernanceVoteOutcome {
+    GovernanceVoteOutcome {
+        proposal_id: id.into(),
+        yes_weight: 80,
+        no_weight: 20,
+        finalized_height: 105,
+        participation: None,
+    }
+}
+
+fn main() {
+    // 1. Toggle off: no statement needed, from vote to commit.
+    let mut off = engine(false);
+    let bare = proposal("prop-pull-bare", None);
+    off.open_proposal_vote(&bare, VoterSnapshot { domain_id: DOMAIN.into(), weights: HashMap::new() })
+        .expect("no statement required");
+    match off.commit_proposal(&bare, &outcome("prop-pull-bare"), 110).expect("commits") {
+        CommitOutcome::Applied(changes) => assert_eq!(changes[0].impact_statement, None),
+        other => panic!("expected Applied, got {other:?}"),
+    }
+
+    // 2. Toggle on: refused before the vote opens, and no session exists.
+    let mut on = engine(true);
+    let snapshot = || VoterSnapshot { domain_id: DOMAIN.into(), weights: HashMap::new() };
+    let refused = on.open_proposal_vote(&bare, snapshot()).expect_err("statement required");
+    assert_eq!(refused, "Constitution requires an impact statement for proposals restricting capabilities");
+    assert_eq!(on.validate_proposal(&bare).map_err(|r| r.code), Err(ReasonCode::CapabilityImpactStatementMissing));
+    assert!(on.vote_session("prop-pull-bare").is_none());
+
+    // 3. Present but incomplete: a miscount, then no alternatives.
+    let miscounted = proposal("prop-pull-2", Some(ImpactStatement { affected_capability_count: 3, ..statement(None) }));
+    let reason = on.validate_proposal(&miscounted).expect_err("counts 3, restricts 1");
+    assert_eq!(reason.code, ReasonCode::CapabilityImpactStatementCount);
+    let unexplored = proposal("prop-pull-2", Some(ImpactStatement { alternatives_considered: vec![" ".into()], ..statement(None) }));
+    assert_eq!(
+        on.validate_proposal(&unexplored).unwrap_err().to_string(),
+        "Impact statement must fill in alternatives_considered"
+    );
+
+    // 4. Complete: previewed for voters, and kept with the applied change.
+    let stated = proposal("prop-pull-2", Some(statement(Some(40))));
+    on.open_proposal_vote(&stated, snapshot()).expect("complete statement");
+    let preview = on.preview(&stated).expect("previewable");
+    assert_eq!(preview.impact_statement, stated.impact_statement);
+    assert!(preview.warnings.is_empty());
+    on.commit_proposal(&stated, &outcome("prop-pull-2"), 110).expect("commits");
+    let history = on.change_history(DOMAIN);
+    assert_eq!(history.last().and_then(|c| c.impact_statement.as_ref()), stated.impact_statement.as_ref());
+
+    #[cfg(feature = "the_element")]
+    element_estimate(&stated);
+    println!("impact statements: optional when off, required before voting when on");
+}
+
+/// Two athletes can use `move:bci_pull`; an estimate of 40 is off by more than 2x, 3 is not.
+#[cfg(feature = "the_element")]
+fn element_estimate(stated: &GovernanceProposal) {
+    use cybernetic_governance::element_bridge::preview_with_element;
+    use the_element::{
+        default_element, AgentId, CapabilityClass, CapabilityDomain, CyberneticAbility, Requirement, RiskTier,
+    };
+
+    let gov = engine(true);
+    let mut element = default_element();
+    let pull = the_element::CapabilityId("move:bci_pull".into());
+    element.upsert_ability(CyberneticAbility {
+        id: pull.clone(),
+        name: "BCI pull".into(),
+        domain: CapabilityDomain::Motor,
+        class_: CapabilityClass::Enhancement,
+        risk_tier: RiskTier::Low,
+        description: "Arena move".into(),
+        requires: Requirement::default(),
+        ai_delegable: false,
+        require_explicit_opt_in: true,
+    });
+    let athletes: Vec<AgentId> = ["ana", "kofi", "lea"]
+        .iter()
+        .map(|n| AgentId::new(format!("did:arena:athlete:{n}")).expect("valid DID"))
+        .collect();
+    for athlete in &athletes[..2] {
+        element.request_enable(athlete, &pull, true).expect("move is enableable");
+    }
+
+    let preview = preview_with_element(&gov, stated, &element, &athletes).expect("previewable");
+    assert_eq!(preview.warnings.iter().map(|w| w.code).collect::<Vec<_>>(), [ReasonCode::CapabilityImpactEstimateOff]);
+    assert_eq!(
+        preview.warnings[0].to_string(),
+        "Impact statement estimates 40 affected agents; the element bridge finds 2, off by more than 2x"
+    );
+    let close = proposal("prop-pull-3", Some(statement(Some(3))));
+    assert!(preview_with_element(&gov, &close, &element, &athletes).expect("previewable").warnings.is_empty());
+}
@@ -0,0 +1,80 @@
+// path: cybernetic-governance/examples/league_templates.rs
+
+//! Example: arenas stamped from the league template (run with `--features toml,test-util`).
+//! - `templates/bci_xr_league.toml` loads one template; loading it again is refused, since
+//!   template ids are immutable
+//! - One arena takes the template as is, another adds and removes a move and raises its
+//!   minimum; overrides that drop a recommended protection or the template minimum are refused
+//! - Instantiating into an existing arena fails and leaves that arena as it was
+//! - After a restriction, drift reports the added, removed and disabled moves, and the
+//!   recommendations this constitution does not protect
+
+use cybernetic_governance::builders::{caps, vote, ConstitutionBuilder, DomainBuilder, ProposalBuilder};
+use cybernetic_governance::{CapabilityGovernance, CommitOutcome, DomainOverrides};
+
+const LEAGUE: &str = include_str!("../templates/bci_xr_league.toml");
+const TEMPLATE: &str = "bci-xr-championship-v1";
+const EAST: &str = "arena:phoenix:east";
+const WEST: &str = "arena:phoenix:west";
+
+fn main() {
+    let mut gov = CapabilityGovernance::new(ConstitutionBuilder::new().build());
+
+    // 1. The league file registers once.
+    assert_eq!(gov.load_templates_toml(LEAGUE), Ok(1));
+    let again = gov.load_templates_toml(LEAGUE).expect_err("already registered");
+    assert_eq!(again, format!("Template {TEMPLATE} already registered"));
+    let template = gov.get_template(TEMPLATE).expect("registered").clone();
+    assert_eq!((template.capabilities.len(), template.min_capability_count), (7, 5));
+    assert!(gov.load_templates_toml("[[template]]\ntemplate_id = 7").expect_err("bad TOML").starts_with("Invalid"));
+
+    // 2. East as the template has it; west with its own moves and a higher minimum.
+    let east = gov.instantiate_domain(TEMPLATE, EAST, DomainOverrides::default()).expect("fresh arena");
+    assert_eq!(east.allowed_capabilities, template.capabilities);
+    assert_eq!((east.description.as_str(), east.min_capability_count), (template.description.as_str(), 5));
+    let west = DomainOverrides {
+        description: Some("Phoenix west, no shield".into()),
+        add_capabilities: caps(&["move:bci_dash"]),
+        remove_capabilities: caps(&["move:bci_shield"]),
+        min_capability_count: Some(6),
+    };
+    let west = gov.instantiate_domain(TEMPLATE, WEST, west).expect("fresh arena");
+    assert!(west.allowed_capabilities.contains(&"move:bci_dash".parse().expect("valid id")));
+    assert_eq!((west.allowed_capabilities.len(), west.min_capability_count), (7, 6));
+
+    // 3. Overrides may not weaken the template.
+    let unsafe_arena = DomainOverrides { remove_capabilities: caps(&["safety:emergency_stop"]), ..Default::default() };
+    let err = gov.instantiate_domain(TEMPLATE, "arena:phoenix:north", unsafe_arena).expect_err("recommended");
+    assert_eq!(err, "Cannot remove recommended non-restrictable capability: safety:emergency_stop");
+    let lax = DomainOverrides { min_capability_count: Some(4), ..Default::default() };
+    let err = gov.instantiate_domain(TEMPLATE, "arena:phoenix:north", lax).expect_err("below the template");
+    assert_eq!(err, "min_capability_count 4 is below the template minimum 5");
+    let err = gov.instantiate_domain("bci-xr-open-v9", "arena:phoenix:north", DomainOverrides::default());
+    assert_eq!(err.expect_err("unknown template"), "Unknown template: bci-xr-open-v9");
+    assert!(gov.get_domain_state("arena:phoenix:north").is_none());
+
+    // 4. An existing arena is never overwritten, whether stamped or registered by hand.
+    let trimmed = DomainOverrides { remove_capabilities: caps(&["move:bci_push"]), ..Default::default() };
+    let err = gov.instantiate_domain(TEMPLATE, EAST, trimmed.clone()).expect_err("east exists");
+    println!("refused: {err}");
+    assert_eq!(err, format!("Domain {EAST} already exists"));
+    assert_eq!(gov.get_domain_state(EAST).expect("east").domain.allowed_capabilities, template.capabilities);
+    let practice = DomainBuilder::new("arena:phoenix:practice").allow(&["move:a", "move:b", "move:c"]).build_valid();
+    gov.upsert_domain(practice).expect("valid domain");
+    assert!(gov.instantiate_domain(TEMPLATE, "arena:phoenix:practice", trimmed).is_err());
+
+    // 5. Drift after west restricts the pull.
+    let trim = ProposalBuilder::new("west-no-pull", WEST).restrict(&["move:bci_pull"]).build_valid_under(&gov);
+    let committed = gov.commit_proposal(&trim, &vote("west-no-pull", 80, 20, 1_000), 1_000);
+    assert!(matches!(committed, Ok(CommitOutcome::Applied(_))), "{committed:?}");
+    let drift = gov.template_drift(WEST).expect("stamped from a template");
+    assert_eq!(drift.added_capabilities, caps(&["move:bci_dash"]));
+    assert_eq!(drift.removed_capabilities, caps(&["move:bci_shield"]));
+    assert_eq!(drift.disabled_template_capabilities, caps(&["move:bci_pull"]));
+    assert_eq!((drift.template_min_capability_count, drift.domain_min_capability_count), (5, 6));
+    let unprotected = caps(&["safety:session_exit", "access:baseline_play", "research:noninvasive_bci"]);
+    assert_eq!(drift.unprotected_recommendations, unprotected);
+    assert!(gov.template_drift(EAST).expect("stamped").disabled_template_capabilities.is_empty());
+    assert!(gov.template_drift("arena:phoenix:practice").is_err(), "not from a template");
+    println!("{}", serde_json::to_string_pretty(&drift).expect("drift json"));
+}
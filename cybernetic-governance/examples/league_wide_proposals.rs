@@ -0,0 +1,88 @@
+// path: cybernetic-governance/examples/league_wide_proposals.rs
+
+//! Example: one proposal over every arena of a league (run with `--features test-util`).
+//! - Three arenas of six capabilities; the third keeps at least five enabled
+//! - Restricting two moves league-wide: the per-domain preview passes the first two arenas
+//!   and rejects the third on its floor, and the commit fails naming that arena, with no
+//!   arena changed
+//! - The same restriction over the first two arenas only commits atomically, one change per
+//!   arena; a list naming an unregistered arena is refused before anything is evaluated
+
+use cybernetic_governance::builders::{vote, ConstitutionBuilder, DomainBuilder, ProposalBuilder};
+use cybernetic_governance::{
+    CapabilityGovernance, CommitOutcome, DomainChangeResult, GovernanceProposal, ProposalTarget, ReasonCode,
+};
+
+const ARENAS: [&str; 3] = ["arena:league:north", "arena:league:south", "arena:league:tight"];
+const HEIGHT: u64 = 1_000;
+
+fn league() -> CapabilityGovernance {
+    let mut gov = CapabilityGovernance::new(ConstitutionBuilder::new().build());
+    for (arena, floor) in ARENAS.into_iter().zip([3, 3, 5]) {
+        let domain = DomainBuilder::new(arena)
+            .allow(&["safety:emergency_stop", "safety:session_exit", "move:walk", "move:dash", "move:jump"])
+            .allow(&["move:shield"])
+            .min_capability_count(floor)
+            .build_valid();
+        gov.upsert_domain(domain).expect("valid arena");
+    }
+    gov
+}
+
+fn no_dash_or_shield(id: &str, target: ProposalTarget) -> GovernanceProposal {
+    let mut proposal = ProposalBuilder::new(id, ARENAS[0])
+        .restrict(&["move:dash", "move:shield"])
+        .activation_height(HEIGHT)
+        .build();
+    proposal.target = Some(target);
+    proposal
+}
+
+fn disabled_counts(gov: &CapabilityGovernance) -> Vec<usize> {
+    ARENAS.iter().map(|a| gov.get_domain_state(a).expect("arena").disabled_capabilities.len()).collect()
+}
+
+fn main() {
+    let mut gov = league();
+
+    // 1. League-wide: the tight arena would keep four of its floor of five.
+    let everywhere = no_dash_or_shield("league-no-dash", ProposalTarget::AllDomains);
+    let outcome = vote("league-no-dash", 80, 20, HEIGHT);
+    let per_domain = gov.evaluate_proposal_per_domain(&everywhere, &outcome, HEIGHT).expect("all arenas known");
+    assert_eq!(per_domain.keys().map(String::as_str).collect::<Vec<_>>(), ARENAS);
+    for arena in &ARENAS[..2] {
+        assert!(matches!(per_domain[*arena], DomainChangeResult::Passes { .. }), "{arena}");
+    }
+    match &per_domain[ARENAS[2]] {
+        DomainChangeResult::Rejected { coded_reason: Some(reason), .. } => {
+            assert_eq!(reason.code, ReasonCode::CapabilityDomainFloor)
+        }
+        other => panic!("the tight arena should be rejected: {other:?}"),
+    }
+
+    // 2. The commit fails as a whole, naming the arena, and changes nothing anywhere.
+    let err = gov.commit_proposal(&everywhere, &outcome, HEIGHT).expect_err("tight arena floor");
+    println!("refused: {err}");
+    assert!(err.starts_with(&format!("Domain {}: ", ARENAS[2])));
+    assert_eq!(disabled_counts(&gov), [0, 0, 0]);
+    assert!(ARENAS.iter().all(|a| gov.change_history(a).is_empty()));
+
+    // 3. Over the two roomy arenas (one named twice), it applies to both at once.
+    let pair = ProposalTarget::Domains(vec![ARENAS[0].into(), ARENAS[1].into(), ARENAS[0].into()]);
+    let roomy = no_dash_or_shield("roomy-no-dash", pair);
+    match gov.commit_proposal(&roomy, &vote("roomy-no-dash", 80, 20, HEIGHT), HEIGHT) {
+        Ok(CommitOutcome::Applied(changes)) => {
+            let domains: Vec<&str> = changes.iter().map(|c| c.domain_id.as_str()).collect();
+            assert_eq!(domains, &ARENAS[..2]);
+        }
+        other => panic!("two roomy arenas should apply: {other:?}"),
+    }
+    assert_eq!(disabled_counts(&gov), [2, 2, 0]);
+
+    // 4. An unregistered arena in the list: refused up front.
+    let stray = ProposalTarget::Domains(vec![ARENAS[2].into(), "arena:league:ghost".into()]);
+    let stray = no_dash_or_shield("stray", stray);
+    let err = gov.commit_proposal(&stray, &vote("stray", 80, 20, HEIGHT), HEIGHT).expect_err("unknown arena");
+    assert!(err.contains("arena:league:ghost"), "{err}");
+    assert_eq!(disabled_counts(&gov), [2, 2, 0]);
+}
@@ -0,0 +1,58 @@
+// path: cybernetic-governance/examples/match_overrides.rs
+
+//! Example: a referee pulling a move for one match on the arena fixture
+//! (run with `--features test-util`).
+//! - After a headset fault, the arena's referee disables `bci_push` for match 017 from height
+//!   1,070 to 1,100: only that match sees it, and only until then
+//! - Overrides from anyone but a registered referee, longer than the constitution's 300
+//!   heights or touching a nonrestrictable capability are refused
+//! - `advance_height` drops the override once it has lapsed
+
+use cybernetic_governance::builders::{bci_xr_arena, cap, caps, BCI_XR_ARENA};
+use cybernetic_governance::MatchOverride;
+
+const MATCH: &str = "match-2026-phx-017";
+
+fn main() {
+    let mut gov = bci_xr_arena();
+    let fault = MatchOverride {
+        match_id: MATCH.into(),
+        domain_id: BCI_XR_ARENA.into(),
+        disabled: caps(&["move:bci_push"]),
+        issued_by: "referee:phoenix-01".into(),
+        valid_from_height: 1_070,
+        valid_to_height: 1_100,
+        rationale: "Headset signal fault on blue corner".into(),
+    };
+
+    // 1. Refused: a spectator, a season-long override, a safety capability.
+    let spoofed = MatchOverride { issued_by: "spectator:42".into(), ..fault.clone() };
+    let err = gov.issue_override(spoofed, 1_070).expect_err("not a referee");
+    println!("Override rejected: {err}");
+    assert_eq!(err, "spectator:42 is not a registered referee");
+    let season = MatchOverride { valid_to_height: 1_400, ..fault.clone() };
+    let err = gov.issue_override(season, 1_070).expect_err("too long");
+    assert_eq!(err, "Override lasts 330 heights; max_match_override_heights is 300");
+    let exit = MatchOverride { disabled: caps(&["safety:session_exit"]), ..fault.clone() };
+    let err = gov.issue_override(exit, 1_070).expect_err("nonrestrictable");
+    assert_eq!(err, "Cannot override non-restrictable capability: safety:session_exit");
+    assert!(gov.match_overrides().is_empty());
+
+    // 2. The referee's override: this match only, until height 1,100.
+    gov.issue_override(fault, 1_070).expect("referee override is within limits");
+    let in_match = gov.effective_capabilities(BCI_XR_ARENA, 1_080, Some(MATCH)).expect("domain exists");
+    println!("In {MATCH}: {} enabled", in_match.enabled_count);
+    assert_eq!(in_match.enabled_count, 6);
+    let [push] = &in_match.disabled[..] else { panic!("only the push is disabled") };
+    assert_eq!((push.capability, push.sunset_height), (&cap("move:bci_push"), Some(1_100)));
+    assert!(push.match_override.is_some_and(|o| o.issued_by == "referee:phoenix-01"));
+    let elsewhere = gov.effective_capabilities(BCI_XR_ARENA, 1_080, Some("match-2026-phx-018")).expect("known");
+    assert_eq!(elsewhere.enabled_count, 7);
+    let lapsed = gov.effective_capabilities(BCI_XR_ARENA, 1_100, Some(MATCH)).expect("domain exists");
+    assert_eq!(lapsed.enabled_count, 7);
+
+    // 3. Advancing past it drops the override.
+    gov.advance_height(1_100);
+    println!("Overrides left after expiry: {}", gov.match_overrides().len());
+    assert!(gov.match_overrides().is_empty());
+}
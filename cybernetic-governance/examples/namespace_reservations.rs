@@ -0,0 +1,81 @@
+// path: cybernetic-governance/examples/namespace_reservations.rs
+
+//! Example: reserving whole capability namespaces as non-restrictable
+//! (run with `--features test-util`).
+//! - The constitution reserves `safety` and `access`; a `safety:heartbeat_monitor` capability
+//!   added to a domain later is protected with no constitution change, and the upsert warns
+//! - The preview attributes each dropped restriction to the explicit list or the namespace
+//! - Namespaces match whole segments: `safety_net:rope` is not under `safety`
+//! - Removing a namespace is a weakening amendment and waits out the time-lock
+
+use cybernetic_governance::builders::{cap, vote, ConstitutionBuilder, DomainBuilder, ProposalBuilder};
+use cybernetic_governance::{
+    AmendmentStatus, CapabilityGovernance, CommitOutcome, ConstitutionAmendment, ConstitutionChange,
+    NonrestrictableRule, ReasonCode,
+};
+
+const ARENA: &str = "arena:phoenix:night_league";
+const TIMELOCK: u64 = 10_000;
+
+fn main() {
+    let constitution = ConstitutionBuilder::new().nonrestrictable_namespaces(&["safety", "access"]).build();
+    assert!(constitution.is_nonrestrictable(&cap("access:ramp:east")));
+    assert!(!constitution.is_nonrestrictable(&cap("safety_net:rope")));
+    let mut gov = CapabilityGovernance::new(constitution);
+    let arena = DomainBuilder::new(ARENA)
+        .allow(&["safety:emergency_stop", "move:dash", "move:jump", "move:slide", "move:bci_pull"])
+        .min_capability_count(3);
+    assert!(gov.upsert_domain(arena.clone().build_valid()).expect("valid domain").is_empty());
+
+    // 1. A new safety capability: protected at once, and the operators are told.
+    let with_monitor = arena.allow(&["safety:heartbeat_monitor"]).build_valid();
+    let warnings = gov.upsert_domain(with_monitor.clone()).expect("valid domain");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].code, ReasonCode::CapabilityReservedNamespace);
+    assert_eq!(warnings[0].params["namespace"], "safety");
+    println!("{}", warnings[0]);
+    assert!(gov.upsert_domain(with_monitor).expect("valid domain").is_empty(), "only new capabilities warn");
+
+    // 2. The preview says which rule drops which restriction; the rest applies.
+    let restrict = ["safety:heartbeat_monitor", "safety:emergency_stop", "move:dash"];
+    let sweep = ProposalBuilder::new("sweep", ARENA).restrict(&restrict).activation_height(1_000);
+    let sweep = sweep.build_valid_under(&gov);
+    let preview = gov.preview(&sweep).expect("previewable");
+    let domain = &preview.domains[0];
+    assert_eq!(domain.would_disable, [cap("move:dash")].into());
+    assert_eq!(domain.constitution_rules[&cap("safety:emergency_stop")], NonrestrictableRule::ExplicitList);
+    let by_namespace = NonrestrictableRule::Namespace("safety".into());
+    assert_eq!(domain.constitution_rules[&cap("safety:heartbeat_monitor")], by_namespace);
+    let committed = gov.commit_proposal(&sweep, &vote("sweep", 80, 20, 1_005), 1_010).expect("applies");
+    assert!(matches!(committed, CommitOutcome::Applied(_)));
+    let effective = gov.effective_capabilities(ARENA, 1_010, None).expect("known domain");
+    assert_eq!(effective.enabled_count, 5);
+
+    // 3. Namespace amendments: malformed ones are refused; removing one weakens protection.
+    let bad = ConstitutionAmendment {
+        amendment_id: "bad".into(),
+        changes: vec![ConstitutionChange::AddNonrestrictableNamespace("Access!".into())],
+        rationale: "typo".into(),
+    };
+    assert!(gov.propose_amendment(bad).unwrap_err().contains("Malformed non-restrictable namespace"));
+    let lift = ConstitutionAmendment {
+        amendment_id: "lift-safety".into(),
+        changes: vec![ConstitutionChange::RemoveNonrestrictableNamespace("safety".into())],
+        rationale: "List safety capabilities one by one instead".into(),
+    };
+    let assessment = gov.propose_amendment(lift).expect("proposed");
+    assert!(assessment.weakens_protections && assessment.irreversible_risk);
+    let status = gov.approve_amendment("lift-safety", &vote("lift-safety", 95, 5, 2_000), 2_000).expect("voted");
+    assert_eq!(status, AmendmentStatus::Approved { approved_height: 2_000, effective_height: 2_000 + TIMELOCK });
+
+    // 4. Until the time-lock ends the monitor stays protected; afterwards it can be restricted.
+    let monitor = ProposalBuilder::new("monitor", ARENA).restrict(&["safety:heartbeat_monitor"]);
+    let monitor = monitor.activation_height(3_000).build_valid_under(&gov);
+    assert!(gov.apply_due_amendments(2_000 + TIMELOCK - 1).is_empty());
+    assert!(gov.preview(&monitor).expect("previewable").domains[0].would_disable.is_empty());
+    assert_eq!(gov.apply_due_amendments(2_000 + TIMELOCK).len(), 1);
+    let preview = gov.preview(&monitor).expect("previewable");
+    assert_eq!(preview.domains[0].would_disable, [cap("safety:heartbeat_monitor")].into());
+    assert!(preview.domains[0].constitution_rules.is_empty());
+    println!("{}", serde_json::to_string_pretty(&gov.constitution().nonrestrictable_namespaces).expect("json"));
+}
@@ -0,0 +1,59 @@
+// path: cybernetic-governance/examples/outcome_heights.rs
+
+//! Example: how old, how new and how far ahead a vote outcome may be (run with
+//! `--features test-util`).
+//! - The arena fixture accepts outcomes up to 5,000 heights old: an outcome finalized at
+//!   1,005 still applies at 6,005 and is stale at 6,006
+//! - An outcome finalized after the commit height is refused
+//! - With a height source configured, a caller's height ahead of it is refused, and
+//!   `commit_proposal_now` commits at the source's height, which the change records
+
+use std::sync::Arc;
+
+use cybernetic_governance::builders::{bci_xr_arena, vote, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{CommitOutcome, ManualHeightSource, Reason, ReasonCode};
+
+const FINALIZED: u64 = 1_005;
+
+fn message(code: ReasonCode) -> String {
+    Reason::new(code).to_string()
+}
+
+fn main() {
+    let trim = ProposalBuilder::new("trim-pull", BCI_XR_ARENA).restrict(&["move:bci_pull"]).activation_height(1_000);
+    let trim = trim.build_valid_under(&bci_xr_arena());
+    let outcome = vote("trim-pull", 80, 20, FINALIZED);
+
+    // 1. One height past the limit is stale; exactly at the limit still applies.
+    let mut gov = bci_xr_arena();
+    let err = gov.commit_proposal(&trim, &outcome, FINALIZED + 5_001).expect_err("stale");
+    assert_eq!(err, message(ReasonCode::CapabilityOutcomeStale));
+    assert!(gov.change_history(BCI_XR_ARENA).is_empty());
+    match gov.commit_proposal(&trim, &outcome, FINALIZED + 5_000) {
+        Ok(CommitOutcome::Applied(changes)) => assert_eq!(changes[0].height, 6_005),
+        other => panic!("at the limit the outcome applies: {other:?}"),
+    }
+
+    // 2. An outcome finalized after the commit height is from the future.
+    let mut gov = bci_xr_arena();
+    let err = gov.commit_proposal(&trim, &outcome, FINALIZED - 1).expect_err("future");
+    assert_eq!(err, message(ReasonCode::CapabilityOutcomeFuture));
+
+    // 3. A height source: callers cannot run ahead of it, and `now` commits at its height.
+    let source = ManualHeightSource::new(1_010);
+    gov.set_height_source(Arc::new(source.clone()));
+    let err = gov.commit_proposal(&trim, &outcome, 1_020).expect_err("ahead of the source");
+    println!("refused: {err}");
+    assert!(err.starts_with("current_height 1020 is ahead of height source"), "{err}");
+    source.advance(15);
+    match gov.commit_proposal_now(&trim, &outcome) {
+        Ok(CommitOutcome::Applied(changes)) => assert_eq!(changes[0].height, 1_025),
+        other => panic!("committed at the source's height: {other:?}"),
+    }
+    assert_eq!(gov.change_history(BCI_XR_ARENA)[0].height, 1_025);
+
+    // 4. Without a source, `now` has nothing to go on.
+    let mut bare = bci_xr_arena();
+    assert!(bare.source_height().is_err());
+    assert!(bare.commit_proposal_now(&trim, &outcome).is_err());
+}
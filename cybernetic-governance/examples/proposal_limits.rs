@@ -0,0 +1,124 @@
+// path: cybernetic-governance/examples/proposal_limits.rs
+
+//! Example: capability proposals right at and just over each `ProposalLimits` bound.
+//! - At the limit a proposal previews and evaluates as usual; one over is refused by
+//!   preview, evaluation and commit alike, naming the limit and the measured size
+//! - A `Domains` target counts duplicates; limits survive a snapshot round trip
+
+use cybernetic_governance::*;
+use std::collections::{HashMap, HashSet};
+
+fn cap(id: &str) -> CapabilityId {
+    CapabilityId(id.to_string())
+}
+
+fn caps(ids: &[&str]) -> HashSet<CapabilityId> {
+    ids.iter().map(|id| cap(id)).collect()
+}
+
+fn domain(id: &str) -> CompetitiveDomain {
+    CompetitiveDomain {
+        id: id.into(),
+        description: "Limits arena".into(),
+        allowed_capabilities: caps(&[
+            "safety:emergency_stop", "move:push", "move:pull", "move:shield", "move:dash", "move:feint",
+            "move:grab", "move:throw", "move:block", "move:taunt",
+        ]),
+        min_capability_count: 4,
+        capability_categories: HashMap::new(),
+        category_floors: HashMap::new(),
+    }
+}
+
+fn proposal(restrict: &[&str], protect: &[&str], sunsets: &[&str], target: Option<ProposalTarget>) -> GovernanceProposal {
+    GovernanceProposal {
+        proposal_id: "prop-limits".into(),
+        domain_id: "arena:north".into(),
+        target,
+        restrict_capabilities: caps(restrict),
+        protect_capabilities: caps(protect),
+        required_supermajority: 0.75,
+        activation_height: 100,
+        sunset_height: None,
+        capability_sunsets: sunsets.iter().map(|id| (cap(id), 500)).collect(),
+        impact_statement: None,
+    }
+}
+
+fn main() {
+    let constitution = GovernanceConstitution {
+        global_min_capability_floor: 2,
+        max_restriction_fraction_per_turn: 0.5,
+        max_cumulative_disabled_fraction: None,
+        min_supermajority_floor: 0.67,
+        hard_protect_safety_capabilities: true,
+        globally_nonrestrictable: HashSet::new(),
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.9,
+        max_outcome_age_heights: None,
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: HashMap::new(),
+        threshold_mode: ThresholdMode::default(),
+        referees: HashSet::new(),
+        max_match_override_heights: 100,
+        amendment_rules: AmendmentRules::default(),
+        require_impact_statement: false,
+        impact_estimate_factor: 2.0,
+        appeal_panel: HashSet::new(),
+        appeal_window_heights: 0,
+    };
+    let mut gov = CapabilityGovernance::new(constitution);
+    gov.upsert_domain(domain("arena:north")).expect("valid domain");
+    gov.upsert_domain(domain("arena:south")).expect("valid domain");
+    gov.set_proposal_limits(ProposalLimits {
+        max_restrict_capabilities: 2,
+        max_protect_capabilities: 2,
+        max_capability_sunsets: 1,
+        max_target_domains: 2,
+    });
+    let outcome = GovernanceVoteOutcome {
+        proposal_id: "prop-limits".into(),
+        yes_weight: 9,
+        no_weight: 1,
+        finalized_height: 100,
+        participation: None,
+    };
+    let both = || ProposalTarget::Domains(vec!["arena:north".into(), "arena:south".into()]);
+
+    let at_limit = [
+        proposal(&["move:push", "move:pull"], &[], &[], None),
+        proposal(&[], &["move:dash", "move:feint"], &[], None),
+        proposal(&["move:push"], &[], &["move:push"], None),
+        proposal(&["move:push"], &[], &[], Some(both())),
+    ];
+    for proposal in &at_limit {
+        assert_eq!(gov.check_proposal_limits(proposal), Ok(()));
+        gov.preview(proposal).expect("previews at the limit");
+        gov.evaluate_proposal_per_domain(proposal, &outcome, 100).expect("evaluates at the limit");
+    }
+
+    let mut duplicated = both();
+    if let ProposalTarget::Domains(ids) = &mut duplicated {
+        ids.push("arena:north".into());
+    }
+    let over_limit = [
+        (proposal(&["move:push", "move:pull", "move:grab"], &[], &[], None), ProposalLimit::RestrictCapabilities, 3, 2),
+        (proposal(&[], &["move:dash", "move:feint", "move:block"], &[], None), ProposalLimit::ProtectCapabilities, 3, 2),
+        (proposal(&["move:push", "move:pull"], &[], &["move:push", "move:pull"], None), ProposalLimit::CapabilitySunsets, 2, 1),
+        (proposal(&["move:push"], &[], &[], Some(duplicated)), ProposalLimit::TargetDomains, 3, 2),
+    ];
+    for (proposal, limit, measured, max) in &over_limit {
+        let exceeded = ProposalLimitExceeded { limit: *limit, measured: *measured, max: *max };
+        assert_eq!(gov.check_proposal_limits(proposal), Err(exceeded.clone()));
+        let message = exceeded.to_string();
+        assert_eq!(gov.preview(proposal).err(), Some(message.clone()));
+        assert_eq!(gov.evaluate_proposal_per_domain(proposal, &outcome, 100).err(), Some(message.clone()));
+        assert_eq!(gov.commit_proposal(proposal, &outcome, 100).err(), Some(message));
+        println!("{exceeded}");
+    }
+    assert!(gov.change_history("arena:north").is_empty(), "nothing committed over the limits");
+
+    let restored = CapabilityGovernance::restore(gov.snapshot()).expect("restores");
+    assert_eq!(restored.proposal_limits(), gov.proposal_limits());
+}
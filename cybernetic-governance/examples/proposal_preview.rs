@@ -0,0 +1,43 @@
+// path: cybernetic-governance/examples/proposal_preview.rs
+
+//! Example: previewing the arena lockdown before anyone votes (run with `--features test-util`).
+//! - Of the four restrictions, three would apply and the nonrestrictable research capability
+//!   is skipped, leaving four enabled: below the domain floor of five, so the preview says the
+//!   constitution would reject it
+//! - The research restriction's own 90% threshold is reported unmet at the weakest passing
+//!   ratio, 75%
+//! - Previewing changes nothing
+
+use cybernetic_governance::builders::{bci_xr_arena, bci_xr_lockdown, cap, caps, BCI_XR_ARENA};
+
+fn main() {
+    let gov = bci_xr_arena();
+    let (lockdown, _) = bci_xr_lockdown();
+
+    // 1. Three restrictions apply, one is skipped, and the floor rejects the result.
+    let preview = gov.preview(&lockdown).expect("proposal is well-formed");
+    let arena = &preview.domains[0];
+    println!(
+        "Preview {}: {} would be disabled, {} skipped by constitution, {} enabled after (floor {})",
+        arena.domain_id,
+        arena.would_disable.len(),
+        arena.skipped_due_to_constitution.len(),
+        arena.enabled_after,
+        arena.domain_floor.max(arena.global_floor),
+    );
+    assert_eq!(arena.would_disable, caps(&["move:bci_push", "move:bci_pull", "move:bci_shield"]));
+    assert_eq!(arena.skipped_due_to_constitution, caps(&["research:noninvasive_bci"]));
+    assert_eq!((arena.enabled_before, arena.enabled_after), (7, 4));
+    assert!(arena.rejection.is_some(), "four enabled is below the domain floor of 5");
+
+    // 2. Thresholds of their own are checked at the weakest ratio that would pass.
+    assert_eq!(preview.yes_ratio, 0.75);
+    let [research] = &preview.thresholds[..] else { panic!("one restriction has a threshold of its own") };
+    assert_eq!(research.capability, cap("research:noninvasive_bci"));
+    assert_eq!((research.required_ratio, research.met), (0.90, false));
+    serde_json::to_string(&preview).expect("preview serializes");
+
+    // 3. Nothing changed.
+    let state = gov.get_domain_state(BCI_XR_ARENA).expect("arena");
+    assert!(state.disabled_capabilities.is_empty(), "previewing changes nothing");
+}
@@ -0,0 +1,75 @@
+// path: cybernetic-governance/examples/restriction_fractions.rs
+
+//! Example: the per-turn cap counts only what a proposal newly disables (run with
+//! `--features test-util`).
+//! - 7 of 20 moves already disabled (35%) and a 0.40 per-turn cap: disabling one more is a 5%
+//!   turn and passes
+//! - Nine at once is a 45% turn and is refused, however little was disabled before
+//! - With a 0.35 cumulative cap configured, the same one extra move is refused: 40% would be
+//!   disabled overall
+
+use cybernetic_governance::builders::{vote, ConstitutionBuilder, DomainBuilder, ProposalBuilder};
+use cybernetic_governance::{CapabilityGovernance, CommitOutcome, ReasonCode};
+
+const DOMAIN: &str = "league:phoenix:xr_moves";
+const HEIGHT: u64 = 1_000;
+
+fn moves() -> Vec<String> {
+    (1..=20).map(|n| format!("move:m{n:02}")).collect()
+}
+
+fn engine(cumulative_cap: Option<f64>) -> CapabilityGovernance {
+    let mut constitution = ConstitutionBuilder::new().max_restriction_fraction_per_turn(0.40);
+    if let Some(cap) = cumulative_cap {
+        constitution = constitution.max_cumulative_disabled_fraction(cap);
+    }
+    let mut gov = CapabilityGovernance::new(constitution.build());
+    let moves = moves();
+    let ids: Vec<&str> = moves.iter().map(String::as_str).collect();
+    gov.upsert_domain(DomainBuilder::new(DOMAIN).allow(&ids).build_valid()).expect("valid domain");
+    gov
+}
+
+fn commit(gov: &mut CapabilityGovernance, id: &str, restrict: &[String]) -> Result<CommitOutcome, String> {
+    let restrict: Vec<&str> = restrict.iter().map(String::as_str).collect();
+    let proposal = ProposalBuilder::new(id, DOMAIN).restrict(&restrict).activation_height(HEIGHT).build();
+    gov.commit_proposal(&proposal, &vote(id, 90, 10, HEIGHT), HEIGHT)
+}
+
+fn rejection(gov: &CapabilityGovernance, id: &str, restrict: &[String]) -> Option<ReasonCode> {
+    let restrict: Vec<&str> = restrict.iter().map(String::as_str).collect();
+    let proposal = ProposalBuilder::new(id, DOMAIN).restrict(&restrict).activation_height(HEIGHT).build();
+    let preview = gov.preview(&proposal).expect("previewable");
+    preview.domains[0].coded_rejection.as_ref().map(|r| r.code)
+}
+
+fn disabled_count(gov: &CapabilityGovernance) -> usize {
+    gov.get_domain_state(DOMAIN).expect("domain").disabled_capabilities.len()
+}
+
+fn main() {
+    let moves = moves();
+
+    // 1. Seven moves disabled in earlier turns: 35% of the domain.
+    let mut gov = engine(None);
+    assert!(matches!(commit(&mut gov, "season-opener", &moves[..7]), Ok(CommitOutcome::Applied(_))));
+    assert_eq!(disabled_count(&gov), 7);
+
+    // 2. One more is a 5% turn: it passes although 40% ends up disabled.
+    assert_eq!(rejection(&gov, "one-more", &moves[7..8]), None);
+    let applied = commit(&mut gov, "one-more", &moves[7..8]).expect("within the per-turn cap");
+    assert!(matches!(applied, CommitOutcome::Applied(ref changes) if changes[0].newly_disabled.len() == 1));
+    assert_eq!(disabled_count(&gov), 8);
+
+    // 3. Nine at once is a 45% turn, and the per-turn cap refuses it.
+    let fresh = engine(None);
+    assert_eq!(rejection(&fresh, "nine", &moves[..9]), Some(ReasonCode::CapabilityRestrictionFraction));
+
+    // 4. With a cumulative cap of 0.35, the same extra move is refused, and nothing changes.
+    let mut capped = engine(Some(0.35));
+    assert!(matches!(commit(&mut capped, "season-opener", &moves[..7]), Ok(CommitOutcome::Applied(_))));
+    assert_eq!(rejection(&capped, "one-more", &moves[7..8]), Some(ReasonCode::CapabilityCumulativeFraction));
+    let err = commit(&mut capped, "one-more", &moves[7..8]).expect_err("cumulative cap");
+    println!("refused: {err}");
+    assert_eq!(disabled_count(&capped), 7);
+}
@@ -0,0 +1,85 @@
+// path: cybernetic-governance/examples/restriction_sunsets.rs
+
+//! Example: temporary restrictions on the arena fixture (run with `--features test-util`).
+//! - `bci_shield` is disabled for the season with a per-capability sunset while `bci_pull` is
+//!   disabled permanently; at the sunset only the shield comes back, and the history says so
+//! - Protecting `bci_push` before its sunset re-enables it and clears the entry: nothing
+//!   expires later
+//! - That protection then holds against an 80% restriction, and a 95% one lifts it and
+//!   restricts with a fresh sunset
+//! - A sunset at or before activation, per proposal or per capability, is refused at
+//!   validation
+
+use cybernetic_governance::builders::{bci_xr_arena, cap, vote, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{AppliedChange, CapabilityGovernance, ChangeKind, CommitOutcome, ReasonCode};
+
+fn commit(gov: &mut CapabilityGovernance, builder: ProposalBuilder, yes: u128, height: u64) -> AppliedChange {
+    let proposal = builder.activation_height(height).build_valid_under(gov);
+    let outcome = vote(&proposal.proposal_id, yes, 100 - yes, height);
+    match gov.commit_proposal(&proposal, &outcome, height) {
+        Ok(CommitOutcome::Applied(mut changes)) => changes.remove(0),
+        other => panic!("{} should apply: {other:?}", proposal.proposal_id),
+    }
+}
+
+fn is_disabled(gov: &CapabilityGovernance, id: &str) -> bool {
+    gov.get_domain_state(BCI_XR_ARENA).expect("arena").disabled_capabilities.contains(&cap(id))
+}
+
+fn sunset_of(gov: &CapabilityGovernance, id: &str) -> Option<u64> {
+    gov.get_domain_state(BCI_XR_ARENA).expect("arena").disabled_until.get(&cap(id)).copied()
+}
+
+fn main() {
+    let mut gov = bci_xr_arena();
+
+    // 1. The shield for the season, the pull for good.
+    let season = ProposalBuilder::new("season-2026", BCI_XR_ARENA)
+        .restrict(&["move:bci_shield", "move:bci_pull"])
+        .capability_sunset("move:bci_shield", 2_000);
+    commit(&mut gov, season, 80, 1_000);
+    assert_eq!((sunset_of(&gov, "move:bci_shield"), sunset_of(&gov, "move:bci_pull")), (Some(2_000), None));
+
+    // 2. Nothing lapses before the sunset; at it, only the shield returns.
+    assert!(gov.advance_height(1_999).is_empty());
+    let expired = gov.advance_height(2_000);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].capability, cap("move:bci_shield"));
+    assert_eq!(expired[0].restricted_by.as_deref(), Some("season-2026"));
+    assert!(!is_disabled(&gov, "move:bci_shield") && is_disabled(&gov, "move:bci_pull"));
+    let last = gov.change_history(BCI_XR_ARENA).last().expect("expiry recorded");
+    assert_eq!((last.kind, last.height, last.proposal_id.as_str()), (ChangeKind::SunsetExpired, 2_000, "season-2026"));
+    assert!(gov.advance_height(10_000).is_empty(), "the permanent restriction stays");
+
+    // 3. A cup-week restriction on the push, re-enabled early by protecting it.
+    let cup = ProposalBuilder::new("cup-week", BCI_XR_ARENA).restrict(&["move:bci_push"]).sunset_height(12_000);
+    commit(&mut gov, cup, 80, 10_000);
+    assert_eq!(sunset_of(&gov, "move:bci_push"), Some(12_000));
+    let reinstate = ProposalBuilder::new("reinstate-push", BCI_XR_ARENA).protect(&["move:bci_push"]);
+    let change = commit(&mut gov, reinstate, 80, 10_100);
+    assert_eq!(change.re_enabled, [cap("move:bci_push")].into());
+    assert_eq!(sunset_of(&gov, "move:bci_push"), None, "entry cleared");
+    assert!(gov.advance_height(12_000).is_empty(), "nothing left to expire");
+
+    // 4. The protection holds against 80%; 95% lifts it, with a sunset of its own.
+    let retry = ProposalBuilder::new("push-retry", BCI_XR_ARENA).restrict(&["move:bci_push"]).sunset_height(13_000);
+    let change = commit(&mut gov, retry, 80, 12_100);
+    assert_eq!(change.skipped_due_to_protection, [cap("move:bci_push")].into());
+    assert!(!is_disabled(&gov, "move:bci_push"));
+    let lift = ProposalBuilder::new("push-lift", BCI_XR_ARENA).restrict(&["move:bci_push"]).sunset_height(13_000);
+    commit(&mut gov, lift, 95, 12_200);
+    assert_eq!(sunset_of(&gov, "move:bci_push"), Some(13_000));
+    assert!(!gov.get_domain_state(BCI_XR_ARENA).expect("arena").protections.contains_key(&cap("move:bci_push")));
+    assert_eq!(gov.advance_height(13_000).len(), 1);
+
+    // 5. Sunsets must come after activation.
+    let early = ProposalBuilder::new("early", BCI_XR_ARENA).restrict(&["move:bci_push"]).activation_height(14_000);
+    let reason = gov.validate_proposal(&early.clone().sunset_height(14_000).build()).expect_err("sunset first");
+    assert_eq!(reason.code, ReasonCode::CapabilitySunsetOrder);
+    let per_capability = early.capability_sunset("move:bci_push", 13_500).build();
+    let reason = gov.validate_proposal(&per_capability).expect_err("sunset first");
+    assert_eq!(reason.code, ReasonCode::CapabilityCapabilitySunsetOrder);
+    assert_eq!(reason.params["capability"], "move:bci_push");
+
+    println!("{}", serde_json::to_string_pretty(gov.change_history(BCI_XR_ARENA)).expect("history json"));
+}
@@ -0,0 +1,51 @@
+// path: cybernetic-governance/examples/safety_oracle.rs
+
+//! Example: the vendor's safety oracle on the arena fixture (run with `--features test-util`).
+//! - A 90% vote restricting `bci_push` passes evaluation, but the oracle vetoes the commit
+//!   while an athlete is using the move mid-match; nothing is applied
+//! - The veto is in the domain's history, with the oracle's reason
+//! - The arena's constitution allows no override, even with the veto's token
+
+use std::sync::Arc;
+
+use cybernetic_governance::builders::{bci_xr_arena, cap, vote, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{ActiveUseOracle, ChangeKind, CommitOutcome, OracleOverride, OracleVerdict};
+
+fn main() {
+    let mut gov = bci_xr_arena();
+    let oracle = Arc::new(ActiveUseOracle::new());
+    oracle.mark_in_use(BCI_XR_ARENA, cap("move:bci_push"));
+    gov.set_safety_oracle(oracle);
+
+    // 1. Approved and constitutional, yet vetoed at commit.
+    let restrict_push = ProposalBuilder::new("prop-2026-04-restrict-push", BCI_XR_ARENA)
+        .restrict(&["move:bci_push"])
+        .activation_height(1_000)
+        .build_valid_under(&gov);
+    let outcome = vote("prop-2026-04-restrict-push", 900, 100, 1_060);
+    let evaluated = gov.evaluate_proposal(&restrict_push, &outcome, 1_070);
+    assert!(matches!(evaluated, Ok(Some(_))), "approved and constitutional");
+    let vetoes = match gov.commit_proposal(&restrict_push, &outcome, 1_070) {
+        Ok(CommitOutcome::Vetoed(vetoes)) => vetoes,
+        other => panic!("the oracle should veto: {other:?}"),
+    };
+    let review = vetoes[0].oracle_review.as_ref().expect("veto reviewed");
+    let OracleVerdict::Veto { reasons } = &review.verdict else { panic!("vetoed") };
+    println!("Safety oracle vetoed commit: {}", reasons.join("; "));
+    assert_eq!(reasons, &["move:bci_push is in active use"]);
+    let state = gov.get_domain_state(BCI_XR_ARENA).expect("arena");
+    assert!(!state.disabled_capabilities.contains(&cap("move:bci_push")), "nothing applied");
+
+    // 2. The veto is on record.
+    let last = gov.change_history(BCI_XR_ARENA).last().expect("the veto is recorded");
+    assert_eq!((last.kind, last.proposal_id.as_str()), (ChangeKind::OracleVeto, "prop-2026-04-restrict-push"));
+
+    // 3. This constitution allows no override, token or not.
+    let acknowledged = OracleOverride {
+        acknowledged_by: vec!["vendor:safety-officer".into()],
+        acknowledgment_tokens: [review.override_token.clone()].into_iter().collect(),
+    };
+    let overridden = gov.commit_proposal_with_override(&restrict_push, &outcome, 1_070, &acknowledged);
+    let err = overridden.expect_err("no override under this constitution");
+    assert_eq!(err, "Constitution does not allow overriding the safety oracle");
+}
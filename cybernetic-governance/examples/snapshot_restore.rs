@@ -0,0 +1,130 @@
+// path: cybernetic-governance/examples/snapshot_restore.rs
+
+//! Example: the golden arena snapshot (run with `--features test-util`).
+//! - `golden/arena_snapshot.json` is the arena fixture after a seasonal restriction on
+//!   `bci_pull` and a protection on `bci_shield`; rebuilding it gives the same bytes
+//! - Restored from the golden file, the engine evaluates a known proposal exactly as the
+//!   engine it was taken from
+//! - Sunsets or attributions for enabled capabilities, and protections on disabled ones, are
+//!   refused on restore
+//! - So are votes counting a voter outside their snapshot, or held in a domain that does not exist
+//! - `--bless` rewrites the golden file instead of checking it; review the diff
+
+use cybernetic_governance::builders::{bci_xr_arena, vote, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{CapabilityGovernance, CommitOutcome, GovernanceSnapshot, RestoreError, VoterId, VoterRegistry};
+use serde_json::{json, Value};
+
+const GOLDEN: &str = include_str!("../golden/arena_snapshot.json");
+
+fn commit(gov: &mut CapabilityGovernance, builder: ProposalBuilder, height: u64) {
+    let proposal = builder.activation_height(height).build_valid_under(gov);
+    let outcome = vote(&proposal.proposal_id, 80, 20, height);
+    match gov.commit_proposal(&proposal, &outcome, height) {
+        Ok(CommitOutcome::Applied(_)) => {}
+        other => panic!("{} should apply: {other:?}", proposal.proposal_id),
+    }
+}
+
+fn season() -> CapabilityGovernance {
+    let mut gov = bci_xr_arena();
+    let pull = ProposalBuilder::new("season-pull", BCI_XR_ARENA).restrict(&["move:bci_pull"]).sunset_height(2_000);
+    commit(&mut gov, pull, 1_000);
+    commit(&mut gov, ProposalBuilder::new("keep-shield", BCI_XR_ARENA).protect(&["move:bci_shield"]), 1_010);
+    gov
+}
+
+/// Restore the golden snapshot with its arena edited; the edit must make it inconsistent.
+fn restore_edited(edit: impl FnOnce(&mut Value)) -> RestoreError {
+    let mut value: Value = serde_json::from_str(GOLDEN).expect("golden parses");
+    edit(&mut value["domains"][BCI_XR_ARENA]);
+    match CapabilityGovernance::restore(serde_json::from_value(value).expect("still a snapshot")) {
+        Ok(_) => panic!("the edited snapshot should be refused"),
+        Err(err) => err,
+    }
+}
+
+/// Restore `gov`'s snapshot with its votes edited; the edit must make it inconsistent.
+fn restore_votes_edited(gov: &CapabilityGovernance, edit: impl FnOnce(&mut Value)) -> RestoreError {
+    let mut value = serde_json::to_value(gov.snapshot()).expect("snapshot serializes");
+    edit(&mut value["votes"]);
+    match CapabilityGovernance::restore(serde_json::from_value(value).expect("still a snapshot")) {
+        Ok(_) => panic!("the edited snapshot should be refused"),
+        Err(err) => err,
+    }
+}
+
+fn main() {
+    // 1. Rebuilding the season gives the golden bytes.
+    let gov = season();
+    let json = serde_json::to_string_pretty(&gov.snapshot()).expect("snapshot serializes") + "\n";
+    if std::env::args().any(|a| a == "--bless") {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/golden/arena_snapshot.json");
+        std::fs::write(path, &json).expect("golden file is writable");
+        println!("blessed {path}");
+        return;
+    }
+    assert_eq!(json, GOLDEN, "snapshot drifted from the golden file; rerun with --bless and review");
+
+    // 2. Restored from the golden file, a known proposal evaluates identically.
+    let snapshot: GovernanceSnapshot = serde_json::from_str(GOLDEN).expect("golden parses");
+    let restored = CapabilityGovernance::restore(snapshot).expect("golden snapshot is consistent");
+    let both = ProposalBuilder::new("trim-push-shield", BCI_XR_ARENA)
+        .restrict(&["move:bci_push", "move:bci_shield"])
+        .activation_height(1_500)
+        .build_valid_under(&gov);
+    let outcome = vote("trim-push-shield", 80, 20, 1_500);
+    let evaluate = |engine: &CapabilityGovernance| {
+        let results = engine.evaluate_proposal_per_domain(&both, &outcome, 1_500).expect("arena known");
+        serde_json::to_value(results).expect("results serialize")
+    };
+    let original = evaluate(&gov);
+    assert_eq!(evaluate(&restored), original);
+    let change = &original[BCI_XR_ARENA]["Passes"]["change"];
+    assert_eq!(change["newly_disabled"], json!(["move:bci_push"]), "{change}");
+    assert_eq!(change["skipped_due_to_protection"], json!(["move:bci_shield"]), "{change}");
+    let again = serde_json::to_string_pretty(&restored.snapshot()).expect("snapshot serializes") + "\n";
+    assert_eq!(again, GOLDEN, "restore then snapshot round-trips");
+
+    // 3. Bookkeeping that contradicts the disabled set is refused.
+    let err = restore_edited(|d| d["disabled_until"]["move:bci_push"] = json!(3_000));
+    println!("refused: {err}");
+    assert_eq!(
+        err,
+        RestoreError::RecordNotDisabled {
+            domain_id: BCI_XR_ARENA.into(),
+            record: "disabled_until".into(),
+            capability: "move:bci_push".into(),
+        }
+    );
+    let err = restore_edited(|d| d["disabled_by"]["move:bci_shield"] = json!("keep-shield"));
+    assert!(matches!(err, RestoreError::RecordNotDisabled { ref record, .. } if record == "disabled_by"), "{err}");
+    let err = restore_edited(|d| {
+        let shield = d["protections"]["move:bci_shield"].clone();
+        d["protections"]["move:bci_pull"] = shield;
+    });
+    assert_eq!(
+        err,
+        RestoreError::InvalidProtection { domain_id: BCI_XR_ARENA.into(), capability: "move:bci_pull".into() }
+    );
+
+    // 4. Votes are checked against their snapshot and domain.
+    let mut voting = season();
+    let ana = VoterId("did:psv:athlete:ana".into());
+    let mut voters = VoterRegistry::new();
+    voters.register(ana.clone(), 10);
+    voters.set_eligibility(&ana, BCI_XR_ARENA, true).expect("registered");
+    voting.open_vote(&both, voters.snapshot(BCI_XR_ARENA)).expect("first vote");
+    voting.cast("trim-push-shield", &ana, true).expect("in snapshot");
+    CapabilityGovernance::restore(voting.snapshot()).expect("consistent vote");
+    let err = restore_votes_edited(&voting, |v| v["trim-push-shield"]["ballots"]["did:psv:athlete:zed"] = json!(true));
+    println!("refused: {err}");
+    let zed = RestoreError::UnknownVoter { proposal_id: "trim-push-shield".into(), voter: "did:psv:athlete:zed".into() };
+    assert_eq!(err, zed);
+    let err = restore_votes_edited(&voting, |v| v["trim-push-shield"]["commitments"]["did:psv:athlete:zed"] = json!("00"));
+    assert_eq!(err, zed, "commitments too");
+    let err = restore_votes_edited(&voting, |v| v["trim-push-shield"]["domain_id"] = json!("arena:nowhere"));
+    assert_eq!(
+        err,
+        RestoreError::DanglingVote { proposal_id: "trim-push-shield".into(), domain_id: "arena:nowhere".into() }
+    );
+}
@@ -0,0 +1,64 @@
+// path: cybernetic-governance/examples/unknown_capabilities.rs
+
+//! Example: a proposal naming two real and two bogus capabilities (run with
+//! `--features test-util`).
+//! - Lenient (the default): the real two are disabled, the bogus two are reported as
+//!   unrecognized and never enter `disabled_capabilities`
+//! - The floors count only the domain's own capabilities: three of five stay enabled, which
+//!   meets a floor of 3 that four "disabled" ids would have broken
+//! - Strict: the whole proposal is refused and nothing changes
+
+use cybernetic_governance::builders::{caps, vote, ConstitutionBuilder, DomainBuilder, ProposalBuilder};
+use cybernetic_governance::{CapabilityGovernance, CommitOutcome, ReasonCode, UnknownCapabilityPolicy};
+
+const DOMAIN: &str = "arena:phoenix:xr_sprint";
+const HEIGHT: u64 = 1_000;
+const REAL: [&str; 2] = ["move:dash", "move:jump"];
+const BOGUS: [&str; 2] = ["move:teleport", "cheat:wallhack"];
+
+fn engine(policy: UnknownCapabilityPolicy) -> CapabilityGovernance {
+    let constitution = ConstitutionBuilder::new().max_restriction_fraction_per_turn(0.5).build();
+    let mut gov = CapabilityGovernance::new(constitution);
+    gov.set_unknown_capability_policy(policy);
+    let domain = DomainBuilder::new(DOMAIN)
+        .allow(&["safety:emergency_stop", "safety:session_exit", "move:walk", "move:dash", "move:jump"])
+        .min_capability_count(3)
+        .build_valid();
+    gov.upsert_domain(domain).expect("valid domain");
+    gov
+}
+
+fn main() {
+    let proposal = ProposalBuilder::new("trim-sprint", DOMAIN)
+        .restrict(&REAL)
+        .restrict(&BOGUS)
+        .activation_height(HEIGHT)
+        .build();
+    let outcome = vote("trim-sprint", 90, 10, HEIGHT);
+
+    // 1. Lenient: applied for the real two, the bogus two reported.
+    let mut gov = engine(UnknownCapabilityPolicy::Lenient);
+    let changes = match gov.commit_proposal(&proposal, &outcome, HEIGHT) {
+        Ok(CommitOutcome::Applied(changes)) => changes,
+        other => panic!("lenient commit should apply: {other:?}"),
+    };
+    println!("{}", serde_json::to_string_pretty(&changes).expect("changes json"));
+    assert_eq!(changes[0].unrecognized, caps(&BOGUS));
+    assert_eq!(changes[0].newly_disabled, caps(&REAL));
+    let state = gov.get_domain_state(DOMAIN).expect("domain");
+    assert_eq!(state.disabled_capabilities, caps(&REAL), "no bogus id stored");
+
+    // 2. The enabled count is over the domain's own five: three remain, at the floor of 3.
+    let effective = gov.effective_capabilities(DOMAIN, HEIGHT, None).expect("known domain");
+    assert_eq!((effective.enabled_count, effective.domain_floor), (3, 3));
+
+    // 3. Strict: the same proposal is refused outright.
+    let mut strict = engine(UnknownCapabilityPolicy::Strict);
+    let preview = strict.preview(&proposal).expect("previewable");
+    let rejection = preview.domains[0].coded_rejection.as_ref().expect("rejected");
+    assert_eq!(rejection.code, ReasonCode::CapabilityUnknownCapabilities);
+    assert_eq!(rejection.params["capabilities"], "cheat:wallhack, move:teleport");
+    assert!(strict.commit_proposal(&proposal, &outcome, HEIGHT).is_err());
+    assert!(strict.get_domain_state(DOMAIN).expect("domain").disabled_capabilities.is_empty());
+    assert!(strict.change_history(DOMAIN).is_empty());
+}
@@ -0,0 +1,58 @@
+// path: cybernetic-governance/examples/vote_receipts.rs
+
+//! Example: arena voters checking their weighted votes were counted as cast (run with
+//! `--features test-util`).
+//! - `cast_vote` hands back a receipt; a voter may change their ballot until the vote closes,
+//!   and only the latest counts
+//! - A ballot cast with plain `cast` is final: `cast_vote` cannot replace it
+//! - Closing publishes a leaf for every counted ballot, those cast with plain `cast` included;
+//!   the replaced ballot's receipt fails inclusion and the others verify
+//! - The same votes cast again publish the same leaves and root
+//! - The published ballots are part of the vote session and survive a snapshot
+
+use cybernetic_governance::builders::{bci_xr_arena, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{verify_receipt_inclusion, CapabilityGovernance, CountedBallots, VoterId, VoterRegistry};
+
+fn main() {
+    let counted = run();
+    assert_eq!(run(), counted, "replaying the vote publishes the same ballots");
+    println!("{} ballots counted under root {}", counted.leaves.len(), counted.root.as_deref().unwrap_or("-"));
+}
+
+fn run() -> CountedBallots {
+    let mut gov = bci_xr_arena();
+    let mut voters = VoterRegistry::new();
+    for (voter, weight) in [("did:psv:athlete:ana", 70), ("did:psv:athlete:ben", 20), ("did:psv:athlete:kofi", 10)] {
+        voters.register(VoterId(voter.into()), weight);
+        voters.set_eligibility(&VoterId(voter.into()), BCI_XR_ARENA, true).expect("registered");
+    }
+    let [ana, ben, kofi] = ["ana", "ben", "kofi"].map(|n| VoterId(format!("did:psv:athlete:{n}")));
+    let trim = ProposalBuilder::new("trim-pull", BCI_XR_ARENA).restrict(&["move:bci_pull"]).build();
+    gov.open_vote(&trim, voters.snapshot(BCI_XR_ARENA)).expect("first vote on trim-pull");
+
+    // 1. Ana votes yes, then no; ben takes a receipt, kofi casts without one.
+    let first = gov.cast_vote("trim-pull", &ana, true, "ana-salt-1", 100).expect("in snapshot");
+    let changed = gov.cast_vote("trim-pull", &ana, false, "ana-salt-2", 102).expect("in snapshot");
+    let bens = gov.cast_vote("trim-pull", &ben, true, "ben-salt", 103).expect("in snapshot");
+    gov.cast("trim-pull", &kofi, true).expect("in snapshot");
+    let recast = gov.cast_vote("trim-pull", &kofi, false, "kofi-salt", 104).expect_err("cast is final");
+    assert_eq!(recast, "Voter did:psv:athlete:kofi has already voted with a final ballot");
+    let outsider = VoterId("did:psv:athlete:zed".into());
+    assert!(gov.cast_vote("trim-pull", &outsider, true, "zed-salt", 103).is_err());
+
+    // 2. Closing counts ana once, as she last voted, and publishes three leaves.
+    let outcome = gov.close_vote("trim-pull", 105).expect("open vote");
+    assert_eq!((outcome.yes_weight, outcome.no_weight), (30, 70));
+    let counted = gov.counted_ballots("trim-pull").expect("closed").clone();
+    assert_eq!(counted.leaves.len(), 3);
+    assert!(verify_receipt_inclusion(&changed, &counted));
+    assert!(verify_receipt_inclusion(&bens, &counted));
+    assert!(!verify_receipt_inclusion(&first, &counted), "replaced by ana's second ballot");
+    assert!(gov.cast_vote("trim-pull", &ben, false, "ben-salt-2", 106).is_err());
+
+    // 3. The receipt is checked against the same ballots after a snapshot round trip.
+    let json = serde_json::to_string(&gov.snapshot()).expect("snapshot json");
+    let restored = CapabilityGovernance::restore(serde_json::from_str(&json).expect("snapshot")).expect("restores");
+    assert_eq!(restored.counted_ballots("trim-pull"), Some(&counted));
+    counted
+}
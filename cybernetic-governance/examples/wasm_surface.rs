@@ -0,0 +1,87 @@
+// path: cybernetic-governance/examples/wasm_surface.rs
+
+//! Example: the browser JSON surface, called natively (run with `--features wasm,test-util`).
+//! - The arena built from JSON evaluates the lockdown, refuses to commit it and commits a
+//!   trim exactly as the native engine does; its snapshot restores to an engine that agrees
+//!   on the next proposal
+//! - Malformed JSON, an inconsistent domain state and a stale vote fail with the messages a
+//!   JS caller would see in the exception
+//! - The engine's exported log verifies, and an edited entry is reported by index
+
+use cybernetic_governance::builders::{
+    bci_xr_arena, bci_xr_arena_constitution, bci_xr_arena_domain, bci_xr_lockdown, cap, vote, ProposalBuilder,
+    BCI_XR_ARENA,
+};
+use cybernetic_governance::wasm::{try_verify_event_log, WasmGovernance};
+use cybernetic_governance::{Reason, ReasonCode, RestoreError};
+use serde_json::{json, Value};
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("serializes")
+}
+
+fn value(json: &str) -> Value {
+    serde_json::from_str(json).expect("the surface returns JSON")
+}
+
+fn main() {
+    let constitution = to_json(&bci_xr_arena_constitution());
+    let mut native = bci_xr_arena();
+    let mut web = WasmGovernance::try_new(&constitution).expect("valid constitution");
+    assert_eq!(value(&web.try_upsert_domain(&to_json(&bci_xr_arena_domain())).expect("valid domain")), json!([]));
+
+    // 1. The lockdown evaluates and fails to commit exactly as natively; a trim commits alike.
+    let (lockdown, outcome) = bci_xr_lockdown();
+    let (proposal_json, outcome_json) = (to_json(&lockdown), to_json(&outcome));
+    let evaluated = web.try_evaluate_proposal(&proposal_json, &outcome_json, 1_010).expect("arena known");
+    let expected = native.evaluate_proposal_per_domain(&lockdown, &outcome, 1_010).expect("arena known");
+    assert_eq!(value(&evaluated), serde_json::to_value(&expected).expect("serializes"));
+    let err = web.try_commit_proposal(&proposal_json, &outcome_json, 1_010).expect_err("below the domain floor");
+    assert_eq!(err, native.commit_proposal(&lockdown, &outcome, 1_010).expect_err("below the domain floor"));
+    let trim = ProposalBuilder::new("trim-pull", BCI_XR_ARENA).restrict(&["move:bci_pull"]).build();
+    let trim_outcome = vote("trim-pull", 80, 20, 1_010);
+    let committed = web.try_commit_proposal(&to_json(&trim), &to_json(&trim_outcome), 1_010).expect("commits");
+    let native_committed = native.commit_proposal(&trim, &trim_outcome, 1_010).expect("commits");
+    assert_eq!(value(&committed), serde_json::to_value(&native_committed).expect("serializes"));
+    let effective = value(&web.try_effective_capabilities(BCI_XR_ARENA, 1_010, None).expect("arena known"));
+    assert_eq!(effective["enabled_count"], json!(6));
+
+    // 2. The snapshot round-trips and the restored engine agrees on the next proposal.
+    let snapshot = value(&web.try_snapshot().expect("snapshot serializes"));
+    assert_eq!(snapshot, serde_json::to_value(native.snapshot()).expect("serializes"));
+    let restored = WasmGovernance::try_from_snapshot(&snapshot.to_string()).expect("consistent snapshot");
+    let unlock = ProposalBuilder::new("unlock-push", BCI_XR_ARENA).protect(&["move:bci_push"]).build();
+    let unlock_outcome = to_json(&vote("unlock-push", 80, 20, 1_020));
+    let from_restored = restored.try_evaluate_proposal(&to_json(&unlock), &unlock_outcome, 1_020).expect("known");
+    assert_eq!(from_restored, web.try_evaluate_proposal(&to_json(&unlock), &unlock_outcome, 1_020).expect("known"));
+
+    // 3. Errors arrive as the strings a JS caller would catch.
+    let err = WasmGovernance::try_new("{\"global_min_capability_floor\":").err().expect("truncated");
+    assert!(err.starts_with("Invalid constitution JSON: "), "{err}");
+    let err = web.try_preview("[]").expect_err("not a proposal");
+    assert!(err.starts_with("Invalid proposal JSON: "), "{err}");
+    let mut stray = native.get_domain_state(BCI_XR_ARENA).expect("arena").clone();
+    stray.disabled_capabilities.insert(cap("move:teleport"));
+    let err = WasmGovernance::try_with_domain_states(&constitution, &to_json(&[stray])).err().expect("stray");
+    let teleport = "move:teleport".to_string();
+    let expected = RestoreError::DisabledNotInDomain { domain_id: BCI_XR_ARENA.into(), capability: teleport };
+    assert_eq!(err, expected.to_string());
+    let late = ProposalBuilder::new("trim-late", BCI_XR_ARENA).restrict(&["move:bci_push"]).build();
+    let stale = to_json(&vote("trim-late", 80, 20, 1_010));
+    let err = web.try_commit_proposal(&to_json(&late), &stale, 6_011).expect_err("stale vote");
+    println!("refused: {err}");
+    assert_eq!(err, Reason::new(ReasonCode::CapabilityOutcomeStale).to_string());
+
+    // 4. Logs verify without the engine; an edited entry is pinpointed.
+    let jsonl = native.export_log_jsonl();
+    assert_eq!(value(&try_verify_event_log(&jsonl).expect("parses")), json!({ "ok": true }));
+    let tampered: Vec<String> = jsonl
+        .lines()
+        .enumerate()
+        .map(|(i, line)| if i == 1 { line.replace("trim-pull", "trim-push") } else { line.to_string() })
+        .collect();
+    let tampered = tampered.join("\n");
+    let report = value(&try_verify_event_log(&tampered).expect("parses"));
+    assert_eq!((&report["ok"], &report["index"]), (&json!(false), &json!(1)), "{report}");
+    assert!(try_verify_event_log("not a log").is_err());
+}
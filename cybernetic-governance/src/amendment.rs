@@ -0,0 +1,562 @@
+// path: cybernetic-governance/src/amendment.rs
+
+//! Constitutional amendments with a time-lock.
+//! - Amendments are typed field changes, never free-form replacement
+//! - Approval needs a supermajority strictly above `min_supermajority_floor`
+//!   plus a margin; weakening hard protections needs an even higher threshold
+//! - Approved amendments wait `timelock_heights` before applying, during which
+//!   a counter-proposal can cancel them
+//! - Each vote outcome must name what it decides: the amendment, or the counter-proposal
+//! - A due amendment is applied only if the amended constitution is sound and every domain
+//!   already satisfies it (floors, non-restrictable capabilities); otherwise it is rejected
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    validate_nonrestrictable_namespace, CapabilityGovernance, CapabilityId, GovernanceConstitution, GovernanceEvent,
+    GovernanceVoteOutcome, ThresholdMode,
+};
+
+/// Rules governing how the constitution itself may change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AmendmentRules {
+    /// Added to `min_supermajority_floor`; approval needs a yes ratio strictly above the sum.
+    pub supermajority_margin: f64,
+    /// Heights between approval and application.
+    pub timelock_heights: u64,
+    /// Yes ratio required when an amendment weakens hard protections.
+    pub weakening_supermajority: f64,
+}
+
+impl Default for AmendmentRules {
+    fn default() -> Self {
+        AmendmentRules {
+            supermajority_margin: 0.05,
+            timelock_heights: 10_000,
+            weakening_supermajority: 0.9,
+        }
+    }
+}
+
+/// A single typed change to a constitutional field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConstitutionChange {
+    SetGlobalMinCapabilityFloor(usize),
+    SetMaxRestrictionFractionPerTurn(f64),
+    SetMaxCumulativeDisabledFraction(Option<f64>),
+    SetMinSupermajorityFloor(f64),
+    SetHardProtectSafetyCapabilities(bool),
+    AddNonrestrictable(CapabilityId),
+    RemoveNonrestrictable(CapabilityId),
+    /// Protect every capability under the namespace; see `nonrestrictable_namespaces`.
+    AddNonrestrictableNamespace(String),
+    RemoveNonrestrictableNamespace(String),
+    SetProtectedOverrideSupermajority(f64),
+    SetMaxOutcomeAgeHeights(Option<u64>),
+    SetAllowOracleOverride(bool),
+    /// `None` removes the capability's threshold.
+    SetCapabilityThreshold(CapabilityId, Option<f64>),
+    /// `None` removes the namespace's threshold.
+    SetNamespaceThreshold(String, Option<f64>),
+    SetThresholdMode(ThresholdMode),
+    AddReferee(String),
+    RemoveReferee(String),
+    SetMaxMatchOverrideHeights(u64),
+    SetAmendmentRules(AmendmentRules),
+    SetRequireImpactStatement(bool),
+    SetImpactEstimateFactor(f64),
+    AddAppealReviewer(String),
+    RemoveAppealReviewer(String),
+    SetAppealWindowHeights(u64),
+}
+
+impl ConstitutionChange {
+    /// Whether this change loosens a hard protection relative to `current`.
+    pub fn weakens(&self, current: &GovernanceConstitution) -> bool {
+        match self {
+            ConstitutionChange::SetGlobalMinCapabilityFloor(v) => *v < current.global_min_capability_floor,
+            ConstitutionChange::SetMaxRestrictionFractionPerTurn(v) => *v > current.max_restriction_fraction_per_turn,
+            ConstitutionChange::SetMaxCumulativeDisabledFraction(v) => {
+                match (v, current.max_cumulative_disabled_fraction) {
+                    (None, Some(_)) => true,
+                    (Some(new), Some(old)) => *new > old,
+                    _ => false,
+                }
+            }
+            ConstitutionChange::SetMinSupermajorityFloor(v) => *v < current.min_supermajority_floor,
+            ConstitutionChange::SetHardProtectSafetyCapabilities(v) => {
+                !*v && current.hard_protect_safety_capabilities
+            }
+            ConstitutionChange::AddNonrestrictable(_) => false,
+            ConstitutionChange::RemoveNonrestrictable(cap) => current.globally_nonrestrictable.contains(cap),
+            ConstitutionChange::AddNonrestrictableNamespace(_) => false,
+            ConstitutionChange::RemoveNonrestrictableNamespace(ns) => current.nonrestrictable_namespaces.contains(ns),
+            ConstitutionChange::SetProtectedOverrideSupermajority(v) => {
+                *v < current.protected_override_supermajority
+            }
+            ConstitutionChange::SetMaxOutcomeAgeHeights(v) => match (v, current.max_outcome_age_heights) {
+                (None, Some(_)) => true,
+                (Some(new), Some(old)) => *new > old,
+                _ => false,
+            },
+            ConstitutionChange::SetAllowOracleOverride(v) => *v && !current.allow_oracle_override,
+            ConstitutionChange::SetCapabilityThreshold(cap, v) => {
+                match (v, current.per_capability_thresholds.get(cap)) {
+                    (None, Some(_)) => true,
+                    (Some(new), Some(old)) => new < old,
+                    _ => false,
+                }
+            }
+            ConstitutionChange::SetNamespaceThreshold(prefix, v) => {
+                match (v, current.per_namespace_thresholds.get(prefix)) {
+                    (None, Some(_)) => true,
+                    (Some(new), Some(old)) => new < old,
+                    _ => false,
+                }
+            }
+            ConstitutionChange::SetThresholdMode(_) => false,
+            ConstitutionChange::AddReferee(id) => !current.referees.contains(id),
+            ConstitutionChange::RemoveReferee(_) => false,
+            ConstitutionChange::SetMaxMatchOverrideHeights(v) => *v > current.max_match_override_heights,
+            ConstitutionChange::SetAmendmentRules(rules) => {
+                rules.supermajority_margin < current.amendment_rules.supermajority_margin
+                    || rules.timelock_heights < current.amendment_rules.timelock_heights
+                    || rules.weakening_supermajority < current.amendment_rules.weakening_supermajority
+            }
+            ConstitutionChange::SetRequireImpactStatement(v) => !*v && current.require_impact_statement,
+            // Only loosens a preview warning.
+            ConstitutionChange::SetImpactEstimateFactor(_) => false,
+            // Appeals can only re-enable capabilities.
+            ConstitutionChange::AddAppealReviewer(_)
+            | ConstitutionChange::RemoveAppealReviewer(_)
+            | ConstitutionChange::SetAppealWindowHeights(_) => false,
+        }
+    }
+
+    fn apply_to(&self, c: &mut GovernanceConstitution) {
+        match self {
+            ConstitutionChange::SetGlobalMinCapabilityFloor(v) => c.global_min_capability_floor = *v,
+            ConstitutionChange::SetMaxRestrictionFractionPerTurn(v) => c.max_restriction_fraction_per_turn = *v,
+            ConstitutionChange::SetMaxCumulativeDisabledFraction(v) => c.max_cumulative_disabled_fraction = *v,
+            ConstitutionChange::SetMinSupermajorityFloor(v) => c.min_supermajority_floor = *v,
+            ConstitutionChange::SetHardProtectSafetyCapabilities(v) => c.hard_protect_safety_capabilities = *v,
+            ConstitutionChange::AddNonrestrictable(cap) => {
+                c.globally_nonrestrictable.insert(cap.clone());
+            }
+            ConstitutionChange::RemoveNonrestrictable(cap) => {
+                c.globally_nonrestrictable.remove(cap);
+            }
+            ConstitutionChange::AddNonrestrictableNamespace(ns) => {
+                c.nonrestrictable_namespaces.insert(ns.clone());
+            }
+            ConstitutionChange::RemoveNonrestrictableNamespace(ns) => {
+                c.nonrestrictable_namespaces.remove(ns);
+            }
+            ConstitutionChange::SetProtectedOverrideSupermajority(v) => c.protected_override_supermajority = *v,
+            ConstitutionChange::SetMaxOutcomeAgeHeights(v) => c.max_outcome_age_heights = *v,
+            ConstitutionChange::SetAllowOracleOverride(v) => c.allow_oracle_override = *v,
+            ConstitutionChange::SetCapabilityThreshold(cap, v) => match v {
+                Some(t) => {
+                    c.per_capability_thresholds.insert(cap.clone(), *t);
+                }
+                None => {
+                    c.per_capability_thresholds.remove(cap);
+                }
+            },
+            ConstitutionChange::SetNamespaceThreshold(prefix, v) => match v {
+                Some(t) => {
+                    c.per_namespace_thresholds.insert(prefix.clone(), *t);
+                }
+                None => {
+                    c.per_namespace_thresholds.remove(prefix);
+                }
+            },
+            ConstitutionChange::SetThresholdMode(mode) => c.threshold_mode = *mode,
+            ConstitutionChange::AddReferee(id) => {
+                c.referees.insert(id.clone());
+            }
+            ConstitutionChange::RemoveReferee(id) => {
+                c.referees.remove(id);
+            }
+            ConstitutionChange::SetMaxMatchOverrideHeights(v) => c.max_match_override_heights = *v,
+            ConstitutionChange::SetAmendmentRules(rules) => c.amendment_rules = rules.clone(),
+            ConstitutionChange::SetRequireImpactStatement(v) => c.require_impact_statement = *v,
+            ConstitutionChange::SetImpactEstimateFactor(v) => c.impact_estimate_factor = *v,
+            ConstitutionChange::AddAppealReviewer(id) => {
+                c.appeal_panel.insert(id.clone());
+            }
+            ConstitutionChange::RemoveAppealReviewer(id) => {
+                c.appeal_panel.remove(id);
+            }
+            ConstitutionChange::SetAppealWindowHeights(v) => c.appeal_window_heights = *v,
+        }
+    }
+}
+
+/// A proposed constitutional amendment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConstitutionAmendment {
+    pub amendment_id: String,
+    pub changes: Vec<ConstitutionChange>,
+    pub rationale: String,
+}
+
+/// Lifecycle of an amendment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AmendmentStatus {
+    Proposed,
+    /// Approved; applies once the chain reaches `effective_height`.
+    Approved { approved_height: u64, effective_height: u64 },
+    /// Cancelled during the time-lock by a counter-proposal.
+    Cancelled { counter_proposal_id: String, height: u64 },
+    Applied { height: u64 },
+    /// Due, but the amended constitution failed validation; nothing changed.
+    Rejected { height: u64, reason: String },
+}
+
+/// Assessment returned when proposing and approving amendments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AmendmentAssessment {
+    pub amendment_id: String,
+    /// The amendment loosens at least one hard protection.
+    pub weakens_protections: bool,
+    /// Weakening changes that are hard to undo once applied (e.g. removing
+    /// a non-restrictable capability or lowering the supermajority floor).
+    pub irreversible_risk: bool,
+    /// Yes ratio the vote must strictly exceed (or meet, for weakening amendments).
+    pub required_yes_ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AmendmentRecord {
+    pub amendment: ConstitutionAmendment,
+    pub assessment: AmendmentAssessment,
+    pub status: AmendmentStatus,
+}
+
+/// Entry in the amendment history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AppliedAmendment {
+    pub amendment_id: String,
+    pub applied_height: u64,
+    pub before_hash: String,
+    pub after_hash: String,
+}
+
+/// A counter-proposal cancelling a time-locked amendment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AmendmentCancellation {
+    pub counter_proposal_id: String,
+    pub cancels_amendment_id: String,
+}
+
+impl GovernanceConstitution {
+    /// SHA-256 over canonical JSON: object keys sorted, set-valued fields in sorted order.
+    /// This is the constitution's fingerprint; see `fingerprint`.
+    pub fn canonical_hash(&self) -> String {
+        let mut value = serde_json::to_value(self).expect("constitution serialization");
+        sort_arrays(&mut value);
+        let payload = serde_json::to_vec(&value).expect("hash serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&payload);
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// `canonical_hash`, under its original name.
+    pub fn content_hash(&self) -> String {
+        self.canonical_hash()
+    }
+}
+
+/// Sets serialize in arbitrary order; sort every array for a stable form.
+pub(crate) fn sort_arrays(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(items) => {
+            items.iter_mut().for_each(sort_arrays);
+            items.sort_by_key(|v| v.to_string());
+        }
+        serde_json::Value::Object(map) => map.values_mut().for_each(sort_arrays),
+        _ => {}
+    }
+}
+
+fn yes_ratio(outcome: &GovernanceVoteOutcome) -> Option<f64> {
+    let total = outcome.yes_weight.checked_add(outcome.no_weight)?;
+    if total == 0 {
+        return None;
+    }
+    Some(outcome.yes_weight as f64 / total as f64)
+}
+
+impl CapabilityGovernance {
+    fn assess_amendment(&self, amendment: &ConstitutionAmendment) -> AmendmentAssessment {
+        let c = &self.constitution;
+        let weakens_protections = amendment.changes.iter().any(|ch| ch.weakens(c));
+        let irreversible_risk = amendment.changes.iter().any(|ch| {
+            ch.weakens(c)
+                && matches!(
+                    ch,
+                    ConstitutionChange::RemoveNonrestrictable(_)
+                        | ConstitutionChange::RemoveNonrestrictableNamespace(_)
+                        | ConstitutionChange::SetMinSupermajorityFloor(_)
+                        | ConstitutionChange::SetHardProtectSafetyCapabilities(_)
+                )
+        });
+        let base = c.min_supermajority_floor + c.amendment_rules.supermajority_margin;
+        let required_yes_ratio = if weakens_protections {
+            base.max(c.amendment_rules.weakening_supermajority)
+        } else {
+            base
+        };
+        AmendmentAssessment {
+            amendment_id: amendment.amendment_id.clone(),
+            weakens_protections,
+            irreversible_risk,
+            required_yes_ratio,
+        }
+    }
+
+    /// Register an amendment for voting.
+    pub fn propose_amendment(&mut self, amendment: ConstitutionAmendment) -> Result<AmendmentAssessment, String> {
+        if amendment.changes.is_empty() {
+            return Err("Amendment contains no changes".into());
+        }
+        if self.amendments.contains_key(&amendment.amendment_id) {
+            return Err("Amendment id already used".into());
+        }
+        for change in &amendment.changes {
+            if let ConstitutionChange::AddNonrestrictableNamespace(ns) = change {
+                validate_nonrestrictable_namespace(ns)?;
+            }
+        }
+        let assessment = self.assess_amendment(&amendment);
+        self.event_log.append(
+            &self.constitution,
+            None,
+            GovernanceEvent::AmendmentProposed {
+                amendment_id: amendment.amendment_id.clone(),
+            },
+        );
+        self.amendments.insert(
+            amendment.amendment_id.clone(),
+            AmendmentRecord {
+                amendment,
+                assessment: assessment.clone(),
+                status: AmendmentStatus::Proposed,
+            },
+        );
+        Ok(assessment)
+    }
+
+    /// Record the vote on a proposed amendment. Returns the new status; a
+    /// failed vote leaves the amendment in `Proposed`.
+    pub fn approve_amendment(
+        &mut self,
+        amendment_id: &str,
+        outcome: &GovernanceVoteOutcome,
+        current_height: u64,
+    ) -> Result<AmendmentStatus, String> {
+        // Re-assess against the constitution in force now, not at proposal time.
+        let record = self
+            .amendments
+            .get(amendment_id)
+            .ok_or_else(|| "Unknown amendment".to_string())?;
+        if record.status != AmendmentStatus::Proposed {
+            return Err("Amendment is not awaiting a vote".into());
+        }
+        if outcome.proposal_id != amendment_id {
+            return Err(format!("Vote outcome is for {}, not amendment {amendment_id}", outcome.proposal_id));
+        }
+        if outcome.finalized_height > current_height {
+            return Err("Vote outcome finalized in the future".into());
+        }
+        let assessment = self.assess_amendment(&record.amendment);
+        let ratio = yes_ratio(outcome).unwrap_or(0.0);
+        let passed = if assessment.weakens_protections {
+            ratio >= assessment.required_yes_ratio
+                && ratio > self.constitution.min_supermajority_floor + self.constitution.amendment_rules.supermajority_margin
+        } else {
+            ratio > assessment.required_yes_ratio
+        };
+
+        let timelock = self.constitution.amendment_rules.timelock_heights;
+        let record = self.amendments.get_mut(amendment_id).expect("checked above");
+        record.assessment = assessment;
+        if passed {
+            record.status = AmendmentStatus::Approved {
+                approved_height: current_height,
+                effective_height: current_height.saturating_add(timelock),
+            };
+            self.event_log.append(
+                &self.constitution,
+                Some(current_height),
+                GovernanceEvent::AmendmentStatusChanged {
+                    amendment_id: amendment_id.to_string(),
+                    status: record.status.clone(),
+                },
+            );
+        }
+        Ok(record.status.clone())
+    }
+
+    /// Cancel a time-locked amendment with a counter-proposal that met the supermajority floor.
+    pub fn cancel_amendment(
+        &mut self,
+        cancellation: &AmendmentCancellation,
+        outcome: &GovernanceVoteOutcome,
+        current_height: u64,
+    ) -> Result<AmendmentStatus, String> {
+        if outcome.proposal_id != cancellation.counter_proposal_id {
+            return Err(format!(
+                "Vote outcome is for {}, not counter-proposal {}",
+                outcome.proposal_id, cancellation.counter_proposal_id
+            ));
+        }
+        if outcome.finalized_height > current_height {
+            return Err("Vote outcome finalized in the future".into());
+        }
+        let floor = self.constitution.min_supermajority_floor;
+        let record = self
+            .amendments
+            .get_mut(&cancellation.cancels_amendment_id)
+            .ok_or_else(|| "Unknown amendment".to_string())?;
+        match record.status {
+            AmendmentStatus::Approved { effective_height, .. } if current_height < effective_height => {}
+            _ => return Err("Only approved amendments still inside their time-lock can be cancelled".into()),
+        }
+        if yes_ratio(outcome).unwrap_or(0.0) < floor {
+            return Err("Counter-proposal did not meet min_supermajority_floor".into());
+        }
+        record.status = AmendmentStatus::Cancelled {
+            counter_proposal_id: cancellation.counter_proposal_id.clone(),
+            height: current_height,
+        };
+        self.event_log.append(
+            &self.constitution,
+            Some(current_height),
+            GovernanceEvent::AmendmentStatusChanged {
+                amendment_id: cancellation.cancels_amendment_id.clone(),
+                status: record.status.clone(),
+            },
+        );
+        Ok(record.status.clone())
+    }
+
+    /// Approved amendments still waiting out their time-lock.
+    pub fn pending_amendments(&self) -> Vec<&AmendmentRecord> {
+        let mut pending: Vec<&AmendmentRecord> = self
+            .amendments
+            .values()
+            .filter(|r| matches!(r.status, AmendmentStatus::Approved { .. }))
+            .collect();
+        pending.sort_by(|a, b| a.amendment.amendment_id.cmp(&b.amendment.amendment_id));
+        pending
+    }
+
+    /// Apply every approved amendment whose time-lock has elapsed, in order of
+    /// effective height (ties by id). One whose amended constitution fails
+    /// `validate_amended` is marked `Rejected` instead and left out of the result.
+    pub fn apply_due_amendments(&mut self, current_height: u64) -> Vec<AppliedAmendment> {
+        let mut due: Vec<(u64, String)> = self
+            .amendments
+            .values()
+            .filter_map(|r| match r.status {
+                AmendmentStatus::Approved { effective_height, .. } if effective_height <= current_height => {
+                    Some((effective_height, r.amendment.amendment_id.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        due.sort();
+
+        let mut applied = Vec::new();
+        for (_, amendment_id) in due {
+            let mut amended = self.constitution.clone();
+            for change in &self.amendments[&amendment_id].amendment.changes {
+                change.apply_to(&mut amended);
+            }
+            if let Err(reason) = self.validate_amended(&amended) {
+                let record = self.amendments.get_mut(&amendment_id).expect("id from map");
+                record.status = AmendmentStatus::Rejected { height: current_height, reason };
+                self.event_log.append(
+                    &self.constitution,
+                    Some(current_height),
+                    GovernanceEvent::AmendmentStatusChanged { amendment_id, status: record.status.clone() },
+                );
+                continue;
+            }
+            let record = self.amendments.get_mut(&amendment_id).expect("id from map");
+            let before_hash = self.constitution.canonical_hash();
+            self.constitution = amended;
+            record.status = AmendmentStatus::Applied { height: current_height };
+            let entry = AppliedAmendment {
+                amendment_id,
+                applied_height: current_height,
+                before_hash,
+                after_hash: self.constitution.canonical_hash(),
+            };
+            self.amendment_history.push(entry.clone());
+            self.event_log.append(
+                &self.constitution,
+                Some(current_height),
+                GovernanceEvent::AmendmentApplied { applied: entry.clone() },
+            );
+            applied.push(entry);
+        }
+        applied
+    }
+
+    /// Whether `amended` may replace the constitution: ratios within 0..=1, and every domain's
+    /// current state within its floors, with no disabled capability made non-restrictable.
+    pub fn validate_amended(&self, amended: &GovernanceConstitution) -> Result<(), String> {
+        let mut ratios = vec![
+            ("max_restriction_fraction_per_turn", amended.max_restriction_fraction_per_turn),
+            ("min_supermajority_floor", amended.min_supermajority_floor),
+            ("protected_override_supermajority", amended.protected_override_supermajority),
+            ("amendment_rules.supermajority_margin", amended.amendment_rules.supermajority_margin),
+            ("amendment_rules.weakening_supermajority", amended.amendment_rules.weakening_supermajority),
+        ];
+        ratios.extend(amended.max_cumulative_disabled_fraction.map(|f| ("max_cumulative_disabled_fraction", f)));
+        ratios.extend(amended.per_capability_thresholds.values().map(|t| ("per_capability_thresholds", *t)));
+        ratios.extend(amended.per_namespace_thresholds.values().map(|t| ("per_namespace_thresholds", *t)));
+        if let Some((field, value)) = ratios.into_iter().find(|(_, v)| !(0.0..=1.0).contains(v)) {
+            return Err(format!("{field} of {value} is outside 0..=1"));
+        }
+        let mut domain_ids: Vec<&String> = self.domains.keys().collect();
+        domain_ids.sort();
+        for domain_id in domain_ids {
+            let state = &self.domains[domain_id];
+            let allowed = &state.domain.allowed_capabilities;
+            let enabled = allowed.len() - state.disabled_capabilities.iter().filter(|c| allowed.contains(*c)).count();
+            if enabled < amended.global_min_capability_floor {
+                return Err(format!(
+                    "Domain {domain_id} has {enabled} capabilities enabled, below the amended global floor of {}",
+                    amended.global_min_capability_floor
+                ));
+            }
+            let mut disabled: Vec<&CapabilityId> = state.disabled_capabilities.iter().collect();
+            disabled.sort();
+            if let Some(cap) = disabled.into_iter().find(|c| amended.is_nonrestrictable(c)) {
+                return Err(format!("Domain {domain_id} disables {cap}, which the amendment makes non-restrictable"));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn amendment_history(&self) -> &[AppliedAmendment] {
+        &self.amendment_history
+    }
+
+    pub fn constitution(&self) -> &GovernanceConstitution {
+        &self.constitution
+    }
+}
@@ -0,0 +1,300 @@
+// path: cybernetic-governance/src/appeal.rs
+
+//! Appeals by affected competitors against restrictions a governance turn applied.
+//! - Only capabilities currently disabled in the domain can be appealed, and only within
+//!   `appeal_window_heights` of the change that disabled them; a window of 0 disables appeals
+//! - A second filing by the same appellant against the same change is merged into the open appeal
+//! - Only the constitution's `appeal_panel` may review; an upheld appeal with `reinstate`
+//!   re-enables the capability and records an `AppealReinstated` change
+//! - Filing, merging and review are all appended to the event log
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+
+use crate::{AppliedChange, CapabilityGovernance, CapabilityId, ChangeKind, GovernanceEvent, TallySource};
+
+/// Why the appellant says the restriction should not stand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AppealGrounds {
+    /// The turn that applied the restriction did not follow the rules.
+    Procedural,
+    /// The restriction hits affected competitors much harder than its impact statement said.
+    DisproportionateImpact,
+    /// The capability is an accessibility or assistive need of the appellant.
+    AccessibilityNeed,
+    Other(String),
+}
+
+/// A panel member's decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AppealVerdict {
+    /// `reinstate` re-enables the capability; without it the finding is recorded only.
+    Upheld { reinstate: bool },
+    Denied { rationale: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AppealDecision {
+    pub reviewer: String,
+    pub verdict: AppealVerdict,
+    pub decided_height: u64,
+    /// Whether the decision re-enabled the capability. An upheld appeal reinstates nothing
+    /// once the restriction has lapsed or been replaced by a later proposal's.
+    pub reinstated: bool,
+}
+
+/// One appellant's appeal against one restriction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CapabilityAppeal {
+    pub appeal_id: String,
+    pub domain_id: String,
+    pub capability: CapabilityId,
+    pub appellant: String,
+    /// Proposal whose change disabled the capability.
+    pub proposal_id: String,
+    /// Height of that change; the appeal window counts from here.
+    pub change_height: u64,
+    /// Grounds from every merged filing, first filing first.
+    pub grounds: Vec<AppealGrounds>,
+    pub evidence_refs: Vec<String>,
+    pub filed_height: u64,
+    /// Number of filings merged into this appeal.
+    pub filings: u32,
+    /// `None` while the appeal awaits review.
+    #[serde(default)]
+    pub decision: Option<AppealDecision>,
+}
+
+impl CapabilityAppeal {
+    pub fn is_pending(&self) -> bool {
+        self.decision.is_none()
+    }
+}
+
+/// Every appeal filed with the engine, in filing order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AppealRegistry {
+    appeals: Vec<CapabilityAppeal>,
+}
+
+impl AppealRegistry {
+    pub fn get(&self, appeal_id: &str) -> Option<&CapabilityAppeal> {
+        self.appeals.iter().find(|a| a.appeal_id == appeal_id)
+    }
+
+    pub fn appeals(&self) -> &[CapabilityAppeal] {
+        &self.appeals
+    }
+
+    pub fn pending(&self) -> impl Iterator<Item = &CapabilityAppeal> {
+        self.appeals.iter().filter(|a| a.is_pending())
+    }
+
+    pub fn for_domain<'a>(&'a self, domain_id: &'a str) -> impl Iterator<Item = &'a CapabilityAppeal> + 'a {
+        self.appeals.iter().filter(move |a| a.domain_id == domain_id)
+    }
+}
+
+impl CapabilityGovernance {
+    /// File an appeal against the restriction of `capability` in `domain_id`. Filing again
+    /// against the same change while the first appeal is open adds the new grounds and
+    /// evidence to it instead; `filings` counts how many were merged.
+    pub fn file_appeal(
+        &mut self,
+        domain_id: &str,
+        capability: &CapabilityId,
+        appellant: &str,
+        grounds: AppealGrounds,
+        evidence_refs: Vec<String>,
+        current_height: u64,
+    ) -> Result<&CapabilityAppeal, String> {
+        self.check_height(current_height)?;
+        let window = self.constitution.appeal_window_heights;
+        if window == 0 {
+            return Err("Constitution does not allow appeals".into());
+        }
+        if appellant.trim().is_empty() {
+            return Err("Appeal must name the appellant".into());
+        }
+        if evidence_refs.iter().any(|r| r.trim().is_empty()) {
+            return Err("Evidence references must not be empty".into());
+        }
+        let state = self
+            .domains
+            .get(domain_id)
+            .ok_or_else(|| format!("Unknown domain_id: {domain_id}"))?;
+        if !state.disabled_capabilities.contains(capability) {
+            return Err(format!("Capability {} is not disabled in domain {domain_id}", capability.0));
+        }
+        // The latest proposal to disable the capability is the one keeping it disabled.
+        let (proposal_id, change_height) = self
+            .change_history(domain_id)
+            .iter()
+            .rev()
+            .find(|c| c.kind == ChangeKind::Proposal && c.newly_disabled.contains(capability))
+            .map(|c| (c.proposal_id.clone(), c.height))
+            .ok_or_else(|| format!("No recorded change disabled {} in domain {domain_id}", capability.0))?;
+        let closes = change_height.saturating_add(window);
+        if current_height > closes {
+            return Err(format!("Appeal window for {proposal_id} closed at height {closes}"));
+        }
+
+        let existing = self.appeals.appeals.iter().position(|a| {
+            a.domain_id == domain_id
+                && &a.capability == capability
+                && a.appellant == appellant
+                && a.proposal_id == proposal_id
+                && a.change_height == change_height
+        });
+        let index = match existing {
+            Some(index) => {
+                let appeal = &mut self.appeals.appeals[index];
+                if !appeal.is_pending() {
+                    return Err(format!("Appeal {} against this change was already decided", appeal.appeal_id));
+                }
+                if !appeal.grounds.contains(&grounds) {
+                    appeal.grounds.push(grounds);
+                }
+                for evidence in evidence_refs {
+                    if !appeal.evidence_refs.contains(&evidence) {
+                        appeal.evidence_refs.push(evidence);
+                    }
+                }
+                appeal.filings += 1;
+                self.event_log.append(
+                    &self.constitution,
+                    Some(current_height),
+                    GovernanceEvent::AppealMerged { appeal: appeal.clone() },
+                );
+                index
+            }
+            None => {
+                let mut evidence = Vec::new();
+                for r in evidence_refs {
+                    if !evidence.contains(&r) {
+                        evidence.push(r);
+                    }
+                }
+                let appeal = CapabilityAppeal {
+                    appeal_id: format!("appeal-{}", self.appeals.appeals.len() + 1),
+                    domain_id: domain_id.to_string(),
+                    capability: capability.clone(),
+                    appellant: appellant.to_string(),
+                    proposal_id,
+                    change_height,
+                    grounds: vec![grounds],
+                    evidence_refs: evidence,
+                    filed_height: current_height,
+                    filings: 1,
+                    decision: None,
+                };
+                self.event_log.append(
+                    &self.constitution,
+                    Some(current_height),
+                    GovernanceEvent::AppealFiled { appeal: appeal.clone() },
+                );
+                self.appeals.appeals.push(appeal);
+                self.appeals.appeals.len() - 1
+            }
+        };
+        Ok(&self.appeals.appeals[index])
+    }
+
+    /// Decide a pending appeal. The reviewer must sit on the constitution's `appeal_panel`
+    /// and may not decide their own appeal.
+    pub fn review_appeal(
+        &mut self,
+        appeal_id: &str,
+        reviewer: &str,
+        verdict: AppealVerdict,
+        current_height: u64,
+    ) -> Result<&CapabilityAppeal, String> {
+        self.check_height(current_height)?;
+        if !self.constitution.appeal_panel.contains(reviewer) {
+            return Err(format!("{reviewer} is not on the appeal review panel"));
+        }
+        let index = self
+            .appeals
+            .appeals
+            .iter()
+            .position(|a| a.appeal_id == appeal_id)
+            .ok_or_else(|| format!("Unknown appeal_id: {appeal_id}"))?;
+        let appeal = &self.appeals.appeals[index];
+        if !appeal.is_pending() {
+            return Err(format!("Appeal {appeal_id} was already decided"));
+        }
+        if appeal.appellant == reviewer {
+            return Err(format!("{reviewer} cannot review their own appeal"));
+        }
+        if let AppealVerdict::Denied { rationale } = &verdict {
+            if rationale.trim().is_empty() {
+                return Err("A denial must give a rationale".into());
+            }
+        }
+
+        let reinstatement = match verdict {
+            AppealVerdict::Upheld { reinstate: true } => self.reinstate(index, current_height),
+            _ => None,
+        };
+        let appeal = &mut self.appeals.appeals[index];
+        appeal.decision = Some(AppealDecision {
+            reviewer: reviewer.to_string(),
+            verdict,
+            decided_height: current_height,
+            reinstated: reinstatement.is_some(),
+        });
+        let appeal = appeal.clone();
+        if let Some(change) = &reinstatement {
+            self.history.entry(change.domain_id.clone()).or_default().push(change.clone());
+        }
+        self.event_log.append(
+            &self.constitution,
+            Some(current_height),
+            GovernanceEvent::AppealReviewed { appeal, reinstatement: reinstatement.map(Box::new) },
+        );
+        Ok(&self.appeals.appeals[index])
+    }
+
+    /// Re-enable the appealed capability if the appealed proposal still holds it disabled.
+    fn reinstate(&mut self, index: usize, current_height: u64) -> Option<AppliedChange> {
+        let appeal = &self.appeals.appeals[index];
+        let state = self.domains.get_mut(&appeal.domain_id)?;
+        let cap = &appeal.capability;
+        if !state.disabled_capabilities.contains(cap)
+            || state.disabled_by.get(cap) != Some(&appeal.proposal_id)
+        {
+            return None;
+        }
+        state.disabled_capabilities.remove(cap);
+        state.disabled_until.remove(cap);
+        state.disabled_by.remove(cap);
+        Some(AppliedChange {
+            domain_id: appeal.domain_id.clone(),
+            proposal_id: appeal.proposal_id.clone(),
+            kind: ChangeKind::AppealReinstated,
+            height: current_height,
+            tally_source: TallySource::default(),
+            newly_disabled: HashSet::new(),
+            skipped_due_to_constitution: HashSet::new(),
+            skipped_due_to_protection: HashSet::new(),
+            skipped_due_to_threshold: HashSet::new(),
+            protection_conflicts: HashSet::new(),
+            re_enabled: [cap.clone()].into_iter().collect(),
+            newly_protected: HashSet::new(),
+            unrecognized: HashSet::new(),
+            oracle_review: None,
+            impact_statement: None,
+            constitution_fingerprint: Some(self.constitution.canonical_hash()),
+        })
+    }
+
+    /// Appeals filed so far.
+    pub fn appeals(&self) -> &AppealRegistry {
+        &self.appeals
+    }
+}
@@ -0,0 +1,350 @@
+// path: cybernetic-governance/src/builders.rs
+
+//! Fluent builders and fixtures for tests, behind the `test-util` feature.
+//! - `ConstitutionBuilder` starts from the permissive arena constitution (floor 3, 40% per
+//!   turn, 0.67 supermajority floor, `safety:emergency_stop` nonrestrictable)
+//! - `DomainBuilder` and `ProposalBuilder` start empty and well-formed; `build_valid` panics
+//!   unless the engine would accept the result (`validate_categories`, `validate_proposal`)
+//! - Nothing here draws ids or reads a clock: heights and ids are whatever the caller sets
+//! - `bci_xr_arena` reproduces the engine of the example of that name before its first vote
+
+use std::collections::{HashMap, HashSet};
+
+use crate::categories::default_catch_all_category;
+use crate::{
+    AmendmentRules, CapabilityGovernance, CapabilityId, CategoryId, CompetitiveDomain, GovernanceConstitution,
+    GovernanceProposal, GovernanceVoteOutcome, ImpactStatement, ThresholdMode,
+};
+
+/// Capability id from a fixture literal; panics on a malformed id.
+pub fn cap(id: &str) -> CapabilityId {
+    CapabilityId::new(id).expect("fixture capability id is valid")
+}
+
+pub fn caps(ids: &[&str]) -> HashSet<CapabilityId> {
+    ids.iter().map(|id| cap(id)).collect()
+}
+
+/// Builds a `GovernanceConstitution`. Constitutions have no validation of their own, so there
+/// is no `build_valid`.
+#[derive(Debug, Clone)]
+pub struct ConstitutionBuilder {
+    constitution: GovernanceConstitution,
+}
+
+impl Default for ConstitutionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConstitutionBuilder {
+    pub fn new() -> Self {
+        Self {
+            constitution: GovernanceConstitution {
+                global_min_capability_floor: 3,
+                max_restriction_fraction_per_turn: 0.40,
+                max_cumulative_disabled_fraction: None,
+                min_supermajority_floor: 0.67,
+                hard_protect_safety_capabilities: true,
+                globally_nonrestrictable: caps(&["safety:emergency_stop"]),
+                nonrestrictable_namespaces: HashSet::new(),
+                protected_override_supermajority: 0.90,
+                max_outcome_age_heights: None,
+                allow_oracle_override: false,
+                per_capability_thresholds: HashMap::new(),
+                per_namespace_thresholds: HashMap::new(),
+                threshold_mode: ThresholdMode::default(),
+                referees: HashSet::new(),
+                max_match_override_heights: 0,
+                amendment_rules: AmendmentRules::default(),
+                require_impact_statement: false,
+                impact_estimate_factor: 2.0,
+                appeal_panel: HashSet::new(),
+                appeal_window_heights: 0,
+            },
+        }
+    }
+
+    pub fn global_min_capability_floor(mut self, floor: usize) -> Self {
+        self.constitution.global_min_capability_floor = floor;
+        self
+    }
+
+    pub fn max_restriction_fraction_per_turn(mut self, fraction: f64) -> Self {
+        self.constitution.max_restriction_fraction_per_turn = fraction;
+        self
+    }
+
+    pub fn max_cumulative_disabled_fraction(mut self, fraction: f64) -> Self {
+        self.constitution.max_cumulative_disabled_fraction = Some(fraction);
+        self
+    }
+
+    pub fn min_supermajority_floor(mut self, ratio: f64) -> Self {
+        self.constitution.min_supermajority_floor = ratio;
+        self
+    }
+
+    /// Replaces the nonrestrictable set.
+    pub fn nonrestrictable(mut self, ids: &[&str]) -> Self {
+        self.constitution.globally_nonrestrictable = caps(ids);
+        self
+    }
+
+    /// Replaces the non-restrictable namespaces.
+    pub fn nonrestrictable_namespaces(mut self, namespaces: &[&str]) -> Self {
+        self.constitution.nonrestrictable_namespaces = namespaces.iter().map(|ns| ns.to_string()).collect();
+        self
+    }
+
+    pub fn max_outcome_age_heights(mut self, heights: u64) -> Self {
+        self.constitution.max_outcome_age_heights = Some(heights);
+        self
+    }
+
+    pub fn capability_threshold(mut self, id: &str, ratio: f64) -> Self {
+        self.constitution.per_capability_thresholds.insert(cap(id), ratio);
+        self
+    }
+
+    pub fn namespace_threshold(mut self, prefix: &str, ratio: f64) -> Self {
+        self.constitution.per_namespace_thresholds.insert(prefix.into(), ratio);
+        self
+    }
+
+    /// Referees allowed to issue match overrides lasting up to `max_heights`.
+    pub fn referees(mut self, referees: &[&str], max_heights: u64) -> Self {
+        self.constitution.referees = referees.iter().map(|r| r.to_string()).collect();
+        self.constitution.max_match_override_heights = max_heights;
+        self
+    }
+
+    /// Panel deciding appeals filed up to `window_heights` after a restriction.
+    pub fn appeal_panel(mut self, panel: &[&str], window_heights: u64) -> Self {
+        self.constitution.appeal_panel = panel.iter().map(|p| p.to_string()).collect();
+        self.constitution.appeal_window_heights = window_heights;
+        self
+    }
+
+    pub fn require_impact_statement(mut self) -> Self {
+        self.constitution.require_impact_statement = true;
+        self
+    }
+
+    pub fn amendment_rules(mut self, rules: AmendmentRules) -> Self {
+        self.constitution.amendment_rules = rules;
+        self
+    }
+
+    pub fn build(self) -> GovernanceConstitution {
+        self.constitution
+    }
+}
+
+/// Builds a `CompetitiveDomain`, uncategorized unless `category` is called.
+#[derive(Debug, Clone)]
+pub struct DomainBuilder {
+    domain: CompetitiveDomain,
+}
+
+impl DomainBuilder {
+    pub fn new(id: &str) -> Self {
+        Self {
+            domain: CompetitiveDomain {
+                id: id.into(),
+                description: format!("Fixture domain {id}"),
+                allowed_capabilities: HashSet::new(),
+                min_capability_count: 0,
+                capability_categories: HashMap::new(),
+                category_floors: HashMap::new(),
+            },
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.domain.description = description.into();
+        self
+    }
+
+    /// Adds `ids` to the allowed capabilities.
+    pub fn allow(mut self, ids: &[&str]) -> Self {
+        self.domain.allowed_capabilities.extend(ids.iter().map(|id| cap(id)));
+        self
+    }
+
+    pub fn min_capability_count(mut self, count: usize) -> Self {
+        self.domain.min_capability_count = count;
+        self
+    }
+
+    /// Allows `ids` and files them under `category`.
+    pub fn category(mut self, category: &str, ids: &[&str]) -> Self {
+        for id in ids {
+            self.domain.allowed_capabilities.insert(cap(id));
+            self.domain.capability_categories.insert(cap(id), CategoryId(category.into()));
+        }
+        self
+    }
+
+    pub fn category_floor(mut self, category: &str, floor: usize) -> Self {
+        self.domain.category_floors.insert(CategoryId(category.into()), floor);
+        self
+    }
+
+    pub fn build(self) -> CompetitiveDomain {
+        self.domain
+    }
+
+    /// `build`, panicking unless `upsert_domain` would accept the domain under the default
+    /// catch-all category.
+    pub fn build_valid(self) -> CompetitiveDomain {
+        let domain = self.build();
+        if let Err(e) = domain.validate_categories(&default_catch_all_category()) {
+            panic!("fixture domain is invalid: {e}");
+        }
+        domain
+    }
+}
+
+/// Builds a permanent `GovernanceProposal` needing a 0.75 supermajority from height 100.
+#[derive(Debug, Clone)]
+pub struct ProposalBuilder {
+    proposal: GovernanceProposal,
+}
+
+impl ProposalBuilder {
+    pub fn new(proposal_id: &str, domain_id: &str) -> Self {
+        Self {
+            proposal: GovernanceProposal {
+                proposal_id: proposal_id.into(),
+                domain_id: domain_id.into(),
+                target: None,
+                restrict_capabilities: HashSet::new(),
+                protect_capabilities: HashSet::new(),
+                required_supermajority: 0.75,
+                activation_height: 100,
+                sunset_height: None,
+                capability_sunsets: HashMap::new(),
+                impact_statement: None,
+            },
+        }
+    }
+
+    pub fn restrict(mut self, ids: &[&str]) -> Self {
+        self.proposal.restrict_capabilities.extend(ids.iter().map(|id| cap(id)));
+        self
+    }
+
+    pub fn protect(mut self, ids: &[&str]) -> Self {
+        self.proposal.protect_capabilities.extend(ids.iter().map(|id| cap(id)));
+        self
+    }
+
+    pub fn required_supermajority(mut self, ratio: f64) -> Self {
+        self.proposal.required_supermajority = ratio;
+        self
+    }
+
+    pub fn activation_height(mut self, height: u64) -> Self {
+        self.proposal.activation_height = height;
+        self
+    }
+
+    pub fn sunset_height(mut self, height: u64) -> Self {
+        self.proposal.sunset_height = Some(height);
+        self
+    }
+
+    pub fn capability_sunset(mut self, id: &str, height: u64) -> Self {
+        self.proposal.capability_sunsets.insert(cap(id), height);
+        self
+    }
+
+    pub fn impact_statement(mut self, statement: ImpactStatement) -> Self {
+        self.proposal.impact_statement = Some(statement);
+        self
+    }
+
+    pub fn build(self) -> GovernanceProposal {
+        self.proposal
+    }
+
+    /// `build`, panicking unless `validate_proposal` accepts it under the `ConstitutionBuilder`
+    /// defaults.
+    pub fn build_valid(self) -> GovernanceProposal {
+        self.build_valid_under(&CapabilityGovernance::new(ConstitutionBuilder::new().build()))
+    }
+
+    /// `build`, panicking unless `gov.validate_proposal` accepts it.
+    pub fn build_valid_under(self, gov: &CapabilityGovernance) -> GovernanceProposal {
+        let proposal = self.build();
+        if let Err(reason) = gov.validate_proposal(&proposal) {
+            panic!("fixture proposal {} is invalid: {reason}", proposal.proposal_id);
+        }
+        proposal
+    }
+}
+
+pub fn vote(proposal_id: &str, yes_weight: u128, no_weight: u128, finalized_height: u64) -> GovernanceVoteOutcome {
+    GovernanceVoteOutcome {
+        proposal_id: proposal_id.into(),
+        yes_weight,
+        no_weight,
+        finalized_height,
+        participation: None,
+    }
+}
+
+/// Domain of the `bci_xr_arena` example.
+pub const BCI_XR_ARENA: &str = "arena:phoenix:bci_xr_championship";
+
+/// Constitution of the `bci_xr_arena` example: four nonrestrictable capabilities, research
+/// restrictions at 0.90 and one referee.
+pub fn bci_xr_arena_constitution() -> GovernanceConstitution {
+    ConstitutionBuilder::new()
+        .global_min_capability_floor(4)
+        .nonrestrictable(&[
+            "safety:emergency_stop",
+            "safety:session_exit",
+            "access:baseline_play",
+            "research:noninvasive_bci",
+        ])
+        .max_outcome_age_heights(5_000)
+        .namespace_threshold("research:", 0.90)
+        .referees(&["referee:phoenix-01"], 300)
+        .build()
+}
+
+pub fn bci_xr_arena_domain() -> CompetitiveDomain {
+    DomainBuilder::new(BCI_XR_ARENA)
+        .description("Phoenix BCI/XR competitive cybernetic arena")
+        .allow(&[
+            "safety:emergency_stop",
+            "safety:session_exit",
+            "access:baseline_play",
+            "research:noninvasive_bci",
+            "move:bci_push",
+            "move:bci_pull",
+            "move:bci_shield",
+        ])
+        .min_capability_count(5)
+        .build_valid()
+}
+
+/// The `bci_xr_arena` example's engine with its domain upserted, before any proposal.
+pub fn bci_xr_arena() -> CapabilityGovernance {
+    let mut gov = CapabilityGovernance::new(bci_xr_arena_constitution());
+    gov.upsert_domain(bci_xr_arena_domain()).expect("arena domain is valid");
+    gov
+}
+
+/// The example's first proposal and its 80% vote: three moves plus a nonrestrictable
+/// research capability.
+pub fn bci_xr_lockdown() -> (GovernanceProposal, GovernanceVoteOutcome) {
+    let proposal = ProposalBuilder::new("prop-2026-01-lockdown", BCI_XR_ARENA)
+        .restrict(&["move:bci_push", "move:bci_pull", "move:bci_shield", "research:noninvasive_bci"])
+        .activation_height(1_000)
+        .build_valid_under(&bci_xr_arena());
+    (proposal, vote("prop-2026-01-lockdown", 800, 200, 1_005))
+}
@@ -0,0 +1,132 @@
+// path: cybernetic-governance/src/categories.rs
+
+//! Capability categories and per-category floors.
+//! - A domain may sort its capabilities into categories (`move`, `safety`, ...) and give any
+//!   category a floor: the number of its capabilities that must stay enabled
+//! - Floors apply on top of `min_capability_count`, so a domain cannot keep its count by
+//!   disabling every move while leaving safety functions on
+//! - Domains from before categories list none; all their capabilities belong to the engine's
+//!   catch-all category (`set_catch_all_category`), which floors may name like any other
+//! - `upsert_domain` refuses a domain that categorizes some capabilities but not all, or whose
+//!   floors cannot be met with every capability enabled
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use crate::{CapabilityGovernance, CapabilityId, CompetitiveDomain};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CategoryId(pub String);
+
+impl fmt::Display for CategoryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Category capabilities of legacy domains fall into unless the engine names another.
+pub fn default_catch_all_category() -> CategoryId {
+    CategoryId("uncategorized".into())
+}
+
+/// Enabled capabilities of one category against its floor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CategoryCount {
+    pub category: CategoryId,
+    /// Capabilities the domain allows in this category.
+    pub allowed: usize,
+    pub enabled: usize,
+    /// 0 when the domain sets no floor for the category.
+    pub floor: usize,
+}
+
+impl CompetitiveDomain {
+    /// Category of `capability`; `catch_all` when the domain categorizes nothing.
+    pub fn category_of<'a>(&'a self, capability: &CapabilityId, catch_all: &'a CategoryId) -> &'a CategoryId {
+        self.capability_categories.get(capability).unwrap_or(catch_all)
+    }
+
+    /// Check that every allowed capability has a category, that only allowed capabilities are
+    /// categorized, and that every floor can be met with the whole category enabled.
+    pub fn validate_categories(&self, catch_all: &CategoryId) -> Result<(), String> {
+        if !self.capability_categories.is_empty() {
+            let mut uncategorized: Vec<&str> = self
+                .allowed_capabilities
+                .iter()
+                .filter(|c| !self.capability_categories.contains_key(*c))
+                .map(|c| c.0.as_str())
+                .collect();
+            if !uncategorized.is_empty() {
+                uncategorized.sort_unstable();
+                return Err(format!("Domain {} leaves uncategorized: {}", self.id, uncategorized.join(", ")));
+            }
+            let mut foreign: Vec<&str> = self
+                .capability_categories
+                .keys()
+                .filter(|c| !self.allowed_capabilities.contains(*c))
+                .map(|c| c.0.as_str())
+                .collect();
+            if !foreign.is_empty() {
+                foreign.sort_unstable();
+                let foreign = foreign.join(", ");
+                return Err(format!("Domain {} categorizes capabilities it does not allow: {foreign}", self.id));
+            }
+        }
+        let mut floors: Vec<(&CategoryId, &usize)> = self.category_floors.iter().collect();
+        floors.sort_unstable();
+        for (category, &floor) in floors {
+            let allowed = self
+                .allowed_capabilities
+                .iter()
+                .filter(|c| self.category_of(c, catch_all) == category)
+                .count();
+            if floor > allowed {
+                return Err(format!(
+                    "Domain {} sets a floor of {floor} for category {category}, which has only {allowed} capabilities",
+                    self.id
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Enabled count of every category with capabilities or a floor, sorted by category, with
+    /// `disabled` switched off.
+    pub fn category_counts(&self, disabled: &HashSet<CapabilityId>, catch_all: &CategoryId) -> Vec<CategoryCount> {
+        let mut counts: BTreeMap<&CategoryId, CategoryCount> = BTreeMap::new();
+        let entry = |category: &CategoryId| -> CategoryCount {
+            CategoryCount {
+                category: category.clone(),
+                allowed: 0,
+                enabled: 0,
+                floor: self.category_floors.get(category).copied().unwrap_or(0),
+            }
+        };
+        for cap in &self.allowed_capabilities {
+            let category = self.category_of(cap, catch_all);
+            let count = counts.entry(category).or_insert_with(|| entry(category));
+            count.allowed += 1;
+            if !disabled.contains(cap) {
+                count.enabled += 1;
+            }
+        }
+        for category in self.category_floors.keys() {
+            counts.entry(category).or_insert_with(|| entry(category));
+        }
+        counts.into_values().collect()
+    }
+}
+
+impl CapabilityGovernance {
+    /// Category the capabilities of domains without categories belong to.
+    pub fn set_catch_all_category(&mut self, category: CategoryId) {
+        self.catch_all_category = category;
+    }
+
+    pub fn catch_all_category(&self) -> &CategoryId {
+        &self.catch_all_category
+    }
+}
@@ -0,0 +1,280 @@
+// path: cybernetic-governance/src/commit_reveal.rs
+
+//! Commit-reveal voting, an optional mode of engine-collected votes.
+//! - Voters first commit to `commitment_hash(proposal, voter, support, salt)`, then reveal
+//!   `support` and `salt`; nobody sees a ballot until the commit window has closed
+//! - Windows are counted from the height source at opening: commits are taken below
+//!   `commit_until`, reveals from there until `reveal_until`, and the vote closes after
+//! - A commitment is the ballot's `VoteReceipt` leaf, so revealed ballots publish the
+//!   committed leaf and `VoteReceipt::new` with the same salt verifies inclusion
+//! - Unrevealed commitments are ignored or, under `UnrevealedPolicy::Abstain`, counted as
+//!   abstentions: they add to turnout, never to the yes/no ratio
+//! - `CommitRevealRules::min_turnout` sets a quorum, as a fraction of the snapshot's weight:
+//!   a vote whose turnout falls short does not pass, whatever its ratio
+//! - Plain `open_vote` sessions stay direct votes; `cast` and `cast_vote` refuse
+//!   commit-reveal sessions
+
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use steward_ids::receipts::VoteReceipt;
+
+use crate::{CapabilityGovernance, GovernanceProposal, GovernanceVoteOutcome, VoteSession, VoterId, VoterSnapshot};
+
+/// What closing a commit-reveal vote does with commitments never revealed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UnrevealedPolicy {
+    /// Not counted at all.
+    #[default]
+    Ignore,
+    /// Counted as abstentions: their weight adds to turnout only.
+    Abstain,
+}
+
+/// Window lengths, in heights, and the quorum for a commit-reveal vote.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CommitRevealRules {
+    pub commit_heights: u64,
+    pub reveal_heights: u64,
+    #[serde(default)]
+    pub unrevealed: UnrevealedPolicy,
+    /// Fraction (0..=1) of the snapshot's weight that must turn out for the vote to pass:
+    /// revealed ballots, plus unrevealed commitments under `Abstain`. `None` = no quorum.
+    #[serde(default)]
+    pub min_turnout: Option<f64>,
+}
+
+/// A commit-reveal vote's windows, fixed when it opens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CommitRevealWindow {
+    pub opened_at: u64,
+    /// First height at which commits are refused and reveals accepted.
+    pub commit_until: u64,
+    /// First height at which reveals are refused and the vote may close.
+    pub reveal_until: u64,
+    pub unrevealed: UnrevealedPolicy,
+    /// Turnout weight the vote needs to pass: `min_turnout` of the snapshot, rounded up.
+    #[serde(default)]
+    pub quorum_weight: u128,
+}
+
+/// Who took part in a commit-reveal vote, recorded on its outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoteParticipation {
+    pub committed: usize,
+    pub revealed: usize,
+    /// Weight of unrevealed commitments under `UnrevealedPolicy::Abstain`; 0 otherwise.
+    pub abstain_weight: u128,
+    /// The window's `quorum_weight`; the outcome passes only if `turnout_weight` reaches it.
+    #[serde(default)]
+    pub quorum_weight: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CommitRevealError {
+    NoVote,
+    /// The vote is a direct vote, or already closed.
+    NotOpenCommitReveal,
+    NotInSnapshot(VoterId),
+    NoHeightSource,
+    CommitWindowClosed { commit_until: u64, height: u64 },
+    DoubleCommit(VoterId),
+    RevealTooEarly { commit_until: u64, height: u64 },
+    RevealTooLate { reveal_until: u64, height: u64 },
+    NoCommitment(VoterId),
+    AlreadyRevealed(VoterId),
+    /// The revealed support and salt do not hash to the voter's commitment.
+    CommitmentMismatch(VoterId),
+}
+
+impl fmt::Display for CommitRevealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommitRevealError::NoVote => f.write_str("No open vote for this proposal"),
+            CommitRevealError::NotOpenCommitReveal => f.write_str("Vote is not an open commit-reveal vote"),
+            CommitRevealError::NotInSnapshot(voter) => write!(f, "Voter {} is not in the vote snapshot", voter.0),
+            CommitRevealError::NoHeightSource => f.write_str("No height source configured"),
+            CommitRevealError::CommitWindowClosed { commit_until, height } => {
+                write!(f, "Commit window closed at height {commit_until} (now {height})")
+            }
+            CommitRevealError::DoubleCommit(voter) => write!(f, "Voter {} has already committed", voter.0),
+            CommitRevealError::RevealTooEarly { commit_until, height } => {
+                write!(f, "Reveals open at height {commit_until} (now {height})")
+            }
+            CommitRevealError::RevealTooLate { reveal_until, height } => {
+                write!(f, "Reveal window closed at height {reveal_until} (now {height})")
+            }
+            CommitRevealError::NoCommitment(voter) => write!(f, "Voter {} has no commitment", voter.0),
+            CommitRevealError::AlreadyRevealed(voter) => write!(f, "Voter {} has already revealed", voter.0),
+            CommitRevealError::CommitmentMismatch(voter) => {
+                write!(f, "Reveal by {} does not match their commitment", voter.0)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommitRevealError {}
+
+/// The commitment a voter publishes for `support` under `salt`; the ballot's receipt leaf.
+pub fn commitment_hash(proposal_id: &str, voter: &VoterId, support: bool, salt: &str) -> String {
+    VoteReceipt::new(proposal_id, &voter.0, support, salt, 0).leaf()
+}
+
+impl GovernanceVoteOutcome {
+    /// Yes, no and abstain weight together, for quorum checks; `None` on overflow.
+    pub fn turnout_weight(&self) -> Option<u128> {
+        let abstain = self.participation.map_or(0, |p| p.abstain_weight);
+        self.yes_weight.checked_add(self.no_weight)?.checked_add(abstain)
+    }
+
+    /// Whether turnout reaches the commit-reveal quorum; always for direct votes. `None` on
+    /// overflow.
+    pub fn meets_quorum(&self) -> Option<bool> {
+        let quorum = self.participation.map_or(0, |p| p.quorum_weight);
+        Some(self.turnout_weight()? >= quorum)
+    }
+}
+
+impl CapabilityGovernance {
+    /// Open a commit-reveal vote whose windows start at the height source's current height.
+    pub fn open_commit_reveal_vote(
+        &mut self,
+        proposal: &GovernanceProposal,
+        snapshot: VoterSnapshot,
+        rules: CommitRevealRules,
+    ) -> Result<CommitRevealWindow, String> {
+        let opened_at = self.source_height()?;
+        let commit_until = opened_at
+            .checked_add(rules.commit_heights)
+            .ok_or_else(|| "Commit window overflows u64".to_string())?;
+        let reveal_until = commit_until
+            .checked_add(rules.reveal_heights)
+            .ok_or_else(|| "Reveal window overflows u64".to_string())?;
+        let quorum_weight = match rules.min_turnout {
+            None => 0,
+            Some(fraction) if !(0.0..=1.0).contains(&fraction) => {
+                return Err(format!("min_turnout {fraction} is outside 0..=1"));
+            }
+            Some(fraction) => {
+                let total = snapshot
+                    .weights
+                    .values()
+                    .try_fold(0u128, |sum, w| sum.checked_add(*w))
+                    .ok_or_else(|| "Vote tally overflowed u128".to_string())?;
+                ((total as f64) * fraction).ceil().min(total as f64) as u128
+            }
+        };
+        let window = CommitRevealWindow {
+            opened_at,
+            commit_until,
+            reveal_until,
+            unrevealed: rules.unrevealed,
+            quorum_weight,
+        };
+        self.open_vote(proposal, snapshot)?;
+        if let Some(session) = self.votes.get_mut(&proposal.proposal_id) {
+            session.commit_reveal = Some(window);
+        }
+        Ok(window)
+    }
+
+    /// Record `voter`'s commitment. One per voter, before `commit_until`.
+    pub fn commit_vote(
+        &mut self,
+        proposal_id: &str,
+        voter: &VoterId,
+        commitment: &str,
+    ) -> Result<(), CommitRevealError> {
+        let height = self.source_height().map_err(|_| CommitRevealError::NoHeightSource)?;
+        let (session, window) = self.commit_reveal_session(proposal_id, voter)?;
+        if height >= window.commit_until {
+            return Err(CommitRevealError::CommitWindowClosed { commit_until: window.commit_until, height });
+        }
+        if session.commitments.contains_key(voter) {
+            return Err(CommitRevealError::DoubleCommit(voter.clone()));
+        }
+        session.commitments.insert(voter.clone(), commitment.to_string());
+        Ok(())
+    }
+
+    /// Reveal `voter`'s ballot; counted only if it hashes to their commitment. Returns the
+    /// ballot's receipt, whose leaf is the commitment.
+    pub fn reveal_vote(
+        &mut self,
+        proposal_id: &str,
+        voter: &VoterId,
+        support: bool,
+        salt: &str,
+    ) -> Result<VoteReceipt, CommitRevealError> {
+        let height = self.source_height().map_err(|_| CommitRevealError::NoHeightSource)?;
+        let (session, window) = self.commit_reveal_session(proposal_id, voter)?;
+        if height < window.commit_until {
+            return Err(CommitRevealError::RevealTooEarly { commit_until: window.commit_until, height });
+        }
+        if height >= window.reveal_until {
+            return Err(CommitRevealError::RevealTooLate { reveal_until: window.reveal_until, height });
+        }
+        let commitment = session
+            .commitments
+            .get(voter)
+            .ok_or_else(|| CommitRevealError::NoCommitment(voter.clone()))?;
+        if session.ballots.contains_key(voter) {
+            return Err(CommitRevealError::AlreadyRevealed(voter.clone()));
+        }
+        let receipt = VoteReceipt::new(proposal_id, &voter.0, support, salt, height);
+        if receipt.leaf() != *commitment {
+            return Err(CommitRevealError::CommitmentMismatch(voter.clone()));
+        }
+        session.ballots.insert(voter.clone(), support);
+        session.ballot_leaves.insert(voter.clone(), receipt.leaf());
+        Ok(receipt)
+    }
+
+    fn commit_reveal_session(
+        &mut self,
+        proposal_id: &str,
+        voter: &VoterId,
+    ) -> Result<(&mut VoteSession, CommitRevealWindow), CommitRevealError> {
+        let session = self.votes.get_mut(proposal_id).ok_or(CommitRevealError::NoVote)?;
+        let window = match session.commit_reveal {
+            Some(window) if session.outcome.is_none() => window,
+            _ => return Err(CommitRevealError::NotOpenCommitReveal),
+        };
+        if !session.snapshot.weights.contains_key(voter) {
+            return Err(CommitRevealError::NotInSnapshot(voter.clone()));
+        }
+        Ok((session, window))
+    }
+}
+
+impl VoteSession {
+    /// Participation in a commit-reveal vote: revealed ballots, and unrevealed commitments
+    /// as the window's policy counts them.
+    pub(crate) fn participation(&self, window: &CommitRevealWindow) -> Result<VoteParticipation, String> {
+        let unrevealed = self.commitments.keys().filter(|voter| !self.ballots.contains_key(*voter));
+        let mut abstain_weight: u128 = 0;
+        if window.unrevealed == UnrevealedPolicy::Abstain {
+            for voter in unrevealed {
+                let weight = self
+                    .snapshot
+                    .weights
+                    .get(voter)
+                    .ok_or_else(|| format!("Voter {} is not in the vote snapshot", voter.0))?;
+                abstain_weight = abstain_weight
+                    .checked_add(*weight)
+                    .ok_or_else(|| "Vote tally overflowed u128".to_string())?;
+            }
+        }
+        Ok(VoteParticipation {
+            committed: self.commitments.len(),
+            revealed: self.ballots.len(),
+            abstain_weight,
+            quorum_weight: window.quorum_weight,
+        })
+    }
+}
@@ -0,0 +1,133 @@
+// path: cybernetic-governance/src/effective.rs
+
+//! "What can players actually do right now" for a domain.
+//! - Resolves disabled, protected and sunset state in one place
+//! - Borrows from the engine (no domain clones) so XR runtimes can call it per frame
+//! - Optionally layers a match's active referee overrides on top
+//! - Counts enabled capabilities per category, against each category's floor
+//! - Serializes directly for spectator APIs
+
+use serde::Serialize;
+use std::collections::HashSet;
+
+use crate::{CapabilityGovernance, CapabilityId, CategoryCount, MatchOverride};
+
+/// A capability players may use at the queried height.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EnabledCapability<'a> {
+    pub capability: &'a CapabilityId,
+    /// Proposal that explicitly protected this capability, if any.
+    pub protected_by: Option<&'a str>,
+}
+
+/// A capability switched off at the queried height.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DisabledCapability<'a> {
+    pub capability: &'a CapabilityId,
+    /// Proposal responsible for the restriction, if known.
+    pub disabled_by: Option<&'a str>,
+    /// Height at which the restriction lapses; `None` = permanent.
+    pub sunset_height: Option<u64>,
+    /// Referee override responsible, when disabled only for the queried match.
+    pub match_override: Option<&'a MatchOverride>,
+}
+
+/// Effective capability view of one domain at one height.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EffectiveCapabilities<'a> {
+    pub domain_id: &'a str,
+    pub height: u64,
+    /// Match whose overrides were applied, if any.
+    pub match_id: Option<&'a str>,
+    /// Enabled capabilities, sorted by id.
+    pub enabled: Vec<EnabledCapability<'a>>,
+    /// Disabled capabilities, sorted by id.
+    pub disabled: Vec<DisabledCapability<'a>>,
+    pub enabled_count: usize,
+    /// The domain's `min_capability_count`.
+    pub domain_floor: usize,
+    /// The constitution's `global_min_capability_floor`.
+    pub global_floor: usize,
+    /// Enabled capabilities per category, sorted by category.
+    pub categories: Vec<CategoryCount>,
+}
+
+impl EffectiveCapabilities<'_> {
+    /// How many more capabilities could be disabled before hitting the stricter floor.
+    pub fn headroom(&self) -> usize {
+        self.enabled_count
+            .saturating_sub(self.domain_floor.max(self.global_floor))
+    }
+}
+
+impl CapabilityGovernance {
+    /// Enabled and disabled capabilities of `domain_id` at `current_height`.
+    /// Restrictions whose sunset has been reached count as enabled even if
+    /// `advance_height` has not run yet. With `match_id`, that match's active
+    /// referee overrides are applied on top of the domain state.
+    pub fn effective_capabilities<'a>(
+        &'a self,
+        domain_id: &str,
+        current_height: u64,
+        match_id: Option<&'a str>,
+    ) -> Result<EffectiveCapabilities<'a>, String> {
+        let state = self
+            .domains
+            .get(domain_id)
+            .ok_or_else(|| format!("Unknown domain_id: {domain_id}"))?;
+
+        let overrides: Vec<&MatchOverride> = match match_id {
+            Some(m) => self
+                .match_overrides
+                .iter()
+                .filter(|o| o.match_id == m && o.domain_id == domain_id && o.is_active_at(current_height))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let mut enabled = Vec::new();
+        let mut disabled = Vec::new();
+        for cap in &state.domain.allowed_capabilities {
+            let sunset_height = state.disabled_until.get(cap).copied();
+            let lapsed = sunset_height.is_some_and(|h| h <= current_height);
+            if state.disabled_capabilities.contains(cap) && !lapsed {
+                disabled.push(DisabledCapability {
+                    capability: cap,
+                    disabled_by: state.disabled_by.get(cap).map(|p| p.as_str()),
+                    sunset_height,
+                    match_override: None,
+                });
+            } else if let Some(o) = overrides.iter().find(|o| o.disabled.contains(cap)) {
+                disabled.push(DisabledCapability {
+                    capability: cap,
+                    disabled_by: None,
+                    sunset_height: Some(o.valid_to_height),
+                    match_override: Some(o),
+                });
+            } else {
+                enabled.push(EnabledCapability {
+                    capability: cap,
+                    protected_by: state.protections.get(cap).map(|r| r.proposal_id.as_str()),
+                });
+            }
+        }
+        enabled.sort_unstable_by(|a, b| a.capability.0.cmp(&b.capability.0));
+        disabled.sort_unstable_by(|a, b| a.capability.0.cmp(&b.capability.0));
+        let off: HashSet<CapabilityId> = disabled.iter().map(|d| d.capability.clone()).collect();
+
+        Ok(EffectiveCapabilities {
+            domain_id: &state.domain.id,
+            height: current_height,
+            match_id,
+            enabled_count: enabled.len(),
+            enabled,
+            disabled,
+            domain_floor: state.domain.min_capability_count,
+            global_floor: self.constitution.global_min_capability_floor,
+            categories: state.domain.category_counts(&off, &self.catch_all_category),
+        })
+    }
+}
@@ -0,0 +1,197 @@
+// path: cybernetic-governance/src/element_bridge.rs
+
+//! Bridge from arena-level domain rules into `the_element` agent profiles
+//! (feature `the_element`).
+//! - Domain disables become element governance-turns, one per responsible proposal
+//! - Element baseline rights are never restricted: reported and skipped
+//! - Protection re-enables and sunset expirations are propagated as unlocks
+//! - Turns carry the time of the change behind them when the engine has a height-time mapper
+//! - Previews can check an impact statement's affected-agent estimate against the agents
+//!   the proposal would actually reach
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashSet};
+
+use the_element::{AgentId, GovernanceTurnId, TheElement};
+
+use crate::{CapabilityGovernance, CapabilityId, ChangeKind, GovernanceProposal, ProposalPreview};
+
+/// Both crates alias `steward_ids::CapabilityId`; kept for call-site clarity.
+pub fn to_element_capability(cap: &CapabilityId) -> the_element::CapabilityId {
+    cap.clone()
+}
+
+/// One governance-turn issued to an agent's profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BridgedTurn {
+    pub agent: AgentId,
+    pub turn_id: GovernanceTurnId,
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub restricted: HashSet<the_element::CapabilityId>,
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub unlocked: HashSet<the_element::CapabilityId>,
+    /// When the change behind the turn was committed, by the engine's height-time mapper;
+    /// omitted without one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_ms: Option<u64>,
+}
+
+/// What `apply_domain_to_agents` did.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BridgeReport {
+    pub turns: Vec<BridgedTurn>,
+    /// Domain restrictions on element baseline rights; never applied.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub skipped_baseline: HashSet<CapabilityId>,
+    /// Turns the element rejected (e.g. over its per-turn fraction), and change heights the
+    /// engine's mapper could not map.
+    pub errors: Vec<String>,
+}
+
+/// `CapabilityGovernance::preview`, warning when the impact statement's affected-agent
+/// estimate is off by more than the constitution's `impact_estimate_factor` from the number
+/// of `agents` currently able to use a capability the proposal would disable.
+pub fn preview_with_element(
+    gov: &CapabilityGovernance,
+    proposal: &GovernanceProposal,
+    element: &TheElement,
+    agents: &[AgentId],
+) -> Result<ProposalPreview, String> {
+    let mut preview = gov.preview(proposal)?;
+    let would_disable: HashSet<the_element::CapabilityId> = preview
+        .domains
+        .iter()
+        .flat_map(|d| d.would_disable.iter().map(to_element_capability))
+        .filter(|cap| !element.is_baseline(cap))
+        .collect();
+    let observed = agents
+        .iter()
+        .filter(|agent| would_disable.iter().any(|cap| element.can_use(agent, cap)))
+        .count() as u32;
+    if let Some(statement) = &preview.impact_statement {
+        preview
+            .warnings
+            .extend(statement.estimate_warning(observed, gov.constitution().impact_estimate_factor));
+    }
+    Ok(preview)
+}
+
+/// Mirror `domain_id`'s current restrictions onto each agent's profile.
+pub fn apply_domain_to_agents(
+    gov: &CapabilityGovernance,
+    domain_id: &str,
+    element: &mut TheElement,
+    agents: &[AgentId],
+) -> Result<BridgeReport, String> {
+    let state = gov
+        .get_domain_state(domain_id)
+        .ok_or_else(|| format!("Unknown domain_id: {domain_id}"))?;
+    let mut report = BridgeReport::default();
+
+    // Disables grouped by the proposal responsible for them.
+    let mut restrictions: BTreeMap<String, HashSet<the_element::CapabilityId>> = BTreeMap::new();
+    for cap in &state.disabled_capabilities {
+        let element_cap = to_element_capability(cap);
+        if element.is_baseline(&element_cap) {
+            report.skipped_baseline.insert(cap.clone());
+            continue;
+        }
+        let proposal_id = state
+            .disabled_by
+            .get(cap)
+            .cloned()
+            .unwrap_or_else(|| "unattributed".to_string());
+        restrictions.entry(proposal_id).or_default().insert(element_cap);
+    }
+
+    // Capabilities the domain re-enabled (protection, sunset or appeal) and still allows,
+    // keyed by the turn id of the latest change that re-enabled them.
+    let mut unlocks: BTreeMap<String, HashSet<the_element::CapabilityId>> = BTreeMap::new();
+    let mut heights: BTreeMap<String, u64> = BTreeMap::new();
+    let mut seen = HashSet::new();
+    for change in gov.change_history(domain_id).iter().rev() {
+        let suffix = match change.kind {
+            ChangeKind::SunsetExpired => "sunset",
+            ChangeKind::Proposal => "re-enable",
+            ChangeKind::AppealReinstated => "appeal",
+            ChangeKind::OracleVeto => continue,
+        };
+        for cap in &change.re_enabled {
+            if state.disabled_capabilities.contains(cap) || !seen.insert(cap.clone()) {
+                continue;
+            }
+            let turn = format!("domain:{domain_id}:{}:{suffix}", change.proposal_id);
+            heights.entry(turn.clone()).or_insert(change.height);
+            unlocks.entry(turn).or_default().insert(to_element_capability(cap));
+        }
+    }
+    for proposal_id in restrictions.keys() {
+        let latest = gov
+            .change_history(domain_id)
+            .iter()
+            .rev()
+            .find(|c| c.kind == ChangeKind::Proposal && &c.proposal_id == proposal_id);
+        if let Some(change) = latest {
+            heights.insert(format!("domain:{domain_id}:{proposal_id}"), change.height);
+        }
+    }
+    let mut timestamps: BTreeMap<String, u64> = BTreeMap::new();
+    if let Some(mapper) = gov.height_time_mapper() {
+        for (turn, height) in heights {
+            match mapper.time_at(height) {
+                Ok(ms) => {
+                    timestamps.insert(turn, ms);
+                }
+                Err(e) => report.errors.push(format!("{turn}: {e}")),
+            }
+        }
+    }
+
+    let empty = HashSet::new();
+    for agent in agents {
+        for (proposal_id, caps) in &restrictions {
+            let restrict: HashSet<_> = caps.iter().filter(|c| element.can_use(agent, c)).cloned().collect();
+            if restrict.is_empty() {
+                continue;
+            }
+            let turn_id = GovernanceTurnId(format!("domain:{domain_id}:{proposal_id}"));
+            match element.governance_turn(&turn_id, agent, &restrict, &empty) {
+                Ok(()) => report.turns.push(BridgedTurn {
+                    agent: agent.clone(),
+                    timestamp_ms: timestamps.get(&turn_id.0).copied(),
+                    turn_id,
+                    restricted: restrict,
+                    unlocked: HashSet::new(),
+                }),
+                Err(e) => report.errors.push(format!("{} / {}: {e}", agent.0, turn_id.0)),
+            }
+        }
+        for (turn, caps) in &unlocks {
+            // Agent self-blocks always win; leave those alone.
+            let blocked = element.get_profile(agent).map(|p| p.blocked_capabilities.clone());
+            let unlock: HashSet<_> = caps
+                .iter()
+                .filter(|c| !element.can_use(agent, c))
+                .filter(|c| !blocked.as_ref().is_some_and(|b| b.contains(*c)))
+                .cloned()
+                .collect();
+            if unlock.is_empty() {
+                continue;
+            }
+            let turn_id = GovernanceTurnId(turn.clone());
+            match element.governance_turn(&turn_id, agent, &empty, &unlock) {
+                Ok(()) => report.turns.push(BridgedTurn {
+                    agent: agent.clone(),
+                    timestamp_ms: timestamps.get(turn).copied(),
+                    turn_id,
+                    restricted: HashSet::new(),
+                    unlocked: unlock,
+                }),
+                Err(e) => report.errors.push(format!("{} / {}: {e}", agent.0, turn_id.0)),
+            }
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,212 @@
+// path: cybernetic-governance/src/eventlog.rs
+
+//! Append-only, hash-chained log of every governance state change.
+//! - Each entry carries `prev_hash` and a `self_hash` over canonical JSON
+//! - Each entry also carries the fingerprint of the constitution in force once it was made
+//! - Entries are appended by the same engine methods that mutate state
+//! - `verify_log()` pinpoints the first broken entry; `export_log_jsonl()` for auditors, and
+//!   `export_log` for logs too long to hold as one string (a `steward_ids::stream`)
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use steward_ids::stream::{self, Compression, StreamError, StreamManifest, StreamReader};
+
+use crate::amendment::sort_arrays;
+use crate::{
+    AmendmentStatus, AppliedAmendment, AppliedChange, CapabilityAppeal, CapabilityGovernance, CompetitiveDomain,
+    ExpiredRestriction, GovernanceConstitution, MatchOverride,
+};
+
+/// `prev_hash` of the first entry.
+pub const GENESIS_HASH: &str = "genesis";
+
+/// `steward_ids::stream` kind of `GovernanceEventLog::write_stream`.
+pub const EVENT_LOG_STREAM_KIND: &str = "governance.event_log";
+
+/// A state change recorded in the log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GovernanceEvent {
+    DomainUpserted { domain: CompetitiveDomain },
+    ProposalCommitted { changes: Vec<AppliedChange> },
+    OracleVetoed { vetoes: Vec<AppliedChange> },
+    SunsetsExpired { expired: Vec<ExpiredRestriction> },
+    AmendmentProposed { amendment_id: String },
+    AmendmentStatusChanged { amendment_id: String, status: AmendmentStatus },
+    AmendmentApplied { applied: AppliedAmendment },
+    MatchOverrideIssued { match_override: MatchOverride },
+    MatchOverridesExpired { expired: Vec<MatchOverride> },
+    AppealFiled { appeal: CapabilityAppeal },
+    /// A repeat filing merged into an open appeal; carries the appeal as merged.
+    AppealMerged { appeal: CapabilityAppeal },
+    /// Carries the appeal as decided and, when it re-enabled the capability, the change made.
+    AppealReviewed { appeal: CapabilityAppeal, reinstatement: Option<Box<AppliedChange>> },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GovernanceLogEntry {
+    pub seq: u64,
+    /// Height the change was made at; `None` for height-less operations (domain upserts).
+    pub height: Option<u64>,
+    pub event: GovernanceEvent,
+    /// `GovernanceConstitution::canonical_hash` after the event; `None` for entries recorded
+    /// before fingerprints existed, whose hashes do not cover one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constitution_fingerprint: Option<String>,
+    pub prev_hash: String,
+    pub self_hash: String,
+}
+
+impl GovernanceLogEntry {
+    /// Hash over the canonical form of everything except `self_hash`.
+    fn compute_hash(&self) -> String {
+        let mut value = serde_json::json!({
+            "seq": self.seq,
+            "height": self.height,
+            "event": self.event,
+            "prev_hash": self.prev_hash,
+        });
+        if let Some(fingerprint) = &self.constitution_fingerprint {
+            value["constitution_fingerprint"] = serde_json::json!(fingerprint);
+        }
+        sort_arrays(&mut value);
+        let payload = serde_json::to_vec(&value).expect("log entry serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&payload);
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// First entry that fails verification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LogVerifyError {
+    pub index: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for LogVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Event log entry {}: {}", self.index, self.reason)
+    }
+}
+
+impl std::error::Error for LogVerifyError {}
+
+/// Append-only event log. Only the engine can append.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GovernanceEventLog {
+    entries: Vec<GovernanceLogEntry>,
+}
+
+impl GovernanceEventLog {
+    pub(crate) fn append(
+        &mut self,
+        constitution: &GovernanceConstitution,
+        height: Option<u64>,
+        event: GovernanceEvent,
+    ) {
+        let prev_hash = self
+            .entries
+            .last()
+            .map(|e| e.self_hash.clone())
+            .unwrap_or_else(|| GENESIS_HASH.to_string());
+        let mut entry = GovernanceLogEntry {
+            seq: self.entries.len() as u64,
+            height,
+            event,
+            constitution_fingerprint: Some(constitution.canonical_hash()),
+            prev_hash,
+            self_hash: String::new(),
+        };
+        entry.self_hash = entry.compute_hash();
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[GovernanceLogEntry] {
+        &self.entries
+    }
+
+    /// Check sequence numbers, the hash chain and every self-hash.
+    pub fn verify(&self) -> Result<(), LogVerifyError> {
+        let mut prev_hash = GENESIS_HASH;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.seq != index as u64 {
+                return Err(LogVerifyError {
+                    index,
+                    reason: format!("sequence number {} out of order", entry.seq),
+                });
+            }
+            if entry.prev_hash != prev_hash {
+                return Err(LogVerifyError {
+                    index,
+                    reason: "prev_hash does not match previous entry".into(),
+                });
+            }
+            if entry.compute_hash() != entry.self_hash {
+                return Err(LogVerifyError {
+                    index,
+                    reason: "self_hash does not match entry contents".into(),
+                });
+            }
+            prev_hash = &entry.self_hash;
+        }
+        Ok(())
+    }
+
+    /// One JSON entry per line, in one string; see `write_stream` for long logs.
+    pub fn to_jsonl(&self) -> String {
+        self.entries
+            .iter()
+            .map(|e| serde_json::to_string(e).expect("log entry serialization"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Parse an exported log for offline verification.
+    pub fn from_jsonl(jsonl: &str) -> Result<Self, String> {
+        let entries = jsonl
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .enumerate()
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|e| format!("Line {}: {e}", i + 1))
+            })
+            .collect::<Result<Vec<GovernanceLogEntry>, String>>()?;
+        Ok(Self { entries })
+    }
+
+    /// The entries, written one at a time, as a stream of `EVENT_LOG_STREAM_KIND`.
+    pub fn write_stream<W: Write>(&self, sink: W, compression: Compression) -> io::Result<StreamManifest> {
+        stream::write_stream(sink, EVENT_LOG_STREAM_KIND, compression, &self.entries)
+    }
+
+    /// Read a log `write_stream` wrote, for offline verification. The stream must end in the
+    /// manifest it promised; the entries' own hash chain is left to `verify`.
+    pub fn read_stream<R: BufRead>(source: R) -> Result<Self, StreamError> {
+        let records = StreamReader::open(source, EVENT_LOG_STREAM_KIND)?.records();
+        Ok(Self { entries: records.collect::<Result<_, _>>()? })
+    }
+}
+
+impl CapabilityGovernance {
+    pub fn event_log(&self) -> &GovernanceEventLog {
+        &self.event_log
+    }
+
+    pub fn verify_log(&self) -> Result<(), LogVerifyError> {
+        self.event_log.verify()
+    }
+
+    pub fn export_log_jsonl(&self) -> String {
+        self.event_log.to_jsonl()
+    }
+
+    pub fn export_log<W: Write>(&self, sink: W, compression: Compression) -> io::Result<StreamManifest> {
+        self.event_log.write_stream(sink, compression)
+    }
+}
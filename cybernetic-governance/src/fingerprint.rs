@@ -0,0 +1,170 @@
+// path: cybernetic-governance/src/fingerprint.rs
+
+//! Constitution fingerprints, for checking from outside which constitution the engine runs.
+//! - The fingerprint is `GovernanceConstitution::canonical_hash`: SHA-256 over canonical JSON
+//!   (object keys sorted, set-valued arrays sorted), the hash the amendment history records
+//! - Every applied change and every event-log entry carries the fingerprint of the constitution
+//!   in force when it was made; entries recorded before fingerprints existed carry none
+//! - `export_constitution` publishes the constitution with its fingerprint and amendment
+//!   history, optionally signed; `GovernanceEventLog::verify_fingerprints` checks a log against it
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+use crate::amendment::sort_arrays;
+use crate::{
+    AppliedAmendment, CapabilityGovernance, GovernanceConstitution, GovernanceEvent, GovernanceEventLog,
+    LogVerifyError,
+};
+
+/// Signs exported constitution documents.
+pub trait Signer {
+    /// Identity a verifier checks the signature against, e.g. a DID or key id.
+    fn signer_id(&self) -> String;
+    fn sign(&self, message: &[u8]) -> String;
+}
+
+/// Checks signatures made by a `Signer`.
+pub trait SignatureVerifier {
+    /// Whether `signature` is `signer_id`'s signature over `message`.
+    fn verify(&self, signer_id: &str, message: &[u8], signature: &str) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConstitutionSignature {
+    pub signer_id: String,
+    /// Over the bytes of the document's `payload_hash`.
+    pub signature: String,
+}
+
+/// The constitution as exported for public verification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SignedConstitutionDocument {
+    pub constitution: GovernanceConstitution,
+    /// `constitution.canonical_hash()` at export.
+    pub fingerprint: String,
+    /// Applied amendments, oldest first; each `before_hash` and `after_hash` is the
+    /// fingerprint of a version the engine has run.
+    pub amendment_history: Vec<AppliedAmendment>,
+    #[serde(default)]
+    pub signature: Option<ConstitutionSignature>,
+}
+
+impl SignedConstitutionDocument {
+    /// Hash over everything except the signature; what a `Signer` signs.
+    pub fn payload_hash(&self) -> String {
+        let mut value = serde_json::json!({
+            "constitution": self.constitution,
+            "fingerprint": self.fingerprint,
+            "amendment_history": self.amendment_history,
+        });
+        sort_arrays(&mut value);
+        let payload = serde_json::to_vec(&value).expect("document serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&payload);
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Check that the constitution matches the fingerprint and that the amendment history
+    /// links up and ends at it. Says nothing about the signature.
+    pub fn verify(&self) -> Result<(), String> {
+        let actual = self.constitution.canonical_hash();
+        if actual != self.fingerprint {
+            return Err(format!(
+                "Constitution fingerprint mismatch: document claims {}, contents hash to {actual}",
+                self.fingerprint
+            ));
+        }
+        for pair in self.amendment_history.windows(2) {
+            if pair[0].after_hash != pair[1].before_hash {
+                return Err(format!(
+                    "Amendment {} does not start from the constitution amendment {} left",
+                    pair[1].amendment_id, pair[0].amendment_id
+                ));
+            }
+        }
+        if let Some(last) = self.amendment_history.last() {
+            if last.after_hash != self.fingerprint {
+                return Err(format!("Amendment history ends at {}, not at the exported constitution", last.after_hash));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the signature over `payload_hash` with `verifier`.
+    pub fn verify_signature(&self, verifier: &dyn SignatureVerifier) -> Result<(), String> {
+        let signature = self.signature.as_ref().ok_or("Constitution document is not signed")?;
+        if !verifier.verify(&signature.signer_id, self.payload_hash().as_bytes(), &signature.signature) {
+            return Err(format!("Signature by {} does not verify", signature.signer_id));
+        }
+        Ok(())
+    }
+
+    /// Fingerprints of every version the document accounts for, oldest first.
+    pub fn versions(&self) -> Vec<&str> {
+        let mut versions: Vec<&str> =
+            self.amendment_history.first().map(|a| a.before_hash.as_str()).into_iter().collect();
+        versions.extend(self.amendment_history.iter().map(|a| a.after_hash.as_str()));
+        if versions.last() != Some(&self.fingerprint.as_str()) {
+            versions.push(&self.fingerprint);
+        }
+        versions
+    }
+}
+
+impl GovernanceEventLog {
+    /// Check that every entry, and every applied change inside one, was recorded under a
+    /// constitution version `document` accounts for. Entries without a fingerprint are skipped.
+    pub fn verify_fingerprints(&self, document: &SignedConstitutionDocument) -> Result<(), LogVerifyError> {
+        let versions = document.versions();
+        for (index, entry) in self.entries().iter().enumerate() {
+            let changes = match &entry.event {
+                GovernanceEvent::ProposalCommitted { changes } => changes.as_slice(),
+                GovernanceEvent::OracleVetoed { vetoes } => vetoes.as_slice(),
+                GovernanceEvent::AppealReviewed { reinstatement: Some(change), .. } => std::slice::from_ref(&**change),
+                _ => &[],
+            };
+            let recorded = entry
+                .constitution_fingerprint
+                .iter()
+                .chain(changes.iter().filter_map(|c| c.constitution_fingerprint.as_ref()));
+            for fingerprint in recorded {
+                if !versions.contains(&fingerprint.as_str()) {
+                    return Err(LogVerifyError {
+                        index,
+                        reason: format!(
+                            "constitution fingerprint {fingerprint} matches no version in the amendment history"
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CapabilityGovernance {
+    /// Fingerprint of the constitution in force.
+    pub fn constitution_fingerprint(&self) -> String {
+        self.constitution.canonical_hash()
+    }
+
+    /// The constitution, its fingerprint and the amendment history, signed by `signer` if given.
+    pub fn export_constitution(&self, signer: Option<&dyn Signer>) -> SignedConstitutionDocument {
+        let mut document = SignedConstitutionDocument {
+            constitution: self.constitution.clone(),
+            fingerprint: self.constitution_fingerprint(),
+            amendment_history: self.amendment_history.clone(),
+            signature: None,
+        };
+        if let Some(signer) = signer {
+            document.signature = Some(ConstitutionSignature {
+                signer_id: signer.signer_id(),
+                signature: signer.sign(document.payload_hash().as_bytes()),
+            });
+        }
+        document
+    }
+}
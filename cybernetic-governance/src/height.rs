@@ -0,0 +1,41 @@
+// path: cybernetic-governance/src/height.rs
+
+//! Height sources for the governance engine.
+//! - `HeightSource` abstracts "what height is it now" (manual, chain-backed, ...)
+//! - `ManualHeightSource` is a shared counter for tests and simulations
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Source of the current block / epoch height.
+pub trait HeightSource: Send + Sync {
+    fn current_height(&self) -> u64;
+}
+
+/// Manually driven height; clones share the same counter.
+#[derive(Debug, Clone, Default)]
+pub struct ManualHeightSource {
+    height: Arc<AtomicU64>,
+}
+
+impl ManualHeightSource {
+    pub fn new(height: u64) -> Self {
+        Self {
+            height: Arc::new(AtomicU64::new(height)),
+        }
+    }
+
+    pub fn set(&self, height: u64) {
+        self.height.store(height, Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, by: u64) -> u64 {
+        self.height.fetch_add(by, Ordering::SeqCst) + by
+    }
+}
+
+impl HeightSource for ManualHeightSource {
+    fn current_height(&self) -> u64 {
+        self.height.load(Ordering::SeqCst)
+    }
+}
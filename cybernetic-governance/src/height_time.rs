@@ -0,0 +1,332 @@
+// path: cybernetic-governance/src/height_time.rs
+
+//! Mapping block heights to wall-clock milliseconds and back, where governance meets the
+//! millisecond-based rest of the workspace.
+//! - `HeightTimeMapper::time_at` is the time a height is reached, `height_at` the last
+//!   height reached by a time; both never decrease, and `height_at(time_at(h)) == h`
+//!   wherever blocks are at least a millisecond apart
+//! - `LinearHeightTime`: `genesis_ms + height * block_interval_ms`, optionally up to a
+//!   maximum height
+//! - `CheckpointHeightTime`: interpolates between observed `(height, timestamp)`
+//!   checkpoints, and extrapolates at their average rate at most `horizon_heights` past
+//!   the last; checkpoints must arrive with strictly increasing heights and timestamps
+//! - Anything a mapper will not answer is a `MappingError`, never a guess
+//! - With a mapper set on the engine: wall-clock sunsets become heights, appeal windows
+//!   get a closing time, and element-bridge turns carry the time of the change behind them
+
+use serde::{Serialize, Deserialize};
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use crate::{CapabilityGovernance, CapabilityId, GovernanceProposal};
+
+/// Why a height or time could not be mapped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MappingError {
+    /// The engine has no mapper; see `CapabilityGovernance::set_height_time_mapper`.
+    NoMapper,
+    /// The mapper has no checkpoints yet.
+    NoCheckpoints,
+    /// Earlier than the mapper's first known time.
+    BeforeStart { time_ms: u64, start_ms: u64 },
+    /// Lower than the mapper's first known height.
+    BelowStart { height: u64, start_height: u64 },
+    /// Past the last height the mapper will extrapolate to.
+    HeightBeyondHorizon { height: u64, max_height: u64 },
+    /// Past the time of the last height the mapper will extrapolate to.
+    TimeBeyondHorizon { time_ms: u64, max_time_ms: u64 },
+    /// A checkpoint that does not come strictly after the last one in both height and time.
+    NonMonotonicCheckpoint { checkpoint: HeightCheckpoint, last: HeightCheckpoint },
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingError::NoMapper => write!(f, "No height-time mapper configured"),
+            MappingError::NoCheckpoints => write!(f, "No height checkpoints observed"),
+            MappingError::BeforeStart { time_ms, start_ms } => {
+                write!(f, "Time {time_ms} is before the first mapped time {start_ms}")
+            }
+            MappingError::BelowStart { height, start_height } => {
+                write!(f, "Height {height} is below the first mapped height {start_height}")
+            }
+            MappingError::HeightBeyondHorizon { height, max_height } => {
+                write!(f, "Height {height} is beyond the mapping horizon at height {max_height}")
+            }
+            MappingError::TimeBeyondHorizon { time_ms, max_time_ms } => {
+                write!(f, "Time {time_ms} is beyond the mapping horizon at {max_time_ms}")
+            }
+            MappingError::NonMonotonicCheckpoint { checkpoint, last } => write!(
+                f,
+                "Checkpoint at height {} ({}) does not follow height {} ({})",
+                checkpoint.height, checkpoint.timestamp_ms, last.height, last.timestamp_ms
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MappingError {}
+
+/// Converts between block heights and wall-clock milliseconds.
+pub trait HeightTimeMapper: Send + Sync {
+    /// When `height` is (or is expected to be) reached.
+    fn time_at(&self, height: u64) -> Result<u64, MappingError>;
+
+    /// The last height reached at or before `time_ms`.
+    fn height_at(&self, time_ms: u64) -> Result<u64, MappingError>;
+
+    /// The first height reached at or after `time_ms`: a sunset at this height lapses no
+    /// earlier than `time_ms`.
+    fn first_height_at_or_after(&self, time_ms: u64) -> Result<u64, MappingError> {
+        let height = self.height_at(time_ms)?;
+        if self.time_at(height)? >= time_ms {
+            Ok(height)
+        } else {
+            let next = height + 1;
+            self.time_at(next)?;
+            Ok(next)
+        }
+    }
+}
+
+/// A fixed block interval from genesis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearHeightTime {
+    genesis_ms: u64,
+    block_interval_ms: u64,
+    /// Highest height mapped; `None` maps every height.
+    max_height: Option<u64>,
+}
+
+impl LinearHeightTime {
+    /// Panics on a zero interval, which would map every height to genesis.
+    pub fn new(genesis_ms: u64, block_interval_ms: u64) -> Self {
+        assert!(block_interval_ms > 0, "block interval must be positive");
+        Self { genesis_ms, block_interval_ms, max_height: None }
+    }
+
+    /// Refuse heights above `max_height`, and times after it is reached.
+    pub fn with_max_height(mut self, max_height: u64) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    pub fn genesis_ms(&self) -> u64 {
+        self.genesis_ms
+    }
+
+    pub fn block_interval_ms(&self) -> u64 {
+        self.block_interval_ms
+    }
+}
+
+impl HeightTimeMapper for LinearHeightTime {
+    fn time_at(&self, height: u64) -> Result<u64, MappingError> {
+        if let Some(max_height) = self.max_height.filter(|max| height > *max) {
+            return Err(MappingError::HeightBeyondHorizon { height, max_height });
+        }
+        Ok(self.genesis_ms.saturating_add(height.saturating_mul(self.block_interval_ms)))
+    }
+
+    fn height_at(&self, time_ms: u64) -> Result<u64, MappingError> {
+        if time_ms < self.genesis_ms {
+            return Err(MappingError::BeforeStart { time_ms, start_ms: self.genesis_ms });
+        }
+        if let Some(max_height) = self.max_height {
+            let max_time_ms = self.time_at(max_height)?;
+            if time_ms > max_time_ms {
+                return Err(MappingError::TimeBeyondHorizon { time_ms, max_time_ms });
+            }
+        }
+        Ok((time_ms - self.genesis_ms) / self.block_interval_ms)
+    }
+}
+
+/// A height observed at a wall-clock time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HeightCheckpoint {
+    pub height: u64,
+    pub timestamp_ms: u64,
+}
+
+/// Interpolates between observed checkpoints; see the module docs. Shared behind an `Arc`,
+/// it keeps mapping for the engine while a chain watcher `observe`s new checkpoints.
+#[derive(Debug, Default)]
+pub struct CheckpointHeightTime {
+    /// Oldest first, strictly increasing in height and time.
+    checkpoints: RwLock<Vec<HeightCheckpoint>>,
+    /// How many heights past the last checkpoint are extrapolated; 0 extrapolates nothing.
+    horizon_heights: u64,
+}
+
+impl CheckpointHeightTime {
+    pub fn new(horizon_heights: u64) -> Self {
+        Self { checkpoints: RwLock::default(), horizon_heights }
+    }
+
+    /// `new`, then `observe` each of `checkpoints` in order.
+    pub fn from_checkpoints(
+        checkpoints: impl IntoIterator<Item = HeightCheckpoint>,
+        horizon_heights: u64,
+    ) -> Result<Self, MappingError> {
+        let mapper = Self::new(horizon_heights);
+        for checkpoint in checkpoints {
+            mapper.observe(checkpoint)?;
+        }
+        Ok(mapper)
+    }
+
+    /// Append `checkpoint`; it must be strictly after the last one in both height and time.
+    pub fn observe(&self, checkpoint: HeightCheckpoint) -> Result<(), MappingError> {
+        let mut checkpoints = self.checkpoints.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(&last) = checkpoints.last() {
+            if checkpoint.height <= last.height || checkpoint.timestamp_ms <= last.timestamp_ms {
+                return Err(MappingError::NonMonotonicCheckpoint { checkpoint, last });
+            }
+        }
+        checkpoints.push(checkpoint);
+        Ok(())
+    }
+
+    /// Observed checkpoints, oldest first.
+    pub fn checkpoints(&self) -> Vec<HeightCheckpoint> {
+        self.read().clone()
+    }
+
+    pub fn horizon_heights(&self) -> u64 {
+        self.horizon_heights
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Vec<HeightCheckpoint>> {
+        self.checkpoints.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// First and last checkpoints, and the last height extrapolated to with its time.
+    fn bounds(checkpoints: &[HeightCheckpoint], horizon_heights: u64) -> Result<Bounds, MappingError> {
+        let (Some(&first), Some(&last)) = (checkpoints.first(), checkpoints.last()) else {
+            return Err(MappingError::NoCheckpoints);
+        };
+        let horizon = if horizon_heights == 0 || first == last {
+            last
+        } else {
+            let height = last.height.saturating_add(horizon_heights);
+            HeightCheckpoint { height, timestamp_ms: interpolate_time(first, last, height) }
+        };
+        Ok(Bounds { first, last, horizon })
+    }
+}
+
+struct Bounds {
+    first: HeightCheckpoint,
+    last: HeightCheckpoint,
+    horizon: HeightCheckpoint,
+}
+
+/// Time at `height` on the line through `a` and `b`, rounded up so the height is reached.
+fn interpolate_time(a: HeightCheckpoint, b: HeightCheckpoint, height: u64) -> u64 {
+    let span = u128::from(b.timestamp_ms - a.timestamp_ms) * u128::from(height - a.height);
+    let offset = span.div_ceil(u128::from(b.height - a.height));
+    u64::try_from(u128::from(a.timestamp_ms) + offset).unwrap_or(u64::MAX)
+}
+
+/// Height at `time_ms` on the line through `a` and `b`, rounded down to one reached by then.
+fn interpolate_height(a: HeightCheckpoint, b: HeightCheckpoint, time_ms: u64) -> u64 {
+    let span = u128::from(b.height - a.height) * u128::from(time_ms - a.timestamp_ms);
+    let offset = span / u128::from(b.timestamp_ms - a.timestamp_ms);
+    u64::try_from(u128::from(a.height) + offset).unwrap_or(u64::MAX)
+}
+
+impl HeightTimeMapper for CheckpointHeightTime {
+    fn time_at(&self, height: u64) -> Result<u64, MappingError> {
+        let checkpoints = self.read();
+        let Bounds { first, last, horizon } = Self::bounds(&checkpoints, self.horizon_heights)?;
+        if height < first.height {
+            return Err(MappingError::BelowStart { height, start_height: first.height });
+        }
+        if height > horizon.height {
+            return Err(MappingError::HeightBeyondHorizon { height, max_height: horizon.height });
+        }
+        if height == last.height {
+            return Ok(last.timestamp_ms);
+        }
+        if height > last.height {
+            // Within the horizon, so there are at least two checkpoints.
+            return Ok(interpolate_time(first, last, height));
+        }
+        let i = checkpoints.partition_point(|c| c.height <= height);
+        Ok(interpolate_time(checkpoints[i - 1], checkpoints[i], height))
+    }
+
+    fn height_at(&self, time_ms: u64) -> Result<u64, MappingError> {
+        let checkpoints = self.read();
+        let Bounds { first, last, horizon } = Self::bounds(&checkpoints, self.horizon_heights)?;
+        if time_ms < first.timestamp_ms {
+            return Err(MappingError::BeforeStart { time_ms, start_ms: first.timestamp_ms });
+        }
+        if time_ms > horizon.timestamp_ms {
+            return Err(MappingError::TimeBeyondHorizon { time_ms, max_time_ms: horizon.timestamp_ms });
+        }
+        if time_ms == last.timestamp_ms {
+            return Ok(last.height);
+        }
+        if time_ms > last.timestamp_ms {
+            return Ok(interpolate_height(first, last, time_ms).min(horizon.height));
+        }
+        let i = checkpoints.partition_point(|c| c.timestamp_ms <= time_ms);
+        Ok(interpolate_height(checkpoints[i - 1], checkpoints[i], time_ms))
+    }
+}
+
+impl GovernanceProposal {
+    /// Lapse the proposal's restrictions at the first height reached at or after `time_ms`.
+    pub fn set_sunset_at_ms(&mut self, mapper: &dyn HeightTimeMapper, time_ms: u64) -> Result<u64, MappingError> {
+        let height = mapper.first_height_at_or_after(time_ms)?;
+        self.sunset_height = Some(height);
+        Ok(height)
+    }
+
+    /// `set_sunset_at_ms` for one capability, overriding the proposal's sunset.
+    pub fn set_capability_sunset_at_ms(
+        &mut self,
+        mapper: &dyn HeightTimeMapper,
+        capability: CapabilityId,
+        time_ms: u64,
+    ) -> Result<u64, MappingError> {
+        let height = mapper.first_height_at_or_after(time_ms)?;
+        self.capability_sunsets.insert(capability, height);
+        Ok(height)
+    }
+}
+
+impl CapabilityGovernance {
+    /// Map heights and times with `mapper`.
+    pub fn set_height_time_mapper(&mut self, mapper: Arc<dyn HeightTimeMapper>) {
+        self.height_time = Some(mapper);
+    }
+
+    pub fn height_time_mapper(&self) -> Option<&dyn HeightTimeMapper> {
+        self.height_time.as_deref()
+    }
+
+    /// When `height` is reached, by the configured mapper.
+    pub fn time_at_height(&self, height: u64) -> Result<u64, MappingError> {
+        self.height_time_mapper().ok_or(MappingError::NoMapper)?.time_at(height)
+    }
+
+    /// The last height reached by `time_ms`, by the configured mapper.
+    pub fn height_at_time(&self, time_ms: u64) -> Result<u64, MappingError> {
+        self.height_time_mapper().ok_or(MappingError::NoMapper)?.height_at(time_ms)
+    }
+
+    /// When the appeal window of a change committed at `change_height` closes; `None` when
+    /// the constitution allows no appeals.
+    pub fn appeal_window_closes_at_ms(&self, change_height: u64) -> Result<Option<u64>, MappingError> {
+        let window = self.constitution.appeal_window_heights;
+        if window == 0 {
+            return Ok(None);
+        }
+        self.time_at_height(change_height.saturating_add(window)).map(Some)
+    }
+}
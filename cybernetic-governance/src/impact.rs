@@ -0,0 +1,75 @@
+// path: cybernetic-governance/src/impact.rs
+
+//! Impact statements on restriction proposals.
+//! - With `require_impact_statement` in the constitution, a proposal restricting any
+//!   capability must carry an `ImpactStatement`; checked in `validate_proposal`, so
+//!   `open_proposal_vote` refuses it before any ballot is cast
+//! - A statement, required or not, must count the proposal's restrictions and name at
+//!   least one alternative and how the restriction can be undone
+//! - Previews show the statement; committed changes keep it in the domain's history
+//! - With the `the_element` feature, `element_bridge::preview_with_element` warns when the
+//!   affected-agent estimate is off by more than `impact_estimate_factor`
+
+use serde::{Serialize, Deserialize};
+
+use crate::{CapabilityGovernance, GovernanceProposal, Reason, ReasonCode};
+
+/// Who and what a restriction proposal affects, written for voters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ImpactStatement {
+    /// Must equal the number of `restrict_capabilities`.
+    pub affected_capability_count: usize,
+    #[serde(default)]
+    pub estimated_affected_agents: Option<u32>,
+    #[serde(default)]
+    pub alternatives_considered: Vec<String>,
+    /// How, and how easily, the restriction can be lifted.
+    pub reversibility_note: String,
+}
+
+impl ImpactStatement {
+    /// Minimal completeness for a proposal restricting `restricted` capabilities.
+    pub fn check(&self, restricted: usize) -> Result<(), Reason> {
+        if self.affected_capability_count != restricted {
+            return Err(Reason::new(ReasonCode::CapabilityImpactStatementCount)
+                .with("stated", self.affected_capability_count)
+                .with("restricted", restricted));
+        }
+        if !self.alternatives_considered.iter().any(|a| !a.trim().is_empty()) {
+            return Err(Reason::new(ReasonCode::CapabilityImpactStatementIncomplete).with("field", "alternatives_considered"));
+        }
+        if self.reversibility_note.trim().is_empty() {
+            return Err(Reason::new(ReasonCode::CapabilityImpactStatementIncomplete).with("field", "reversibility_note"));
+        }
+        Ok(())
+    }
+
+    /// A warning when `observed` affected agents and the estimate differ by more than
+    /// `factor` either way; `None` without an estimate.
+    pub fn estimate_warning(&self, observed: u32, factor: f64) -> Option<Reason> {
+        let estimated = self.estimated_affected_agents?;
+        let (low, high) = (estimated.min(observed) as f64, estimated.max(observed) as f64);
+        (high > low * factor).then(|| {
+            Reason::new(ReasonCode::CapabilityImpactEstimateOff)
+                .with("estimated", estimated)
+                .with("observed", observed)
+                .with("factor", factor)
+        })
+    }
+}
+
+impl CapabilityGovernance {
+    /// `proposal`'s impact statement: required when it restricts capabilities under a
+    /// constitution demanding one, and complete whenever present.
+    pub fn check_impact_statement(&self, proposal: &GovernanceProposal) -> Result<(), Reason> {
+        let restricted = proposal.restrict_capabilities.len();
+        match &proposal.impact_statement {
+            Some(statement) => statement.check(restricted),
+            None if restricted > 0 && self.constitution.require_impact_statement => {
+                Err(Reason::new(ReasonCode::CapabilityImpactStatementMissing))
+            }
+            None => Ok(()),
+        }
+    }
+}
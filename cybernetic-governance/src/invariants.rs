@@ -0,0 +1,77 @@
+// path: cybernetic-governance/src/invariants.rs
+
+//! State checks for generated operation sequences (feature `test-util`).
+//! - `governance.disabled_allowed`: only a domain's own capabilities are disabled
+//! - `governance.freedom_budget`: every domain keeps `min_capability_count`, the constitution's
+//!   global floor and every category floor enabled
+//! - `governance.nonrestrictable_enabled`: no constitutionally non-restrictable capability is
+//!   disabled
+//! - `governance.bookkeeping`: sunsets and attributions only name disabled capabilities, and
+//!   nothing is both protected and disabled
+//! - `governance.history_recorded`: each committed proposal has a `Proposal` entry in the
+//!   history of every domain it applied to
+//! - `governance.event_log`: the event log's hash chain verifies
+
+use steward_ids::invariants::InvariantViolation;
+
+use crate::{CapabilityGovernance, ChangeKind};
+
+impl CapabilityGovernance {
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let mut domain_ids: Vec<_> = self.domains.keys().collect();
+        domain_ids.sort();
+        for domain_id in domain_ids {
+            let state = &self.domains[domain_id];
+            let domain = &state.domain;
+            let mut disabled: Vec<_> = state.disabled_capabilities.iter().collect();
+            disabled.sort();
+            if let Some(cap) = disabled.iter().find(|c| !domain.allowed_capabilities.contains(**c)) {
+                let detail = format!("{domain_id} disables {cap}, which it does not allow");
+                return Err(InvariantViolation::new("governance.disabled_allowed", detail));
+            }
+            let enabled = domain.allowed_capabilities.len() - disabled.len();
+            let floor = domain.min_capability_count.max(self.constitution.global_min_capability_floor);
+            if enabled < floor {
+                let detail = format!("{domain_id} has {enabled} capabilities enabled, below its floor of {floor}");
+                return Err(InvariantViolation::new("governance.freedom_budget", detail));
+            }
+            let counts = domain.category_counts(&state.disabled_capabilities, &self.catch_all_category);
+            if let Some(count) = counts.iter().find(|c| c.enabled < c.floor) {
+                let detail = format!(
+                    "{domain_id} has {} of category {} enabled, below its floor of {}",
+                    count.enabled, count.category, count.floor
+                );
+                return Err(InvariantViolation::new("governance.freedom_budget", detail));
+            }
+            if let Some(cap) = disabled.iter().find(|c| self.constitution.is_nonrestrictable(c)) {
+                let detail = format!("{domain_id} disables non-restrictable {cap}");
+                return Err(InvariantViolation::new("governance.nonrestrictable_enabled", detail));
+            }
+            let stray = state
+                .disabled_until
+                .keys()
+                .chain(state.disabled_by.keys())
+                .filter(|c| !state.disabled_capabilities.contains(*c))
+                .chain(state.protections.keys().filter(|c| state.disabled_capabilities.contains(*c)))
+                .min();
+            if let Some(cap) = stray {
+                let detail = format!("{domain_id}'s sunsets, attributions or protections disagree on {cap}");
+                return Err(InvariantViolation::new("governance.bookkeeping", detail));
+            }
+        }
+        let mut committed: Vec<_> = self.committed.iter().collect();
+        committed.sort();
+        for (proposal_id, domain_ids) in committed {
+            let missing = domain_ids.iter().find(|domain_id| {
+                !self.history.get(*domain_id).is_some_and(|changes| {
+                    changes.iter().any(|c| c.proposal_id == *proposal_id && c.kind == ChangeKind::Proposal)
+                })
+            });
+            if let Some(domain_id) = missing {
+                let detail = format!("{proposal_id} is committed but absent from {domain_id}'s history");
+                return Err(InvariantViolation::new("governance.history_recorded", detail));
+            }
+        }
+        self.verify_log().map_err(|e| InvariantViolation::new("governance.event_log", e.to_string()))
+    }
+}
@@ -7,72 +7,493 @@
 //! - Designed for integration with BCI / neuromorphic and cybernetic-chipset vNodes. [web:6][web:9]
 
 use serde::{Serialize, Deserialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+pub mod appeal;
+pub use appeal::{AppealDecision, AppealGrounds, AppealRegistry, AppealVerdict, CapabilityAppeal};
+pub mod amendment;
+pub use amendment::{
+    AmendmentAssessment, AmendmentCancellation, AmendmentRecord, AmendmentRules, AmendmentStatus,
+    AppliedAmendment, ConstitutionAmendment, ConstitutionChange,
+};
+#[cfg(feature = "test-util")]
+pub mod builders;
+pub mod categories;
+pub use categories::{CategoryCount, CategoryId};
+pub mod commit_reveal;
+pub use commit_reveal::{
+    commitment_hash, CommitRevealError, CommitRevealRules, CommitRevealWindow, UnrevealedPolicy, VoteParticipation,
+};
+pub mod effective;
+pub use effective::{DisabledCapability, EffectiveCapabilities, EnabledCapability};
+#[cfg(feature = "the_element")]
+pub mod element_bridge;
+pub mod eventlog;
+pub use eventlog::{GovernanceEvent, GovernanceEventLog, GovernanceLogEntry, LogVerifyError, EVENT_LOG_STREAM_KIND};
+pub mod fingerprint;
+pub use fingerprint::{ConstitutionSignature, SignatureVerifier, SignedConstitutionDocument, Signer};
+pub mod height;
+pub use height::{HeightSource, ManualHeightSource};
+pub mod height_time;
+pub use height_time::{CheckpointHeightTime, HeightCheckpoint, HeightTimeMapper, LinearHeightTime, MappingError};
+pub mod impact;
+#[cfg(feature = "test-util")]
+mod invariants;
+pub use impact::ImpactStatement;
+pub mod limits;
+pub use limits::{ProposalLimit, ProposalLimitExceeded, ProposalLimits};
+pub mod oracle;
+pub use oracle::{ActiveUseOracle, OracleOverride, OracleReview, OracleVerdict, SafetyOracle};
+pub mod preview;
+pub use preview::{DomainPreview, ProposalPreview, ThresholdCheck};
+pub mod referee;
+pub use referee::MatchOverride;
+pub mod snapshot;
+pub use snapshot::{GovernanceSnapshot, RestoreError, GOVERNANCE_SNAPSHOT_SCHEMA_VERSION};
+pub mod templates;
+pub use templates::{DomainOverrides, DomainTemplate, TemplateDrift};
+#[cfg(feature = "toml")]
+pub use templates::templates_from_toml;
+pub mod voting;
+pub use voting::{TallySource, VoteSession, VoterId, VoterRecord, VoterRegistry, VoterSnapshot};
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 /// Core module or capability IDs in the cybernetic / biomechanical system.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CapabilityId(pub String);
+/// Shared with `the_element` via `steward-ids`.
+pub use steward_ids::{CapabilityId, Did, IdError};
+pub use steward_ids::messages::{Messages, Reason, ReasonCode};
+pub use steward_ids::receipts::{verify_receipt_inclusion, CountedBallots, VoteReceipt};
+pub use steward_ids::stream::{Compression, StreamError, StreamManifest};
+
+/// Crate version stamped into exported JSON Schemas.
+#[cfg(feature = "schema")]
+pub const SCHEMA_CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// “Competitive domain” describes a game / sport / XR grid where cybernetic moves occur.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CompetitiveDomain {
     pub id: String,
     /// Human readable description of the game / sport / xr-grid.
     pub description: String,
     /// Capabilities that define the legal move-space in this domain.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub allowed_capabilities: HashSet<CapabilityId>,
     /// A minimal “freedom budget” – number of capabilities that must remain
     /// enabled; governance cannot drop below this. [web:9]
     pub min_capability_count: usize,
+    /// Category of every allowed capability; empty for domains from before categories,
+    /// whose capabilities all fall in the engine's catch-all category.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "steward_ids::ordered::map")]
+    pub capability_categories: HashMap<CapabilityId, CategoryId>,
+    /// Capabilities of each category that must remain enabled, on top of `min_capability_count`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "steward_ids::ordered::map")]
+    pub category_floors: HashMap<CategoryId, usize>,
+}
+
+/// Which domains a proposal applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ProposalTarget {
+    Domain(String),
+    /// League-wide change over a fixed list of domains.
+    Domains(Vec<String>),
+    /// Every domain registered at evaluation time.
+    AllDomains,
 }
 
 /// A governance-turn proposal about capabilities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GovernanceProposal {
     pub proposal_id: String,
-    /// Domain this proposal applies to.
+    /// Domain this proposal applies to (used when `target` is `None`).
     pub domain_id: String,
+    /// Multi-domain target; overrides `domain_id` when set.
+    #[serde(default)]
+    pub target: Option<ProposalTarget>,
     /// Capabilities to restrict (disable) if the proposal passes.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub restrict_capabilities: HashSet<CapabilityId>,
     /// Capabilities to explicitly protect (whitelist) regardless of other rules.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub protect_capabilities: HashSet<CapabilityId>,
     /// Required supermajority threshold (0.0–1.0) for this proposal to apply.
     pub required_supermajority: f64,
     /// Epoch height or block number at which this proposal becomes eligible.
     pub activation_height: u64,
+    /// Height at which this proposal's restrictions lapse. `None` = permanent.
+    #[serde(default)]
+    pub sunset_height: Option<u64>,
+    /// Per-capability sunsets, overriding `sunset_height` for the named capabilities.
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub capability_sunsets: HashMap<CapabilityId, u64>,
+    /// Required on restriction proposals when the constitution asks for one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impact_statement: Option<ImpactStatement>,
 }
 
 /// Result of a governance vote.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GovernanceVoteOutcome {
     pub proposal_id: String,
     pub yes_weight: u128,
     pub no_weight: u128,
     /// Block or epoch height where tally was finalized.
     pub finalized_height: u64,
+    /// Commit and reveal counts, for commit-reveal votes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub participation: Option<VoteParticipation>,
 }
 
 /// Immutable “constitutional” parameters that governance cannot bypass. [web:2][web:8]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GovernanceConstitution {
     /// Absolute floor for min_capability_count in any domain.
     pub global_min_capability_floor: usize,
-    /// Absolute maximum fraction of capabilities that can be restricted in a single turn.
+    /// Absolute maximum fraction of a domain's capabilities that a single turn
+    /// may newly disable. Capabilities already disabled by earlier turns do not count.
     pub max_restriction_fraction_per_turn: f64,
+    /// Optional cap on the fraction of a domain's capabilities disabled in total
+    /// (earlier turns included) after a proposal applies. `None` = no cumulative cap.
+    #[serde(default)]
+    pub max_cumulative_disabled_fraction: Option<f64>,
     /// Minimal required_supermajority to *ever* restrict a capability.
     pub min_supermajority_floor: f64,
     /// Whether BCI/biomech safety capabilities are *hard protected*.
     pub hard_protect_safety_capabilities: bool,
     /// Capabilities that are globally non-restrictable (e.g., safety & access). [web:9]
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub globally_nonrestrictable: HashSet<CapabilityId>,
+    /// Namespaces whose every capability is non-restrictable, present or added later, e.g.
+    /// `safety` or `access:doors`: a capability is covered when its id continues the entry
+    /// with `:`. Entries follow the capability namespace rules.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty", serialize_with = "steward_ids::ordered::set")]
+    pub nonrestrictable_namespaces: HashSet<String>,
+    /// Supermajority a later proposal needs to restrict a capability that an
+    /// earlier proposal explicitly protected.
+    #[serde(default = "default_protected_override_supermajority")]
+    pub protected_override_supermajority: f64,
+    /// Vote outcomes finalized more than this many heights ago are rejected
+    /// as stale. `None` = outcomes never go stale.
+    #[serde(default)]
+    pub max_outcome_age_heights: Option<u64>,
+    /// Whether a safety-oracle veto may be overridden (with acknowledgment tokens).
+    #[serde(default)]
+    pub allow_oracle_override: bool,
+    /// Yes ratio required to restrict specific capabilities.
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub per_capability_thresholds: HashMap<CapabilityId, f64>,
+    /// Yes ratio required to restrict capabilities whose id starts with the
+    /// given prefix (e.g. `research:`). Exact entries win, then the longest prefix.
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub per_namespace_thresholds: HashMap<String, f64>,
+    /// What happens when a named restriction misses its own threshold.
+    #[serde(default)]
+    pub threshold_mode: ThresholdMode,
+    /// Referee ids allowed to issue match-scoped overrides.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub referees: HashSet<String>,
+    /// Longest a match override may last, in heights. 0 disables overrides.
+    #[serde(default)]
+    pub max_match_override_heights: u64,
+    /// Thresholds and time-lock for amending this constitution.
+    #[serde(default)]
+    pub amendment_rules: AmendmentRules,
+    /// Whether proposals restricting capabilities must carry an `ImpactStatement`.
+    #[serde(default)]
+    pub require_impact_statement: bool,
+    /// How far (as a factor, either way) an impact statement's affected-agent estimate may
+    /// be from what the element bridge observes before the preview warns.
+    #[serde(default = "default_impact_estimate_factor")]
+    pub impact_estimate_factor: f64,
+    /// Review panel ids allowed to decide capability appeals.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub appeal_panel: HashSet<String>,
+    /// Heights after a restricting change during which it may be appealed. 0 disables appeals.
+    #[serde(default)]
+    pub appeal_window_heights: u64,
+}
+
+fn default_protected_override_supermajority() -> f64 {
+    0.9
+}
+
+fn default_impact_estimate_factor() -> f64 {
+    2.0
+}
+
+/// Handling of restrictions whose per-capability threshold is not met.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ThresholdMode {
+    /// Apply the restrictions that met their threshold; skip the rest.
+    #[default]
+    SkipUnmet,
+    /// Reject the whole proposal.
+    RejectProposal,
+}
+
+/// Why the constitution never restricts a capability.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum NonrestrictableRule {
+    /// It is in `globally_nonrestrictable`.
+    ExplicitList,
+    /// It falls under this entry of `nonrestrictable_namespaces`.
+    Namespace(String),
+}
+
+/// Check a `nonrestrictable_namespaces` entry: a namespace, optionally followed by
+/// `:`-separated name segments, as in a capability id.
+pub fn validate_nonrestrictable_namespace(namespace: &str) -> Result<(), String> {
+    CapabilityId::validate(&format!("{namespace}:_"))
+        .map_err(|_| format!("Malformed non-restrictable namespace {namespace:?}: expected <namespace>[:<name>...]"))
+}
+
+impl GovernanceConstitution {
+    /// The rule keeping `cap` from ever being restricted: the explicit list first, else the
+    /// longest covering namespace.
+    pub fn nonrestrictable_rule(&self, cap: &CapabilityId) -> Option<NonrestrictableRule> {
+        if self.globally_nonrestrictable.contains(cap) {
+            return Some(NonrestrictableRule::ExplicitList);
+        }
+        self.nonrestrictable_namespaces
+            .iter()
+            .filter(|ns| cap.0.strip_prefix(ns.as_str()).is_some_and(|rest| rest.starts_with(':')))
+            .max_by_key(|ns| ns.len())
+            .map(|ns| NonrestrictableRule::Namespace(ns.clone()))
+    }
+
+    pub fn is_nonrestrictable(&self, cap: &CapabilityId) -> bool {
+        self.nonrestrictable_rule(cap).is_some()
+    }
+
+    /// Per-capability yes-ratio requirement: an exact entry, else the longest
+    /// matching namespace prefix, else `None`.
+    pub fn capability_threshold(&self, cap: &CapabilityId) -> Option<f64> {
+        if let Some(t) = self.per_capability_thresholds.get(cap) {
+            return Some(*t);
+        }
+        self.per_namespace_thresholds
+            .iter()
+            .filter(|(prefix, _)| cap.0.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, t)| *t)
+    }
+}
+
+/// Marks a capability as explicitly protected by an applied proposal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProtectionRecord {
+    pub proposal_id: String,
+    pub protected_at_height: u64,
 }
 
 /// Runtime state for a domain (simplified).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DomainState {
     pub domain: CompetitiveDomain,
     /// Currently disabled capabilities (after prior governance-turns).
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub disabled_capabilities: HashSet<CapabilityId>,
+    /// Capabilities explicitly protected by earlier proposals.
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub protections: HashMap<CapabilityId, ProtectionRecord>,
+    /// Sunset heights of temporary restrictions; absent = permanent.
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub disabled_until: HashMap<CapabilityId, u64>,
+    /// Proposal responsible for each currently disabled capability.
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub disabled_by: HashMap<CapabilityId, String>,
+}
+
+/// A temporary restriction lifted by `advance_height`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExpiredRestriction {
+    pub domain_id: String,
+    pub capability: CapabilityId,
+    /// Proposal that had disabled the capability, if known.
+    pub restricted_by: Option<String>,
+    pub sunset_height: u64,
+}
+
+/// What produced an entry in a domain's change history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ChangeKind {
+    /// A committed governance proposal.
+    #[default]
+    Proposal,
+    /// A temporary restriction reaching its sunset height.
+    SunsetExpired,
+    /// A commit blocked by the safety oracle; nothing was applied.
+    OracleVeto,
+    /// An upheld appeal re-enabling a capability the proposal had disabled.
+    AppealReinstated,
+}
+
+/// How proposals naming capabilities outside the domain's `allowed_capabilities` are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UnknownCapabilityPolicy {
+    /// Reject the whole proposal.
+    Strict,
+    /// Skip unknown capabilities and report them.
+    #[default]
+    Lenient,
+}
+
+/// Record of a committed proposal's effect on one domain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AppliedChange {
+    #[serde(default)]
+    pub domain_id: String,
+    /// Proposal that caused the change (for sunsets: the proposal whose restriction lapsed).
+    pub proposal_id: String,
+    #[serde(default)]
+    pub kind: ChangeKind,
+    /// Height at which the change was committed.
+    pub height: u64,
+    /// Whether the vote outcome was caller-supplied or tallied by the engine.
+    #[serde(default)]
+    pub tally_source: TallySource,
+    /// Capabilities moved from enabled to disabled by this change.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub newly_disabled: HashSet<CapabilityId>,
+    /// Requested restrictions dropped because the constitution forbids them.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub skipped_due_to_constitution: HashSet<CapabilityId>,
+    /// Requested restrictions dropped because an earlier protection holds and
+    /// the vote missed `protected_override_supermajority`.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub skipped_due_to_protection: HashSet<CapabilityId>,
+    /// Requested restrictions dropped because the yes ratio missed the
+    /// capability's own threshold (`ThresholdMode::SkipUnmet`).
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub skipped_due_to_threshold: HashSet<CapabilityId>,
+    /// Capabilities both restricted and protected by this proposal; protection won.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub protection_conflicts: HashSet<CapabilityId>,
+    /// Previously disabled capabilities re-enabled by this proposal's protections.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub re_enabled: HashSet<CapabilityId>,
+    /// Capabilities newly recorded as protected.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub newly_protected: HashSet<CapabilityId>,
+    /// Restrictions naming capabilities the domain does not allow (skipped).
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub unrecognized: HashSet<CapabilityId>,
+    /// Safety-oracle review of this change, if an oracle is configured.
+    #[serde(default)]
+    pub oracle_review: Option<OracleReview>,
+    /// The proposal's impact statement, as voters saw it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impact_statement: Option<ImpactStatement>,
+    /// Fingerprint of the constitution in force when the change was made; `None` for changes
+    /// recorded before fingerprints existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constitution_fingerprint: Option<String>,
+}
+
+/// Result of `commit_proposal`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CommitOutcome {
+    /// The proposal passed and its changes (one per target domain) are now stored.
+    Applied(Vec<AppliedChange>),
+    /// The proposal was committed earlier; nothing changed. Carries the original records.
+    AlreadyCommitted(Vec<AppliedChange>),
+    /// Timing or vote thresholds not met; nothing changed.
+    NotPassed,
+    /// The safety oracle vetoed at least one domain; nothing was applied.
+    /// Carries the veto records appended to the affected domains' histories.
+    Vetoed(Vec<AppliedChange>),
+}
+
+/// Per-domain result of previewing a (possibly multi-domain) proposal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DomainChangeResult {
+    Passes {
+        new_state: Box<DomainState>,
+        change: Box<AppliedChange>,
+    },
+    /// Timing or vote thresholds not met.
+    NotPassed,
+    /// The constitution rejects the change for this domain.
+    Rejected {
+        reason: String,
+        /// `reason` as a code and parameters.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        coded_reason: Option<Reason>,
+    },
+}
+
+/// Tentative per-domain effect of a proposal before floors and caps are checked.
+struct StagedDomain {
+    disabled: HashSet<CapabilityId>,
+    protections: HashMap<CapabilityId, ProtectionRecord>,
+    restricted_now: HashSet<CapabilityId>,
+    skipped_due_to_constitution: HashSet<CapabilityId>,
+    skipped_due_to_protection: HashSet<CapabilityId>,
+    skipped_due_to_threshold: HashSet<CapabilityId>,
+    protection_conflicts: HashSet<CapabilityId>,
+    re_enabled: HashSet<CapabilityId>,
+    newly_protected: HashSet<CapabilityId>,
+    unrecognized: HashSet<CapabilityId>,
+}
+
+/// Internal evaluation result shared by preview and commit paths.
+struct Evaluation {
+    new_state: DomainState,
+    newly_disabled: HashSet<CapabilityId>,
+    skipped_due_to_constitution: HashSet<CapabilityId>,
+    skipped_due_to_protection: HashSet<CapabilityId>,
+    skipped_due_to_threshold: HashSet<CapabilityId>,
+    protection_conflicts: HashSet<CapabilityId>,
+    re_enabled: HashSet<CapabilityId>,
+    newly_protected: HashSet<CapabilityId>,
+    unrecognized: HashSet<CapabilityId>,
+}
+
+impl Evaluation {
+    fn into_change(
+        self,
+        proposal: &GovernanceProposal,
+        domain_id: &str,
+        height: u64,
+        tally_source: TallySource,
+        constitution_fingerprint: String,
+    ) -> (DomainState, AppliedChange) {
+        let change = AppliedChange {
+            domain_id: domain_id.to_string(),
+            proposal_id: proposal.proposal_id.clone(),
+            kind: ChangeKind::Proposal,
+            height,
+            tally_source,
+            newly_disabled: self.newly_disabled,
+            skipped_due_to_constitution: self.skipped_due_to_constitution,
+            skipped_due_to_protection: self.skipped_due_to_protection,
+            skipped_due_to_threshold: self.skipped_due_to_threshold,
+            protection_conflicts: self.protection_conflicts,
+            re_enabled: self.re_enabled,
+            newly_protected: self.newly_protected,
+            unrecognized: self.unrecognized,
+            oracle_review: None,
+            impact_statement: proposal.impact_statement.clone(),
+            constitution_fingerprint: Some(constitution_fingerprint),
+        };
+        (self.new_state, change)
+    }
 }
 
 /// Governance engine for capability changes.
@@ -80,6 +501,35 @@ pub struct CapabilityGovernance {
     constitution: GovernanceConstitution,
     /// Domain states indexed by domain_id.
     domains: HashMap<String, DomainState>,
+    /// Applied changes per domain_id, in commit order.
+    history: HashMap<String, Vec<AppliedChange>>,
+    /// proposal_id -> domain_ids for every committed proposal.
+    committed: HashMap<String, Vec<String>>,
+    unknown_capability_policy: UnknownCapabilityPolicy,
+    proposal_limits: ProposalLimits,
+    /// Engine-collected votes by proposal_id.
+    votes: HashMap<String, VoteSession>,
+    /// Constitutional amendments by amendment_id.
+    amendments: HashMap<String, AmendmentRecord>,
+    amendment_history: Vec<AppliedAmendment>,
+    /// Authoritative height, when configured.
+    height_source: Option<Arc<dyn HeightSource>>,
+    /// Converts heights to wall-clock time and back, when configured.
+    height_time: Option<Arc<dyn HeightTimeMapper>>,
+    /// Consulted before restrictions are committed, when configured.
+    safety_oracle: Option<Arc<dyn SafetyOracle>>,
+    /// Hash-chained record of every state change; appended where state mutates.
+    event_log: GovernanceEventLog,
+    /// Referee overrides not yet expired.
+    match_overrides: Vec<MatchOverride>,
+    /// League domain templates by template_id.
+    templates: HashMap<String, DomainTemplate>,
+    /// domain_id -> template_id for domains created by `instantiate_domain`.
+    domain_templates: HashMap<String, String>,
+    /// Appeals against applied restrictions.
+    appeals: AppealRegistry,
+    /// Category of capabilities in domains that categorize none.
+    catch_all_category: CategoryId,
 }
 
 impl CapabilityGovernance {
@@ -87,37 +537,510 @@ impl CapabilityGovernance {
         Self {
             constitution,
             domains: HashMap::new(),
+            history: HashMap::new(),
+            committed: HashMap::new(),
+            unknown_capability_policy: UnknownCapabilityPolicy::default(),
+            proposal_limits: ProposalLimits::default(),
+            votes: HashMap::new(),
+            amendments: HashMap::new(),
+            amendment_history: Vec::new(),
+            height_source: None,
+            height_time: None,
+            safety_oracle: None,
+            event_log: GovernanceEventLog::default(),
+            match_overrides: Vec::new(),
+            templates: HashMap::new(),
+            domain_templates: HashMap::new(),
+            appeals: AppealRegistry::default(),
+            catch_all_category: categories::default_catch_all_category(),
+        }
+    }
+
+    /// Attach an authoritative height source. Caller-supplied heights may then
+    /// not run ahead of it, and the `*_now` methods read from it.
+    pub fn set_height_source(&mut self, source: Arc<dyn HeightSource>) {
+        self.height_source = Some(source);
+    }
+
+    /// Attach a safety oracle that may veto restrictions at commit time.
+    pub fn set_safety_oracle(&mut self, oracle: Arc<dyn SafetyOracle>) {
+        self.safety_oracle = Some(oracle);
+    }
+
+    /// Current height from the configured source.
+    pub fn source_height(&self) -> Result<u64, String> {
+        self.height_source
+            .as_ref()
+            .map(|s| s.current_height())
+            .ok_or_else(|| "No height source configured".to_string())
+    }
+
+    /// `commit_proposal` at the height reported by the height source.
+    pub fn commit_proposal_now(
+        &mut self,
+        proposal: &GovernanceProposal,
+        vote_outcome: &GovernanceVoteOutcome,
+    ) -> Result<CommitOutcome, String> {
+        let height = self.source_height()?;
+        self.commit_proposal(proposal, vote_outcome, height)
+    }
+
+    /// Reject heights that claim to be ahead of the configured source.
+    fn check_height(&self, current_height: u64) -> Result<(), Reason> {
+        if let Some(source) = &self.height_source {
+            let authoritative = source.current_height();
+            if current_height > authoritative {
+                return Err(Reason::new(ReasonCode::CapabilityHeightAhead)
+                    .with("current_height", current_height)
+                    .with("authoritative", authoritative));
+            }
         }
+        Ok(())
     }
 
-    pub fn upsert_domain(&mut self, domain: CompetitiveDomain) {
+    /// Choose how restrictions naming capabilities outside a domain are handled.
+    pub fn set_unknown_capability_policy(&mut self, policy: UnknownCapabilityPolicy) {
+        self.unknown_capability_policy = policy;
+    }
+
+    /// Add or replace a domain after `CompetitiveDomain::validate_categories`. Warns about each
+    /// capability the domain newly allows that a `nonrestrictable_namespaces` entry (rather
+    /// than the explicit list) protects, sorted by id: its operators may not expect that it
+    /// can never be restricted.
+    pub fn upsert_domain(&mut self, domain: CompetitiveDomain) -> Result<Vec<Reason>, String> {
+        domain.validate_categories(&self.catch_all_category)?;
+        let previous = self.domains.get(&domain.id).map(|state| &state.domain.allowed_capabilities);
+        let mut added: Vec<&CapabilityId> =
+            domain.allowed_capabilities.iter().filter(|cap| previous.is_none_or(|p| !p.contains(*cap))).collect();
+        added.sort_unstable();
+        let warnings: Vec<Reason> = added
+            .into_iter()
+            .filter_map(|cap| match self.constitution.nonrestrictable_rule(cap)? {
+                NonrestrictableRule::ExplicitList => None,
+                NonrestrictableRule::Namespace(namespace) => Some(
+                    Reason::new(ReasonCode::CapabilityReservedNamespace)
+                        .with("capability", cap.as_str())
+                        .with("namespace", namespace),
+                ),
+            })
+            .collect();
         let entry = self.domains.entry(domain.id.clone()).or_insert(DomainState {
             domain: domain.clone(),
             disabled_capabilities: HashSet::new(),
+            protections: HashMap::new(),
+            disabled_until: HashMap::new(),
+            disabled_by: HashMap::new(),
         });
-        entry.domain = domain;
+        entry.domain = domain.clone();
+        self.event_log.append(&self.constitution, None, GovernanceEvent::DomainUpserted { domain });
+        Ok(warnings)
     }
 
     /// Core logic: check if a governance proposal *may* apply, and if so,
     /// compute the new DomainState after restrictions.
+    /// Pure preview: nothing is stored; use `commit_proposal` to apply.
+    /// Multi-domain proposals must use `evaluate_proposal_per_domain`.
     pub fn evaluate_proposal(
         &self,
         proposal: &GovernanceProposal,
         vote_outcome: &GovernanceVoteOutcome,
         current_height: u64,
     ) -> Result<Option<DomainState>, String> {
-        let state = match self.domains.get(&proposal.domain_id) {
-            Some(s) => s,
-            None => return Err("Unknown domain_id".into()),
+        let domain_ids = self.target_domain_ids(proposal)?;
+        if domain_ids.len() != 1 {
+            return Err(Reason::new(ReasonCode::CapabilityMultiDomain).into());
+        }
+        let yes_ratio = match self.passes_vote(proposal, vote_outcome, current_height)? {
+            Some(r) => r,
+            None => return Ok(None),
+        };
+        let state = &self.domains[&domain_ids[0]];
+        let evaluation = self.evaluate_domain(proposal, state, yes_ratio, current_height)?;
+        Ok(Some(evaluation.new_state))
+    }
+
+    /// Preview a proposal against every target domain, running the full
+    /// constitutional check per domain. Nothing is stored. Keyed (and
+    /// serialized) in domain id order.
+    pub fn evaluate_proposal_per_domain(
+        &self,
+        proposal: &GovernanceProposal,
+        vote_outcome: &GovernanceVoteOutcome,
+        current_height: u64,
+    ) -> Result<BTreeMap<String, DomainChangeResult>, String> {
+        let domain_ids = self.target_domain_ids(proposal)?;
+        let yes_ratio = self.passes_vote(proposal, vote_outcome, current_height)?;
+        let mut results = BTreeMap::new();
+        for domain_id in domain_ids {
+            let result = match yes_ratio {
+                None => DomainChangeResult::NotPassed,
+                Some(ratio) => {
+                    let state = &self.domains[&domain_id];
+                    match self.evaluate_domain(proposal, state, ratio, current_height) {
+                        Ok(evaluation) => {
+                            let (new_state, change) = evaluation.into_change(
+                                proposal,
+                                &domain_id,
+                                current_height,
+                                TallySource::CallerSupplied,
+                                self.constitution_fingerprint(),
+                            );
+                            DomainChangeResult::Passes {
+                                new_state: Box::new(new_state),
+                                change: Box::new(change),
+                            }
+                        }
+                        Err(reason) => DomainChangeResult::Rejected {
+                            reason: reason.to_string(),
+                            coded_reason: Some(reason),
+                        },
+                    }
+                }
+            };
+            results.insert(domain_id, result);
+        }
+        Ok(results)
+    }
+
+    /// Evaluate and, if the proposal passes in every target domain, atomically
+    /// replace the stored DomainStates and append an `AppliedChange` to each
+    /// domain's history. If any domain fails its constitutional checks nothing
+    /// is applied anywhere. Re-committing an already committed proposal is a no-op.
+    pub fn commit_proposal(
+        &mut self,
+        proposal: &GovernanceProposal,
+        vote_outcome: &GovernanceVoteOutcome,
+        current_height: u64,
+    ) -> Result<CommitOutcome, String> {
+        self.commit_with_source(proposal, vote_outcome, current_height, TallySource::CallerSupplied, None)
+    }
+
+    /// Commit despite safety-oracle vetoes. Requires `allow_oracle_override`
+    /// in the constitution and, for every vetoed domain, the `override_token`
+    /// reported in its veto record. The override is recorded in the history.
+    pub fn commit_proposal_with_override(
+        &mut self,
+        proposal: &GovernanceProposal,
+        vote_outcome: &GovernanceVoteOutcome,
+        current_height: u64,
+        oracle_override: &OracleOverride,
+    ) -> Result<CommitOutcome, String> {
+        if !self.constitution.allow_oracle_override {
+            return Err("Constitution does not allow overriding the safety oracle".into());
+        }
+        if oracle_override.acknowledged_by.is_empty() {
+            return Err("Oracle override must name who acknowledges it".into());
+        }
+        self.commit_with_source(
+            proposal,
+            vote_outcome,
+            current_height,
+            TallySource::CallerSupplied,
+            Some(oracle_override),
+        )
+    }
+
+    /// Commit using the outcome computed by `close_vote` for this proposal. The proposal must
+    /// be the one the vote was opened for: same content hash, same domain.
+    pub fn commit_tallied_proposal(
+        &mut self,
+        proposal: &GovernanceProposal,
+        current_height: u64,
+    ) -> Result<CommitOutcome, String> {
+        let session = self
+            .votes
+            .get(&proposal.proposal_id)
+            .ok_or_else(|| "No closed engine tally for this proposal".to_string())?;
+        let outcome = session
+            .outcome
+            .clone()
+            .ok_or_else(|| "No closed engine tally for this proposal".to_string())?;
+        session.check_proposal(proposal)?;
+        self.commit_with_source(proposal, &outcome, current_height, TallySource::EngineTallied, None)
+    }
+
+    fn commit_with_source(
+        &mut self,
+        proposal: &GovernanceProposal,
+        vote_outcome: &GovernanceVoteOutcome,
+        current_height: u64,
+        tally_source: TallySource,
+        oracle_override: Option<&OracleOverride>,
+    ) -> Result<CommitOutcome, String> {
+        if let Some(domain_ids) = self.committed.get(&proposal.proposal_id) {
+            let originals = domain_ids
+                .iter()
+                .filter_map(|domain_id| {
+                    self.history.get(domain_id)?.iter().find(|c| {
+                        c.kind == ChangeKind::Proposal && c.proposal_id == proposal.proposal_id
+                    })
+                })
+                .cloned()
+                .collect();
+            return Ok(CommitOutcome::AlreadyCommitted(originals));
+        }
+
+        let domain_ids = self.target_domain_ids(proposal)?;
+        let yes_ratio = match self.passes_vote(proposal, vote_outcome, current_height)? {
+            Some(r) => r,
+            None => return Ok(CommitOutcome::NotPassed),
+        };
+
+        // Stage every domain first; a single violation aborts the whole commit.
+        let mut staged = Vec::new();
+        for domain_id in &domain_ids {
+            let state = &self.domains[domain_id];
+            let evaluation = self
+                .evaluate_domain(proposal, state, yes_ratio, current_height)
+                .map_err(|e| format!("Domain {domain_id}: {e}"))?;
+            staged.push((domain_id.clone(), evaluation));
+        }
+
+        // Safety oracle: reviewed per domain against the stored state. Any
+        // veto not covered by an acknowledged override blocks the whole commit.
+        let mut reviews = HashMap::new();
+        let mut vetoes = Vec::new();
+        if let Some(oracle) = &self.safety_oracle {
+            for (domain_id, evaluation) in &staged {
+                if evaluation.newly_disabled.is_empty() {
+                    continue;
+                }
+                let verdict = oracle.review(&self.domains[domain_id], &evaluation.newly_disabled);
+                let token = oracle::override_token(
+                    &proposal.proposal_id,
+                    domain_id,
+                    &evaluation.newly_disabled,
+                );
+                let vetoed = matches!(verdict, OracleVerdict::Veto { .. });
+                let overridden = vetoed
+                    && oracle_override.is_some_and(|o| o.acknowledgment_tokens.contains(&token));
+                let review = OracleReview {
+                    verdict,
+                    reviewed_capabilities: evaluation.newly_disabled.clone(),
+                    override_token: token,
+                    overridden,
+                    acknowledged_by: match (overridden, oracle_override) {
+                        (true, Some(o)) => o.acknowledged_by.clone(),
+                        _ => Vec::new(),
+                    },
+                };
+                if vetoed && !overridden {
+                    vetoes.push(AppliedChange {
+                        domain_id: domain_id.clone(),
+                        proposal_id: proposal.proposal_id.clone(),
+                        kind: ChangeKind::OracleVeto,
+                        height: current_height,
+                        tally_source,
+                        newly_disabled: HashSet::new(),
+                        skipped_due_to_constitution: HashSet::new(),
+                        skipped_due_to_protection: HashSet::new(),
+                        skipped_due_to_threshold: HashSet::new(),
+                        protection_conflicts: HashSet::new(),
+                        re_enabled: HashSet::new(),
+                        newly_protected: HashSet::new(),
+                        unrecognized: HashSet::new(),
+                        oracle_review: Some(review),
+                        impact_statement: proposal.impact_statement.clone(),
+                        constitution_fingerprint: Some(self.constitution_fingerprint()),
+                    });
+                } else {
+                    reviews.insert(domain_id.clone(), review);
+                }
+            }
+        }
+        if !vetoes.is_empty() {
+            for veto in &vetoes {
+                self.history.entry(veto.domain_id.clone()).or_default().push(veto.clone());
+            }
+            self.event_log.append(
+                &self.constitution,
+                Some(current_height),
+                GovernanceEvent::OracleVetoed { vetoes: vetoes.clone() },
+            );
+            return Ok(CommitOutcome::Vetoed(vetoes));
+        }
+
+        let mut changes = Vec::new();
+        for (domain_id, evaluation) in staged {
+            let (new_state, mut change) = evaluation.into_change(
+                proposal,
+                &domain_id,
+                current_height,
+                tally_source,
+                self.constitution_fingerprint(),
+            );
+            change.oracle_review = reviews.remove(&domain_id);
+            self.domains.insert(domain_id.clone(), new_state);
+            self.history.entry(domain_id).or_default().push(change.clone());
+            changes.push(change);
+        }
+        self.committed.insert(proposal.proposal_id.clone(), domain_ids);
+        self.event_log.append(
+            &self.constitution,
+            Some(current_height),
+            GovernanceEvent::ProposalCommitted { changes: changes.clone() },
+        );
+        Ok(CommitOutcome::Applied(changes))
+    }
+
+    /// Resolve the proposal's target into existing domain ids.
+    fn target_domain_ids(&self, proposal: &GovernanceProposal) -> Result<Vec<String>, Reason> {
+        self.check_proposal_limits(proposal).map_err(|e| e.reason())?;
+        let ids = match &proposal.target {
+            None => vec![proposal.domain_id.clone()],
+            Some(ProposalTarget::Domain(id)) => vec![id.clone()],
+            Some(ProposalTarget::Domains(ids)) => {
+                let mut unique = Vec::new();
+                for id in ids {
+                    if !unique.contains(id) {
+                        unique.push(id.clone());
+                    }
+                }
+                unique
+            }
+            Some(ProposalTarget::AllDomains) => {
+                let mut all: Vec<String> = self.domains.keys().cloned().collect();
+                all.sort();
+                all
+            }
         };
+        if ids.is_empty() {
+            return Err(Reason::new(ReasonCode::CapabilityNoDomains));
+        }
+        for id in &ids {
+            if !self.domains.contains_key(id) {
+                return Err(Reason::new(ReasonCode::CapabilityUnknownDomain).with("domain", id));
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Structural checks that do not depend on votes or domain state, proposal limits first
+    /// and the impact statement last.
+    pub fn validate_proposal(&self, proposal: &GovernanceProposal) -> Result<(), Reason> {
+        self.check_proposal_limits(proposal).map_err(|e| e.reason())?;
+        if let Some(sunset) = proposal.sunset_height {
+            if sunset <= proposal.activation_height {
+                return Err(Reason::new(ReasonCode::CapabilitySunsetOrder));
+            }
+        }
+        for (cap, sunset) in &proposal.capability_sunsets {
+            if *sunset <= proposal.activation_height {
+                return Err(Reason::new(ReasonCode::CapabilityCapabilitySunsetOrder).with("capability", &cap.0));
+            }
+            if !proposal.restrict_capabilities.contains(cap) {
+                return Err(Reason::new(ReasonCode::CapabilitySunsetUnrestricted).with("capability", &cap.0));
+            }
+        }
+        self.check_impact_statement(proposal)
+    }
+
+    /// Re-enable every temporary restriction whose sunset height is at or
+    /// below `new_height`, recording each expiry in the domain's change history.
+    /// Match overrides that have run out are dropped as well.
+    pub fn advance_height(&mut self, new_height: u64) -> Vec<ExpiredRestriction> {
+        let mut expired = Vec::new();
+        let mut domain_ids: Vec<String> = self.domains.keys().cloned().collect();
+        domain_ids.sort();
+        for domain_id in domain_ids {
+            let state = self.domains.get_mut(&domain_id).expect("domain id from keys");
+            let mut lapsed: Vec<(CapabilityId, u64)> = state
+                .disabled_until
+                .iter()
+                .filter(|(_, sunset)| **sunset <= new_height)
+                .map(|(cap, sunset)| (cap.clone(), *sunset))
+                .collect();
+            lapsed.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0 .0.cmp(&b.0 .0)));
+
+            for (cap, sunset_height) in lapsed {
+                state.disabled_until.remove(&cap);
+                state.disabled_capabilities.remove(&cap);
+                let restricted_by = state.disabled_by.remove(&cap);
+
+                self.history.entry(domain_id.clone()).or_default().push(AppliedChange {
+                    domain_id: domain_id.clone(),
+                    proposal_id: restricted_by.clone().unwrap_or_default(),
+                    kind: ChangeKind::SunsetExpired,
+                    height: new_height,
+                    tally_source: TallySource::default(),
+                    newly_disabled: HashSet::new(),
+                    skipped_due_to_constitution: HashSet::new(),
+                    skipped_due_to_protection: HashSet::new(),
+                    skipped_due_to_threshold: HashSet::new(),
+                    protection_conflicts: HashSet::new(),
+                    re_enabled: [cap.clone()].into_iter().collect(),
+                    newly_protected: HashSet::new(),
+                    unrecognized: HashSet::new(),
+                    oracle_review: None,
+                    impact_statement: None,
+                    constitution_fingerprint: Some(self.constitution_fingerprint()),
+                });
+                expired.push(ExpiredRestriction {
+                    domain_id: domain_id.clone(),
+                    capability: cap,
+                    restricted_by,
+                    sunset_height,
+                });
+            }
+        }
+        if !expired.is_empty() {
+            self.event_log.append(
+                &self.constitution,
+                Some(new_height),
+                GovernanceEvent::SunsetsExpired { expired: expired.clone() },
+            );
+        }
+        self.expire_match_overrides(new_height);
+        expired
+    }
+
+    /// Applied changes for a domain, oldest first.
+    pub fn change_history(&self, domain_id: &str) -> &[AppliedChange] {
+        self.history
+            .get(domain_id)
+            .map(|h| h.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Proposal-level checks: structure, timing, and vote thresholds.
+    /// Returns the yes ratio when the proposal passes, `None` otherwise.
+    fn passes_vote(
+        &self,
+        proposal: &GovernanceProposal,
+        vote_outcome: &GovernanceVoteOutcome,
+        current_height: u64,
+    ) -> Result<Option<f64>, Reason> {
+        self.validate_proposal(proposal)?;
+        self.check_height(current_height)?;
+
+        // 0. Outcome sanity: never from the future, never older than the staleness limit.
+        if vote_outcome.proposal_id != proposal.proposal_id {
+            return Err(Reason::new(ReasonCode::CapabilityOutcomeMismatch));
+        }
+        if vote_outcome.finalized_height > current_height {
+            return Err(Reason::new(ReasonCode::CapabilityOutcomeFuture));
+        }
+        if let Some(max_age) = self.constitution.max_outcome_age_heights {
+            if current_height - vote_outcome.finalized_height > max_age {
+                return Err(Reason::new(ReasonCode::CapabilityOutcomeStale));
+            }
+        }
 
         // 1. Check height / timing: proposal cannot auto-apply before activation. [web:8]
         if current_height < proposal.activation_height || vote_outcome.finalized_height < proposal.activation_height {
             return Ok(None);
         }
 
-        // 2. Check supermajority threshold.
-        let total = vote_outcome.yes_weight + vote_outcome.no_weight;
+        // 2. Commit-reveal quorum: turnout, abstentions included, must reach it.
+        if !vote_outcome.meets_quorum().ok_or_else(|| Reason::new(ReasonCode::CapabilityWeightOverflow))? {
+            return Ok(None);
+        }
+
+        // 3. Check supermajority threshold.
+        let total = vote_outcome
+            .yes_weight
+            .checked_add(vote_outcome.no_weight)
+            .ok_or_else(|| Reason::new(ReasonCode::CapabilityWeightOverflow))?;
         if total == 0 {
             return Ok(None);
         }
@@ -127,53 +1050,228 @@ impl CapabilityGovernance {
             // Proposal fails; no change.
             return Ok(None);
         }
+        Ok(Some(yes_ratio))
+    }
 
+    /// Compute the tentative disabled set and protections without enforcing
+    /// floors or caps. Shared by `evaluate_domain` and `preview`.
+    fn stage_domain(
+        &self,
+        proposal: &GovernanceProposal,
+        state: &DomainState,
+        yes_ratio: f64,
+        current_height: u64,
+    ) -> StagedDomain {
         // 3. Compute tentative restricted set.
         let mut disabled = state.disabled_capabilities.clone();
+        let mut protections = state.protections.clone();
+        let mut skipped_due_to_constitution = HashSet::new();
+        let mut skipped_due_to_protection = HashSet::new();
+        let mut skipped_due_to_threshold = HashSet::new();
+        let mut protection_conflicts = HashSet::new();
+        let mut unrecognized = HashSet::new();
+        let mut restricted_now = HashSet::new();
         for cap in &proposal.restrict_capabilities {
-            // Apply constitutional non-restrictable list. [web:9]
-            if self.constitution.globally_nonrestrictable.contains(cap) {
+            // Only capabilities that belong to the domain can be restricted.
+            if !state.domain.allowed_capabilities.contains(cap) {
+                unrecognized.insert(cap.clone());
+                continue;
+            }
+            // Apply constitutional non-restrictable list and namespaces. [web:9]
+            if self.constitution.is_nonrestrictable(cap) {
+                skipped_due_to_constitution.insert(cap.clone());
                 continue;
             }
+            // Same proposal restricts and protects: protection wins.
+            if proposal.protect_capabilities.contains(cap) {
+                protection_conflicts.insert(cap.clone());
+                continue;
+            }
+            // Capability- or namespace-specific supermajority.
+            if let Some(threshold) = self.constitution.capability_threshold(cap) {
+                if yes_ratio < threshold {
+                    skipped_due_to_threshold.insert(cap.clone());
+                    continue;
+                }
+            }
+            // Earlier protection: only a higher supermajority may lift it.
+            if protections.contains_key(cap) {
+                if yes_ratio < self.constitution.protected_override_supermajority {
+                    skipped_due_to_protection.insert(cap.clone());
+                    continue;
+                }
+                protections.remove(cap);
+            }
             disabled.insert(cap.clone());
+            restricted_now.insert(cap.clone());
+        }
+
+        // Explicit protection removes capabilities from the disabled set,
+        // including ones disabled by earlier proposals (re-enable).
+        let mut re_enabled = HashSet::new();
+        let mut newly_protected = HashSet::new();
+        for cap in &proposal.protect_capabilities {
+            if disabled.remove(cap) && state.disabled_capabilities.contains(cap) {
+                re_enabled.insert(cap.clone());
+            }
+            if !protections.contains_key(cap) {
+                newly_protected.insert(cap.clone());
+            }
+            protections.insert(cap.clone(), ProtectionRecord {
+                proposal_id: proposal.proposal_id.clone(),
+                protected_at_height: current_height,
+            });
         }
 
-        // 4. Enforce domain and global capability floors.
-        let total_caps = state.domain.allowed_capabilities.len();
-        let disabled_count = disabled.len().min(total_caps);
+        StagedDomain {
+            disabled,
+            protections,
+            restricted_now,
+            skipped_due_to_constitution,
+            skipped_due_to_protection,
+            skipped_due_to_threshold,
+            protection_conflicts,
+            re_enabled,
+            newly_protected,
+            unrecognized,
+        }
+    }
+
+    /// Capabilities left disabled once hard safety protection is applied.
+    fn hard_protected_disabled(&self, disabled: &HashSet<CapabilityId>) -> HashSet<CapabilityId> {
+        disabled
+            .iter()
+            .filter(|cap| {
+                // Skip disabling safety capabilities.
+                !(self.constitution.hard_protect_safety_capabilities && self.constitution.is_nonrestrictable(cap))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Domain-level constitutional evaluation of a passed proposal.
+    fn evaluate_domain(
+        &self,
+        proposal: &GovernanceProposal,
+        state: &DomainState,
+        yes_ratio: f64,
+        current_height: u64,
+    ) -> Result<Evaluation, Reason> {
+        let StagedDomain {
+            disabled,
+            protections,
+            restricted_now,
+            skipped_due_to_constitution,
+            skipped_due_to_protection,
+            skipped_due_to_threshold,
+            protection_conflicts,
+            re_enabled,
+            newly_protected,
+            unrecognized,
+        } = self.stage_domain(proposal, state, yes_ratio, current_height);
+        if !unrecognized.is_empty()
+            && self.unknown_capability_policy == UnknownCapabilityPolicy::Strict
+        {
+            let mut ids: Vec<&str> = unrecognized.iter().map(|c| c.0.as_str()).collect();
+            ids.sort_unstable();
+            return Err(Reason::new(ReasonCode::CapabilityUnknownCapabilities).with("capabilities", ids.join(", ")));
+        }
+        if !skipped_due_to_threshold.is_empty()
+            && self.constitution.threshold_mode == ThresholdMode::RejectProposal
+        {
+            let mut ids: Vec<&str> = skipped_due_to_threshold.iter().map(|c| c.0.as_str()).collect();
+            ids.sort_unstable();
+            return Err(Reason::new(ReasonCode::CapabilityThresholdMissed).with("capabilities", ids.join(", ")));
+        }
+
+        // 4. Enforce domain and global capability floors, counting only
+        //    capabilities that actually belong to the domain.
+        let allowed = &state.domain.allowed_capabilities;
+        let total_caps = allowed.len();
+        let disabled_count = disabled.iter().filter(|c| allowed.contains(*c)).count();
         let enabled_count = total_caps - disabled_count;
 
         // Per-domain floor:
         if enabled_count < state.domain.min_capability_count {
-            return Err("Proposal would violate domain.min_capability_count; rejected".into());
+            return Err(Reason::new(ReasonCode::CapabilityDomainFloor));
         }
 
         // Global floor:
         if enabled_count < self.constitution.global_min_capability_floor {
-            return Err("Proposal would violate global_min_capability_floor; rejected".into());
+            return Err(Reason::new(ReasonCode::CapabilityGlobalFloor));
+        }
+
+        // Per-category floors:
+        let counts = state.domain.category_counts(&disabled, &self.catch_all_category);
+        if let Some(short) = counts.iter().find(|c| c.enabled < c.floor) {
+            return Err(Reason::new(ReasonCode::CapabilityCategoryFloor)
+                .with("category", &short.category)
+                .with("enabled", short.enabled)
+                .with("floor", short.floor));
         }
 
-        // Per-turn maximum restriction fraction:
-        let restrict_fraction = (disabled_count as f64) / (total_caps as f64);
+        // Per-turn maximum restriction fraction: only capabilities this proposal
+        // moves from enabled to disabled count against the per-turn cap.
+        let newly_disabled_count = disabled
+            .difference(&state.disabled_capabilities)
+            .filter(|c| allowed.contains(*c))
+            .count();
+        let restrict_fraction = (newly_disabled_count as f64) / (total_caps as f64);
         if restrict_fraction > self.constitution.max_restriction_fraction_per_turn {
-            return Err("Proposal over max_restriction_fraction_per_turn; rejected".into());
+            return Err(Reason::new(ReasonCode::CapabilityRestrictionFraction));
         }
 
-        // 5. Hard protection for safety capabilities (e.g., fail-safes, safe-exit, pause). [web:9]
-        let mut final_disabled = HashSet::new();
-        for cap in disabled {
-            if self.constitution.hard_protect_safety_capabilities
-                && self.constitution.globally_nonrestrictable.contains(&cap)
-            {
-                // Skip disabling this safety capability.
-                continue;
+        // Optional cumulative cap across all turns:
+        if let Some(max_cumulative) = self.constitution.max_cumulative_disabled_fraction {
+            let cumulative_fraction = (disabled_count as f64) / (total_caps as f64);
+            if cumulative_fraction > max_cumulative {
+                return Err(Reason::new(ReasonCode::CapabilityCumulativeFraction));
             }
-            final_disabled.insert(cap);
         }
 
+        // 5. Hard protection for safety capabilities (e.g., fail-safes, safe-exit, pause). [web:9]
+        let final_disabled = self.hard_protected_disabled(&disabled);
+
+        let newly_disabled = final_disabled
+            .difference(&state.disabled_capabilities)
+            .cloned()
+            .collect();
+
+        // Sunsets and attribution: restrictions applied now take this proposal's
+        // sunset (or become permanent); re-enabled capabilities drop their entries.
+        let mut disabled_until = state.disabled_until.clone();
+        let mut disabled_by = state.disabled_by.clone();
+        for cap in &restricted_now {
+            let sunset = proposal
+                .capability_sunsets
+                .get(cap)
+                .copied()
+                .or(proposal.sunset_height);
+            match sunset {
+                Some(h) => disabled_until.insert(cap.clone(), h),
+                None => disabled_until.remove(cap),
+            };
+            disabled_by.insert(cap.clone(), proposal.proposal_id.clone());
+        }
+        disabled_until.retain(|cap, _| final_disabled.contains(cap));
+        disabled_by.retain(|cap, _| final_disabled.contains(cap));
+
         let mut new_state = state.clone();
         new_state.disabled_capabilities = final_disabled;
-        Ok(Some(new_state))
+        new_state.protections = protections;
+        new_state.disabled_until = disabled_until;
+        new_state.disabled_by = disabled_by;
+        Ok(Evaluation {
+            new_state,
+            newly_disabled,
+            skipped_due_to_constitution,
+            skipped_due_to_protection,
+            skipped_due_to_threshold,
+            protection_conflicts,
+            re_enabled,
+            newly_protected,
+            unrecognized,
+        })
     }
 
     pub fn get_domain_state(&self, domain_id: &str) -> Option<&DomainState> {
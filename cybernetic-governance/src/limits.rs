@@ -0,0 +1,117 @@
+// path: cybernetic-governance/src/limits.rs
+
+//! Size limits on capability proposals.
+//! - Checked before any other proposal work: first thing in `validate_proposal` and in
+//!   resolving a proposal's target domains, so previews, evaluation and commits all refuse
+//!   an oversized proposal before walking its sets
+//! - Measured as set and list lengths (a `Domains` target counts every listed id,
+//!   duplicates included); limits are engine configuration and travel in snapshots
+
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use crate::{CapabilityGovernance, GovernanceProposal, ProposalTarget, Reason, ReasonCode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalLimit {
+    RestrictCapabilities,
+    ProtectCapabilities,
+    CapabilitySunsets,
+    TargetDomains,
+}
+
+impl fmt::Display for ProposalLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ProposalLimit::RestrictCapabilities => "restrict_capabilities",
+            ProposalLimit::ProtectCapabilities => "protect_capabilities",
+            ProposalLimit::CapabilitySunsets => "capability_sunsets",
+            ProposalLimit::TargetDomains => "target_domains",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProposalLimits {
+    pub max_restrict_capabilities: usize,
+    pub max_protect_capabilities: usize,
+    pub max_capability_sunsets: usize,
+    /// Ids listed by a `ProposalTarget::Domains` target.
+    pub max_target_domains: usize,
+}
+
+impl Default for ProposalLimits {
+    fn default() -> Self {
+        Self {
+            max_restrict_capabilities: 256,
+            max_protect_capabilities: 256,
+            max_capability_sunsets: 256,
+            max_target_domains: 64,
+        }
+    }
+}
+
+/// The first limit a proposal broke, with the proposal's measured size.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProposalLimitExceeded {
+    pub limit: ProposalLimit,
+    pub measured: usize,
+    pub max: usize,
+}
+
+impl ProposalLimitExceeded {
+    pub fn reason(&self) -> Reason {
+        Reason::new(ReasonCode::ProposalLimitExceeded)
+            .with("limit", self.limit)
+            .with("measured", self.measured)
+            .with("max", self.max)
+    }
+}
+
+impl fmt::Display for ProposalLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reason().fmt(f)
+    }
+}
+
+impl std::error::Error for ProposalLimitExceeded {}
+
+impl ProposalLimits {
+    pub fn check(&self, proposal: &GovernanceProposal) -> Result<(), ProposalLimitExceeded> {
+        let target_domains = match &proposal.target {
+            Some(ProposalTarget::Domains(ids)) => ids.len(),
+            _ => 1,
+        };
+        let measured = [
+            (ProposalLimit::RestrictCapabilities, proposal.restrict_capabilities.len(), self.max_restrict_capabilities),
+            (ProposalLimit::ProtectCapabilities, proposal.protect_capabilities.len(), self.max_protect_capabilities),
+            (ProposalLimit::CapabilitySunsets, proposal.capability_sunsets.len(), self.max_capability_sunsets),
+            (ProposalLimit::TargetDomains, target_domains, self.max_target_domains),
+        ];
+        match measured.into_iter().find(|(_, measured, max)| measured > max) {
+            Some((limit, measured, max)) => Err(ProposalLimitExceeded { limit, measured, max }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl CapabilityGovernance {
+    /// Replace the default proposal limits.
+    pub fn set_proposal_limits(&mut self, limits: ProposalLimits) {
+        self.proposal_limits = limits;
+    }
+
+    pub fn proposal_limits(&self) -> &ProposalLimits {
+        &self.proposal_limits
+    }
+
+    /// `proposal` against the engine's limits; intake can call this on its own to answer
+    /// before anything else.
+    pub fn check_proposal_limits(&self, proposal: &GovernanceProposal) -> Result<(), ProposalLimitExceeded> {
+        self.proposal_limits.check(proposal)
+    }
+}
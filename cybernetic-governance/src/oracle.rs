@@ -0,0 +1,115 @@
+// path: cybernetic-governance/src/oracle.rs
+
+//! Safety-oracle veto hook.
+//! - A `SafetyOracle` reviews restrictions at commit time (never at evaluate)
+//! - A veto blocks the commit and is recorded in the change history
+//! - Overriding a veto needs `allow_oracle_override` in the constitution plus
+//!   the acknowledgment token issued with each veto
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::{CapabilityId, DomainState};
+
+/// Verdict returned by a safety oracle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OracleVerdict {
+    Approve,
+    Veto { reasons: Vec<String> },
+}
+
+/// Automated safety system consulted before restrictions are committed.
+pub trait SafetyOracle: Send + Sync {
+    /// `domain` is the stored state before the change; `newly_disabled` the
+    /// capabilities the commit would switch off.
+    fn review(&self, domain: &DomainState, newly_disabled: &HashSet<CapabilityId>) -> OracleVerdict;
+}
+
+/// Oracle outcome attached to an `AppliedChange`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OracleReview {
+    pub verdict: OracleVerdict,
+    /// Capabilities the oracle was asked about.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub reviewed_capabilities: HashSet<CapabilityId>,
+    /// Token that must be echoed in an `OracleOverride` to force this change.
+    pub override_token: String,
+    /// True if a veto was overridden and the change applied anyway.
+    pub overridden: bool,
+    /// Who acknowledged the override; empty unless `overridden`.
+    #[serde(default)]
+    pub acknowledged_by: Vec<String>,
+}
+
+/// Explicit acknowledgment of oracle vetoes, required to override them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OracleOverride {
+    /// Who is taking responsibility for the override.
+    pub acknowledged_by: Vec<String>,
+    /// One `override_token` per vetoed domain, as reported in the veto record.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub acknowledgment_tokens: HashSet<String>,
+}
+
+/// Deterministic token binding a veto to (proposal, domain, capabilities).
+pub fn override_token(proposal_id: &str, domain_id: &str, caps: &HashSet<CapabilityId>) -> String {
+    let mut ids: Vec<&str> = caps.iter().map(|c| c.0.as_str()).collect();
+    ids.sort_unstable();
+    let mut hasher = Sha256::new();
+    hasher.update(proposal_id.as_bytes());
+    hasher.update(b"|");
+    hasher.update(domain_id.as_bytes());
+    hasher.update(b"|");
+    hasher.update(ids.join(",").as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Vetoes any restriction touching a capability currently in active use
+/// (e.g. `move:bci_shield` held by a connected athlete mid-match).
+#[derive(Debug, Default)]
+pub struct ActiveUseOracle {
+    /// domain_id -> capabilities currently in active use.
+    in_use: Mutex<HashMap<String, HashSet<CapabilityId>>>,
+}
+
+impl ActiveUseOracle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mark_in_use(&self, domain_id: &str, cap: CapabilityId) {
+        let mut in_use = self.in_use.lock().expect("active-use lock poisoned");
+        in_use.entry(domain_id.to_string()).or_default().insert(cap);
+    }
+
+    pub fn release(&self, domain_id: &str, cap: &CapabilityId) {
+        let mut in_use = self.in_use.lock().expect("active-use lock poisoned");
+        if let Some(caps) = in_use.get_mut(domain_id) {
+            caps.remove(cap);
+        }
+    }
+}
+
+impl SafetyOracle for ActiveUseOracle {
+    fn review(&self, domain: &DomainState, newly_disabled: &HashSet<CapabilityId>) -> OracleVerdict {
+        let in_use = self.in_use.lock().expect("active-use lock poisoned");
+        let Some(active) = in_use.get(&domain.domain.id) else {
+            return OracleVerdict::Approve;
+        };
+        let mut reasons: Vec<String> = newly_disabled
+            .intersection(active)
+            .map(|c| format!("{} is in active use", c.0))
+            .collect();
+        if reasons.is_empty() {
+            OracleVerdict::Approve
+        } else {
+            reasons.sort();
+            OracleVerdict::Veto { reasons }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// path: cybernetic-governance/src/preview.rs
+
+//! Voter-facing preview of what a proposal would change.
+//! - No vote outcome needed and nothing is mutated
+//! - Shows restrictions the constitution would silently drop, each with the rule dropping it:
+//!   the explicit non-restrictable list or a non-restrictable namespace
+//! - Reports the resulting enabled count against both floors and the per-turn cap, and per
+//!   category against the category floors
+//! - Shows per-capability required vs achieved yes ratios
+//! - Shows the proposal's impact statement, with any warnings about it
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    CapabilityGovernance, CapabilityId, CategoryCount, GovernanceProposal, GovernanceVoteOutcome, ImpactStatement,
+    NonrestrictableRule, Reason,
+};
+
+/// A restriction carrying its own constitutional threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ThresholdCheck {
+    pub capability: CapabilityId,
+    pub required_ratio: f64,
+    pub achieved_ratio: f64,
+    pub met: bool,
+}
+
+/// Preview of one target domain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DomainPreview {
+    pub domain_id: String,
+    /// Capabilities that would move from enabled to disabled.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub would_disable: HashSet<CapabilityId>,
+    /// Restrictions dropped because the constitution makes the capability non-restrictable.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub skipped_due_to_constitution: HashSet<CapabilityId>,
+    /// The rule dropping each of `skipped_due_to_constitution`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub constitution_rules: BTreeMap<CapabilityId, NonrestrictableRule>,
+    /// Already-disabled safety capabilities that hard protection would re-enable.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub skipped_due_to_hard_protection: HashSet<CapabilityId>,
+    /// Restrictions blocked by an earlier protection at the preview's yes ratio.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub skipped_due_to_protection: HashSet<CapabilityId>,
+    /// Restrictions whose per-capability threshold is not met.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub skipped_due_to_threshold: HashSet<CapabilityId>,
+    /// Capabilities both restricted and protected by the proposal; protection wins.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub protection_conflicts: HashSet<CapabilityId>,
+    /// Restrictions naming capabilities the domain does not allow.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub unknown: HashSet<CapabilityId>,
+    /// Disabled capabilities the proposal's protections would re-enable.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub would_re_enable: HashSet<CapabilityId>,
+    pub enabled_before: usize,
+    pub enabled_after: usize,
+    pub domain_floor: usize,
+    pub global_floor: usize,
+    /// Enabled capabilities per category after the change, sorted by category.
+    #[serde(default)]
+    pub categories_after: Vec<CategoryCount>,
+    /// Fraction of the domain newly disabled by this proposal.
+    pub restriction_fraction: f64,
+    pub max_restriction_fraction_per_turn: f64,
+    /// Why the constitution would reject this domain's change, if it would.
+    pub rejection: Option<String>,
+    /// `rejection` as a code and parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coded_rejection: Option<Reason>,
+}
+
+/// Preview of a proposal across all its target domains.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProposalPreview {
+    pub proposal_id: String,
+    /// Yes ratio the preview was computed at: the weakest passing ratio for
+    /// `preview`, the achieved ratio for `preview_with_outcome`.
+    pub yes_ratio: f64,
+    /// Requested restrictions carrying their own threshold, sorted by id.
+    pub thresholds: Vec<ThresholdCheck>,
+    pub domains: Vec<DomainPreview>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impact_statement: Option<ImpactStatement>,
+    /// Advisory findings, e.g. an affected-agent estimate far from what the element bridge sees.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Reason>,
+}
+
+impl CapabilityGovernance {
+    /// Show what `proposal` would change if it passed with the smallest
+    /// admissible supermajority, against the currently stored domain states.
+    pub fn preview(&self, proposal: &GovernanceProposal) -> Result<ProposalPreview, String> {
+        let assumed_yes_ratio = proposal
+            .required_supermajority
+            .max(self.constitution.min_supermajority_floor);
+        self.preview_at(proposal, assumed_yes_ratio)
+    }
+
+    /// Like `preview`, but at the yes ratio a (possibly still open) vote achieved.
+    pub fn preview_with_outcome(
+        &self,
+        proposal: &GovernanceProposal,
+        vote_outcome: &GovernanceVoteOutcome,
+    ) -> Result<ProposalPreview, String> {
+        if vote_outcome.proposal_id != proposal.proposal_id {
+            return Err("Vote outcome belongs to a different proposal".into());
+        }
+        let total = vote_outcome
+            .yes_weight
+            .checked_add(vote_outcome.no_weight)
+            .ok_or_else(|| "Vote weights overflow u128".to_string())?;
+        let achieved = if total == 0 {
+            0.0
+        } else {
+            vote_outcome.yes_weight as f64 / total as f64
+        };
+        self.preview_at(proposal, achieved)
+    }
+
+    fn preview_at(&self, proposal: &GovernanceProposal, yes_ratio: f64) -> Result<ProposalPreview, String> {
+        self.validate_proposal(proposal)?;
+
+        let mut thresholds: Vec<ThresholdCheck> = proposal
+            .restrict_capabilities
+            .iter()
+            .filter_map(|cap| {
+                let required_ratio = self.constitution.capability_threshold(cap)?;
+                Some(ThresholdCheck {
+                    capability: cap.clone(),
+                    required_ratio,
+                    achieved_ratio: yes_ratio,
+                    met: yes_ratio >= required_ratio,
+                })
+            })
+            .collect();
+        thresholds.sort_by(|a, b| a.capability.0.cmp(&b.capability.0));
+
+        let mut domains = Vec::new();
+        for domain_id in self.target_domain_ids(proposal)? {
+            let state = &self.domains[&domain_id];
+            let staged = self.stage_domain(proposal, state, yes_ratio, proposal.activation_height);
+            let final_disabled = self.hard_protected_disabled(&staged.disabled);
+
+            let allowed = &state.domain.allowed_capabilities;
+            let total_caps = allowed.len();
+            let enabled_before = total_caps
+                - state.disabled_capabilities.iter().filter(|c| allowed.contains(*c)).count();
+            let enabled_after =
+                total_caps - final_disabled.iter().filter(|c| allowed.contains(*c)).count();
+            let would_disable: HashSet<CapabilityId> = final_disabled
+                .difference(&state.disabled_capabilities)
+                .cloned()
+                .collect();
+            let restriction_fraction = if total_caps == 0 {
+                0.0
+            } else {
+                would_disable.len() as f64 / total_caps as f64
+            };
+            let coded_rejection = self
+                .evaluate_domain(proposal, state, yes_ratio, proposal.activation_height)
+                .err();
+
+            domains.push(DomainPreview {
+                domain_id,
+                would_disable,
+                skipped_due_to_hard_protection: staged
+                    .disabled
+                    .difference(&final_disabled)
+                    .cloned()
+                    .collect(),
+                constitution_rules: staged
+                    .skipped_due_to_constitution
+                    .iter()
+                    .filter_map(|cap| Some((cap.clone(), self.constitution.nonrestrictable_rule(cap)?)))
+                    .collect(),
+                skipped_due_to_constitution: staged.skipped_due_to_constitution,
+                skipped_due_to_protection: staged.skipped_due_to_protection,
+                skipped_due_to_threshold: staged.skipped_due_to_threshold,
+                protection_conflicts: staged.protection_conflicts,
+                unknown: staged.unrecognized,
+                would_re_enable: staged.re_enabled,
+                enabled_before,
+                enabled_after,
+                domain_floor: state.domain.min_capability_count,
+                global_floor: self.constitution.global_min_capability_floor,
+                categories_after: state.domain.category_counts(&final_disabled, &self.catch_all_category),
+                restriction_fraction,
+                max_restriction_fraction_per_turn: self.constitution.max_restriction_fraction_per_turn,
+                rejection: coded_rejection.as_ref().map(Reason::to_string),
+                coded_rejection,
+            });
+        }
+
+        Ok(ProposalPreview {
+            proposal_id: proposal.proposal_id.clone(),
+            yes_ratio,
+            thresholds,
+            domains,
+            impact_statement: proposal.impact_statement.clone(),
+            warnings: Vec::new(),
+        })
+    }
+}
@@ -0,0 +1,102 @@
+// path: cybernetic-governance/src/referee.rs
+
+//! Referee-issued, match-scoped capability overrides.
+//! - Only referees named in the constitution may issue them
+//! - Never touch `globally_nonrestrictable` capabilities or those in `nonrestrictable_namespaces`
+//! - Duration is capped by `max_match_override_heights`; they expire on their own
+//!   and are layered on top of domain state by `effective_capabilities`
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+
+use crate::{CapabilityGovernance, CapabilityId, GovernanceEvent};
+
+/// A short-lived exception for one match, without a governance proposal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MatchOverride {
+    pub match_id: String,
+    pub domain_id: String,
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub disabled: HashSet<CapabilityId>,
+    /// Referee id; must be in the constitution's `referees`.
+    pub issued_by: String,
+    pub valid_from_height: u64,
+    /// Exclusive: the override no longer applies at this height.
+    pub valid_to_height: u64,
+    pub rationale: String,
+}
+
+impl MatchOverride {
+    pub fn is_active_at(&self, height: u64) -> bool {
+        self.valid_from_height <= height && height < self.valid_to_height
+    }
+}
+
+impl CapabilityGovernance {
+    /// Register a referee override after checking it against the constitution.
+    pub fn issue_override(&mut self, match_override: MatchOverride, current_height: u64) -> Result<(), String> {
+        self.check_height(current_height)?;
+        if !self.constitution.referees.contains(&match_override.issued_by) {
+            return Err(format!("{} is not a registered referee", match_override.issued_by));
+        }
+        let state = self
+            .domains
+            .get(&match_override.domain_id)
+            .ok_or_else(|| format!("Unknown domain_id: {}", match_override.domain_id))?;
+        if match_override.disabled.is_empty() {
+            return Err("Override disables no capabilities".into());
+        }
+        let mut ids: Vec<&CapabilityId> = match_override.disabled.iter().collect();
+        ids.sort_by(|a, b| a.0.cmp(&b.0));
+        for cap in ids {
+            if self.constitution.is_nonrestrictable(cap) {
+                return Err(format!("Cannot override non-restrictable capability: {}", cap.0));
+            }
+            if !state.domain.allowed_capabilities.contains(cap) {
+                return Err(format!("Capability {} is not part of the domain", cap.0));
+            }
+        }
+        if match_override.valid_to_height <= match_override.valid_from_height {
+            return Err("valid_to_height must be later than valid_from_height".into());
+        }
+        let duration = match_override.valid_to_height - match_override.valid_from_height;
+        if duration > self.constitution.max_match_override_heights {
+            return Err(format!(
+                "Override lasts {duration} heights; max_match_override_heights is {}",
+                self.constitution.max_match_override_heights
+            ));
+        }
+        if match_override.valid_to_height <= current_height {
+            return Err("Override has already expired".into());
+        }
+
+        self.event_log.append(
+            &self.constitution,
+            Some(current_height),
+            GovernanceEvent::MatchOverrideIssued {
+                match_override: match_override.clone(),
+            },
+        );
+        self.match_overrides.push(match_override);
+        Ok(())
+    }
+
+    /// Overrides that have not yet been expired by `advance_height`.
+    pub fn match_overrides(&self) -> &[MatchOverride] {
+        &self.match_overrides
+    }
+
+    /// Drop overrides whose validity ended at or before `height`.
+    pub(crate) fn expire_match_overrides(&mut self, height: u64) {
+        let (expired, live): (Vec<MatchOverride>, Vec<MatchOverride>) = self
+            .match_overrides
+            .drain(..)
+            .partition(|o| o.valid_to_height <= height);
+        self.match_overrides = live;
+        if !expired.is_empty() {
+            let event = GovernanceEvent::MatchOverridesExpired { expired };
+            self.event_log.append(&self.constitution, Some(height), event);
+        }
+    }
+}
@@ -0,0 +1,298 @@
+// path: cybernetic-governance/src/snapshot.rs
+
+//! Persistence of the complete `CapabilityGovernance` state.
+//! - `snapshot()` captures constitution, domains, histories, votes, amendments
+//! - `restore()` checks the schema version and re-validates domain integrity: disabled sets
+//!   within the domain and its floors, sunsets and attributions only for disabled
+//!   capabilities, protections only for enabled ones; every vote's domain must exist and
+//!   its ballots, commitments and ballot leaves come from voters in its snapshot
+//! - `fixtures/arena_snapshot.json` is a golden snapshot of the arena fixture; the
+//!   `snapshot_restore` example restores it and checks it round-trips byte for byte
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{
+    AmendmentRecord, AppealRegistry, AppliedAmendment, AppliedChange, CapabilityGovernance, CategoryId, DomainState,
+    DomainTemplate, GovernanceConstitution, GovernanceEventLog, MatchOverride, ProposalLimits, UnknownCapabilityPolicy,
+    VoteSession,
+};
+
+/// Bump when the snapshot layout changes incompatibly.
+pub const GOVERNANCE_SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+/// Serializable image of a `CapabilityGovernance` engine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GovernanceSnapshot {
+    pub schema_version: u32,
+    pub constitution: GovernanceConstitution,
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    pub domains: HashMap<String, DomainState>,
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    pub history: HashMap<String, Vec<AppliedChange>>,
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    pub committed: HashMap<String, Vec<String>>,
+    pub unknown_capability_policy: UnknownCapabilityPolicy,
+    #[serde(default)]
+    pub proposal_limits: ProposalLimits,
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    pub votes: HashMap<String, VoteSession>,
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    pub amendments: HashMap<String, AmendmentRecord>,
+    pub amendment_history: Vec<AppliedAmendment>,
+    #[serde(default)]
+    pub event_log: GovernanceEventLog,
+    #[serde(default)]
+    pub match_overrides: Vec<MatchOverride>,
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub templates: HashMap<String, DomainTemplate>,
+    #[serde(default, serialize_with = "steward_ids::ordered::map")]
+    pub domain_templates: HashMap<String, String>,
+    #[serde(default)]
+    pub appeals: AppealRegistry,
+    #[serde(default = "crate::categories::default_catch_all_category")]
+    pub catch_all_category: CategoryId,
+}
+
+/// Why a snapshot could not be restored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RestoreError {
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
+    /// A domain is stored under a key different from its own id.
+    DomainKeyMismatch { key: String, domain_id: String },
+    /// A disabled capability is not one of the domain's allowed capabilities.
+    DisabledNotInDomain { domain_id: String, capability: String },
+    /// A sunset (`disabled_until`) or attribution (`disabled_by`) names a capability that
+    /// is not disabled.
+    RecordNotDisabled { domain_id: String, record: String, capability: String },
+    /// A protection names a capability the domain does not allow or has disabled.
+    InvalidProtection { domain_id: String, capability: String },
+    /// Enabled capabilities fall below the domain or global floor.
+    FloorViolated { domain_id: String, enabled: usize, floor: usize },
+    /// Categories fail `CompetitiveDomain::validate_categories`.
+    InvalidCategories { domain_id: String, reason: String },
+    /// Enabled capabilities of a category fall below its floor.
+    CategoryFloorViolated { domain_id: String, category: CategoryId, enabled: usize, floor: usize },
+    /// A committed proposal points at a domain that does not exist.
+    DanglingCommit { proposal_id: String, domain_id: String },
+    /// A vote, or its voter snapshot, is for a domain that does not exist.
+    DanglingVote { proposal_id: String, domain_id: String },
+    /// A ballot, commitment or ballot leaf is from a voter outside the vote's snapshot.
+    UnknownVoter { proposal_id: String, voter: String },
+    /// The event log's hash chain does not verify.
+    EventLogInvalid { index: usize, reason: String },
+}
+
+impl fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RestoreError::UnsupportedSchemaVersion { found, supported } => {
+                write!(f, "Unsupported snapshot schema version {found} (supported: {supported})")
+            }
+            RestoreError::DomainKeyMismatch { key, domain_id } => {
+                write!(f, "Domain stored under key {key} has id {domain_id}")
+            }
+            RestoreError::DisabledNotInDomain { domain_id, capability } => {
+                write!(f, "Domain {domain_id} disables {capability}, which it does not allow")
+            }
+            RestoreError::RecordNotDisabled { domain_id, record, capability } => {
+                write!(f, "Domain {domain_id} has a {record} entry for {capability}, which is not disabled")
+            }
+            RestoreError::InvalidProtection { domain_id, capability } => {
+                write!(f, "Domain {domain_id} protects {capability}, which it does not allow or has disabled")
+            }
+            RestoreError::FloorViolated { domain_id, enabled, floor } => {
+                write!(f, "Domain {domain_id} has {enabled} enabled capabilities, below floor {floor}")
+            }
+            RestoreError::InvalidCategories { domain_id, reason } => {
+                write!(f, "Domain {domain_id} has invalid categories: {reason}")
+            }
+            RestoreError::CategoryFloorViolated { domain_id, category, enabled, floor } => {
+                write!(f, "Domain {domain_id} has {enabled} enabled in category {category}, below floor {floor}")
+            }
+            RestoreError::DanglingCommit { proposal_id, domain_id } => {
+                write!(f, "Committed proposal {proposal_id} references unknown domain {domain_id}")
+            }
+            RestoreError::DanglingVote { proposal_id, domain_id } => {
+                write!(f, "Vote on {proposal_id} references unknown domain {domain_id}")
+            }
+            RestoreError::UnknownVoter { proposal_id, voter } => {
+                write!(f, "Vote on {proposal_id} records voter {voter}, who is not in its snapshot")
+            }
+            RestoreError::EventLogInvalid { index, reason } => {
+                write!(f, "Event log entry {index} is invalid: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {}
+
+impl CapabilityGovernance {
+    /// Capture the full engine state.
+    pub fn snapshot(&self) -> GovernanceSnapshot {
+        GovernanceSnapshot {
+            schema_version: GOVERNANCE_SNAPSHOT_SCHEMA_VERSION,
+            constitution: self.constitution.clone(),
+            domains: self.domains.clone(),
+            history: self.history.clone(),
+            committed: self.committed.clone(),
+            unknown_capability_policy: self.unknown_capability_policy,
+            proposal_limits: self.proposal_limits.clone(),
+            votes: self.votes.clone(),
+            amendments: self.amendments.clone(),
+            amendment_history: self.amendment_history.clone(),
+            event_log: self.event_log.clone(),
+            match_overrides: self.match_overrides.clone(),
+            templates: self.templates.clone(),
+            domain_templates: self.domain_templates.clone(),
+            appeals: self.appeals.clone(),
+            catch_all_category: self.catch_all_category.clone(),
+        }
+    }
+
+    /// Rebuild an engine from a snapshot, validating integrity first.
+    pub fn restore(snapshot: GovernanceSnapshot) -> Result<Self, RestoreError> {
+        if snapshot.schema_version != GOVERNANCE_SNAPSHOT_SCHEMA_VERSION {
+            return Err(RestoreError::UnsupportedSchemaVersion {
+                found: snapshot.schema_version,
+                supported: GOVERNANCE_SNAPSHOT_SCHEMA_VERSION,
+            });
+        }
+
+        let mut domain_ids: Vec<&String> = snapshot.domains.keys().collect();
+        domain_ids.sort();
+        for key in domain_ids {
+            let state = &snapshot.domains[key];
+            if &state.domain.id != key {
+                return Err(RestoreError::DomainKeyMismatch {
+                    key: key.clone(),
+                    domain_id: state.domain.id.clone(),
+                });
+            }
+            let allowed = &state.domain.allowed_capabilities;
+            let mut disabled: Vec<&str> = state.disabled_capabilities.iter().map(|c| c.0.as_str()).collect();
+            disabled.sort_unstable();
+            for cap in disabled {
+                if !allowed.iter().any(|a| a.0 == cap) {
+                    return Err(RestoreError::DisabledNotInDomain {
+                        domain_id: key.clone(),
+                        capability: cap.to_string(),
+                    });
+                }
+            }
+            let records = [
+                ("disabled_until", state.disabled_until.keys().map(|c| c.0.as_str()).collect::<Vec<_>>()),
+                ("disabled_by", state.disabled_by.keys().map(|c| c.0.as_str()).collect()),
+            ];
+            for (record, mut caps) in records {
+                caps.retain(|c| !state.disabled_capabilities.iter().any(|d| d.0 == *c));
+                caps.sort_unstable();
+                if let Some(cap) = caps.first() {
+                    return Err(RestoreError::RecordNotDisabled {
+                        domain_id: key.clone(),
+                        record: record.to_string(),
+                        capability: cap.to_string(),
+                    });
+                }
+            }
+            let mut invalid: Vec<&str> = state
+                .protections
+                .keys()
+                .filter(|c| !allowed.contains(*c) || state.disabled_capabilities.contains(*c))
+                .map(|c| c.0.as_str())
+                .collect();
+            invalid.sort_unstable();
+            if let Some(cap) = invalid.first() {
+                return Err(RestoreError::InvalidProtection { domain_id: key.clone(), capability: cap.to_string() });
+            }
+            let enabled = allowed.len() - state.disabled_capabilities.len();
+            let floor = state
+                .domain
+                .min_capability_count
+                .max(snapshot.constitution.global_min_capability_floor);
+            if enabled < floor {
+                return Err(RestoreError::FloorViolated {
+                    domain_id: key.clone(),
+                    enabled,
+                    floor,
+                });
+            }
+            state
+                .domain
+                .validate_categories(&snapshot.catch_all_category)
+                .map_err(|reason| RestoreError::InvalidCategories { domain_id: key.clone(), reason })?;
+            let counts = state.domain.category_counts(&state.disabled_capabilities, &snapshot.catch_all_category);
+            if let Some(short) = counts.into_iter().find(|c| c.enabled < c.floor) {
+                return Err(RestoreError::CategoryFloorViolated {
+                    domain_id: key.clone(),
+                    category: short.category,
+                    enabled: short.enabled,
+                    floor: short.floor,
+                });
+            }
+        }
+
+        for (proposal_id, domains) in &snapshot.committed {
+            for domain_id in domains {
+                if !snapshot.domains.contains_key(domain_id) {
+                    return Err(RestoreError::DanglingCommit {
+                        proposal_id: proposal_id.clone(),
+                        domain_id: domain_id.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut vote_ids: Vec<&String> = snapshot.votes.keys().collect();
+        vote_ids.sort();
+        for proposal_id in vote_ids {
+            let session = &snapshot.votes[proposal_id];
+            let mut domains = std::iter::once(&session.snapshot.domain_id).chain(session.domain_id.as_ref());
+            if let Some(domain_id) = domains.find(|d| !snapshot.domains.contains_key(*d)) {
+                return Err(RestoreError::DanglingVote {
+                    proposal_id: proposal_id.clone(),
+                    domain_id: domain_id.clone(),
+                });
+            }
+            let mut voters: Vec<&str> = session
+                .ballots
+                .keys()
+                .chain(session.ballot_leaves.keys())
+                .chain(session.commitments.keys())
+                .chain(session.final_ballots.iter())
+                .filter(|voter| !session.snapshot.weights.contains_key(*voter))
+                .map(|voter| voter.0.as_str())
+                .collect();
+            voters.sort_unstable();
+            if let Some(voter) = voters.first() {
+                return Err(RestoreError::UnknownVoter { proposal_id: proposal_id.clone(), voter: voter.to_string() });
+            }
+        }
+
+        snapshot
+            .event_log
+            .verify()
+            .map_err(|e| RestoreError::EventLogInvalid { index: e.index, reason: e.reason })?;
+
+        let mut engine = CapabilityGovernance::new(snapshot.constitution);
+        engine.domains = snapshot.domains;
+        engine.history = snapshot.history;
+        engine.committed = snapshot.committed;
+        engine.unknown_capability_policy = snapshot.unknown_capability_policy;
+        engine.proposal_limits = snapshot.proposal_limits;
+        engine.votes = snapshot.votes;
+        engine.amendments = snapshot.amendments;
+        engine.amendment_history = snapshot.amendment_history;
+        engine.event_log = snapshot.event_log;
+        engine.match_overrides = snapshot.match_overrides;
+        engine.templates = snapshot.templates;
+        engine.domain_templates = snapshot.domain_templates;
+        engine.appeals = snapshot.appeals;
+        engine.catch_all_category = snapshot.catch_all_category;
+        Ok(engine)
+    }
+}
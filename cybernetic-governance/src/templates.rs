@@ -0,0 +1,226 @@
+// path: cybernetic-governance/src/templates.rs
+
+//! League-wide domain templates.
+//! - `DomainTemplate` standardizes capability sets across arenas
+//! - `instantiate_domain` creates a new domain, never overwriting an existing one
+//! - `template_drift` reports how a live domain diverged after governance activity
+//! - Templates load from TOML (feature `toml`) so leagues can version them in git
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::{CapabilityGovernance, CapabilityId, CompetitiveDomain};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DomainTemplate {
+    pub template_id: String,
+    pub description: String,
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub capabilities: HashSet<CapabilityId>,
+    pub min_capability_count: usize,
+    /// Capabilities the league recommends treating as non-restrictable.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub recommended_nonrestrictable: HashSet<CapabilityId>,
+}
+
+/// Per-arena adjustments applied at instantiation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DomainOverrides {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub add_capabilities: HashSet<CapabilityId>,
+    /// May not include recommended non-restrictable capabilities.
+    #[serde(default, serialize_with = "steward_ids::ordered::set")]
+    pub remove_capabilities: HashSet<CapabilityId>,
+    /// May only raise the template minimum.
+    #[serde(default)]
+    pub min_capability_count: Option<usize>,
+}
+
+/// Divergence of a live domain from the template it was created from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateDrift {
+    pub domain_id: String,
+    pub template_id: String,
+    /// In the domain but not the template.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub added_capabilities: HashSet<CapabilityId>,
+    /// In the template but not the domain.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub removed_capabilities: HashSet<CapabilityId>,
+    /// Template capabilities currently disabled by governance.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub disabled_template_capabilities: HashSet<CapabilityId>,
+    pub template_min_capability_count: usize,
+    pub domain_min_capability_count: usize,
+    /// Recommended non-restrictable capabilities the constitution does not protect.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub unprotected_recommendations: HashSet<CapabilityId>,
+}
+
+impl DomainTemplate {
+    fn validate(&self) -> Result<(), String> {
+        if self.capabilities.len() < self.min_capability_count {
+            return Err(format!(
+                "Template {} has fewer capabilities than its min_capability_count",
+                self.template_id
+            ));
+        }
+        if let Some(cap) = self
+            .recommended_nonrestrictable
+            .iter()
+            .find(|c| !self.capabilities.contains(*c))
+        {
+            return Err(format!(
+                "Template {} recommends protecting {}, which it does not include",
+                self.template_id, cap.0
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// TOML layout: one `[[template]]` table per template.
+#[cfg(feature = "toml")]
+#[derive(Deserialize)]
+struct TemplateFile {
+    #[serde(default)]
+    template: Vec<DomainTemplate>,
+}
+
+/// Parse templates from a TOML document.
+#[cfg(feature = "toml")]
+pub fn templates_from_toml(source: &str) -> Result<Vec<DomainTemplate>, String> {
+    let file: TemplateFile = toml::from_str(source).map_err(|e| format!("Invalid template TOML: {e}"))?;
+    Ok(file.template)
+}
+
+impl CapabilityGovernance {
+    /// Add a template to the registry. Template ids are immutable once registered.
+    pub fn register_template(&mut self, template: DomainTemplate) -> Result<(), String> {
+        template.validate()?;
+        if self.templates.contains_key(&template.template_id) {
+            return Err(format!("Template {} already registered", template.template_id));
+        }
+        self.templates.insert(template.template_id.clone(), template);
+        Ok(())
+    }
+
+    /// Register every template in a TOML document; stops at the first error.
+    #[cfg(feature = "toml")]
+    pub fn load_templates_toml(&mut self, source: &str) -> Result<usize, String> {
+        let templates = templates_from_toml(source)?;
+        let count = templates.len();
+        for template in templates {
+            self.register_template(template)?;
+        }
+        Ok(count)
+    }
+
+    pub fn get_template(&self, template_id: &str) -> Option<&DomainTemplate> {
+        self.templates.get(template_id)
+    }
+
+    /// Create `domain_id` from a template. Fails if the domain already exists.
+    pub fn instantiate_domain(
+        &mut self,
+        template_id: &str,
+        domain_id: &str,
+        overrides: DomainOverrides,
+    ) -> Result<&CompetitiveDomain, String> {
+        if self.domains.contains_key(domain_id) {
+            return Err(format!("Domain {domain_id} already exists"));
+        }
+        let template = self
+            .templates
+            .get(template_id)
+            .ok_or_else(|| format!("Unknown template: {template_id}"))?;
+
+        for cap in &overrides.remove_capabilities {
+            if template.recommended_nonrestrictable.contains(cap) {
+                return Err(format!("Cannot remove recommended non-restrictable capability: {}", cap.0));
+            }
+            if !template.capabilities.contains(cap) {
+                return Err(format!("Template {template_id} does not include {}", cap.0));
+            }
+        }
+        let min_capability_count = match overrides.min_capability_count {
+            Some(min) if min < template.min_capability_count => {
+                return Err(format!(
+                    "min_capability_count {min} is below the template minimum {}",
+                    template.min_capability_count
+                ));
+            }
+            Some(min) => min,
+            None => template.min_capability_count,
+        };
+        let allowed_capabilities: HashSet<CapabilityId> = template
+            .capabilities
+            .difference(&overrides.remove_capabilities)
+            .cloned()
+            .chain(overrides.add_capabilities.iter().cloned())
+            .collect();
+        if allowed_capabilities.len() < min_capability_count {
+            return Err(format!(
+                "Domain would have {} capabilities, below min_capability_count {min_capability_count}",
+                allowed_capabilities.len()
+            ));
+        }
+
+        let domain = CompetitiveDomain {
+            id: domain_id.to_string(),
+            description: overrides
+                .description
+                .unwrap_or_else(|| template.description.clone()),
+            allowed_capabilities,
+            min_capability_count,
+            capability_categories: HashMap::new(),
+            category_floors: HashMap::new(),
+        };
+        self.upsert_domain(domain)?;
+        self.domain_templates
+            .insert(domain_id.to_string(), template_id.to_string());
+        Ok(&self.domains[domain_id].domain)
+    }
+
+    /// How `domain_id` differs from the template it was instantiated from.
+    pub fn template_drift(&self, domain_id: &str) -> Result<TemplateDrift, String> {
+        let state = self
+            .domains
+            .get(domain_id)
+            .ok_or_else(|| format!("Unknown domain_id: {domain_id}"))?;
+        let template_id = self
+            .domain_templates
+            .get(domain_id)
+            .ok_or_else(|| format!("Domain {domain_id} was not created from a template"))?;
+        let template = self
+            .templates
+            .get(template_id)
+            .ok_or_else(|| format!("Unknown template: {template_id}"))?;
+        let allowed = &state.domain.allowed_capabilities;
+
+        Ok(TemplateDrift {
+            domain_id: domain_id.to_string(),
+            template_id: template_id.clone(),
+            added_capabilities: allowed.difference(&template.capabilities).cloned().collect(),
+            removed_capabilities: template.capabilities.difference(allowed).cloned().collect(),
+            disabled_template_capabilities: state
+                .disabled_capabilities
+                .intersection(&template.capabilities)
+                .cloned()
+                .collect(),
+            template_min_capability_count: template.min_capability_count,
+            domain_min_capability_count: state.domain.min_capability_count,
+            unprotected_recommendations: template
+                .recommended_nonrestrictable
+                .iter()
+                .filter(|cap| !self.constitution.is_nonrestrictable(cap))
+                .cloned()
+                .collect(),
+        })
+    }
+}
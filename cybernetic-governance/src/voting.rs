@@ -0,0 +1,369 @@
+// path: cybernetic-governance/src/voting.rs
+
+//! Engine-side vote collection.
+//! - `VoterRegistry` holds voter weights and per-domain eligibility
+//! - A `VoterSnapshot` freezes eligible weights when a vote opens
+//! - Ballots are deduplicated per voter; the tally is computed by the engine
+//! - A vote is opened for one proposal: its content hash and domain are recorded, and
+//!   `commit_tallied_proposal` refuses a proposal that differs from the one voted on
+//! - `cast_vote` returns a `VoteReceipt`; closing the vote publishes the counted ballots'
+//!   leaves with the outcome, for `verify_receipt_inclusion`
+//! - A ballot cast with `cast` is final: `cast_vote` does not replace it. Its leaf is salted
+//!   from the proposal, the voter and the vote's proposal hash, so replays publish the same
+//!   leaves
+//! - Sessions opened with `open_commit_reveal_vote` take sealed ballots instead; see `commit_reveal`
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use steward_ids::receipts::{CountedBallots, VoteReceipt};
+
+use crate::amendment::sort_arrays;
+use crate::commit_reveal::CommitRevealWindow;
+use crate::{CapabilityGovernance, Did, GovernanceProposal, GovernanceVoteOutcome};
+
+/// Voter identity: a DID or an opaque arena-issued id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoterId(pub String);
+
+impl From<Did> for VoterId {
+    fn from(did: Did) -> Self {
+        VoterId(did.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoterRecord {
+    pub voter: VoterId,
+    pub weight: u128,
+    /// Domains in which this voter is eligible.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub eligible_domains: HashSet<String>,
+}
+
+/// Registry of known voters, their weights, and per-domain eligibility.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoterRegistry {
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    voters: HashMap<VoterId, VoterRecord>,
+}
+
+impl VoterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a voter or update their weight; eligibility is kept.
+    pub fn register(&mut self, voter: VoterId, weight: u128) {
+        self.voters
+            .entry(voter.clone())
+            .and_modify(|r| r.weight = weight)
+            .or_insert(VoterRecord {
+                voter,
+                weight,
+                eligible_domains: HashSet::new(),
+            });
+    }
+
+    pub fn set_eligibility(&mut self, voter: &VoterId, domain_id: &str, eligible: bool) -> Result<(), String> {
+        let record = self
+            .voters
+            .get_mut(voter)
+            .ok_or_else(|| format!("Unknown voter: {}", voter.0))?;
+        if eligible {
+            record.eligible_domains.insert(domain_id.to_string());
+        } else {
+            record.eligible_domains.remove(domain_id);
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, voter: &VoterId) -> Option<&VoterRecord> {
+        self.voters.get(voter)
+    }
+
+    /// Freeze the weights of every voter eligible in `domain_id`.
+    pub fn snapshot(&self, domain_id: &str) -> VoterSnapshot {
+        VoterSnapshot {
+            domain_id: domain_id.to_string(),
+            weights: self
+                .voters
+                .values()
+                .filter(|r| r.eligible_domains.contains(domain_id))
+                .map(|r| (r.voter.clone(), r.weight))
+                .collect(),
+        }
+    }
+}
+
+/// Eligible voters and weights frozen at vote opening.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoterSnapshot {
+    pub domain_id: String,
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    pub weights: HashMap<VoterId, u128>,
+}
+
+/// Where the vote outcome used for a commit came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TallySource {
+    /// Outcome asserted by the caller (back-compat path).
+    #[default]
+    CallerSupplied,
+    /// Outcome computed by `close_vote` from a voter snapshot.
+    EngineTallied,
+}
+
+/// An open or closed engine-collected vote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoteSession {
+    pub proposal_id: String,
+    /// `GovernanceProposal::content_hash` of the proposal the vote was opened for; `None` for
+    /// sessions opened before votes were bound to their proposal, which cannot be committed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proposal_hash: Option<String>,
+    /// Domain of the proposal the vote was opened for; `None` alongside `proposal_hash`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<String>,
+    pub snapshot: VoterSnapshot,
+    /// One ballot per voter: true = yes.
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    pub ballots: HashMap<VoterId, bool>,
+    /// Each ballot's receipt leaf; ballots from `cast` are committed under `sealed_salt`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "steward_ids::ordered::map")]
+    pub ballot_leaves: HashMap<VoterId, String>,
+    /// Voters whose ballot came from `cast`: it is final, and `cast_vote` refuses to replace it.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub final_ballots: BTreeSet<VoterId>,
+    /// Set once the vote is closed.
+    pub outcome: Option<GovernanceVoteOutcome>,
+    /// The ballots `outcome` counted, published with it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counted_ballots: Option<CountedBallots>,
+    /// Set for commit-reveal votes; direct votes leave it empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_reveal: Option<CommitRevealWindow>,
+    /// Commit-reveal commitments by voter; a ballot is in `ballots` once revealed.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "steward_ids::ordered::map")]
+    pub commitments: HashMap<VoterId, String>,
+}
+
+impl GovernanceProposal {
+    /// SHA-256 over canonical JSON: object keys sorted, set-valued fields in sorted order.
+    /// Binds a vote to the exact proposal it was opened for.
+    pub fn content_hash(&self) -> String {
+        let mut value = serde_json::to_value(self).expect("proposal serialization");
+        sort_arrays(&mut value);
+        let payload = serde_json::to_vec(&value).expect("hash serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&payload);
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+impl VoteSession {
+    /// Check that `proposal` is the one this vote was opened for.
+    pub fn check_proposal(&self, proposal: &GovernanceProposal) -> Result<(), String> {
+        let (Some(hash), Some(domain_id)) = (&self.proposal_hash, &self.domain_id) else {
+            return Err("Vote is not bound to a proposal; open a new vote".into());
+        };
+        if domain_id != &proposal.domain_id {
+            return Err(format!("Vote was opened in domain {domain_id}, not {}", proposal.domain_id));
+        }
+        if hash != &proposal.content_hash() {
+            return Err("Proposal differs from the one the vote was opened for".into());
+        }
+        Ok(())
+    }
+}
+
+impl CapabilityGovernance {
+    /// Open an engine-collected vote on `proposal` over a frozen voter snapshot of its domain.
+    /// The proposal's content hash and domain are recorded with the vote.
+    pub fn open_vote(&mut self, proposal: &GovernanceProposal, snapshot: VoterSnapshot) -> Result<(), String> {
+        let proposal_id = &proposal.proposal_id;
+        if self.votes.contains_key(proposal_id) {
+            return Err("Vote already opened for this proposal".into());
+        }
+        if snapshot.domain_id != proposal.domain_id {
+            return Err(format!(
+                "Voter snapshot is for domain {}, the proposal for {}",
+                snapshot.domain_id, proposal.domain_id
+            ));
+        }
+        self.votes.insert(
+            proposal_id.clone(),
+            VoteSession {
+                proposal_id: proposal_id.clone(),
+                proposal_hash: Some(proposal.content_hash()),
+                domain_id: Some(proposal.domain_id.clone()),
+                snapshot,
+                ballots: HashMap::new(),
+                ballot_leaves: HashMap::new(),
+                final_ballots: BTreeSet::new(),
+                outcome: None,
+                counted_ballots: None,
+                commit_reveal: None,
+                commitments: HashMap::new(),
+            },
+        );
+        Ok(())
+    }
+
+    /// `open_vote` for a proposal that passes `validate_proposal`, so a proposal missing
+    /// what the constitution requires (e.g. an impact statement) never reaches voters.
+    pub fn open_proposal_vote(&mut self, proposal: &GovernanceProposal, snapshot: VoterSnapshot) -> Result<(), String> {
+        self.validate_proposal(proposal)?;
+        self.open_vote(proposal, snapshot)
+    }
+
+    /// Cast a ballot. Each snapshot voter may vote exactly once.
+    pub fn cast(&mut self, proposal_id: &str, voter: &VoterId, support: bool) -> Result<(), String> {
+        let session = self.open_session_for(proposal_id, voter)?;
+        if session.ballots.contains_key(voter) {
+            return Err(format!("Voter {} has already voted", voter.0));
+        }
+        let leaf = session.sealed_leaf(voter, support);
+        session.ballots.insert(voter.clone(), support);
+        session.ballot_leaves.insert(voter.clone(), leaf);
+        session.final_ballots.insert(voter.clone());
+        Ok(())
+    }
+
+    /// Cast a ballot and get a receipt committing to it under the voter's `salt`. Unlike `cast`,
+    /// a voter may change their ballot until the vote closes; only the latest is counted, so
+    /// receipts for earlier ones stop verifying. A ballot cast with `cast` is final and is not
+    /// replaced. `cast_at` is the caller's height.
+    pub fn cast_vote(
+        &mut self,
+        proposal_id: &str,
+        voter: &VoterId,
+        support: bool,
+        salt: &str,
+        cast_at: u64,
+    ) -> Result<VoteReceipt, String> {
+        let session = self.open_session_for(proposal_id, voter)?;
+        if session.final_ballots.contains(voter) {
+            return Err(format!("Voter {} has already voted with a final ballot", voter.0));
+        }
+        let receipt = VoteReceipt::new(proposal_id, &voter.0, support, salt, cast_at);
+        session.ballots.insert(voter.clone(), support);
+        session.ballot_leaves.insert(voter.clone(), receipt.leaf());
+        Ok(receipt)
+    }
+
+    fn open_session_for(&mut self, proposal_id: &str, voter: &VoterId) -> Result<&mut VoteSession, String> {
+        let session = self
+            .votes
+            .get_mut(proposal_id)
+            .ok_or_else(|| "No open vote for this proposal".to_string())?;
+        if session.outcome.is_some() {
+            return Err("Vote already closed".into());
+        }
+        if session.commit_reveal.is_some() {
+            return Err("Commit-reveal vote: commit and reveal ballots instead".into());
+        }
+        if !session.snapshot.weights.contains_key(voter) {
+            return Err(format!("Voter {} is not in the vote snapshot", voter.0));
+        }
+        Ok(session)
+    }
+
+    /// Close the vote, compute yes/no weights from the snapshot and publish the counted ballots.
+    /// A commit-reveal vote closes once the height source reaches its `reveal_until`, and its
+    /// outcome records participation.
+    pub fn close_vote(&mut self, proposal_id: &str, finalized_height: u64) -> Result<GovernanceVoteOutcome, String> {
+        let height = match self.votes.get(proposal_id).and_then(|s| s.commit_reveal) {
+            Some(_) => Some(self.source_height()?),
+            None => None,
+        };
+        let session = self
+            .votes
+            .get_mut(proposal_id)
+            .ok_or_else(|| "No open vote for this proposal".to_string())?;
+        if session.outcome.is_some() {
+            return Err("Vote already closed".into());
+        }
+        let participation = match (&session.commit_reveal, height) {
+            (Some(window), Some(height)) if height < window.reveal_until => {
+                return Err(format!("Reveal window open until height {}", window.reveal_until));
+            }
+            (Some(window), _) => Some(session.participation(window)?),
+            (None, _) => None,
+        };
+
+        let mut yes_weight: u128 = 0;
+        let mut no_weight: u128 = 0;
+        for (voter, support) in &session.ballots {
+            let weight = *session
+                .snapshot
+                .weights
+                .get(voter)
+                .ok_or_else(|| format!("Voter {} is not in the vote snapshot", voter.0))?;
+            let total = if *support { &mut yes_weight } else { &mut no_weight };
+            *total = total
+                .checked_add(weight)
+                .ok_or_else(|| "Vote tally overflowed u128".to_string())?;
+        }
+        yes_weight
+            .checked_add(no_weight)
+            .ok_or_else(|| "Vote tally overflowed u128".to_string())?;
+
+        let outcome = GovernanceVoteOutcome {
+            proposal_id: proposal_id.to_string(),
+            yes_weight,
+            no_weight,
+            finalized_height,
+            participation,
+        };
+        let leaves = session.ballots.iter().map(|(voter, support)| match session.ballot_leaves.get(voter) {
+            Some(leaf) => leaf.clone(),
+            None => session.sealed_leaf(voter, *support),
+        });
+        session.counted_ballots = Some(CountedBallots::new(proposal_id, leaves));
+        session.outcome = Some(outcome.clone());
+        Ok(outcome)
+    }
+
+    /// The ballots counted by `proposal_id`'s closed vote, for `verify_receipt_inclusion`.
+    pub fn counted_ballots(&self, proposal_id: &str) -> Option<&CountedBallots> {
+        self.votes.get(proposal_id)?.counted_ballots.as_ref()
+    }
+
+    pub fn vote_session(&self, proposal_id: &str) -> Option<&VoteSession> {
+        self.votes.get(proposal_id)
+    }
+
+    /// Every vote, open or closed, by proposal id.
+    pub fn vote_sessions(&self) -> Vec<&VoteSession> {
+        let mut sessions: Vec<&VoteSession> = self.votes.values().collect();
+        sessions.sort_by(|a, b| a.proposal_id.cmp(&b.proposal_id));
+        sessions
+    }
+}
+
+impl VoteSession {
+    /// The salt of `voter`'s receipt-less ballot: SHA-256 over the proposal id, the voter and
+    /// the vote's proposal hash, so the same vote replayed publishes the same leaves.
+    fn sealed_salt(&self, voter: &VoterId) -> String {
+        let mut hasher = Sha256::new();
+        let nonce = self.proposal_hash.as_deref().unwrap_or("");
+        for part in ["sealed-ballot", self.proposal_id.as_str(), voter.0.as_str(), nonce] {
+            hasher.update(part.as_bytes());
+            hasher.update([0u8]);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// The receipt leaf of a ballot cast without a receipt.
+    fn sealed_leaf(&self, voter: &VoterId, support: bool) -> String {
+        VoteReceipt::new(&self.proposal_id, &voter.0, support, &self.sealed_salt(voter), 0).leaf()
+    }
+}
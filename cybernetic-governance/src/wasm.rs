@@ -0,0 +1,212 @@
+// path: cybernetic-governance/src/wasm.rs
+
+//! wasm-bindgen surface for browser voting UIs (feature `wasm`).
+//! - JSON strings in, JSON strings out; errors surface as JS exceptions
+//! - Lets voters preview constitutional effects client-side
+//! - Verifies exported event logs without a server round-trip
+//! - Each binding wraps a `try_` twin returning `String` errors, so native code (and the
+//!   `wasm_surface` example) exercises the same JSON in and out; `tests/wasm.rs` calls the
+//!   bindings themselves under `wasm-bindgen-test`
+
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    categories, AppealRegistry, CapabilityGovernance, DomainState, GovernanceConstitution, GovernanceEventLog,
+    GovernanceProposal, GovernanceSnapshot, GovernanceVoteOutcome, ProposalLimits, UnknownCapabilityPolicy,
+    GOVERNANCE_SNAPSHOT_SCHEMA_VERSION,
+};
+
+fn parse<T: serde::de::DeserializeOwned>(what: &str, json: &str) -> Result<T, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid {what} JSON: {e}"))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, String> {
+    serde_json::to_string(value).map_err(|e| e.to_string())
+}
+
+fn js_error(message: String) -> JsValue {
+    JsValue::from_str(&message)
+}
+
+/// A governance engine owned by JS.
+#[wasm_bindgen]
+pub struct WasmGovernance {
+    inner: CapabilityGovernance,
+}
+
+#[wasm_bindgen]
+impl WasmGovernance {
+    /// Engine with a constitution and no domains.
+    #[wasm_bindgen(constructor)]
+    pub fn new(constitution_json: &str) -> Result<WasmGovernance, JsValue> {
+        Self::try_new(constitution_json).map_err(js_error)
+    }
+
+    /// Engine from a constitution plus a JSON array of `DomainState`s, as
+    /// served by the arena. States are integrity-checked like a snapshot restore.
+    #[wasm_bindgen(js_name = withDomainStates)]
+    pub fn with_domain_states(constitution_json: &str, states_json: &str) -> Result<WasmGovernance, JsValue> {
+        Self::try_with_domain_states(constitution_json, states_json).map_err(js_error)
+    }
+
+    /// Engine from a full `GovernanceSnapshot`.
+    #[wasm_bindgen(js_name = fromSnapshot)]
+    pub fn from_snapshot(snapshot_json: &str) -> Result<WasmGovernance, JsValue> {
+        Self::try_from_snapshot(snapshot_json).map_err(js_error)
+    }
+
+    /// Warnings, as a JSON array of `Reason`s.
+    #[wasm_bindgen(js_name = upsertDomain)]
+    pub fn upsert_domain(&mut self, domain_json: &str) -> Result<String, JsValue> {
+        self.try_upsert_domain(domain_json).map_err(js_error)
+    }
+
+    /// `ProposalPreview` as JSON.
+    pub fn preview(&self, proposal_json: &str) -> Result<String, JsValue> {
+        self.try_preview(proposal_json).map_err(js_error)
+    }
+
+    /// Per-domain `DomainChangeResult`s as JSON.
+    #[wasm_bindgen(js_name = evaluateProposal)]
+    pub fn evaluate_proposal(
+        &self,
+        proposal_json: &str,
+        outcome_json: &str,
+        current_height: u64,
+    ) -> Result<String, JsValue> {
+        self.try_evaluate_proposal(proposal_json, outcome_json, current_height).map_err(js_error)
+    }
+
+    /// `CommitOutcome` as JSON.
+    #[wasm_bindgen(js_name = commitProposal)]
+    pub fn commit_proposal(
+        &mut self,
+        proposal_json: &str,
+        outcome_json: &str,
+        current_height: u64,
+    ) -> Result<String, JsValue> {
+        self.try_commit_proposal(proposal_json, outcome_json, current_height).map_err(js_error)
+    }
+
+    /// `EffectiveCapabilities` as JSON.
+    #[wasm_bindgen(js_name = effectiveCapabilities)]
+    pub fn effective_capabilities(
+        &self,
+        domain_id: &str,
+        current_height: u64,
+        match_id: Option<String>,
+    ) -> Result<String, JsValue> {
+        self.try_effective_capabilities(domain_id, current_height, match_id.as_deref()).map_err(js_error)
+    }
+
+    /// The whole engine as a `GovernanceSnapshot`, for `fromSnapshot` later.
+    pub fn snapshot(&self) -> Result<String, JsValue> {
+        self.try_snapshot().map_err(js_error)
+    }
+}
+
+/// The bindings above with `String` errors: the JS exception carries the same message.
+/// `JsValue` only exists inside a wasm host, so native callers and checks use these.
+impl WasmGovernance {
+    pub fn try_new(constitution_json: &str) -> Result<WasmGovernance, String> {
+        let constitution: GovernanceConstitution = parse("constitution", constitution_json)?;
+        Ok(Self {
+            inner: CapabilityGovernance::new(constitution),
+        })
+    }
+
+    pub fn try_with_domain_states(constitution_json: &str, states_json: &str) -> Result<WasmGovernance, String> {
+        let constitution: GovernanceConstitution = parse("constitution", constitution_json)?;
+        let states: Vec<DomainState> = parse("domain states", states_json)?;
+        let snapshot = GovernanceSnapshot {
+            schema_version: GOVERNANCE_SNAPSHOT_SCHEMA_VERSION,
+            constitution,
+            domains: states.into_iter().map(|s| (s.domain.id.clone(), s)).collect(),
+            history: HashMap::new(),
+            committed: HashMap::new(),
+            unknown_capability_policy: UnknownCapabilityPolicy::default(),
+            proposal_limits: ProposalLimits::default(),
+            votes: HashMap::new(),
+            amendments: HashMap::new(),
+            amendment_history: Vec::new(),
+            event_log: GovernanceEventLog::default(),
+            match_overrides: Vec::new(),
+            templates: HashMap::new(),
+            domain_templates: HashMap::new(),
+            appeals: AppealRegistry::default(),
+            catch_all_category: categories::default_catch_all_category(),
+        };
+        Self::restore(snapshot)
+    }
+
+    pub fn try_from_snapshot(snapshot_json: &str) -> Result<WasmGovernance, String> {
+        Self::restore(parse("snapshot", snapshot_json)?)
+    }
+
+    pub fn try_upsert_domain(&mut self, domain_json: &str) -> Result<String, String> {
+        to_json(&self.inner.upsert_domain(parse("domain", domain_json)?)?)
+    }
+
+    pub fn try_preview(&self, proposal_json: &str) -> Result<String, String> {
+        let proposal: GovernanceProposal = parse("proposal", proposal_json)?;
+        to_json(&self.inner.preview(&proposal)?)
+    }
+
+    pub fn try_evaluate_proposal(
+        &self,
+        proposal_json: &str,
+        outcome_json: &str,
+        current_height: u64,
+    ) -> Result<String, String> {
+        let proposal: GovernanceProposal = parse("proposal", proposal_json)?;
+        let outcome: GovernanceVoteOutcome = parse("vote outcome", outcome_json)?;
+        to_json(&self.inner.evaluate_proposal_per_domain(&proposal, &outcome, current_height)?)
+    }
+
+    pub fn try_commit_proposal(
+        &mut self,
+        proposal_json: &str,
+        outcome_json: &str,
+        current_height: u64,
+    ) -> Result<String, String> {
+        let proposal: GovernanceProposal = parse("proposal", proposal_json)?;
+        let outcome: GovernanceVoteOutcome = parse("vote outcome", outcome_json)?;
+        to_json(&self.inner.commit_proposal(&proposal, &outcome, current_height)?)
+    }
+
+    pub fn try_effective_capabilities(
+        &self,
+        domain_id: &str,
+        current_height: u64,
+        match_id: Option<&str>,
+    ) -> Result<String, String> {
+        to_json(&self.inner.effective_capabilities(domain_id, current_height, match_id)?)
+    }
+
+    pub fn try_snapshot(&self) -> Result<String, String> {
+        to_json(&self.inner.snapshot())
+    }
+
+    fn restore(snapshot: GovernanceSnapshot) -> Result<WasmGovernance, String> {
+        let inner = CapabilityGovernance::restore(snapshot).map_err(|e| e.to_string())?;
+        Ok(Self { inner })
+    }
+}
+
+/// Verify an exported JSONL event log. Returns `{"ok":true}` or
+/// `{"ok":false,"index":N,"reason":"..."}`.
+#[wasm_bindgen(js_name = verifyEventLog)]
+pub fn verify_event_log(jsonl: &str) -> Result<String, JsValue> {
+    try_verify_event_log(jsonl).map_err(js_error)
+}
+
+/// `verify_event_log` with a `String` error, for native callers.
+pub fn try_verify_event_log(jsonl: &str) -> Result<String, String> {
+    let log = GovernanceEventLog::from_jsonl(jsonl)?;
+    let result = match log.verify() {
+        Ok(()) => serde_json::json!({ "ok": true }),
+        Err(e) => serde_json::json!({ "ok": false, "index": e.index, "reason": e.reason }),
+    };
+    Ok(result.to_string())
+}
@@ -0,0 +1,75 @@
+// path: cybernetic-governance/tests/wasm.rs
+
+//! The `#[wasm_bindgen]` exports themselves, inside a wasm host
+//! (run with `wasm-pack test --node --features wasm,test-util`).
+//! - The arena's constitution and domain load through the constructor and `upsertDomain`
+//! - `preview` answers the lockdown as the native engine does
+//! - The native engine's exported log passes `verifyEventLog`; an edited entry is reported by index
+//! - Malformed JSON throws, and the exception carries the `try_` twin's message
+
+#![cfg(target_arch = "wasm32")]
+
+use cybernetic_governance::builders::{
+    bci_xr_arena, bci_xr_arena_constitution, bci_xr_arena_domain, bci_xr_lockdown, vote, ProposalBuilder,
+    BCI_XR_ARENA,
+};
+use cybernetic_governance::wasm::{verify_event_log, WasmGovernance};
+use serde_json::{json, Value};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("serializes")
+}
+
+fn value(json: &str) -> Value {
+    serde_json::from_str(json).expect("the surface returns JSON")
+}
+
+fn arena() -> WasmGovernance {
+    let mut web = WasmGovernance::new(&to_json(&bci_xr_arena_constitution())).expect("valid constitution");
+    let warnings = web.upsert_domain(&to_json(&bci_xr_arena_domain())).expect("valid domain");
+    assert_eq!(value(&warnings), json!([]));
+    web
+}
+
+#[wasm_bindgen_test]
+fn arena_loads_and_previews_as_natively() {
+    let web = arena();
+    let native = bci_xr_arena();
+    let (lockdown, _) = bci_xr_lockdown();
+    let preview = web.preview(&to_json(&lockdown)).expect("arena known");
+    let expected = native.preview(&lockdown).expect("arena known");
+    assert_eq!(value(&preview), serde_json::to_value(&expected).expect("serializes"));
+    let snapshot = value(&web.snapshot().expect("snapshot serializes"));
+    assert_eq!(snapshot, serde_json::to_value(native.snapshot()).expect("serializes"));
+}
+
+#[wasm_bindgen_test]
+fn event_log_verifies_and_pinpoints_an_edit() {
+    let mut native = bci_xr_arena();
+    let trim = ProposalBuilder::new("trim-pull", BCI_XR_ARENA).restrict(&["move:bci_pull"]).build();
+    native.commit_proposal(&trim, &vote("trim-pull", 80, 20, 1_010), 1_010).expect("commits");
+    let jsonl = native.export_log_jsonl();
+    assert_eq!(value(&verify_event_log(&jsonl).expect("parses")), json!({ "ok": true }));
+
+    let lines: Vec<&str> = jsonl.lines().collect();
+    let edited = lines.iter().position(|line| line.contains("trim-pull")).expect("the commit is logged");
+    let tampered: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| if i == edited { line.replace("trim-pull", "trim-push") } else { line.to_string() })
+        .collect();
+    let report = value(&verify_event_log(&tampered.join("\n")).expect("parses"));
+    assert_eq!((&report["ok"], &report["index"]), (&json!(false), &json!(edited)), "{report}");
+    assert!(verify_event_log("not a log").is_err());
+}
+
+#[wasm_bindgen_test]
+fn malformed_json_throws_the_native_message() {
+    let truncated = "{\"global_min_capability_floor\":";
+    let thrown = WasmGovernance::new(truncated).err().expect("truncated");
+    let expected = WasmGovernance::try_new(truncated).err().expect("truncated");
+    assert_eq!(thrown.as_string(), Some(expected));
+    let thrown = arena().preview("[]").expect_err("not a proposal");
+    assert!(thrown.as_string().expect("a string").starts_with("Invalid proposal JSON: "));
+}
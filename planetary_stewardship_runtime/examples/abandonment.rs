@@ -0,0 +1,104 @@
+// path: planetary_stewardship_runtime/examples/abandonment.rs
+
+//! Example: abandonment patterns reported to coordinators and fed into recommendations
+//! (run with `--features test-util`).
+//! - Three newcomers abandon the creek cleanup while three returning volunteers complete it
+//! - The report breaks the closings down by template, difficulty, skill and cohort; in
+//!   aggregate-only form it names no assignee
+//! - `recommend_for` ranks the cleanup below tree planting for a newcomer, but not for a
+//!   returning volunteer, and still recommends it to both
+//! - A volunteer abandoning three times in a row counts as a repeat abandoner
+
+use planetary_stewardship_runtime::builders::{
+    did, mission_id, saep_without_rollback_plans, ConsentRecordBuilder, MissionTemplateBuilder,
+};
+use planetary_stewardship_runtime::{
+    AbandonmentCounts, AbandonmentWindow, AssigneeCohort, AssignmentStatus, ConsentRegistry, Did, MicroMissionsEngine,
+    SaepEngine, StewardModule,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn counts(closed: usize, abandoned: usize) -> AbandonmentCounts {
+    AbandonmentCounts { closed, abandoned, expired: 0 }
+}
+
+/// Assign `mission` to `assignee` at `at_ms` and close it a minute later.
+fn run(missions: &mut MicroMissionsEngine, mission: &str, assignee: &Did, at_ms: u64, status: AssignmentStatus) {
+    let assigned = missions.assign_mission(&mission_id(mission), assignee.clone(), at_ms).expect("assigned");
+    missions.close_assignment(&assigned.key(), status, at_ms + 60_000).expect("closed");
+}
+
+fn main() {
+    let returning: Vec<Did> = (1..=3).map(|i| did(&format!("did:psv:steward:regular-{i}"))).collect();
+    let newcomers: Vec<Did> = (1..=3).map(|i| did(&format!("did:psv:steward:newcomer-{i}"))).collect();
+    let (dee, nia) = (did("did:psv:steward:dee"), did("did:psv:steward:nia"));
+    let mut consent = ConsentRegistry::new();
+    for volunteer in returning.iter().chain(&newcomers).chain([&dee, &nia]) {
+        for mission in ["creek-cleanup", "tree-planting"] {
+            let record = ConsentRecordBuilder::new(volunteer, StewardModule::MME).mission(&mission_id(mission));
+            consent.upsert_consent(record.at_ms(T0).build());
+        }
+    }
+    let config = saep_without_rollback_plans();
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), consent);
+    missions.add_template(MissionTemplateBuilder::new("creek-cleanup").difficulty("M").skill("litter picking").build());
+    missions.add_template(MissionTemplateBuilder::new("tree-planting").build());
+    let skills = ["litter picking".to_string()];
+    let now = T0 + DAY_MS;
+    let ranked = |missions: &MicroMissionsEngine, assignee: &Did| -> Vec<(String, f64)> {
+        let matches = missions.recommend_for(assignee, &skills, 0.5, now).expect("archive readable");
+        matches.into_iter().map(|m| (m.mission_id.to_string(), m.score)).collect()
+    };
+
+    // 1. No history: both templates fit fully, tied and ranked by id.
+    let tied = vec![("creek-cleanup".to_string(), 1.0), ("tree-planting".to_string(), 1.0)];
+    assert_eq!(ranked(&missions, &nia), tied);
+
+    // 2. Regulars plant trees, then complete the cleanup; three newcomers abandon it.
+    for (i, regular) in returning.iter().enumerate() {
+        let at = T0 + i as u64 * 1_000;
+        run(&mut missions, "tree-planting", regular, at, AssignmentStatus::Completed);
+        run(&mut missions, "creek-cleanup", regular, at + 600_000, AssignmentStatus::Completed);
+    }
+    for (i, newcomer) in newcomers.iter().enumerate() {
+        run(&mut missions, "creek-cleanup", newcomer, T0 + 3_600_000 + i as u64, AssignmentStatus::Abandoned);
+    }
+
+    // 3. The report: the cleanup's abandonments all come from first-time assignments.
+    let window = AbandonmentWindow { from_ms: Some(T0), until_ms: Some(now), aggregate_only: false };
+    let report = missions.abandonment_report(&window).expect("archive readable");
+    assert_eq!((report.overall, report.first_time, report.returning), (counts(9, 3), counts(6, 3), counts(3, 0)));
+    let creek = &report.by_template[&mission_id("creek-cleanup")];
+    assert_eq!((creek.cohort(AssigneeCohort::FirstTime), &creek.returning), (&counts(3, 3), &counts(3, 0)));
+    assert_eq!((report.by_difficulty["M"], report.by_difficulty["S"]), (counts(6, 3), counts(3, 0)));
+    assert_eq!(report.by_skill["litter picking"], counts(6, 3));
+    let named: Vec<&str> = report.assignees.iter().map(|row| row.assignee.as_str()).collect();
+    assert_eq!(named.len(), 6);
+    assert!(named.windows(2).all(|w| w[0] < w[1]), "rows are ordered by assignee id");
+
+    // 4. Recommendations: the newcomer gets the cleanup at half score, below tree planting;
+    //    a regular, whose cohort completed it, still gets it first. Neither loses it.
+    let for_newcomer = vec![("tree-planting".to_string(), 1.0), ("creek-cleanup".to_string(), 0.5)];
+    assert_eq!(ranked(&missions, &nia), for_newcomer);
+    assert_eq!(ranked(&missions, &returning[0]), tied);
+    assert_eq!(missions.recommend_for_skills(&skills, 0.5).len(), 2, "the skill match itself is unchanged");
+
+    // 5. Three abandonments in a row make a repeat abandoner; aggregate-only names no one.
+    for i in 0..3 {
+        run(&mut missions, "tree-planting", &dee, T0 + 7_200_000 + i * 600_000, AssignmentStatus::Abandoned);
+    }
+    let full = missions.abandonment_report(&window).expect("archive readable");
+    assert_eq!(full.repeat_abandoners, 1);
+    assert_eq!(full.assignees.iter().find(|row| row.assignee == dee).unwrap().trailing_abandonments, 3);
+    let aggregate =
+        missions.abandonment_report(&AbandonmentWindow { aggregate_only: true, ..window }).expect("archive readable");
+    assert_eq!((aggregate.repeat_abandoners, aggregate.assignees.len()), (1, 0));
+    let json = serde_json::to_string_pretty(&aggregate).expect("report json");
+    for volunteer in returning.iter().chain(&newcomers).chain([&dee]) {
+        assert!(!json.contains(volunteer.as_str()), "{volunteer} named in an aggregate-only report");
+    }
+    assert!(!json.contains("did:"));
+    println!("{json}");
+}
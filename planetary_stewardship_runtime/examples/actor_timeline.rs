@@ -0,0 +1,134 @@
+// path: planetary_stewardship_runtime/examples/actor_timeline.rs
+
+//! Example: per-actor timelines with advisory flags.
+//! - Four synthetic histories, each built to trip exactly one flag
+//! - Flags are metadata only: every attestation here is issued normally
+//! - Timelines serialize for the verifier dashboard
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::{
+    ActionRef, ConsentRecord, ConsentRegistry, Did, ImpactMetrics, PlanId, PlanetaryLedger, RollbackPlan,
+    SaepConfig, SaepEngine, SaepRefs, StewardModule, TimelineFlag, TimelineOptions,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const MINUTE_MS: u64 = 60_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn metrics(co2eq_reduced: f64) -> ImpactMetrics {
+    ImpactMetrics {
+        co2eq_reduced,
+        biodiversity_index_delta: 0.0,
+        biodiversity_detail: None,
+        restored_area_m2: 25.0,
+        avoided_emissions_co2eq: 0.0,
+        custom: Default::default(),
+    }
+}
+
+/// Issue under a fresh rollback plan, as SAEP's defaults require.
+fn issue(ledger: &mut PlanetaryLedger, actor: &Did, at_ms: u64, co2eq_reduced: f64, evidence_uri: &str) {
+    let reserved = ledger.reserve_attestation_id();
+    let plan = ledger
+        .register_rollback_plan(RollbackPlan {
+            id: PlanId(format!("rollback:{}", reserved.0)),
+            action_ref: ActionRef::Attestation(reserved),
+            steps: vec!["Revoke the attestation".into()],
+            responsible: actor.clone(),
+            created_ms: at_ms,
+        })
+        .expect("reserved id");
+    ledger
+        .issue_attestation_with_refs(
+            actor.clone(),
+            None,
+            format!("Field work, {co2eq_reduced} t CO2e"),
+            metrics(co2eq_reduced),
+            evidence_uri,
+            vec![],
+            at_ms,
+            SaepRefs { intent_entry_id: None, rollback_plan_id: Some(plan) },
+        )
+        .expect("attestation issued");
+}
+
+fn flag_name(flag: &TimelineFlag) -> &'static str {
+    match flag {
+        TimelineFlag::BurstCluster { .. } => "BurstCluster",
+        TimelineFlag::MetricOutlier { .. } => "MetricOutlier",
+        TimelineFlag::ReusedEvidence { .. } => "ReusedEvidence",
+        TimelineFlag::ReusedContentHash { .. } => "ReusedContentHash",
+        TimelineFlag::GapThenSpike { .. } => "GapThenSpike",
+    }
+}
+
+fn main() {
+    let burst = Did::new("did:psv:steward:burst").expect("valid DID");
+    let outlier = Did::new("did:psv:steward:outlier").expect("valid DID");
+    let reuse = Did::new("did:psv:steward:reuse").expect("valid DID");
+    let spike = Did::new("did:psv:steward:spike").expect("valid DID");
+
+    let mut consent = ConsentRegistry::new();
+    for actor in [&burst, &outlier, &reuse, &spike] {
+        consent.upsert_consent(ConsentRecord {
+            participant: actor.clone(),
+            module: StewardModule::PLGA,
+            mission: None,
+            consent_given: true,
+            timestamp_ms: T0,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        });
+    }
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(SaepConfig::default()),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    );
+
+    // Three identical claims in three minutes, then ordinary work.
+    for (i, at) in [0, MINUTE_MS, 2 * MINUTE_MS].into_iter().enumerate() {
+        issue(&mut ledger, &burst, T0 + at, 1.0, &format!("ipfs://burst-{i}"));
+    }
+    issue(&mut ledger, &burst, T0 + 7 * DAY_MS, 1.3, "ipfs://burst-3");
+
+    // Weekly claims around 1 t, then one at 9 t.
+    for (week, co2) in [1.0, 1.1, 0.9, 1.0, 1.05, 9.0].into_iter().enumerate() {
+        issue(&mut ledger, &outlier, T0 + week as u64 * 7 * DAY_MS, co2, &format!("ipfs://outlier-{week}"));
+    }
+
+    // The same photos, six weeks apart.
+    issue(&mut ledger, &reuse, T0, 1.0, "ipfs://creek-photos");
+    issue(&mut ledger, &reuse, T0 + 45 * DAY_MS, 1.2, "ipfs://creek-photos");
+
+    // Weekly claims, four months of silence, then six times the usual.
+    for (week, co2) in [1.0, 1.2, 0.8].into_iter().enumerate() {
+        issue(&mut ledger, &spike, T0 + week as u64 * 7 * DAY_MS, co2, &format!("ipfs://spike-{week}"));
+    }
+    issue(&mut ledger, &spike, T0 + 14 * DAY_MS + 120 * DAY_MS, 6.0, "ipfs://spike-3");
+
+    for (actor, expected) in [
+        (&burst, "BurstCluster"),
+        (&outlier, "MetricOutlier"),
+        (&reuse, "ReusedEvidence"),
+        (&spike, "GapThenSpike"),
+    ] {
+        let timeline = ledger.actor_timeline(actor, TimelineOptions::default());
+        let flags: Vec<&TimelineFlag> = timeline.entries.iter().flat_map(|e| &e.flags).collect();
+        println!("{actor}: {} entries, flags {}", timeline.entries.len(), serde_json::to_string(&flags).expect("flags json"));
+        assert_eq!(flags.len(), 1, "{actor} should trip exactly one flag");
+        assert_eq!(flag_name(flags[0]), expected);
+        assert!(timeline.entries.windows(2).all(|w| w[0].attestation.timestamp_ms <= w[1].attestation.timestamp_ms));
+    }
+
+    // Thresholds are the caller's: a stricter burst size silences the burst flag.
+    let lenient = TimelineOptions { burst_min_count: 4, ..TimelineOptions::default() };
+    assert_eq!(ledger.actor_timeline(&burst, lenient).flagged().count(), 0);
+
+    let dashboard = serde_json::to_value(ledger.actor_timeline(&spike, TimelineOptions::default())).expect("timeline json");
+    assert_eq!(dashboard["entries"][3]["gap_before_ms"], 120 * DAY_MS);
+}
@@ -0,0 +1,146 @@
+// path: planetary_stewardship_runtime/examples/assignment_archive.rs
+
+//! Example: a long-running deployment closing assignments into an archive (run with `--features test-util`).
+//! - Assigning and closing 100k missions keeps the working set at what is open; the time an
+//!   assignment takes at 100k archived entries stays within a small multiple of the time at 0
+//! - A `FileArchive` is queried by assignee, status and closing time, one line at a time,
+//!   and counted again on reopening
+//! - `assignment_state` round-trips through JSON: open assignments in full, the file archive
+//!   by path; a duplicate open assignment is refused
+//!
+//! Timings are noisy in debug builds; run with `--release` for representative numbers.
+
+use std::time::{Duration, Instant};
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    ArchiveDescriptor, AssignmentArchive, AssignmentFilter, AssignmentState, AssignmentStatus, ConsentRecord,
+    ConsentRegistry, Did, FileArchive, MicroMissionsEngine, MissionId, MissionTemplate, ReasonCode, SaepEngine,
+    StewardModule, ValidationCode,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const ARCHIVED: u64 = 100_000;
+const SAMPLE: u64 = 500;
+
+fn did(s: &str) -> Did {
+    Did::new(s).expect("valid DID")
+}
+
+fn engine(stewards: &[&Did]) -> MicroMissionsEngine {
+    let mut consent = ConsentRegistry::new();
+    let ids = ["creek-cleanup", "tree-watering"].map(|id| MissionId::new(id).expect("valid mission id"));
+    for (steward, mission) in stewards.iter().flat_map(|s| ids.iter().map(move |m| (s, m))) {
+        consent.upsert_consent(ConsentRecord {
+            participant: (*steward).clone(),
+            module: StewardModule::MME,
+            mission: Some(mission.clone()),
+            consent_given: true,
+            timestamp_ms: T0,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        });
+    }
+    let config = saep_without_rollback_plans();
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), consent);
+    for id in ids {
+        missions.add_template(MissionTemplate {
+            id: id.clone(),
+            title: id.to_string().replace('-', " "),
+            description: format!("Neighbourhood {id}"),
+            difficulty: "S".into(),
+            expected_impact: serde_json::json!({}),
+            location_hint: "geo".into(),
+            required_skills: vec![],
+            default_language: None,
+            localized_content: Default::default(),
+            high_demand: false,
+        });
+    }
+    missions
+}
+
+/// Assign and complete `count` missions from `*now`, timing only the assignments.
+fn churn(missions: &mut MicroMissionsEngine, steward: &Did, mission: &MissionId, now: &mut u64, n: u64) -> Duration {
+    let mut assigning = Duration::ZERO;
+    for _ in 0..n {
+        let started = Instant::now();
+        let assigned = missions.assign_mission(mission, steward.clone(), *now).expect("consented");
+        assigning += started.elapsed();
+        missions.close_assignment(&assigned.key(), AssignmentStatus::Completed, *now + 1).expect("open");
+        *now += 2;
+    }
+    assigning / n.max(1) as u32
+}
+
+fn main() {
+    let ana = did("did:psv:steward:ana");
+    let kofi = did("did:psv:steward:kofi");
+    let creek = MissionId::new("creek-cleanup").expect("valid mission id");
+    let watering = MissionId::new("tree-watering").expect("valid mission id");
+
+    // 1. Latency against archive size, with one long-lived assignment kept open throughout.
+    let mut missions = engine(&[&ana]);
+    missions.assign_mission(&watering, ana.clone(), T0).expect("consented");
+    let mut now = T0 + 1;
+    let mut samples = Vec::new();
+    for target in [0, 10_000, ARCHIVED] {
+        let archived = missions.assignment_archive().len() as u64;
+        churn(&mut missions, &ana, &creek, &mut now, target.saturating_sub(archived));
+        let archived = missions.assignment_archive().len();
+        samples.push((archived, churn(&mut missions, &ana, &creek, &mut now, SAMPLE)));
+    }
+    for (archived, per_assignment) in &samples {
+        println!("{archived:>7} archived: {per_assignment:?} per assignment");
+    }
+    let (first, last) = (samples[0].1, samples[samples.len() - 1].1);
+    assert!(last <= first * 4 + Duration::from_micros(50), "assignment slowed from {first:?} to {last:?}");
+    assert_eq!(missions.active_assignments().len(), 1);
+    let completed = AssignmentFilter { mission: Some(creek.clone()), ..AssignmentFilter::default() };
+    assert_eq!(missions.archive_query(&completed).count() as u64, ARCHIVED + SAMPLE);
+
+    // 2. A file archive, queried by assignee, status and closing window.
+    let path = std::env::temp_dir().join(format!("assignment-archive-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mut missions = engine(&[&ana, &kofi])
+        .with_assignment_archive(Box::new(FileArchive::open(&path).expect("temp dir is writable")));
+    for (i, steward) in [&ana, &kofi, &ana].into_iter().enumerate() {
+        let at = T0 + i as u64 * 1_000;
+        let assigned = missions.assign_mission(&creek, steward.clone(), at).expect("consented");
+        let status = if i == 1 { AssignmentStatus::Abandoned } else { AssignmentStatus::Completed };
+        missions.close_assignment(&assigned.key(), status, at + 500).expect("appended");
+    }
+    let open = missions.assign_mission(&watering, kofi.clone(), T0 + 5_000).expect("consented");
+    let anas = AssignmentFilter {
+        assignee: Some(ana.clone()),
+        closed_from_ms: Some(T0 + 1_000),
+        ..AssignmentFilter::default()
+    };
+    let found: Vec<_> = missions.archive_query(&anas).collect::<Result<_, _>>().expect("readable");
+    assert_eq!(found.iter().map(|r| r.closed_ts_ms).collect::<Vec<_>>(), [T0 + 2_500]);
+    let abandoned = AssignmentFilter { status: Some(AssignmentStatus::Abandoned), ..AssignmentFilter::default() };
+    let abandoned_by = missions.archive_query(&abandoned).filter_map(Result::ok).next().map(|r| r.assignment.assignee);
+    assert_eq!(abandoned_by, Some(kofi.clone()));
+    assert_eq!(FileArchive::open(&path).map(|a| a.len()), Ok(3));
+
+    // 3. The same mission, assignee and time cannot be open twice.
+    let refused = missions.assign_mission(&watering, kofi.clone(), T0 + 5_000).expect_err("already open");
+    assert_eq!(refused, format!("Mission tree-watering is already assigned to {kofi} at {}", T0 + 5_000));
+    let report = missions.validate_assignment(&watering, &kofi, T0 + 5_000);
+    assert!(report.has(ValidationCode::AlreadyAssigned));
+    assert_eq!(report.issues[0].reason.as_ref().map(|r| r.code), Some(ReasonCode::MissionAlreadyAssigned));
+
+    // 4. Persisted state names the file rather than copying it; restoring reopens it.
+    let json = serde_json::to_string(&missions.assignment_state()).expect("serializable");
+    let state: AssignmentState = serde_json::from_str(&json).expect("round-trips");
+    assert!(matches!(&state.archive, ArchiveDescriptor::File { path: p } if *p == path.display().to_string()));
+    let restored = engine(&[&ana, &kofi]).with_assignment_state(state).expect("file reopens");
+    assert_eq!(restored.active_assignment(&open.key()).map(|a| &a.assignee), Some(&kofi));
+    assert_eq!(restored.archive_query(&AssignmentFilter::default()).count(), 3);
+    let twice = AssignmentState { active: vec![open.clone(), open], archive: ArchiveDescriptor::InMemory(vec![]) };
+    assert!(engine(&[]).with_assignment_state(twice).is_err());
+
+    std::fs::remove_file(&path).expect("temp archive removed");
+    println!("{} archived assignments queried without loading them", restored.assignment_archive().len());
+}
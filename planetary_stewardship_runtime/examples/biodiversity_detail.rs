@@ -0,0 +1,133 @@
+// path: planetary_stewardship_runtime/examples/biodiversity_detail.rs
+
+//! Example: biodiversity deltas with the index and survey behind them (run with `--features test-util`).
+//! - Under a policy requiring detail, a nonzero delta without it is refused; a zero delta
+//!   needs none
+//! - A survey dated after the attestation, or before the policy's window, is refused, with
+//!   the window's edges themselves accepted; issuance and validation agree
+//! - Summaries keep Shannon deltas, richness counts and detail-less deltas apart
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AttestationRequest, BiodiversityDetail, BiodiversityTotal, ConsentRecord, ConsentRegistry, Did, ImpactMetrics,
+    IndexKind, MetricsPolicy, ModuleMetrics, PlanetaryLedger, SaepConfig, SaepEngine, SaepRefs, StewardModule,
+    ValidationCode,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn detail(index_kind: IndexKind, survey_date_ms: u64, species_observed: Option<u32>) -> BiodiversityDetail {
+    BiodiversityDetail {
+        index_kind,
+        survey_method: "transect".into(),
+        survey_date_ms,
+        species_observed,
+        reference_uri: "https://example.org/methods/riparian-transect-v2".into(),
+    }
+}
+
+fn request(actor: &Did, delta: f64, detail: Option<BiodiversityDetail>) -> AttestationRequest {
+    AttestationRequest {
+        actor_did: actor.clone(),
+        mission_id: None,
+        description: "Riparian replanting".into(),
+        module: StewardModule::PLGA,
+        impact_metrics: ImpactMetrics {
+            biodiversity_index_delta: delta,
+            biodiversity_detail: detail,
+            ..ImpactMetrics::default()
+        },
+        module_metrics: ModuleMetrics::new(),
+        evidence: vec![],
+        verifier_dids: vec![],
+        timestamp_ms: T0,
+        refs: SaepRefs::default(),
+        capture_window: None,
+        witness_endorsement: None,
+    }
+}
+
+/// Issue `r`, checking the dry run reports the same biodiversity outcome first.
+fn issue(ledger: &mut PlanetaryLedger, r: AttestationRequest) -> Result<(), String> {
+    let previewed = ledger
+        .validate_attestation(&r)
+        .issues
+        .into_iter()
+        .find(|i| i.code == ValidationCode::Biodiversity)
+        .map(|i| i.message);
+    let result = ledger
+        .issue_attestation(r.actor_did, r.mission_id, r.description, r.impact_metrics, "ipfs://survey", r.verifier_dids, r.timestamp_ms)
+        .map(|_| ());
+    assert_eq!(previewed.as_ref(), result.as_ref().err(), "dry run agrees");
+    result
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mut consent = ConsentRegistry::new();
+    consent.upsert_consent(ConsentRecord {
+        participant: ana.clone(),
+        module: StewardModule::PLGA,
+        mission: None,
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    });
+    let config = saep_without_rollback_plans();
+    let policy = MetricsPolicy { require_biodiversity_detail: true, max_survey_age_ms: 90 * DAY_MS };
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_metrics_policy(policy);
+
+    // 1. Required detail: a bare delta is refused, a zero delta needs nothing.
+    let refused = issue(&mut ledger, request(&ana, 0.12, None)).expect_err("delta without detail");
+    assert_eq!(refused, "Biodiversity delta requires biodiversity_detail (index, survey and reference)");
+    issue(&mut ledger, request(&ana, 0.0, None)).expect("no delta, no detail needed");
+
+    // 2. Survey date window: [T0 - 90 days, T0], edges included.
+    let shannon = |at_ms| Some(detail(IndexKind::ShannonDiversity, at_ms, None));
+    issue(&mut ledger, request(&ana, 0.12, shannon(T0))).expect("surveyed the same day");
+    issue(&mut ledger, request(&ana, 0.08, shannon(T0 - 90 * DAY_MS))).expect("at the window's start");
+    let stale = issue(&mut ledger, request(&ana, 0.05, shannon(T0 - 90 * DAY_MS - 1))).expect_err("one ms too old");
+    assert!(stale.starts_with("Biodiversity survey dated"), "{stale}");
+    issue(&mut ledger, request(&ana, 0.05, shannon(T0 + 1))).expect_err("surveyed after the attestation");
+
+    // 3. Internal consistency: richness needs a species count, every survey a reference.
+    let richness = |species| Some(detail(IndexKind::SpeciesRichness, T0 - DAY_MS, species));
+    let uncounted = issue(&mut ledger, request(&ana, 4.0, richness(None))).expect_err("no species count");
+    assert_eq!(uncounted, "Species richness detail must give species_observed");
+    issue(&mut ledger, request(&ana, 4.0, richness(Some(31)))).expect("counted");
+    let mut unreferenced = detail(IndexKind::CustomRef("doi:10.1000/ibi".into()), T0, None);
+    unreferenced.reference_uri = " ".into();
+    issue(&mut ledger, request(&ana, 0.3, Some(unreferenced))).expect_err("no methodology reference");
+
+    // 4. Summaries: one total per index, in kind order; the zero-delta record is left out.
+    let summary = ledger.impact_summary(Some(&ana));
+    println!("{}", serde_json::to_string_pretty(&summary.biodiversity_by_index).expect("totals json"));
+    assert_eq!(summary.attestations, 4);
+    assert_eq!(
+        summary.biodiversity_by_index,
+        [
+            BiodiversityTotal { index_kind: Some(IndexKind::ShannonDiversity), attestations: 2, delta: 0.12 + 0.08 },
+            BiodiversityTotal { index_kind: Some(IndexKind::SpeciesRichness), attestations: 1, delta: 4.0 },
+        ]
+    );
+
+    // 5. The default policy accepts a bare delta and reports it as unspecified.
+    let open = SaepConfig { enforce_informed_consent: false, ..saep_without_rollback_plans() };
+    let mut lenient = PlanetaryLedger::new(SaepEngine::new(open), ConsentRegistry::new());
+    assert_eq!(lenient.metrics_policy(), &MetricsPolicy::default());
+    issue(&mut lenient, request(&ana, 0.12, None)).expect("detail not required by default");
+    let unspecified = &lenient.impact_summary(None).biodiversity_by_index;
+    assert_eq!(unspecified, &[BiodiversityTotal { index_kind: None, attestations: 1, delta: 0.12 }]);
+}
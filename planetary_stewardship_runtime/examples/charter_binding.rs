@@ -0,0 +1,102 @@
+// path: planetary_stewardship_runtime/examples/charter_binding.rs
+
+//! Example: changing which modules the co-stewardship charter binds (run with `--features test-util`).
+//! - CSC starts unbound; binding it needs no proposal
+//! - Unbinding VET is refused for a module-scoped proposal, a simple majority, an unapplied
+//!   proposal and a proposal naming another module
+//! - An ecosystem-wide proposal passed with a supermajority unbinds VET once, and only once
+//! - Every change and refusal is in the audit trail with the set's hash before and after
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    charter_set_hash, AppliedProposalRef, CharterError, Did, GovernanceAuditAction, GovernanceAuditOutcome,
+    GovernanceEngine, GovernanceProposal, GovernanceScope, ModuleId, QuadraticOutcome, SaepEngine,
+    StewardModule, DEFAULT_CHARTER_BOUND_MODULES, UNBIND_MODULE_KEY,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn unbind_proposal(id: &str, scope: GovernanceScope, module: &str) -> GovernanceProposal {
+    GovernanceProposal {
+        proposal_id: id.into(),
+        scope,
+        title: format!("Release {module} from the charter"),
+        description: format!("Move {module} under ordinary module governance"),
+        payload: serde_json::json!({ UNBIND_MODULE_KEY: module }),
+        can_introduce_restrictions: false,
+    }
+}
+
+fn votes(proposal: &GovernanceProposal, support: f64, opposition: f64) -> QuadraticOutcome {
+    QuadraticOutcome { proposal_id: proposal.proposal_id.clone(), total_support: support, total_opposition: opposition }
+}
+
+fn main() {
+    let ops = Did::new("did:psv:steward:ops").expect("valid DID");
+    let config = saep_without_rollback_plans();
+    let mut governance = GovernanceEngine::new(SaepEngine::new(config.clone())).with_unbind_supermajority(0.5);
+    assert_eq!(governance.charter_bound_modules(), DEFAULT_CHARTER_BOUND_MODULES);
+    assert!(!governance.is_charter_bound(StewardModule::CSC));
+
+    // 1. Binding is always allowed; binding twice is a no-op.
+    assert!(governance.bind_module(StewardModule::CSC, ops.clone(), T0));
+    assert!(!governance.bind_module(StewardModule::CSC, ops.clone(), T0 + 1));
+    let deployment = GovernanceEngine::with_charter_bound_modules(SaepEngine::new(config), [StewardModule::CSC]);
+    assert_eq!(deployment.charter_bound_modules(), [StewardModule::CSC]);
+
+    // 2. Refusals. A module-scoped proposal cannot touch the charter, however it passed.
+    let scoped = unbind_proposal("prop-vet-local", GovernanceScope::Module(ModuleId("VET".into())), "VET");
+    let scoped_ref = governance.apply_proposal(scoped.clone(), votes(&scoped, 10.0, 0.0), T0 + 2).expect("passes");
+    let err = governance.unbind_module(StewardModule::VET, scoped_ref, ops.clone(), T0 + 3).expect_err("module-scoped");
+    println!("refused: {err}");
+    assert!(matches!(err, CharterError::NotEcosystemWide { .. }));
+
+    // A simple majority is not enough; `with_unbind_supermajority(0.5)` did not lower the floor.
+    let narrow = unbind_proposal("prop-vet-narrow", GovernanceScope::EcosystemWide, "VET");
+    let narrow_ref = governance.apply_proposal(narrow.clone(), votes(&narrow, 6.0, 4.0), T0 + 4).expect("passes");
+    let err = governance.unbind_module(StewardModule::VET, narrow_ref, ops.clone(), T0 + 5).expect_err("60% support");
+    println!("refused: {err}");
+    assert!(matches!(err, CharterError::InsufficientMajority { required, .. } if (required - 2.0 / 3.0).abs() < 1e-9));
+
+    // Only applied proposals count, and a proposal authorizes only the module it names.
+    let unknown = AppliedProposalRef { proposal_id: "prop-never-voted".into() };
+    let err = governance.unbind_module(StewardModule::VET, unknown, ops.clone(), T0 + 6).expect_err("not applied");
+    assert!(matches!(err, CharterError::UnknownProposal { .. }));
+    let dccn = unbind_proposal("prop-dccn", GovernanceScope::EcosystemWide, "DCCN");
+    let dccn_ref = governance.apply_proposal(dccn.clone(), votes(&dccn, 9.0, 1.0), T0 + 7).expect("passes");
+    let err = governance.unbind_module(StewardModule::VET, dccn_ref, ops.clone(), T0 + 8).expect_err("names DCCN");
+    assert!(matches!(err, CharterError::WrongModule { named: Some(StewardModule::DCCN), .. }));
+    assert!(governance.is_charter_bound(StewardModule::VET));
+
+    // A proposal that did not pass is never applied, and ids are applied once.
+    let failed = unbind_proposal("prop-failed", GovernanceScope::EcosystemWide, "VET");
+    assert!(governance.apply_proposal(failed.clone(), votes(&failed, 1.0, 3.0), T0 + 9).is_err());
+    assert!(governance.apply_proposal(dccn.clone(), votes(&dccn, 9.0, 1.0), T0 + 10).is_err());
+
+    // 3. A supermajority ecosystem-wide proposal unbinds VET, once.
+    let broad = unbind_proposal("prop-vet", GovernanceScope::EcosystemWide, "VET");
+    let broad_ref = governance.apply_proposal(broad.clone(), votes(&broad, 8.0, 2.0), T0 + 11).expect("passes");
+    let before = charter_set_hash(&governance.charter_bound_modules().into_iter().collect());
+    governance.unbind_module(StewardModule::VET, broad_ref.clone(), ops.clone(), T0 + 12).expect("authorized");
+    assert!(!governance.is_charter_bound(StewardModule::VET));
+    assert!(governance.applied_proposal(&broad_ref).expect("applied").spent);
+
+    governance.bind_module(StewardModule::VET, ops.clone(), T0 + 13);
+    let err = governance.unbind_module(StewardModule::VET, broad_ref, ops, T0 + 14).expect_err("already used");
+    assert!(matches!(err, CharterError::AuthorizationSpent { .. }));
+
+    // 4. The audit trail: two binds, one unbind, five refusals.
+    let trail = governance.audit_trail();
+    println!("{}", serde_json::to_string_pretty(trail).expect("audit json"));
+    assert_eq!(trail.len(), 8);
+    assert_eq!(trail.iter().filter(|e| matches!(e.outcome, GovernanceAuditOutcome::Vetoed { .. })).count(), 5);
+    let unbound = trail
+        .iter()
+        .find(|e| e.outcome == GovernanceAuditOutcome::Applied && matches!(e.action, GovernanceAuditAction::UnbindCharter { .. }))
+        .expect("unbind audited");
+    let GovernanceAuditAction::UnbindCharter { before_hash, after_hash, .. } = &unbound.action else {
+        unreachable!()
+    };
+    assert_eq!(before_hash, &before);
+    assert_ne!(before_hash, after_hash);
+}
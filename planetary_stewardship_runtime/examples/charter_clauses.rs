@@ -0,0 +1,139 @@
+// path: planetary_stewardship_runtime/examples/charter_clauses.rs
+
+//! Example: vetoes that cite the charter clause they enforce (run with `--features test-util`).
+//! - The founding charter has one clause, which the built-in rule against military use cites;
+//!   a vetoed pause records the clause id and an excerpt, and a vetoed proposal's reason (the
+//!   one a preview shows) carries them too
+//! - A rule against surveillance cannot be set before the charter has a clause for it
+//! - Version 2, adopted through an ecosystem-wide proposal, adds that clause; only the new
+//!   clause is stamped with the proposal, and the audit trail has the document hashes
+//! - Version 3 drops the clause: the applied proposal reports the orphaned rule at once, and
+//!   the rule, still enforced, cites version 2's text
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AdminAction, CharterClause, CharterDocument, CharterError, CharterRule, CharterRuleSet, ConfigChange, Did,
+    DualControl, DualControlPolicy, GovernanceAuditAction, GovernanceAuditOutcome, GovernanceEngine, GovernanceProposal,
+    GovernanceScope, ModuleId, OrphanedCharterRule, QuadraticOutcome, ReasonCode, SaepEngine, StewardModule,
+    NON_WEAPONIZATION_CLAUSE,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const DATA_DIGNITY: &str = "data-dignity";
+
+fn adoption(id: &str, version: u32, clauses: Vec<CharterClause>) -> GovernanceProposal {
+    let change = ConfigChange::AdoptCharter { document: CharterDocument { version, clauses } };
+    let (title, description) = change.describe();
+    GovernanceProposal {
+        proposal_id: id.into(),
+        scope: change.scope(),
+        title,
+        description,
+        payload: change.to_payload(),
+        can_introduce_restrictions: change.introduces_restrictions(),
+    }
+}
+
+fn passed(proposal: &GovernanceProposal) -> QuadraticOutcome {
+    QuadraticOutcome { proposal_id: proposal.proposal_id.clone(), total_support: 9.0, total_opposition: 1.0 }
+}
+
+fn main() -> Result<(), String> {
+    let ops = Did::new("did:psv:steward:ops").expect("valid DID");
+    let config = saep_without_rollback_plans();
+    let mut governance = GovernanceEngine::new(SaepEngine::new(config));
+    // Pauses are left out of dual control here, so they execute on proposal.
+    let mut admin = DualControl::new(DualControlPolicy {
+        admins: [ops.clone()].into(),
+        dual_control: [].into(),
+        ..DualControlPolicy::default()
+    });
+    admin.bind(&governance)?;
+    let founding = governance.charter().active().document.clone();
+    let non_weaponization = founding.clause(NON_WEAPONIZATION_CLAUSE).expect("founding clause").clone();
+
+    // 1. A pause for military logistics is vetoed; the record cites the clause.
+    let reason = "Reserve VET capacity for military logistics".to_string();
+    let pause = AdminAction::PauseModule { module: StewardModule::VET, reason };
+    let err = admin.propose(&mut governance, pause, &ops, T0).expect_err("charter");
+    println!("refused: {err}");
+    let GovernanceAuditOutcome::Vetoed { citations, .. } = &governance.audit_trail()[0].outcome else {
+        return Err("the pause should be vetoed".into());
+    };
+    assert_eq!(citations.len(), 1);
+    assert_eq!((citations[0].clause.as_str(), citations[0].version), (NON_WEAPONIZATION_CLAUSE, 1));
+    let excerpt = citations[0].excerpt.trim_end_matches('…');
+    assert!(non_weaponization.text.starts_with(excerpt));
+
+    // 2. So is a restrictive proposal; the refusal a preview gets names the clause.
+    let drills = GovernanceProposal {
+        proposal_id: "prop-vet-drills".into(),
+        scope: GovernanceScope::Module(ModuleId("VET".into())),
+        title: "Close VET missions for drills".into(),
+        description: "Restrict VET missions to military drills".into(),
+        payload: serde_json::json!({}),
+        can_introduce_restrictions: true,
+    };
+    let refused = governance.check_proposal(&drills, &passed(&drills)).expect_err("charter");
+    assert_eq!(refused.code, ReasonCode::GovernanceCharter);
+    assert_eq!(refused.params["clause"], NON_WEAPONIZATION_CLAUSE);
+    println!("preview: {refused}");
+
+    // 3. A rule needs its clause in the active charter.
+    let surveillance = CharterRule {
+        id: "no-surveillance".into(),
+        terms: ["surveillance".to_string()].into(),
+        clause: DATA_DIGNITY.into(),
+    };
+    let mut rules = CharterRuleSet::default();
+    rules.rules.push(surveillance);
+    let err = governance.set_charter_rules(rules.clone()).expect_err("no such clause yet");
+    println!("refused: {err}");
+    assert!(matches!(err, CharterError::UnknownClause { version: 1, .. }));
+
+    // 4. Version 2 adds the clause, through a proposal; only the new clause is the proposal's.
+    let dignity = CharterClause {
+        id: DATA_DIGNITY.into(),
+        text: "No module may be used to watch participants beyond what they consented to.".into(),
+        adopted_via: None,
+    };
+    let v2 = adoption("prop-charter-v2", 2, vec![non_weaponization.clone(), dignity]);
+    let v2_ref = governance.apply_proposal(v2.clone(), passed(&v2), T0 + 1)?;
+    let active = &governance.charter().active().document;
+    assert_eq!(active.version, 2);
+    assert_eq!(active.clause(DATA_DIGNITY).and_then(|c| c.adopted_via.as_deref()), Some("prop-charter-v2"));
+    assert_eq!(active.clause(NON_WEAPONIZATION_CLAUSE).and_then(|c| c.adopted_via.clone()), None);
+    let entry = governance.audit_trail().last().expect("adoption audited");
+    let GovernanceAuditAction::AdoptCharter { version: 2, before_hash, after_hash, .. } = &entry.action else {
+        return Err("expected the adoption in the audit trail".into());
+    };
+    assert_eq!(before_hash, &founding.document_hash());
+    assert_eq!(after_hash, &governance.charter().active().document_hash);
+    governance.set_charter_rules(rules).map_err(|e| e.to_string())?;
+    assert!(governance.applied_proposal(&v2_ref).expect("applied").orphaned_rules.is_empty());
+
+    // 5. A version that is not newer is refused before the vote counts.
+    let stale = adoption("prop-charter-v2-again", 2, vec![non_weaponization.clone()]);
+    let refused = governance.check_proposal(&stale, &passed(&stale)).expect_err("stale version");
+    assert_eq!(refused.code, ReasonCode::GovernanceCharterVersion);
+
+    // 6. Version 3 drops the clause: the orphaned rule is reported when it is applied.
+    let v3 = adoption("prop-charter-v3", 3, vec![non_weaponization]);
+    let v3_ref = governance.apply_proposal(v3.clone(), passed(&v3), T0 + 2)?;
+    let orphaned = &governance.applied_proposal(&v3_ref).expect("applied").orphaned_rules;
+    let expected = OrphanedCharterRule { rule: "no-surveillance".into(), clause: DATA_DIGNITY.into() };
+    assert_eq!(orphaned, &[expected]);
+    let applied = governance.applied_proposal(&v3_ref).expect("applied");
+    println!("{}", serde_json::to_string_pretty(applied).expect("applied proposal json"));
+
+    // 7. The orphaned rule still holds, citing the last version that had its clause.
+    let reason = "Pause PSM while surveillance cameras are installed".to_string();
+    let pause = AdminAction::PauseModule { module: StewardModule::PSM, reason };
+    admin.propose(&mut governance, pause, &ops, T0 + 3).expect_err("charter");
+    let GovernanceAuditOutcome::Vetoed { citations, .. } = &governance.audit_trail().last().expect("veto").outcome
+    else {
+        return Err("the pause should be vetoed".into());
+    };
+    assert_eq!((citations[0].clause.as_str(), citations[0].version), (DATA_DIGNITY, 2));
+    Ok(())
+}
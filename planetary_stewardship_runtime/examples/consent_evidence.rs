@@ -0,0 +1,115 @@
+// path: planetary_stewardship_runtime/examples/consent_evidence.rs
+
+//! Example: proving at audit time that a signed consent form is the one given at grant time.
+//! - The default policy accepts any evidence, hashed or not
+//! - A scheme allowlist refuses `ftp://` evidence; schemes match case-insensitively
+//! - A hash can be required per module or per consent purpose; withdrawals never need one
+//! - A verifier that fetches the form refuses a hash that no longer matches, or a form it
+//!   cannot read; the no-op default records hashes as given
+//! - Strict evidence discounts an unhashed grant that reached the registry unchecked
+//! - Exported records carry the hash and import with it
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::{
+    ConsentEvidencePolicy, ConsentRecord, ConsentRegistry, Did, EvidenceVerifier, ReasonCode, StewardModule,
+    PLGA_ATTESTATION_PURPOSE,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+/// Forms as an archive would serve them, by URI.
+struct Archive(BTreeMap<&'static str, &'static str>);
+
+impl EvidenceVerifier for Archive {
+    fn content_hash(&self, uri: &str) -> Result<Option<String>, String> {
+        self.0.get(uri).map(|hash| Some(hash.to_string())).ok_or_else(|| "404 Not Found".to_string())
+    }
+}
+
+fn record(module: StewardModule, consent_given: bool, uri: Option<&str>, hash: Option<&str>) -> ConsentRecord {
+    ConsentRecord {
+        participant: Did::new("did:psv:steward:ana").expect("valid DID"),
+        module,
+        mission: None,
+        consent_given,
+        timestamp_ms: T0,
+        evidence_uri: uri.map(str::to_string),
+        evidence_hash: hash.map(str::to_string),
+        prompt_hash: None,
+    }
+}
+
+fn refused(registry: &mut ConsentRegistry, record: ConsentRecord) -> ReasonCode {
+    registry.submit_consent(record).expect_err("refused by the evidence policy").code
+}
+
+fn main() {
+    let form = "https://forms.example.org/consent/ana.pdf";
+    let (mme, plga) = (StewardModule::MME, StewardModule::PLGA);
+
+    // 1. Default: anything goes.
+    let mut open = ConsentRegistry::new();
+    open.submit_consent(record(mme, true, Some("ftp://old-share/ana.pdf"), None)).expect("no policy");
+
+    // 2. Scheme allowlist.
+    let schemes =
+        ConsentEvidencePolicy { allowed_schemes: ["https".into(), "ipfs".into()].into(), ..Default::default() };
+    let mut registry = ConsentRegistry::new().with_evidence_policy(schemes);
+    let ftp = record(mme, true, Some("ftp://old-share/ana.pdf"), None);
+    assert_eq!(refused(&mut registry, ftp), ReasonCode::ConsentEvidenceScheme);
+    registry.submit_consent(record(mme, true, Some("IPFS://bafy-consent"), None)).expect("ipfs, any case");
+    registry.submit_consent(record(mme, true, None, None)).expect("no URI, nothing to check");
+
+    // 3. Hash required for MME grants: PLGA grants and withdrawals are unaffected.
+    let by_module = ConsentEvidencePolicy { hash_required_modules: [mme].into(), ..Default::default() };
+    let mut registry = ConsentRegistry::new().with_evidence_policy(by_module.clone());
+    let missing = registry.submit_consent(record(mme, true, Some(form), None)).expect_err("needs a hash");
+    assert_eq!(missing.code, ReasonCode::ConsentEvidenceHashMissing);
+    assert_eq!(missing.to_string(), "Consent by did:psv:steward:ana to MME must carry an evidence hash");
+    registry.submit_consent(record(plga, true, Some(form), None)).expect("PLGA is not listed");
+    registry.submit_consent(record(mme, false, None, None)).expect("withdrawals always go through");
+
+    // 4. Hash required by purpose: attestation consent is asked in every module but MME.
+    let attestation = [PLGA_ATTESTATION_PURPOSE.to_string()].into();
+    let by_purpose = ConsentEvidencePolicy { hash_required_purposes: attestation, ..Default::default() };
+    assert!(by_purpose.hash_required(plga) && !by_purpose.hash_required(mme));
+    let mut registry = ConsentRegistry::new().with_evidence_policy(by_purpose);
+    assert_eq!(refused(&mut registry, record(plga, true, Some(form), None)), ReasonCode::ConsentEvidenceHashMissing);
+    registry.submit_consent(record(mme, true, Some(form), None)).expect("MME asks another purpose");
+
+    // 5. Fetch and compare: the archive now serves an edited form.
+    let archive = Archive([(form, "sha256:edited")].into());
+    let mut registry =
+        ConsentRegistry::new().with_evidence_policy(by_module.clone()).with_evidence_verifier(Arc::new(archive));
+    let mismatch = registry.submit_consent(record(mme, true, Some(form), Some("sha256:signed"))).expect_err("edited");
+    assert_eq!(mismatch.code, ReasonCode::ConsentEvidenceHashMismatch);
+    assert_eq!(mismatch.params["actual"], "sha256:edited");
+    let gone = record(mme, true, Some("https://forms.example.org/consent/gone.pdf"), Some("sha256:signed"));
+    assert_eq!(refused(&mut registry, gone), ReasonCode::ConsentEvidenceUnreadable);
+    registry.submit_consent(record(mme, true, Some(form), Some("sha256:edited"))).expect("matches what is served");
+    let mut trusting = ConsentRegistry::new().with_evidence_policy(by_module.clone());
+    trusting.submit_consent(record(mme, true, Some(form), Some("sha256:signed"))).expect("no-op verifier");
+
+    // 6. Strict evidence: an unhashed grant loaded unchecked stops counting.
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mut lenient = ConsentRegistry::new().with_evidence_policy(by_module.clone());
+    let mut strict = ConsentRegistry::new().with_evidence_policy(by_module).with_strict_evidence();
+    for registry in [&mut lenient, &mut strict] {
+        registry.upsert_consent(record(mme, true, Some(form), None));
+        registry.upsert_consent(record(plga, true, Some(form), None));
+    }
+    assert!(lenient.has_valid_consent(&ana, mme, None));
+    assert!(!strict.has_valid_consent(&ana, mme, None));
+    assert!(strict.has_valid_consent(&ana, plga, None), "PLGA needs no hash");
+
+    // 7. Export and import keep the hash; unhashed records serialize as before.
+    let exported: Vec<ConsentRecord> = trusting.records().cloned().collect();
+    let json = serde_json::to_string(&exported).expect("records json");
+    let imported: Vec<ConsentRecord> = serde_json::from_str(&json).expect("records load");
+    assert_eq!(imported[0].evidence_hash.as_deref(), Some("sha256:signed"));
+    let plain = serde_json::to_value(record(plga, true, Some(form), None)).expect("record json");
+    assert!(plain.get("evidence_hash").is_none());
+    println!("{json}");
+}
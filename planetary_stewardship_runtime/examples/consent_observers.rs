@@ -0,0 +1,150 @@
+// path: planetary_stewardship_runtime/examples/consent_observers.rs
+
+//! Example: engines reacting to consent changes (run with `--features test-util`).
+//! - Ana withdraws MME consent mid-assignment: the assignment stays open but is suspended
+//!   pending consent, cannot be completed, and resumes when she consents again
+//! - A lapsed grant suspends it the same way; abandoning a suspended assignment still works
+//! - An observer that errors or panics is recorded as a failure; the registry keeps the
+//!   change and the engines still hear it
+//! - A ledger told to sweep on withdrawal redacts without waiting for an admin to run retention
+
+use std::sync::{Arc, Mutex};
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AssignmentStatus, ConsentChange, ConsentObserver, ConsentRecord, ConsentRegistry, Did, ImpactMetrics,
+    MicroMissionsEngine, MissionId, MissionTemplate, OpenAssignmentStatus, PlanetaryLedger, RetentionPolicy, SaepEngine,
+    StewardModule,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn consent(
+    who: &Did,
+    module: StewardModule,
+    mission: Option<&MissionId>,
+    consent_given: bool,
+    at_ms: u64,
+) -> ConsentRecord {
+    ConsentRecord {
+        participant: who.clone(),
+        module,
+        mission: mission.cloned(),
+        consent_given,
+        timestamp_ms: at_ms,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    }
+}
+
+/// Writes every withdrawal to a notification outbox; refuses once the outbox is full.
+struct Outbox {
+    sent: Mutex<Vec<String>>,
+    capacity: usize,
+}
+
+impl ConsentObserver for Outbox {
+    fn on_withdrawal(&self, record: &ConsentRecord) -> Result<(), String> {
+        let mut sent = self.sent.lock().unwrap();
+        if sent.len() == self.capacity {
+            return Err("outbox full".into());
+        }
+        sent.push(format!("{} withdrew {:?} consent", record.participant, record.module));
+        Ok(())
+    }
+}
+
+struct Broken;
+
+impl ConsentObserver for Broken {
+    fn on_grant(&self, _record: &ConsentRecord) -> Result<(), String> {
+        panic!("observer bug")
+    }
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let creek = MissionId::new("creek-cleanup").expect("valid mission id");
+    let config = saep_without_rollback_plans();
+
+    let outbox = Arc::new(Outbox { sent: Mutex::new(Vec::new()), capacity: 1 });
+    let registry =
+        ConsentRegistry::new().with_observer("outbox", outbox.clone()).with_observer("broken", Arc::new(Broken));
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config.clone()), registry);
+    missions.add_template(MissionTemplate {
+        id: creek.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+
+    // 1. A panicking observer does not stop the grant, or the engine hearing of it.
+    missions.consent_mut().upsert_consent(consent(&ana, StewardModule::MME, Some(&creek), true, T0));
+    assert!(missions.consent().has_valid_consent(&ana, StewardModule::MME, Some(&creek)));
+    let failures = missions.consent_mut().take_observer_failures();
+    assert_eq!((failures.len(), failures[0].observer.as_str()), (1, "broken"));
+    assert_eq!(failures[0].message, "Observer panicked: observer bug");
+    let key = missions.assign_mission(&creek, ana.clone(), T0 + DAY_MS).expect("consented").key();
+
+    // 2. Withdrawing mid-assignment suspends it; it cannot be completed meanwhile.
+    missions.consent_mut().upsert_consent(consent(&ana, StewardModule::MME, Some(&creek), false, T0 + 2 * DAY_MS));
+    let suspended = missions.active_assignment(&key).expect("still open").clone();
+    assert_eq!(suspended.status, OpenAssignmentStatus::SuspendedPendingConsent { since_ms: T0 + 2 * DAY_MS });
+    assert_eq!(missions.suspended_assignments(&ana).count(), 1);
+    assert!(missions.close_assignment(&key, AssignmentStatus::Completed, T0 + 3 * DAY_MS).is_err());
+    println!("{}", serde_json::to_string_pretty(&suspended).expect("assignment json"));
+
+    // 3. Re-granting resumes it.
+    missions.consent_mut().upsert_consent(consent(&ana, StewardModule::MME, Some(&creek), true, T0 + 4 * DAY_MS));
+    assert!(missions.active_assignment(&key).expect("still open").status.is_active());
+    assert_eq!(missions.suspended_assignments(&ana).count(), 0);
+
+    // 4. A grant that lapses suspends it too; a suspended assignment can still be abandoned.
+    let lapsed = missions.consent_mut().expire_grants(T0 + 5 * DAY_MS, T0 + 6 * DAY_MS);
+    assert_eq!(lapsed.len(), 1);
+    assert_eq!(
+        missions.active_assignment(&key).expect("still open").status,
+        OpenAssignmentStatus::SuspendedPendingConsent { since_ms: T0 + 6 * DAY_MS }
+    );
+    let abandoned = missions.close_assignment(&key, AssignmentStatus::Abandoned, T0 + 7 * DAY_MS).expect("abandoned");
+    assert_eq!(abandoned.assignment.assignee, ana);
+
+    // 5. An observer's error is recorded; the withdrawal it failed on stands.
+    missions.consent_mut().upsert_consent(consent(&ana, StewardModule::PLGA, None, false, T0 + 8 * DAY_MS));
+    let failures = missions.consent_mut().take_observer_failures();
+    assert_eq!(failures.len(), 2, "outbox full, broken on the re-grant");
+    let full = failures.iter().find(|f| f.observer == "outbox").expect("outbox failed");
+    assert_eq!((full.change, full.message.as_str()), (ConsentChange::Withdrawn, "outbox full"));
+    assert!(missions.consent().records().any(|r| r.module == StewardModule::PLGA && !r.consent_given));
+    assert_eq!(outbox.sent.lock().unwrap().len(), 1);
+
+    // 6. A ledger sweeping on withdrawal redacts as soon as consent is withdrawn.
+    let mut registry = ConsentRegistry::new();
+    registry.upsert_consent(consent(&ana, StewardModule::PLGA, None, true, T0));
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config),
+        registry,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_retention_policy(RetentionPolicy::Tombstone)
+    .with_retention_on_withdrawal();
+    let metrics = ImpactMetrics { co2eq_reduced: 0.4, ..ImpactMetrics::default() };
+    ledger
+        .issue_attestation(ana.clone(), None, "Creek cleanup".into(), metrics, "ipfs://creek", vec![], T0)
+        .expect("consented");
+    ledger.consent_mut().upsert_consent(consent(&ana, StewardModule::PLGA, None, false, T0 + DAY_MS));
+    assert!(ledger.get_attestations_for_actor(&ana).is_empty());
+    assert_eq!(ledger.redaction_log().entries().len(), 1);
+    assert!(ledger.consent_mut().take_withdrawals(StewardModule::PLGA).is_empty(), "nothing left queued");
+    assert!(ledger.consent().observer_failures().is_empty());
+}
@@ -0,0 +1,108 @@
+// path: planetary_stewardship_runtime/examples/consent_prompts.rs
+
+//! Example: consent prompt descriptors and strict prompt checking (run with `--features test-util`).
+//! - The front-end renders `ConsentPromptDescriptor::for_action` and stores its hash on the consent
+//! - Under strict prompts, editing the mission description invalidates consent gathered before the edit
+//! - Re-consenting to the new prompt restores it; lenient registries ignore prompt hashes
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    ConsentPromptDescriptor, ConsentRecord, ConsentRegistry, Did, EthicsContext, EthicsDecision, MicroMissionsEngine,
+    MissionId, MissionTemplate, SaepEngine, StewardModule, MME_ASSIGNMENT_PURPOSE,
+    PLGA_ATTESTATION_PURPOSE,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn template(id: &MissionId, description: &str) -> MissionTemplate {
+    MissionTemplate {
+        id: id.clone(),
+        title: "Creek cleanup".into(),
+        description: description.into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    }
+}
+
+fn consent(who: &Did, mission: &MissionId, prompt_hash: Option<String>, at_ms: u64) -> ConsentRecord {
+    ConsentRecord {
+        participant: who.clone(),
+        module: StewardModule::MME,
+        mission: Some(mission.clone()),
+        consent_given: true,
+        timestamp_ms: at_ms,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash,
+    }
+}
+
+/// What the front-end would render for assigning `tpl`.
+fn mme_prompt(saep: &SaepEngine, who: &Did, tpl: &MissionTemplate) -> ConsentPromptDescriptor {
+    let decision: EthicsDecision = saep.evaluate(&EthicsContext {
+        actor: who.clone(),
+        affected_parties: vec![],
+        module: StewardModule::MME,
+        description: tpl.description.clone(),
+        estimated_impact: tpl.expected_impact.clone(),
+    });
+    ConsentPromptDescriptor::for_action(StewardModule::MME, Some(tpl), MME_ASSIGNMENT_PURPOSE, &decision)
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mission = MissionId::new("creek-cleanup").expect("valid mission id");
+    let config = saep_without_rollback_plans();
+    let saep = SaepEngine::new(config.clone());
+    let original = template(&mission, "Remove litter along the creek");
+    let edited = template(&mission, "Remove litter along the creek and photograph every volunteer");
+
+    // 1. The front-end shows the descriptor and records its hash with the consent.
+    let prompt = mme_prompt(&saep, &ana, &original);
+    println!("{}", serde_json::to_string_pretty(&prompt).expect("prompt json"));
+    assert!(prompt.public_intent_log);
+    let mut strict = ConsentRegistry::new().with_strict_prompts();
+    strict.upsert_consent(consent(&ana, &mission, Some(prompt.canonical_hash()), T0));
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config.clone()), strict);
+    missions.add_template(original.clone());
+    missions.assign_mission(&mission, ana.clone(), T0 + 1).expect("consented to this exact prompt");
+
+    // 2. The mission description changes: the old consent no longer covers it.
+    missions.add_template(edited.clone());
+    let err = missions.assign_mission(&mission, ana.clone(), T0 + 2).expect_err("prompt changed since consent");
+    println!("refused after edit: {err}");
+
+    // 3. Re-consenting to the new prompt restores it.
+    let renewed = mme_prompt(&saep, &ana, &edited);
+    assert_ne!(renewed.canonical_hash(), prompt.canonical_hash());
+    missions.consent_mut().upsert_consent(consent(&ana, &mission, Some(renewed.canonical_hash()), T0 + 3));
+    missions.assign_mission(&mission, ana.clone(), T0 + 4).expect("consented to the edited prompt");
+
+    // 4. Strict registries also refuse consent that carries no prompt hash at all.
+    missions.consent_mut().upsert_consent(consent(&ana, &mission, None, T0 + 5));
+    assert!(missions.assign_mission(&mission, ana.clone(), T0 + 6).is_err());
+
+    // 5. Lenient registries keep the previous behaviour: the hash is not checked.
+    let mut lenient = ConsentRegistry::new();
+    lenient.upsert_consent(consent(&ana, &mission, Some(prompt.canonical_hash()), T0));
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), lenient);
+    missions.add_template(edited);
+    missions.assign_mission(&mission, ana.clone(), T0 + 7).expect("lenient registry ignores prompt hashes");
+
+    // PLGA prompts describe the module; there is no mission section.
+    let decision = saep.evaluate(&EthicsContext {
+        actor: ana,
+        affected_parties: vec![],
+        module: StewardModule::PLGA,
+        description: "Attestation".into(),
+        estimated_impact: serde_json::json!({}),
+    });
+    let plga = ConsentPromptDescriptor::for_action(StewardModule::PLGA, None, PLGA_ATTESTATION_PURPOSE, &decision);
+    assert!(plga.mission_title.is_none());
+    println!("PLGA prompt hash: {}", plga.canonical_hash());
+}
@@ -0,0 +1,174 @@
+// path: planetary_stewardship_runtime/examples/consent_requirements.rs
+
+//! Example: asking what consent an action needs before attempting it, checked against
+//! enforcement for every (module, action) pair (run with `--features test-util`).
+//! - Under each config, an action predicted to need consent is refused without it with the
+//!   predicted reason (by both the call and its `validate_*` dry run), and succeeds with it
+//! - An action predicted to need none succeeds with an empty consent registry
+//! - Module overrides move the requirement per module
+//! - Attestations in modules other than PLGA are issued under an empty metric schema, and ask
+//!   consent in their own module
+
+use std::collections::BTreeMap;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    consent_requirements, ActionKind, AttestationRequest, ConsentRecord, ConsentRegistry, Did, EvidenceArtifact,
+    GovernanceEngine, GovernanceProposal, GovernanceScope, ImpactMetrics, IntentEntry, IntentLog, MetricSchema,
+    MicroMissionsEngine, MissionId, MissionTemplate, ModuleId, ModuleMetrics, PlanetaryLedger, QuadraticOutcome,
+    SaepConfig, SaepEngine, SaepFlag, StewardModule, ValidationCode,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn consent(who: &Did, module: StewardModule, mission: Option<&MissionId>) -> ConsentRecord {
+    ConsentRecord {
+        participant: who.clone(),
+        module,
+        mission: mission.cloned(),
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    }
+}
+
+fn template(id: &MissionId) -> MissionTemplate {
+    MissionTemplate {
+        id: id.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    }
+}
+
+/// What enforcement (and its dry run, where there is one) says for `action` in `module` with no
+/// consent recorded; the same action is then retried with consent in `module`, which must pass.
+fn enforce(config: &SaepConfig, module: StewardModule, action: ActionKind) -> Result<(), String> {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let creek = MissionId::new("creek-cleanup").expect("valid mission id");
+    let saep = || SaepEngine::new(config.clone());
+    match action {
+        ActionKind::IssueAttestation => {
+            let attest = |registry: ConsentRegistry| {
+                let mut ledger = PlanetaryLedger::new(saep(), registry);
+                if module != StewardModule::PLGA {
+                    ledger.register_metric_schema(MetricSchema::new(module, vec![])).expect("takes attestations");
+                }
+                let request = AttestationRequest {
+                    actor_did: ana.clone(),
+                    mission_id: None,
+                    description: "Creek cleanup".into(),
+                    module,
+                    impact_metrics: ImpactMetrics::default(),
+                    module_metrics: ModuleMetrics::new(),
+                    evidence: vec![EvidenceArtifact::new("ipfs://creek", "image/jpeg")],
+                    verifier_dids: vec![],
+                    timestamp_ms: T0,
+                    refs: Default::default(),
+                    capture_window: None,
+                    witness_endorsement: None,
+                };
+                let report = ledger.validate_attestation(&request);
+                let result = ledger.issue_attestation_idempotent(request, None).map(|_| ());
+                let previewed = report.issues.iter().find(|i| i.code == ValidationCode::ConsentMissing);
+                assert_eq!(previewed.map(|i| &i.message), result.as_ref().err(), "dry run agrees");
+                result
+            };
+            let refused = attest(ConsentRegistry::new());
+            let mut registry = ConsentRegistry::new();
+            registry.upsert_consent(consent(&ana, module, None));
+            attest(registry).expect("predicted consent is enough");
+            refused
+        }
+        ActionKind::AssignMission => {
+            let assign = |registry: ConsentRegistry| {
+                let mut missions = MicroMissionsEngine::new(saep(), registry);
+                missions.add_template(template(&creek));
+                let report = missions.validate_assignment(&creek, &ana, T0);
+                let result = missions.assign_mission(&creek, ana.clone(), T0).map(|_| ());
+                let previewed = report.issues.iter().find(|i| i.code == ValidationCode::ConsentMissing);
+                assert_eq!(previewed.map(|i| &i.message), result.as_ref().err(), "dry run agrees");
+                result
+            };
+            let refused = assign(ConsentRegistry::new());
+            let mut registry = ConsentRegistry::new();
+            registry.upsert_consent(consent(&ana, module, Some(&creek)));
+            assign(registry).expect("predicted consent is enough");
+            refused
+        }
+        ActionKind::ApplyProposal => {
+            let governance = GovernanceEngine::new(saep());
+            let proposal = GovernanceProposal {
+                proposal_id: format!("prop-{module:?}"),
+                scope: GovernanceScope::Module(ModuleId(format!("{module:?}"))),
+                title: "Publish quarterly restoration map".into(),
+                description: "Open data release for the watershed".into(),
+                payload: serde_json::json!({}),
+                can_introduce_restrictions: false,
+            };
+            let outcome = QuadraticOutcome { proposal_id: proposal.proposal_id.clone(), total_support: 9.0, total_opposition: 2.0 };
+            assert_eq!(governance.can_apply_proposal(&proposal, &outcome), Ok(true));
+            Ok(())
+        }
+        ActionKind::LogIntent => {
+            let mut log = IntentLog::new();
+            log.publish(IntentEntry {
+                actor: ana,
+                module,
+                description: "Survey the creek".into(),
+                decision_id: "self:survey".into(),
+                timestamp_ms: T0,
+            });
+            log.verify().expect("published");
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let base = saep_without_rollback_plans();
+    let no_consent = SaepConfig { enforce_informed_consent: false, ..base.clone() };
+    let mut mme_off = base.clone();
+    mme_off.module_overrides.insert(StewardModule::MME, BTreeMap::from([(SaepFlag::EnforceInformedConsent, false)]));
+    let mut plga_only = no_consent.clone();
+    plga_only.module_overrides.insert(StewardModule::PLGA, BTreeMap::from([(SaepFlag::EnforceInformedConsent, true)]));
+
+    // 1. The preview for an attestation under the default (reversibility aside) config.
+    let preview = consent_requirements(&base, StewardModule::PLGA, ActionKind::IssueAttestation);
+    println!("{}", serde_json::to_string_pretty(&preview).expect("requirements json"));
+
+    // 2. Drift check: every config, module and action.
+    let mut required = 0;
+    for (name, config) in [("base", &base), ("no_consent", &no_consent), ("mme_off", &mme_off), ("plga_only", &plga_only)] {
+        for module in StewardModule::ALL {
+            for action in ActionKind::ALL {
+                let predicted = consent_requirements(config, module, action);
+                if !predicted.applicable {
+                    assert!(!predicted.required() && predicted.freshness.is_none());
+                    continue;
+                }
+                let outcome = enforce(config, module, action);
+                let expected = if predicted.required() { Err(predicted.missing_reason()) } else { Ok(()) };
+                assert_eq!(outcome, expected, "{name}: {action:?} in {module:?}");
+                required += predicted.required() as usize;
+            }
+        }
+    }
+    // Attestations in the seven modules that take them and assignments under base, those
+    // attestations again under mme_off, and PLGA's alone under plga_only.
+    assert_eq!(required, 16);
+
+    // 3. Overrides change the answer per module.
+    assert!(!consent_requirements(&mme_off, StewardModule::MME, ActionKind::AssignMission).required());
+    assert!(consent_requirements(&mme_off, StewardModule::PLGA, ActionKind::IssueAttestation).required());
+    let freshness = consent_requirements(&base, StewardModule::MME, ActionKind::AssignMission).freshness.expect("required");
+    assert!(freshness.mission_in_prompt && freshness.discloses_public_intent_log && !freshness.discloses_rollback_plan);
+}
@@ -0,0 +1,196 @@
+// path: planetary_stewardship_runtime/examples/content_policy.rs
+
+//! Example: description text under a content policy, from hostile fixtures (run with `--features test-util`).
+//! - Script tags, inline handlers and `javascript:` links are removed; the markdown around them
+//!   is kept, and what was removed is a validation warning
+//! - Bidi overrides and zero-width characters refuse the text, naming the code point
+//! - A 10 MB description is refused by length before anything else looks at it
+//! - Emails and phone numbers warn by default and refuse under `PiiAction::Reject`
+//! - Only the sanitized description is stored, and sanitizing it again changes nothing
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AttestationRequest, CharClass, ConsentRecord, ConsentRegistry, ContentFinding, ContentPolicy, Did, ImpactMetrics,
+    MicroMissionsEngine, MissionId, MissionTemplate, ModuleMetrics, PiiAction, PiiKind, PlanetaryLedger, ReasonCode,
+    SaepConfig, SaepEngine, SaepRefs, StewardModule, ValidationCode,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+
+/// Hostile inputs and what sanitizing leaves of them.
+const SANITIZED: &[(&str, &str)] = &[
+    ("Litter pick <script>fetch('//evil')</script>at the **creek**", "Litter pick at the **creek**"),
+    ("<SCRIPT type=module>alert(1)</script >Done", "Done"),
+    ("<img src=x onerror=alert(1)>Photo [here](https://example.org/p.jpg)", "Photo [here](https://example.org/p.jpg)"),
+    ("[click](javascript:alert(1)) now", "click now"),
+    ("![x](data:image/svg+xml;base64,PHN2Zz4=) seen", "x seen"),
+    ("[sneaky](java&#115;cript:alert(1))", "sneaky"),
+    ("<scr<script>ipt>alert(1)</script>", "ipt>alert(1)"),
+    ("See <https://example.org> not <javascript:alert(1)>", "See <https://example.org> not"),
+    ("a <!-- hidden --> b", "a  b"),
+    ("Depth 3 < 5 and 5 > 3", "Depth 3 < 5 and 5 > 3"),
+    ("Windows\r\nline\rendings  ", "Windows\nline\nendings"),
+];
+
+fn request(actor: &Did, description: &str) -> AttestationRequest {
+    AttestationRequest {
+        actor_did: actor.clone(),
+        mission_id: None,
+        description: description.into(),
+        module: StewardModule::PLGA,
+        impact_metrics: ImpactMetrics { co2eq_reduced: 0.2, ..ImpactMetrics::default() },
+        module_metrics: ModuleMetrics::new(),
+        evidence: vec![],
+        verifier_dids: vec![],
+        timestamp_ms: T0,
+        refs: SaepRefs::default(),
+        capture_window: None,
+        witness_endorsement: None,
+    }
+}
+
+fn template(id: &str, description: &str) -> MissionTemplate {
+    MissionTemplate {
+        id: MissionId::new(id).expect("valid mission id"),
+        title: id.replace('-', " "),
+        description: description.into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    }
+}
+
+fn main() {
+    let policy = ContentPolicy::default();
+
+    // 1. Raw HTML and unsafe links go; markdown, safe links and bare `<` stay.
+    for (input, expected) in SANITIZED {
+        let check = policy.check(input);
+        assert!(check.is_accepted(), "{input:?}: {:?}", check.violations);
+        assert_eq!(check.sanitized, *expected, "{input:?}");
+        assert_eq!(policy.check(&check.sanitized).sanitized, check.sanitized, "idempotent: {input:?}");
+        // Line endings and surrounding whitespace are normalized silently; removals are reported.
+        let normalized = input.replace("\r\n", "\n").replace('\r', "\n");
+        assert_eq!(check.warnings.is_empty(), normalized.trim() == *expected, "{input:?}");
+    }
+
+    // 2. Invisible reordering characters refuse the text, first occurrence of each.
+    let spoofed = "Invoice for \u{202E}gpj.exe and \u{200B}hidden \u{202E}again";
+    let check = policy.check(spoofed);
+    assert_eq!(
+        check.violations,
+        [
+            ContentFinding::DisallowedCharacter { code_point: "U+202E".into(), offset: 12 },
+            ContentFinding::DisallowedCharacter { code_point: "U+200B".into(), offset: 25 },
+        ]
+    );
+    assert!(check.sanitized.is_empty(), "nothing to store");
+    assert_eq!(
+        policy.apply("description", "tab\tok, bell\u{7} not").unwrap_err().to_string(),
+        "The description contains disallowed character U+0007 at 12"
+    );
+    // Narrower classes: digits and letters only, plus spaces.
+    let plain = ContentPolicy {
+        allowed_classes: [CharClass::Letter, CharClass::Digit, CharClass::Whitespace].into(),
+        ..ContentPolicy::default()
+    };
+    assert_eq!(plain.apply("description", "Planted 40 oaks").as_deref(), Ok("Planted 40 oaks"));
+    assert!(plain.apply("description", "Planted 40 oaks 🌳").is_err());
+
+    // 3. A 10 MB description is refused by its length alone.
+    let huge = "a".repeat(10 * 1024 * 1024);
+    let check = policy.check(&huge);
+    assert_eq!(check.violations, [ContentFinding::TooLong { chars: 10 * 1024 * 1024, max: 8_000 }]);
+    let reason = check.violations[0].reason("description");
+    assert_eq!(reason.code, ReasonCode::ContentTooLong);
+    assert_eq!(reason.to_string(), "The description is 10485760 characters long, over the limit of 8000");
+
+    // 4. PII warns by default and refuses under `Reject`; flagged terms only warn.
+    let contact = "Questions: ana.r@example.org or +1 (555) 010-4477. Meet at pier 12.";
+    let check = policy.check(contact);
+    assert!(check.is_accepted());
+    let pii: Vec<_> = check.warnings.iter().filter(|w| matches!(w, ContentFinding::Pii { .. })).collect();
+    assert_eq!(
+        pii,
+        [
+            &ContentFinding::Pii { kind: PiiKind::Email, offset: 11 },
+            &ContentFinding::Pii { kind: PiiKind::Phone, offset: 32 },
+        ]
+    );
+    let strict = ContentPolicy {
+        pii: Some(PiiAction::Reject),
+        flagged_terms: vec!["scam".into()],
+        ..ContentPolicy::default()
+    };
+    assert_eq!(
+        strict.apply("description", contact).unwrap_err().to_string(),
+        "The description contains an email address at 11"
+    );
+    let flagged = strict.check("Not a SCAM, promise. Scampi for volunteers.");
+    assert_eq!(flagged.warnings, [ContentFinding::FlaggedTerm { term: "scam".into() }]);
+
+    // 5. The ledger stores only sanitized text, and reports what it would do beforehand.
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mut consent = ConsentRegistry::new();
+    consent.upsert_consent(ConsentRecord {
+        participant: ana.clone(),
+        module: StewardModule::PLGA,
+        mission: None,
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    });
+    let config = saep_without_rollback_plans();
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    );
+    let report = ledger.validate_attestation(&request(&ana, SANITIZED[0].0));
+    assert!(report.is_valid(), "{:?}", report.issues);
+    assert_eq!(report.warnings[0].code, ValidationCode::ContentSanitized);
+    assert_eq!(report.warnings[0].message, "Removed raw HTML <script> from the description");
+    let report = ledger.validate_attestation(&request(&ana, spoofed));
+    assert!(report.has(ValidationCode::Content));
+    assert_eq!(report.issues.iter().filter(|i| i.code == ValidationCode::Content).count(), 2);
+
+    let r = request(&ana, SANITIZED[0].0);
+    let issued = ledger
+        .issue_attestation(r.actor_did, r.mission_id, r.description, r.impact_metrics, r.evidence, r.verifier_dids, T0)
+        .expect("sanitized and issued");
+    assert_eq!(issued.description, SANITIZED[0].1);
+    let err = ledger
+        .issue_attestation(ana.clone(), None, huge, ImpactMetrics::default(), vec![], vec![], T0)
+        .expect_err("too long");
+    assert!(err.starts_with("The description is 10485760 characters long"), "{err}");
+
+    // 6. Templates registered through the engine are sanitized in every language.
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new());
+    let mut tpl = template("creek-cleanup", SANITIZED[2].0);
+    tpl.default_language = Some("en".parse().expect("valid tag"));
+    tpl.localized_content.insert(
+        "es".parse().expect("valid tag"),
+        planetary_stewardship_runtime::LocalizedTemplate {
+            title: "Limpieza del arroyo".into(),
+            description: "Limpieza <iframe src=//evil></iframe>del arroyo".into(),
+        },
+    );
+    missions.register_template(tpl).expect("sanitized");
+    let stored = missions.get_template(&MissionId::new("creek-cleanup").expect("valid")).expect("registered");
+    assert_eq!(stored.description, SANITIZED[2].1);
+    assert_eq!(stored.localized_content.values().next().expect("es").description, "Limpieza del arroyo");
+    let err = missions.register_template(template("spoof", spoofed)).expect_err("bidi override");
+    assert_eq!(err, "Mission template spoof: The description contains disallowed character U+202E at 12");
+
+    println!("{}", serde_json::to_string_pretty(&policy).expect("policy json"));
+}
@@ -0,0 +1,94 @@
+// path: planetary_stewardship_runtime/examples/custom_metrics.rs
+
+//! Example: attestations carrying a registered custom dimension (run with `--features test-util`).
+//! - The ledger registers `water:nitrate_kg_reduced`; attestations may then carry it
+//! - Unregistered keys, other units and out-of-range values are refused at issuance
+//! - Impact summaries total custom dimensions alongside the built-in ones
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    ConsentRecord, ConsentRegistry, CustomMetricSpec, Did, ImpactMetrics, MetricKey, MetricValue, PlanetaryLedger,
+    SaepEngine, StewardModule,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn metrics(custom: &[(&MetricKey, f64, &str)]) -> ImpactMetrics {
+    let mut metrics = ImpactMetrics { restored_area_m2: 120.0, ..ImpactMetrics::default() };
+    for (key, value, unit) in custom {
+        metrics.custom.insert((*key).clone(), MetricValue::new(*value, *unit));
+    }
+    metrics
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let kofi = Did::new("did:psv:steward:kofi").expect("valid DID");
+    let nitrate = MetricKey::new("water:nitrate_kg_reduced").expect("valid metric key");
+    let turbidity = MetricKey::new("water:turbidity_ntu_reduced").expect("valid metric key");
+
+    let mut consent = ConsentRegistry::new();
+    for actor in [&ana, &kofi] {
+        consent.upsert_consent(ConsentRecord {
+            participant: actor.clone(),
+            module: StewardModule::PLGA,
+            mission: None,
+            consent_given: true,
+            timestamp_ms: T0,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        });
+    }
+    let config = saep_without_rollback_plans();
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    );
+    ledger
+        .register_custom_metric(
+            nitrate.clone(),
+            CustomMetricSpec {
+                unit: "kg".into(),
+                min: 0.0,
+                max: 500.0,
+                description: "Nitrate load kept out of the creek, lab-measured".into(),
+            },
+        )
+        .expect("valid spec");
+
+    let mut issue = |actor: &Did, at_ms: u64, metrics: ImpactMetrics| {
+        ledger.issue_attestation(actor.clone(), None, "Riparian buffer planting".into(), metrics, format!("ipfs://buffer-{at_ms}"), vec![], at_ms)
+    };
+    issue(&ana, T0, metrics(&[(&nitrate, 42.5, "kg")])).expect("registered dimension");
+    issue(&ana, T0 + DAY_MS, metrics(&[(&nitrate, 18.0, "kg")])).expect("registered dimension");
+    issue(&kofi, T0 + DAY_MS, metrics(&[])).expect("custom dimensions are optional");
+
+    // Nothing outside the registry gets in.
+    for (bad, why) in [
+        (metrics(&[(&turbidity, 3.0, "NTU")]), "unregistered key"),
+        (metrics(&[(&nitrate, 0.04, "t")]), "wrong unit"),
+        (metrics(&[(&nitrate, 800.0, "kg")]), "out of range"),
+    ] {
+        let err = issue(&kofi, T0 + 2 * DAY_MS, bad).expect_err(why);
+        println!("refused ({why}): {err}");
+    }
+
+    let ana_summary = ledger.impact_summary(Some(&ana));
+    assert_eq!(ana_summary.attestations, 2);
+    assert_eq!(ana_summary.totals.custom[&nitrate], MetricValue::new(60.5, "kg"));
+    let all = ledger.impact_summary(None);
+    assert_eq!(all.attestations, 3);
+    assert_eq!(all.totals.restored_area_m2, 360.0);
+    println!("{}", serde_json::to_string_pretty(&all).expect("summary json"));
+
+    // Attestations without custom dimensions serialize exactly as before.
+    let plain = serde_json::to_value(ledger.get_attestations_for_actor(&kofi)[0]).expect("attestation json");
+    assert!(plain["impact_metrics"].get("custom").is_none());
+}
@@ -0,0 +1,183 @@
+// path: planetary_stewardship_runtime/examples/dual_control.rs
+
+//! Example: a runtime whose admin operations need two admins (run with `--features test-util`).
+//! - A PLGA pause proposed by ops waits; ops confirming it themselves is refused and logged,
+//!   and the pause stays pending until security confirms it
+//! - A proposal nobody confirms within the window expires and cannot be confirmed late
+//! - Retention, left out of dual control here, executes on proposal; a key revocation goes to
+//!   the key registry given with `with_key_registry`
+//! - Every execution in the log names its proposer, its confirmer when one was needed, and the
+//!   runtime's state hash afterwards
+//! - The runtime binds its engines and key registry to its own controller: a second one, even
+//!   a single-admin one, binds none of them and has every proposal refused, also on a new
+//!   governance engine sharing the runtime's module status
+//! - An admin of the two-admin runtime cannot opt out of dual control, so a revocation they
+//!   propose alone still waits for the other
+//! - A reload cannot change the admin policy; a one-admin runtime opts out, and the log says so
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    build_runtime_with, AdminAction, AdminEffect, AdminLogEvent, AdminOperation, AdminProposal, Did, DualControl,
+    DualControlPolicy, GovernanceEngine, KeyPurpose, KeyRegistry, PublicKey, RuntimeConfig, SaepConfig, SaepEngine,
+    StewardModule, StewardshipRuntime,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const MINUTE_MS: u64 = 60_000;
+
+fn did(value: &str) -> Did {
+    Did::new(value).expect("valid DID")
+}
+
+fn build(admin: DualControlPolicy, clock: Arc<FixedClock>) -> StewardshipRuntime {
+    let saep = saep_without_rollback_plans();
+    let config = RuntimeConfig { saep, admin, ..RuntimeConfig::default() };
+    build_runtime_with(config, clock, Arc::new(SequentialIdGenerator::new(1)))
+}
+
+fn is_active(runtime: &StewardshipRuntime, module: StewardModule) -> bool {
+    runtime.module_status().lock().unwrap().status(module).is_active()
+}
+
+fn pending_id(proposal: AdminProposal) -> String {
+    match proposal {
+        AdminProposal::Pending { action_id, .. } => action_id,
+        AdminProposal::Executed { action_id, .. } => panic!("{action_id} should wait for a second admin"),
+    }
+}
+
+fn main() {
+    let (ops, security) = (did("did:psv:steward:ops"), did("did:psv:city:phoenix:security"));
+    let forester = did("did:psv:city:phoenix:forester");
+    let policy = DualControlPolicy {
+        admins: [ops.clone(), security.clone()].into(),
+        dual_control: AdminOperation::ALL.into_iter().filter(|op| *op != AdminOperation::RetentionExecution).collect(),
+        confirm_window_ms: 30 * MINUTE_MS,
+    };
+    let clock = Arc::new(FixedClock::new(T0));
+    let mut keys = KeyRegistry::new([ops.clone()]);
+    let key = PublicKey {
+        did: forester.clone(),
+        key_id: "forester-2026a".into(),
+        purpose: KeyPurpose::AttestationVerification,
+        material: "forester-2026a-material".into(),
+    };
+    let spare = PublicKey { key_id: "forester-2026b".into(), material: "forester-2026b-material".into(), ..key.clone() };
+    keys.register_key(key, T0, None, &ops, T0).expect("registered");
+    keys.register_key(spare, T0, None, &ops, T0).expect("registered");
+    let keys = keys.into_shared();
+    let mut runtime = build(policy.clone(), clock.clone()).with_key_registry(keys.clone());
+
+    // 1. Ops proposes a PLGA pause; it waits, and ops cannot confirm it alone.
+    let pause = AdminAction::PauseModule { module: StewardModule::PLGA, reason: "Evidence store compromised".into() };
+    let stranger = did("did:psv:steward:stranger");
+    assert!(runtime.propose_admin(pause.clone(), &stranger).is_err(), "not an admin");
+    let paused = pending_id(runtime.propose_admin(pause.clone(), &ops).expect("proposed"));
+    let refused = runtime.confirm_admin(&paused, &ops).expect_err("same admin");
+    println!("refused: {refused}");
+    assert_eq!(runtime.admin().pending().map(|p| p.target.as_str()).collect::<Vec<_>>(), ["StewardshipRuntime 1"]);
+    assert!(is_active(&runtime, StewardModule::PLGA), "not paused yet");
+
+    // 2. Another proposal lapses: past the window it expires and a late confirmation fails.
+    clock.advance(MINUTE_MS);
+    let read_only = AdminAction::SetModuleReadOnly { module: StewardModule::MME, reason: "Audit".into() };
+    let lapsed = pending_id(runtime.propose_admin(read_only, &security).expect("proposed"));
+    clock.advance(30 * MINUTE_MS);
+    let expired = runtime.expire_admin();
+    assert_eq!(expired.iter().map(|p| p.action_id.as_str()).collect::<Vec<_>>(), [paused.as_str(), lapsed.as_str()]);
+    assert!(runtime.confirm_admin(&lapsed, &ops).is_err(), "expired");
+
+    // 3. Proposed again, security confirms the pause within the window.
+    let paused = pending_id(runtime.propose_admin(pause, &ops).expect("proposed"));
+    clock.advance(5 * MINUTE_MS);
+    let effect = runtime.confirm_admin(&paused, &security).expect("confirmed");
+    assert!(effect.pause_id().is_some());
+    assert!(!is_active(&runtime, StewardModule::PLGA), "paused");
+
+    // 4. Retention is left out of dual control: it executes on proposal.
+    let retention = AdminAction::ExecuteRetention { module: StewardModule::PLGA };
+    let executed = runtime.propose_admin(retention, &security).expect("executed");
+    assert_eq!(executed.into_effect(), Some(AdminEffect::RetentionExecuted { redactions: 0 }));
+
+    // 5. A key revocation goes to the registry the runtime was given.
+    let revocation = AdminAction::RevokeKey {
+        did: forester.clone(),
+        key_id: "forester-2026a".into(),
+        revoked_from_ms: T0,
+        reason: "Laptop stolen".into(),
+    };
+    let revoked = pending_id(runtime.propose_admin(revocation, &ops).expect("proposed"));
+    assert_eq!(runtime.confirm_admin(&revoked, &security), Ok(AdminEffect::KeyRevoked));
+    assert!(keys.lock().unwrap().revocation(&forester, "forester-2026a").is_some());
+
+    // 6. Ops cannot switch dual control off in a two-admin runtime, so revoking alone still waits.
+    let err = runtime.opt_out_single_admin(&ops, "Security is on leave".into()).expect_err("two admins");
+    assert_eq!(err, "Only a one-admin deployment may opt out of dual control; this one has 2");
+    assert!(!runtime.admin().is_single_admin());
+    let revocation = AdminAction::RevokeKey {
+        did: forester.clone(),
+        key_id: "forester-2026b".into(),
+        revoked_from_ms: T0,
+        reason: "Suspicious login".into(),
+    };
+    let alone = pending_id(runtime.propose_admin(revocation, &ops).expect("proposed"));
+    assert!(runtime.confirm_admin(&alone, &ops).is_err(), "same admin");
+    assert!(keys.lock().unwrap().revocation(&forester, "forester-2026b").is_none());
+
+    // 7. A controller of ops' own, with no second admin, reaches none of the runtime's engines.
+    let mut rogue = DualControl::new(DualControlPolicy {
+        admins: [ops.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    rogue.opt_out_single_admin(&ops, "In a hurry".into(), T0).expect("ops is its admin");
+    assert!(rogue.bind(&runtime.governance).is_err() && rogue.bind(&*keys.lock().unwrap()).is_err());
+    let resume = AdminAction::ResumeModule {
+        module: StewardModule::PLGA,
+        pause_id: effect.pause_id().expect("paused").clone(),
+    };
+    let err = rogue.propose(&mut runtime.governance, resume.clone(), &ops, T0).expect_err("bound to the runtime");
+    assert_eq!(err, "GovernanceEngine is already bound to another controller");
+    let mut twin = GovernanceEngine::new(SaepEngine::new(SaepConfig::default()))
+        .with_module_status(runtime.module_status().clone());
+    assert!(rogue.propose(&mut twin, resume, &ops, T0).is_err(), "shares the runtime's binding");
+    assert!(!is_active(&runtime, StewardModule::PLGA), "still paused");
+
+    // 8. The log: every execution names who proposed, who confirmed and the state after.
+    let log = runtime.admin_log();
+    println!("{}", serde_json::to_string_pretty(log).expect("log json"));
+    let mut executed = 0;
+    for entry in log {
+        if let AdminLogEvent::Executed { action, confirmed_by, state_hash, .. } = &entry.event {
+            executed += 1;
+            assert_eq!(confirmed_by.is_some(), policy.dual_control.contains(&action.operation()), "{action}");
+            assert_eq!(state_hash.len(), 64);
+        }
+    }
+    assert_eq!(executed, 3);
+    let count = |matches: fn(&AdminLogEvent) -> bool| log.iter().filter(|e| matches(&e.event)).count();
+    assert_eq!(count(|e| matches!(e, AdminLogEvent::Proposed { .. })), 5);
+    assert_eq!(count(|e| matches!(e, AdminLogEvent::ConfirmationRefused { .. })), 2);
+    assert_eq!(count(|e| matches!(e, AdminLogEvent::Expired { .. })), 2);
+    assert_eq!(count(|e| matches!(e, AdminLogEvent::SingleAdminMode { .. })), 0);
+    assert_eq!(log.len(), 12);
+
+    // 9. A reload cannot loosen the policy.
+    let loosened = DualControlPolicy { dual_control: [].into(), ..policy.clone() };
+    let saep = saep_without_rollback_plans();
+    let report = runtime.reload(RuntimeConfig { saep, admin: loosened, ..RuntimeConfig::default() }).expect("valid");
+    assert_eq!(report.rejected.iter().map(|c| c.field.as_str()).collect::<Vec<_>>(), ["admin.dual_control"]);
+    assert_eq!(runtime.admin().policy(), &policy);
+    assert_eq!(runtime.config().admin, policy);
+
+    // 10. A one-admin deployment opts out, on the record.
+    let solo = DualControlPolicy { admins: [ops.clone()].into(), ..DualControlPolicy::default() };
+    let mut solo = build(solo, Arc::new(FixedClock::new(T0)));
+    solo.opt_out_single_admin(&ops, "Pilot with one operator".into()).expect("admin");
+    assert!(solo.admin().is_single_admin());
+    let pause = AdminAction::PauseModule { module: StewardModule::VET, reason: "Pilot ends".into() };
+    assert!(matches!(solo.propose_admin(pause, &ops), Ok(AdminProposal::Executed { .. })));
+    assert!(matches!(&solo.admin_log()[0].event, AdminLogEvent::SingleAdminMode { by, .. } if by == &ops));
+}
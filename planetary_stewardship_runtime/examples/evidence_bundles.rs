@@ -0,0 +1,151 @@
+// path: planetary_stewardship_runtime/examples/evidence_bundles.rs
+
+//! Example: typed evidence bundles under a ledger evidence policy (run with `--features test-util`).
+//! - Small claims get by with a single URI; claims above 1 tCO₂e need three artifacts,
+//!   including a photo and a GPS track
+//! - A record written with the old `evidence_uri` field loads as a one-artifact bundle
+//! - A photo captured long before the attestation is a validation warning, not a rejection
+//! - Reusing a photo's content under a new URI is caught by content hash, both across
+//!   actors (validation) and in the actor's own timeline
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AttestationRequest, ConsentRecord, ConsentRegistry, Did, EvidenceArtifact, EvidencePolicy, EvidenceRule,
+    ImpactMetrics, ModuleMetrics, PlanetaryLedger, SaepEngine, SaepRefs, StewardModule,
+    StewardshipAttestation, TimelineFlag, TimelineOptions, ValidationCode,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn metrics(co2eq_reduced: f64) -> ImpactMetrics {
+    ImpactMetrics { co2eq_reduced, ..ImpactMetrics::default() }
+}
+
+fn photo(name: &str, hash: &str, at_ms: u64) -> EvidenceArtifact {
+    EvidenceArtifact::new(format!("ipfs://{name}"), "image/jpeg")
+        .with_content_hash(format!("sha256:{hash}"))
+        .captured_at(at_ms)
+}
+
+fn request(actor: &Did, co2eq_reduced: f64, evidence: Vec<EvidenceArtifact>, at_ms: u64) -> AttestationRequest {
+    AttestationRequest {
+        actor_did: actor.clone(),
+        mission_id: None,
+        description: "Cover-crop sequestration".into(),
+        module: StewardModule::PLGA,
+        impact_metrics: metrics(co2eq_reduced),
+        module_metrics: ModuleMetrics::new(),
+        evidence,
+        verifier_dids: vec![],
+        timestamp_ms: at_ms,
+        refs: SaepRefs::default(),
+        capture_window: None,
+        witness_endorsement: None,
+    }
+}
+
+fn issue(ledger: &mut PlanetaryLedger, r: AttestationRequest) -> Result<StewardshipAttestation, String> {
+    ledger.issue_attestation(r.actor_did, r.mission_id, r.description, r.impact_metrics, r.evidence, r.verifier_dids, r.timestamp_ms)
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let kofi = Did::new("did:psv:steward:kofi").expect("valid DID");
+    let mut consent = ConsentRegistry::new();
+    for actor in [&ana, &kofi] {
+        consent.upsert_consent(ConsentRecord {
+            participant: actor.clone(),
+            module: StewardModule::PLGA,
+            mission: None,
+            consent_given: true,
+            timestamp_ms: T0,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        });
+    }
+    let config = saep_without_rollback_plans();
+    let policy = EvidencePolicy {
+        rules: vec![EvidenceRule {
+            co2eq_reduced_above: Some(1.0),
+            min_artifacts: 3,
+            required_media_types: vec!["image/*".into(), "application/gpx+xml".into()],
+            ..EvidenceRule::default()
+        }],
+        max_capture_age_ms: Some(30 * DAY_MS),
+    };
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_evidence_policy(policy);
+
+    // 1. A small claim: one bare URI is still enough.
+    let small = ledger
+        .issue_attestation(ana.clone(), None, "Hedge trimming".into(), metrics(0.2), "ipfs://hedge", vec![], T0)
+        .expect("below every threshold");
+    assert_eq!(small.evidence, vec![EvidenceArtifact::from_uri("ipfs://hedge")]);
+
+    // 2. A big claim with one photo falls short; with photos and a track it goes through.
+    let field = vec![photo("field-1", "aa01", T0), photo("field-2", "aa02", T0)];
+    let err = issue(&mut ledger, request(&ana, 4.0, field[..1].to_vec(), T0 + 1)).expect_err("one artifact");
+    println!("rejected: {err}");
+    let report = ledger.validate_attestation(&request(&ana, 4.0, field.clone(), T0 + 1));
+    assert!(report.has(ValidationCode::Evidence), "two photos: too few, and no GPS track");
+
+    let mut bundle = field.clone();
+    bundle.push(
+        EvidenceArtifact::new("ipfs://field-walk.gpx", "application/gpx+xml")
+            .captured_at(T0)
+            .described("Walk of the field boundary"),
+    );
+    bundle.push(EvidenceArtifact::new("ipfs://soil-lab.pdf", "application/pdf").described("Soil carbon lab report"));
+    let big = issue(&mut ledger, request(&ana, 4.0, bundle, T0 + 2)).expect("policy satisfied");
+    println!("{}", serde_json::to_string_pretty(&big.evidence).expect("evidence json"));
+
+    // 3. Records from before bundles load as one untyped artifact and save in the new form.
+    let mut legacy = serde_json::to_value(&small).expect("attestation json");
+    let fields = legacy.as_object_mut().expect("object");
+    fields.remove("evidence");
+    fields.insert("evidence_uri".into(), "ipfs://legacy-photos".into());
+    let migrated: StewardshipAttestation = serde_json::from_value(legacy).expect("legacy record loads");
+    assert_eq!(migrated.evidence, vec![EvidenceArtifact::from_uri("ipfs://legacy-photos")]);
+    assert!(serde_json::to_value(&migrated).expect("attestation json").get("evidence_uri").is_none());
+
+    // 4. Advisory findings: an old photo, and kofi reusing ana's photo under another URI.
+    let later = T0 + 60 * DAY_MS;
+    let reused = EvidenceArtifact { uri: "ipfs://kofi-field".into(), ..photo("x", "aa01", later) };
+    let kofi_bundle = vec![
+        reused,
+        photo("kofi-old", "bb01", T0),
+        EvidenceArtifact::new("ipfs://kofi.gpx", "application/gpx+xml").captured_at(later),
+    ];
+    let report = ledger.validate_attestation(&request(&kofi, 2.0, kofi_bundle.clone(), later));
+    println!("{}", serde_json::to_string_pretty(&report.warnings).expect("warnings json"));
+    assert!(report.is_valid(), "warnings never block: {:?}", report.issues);
+    let codes: Vec<ValidationCode> = report.warnings.iter().map(|w| w.code).collect();
+    assert_eq!(codes, [ValidationCode::EvidenceOutsideWindow, ValidationCode::EvidenceReused]);
+    assert!(report.warnings[1].message.contains(&big.id.0));
+    issue(&mut ledger, request(&kofi, 2.0, kofi_bundle, later)).expect("issued despite the warnings");
+
+    // A mission or epoch window supplied by the caller replaces the policy's.
+    let mut in_epoch = request(&kofi, 0.5, vec![photo("kofi-old", "bb01", T0)], later);
+    in_epoch.capture_window = Some((T0, later));
+    assert!(ledger.validate_attestation(&in_epoch).warnings.iter().all(|w| w.code != ValidationCode::EvidenceOutsideWindow));
+
+    // 5. Ana reusing her own photo content forty days on shows up in her timeline.
+    let again = vec![EvidenceArtifact { uri: "ipfs://field-2-reupload".into(), ..photo("x", "aa02", T0 + 40 * DAY_MS) }];
+    issue(&mut ledger, request(&ana, 0.5, again, T0 + 40 * DAY_MS)).expect("small claim");
+    let timeline = ledger.actor_timeline(&ana, TimelineOptions::default());
+    let flags: Vec<&TimelineFlag> = timeline.entries.iter().flat_map(|e| &e.flags).collect();
+    assert_eq!(
+        flags,
+        [&TimelineFlag::ReusedContentHash { first_attestation: big.id.clone(), content_hash: "sha256:aa02".into() }]
+    );
+}
@@ -0,0 +1,54 @@
+// path: planetary_stewardship_runtime/examples/fixture_builders.rs
+
+//! Example: ledger, attestation and template fixtures for downstream tests (run with
+//! `--features test-util`).
+//! - `build_valid` attestations went through the full issue path under the default SAEP
+//!   config, rollback plan included; the same seed gives the same id and statement
+//! - `issue_on` issues on a ledger the test set up, and reports refusals instead of panicking
+//! - Template `build_valid` returns what `register_template` stores
+
+use planetary_stewardship_runtime::builders::{
+    did, mission_id, AttestationBuilder, ConsentRecordBuilder, LedgerBuilder, MissionTemplateBuilder, FIXTURE_START_MS,
+};
+use planetary_stewardship_runtime::{StewardModule, ValidationCode};
+
+fn main() {
+    let ana = did("did:psv:steward:ana");
+
+    // 1. Same seed, same attestation; reversibility is satisfied with a registered plan.
+    let first = AttestationBuilder::new(&ana).seed(3).build_valid();
+    let again = AttestationBuilder::new(&ana).seed(3).build_valid();
+    assert_eq!(serde_json::to_string(&first).expect("json"), serde_json::to_string(&again).expect("json"));
+    assert_eq!(first.timestamp_ms, FIXTURE_START_MS);
+    assert_eq!(first.rollback_plan_id.as_ref().map(|p| p.0.clone()), Some(format!("plan:{}", first.id.0)));
+    assert_ne!(AttestationBuilder::new(&ana).seed(4).build_valid().id, first.id);
+
+    // 2. On a ledger without consent the claim is refused, and validation says why.
+    let mut ledger = LedgerBuilder::new().seed(3).build();
+    let claim = AttestationBuilder::new(&ana).co2eq_reduced(0.5);
+    assert!(ledger.validate_attestation(&claim.request()).has(ValidationCode::ConsentMissing));
+    assert!(claim.clone().issue_on(&mut ledger).is_err());
+
+    // 3. Withdrawn consent refuses too; given consent issues under the ledger's ids.
+    let withdrawn = ConsentRecordBuilder::new(&ana, StewardModule::PLGA).withdrawn().build();
+    let mut ledger = LedgerBuilder::new().seed(3).consent(withdrawn).build();
+    assert!(claim.clone().issue_on(&mut ledger).is_err());
+    let mut ledger = LedgerBuilder::new().seed(3).consenting(&ana).build();
+    let issued = claim.issue_on(&mut ledger).expect("consented");
+    assert_eq!(issued.impact_metrics.co2eq_reduced, 0.5);
+    assert_eq!(ledger.get_attestations_for_actor(&ana).len(), 1);
+
+    // 4. Templates come back sanitized, localized content included.
+    let template = MissionTemplateBuilder::new("creek-cleanup")
+        .description("Pick litter <script>alert(1)</script>along the creek")
+        .localized("es", "Limpieza del arroyo", "Recoger basura del arroyo")
+        .skill("litter-picking")
+        .build_valid();
+    assert_eq!(template.id, mission_id("creek-cleanup"));
+    assert_eq!(template.description, "Pick litter along the creek");
+    assert_eq!(template.default_language.as_ref().map(|l| l.to_string()).as_deref(), Some("en"));
+    let spoofed = MissionTemplateBuilder::new("spoof").description("Invoice \u{202E}gpj.exe");
+    assert!(std::panic::catch_unwind(|| spoofed.build_valid()).is_err());
+
+    println!("{}", serde_json::to_string_pretty(&first).expect("attestation json"));
+}
@@ -0,0 +1,151 @@
+// path: planetary_stewardship_runtime/examples/governance_anchoring.rs
+
+//! Example: governance outcomes anchored into the ledger (run with `--features governance-anchoring,test-util`).
+//! - A proposal voted through is applied and anchored as a CSC attestation by the collective:
+//!   its title, its tally, and the proposal's and charter's hashes, appended to the ledger's
+//!   change chain
+//! - The anchor is listed, summarized and exported with every other attestation
+//! - Deciding the proposal again, before or after its idempotency key expires, returns the
+//!   same anchor; deciding it on another tally is refused
+//! - A vetoed proposal is anchored with its veto reason; one that fails its vote is not anchored
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::governance_anchor::{proposal_hash, proposal_uri};
+use planetary_stewardship_runtime::{
+    charter_set_hash, AppliedProposalRef, Compression, ConsentRecord, ConsentRegistry, Did, EvidenceArtifact,
+    GovernanceAnchoring, GovernanceAuditOutcome, GovernanceEngine, GovernanceProposal, GovernanceScope, ImpactMetrics,
+    MetricFieldValue, PlanetaryLedger, QuadraticOutcome, QuadraticVote, SaepEngine, StewardModule,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 24 * 3_600_000;
+
+fn proposal(id: &str, title: &str, description: &str) -> GovernanceProposal {
+    GovernanceProposal {
+        proposal_id: id.into(),
+        scope: GovernanceScope::EcosystemWide,
+        title: title.into(),
+        description: description.into(),
+        payload: serde_json::json!({}),
+        can_introduce_restrictions: false,
+    }
+}
+
+fn did(id: &str) -> Did {
+    Did::new(id).expect("valid DID")
+}
+
+fn main() -> Result<(), String> {
+    let config = saep_without_rollback_plans();
+    let clock = Arc::new(FixedClock::new(T0));
+    let ana = did("did:psv:steward:ana");
+    let mut consent = ConsentRegistry::new();
+    consent.upsert_consent(ConsentRecord {
+        participant: ana.clone(),
+        module: StewardModule::PLGA,
+        mission: None,
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    });
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        consent,
+        clock.clone(),
+        Arc::new(SequentialIdGenerator::new(1)),
+    );
+    let mut governance = GovernanceEngine::new(SaepEngine::new(config));
+    let collective = did("did:psv:governance:collective");
+
+    // 1. Setup: the CSC schema and the collective's consent, after one ordinary attestation.
+    ledger.issue_attestation_now(
+        ana.clone(),
+        None,
+        "Seed bank inventory".into(),
+        ImpactMetrics { restored_area_m2: 40.0, ..ImpactMetrics::default() },
+        EvidenceArtifact::new("ipfs://seed-bank", "image/jpeg"),
+        vec![],
+    )?;
+    let anchoring = GovernanceAnchoring::setup(&mut ledger, collective.clone(), T0)?;
+    assert!(ledger.consent().has_valid_consent(&collective, StewardModule::CSC, None));
+
+    // 2. A proposal voted through is applied and anchored.
+    let library = proposal("seed-library", "Open the seed library to every guild", "Lend seeds to all guilds");
+    for (voter, weight, support) in [("ana", 3.0, true), ("ben", 2.0, true), ("chi", 1.0, false)] {
+        let vote = QuadraticVote { voter: did(&format!("did:psv:steward:{voter}")), effective_weight: weight, support };
+        governance.cast_vote(&library, vote, &format!("{voter}-salt"), T0)?;
+    }
+    let tally = governance.finalize_votes(&library)?;
+    let before = ledger.view_head().clone();
+    let anchor = anchoring.decide(&mut governance, &mut ledger, library.clone(), tally.clone(), T0 + 1_000)?;
+    let anchor = anchor.expect("the vote passed");
+    assert_eq!(anchor.outcome, GovernanceAuditOutcome::Applied);
+
+    // 3. Its content: the collective, CSC, the title, the tally and the hashes.
+    let att = &anchor.attestation;
+    assert_eq!((&att.actor_did, att.module), (&collective, StewardModule::CSC));
+    assert_eq!(att.description, library.title);
+    assert_eq!(att.module_metrics["total_support"], MetricFieldValue::Number(5.0));
+    assert_eq!(att.module_metrics["total_opposition"], MetricFieldValue::Number(1.0));
+    assert_eq!(att.module_metrics["approved"], MetricFieldValue::Flag(true));
+    assert_eq!(att.evidence[0].uri, proposal_uri("seed-library"));
+    assert_eq!(att.evidence[0].content_hash, Some(format!("sha256:{}", proposal_hash(&library))));
+    let charter = charter_set_hash(&governance.charter_bound_modules().into_iter().collect());
+    assert_eq!(att.evidence[1].content_hash, Some(format!("sha256:{charter}")));
+    assert_eq!(att.evidence.len(), 2, "no veto reasons");
+
+    // 4. Chain linkage: appended right after the head it was issued at, and now the head.
+    assert_eq!(anchor.prev_head.as_ref(), Some(&before));
+    assert!(anchor.is_chained());
+    assert_eq!(anchor.head.generation, before.generation + 1);
+    assert_eq!(ledger.view_head(), &anchor.head);
+
+    // 5. Queryable and exportable with everything else.
+    assert_eq!(ledger.attestations_in_module(StewardModule::CSC).count(), 1);
+    assert_eq!(ledger.get_attestations_for_actor(&collective).len(), 1);
+    let summary = ledger.impact_summary(None);
+    assert_eq!(summary.module(StewardModule::CSC).map(|t| t.fields["approved"]), Some(1.0));
+    let mut exported = Vec::new();
+    let manifest = ledger.export_attestations(&mut exported, Compression::None).map_err(|e| e.to_string())?;
+    assert_eq!(manifest.records, 2);
+
+    // 6. Anchored once: a retry replays, also past the key's TTL, and a conflicting tally is refused.
+    let retry = anchoring.decide(&mut governance, &mut ledger, library.clone(), tally.clone(), T0 + 2_000)?;
+    let retry = retry.expect("still approved");
+    assert_eq!((retry.attestation.id.clone(), retry.prev_head), (att.id.clone(), None));
+    clock.advance(2 * DAY_MS);
+    let late = anchoring.decide(&mut governance, &mut ledger, library.clone(), tally.clone(), T0 + 2 * DAY_MS)?;
+    assert_eq!(late.expect("still approved").attestation.id, att.id);
+    let recount = QuadraticOutcome { total_opposition: 4.0, ..tally.clone() };
+    let conflict = anchoring.decide(&mut governance, &mut ledger, library.clone(), recount, T0 + 2 * DAY_MS);
+    let err = conflict.expect_err("applied on another tally");
+    assert_eq!(err, "Proposal seed-library was applied as another proposal or on another tally");
+    assert_eq!(ledger.attestations_in_module(StewardModule::CSC).count(), 1);
+
+    // 7. A veto is anchored with its reason, and applies nothing; a failed vote anchors nothing.
+    let drones = proposal("perimeter-drones", "Perimeter drone patrols", "Fit weapon mounts to the perimeter drones");
+    let votes = |p: &GovernanceProposal, support, opposition| QuadraticOutcome {
+        proposal_id: p.proposal_id.clone(),
+        total_support: support,
+        total_opposition: opposition,
+    };
+    let vetoed = anchoring.decide(&mut governance, &mut ledger, drones.clone(), votes(&drones, 4.0, 1.0), T0 + 3_000)?;
+    let vetoed = vetoed.expect("vetoed outcomes are anchored");
+    assert!(matches!(&vetoed.outcome, GovernanceAuditOutcome::Vetoed { reasons, .. } if reasons.len() == 1));
+    assert_eq!(vetoed.attestation.module_metrics["vetoed"], MetricFieldValue::Flag(true));
+    assert_eq!(vetoed.attestation.evidence[2].uri, format!("{}:veto:1", proposal_uri("perimeter-drones")));
+    assert!(vetoed.is_chained());
+    assert!(governance.applied_proposal(&AppliedProposalRef { proposal_id: drones.proposal_id }).is_none());
+    let compost = proposal("compost-fees", "Compost pickup fees", "Charge for compost pickup");
+    let rejected = votes(&compost, 1.0, 3.0);
+    assert!(anchoring.decide(&mut governance, &mut ledger, compost, rejected, T0 + 4_000)?.is_none());
+    assert_eq!(ledger.attestations_in_module(StewardModule::CSC).count(), 2);
+
+    println!("{}", serde_json::to_string_pretty(&anchor.attestation).expect("json"));
+    Ok(())
+}
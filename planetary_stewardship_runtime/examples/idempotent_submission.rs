@@ -0,0 +1,138 @@
+// path: planetary_stewardship_runtime/examples/idempotent_submission.rs
+
+//! Example: a mobile client retrying submissions whose responses were lost (run with `--features test-util`).
+//! - A replay with the same key and request returns the same attestation (and assignment);
+//!   nothing new is issued, and the replay is not seen as evidence reuse
+//! - The same key with a changed request is a conflict; a failed attempt holds no key
+//! - Once the TTL has passed on the ledger's clock, the key creates a new attestation
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AttestationRequest, ConsentRecord, ConsentRegistry, Did, EvidenceArtifact, IdempotencyKey, ImpactMetrics,
+    MicroMissionsEngine, MissionId, MissionTemplate, ModuleMetrics, PlanetaryLedger, ReasonCode, SaepEngine,
+    SaepRefs, StewardModule, ValidationCode,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const HOUR_MS: u64 = 3_600_000;
+
+fn request(actor: &Did, restored_area_m2: f64) -> AttestationRequest {
+    AttestationRequest {
+        actor_did: actor.clone(),
+        mission_id: None,
+        description: "Riverbank ivy removal".into(),
+        module: StewardModule::PLGA,
+        impact_metrics: ImpactMetrics { restored_area_m2, ..ImpactMetrics::default() },
+        module_metrics: ModuleMetrics::new(),
+        evidence: vec![EvidenceArtifact::new("ipfs://ivy", "image/jpeg").with_content_hash("sha256:ivy")],
+        verifier_dids: vec![],
+        timestamp_ms: T0,
+        refs: SaepRefs::default(),
+        capture_window: None,
+        witness_endorsement: None,
+    }
+}
+
+fn consent(who: &Did, module: StewardModule, mission: Option<&MissionId>) -> ConsentRecord {
+    ConsentRecord {
+        participant: who.clone(),
+        module,
+        mission: mission.cloned(),
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    }
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mut registry = ConsentRegistry::new();
+    registry.upsert_consent(consent(&ana, StewardModule::PLGA, None));
+    let config = saep_without_rollback_plans();
+    let clock = Arc::new(FixedClock::new(T0));
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        registry,
+        clock.clone(),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_idempotency_ttl(24 * HOUR_MS);
+
+    // 1. Lost response: the retry gets the same attestation back, and only one exists.
+    let first = ledger
+        .issue_attestation_idempotent(request(&ana, 120.0), Some(IdempotencyKey::new("app-7f3a", &request(&ana, 120.0))))
+        .expect("issued");
+    clock.advance(HOUR_MS);
+    let retry = ledger
+        .issue_attestation_idempotent(request(&ana, 120.0), Some(IdempotencyKey::new("app-7f3a", &request(&ana, 120.0))))
+        .expect("replayed");
+    assert_eq!(retry.id, first.id);
+    assert_eq!(ledger.attestations().count(), 1);
+    assert_eq!(ledger.impact_summary(Some(&ana)).totals.restored_area_m2, 120.0);
+
+    // 2. The replay is not a duplicate; a fresh submission of the same evidence still is.
+    let fresh = ledger.validate_attestation(&request(&ana, 120.0));
+    assert!(fresh.warnings.iter().any(|w| w.code == ValidationCode::EvidenceReused));
+
+    // 3. Same key, changed request: a conflict, and nothing is issued.
+    let mutated = request(&ana, 450.0);
+    let conflict = ledger
+        .issue_attestation_idempotent(mutated.clone(), Some(IdempotencyKey::new("app-7f3a", &mutated)))
+        .expect_err("key bound to the first request");
+    assert_eq!(conflict, "Idempotency key app-7f3a was already used for a different request");
+    assert_eq!(ledger.attestations().count(), 1);
+
+    // 4. Failures hold no key: a refused attempt can be retried once fixed.
+    let kofi = Did::new("did:psv:steward:kofi").expect("valid DID");
+    let kofi_key = || IdempotencyKey::new("app-91c0", &request(&kofi, 30.0));
+    let refused = ledger.issue_attestation_idempotent(request(&kofi, 30.0), Some(kofi_key())).expect_err("no consent yet");
+    assert_eq!(ledger.idempotency_keys_held(), 1);
+    // The dry run names the same check, coded, with the participant it concerns.
+    let report = ledger.validate_attestation(&request(&kofi, 30.0));
+    let reason = report.issues[0].reason.clone().expect("coded issue");
+    assert_eq!((reason.code, reason.to_string()), (ReasonCode::ConsentMissing, refused));
+    assert_eq!(reason.params["participant"], kofi.to_string());
+
+    // 5. Expiry: past the TTL the key is free again and creates a new attestation; recording
+    //    it drops the expired key.
+    clock.set(T0 + 24 * HOUR_MS);
+    let reused = ledger
+        .issue_attestation_idempotent(mutated.clone(), Some(IdempotencyKey::new("app-7f3a", &mutated)))
+        .expect("expired key reused");
+    assert_ne!(reused.id, first.id);
+    assert_eq!(ledger.attestations().count(), 2);
+    assert_eq!(ledger.idempotency_keys_held(), 1);
+
+    // 6. Assignments replay the same way, by the `now_ms` each call is made at.
+    let creek = MissionId::new("creek-cleanup").expect("valid mission id");
+    let mut consent_mme = ConsentRegistry::new();
+    consent_mme.upsert_consent(consent(&ana, StewardModule::MME, Some(&creek)));
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), consent_mme).with_idempotency_ttl(HOUR_MS);
+    missions.add_template(MissionTemplate {
+        id: creek.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+    let assign = |missions: &mut MicroMissionsEngine, now_ms| {
+        let key = IdempotencyKey::new("app-assign-1", &(&creek, &ana));
+        missions.assign_mission_idempotent(&creek, ana.clone(), now_ms, SaepRefs::default(), Some(key))
+    };
+    let assigned = assign(&mut missions, T0).expect("assigned");
+    let replayed = assign(&mut missions, T0 + 1_000).expect("replayed");
+    assert_eq!((replayed.assigned_ts_ms, missions.active_assignments().len()), (assigned.assigned_ts_ms, 1));
+    assign(&mut missions, T0 + HOUR_MS).expect("expired key assigns again");
+    assert_eq!(missions.active_assignments().len(), 2);
+    println!("attestation {} replayed; assignment replayed until its key expired", first.id.0);
+}
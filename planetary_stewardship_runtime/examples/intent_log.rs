@@ -0,0 +1,155 @@
+// path: planetary_stewardship_runtime/examples/intent_log.rs
+
+//! Example: the public intent log behind SAEP's transparency rule (run with `--features test-util`).
+//! - Under `enforce_transparency`, every attestation and assignment runs under an intent entry
+//! - A caller-supplied entry must exist and belong to the same actor and module
+//! - Ledger and missions share one log; it verifies and can be queried by actor and time
+//! - Tampering with a published entry breaks verification
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    ConsentRecord, ConsentRegistry, Did, ImpactMetrics, IntentEntry, IntentEntryId, IntentLog, MicroMissionsEngine,
+    MissionId, MissionTemplate, PlanetaryLedger, SaepEngine, StewardModule,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn metrics() -> ImpactMetrics {
+    ImpactMetrics {
+        co2eq_reduced: 0.3,
+        biodiversity_index_delta: 0.01,
+        biodiversity_detail: None,
+        restored_area_m2: 40.0,
+        avoided_emissions_co2eq: 0.0,
+        custom: Default::default(),
+    }
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let kofi = Did::new("did:psv:steward:kofi").expect("valid DID");
+    let mission = MissionId::new("creek-cleanup").expect("valid mission id");
+    let config = saep_without_rollback_plans();
+    assert!(config.enforce_transparency);
+
+    let mut consent = ConsentRegistry::new();
+    for (who, module, mission) in [
+        (&ana, StewardModule::PLGA, None),
+        (&kofi, StewardModule::PLGA, None),
+        (&ana, StewardModule::MME, Some(mission.clone())),
+    ] {
+        consent.upsert_consent(ConsentRecord {
+            participant: who.clone(),
+            module,
+            mission,
+            consent_given: true,
+            timestamp_ms: T0,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        });
+    }
+    let mission_consent = {
+        let mut registry = ConsentRegistry::new();
+        for record in consent.records() {
+            registry.upsert_consent(record.clone());
+        }
+        registry
+    };
+
+    let log = IntentLog::shared();
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_intent_log(log.clone());
+    let mut missions =
+        MicroMissionsEngine::new(SaepEngine::new(config), mission_consent).with_intent_log(log.clone());
+    missions.add_template(MissionTemplate {
+        id: mission.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+
+    // 1. No intent supplied: the ledger publishes one before issuing.
+    let auto = ledger
+        .issue_attestation(ana.clone(), None, "Planted 12 willows".into(), metrics(), "ipfs://willows", vec![], T0)
+        .expect("attestation issued");
+    let auto_intent = auto.intent_entry_id.clone().expect("transparency requires an intent entry");
+    assert!(log.lock().unwrap().get(&auto_intent).is_some());
+
+    // 2. An intent that was never published is refused, and nothing is issued.
+    let bogus = IntentEntryId("0".repeat(64));
+    let err = ledger
+        .issue_attestation_with_intent(
+            ana.clone(), None, "Planted 8 alders".into(), metrics(), "ipfs://alders", vec![], T0 + 1, Some(bogus),
+        )
+        .expect_err("unknown intent entry");
+    println!("refused: {err}");
+    assert_eq!(ledger.get_attestations_for_actor(&ana).len(), 1);
+
+    // 3. Someone else's intent cannot be borrowed.
+    let kofi_intent = log.lock().unwrap().publish(IntentEntry {
+        actor: kofi.clone(),
+        module: StewardModule::PLGA,
+        description: "Will restore the east bank".into(),
+        decision_id: "dashboard:kofi:east-bank".into(),
+        timestamp_ms: T0 + 2,
+    });
+    let err = ledger
+        .issue_attestation_with_intent(
+            ana.clone(), None, "Restored the east bank".into(), metrics(), "ipfs://east", vec![], T0 + 3,
+            Some(kofi_intent.clone()),
+        )
+        .expect_err("intent belongs to kofi");
+    println!("refused: {err}");
+    let issued = ledger
+        .issue_attestation_with_intent(
+            kofi.clone(), None, "Restored the east bank".into(), metrics(), "ipfs://east", vec![], T0 + 3,
+            Some(kofi_intent.clone()),
+        )
+        .expect("kofi's own intent");
+    assert_eq!(issued.intent_entry_id, Some(kofi_intent));
+
+    // 4. Assignments publish into the same log.
+    let assigned = missions.assign_mission(&mission, ana.clone(), T0 + 10).expect("assigned");
+    assert!(assigned.intent_entry_id.is_some());
+
+    let log = log.lock().unwrap();
+    log.verify().expect("chain intact");
+    let ana_entries = log.query(Some(&ana), T0, T0 + 60_000);
+    println!("{} intent entries, {} by ana in the first minute", log.entries().len(), ana_entries.len());
+    assert_eq!(log.entries().len(), 3);
+    assert_eq!(ana_entries.len(), 2);
+    assert!(log.query(None, T0 + 5, T0 + 10).is_empty());
+
+    // 5. Tampering is detected.
+    let mut tampered: IntentLog = serde_json::from_value({
+        let mut json = serde_json::to_value(&*log).expect("log serializes");
+        json["entries"][0]["entry"]["description"] = "Planted 1000 willows".into();
+        json
+    })
+    .expect("log deserializes");
+    let broken = tampered.verify().expect_err("tampering detected");
+    println!("tampered log: {broken}");
+    tampered.publish(IntentEntry {
+        actor: ana,
+        module: StewardModule::PLGA,
+        description: "appending does not repair the chain".into(),
+        decision_id: "n/a".into(),
+        timestamp_ms: T0 + 20,
+    });
+    assert!(tampered.verify().is_err());
+}
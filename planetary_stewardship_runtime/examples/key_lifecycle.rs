@@ -0,0 +1,176 @@
+// path: planetary_stewardship_runtime/examples/key_lifecycle.rs
+
+//! Example: a forester's keys rotated and revoked under a `KeyRegistry` (run with `--features test-util`).
+//! - An endorsement under a since-rotated key still passes for a claim made before the rotation,
+//!   and is refused for one made after
+//! - A revocation dated back to a compromise: endorsements made before it pass, later ones fail
+//! - A governance tally-signing key can neither endorse nor approve attestations
+//! - Only registry admins change keys, and a revocation waits for a second admin to confirm it;
+//!   every change is in the audit log, which round-trips through JSON with the keys
+//! - Signatures are a toy keyed SHA-256 with the key material as the key
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::{did, AttestationBuilder, LedgerBuilder};
+use planetary_stewardship_runtime::witness::statement_hash;
+use planetary_stewardship_runtime::{
+    AdminAction, AdminProposal, AttestationRequest, Did, DualControl, DualControlPolicy, ImpactThresholdPolicy,
+    KeyError, KeyEventAction, KeyPurpose, KeyRegistry, PendingState, PublicKey, ReasonCode, RegistryKeyResolver,
+    ReviewError, SignatureScheme, VerificationPolicy, VerifierVerdict, WitnessEndorsement,
+};
+use sha2::{Digest, Sha256};
+use steward_runtime_support::FixedClock;
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+struct ToyScheme;
+
+fn sign(material: &str, message: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(material.as_bytes());
+    hasher.update(message);
+    format!("{:x}", hasher.finalize())
+}
+
+impl SignatureScheme for ToyScheme {
+    fn verify(&self, key: &PublicKey, message: &[u8], signature: &str) -> bool {
+        sign(&key.material, message) == signature
+    }
+}
+
+fn key(owner: &Did, key_id: &str, purpose: KeyPurpose) -> PublicKey {
+    PublicKey { did: owner.clone(), key_id: key_id.into(), purpose, material: format!("{key_id}-material") }
+}
+
+/// `request` endorsed by `witness` with key `key_id`.
+fn endorsed(mut request: AttestationRequest, witness: &Did, key_id: &str) -> AttestationRequest {
+    let hash = statement_hash(&request);
+    request.witness_endorsement = Some(WitnessEndorsement {
+        witness_did: witness.clone(),
+        signature: sign(&format!("{key_id}-material"), hash.as_bytes()),
+        statement_hash: hash,
+        key_id: key_id.into(),
+    });
+    request
+}
+
+fn main() {
+    let (crew, forester, city) =
+        (did("did:psv:steward:crew"), did("did:psv:city:phoenix:forester"), did("did:psv:verifier:city"));
+    let (registrar, stranger) = (did("did:psv:city:phoenix:registrar"), did("did:psv:steward:stranger"));
+    let clock = Arc::new(FixedClock::new(T0));
+
+    // 1. Keys: only the registrar may register them, and each registration is logged.
+    let mut registry = KeyRegistry::new([registrar.clone()]);
+    let first = key(&forester, "forester-2026a", KeyPurpose::AttestationVerification);
+    let refused = registry.register_key(first.clone(), T0, None, &stranger, T0).expect_err("not an admin");
+    assert!(refused.contains("not a key registry admin"), "{refused}");
+    for key in [
+        first,
+        key(&forester, "forester-tally", KeyPurpose::GovernanceTallySigning),
+        key(&city, "city-att", KeyPurpose::AttestationVerification),
+        key(&city, "city-tally", KeyPurpose::GovernanceTallySigning),
+    ] {
+        registry.register_key(key, T0, None, &registrar, T0).expect("registered");
+    }
+    assert_eq!(registry.audit_log().len(), 4);
+    let registry = registry.into_shared();
+    let resolver = RegistryKeyResolver::new(registry.clone(), Arc::new(ToyScheme));
+    let mut ledger = LedgerBuilder::new()
+        .seed(11)
+        .clock(clock.clone())
+        .without_rollback_plans()
+        .consenting(&crew)
+        .build()
+        .with_impact_threshold_policy(ImpactThresholdPolicy {
+            co2eq_reduced_above: Some(5.0),
+            witnesses: [forester.clone()].into(),
+            ..ImpactThresholdPolicy::default()
+        })
+        .with_key_resolver(Arc::new(resolver))
+        .with_verification_policy(VerificationPolicy { quorum: 1, review_window_ms: 7 * DAY_MS });
+    let claim = |description: &str, timestamp_ms: u64| {
+        AttestationBuilder::new(&crew).description(description).co2eq_reduced(8.0).timestamp_ms(timestamp_ms).request()
+    };
+
+    // 2. Rotation a day in: the old key keeps covering what was claimed before it.
+    let rotate_at = T0 + DAY_MS;
+    let second = key(&forester, "forester-2026b", KeyPurpose::AttestationVerification);
+    let mut keys = registry.lock().unwrap();
+    keys.rotate_key(&forester, "forester-2026a", second, rotate_at, &registrar, rotate_at).expect("rotated");
+    drop(keys);
+    clock.set(T0 + 2 * DAY_MS);
+    let before = endorsed(claim("Wash cleanup, before rotation", rotate_at - 1), &forester, "forester-2026a");
+    ledger.issue_attestation_idempotent(before, None).expect("pre-rotation endorsement under the rotated key");
+    let after = endorsed(claim("Wash cleanup, after rotation", rotate_at), &forester, "forester-2026a");
+    let report = ledger.validate_attestation(&after);
+    let reason = report.issues[0].reason.clone().expect("coded");
+    assert_eq!(reason.code, ReasonCode::WitnessKeyRejected);
+    println!("{reason}");
+    let after = endorsed(claim("Wash cleanup, after rotation", rotate_at), &forester, "forester-2026b");
+    ledger.issue_attestation_idempotent(after, None).expect("post-rotation endorsement under the successor");
+
+    // 3. Revocation recorded on day 4, effective from a compromise on day 3; the registrar
+    //    proposes it and the security officer confirms.
+    let compromised_at = T0 + 3 * DAY_MS;
+    let security = did("did:psv:city:phoenix:security");
+    let mut admin = DualControl::new(DualControlPolicy {
+        admins: [registrar.clone(), security.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    let revocation = AdminAction::RevokeKey {
+        did: forester.clone(),
+        key_id: "forester-2026b".into(),
+        revoked_from_ms: compromised_at,
+        reason: "Laptop stolen".into(),
+    };
+    let mut keys = registry.lock().unwrap();
+    admin.bind(&*keys).expect("the registry answers to this controller alone");
+    assert!(admin.propose(&mut *keys, revocation.clone(), &stranger, T0 + 4 * DAY_MS).is_err());
+    let proposal = admin.propose(&mut *keys, revocation, &registrar, T0 + 4 * DAY_MS).expect("registry admin");
+    let AdminProposal::Pending { action_id, .. } = proposal else {
+        panic!("a revocation waits for a second admin");
+    };
+    assert!(keys.revocation(&forester, "forester-2026b").is_none());
+    admin.confirm(&mut *keys, &action_id, &security, T0 + 4 * DAY_MS).expect("revoked");
+    drop(keys);
+    clock.set(T0 + 4 * DAY_MS);
+    let earlier = endorsed(claim("Tree survey, day 2", compromised_at - 1), &forester, "forester-2026b");
+    ledger.issue_attestation_idempotent(earlier, None).expect("signed before the compromise");
+    let later = endorsed(claim("Tree survey, day 3", compromised_at), &forester, "forester-2026b");
+    let refused = ledger.issue_attestation_idempotent(later, None).expect_err("signed after the compromise");
+    assert!(refused.contains("revoked"), "{refused}");
+
+    // 4. A tally-signing key endorses nothing, and approves nothing.
+    let tally = endorsed(claim("Tree survey, day 1", T0), &forester, "forester-tally");
+    let refused = ledger.issue_attestation_idempotent(tally, None).expect_err("wrong purpose");
+    assert!(refused.contains("GovernanceTallySigning"), "{refused}");
+    let mut small = AttestationBuilder::new(&crew).description("Seed library").co2eq_reduced(1.0);
+    small = small.timestamp_ms(T0 + 4 * DAY_MS).verifier(&city);
+    let pending = ledger.submit_for_verification(small.request()).expect("submitted");
+    let hash = ledger.pending_verification(&pending).expect("pending").statement_hash.clone();
+    let approve = |key_id: &str| VerifierVerdict::Approve {
+        signature: sign(&format!("{key_id}-material"), hash.as_bytes()),
+        key_id: key_id.into(),
+    };
+    let Err(ReviewError::KeyRejected { error, .. }) = ledger.review(&pending, &city, approve("city-tally")) else {
+        panic!("a tally key cannot approve");
+    };
+    assert!(matches!(error, KeyError::WrongPurpose { purpose: KeyPurpose::GovernanceTallySigning, .. }));
+    assert!(matches!(ledger.review(&pending, &city, approve("city-att")), Ok(PendingState::Finalized { .. })));
+    assert_eq!(ledger.attestations().count(), 4);
+
+    // 5. The registry and its audit log survive a JSON round trip.
+    let registry = registry.lock().unwrap().clone();
+    let restored: KeyRegistry = serde_json::from_str(&serde_json::to_string(&registry).unwrap()).unwrap();
+    assert_eq!(restored, registry);
+    let rotated = restored.resolve(&forester, "forester-2026a", KeyPurpose::AttestationVerification, rotate_at);
+    assert!(matches!(rotated, Err(KeyError::OutsideValidity { .. })));
+    let successor = restored.key(&forester, "forester-2026a").and_then(|k| k.rotated_to.clone());
+    assert_eq!(successor.as_deref(), Some("forester-2026b"));
+    assert!(matches!(restored.audit_log().last().map(|e| &e.action), Some(KeyEventAction::Revoked { .. })));
+    for event in restored.audit_log() {
+        println!("{} {} {} {:?}", event.timestamp_ms, event.did, event.key_id, event.action);
+    }
+}
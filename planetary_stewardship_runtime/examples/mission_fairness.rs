@@ -0,0 +1,140 @@
+// path: planetary_stewardship_runtime/examples/mission_fairness.rs
+
+//! Example: keeping one quick volunteer from taking every mission (run with `--features test-util`).
+//! - Two open assignments at a time: a third is refused with the limit until one is closed
+//! - Two high-demand claims a week, closed ones included: the third is refused until the
+//!   first leaves the rolling window, to the millisecond; ordinary missions are unaffected
+//! - A mission published under a claim delay takes only waitlist joins for an hour, then a
+//!   seeded lottery assigns two entrants; the same seed and entrants give the same draw, in
+//!   whatever order they joined, and an entrant at their cap is passed over
+//! - The seed is committed to when the mission is published: publishing without a commitment
+//!   is refused, and so is a draw with any seed but the committed one
+
+use planetary_stewardship_runtime::builders::{
+    did, mission_id, saep_without_rollback_plans, ConsentRecordBuilder, MissionTemplateBuilder,
+};
+use planetary_stewardship_runtime::fairness::{lottery_order, seed_commitment};
+use planetary_stewardship_runtime::{
+    AssignmentStatus, ClaimDelay, ConsentRegistry, Did, FairnessPolicy, FairnessViolation, HighDemandCap, LotteryDraw,
+    MicroMissionsEngine, SaepEngine, StewardModule, ValidationCode,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const HOUR_MS: u64 = 3_600_000;
+const DAY_MS: u64 = 24 * HOUR_MS;
+
+const ORDINARY: [&str; 4] = ["creek-cleanup", "tree-watering", "litter-pick", "river-survey"];
+const HIGH_DEMAND: [&str; 3] = ["bird-count-1", "bird-count-2", "bird-count-3"];
+
+fn engine(volunteers: &[&Did]) -> MicroMissionsEngine {
+    let mut consent = ConsentRegistry::new();
+    for volunteer in volunteers {
+        for mission in ORDINARY.iter().chain(&HIGH_DEMAND) {
+            let record = ConsentRecordBuilder::new(volunteer, StewardModule::MME).mission(&mission_id(mission));
+            consent.upsert_consent(record.build());
+        }
+    }
+    let saep = SaepEngine::new(saep_without_rollback_plans());
+    let policy = FairnessPolicy {
+        max_active_assignments: Some(2),
+        high_demand: Some(HighDemandCap { max_claims: 2, window_ms: 7 * DAY_MS }),
+        claim_delay: Some(ClaimDelay { delay_ms: HOUR_MS, slots: 2 }),
+    };
+    let mut missions = MicroMissionsEngine::new(saep, consent).with_fairness_policy(policy);
+    for id in &ORDINARY[..3] {
+        missions.add_template(MissionTemplateBuilder::new(id).build());
+    }
+    for id in HIGH_DEMAND {
+        missions.add_template(MissionTemplateBuilder::new(id).high_demand().build());
+    }
+    missions
+}
+
+fn main() -> Result<(), String> {
+    let [ana, kofi, mei, lina, tomas] =
+        ["ana", "kofi", "mei", "lina", "tomas"].map(|n| did(&format!("did:psv:steward:{n}")));
+    let everyone = [&ana, &kofi, &mei, &lina, &tomas];
+    let mut missions = engine(&everyone);
+    let [creek, watering, litter] = ["creek-cleanup", "tree-watering", "litter-pick"].map(mission_id);
+
+    // 1. Ana holds two missions; a third waits until one is closed.
+    missions.assign_mission(&creek, ana.clone(), T0)?;
+    let watering_key = missions.assign_mission(&watering, ana.clone(), T0 + 1)?.key();
+    let refused = missions.check_fairness(&litter, &ana, T0 + 2).expect_err("two open");
+    assert_eq!(refused, FairnessViolation::ActiveAssignments { assignee: ana.clone(), active: 2, max: 2 });
+    assert!(missions.validate_assignment(&litter, &ana, T0 + 2).has(ValidationCode::Fairness));
+    let message = missions.assign_mission(&litter, ana.clone(), T0 + 2).expect_err("two open");
+    assert!(message.contains("the limit is 2"), "{message}");
+    missions.assign_mission(&litter, kofi.clone(), T0 + 2)?;
+    missions.close_assignment(&watering_key, AssignmentStatus::Completed, T0 + 3)?;
+    missions.assign_mission(&litter, ana.clone(), T0 + 4)?;
+
+    // 2. Kofi's high-demand claims: two in a week, counted after they are closed.
+    let [first, second, third] = HIGH_DEMAND.map(mission_id);
+    for (mission, at_ms) in [(&first, T0), (&second, T0 + DAY_MS)] {
+        let key = missions.assign_mission(mission, kofi.clone(), at_ms)?.key();
+        missions.close_assignment(&key, AssignmentStatus::Completed, at_ms + HOUR_MS)?;
+    }
+    let week_edge = T0 + 7 * DAY_MS;
+    let capped = missions.check_fairness(&third, &kofi, week_edge - 1).expect_err("two this week");
+    assert_eq!(
+        capped,
+        FairnessViolation::HighDemandClaims { assignee: kofi.clone(), claims: 2, max: 2, window_ms: 7 * DAY_MS }
+    );
+    assert!(missions.check_fairness(&creek, &kofi, week_edge - 1).is_ok(), "not high demand");
+    assert!(missions.check_fairness(&third, &mei, week_edge - 1).is_ok(), "mei has claimed none");
+
+    // 3. A week after the first claim it rolls out of the window.
+    let key = missions.assign_mission(&third, kofi.clone(), week_edge)?.key();
+    missions.close_assignment(&key, AssignmentStatus::Completed, week_edge + HOUR_MS)?;
+
+    // 4. A new mission under a claim delay: waitlist joins only, for an hour.
+    let survey = mission_id("river-survey");
+    let published = week_edge + DAY_MS;
+    let survey_template = || MissionTemplateBuilder::new("river-survey").build();
+    let uncommitted = missions.publish_template(survey_template(), None, published).expect_err("no commitment");
+    assert!(uncommitted.contains("lottery seed commitment"), "{uncommitted}");
+    missions.publish_template(survey_template(), Some(seed_commitment(&survey, 4)), published)?;
+    let opens_ms = published + HOUR_MS;
+    let early = missions.assign_mission(&survey, tomas.clone(), published + 1).expect_err("waitlist only");
+    assert!(early.contains("takes waitlist joins"), "{early}");
+    for entrant in [&tomas, &mei, &ana, &lina, &kofi, &tomas] {
+        missions.join_waitlist(&survey, entrant.clone(), published + 2)?;
+    }
+    assert_eq!(missions.claim_window(&survey).map(|w| w.entrants.len()), Some(5), "tomas joined twice");
+    let pending = missions.check_fairness(&survey, &mei, opens_ms).expect_err("not drawn");
+    assert_eq!(pending, FairnessViolation::LotteryPending { mission: survey.clone() });
+    assert!(missions.join_waitlist(&survey, did("did:psv:steward:late"), opens_ms).is_err(), "closed");
+    assert!(missions.draw_lottery(&survey, 4, opens_ms - 1).is_err(), "too early");
+    let shopped = missions.draw_lottery(&survey, 6, opens_ms).expect_err("not the committed seed");
+    assert!(shopped.contains("does not match"), "{shopped}");
+    assert!(missions.claim_window(&survey).is_some(), "still waiting for its draw");
+
+    // 5. The draw: two slots, in seed order; ana still holds two missions and is passed over.
+    let draw = missions.draw_lottery(&survey, 4, opens_ms)?;
+    assert!(draw.verify());
+    assert!(!LotteryDraw { seed: 6, order: lottery_order(&survey, 6, &draw.entrants), ..draw.clone() }.verify());
+    assert_eq!(draw.order, [&ana, &mei, &lina, &kofi, &tomas].map(Did::clone));
+    assert_eq!(draw.assigned, [mei.clone(), lina.clone()]);
+    assert_eq!(draw.passed_over.len(), 1);
+    assert_eq!(draw.passed_over[0].entrant, ana);
+    assert!(draw.passed_over[0].reason.contains("active assignments"), "{}", draw.passed_over[0].reason);
+    assert_eq!(missions.lottery_draws(), std::slice::from_ref(&draw));
+
+    // 6. Determinism: the recorded seed and entrants repeat the draw, in any join order.
+    let mut rejoined = draw.entrants.clone();
+    rejoined.reverse();
+    assert_eq!(lottery_order(&survey, 4, &rejoined), draw.order);
+    assert_ne!(lottery_order(&survey, 6, &rejoined), draw.order, "another seed, another order");
+    let mut replay = engine(&everyone);
+    replay.publish_template(survey_template(), Some(draw.seed_commitment.clone()), published)?;
+    for entrant in rejoined {
+        replay.join_waitlist(&survey, entrant, published + 2)?;
+    }
+    assert_eq!(replay.draw_lottery(&survey, 4, opens_ms)?.order, draw.order);
+
+    // 7. After the draw the mission is assigned as any other.
+    missions.assign_mission(&survey, tomas.clone(), opens_ms + 1)?;
+    println!("{}", serde_json::to_string_pretty(&draw).expect("json"));
+    Ok(())
+}
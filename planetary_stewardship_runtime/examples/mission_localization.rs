@@ -0,0 +1,113 @@
+// path: planetary_stewardship_runtime/examples/mission_localization.rs
+
+//! Example: one mission template serving English and Spanish speakers (run with `--features test-util`).
+//! - `template_in` falls back from `es-MX` to `es`, and from an unlisted language to the default
+//! - Search matches the strings of the language asked for
+//! - SAEP reads each assignee's language at assignment: a translation carrying a flagged
+//!   phrase blocks Spanish speakers only, and the assignments still share one template
+//! - A localization with a title but no description is rejected
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    ConsentRegistry, Did, LanguageTag, MicroMissionsEngine, MissionId, MissionTemplate, SaepConfig, SaepEngine,
+    TemplateQuery,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn lang(s: &str) -> LanguageTag {
+    LanguageTag::new(s).expect("valid language tag")
+}
+
+fn template(id: &str, es_description: &str) -> MissionTemplate {
+    serde_json::from_value(serde_json::json!({
+        "id": id,
+        "title": "Creek cleanup",
+        "description": "Remove litter along Salt River creek",
+        "difficulty": "S",
+        "expected_impact": {},
+        "location_hint": "geo",
+        "required_skills": [],
+        "default_language": "en",
+        "localized_content": {
+            "es": { "title": "Limpieza del arroyo", "description": es_description }
+        }
+    }))
+    .expect("template json")
+}
+
+fn main() {
+    // Only the wording rules matter here; consent, intent and rollback checks are other examples' subjects.
+    let config = SaepConfig {
+        enforce_transparency: false,
+        enforce_informed_consent: false,
+        ..saep_without_rollback_plans()
+    };
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), ConsentRegistry::new());
+    let creek = MissionId::new("phx-creek-cleanup").expect("valid mission id");
+    missions
+        .register_template(template(creek.as_str(), "Recoger basura a lo largo del arroyo Salt River"))
+        .expect("complete localization");
+
+    // Tags normalize, and regional variants fall back to the bare language.
+    assert_eq!(lang("ES-mx").as_str(), "es-MX");
+    let es = missions.template_in(&creek, &lang("es-MX")).expect("known mission");
+    println!("{}", serde_json::to_string_pretty(&es).expect("resolved json"));
+    assert_eq!((es.title.as_str(), es.language, es.fallback), ("Limpieza del arroyo", Some(lang("es")), false));
+    let fr = missions.template_in(&creek, &lang("fr")).expect("known mission");
+    assert_eq!((fr.title.as_str(), fr.language, fr.fallback), ("Creek cleanup", Some(lang("en")), true));
+
+    // Search reads the requested language's strings.
+    let search = |text: &str, language: Option<LanguageTag>| {
+        missions.search_templates(&TemplateQuery { language, ..TemplateQuery::text(text) })
+    };
+    assert_eq!(search("arroyo", Some(lang("es"))).len(), 1);
+    assert!(search("arroyo", Some(lang("en"))).is_empty());
+    assert_eq!(search("creek litter", None)[0].template.mission_id, creek);
+
+    // A translation that slipped in a flagged phrase: SAEP sees it only for Spanish speakers.
+    let orchard = MissionId::new("phx-orchard").expect("valid mission id");
+    missions
+        .register_template(template(orchard.as_str(), "Cosecha para exclusive monetization del huerto"))
+        .expect("complete localization");
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let luis = Did::new("did:psv:steward:luis").expect("valid DID");
+    missions.set_preferred_language(luis.clone(), lang("es-MX"));
+    assert!(missions.validate_assignment(&orchard, &ana, T0).is_valid());
+    let err = missions.assign_mission(&orchard, luis.clone(), T0).expect_err("commons rule fires in Spanish");
+    println!("luis: {err}");
+    missions.assign_mission(&orchard, ana.clone(), T0).expect("English description is clean");
+    missions.assign_mission(&creek, luis, T0).expect("clean in both languages");
+    assert_eq!(missions.active_assignments().len(), 2);
+
+    // A Spanish title without its description is refused.
+    let partial = template("phx-shade-trees", "");
+    let err = missions.register_template(partial).expect_err("incomplete localization");
+    println!("rejected: {err}");
+    assert!(err.contains("has a title but no description in es"));
+    assert!(missions.get_template(&MissionId::new("phx-shade-trees").expect("valid mission id")).is_none());
+
+    #[cfg(feature = "toml")]
+    {
+        let bundle = r#"
+            [[template]]
+            id = "phx-shade-trees"
+            title = "Shade trees"
+            description = "Plant mesquite along the bus stop"
+            difficulty = "M"
+            expected_impact = {}
+            location_hint = "geo"
+            required_skills = []
+            default_language = "en"
+
+            [template.localized_content.es]
+            title = "Árboles de sombra"
+            description = "Plantar mezquites junto a la parada de autobús"
+        "#;
+        assert_eq!(missions.load_templates_toml(bundle), Ok(1));
+        let shade = MissionId::new("phx-shade-trees").expect("valid mission id");
+        assert_eq!(missions.template_in(&shade, &lang("es")).expect("loaded").title, "Árboles de sombra");
+        let broken = bundle.replace("description = \"Plantar mezquites junto a la parada de autobús\"", "");
+        assert!(missions.load_templates_toml(&broken).is_err());
+    }
+}
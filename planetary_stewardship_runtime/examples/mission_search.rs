@@ -0,0 +1,89 @@
+// path: planetary_stewardship_runtime/examples/mission_search.rs
+
+//! Example: searching mission templates by text and filters.
+//! - Multi-term queries need every term; title hits outrank description hits
+//! - Tokens match exactly: no stemming, no prefixes, punctuation splits words
+//! - Filters narrow by difficulty, the participant's skills and location kind
+//! - Ties rank by mission id whatever the insertion order, and replacing a template
+//!   re-files it in the index
+
+use planetary_stewardship_runtime::{
+    ConsentRegistry, Difficulty, LocationKind, MicroMissionsEngine, MissionId, MissionTemplate, SaepConfig, SaepEngine,
+    TemplateQuery,
+};
+
+fn template(id: &str, title: &str, description: &str, difficulty: &str, location: &str, skills: &[&str]) -> MissionTemplate {
+    MissionTemplate {
+        id: MissionId::new(id).expect("valid mission id"),
+        title: title.into(),
+        description: description.into(),
+        difficulty: difficulty.into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: location.into(),
+        required_skills: skills.iter().map(|s| s.to_string()).collect(),
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    }
+}
+
+fn library() -> Vec<MissionTemplate> {
+    vec![
+        template("creek-cleanup", "Creek cleanup", "Remove litter along the creek bank.", "S", "geo", &[]),
+        template("creek-survey", "Water quality survey", "Sample the creek; log turbidity and litter.", "M", "geo", &["sampling"]),
+        template("map-creeks", "Map the creeks", "Trace seasonal creeks from satellite tiles.", "M", "virtual", &["gis"]),
+        template("tree-planting", "Tree planting", "Plant shade trees along the greenway.", "L", "geo", &["planting"]),
+        template("seed-library", "Seed library", "Catalogue donated seeds.", "XS", "virtual", &[]),
+    ]
+}
+
+fn ids(missions: &MicroMissionsEngine, query: &TemplateQuery) -> Vec<String> {
+    missions.search_templates(query).into_iter().map(|hit| hit.template.mission_id.to_string()).collect()
+}
+
+fn main() {
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new());
+    for tpl in library() {
+        missions.add_template(tpl);
+    }
+
+    // 1. Multi-term: both terms must match. The cleanup has "creek" twice and "litter" once
+    //    (2 + 1 + 1 = 4 points); the survey has each once in its description (2).
+    let hits = missions.search_templates(&TemplateQuery::text("Creek litter"));
+    println!("{}", serde_json::to_string_pretty(&hits).expect("hits json"));
+    let scored: Vec<(&str, u32)> = hits.iter().map(|h| (h.template.mission_id.as_str(), h.score)).collect();
+    assert_eq!(scored, [("creek-cleanup", 4), ("creek-survey", 2)]);
+    assert!(ids(&missions, &TemplateQuery::text("creek planting")).is_empty());
+
+    // 2. Exact tokens: "creek" is not "creeks", "clean" is not "cleanup"; "creek;" is "creek".
+    assert_eq!(ids(&missions, &TemplateQuery::text("creeks")), ["map-creeks"]);
+    assert!(ids(&missions, &TemplateQuery::text("clean")).is_empty());
+    assert_eq!(ids(&missions, &TemplateQuery::text("turbidity")), ["creek-survey"]);
+
+    // 3. Filters, with or without text.
+    let easy_geo = TemplateQuery {
+        max_difficulty: Some(Difficulty::M),
+        location: Some(LocationKind::Geo),
+        ..TemplateQuery::default()
+    };
+    assert_eq!(ids(&missions, &easy_geo), ["creek-cleanup", "creek-survey"]);
+    let no_skills = TemplateQuery { skills: Some(vec![]), ..TemplateQuery::text("creek") };
+    assert_eq!(ids(&missions, &no_skills), ["creek-cleanup"]);
+    let gis = TemplateQuery { skills: Some(vec!["gis".into()]), min_difficulty: Some(Difficulty::M), ..TemplateQuery::default() };
+    assert_eq!(ids(&missions, &gis), ["map-creeks"]);
+
+    // 4. Stability: equal scores rank by id, in any insertion order; limits cut the tail.
+    let mut reversed = MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new());
+    for tpl in library().into_iter().rev() {
+        reversed.add_template(tpl);
+    }
+    let along = TemplateQuery::text("along");
+    assert_eq!(ids(&missions, &along), ["creek-cleanup", "tree-planting"]);
+    assert_eq!(ids(&reversed, &along), ids(&missions, &along));
+    assert_eq!(ids(&missions, &TemplateQuery { limit: Some(1), ..along.clone() }), ["creek-cleanup"]);
+
+    // 5. Replacing a template re-files it: old words stop matching, new ones match.
+    missions.add_template(template("creek-cleanup", "Creek cleanup", "Pull invasive reeds at the creek.", "S", "geo", &[]));
+    assert_eq!(ids(&missions, &along), ["tree-planting"]);
+    assert_eq!(ids(&missions, &TemplateQuery::text("reeds")), ["creek-cleanup"]);
+}
@@ -0,0 +1,176 @@
+// path: planetary_stewardship_runtime/examples/module_kill_switch.rs
+
+//! Example: pausing a module during an incident (run with `--features test-util`).
+//! - Governance pauses PLGA; issuance is refused while queries keep answering
+//! - Withdrawing consent stays possible under the pause (baseline right)
+//! - Restrictions pass SAEP and the charter, and every attempt lands in the audit trail
+//! - Resuming must name the pause; afterwards issuance works again
+//! - Pauses and resumes are admin actions; this deployment has one on-call admin and has
+//!   opted out of dual control (see the `dual_control` example for two admins). The governance
+//!   engine is bound to that controller, so another one cannot pause or resume through it
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AdminAction, AdminEffect, ConsentRecord, ConsentRegistry, Did, DualControl, DualControlPolicy,
+    GovernanceAuditOutcome, GovernanceEngine, GovernanceProposal, GovernanceScope, ImpactMetrics, MicroMissionsEngine,
+    MissionId, MissionTemplate, ModuleId, ModuleState, ModuleStatusRegistry, PauseId, PlanetaryLedger,
+    QuadraticOutcome, SaepEngine, StewardModule, TimelineOptions,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const HOUR_MS: u64 = 3_600_000;
+
+fn metrics() -> ImpactMetrics {
+    ImpactMetrics {
+        co2eq_reduced: 0.4,
+        biodiversity_index_delta: 0.0,
+        biodiversity_detail: None,
+        restored_area_m2: 30.0,
+        avoided_emissions_co2eq: 0.0,
+        custom: Default::default(),
+    }
+}
+
+fn consent(who: &Did, module: StewardModule, mission: Option<MissionId>, consent_given: bool, at_ms: u64) -> ConsentRecord {
+    ConsentRecord {
+        participant: who.clone(),
+        module,
+        mission,
+        consent_given,
+        timestamp_ms: at_ms,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    }
+}
+
+/// Run `action` on `governance` as `ops`, the single admin.
+fn run(
+    admin: &mut DualControl,
+    governance: &mut GovernanceEngine,
+    action: AdminAction,
+    ops: &Did,
+    at_ms: u64,
+) -> Result<AdminEffect, String> {
+    let proposal = admin.propose(governance, action, ops, at_ms)?;
+    Ok(proposal.into_effect().expect("single-admin mode executes on proposal"))
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let ops = Did::new("did:psv:steward:ops").expect("valid DID");
+    let mission = MissionId::new("creek-cleanup").expect("valid mission id");
+    let config = saep_without_rollback_plans();
+
+    let status = ModuleStatusRegistry::shared();
+    let registry = || {
+        let mut registry = ConsentRegistry::new();
+        registry.upsert_consent(consent(&ana, StewardModule::PLGA, None, true, T0));
+        registry.upsert_consent(consent(&ana, StewardModule::MME, Some(mission.clone()), true, T0));
+        registry
+    };
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        registry(),
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_module_status(status.clone());
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config.clone()), registry()).with_module_status(status.clone());
+    missions.add_template(MissionTemplate {
+        id: mission.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+    let mut governance = GovernanceEngine::new(SaepEngine::new(config)).with_module_status(status.clone());
+    let mut admin = DualControl::new(DualControlPolicy {
+        admins: [ops.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    admin.opt_out_single_admin(&ops, "One on-call operator".into(), T0).expect("ops is the admin");
+    admin.bind(&governance).expect("a new module status registry is unbound");
+    let mut other = DualControl::new(DualControlPolicy {
+        admins: [ops.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    assert!(other.bind(&governance).is_err(), "bound once, to `admin`");
+
+    let issue = |ledger: &mut PlanetaryLedger, at_ms: u64| {
+        ledger.issue_attestation(ana.clone(), None, "Planted willows".into(), metrics(), "ipfs://willows", vec![], at_ms)
+    };
+    let first = issue(&mut ledger, T0).expect("PLGA active");
+
+    // 1. Incident: pause PLGA.
+    let action = AdminAction::PauseModule { module: StewardModule::PLGA, reason: "Evidence store compromised".into() };
+    let effect = run(&mut admin, &mut governance, action, &ops, T0 + HOUR_MS).expect("pause passes SAEP");
+    let pause = effect.pause_id().expect("a pause").clone();
+    let err = issue(&mut ledger, T0 + HOUR_MS + 1).expect_err("issuance blocked while paused");
+    println!("refused: {err}");
+    let typed = status.lock().unwrap().check_writable(StewardModule::PLGA).expect_err("PLGA paused");
+    assert!(matches!(typed.state, ModuleState::Paused { ref id, .. } if id == &pause));
+
+    // 2. Reads keep working, and other modules are untouched.
+    assert!(ledger.get_attestation(&first.id).is_some());
+    assert_eq!(ledger.get_attestations_for_actor(&ana).len(), 1);
+    assert_eq!(ledger.actor_timeline(&ana, TimelineOptions::default()).entries.len(), 1);
+    missions.assign_mission(&mission, ana.clone(), T0 + HOUR_MS + 2).expect("MME still active");
+
+    // 3. Baseline right: consent can be withdrawn (and given back) during the pause.
+    ledger.consent_mut().upsert_consent(consent(&ana, StewardModule::PLGA, None, false, T0 + HOUR_MS + 3));
+    ledger.consent_mut().upsert_consent(consent(&ana, StewardModule::PLGA, None, true, T0 + HOUR_MS + 4));
+
+    // 4. A second pause, or a resume naming the wrong pause, is refused.
+    let again = AdminAction::PauseModule { module: StewardModule::PLGA, reason: "again".into() };
+    assert!(run(&mut admin, &mut governance, again, &ops, T0 + HOUR_MS + 5).is_err());
+    let wrong = AdminAction::ResumeModule { module: StewardModule::PLGA, pause_id: PauseId("pause:PLGA:0:0".into()) };
+    assert!(run(&mut admin, &mut governance, wrong, &ops, T0 + HOUR_MS + 6).is_err());
+
+    // 5. Restricting is itself checked: the charter vetoes this one, and the veto is recorded.
+    let reason = "Hold missions to redeploy volunteers for military logistics".to_string();
+    let action = AdminAction::PauseModule { module: StewardModule::MME, reason };
+    let err = run(&mut admin, &mut governance, action, &ops, T0 + HOUR_MS + 7).expect_err("charter veto");
+    println!("vetoed: {err}");
+    assert!(status.lock().unwrap().status(StewardModule::MME).is_active());
+
+    // 6. Resume: issuance works again.
+    let resume = AdminAction::ResumeModule { module: StewardModule::PLGA, pause_id: pause };
+    run(&mut admin, &mut governance, resume, &ops, T0 + 2 * HOUR_MS).expect("names the pause");
+    issue(&mut ledger, T0 + 2 * HOUR_MS + 1).expect("PLGA active again");
+
+    // 7. A planned read-only window also blocks assignments and proposals scoped to the module.
+    let action = AdminAction::SetModuleReadOnly { module: StewardModule::MME, reason: "Template migration".into() };
+    let effect = run(&mut admin, &mut governance, action, &ops, T0 + 3 * HOUR_MS).expect("read-only passes SAEP");
+    let freeze = effect.pause_id().expect("a freeze").clone();
+    assert!(missions.assign_mission(&mission, ana.clone(), T0 + 3 * HOUR_MS + 1).is_err());
+    let proposal = GovernanceProposal {
+        proposal_id: "prop-mme-radius".into(),
+        scope: GovernanceScope::Module(ModuleId("MME".into())),
+        title: "Widen mission radius".into(),
+        description: "Offer missions up to 20 km away".into(),
+        payload: serde_json::json!({}),
+        can_introduce_restrictions: false,
+    };
+    let votes = QuadraticOutcome { proposal_id: proposal.proposal_id.clone(), total_support: 9.0, total_opposition: 1.0 };
+    assert!(governance.can_apply_proposal(&proposal, &votes).is_err());
+    assert_eq!(missions.active_assignments().len(), 1);
+    let resume = AdminAction::ResumeModule { module: StewardModule::MME, pause_id: freeze };
+    run(&mut admin, &mut governance, resume, &ops, T0 + 4 * HOUR_MS).expect("names the freeze");
+    assert_eq!(governance.can_apply_proposal(&proposal, &votes), Ok(true));
+
+    let trail = governance.audit_trail();
+    println!("{}", serde_json::to_string_pretty(trail).expect("audit json"));
+    assert_eq!(trail.len(), 5);
+    assert_eq!(trail.iter().filter(|e| matches!(e.outcome, GovernanceAuditOutcome::Vetoed { .. })).count(), 1);
+    // The admin log has the opt-out and all seven attempts, refused ones included.
+    assert_eq!(admin.log().len(), 8);
+}
@@ -0,0 +1,170 @@
+// path: planetary_stewardship_runtime/examples/notification_digests.rs
+
+//! Example: what two stewards are told, and when (run with `--features test-util`).
+//! - Ana mutes capability deprecations and takes a daily digest; Ben takes everything at once.
+//!   Preferences are only kept under consent to the notification prompt, and safety-critical
+//!   categories cannot be muted
+//! - A digest asked for twice at the same time is the same digest; notifications posted
+//!   before the next one is due are held
+//! - Ana's assignment is suspended when the MME consent behind it is withdrawn: that is
+//!   delivered at once, cannot be dismissed, and is repeated until acknowledged
+//! - A module pause reaches every subscriber as an emergency; collecting again posts nothing
+//! - A lapsed grant is notified, and a lapsed notification consent drops the preferences
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    notification_prompt, AdminAction, ConsentRecord, ConsentRegistry, DigestFrequency, Did, DualControl,
+    DualControlPolicy, GovernanceEngine, MicroMissionsEngine, MissionId, MissionTemplate, NewNotification,
+    NotificationCategory, NotificationCenter, NotificationPreferences, NotificationSeverity, SaepEngine,
+    StewardModule,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const HOUR_MS: u64 = 3_600_000;
+const DAY_MS: u64 = 24 * HOUR_MS;
+
+fn consent(who: &Did, module: StewardModule, mission: Option<&MissionId>, given: bool, at_ms: u64) -> ConsentRecord {
+    ConsentRecord {
+        participant: who.clone(),
+        module,
+        mission: mission.cloned(),
+        consent_given: given,
+        timestamp_ms: at_ms,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    }
+}
+
+/// A CSC grant to the notification prompt.
+fn notification_consent(who: &Did, at_ms: u64) -> ConsentRecord {
+    ConsentRecord {
+        prompt_hash: Some(notification_prompt().canonical_hash()),
+        ..consent(who, StewardModule::CSC, None, true, at_ms)
+    }
+}
+
+fn deprecation(who: &Did, version: u32) -> NewNotification {
+    let source = format!("ability:soil-probe:v{version}");
+    let title = format!("Soil probe v{version} retires");
+    NewNotification::new(who.clone(), NotificationCategory::CapabilityDeprecation, source, title)
+        .with_severity(NotificationSeverity::Warning)
+}
+
+fn main() -> Result<(), String> {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let ben = Did::new("did:psv:steward:ben").expect("valid DID");
+    let creek = MissionId::new("creek-cleanup").expect("valid mission id");
+    let config = saep_without_rollback_plans();
+
+    let mut center = NotificationCenter::new();
+    let registry = ConsentRegistry::new().with_observer("notifications", center.consent_observer());
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config.clone()), registry);
+    missions.add_template(MissionTemplate {
+        id: creek.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+    let mut governance = GovernanceEngine::new(SaepEngine::new(config));
+
+    // 1. Preferences, kept under consent to the notification prompt.
+    let quiet = NotificationPreferences {
+        muted: [NotificationCategory::CapabilityDeprecation].into(),
+        digest: DigestFrequency::Daily,
+    };
+    let unprompted = consent(&ana, StewardModule::CSC, None, true, T0);
+    assert!(center.set_preferences(unprompted, quiet.clone()).is_err(), "not the notification prompt");
+    let mut reckless = quiet.clone();
+    reckless.muted.insert(NotificationCategory::AssignmentSuspended);
+    assert!(center.set_preferences(notification_consent(&ana, T0), reckless).is_err(), "safety-critical");
+    let eager = NotificationPreferences { digest: DigestFrequency::Immediate, ..Default::default() };
+    for (who, preferences) in [(&ana, quiet), (&ben, eager)] {
+        missions.consent_mut().upsert_consent(notification_consent(who, T0));
+        center.set_preferences(notification_consent(who, T0), preferences)?;
+    }
+    assert_eq!(center.subscribers().count(), 2);
+
+    // 2. Muting: Ana's deprecation notice is dropped, Ben's is kept.
+    assert_eq!(center.post(deprecation(&ana, 1), T0 + 1)?, None);
+    assert!(center.post(deprecation(&ben, 1), T0 + 1)?.is_some());
+    assert_eq!(center.post(deprecation(&ben, 1), T0 + 2)?, None, "one notification per source");
+    let arena_turn = |n: u32, title: &str| {
+        let source = format!("arena:phoenix:turn:{n}");
+        NewNotification::new(ana.clone(), NotificationCategory::GovernanceTurn, source, title)
+    };
+    let turn = arena_turn(12, "Your turn")
+        .with_body("The Phoenix arena is waiting on your reply to the water-sharing proposal.");
+    let turn = center.post(turn, T0 + 3)?.expect("not muted");
+
+    // 3. Digests: the first is due at once; asked again at the same time, it is the same digest.
+    let first = center.digest_for(&ana, T0 + HOUR_MS);
+    assert_eq!(first.items.iter().map(|n| &n.id).collect::<Vec<_>>(), [&turn]);
+    assert_eq!(center.digest_for(&ana, T0 + HOUR_MS), first);
+    assert!(center.pending(&ana).is_empty(), "delivered and not safety-critical");
+    assert_eq!(center.digest_for(&ben, T0 + HOUR_MS).items.len(), 1);
+
+    // 4. Before Ana's next daily digest is due, a new turn is held.
+    center.post(arena_turn(13, "Your turn again"), T0 + 2 * HOUR_MS)?;
+    let held = center.digest_for(&ana, T0 + 3 * HOUR_MS);
+    assert_eq!((held.items.len(), held.held, held.next_due_ms), (0, 1, T0 + HOUR_MS + DAY_MS));
+
+    // 5. Withdrawing the consent behind Ana's assignment suspends it: delivered at once.
+    missions.consent_mut().upsert_consent(consent(&ana, StewardModule::MME, Some(&creek), true, T0));
+    missions.assign_mission(&creek, ana.clone(), T0 + 4 * HOUR_MS)?;
+    missions.consent_mut().upsert_consent(consent(&ana, StewardModule::MME, Some(&creek), false, T0 + 5 * HOUR_MS));
+    let suspended = center.collect_missions(&missions, T0 + 5 * HOUR_MS)?;
+    assert_eq!(suspended.len(), 1);
+    assert!(center.collect_missions(&missions, T0 + 6 * HOUR_MS)?.is_empty(), "collected once");
+    let err = center.acknowledge(&ana, &suspended[0], T0 + 5 * HOUR_MS).expect_err("not delivered yet");
+    println!("refused: {err}");
+    let urgent = center.digest_for(&ana, T0 + 6 * HOUR_MS);
+    let categories: Vec<_> = urgent.items.iter().map(|n| n.category).collect();
+    assert_eq!(categories, [NotificationCategory::AssignmentSuspended]);
+    assert_eq!(urgent.held, 1, "the turn still waits for the daily digest");
+    println!("{}", serde_json::to_string_pretty(&urgent).expect("digest json"));
+
+    // 6. It cannot be dismissed, and comes back until acknowledged.
+    let err = center.dismiss(&ana, &suspended[0]).expect_err("safety-critical");
+    println!("refused: {err}");
+    let daily = center.digest_for(&ana, T0 + HOUR_MS + DAY_MS);
+    assert_eq!((daily.items.len(), daily.outstanding.len()), (1, 1));
+    let ack = center.acknowledge(&ana, &suspended[0], T0 + HOUR_MS + DAY_MS + 1)?;
+    assert_eq!(ack.category, NotificationCategory::AssignmentSuspended);
+    assert!(center.digest_for(&ana, T0 + HOUR_MS + DAY_MS + 2).outstanding.is_empty());
+    assert!(center.pending(&ana).is_empty());
+
+    // 7. A module pause reaches every subscriber as an emergency, once.
+    let ops = Did::new("did:psv:steward:ops").expect("valid DID");
+    let now = T0 + 2 * DAY_MS;
+    let mut admin = DualControl::new(DualControlPolicy {
+        admins: [ops.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    admin.opt_out_single_admin(&ops, "One on-call operator".into(), now)?;
+    admin.bind(&governance)?;
+    let pause = AdminAction::PauseModule { module: StewardModule::PLGA, reason: "Evidence store compromised".into() };
+    admin.propose(&mut governance, pause, &ops, now)?;
+    let emergencies = center.collect_governance(&governance, now)?;
+    assert_eq!(emergencies.len(), 2);
+    assert!(center.collect_governance(&governance, now + 1)?.is_empty());
+    let ben_digest = center.digest_for(&ben, now + 1);
+    assert_eq!(ben_digest.items[0].category, NotificationCategory::GovernanceEmergency);
+    assert_eq!(ben_digest.items[0].severity, NotificationSeverity::Critical);
+
+    // 8. Lapsed grants are notified; Ana's lapsed notification consent drops the preferences.
+    let lapsed = missions.consent_mut().expire_grants(T0 + 1, now + 2);
+    assert_eq!(lapsed.len(), 2, "both notification consents");
+    let expiries = center.collect_consent(now + 2)?;
+    assert_eq!(expiries.len(), 2);
+    assert_eq!(center.subscribers().count(), 0);
+    assert_eq!(center.preferences(&ana), NotificationPreferences::default());
+    assert!(center.post(deprecation(&ana, 2), now + 3)?.is_some(), "no longer muted");
+    Ok(())
+}
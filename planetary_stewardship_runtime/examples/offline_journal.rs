@@ -0,0 +1,211 @@
+// path: planetary_stewardship_runtime/examples/offline_journal.rs
+
+//! Example: two field devices syncing overlapping journals after a day offline (run with `--features test-util`).
+//! - The phone queued consents, an assignment, an attestation and the mission's completion;
+//!   its clock is set a day and a half ahead
+//! - The crew tablet picked up the phone's first entries over local sync and added its own
+//! - Syncing both through the ledger and the mission engine creates nothing twice, gives every
+//!   entry a result, and rejects a bad entry or a reused key without stopping the rest
+//! - Under `StopOnRejection`, entries after a rejection are not attempted; a rejected key can
+//!   be retried once fixed
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AssignmentRef, AssignmentStatus, AttestationRequest, ConsentRecord, ConsentRegistry, Did, EvidenceArtifact,
+    ImpactMetrics, JournalEffect, JournalOp, JournalOutcome, JournalReport, MicroMissionsEngine, MissionId,
+    MissionTemplate, ModuleMetrics, MutationJournal, PlanetaryLedger, ReplayPolicy, SaepEngine, SaepRefs,
+    StewardModule,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const HOUR_MS: u64 = 3_600_000;
+const SKEW_MS: u64 = 36 * HOUR_MS;
+/// Server time at the first sync.
+const NOW: u64 = T0 + 26 * HOUR_MS;
+const WEEK_MS: u64 = 7 * 24 * HOUR_MS;
+
+fn did(s: &str) -> Did {
+    Did::new(s).expect("valid DID")
+}
+
+fn consent(who: &Did, module: StewardModule, mission: Option<&MissionId>, at: u64) -> JournalOp {
+    JournalOp::SubmitConsent {
+        record: ConsentRecord {
+            participant: who.clone(),
+            module,
+            mission: mission.cloned(),
+            consent_given: true,
+            timestamp_ms: at,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        },
+    }
+}
+
+fn attestation(actor: &Did, what: &str, area: f64) -> JournalOp {
+    JournalOp::IssueAttestation {
+        request: Box::new(AttestationRequest {
+            actor_did: actor.clone(),
+            mission_id: None,
+            description: what.into(),
+            module: StewardModule::PLGA,
+            impact_metrics: ImpactMetrics { restored_area_m2: area, ..ImpactMetrics::default() },
+            module_metrics: ModuleMetrics::new(),
+            evidence: vec![EvidenceArtifact::new(format!("ipfs://{area}"), "image/jpeg")
+                .with_content_hash(format!("sha256:{area}"))],
+            verifier_dids: vec![],
+            timestamp_ms: T0,
+            refs: SaepRefs::default(),
+            capture_window: None,
+            witness_endorsement: None,
+        }),
+    }
+}
+
+/// Outcomes by entry key, with the other engine's entries left out.
+fn outcomes(report: &JournalReport) -> Vec<(&str, &JournalOutcome)> {
+    report
+        .results
+        .iter()
+        .filter(|r| !matches!(r.outcome, JournalOutcome::OtherModule { .. }))
+        .map(|r| (r.key.as_str(), &r.outcome))
+        .collect()
+}
+
+fn main() {
+    let (ana, kofi, lee) = (did("did:psv:steward:ana"), did("did:psv:steward:kofi"), did("did:psv:steward:lee"));
+    let creek = MissionId::new("creek-cleanup").expect("valid mission id");
+    let config = saep_without_rollback_plans();
+    let clock = Arc::new(FixedClock::new(NOW));
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        ConsentRegistry::new(),
+        clock.clone(),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_idempotency_ttl(WEEK_MS);
+    let mut missions =
+        MicroMissionsEngine::new(SaepEngine::new(config), ConsentRegistry::new()).with_idempotency_ttl(WEEK_MS);
+    missions.add_template(MissionTemplate {
+        id: creek.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+
+    // 1. Queued offline, by the devices' own clocks.
+    let phone_at = |hours| T0 + hours * HOUR_MS + SKEW_MS;
+    let mut phone = MutationJournal::new("phone-ana");
+    let assign = JournalOp::AssignMission { mission: creek.clone(), assignee: ana.clone(), refs: SaepRefs::default() };
+    let shared = [
+        ("ana-1", phone_at(1), consent(&ana, StewardModule::PLGA, None, phone_at(1))),
+        ("ana-2", phone_at(1), consent(&ana, StewardModule::MME, Some(&creek), phone_at(1))),
+        ("ana-3", phone_at(2), assign),
+    ];
+    for (key, at, op) in shared.clone() {
+        phone.append(key, at, op).expect("new key");
+    }
+    phone.append("ana-4", phone_at(5), attestation(&ana, "Creek litter removal", 120.0)).expect("new key");
+    let done = JournalOp::CloseAssignment {
+        assignment: AssignmentRef::Journaled("ana-3".into()),
+        status: AssignmentStatus::Completed,
+    };
+    phone.append("ana-5", phone_at(5), done).expect("new key");
+    assert!(phone.append("ana-5", phone_at(6), attestation(&ana, "again", 1.0)).is_err(), "keys are unique");
+
+    let mut tablet = MutationJournal::new("tablet-crew");
+    for (key, at, op) in shared {
+        tablet.append(key, at, op).expect("new key");
+    }
+    tablet.append("crew-1", T0 + 3 * HOUR_MS, consent(&kofi, StewardModule::PLGA, None, T0)).expect("new key");
+    tablet.append("crew-2", T0 + 4 * HOUR_MS, attestation(&kofi, "Bank replanting", 80.0)).expect("new key");
+    tablet.append("crew-3", T0 + 4 * HOUR_MS, attestation(&lee, "Bank replanting", 40.0)).expect("new key");
+    // The tablet's key generator collided with the phone's.
+    tablet.append("ana-4", T0 + 4 * HOUR_MS, attestation(&kofi, "Reed planting", 15.0)).expect("new to this journal");
+
+    // 2. Journals are stored on the device as JSON until the device is back online.
+    let stored = serde_json::to_string(&tablet).expect("journal json");
+    let tablet: MutationJournal = serde_json::from_str(&stored).expect("journal loads");
+
+    // 3. Sync the phone, then the tablet, through both engines.
+    let phone_plga = ledger.apply_journal(&phone, ReplayPolicy::default());
+    let phone_mme = missions.apply_journal(&phone, ReplayPolicy::default(), NOW);
+    clock.advance(HOUR_MS);
+    let tablet_plga = ledger.apply_journal(&tablet, ReplayPolicy::default());
+    let tablet_mme = missions.apply_journal(&tablet, ReplayPolicy::default(), NOW + HOUR_MS);
+
+    for (journal, reports) in [(&phone, [&phone_plga, &phone_mme]), (&tablet, [&tablet_plga, &tablet_mme])] {
+        for report in reports {
+            assert_eq!(report.results.len(), journal.len(), "one result per entry");
+        }
+        let handled = outcomes(reports[0]).len() + outcomes(reports[1]).len();
+        assert_eq!(handled, journal.len(), "each entry is handled by exactly one engine");
+    }
+    assert_eq!(phone_plga.applied().count() + phone_mme.applied().count(), phone.len());
+    let ana_5 = phone_mme.results.iter().find(|r| r.key == "ana-5").expect("reported");
+    assert!(matches!(&ana_5.outcome, JournalOutcome::Applied { effect: JournalEffect::Closed { .. } }));
+
+    // 4. The overlap is skipped; the bad entry and the reused key are rejected, the rest applied.
+    let tablet_all: Vec<_> = outcomes(&tablet_plga).into_iter().chain(outcomes(&tablet_mme)).collect();
+    let skipped: Vec<&str> =
+        tablet_all.iter().filter(|(_, o)| matches!(o, JournalOutcome::Skipped { .. })).map(|(k, _)| *k).collect();
+    assert_eq!(skipped, ["ana-1", "ana-2", "ana-3"]);
+    let rejected: Vec<(&str, &Vec<String>)> = tablet_all
+        .iter()
+        .filter_map(|(k, o)| match o {
+            JournalOutcome::Rejected { reasons } => Some((*k, reasons)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(rejected.len(), 2);
+    assert_eq!(rejected[0].0, "crew-3");
+    assert_eq!(rejected[0].1, &["No valid KSCP consent for PLGA attestation"], "lee never consented");
+    assert_eq!(rejected[1].0, "ana-4");
+    assert_eq!(rejected[1].1, &["Idempotency key ana-4 was already used for a different request"]);
+    for (key, reasons) in &rejected {
+        println!("{key} rejected: {reasons:?}");
+    }
+
+    // 5. Nothing exists twice.
+    assert_eq!(ledger.attestations().count(), 2, "ana's once, kofi's once");
+    assert_eq!(ledger.impact_summary(Some(&ana)).totals.restored_area_m2, 120.0);
+    assert_eq!(missions.active_assignments().len(), 0);
+    assert_eq!(missions.assignment_archive().len(), 1);
+
+    // 6. Client clocks are reported, not trusted: the phone's future-dated consent is stamped
+    //    at the server's apply time.
+    let ana_1 = &phone_plga.results[0];
+    assert_eq!((ana_1.client_ts_ms, ana_1.applied_ms), (phone_at(1), Some(NOW)));
+    assert!(ana_1.client_ts_ms > NOW, "the phone is ahead");
+    let recorded = ledger.consent().records().find(|r| r.participant == ana).expect("recorded");
+    assert_eq!(recorded.timestamp_ms, NOW);
+
+    // 7. Dependent entries: stop at the first rejection; the rejected key stays free.
+    let mut retry = MutationJournal::new("tablet-crew");
+    retry.append("crew-3", T0 + 5 * HOUR_MS, attestation(&lee, "Bank replanting", 40.0)).expect("new key");
+    retry.append("crew-4", T0 + 5 * HOUR_MS, attestation(&lee, "Bank mulching", 10.0)).expect("new key");
+    let stopped = ledger.apply_journal(&retry, ReplayPolicy::StopOnRejection);
+    assert!(matches!(stopped.results[1].outcome, JournalOutcome::NotAttempted));
+    assert_eq!(stopped.results[1].applied_ms, None);
+    let mut fixed = MutationJournal::new("tablet-crew");
+    let lee_consent = consent(&lee, StewardModule::PLGA, None, T0 + 5 * HOUR_MS);
+    fixed.append("crew-0", T0 + 5 * HOUR_MS, lee_consent).expect("new key");
+    for entry in retry.entries() {
+        fixed.append(entry.key.clone(), entry.client_ts_ms, entry.op.clone()).expect("new key");
+    }
+    let report = ledger.apply_journal(&fixed, ReplayPolicy::StopOnRejection);
+    assert_eq!(report.applied().count(), 3);
+    assert_eq!(ledger.attestations().count(), 4);
+
+    println!("{}", serde_json::to_string_pretty(&tablet_plga).expect("report json"));
+}
@@ -0,0 +1,66 @@
+// path: planetary_stewardship_runtime/examples/proposal_limits.rs
+
+//! Example: proposal payloads right at and just over each `ProposalLimits` bound (run with `--features test-util`).
+//! - At the limit a proposal goes on to the usual SAEP and charter checks; one over is
+//!   refused first, naming the limit and the measured value
+//! - Operations are checked before bytes, and bytes before depth
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    limits, GovernanceEngine, GovernanceProposal, GovernanceScope, ModuleId, ProposalLimit, ProposalLimitExceeded,
+    ProposalLimits, QuadraticOutcome, SaepEngine,
+};
+use serde_json::json;
+
+fn proposal(payload: serde_json::Value) -> GovernanceProposal {
+    GovernanceProposal {
+        proposal_id: "prop-limits".into(),
+        scope: GovernanceScope::Module(ModuleId("PLGA".into())),
+        title: "Publish quarterly restoration map".into(),
+        description: "Open data release for the watershed".into(),
+        payload,
+        can_introduce_restrictions: false,
+    }
+}
+
+fn main() {
+    let config = saep_without_rollback_plans();
+    let limits = ProposalLimits { max_payload_bytes: 64, max_payload_depth: 3, max_patch_operations: 4 };
+    let governance = GovernanceEngine::new(SaepEngine::new(config)).with_proposal_limits(limits);
+    let passed = QuadraticOutcome { proposal_id: "prop-limits".into(), total_support: 9.0, total_opposition: 2.0 };
+
+    // `{"note":"…"}` is 11 bytes plus the note.
+    let at_bytes = json!({ "note": "x".repeat(53) });
+    let over_bytes = json!({ "note": "x".repeat(54) });
+    let at_depth = json!({ "a": { "b": {} } });
+    let over_depth = json!({ "a": { "b": { "c": {} } } });
+    let at_ops = json!([{}, {}, {}, {}]);
+    let over_ops = json!([{}, {}, {}, {}, {}]);
+    assert_eq!((limits::payload_bytes(&at_bytes), limits::payload_bytes(&over_bytes)), (64, 65));
+    assert_eq!((limits::payload_depth(&at_depth), limits::payload_depth(&over_depth)), (3, 4));
+
+    for at_limit in [at_bytes, at_depth, at_ops] {
+        let proposal = proposal(at_limit);
+        assert_eq!(governance.check_proposal_limits(&proposal), Ok(()));
+        assert_eq!(governance.can_apply_proposal(&proposal, &passed), Ok(true));
+    }
+
+    let cases = [
+        (over_bytes, ProposalLimit::PayloadBytes, 65, 64),
+        (over_depth, ProposalLimit::PayloadDepth, 4, 3),
+        (over_ops, ProposalLimit::PatchOperations, 5, 4),
+    ];
+    for (payload, limit, measured, max) in cases {
+        let proposal = proposal(payload);
+        let exceeded = ProposalLimitExceeded { limit, measured, max };
+        assert_eq!(governance.check_proposal_limits(&proposal), Err(exceeded.clone()));
+        assert_eq!(governance.can_apply_proposal(&proposal, &passed), Err(exceeded.to_string()));
+        println!("{exceeded}");
+    }
+
+    // Order: too many operations is reported even when the payload is also too big.
+    let huge_patch = json!((0..5).map(|i| json!({ "op": "add", "path": format!("/tags/{i}"), "value": "x" })).collect::<Vec<_>>());
+    let first = governance.check_proposal_limits(&proposal(huge_patch)).expect_err("over two limits");
+    assert_eq!(first.limit, ProposalLimit::PatchOperations);
+    assert_eq!(ProposalLimits::default().check(&json!({})), Ok(()));
+}
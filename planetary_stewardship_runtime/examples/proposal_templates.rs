@@ -0,0 +1,100 @@
+// path: planetary_stewardship_runtime/examples/proposal_templates.rs
+
+//! Example: routine SAEP changes proposed from templates (run with `--features test-util`).
+//! - Bad parameters (unknown flag, missing field, negative quorum) and no-op changes are refused
+//!   at instantiation, not after the vote
+//! - An applied template proposal changes governance's SAEP settings and quorums
+//! - A hand-written proposal with the same payload is applied the same way; a malformed one is refused
+//! - Editing a template proposal's payload after instantiation is caught at approval
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    ConfigChange, Did, EthicsContext, GovernanceEngine, GovernanceProposal, GovernanceScope, ProposalTemplate,
+    QuadraticOutcome, SaepEngine, SaepFlag, StewardModule,
+};
+use serde_json::json;
+
+const T0: u64 = 1_767_225_600_000;
+
+fn votes(proposal: &GovernanceProposal, support: f64, opposition: f64) -> QuadraticOutcome {
+    QuadraticOutcome { proposal_id: proposal.proposal_id.clone(), total_support: support, total_opposition: opposition }
+}
+
+fn main() {
+    let config = saep_without_rollback_plans();
+    let mut governance = GovernanceEngine::new(SaepEngine::new(config));
+    let ids: Vec<&str> = ProposalTemplate::ALL.iter().map(|t| t.id()).collect();
+    assert_eq!(ids, ["toggle_enforcement_flag", "set_module_override", "update_quorum"]);
+
+    // 1. Invalid parameters never reach a vote.
+    let refused = [
+        ("toggle_enforcement_flag", json!({ "flag": "enforce_kindness", "value": true })),
+        ("set_module_override", json!({ "module": "MME", "flag": "enforce_reversibility" })),
+        ("update_quorum", json!({ "scope": "EcosystemWide", "new_quorum": -5.0 })),
+        ("update_quorum", json!({ "scope": "EcosystemWide", "new_quorum": 40.0, "note": "hi" })),
+        ("toggle_enforcement_flag", json!({ "flag": "enforce_reversibility", "value": false })),
+        ("rename_module", json!({})),
+    ];
+    for (template, params) in refused {
+        let err = governance.instantiate_template(template, &params).expect_err("refused");
+        println!("refused {template}: {err}");
+    }
+
+    // 2. Turn off informed-consent prompts for CSC simulations only.
+    let proposal = governance
+        .instantiate_template("set_module_override", &json!({ "module": "CSC", "flag": "enforce_informed_consent", "value": false }))
+        .expect("valid parameters");
+    println!("{}", serde_json::to_string_pretty(&proposal).expect("proposal json"));
+    assert_eq!(proposal.title, "Disable SAEP enforce_informed_consent for CSC");
+    assert!(governance.template_provenance(&proposal.proposal_id).is_some());
+    governance.apply_proposal(proposal.clone(), votes(&proposal, 9.0, 2.0), T0).expect("passes");
+    let effective = governance.saep_config().for_module(StewardModule::CSC);
+    assert!(!effective.enforce_informed_consent && governance.saep_config().enforce_informed_consent);
+    let ctx = |module| EthicsContext {
+        actor: Did::new("did:psv:steward:ana").expect("valid DID"),
+        affected_parties: vec![],
+        module,
+        description: "Run flood simulation".into(),
+        estimated_impact: json!({}),
+    };
+    let saep = SaepEngine::new(governance.saep_config().clone());
+    assert!(!saep.evaluate(&ctx(StewardModule::CSC)).require_consent);
+    assert!(saep.evaluate(&ctx(StewardModule::PLGA)).require_consent);
+
+    // 3. Quorum: ecosystem-wide proposals now need 40 vote weight in total.
+    let quorum = governance
+        .instantiate_template("update_quorum", &json!({ "scope": "EcosystemWide", "new_quorum": 40.0 }))
+        .expect("valid parameters");
+    governance.apply_proposal(quorum.clone(), votes(&quorum, 30.0, 5.0), T0 + 1).expect("no quorum yet");
+    assert_eq!(governance.quorum(&GovernanceScope::EcosystemWide), 40.0);
+
+    // 4. The same change written by hand goes through the same checks and application.
+    let raw = GovernanceProposal {
+        proposal_id: "prop-transparency-off".into(),
+        scope: GovernanceScope::EcosystemWide,
+        title: "Disable public intent logging".into(),
+        description: "Intent log moves to the new registry".into(),
+        payload: ConfigChange::ToggleEnforcementFlag { flag: SaepFlag::EnforceTransparency, value: false }.to_payload(),
+        can_introduce_restrictions: false,
+    };
+    assert_eq!(governance.can_apply_proposal(&raw, &votes(&raw, 30.0, 5.0)), Ok(false), "below quorum");
+    governance.apply_proposal(raw.clone(), votes(&raw, 35.0, 10.0), T0 + 2).expect("quorum met");
+    assert!(!governance.saep_config().enforce_transparency);
+    let malformed = GovernanceProposal {
+        proposal_id: "prop-malformed".into(),
+        payload: json!({ "change": "ToggleEnforcementFlag", "flag": "enforce_non_harm", "value": "no" }),
+        ..raw.clone()
+    };
+    let err = governance.can_apply_proposal(&malformed, &votes(&malformed, 35.0, 10.0)).expect_err("malformed");
+    println!("refused: {err}");
+
+    // 5. A template proposal edited between instantiation and approval is refused.
+    let mut edited = governance
+        .instantiate_template("toggle_enforcement_flag", &json!({ "flag": "enforce_commons_benefit", "value": false }))
+        .expect("valid parameters");
+    edited.payload["flag"] = json!("enforce_non_harm");
+    let err = governance.apply_proposal(edited.clone(), votes(&edited, 40.0, 1.0), T0 + 3).expect_err("edited payload");
+    println!("refused: {err}");
+    assert!(err.contains("edited after instantiation"));
+    assert!(governance.saep_config().enforce_non_harm);
+}
@@ -0,0 +1,112 @@
+// path: planetary_stewardship_runtime/examples/read_views.rs
+
+//! Example: dashboards reading point-in-time views while a writer keeps issuing
+//! (run with `--features test-util`).
+//! - A view answers as of when it was taken: later writes change neither its records, its
+//!   summary nor its head
+//! - Four reader threads take views while one writer issues 3,000 attestations; the read lock
+//!   is held only to take a view, and every query runs after it is released
+//! - No view is torn: its records, summary and head generation always agree, and each reader
+//!   sees generations only advance
+//! - The missions engine's view does the same for templates, search and open assignments
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use planetary_stewardship_runtime::builders::{
+    did, mission_id, saep_without_rollback_plans, AttestationBuilder, ConsentRecordBuilder, LedgerBuilder,
+    MissionTemplateBuilder,
+};
+use planetary_stewardship_runtime::{
+    ConsentRegistry, MicroMissionsEngine, SaepEngine, StewardModule, TemplateQuery, VIEW_GENESIS_HASH,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const WRITES: u64 = 3_000;
+const READERS: usize = 4;
+
+fn main() {
+    let (ana, ben) = (did("did:psv:steward:ana"), did("did:psv:steward:ben"));
+    let config = saep_without_rollback_plans();
+    let mut ledger = LedgerBuilder::new().seed(3).saep(config.clone()).consenting(&ana).consenting(&ben).build();
+    let claim = |actor, i: u64| {
+        AttestationBuilder::new(actor).description(&format!("Street tree watering, round {i}")).timestamp_ms(T0 + i)
+    };
+
+    // 1. Point in time: a view keeps what it saw.
+    let empty = ledger.read_view();
+    assert_eq!((empty.head().generation, empty.head().head_hash.as_str()), (0, VIEW_GENESIS_HASH));
+    claim(&ana, 0).co2eq_reduced(1.0).issue_on(&mut ledger).expect("issued");
+    let one = ledger.read_view();
+    claim(&ben, 1).co2eq_reduced(1.0).issue_on(&mut ledger).expect("issued");
+    assert_eq!((empty.attestations().count(), one.attestations().count()), (0, 1));
+    assert_eq!(one.impact_summary(None).attestations, 1);
+    assert!(one.get_attestations_for_actor(&ben).is_empty());
+    assert_eq!(one.head().generation, 1);
+    let two = ledger.read_view();
+    assert_eq!(two.head(), ledger.view_head());
+    assert_ne!(two.head().head_hash, one.head().head_hash);
+    let json = |summary| serde_json::to_value(summary).expect("summary json");
+    assert_eq!(json(two.impact_summary(Some(&ben))), json(ledger.impact_summary(Some(&ben))));
+
+    // 2. One writer, four readers. Every claim is 1 t, so a view's total is its record count.
+    let ledger = Arc::new(RwLock::new(ledger));
+    let done = Arc::new(AtomicBool::new(false));
+    let readers: Vec<_> = (0..READERS)
+        .map(|_| {
+            let (ledger, done) = (ledger.clone(), done.clone());
+            thread::spawn(move || {
+                let (mut last, mut views) = (0, 0u64);
+                loop {
+                    let finished = done.load(Ordering::Acquire);
+                    let view = ledger.read().expect("ledger lock").read_view();
+                    let generation = view.head().generation;
+                    assert!(generation >= last, "generation went back from {last} to {generation}");
+                    let records: Vec<_> = view.attestations().collect();
+                    assert_eq!(records.len() as u64, generation, "one record per change");
+                    let summary = view.impact_summary(None);
+                    assert_eq!(summary.attestations, records.len());
+                    assert_eq!(summary.totals.co2eq_reduced, records.len() as f64);
+                    if let Some(latest) = records.last() {
+                        assert_eq!(view.get_attestation(&latest.id).map(|a| &a.id), Some(&latest.id));
+                    }
+                    (last, views) = (generation, views + 1);
+                    if finished {
+                        return (last, views);
+                    }
+                }
+            })
+        })
+        .collect();
+    for i in 2..WRITES {
+        let actor = if i % 2 == 0 { &ana } else { &ben };
+        let mut ledger = ledger.write().expect("ledger lock");
+        claim(actor, i).co2eq_reduced(1.0).issue_on(&mut ledger).expect("issued");
+    }
+    done.store(true, Ordering::Release);
+    for reader in readers {
+        let (last, views) = reader.join().expect("no reader panicked");
+        assert_eq!(last, WRITES, "the final view sees every write");
+        println!("reader took {views} views");
+    }
+    let ledger = ledger.read().expect("ledger lock");
+    assert_eq!(json(ledger.read_view().impact_summary(None)), json(ledger.impact_summary(None)));
+
+    // 3. Missions: templates, search and open assignments as of the view.
+    let creek = mission_id("creek-cleanup");
+    let mut consent = ConsentRegistry::new();
+    consent.upsert_consent(ConsentRecordBuilder::new(&ana, StewardModule::MME).mission(&creek).at_ms(T0).build());
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), consent);
+    missions.add_template(MissionTemplateBuilder::new("creek-cleanup").build_valid());
+    let before = missions.read_view();
+    let assigned = missions.assign_mission(&creek, ana.clone(), T0).expect("assigned");
+    missions.add_template(MissionTemplateBuilder::new("creek-survey").build_valid());
+    let after = missions.read_view();
+    assert_eq!((before.head().generation, after.head().generation), (1, 3));
+    assert_eq!(before.active_assignments().len(), 0);
+    assert_eq!(after.active_assignment(&assigned.key()).map(|a| &a.assignee), Some(&ana));
+    let query = TemplateQuery::text("creek");
+    assert_eq!((before.search_templates(&query).len(), after.search_templates(&query).len()), (1, 2));
+    assert_eq!(after.search_templates(&query), missions.search_templates(&query));
+}
@@ -0,0 +1,188 @@
+// path: planetary_stewardship_runtime/examples/retention_sweep.rs
+
+//! Example: redacting a participant's records after they withdraw consent (run with `--features test-util`).
+//! - The ledger pseudonymizes: ana's attestations move to a stable pseudonym, her personal
+//!   photo is dropped, and impact totals stay the same (overall and per pseudonym)
+//! - MME tombstones: kofi's assignment keeps its mission and time, not his DID
+//! - Each redaction is hash-chained and covers what was removed; the redaction log and the
+//!   public intent log both still verify, and a tampered redaction log does not
+//! - Running a retention policy is an admin action: the data-protection officer proposes it
+//!   and operations confirms, on the engine it was proposed on. A second controller, even in
+//!   single-admin mode, cannot run it on engines bound to the first
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::retention::content_hash;
+use planetary_stewardship_runtime::{
+    AdminAction, AdminEffect, AdminProposal, AdminTarget, ConsentRecord, ConsentRegistry, Did, DualControl,
+    DualControlPolicy, EvidenceArtifact, ImpactMetrics, MicroMissionsEngine, MissionId,
+    MissionTemplate, PlanetaryLedger, RedactionLog, RedactionMode, RetentionPolicy, SaepEngine,
+    StewardModule, TOMBSTONE_DID,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn consent(who: &Did, module: StewardModule, mission: Option<MissionId>, consent_given: bool, at_ms: u64) -> ConsentRecord {
+    ConsentRecord {
+        participant: who.clone(),
+        module,
+        mission,
+        consent_given,
+        timestamp_ms: at_ms,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    }
+}
+
+fn metrics(co2eq_reduced: f64, restored_area_m2: f64) -> ImpactMetrics {
+    ImpactMetrics { co2eq_reduced, restored_area_m2, ..ImpactMetrics::default() }
+}
+
+/// Retention for `module` on `target`, proposed by `dpo` and confirmed by `ops`.
+fn execute_retention(
+    admin: &mut DualControl,
+    target: &mut dyn AdminTarget,
+    module: StewardModule,
+    (dpo, ops): (&Did, &Did),
+    at_ms: u64,
+) -> Result<AdminEffect, String> {
+    let AdminProposal::Pending { action_id, .. } =
+        admin.propose(target, AdminAction::ExecuteRetention { module }, dpo, at_ms)?
+    else {
+        return Err("retention runs under dual control".into());
+    };
+    admin.confirm(target, &action_id, ops, at_ms + 1)
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let kofi = Did::new("did:psv:steward:kofi").expect("valid DID");
+    let config = saep_without_rollback_plans();
+    let salt = "phx-2026-retention";
+    let dpo = Did::new("did:psv:steward:dpo").expect("valid DID");
+    let ops = Did::new("did:psv:steward:ops").expect("valid DID");
+    let mut admin = DualControl::new(DualControlPolicy {
+        admins: [dpo.clone(), ops.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+
+    let mut registry = ConsentRegistry::new();
+    for who in [&ana, &kofi] {
+        registry.upsert_consent(consent(who, StewardModule::PLGA, None, true, T0));
+    }
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        registry,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_retention_policy(RetentionPolicy::Pseudonymize { salt: salt.into() });
+    assert_eq!(admin.bind(&ledger).as_deref(), Ok("PlanetaryLedger 1"));
+
+    let selfie = EvidenceArtifact::new("ipfs://ana-at-the-creek", "image/jpeg").personal();
+    let site = EvidenceArtifact::new("ipfs://creek-before-after", "image/jpeg");
+    let first = ledger
+        .issue_attestation(ana.clone(), None, "Creek cleanup".into(), metrics(0.4, 30.0), vec![selfie.clone(), site.clone()], vec![], T0)
+        .expect("consented");
+    ledger
+        .issue_attestation(ana.clone(), None, "Planted willows".into(), metrics(0.9, 12.5), "ipfs://willows", vec![kofi.clone()], T0 + DAY_MS)
+        .expect("consented");
+    ledger
+        .issue_attestation(kofi.clone(), None, "Seed library".into(), metrics(0.2, 0.0), "ipfs://seeds", vec![], T0 + DAY_MS)
+        .expect("consented");
+    let overall = ledger.impact_summary(None);
+    let ana_before = ledger.impact_summary(Some(&ana));
+
+    // 1. Ana withdraws; the sweep picks the withdrawal up from the consent registry.
+    ledger.consent_mut().upsert_consent(consent(&ana, StewardModule::PLGA, None, false, T0 + 2 * DAY_MS));
+    let plga = StewardModule::PLGA;
+    let effect = execute_retention(&mut admin, &mut ledger, plga, (&dpo, &ops), T0 + 2 * DAY_MS + 1);
+    assert_eq!(effect, Ok(AdminEffect::RetentionExecuted { redactions: 2 }), "queued withdrawal is ana's");
+    let redactions = ledger.redaction_log().entries().to_vec();
+    assert!(redactions.iter().all(|r| r.mode == RedactionMode::Pseudonymized));
+    let effect = execute_retention(&mut admin, &mut ledger, plga, (&dpo, &ops), T0 + 2 * DAY_MS + 3);
+    assert_eq!(effect, Ok(AdminEffect::RetentionExecuted { redactions: 0 }), "queue drained");
+    println!("{}", serde_json::to_string_pretty(ledger.redaction_log()).expect("redaction log json"));
+
+    // 2. Nothing names ana any more; totals are unchanged, and the pseudonym carries hers.
+    let pseudonym = RetentionPolicy::pseudonym(salt, &ana);
+    assert!(ledger.get_attestations_for_actor(&ana).is_empty());
+    assert_eq!(ledger.get_attestations_for_actor(&pseudonym).len(), 2);
+    let after = ledger.impact_summary(None);
+    assert_eq!((after.attestations, after.totals.co2eq_reduced), (overall.attestations, overall.totals.co2eq_reduced));
+    let ana_after = ledger.impact_summary(Some(&pseudonym));
+    assert_eq!(
+        (ana_after.attestations, ana_after.totals.co2eq_reduced, ana_after.totals.restored_area_m2),
+        (ana_before.attestations, ana_before.totals.co2eq_reduced, ana_before.totals.restored_area_m2)
+    );
+    let redacted = ledger.get_attestation(&first.id).expect("still on the ledger");
+    assert_eq!(redacted.evidence, vec![site]);
+    assert_eq!(redacted.description, "Creek cleanup");
+
+    // 3. The redaction covers exactly what was removed, and everything still verifies.
+    let record = &redactions[0];
+    assert_eq!(record.original_hash, content_hash(&first));
+    assert_eq!(record.removed_content_hash, content_hash(&serde_json::json!({ "actor_did": ana, "evidence": [selfie] })));
+    assert!(!serde_json::to_string(ledger.redaction_log()).expect("log json").contains(ana.as_str()));
+    ledger.verify_redactions().expect("redaction chain and redacted records verify");
+    ledger.intent_log().lock().unwrap().verify().expect("intent log untouched");
+
+    let mut tampered = serde_json::to_value(ledger.redaction_log()).expect("log json");
+    tampered["entries"][0]["removed_content_hash"] = "0".repeat(64).into();
+    let tampered: RedactionLog = serde_json::from_value(tampered).expect("log json");
+    let err = tampered.verify(|_, _| true).expect_err("edited redaction");
+    println!("tampered: {err}");
+    assert_eq!(err.index, 0);
+
+    // 4. Only withdrawals are swept, and only through `admin`: kofi, still consenting, keeps his
+    //    record, and a single-admin controller of the DPO's own is refused by the ledger.
+    let effect = execute_retention(&mut admin, &mut ledger, plga, (&dpo, &ops), T0 + 3 * DAY_MS);
+    assert_eq!(effect, Ok(AdminEffect::RetentionExecuted { redactions: 0 }));
+    assert_eq!(ledger.get_attestations_for_actor(&kofi).len(), 1);
+    let mut rogue = DualControl::new(DualControlPolicy {
+        admins: [dpo.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    rogue.opt_out_single_admin(&dpo, "No time for a second admin".into(), T0 + 3 * DAY_MS).expect("dpo is its admin");
+    assert_eq!(rogue.bind(&ledger), Err("PlanetaryLedger is already bound to another controller".into()));
+    let sweep = AdminAction::ExecuteRetention { module: plga };
+    assert!(rogue.propose(&mut ledger, sweep, &dpo, T0 + 3 * DAY_MS).is_err());
+    assert_eq!(ledger.redaction_log().entries().len(), 2);
+
+    // 5. MME tombstones kofi's assignment on withdrawal.
+    let creek = MissionId::new("creek-cleanup").expect("valid mission id");
+    let mut registry = ConsentRegistry::new();
+    registry.upsert_consent(consent(&kofi, StewardModule::MME, Some(creek.clone()), true, T0));
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), registry)
+        .with_retention_policy(RetentionPolicy::Tombstone);
+    missions.add_template(MissionTemplate {
+        id: creek.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+    missions.assign_mission(&creek, kofi.clone(), T0 + DAY_MS).expect("consented");
+    missions.consent_mut().upsert_consent(consent(&kofi, StewardModule::MME, Some(creek.clone()), false, T0 + 2 * DAY_MS));
+    assert_eq!(admin.bind(&missions).as_deref(), Ok("MicroMissionsEngine 2"));
+    let sweep = AdminAction::ExecuteRetention { module: StewardModule::MME };
+    let Ok(AdminProposal::Pending { action_id, .. }) = admin.propose(&mut missions, sweep, &dpo, T0 + 3 * DAY_MS) else {
+        panic!("retention runs under dual control");
+    };
+    let err = admin.confirm(&mut ledger, &action_id, &ops, T0 + 3 * DAY_MS + 1).expect_err("not the ledger's");
+    assert_eq!(err, format!("{action_id} was proposed on MicroMissionsEngine 2, not PlanetaryLedger 1"));
+    let effect = admin.confirm(&mut missions, &action_id, &ops, T0 + 3 * DAY_MS + 2);
+    assert_eq!(effect, Ok(AdminEffect::RetentionExecuted { redactions: 1 }), "kofi's withdrawal");
+    let assignment = missions.active_assignments().next().expect("kept, redacted");
+    assert_eq!((assignment.assignee.as_str(), &assignment.mission.id), (TOMBSTONE_DID, &creek));
+    missions.verify_redactions().expect("redacted assignment verifies");
+}
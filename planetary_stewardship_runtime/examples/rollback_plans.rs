@@ -0,0 +1,185 @@
+// path: planetary_stewardship_runtime/examples/rollback_plans.rs
+
+//! Example: the rollback plan registry behind SAEP's reversibility rule.
+//! - Under `enforce_reversibility`, attestations and assignments need a registered plan
+//! - Plans only register against actions that exist; attestation ids are reserved first
+//! - Ledger and missions share one registry; a plan must cover the action it is used for
+//! - Invoked plans are spent, and `plans_never_invoked` feeds audits
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::{
+    ActionRef, AttestationId, ConsentRecord, ConsentRegistry, Did, GovernanceProposal, GovernanceScope,
+    ImpactMetrics, MicroMissionsEngine, MissionId, MissionTemplate, ModuleId, PlanId, PlanetaryLedger,
+    RollbackPlan, RollbackPlanRegistry, SaepConfig, SaepEngine, SaepRefs, StewardModule,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn metrics() -> ImpactMetrics {
+    ImpactMetrics {
+        co2eq_reduced: 0.3,
+        biodiversity_index_delta: 0.01,
+        biodiversity_detail: None,
+        restored_area_m2: 40.0,
+        avoided_emissions_co2eq: 0.0,
+        custom: Default::default(),
+    }
+}
+
+fn plan(id: &str, action_ref: ActionRef, responsible: &Did, created_ms: u64) -> RollbackPlan {
+    RollbackPlan {
+        id: PlanId(id.into()),
+        action_ref,
+        steps: vec!["Revert the action".into(), "Notify affected participants".into()],
+        responsible: responsible.clone(),
+        created_ms,
+    }
+}
+
+fn plan_ref(id: PlanId) -> SaepRefs {
+    SaepRefs { intent_entry_id: None, rollback_plan_id: Some(id) }
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let kofi = Did::new("did:psv:steward:kofi").expect("valid DID");
+    let mission = MissionId::new("creek-cleanup").expect("valid mission id");
+    let config = SaepConfig::default();
+    assert!(config.enforce_reversibility);
+
+    let consent = || {
+        let mut registry = ConsentRegistry::new();
+        for (who, module, mission) in [
+            (&ana, StewardModule::PLGA, None),
+            (&ana, StewardModule::MME, Some(mission.clone())),
+            (&kofi, StewardModule::MME, Some(mission.clone())),
+        ] {
+            registry.upsert_consent(ConsentRecord {
+                participant: who.clone(),
+                module,
+                mission,
+                consent_given: true,
+                timestamp_ms: T0,
+                evidence_uri: None,
+                evidence_hash: None,
+                prompt_hash: None,
+            });
+        }
+        registry
+    };
+
+    let registry = RollbackPlanRegistry::shared();
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        consent(),
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_rollback_registry(registry.clone());
+    let mut missions =
+        MicroMissionsEngine::new(SaepEngine::new(config), consent()).with_rollback_registry(registry.clone());
+    missions.add_template(MissionTemplate {
+        id: mission.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    });
+
+    // 1. No plan, no attestation.
+    let err = ledger
+        .issue_attestation(ana.clone(), None, "Planted 12 willows".into(), metrics(), "ipfs://willows", vec![], T0)
+        .expect_err("reversibility requires a plan");
+    println!("refused: {err}");
+
+    // 2. Plans cannot target actions that do not exist.
+    let ghost = plan("plan:ghost", ActionRef::Attestation(AttestationId("att-ghost".into())), &ana, T0);
+    let err = ledger.register_rollback_plan(ghost).expect_err("unknown attestation");
+    println!("refused: {err}");
+    let stray = MissionId::new("no-such-mission").expect("valid mission id");
+    let err = missions
+        .register_rollback_plan(plan("plan:stray", ActionRef::Assignment { mission: stray, assignee: ana.clone() }, &ana, T0))
+        .expect_err("unknown mission");
+    println!("refused: {err}");
+
+    // 3. Reserve an id, plan for it, issue under it.
+    let reserved = ledger.reserve_attestation_id();
+    let willows = ledger
+        .register_rollback_plan(plan("plan:willows", ActionRef::Attestation(reserved.clone()), &ana, T0))
+        .expect("reserved id resolves");
+    let attestation = ledger
+        .issue_attestation_with_refs(
+            ana.clone(), None, "Planted 12 willows".into(), metrics(), "ipfs://willows", vec![], T0,
+            plan_ref(willows.clone()),
+        )
+        .expect("attestation issued");
+    assert_eq!(attestation.id, reserved);
+    assert_eq!(attestation.rollback_plan_id, Some(willows));
+
+    // 4. Assignment plans live in the same registry and must match mission and assignee.
+    let ana_plan = missions
+        .register_rollback_plan(plan(
+            "plan:creek:ana",
+            ActionRef::Assignment { mission: mission.clone(), assignee: ana.clone() },
+            &kofi,
+            T0 + DAY_MS,
+        ))
+        .expect("mission exists");
+    let err = missions
+        .assign_mission_with_refs(&mission, kofi.clone(), T0 + DAY_MS, plan_ref(ana_plan.clone()))
+        .expect_err("plan covers ana, not kofi");
+    println!("refused: {err}");
+    missions
+        .assign_mission_with_refs(&mission, ana.clone(), T0 + DAY_MS, plan_ref(ana_plan.clone()))
+        .expect("assigned");
+
+    // 5. Governance proposals resolve against the proposals a caller knows about.
+    let proposals = [GovernanceProposal {
+        proposal_id: "prop-creek-access".into(),
+        scope: GovernanceScope::Module(ModuleId("MME".into())),
+        title: "Open the creek path to volunteers".into(),
+        description: "Allow weekend access".into(),
+        payload: serde_json::json!({}),
+        can_introduce_restrictions: false,
+    }];
+    registry
+        .lock()
+        .unwrap()
+        .register(
+            plan("plan:creek-access", ActionRef::GovernanceProposal("prop-creek-access".into()), &kofi, T0 + DAY_MS),
+            &proposals[..],
+        )
+        .expect("known proposal");
+
+    // 6. Invoking spends a plan.
+    let mut registry = registry.lock().unwrap();
+    registry
+        .mark_invoked(&ana_plan, kofi.clone(), T0 + 2 * DAY_MS, "Ana moved away; mission reassigned".into())
+        .expect("first invocation");
+    assert!(registry.mark_invoked(&ana_plan, kofi, T0 + 2 * DAY_MS, "again".into()).is_err());
+    drop(registry);
+    let err = missions
+        .assign_mission_with_refs(&mission, ana, T0 + 3 * DAY_MS, plan_ref(ana_plan))
+        .expect_err("spent plan");
+    println!("refused: {err}");
+
+    // 7. Audit: plans older than a day that nobody has ever exercised.
+    let registry = ledger.rollback_registry();
+    let registry = registry.lock().unwrap();
+    let untested: Vec<&str> = registry
+        .plans_never_invoked(T0 + DAY_MS)
+        .iter()
+        .map(|p| p.plan.id.0.as_str())
+        .collect();
+    println!("{} plans, never invoked before day 1: {untested:?}", registry.plans().len());
+    assert_eq!(registry.plans().len(), 3);
+    assert_eq!(untested, ["plan:willows"]);
+}
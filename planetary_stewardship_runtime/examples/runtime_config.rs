@@ -0,0 +1,100 @@
+// path: planetary_stewardship_runtime/examples/runtime_config.rs
+
+//! Example: a deployment's runtime from `config/runtime.example.toml`
+//! (run with `--features toml,test-util`).
+//! - An empty config builds the same engines, with the same policies, as assembling the
+//!   defaults by hand, and they decide the same claim the same way
+//! - The example file loads, and its settings reach every engine
+//! - A module override outside the charter set, and an unknown field, are refused at load
+//! - A reload applies a stricter verification quorum and smaller proposal limits, and refuses
+//!   to bind CSC to the charter, which takes a governance proposal
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::{
+    did, AttestationBuilder, ConsentRecordBuilder, LedgerBuilder, FIXTURE_START_MS,
+};
+use planetary_stewardship_runtime::{
+    build_runtime_with, ConsentRegistry, GovernanceEngine, GovernanceScope, MicroMissionsEngine, PlanetaryLedger,
+    RuntimeConfig, RuntimeConfigError, SaepConfig, SaepEngine, ScopePath, StewardModule, StewardshipRuntime,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/config/runtime.example.toml");
+
+fn build(config: RuntimeConfig) -> StewardshipRuntime {
+    build_runtime_with(config, Arc::new(FixedClock::new(FIXTURE_START_MS)), Arc::new(SequentialIdGenerator::new(1)))
+}
+
+fn json<T: serde::Serialize>(value: T) -> serde_json::Value {
+    serde_json::to_value(value).expect("json")
+}
+
+fn main() {
+    let ana = did("did:psv:steward:ana");
+
+    // 1. An empty file is today's hand-assembled runtime.
+    let empty = RuntimeConfig::from_toml("").expect("empty TOML");
+    assert_eq!(json(&empty), json(RuntimeConfig::from_json("{}").expect("empty JSON")));
+    let mut runtime = build(empty);
+    runtime.upsert_consent(ConsentRecordBuilder::new(&ana, StewardModule::PLGA).build()).expect("consent");
+    let mut ledger: PlanetaryLedger = LedgerBuilder::new().consenting(&ana).build();
+    let missions = MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new());
+    let governance = GovernanceEngine::new(SaepEngine::new(SaepConfig::default()));
+    assert_eq!(json(runtime.ledger.evidence_policy()), json(ledger.evidence_policy()));
+    assert_eq!(json(runtime.ledger.metrics_policy()), json(ledger.metrics_policy()));
+    assert_eq!(json(runtime.ledger.content_policy()), json(ledger.content_policy()));
+    assert_eq!(json(runtime.ledger.impact_threshold_policy()), json(ledger.impact_threshold_policy()));
+    assert_eq!(json(runtime.ledger.verification_policy()), json(ledger.verification_policy()));
+    assert_eq!(json(runtime.ledger.retention_policy()), json(ledger.retention_policy()));
+    assert_eq!(json(runtime.missions.template_review_policy()), json(missions.template_review_policy()));
+    assert_eq!(json(runtime.missions.content_policy()), json(missions.content_policy()));
+    assert_eq!(runtime.governance.charter_bound_modules(), governance.charter_bound_modules());
+    assert_eq!(runtime.governance.proposal_limits(), governance.proposal_limits());
+    let root = ScopePath::root();
+    assert_eq!(runtime.governance.scope_tree().effective(&root), governance.scope_tree().effective(&root));
+    let claim = AttestationBuilder::new(&ana).description("Street tree watering").co2eq_reduced(0.4).request();
+    let built = runtime.ledger.issue_attestation_idempotent(claim.clone(), None).expect_err("no rollback plan");
+    assert_eq!(Err(built.clone()), ledger.issue_attestation_idempotent(claim, None).map(|_| ()), "same decision");
+    println!("defaults: {built}");
+
+    // 2. The example file: every engine gets its section.
+    let config = RuntimeConfig::load(EXAMPLE).expect("example config");
+    let mut runtime = build(config.clone());
+    assert_eq!(runtime.ledger.verification_policy().quorum, 2);
+    assert_eq!(runtime.ingestion.limits().max_depth, 20_000);
+    assert_eq!(runtime.missions.template_review_policy().required_signoffs, 1);
+    assert!(!runtime.governance.is_charter_bound(StewardModule::CSC));
+    assert_eq!(runtime.governance.effective_settings(&GovernanceScope::EcosystemWide).quorum, Some(40.0));
+    assert!(!runtime.config().saep.for_module(StewardModule::MME).enforce_reversibility);
+    assert_eq!(runtime.admin().policy().admins.len(), 2);
+
+    // 3. Problems are found at load, all of them.
+    let mut overridden = json(&config);
+    overridden["saep"]["module_overrides"]["CSC"] = serde_json::json!({ "enforce_transparency": false });
+    overridden["ledger"]["verification"]["quorum"] = 0.into();
+    let Err(RuntimeConfigError::Invalid(issues)) = RuntimeConfig::from_json(&overridden.to_string()) else {
+        panic!("an override outside the charter set");
+    };
+    let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+    assert_eq!(fields, ["saep.module_overrides.CSC", "ledger.verification.quorum"]);
+    println!("{}", RuntimeConfigError::Invalid(issues));
+    let typo = RuntimeConfig::from_json(r#"{"ledger": {"verifcation": {}}}"#).expect_err("unknown field");
+    assert!(matches!(typo, RuntimeConfigError::Parse(_)), "{typo}");
+
+    // 4. Reload: hot sections apply, the charter does not move.
+    let mut next = config.clone();
+    next.ledger.verification.quorum = 3;
+    next.governance.proposal_limits.max_patch_operations = 50;
+    next.governance.charter_bound_modules.insert(StewardModule::CSC);
+    let report = runtime.reload(next).expect("valid config");
+    let applied: Vec<&str> = report.applied.iter().map(|c| c.field.as_str()).collect();
+    let rejected: Vec<&str> = report.rejected.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(applied, ["governance.proposal_limits", "ledger.verification"]);
+    assert_eq!(rejected, ["governance.charter_bound_modules"]);
+    assert_eq!(runtime.ledger.verification_policy().quorum, 3);
+    assert_eq!(runtime.governance.proposal_limits().max_patch_operations, 50);
+    assert!(!runtime.governance.is_charter_bound(StewardModule::CSC));
+    println!("reload applied {applied:?}, rejected {rejected:?}");
+    assert!(runtime.reload(runtime.config().clone()).expect("same config").is_unchanged());
+}
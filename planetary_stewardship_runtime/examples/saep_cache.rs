@@ -0,0 +1,132 @@
+// path: planetary_stewardship_runtime/examples/saep_cache.rs
+
+//! Example: caching SAEP decisions without ever returning a different one.
+//! - 2,000 generated contexts go through a cached and an uncached engine that share the rule
+//!   weights; a calibrator moves a weight every 250 contexts and the clock drifts. Every
+//!   cached decision serializes to the same bytes as the fresh one
+//! - The cache never holds more than its capacity, and contexts with affected parties never
+//!   touch it
+//! - The impact payload's key order does not matter; a decision past its TTL is evaluated
+//!   again; `clear_cache` empties the cache and keeps the counters
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::{
+    Did, EthicsContext, ReasonCode, RuleCalibration, SaepCacheConfig, SaepConfig, SaepEngine, SaepVerdict,
+    StewardModule,
+};
+use steward_runtime_support::{Clock, FixedClock};
+
+const T0: u64 = 1_767_225_600_000;
+const MINUTE_MS: u64 = 60_000;
+const SEED: u64 = 0x5AE9_CAC4E;
+
+const MODULES: [StewardModule; 3] = [StewardModule::PLGA, StewardModule::MME, StewardModule::VET];
+const DESCRIPTIONS: [&str; 6] = [
+    "Plant native shrubs along the wash",
+    "Survey pollinators at dawn",
+    "Repair the community weapon locker",
+    "Exclusive monetization of the seed bank",
+    "Map coercive grazing leases",
+    "Clean the canal intake screens",
+];
+
+/// SplitMix64: enough to generate contexts reproducibly.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+fn context(rng: &mut Rng, actor: &Did, neighbour: &Did) -> EthicsContext {
+    let module = MODULES[rng.below(MODULES.len() as u64) as usize];
+    let description = DESCRIPTIONS[rng.below(DESCRIPTIONS.len() as u64) as usize].to_string();
+    let mut impact = serde_json::Map::new();
+    if rng.below(2) == 0 {
+        impact.insert("water_l".into(), (rng.below(3) * 500).into());
+    }
+    let affected_parties = if rng.below(5) == 0 { vec![neighbour.clone()] } else { vec![] };
+    EthicsContext {
+        actor: actor.clone(),
+        affected_parties,
+        module,
+        description,
+        estimated_impact: serde_json::Value::Object(impact),
+    }
+}
+
+fn main() -> Result<(), String> {
+    let ethicist = Did::new("did:psv:city:phoenix:ethicist").expect("valid DID");
+    let crew = Did::new("did:psv:steward:crew").expect("valid DID");
+    let neighbour = Did::new("did:psv:resident:marisol").expect("valid DID");
+    let clock = Arc::new(FixedClock::new(T0));
+    let calibration = RuleCalibration::new([ethicist.clone()]).into_shared();
+    let config = SaepCacheConfig { capacity: 64, ttl_ms: 30 * MINUTE_MS };
+    let cached = SaepEngine::new(SaepConfig::default())
+        .with_calibration(calibration.clone())
+        .with_cache(config, clock.clone());
+    let fresh = SaepEngine::new(SaepConfig::default()).with_calibration(calibration.clone());
+
+    // 1. Generated contexts: cached and fresh decisions agree byte for byte, across weight changes.
+    let mut rng = Rng(SEED);
+    let mut bypassed = 0;
+    for n in 0..2_000u64 {
+        if n % 250 == 0 {
+            let weight = [1.0, 0.3, 0.6, 0.1][(n / 250 % 4) as usize];
+            let now_ms = clock.now_ms();
+            let mut calibration = calibration.lock().unwrap();
+            calibration.set_rule_weight(ReasonCode::SaepNonHarm, weight, &ethicist, now_ms)?;
+        }
+        clock.advance(rng.below(20_000));
+        let ctx = context(&mut rng, &crew, &neighbour);
+        bypassed += u64::from(!ctx.affected_parties.is_empty());
+        let (a, b) = (cached.evaluate(&ctx), fresh.evaluate(&ctx));
+        let (a, b) = (serde_json::to_vec(&a).expect("json"), serde_json::to_vec(&b).expect("json"));
+        assert_eq!(a, b, "context {n} ({}) decided differently from the cache", ctx.description);
+    }
+    let stats = cached.cache_stats().expect("cache configured");
+    println!("{}", serde_json::to_string_pretty(&stats).expect("stats json"));
+    assert_eq!(stats.bypassed, bypassed, "affected parties never touch the cache");
+    assert_eq!(stats.hits + stats.misses + stats.bypassed, 2_000);
+    assert!(stats.hits > 0 && stats.evictions > 0 && stats.expired > 0);
+    assert!(stats.entries <= config.capacity);
+
+    // 2. The same impact built in another key order is the same entry.
+    cached.clear_cache();
+    let planting = |impact: serde_json::Value| EthicsContext {
+        actor: crew.clone(),
+        affected_parties: vec![],
+        module: StewardModule::PLGA,
+        description: DESCRIPTIONS[0].into(),
+        estimated_impact: impact,
+    };
+    let hits = cached.cache_stats().expect("cache configured").hits;
+    cached.evaluate(&planting(serde_json::json!({ "area_m2": 40, "participants": 6 })));
+    let decision = cached.evaluate(&planting(serde_json::json!({ "participants": 6, "area_m2": 40 })));
+    assert_eq!(decision.verdict(), SaepVerdict::Allow);
+    assert_eq!(cached.cache_stats().expect("cache configured").hits, hits + 1);
+
+    // 3. Past the TTL the decision is evaluated again.
+    clock.advance(30 * MINUTE_MS);
+    let before = cached.cache_stats().expect("cache configured");
+    cached.evaluate(&planting(serde_json::json!({ "area_m2": 40, "participants": 6 })));
+    let after = cached.cache_stats().expect("cache configured");
+    assert_eq!((after.expired, after.misses), (before.expired + 1, before.misses + 1));
+
+    // 4. `clear_cache` empties it and keeps the counters for operators.
+    cached.clear_cache();
+    let cleared = cached.cache_stats().expect("cache configured");
+    assert_eq!((cleared.entries, cleared.hits), (0, after.hits));
+    assert!(fresh.cache_stats().is_none(), "no cache unless configured");
+    Ok(())
+}
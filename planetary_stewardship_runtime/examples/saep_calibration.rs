@@ -0,0 +1,80 @@
+// path: planetary_stewardship_runtime/examples/saep_calibration.rs
+
+//! Example: reviewers overturning non-harm denials, and a calibrator acting on it (run with
+//! `--features test-util`).
+//! - Denials are escalated and resolved; each resolution counts toward the rules that fired,
+//!   and `rule_performance_report` shows their precision
+//! - Feedback alone changes nothing: the non-harm rule still denies
+//! - Only a calibrator may set a weight; once non-harm is set below the deny threshold, the
+//!   next `evaluate`, in every engine sharing the calibration, escalates instead of denying
+//! - The commons-benefit rule keeps its weight, so a decision it also fired on still denies
+
+use planetary_stewardship_runtime::builders::did;
+use planetary_stewardship_runtime::{
+    EthicsContext, ReasonCode, ReviewOutcome, RuleAction, RuleCalibration, SaepConfig, SaepEngine, SaepVerdict,
+    StewardModule,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn context(description: &str) -> EthicsContext {
+    EthicsContext {
+        actor: did("did:psv:steward:crew"),
+        affected_parties: vec![],
+        module: StewardModule::MME,
+        description: description.into(),
+        estimated_impact: serde_json::json!({}),
+    }
+}
+
+fn main() {
+    let (ethicist, reviewer, crew) =
+        (did("did:psv:city:phoenix:ethicist"), did("did:psv:city:phoenix:reviewer"), did("did:psv:steward:crew"));
+    let calibration = RuleCalibration::new([ethicist.clone()]).into_shared();
+    let saep = SaepEngine::new(SaepConfig::default()).with_calibration(calibration.clone());
+    let missions_saep = SaepEngine::new(SaepConfig::default()).with_calibration(calibration.clone());
+
+    // 1. Ten non-harm denials reviewed: three upheld, seven were weed-whacker false positives.
+    let flagged = context("Clear the wash with a weed whacker, no weapon involved");
+    let decision = saep.evaluate(&flagged);
+    assert_eq!(decision.verdict(), SaepVerdict::Deny);
+    for n in 0..10 {
+        let outcome = if n < 3 { ReviewOutcome::Upheld } else { ReviewOutcome::Overturned };
+        let mut calibration = calibration.lock().unwrap();
+        let id = calibration.escalate(&flagged, &decision, T0 + n).expect("a rule fired");
+        calibration.resolve_escalation(&id, outcome, &reviewer, T0 + n + 1).expect("open");
+    }
+    let report = calibration.lock().unwrap().rule_performance_report();
+    assert_eq!(report.len(), 1);
+    assert_eq!((report[0].rule, report[0].upheld, report[0].overturned), (ReasonCode::SaepNonHarm, 3, 7));
+    assert_eq!(report[0].precision, Some(0.3));
+    assert_eq!(report[0].action, RuleAction::Deny, "feedback alone adjusts nothing");
+    assert_eq!(saep.evaluate(&flagged).verdict(), SaepVerdict::Deny);
+
+    // 2. Only a calibrator moves a weight; the change is logged with who approved it.
+    let mut guard = calibration.lock().unwrap();
+    assert!(guard.set_rule_weight(ReasonCode::SaepNonHarm, 0.3, &crew, T0 + 20).is_err());
+    assert!(guard.set_rule_weight(ReasonCode::SaepNonHarm, 1.5, &ethicist, T0 + 20).is_err());
+    guard.set_rule_weight(ReasonCode::SaepNonHarm, 0.3, &ethicist, T0 + 20).expect("calibrator");
+    let change = guard.weight_log().last().expect("logged").clone();
+    assert_eq!((change.before, change.after, change.approved_by.clone()), (1.0, 0.3, ethicist.clone()));
+    drop(guard);
+
+    // 3. From the next evaluate on, in both engines, non-harm escalates; the action is still held.
+    for engine in [&saep, &missions_saep] {
+        let decision = engine.evaluate(&flagged);
+        assert_eq!(decision.verdict(), SaepVerdict::Escalate);
+        assert!(!decision.allowed);
+        assert_eq!(decision.blocked("mission").code, ReasonCode::SaepEscalated);
+    }
+    println!("{}", saep.evaluate(&flagged).blocked("mission"));
+
+    // 4. A full-weight rule firing alongside still denies.
+    let both = saep.evaluate(&context("Weapon cache for exclusive monetization"));
+    assert_eq!(both.escalated, [ReasonCode::SaepNonHarm]);
+    assert_eq!(both.verdict(), SaepVerdict::Deny);
+    assert_eq!(both.blocked("mission").code, ReasonCode::SaepBlocked);
+    for row in calibration.lock().unwrap().rule_performance_report() {
+        println!("{:?}: precision {:?}, weight {}, {:?}", row.rule, row.precision, row.weight, row.action);
+    }
+}
@@ -0,0 +1,152 @@
+// path: planetary_stewardship_runtime/examples/scope_hierarchy.rs
+
+//! Example: a city program inside a bioregion inside the ecosystem (run with `--features test-util`).
+//! - Settings applied at a parent scope are in force at every descendant that does not set
+//!   its own; module shorthands sit directly under the ecosystem
+//! - A city-level quorum and voter set shadow the bioregion's; removing them restores
+//!   inheritance
+//! - Charter terms set ecosystem-wide reach restrictive proposals in the city
+//! - Proposals for unregistered paths are refused; moving the city to another bioregion is
+//!   validated, audited, and changes what it inherits
+
+use std::collections::BTreeSet;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    ConfigChange, Did, GovernanceAuditAction, GovernanceEngine, GovernanceProposal, GovernanceScope, ModuleId,
+    QuadraticOutcome, QuadraticVote, ReasonCode, SaepEngine, ScopePath, ScopeSettings,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn path(s: &str) -> ScopePath {
+    ScopePath::new(s).expect("valid scope path")
+}
+
+fn did(s: &str) -> Did {
+    Did::new(s).expect("valid DID")
+}
+
+fn settings_proposal(id: &str, scope: &GovernanceScope, settings: ScopeSettings) -> GovernanceProposal {
+    let change = ConfigChange::SetScopeSettings { scope: scope.clone(), settings };
+    let (title, description) = change.describe();
+    GovernanceProposal {
+        proposal_id: id.into(),
+        scope: scope.clone(),
+        title,
+        description,
+        payload: change.to_payload(),
+        can_introduce_restrictions: change.introduces_restrictions(),
+    }
+}
+
+fn votes(proposal: &GovernanceProposal, support: f64, opposition: f64) -> QuadraticOutcome {
+    QuadraticOutcome { proposal_id: proposal.proposal_id.clone(), total_support: support, total_opposition: opposition }
+}
+
+fn main() {
+    let config = saep_without_rollback_plans();
+    let mut governance = GovernanceEngine::new(SaepEngine::new(config));
+    let (sonora, mojave) = (path("ecosystem/bioregion:sonora"), path("ecosystem/bioregion:mojave"));
+    let phoenix = path("ecosystem/bioregion:sonora/city:phoenix");
+    let phoenix_mme = phoenix.child("module:MME").expect("valid segment");
+    for scope in [&sonora, &mojave, &phoenix, &phoenix_mme] {
+        governance.register_scope(scope.clone()).expect("parent registered first");
+    }
+    assert!(governance.register_scope(path("ecosystem/bioregion:atlantis/city:x")).is_err());
+    let at = |p: &ScopePath| GovernanceScope::Path(p.clone());
+
+    // 1. Ecosystem-wide defaults reach every scope, module shorthands included.
+    let ecosystem = ScopeSettings {
+        quorum: Some(50.0),
+        charter_terms: Some(BTreeSet::from(["eviction".to_string()])),
+        ..ScopeSettings::default()
+    };
+    let root = settings_proposal("root-defaults", &GovernanceScope::EcosystemWide, ecosystem);
+    governance.apply_proposal(root.clone(), votes(&root, 40.0, 15.0), T0).expect("no quorum yet");
+    assert_eq!(governance.quorum(&at(&phoenix_mme)), 50.0);
+    assert_eq!(governance.quorum(&GovernanceScope::Module(ModuleId("MME".into()))), 50.0);
+
+    // 2. The bioregion lowers it for everything inside Sonora.
+    let lower = ScopeSettings { quorum: Some(20.0), ..ScopeSettings::default() };
+    let regional = settings_proposal("sonora", &at(&sonora), lower);
+    governance.apply_proposal(regional.clone(), votes(&regional, 45.0, 10.0), T0 + 1).expect("meets 50");
+    assert_eq!(governance.quorum(&at(&phoenix_mme)), 20.0);
+    assert_eq!(governance.quorum(&at(&mojave)), 50.0);
+
+    // 3. Phoenix overrides the bioregion: its own quorum and voter roll.
+    let (ana, kofi, lee) = (did("did:psv:steward:ana"), did("did:psv:steward:kofi"), did("did:psv:steward:lee"));
+    let city = ScopeSettings {
+        quorum: Some(5.0),
+        eligible_voters: Some(BTreeSet::from([ana.clone(), kofi.clone()])),
+        ..ScopeSettings::default()
+    };
+    let local = settings_proposal("phoenix", &at(&phoenix), city);
+    assert!(local.can_introduce_restrictions, "a voter roll restricts");
+    governance.apply_proposal(local.clone(), votes(&local, 18.0, 4.0), T0 + 2).expect("meets 20");
+    assert_eq!(governance.quorum(&at(&phoenix_mme)), 5.0, "city shadows bioregion");
+    assert_eq!(governance.quorum(&at(&sonora)), 20.0);
+    let cast = |voter: &Did, weight| QuadraticVote { voter: voter.clone(), effective_weight: weight, support: true };
+    let ballot = [cast(&ana, 3.0), cast(&kofi, 3.0), cast(&lee, 9.0)];
+    let garden = GovernanceProposal {
+        proposal_id: "phoenix-garden".into(),
+        scope: at(&phoenix_mme),
+        title: "Community garden missions".into(),
+        description: "Add weekly garden missions for the Phoenix program".into(),
+        payload: serde_json::json!({}),
+        can_introduce_restrictions: false,
+    };
+    let outcome = governance.tally_proposal(&garden, &ballot);
+    assert_eq!(outcome.total_support, 6.0, "lee is not on the Phoenix roll");
+    assert_eq!(governance.can_apply_proposal(&garden, &outcome), Ok(true));
+    let regional_garden = GovernanceProposal { scope: at(&sonora), ..garden.clone() };
+    assert_eq!(governance.tally_proposal(&regional_garden, &ballot).total_support, 15.0);
+    assert_eq!(governance.can_apply_proposal(&regional_garden, &outcome), Ok(false), "below 20");
+
+    // 4. Charter terms set at the root still bind restrictive city proposals.
+    let closure = GovernanceProposal {
+        proposal_id: "phoenix-closure".into(),
+        description: "Close the river path pending eviction of campers".into(),
+        can_introduce_restrictions: true,
+        ..garden.clone()
+    };
+    let refused = governance.check_proposal(&closure, &votes(&closure, 6.0, 0.0)).expect_err("charter term");
+    assert_eq!(refused.code, ReasonCode::GovernanceScopeCharter);
+    println!("refused: {refused}");
+
+    // 5. Removing the city's override restores inheritance from Sonora.
+    let reset = settings_proposal("phoenix-reset", &at(&phoenix), ScopeSettings::default());
+    governance.apply_proposal(reset.clone(), votes(&reset, 5.0, 1.0), T0 + 3).expect("meets 5");
+    assert_eq!(governance.quorum(&at(&phoenix_mme)), 20.0);
+    assert!(governance.is_eligible_voter(&at(&phoenix_mme), &lee));
+    assert_eq!(governance.scope_tree().settings(&phoenix), Some(&ScopeSettings::default()));
+
+    // 6. Unregistered paths cannot be targeted, directly or through a change.
+    let atlantis = GovernanceScope::Path(path("ecosystem/bioregion:atlantis"));
+    let lost = settings_proposal("atlantis", &atlantis, ScopeSettings::default());
+    let refused = governance.check_proposal(&lost, &votes(&lost, 60.0, 0.0)).expect_err("unknown scope");
+    assert_eq!(refused.to_string(), "Governance scope ecosystem/bioregion:atlantis is not registered");
+    let smuggled = GovernanceProposal { scope: GovernanceScope::EcosystemWide, ..lost };
+    assert!(governance.check_proposal(&smuggled, &votes(&smuggled, 60.0, 0.0)).is_err());
+
+    // 7. Phoenix moves to the Mojave: invalid moves are refused, the valid one is audited.
+    let steward = did("did:psv:steward:council");
+    assert!(governance.reparent_scope(&phoenix, &phoenix_mme, steward.clone(), T0 + 4).is_err(), "below itself");
+    assert!(governance.reparent_scope(&ScopePath::root(), &mojave, steward.clone(), T0 + 4).is_err());
+    assert!(governance.reparent_scope(&phoenix, &sonora, steward.clone(), T0 + 4).is_err(), "already there");
+    assert!(governance.audit_trail().is_empty());
+    let moved = governance.reparent_scope(&phoenix, &mojave, steward, T0 + 4).expect("valid move");
+    assert_eq!(moved, path("ecosystem/bioregion:mojave/city:phoenix"));
+    let moved_mme = moved.child("module:MME").expect("valid segment");
+    assert!(governance.scope_tree().contains(&moved_mme) && !governance.scope_tree().contains(&phoenix_mme));
+    assert_eq!(governance.quorum(&at(&moved_mme)), 50.0, "Mojave sets none; the ecosystem's applies");
+    let entry = governance.audit_trail().last().expect("audited");
+    let GovernanceAuditAction::ReparentScope { from, to } = &entry.action else {
+        panic!("expected a scope move, got {:?}", entry.action);
+    };
+    assert_eq!((from, to), (&phoenix, &moved));
+
+    for scope in governance.scope_tree().scopes() {
+        println!("{scope}: {}", serde_json::to_string(&governance.effective_settings(&at(scope))).expect("json"));
+    }
+}
@@ -0,0 +1,165 @@
+// path: planetary_stewardship_runtime/examples/skill_taxonomy.rs
+
+//! Example: matching volunteers to missions through a skill taxonomy.
+//! - Templates and volunteers spell skills differently; synonyms resolve both to one canonical id
+//! - A volunteer with a specialization qualifies for the broader skill; one with only the broader
+//!   skill earns reduced credit for the specialization
+//! - Under `Reject` an unknown skill refuses the template with a suggestion; under `CreatePending`
+//!   it is filed for curators, who confirm it by defining it
+//! - A bundle with several unknown skills reports all of them
+
+use planetary_stewardship_runtime::{
+    ConsentRegistry, MicroMissionsEngine, MissionId, MissionTemplate, SaepConfig, SaepEngine, SkillCredit, SkillId,
+    SkillSpec, SkillTaxonomy, TemplateQuery, UnknownSkillPolicy,
+};
+
+fn skill(id: &str, display_name: &str, synonyms: &[&str], parent: Option<&str>) -> SkillSpec {
+    SkillSpec {
+        id: SkillId(id.into()),
+        display_name: display_name.into(),
+        synonyms: synonyms.iter().map(|s| s.to_string()).collect(),
+        parent: parent.map(|p| SkillId(p.into())),
+    }
+}
+
+/// Children listed before their parents: order does not matter.
+fn taxonomy(policy: UnknownSkillPolicy) -> SkillTaxonomy {
+    SkillTaxonomy::from_specs(
+        vec![
+            skill("drone-mapping", "Drone mapping", &["UAV survey", "aerial photogrammetry"], Some("gis")),
+            skill("gis", "GIS", &["mapping", "geographic information systems"], None),
+            skill("water-sampling", "Water sampling", &["sampling", "water testing"], None),
+            skill("first-aid", "First aid", &["CPR"], None),
+        ],
+        policy,
+    )
+    .expect("valid taxonomy")
+}
+
+fn template(id: &str, skills: &[&str]) -> MissionTemplate {
+    MissionTemplate {
+        id: MissionId::new(id).expect("valid mission id"),
+        title: id.replace('-', " "),
+        description: "Volunteer mission".into(),
+        difficulty: "M".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: skills.iter().map(|s| s.to_string()).collect(),
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    }
+}
+
+fn engine(policy: UnknownSkillPolicy) -> MicroMissionsEngine {
+    MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new())
+        .with_skill_taxonomy(taxonomy(policy))
+}
+
+fn skills(names: &[&str]) -> Vec<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+fn main() {
+    // 0. Parents must exist and may not loop.
+    let lidar = skill("lidar", "Lidar", &[], Some("remote-sensing"));
+    let orphan = SkillTaxonomy::from_specs(vec![lidar], UnknownSkillPolicy::Reject);
+    assert_eq!(orphan.unwrap_err(), "Skill lidar has unknown parent remote-sensing");
+    let looped = SkillTaxonomy::from_specs(
+        vec![skill("a", "A", &[], Some("b")), skill("b", "B", &[], Some("a"))],
+        UnknownSkillPolicy::Reject,
+    );
+    assert!(looped.unwrap_err().contains("loop back"));
+    let shared = SkillTaxonomy::from_specs(
+        vec![skill("gis", "GIS", &["mapping"], None), skill("cartography", "Cartography", &["Mapping"], None)],
+        UnknownSkillPolicy::Reject,
+    );
+    assert_eq!(shared.unwrap_err(), "Skill name \"mapping\" is used by both cartography and gis");
+
+    // 1. Synonyms, display names and ids resolve alike, whatever the case or punctuation.
+    let tax = taxonomy(UnknownSkillPolicy::Reject);
+    for name in ["UAV survey", "uav_survey", "Aerial  Photogrammetry", "Drone mapping", "drone-mapping"] {
+        assert_eq!(tax.normalize_skill(name), Some(SkillId("drone-mapping".into())), "{name}");
+    }
+    assert_eq!(tax.normalize_skill("cpr"), Some(SkillId("first-aid".into())));
+    assert_eq!(tax.normalize_skill("knitting"), None);
+
+    // 2. Registered templates store canonical ids, so search matches any spelling.
+    let mut missions = engine(UnknownSkillPolicy::Reject);
+    missions.register_template(template("creek-survey", &["Water testing", "sampling"])).expect("known skills");
+    missions.register_template(template("canopy-map", &["UAV Survey"])).expect("known skills");
+    missions.register_template(template("trail-map", &["mapping"])).expect("known skills");
+    missions.register_template(template("seed-library", &[])).expect("no skills");
+    let creek = missions.get_template(&MissionId::new("creek-survey").expect("valid")).expect("registered");
+    assert_eq!(creek.required_skills, ["water-sampling"], "repeats collapse to one id");
+    let query = TemplateQuery { skills: Some(skills(&["water testing"])), ..TemplateQuery::default() };
+    let found: Vec<String> =
+        missions.search_templates(&query).iter().map(|h| h.template.mission_id.to_string()).collect();
+    assert_eq!(found, ["creek-survey", "seed-library"]);
+    // A drone mapper covers GIS work.
+    let query = TemplateQuery { skills: Some(skills(&["aerial photogrammetry"])), ..TemplateQuery::default() };
+    assert_eq!(missions.search_templates(&query).len(), 3);
+
+    // 3. Parent matches earn reduced credit: a GIS volunteer is a partial fit for drone mapping.
+    let recommended = missions.recommend_for_skills(&skills(&["Geographic Information Systems"]), 0.5);
+    let scores: Vec<(&str, f64)> = recommended.iter().map(|m| (m.mission_id.as_str(), m.score)).collect();
+    assert_eq!(scores, [("seed-library", 1.0), ("trail-map", 1.0), ("canopy-map", 0.5)]);
+    assert_eq!(recommended[2].credits[0].1, SkillCredit::Parent { held: SkillId("gis".into()) });
+    let no_partial = missions.recommend_for_skills(&skills(&["gis"]), 0.0);
+    assert!(no_partial.iter().all(|m| m.mission_id.as_str() != "canopy-map"));
+
+    // 4. Reject: an unknown skill refuses the template, naming the closest known spelling.
+    let err = missions.register_template(template("reef-check", &["Water testin"])).expect_err("unknown skill");
+    assert_eq!(
+        err,
+        "Mission template reef-check requires unknown skill \"Water testin\" \
+         (did you mean \"water testing\", i.e. water-sampling?)"
+    );
+    assert!(missions.get_template(&MissionId::new("reef-check").expect("valid")).is_none());
+
+    // 5. CreatePending: the unknown skill is filed for curators, then confirmed by defining it.
+    let mut pending = engine(UnknownSkillPolicy::CreatePending);
+    pending.register_template(template("nest-watch", &["Bird Banding", "first aid"])).expect("filed pending");
+    let nest = pending.get_template(&MissionId::new("nest-watch").expect("valid")).expect("registered");
+    assert_eq!(nest.required_skills, ["bird-banding", "first-aid"]);
+    let tax = pending.skill_taxonomy_mut().expect("taxonomy");
+    assert!(tax.is_pending(&SkillId("bird-banding".into())));
+    assert_eq!(tax.pending()[&SkillId("bird-banding".into())], "Bird Banding");
+    tax.define(skill("bird-banding", "Bird banding", &["ringing"], None)).expect("confirmed");
+    assert!(tax.pending().is_empty());
+    assert_eq!(tax.normalize_skill("ringing"), Some(SkillId("bird-banding".into())));
+
+    // 6. A bundle reports every unrecognized skill at once, and loads nothing.
+    #[cfg(feature = "toml")]
+    {
+        let bundle = r#"
+            [[template]]
+            id = "bat-count"
+            title = "Bat count"
+            description = "Count bats leaving the bridge at dusk"
+            difficulty = "S"
+            expected_impact = {}
+            location_hint = "geo"
+            required_skills = ["frist aid", "acoustic monitoring"]
+
+            [[template]]
+            id = "storm-drain-map"
+            title = "Storm drain map"
+            description = "Map storm drains after the monsoon"
+            difficulty = "M"
+            expected_impact = {}
+            location_hint = "geo"
+            required_skills = ["maping"]
+        "#;
+        let err = missions.load_templates_toml(bundle).expect_err("unknown skills");
+        println!("{err}");
+        let clauses: Vec<&str> = err.split("; ").collect();
+        assert_eq!(clauses.len(), 3);
+        assert!(clauses[0].ends_with("\"frist aid\" (did you mean \"first-aid\", i.e. first-aid?)"));
+        assert!(clauses[1].ends_with("\"acoustic monitoring\""), "nothing close enough to suggest");
+        assert!(clauses[2].ends_with("\"maping\" (did you mean \"mapping\", i.e. gis?)"));
+        assert!(missions.get_template(&MissionId::new("bat-count").expect("valid")).is_none());
+    }
+
+    println!("{}", serde_json::to_string_pretty(&recommended).expect("matches json"));
+}
@@ -0,0 +1,55 @@
+// path: planetary_stewardship_runtime/examples/streamed_export.rs
+
+//! Example: moving a ledger's attestations to another ledger through a stream
+//! (run with `--features test-util`, gzip-compressed with `--features flate2` too).
+//! - `export_attestations` writes a header, the attestations and a manifest; the restored
+//!   ledger holds the same attestations and reports the same totals
+//! - An export cut short restores nothing, and says the export was interrupted
+//! - Restoring the same stream twice is refused: every id must be new
+
+use planetary_stewardship_runtime::builders::{did, AttestationBuilder, LedgerBuilder};
+use planetary_stewardship_runtime::{Compression, PlanetaryLedger, StreamError};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn fresh() -> PlanetaryLedger {
+    LedgerBuilder::new().seed(5).without_rollback_plans().build()
+}
+
+fn main() {
+    let (ana, ben) = (did("did:psv:steward:ana"), did("did:psv:steward:ben"));
+    let mut ledger = LedgerBuilder::new().seed(3).without_rollback_plans().consenting(&ana).consenting(&ben).build();
+    for i in 0..200u64 {
+        let actor = if i % 2 == 0 { &ana } else { &ben };
+        let claim = AttestationBuilder::new(actor).description(&format!("Street tree watering, round {i}"));
+        claim.timestamp_ms(T0 + i).co2eq_reduced(0.5).issue_on(&mut ledger).expect("issued");
+    }
+    let compression = if Compression::Gzip.available() { Compression::Gzip } else { Compression::None };
+
+    // 1. Export, then restore into an empty ledger.
+    let mut exported = Vec::new();
+    let manifest = ledger.export_attestations(&mut exported, compression).expect("exported");
+    assert_eq!(manifest.records, 200);
+    let header = std::str::from_utf8(exported.split(|b| *b == b'\n').next().expect("header")).expect("plain header");
+    println!("{header} ({} bytes in all)", exported.len());
+    let mut restored = fresh();
+    assert_eq!(restored.restore_attestations(exported.as_slice()).expect("restored"), 200);
+    let json = |summary| serde_json::to_value(summary).expect("summary json");
+    let all = |l: &PlanetaryLedger| serde_json::to_value(l.attestations().collect::<Vec<_>>()).expect("json");
+    assert_eq!(all(&restored), all(&ledger));
+    assert_eq!(json(restored.impact_summary(Some(&ben))), json(ledger.impact_summary(Some(&ben))));
+    assert_eq!(restored.read_view().attestations().count(), 200, "restored attestations reach views");
+
+    // 2. Cut short: nothing restored.
+    let mut cut = fresh();
+    let error = cut.restore_attestations(&exported[..exported.len() * 2 / 3]).expect_err("interrupted");
+    assert!(matches!(error, StreamError::Interrupted { .. }), "{error}");
+    assert_eq!(cut.attestations().count(), 0);
+    println!("{error}");
+
+    // 3. The same stream again: refused, and the ledger is unchanged.
+    let again = restored.restore_attestations(exported.as_slice()).expect_err("ids already recorded");
+    assert!(matches!(again, StreamError::Rejected { index: 0, .. }), "{again}");
+    assert_eq!(restored.attestations().count(), 200);
+}
+
@@ -0,0 +1,98 @@
+// path: planetary_stewardship_runtime/examples/template_review.rs
+
+//! Example: mission templates going through review before volunteers see them
+//! (run with `--features test-util`).
+//! - A draft SAEP blocks is rejected with SAEP's reasons, for its submitter to read
+//! - One reviewer's sign-off approves a draft SAEP lets through; until then it is neither
+//!   searchable nor assignable
+//! - An edit of the approved template goes back to review; the assignment made under v1 keeps
+//!   v1, and later assignments get whichever version is approved next
+//! - Refused sign-offs change nothing; a rejected edit can be submitted again
+
+use planetary_stewardship_runtime::builders::{
+    did, mission_id, saep_without_rollback_plans, ConsentRecordBuilder, MissionTemplateBuilder,
+};
+use planetary_stewardship_runtime::{
+    ConsentRegistry, MicroMissionsEngine, Reason, ReasonCode, SaepConfig, SaepEngine, StewardModule, TemplateId,
+    TemplateQuery, TemplateReviewPolicy, TemplateState, TemplateVerdict,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn main() {
+    let (ana, ben, cy) = (did("did:psv:steward:ana"), did("did:psv:steward:ben"), did("did:psv:steward:cy"));
+    let (rev1, rev2) = (did("did:psv:reviewer:one"), did("did:psv:reviewer:two"));
+    let cleanup = MissionTemplateBuilder::new("creek-cleanup")
+        .description("Remove litter along the creek bank.")
+        .expected_impact(serde_json::json!({ "litter_kg": 20 }));
+    let mut consent = ConsentRegistry::new();
+    for volunteer in [&ben, &cy] {
+        let record = ConsentRecordBuilder::new(volunteer, StewardModule::MME).mission(&mission_id("creek-cleanup"));
+        consent.upsert_consent(record.at_ms(T0).build());
+    }
+    let config = saep_without_rollback_plans();
+    let policy = TemplateReviewPolicy { reviewers: [rev1.clone(), rev2.clone()].into(), required_signoffs: 1 };
+    let mut missions =
+        MicroMissionsEngine::new(SaepEngine::new(config), consent).with_template_review_policy(policy);
+    let searchable = |missions: &MicroMissionsEngine| missions.search_templates(&TemplateQuery::text("creek")).len();
+
+    // 1. SAEP auto-rejection: the reasons stay on the submission; no reviewer is asked.
+    let drone = MissionTemplateBuilder::new("drone-patrol").description("Coercive patrols of the park.").build();
+    let id = missions.submit_template(drone, ana.clone()).expect("valid template");
+    assert_eq!(id.to_string(), "drone-patrol@v1");
+    let non_harm = Reason::new(ReasonCode::SaepNonHarm).to_string();
+    assert_eq!(missions.review_template(&id), Ok(TemplateState::Rejected { reasons: vec![non_harm.clone()] }));
+    let submission = missions.template_submission(&id.mission).expect("kept for the submitter");
+    assert_eq!(submission.state, TemplateState::Rejected { reasons: vec![non_harm] });
+    assert!(missions.templates_awaiting_signoff(&rev1).is_empty());
+    assert!(missions.get_template(&id.mission).is_none());
+
+    // 2. Reviewer approval: SAEP passes it on, and one sign-off publishes it.
+    let v1 = missions.submit_template(cleanup.clone().build(), ana.clone()).expect("valid template");
+    assert!(missions.sign_off_template(&v1, &rev1, TemplateVerdict::Approve, T0).unwrap_err().contains("not awaiting"));
+    assert_eq!(missions.review_template(&v1), Ok(TemplateState::AwaitingSignoff));
+    assert_eq!(searchable(&missions), 0);
+    assert!(missions.assign_mission(&v1.mission, ben.clone(), T0).is_err(), "not assignable before approval");
+    assert_eq!(missions.templates_awaiting_signoff(&rev2).len(), 1);
+    assert!(missions.templates_awaiting_signoff(&ana).is_empty());
+
+    // 3. Refused sign-offs: outsiders, and the submitter, cannot approve.
+    assert!(missions.sign_off_template(&v1, &cy, TemplateVerdict::Approve, T0).unwrap_err().contains("does not review"));
+    let self_review = TemplateReviewPolicy { reviewers: [ana.clone()].into(), required_signoffs: 1 };
+    let mut own = MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new())
+        .with_template_review_policy(self_review);
+    let own_id = own.submit_template(cleanup.clone().build(), ana.clone()).expect("valid template");
+    own.review_template(&own_id).expect("draft");
+    assert!(own.sign_off_template(&own_id, &ana, TemplateVerdict::Approve, T0).unwrap_err().contains("cannot sign"));
+    assert!(missions.template_submission(&v1.mission).unwrap().signoffs.is_empty());
+
+    assert_eq!(missions.sign_off_template(&v1, &rev1, TemplateVerdict::Approve, T0), Ok(TemplateState::Approved));
+    assert_eq!(missions.template_version(&v1.mission), Some(1));
+    assert_eq!(searchable(&missions), 1);
+    let first = missions.assign_mission(&v1.mission, ben.clone(), T0).expect("assignable once approved");
+    assert_eq!(first.template_version, 1);
+
+    // 4. Version pinning: an edit goes back to review, the open assignment keeps v1.
+    let edited = cleanup.clone().description("Remove litter and invasive plants along the creek bank.").build();
+    assert!(missions.submit_template(edited.clone(), ben.clone()).unwrap_err().contains("Only"));
+    let v2 = missions.submit_template(edited, ana.clone()).expect("valid edit");
+    assert_eq!(v2, TemplateId { mission: v1.mission.clone(), version: 2 });
+    assert_eq!(missions.template_version(&v2.mission), None);
+    assert_eq!(searchable(&missions), 0);
+    assert!(missions.assign_mission(&v2.mission, cy.clone(), T0).is_err(), "back in review");
+    assert!(missions.review_template(&v1).unwrap_err().contains("superseded"));
+    let open = missions.active_assignments().next().expect("still open");
+    assert_eq!((open.template_version, open.mission.description.as_str()), (1, "Remove litter along the creek bank."));
+
+    // 5. A rejection keeps the edit out; the reviewer's reason is attached.
+    missions.review_template(&v2).expect("draft");
+    let reject = TemplateVerdict::Reject { reason: "Invasive removal needs a permit".into() };
+    let state = missions.sign_off_template(&v2, &rev2, reject, T0 + 1).unwrap();
+    assert_eq!(state, TemplateState::Rejected { reasons: vec![format!("{rev2}: Invasive removal needs a permit")] });
+    let v3 = missions.submit_template(cleanup.build(), ana.clone()).expect("valid edit");
+    missions.review_template(&v3).expect("draft");
+    missions.sign_off_template(&v3, &rev2, TemplateVerdict::Approve, T0 + 2).expect("approved");
+    let second = missions.assign_mission(&v3.mission, cy, T0 + 2).expect("assignable again");
+    assert_eq!(second.template_version, 3);
+    println!("{}", serde_json::to_string_pretty(missions.template_submission(&v3.mission).unwrap()).expect("json"));
+}
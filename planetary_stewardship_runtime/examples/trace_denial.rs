@@ -0,0 +1,112 @@
+// path: planetary_stewardship_runtime/examples/trace_denial.rs
+
+//! Example: capturing decision-path tracing on attestation denials.
+//! - Installs a collecting `tracing-subscriber` layer
+//! - Drives a consent-missing denial and a SAEP denial through `PlanetaryLedger`
+//! - Asserts the key events carry the shared field names
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use planetary_stewardship_runtime::{
+    ConsentRegistry, Did, ImpactMetrics, PlanetaryLedger, SaepConfig, SaepEngine,
+};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+#[derive(Debug, Clone)]
+struct Captured {
+    span: Option<&'static str>,
+    fields: BTreeMap<String, String>,
+}
+
+impl Captured {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.fields.get(key).map(String::as_str)
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+#[derive(Clone, Default)]
+struct CollectingLayer {
+    events: Arc<Mutex<Vec<Captured>>>,
+}
+
+impl<S> Layer<S> for CollectingLayer
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = BTreeMap::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        let span = ctx.event_span(event).map(|s| s.name());
+        self.events.lock().unwrap().push(Captured { span, fields });
+    }
+}
+
+fn metrics() -> ImpactMetrics {
+    ImpactMetrics {
+        co2eq_reduced: 1.2,
+        biodiversity_index_delta: 0.0,
+        biodiversity_detail: None,
+        restored_area_m2: 0.0,
+        avoided_emissions_co2eq: 1.2,
+        custom: Default::default(),
+    }
+}
+
+fn main() {
+    let layer = CollectingLayer::default();
+    let events = layer.events.clone();
+    let subscriber = tracing_subscriber::registry().with(layer);
+
+    tracing::subscriber::with_default(subscriber, || {
+        let mut ledger = PlanetaryLedger::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new());
+        let actor = Did::new("did:psv:steward:kofi").expect("valid DID");
+
+        // No consent record on file.
+        let err = ledger
+            .issue_attestation(actor.clone(), None, "Creek cleanup".into(), metrics(), "ipfs://evidence", vec![], 0)
+            .unwrap_err();
+        println!("consent path: {err}");
+
+        // SAEP non-harm rule fires before consent is checked.
+        let err = ledger
+            .issue_attestation(actor, None, "Coercive patrol logging".into(), metrics(), "ipfs://evidence", vec![], 0)
+            .unwrap_err();
+        println!("saep path:    {err}");
+    });
+
+    let events = events.lock().unwrap();
+    for e in events.iter() {
+        println!("{:<28} {:?}", e.span.unwrap_or("-"), e.fields);
+    }
+
+    let rejected: Vec<&Captured> = events
+        .iter()
+        .filter(|e| e.get("message") == Some("attestation rejected"))
+        .collect();
+    assert_eq!(rejected.len(), 2, "one rejection event per denial");
+    assert!(rejected.iter().all(|e| e.span == Some("plga.issue_attestation")));
+    assert!(rejected.iter().all(|e| e.get("decision") == Some("deny") && e.fields.contains_key("duration_us")));
+    assert_eq!(rejected[0].get("reason"), Some("consent_missing"));
+    assert_eq!(rejected[1].get("reason"), Some("saep"));
+
+    assert!(events
+        .iter()
+        .any(|e| e.span == Some("saep.evaluate") && e.get("rule") == Some("non_harm")));
+    println!("Denial-path tracing events present.");
+}
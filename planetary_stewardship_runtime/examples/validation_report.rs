@@ -0,0 +1,155 @@
+// path: planetary_stewardship_runtime/examples/validation_report.rs
+
+//! Example: dry-run validation before submitting.
+//! - A request failing SAEP, consent and the rollback-plan rule reports all three problems,
+//!   while the real issuance stops at the first
+//! - Once fixed, the report is clean and lists what SAEP will attach
+//! - Validation publishes nothing: the intent log is untouched until the real call
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::{
+    ActionRef, AttestationRequest, ConsentPromptDescriptor, ConsentRecord, ConsentRegistry, Did, EthicsContext, EvidenceArtifact,
+    ImpactMetrics, MicroMissionsEngine, MissionId, MissionTemplate, ModuleMetrics, PlanId, PlanetaryLedger,
+    RollbackPlan, SaepConfig, SaepEngine, SaepRefs, StewardModule, ValidationCode, ValidationCondition,
+    MME_ASSIGNMENT_PURPOSE,
+    PLGA_ATTESTATION_PURPOSE,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn request(actor: &Did, description: &str, refs: SaepRefs) -> AttestationRequest {
+    AttestationRequest {
+        actor_did: actor.clone(),
+        mission_id: None,
+        description: description.into(),
+        module: StewardModule::PLGA,
+        impact_metrics: ImpactMetrics { co2eq_reduced: 0.6, restored_area_m2: 40.0, ..ImpactMetrics::default() },
+        module_metrics: ModuleMetrics::new(),
+        evidence: vec![EvidenceArtifact::from_uri("ipfs://hedgerow")],
+        verifier_dids: vec![],
+        timestamp_ms: T0,
+        refs,
+        capture_window: None,
+        witness_endorsement: None,
+    }
+}
+
+fn issue(ledger: &mut PlanetaryLedger, r: AttestationRequest) -> Result<String, String> {
+    ledger
+        .issue_attestation_with_refs(
+            r.actor_did,
+            r.mission_id,
+            r.description,
+            r.impact_metrics,
+            r.evidence,
+            r.verifier_dids,
+            r.timestamp_ms,
+            r.refs,
+        )
+        .map(|a| a.id.0)
+}
+
+fn main() {
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(SaepConfig::default()),
+        ConsentRegistry::new(),
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    );
+    let intent_entries = |ledger: &PlanetaryLedger| ledger.intent_log().lock().unwrap().entries().len();
+
+    // 1. Three independent problems: SAEP's commons rule, no consent, no rollback plan.
+    let bad = request(&ana, "Hedgerow planting for exclusive monetization", SaepRefs::default());
+    let report = ledger.validate_attestation(&bad);
+    println!("{}", serde_json::to_string_pretty(&report).expect("report json"));
+    let codes: Vec<ValidationCode> = report.issues.iter().map(|i| i.code).collect();
+    assert_eq!(codes, [ValidationCode::Saep, ValidationCode::ConsentMissing, ValidationCode::RollbackPlan]);
+    // The real path still fails on the first check alone.
+    let err = issue(&mut ledger, bad).expect_err("SAEP denies");
+    assert_eq!(err, report.issues[0].message);
+
+    // 2. Fix all three: honest description, consent to the prompt the report names, a plan.
+    let mut fixed = request(&ana, "Hedgerow planting", SaepRefs::default());
+    let consent = ledger.validate_attestation(&fixed).conditions.into_iter().find_map(|c| match c {
+        ValidationCondition::Consent { purpose, prompt_hash } => Some((purpose, prompt_hash)),
+        _ => None,
+    });
+    let (purpose, prompt_hash) = consent.expect("SAEP requires consent");
+    assert_eq!(purpose, PLGA_ATTESTATION_PURPOSE);
+    ledger.consent_mut().upsert_consent(ConsentRecord {
+        participant: ana.clone(),
+        module: StewardModule::PLGA,
+        mission: None,
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: Some(prompt_hash),
+    });
+    let reserved = ledger.reserve_attestation_id();
+    let plan = ledger
+        .register_rollback_plan(RollbackPlan {
+            id: PlanId("rollback:hedgerow".into()),
+            action_ref: ActionRef::Attestation(reserved),
+            steps: vec!["Revoke the attestation".into()],
+            responsible: ana.clone(),
+            created_ms: T0,
+        })
+        .expect("reserved id");
+    fixed.refs.rollback_plan_id = Some(plan.clone());
+
+    let report = ledger.validate_attestation(&fixed);
+    assert!(report.is_valid(), "{:?}", report.issues);
+    assert!(report.conditions.contains(&ValidationCondition::RollbackPlan { plan_id: Some(plan) }));
+    assert!(report.conditions.contains(&ValidationCondition::PublicIntentLog { intent_entry_id: None }));
+    assert_eq!(intent_entries(&ledger), 0, "validation publishes nothing");
+    issue(&mut ledger, fixed).expect("report was clean");
+    assert_eq!(intent_entries(&ledger), 1);
+
+    // 3. Assignments: an unknown template stops early; a known one reports the missing plan.
+    let mission = MissionId::new("creek-cleanup").expect("valid mission id");
+    let template = MissionTemplate {
+        id: mission.clone(),
+        title: "Creek cleanup".into(),
+        description: "Remove litter along the creek".into(),
+        difficulty: "S".into(),
+        expected_impact: serde_json::json!({}),
+        location_hint: "geo".into(),
+        required_skills: vec![],
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    };
+    let decision = SaepEngine::new(SaepConfig::default()).evaluate(&EthicsContext {
+        actor: ana.clone(),
+        affected_parties: vec![],
+        module: StewardModule::MME,
+        description: template.description.clone(),
+        estimated_impact: template.expected_impact.clone(),
+    });
+    let prompt = ConsentPromptDescriptor::for_action(StewardModule::MME, Some(&template), MME_ASSIGNMENT_PURPOSE, &decision);
+    let mut consents = ConsentRegistry::new();
+    consents.upsert_consent(ConsentRecord {
+        participant: ana.clone(),
+        module: StewardModule::MME,
+        mission: Some(mission.clone()),
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: Some(prompt.canonical_hash()),
+    });
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), consents);
+    let unknown = missions.validate_assignment(&mission, &ana, T0);
+    assert_eq!(unknown.issues.len(), 1);
+    assert!(unknown.has(ValidationCode::UnknownMission));
+
+    missions.add_template(template);
+    let report = missions.validate_assignment(&mission, &ana, T0);
+    let codes: Vec<ValidationCode> = report.issues.iter().map(|i| i.code).collect();
+    assert_eq!(codes, [ValidationCode::RollbackPlan]);
+    assert_eq!(missions.assign_mission(&mission, ana, T0).expect_err("no plan"), report.issues[0].message);
+}
@@ -0,0 +1,187 @@
+// path: planetary_stewardship_runtime/examples/verification_queue.rs
+
+//! Example: three verifiers reviewing claims under a 2-of-3 quorum (run with `--features test-util`).
+//! - A split panel: one rejects, the other two approve, and the claim issues with the approvers
+//! - A claim sent back with notes, revised by its actor and approved on the second round
+//! - A claim two verifiers reject, which can no longer reach quorum
+//! - A verifier approving after the deadline, and a sweep expiring what nobody reviewed
+//! - Every refused call leaves the item as it was; signatures are a toy keyed SHA-256
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::{did, AttestationBuilder, LedgerBuilder};
+use planetary_stewardship_runtime::witness::statement_hash;
+use planetary_stewardship_runtime::{
+    Did, KeyError, KeyPurpose, KeyResolver, PendingState, PublicKey, ReviewError, VerificationPolicy,
+    VerifierVerdict,
+};
+use sha2::{Digest, Sha256};
+use steward_runtime_support::FixedClock;
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+/// Each DID's secret; a signature is SHA-256 over secret and message.
+struct ToyKeys(BTreeMap<Did, &'static str>);
+
+fn sign(secret: &str, message: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(secret.as_bytes());
+    hasher.update(message);
+    format!("{:x}", hasher.finalize())
+}
+
+impl KeyResolver for ToyKeys {
+    fn resolve(&self, did: &Did, key_id: &str, purpose: KeyPurpose, _as_of_ms: u64) -> Result<PublicKey, KeyError> {
+        let secret = self.0.get(did).ok_or(KeyError::UnknownKey { did: did.clone(), key_id: key_id.into() })?;
+        Ok(PublicKey { did: did.clone(), key_id: key_id.into(), purpose, material: secret.to_string() })
+    }
+
+    fn check_signature(&self, key: &PublicKey, message: &[u8], signature: &str) -> bool {
+        sign(&key.material, message) == signature
+    }
+}
+
+fn approve(secret: &str, hash: &str) -> VerifierVerdict {
+    VerifierVerdict::Approve { signature: sign(secret, hash.as_bytes()), key_id: String::new() }
+}
+
+fn reject(reason: &str) -> VerifierVerdict {
+    VerifierVerdict::Reject { reason: reason.into() }
+}
+
+fn main() {
+    let (ana, ben) = (did("did:psv:steward:ana"), did("did:psv:steward:ben"));
+    let (city, coop, uni) = (did("did:psv:verifier:city"), did("did:psv:verifier:coop"), did("did:psv:verifier:uni"));
+    let keys = ToyKeys(BTreeMap::from([(city.clone(), "city"), (coop.clone(), "coop"), (uni.clone(), "uni")]));
+    let clock = Arc::new(FixedClock::new(T0));
+    let mut ledger = LedgerBuilder::new()
+        .seed(7)
+        .clock(clock.clone())
+        .without_rollback_plans()
+        .consenting(&ana)
+        .consenting(&ben)
+        .build()
+        .with_key_resolver(Arc::new(keys))
+        .with_verification_policy(VerificationPolicy { quorum: 2, review_window_ms: 3 * DAY_MS });
+    let claim = |actor: &Did, description: &str| {
+        AttestationBuilder::new(actor)
+            .description(description)
+            .co2eq_reduced(4.0)
+            .timestamp_ms(T0)
+            .verifier(&city)
+            .verifier(&coop)
+            .verifier(&uni)
+            .request()
+    };
+
+    // 1. Submission: refused without enough distinct verifiers for the quorum.
+    let mut thin = claim(&ana, "Hedgerow planting");
+    thin.verifier_dids.truncate(1);
+    assert!(ledger.submit_for_verification(thin).unwrap_err().contains("quorum is 2"));
+    let mut twice = claim(&ana, "Hedgerow planting");
+    twice.verifier_dids[1] = city.clone();
+    assert!(ledger.submit_for_verification(twice).unwrap_err().contains("twice"));
+    let attestations_before = ledger.attestations().count();
+
+    // 2. A split panel: nothing issues until the second approval.
+    let split = ledger.submit_for_verification(claim(&ana, "Creek bank planting")).expect("submitted");
+    let hash = ledger.pending_verification(&split).expect("pending").statement_hash.clone();
+    assert_eq!(hash, statement_hash(&ledger.pending_verification(&split).unwrap().attestation_draft));
+    assert_eq!(ledger.verification_inbox(&coop).len(), 1);
+    assert_eq!(ledger.review(&split, &city, approve("city", &hash)), Ok(PendingState::AwaitingReview));
+    assert!(ledger.verification_inbox(&city).is_empty(), "reviewed items leave the inbox");
+    assert_eq!(ledger.review(&split, &coop, reject("Photos predate the planting")), Ok(PendingState::AwaitingReview));
+    assert_eq!(ledger.attestations().count(), attestations_before);
+
+    // 3. Refused reviews change nothing.
+    let outsider = did("did:psv:verifier:outsider");
+    assert_eq!(
+        ledger.review(&split, &outsider, approve("uni", &hash)),
+        Err(ReviewError::NotRequested { verifier: outsider.clone() })
+    );
+    assert_eq!(
+        ledger.review(&split, &city, approve("city", &hash)),
+        Err(ReviewError::AlreadyReviewed { verifier: city.clone() })
+    );
+    assert_eq!(
+        ledger.review(&split, &uni, approve("coop", &hash)),
+        Err(ReviewError::BadSignature { verifier: uni.clone() })
+    );
+    assert_eq!(ledger.pending_verification(&split).unwrap().reviews.len(), 2);
+
+    // 4. The second approval issues the claim, verified by the two approvers.
+    let PendingState::Finalized { attestation_id } = ledger.review(&split, &uni, approve("uni", &hash)).unwrap() else {
+        panic!("quorum reached");
+    };
+    let issued = ledger.attestations().find(|a| a.id == attestation_id).expect("issued");
+    assert_eq!(issued.verifier_dids, vec![city.clone(), uni.clone()]);
+    assert!(matches!(
+        ledger.review(&split, &uni, approve("uni", &hash)),
+        Err(ReviewError::WrongState { state: PendingState::Finalized { .. } })
+    ));
+
+    // 5. Changes requested: back with the actor, then revised and approved.
+    let revise = ledger.submit_for_verification(claim(&ben, "Meadow restoration")).expect("submitted");
+    let notes = "Add the seed mix invoice";
+    let back = ledger.review(&revise, &coop, VerifierVerdict::RequestChanges { notes: notes.into() }).unwrap();
+    assert_eq!(back, PendingState::ChangesRequested { by: coop.clone(), notes: notes.into() });
+    assert!(ledger.verification_inbox(&city).is_empty(), "nothing to review while the actor revises");
+    let hash = ledger.pending_verification(&revise).unwrap().statement_hash.clone();
+    assert!(matches!(ledger.review(&revise, &city, approve("city", &hash)), Err(ReviewError::WrongState { .. })));
+    let revised = claim(&ben, "Meadow restoration, seed mix invoice attached");
+    assert_eq!(
+        ledger.resubmit_verification(&revise, &ana, revised.clone()),
+        Err(ReviewError::NotActor { actor: ben.clone() })
+    );
+    clock.advance(DAY_MS);
+    ledger.resubmit_verification(&revise, &ben, revised).expect("resubmitted");
+    let pending = ledger.pending_verification(&revise).unwrap();
+    assert!(pending.reviews.is_empty());
+    assert_eq!(pending.deadline_ms, T0 + 4 * DAY_MS, "the window restarts");
+    let new_hash = pending.statement_hash.clone();
+    assert_ne!(new_hash, hash);
+    assert_eq!(
+        ledger.review(&revise, &city, approve("city", &hash)),
+        Err(ReviewError::BadSignature { verifier: city.clone() }),
+        "approvals sign the current draft"
+    );
+    ledger.review(&revise, &city, approve("city", &new_hash)).unwrap();
+    let done = ledger.review(&revise, &coop, approve("coop", &new_hash)).unwrap();
+    assert!(matches!(done, PendingState::Finalized { .. }));
+
+    // 6. Two rejections of three leave quorum out of reach.
+    let doomed = ledger.submit_for_verification(claim(&ana, "Wetland survey")).expect("submitted");
+    assert_eq!(ledger.review(&doomed, &uni, reject("No survey data")), Ok(PendingState::AwaitingReview));
+    let state = ledger.review(&doomed, &city, reject("Site is outside the mission area")).unwrap();
+    let reasons = vec![
+        format!("{uni}: No survey data"),
+        format!("{city}: Site is outside the mission area"),
+    ];
+    assert_eq!(state, PendingState::Rejected { reasons });
+    assert!(ledger.verification_inbox(&coop).is_empty());
+
+    // 7. Deadlines: a late approval is refused and expires the item; a sweep expires the rest.
+    let late = ledger.submit_for_verification(claim(&ana, "Dune fencing")).expect("submitted");
+    let forgotten = ledger.submit_for_verification(claim(&ben, "Litter survey")).expect("submitted");
+    let inbox: Vec<_> = ledger.verification_inbox(&uni).into_iter().map(|p| p.id.clone()).collect();
+    assert_eq!(inbox.len(), 2);
+    let hash = ledger.pending_verification(&late).unwrap().statement_hash.clone();
+    ledger.review(&late, &city, approve("city", &hash)).unwrap();
+    clock.advance(3 * DAY_MS);
+    assert!(ledger.verification_inbox(&uni).is_empty(), "past their deadline");
+    assert_eq!(
+        ledger.review(&late, &coop, approve("coop", &hash)),
+        Err(ReviewError::PastDeadline { deadline_ms: T0 + 4 * DAY_MS, at_ms: T0 + 4 * DAY_MS })
+    );
+    assert_eq!(ledger.pending_verification(&late).unwrap().state, PendingState::Expired);
+    assert_eq!(ledger.expire_verifications(), vec![forgotten.clone()]);
+    assert!(ledger.expire_verifications().is_empty());
+
+    // 8. Each actor can follow their claims; only the two quorum approvals issued anything.
+    let states: Vec<_> = ledger.verifications_submitted_by(&ben).into_iter().map(|p| p.state.clone()).collect();
+    assert_eq!(states.len(), 2);
+    assert!(states.contains(&PendingState::Expired));
+    assert_eq!(ledger.attestations().count(), attestations_before + 2);
+}
@@ -0,0 +1,113 @@
+// path: planetary_stewardship_runtime/examples/verifier_conflicts.rs
+
+//! Example: verifiers declaring conflicts of interest (run with `--features test-util`).
+//! - Under a policy that forbids family, flags same-guild and (by default) does not count an
+//!   employer, each treatment shows up on the issued attestation or refuses it
+//! - A declaration that has expired no longer applies; one a verifier makes about themselves,
+//!   or that expires before it starts, is refused
+//! - A conflict found after the fact is declared into the past: nothing issued changes, and
+//!   the report lists the attestations whose quorum no longer holds
+
+use planetary_stewardship_runtime::builders::{did, AttestationBuilder, LedgerBuilder, FIXTURE_START_MS};
+use planetary_stewardship_runtime::{
+    ConflictDeclaration, ConflictPolicy, ConflictTreatment, Did, RelationshipKind, ValidationCode, VerificationPolicy,
+    VerifierAuditAction, VerifierPolicy,
+};
+
+const T0: u64 = FIXTURE_START_MS;
+const DAY_MS: u64 = 86_400_000;
+
+fn declaration(verifier: &Did, actor: &Did, relationship: RelationshipKind, declared_ms: u64) -> ConflictDeclaration {
+    let (verifier, actor) = (verifier.clone(), actor.clone());
+    ConflictDeclaration { verifier, actor, relationship, declared_ms, expires_ms: None }
+}
+
+fn main() -> Result<(), String> {
+    let [ana, kofi, boss, cousin, guildmate, treasurer, city, ops] =
+        ["ana", "kofi", "boss", "cousin", "guildmate", "treasurer", "city", "ops"]
+            .map(|n| did(&format!("did:psv:steward:{n}")));
+    let policy = VerifierPolicy {
+        conflicts: ConflictPolicy::default()
+            .with_treatment(RelationshipKind::Family, ConflictTreatment::Forbidden)
+            .with_treatment(RelationshipKind::SameGuild, ConflictTreatment::CountedFlagged),
+        ..VerifierPolicy::default()
+    };
+    let mut ledger = LedgerBuilder::new()
+        .seed(21)
+        .consenting(&ana)
+        .consenting(&kofi)
+        .build()
+        .with_verifier_policy(policy)
+        .with_verification_policy(VerificationPolicy { quorum: 2, ..VerificationPolicy::default() });
+    let claim = |actor: &Did, verifiers: &[&Did], at_ms: u64| {
+        verifiers.iter().fold(AttestationBuilder::new(actor).timestamp_ms(at_ms), |b, v| b.verifier(v))
+    };
+
+    // 1. Kofi's attestations from before anyone declared anything.
+    let early = claim(&kofi, &[&treasurer], T0).seed(1).issue_on(&mut ledger)?;
+    let backed = claim(&kofi, &[&treasurer, &city, &guildmate], T0 + DAY_MS).seed(2).issue_on(&mut ledger)?;
+    let thin = claim(&kofi, &[&treasurer, &city], T0 + 2 * DAY_MS).seed(3).issue_on(&mut ledger)?;
+    assert!(thin.non_counting_verifiers.is_empty() && thin.verifier_conflicts.is_empty());
+
+    // 2. Each verifier declares their relationship with ana; the employment ends after ten days.
+    let employed_until = Some(T0 + 10 * DAY_MS);
+    let employment =
+        ConflictDeclaration { expires_ms: employed_until, ..declaration(&boss, &ana, RelationshipKind::Employer, T0) };
+    for (d, by) in [
+        (employment, &boss),
+        (declaration(&cousin, &ana, RelationshipKind::Family, T0), &cousin),
+        (declaration(&guildmate, &ana, RelationshipKind::SameGuild, T0), &guildmate),
+    ] {
+        let report = ledger.declare_conflict(d, by.clone(), T0)?;
+        assert!(report.quorum_no_longer_holds.is_empty(), "ana has nothing issued yet");
+    }
+    let own = ledger.declare_conflict(declaration(&city, &city, RelationshipKind::Other, T0), city.clone(), T0);
+    assert!(own.is_err(), "a verifier cannot conflict with themselves");
+    let backwards =
+        ConflictDeclaration { expires_ms: Some(T0), ..declaration(&city, &ana, RelationshipKind::Other, T0 + DAY_MS) };
+    assert!(ledger.declare_conflict(backwards, city.clone(), T0).is_err());
+
+    // 3. NotCounted: the employer stays listed but does not count.
+    let employed = claim(&ana, &[&boss, &city], T0 + DAY_MS).seed(4).issue_on(&mut ledger)?;
+    assert_eq!(employed.non_counting_verifiers, vec![boss.clone()]);
+    assert_eq!(employed.verifier_conflicts.len(), 1);
+    assert_eq!(employed.verifier_conflicts[0].treatment, ConflictTreatment::NotCounted);
+
+    // 4. CountedFlagged: the guildmate counts, and the conflict is on record.
+    let guild = claim(&ana, &[&guildmate, &city], T0 + DAY_MS).seed(5).issue_on(&mut ledger)?;
+    assert!(guild.non_counting_verifiers.is_empty());
+    assert_eq!(guild.verifier_conflicts[0].relationship, RelationshipKind::SameGuild);
+    assert_eq!(guild.verifier_conflicts[0].treatment, ConflictTreatment::CountedFlagged);
+
+    // 5. Forbidden: a cousin's signature refuses the claim, and validation says why.
+    let family = claim(&ana, &[&cousin, &city], T0 + DAY_MS).seed(6);
+    assert!(ledger.validate_attestation(&family.request()).has(ValidationCode::VerifierConflict));
+    let refused = family.issue_on(&mut ledger).expect_err("forbidden");
+    assert!(refused.contains("may not verify"), "{refused}");
+
+    // 6. After the employment ends, the former employer counts again.
+    let later = claim(&ana, &[&boss, &city], T0 + 11 * DAY_MS).seed(7).issue_on(&mut ledger)?;
+    assert!(later.non_counting_verifiers.is_empty() && later.verifier_conflicts.is_empty());
+
+    // 7. Kofi's treasurer turns out to have lent money since the second day. Quorum is two:
+    //    `thin` drops to one counting verifier, `backed` keeps two, `early` predates the loan.
+    let loan = declaration(&treasurer, &kofi, RelationshipKind::Financial, T0 + DAY_MS);
+    let report = ledger.declare_conflict(loan, ops.clone(), T0 + 12 * DAY_MS)?;
+    assert_eq!(report.quorum, 2);
+    assert_eq!(report.quorum_no_longer_holds.len(), 1);
+    assert_eq!(report.quorum_no_longer_holds[0].attestation_id, thin.id);
+    assert_eq!(report.quorum_no_longer_holds[0].counting_verifiers, 1);
+    let listed: Vec<_> = report.quorum_no_longer_holds.iter().map(|r| &r.attestation_id).collect();
+    assert!(!listed.contains(&&backed.id) && !listed.contains(&&early.id));
+    let unchanged = ledger.get_attestations_for_actor(&kofi);
+    assert!(unchanged.iter().all(|a| a.non_counting_verifiers.is_empty() && a.verifier_conflicts.is_empty()));
+
+    // 8. Every declaration is audited, with who made it.
+    let audit = ledger.verifier_registry().audit_trail();
+    assert_eq!(audit.len(), 4);
+    let last = audit.last().expect("the loan");
+    assert_eq!(last.action, VerifierAuditAction::DeclareConflict { relationship: RelationshipKind::Financial });
+    assert_eq!(last.by, Some(ops.clone()));
+    println!("{}", serde_json::to_string_pretty(&report).expect("json"));
+    Ok(())
+}
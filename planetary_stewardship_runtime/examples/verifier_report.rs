@@ -0,0 +1,120 @@
+// path: planetary_stewardship_runtime/examples/verifier_report.rs
+
+//! Example: verifier independence report and downgrades (run with `--features test-util`).
+//! - One verifier signs nearly all of one actor's attestations; two others always sign together
+//! - The report flags both patterns; auto-downgrade stops the first verifier counting for that actor
+//! - Earlier attestations are untouched; restoring the verifier, an admin action one admin
+//!   proposes and another confirms, is audited like the downgrade
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::{
+    AdminAction, AdminEffect, AdminProposal, ConsentRecord, ConsentRegistry, Did, DualControl, DualControlPolicy,
+    ImpactMetrics, PlanetaryLedger, SaepEngine, StewardModule, VerifierAuditAction, VerifierFlag,
+    VerifierPolicy,
+};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 86_400_000;
+
+fn did(s: &str) -> Did {
+    Did::new(s).expect("valid DID")
+}
+
+fn main() {
+    let ana = did("did:psv:steward:ana");
+    let kofi = did("did:psv:steward:kofi");
+    let close = did("did:psv:verifier:close-friend");
+    let pair_a = did("did:psv:verifier:coop-a");
+    let pair_b = did("did:psv:verifier:coop-b");
+    let city = did("did:psv:verifier:city");
+
+    let mut consent = ConsentRegistry::new();
+    for actor in [&ana, &kofi] {
+        consent.upsert_consent(ConsentRecord {
+            participant: actor.clone(),
+            module: StewardModule::PLGA,
+            mission: None,
+            consent_given: true,
+            timestamp_ms: T0,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        });
+    }
+    let config = saep_without_rollback_plans();
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_verifier_policy(VerifierPolicy { auto_downgrade: true, ..VerifierPolicy::default() });
+
+    let issue = |ledger: &mut PlanetaryLedger, actor: &Did, verifiers: Vec<Did>, at_ms: u64| {
+        let metrics = ImpactMetrics { restored_area_m2: 20.0, ..ImpactMetrics::default() };
+        ledger
+            .issue_attestation(actor.clone(), None, "Pollinator strip".into(), metrics, format!("ipfs://strip-{at_ms}"), verifiers, at_ms)
+            .expect("attestation issued")
+    };
+    // Twenty days: the close friend signs every one of ana's attestations but the last;
+    // the co-op verifiers sign kofi's, always as a pair; the city signs for both now and then.
+    for day in 0..20 {
+        let at = T0 + day * DAY_MS;
+        let mut for_ana = if day < 19 { vec![close.clone()] } else { vec![city.clone()] };
+        if day % 5 == 0 {
+            for_ana.push(city.clone());
+        }
+        issue(&mut ledger, &ana, for_ana, at);
+        let mut for_kofi = vec![pair_a.clone(), pair_b.clone()];
+        if day % 7 == 0 {
+            for_kofi.push(city.clone());
+        }
+        issue(&mut ledger, &kofi, for_kofi, at + 1);
+    }
+
+    let report = ledger.verifier_report((T0, T0 + 20 * DAY_MS));
+    println!("{}", serde_json::to_string_pretty(&report).expect("report json"));
+    let summary = report.verifiers.iter().find(|s| s.verifier == close).expect("close friend verified");
+    assert_eq!((summary.attestations_verified, summary.distinct_actors), (19, 1));
+    assert_eq!(summary.herfindahl, 1.0);
+    assert!(report.flags.contains(&VerifierFlag::ActorCoverage { verifier: close.clone(), actor: ana.clone(), coverage: 0.95 }));
+    assert!(report.flags.iter().any(|f| matches!(f, VerifierFlag::AlwaysTogether { a, b, .. } if a == &pair_a && b == &pair_b)));
+    assert!(!report.flags.iter().any(|f| f.relationship().is_some_and(|(v, _)| v == &city)));
+
+    // A narrower window only sums the days in it.
+    let last_week = ledger.verifier_report((T0 + 13 * DAY_MS, T0 + 20 * DAY_MS));
+    assert_eq!(last_week.verifiers.iter().find(|s| s.verifier == close).map(|s| s.attestations_verified), Some(6));
+
+    // Auto-downgrade: the flagged relationships stop counting going forward.
+    let now = T0 + 20 * DAY_MS;
+    let downgraded = ledger.apply_verifier_report(&report, now);
+    assert!(downgraded.iter().any(|d| d.verifier == close && d.actor == ana && d.by.is_none()));
+    let later = issue(&mut ledger, &ana, vec![close.clone(), city.clone()], now + 1);
+    assert_eq!(later.non_counting_verifiers, vec![close.clone()]);
+    assert_eq!(later.counting_verifiers().collect::<Vec<_>>(), vec![&city]);
+    // Other actors, and earlier attestations, are unaffected.
+    let for_kofi = issue(&mut ledger, &kofi, vec![close.clone()], now + 2);
+    assert!(for_kofi.non_counting_verifiers.is_empty());
+    assert!(ledger.get_attestations_for_actor(&ana)[0].non_counting_verifiers.is_empty());
+
+    // Reversible and audited.
+    let (ops, auditor) = (did("did:psv:steward:ops"), did("did:psv:steward:auditor"));
+    let mut admin = DualControl::new(DualControlPolicy {
+        admins: [ops.clone(), auditor.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    admin.bind(&ledger).expect("a new ledger is unbound");
+    let restore = AdminAction::RestoreVerifier { verifier: close.clone(), actor: ana.clone() };
+    let Ok(AdminProposal::Pending { action_id, .. }) = admin.propose(&mut ledger, restore, &ops, now + 3) else {
+        panic!("restoring a verifier waits for a second admin");
+    };
+    let effect = admin.confirm(&mut ledger, &action_id, &auditor, now + 3).expect("was downgraded");
+    assert_eq!(effect, AdminEffect::VerifierRestored);
+    assert!(issue(&mut ledger, &ana, vec![close.clone()], now + 4).non_counting_verifiers.is_empty());
+    let trail = ledger.verifier_registry().audit_trail();
+    assert!(matches!(trail.last().map(|e| (&e.action, &e.by)), Some((VerifierAuditAction::Restore, Some(by))) if by == &ops));
+    println!("audit entries: {}", trail.len());
+}
@@ -0,0 +1,65 @@
+// path: planetary_stewardship_runtime/examples/vote_receipts.rs
+
+//! Example: voters checking their quadratic votes were counted as cast.
+//! - `cast_vote` hands back a receipt that, without the voter's salt, says nothing of the vote
+//! - A voter who changes their vote is counted once; the earlier receipt fails inclusion and
+//!   the new one verifies against the ballots published at finalization
+//! - An anchored receipt only verifies against the root it was anchored to
+
+use planetary_stewardship_runtime::{
+    verify_receipt_inclusion, Did, GovernanceEngine, GovernanceProposal, GovernanceScope, QuadraticVote, SaepConfig,
+    SaepEngine,
+};
+
+const T0: u64 = 1_767_225_600_000;
+
+fn vote(voter: &Did, effective_weight: f64, support: bool) -> QuadraticVote {
+    QuadraticVote { voter: voter.clone(), effective_weight, support }
+}
+
+fn main() {
+    let mut governance = GovernanceEngine::new(SaepEngine::new(SaepConfig::default()));
+    let proposal = GovernanceProposal {
+        proposal_id: "creek-buffer".into(),
+        scope: GovernanceScope::EcosystemWide,
+        title: "Widen the creek buffer".into(),
+        description: "Extend the no-mow buffer along the creek to 10 m".into(),
+        payload: serde_json::json!({}),
+        can_introduce_restrictions: false,
+    };
+    let ana = Did::new("did:psv:steward:ana").expect("valid DID");
+    let ben = Did::new("did:psv:steward:ben").expect("valid DID");
+
+    // 1. Ana votes yes, then changes her mind; ben votes yes.
+    let first = governance.cast_vote(&proposal, vote(&ana, 3.0, true), "ana-salt-1", T0).expect("eligible");
+    let changed = governance.cast_vote(&proposal, vote(&ana, 3.0, false), "ana-salt-2", T0 + 60_000).expect("eligible");
+    let bens = governance.cast_vote(&proposal, vote(&ben, 2.0, true), "ben-salt", T0 + 90_000).expect("eligible");
+
+    // 2. The receipt alone does not open: only the voter's salt says who voted and how.
+    assert!(changed.opens_to(ana.as_str(), false, "ana-salt-2"));
+    assert!(!changed.opens_to(ana.as_str(), true, "ana-salt-2"));
+    assert!(!changed.opens_to(ana.as_str(), false, "guess"));
+
+    // 3. Finalizing counts each voter's latest vote and publishes the counted ballots.
+    let outcome = governance.finalize_votes(&proposal).expect("first finalization");
+    assert_eq!((outcome.total_support, outcome.total_opposition), (2.0, 3.0));
+    let counted = governance.counted_ballots("creek-buffer").expect("finalized").clone();
+    assert_eq!(counted.leaves.len(), 2);
+    assert!(verify_receipt_inclusion(&changed, &counted));
+    assert!(verify_receipt_inclusion(&bens, &counted));
+    assert!(!verify_receipt_inclusion(&first, &counted), "replaced by ana's second vote");
+
+    // 4. Finalized votes take no more ballots.
+    assert!(governance.cast_vote(&proposal, vote(&ben, 2.0, false), "ben-salt-2", T0 + 120_000).is_err());
+    assert!(governance.finalize_votes(&proposal).is_err());
+
+    // 5. Anchored to the published root, a receipt fails against any other tally.
+    let mut anchored = bens.clone();
+    anchored.anchor_to(&counted);
+    assert!(verify_receipt_inclusion(&anchored, &counted));
+    let mut rewritten = counted.clone();
+    rewritten.leaves.retain(|leaf| *leaf != changed.leaf());
+    rewritten.root = Some(rewritten.leaves[0].clone());
+    assert!(!verify_receipt_inclusion(&anchored, &rewritten));
+    println!("{}", serde_json::to_string_pretty(&counted).expect("ballots json"));
+}
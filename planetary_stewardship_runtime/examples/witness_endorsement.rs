@@ -0,0 +1,152 @@
+// path: planetary_stewardship_runtime/examples/witness_endorsement.rs
+
+//! Example: a city forester co-signing the large claims of a wetland restoration (run with `--features test-util`).
+//! - Thresholds of 10 tCO₂e and 10,000 m²: a claim exactly at them needs no witness,
+//!   one just above is refused until a listed witness endorses it
+//! - The dry run names the statement hash to sign and the witnesses who may sign it
+//! - An endorsement over another statement, by someone not listed, or with a bad
+//!   signature is refused; the accepted one is recorded on the attestation
+//! - Signatures here are a toy keyed SHA-256; a deployment resolves real DID keys
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::saep_without_rollback_plans;
+use planetary_stewardship_runtime::witness::statement_hash;
+use planetary_stewardship_runtime::{
+    AttestationRequest, ConsentRecord, ConsentRegistry, Did, EvidenceArtifact, ImpactMetrics, ImpactThresholdPolicy,
+    KeyError, KeyPurpose, KeyResolver, ModuleMetrics, PlanetaryLedger, PublicKey, ReasonCode, SaepEngine,
+    SaepRefs, StewardModule, ValidationCode, ValidationCondition, WitnessEndorsement,
+};
+use sha2::{Digest, Sha256};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+const T0: u64 = 1_767_225_600_000;
+
+/// Each DID's secret; a signature is SHA-256 over secret and message.
+struct ToyKeys(BTreeMap<Did, &'static str>);
+
+fn sign(secret: &str, message: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(secret.as_bytes());
+    hasher.update(message);
+    format!("{:x}", hasher.finalize())
+}
+
+impl KeyResolver for ToyKeys {
+    fn resolve(&self, did: &Did, key_id: &str, purpose: KeyPurpose, _as_of_ms: u64) -> Result<PublicKey, KeyError> {
+        let secret = self.0.get(did).ok_or(KeyError::UnknownKey { did: did.clone(), key_id: key_id.into() })?;
+        Ok(PublicKey { did: did.clone(), key_id: key_id.into(), purpose, material: secret.to_string() })
+    }
+
+    fn check_signature(&self, key: &PublicKey, message: &[u8], signature: &str) -> bool {
+        sign(&key.material, message) == signature
+    }
+}
+
+fn did(s: &str) -> Did {
+    Did::new(s).expect("valid DID")
+}
+
+fn request(actor: &Did, co2eq_reduced: f64, restored_area_m2: f64) -> AttestationRequest {
+    AttestationRequest {
+        actor_did: actor.clone(),
+        mission_id: None,
+        description: "Salt River wetland restoration, phase 2".into(),
+        module: StewardModule::PLGA,
+        impact_metrics: ImpactMetrics { co2eq_reduced, restored_area_m2, ..ImpactMetrics::default() },
+        module_metrics: ModuleMetrics::new(),
+        evidence: vec![EvidenceArtifact::new("ipfs://wetland-survey", "application/pdf")],
+        verifier_dids: vec![],
+        timestamp_ms: T0,
+        refs: SaepRefs::default(),
+        capture_window: None,
+        witness_endorsement: None,
+    }
+}
+
+fn endorse(witness: &Did, secret: &str, hash: &str) -> WitnessEndorsement {
+    WitnessEndorsement {
+        witness_did: witness.clone(),
+        signature: sign(secret, hash.as_bytes()),
+        statement_hash: hash.to_string(),
+        key_id: String::new(),
+    }
+}
+
+fn main() {
+    let crew = did("did:psv:steward:salt-river-crew");
+    let forester = did("did:psv:city:phoenix:forester");
+    let neighbour = did("did:psv:steward:neighbour");
+    let mut registry = ConsentRegistry::new();
+    registry.upsert_consent(ConsentRecord {
+        participant: crew.clone(),
+        module: StewardModule::PLGA,
+        mission: None,
+        consent_given: true,
+        timestamp_ms: T0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    });
+    let keys = ToyKeys([(forester.clone(), "forester-secret"), (neighbour.clone(), "neighbour-secret")].into());
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(saep_without_rollback_plans()),
+        registry,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    )
+    .with_impact_threshold_policy(ImpactThresholdPolicy {
+        co2eq_reduced_above: Some(10.0),
+        restored_area_m2_above: Some(10_000.0),
+        witnesses: [forester.clone()].into(),
+    })
+    .with_key_resolver(Arc::new(keys));
+
+    // 1. Exactly at both thresholds: no witness needed, issued as before.
+    let at = request(&crew, 10.0, 10_000.0);
+    let report = ledger.validate_attestation(&at);
+    assert!(report.is_valid(), "{:?}", report.issues);
+    assert!(!report.conditions.iter().any(|c| matches!(c, ValidationCondition::Witness { .. })));
+    let plain = ledger.issue_attestation_idempotent(at, None).expect("at threshold");
+    assert_eq!(plain.witness_endorsement, None);
+
+    // 2. Just above: the dry run names the statement and witnesses; issuance is refused.
+    let above = request(&crew, 10.5, 10_000.0);
+    let hash = statement_hash(&above);
+    let report = ledger.validate_attestation(&above);
+    assert!(report.has(ValidationCode::Witness));
+    let reason = report.issues[0].reason.clone().expect("coded");
+    assert_eq!(reason.code, ReasonCode::WitnessRequired);
+    assert!(report.conditions.contains(&ValidationCondition::Witness {
+        statement_hash: hash.clone(),
+        witnesses: vec![forester.clone()],
+    }));
+    let refused = ledger.issue_attestation_idempotent(above.clone(), None).expect_err("needs a witness");
+    assert_eq!(refused, reason.to_string());
+
+    // 3. An endorsement of another statement (a smaller area) does not carry over.
+    let other = statement_hash(&request(&crew, 10.5, 8_000.0));
+    let mut stale = above.clone();
+    stale.witness_endorsement = Some(endorse(&forester, "forester-secret", &other));
+    let report = ledger.validate_attestation(&stale);
+    assert_eq!(report.issues[0].reason.as_ref().map(|r| r.code), Some(ReasonCode::WitnessStatementMismatch));
+    ledger.issue_attestation_idempotent(stale, None).expect_err("endorses another statement");
+
+    // 4. Neither does one by a DID outside the witness set, nor a forged signature.
+    let mut unlisted = above.clone();
+    unlisted.witness_endorsement = Some(endorse(&neighbour, "neighbour-secret", &hash));
+    assert!(ledger.issue_attestation_idempotent(unlisted, None).unwrap_err().contains("is not a witness"));
+    let mut forged = above.clone();
+    forged.witness_endorsement = Some(endorse(&forester, "guessed", &hash));
+    assert!(ledger.issue_attestation_idempotent(forged, None).unwrap_err().contains("does not verify"));
+
+    // 5. The forester's endorsement of this statement is accepted and recorded.
+    let mut witnessed = above;
+    witnessed.witness_endorsement = Some(endorse(&forester, "forester-secret", &hash));
+    assert!(ledger.validate_attestation(&witnessed).is_valid());
+    let att = ledger.issue_attestation_idempotent(witnessed, None).expect("witnessed");
+    assert_eq!(att.witness_endorsement.as_ref().map(|e| &e.witness_did), Some(&forester));
+    assert_eq!(ledger.attestations().count(), 2);
+    println!("{} witnessed attestation {} for {hash}", forester, att.id.0);
+}
@@ -0,0 +1,290 @@
+// path: planetary_stewardship_runtime/src/abandonment.rs
+
+//! Abandonment patterns: `MicroMissionsEngine::abandonment_report` and the abandonment-aware
+//! recommendations of `recommend_for`.
+//! - The report counts archived assignments closed in a window, as completed, abandoned or
+//!   expired, overall and per template, difficulty, required skill and assignee cohort;
+//!   difficulty and skills are those of the template version the assignment was made under
+//! - An assignment is first-time when it is its assignee's earliest, open or archived;
+//!   every later one is returning
+//! - Per-assignee rows are there for coordinators following up, ordered by assignee id and
+//!   never by how much they abandoned; `aggregate_only` leaves them out, so the report names
+//!   no one
+//! - `recommend_for` hands its `MissionScorer` only an aggregate-only report and the
+//!   assignee's cohort, never anyone's own history, in keeping with SAEP's
+//!   `forbid_punitive_scoring`. Scoring only reorders: every template the skills match is
+//!   still recommended
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use crate::{
+    ArchivedAssignment, AssignmentFilter, AssignmentStatus, Did, MicroMissionsEngine, MissionId, MissionTemplate,
+    SkillMatch,
+};
+
+const DAY_MS: u64 = 86_400_000;
+
+/// Trailing abandonments in a row that make an assignee a repeat abandoner.
+pub const REPEAT_ABANDONMENT_STREAK: usize = 3;
+
+/// Which closings `abandonment_report` covers, and whether it may name assignees.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AbandonmentWindow {
+    /// Closed at or after this time.
+    #[serde(default)]
+    pub from_ms: Option<u64>,
+    /// Closed before this time.
+    #[serde(default)]
+    pub until_ms: Option<u64>,
+    /// Leave out `AbandonmentReport::assignees`.
+    #[serde(default)]
+    pub aggregate_only: bool,
+}
+
+impl AbandonmentWindow {
+    fn covers(&self, record: &ArchivedAssignment) -> bool {
+        self.from_ms.is_none_or(|t| record.closed_ts_ms >= t) && self.until_ms.is_none_or(|t| record.closed_ts_ms < t)
+    }
+}
+
+/// Whether an assignment was its assignee's first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AssigneeCohort {
+    FirstTime,
+    Returning,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AbandonmentCounts {
+    pub closed: usize,
+    pub abandoned: usize,
+    pub expired: usize,
+}
+
+impl AbandonmentCounts {
+    /// Abandoned share of closings; 0 with none.
+    pub fn rate(&self) -> f64 {
+        if self.closed == 0 {
+            0.0
+        } else {
+            self.abandoned as f64 / self.closed as f64
+        }
+    }
+
+    fn count(&mut self, status: AssignmentStatus) {
+        self.closed += 1;
+        self.abandoned += usize::from(status == AssignmentStatus::Abandoned);
+        self.expired += usize::from(status == AssignmentStatus::Expired);
+    }
+}
+
+/// One template's closings, in total and per cohort.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateAbandonment {
+    pub total: AbandonmentCounts,
+    pub first_time: AbandonmentCounts,
+    pub returning: AbandonmentCounts,
+}
+
+impl TemplateAbandonment {
+    pub fn cohort(&self, cohort: AssigneeCohort) -> &AbandonmentCounts {
+        match cohort {
+            AssigneeCohort::FirstTime => &self.first_time,
+            AssigneeCohort::Returning => &self.returning,
+        }
+    }
+
+    fn count(&mut self, cohort: AssigneeCohort, status: AssignmentStatus) {
+        self.total.count(status);
+        match cohort {
+            AssigneeCohort::FirstTime => self.first_time.count(status),
+            AssigneeCohort::Returning => self.returning.count(status),
+        }
+    }
+}
+
+/// One assignee's closings in the window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AssigneeAbandonment {
+    pub assignee: Did,
+    pub counts: AbandonmentCounts,
+    /// Abandonments in a row up to their latest closing.
+    pub trailing_abandonments: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AbandonmentReport {
+    pub window: AbandonmentWindow,
+    pub overall: AbandonmentCounts,
+    pub first_time: AbandonmentCounts,
+    pub returning: AbandonmentCounts,
+    pub by_template: BTreeMap<MissionId, TemplateAbandonment>,
+    /// By `MissionTemplate::difficulty`, as written.
+    pub by_difficulty: BTreeMap<String, AbandonmentCounts>,
+    /// By required skill; a template requiring several counts under each.
+    pub by_skill: BTreeMap<String, AbandonmentCounts>,
+    /// Assignees whose last `REPEAT_ABANDONMENT_STREAK` or more closings were abandonments.
+    pub repeat_abandoners: usize,
+    /// By assignee id; empty when `aggregate_only`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<AssigneeAbandonment>,
+}
+
+/// Ranks recommended templates for one assignee; `MicroMissionsEngine::with_mission_scorer`.
+pub trait MissionScorer: Send + Sync {
+    /// How long before `now_ms` closings count as recent.
+    fn lookback_ms(&self) -> u64;
+
+    /// The ranking score of `template`, whose required skills the assignee's earned
+    /// `skill_score` (as `recommend_for_skills`), for an assignee in `cohort`. `recent` is
+    /// aggregate-only.
+    fn score(
+        &self,
+        template: &MissionTemplate,
+        skill_score: f64,
+        cohort: AssigneeCohort,
+        recent: &AbandonmentReport,
+    ) -> f64;
+}
+
+/// Down-weights templates assignees like this one have recently abandoned: the skill score
+/// loses `max_penalty` times the template's abandonment rate in the assignee's cohort, or
+/// across cohorts while the cohort has too few closings to go by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefaultMissionScorer {
+    /// Share of the score lost to a template every resembling assignee abandoned; at most 0.9.
+    pub max_penalty: f64,
+    /// Closings a rate needs before it counts.
+    pub min_closed: usize,
+    pub lookback_ms: u64,
+}
+
+impl Default for DefaultMissionScorer {
+    fn default() -> Self {
+        Self { max_penalty: 0.5, min_closed: 3, lookback_ms: 90 * DAY_MS }
+    }
+}
+
+impl MissionScorer for DefaultMissionScorer {
+    fn lookback_ms(&self) -> u64 {
+        self.lookback_ms
+    }
+
+    fn score(
+        &self,
+        template: &MissionTemplate,
+        skill_score: f64,
+        cohort: AssigneeCohort,
+        recent: &AbandonmentReport,
+    ) -> f64 {
+        let Some(closings) = recent.by_template.get(&template.id) else {
+            return skill_score;
+        };
+        let rate = [closings.cohort(cohort), &closings.total]
+            .into_iter()
+            .find(|counts| counts.closed >= self.min_closed.max(1))
+            .map_or(0.0, AbandonmentCounts::rate);
+        skill_score * (1.0 - self.max_penalty.clamp(0.0, 0.9) * rate)
+    }
+}
+
+impl MicroMissionsEngine {
+    /// Rank `recommend_for` results with `scorer` instead of `DefaultMissionScorer`.
+    pub fn with_mission_scorer(mut self, scorer: Arc<dyn MissionScorer>) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Abandonment of the assignments closed in `window`; reads the whole archive, to tell
+    /// first-time assignments from returning ones.
+    pub fn abandonment_report(&self, window: &AbandonmentWindow) -> Result<AbandonmentReport, String> {
+        let mut first_assigned: HashMap<Did, u64> = HashMap::new();
+        let mut note_assigned = |assignee: &Did, at_ms: u64| {
+            let first = first_assigned.entry(assignee.clone()).or_insert(at_ms);
+            *first = (*first).min(at_ms);
+        };
+        for open in self.active_assignments.values() {
+            note_assigned(&open.assignee, open.assigned_ts_ms);
+        }
+        let mut closed = Vec::new();
+        for record in self.archive.query(&AssignmentFilter::default()) {
+            let record = record?;
+            note_assigned(&record.assignment.assignee, record.assignment.assigned_ts_ms);
+            if window.covers(&record) {
+                closed.push(record);
+            }
+        }
+        closed.sort_by_key(|r| r.closed_ts_ms);
+
+        let mut report = AbandonmentReport { window: window.clone(), ..AbandonmentReport::default() };
+        let mut by_assignee: BTreeMap<&Did, AssigneeAbandonment> = BTreeMap::new();
+        for record in &closed {
+            let (assignment, status) = (&record.assignment, record.status);
+            let cohort = match first_assigned.get(&assignment.assignee) {
+                Some(first) if *first < assignment.assigned_ts_ms => AssigneeCohort::Returning,
+                _ => AssigneeCohort::FirstTime,
+            };
+            report.overall.count(status);
+            match cohort {
+                AssigneeCohort::FirstTime => report.first_time.count(status),
+                AssigneeCohort::Returning => report.returning.count(status),
+            }
+            report.by_template.entry(assignment.mission.id.clone()).or_default().count(cohort, status);
+            report.by_difficulty.entry(assignment.mission.difficulty.clone()).or_default().count(status);
+            for skill in &assignment.mission.required_skills {
+                report.by_skill.entry(skill.clone()).or_default().count(status);
+            }
+            let row = by_assignee.entry(&assignment.assignee).or_insert_with(|| AssigneeAbandonment {
+                assignee: assignment.assignee.clone(),
+                counts: AbandonmentCounts::default(),
+                trailing_abandonments: 0,
+            });
+            row.counts.count(status);
+            row.trailing_abandonments =
+                if status == AssignmentStatus::Abandoned { row.trailing_abandonments + 1 } else { 0 };
+        }
+        report.repeat_abandoners =
+            by_assignee.values().filter(|row| row.trailing_abandonments >= REPEAT_ABANDONMENT_STREAK).count();
+        if !window.aggregate_only {
+            report.assignees = by_assignee.into_values().collect();
+        }
+        Ok(report)
+    }
+
+    /// `recommend_for_skills` for `assignee`, ranked by the engine's `MissionScorer` against
+    /// the abandonment of its lookback before `now_ms`. Every match is kept, however low it
+    /// scores; ties rank by mission id.
+    pub fn recommend_for(
+        &self,
+        assignee: &Did,
+        skills: &[String],
+        parent_credit: f64,
+        now_ms: u64,
+    ) -> Result<Vec<SkillMatch>, String> {
+        let window = AbandonmentWindow {
+            from_ms: Some(now_ms.saturating_sub(self.scorer.lookback_ms())),
+            until_ms: Some(now_ms),
+            aggregate_only: true,
+        };
+        let recent = self.abandonment_report(&window)?;
+        let own = AssignmentFilter { assignee: Some(assignee.clone()), ..AssignmentFilter::default() };
+        let returning = self.active_assignments.values().any(|a| &a.assignee == assignee)
+            || self.archive.query(&own).next().transpose()?.is_some();
+        let cohort = if returning { AssigneeCohort::Returning } else { AssigneeCohort::FirstTime };
+        let mut matches = self.recommend_for_skills(skills, parent_credit);
+        for m in &mut matches {
+            let template = self.templates.get(&m.mission_id).expect("matches come from templates");
+            m.score = self.scorer.score(template, m.score, cohort, &recent).max(0.0);
+        }
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.mission_id.cmp(&b.mission_id)));
+        Ok(matches)
+    }
+}
@@ -0,0 +1,265 @@
+// path: planetary_stewardship_runtime/src/assignment_archive.rs
+
+//! Active and archived mission assignments.
+//! - The engine keeps only open assignments in memory, keyed by `AssignmentKey`, so assigning
+//!   and looking up cost the same however much history has built up
+//! - `close_assignment` moves an assignment, as completed, abandoned or expired, into an
+//!   `AssignmentArchive`: `InMemoryArchive` by default, or `FileArchive`, an append-only
+//!   JSON-lines file read back one record at a time
+//! - `archive_query` filters by assignee, mission, status and closing time while streaming
+//! - `AssignmentState` persists the split: open assignments in full, the archive as its
+//!   records (in memory) or its path (file), never both copies of a record
+//! - Retention redacts open assignments only; archives are append-only
+//! - An open assignment is suspended, not closed, while its assignee's consent no longer
+//!   covers it; see `consent_observer`
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use crate::{AssignedMission, Did, MissionId};
+
+/// Identifies an assignment: a mission is assigned to someone at most once per millisecond.
+/// Orders by assignment time first.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AssignmentKey {
+    pub assigned_ts_ms: u64,
+    pub mission: MissionId,
+    pub assignee: Did,
+}
+
+impl AssignedMission {
+    pub fn key(&self) -> AssignmentKey {
+        AssignmentKey {
+            assigned_ts_ms: self.assigned_ts_ms,
+            mission: self.mission.id.clone(),
+            assignee: self.assignee.clone(),
+        }
+    }
+}
+
+impl fmt::Display for AssignmentKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} for {} at {}", self.mission, self.assignee, self.assigned_ts_ms)
+    }
+}
+
+/// How an assignment left the working set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AssignmentStatus {
+    Completed,
+    Abandoned,
+    Expired,
+}
+
+/// Where an open assignment stands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OpenAssignmentStatus {
+    #[default]
+    Active,
+    /// The assignee's consent stopped covering the assignment at `since_ms`; it resumes when
+    /// they consent again, or they abandon it.
+    SuspendedPendingConsent { since_ms: u64 },
+}
+
+impl OpenAssignmentStatus {
+    pub fn is_active(&self) -> bool {
+        *self == OpenAssignmentStatus::Active
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ArchivedAssignment {
+    pub assignment: AssignedMission,
+    pub status: AssignmentStatus,
+    pub closed_ts_ms: u64,
+}
+
+/// Which archived assignments `archive_query` yields; unset fields match everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AssignmentFilter {
+    #[serde(default)]
+    pub assignee: Option<Did>,
+    #[serde(default)]
+    pub mission: Option<MissionId>,
+    #[serde(default)]
+    pub status: Option<AssignmentStatus>,
+    /// Closed at or after this time.
+    #[serde(default)]
+    pub closed_from_ms: Option<u64>,
+    /// Closed before this time.
+    #[serde(default)]
+    pub closed_until_ms: Option<u64>,
+}
+
+impl AssignmentFilter {
+    pub fn matches(&self, record: &ArchivedAssignment) -> bool {
+        self.assignee.as_ref().is_none_or(|d| &record.assignment.assignee == d)
+            && self.mission.as_ref().is_none_or(|m| &record.assignment.mission.id == m)
+            && self.status.is_none_or(|s| record.status == s)
+            && self.closed_from_ms.is_none_or(|t| record.closed_ts_ms >= t)
+            && self.closed_until_ms.is_none_or(|t| record.closed_ts_ms < t)
+    }
+}
+
+/// Matching archived assignments in the order they were archived; a record that cannot be
+/// read is an `Err` item, and iteration goes on past it.
+pub type ArchiveIter<'a> = Box<dyn Iterator<Item = Result<ArchivedAssignment, String>> + 'a>;
+
+/// Where closed assignments go. Records are only ever appended.
+pub trait AssignmentArchive: Send + Sync {
+    fn append(&mut self, record: ArchivedAssignment) -> Result<(), String>;
+
+    fn query<'a>(&'a self, filter: &'a AssignmentFilter) -> ArchiveIter<'a>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How to persist this archive in an `AssignmentState`.
+    fn descriptor(&self) -> ArchiveDescriptor;
+}
+
+/// An archive as `AssignmentState` records it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ArchiveDescriptor {
+    InMemory(Vec<ArchivedAssignment>),
+    File { path: String },
+    /// A custom backend, by name; restoring keeps the archive the engine already has.
+    External(String),
+}
+
+impl ArchiveDescriptor {
+    /// The archive this describes; `None` for `External`.
+    pub fn open(self) -> Result<Option<Box<dyn AssignmentArchive>>, String> {
+        Ok(match self {
+            ArchiveDescriptor::InMemory(records) => Some(Box::new(InMemoryArchive { records })),
+            ArchiveDescriptor::File { path } => Some(Box::new(FileArchive::open(path)?)),
+            ArchiveDescriptor::External(_) => None,
+        })
+    }
+}
+
+/// Archived assignments held in memory; the default.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryArchive {
+    records: Vec<ArchivedAssignment>,
+}
+
+impl InMemoryArchive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AssignmentArchive for InMemoryArchive {
+    fn append(&mut self, record: ArchivedAssignment) -> Result<(), String> {
+        self.records.push(record);
+        Ok(())
+    }
+
+    fn query<'a>(&'a self, filter: &'a AssignmentFilter) -> ArchiveIter<'a> {
+        Box::new(self.records.iter().filter(|r| filter.matches(r)).cloned().map(Ok))
+    }
+
+    fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    fn descriptor(&self) -> ArchiveDescriptor {
+        ArchiveDescriptor::InMemory(self.records.clone())
+    }
+}
+
+/// Archived assignments appended to a JSON-lines file, one record per line.
+/// Queries read the file line by line; only the record count is kept in memory.
+#[derive(Debug)]
+pub struct FileArchive {
+    path: PathBuf,
+    len: usize,
+}
+
+impl FileArchive {
+    /// Open `path`, creating it if missing; existing records are counted, not loaded.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, String> {
+        let path = path.into();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .read(true)
+            .open(&path)
+            .map_err(|e| format!("Cannot open assignment archive {}: {e}", path.display()))?;
+        let len = BufReader::new(file).lines().map_while(Result::ok).filter(|l| !l.trim().is_empty()).count();
+        Ok(Self { path, len })
+    }
+
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+impl AssignmentArchive for FileArchive {
+    fn append(&mut self, record: ArchivedAssignment) -> Result<(), String> {
+        let line = serde_json::to_string(&record).map_err(|e| format!("Cannot encode archived assignment: {e}"))?;
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("Cannot open assignment archive {}: {e}", self.path.display()))?;
+        writeln!(file, "{line}")
+            .map_err(|e| format!("Cannot append to assignment archive {}: {e}", self.path.display()))?;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn query<'a>(&'a self, filter: &'a AssignmentFilter) -> ArchiveIter<'a> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) => {
+                let message = format!("Cannot read assignment archive {}: {e}", self.path.display());
+                return Box::new(std::iter::once(Err(message)));
+            }
+        };
+        let records = BufReader::new(file).lines().enumerate().filter_map(move |(n, line)| {
+            let line = match line {
+                Ok(line) if line.trim().is_empty() => return None,
+                Ok(line) => line,
+                Err(e) => return Some(Err(format!("Assignment archive line {}: {e}", n + 1))),
+            };
+            match serde_json::from_str::<ArchivedAssignment>(&line) {
+                Ok(record) => filter.matches(&record).then_some(Ok(record)),
+                Err(e) => Some(Err(format!("Assignment archive line {}: {e}", n + 1))),
+            }
+        });
+        Box::new(records)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn descriptor(&self) -> ArchiveDescriptor {
+        ArchiveDescriptor::File { path: self.path.display().to_string() }
+    }
+}
+
+/// An engine's assignments as persisted: open ones oldest first, and the archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AssignmentState {
+    pub active: Vec<AssignedMission>,
+    pub archive: ArchiveDescriptor,
+}
+
+/// Open assignments by key.
+pub(crate) type ActiveAssignments = BTreeMap<AssignmentKey, AssignedMission>;
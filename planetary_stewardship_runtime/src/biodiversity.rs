@@ -0,0 +1,131 @@
+// path: planetary_stewardship_runtime/src/biodiversity.rs
+
+//! What a `biodiversity_index_delta` measures.
+//! - `BiodiversityDetail` names the index, the survey behind the delta and where its
+//!   methodology is written down; optional on `ImpactMetrics`, so older records load unchanged
+//! - Detail, when present, must hold together: a method and a reference, a species count for
+//!   richness, and a survey dated within `MetricsPolicy::max_survey_age_ms` before the attestation
+//! - `MetricsPolicy::require_biodiversity_detail` refuses any nonzero delta without detail
+//! - Summaries total deltas per index kind: a Shannon delta and a richness count do not add up
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::ImpactMetrics;
+
+/// Index a biodiversity delta is expressed in.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum IndexKind {
+    ShannonDiversity,
+    SpeciesRichness,
+    /// Another index, named by a reference to its definition.
+    CustomRef(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BiodiversityDetail {
+    pub index_kind: IndexKind,
+    /// e.g. `transect`, `quadrat`, `eDNA`, `point count`.
+    pub survey_method: String,
+    pub survey_date_ms: u64,
+    /// Species recorded by the survey; required for `SpeciesRichness`.
+    #[serde(default)]
+    pub species_observed: Option<u32>,
+    /// Methodology or dataset the survey followed.
+    pub reference_uri: String,
+}
+
+/// Rules on the built-in impact metrics. The default requires no detail.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricsPolicy {
+    /// Refuse a nonzero `biodiversity_index_delta` without `biodiversity_detail`.
+    #[serde(default)]
+    pub require_biodiversity_detail: bool,
+    /// How long before the attestation its survey may have been done.
+    #[serde(default = "default_max_survey_age_ms")]
+    pub max_survey_age_ms: u64,
+}
+
+fn default_max_survey_age_ms() -> u64 {
+    365 * 24 * 60 * 60 * 1000
+}
+
+impl Default for MetricsPolicy {
+    fn default() -> Self {
+        Self {
+            require_biodiversity_detail: false,
+            max_survey_age_ms: default_max_survey_age_ms(),
+        }
+    }
+}
+
+impl MetricsPolicy {
+    /// `[start_ms, end_ms]` a survey must fall in for an attestation at `timestamp_ms`.
+    pub fn survey_window(&self, timestamp_ms: u64) -> (u64, u64) {
+        (timestamp_ms.saturating_sub(self.max_survey_age_ms), timestamp_ms)
+    }
+
+    /// First problem with `metrics`' biodiversity claim for an attestation at `timestamp_ms`.
+    pub fn check(&self, metrics: &ImpactMetrics, timestamp_ms: u64) -> Result<(), String> {
+        let Some(detail) = &metrics.biodiversity_detail else {
+            if self.require_biodiversity_detail && metrics.biodiversity_index_delta != 0.0 {
+                return Err("Biodiversity delta requires biodiversity_detail (index, survey and reference)".into());
+            }
+            return Ok(());
+        };
+        if detail.survey_method.trim().is_empty() {
+            return Err("Biodiversity detail must name its survey_method".into());
+        }
+        if detail.reference_uri.trim().is_empty() {
+            return Err("Biodiversity detail must give a reference_uri".into());
+        }
+        if let IndexKind::CustomRef(reference) = &detail.index_kind {
+            if reference.trim().is_empty() {
+                return Err("Custom biodiversity index must name its reference".into());
+            }
+        }
+        if detail.index_kind == IndexKind::SpeciesRichness && detail.species_observed.is_none() {
+            return Err("Species richness detail must give species_observed".into());
+        }
+        let (start, end) = self.survey_window(timestamp_ms);
+        if detail.survey_date_ms < start || detail.survey_date_ms > end {
+            return Err(format!(
+                "Biodiversity survey dated {} ms is outside [{start}, {end}]",
+                detail.survey_date_ms
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Biodiversity deltas of one index kind; `None` for deltas given without detail.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BiodiversityTotal {
+    pub index_kind: Option<IndexKind>,
+    pub attestations: usize,
+    pub delta: f64,
+}
+
+/// Totals per index kind over `metrics` (in the order given), unspecified first, then by kind.
+/// Metrics with neither detail nor a delta are left out.
+pub(crate) fn totals_by_index<'a>(metrics: impl IntoIterator<Item = &'a ImpactMetrics>) -> Vec<BiodiversityTotal> {
+    let mut totals: BTreeMap<Option<IndexKind>, BiodiversityTotal> = BTreeMap::new();
+    for m in metrics {
+        if m.biodiversity_detail.is_none() && m.biodiversity_index_delta == 0.0 {
+            continue;
+        }
+        let index_kind = m.biodiversity_detail.as_ref().map(|d| d.index_kind.clone());
+        let total = totals.entry(index_kind.clone()).or_insert(BiodiversityTotal {
+            index_kind,
+            attestations: 0,
+            delta: 0.0,
+        });
+        total.attestations += 1;
+        total.delta += m.biodiversity_index_delta;
+    }
+    totals.into_values().collect()
+}
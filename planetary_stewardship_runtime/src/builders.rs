@@ -0,0 +1,396 @@
+// path: planetary_stewardship_runtime/src/builders.rs
+
+//! Fluent builders and fixtures for tests, behind the `test-util` feature.
+//! - `LedgerBuilder`: a ledger on a fixed clock and a seeded `SequentialIdGenerator`, with the
+//!   default SAEP config and whatever consent the test grants
+//! - `saep_without_rollback_plans`, and `LedgerBuilder::without_rollback_plans`, for fixtures
+//!   about anything but rollback plans, which the `rollback_plans` example covers
+//! - `AttestationBuilder`: a small PLGA claim; `issue_on` registers the rollback plan SAEP
+//!   requires, and `build_valid` issues it on a fresh seeded ledger, so the attestation has
+//!   passed every check `issue_attestation_with_refs` makes
+//! - `MissionTemplateBuilder`: `build_valid` returns the template as `register_template`
+//!   stores it, after validation and content sanitizing
+//! - `ConsentRecordBuilder`: consent given at the fixture start unless told otherwise
+//! - The same builder calls with the same seed produce identical ids, hashes and timestamps
+
+use std::sync::Arc;
+
+use steward_runtime_support::{Clock, FixedClock, IdGenerator, SequentialIdGenerator};
+
+use crate::{
+    ActionRef, AttestationRequest, ConsentRecord, ConsentRegistry, Did, EvidenceArtifact, ImpactMetrics, IntentEntryId,
+    LanguageTag, LocalizedTemplate, MetricFieldValue, MicroMissionsEngine, MissionId, MissionTemplate, ModuleMetrics,
+    PlanId, PlanetaryLedger, RollbackPlan, SaepConfig, SaepEngine, SaepRefs, StewardModule, StewardshipAttestation,
+};
+
+/// 2026-01-01T00:00:00Z; fixtures are stamped here unless told otherwise.
+pub const FIXTURE_START_MS: u64 = 1_767_225_600_000;
+
+/// DID from a fixture literal; panics on a malformed DID.
+pub fn did(s: &str) -> Did {
+    Did::new(s).expect("fixture DID is valid")
+}
+
+/// Mission id from a fixture literal; panics on a malformed id.
+pub fn mission_id(s: &str) -> MissionId {
+    MissionId::new(s).expect("fixture mission id is valid")
+}
+
+fn language(tag: &str) -> LanguageTag {
+    tag.parse().expect("fixture language tag is valid")
+}
+
+/// The default SAEP config without the reversibility rule, so actions need no rollback plan.
+pub fn saep_without_rollback_plans() -> SaepConfig {
+    SaepConfig { enforce_reversibility: false, ..SaepConfig::default() }
+}
+
+/// Builds a `ConsentRecord`, given at `FIXTURE_START_MS` for the whole module.
+#[derive(Debug, Clone)]
+pub struct ConsentRecordBuilder {
+    record: ConsentRecord,
+}
+
+impl ConsentRecordBuilder {
+    pub fn new(participant: &Did, module: StewardModule) -> Self {
+        Self {
+            record: ConsentRecord {
+                participant: participant.clone(),
+                module,
+                mission: None,
+                consent_given: true,
+                timestamp_ms: FIXTURE_START_MS,
+                evidence_uri: None,
+                evidence_hash: None,
+                prompt_hash: None,
+            },
+        }
+    }
+
+    pub fn mission(mut self, mission: &MissionId) -> Self {
+        self.record.mission = Some(mission.clone());
+        self
+    }
+
+    pub fn at_ms(mut self, timestamp_ms: u64) -> Self {
+        self.record.timestamp_ms = timestamp_ms;
+        self
+    }
+
+    pub fn withdrawn(mut self) -> Self {
+        self.record.consent_given = false;
+        self
+    }
+
+    pub fn evidence(mut self, uri: &str, hash: &str) -> Self {
+        self.record.evidence_uri = Some(uri.into());
+        self.record.evidence_hash = Some(hash.into());
+        self
+    }
+
+    pub fn prompt_hash(mut self, hash: &str) -> Self {
+        self.record.prompt_hash = Some(hash.into());
+        self
+    }
+
+    pub fn build(self) -> ConsentRecord {
+        self.record
+    }
+}
+
+/// Builds a `PlanetaryLedger` reading a clock fixed at `FIXTURE_START_MS` and drawing ids from
+/// `SequentialIdGenerator::new(1)`.
+pub struct LedgerBuilder {
+    saep: SaepConfig,
+    consents: Vec<ConsentRecord>,
+    clock: Arc<dyn Clock>,
+    ids: Arc<dyn IdGenerator>,
+}
+
+impl Default for LedgerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LedgerBuilder {
+    pub fn new() -> Self {
+        Self {
+            saep: SaepConfig::default(),
+            consents: Vec::new(),
+            clock: Arc::new(FixedClock::new(FIXTURE_START_MS)),
+            ids: Arc::new(SequentialIdGenerator::new(1)),
+        }
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.ids = Arc::new(SequentialIdGenerator::new(seed));
+        self
+    }
+
+    /// Share a clock (e.g. one the test advances) instead of the fixed fixture clock.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Share an id source (e.g. one a whole scenario draws from) instead of a seeded one.
+    pub fn ids(mut self, ids: Arc<dyn IdGenerator>) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    pub fn saep(mut self, config: SaepConfig) -> Self {
+        self.saep = config;
+        self
+    }
+
+    /// Issue without rollback plans; see `saep_without_rollback_plans`.
+    pub fn without_rollback_plans(mut self) -> Self {
+        self.saep.enforce_reversibility = false;
+        self
+    }
+
+    pub fn consent(mut self, record: ConsentRecord) -> Self {
+        self.consents.push(record);
+        self
+    }
+
+    /// PLGA consent for `participant`, given at the ledger's current time.
+    pub fn consenting(self, participant: &Did) -> Self {
+        let now_ms = self.clock.now_ms();
+        self.consent(ConsentRecordBuilder::new(participant, StewardModule::PLGA).at_ms(now_ms).build())
+    }
+
+    pub fn build(self) -> PlanetaryLedger {
+        let mut registry = ConsentRegistry::new();
+        for record in self.consents {
+            registry.upsert_consent(record);
+        }
+        PlanetaryLedger::with_runtime(SaepEngine::new(self.saep), registry, self.clock, self.ids)
+    }
+}
+
+/// Builds a 0.2 tCO₂e PLGA claim with one evidence link, stamped at `FIXTURE_START_MS`.
+#[derive(Debug, Clone)]
+pub struct AttestationBuilder {
+    request: AttestationRequest,
+    seed: u64,
+}
+
+impl AttestationBuilder {
+    pub fn new(actor: &Did) -> Self {
+        Self {
+            request: AttestationRequest {
+                actor_did: actor.clone(),
+                mission_id: None,
+                description: "Removed 12 kg of litter from the creek bed".into(),
+                module: StewardModule::PLGA,
+                impact_metrics: ImpactMetrics {
+                    co2eq_reduced: 0.2,
+                    ..ImpactMetrics::default()
+                },
+                module_metrics: ModuleMetrics::new(),
+                evidence: vec![EvidenceArtifact::from_uri("ipfs://fixture-evidence")],
+                verifier_dids: vec![],
+                timestamp_ms: FIXTURE_START_MS,
+                refs: SaepRefs::default(),
+                capture_window: None,
+                witness_endorsement: None,
+            },
+            seed: 1,
+        }
+    }
+
+    pub fn mission(mut self, mission: &MissionId) -> Self {
+        self.request.mission_id = Some(mission.clone());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.request.description = description.into();
+        self
+    }
+
+    pub fn impact_metrics(mut self, metrics: ImpactMetrics) -> Self {
+        self.request.impact_metrics = metrics;
+        self
+    }
+
+    pub fn co2eq_reduced(mut self, tonnes: f64) -> Self {
+        self.request.impact_metrics.co2eq_reduced = tonnes;
+        self
+    }
+
+    /// Claim in `module` instead, under its registered schema; drops the PLGA metrics.
+    pub fn module(mut self, module: StewardModule) -> Self {
+        self.request.module = module;
+        self.request.impact_metrics = ImpactMetrics::default();
+        self
+    }
+
+    pub fn module_metric(mut self, name: &str, value: impl Into<MetricFieldValue>) -> Self {
+        self.request.module_metrics.insert(name.into(), value.into());
+        self
+    }
+
+    /// Replaces the evidence with `artifacts`.
+    pub fn evidence(mut self, artifacts: Vec<EvidenceArtifact>) -> Self {
+        self.request.evidence = artifacts;
+        self
+    }
+
+    pub fn verifier(mut self, verifier: &Did) -> Self {
+        self.request.verifier_dids.push(verifier.clone());
+        self
+    }
+
+    pub fn timestamp_ms(mut self, timestamp_ms: u64) -> Self {
+        self.request.timestamp_ms = timestamp_ms;
+        self
+    }
+
+    pub fn capture_window(mut self, start_ms: u64, end_ms: u64) -> Self {
+        self.request.capture_window = Some((start_ms, end_ms));
+        self
+    }
+
+    pub fn intent(mut self, entry: IntentEntryId) -> Self {
+        self.request.refs.intent_entry_id = Some(entry);
+        self
+    }
+
+    /// Issue under an already registered plan instead of one `issue_on` registers.
+    pub fn rollback_plan(mut self, plan: PlanId) -> Self {
+        self.request.refs.rollback_plan_id = Some(plan);
+        self
+    }
+
+    /// Seed of the ledger `build_valid` issues on.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// The request as built, unchecked.
+    pub fn request(&self) -> AttestationRequest {
+        self.request.clone()
+    }
+
+    /// Issue on `ledger`. Without `rollback_plan`, reserves an id and registers a two-step plan
+    /// for it first, so the claim passes under `enforce_reversibility`.
+    pub fn issue_on(self, ledger: &mut PlanetaryLedger) -> Result<StewardshipAttestation, String> {
+        let mut request = self.request;
+        if request.refs.rollback_plan_id.is_none() {
+            let id = ledger.reserve_attestation_id();
+            let plan = RollbackPlan {
+                id: PlanId(format!("plan:{}", id.0)),
+                action_ref: ActionRef::Attestation(id),
+                steps: vec!["Revoke the attestation".into(), "Notify its verifiers".into()],
+                responsible: request.actor_did.clone(),
+                created_ms: request.timestamp_ms,
+            };
+            request.refs.rollback_plan_id = Some(ledger.register_rollback_plan(plan)?);
+        }
+        ledger.issue_attestation_idempotent(request, None)
+    }
+
+    /// Issue on a fresh ledger seeded with `seed` where the actor has consented, panicking if
+    /// the ledger refuses the claim.
+    pub fn build_valid(self) -> StewardshipAttestation {
+        let mut ledger = LedgerBuilder::new().seed(self.seed).consenting(&self.request.actor_did).build();
+        let actor = self.request.actor_did.clone();
+        self.issue_on(&mut ledger)
+            .unwrap_or_else(|e| panic!("fixture attestation for {actor} is invalid: {e}"))
+    }
+}
+
+/// Builds a small, geo-located `MissionTemplate` with no required skills.
+#[derive(Debug, Clone)]
+pub struct MissionTemplateBuilder {
+    template: MissionTemplate,
+}
+
+impl MissionTemplateBuilder {
+    pub fn new(id: &str) -> Self {
+        let title = id.replace('-', " ");
+        Self {
+            template: MissionTemplate {
+                id: mission_id(id),
+                description: format!("{title}."),
+                title,
+                difficulty: "S".into(),
+                expected_impact: serde_json::json!({}),
+                location_hint: "geo".into(),
+                required_skills: vec![],
+                default_language: None,
+                localized_content: Default::default(),
+                high_demand: false,
+            },
+        }
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.template.title = title.into();
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.template.description = description.into();
+        self
+    }
+
+    pub fn difficulty(mut self, difficulty: &str) -> Self {
+        self.template.difficulty = difficulty.into();
+        self
+    }
+
+    pub fn expected_impact(mut self, impact: serde_json::Value) -> Self {
+        self.template.expected_impact = impact;
+        self
+    }
+
+    pub fn location_hint(mut self, hint: &str) -> Self {
+        self.template.location_hint = hint.into();
+        self
+    }
+
+    pub fn skill(mut self, skill: &str) -> Self {
+        self.template.required_skills.push(skill.into());
+        self
+    }
+
+    pub fn default_language(mut self, tag: &str) -> Self {
+        self.template.default_language = Some(language(tag));
+        self
+    }
+
+    pub fn high_demand(mut self) -> Self {
+        self.template.high_demand = true;
+        self
+    }
+
+    /// The mission in `tag`; the default language becomes `en` if none is set.
+    pub fn localized(mut self, tag: &str, title: &str, description: &str) -> Self {
+        self.template.default_language.get_or_insert_with(|| language("en"));
+        let content = LocalizedTemplate { title: title.into(), description: description.into() };
+        self.template.localized_content.insert(language(tag), content);
+        self
+    }
+
+    pub fn build(self) -> MissionTemplate {
+        self.template
+    }
+
+    /// The template as `register_template` stores it on an engine with default policies,
+    /// panicking if it is refused.
+    pub fn build_valid(self) -> MissionTemplate {
+        let template = self.build();
+        let id = template.id.clone();
+        let mut engine = MicroMissionsEngine::new(SaepEngine::new(SaepConfig::default()), ConsentRegistry::new());
+        if let Err(e) = engine.register_template(template) {
+            panic!("fixture template {id} is invalid: {e}");
+        }
+        engine.get_template(&id).cloned().expect("registered template")
+    }
+}
@@ -0,0 +1,147 @@
+// path: planetary_stewardship_runtime/src/charter.rs
+
+//! Charter-bound module set, changed through governance.
+//! - Binding a module to the co-stewardship charter is always allowed
+//! - Unbinding needs an applied, ecosystem-wide proposal that names the module and passed
+//!   with at least `CHARTER_UNBIND_FLOOR` support; each proposal authorizes one unbinding
+//! - Every change, and every refused unbinding, lands in `GovernanceEngine::audit_trail`
+//!   with the set's hash before and after
+//! - CSC is unbound by default, but may be bound like any other module
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{GovernanceProposal, OrphanedCharterRule, QuadraticOutcome, Reason, ReasonCode, StewardModule};
+
+/// Smallest support share (support / votes cast) that can unbind a module.
+/// `GovernanceEngine::with_unbind_supermajority` can only raise it.
+pub const CHARTER_UNBIND_FLOOR: f64 = 2.0 / 3.0;
+
+/// Payload key an unbinding proposal uses to name its module, e.g. `{"unbind_module": "VET"}`.
+pub const UNBIND_MODULE_KEY: &str = "unbind_module";
+
+/// Modules bound unless a deployment says otherwise: everything except CSC.
+pub const DEFAULT_CHARTER_BOUND_MODULES: [StewardModule; 7] = [
+    StewardModule::PLGA,
+    StewardModule::MME,
+    StewardModule::VET,
+    StewardModule::OCG,
+    StewardModule::DCCN,
+    StewardModule::REBL,
+    StewardModule::PSM,
+];
+
+/// Names a proposal `GovernanceEngine::apply_proposal` recorded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AppliedProposalRef {
+    pub proposal_id: String,
+}
+
+/// A proposal as it was applied, with the tally it passed on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AppliedProposal {
+    pub proposal: GovernanceProposal,
+    pub outcome: QuadraticOutcome,
+    pub applied_ms: u64,
+    /// Set once the proposal has authorized an unbinding.
+    #[serde(default)]
+    pub spent: bool,
+    /// Charter rules whose clause the charter version this proposal adopted dropped.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orphaned_rules: Vec<OrphanedCharterRule>,
+}
+
+impl AppliedProposal {
+    /// Support over all weight cast; 0 when nothing was cast.
+    pub fn support_share(&self) -> f64 {
+        let cast = self.outcome.total_support + self.outcome.total_opposition;
+        if cast > 0.0 {
+            self.outcome.total_support / cast
+        } else {
+            0.0
+        }
+    }
+
+    /// The module named under `UNBIND_MODULE_KEY`, if any.
+    pub fn unbind_target(&self) -> Option<StewardModule> {
+        self.proposal
+            .payload
+            .get(UNBIND_MODULE_KEY)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+}
+
+/// Why an unbinding was refused.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CharterError {
+    NotBound { module: StewardModule },
+    UnknownProposal { proposal_id: String },
+    /// Module-scoped proposals cannot change the charter.
+    NotEcosystemWide { proposal_id: String },
+    InsufficientMajority { proposal_id: String, support_share: f64, required: f64 },
+    /// The proposal names another module, or none.
+    WrongModule { proposal_id: String, module: StewardModule, named: Option<StewardModule> },
+    /// The proposal already authorized an unbinding.
+    AuthorizationSpent { proposal_id: String },
+    /// A charter rule cites a clause the active charter version does not have.
+    UnknownClause { rule: String, clause: String, version: u32 },
+}
+
+impl CharterError {
+    pub fn reason(&self) -> Reason {
+        match self {
+            CharterError::NotBound { module } => {
+                Reason::new(ReasonCode::CharterNotBound).with("module", format!("{module:?}"))
+            }
+            CharterError::UnknownProposal { proposal_id } => {
+                Reason::new(ReasonCode::CharterUnknownProposal).with("proposal", proposal_id)
+            }
+            CharterError::NotEcosystemWide { proposal_id } => {
+                Reason::new(ReasonCode::CharterNotEcosystemWide).with("proposal", proposal_id)
+            }
+            CharterError::InsufficientMajority { proposal_id, support_share, required } => {
+                Reason::new(ReasonCode::CharterInsufficientMajority)
+                    .with("proposal", proposal_id)
+                    .with("support_share", format!("{support_share:.3}"))
+                    .with("required", format!("{required:.3}"))
+            }
+            CharterError::WrongModule { proposal_id, module, named } => match named {
+                Some(named) => Reason::new(ReasonCode::CharterWrongModule)
+                    .with("proposal", proposal_id)
+                    .with("module", format!("{module:?}"))
+                    .with("named", format!("{named:?}")),
+                None => Reason::new(ReasonCode::CharterModuleUnnamed)
+                    .with("proposal", proposal_id)
+                    .with("key", format!("{UNBIND_MODULE_KEY:?}")),
+            },
+            CharterError::AuthorizationSpent { proposal_id } => {
+                Reason::new(ReasonCode::CharterAuthorizationSpent).with("proposal", proposal_id)
+            }
+            CharterError::UnknownClause { rule, clause, version } => Reason::new(ReasonCode::CharterUnknownClause)
+                .with("rule", rule)
+                .with("clause", clause)
+                .with("version", version),
+        }
+    }
+}
+
+impl fmt::Display for CharterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reason().fmt(f)
+    }
+}
+
+impl std::error::Error for CharterError {}
+
+/// SHA-256 over the bound modules in `StewardModule` order.
+pub fn charter_set_hash(modules: &HashSet<StewardModule>) -> String {
+    let mut sorted: Vec<&StewardModule> = modules.iter().collect();
+    sorted.sort();
+    let payload = serde_json::to_vec(&sorted).expect("module set serialization");
+    format!("{:x}", Sha256::digest(&payload))
+}
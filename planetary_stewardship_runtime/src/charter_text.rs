@@ -0,0 +1,229 @@
+// path: planetary_stewardship_runtime/src/charter_text.rs
+
+//! The co-stewardship charter's text, so vetoes can cite the clause they enforce.
+//! - A `CharterDocument` is one version of the charter: clauses with an id, their text and
+//!   the proposal that adopted them. `GovernanceEngine` keeps every version it adopted, with
+//!   its hash; the latest is the active one
+//! - New versions are adopted through an ecosystem-wide `ConfigChange::AdoptCharter` proposal,
+//!   applied like any other; the engine stamps changed clauses with the proposal's id and
+//!   audits the document hash before and after
+//! - `CharterRuleSet` holds the forbidden-term rules the engine enforces in charter-bound
+//!   modules. Each rule cites a clause: setting rules requires the clause in the active
+//!   version, and adopting a version without it reports the rule as orphaned in the applied
+//!   proposal. An orphaned rule is still enforced, citing the last version that had its clause
+//! - Veto records, rejection reasons and previews carry the clause id and an excerpt
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+
+/// Longest excerpt of a clause's text a citation carries, in characters.
+pub const CHARTER_EXCERPT_CHARS: usize = 120;
+
+/// Clause the built-in rule cites.
+pub const NON_WEAPONIZATION_CLAUSE: &str = "non-weaponization";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CharterClause {
+    /// Stable across versions, e.g. `non-weaponization` or `4.2`.
+    pub id: String,
+    pub text: String,
+    /// Proposal that adopted this text; `None` for the founding charter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adopted_via: Option<String>,
+}
+
+/// One version of the charter, clauses in document order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CharterDocument {
+    pub version: u32,
+    pub clauses: Vec<CharterClause>,
+}
+
+impl CharterDocument {
+    pub fn clause(&self, id: &str) -> Option<&CharterClause> {
+        self.clauses.iter().find(|c| c.id == id)
+    }
+
+    /// SHA-256 over the document's JSON.
+    pub fn document_hash(&self) -> String {
+        let payload = serde_json::to_vec(self).expect("charter serialization");
+        format!("{:x}", Sha256::digest(&payload))
+    }
+
+    /// Clause ids present and unique, and every clause has text.
+    pub fn check(&self) -> Result<(), String> {
+        let mut seen = BTreeSet::new();
+        for clause in &self.clauses {
+            if clause.id.trim().is_empty() {
+                return Err(format!("Charter version {} has a clause without an id", self.version));
+            }
+            if !seen.insert(clause.id.as_str()) {
+                return Err(format!("Charter version {} has clause {} twice", self.version, clause.id));
+            }
+            if clause.text.trim().is_empty() {
+                return Err(format!("Charter clause {} has no text", clause.id));
+            }
+        }
+        Ok(())
+    }
+
+    /// This document with `proposal_id` as the adopter of every clause that is new or
+    /// reworded since `previous`; unchanged clauses keep their adopter.
+    pub(crate) fn stamped(mut self, previous: &CharterDocument, proposal_id: &str) -> CharterDocument {
+        for clause in &mut self.clauses {
+            clause.adopted_via = match previous.clause(&clause.id) {
+                Some(old) if old.text == clause.text => old.adopted_via.clone(),
+                _ => Some(proposal_id.to_string()),
+            };
+        }
+        self
+    }
+}
+
+/// A clause as cited by a veto: its id, the version the text comes from, and the start of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CharterCitation {
+    pub clause: String,
+    pub version: u32,
+    pub excerpt: String,
+}
+
+impl CharterCitation {
+    pub fn new(clause: &CharterClause, version: u32) -> Self {
+        let mut excerpt: String = clause.text.chars().take(CHARTER_EXCERPT_CHARS).collect();
+        if excerpt.len() < clause.text.len() {
+            excerpt.push('…');
+        }
+        Self { clause: clause.id.clone(), version, excerpt }
+    }
+}
+
+/// Text that a charter-bound module may not be restricted or governed for: any of `terms`,
+/// matched case-insensitively, under `clause`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CharterRule {
+    pub id: String,
+    /// Lowercase.
+    pub terms: BTreeSet<String>,
+    pub clause: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CharterRuleSet {
+    pub rules: Vec<CharterRule>,
+}
+
+impl Default for CharterRuleSet {
+    /// No weaponization or military use, under `NON_WEAPONIZATION_CLAUSE`.
+    fn default() -> Self {
+        Self {
+            rules: vec![CharterRule {
+                id: "no-weaponization".into(),
+                terms: ["weapon".to_string(), "military".to_string()].into(),
+                clause: NON_WEAPONIZATION_CLAUSE.into(),
+            }],
+        }
+    }
+}
+
+impl CharterRuleSet {
+    /// The first rule with a term in `text`.
+    pub fn rule_for(&self, text: &str) -> Option<&CharterRule> {
+        let text = text.to_lowercase();
+        self.rules.iter().find(|rule| rule.terms.iter().any(|term| text.contains(term.as_str())))
+    }
+
+    /// Rules citing a clause `document` does not have, in rule order.
+    pub fn orphaned_by(&self, document: &CharterDocument) -> Vec<OrphanedCharterRule> {
+        self.rules
+            .iter()
+            .filter(|rule| document.clause(&rule.clause).is_none())
+            .map(|rule| OrphanedCharterRule { rule: rule.id.clone(), clause: rule.clause.clone() })
+            .collect()
+    }
+}
+
+/// A rule whose clause an adopted charter version dropped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OrphanedCharterRule {
+    pub rule: String,
+    pub clause: String,
+}
+
+/// One adopted version and its hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CharterVersion {
+    pub document: CharterDocument,
+    pub document_hash: String,
+    /// `None` for the founding version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adopted_via: Option<String>,
+    pub adopted_ms: u64,
+}
+
+/// Every charter version a `GovernanceEngine` adopted, oldest first; never empty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CharterRegistry {
+    versions: Vec<CharterVersion>,
+}
+
+impl Default for CharterRegistry {
+    fn default() -> Self {
+        Self::founded(default_charter())
+    }
+}
+
+impl CharterRegistry {
+    /// A registry whose founding version is `document`.
+    pub fn founded(document: CharterDocument) -> Self {
+        let document_hash = document.document_hash();
+        Self { versions: vec![CharterVersion { document, document_hash, adopted_via: None, adopted_ms: 0 }] }
+    }
+
+    pub fn active(&self) -> &CharterVersion {
+        self.versions.last().expect("registry is never empty")
+    }
+
+    pub fn version(&self, version: u32) -> Option<&CharterVersion> {
+        self.versions.iter().find(|v| v.document.version == version)
+    }
+
+    pub fn versions(&self) -> &[CharterVersion] {
+        &self.versions
+    }
+
+    /// `clause` as the active version words it, or as the latest version that had it.
+    pub fn cite(&self, clause: &str) -> Option<CharterCitation> {
+        self.versions
+            .iter()
+            .rev()
+            .find_map(|v| v.document.clause(clause).map(|c| CharterCitation::new(c, v.document.version)))
+    }
+
+    pub(crate) fn push(&mut self, version: CharterVersion) {
+        self.versions.push(version);
+    }
+}
+
+/// Version 1: the clause the built-in rule cites.
+pub fn default_charter() -> CharterDocument {
+    CharterDocument {
+        version: 1,
+        clauses: vec![CharterClause {
+            id: NON_WEAPONIZATION_CLAUSE.into(),
+            text: "Charter-bound modules serve the commons; they may not be restricted, governed or repurposed \
+                   for weaponization or military ends."
+                .into(),
+            adopted_via: None,
+        }],
+    }
+}
@@ -0,0 +1,189 @@
+// path: planetary_stewardship_runtime/src/conflict.rs
+
+//! Verifier conflict-of-interest declarations.
+//! - A verifier's relationship with an actor (employer, family, same guild, ...) is declared
+//!   into the verifier registry with `declare_conflict`; it holds for claims timestamped from
+//!   `declared_ms` until `expires_ms`
+//! - `ConflictPolicy`, part of the `VerifierPolicy`, picks per relationship kind what
+//!   issuance does with a conflicted verifier's signature: `NotCounted` lists it in
+//!   `non_counting_verifiers` (and it does not count toward a verification quorum),
+//!   `CountedFlagged` counts it, `Forbidden` refuses the claim. With several declarations
+//!   for one pair, the strictest applies
+//! - Whatever was applied is recorded on the attestation in `verifier_conflicts`
+//! - A conflict found after the fact is declared with a `declared_ms` in the past. Issued
+//!   attestations are left as they are; `declare_conflict` returns a `ConflictReevaluation`
+//!   listing those whose verification quorum would no longer hold, for a person to act on
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::{AttestationId, Did, PlanetaryLedger, Reason, ReasonCode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RelationshipKind {
+    /// The verifier employs the actor, or the actor the verifier.
+    Employer,
+    Family,
+    SameGuild,
+    Financial,
+    Other,
+}
+
+impl RelationshipKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationshipKind::Employer => "employer",
+            RelationshipKind::Family => "family",
+            RelationshipKind::SameGuild => "same guild",
+            RelationshipKind::Financial => "financial",
+            RelationshipKind::Other => "other",
+        }
+    }
+}
+
+/// What issuance does with a conflicted verifier's signature, mildest first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConflictTreatment {
+    CountedFlagged,
+    #[default]
+    NotCounted,
+    Forbidden,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConflictDeclaration {
+    pub verifier: Did,
+    pub actor: Did,
+    pub relationship: RelationshipKind,
+    /// Since when the relationship holds; before now for a conflict found after the fact.
+    pub declared_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_ms: Option<u64>,
+}
+
+impl ConflictDeclaration {
+    /// Whether the declaration covers a claim timestamped `at_ms`.
+    pub fn covers(&self, at_ms: u64) -> bool {
+        self.declared_ms <= at_ms && self.expires_ms.is_none_or(|expires| at_ms < expires)
+    }
+}
+
+/// Treatment per relationship kind; kinds it does not name are `NotCounted`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConflictPolicy {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub treatments: BTreeMap<RelationshipKind, ConflictTreatment>,
+}
+
+impl ConflictPolicy {
+    pub fn with_treatment(mut self, relationship: RelationshipKind, treatment: ConflictTreatment) -> Self {
+        self.treatments.insert(relationship, treatment);
+        self
+    }
+
+    pub fn treatment(&self, relationship: RelationshipKind) -> ConflictTreatment {
+        self.treatments.get(&relationship).copied().unwrap_or_default()
+    }
+}
+
+/// The treatment issuance gave one listed verifier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AppliedConflict {
+    pub verifier: Did,
+    pub relationship: RelationshipKind,
+    pub treatment: ConflictTreatment,
+}
+
+/// An issued attestation a newly declared conflict would have changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QuorumAtRisk {
+    pub attestation_id: AttestationId,
+    /// What issuance would do with the verifier now.
+    pub treatment: ConflictTreatment,
+    /// Verifiers that would still count.
+    pub counting_verifiers: usize,
+}
+
+/// Advisory: issued attestations whose verification quorum a declaration undoes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConflictReevaluation {
+    pub declaration: ConflictDeclaration,
+    pub quorum: usize,
+    /// By attestation id. Attestations the verifier signed that stay above quorum are not listed.
+    pub quorum_no_longer_holds: Vec<QuorumAtRisk>,
+}
+
+impl PlanetaryLedger {
+    /// Record `declaration` in the verifier registry, then report the issued attestations of
+    /// its actor and verifier whose quorum it undoes. Nothing issued is changed.
+    pub fn declare_conflict(
+        &mut self,
+        declaration: ConflictDeclaration,
+        by: Did,
+        now_ms: u64,
+    ) -> Result<ConflictReevaluation, String> {
+        self.verifiers.declare_conflict(declaration.clone(), by, now_ms)?;
+        let quorum = self.verification_policy.quorum.max(1);
+        let treatment = self.verifiers.policy.conflicts.treatment(declaration.relationship);
+        let mut affected: Vec<QuorumAtRisk> = self
+            .attestations
+            .values()
+            .filter(|a| a.actor_did == declaration.actor && declaration.covers(a.timestamp_ms))
+            .filter(|a| a.verifier_dids.contains(&declaration.verifier))
+            .filter_map(|a| {
+                let counting = a.counting_verifiers().filter(|v| {
+                    **v != declaration.verifier || treatment == ConflictTreatment::CountedFlagged
+                });
+                let counting_verifiers = counting.count();
+                (treatment == ConflictTreatment::Forbidden || counting_verifiers < quorum)
+                    .then(|| QuorumAtRisk { attestation_id: a.id.clone(), treatment, counting_verifiers })
+            })
+            .collect();
+        affected.sort_by(|a, b| a.attestation_id.0.cmp(&b.attestation_id.0));
+        Ok(ConflictReevaluation { declaration, quorum, quorum_no_longer_holds: affected })
+    }
+
+    /// The treatment of every listed verifier with a declared conflict with `actor` at `at_ms`.
+    pub(crate) fn applied_conflicts(&self, actor: &Did, verifiers: &[Did], at_ms: u64) -> Vec<AppliedConflict> {
+        let policy = &self.verifiers.policy.conflicts;
+        verifiers
+            .iter()
+            .filter_map(|verifier| {
+                self.verifiers
+                    .conflicts_between(verifier, actor, at_ms)
+                    .map(|d| (policy.treatment(d.relationship), d.relationship))
+                    .max()
+                    .map(|(treatment, relationship)| AppliedConflict {
+                        verifier: verifier.clone(),
+                        relationship,
+                        treatment,
+                    })
+            })
+            .collect()
+    }
+
+    /// Whether `verifier`'s approval counts toward a verification quorum for `actor`'s claim at `at_ms`.
+    pub(crate) fn counts_toward_quorum(&self, verifier: &Did, actor: &Did, at_ms: u64) -> bool {
+        self.applied_conflicts(actor, std::slice::from_ref(verifier), at_ms)
+            .iter()
+            .all(|c| c.treatment == ConflictTreatment::CountedFlagged)
+    }
+}
+
+/// Refusal of a claim listing a verifier whose conflict is `Forbidden`.
+pub(crate) fn forbidden(conflicts: &[AppliedConflict], actor: &Did) -> Result<(), Reason> {
+    match conflicts.iter().find(|c| c.treatment == ConflictTreatment::Forbidden) {
+        Some(c) => Err(Reason::new(ReasonCode::VerifierConflictForbidden)
+            .with("verifier", &c.verifier)
+            .with("actor", actor)
+            .with("relationship", c.relationship.as_str())),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,103 @@
+// path: planetary_stewardship_runtime/src/consent_evidence.rs
+
+//! Evidence behind consent grants: where it may live, and proof it has not changed.
+//! - `ConsentRecord.evidence_hash` pins the content at `evidence_uri` (e.g. a signed
+//!   consent PDF) as it was when consent was given
+//! - `ConsentEvidencePolicy` on the registry limits URI schemes and makes the hash mandatory
+//!   for chosen modules, or for modules whose actions ask consent for chosen purposes
+//! - `ConsentRegistry::submit_consent` checks a grant against the policy and hands URI and
+//!   hash to the registry's `EvidenceVerifier`; the default verifier fetches nothing, so the
+//!   core stays dependency-free. `upsert_consent` stays unchecked, for records accepted
+//!   before (store reloads, fixtures)
+//! - Withdrawals are never refused, whatever their evidence
+//! - With strict evidence, a grant the policy wants hashed but that carries no hash does
+//!   not count as valid consent, however it got into the registry
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+
+use crate::consent_requirements::{consent_scope, ActionKind};
+use crate::{ConsentRecord, Reason, ReasonCode, StewardModule};
+
+/// Looks at the content behind an evidence URI.
+pub trait EvidenceVerifier: Send + Sync {
+    /// The hash of what `uri` holds now, in the form records carry (e.g. `sha256:<hex>`),
+    /// or `None` if this verifier does not fetch; an error if the content cannot be read.
+    fn content_hash(&self, uri: &str) -> Result<Option<String>, String>;
+}
+
+/// Fetches nothing; hashes are recorded as given.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopEvidenceVerifier;
+
+impl EvidenceVerifier for NoopEvidenceVerifier {
+    fn content_hash(&self, _uri: &str) -> Result<Option<String>, String> {
+        Ok(None)
+    }
+}
+
+/// What a consent grant's evidence must satisfy. The default accepts anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentEvidencePolicy {
+    /// Lowercase URI schemes (`https`, `ipfs`, ...) an `evidence_uri` may use; empty allows any.
+    #[serde(default)]
+    pub allowed_schemes: BTreeSet<String>,
+    /// Modules whose grants must carry `evidence_hash`.
+    #[serde(default)]
+    pub hash_required_modules: BTreeSet<StewardModule>,
+    /// Consent purposes (e.g. `MME_ASSIGNMENT_PURPOSE`) whose grants must carry `evidence_hash`.
+    #[serde(default)]
+    pub hash_required_purposes: BTreeSet<String>,
+}
+
+impl ConsentEvidencePolicy {
+    /// Whether a grant in `module` must carry a hash: listed itself, or one of its actions
+    /// asks consent for a listed purpose.
+    pub fn hash_required(&self, module: StewardModule) -> bool {
+        self.hash_required_modules.contains(&module)
+            || ActionKind::ALL
+                .into_iter()
+                .filter(|action| action.runs_in(module))
+                .filter_map(|action| consent_scope(action))
+                .any(|(_, purpose)| self.hash_required_purposes.contains(purpose))
+    }
+
+    /// Whether `record` lacks a hash the policy requires; only grants can.
+    pub fn missing_hash(&self, record: &ConsentRecord) -> bool {
+        record.consent_given && record.evidence_hash.is_none() && self.hash_required(record.module)
+    }
+
+    /// `record` against the policy, then its hash against what `verifier` finds at its URI.
+    pub fn check(&self, record: &ConsentRecord, verifier: &dyn EvidenceVerifier) -> Result<(), Reason> {
+        if !record.consent_given {
+            return Ok(());
+        }
+        if let Some(uri) = &record.evidence_uri {
+            let scheme = uri.split_once(':').map(|(s, _)| s.to_ascii_lowercase()).unwrap_or_default();
+            if !self.allowed_schemes.is_empty() && !self.allowed_schemes.contains(&scheme) {
+                let allowed: Vec<&str> = self.allowed_schemes.iter().map(String::as_str).collect();
+                return Err(Reason::new(ReasonCode::ConsentEvidenceScheme)
+                    .with("uri", uri)
+                    .with("allowed", allowed.join(", ")));
+            }
+        }
+        if self.missing_hash(record) {
+            return Err(Reason::new(ReasonCode::ConsentEvidenceHashMissing)
+                .with("participant", &record.participant)
+                .with("module", format!("{:?}", record.module)));
+        }
+        if let (Some(uri), Some(expected)) = (&record.evidence_uri, &record.evidence_hash) {
+            let found = verifier.content_hash(uri).map_err(|detail| {
+                Reason::new(ReasonCode::ConsentEvidenceUnreadable).with("uri", uri).with("detail", detail)
+            })?;
+            if let Some(actual) = found.filter(|actual| actual != expected) {
+                return Err(Reason::new(ReasonCode::ConsentEvidenceHashMismatch)
+                    .with("uri", uri)
+                    .with("expected", expected)
+                    .with("actual", actual));
+            }
+        }
+        Ok(())
+    }
+}
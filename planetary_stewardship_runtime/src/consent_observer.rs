@@ -0,0 +1,165 @@
+// path: planetary_stewardship_runtime/src/consent_observer.rs
+
+//! Telling dependent engines when consent changes.
+//! - `ConsentObserver`s registered on a `ConsentRegistry` hear every grant, withdrawal and
+//!   expiry, after the registry has recorded it: an observer reads the new state, never a
+//!   half-applied one
+//! - An observer that errors or panics is recorded as an `ObserverFailure`; the change
+//!   stands, and the other observers still hear it
+//! - Engines observe their own registry through a `ConsentEventQueue` and act on it when the
+//!   `ConsentRegistryMut` returned by their `consent_mut` is dropped:
+//!   `MicroMissionsEngine` suspends open assignments whose consent basis is gone (and resumes
+//!   them on re-grant), `PlanetaryLedger` sweeps retention if told to with
+//!   `with_retention_on_withdrawal`
+
+use serde::{Serialize, Deserialize};
+use std::ops::{Deref, DerefMut};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use crate::{ConsentRecord, ConsentRegistry, Did, StewardModule};
+
+/// What happened to a consent record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConsentChange {
+    Granted,
+    Withdrawn,
+    /// A grant lapsed; see `ConsentRegistry::expire_grants`.
+    Expired,
+}
+
+/// A change and the record as the registry now holds it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentEvent {
+    pub change: ConsentChange,
+    pub record: ConsentRecord,
+}
+
+/// Hears consent changes; every callback defaults to doing nothing.
+pub trait ConsentObserver: Send + Sync {
+    fn on_grant(&self, _record: &ConsentRecord) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_withdrawal(&self, _record: &ConsentRecord) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_expiry(&self, _record: &ConsentRecord) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// An observer that errored or panicked on a change the registry kept.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ObserverFailure {
+    /// Name the observer was registered under.
+    pub observer: String,
+    pub change: ConsentChange,
+    pub participant: Did,
+    pub module: StewardModule,
+    pub message: String,
+}
+
+/// Collects events for whoever holds a clone to act on later.
+#[derive(Debug, Clone, Default)]
+pub struct ConsentEventQueue {
+    events: Arc<Mutex<Vec<ConsentEvent>>>,
+}
+
+impl ConsentEventQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queued events, oldest first; the queue is left empty.
+    pub fn take(&self) -> Vec<ConsentEvent> {
+        std::mem::take(&mut *self.events.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    fn push(&self, change: ConsentChange, record: &ConsentRecord) -> Result<(), String> {
+        let mut events = self.events.lock().map_err(|_| "Consent event queue lock poisoned".to_string())?;
+        events.push(ConsentEvent { change, record: record.clone() });
+        Ok(())
+    }
+}
+
+impl ConsentObserver for ConsentEventQueue {
+    fn on_grant(&self, record: &ConsentRecord) -> Result<(), String> {
+        self.push(ConsentChange::Granted, record)
+    }
+
+    fn on_withdrawal(&self, record: &ConsentRecord) -> Result<(), String> {
+        self.push(ConsentChange::Withdrawn, record)
+    }
+
+    fn on_expiry(&self, record: &ConsentRecord) -> Result<(), String> {
+        self.push(ConsentChange::Expired, record)
+    }
+}
+
+/// Call `observer` for `change`, turning an error or panic into a failure message.
+pub(crate) fn notify(
+    observer: &dyn ConsentObserver,
+    change: ConsentChange,
+    record: &ConsentRecord,
+) -> Result<(), String> {
+    let outcome = catch_unwind(AssertUnwindSafe(|| match change {
+        ConsentChange::Granted => observer.on_grant(record),
+        ConsentChange::Withdrawn => observer.on_withdrawal(record),
+        ConsentChange::Expired => observer.on_expiry(record),
+    }));
+    match outcome {
+        Ok(result) => result,
+        Err(panic) => Err(match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+            (Some(message), _) => format!("Observer panicked: {message}"),
+            (_, Some(message)) => format!("Observer panicked: {message}"),
+            _ => "Observer panicked".to_string(),
+        }),
+    }
+}
+
+/// An engine owning a consent registry it observes.
+pub trait ConsentDependent {
+    fn registry(&self) -> &ConsentRegistry;
+
+    fn registry_mut(&mut self) -> &mut ConsentRegistry;
+
+    /// Act on the changes queued since the last call.
+    fn apply_consent_events(&mut self);
+}
+
+/// Mutable access to an engine's consent registry; the engine acts on the changes made
+/// through it when it is dropped.
+pub struct ConsentRegistryMut<'a, E: ConsentDependent + ?Sized> {
+    engine: &'a mut E,
+}
+
+impl<'a, E: ConsentDependent + ?Sized> ConsentRegistryMut<'a, E> {
+    pub(crate) fn new(engine: &'a mut E) -> Self {
+        Self { engine }
+    }
+}
+
+impl<E: ConsentDependent + ?Sized> Deref for ConsentRegistryMut<'_, E> {
+    type Target = ConsentRegistry;
+
+    fn deref(&self) -> &ConsentRegistry {
+        self.engine.registry()
+    }
+}
+
+impl<E: ConsentDependent + ?Sized> DerefMut for ConsentRegistryMut<'_, E> {
+    fn deref_mut(&mut self) -> &mut ConsentRegistry {
+        self.engine.registry_mut()
+    }
+}
+
+impl<E: ConsentDependent + ?Sized> Drop for ConsentRegistryMut<'_, E> {
+    fn drop(&mut self) {
+        self.engine.apply_consent_events();
+    }
+}
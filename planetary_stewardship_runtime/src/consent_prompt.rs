@@ -0,0 +1,96 @@
+// path: planetary_stewardship_runtime/src/consent_prompt.rs
+
+//! Consent prompt descriptors: what a participant is shown before consenting.
+//! - Generated from the module, mission and SAEP decision, so front-ends stop hand-writing scope
+//! - `canonical_hash` is stored on the `ConsentRecord` as `prompt_hash`
+//! - Under strict prompts, KSCP treats consent as invalid once the descriptor for that action changes
+//! - PLGA prompts describe the module only (the ledger holds no mission templates);
+//!   MME prompts include the mission title and description
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+use crate::{EthicsDecision, MissionId, MissionTemplate, StewardModule};
+
+/// Bump when the descriptor layout or wording rules change; old hashes then stop matching.
+pub const CONSENT_PROMPT_VERSION: u32 = 1;
+
+/// Purpose the ledger uses when checking consent for an attestation.
+pub const PLGA_ATTESTATION_PURPOSE: &str = "Record attestations of your stewardship work";
+
+/// Purpose MME uses when checking consent for a mission assignment.
+pub const MME_ASSIGNMENT_PURPOSE: &str = "Assign you this micro-mission";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentPromptDescriptor {
+    pub version: u32,
+    pub module: StewardModule,
+    #[serde(default)]
+    pub mission_id: Option<MissionId>,
+    #[serde(default)]
+    pub mission_title: Option<String>,
+    #[serde(default)]
+    pub mission_description: Option<String>,
+    pub purpose: String,
+    /// What will be recorded about the participant, one item per line of the dialog.
+    pub recorded_data: Vec<String>,
+    pub retention: String,
+    /// An entry naming the participant will appear in the public intent log.
+    pub public_intent_log: bool,
+    /// The action can be undone through a registered rollback plan.
+    pub rollback_plan_required: bool,
+}
+
+impl ConsentPromptDescriptor {
+    pub fn for_action(
+        module: StewardModule,
+        mission: Option<&MissionTemplate>,
+        purpose: &str,
+        decision: &EthicsDecision,
+    ) -> Self {
+        let mut recorded_data = match module {
+            StewardModule::PLGA => vec![
+                "Your DID as the attesting actor".to_string(),
+                "The description, impact metrics and evidence link you submit".to_string(),
+                "The DIDs of any verifiers you name".to_string(),
+            ],
+            StewardModule::MME => vec![
+                "Your DID as the assignee".to_string(),
+                "The mission and the time it was assigned".to_string(),
+            ],
+            other => vec![format!("Your DID and the actions you take in {other:?}")],
+        };
+        if decision.require_public_intent_log {
+            recorded_data.push("A public intent entry naming you and this action".to_string());
+        }
+        let retention = match module {
+            StewardModule::PLGA => {
+                "Attestations are kept and cannot be transferred; a rollback plan can revoke one, \
+                 but the fact it was issued stays on record"
+            }
+            StewardModule::MME => "Assignments are kept while the mission is active and in its history afterwards",
+            _ => "Records are kept for the lifetime of the module's ledger",
+        };
+        Self {
+            version: CONSENT_PROMPT_VERSION,
+            module,
+            mission_id: mission.map(|m| m.id.clone()),
+            mission_title: mission.map(|m| m.title.clone()),
+            mission_description: mission.map(|m| m.description.clone()),
+            purpose: purpose.to_string(),
+            recorded_data,
+            retention: retention.to_string(),
+            public_intent_log: decision.require_public_intent_log,
+            rollback_plan_required: decision.require_rollback_plan,
+        }
+    }
+
+    /// SHA-256 over the serialized descriptor (struct field order is fixed).
+    pub fn canonical_hash(&self) -> String {
+        let payload = serde_json::to_vec(self).expect("consent prompt serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&payload);
+        format!("{:x}", hasher.finalize())
+    }
+}
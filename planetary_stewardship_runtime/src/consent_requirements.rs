@@ -0,0 +1,165 @@
+// path: planetary_stewardship_runtime/src/consent_requirements.rs
+
+//! KSCP consent an action needs, known before attempting it.
+//! - `consent_requirements(config, module, action)` reads the module's effective SAEP flags;
+//!   `issue_attestation` and `assign_mission` (and their `validate_*` dry runs) check consent
+//!   through the returned requirements, so the preview and enforcement cannot drift apart
+//! - A scope is looked up as (the party's DID, `module`, the action's mission): consent for
+//!   another mission, or module-wide consent for a mission action, does not stand in
+//! - Freshness: only the latest record per scope counts, and under strict prompts its
+//!   `prompt_hash` must match the prompt built from what `freshness` lists
+//! - Proposals and self-published intent entries collect no consent; nor does any action
+//!   ask affected parties (e.g. named verifiers) today
+
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    ConsentPromptDescriptor, ConsentRegistry, Did, MissionId, Reason, ReasonCode, SaepConfig, StewardModule,
+    MME_ASSIGNMENT_PURPOSE, PLGA_ATTESTATION_PURPOSE,
+};
+
+/// Operations that can be refused for missing consent, or are asked about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ActionKind {
+    IssueAttestation,
+    AssignMission,
+    ApplyProposal,
+    LogIntent,
+}
+
+impl ActionKind {
+    pub const ALL: [ActionKind; 4] =
+        [ActionKind::IssueAttestation, ActionKind::AssignMission, ActionKind::ApplyProposal, ActionKind::LogIntent];
+
+    /// Whether the action runs in `module`: attestations are any module's but MME's (PLGA's,
+    /// or another's under its `MetricSchema`), assignments MME's, proposals and intent entries
+    /// any module's.
+    pub fn runs_in(self, module: StewardModule) -> bool {
+        match self {
+            ActionKind::IssueAttestation => module != StewardModule::MME,
+            ActionKind::AssignMission => module == StewardModule::MME,
+            ActionKind::ApplyProposal | ActionKind::LogIntent => true,
+        }
+    }
+}
+
+/// Whose consent a scope is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConsentParty {
+    /// The attesting actor.
+    Actor,
+    /// The participant a mission is assigned to.
+    Assignee,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentScope {
+    pub party: ConsentParty,
+    pub module: StewardModule,
+    /// Purpose of the consent prompt shown for the action.
+    pub purpose: String,
+}
+
+/// What the consent prompt, and so a strict-prompt `prompt_hash`, depends on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentFreshness {
+    /// The prompt names the mission: editing its title or description invalidates consent.
+    pub mission_in_prompt: bool,
+    /// The prompt discloses a public intent entry (`enforce_transparency`).
+    pub discloses_public_intent_log: bool,
+    /// The prompt discloses a required rollback plan (`enforce_reversibility`).
+    pub discloses_rollback_plan: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentRequirements {
+    pub module: StewardModule,
+    pub action: ActionKind,
+    /// `false` when the action never runs in `module`; nothing is required then.
+    pub applicable: bool,
+    /// Consent to collect up front; empty when none is needed.
+    pub scopes: Vec<ConsentScope>,
+    pub affected_party_consent: bool,
+    /// Set when `scopes` is not empty.
+    pub freshness: Option<ConsentFreshness>,
+}
+
+impl ConsentRequirements {
+    pub fn required(&self) -> bool {
+        !self.scopes.is_empty()
+    }
+
+    /// Purpose of the action's consent prompt.
+    pub fn purpose(&self) -> Option<&str> {
+        self.scopes.first().map(|s| s.purpose.as_str())
+    }
+
+    /// Error enforcement returns when a scope is not met.
+    pub fn missing_reason(&self) -> String {
+        let action = match self.action {
+            ActionKind::IssueAttestation => format!("{:?} attestation", self.module),
+            ActionKind::AssignMission => "mission assignment".into(),
+            ActionKind::ApplyProposal => "governance proposal".into(),
+            ActionKind::LogIntent => "intent entry".into(),
+        };
+        format!("No valid KSCP consent for {action}")
+    }
+
+    /// `party`'s consent in every scope, for the action's `mission` and current `prompt`.
+    pub(crate) fn check(
+        &self,
+        registry: &ConsentRegistry,
+        party: &Did,
+        mission: Option<&MissionId>,
+        prompt: &ConsentPromptDescriptor,
+    ) -> Result<(), Reason> {
+        for scope in &self.scopes {
+            if !registry.has_valid_consent_for_prompt(party, scope.module, mission, prompt) {
+                return Err(Reason::new(ReasonCode::ConsentMissing)
+                    .with("detail", self.missing_reason())
+                    .with("participant", party)
+                    .with("module", format!("{:?}", scope.module)));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whose consent `action_kind` asks, and for what purpose, when consent is enforced.
+pub(crate) fn consent_scope(action_kind: ActionKind) -> Option<(ConsentParty, &'static str)> {
+    match action_kind {
+        ActionKind::IssueAttestation => Some((ConsentParty::Actor, PLGA_ATTESTATION_PURPOSE)),
+        ActionKind::AssignMission => Some((ConsentParty::Assignee, MME_ASSIGNMENT_PURPOSE)),
+        // The collective decides proposals; an intent entry is published by its own actor.
+        ActionKind::ApplyProposal | ActionKind::LogIntent => None,
+    }
+}
+
+/// Consent `action_kind` needs in `module` under `config` (with the module's overrides).
+pub fn consent_requirements(config: &SaepConfig, module: StewardModule, action_kind: ActionKind) -> ConsentRequirements {
+    let effective = config.for_module(module);
+    let applicable = action_kind.runs_in(module);
+    let scopes: Vec<ConsentScope> = consent_scope(action_kind)
+        .filter(|_| applicable && effective.enforce_informed_consent)
+        .map(|(party, purpose)| ConsentScope { party, module, purpose: purpose.to_string() })
+        .into_iter()
+        .collect();
+    let freshness = (!scopes.is_empty()).then(|| ConsentFreshness {
+        mission_in_prompt: action_kind == ActionKind::AssignMission,
+        discloses_public_intent_log: effective.enforce_transparency,
+        discloses_rollback_plan: effective.enforce_reversibility,
+    });
+    ConsentRequirements {
+        module,
+        action: action_kind,
+        applicable,
+        scopes,
+        affected_party_consent: false,
+        freshness,
+    }
+}
@@ -0,0 +1,498 @@
+// path: planetary_stewardship_runtime/src/content.rs
+
+//! Content policy for free-text descriptions that dashboards render as markdown.
+//! - Applied to attestation descriptions at issuance and to template descriptions (every
+//!   language) at `register_template` and bundle load; only the sanitized text is stored
+//! - Violations refuse the text rather than truncating it: over `max_chars`, a control or
+//!   invisible formatting character (bidi overrides, zero-width joiners), a character outside
+//!   `allowed_classes`, or PII under `PiiAction::Reject`
+//! - Sanitization keeps the markdown subset and removes raw HTML (with `script`/`style`
+//!   bodies and comments), and links, images and autolinks whose scheme is not in
+//!   `allowed_link_schemes`; line endings become `\n` and the ends are trimmed
+//! - Sanitizing repeats until nothing changes, so sanitized text sanitizes to itself and
+//!   hashes over it are stable
+//! - PII (emails, phone numbers) and `flagged_terms` are matched after sanitizing; flags are
+//!   warnings, and never stop issuance
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+
+use crate::validation::{ValidationCode, ValidationReport};
+use crate::{LanguageTag, MicroMissionsEngine, MissionTemplate, PlanetaryLedger, Reason, ReasonCode};
+
+/// What PII in a description does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PiiAction {
+    /// Accepted with a warning; descriptions sometimes need contact details.
+    #[default]
+    Warn,
+    Reject,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CharClass {
+    Letter,
+    Digit,
+    /// Spaces, tabs and newlines.
+    Whitespace,
+    Punctuation,
+    /// Everything else printable: currency, math, emoji.
+    Symbol,
+}
+
+impl CharClass {
+    pub fn of(c: char) -> Self {
+        if c.is_alphabetic() {
+            CharClass::Letter
+        } else if c.is_numeric() {
+            CharClass::Digit
+        } else if c.is_whitespace() {
+            CharClass::Whitespace
+        } else if c.is_ascii_punctuation() || is_unicode_punctuation(c) {
+            CharClass::Punctuation
+        } else {
+            CharClass::Symbol
+        }
+    }
+}
+
+/// General punctuation, CJK and fullwidth punctuation outside ASCII.
+fn is_unicode_punctuation(c: char) -> bool {
+    matches!(c, '\u{00A1}' | '\u{00AB}' | '\u{00BB}' | '\u{00BF}' | '\u{2010}'..='\u{2027}' | '\u{2030}'..='\u{205E}'
+        | '\u{3001}'..='\u{3003}' | '\u{3008}'..='\u{3011}' | '\u{FF01}'..='\u{FF0F}')
+}
+
+/// Controls other than tab and newline, and invisible characters that reorder or hide text.
+fn is_forbidden(c: char) -> bool {
+    (c.is_control() && c != '\n' && c != '\t')
+        || matches!(c, '\u{061C}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}' | '\u{FEFF}')
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PiiKind {
+    Email,
+    /// A `+` or separator-written number of 10 to 15 digits.
+    Phone,
+}
+
+/// One finding about a text. Offsets count characters of the sanitized text, except for
+/// `DisallowedCharacter`, which counts the submitted text with line endings normalized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ContentFinding {
+    TooLong { chars: usize, max: usize },
+    /// First occurrence of each offending code point, written `U+XXXX`.
+    DisallowedCharacter { code_point: String, offset: usize },
+    /// Raw HTML removed by sanitizing, by tag name (`!--` for comments).
+    RawHtml { tag: String },
+    /// A link, image or autolink removed by sanitizing; its text is kept.
+    UnsafeLink { scheme: String },
+    Pii { kind: PiiKind, offset: usize },
+    FlaggedTerm { term: String },
+}
+
+impl ContentFinding {
+    /// The finding as a coded reason about `field`.
+    pub fn reason(&self, field: &str) -> Reason {
+        let reason = match self {
+            ContentFinding::TooLong { chars, max } => {
+                Reason::new(ReasonCode::ContentTooLong).with("chars", chars).with("max", max)
+            }
+            ContentFinding::DisallowedCharacter { code_point, offset } => {
+                Reason::new(ReasonCode::ContentDisallowedCharacter)
+                    .with("code_point", code_point)
+                    .with("offset", offset)
+            }
+            ContentFinding::RawHtml { tag } => {
+                Reason::new(ReasonCode::ContentSanitized).with("removed", format!("raw HTML <{tag}>"))
+            }
+            ContentFinding::UnsafeLink { scheme } => {
+                Reason::new(ReasonCode::ContentSanitized).with("removed", format!("a {scheme}: link"))
+            }
+            ContentFinding::Pii { kind, offset } => {
+                let kind = match kind {
+                    PiiKind::Email => "an email address",
+                    PiiKind::Phone => "a phone number",
+                };
+                Reason::new(ReasonCode::ContentPii).with("kind", kind).with("offset", offset)
+            }
+            ContentFinding::FlaggedTerm { term } => Reason::new(ReasonCode::ContentFlaggedTerm).with("term", term),
+        };
+        reason.with("field", field)
+    }
+}
+
+/// `ContentPolicy::check`'s result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ContentCheck {
+    /// The text to store; empty when there are violations.
+    pub sanitized: String,
+    /// Findings that refuse the text.
+    pub violations: Vec<ContentFinding>,
+    /// What sanitizing removed, and flags.
+    pub warnings: Vec<ContentFinding>,
+}
+
+impl ContentCheck {
+    pub fn is_accepted(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Limits on description text. The default allows every character class and 8 000 characters,
+/// and warns on PII.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ContentPolicy {
+    /// In characters, of the submitted text.
+    pub max_chars: usize,
+    pub allowed_classes: BTreeSet<CharClass>,
+    /// Lowercase; links without a scheme (relative, fragment) are always kept.
+    pub allowed_link_schemes: Vec<String>,
+    /// `None` does not look for PII.
+    #[serde(default)]
+    pub pii: Option<PiiAction>,
+    /// Single words flagged for moderation wherever they occur as a whole word, in any case.
+    #[serde(default)]
+    pub flagged_terms: Vec<String>,
+}
+
+impl Default for ContentPolicy {
+    fn default() -> Self {
+        Self {
+            max_chars: 8_000,
+            allowed_classes: [
+                CharClass::Letter,
+                CharClass::Digit,
+                CharClass::Whitespace,
+                CharClass::Punctuation,
+                CharClass::Symbol,
+            ]
+            .into(),
+            allowed_link_schemes: ["http", "https", "mailto", "ipfs"].map(String::from).to_vec(),
+            pii: Some(PiiAction::Warn),
+            flagged_terms: Vec::new(),
+        }
+    }
+}
+
+impl ContentPolicy {
+    /// Check and sanitize `text`.
+    pub fn check(&self, text: &str) -> ContentCheck {
+        let mut check = ContentCheck { sanitized: String::new(), violations: Vec::new(), warnings: Vec::new() };
+        let chars = text.chars().count();
+        if chars > self.max_chars {
+            check.violations.push(ContentFinding::TooLong { chars, max: self.max_chars });
+            return check;
+        }
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let mut seen = BTreeSet::new();
+        for (offset, c) in text.chars().enumerate() {
+            if (is_forbidden(c) || !self.allowed_classes.contains(&CharClass::of(c))) && seen.insert(c) {
+                let code_point = format!("U+{:04X}", c as u32);
+                check.violations.push(ContentFinding::DisallowedCharacter { code_point, offset });
+            }
+        }
+        if !check.violations.is_empty() {
+            return check;
+        }
+
+        let mut sanitized = text.trim().to_string();
+        loop {
+            let next = self.sanitize_pass(&sanitized, &mut check.warnings);
+            let next = next.trim().to_string();
+            if next == sanitized {
+                break;
+            }
+            sanitized = next;
+        }
+        if let Some(action) = self.pii {
+            for finding in find_pii(&sanitized) {
+                match action {
+                    PiiAction::Warn => check.warnings.push(finding),
+                    PiiAction::Reject => check.violations.push(finding),
+                }
+            }
+        }
+        let words: BTreeSet<String> = sanitized
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        for term in &self.flagged_terms {
+            if words.contains(&term.to_lowercase()) {
+                check.warnings.push(ContentFinding::FlaggedTerm { term: term.clone() });
+            }
+        }
+        if check.violations.is_empty() {
+            check.sanitized = sanitized;
+        }
+        check
+    }
+
+    /// The sanitized text, or the first violation as a reason about `field`.
+    pub fn apply(&self, field: &str, text: &str) -> Result<String, Reason> {
+        let check = self.check(text);
+        match check.violations.first() {
+            Some(violation) => Err(violation.reason(field)),
+            None => Ok(check.sanitized),
+        }
+    }
+
+    fn scheme_allowed(&self, scheme: &str) -> bool {
+        self.allowed_link_schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme))
+    }
+
+    /// One left-to-right pass removing raw HTML and unsafe links.
+    fn sanitize_pass(&self, text: &str, removed: &mut Vec<ContentFinding>) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut out = String::with_capacity(text.len());
+        let mut i = 0;
+        while i < chars.len() {
+            match chars[i] {
+                '<' => match self.html_at(&chars, i) {
+                    Html::Keep => {
+                        out.push('<');
+                        i += 1;
+                    }
+                    Html::Autolink(end) => {
+                        out.extend(&chars[i..end]);
+                        i = end;
+                    }
+                    Html::Remove { end, finding } => {
+                        removed.push(finding);
+                        i = end;
+                    }
+                },
+                '!' | '[' => match self.link_at(&chars, i) {
+                    Some((end, text_range, scheme)) => {
+                        out.extend(&chars[text_range]);
+                        removed.push(ContentFinding::UnsafeLink { scheme });
+                        i = end;
+                    }
+                    None => {
+                        out.push(chars[i]);
+                        i += 1;
+                    }
+                },
+                c => {
+                    out.push(c);
+                    i += 1;
+                }
+            }
+        }
+        out
+    }
+
+    /// What to do with the `<` at `start`.
+    fn html_at(&self, chars: &[char], start: usize) -> Html {
+        let next = chars.get(start + 1).copied();
+        if !next.is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?')) {
+            return Html::Keep;
+        }
+        let close = |from: usize, pattern: &str| -> Option<usize> {
+            let pattern: Vec<char> = pattern.chars().collect();
+            (from..chars.len()).find(|&j| {
+                chars[j..].len() >= pattern.len()
+                    && chars[j..j + pattern.len()].iter().zip(&pattern).all(|(a, b)| a.eq_ignore_ascii_case(b))
+            }).map(|j| j + pattern.len())
+        };
+        if chars[start + 1..].starts_with(&['!', '-', '-']) {
+            let end = close(start + 4, "-->").unwrap_or(chars.len());
+            return Html::Remove { end, finding: ContentFinding::RawHtml { tag: "!--".into() } };
+        }
+        let Some(gt) = chars[start..].iter().position(|&c| c == '>').map(|p| start + p) else {
+            // Unclosed: not a tag to a renderer once the `<` is gone.
+            return Html::Remove { end: start + 1, finding: ContentFinding::RawHtml { tag: String::new() } };
+        };
+        let inner: String = chars[start + 1..gt].iter().collect();
+        if let Some((scheme, rest)) = inner.split_once(':') {
+            if is_scheme(scheme) && !rest.is_empty() && !inner.contains(char::is_whitespace) {
+                if self.scheme_allowed(scheme) {
+                    return Html::Autolink(gt + 1);
+                }
+                let finding = ContentFinding::UnsafeLink { scheme: scheme.to_lowercase() };
+                return Html::Remove { end: gt + 1, finding };
+            }
+        }
+        let tag: String = inner
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        let opening = !inner.starts_with('/') && !inner.ends_with('/');
+        let end = match tag.as_str() {
+            "script" | "style" if opening => close(gt + 1, &format!("</{tag}"))
+                .map(|after| chars[after..].iter().position(|&c| c == '>').map_or(chars.len(), |p| after + p + 1))
+                .unwrap_or(chars.len()),
+            _ => gt + 1,
+        };
+        Html::Remove { end, finding: ContentFinding::RawHtml { tag } }
+    }
+
+    /// An unsafe `[text](target)` or `![alt](target)` at `start`: its end, the range of its
+    /// text and the offending scheme.
+    fn link_at(&self, chars: &[char], start: usize) -> Option<(usize, std::ops::Range<usize>, String)> {
+        let open = if chars[start] == '!' { start + 1 } else { start };
+        if chars.get(open) != Some(&'[') {
+            return None;
+        }
+        let close = open + chars[open..].iter().position(|&c| c == ']')?;
+        if chars.get(close + 1) != Some(&'(') {
+            return None;
+        }
+        // Destinations may hold balanced parentheses, as in `javascript:alert(1)`.
+        let mut depth = 0usize;
+        let end = (close + 1..chars.len()).find(|&j| {
+            match chars[j] {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            depth == 0
+        })?;
+        let target: String = chars[close + 2..end].iter().collect();
+        let target = target.trim().split(char::is_whitespace).next().unwrap_or("");
+        let target = target.trim_start_matches('<');
+        let prefix = target.split(['/', '?', '#']).next().unwrap_or("");
+        let scheme = match prefix.split_once(':') {
+            Some((scheme, _)) if is_scheme(scheme) && self.scheme_allowed(scheme) => return None,
+            Some((scheme, _)) => scheme.to_lowercase(),
+            // An entity or escape could be hiding the colon.
+            None if prefix.contains(['&', '%', '\\']) => prefix.to_lowercase(),
+            None => return None,
+        };
+        Some((end + 1, open + 1..close, scheme))
+    }
+}
+
+enum Html {
+    Keep,
+    /// An allowed autolink ending before the index.
+    Autolink(usize),
+    Remove { end: usize, finding: ContentFinding },
+}
+
+/// A letter followed by letters, digits, `+`, `-` or `.`.
+fn is_scheme(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Emails and phone numbers, in order of offset.
+fn find_pii(text: &str) -> Vec<ContentFinding> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut found = Vec::new();
+    let local = |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-');
+    let domain = |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '-');
+    for (at, _) in chars.iter().enumerate().filter(|(_, &c)| c == '@') {
+        let start = (0..at).rev().take_while(|&j| local(chars[j])).last();
+        let end = (at + 1..chars.len()).take_while(|&j| domain(chars[j])).last();
+        let (Some(start), Some(end)) = (start, end) else { continue };
+        let host: String = chars[at + 1..=end].iter().collect();
+        let host = host.trim_end_matches(['.', '-']);
+        let tld = host.rsplit('.').next().unwrap_or("");
+        if host.contains('.') && tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()) {
+            found.push(ContentFinding::Pii { kind: PiiKind::Email, offset: start });
+        }
+    }
+    let separator = |c: char| matches!(c, ' ' | '-' | '.' | '(' | ')');
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let boundary = i == 0 || !chars[i - 1].is_alphanumeric();
+        if !(boundary && (c.is_ascii_digit() || c == '+' || c == '(')) {
+            i += 1;
+            continue;
+        }
+        let mut j = i + 1;
+        while j < chars.len() && (chars[j].is_ascii_digit() || separator(chars[j])) {
+            j += 1;
+        }
+        let mut end = j;
+        while end > i && !chars[end - 1].is_ascii_digit() {
+            end -= 1;
+        }
+        let run = &chars[i..end];
+        let digits = run.iter().filter(|c| c.is_ascii_digit()).count();
+        let written = c == '+' || run.iter().any(|&c| separator(c));
+        let bounded = chars.get(end).is_none_or(|c| !c.is_alphanumeric());
+        if (10..=15).contains(&digits) && written && bounded {
+            found.push(ContentFinding::Pii { kind: PiiKind::Phone, offset: i });
+        }
+        i = j.max(i + 1);
+    }
+    found.sort_by_key(|f| match f {
+        ContentFinding::Pii { offset, .. } => *offset,
+        _ => 0,
+    });
+    found
+}
+
+/// Report `text`'s violations as `ValidationCode::Content` issues and its findings as warnings;
+/// returns the text issuance would store, or `text` itself when it would be refused.
+pub(crate) fn report(policy: &ContentPolicy, field: &str, text: &str, report: &mut ValidationReport) -> String {
+    let check = policy.check(text);
+    for violation in &check.violations {
+        report.check(ValidationCode::Content, Err(violation.reason(field)));
+    }
+    for warning in &check.warnings {
+        let code = match warning {
+            ContentFinding::RawHtml { .. } | ContentFinding::UnsafeLink { .. } => ValidationCode::ContentSanitized,
+            _ => ValidationCode::ContentFlagged,
+        };
+        report.warn(code, warning.reason(field));
+    }
+    if check.is_accepted() {
+        check.sanitized
+    } else {
+        text.to_string()
+    }
+}
+
+impl PlanetaryLedger {
+    /// Limits on attestation descriptions; see `ContentPolicy`.
+    pub fn with_content_policy(mut self, policy: ContentPolicy) -> Self {
+        self.content_policy = policy;
+        self
+    }
+
+    pub fn content_policy(&self) -> &ContentPolicy {
+        &self.content_policy
+    }
+}
+
+impl MicroMissionsEngine {
+    /// Limits on template descriptions, applied by `register_template` and `load_templates_toml`.
+    pub fn with_content_policy(mut self, policy: ContentPolicy) -> Self {
+        self.content_policy = policy;
+        self
+    }
+
+    pub fn content_policy(&self) -> &ContentPolicy {
+        &self.content_policy
+    }
+
+    /// Replace the template's descriptions, in every language, with their sanitized text.
+    pub(crate) fn sanitize_descriptions(&self, tpl: &mut MissionTemplate) -> Result<(), String> {
+        let id = tpl.id.clone();
+        let refused = |reason: Reason| format!("Mission template {id}: {reason}");
+        tpl.description = self.content_policy.apply("description", &tpl.description).map_err(refused)?;
+        let mut langs: Vec<LanguageTag> = tpl.localized_content.keys().cloned().collect();
+        langs.sort();
+        for lang in langs {
+            let field = format!("description[{lang}]");
+            let text = &tpl.localized_content[&lang].description;
+            let sanitized = self.content_policy.apply(&field, text).map_err(refused)?;
+            tpl.localized_content.get_mut(&lang).expect("listed").description = sanitized;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,703 @@
+// path: planetary_stewardship_runtime/src/dual_control.rs
+
+//! Two-person control over sensitive administrative operations.
+//! - Suspending or restoring a verifier, pausing, freezing or resuming a module, revoking a
+//!   key and executing a retention policy are `AdminAction`s. The engines execute them only
+//!   from an `AdminApproval`, which only `DualControl` issues
+//! - Under `DualControlPolicy`, an operation that needs dual control is proposed by one admin
+//!   and waits in the pending queue until a different admin confirms it within
+//!   `confirm_window_ms`; past the window it expires and must be proposed again. A proposer
+//!   confirming their own action is refused, and the action stays pending
+//! - Operations the deployment leaves out of `dual_control` execute on proposal. A deployment
+//!   with exactly one admin opts out of dual control altogether with `opt_out_single_admin`,
+//!   which is recorded in the log with who opted out and why; with two admins or more, no one
+//!   admin can switch it off
+//! - The log is append-only: every proposal, refusal, cancellation, expiry and execution, the
+//!   latter with who proposed, who confirmed, what the action did and the target's state hash
+//!   afterwards (`AdminTarget::admin_state_hash`)
+//! - Each target is bound once, by `DualControl::bind`, and executes approvals only from that
+//!   controller: a second `DualControl`, with its own admins or in single-admin mode, can
+//!   neither queue nor execute anything on it. A target never bound executes nothing
+//! - A pending action is bound to the target it was proposed on; confirming it on another is
+//!   refused and logged, and it stays pending
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use crate::{Did, GovernanceEngine, KeyRegistry, MicroMissionsEngine, PauseId, PlanetaryLedger, StewardModule};
+
+/// How long a proposed action waits for its confirmation unless configured otherwise: an hour.
+pub const DEFAULT_CONFIRM_WINDOW_MS: u64 = 60 * 60 * 1000;
+
+/// Kinds of `AdminAction`, as a deployment configures which need dual control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AdminOperation {
+    /// Suspending a verifier for an actor, or restoring them.
+    VerifierSuspension,
+    /// Pausing, freezing or resuming a module.
+    ModulePause,
+    KeyRevocation,
+    /// Applying a retention policy to queued consent withdrawals.
+    RetentionExecution,
+}
+
+impl AdminOperation {
+    pub const ALL: [AdminOperation; 4] = [
+        AdminOperation::VerifierSuspension,
+        AdminOperation::ModulePause,
+        AdminOperation::KeyRevocation,
+        AdminOperation::RetentionExecution,
+    ];
+}
+
+/// A sensitive operation, as proposed and as executed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AdminAction {
+    /// `verifier`'s signature stops counting on `actor`'s attestations issued from now on.
+    SuspendVerifier { verifier: Did, actor: Did, reason: String },
+    RestoreVerifier { verifier: Did, actor: Did },
+    /// Emergency stop; passes SAEP and the charter first.
+    PauseModule { module: StewardModule, reason: String },
+    /// Planned freeze with the same effect and checks as a pause.
+    SetModuleReadOnly { module: StewardModule, reason: String },
+    ResumeModule { module: StewardModule, pause_id: PauseId },
+    /// Stop trusting `did`'s key `key_id` for signatures made at or after `revoked_from_ms`.
+    RevokeKey { did: Did, key_id: String, revoked_from_ms: u64, reason: String },
+    /// Apply `module`'s retention policy to every withdrawal queued in its consent registry;
+    /// PLGA or MME.
+    ExecuteRetention { module: StewardModule },
+}
+
+impl AdminAction {
+    pub fn operation(&self) -> AdminOperation {
+        match self {
+            AdminAction::SuspendVerifier { .. } | AdminAction::RestoreVerifier { .. } => {
+                AdminOperation::VerifierSuspension
+            }
+            AdminAction::PauseModule { .. }
+            | AdminAction::SetModuleReadOnly { .. }
+            | AdminAction::ResumeModule { .. } => {
+                AdminOperation::ModulePause
+            }
+            AdminAction::RevokeKey { .. } => AdminOperation::KeyRevocation,
+            AdminAction::ExecuteRetention { .. } => AdminOperation::RetentionExecution,
+        }
+    }
+}
+
+impl fmt::Display for AdminAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdminAction::SuspendVerifier { verifier, actor, .. } => {
+                write!(f, "suspend verifier {verifier} for {actor}")
+            }
+            AdminAction::RestoreVerifier { verifier, actor } => write!(f, "restore verifier {verifier} for {actor}"),
+            AdminAction::PauseModule { module, .. } => write!(f, "pause module {module:?}"),
+            AdminAction::SetModuleReadOnly { module, .. } => write!(f, "set module {module:?} read-only"),
+            AdminAction::ResumeModule { module, pause_id } => write!(f, "resume module {module:?} from {pause_id}"),
+            AdminAction::RevokeKey { did, key_id, .. } => write!(f, "revoke key {key_id} of {did}"),
+            AdminAction::ExecuteRetention { module } => write!(f, "execute {module:?} retention"),
+        }
+    }
+}
+
+/// What an executed `AdminAction` did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AdminEffect {
+    VerifierSuspended,
+    VerifierRestored,
+    /// Paused or set read-only; resuming must name `pause_id`.
+    ModuleRestricted { pause_id: PauseId },
+    ModuleResumed,
+    KeyRevoked,
+    RetentionExecuted { redactions: usize },
+}
+
+impl AdminEffect {
+    /// The restriction a pause or freeze put in force.
+    pub fn pause_id(&self) -> Option<&PauseId> {
+        match self {
+            AdminEffect::ModuleRestricted { pause_id } => Some(pause_id),
+            _ => None,
+        }
+    }
+}
+
+/// A deployment's admins and which operations need a second one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct DualControlPolicy {
+    /// DIDs allowed to propose and confirm admin actions.
+    pub admins: BTreeSet<Did>,
+    /// Operations that execute only once a second admin confirms them.
+    pub dual_control: BTreeSet<AdminOperation>,
+    pub confirm_window_ms: u64,
+}
+
+impl Default for DualControlPolicy {
+    /// No admins yet; every operation under dual control, confirmed within an hour.
+    fn default() -> Self {
+        Self {
+            admins: BTreeSet::new(),
+            dual_control: AdminOperation::ALL.into(),
+            confirm_window_ms: DEFAULT_CONFIRM_WINDOW_MS,
+        }
+    }
+}
+
+/// An action waiting for a second admin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PendingAdminAction {
+    pub action_id: String,
+    pub action: AdminAction,
+    pub proposed_by: Did,
+    /// Label of the target it was proposed on, from `DualControl::bind`; only that one executes it.
+    pub target: String,
+    pub proposed_ms: u64,
+    /// Confirmations from here on are refused.
+    pub expires_ms: u64,
+}
+
+/// What `DualControl::propose` did with an action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AdminProposal {
+    /// Waiting for a second admin until `expires_ms`.
+    Pending { action_id: String, expires_ms: u64 },
+    /// No confirmation needed; executed at once.
+    Executed { action_id: String, effect: AdminEffect },
+}
+
+impl AdminProposal {
+    /// What the action did, if it executed on proposal.
+    pub fn into_effect(self) -> Option<AdminEffect> {
+        match self {
+            AdminProposal::Executed { effect, .. } => Some(effect),
+            AdminProposal::Pending { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AdminLogEvent {
+    Proposed { action_id: String, action: AdminAction, by: Did, expires_ms: u64 },
+    /// A confirmation refused; the action stays pending unless it expired.
+    ConfirmationRefused { action_id: String, by: Did, reason: String },
+    Cancelled { action_id: String, by: Did },
+    Expired { action_id: String, action: AdminAction, proposed_by: Did },
+    /// Executed by the target; `confirmed_by` is `None` when no confirmation was needed.
+    Executed {
+        action_id: String,
+        action: AdminAction,
+        proposed_by: Did,
+        confirmed_by: Option<Did>,
+        effect: AdminEffect,
+        state_hash: String,
+    },
+    /// Approved, but refused by the target (a SAEP veto, an unknown key); nothing changed.
+    Failed {
+        action_id: String,
+        action: AdminAction,
+        proposed_by: Did,
+        confirmed_by: Option<Did>,
+        error: String,
+        state_hash: String,
+    },
+    /// Dual control switched off for this deployment.
+    SingleAdminMode { by: Did, reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AdminLogEntry {
+    pub timestamp_ms: u64,
+    pub event: AdminLogEvent,
+}
+
+/// Leave to execute one `AdminAction`; only `DualControl` issues them, and executing one
+/// consumes it.
+#[derive(Debug)]
+pub struct AdminApproval {
+    /// Id of the `DualControl` that issued it.
+    pub(crate) controller: u64,
+    pub(crate) action_id: String,
+    pub(crate) action: AdminAction,
+    pub(crate) proposed_by: Did,
+    pub(crate) confirmed_by: Option<Did>,
+}
+
+impl AdminApproval {
+    pub fn action_id(&self) -> &str {
+        &self.action_id
+    }
+
+    pub fn action(&self) -> &AdminAction {
+        &self.action
+    }
+
+    /// The admin whose name the target records on the change.
+    pub fn proposed_by(&self) -> &Did {
+        &self.proposed_by
+    }
+
+    pub fn confirmed_by(&self) -> Option<&Did> {
+        self.confirmed_by.as_ref()
+    }
+
+    /// Refusal for an action `target` does not execute.
+    pub(crate) fn not_for(&self, target: &str) -> String {
+        format!("{} is not {target}'s to execute ({})", self.action, self.action_id)
+    }
+
+    /// `Ok` if the controller `binding` names issued this approval.
+    pub(crate) fn admit(&self, target: &str, binding: &AdminBinding) -> Result<(), String> {
+        if binding.controller() != Some(self.controller) {
+            let action_id = &self.action_id;
+            return Err(format!("{target} is not bound to the controller that approved {action_id}"));
+        }
+        Ok(())
+    }
+}
+
+/// The `DualControl` a target takes approvals from: unset until `DualControl::bind`, then fixed.
+/// Clones share it; it is not serialized, so a target read back executes nothing until bound.
+#[derive(Debug, Clone, Default)]
+pub struct AdminBinding(Arc<OnceLock<BoundTo>>);
+
+#[derive(Debug)]
+struct BoundTo {
+    controller: u64,
+    label: String,
+}
+
+impl AdminBinding {
+    /// The target's name in its controller's pending actions, e.g. `GovernanceEngine 1`.
+    pub fn label(&self) -> Option<&str> {
+        self.0.get().map(|bound| bound.label.as_str())
+    }
+
+    fn controller(&self) -> Option<u64> {
+        self.0.get().map(|bound| bound.controller)
+    }
+}
+
+/// Always equal: like serialization, comparison sees a target's state, not who may change it.
+impl PartialEq for AdminBinding {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Something that executes approved admin actions: an engine, a key registry or a whole
+/// `StewardshipRuntime`.
+pub trait AdminTarget {
+    /// What the target is called in its binding's label and in refusals.
+    fn admin_target_name(&self) -> &'static str;
+
+    /// The controller it executes approvals from.
+    fn admin_binding(&self) -> AdminBinding;
+
+    /// Execute `approval`'s action at `now_ms`; actions for another target, or approved by a
+    /// controller the target is not bound to, are refused.
+    fn execute_admin(&mut self, approval: AdminApproval, now_ms: u64) -> Result<AdminEffect, String>;
+
+    /// Hash of the state admin actions change, logged after each execution.
+    fn admin_state_hash(&self) -> String;
+}
+
+/// SHA-256 over `state`'s JSON, for `AdminTarget::admin_state_hash`.
+pub(crate) fn state_hash<T: Serialize>(state: &T) -> String {
+    let payload = serde_json::to_vec(state).expect("admin state serialization");
+    format!("{:x}", Sha256::digest(&payload))
+}
+
+/// Numbers each `DualControl` in this process; targets are bound to one by its number.
+static CONTROLLERS: AtomicU64 = AtomicU64::new(1);
+
+fn next_controller() -> u64 {
+    CONTROLLERS.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Pending admin actions and the log of everything done with them.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DualControl {
+    /// Not serialized: a controller read back is a new one, and binds its targets afresh.
+    #[serde(skip, default = "next_controller")]
+    id: u64,
+    policy: DualControlPolicy,
+    pending: BTreeMap<String, PendingAdminAction>,
+    log: Vec<AdminLogEntry>,
+    /// Set by `opt_out_single_admin`.
+    single_admin: bool,
+    /// Actions proposed so far; numbers the next action id.
+    proposed: u64,
+    /// Targets bound so far; numbers the next label.
+    #[serde(default)]
+    bound: u64,
+}
+
+impl DualControl {
+    pub fn new(policy: DualControlPolicy) -> Self {
+        let (pending, log) = (BTreeMap::new(), Vec::new());
+        Self { id: next_controller(), policy, pending, log, single_admin: false, proposed: 0, bound: 0 }
+    }
+
+    /// Have `target` execute approvals from this controller and no other. A target is bound
+    /// once: binding it again here returns its label, binding it to another controller is
+    /// refused. Returns the label its pending actions carry.
+    pub fn bind(&mut self, target: &dyn AdminTarget) -> Result<String, String> {
+        let binding = target.admin_binding();
+        if binding.0.get().is_some() {
+            return self.bound_label(target);
+        }
+        let label = format!("{} {}", target.admin_target_name(), self.bound + 1);
+        binding.0.set(BoundTo { controller: self.id, label: label.clone() }).map_err(|_| {
+            format!("{} is already bound to another controller", target.admin_target_name())
+        })?;
+        self.bound += 1;
+        Ok(label)
+    }
+
+    /// The label `target` is bound here under; refused if it is bound elsewhere, or not at all.
+    fn bound_label(&self, target: &dyn AdminTarget) -> Result<String, String> {
+        let name = target.admin_target_name();
+        match target.admin_binding().0.get() {
+            Some(bound) if bound.controller == self.id => Ok(bound.label.clone()),
+            Some(_) => Err(format!("{name} is already bound to another controller")),
+            None => Err(format!("{name} is not bound to a controller; see `DualControl::bind`")),
+        }
+    }
+
+    pub fn policy(&self) -> &DualControlPolicy {
+        &self.policy
+    }
+
+    /// Oldest first.
+    pub fn log(&self) -> &[AdminLogEntry] {
+        &self.log
+    }
+
+    /// By action id; expired actions stay until the next `expire`, `propose` or `confirm`.
+    pub fn pending(&self) -> impl Iterator<Item = &PendingAdminAction> {
+        self.pending.values()
+    }
+
+    pub fn is_single_admin(&self) -> bool {
+        self.single_admin
+    }
+
+    /// Whether `action` waits for a second admin.
+    pub fn requires_confirmation(&self, action: &AdminAction) -> bool {
+        !self.single_admin && self.policy.dual_control.contains(&action.operation())
+    }
+
+    fn require_admin(&self, by: &Did) -> Result<(), String> {
+        if !self.policy.admins.contains(by) {
+            return Err(format!("{by} is not an admin of this deployment"));
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, now_ms: u64, event: AdminLogEvent) {
+        self.log.push(AdminLogEntry { timestamp_ms: now_ms, event });
+    }
+
+    /// Switch dual control off for a deployment too small for two admins; from now on every
+    /// action executes on proposal. Refused while the policy names more than one admin, so a
+    /// single admin of a larger deployment cannot use it to act alone. Recorded with `by` and
+    /// `reason`; there is no way back, since the targets bound here cannot be bound to a new
+    /// `DualControl`.
+    pub fn opt_out_single_admin(&mut self, by: &Did, reason: String, now_ms: u64) -> Result<(), String> {
+        self.require_admin(by)?;
+        if self.policy.admins.len() != 1 {
+            let admins = self.policy.admins.len();
+            return Err(format!("Only a one-admin deployment may opt out of dual control; this one has {admins}"));
+        }
+        if reason.trim().is_empty() {
+            return Err("Opting out of dual control needs a reason".into());
+        }
+        if self.single_admin {
+            return Err("This deployment already runs in single-admin mode".into());
+        }
+        self.single_admin = true;
+        self.record(now_ms, AdminLogEvent::SingleAdminMode { by: by.clone(), reason });
+        #[cfg(feature = "tracing")]
+        tracing::warn!(actor_did = %by, "dual control switched off: single-admin mode");
+        Ok(())
+    }
+
+    /// Propose `action` as admin `by`: queued for a second admin if its operation needs one,
+    /// executed on `target` otherwise. `target` must be bound here. An execution `target`
+    /// refuses is logged and returned as the error.
+    pub fn propose(
+        &mut self,
+        target: &mut dyn AdminTarget,
+        action: AdminAction,
+        by: &Did,
+        now_ms: u64,
+    ) -> Result<AdminProposal, String> {
+        self.require_admin(by)?;
+        let label = self.bound_label(target)?;
+        self.expire(now_ms);
+        self.proposed += 1;
+        let action_id = format!("admin-{}", self.proposed);
+        if !self.requires_confirmation(&action) {
+            let approval = AdminApproval {
+                controller: self.id,
+                action_id: action_id.clone(),
+                action,
+                proposed_by: by.clone(),
+                confirmed_by: None,
+            };
+            let effect = self.execute(target, approval, now_ms)?;
+            return Ok(AdminProposal::Executed { action_id, effect });
+        }
+        let expires_ms = now_ms.saturating_add(self.policy.confirm_window_ms);
+        let pending = PendingAdminAction {
+            action_id: action_id.clone(),
+            action,
+            proposed_by: by.clone(),
+            target: label,
+            proposed_ms: now_ms,
+            expires_ms,
+        };
+        let (action, by) = (pending.action.clone(), by.clone());
+        self.record(now_ms, AdminLogEvent::Proposed { action_id: action_id.clone(), action, by, expires_ms });
+        self.pending.insert(action_id.clone(), pending);
+        Ok(AdminProposal::Pending { action_id, expires_ms })
+    }
+
+    /// Confirm the pending action `action_id` as admin `by`, who must not have proposed it,
+    /// and execute it on `target`, the target it was proposed on. A refused confirmation is
+    /// logged; the action stays pending unless its window has closed.
+    pub fn confirm(
+        &mut self,
+        target: &mut dyn AdminTarget,
+        action_id: &str,
+        by: &Did,
+        now_ms: u64,
+    ) -> Result<AdminEffect, String> {
+        self.require_admin(by)?;
+        let label = self.bound_label(target);
+        let refusal = match self.pending.get(action_id) {
+            None => return Err(format!("No admin action {action_id} is pending")),
+            Some(pending) if &pending.proposed_by == by => {
+                Some(format!("{by} proposed {action_id} and cannot also confirm it"))
+            }
+            Some(pending) if now_ms >= pending.expires_ms => {
+                Some(format!("Admin action {action_id} expired at {}", pending.expires_ms))
+            }
+            Some(pending) => match &label {
+                Ok(label) if *label == pending.target => None,
+                Ok(label) => Some(format!("{action_id} was proposed on {}, not {label}", pending.target)),
+                Err(unbound) => Some(unbound.clone()),
+            },
+        };
+        if let Some(reason) = refusal {
+            let by = by.clone();
+            let event = AdminLogEvent::ConfirmationRefused { action_id: action_id.into(), by, reason: reason.clone() };
+            self.record(now_ms, event);
+            self.expire(now_ms);
+            return Err(reason);
+        }
+        let pending = self.pending.remove(action_id).expect("checked above");
+        self.expire(now_ms);
+        let approval = AdminApproval {
+            controller: self.id,
+            action_id: pending.action_id,
+            action: pending.action,
+            proposed_by: pending.proposed_by,
+            confirmed_by: Some(by.clone()),
+        };
+        self.execute(target, approval, now_ms)
+    }
+
+    /// Withdraw the pending action `action_id`; any admin may.
+    pub fn cancel(&mut self, action_id: &str, by: &Did, now_ms: u64) -> Result<PendingAdminAction, String> {
+        self.require_admin(by)?;
+        let pending = self.pending.remove(action_id).ok_or_else(|| format!("No admin action {action_id} is pending"))?;
+        self.record(now_ms, AdminLogEvent::Cancelled { action_id: action_id.into(), by: by.clone() });
+        Ok(pending)
+    }
+
+    /// Drop and log the pending actions whose window closed by `now_ms`, oldest first.
+    pub fn expire(&mut self, now_ms: u64) -> Vec<PendingAdminAction> {
+        let expired: Vec<String> =
+            self.pending.values().filter(|p| now_ms >= p.expires_ms).map(|p| p.action_id.clone()).collect();
+        let mut dropped = Vec::new();
+        for action_id in expired {
+            let pending = self.pending.remove(&action_id).expect("listed above");
+            let event = AdminLogEvent::Expired {
+                action_id,
+                action: pending.action.clone(),
+                proposed_by: pending.proposed_by.clone(),
+            };
+            self.record(now_ms, event);
+            dropped.push(pending);
+        }
+        dropped
+    }
+
+    fn execute(
+        &mut self,
+        target: &mut dyn AdminTarget,
+        approval: AdminApproval,
+        now_ms: u64,
+    ) -> Result<AdminEffect, String> {
+        let (action_id, action) = (approval.action_id.clone(), approval.action.clone());
+        let (proposed_by, confirmed_by) = (approval.proposed_by.clone(), approval.confirmed_by.clone());
+        let result = target.execute_admin(approval, now_ms);
+        let state_hash = target.admin_state_hash();
+        let event = match &result {
+            Ok(effect) => {
+                let effect = effect.clone();
+                AdminLogEvent::Executed { action_id, action, proposed_by, confirmed_by, effect, state_hash }
+            }
+            Err(error) => {
+                let error = error.clone();
+                AdminLogEvent::Failed { action_id, action, proposed_by, confirmed_by, error, state_hash }
+            }
+        };
+        self.record(now_ms, event);
+        result
+    }
+}
+
+impl AdminTarget for GovernanceEngine {
+    fn admin_target_name(&self) -> &'static str {
+        "GovernanceEngine"
+    }
+
+    /// Kept with the module status, so every engine sharing it answers to one controller.
+    fn admin_binding(&self) -> AdminBinding {
+        self.module_status.lock().unwrap_or_else(|e| e.into_inner()).admin.clone()
+    }
+
+    /// Module pauses, freezes and resumes, recorded under the proposer in the audit trail.
+    fn execute_admin(&mut self, approval: AdminApproval, now_ms: u64) -> Result<AdminEffect, String> {
+        approval.admit(self.admin_target_name(), &self.admin_binding())?;
+        let by = approval.proposed_by.clone();
+        match &approval.action {
+            AdminAction::PauseModule { module, reason } => {
+                let pause_id = self.pause_module(*module, reason.clone(), by, now_ms)?;
+                Ok(AdminEffect::ModuleRestricted { pause_id })
+            }
+            AdminAction::SetModuleReadOnly { module, reason } => {
+                let pause_id = self.set_module_read_only(*module, reason.clone(), by, now_ms)?;
+                Ok(AdminEffect::ModuleRestricted { pause_id })
+            }
+            AdminAction::ResumeModule { module, pause_id } => {
+                self.resume_module(*module, pause_id, by, now_ms)?;
+                Ok(AdminEffect::ModuleResumed)
+            }
+            _ => Err(approval.not_for(self.admin_target_name())),
+        }
+    }
+
+    /// Over every module's state.
+    fn admin_state_hash(&self) -> String {
+        let status = self.module_status.lock().unwrap_or_else(|e| e.into_inner());
+        state_hash(&StewardModule::ALL.map(|module| status.status(module)))
+    }
+}
+
+impl AdminTarget for PlanetaryLedger {
+    fn admin_target_name(&self) -> &'static str {
+        "PlanetaryLedger"
+    }
+
+    fn admin_binding(&self) -> AdminBinding {
+        self.admin.clone()
+    }
+
+    /// Verifier suspensions and restorations, and PLGA retention.
+    fn execute_admin(&mut self, approval: AdminApproval, now_ms: u64) -> Result<AdminEffect, String> {
+        approval.admit(self.admin_target_name(), &self.admin)?;
+        let by = approval.proposed_by.clone();
+        match &approval.action {
+            AdminAction::SuspendVerifier { verifier, actor, reason } => {
+                self.downgrade_verifier(verifier.clone(), actor.clone(), reason.clone(), by, now_ms)?;
+                Ok(AdminEffect::VerifierSuspended)
+            }
+            AdminAction::RestoreVerifier { verifier, actor } => {
+                self.restore_verifier(verifier, actor, by, now_ms)?;
+                Ok(AdminEffect::VerifierRestored)
+            }
+            AdminAction::ExecuteRetention { module: StewardModule::PLGA } => {
+                let redactions = self.sweep_retention()?.len();
+                Ok(AdminEffect::RetentionExecuted { redactions })
+            }
+            _ => Err(approval.not_for(self.admin_target_name())),
+        }
+    }
+
+    /// Over the verifier registry and the redaction log.
+    fn admin_state_hash(&self) -> String {
+        state_hash(&(&self.verifiers, &self.redactions))
+    }
+}
+
+impl AdminTarget for MicroMissionsEngine {
+    fn admin_target_name(&self) -> &'static str {
+        "MicroMissionsEngine"
+    }
+
+    fn admin_binding(&self) -> AdminBinding {
+        self.admin.clone()
+    }
+
+    /// MME retention.
+    fn execute_admin(&mut self, approval: AdminApproval, _now_ms: u64) -> Result<AdminEffect, String> {
+        approval.admit(self.admin_target_name(), &self.admin)?;
+        match &approval.action {
+            AdminAction::ExecuteRetention { module: StewardModule::MME } => {
+                let redactions = self.sweep_retention()?.len();
+                Ok(AdminEffect::RetentionExecuted { redactions })
+            }
+            _ => Err(approval.not_for(self.admin_target_name())),
+        }
+    }
+
+    /// Over the redaction log.
+    fn admin_state_hash(&self) -> String {
+        state_hash(&self.redactions)
+    }
+}
+
+impl AdminTarget for KeyRegistry {
+    fn admin_target_name(&self) -> &'static str {
+        "KeyRegistry"
+    }
+
+    fn admin_binding(&self) -> AdminBinding {
+        self.admin.clone()
+    }
+
+    /// Key revocations, recorded under the proposer, who must also be a registry admin.
+    fn execute_admin(&mut self, approval: AdminApproval, now_ms: u64) -> Result<AdminEffect, String> {
+        approval.admit(self.admin_target_name(), &self.admin)?;
+        match &approval.action {
+            AdminAction::RevokeKey { did, key_id, revoked_from_ms, reason } => {
+                self.revoke_key(did, key_id, *revoked_from_ms, reason.clone(), &approval.proposed_by, now_ms)?;
+                Ok(AdminEffect::KeyRevoked)
+            }
+            _ => Err(approval.not_for(self.admin_target_name())),
+        }
+    }
+
+    /// Over the whole registry, audit log included.
+    fn admin_state_hash(&self) -> String {
+        state_hash(self)
+    }
+}
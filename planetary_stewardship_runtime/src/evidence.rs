@@ -0,0 +1,201 @@
+// path: planetary_stewardship_runtime/src/evidence.rs
+
+//! Attestation evidence bundles.
+//! - An attestation carries typed artifacts (photos, a GPS track, a lab report), not one URI
+//! - Records written with a single `evidence_uri` still load, as a one-artifact bundle
+//! - `EvidencePolicy` asks more of bigger claims: a minimum artifact count and required
+//!   media types once an impact threshold is crossed
+//! - Capture times outside the expected window are advisory (a validation warning), never a rejection
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::ImpactMetrics;
+
+/// One piece of evidence behind an attestation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EvidenceArtifact {
+    pub uri: String,
+    /// e.g. `image/jpeg`, `application/gpx+xml`; empty when unknown (migrated records).
+    #[serde(default)]
+    pub media_type: String,
+    /// Digest of the artifact's bytes, e.g. `sha256:<hex>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub captured_at_ms: Option<u64>,
+    #[serde(default)]
+    pub description: String,
+    /// Identifies the participant (a face, a home); dropped when their records are pseudonymized.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub personal: bool,
+}
+
+impl EvidenceArtifact {
+    /// Untyped artifact, as migrated from a single `evidence_uri`.
+    pub fn from_uri(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            media_type: String::new(),
+            content_hash: None,
+            captured_at_ms: None,
+            description: String::new(),
+            personal: false,
+        }
+    }
+
+    pub fn new(uri: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self { media_type: media_type.into(), ..Self::from_uri(uri) }
+    }
+
+    pub fn with_content_hash(mut self, hash: impl Into<String>) -> Self {
+        self.content_hash = Some(hash.into());
+        self
+    }
+
+    pub fn captured_at(mut self, at_ms: u64) -> Self {
+        self.captured_at_ms = Some(at_ms);
+        self
+    }
+
+    pub fn described(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn personal(mut self) -> Self {
+        self.personal = true;
+        self
+    }
+
+    /// `pattern` is a media type or a `type/*` wildcard.
+    pub fn matches_media_type(&self, pattern: &str) -> bool {
+        match pattern.strip_suffix("/*") {
+            Some(top) => self.media_type.split('/').next() == Some(top),
+            None => self.media_type.eq_ignore_ascii_case(pattern),
+        }
+    }
+}
+
+/// What issuance accepts as evidence: a bundle, one artifact, or a bare URI.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EvidenceBundle(pub Vec<EvidenceArtifact>);
+
+impl From<Vec<EvidenceArtifact>> for EvidenceBundle {
+    fn from(artifacts: Vec<EvidenceArtifact>) -> Self {
+        Self(artifacts)
+    }
+}
+
+impl From<EvidenceArtifact> for EvidenceBundle {
+    fn from(artifact: EvidenceArtifact) -> Self {
+        Self(vec![artifact])
+    }
+}
+
+impl From<String> for EvidenceBundle {
+    fn from(uri: String) -> Self {
+        EvidenceArtifact::from_uri(uri).into()
+    }
+}
+
+impl From<&str> for EvidenceBundle {
+    fn from(uri: &str) -> Self {
+        EvidenceArtifact::from_uri(uri).into()
+    }
+}
+
+/// Accepts an artifact list or a legacy URI string (`"evidence_uri": "ipfs://..."`).
+pub fn bundle_or_uri<'de, D>(deserializer: D) -> Result<Vec<EvidenceArtifact>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Wire {
+        Uri(String),
+        Bundle(Vec<EvidenceArtifact>),
+    }
+    Ok(match Wire::deserialize(deserializer)? {
+        Wire::Uri(uri) => vec![EvidenceArtifact::from_uri(uri)],
+        Wire::Bundle(artifacts) => artifacts,
+    })
+}
+
+/// Evidence required once a claim crosses any of the thresholds set here.
+/// A rule with no thresholds applies to every attestation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EvidenceRule {
+    #[serde(default)]
+    pub co2eq_reduced_above: Option<f64>,
+    #[serde(default)]
+    pub restored_area_m2_above: Option<f64>,
+    #[serde(default)]
+    pub min_artifacts: usize,
+    /// Each needs at least one matching artifact; `type/*` wildcards allowed.
+    #[serde(default)]
+    pub required_media_types: Vec<String>,
+}
+
+impl EvidenceRule {
+    pub fn applies_to(&self, metrics: &ImpactMetrics) -> bool {
+        let thresholds = [
+            (self.co2eq_reduced_above, metrics.co2eq_reduced),
+            (self.restored_area_m2_above, metrics.restored_area_m2),
+        ];
+        if thresholds.iter().all(|(t, _)| t.is_none()) {
+            return true;
+        }
+        thresholds.iter().any(|(t, value)| t.is_some_and(|t| *value > t))
+    }
+}
+
+/// Per-ledger evidence requirements. The default requires nothing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EvidencePolicy {
+    #[serde(default)]
+    pub rules: Vec<EvidenceRule>,
+    /// Artifacts captured longer than this before the attestation are flagged,
+    /// unless the request names its own capture window.
+    #[serde(default)]
+    pub max_capture_age_ms: Option<u64>,
+}
+
+impl EvidencePolicy {
+    /// First unmet requirement of the rules that apply to `metrics`.
+    pub fn check(&self, metrics: &ImpactMetrics, evidence: &[EvidenceArtifact]) -> Result<(), String> {
+        for rule in self.rules.iter().filter(|r| r.applies_to(metrics)) {
+            if evidence.len() < rule.min_artifacts {
+                return Err(format!(
+                    "Claim requires at least {} evidence artifact(s), got {}",
+                    rule.min_artifacts,
+                    evidence.len()
+                ));
+            }
+            if let Some(missing) = rule
+                .required_media_types
+                .iter()
+                .find(|t| !evidence.iter().any(|a| a.matches_media_type(t)))
+            {
+                return Err(format!("Claim requires {missing} evidence"));
+            }
+        }
+        Ok(())
+    }
+
+    /// `[start_ms, end_ms]` artifacts should be captured in for an attestation at `timestamp_ms`.
+    pub fn capture_window(&self, timestamp_ms: u64) -> (u64, u64) {
+        let start = self.max_capture_age_ms.map(|age| timestamp_ms.saturating_sub(age)).unwrap_or(0);
+        (start, timestamp_ms)
+    }
+}
+
+/// Artifacts whose capture time falls outside `[start_ms, end_ms]`; undated ones are not flagged.
+pub fn captured_outside(evidence: &[EvidenceArtifact], (start_ms, end_ms): (u64, u64)) -> Vec<&EvidenceArtifact> {
+    evidence
+        .iter()
+        .filter(|a| a.captured_at_ms.is_some_and(|at| at < start_ms || at > end_ms))
+        .collect()
+}
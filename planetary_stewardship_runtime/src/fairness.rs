@@ -0,0 +1,337 @@
+// path: planetary_stewardship_runtime/src/fairness.rs
+
+//! Fairness rules for mission assignment, so no one volunteer can take every new mission.
+//! - `FairnessPolicy` caps open assignments per assignee, and claims of templates marked
+//!   `high_demand` per assignee within a rolling window; a mission published with
+//!   `publish_template` may also take only waitlist joins for a claim delay, then be assigned
+//!   by lottery instead of first come, first served
+//! - The limits are availability rules, the same for everyone: nothing is weighted by who
+//!   someone is or what they did before, beyond counting what they hold and claimed
+//! - `check_fairness` returns the limit broken as a `FairnessViolation`; assignment and
+//!   `validate_assignment` refuse with it
+//! - A lottery orders entrants by SHA-256 of the seed, mission and DID, so anyone holding the
+//!   recorded seed and entrant list can repeat it (`LotteryDraw::verify`). Drawn entrants are
+//!   assigned in that order, up to the policy's slots, through the normal assignment checks;
+//!   one refused by them is passed over, with why
+//! - The seed is committed to (`seed_commitment`) when the mission is published, before anyone
+//!   joins, and the draw refuses any other seed: whoever draws cannot shop for a seed once
+//!   the entrants are known
+//! - Claim windows and draws are kept in memory only, like the search index
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+use crate::{AssignmentFilter, Did, MicroMissionsEngine, MissionId, MissionTemplate, Reason, ReasonCode, SaepRefs};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct FairnessPolicy {
+    /// Open, unsuspended assignments one assignee may hold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_active_assignments: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high_demand: Option<HighDemandCap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_delay: Option<ClaimDelay>,
+}
+
+/// Claims of `high_demand` templates one assignee may make within `window_ms`, closed ones included.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HighDemandCap {
+    pub max_claims: usize,
+    pub window_ms: u64,
+}
+
+/// Waitlist period for newly published missions, and how many entrants the lottery assigns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ClaimDelay {
+    pub delay_ms: u64,
+    pub slots: usize,
+}
+
+/// The fairness limit an assignment would break.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FairnessViolation {
+    ActiveAssignments { assignee: Did, active: usize, max: usize },
+    HighDemandClaims { assignee: Did, claims: usize, max: usize, window_ms: u64 },
+    /// The mission takes waitlist joins until `opens_ms`.
+    ClaimDelay { mission: MissionId, opens_ms: u64 },
+    /// The claim delay is over but the lottery has not been drawn.
+    LotteryPending { mission: MissionId },
+}
+
+impl FairnessViolation {
+    pub fn reason(&self) -> Reason {
+        match self {
+            FairnessViolation::ActiveAssignments { assignee, active, max } => Reason::new(ReasonCode::MissionActiveCap)
+                .with("assignee", assignee)
+                .with("active", active)
+                .with("max", max),
+            FairnessViolation::HighDemandClaims { assignee, claims, max, window_ms } => {
+                Reason::new(ReasonCode::MissionHighDemandCap)
+                    .with("assignee", assignee)
+                    .with("claims", claims)
+                    .with("max", max)
+                    .with("window_ms", window_ms)
+            }
+            FairnessViolation::ClaimDelay { mission, opens_ms } => {
+                Reason::new(ReasonCode::MissionClaimDelay).with("mission", mission).with("opens_ms", opens_ms)
+            }
+            FairnessViolation::LotteryPending { mission } => {
+                Reason::new(ReasonCode::MissionLotteryPending).with("mission", mission)
+            }
+        }
+    }
+}
+
+impl fmt::Display for FairnessViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reason().fmt(f)
+    }
+}
+
+impl std::error::Error for FairnessViolation {}
+
+/// A newly published mission taking waitlist joins.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ClaimWindow {
+    pub mission: MissionId,
+    pub published_ms: u64,
+    /// Joins are taken before this time, the lottery drawn from it on.
+    pub opens_ms: u64,
+    pub slots: usize,
+    /// `seed_commitment` of the seed the lottery will be drawn with, fixed at publication.
+    pub seed_commitment: String,
+    /// In the order they joined; the order does not affect the draw.
+    pub entrants: Vec<Did>,
+}
+
+/// An entrant the lottery drew but assignment refused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PassedOver {
+    pub entrant: Did,
+    pub reason: String,
+}
+
+/// One lottery, as drawn: enough to repeat it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LotteryDraw {
+    pub mission: MissionId,
+    pub seed: u64,
+    /// Published with the mission; `seed` opens it.
+    pub seed_commitment: String,
+    pub drawn_ms: u64,
+    pub slots: usize,
+    /// In the order they joined.
+    pub entrants: Vec<Did>,
+    /// `entrants` in draw order.
+    pub order: Vec<Did>,
+    /// Assigned at `drawn_ms`, in draw order.
+    pub assigned: Vec<Did>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passed_over: Vec<PassedOver>,
+}
+
+impl LotteryDraw {
+    /// Whether `seed` is the one committed to at publication, and `order` is what it gives for
+    /// `entrants`.
+    pub fn verify(&self) -> bool {
+        seed_commitment(&self.mission, self.seed) == self.seed_commitment
+            && lottery_order(&self.mission, self.seed, &self.entrants) == self.order
+    }
+}
+
+/// SHA-256 over the mission id and the seed (little-endian), published in place of the seed
+/// until the draw.
+pub fn seed_commitment(mission: &MissionId, seed: u64) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"lottery-seed");
+    hasher.update([0]);
+    hasher.update(mission.0.as_bytes());
+    hasher.update([0]);
+    hasher.update(seed.to_le_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// `entrants` by SHA-256 of the seed (little-endian), mission id and DID.
+pub fn lottery_order(mission: &MissionId, seed: u64, entrants: &[Did]) -> Vec<Did> {
+    let mut keyed: Vec<([u8; 32], &Did)> = entrants
+        .iter()
+        .map(|entrant| {
+            let mut hasher = Sha256::new();
+            hasher.update(seed.to_le_bytes());
+            hasher.update(mission.0.as_bytes());
+            hasher.update([0]);
+            hasher.update(entrant.0.as_bytes());
+            (hasher.finalize().into(), entrant)
+        })
+        .collect();
+    keyed.sort();
+    keyed.into_iter().map(|(_, entrant)| entrant.clone()).collect()
+}
+
+impl MicroMissionsEngine {
+    pub fn with_fairness_policy(mut self, policy: FairnessPolicy) -> Self {
+        self.fairness = policy;
+        self
+    }
+
+    pub fn fairness_policy(&self) -> &FairnessPolicy {
+        &self.fairness
+    }
+
+    /// `register_template` for a mission published at `now_ms`. Under a claim delay a mission
+    /// not already published opens a waitlist, and needs the `seed_commitment` of the seed its
+    /// lottery will be drawn with; a new version of a published one does not.
+    pub fn publish_template(
+        &mut self,
+        tpl: MissionTemplate,
+        seed_commitment: Option<String>,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        let mission = tpl.id.clone();
+        let new = !self.templates.contains_key(&mission);
+        let delay = self.fairness.claim_delay.clone().filter(|_| new);
+        if delay.is_some() && seed_commitment.is_none() {
+            return Err(format!("Mission {mission} opens a waitlist and needs a lottery seed commitment"));
+        }
+        self.register_template(tpl)?;
+        if let (Some(delay), Some(seed_commitment)) = (delay, seed_commitment) {
+            let window = ClaimWindow {
+                mission: mission.clone(),
+                published_ms: now_ms,
+                opens_ms: now_ms.saturating_add(delay.delay_ms),
+                slots: delay.slots,
+                seed_commitment,
+                entrants: Vec::new(),
+            };
+            self.claim_windows.insert(mission, window);
+        }
+        Ok(())
+    }
+
+    /// The waitlist of `mission`, while it has one.
+    pub fn claim_window(&self, mission: &MissionId) -> Option<&ClaimWindow> {
+        self.claim_windows.get(mission)
+    }
+
+    /// Put `participant` on `mission`'s waitlist; joining twice keeps one entry.
+    pub fn join_waitlist(
+        &mut self,
+        mission: &MissionId,
+        participant: Did,
+        now_ms: u64,
+    ) -> Result<&ClaimWindow, String> {
+        let window = self
+            .claim_windows
+            .get_mut(mission)
+            .filter(|w| now_ms < w.opens_ms)
+            .ok_or_else(|| Reason::new(ReasonCode::MissionWaitlistClosed).with("mission", mission))?;
+        if !window.entrants.contains(&participant) {
+            window.entrants.push(participant);
+        }
+        Ok(window)
+    }
+
+    /// Draw `mission`'s lottery with `seed` once its claim delay is over, and assign the drawn
+    /// entrants. A seed other than the one committed to at publication is refused. The mission
+    /// is then assigned as any other.
+    pub fn draw_lottery(&mut self, mission: &MissionId, seed: u64, now_ms: u64) -> Result<LotteryDraw, String> {
+        let window = self
+            .claim_windows
+            .get(mission)
+            .ok_or_else(|| Reason::new(ReasonCode::MissionWaitlistClosed).with("mission", mission))?;
+        if now_ms < window.opens_ms {
+            let early = FairnessViolation::ClaimDelay { mission: mission.clone(), opens_ms: window.opens_ms };
+            return Err(early.to_string());
+        }
+        if seed_commitment(mission, seed) != window.seed_commitment {
+            return Err(Reason::new(ReasonCode::MissionLotterySeed).with("mission", mission).into());
+        }
+        let window = self.claim_windows.remove(mission).expect("checked above");
+        let order = lottery_order(mission, seed, &window.entrants);
+        let mut draw = LotteryDraw {
+            mission: mission.clone(),
+            seed,
+            seed_commitment: window.seed_commitment,
+            drawn_ms: now_ms,
+            slots: window.slots,
+            entrants: window.entrants,
+            order: order.clone(),
+            assigned: Vec::new(),
+            passed_over: Vec::new(),
+        };
+        for entrant in order {
+            if draw.assigned.len() == draw.slots {
+                break;
+            }
+            match self.assign_mission_with_refs(mission, entrant.clone(), now_ms, SaepRefs::default()) {
+                Ok(_) => draw.assigned.push(entrant),
+                Err(reason) => draw.passed_over.push(PassedOver { entrant, reason }),
+            }
+        }
+        self.lottery_draws.push(draw.clone());
+        Ok(draw)
+    }
+
+    /// Every lottery drawn, oldest first.
+    pub fn lottery_draws(&self) -> &[LotteryDraw] {
+        &self.lottery_draws
+    }
+
+    /// The first fairness limit assigning `mission` to `assignee` at `now_ms` would break.
+    /// Archived assignments that cannot be read do not count toward the high-demand cap.
+    pub fn check_fairness(&self, mission: &MissionId, assignee: &Did, now_ms: u64) -> Result<(), FairnessViolation> {
+        if let Some(window) = self.claim_windows.get(mission) {
+            return Err(if now_ms < window.opens_ms {
+                FairnessViolation::ClaimDelay { mission: mission.clone(), opens_ms: window.opens_ms }
+            } else {
+                FairnessViolation::LotteryPending { mission: mission.clone() }
+            });
+        }
+        let held = || self.active_assignments.values().filter(|a| &a.assignee == assignee);
+        if let Some(max) = self.fairness.max_active_assignments {
+            let active = held().filter(|a| a.status.is_active()).count();
+            if active >= max {
+                return Err(FairnessViolation::ActiveAssignments { assignee: assignee.clone(), active, max });
+            }
+        }
+        let Some(cap) = &self.fairness.high_demand else {
+            return Ok(());
+        };
+        if !self.templates.get(mission).is_some_and(|t| t.high_demand) {
+            return Ok(());
+        }
+        let since = now_ms.saturating_sub(cap.window_ms);
+        let claimed = |mission: &MissionTemplate, assigned_ts_ms: u64| mission.high_demand && assigned_ts_ms > since;
+        let filter = AssignmentFilter {
+            assignee: Some(assignee.clone()),
+            closed_from_ms: Some(since),
+            ..AssignmentFilter::default()
+        };
+        let archived = self
+            .archive
+            .query(&filter)
+            .filter_map(Result::ok)
+            .filter(|r| claimed(&r.assignment.mission, r.assignment.assigned_ts_ms))
+            .count();
+        let claims = archived + held().filter(|a| claimed(&a.mission, a.assigned_ts_ms)).count();
+        if claims >= cap.max_claims {
+            return Err(FairnessViolation::HighDemandClaims {
+                assignee: assignee.clone(),
+                claims,
+                max: cap.max_claims,
+                window_ms: cap.window_ms,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+// path: planetary_stewardship_runtime/src/governance_anchor.rs
+
+//! Governance outcomes anchored into the ledger (feature `governance-anchoring`).
+//! - When `GovernanceEngine` approves or vetoes a proposal, `GovernanceAnchoring` issues a CSC
+//!   attestation for it under the governance collective's DID: described by the proposal's
+//!   title, with the tally under `governance_metric_schema` and, as evidence, the proposal's
+//!   and the charter's hashes and one artifact per veto reason
+//! - Setup registers the CSC schema and records the collective's CSC consent, so the anchor
+//!   passes the same checks as any attestation; a CSC under `enforce_reversibility` still
+//!   wants a rollback plan, which anchors do not carry
+//! - Anchors are ordinary attestations: listed, summarized, exported and redacted with the
+//!   rest, and appended to the ledger's change chain like them
+//! - Outcomes are anchored only through `decide`, which asks the engine itself: there is no
+//!   way to anchor a veto the engine did not make
+//! - Each outcome is anchored once. The idempotency key `governance:<proposal id>` replays
+//!   a retry (and refuses the same proposal with another outcome); past the key's TTL, an
+//!   anchor already on the ledger is found and returned instead
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    charter_set_hash, AttestationRequest, ConsentPromptDescriptor, ConsentRecord, Did, EthicsContext,
+    EvidenceArtifact, GovernanceAuditOutcome, GovernanceEngine, GovernanceProposal, IdempotencyKey, ImpactMetrics,
+    MetricField, MetricFieldKind, MetricFieldValue, MetricSchema, ModuleMetrics, PlanetaryLedger, QuadraticOutcome,
+    ReasonCode, SaepRefs, StewardModule, StewardshipAttestation, ViewHead, PLGA_ATTESTATION_PURPOSE,
+};
+
+/// Evidence URI of the proposal an anchor is for.
+pub fn proposal_uri(proposal_id: &str) -> String {
+    format!("governance:proposal:{proposal_id}")
+}
+
+/// SHA-256 of `proposal`'s JSON, as anchored.
+pub fn proposal_hash(proposal: &GovernanceProposal) -> String {
+    let payload = serde_json::to_vec(proposal).expect("proposal serialization");
+    format!("{:x}", Sha256::digest(&payload))
+}
+
+/// CSC metrics of an anchor: the tally, and which way it went.
+pub fn governance_metric_schema() -> MetricSchema {
+    let weight = |name| MetricField::new(name, MetricFieldKind::Number).required();
+    let flag = |name| MetricField::new(name, MetricFieldKind::Flag).required();
+    MetricSchema::new(
+        StewardModule::CSC,
+        vec![weight("total_support"), weight("total_opposition"), flag("approved"), flag("vetoed")],
+    )
+}
+
+/// A governance outcome as anchored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GovernanceAnchor {
+    pub proposal_id: String,
+    pub outcome: GovernanceAuditOutcome,
+    pub attestation: StewardshipAttestation,
+    /// Change-chain head the attestation was appended to; `None` when the outcome was already
+    /// anchored and the original attestation is returned.
+    pub prev_head: Option<ViewHead>,
+    /// The ledger's head when anchoring returned.
+    pub head: ViewHead,
+}
+
+impl GovernanceAnchor {
+    /// Whether `head` is `prev_head` advanced by exactly this attestation.
+    pub fn is_chained(&self) -> bool {
+        self.prev_head.as_ref().is_some_and(|prev| {
+            let mut next = prev.clone();
+            next.advance("attestation", &self.attestation.id, Some(&self.attestation));
+            next == self.head
+        })
+    }
+}
+
+/// Issues governance anchors into a ledger under the collective's DID.
+#[derive(Debug, Clone)]
+pub struct GovernanceAnchoring {
+    collective: Did,
+}
+
+impl GovernanceAnchoring {
+    /// Prepare `ledger` to take anchors from `collective`: register `governance_metric_schema`
+    /// (a CSC schema already there must be the same) and submit the collective's CSC consent,
+    /// given at `now_ms` to the prompt the ledger shows for CSC attestations.
+    pub fn setup(ledger: &mut PlanetaryLedger, collective: Did, now_ms: u64) -> Result<Self, String> {
+        let schema = governance_metric_schema();
+        match ledger.metric_schemas().get(StewardModule::CSC) {
+            Some(existing) if *existing != schema => {
+                return Err("CSC already has a metric schema other than the governance one".into());
+            }
+            Some(_) => {}
+            None => ledger.register_metric_schema(schema)?,
+        }
+        let decision = ledger.saep.evaluate(&EthicsContext {
+            actor: collective.clone(),
+            affected_parties: vec![],
+            module: StewardModule::CSC,
+            description: String::new(),
+            estimated_impact: serde_json::Value::Null,
+        });
+        let prompt = ConsentPromptDescriptor::for_action(StewardModule::CSC, None, PLGA_ATTESTATION_PURPOSE, &decision);
+        ledger
+            .consent_mut()
+            .submit_consent(ConsentRecord {
+                participant: collective.clone(),
+                module: StewardModule::CSC,
+                mission: None,
+                consent_given: true,
+                timestamp_ms: now_ms,
+                evidence_uri: None,
+                evidence_hash: None,
+                prompt_hash: Some(prompt.canonical_hash()),
+            })
+            .map_err(|e| e.to_string())?;
+        Ok(Self { collective })
+    }
+
+    pub fn collective(&self) -> &Did {
+        &self.collective
+    }
+
+    /// Apply `proposal` on `outcome` through `engine` and anchor the result: an approval once
+    /// applied, a veto when SAEP or the charter refuses it. `None` when the vote did not pass,
+    /// which anchors nothing; any other refusal is an error. A proposal the engine already
+    /// applied is anchored (or its anchor returned) without applying it again.
+    pub fn decide(
+        &self,
+        engine: &mut GovernanceEngine,
+        ledger: &mut PlanetaryLedger,
+        proposal: GovernanceProposal,
+        outcome: QuadraticOutcome,
+        now_ms: u64,
+    ) -> Result<Option<GovernanceAnchor>, String> {
+        if !engine.applied.contains_key(&proposal.proposal_id) {
+            match engine.check_proposal(&proposal, &outcome) {
+                Ok(true) => {}
+                Ok(false) => return Ok(None),
+                Err(reason) if is_veto(reason.code) => {
+                    let citations = engine.charter_citation(&reason).into_iter().collect();
+                    let vetoed = GovernanceAuditOutcome::Vetoed { reasons: vec![reason.to_string()], citations };
+                    return self.anchor(engine, ledger, &proposal, &outcome, vetoed, now_ms).map(Some);
+                }
+                Err(reason) => return Err(reason.into()),
+            }
+            engine.apply_proposal(proposal.clone(), outcome.clone(), now_ms)?;
+        }
+        self.anchor(engine, ledger, &proposal, &outcome, GovernanceAuditOutcome::Applied, now_ms).map(Some)
+    }
+
+    /// Anchor `decided`, the outcome of `proposal` on `outcome`, into `ledger` at `now_ms`.
+    /// An approval must be one `engine` applied, on this tally. Only `decide` reaches this, so
+    /// a veto is always the engine's own refusal, never one a caller wrote.
+    fn anchor(
+        &self,
+        engine: &GovernanceEngine,
+        ledger: &mut PlanetaryLedger,
+        proposal: &GovernanceProposal,
+        outcome: &QuadraticOutcome,
+        decided: GovernanceAuditOutcome,
+        now_ms: u64,
+    ) -> Result<GovernanceAnchor, String> {
+        let id = &proposal.proposal_id;
+        if outcome.proposal_id != *id {
+            return Err(format!("Tally is for proposal {}, not {id}", outcome.proposal_id));
+        }
+        let vetoes = match &decided {
+            GovernanceAuditOutcome::Applied => {
+                let applied = engine.applied.get(id).ok_or_else(|| format!("Proposal {id} was not applied"))?;
+                let tally = (applied.outcome.total_support, applied.outcome.total_opposition);
+                if proposal_hash(&applied.proposal) != proposal_hash(proposal)
+                    || tally != (outcome.total_support, outcome.total_opposition)
+                {
+                    return Err(format!("Proposal {id} was applied as another proposal or on another tally"));
+                }
+                &[][..]
+            }
+            GovernanceAuditOutcome::Vetoed { reasons, .. } => reasons.as_slice(),
+        };
+
+        let proposal_hash = proposal_hash(proposal);
+        let key = IdempotencyKey::new(format!("governance:{id}"), &(&proposal_hash, &decided));
+        let uri = proposal_uri(id);
+        let approved = matches!(decided, GovernanceAuditOutcome::Applied);
+        let anchored = match ledger.idempotent_attestation(&key).map_err(|e| e.to_string())? {
+            Some(att) => Some(att),
+            None => ledger.attestations_in_module(StewardModule::CSC).find(|att| {
+                att.actor_did == self.collective && att.evidence.first().is_some_and(|a| a.uri == uri)
+            }),
+        };
+        if let Some(att) = anchored {
+            if !anchors(att, &proposal_hash, approved, vetoes) {
+                return Err(format!("Proposal {id} is anchored with another outcome as attestation {}", att.id.0));
+            }
+            return Ok(GovernanceAnchor {
+                proposal_id: id.clone(),
+                outcome: decided,
+                attestation: att.clone(),
+                prev_head: None,
+                head: ledger.view_head().clone(),
+            });
+        }
+
+        let module_metrics = ModuleMetrics::from([
+            ("total_support".to_string(), outcome.total_support.into()),
+            ("total_opposition".to_string(), outcome.total_opposition.into()),
+            ("approved".to_string(), approved.into()),
+            ("vetoed".to_string(), (!approved).into()),
+        ]);
+        let mut evidence = vec![
+            EvidenceArtifact::new(uri, "application/json")
+                .with_content_hash(format!("sha256:{proposal_hash}"))
+                .described("The proposal as decided"),
+            EvidenceArtifact::new("governance:charter", "application/json")
+                .with_content_hash(format!("sha256:{}", charter_set_hash(&engine.charter_bound_modules)))
+                .described("Charter-bound modules when it was decided"),
+        ];
+        evidence.extend(vetoes.iter().enumerate().map(|(n, reason)| {
+            EvidenceArtifact::new(format!("{}:veto:{}", proposal_uri(id), n + 1), "text/plain").described(reason)
+        }));
+        let request = AttestationRequest {
+            actor_did: self.collective.clone(),
+            mission_id: None,
+            description: proposal.title.clone(),
+            module: StewardModule::CSC,
+            impact_metrics: ImpactMetrics::default(),
+            module_metrics,
+            evidence,
+            verifier_dids: vec![],
+            timestamp_ms: now_ms,
+            refs: SaepRefs::default(),
+            capture_window: None,
+            witness_endorsement: None,
+        };
+        let prev_head = ledger.view_head().clone();
+        let attestation = ledger.issue_attestation_idempotent(request, Some(key))?;
+        #[cfg(feature = "tracing")]
+        tracing::info!(proposal_id = %id, attestation_id = %attestation.id.0, approved, "governance outcome anchored");
+        Ok(GovernanceAnchor {
+            proposal_id: id.clone(),
+            outcome: decided,
+            attestation,
+            prev_head: Some(prev_head),
+            head: ledger.view_head().clone(),
+        })
+    }
+}
+
+/// Whether `att` anchors the proposal hashed `proposal_hash` with this outcome.
+fn anchors(att: &StewardshipAttestation, proposal_hash: &str, approved: bool, vetoes: &[String]) -> bool {
+    let hash = format!("sha256:{proposal_hash}");
+    att.evidence.first().is_some_and(|a| a.content_hash.as_ref() == Some(&hash))
+        && att.module_metrics.get("approved") == Some(&MetricFieldValue::Flag(approved))
+        && att.evidence.iter().skip(2).map(|a| &a.description).eq(vetoes)
+}
+
+/// Refusals `check_proposal` makes as a veto, rather than for a malformed or misplaced proposal.
+fn is_veto(code: ReasonCode) -> bool {
+    matches!(
+        code,
+        ReasonCode::GovernanceEthicsVeto | ReasonCode::GovernanceCharter | ReasonCode::GovernanceScopeCharter
+    )
+}
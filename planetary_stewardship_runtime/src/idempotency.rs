@@ -0,0 +1,121 @@
+// path: planetary_stewardship_runtime/src/idempotency.rs
+
+//! Idempotency keys for attestation and assignment submission.
+//! - A client retrying a request whose response was lost sends the same key: the first
+//!   success is recorded, and a replay returns what it created (as currently stored, so
+//!   redactions apply) instead of creating anything
+//! - The key is bound to a hash of the request; reusing it for a different request is an
+//!   `IdempotencyConflict`. Failed attempts record nothing, so they can be retried
+//! - Keys expire `ttl_ms` after their first success and may then be reused; expired keys are
+//!   dropped whenever a new one is recorded. The ledger reads its injected clock, the mission
+//!   engine the `now_ms` it is given
+//! - A replay runs no checks and records nothing, so verifier statistics and evidence reuse
+//!   detection see the original attestation once
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{Reason, ReasonCode};
+
+/// Keys are kept for a day unless the engine is configured otherwise.
+pub const DEFAULT_IDEMPOTENCY_TTL_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// A client-chosen key and the hash of the request it was sent with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IdempotencyKey {
+    pub key: String,
+    pub request_hash: String,
+}
+
+impl IdempotencyKey {
+    /// `key` bound to the SHA-256 of `request`'s JSON. Hash what the client sent, before
+    /// defaults such as "now" are filled in, so a retry hashes the same.
+    pub fn new(key: impl Into<String>, request: &impl Serialize) -> Self {
+        let payload = serde_json::to_vec(request).expect("idempotent request serialization");
+        Self {
+            key: key.into(),
+            request_hash: format!("{:x}", Sha256::digest(&payload)),
+        }
+    }
+}
+
+/// The key was first used, and is still held, for a request with another hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IdempotencyConflict {
+    pub key: String,
+    pub stored_hash: String,
+    pub request_hash: String,
+}
+
+impl IdempotencyConflict {
+    pub fn reason(&self) -> Reason {
+        Reason::new(ReasonCode::IdempotencyConflict).with("key", &self.key)
+    }
+}
+
+impl fmt::Display for IdempotencyConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reason().fmt(f)
+    }
+}
+
+impl std::error::Error for IdempotencyConflict {}
+
+#[derive(Debug, Clone)]
+struct HeldKey<V> {
+    request_hash: String,
+    stored_at_ms: u64,
+    value: V,
+}
+
+/// Keys held by one engine, with what each one's first success created.
+#[derive(Debug, Clone)]
+pub(crate) struct IdempotencyStore<V> {
+    pub(crate) ttl_ms: u64,
+    held: HashMap<String, HeldKey<V>>,
+}
+
+impl<V> Default for IdempotencyStore<V> {
+    fn default() -> Self {
+        Self { ttl_ms: DEFAULT_IDEMPOTENCY_TTL_MS, held: HashMap::new() }
+    }
+}
+
+impl<V> IdempotencyStore<V> {
+    fn live(&self, held: &HeldKey<V>, now_ms: u64) -> bool {
+        now_ms < held.stored_at_ms.saturating_add(self.ttl_ms)
+    }
+
+    /// What `key`'s first success created, if the key is held and unexpired at `now_ms`.
+    pub(crate) fn lookup(&self, key: &IdempotencyKey, now_ms: u64) -> Result<Option<&V>, IdempotencyConflict> {
+        match self.held.get(&key.key).filter(|held| self.live(held, now_ms)) {
+            None => Ok(None),
+            Some(held) if held.request_hash == key.request_hash => Ok(Some(&held.value)),
+            Some(held) => Err(IdempotencyConflict {
+                key: key.key.clone(),
+                stored_hash: held.request_hash.clone(),
+                request_hash: key.request_hash.clone(),
+            }),
+        }
+    }
+
+    /// What `key`'s first success created, whatever request it was for, if unexpired at `now_ms`.
+    pub(crate) fn get(&self, key: &str, now_ms: u64) -> Option<&V> {
+        self.held.get(key).filter(|held| self.live(held, now_ms)).map(|held| &held.value)
+    }
+
+    /// Hold `key` for `value` from `now_ms`, dropping every expired key.
+    pub(crate) fn record(&mut self, key: IdempotencyKey, value: V, now_ms: u64) {
+        let ttl_ms = self.ttl_ms;
+        self.held.retain(|_, held| now_ms < held.stored_at_ms.saturating_add(ttl_ms));
+        self.held.insert(key.key, HeldKey { request_hash: key.request_hash, stored_at_ms: now_ms, value });
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.held.len()
+    }
+}
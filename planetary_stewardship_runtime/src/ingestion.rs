@@ -0,0 +1,274 @@
+// path: planetary_stewardship_runtime/src/ingestion.rs
+
+//! Queued attestation ingestion, for bursts the ledger cannot validate as they arrive.
+//! - `IngestionQueue::enqueue` runs only cheap shape checks and hands back a `TicketId`; the
+//!   host drains the queue with `process` on its own schedule, and clients poll `ticket_status`
+//! - Depth is capped overall and per actor; a full queue refuses with `QueueFull` and a hint
+//!   of when a slot should free up, worked out from the drain schedule the host declares
+//! - `process` issues in enqueue order through `issue_attestation_idempotent`, so each
+//!   actor's requests, and the duplicate and idempotency checks between them, are decided as
+//!   if they had been submitted synchronously in that order
+//! - The queue is plain serde state: persist it next to the ledger and load it back to
+//!   resume; pending tickets are then processed as if nothing had happened
+//! - Settled outcomes are kept for `settled_ttl_ms` after processing, then forgotten
+//! - `csv` turns the exports of legacy MRV systems into requests, and imports them
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+pub mod csv;
+
+use crate::{
+    AttestationId, AttestationRequest, Did, IdempotencyKey, PlanetaryLedger, Reason, ReasonCode, StewardshipAttestation,
+    DEFAULT_IDEMPOTENCY_TTL_MS,
+};
+
+/// What `issue_attestation_idempotent` returned for a ticket.
+pub type IssueResult = Result<StewardshipAttestation, String>;
+
+/// Handed out by `enqueue`, in enqueue order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TicketId(pub u64);
+
+impl fmt::Display for TicketId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ticket-{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IngestionLimits {
+    /// Requests waiting, across actors.
+    pub max_depth: usize,
+    pub max_depth_per_actor: usize,
+    /// How often the host calls `process`, and how many requests it takes each time; only
+    /// used to work out `retry_after_hint_ms`.
+    pub drain_interval_ms: u64,
+    pub drain_batch: usize,
+    pub settled_ttl_ms: u64,
+}
+
+impl Default for IngestionLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 10_000,
+            max_depth_per_actor: 50,
+            drain_interval_ms: 1_000,
+            drain_batch: 100,
+            settled_ttl_ms: DEFAULT_IDEMPOTENCY_TTL_MS,
+        }
+    }
+}
+
+/// Which limit a full queue hit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum QueueScope {
+    Global,
+    Actor(Did),
+}
+
+impl fmt::Display for QueueScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueueScope::Global => f.write_str("global"),
+            QueueScope::Actor(did) => did.fmt(f),
+        }
+    }
+}
+
+/// Why `enqueue` refused a request; nothing was queued.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum IngestionError {
+    /// `limit` requests are already waiting in `scope`. `retry_after_hint_ms` is when the
+    /// declared drain schedule should have freed a slot there; a hint, not a reservation.
+    QueueFull { scope: QueueScope, limit: usize, retry_after_hint_ms: u64 },
+    /// A blank description or evidence URI, a capture window ending before it starts, or a
+    /// metric JSON cannot hold.
+    Malformed { field: String, detail: String },
+}
+
+impl IngestionError {
+    pub fn reason(&self) -> Reason {
+        match self {
+            IngestionError::QueueFull { scope, limit, retry_after_hint_ms } => {
+                Reason::new(ReasonCode::IngestionQueueFull)
+                    .with("scope", scope)
+                    .with("limit", limit)
+                    .with("retry_after_ms", retry_after_hint_ms)
+            }
+            IngestionError::Malformed { field, detail } => {
+                Reason::new(ReasonCode::IngestionMalformed).with("field", field).with("detail", detail)
+            }
+        }
+    }
+}
+
+impl fmt::Display for IngestionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reason().fmt(f)
+    }
+}
+
+impl std::error::Error for IngestionError {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TicketStatus {
+    /// `position` requests will be processed before it.
+    Queued { position: usize },
+    Issued { attestation_id: AttestationId, processed_ms: u64 },
+    /// `reason` is the ledger's refusal.
+    Rejected { reason: String, processed_ms: u64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct QueuedRequest {
+    ticket: TicketId,
+    request: AttestationRequest,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    idempotency_key: Option<IdempotencyKey>,
+}
+
+/// Attestation requests waiting for the ledger, oldest first, and recent outcomes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IngestionQueue {
+    limits: IngestionLimits,
+    next_ticket: u64,
+    pending: VecDeque<QueuedRequest>,
+    /// `Issued` or `Rejected` statuses, until `settled_ttl_ms` after processing.
+    settled: BTreeMap<TicketId, TicketStatus>,
+}
+
+impl IngestionQueue {
+    pub fn new(limits: IngestionLimits) -> Self {
+        Self { limits, ..Self::default() }
+    }
+
+    pub fn limits(&self) -> &IngestionLimits {
+        &self.limits
+    }
+
+    /// Applies from the next `enqueue`; requests already waiting stay queued.
+    pub fn set_limits(&mut self, limits: IngestionLimits) {
+        self.limits = limits;
+    }
+
+    /// Requests waiting to be processed.
+    pub fn depth(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn depth_for(&self, actor: &Did) -> usize {
+        self.pending.iter().filter(|q| &q.request.actor_did == actor).count()
+    }
+
+    /// Queue `request`, to be issued under `idempotency_key` (see
+    /// `issue_attestation_idempotent`) when `process` reaches it. Only checks the request's
+    /// shape and the depth limits; the ledger's checks run when it is processed.
+    pub fn enqueue(
+        &mut self,
+        request: AttestationRequest,
+        idempotency_key: Option<IdempotencyKey>,
+    ) -> Result<TicketId, IngestionError> {
+        check_shape(&request)?;
+        let interval = self.limits.drain_interval_ms;
+        if self.pending.len() >= self.limits.max_depth {
+            return Err(IngestionError::QueueFull {
+                scope: QueueScope::Global,
+                limit: self.limits.max_depth,
+                retry_after_hint_ms: interval,
+            });
+        }
+        let actor = &request.actor_did;
+        if self.depth_for(actor) >= self.limits.max_depth_per_actor {
+            // A slot frees up once the drain that reaches the actor's oldest request has run.
+            let ahead = self.pending.iter().position(|q| &q.request.actor_did == actor).unwrap_or_default();
+            let drains = (ahead / self.limits.drain_batch.max(1)) as u64 + 1;
+            return Err(IngestionError::QueueFull {
+                scope: QueueScope::Actor(actor.clone()),
+                limit: self.limits.max_depth_per_actor,
+                retry_after_hint_ms: drains.saturating_mul(interval),
+            });
+        }
+        let ticket = TicketId(self.next_ticket);
+        self.next_ticket += 1;
+        self.pending.push_back(QueuedRequest { ticket, request, idempotency_key });
+        Ok(ticket)
+    }
+
+    /// Issue up to `max_items` waiting requests on `ledger`, oldest first, and forget
+    /// outcomes settled more than `settled_ttl_ms` before `now_ms`.
+    pub fn process(
+        &mut self,
+        ledger: &mut PlanetaryLedger,
+        max_items: usize,
+        now_ms: u64,
+    ) -> Vec<(TicketId, IssueResult)> {
+        let ttl = self.limits.settled_ttl_ms;
+        self.settled.retain(|_, status| match status {
+            TicketStatus::Issued { processed_ms, .. } | TicketStatus::Rejected { processed_ms, .. } => {
+                processed_ms.saturating_add(ttl) > now_ms
+            }
+            TicketStatus::Queued { .. } => false,
+        });
+        let mut results = Vec::new();
+        while results.len() < max_items {
+            let Some(QueuedRequest { ticket, request, idempotency_key }) = self.pending.pop_front() else { break };
+            let result = ledger.issue_attestation_idempotent(request, idempotency_key);
+            let status = match &result {
+                Ok(att) => TicketStatus::Issued { attestation_id: att.id.clone(), processed_ms: now_ms },
+                Err(reason) => TicketStatus::Rejected { reason: reason.clone(), processed_ms: now_ms },
+            };
+            self.settled.insert(ticket, status);
+            results.push((ticket, result));
+        }
+        results
+    }
+
+    /// Where `ticket` stands; `None` if it was never issued here or its outcome was forgotten.
+    pub fn ticket_status(&self, ticket: TicketId) -> Option<TicketStatus> {
+        if let Some(position) = self.pending.iter().position(|q| q.ticket == ticket) {
+            return Some(TicketStatus::Queued { position });
+        }
+        self.settled.get(&ticket).cloned()
+    }
+}
+
+/// Shape checks that need no ledger. Stricter than issuance: a request with a blank description
+/// or evidence URI, or a capture window ending before it starts, is nonsense whatever the
+/// ledger's policies, and a metric JSON cannot hold would not come back from a snapshot.
+fn check_shape(request: &AttestationRequest) -> Result<(), IngestionError> {
+    let malformed = |field: &str, detail: &str| {
+        Err(IngestionError::Malformed { field: field.into(), detail: detail.into() })
+    };
+    if request.description.trim().is_empty() {
+        return malformed("description", "is empty");
+    }
+    let metrics = &request.impact_metrics;
+    for (field, value) in [
+        ("impact_metrics.co2eq_reduced", metrics.co2eq_reduced),
+        ("impact_metrics.biodiversity_index_delta", metrics.biodiversity_index_delta),
+        ("impact_metrics.restored_area_m2", metrics.restored_area_m2),
+        ("impact_metrics.avoided_emissions_co2eq", metrics.avoided_emissions_co2eq),
+    ] {
+        if !value.is_finite() {
+            return malformed(field, "is not a finite number");
+        }
+    }
+    if request.evidence.iter().any(|a| a.uri.trim().is_empty()) {
+        return malformed("evidence", "has an artifact without a URI");
+    }
+    if let Some((start_ms, end_ms)) = request.capture_window {
+        if start_ms > end_ms {
+            return malformed("capture_window", "ends before it starts");
+        }
+    }
+    Ok(())
+}
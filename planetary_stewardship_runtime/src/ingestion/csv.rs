@@ -0,0 +1,485 @@
+// path: planetary_stewardship_runtime/src/ingestion/csv.rs
+
+//! Historical attestations from the CSV exports of legacy MRV systems.
+//! - A `CsvMapping` (serde, kept next to each partner's exports) names the header of the column
+//!   behind each request field; columns it does not name are ignored
+//! - Fields follow RFC 4180: separated by `delimiter`, optionally double-quoted, `""` for a quote
+//!   inside quotes, line breaks allowed inside quotes; blank lines are skipped
+//! - Metric cells are converted to the metric's canonical unit with `units::convert`, from a
+//!   unit the mapping fixes for the column or one read from a unit column
+//! - Timestamps are RFC 3339 (`2025-03-01T09:30:00Z`, offsets and fractions allowed) or epoch
+//!   milliseconds or seconds
+//! - Each row parses on its own: a bad row is reported with the line it starts on and the rest
+//!   of the file is still read; only an unreadable file or header stops everything
+//! - `PlanetaryLedger::ingest_csv` validates and issues each row, marking what it issues
+//!   `AttestationOrigin::ExternalImport`
+
+use serde::{Serialize, Deserialize};
+use std::fmt;
+use std::io::Read;
+
+use crate::units;
+use crate::{
+    AttestationId, AttestationOrigin, AttestationRequest, Did, EvidenceArtifact, IdempotencyKey, ImpactMetrics,
+    MissionId, ModuleMetrics, PlanetaryLedger, SaepRefs, StewardModule,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TimestampFormat {
+    #[default]
+    Rfc3339,
+    EpochMillis,
+    EpochSeconds,
+}
+
+/// An `ImpactMetrics` field a column can fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum HeadlineMetric {
+    Co2eqReduced,
+    BiodiversityIndexDelta,
+    RestoredAreaM2,
+    AvoidedEmissionsCo2eq,
+}
+
+impl HeadlineMetric {
+    /// The unit the ledger stores the metric in.
+    pub fn canonical_unit(self) -> &'static str {
+        match self {
+            HeadlineMetric::Co2eqReduced | HeadlineMetric::AvoidedEmissionsCo2eq => "t",
+            HeadlineMetric::RestoredAreaM2 => "m2",
+            HeadlineMetric::BiodiversityIndexDelta => "index",
+        }
+    }
+
+    fn slot(self, metrics: &mut ImpactMetrics) -> &mut f64 {
+        match self {
+            HeadlineMetric::Co2eqReduced => &mut metrics.co2eq_reduced,
+            HeadlineMetric::BiodiversityIndexDelta => &mut metrics.biodiversity_index_delta,
+            HeadlineMetric::RestoredAreaM2 => &mut metrics.restored_area_m2,
+            HeadlineMetric::AvoidedEmissionsCo2eq => &mut metrics.avoided_emissions_co2eq,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricColumn {
+    pub column: String,
+    pub metric: HeadlineMetric,
+    /// Unit of every cell in the column; the metric's canonical unit when neither this nor
+    /// `unit_column` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Column holding each row's unit; wins over `unit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit_column: Option<String>,
+}
+
+/// Which header holds each request field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CsvMapping {
+    pub actor_did: String,
+    pub description: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub timestamp_format: TimestampFormat,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<String>,
+    /// One artifact per row; an empty cell means none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence_uri: Option<String>,
+    /// DIDs separated by `;`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifier_dids: Option<String>,
+    /// Cells of columns filling the same metric are added up; empty cells count as zero.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metrics: Vec<MetricColumn>,
+    #[serde(default = "default_delimiter")]
+    pub delimiter: char,
+}
+
+fn default_delimiter() -> char {
+    ','
+}
+
+/// Why a row (or, at line 1, the whole file) could not become a request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RowError {
+    /// Line the row starts on, the header being line 1.
+    pub line: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    pub detail: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.column {
+            Some(column) => write!(f, "line {}, column {column}: {}", self.line, self.detail),
+            None => write!(f, "line {}: {}", self.line, self.detail),
+        }
+    }
+}
+
+impl std::error::Error for RowError {}
+
+/// One request per data row, in file order. A file that cannot be read, or whose header lacks
+/// a mapped column, gives a single error at line 1.
+pub fn parse_csv<R: Read>(reader: R, mapping: &CsvMapping) -> Vec<Result<AttestationRequest, RowError>> {
+    parse_rows(reader, mapping).into_iter().map(|(_, row)| row).collect()
+}
+
+/// `parse_csv`, with the line each row starts on.
+fn parse_rows<R: Read>(mut reader: R, mapping: &CsvMapping) -> Vec<(usize, Result<AttestationRequest, RowError>)> {
+    let file_error = |detail: String| vec![(1, Err(RowError { line: 1, column: None, detail }))];
+    let mut text = String::new();
+    if let Err(e) = reader.read_to_string(&mut text) {
+        return file_error(format!("file is not readable as UTF-8 text: {e}"));
+    }
+    let mut records = records(text.trim_start_matches('\u{feff}'), mapping.delimiter).into_iter();
+    let header = match records.next() {
+        Some((_, Ok(header))) => header,
+        Some((_, Err(detail))) => return file_error(format!("header {detail}")),
+        None => return file_error("file has no header".into()),
+    };
+    let columns = match Columns::resolve(&header, mapping) {
+        Ok(columns) => columns,
+        Err(missing) => return file_error(format!("column {missing:?} is not in the header")),
+    };
+    records
+        .map(|(line, record)| {
+            let row = record
+                .map_err(|detail| (None, format!("row {detail}")))
+                .and_then(|fields| columns.request(&fields, mapping))
+                .map_err(|(column, detail)| RowError { line, column, detail });
+            (line, row)
+        })
+        .collect()
+}
+
+/// Header positions of the mapped columns.
+struct Columns {
+    width: usize,
+    names: Vec<String>,
+    actor_did: usize,
+    description: usize,
+    timestamp: usize,
+    mission_id: Option<usize>,
+    evidence_uri: Option<usize>,
+    verifier_dids: Option<usize>,
+    /// Per `CsvMapping::metrics` entry: the value column and the unit column.
+    metrics: Vec<(usize, Option<usize>)>,
+}
+
+impl Columns {
+    fn resolve(header: &[String], mapping: &CsvMapping) -> Result<Self, String> {
+        let names: Vec<String> = header.iter().map(|h| h.trim().to_string()).collect();
+        let find = |name: &str| names.iter().position(|h| h == name).ok_or_else(|| name.to_string());
+        let optional = |name: &Option<String>| name.as_deref().map(find).transpose();
+        let mut metrics = Vec::new();
+        for metric in &mapping.metrics {
+            metrics.push((find(&metric.column)?, optional(&metric.unit_column)?));
+        }
+        Ok(Self {
+            width: names.len(),
+            actor_did: find(&mapping.actor_did)?,
+            description: find(&mapping.description)?,
+            timestamp: find(&mapping.timestamp)?,
+            mission_id: optional(&mapping.mission_id)?,
+            evidence_uri: optional(&mapping.evidence_uri)?,
+            verifier_dids: optional(&mapping.verifier_dids)?,
+            metrics,
+            names,
+        })
+    }
+
+    /// The request in `fields`, or the column at fault and what is wrong with it.
+    fn request(&self, fields: &[String], mapping: &CsvMapping) -> Result<AttestationRequest, (Option<String>, String)> {
+        if fields.len() != self.width {
+            return Err((None, format!("has {} fields; the header has {}", fields.len(), self.width)));
+        }
+        let cell = |i: usize| fields[i].trim();
+        let bad = |i: usize, detail: String| (Some(self.names[i].clone()), detail);
+
+        let actor_did = Did::new(cell(self.actor_did)).map_err(|e| bad(self.actor_did, e.to_string()))?;
+        let mission_id = match self.mission_id.filter(|&i| !cell(i).is_empty()) {
+            Some(i) => Some(MissionId::new(cell(i)).map_err(|e| bad(i, e.to_string()))?),
+            None => None,
+        };
+        let timestamp_ms = parse_timestamp(cell(self.timestamp), mapping.timestamp_format)
+            .map_err(|detail| bad(self.timestamp, detail))?;
+
+        let mut impact_metrics = ImpactMetrics::default();
+        for (spec, &(column, unit_column)) in mapping.metrics.iter().zip(&self.metrics) {
+            if cell(column).is_empty() {
+                continue;
+            }
+            let value: f64 = cell(column)
+                .parse()
+                .ok()
+                .filter(|v: &f64| v.is_finite())
+                .ok_or_else(|| bad(column, format!("{:?} is not a number", cell(column))))?;
+            let unit = match unit_column {
+                Some(i) => cell(i),
+                None => spec.unit.as_deref().unwrap_or(spec.metric.canonical_unit()),
+            };
+            let converted = units::convert(value, unit, spec.metric.canonical_unit())
+                .map_err(|detail| bad(unit_column.unwrap_or(column), detail))?;
+            *spec.metric.slot(&mut impact_metrics) += converted;
+        }
+
+        let evidence = match self.evidence_uri.filter(|&i| !cell(i).is_empty()) {
+            Some(i) => vec![EvidenceArtifact::from_uri(cell(i))],
+            None => vec![],
+        };
+        let mut verifier_dids = Vec::new();
+        if let Some(i) = self.verifier_dids {
+            for did in cell(i).split(';').map(str::trim).filter(|d| !d.is_empty()) {
+                verifier_dids.push(Did::new(did).map_err(|e| bad(i, e.to_string()))?);
+            }
+        }
+        Ok(AttestationRequest {
+            actor_did,
+            mission_id,
+            description: cell(self.description).to_string(),
+            module: StewardModule::PLGA,
+            impact_metrics,
+            module_metrics: ModuleMetrics::new(),
+            evidence,
+            verifier_dids,
+            timestamp_ms,
+            refs: SaepRefs::default(),
+            capture_window: None,
+            witness_endorsement: None,
+        })
+    }
+}
+
+/// The records in `text`, each with the line it starts on. A record with an unterminated quote,
+/// or a stray quote, is an error; the next record still starts on the next line break.
+fn records(text: &str, delimiter: char) -> Vec<(usize, Result<Vec<String>, String>)> {
+    let mut out = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let start = line;
+        let (mut fields, mut field) = (Vec::new(), String::new());
+        let (mut in_quotes, mut was_quoted, mut field_started) = (false, false, false);
+        let mut error: Option<&str> = None;
+        loop {
+            let Some(c) = chars.next() else {
+                if in_quotes {
+                    error.get_or_insert("has an unterminated quoted field");
+                }
+                break;
+            };
+            if in_quotes {
+                match c {
+                    '"' if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    '"' => in_quotes = false,
+                    '\n' => {
+                        line += 1;
+                        field.push(c);
+                    }
+                    _ => field.push(c),
+                }
+                continue;
+            }
+            match c {
+                '"' if !field_started => (in_quotes, was_quoted, field_started) = (true, true, true),
+                '"' => {
+                    error.get_or_insert("has a quote inside an unquoted field");
+                }
+                c if c == delimiter => {
+                    fields.push(std::mem::take(&mut field));
+                    (was_quoted, field_started) = (false, false);
+                }
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\n' => {
+                    line += 1;
+                    break;
+                }
+                _ if was_quoted => {
+                    error.get_or_insert("has text after a closing quote");
+                }
+                _ => {
+                    field.push(c);
+                    field_started = true;
+                }
+            }
+        }
+        let blank = fields.is_empty() && field.trim().is_empty() && !was_quoted && error.is_none();
+        if !blank {
+            fields.push(field);
+            out.push((start, error.map_or(Ok(fields), |e| Err(e.to_string()))));
+        }
+    }
+    out
+}
+
+fn parse_timestamp(cell: &str, format: TimestampFormat) -> Result<u64, String> {
+    let parsed = match format {
+        TimestampFormat::Rfc3339 => parse_rfc3339(cell),
+        TimestampFormat::EpochMillis => cell.parse().ok(),
+        TimestampFormat::EpochSeconds => cell.parse::<u64>().ok().and_then(|s| s.checked_mul(1_000)),
+    };
+    let expected = match format {
+        TimestampFormat::Rfc3339 => "an RFC 3339 timestamp",
+        TimestampFormat::EpochMillis => "a count of epoch milliseconds",
+        TimestampFormat::EpochSeconds => "a count of epoch seconds",
+    };
+    parsed.ok_or_else(|| format!("{cell:?} is not {expected}"))
+}
+
+/// Milliseconds since the epoch for `YYYY-MM-DDTHH:MM:SS[.fff][Z|±HH:MM]`; `None` for anything
+/// else, or a time before 1970.
+fn parse_rfc3339(s: &str) -> Option<u64> {
+    let digits = |range: std::ops::Range<usize>| -> Option<i64> {
+        let part = s.get(range)?;
+        part.bytes().all(|b| b.is_ascii_digit()).then(|| part.parse().ok())?
+    };
+    let bytes = s.as_bytes();
+    let separators = [(4, b'-'), (7, b'-'), (13, b':'), (16, b':')];
+    if bytes.len() < 20 || separators.iter().any(|&(i, b)| bytes[i] != b) || !b"Tt ".contains(&bytes[10]) {
+        return None;
+    }
+    let (year, month, day) = (digits(0..4)?, digits(5..7)?, digits(8..10)?);
+    let (hour, minute, second) = (digits(11..13)?, digits(14..16)?, digits(17..19)?);
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut millis = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return None;
+        }
+        let padded = format!("{:0<3}", &fraction[..len.min(3)]);
+        millis = padded.parse::<i64>().ok()?;
+        rest = &fraction[len..];
+    }
+    let offset_minutes = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes().first()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let (hours, minutes) = rest[1..].split_once(':')?;
+            if hours.len() != 2 || minutes.len() != 2 {
+                return None;
+            }
+            let (hours, minutes): (i64, i64) = (hours.parse().ok()?, minutes.parse().ok()?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            sign * (hours * 60 + minutes)
+        }
+    };
+
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second - offset_minutes * 60;
+    u64::try_from(seconds * 1_000 + millis).ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days from 1970-01-01 to the given proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// What `ingest_csv` did with one row.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CsvRowOutcome {
+    Imported { attestation_id: AttestationId },
+    /// The row did not parse into a request.
+    Malformed {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        column: Option<String>,
+        detail: String,
+    },
+    /// The request parsed, but validation or issuance refused it.
+    Refused { reasons: Vec<String> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CsvRowReport {
+    pub line: usize,
+    pub outcome: CsvRowOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CsvImportReport {
+    pub source_ref: String,
+    /// In file order.
+    pub rows: Vec<CsvRowReport>,
+}
+
+impl CsvImportReport {
+    pub fn imported(&self) -> usize {
+        self.rows.iter().filter(|r| matches!(r.outcome, CsvRowOutcome::Imported { .. })).count()
+    }
+}
+
+impl PlanetaryLedger {
+    /// Parse `reader` with `mapping`, then validate and issue each row, marking what is issued as
+    /// imported from `source_ref`. Each row is issued under the idempotency key
+    /// `<source_ref>:<line>`, so importing the same file again replays instead of duplicating.
+    pub fn ingest_csv<R: Read>(&mut self, reader: R, mapping: &CsvMapping, source_ref: &str) -> CsvImportReport {
+        let mut rows = Vec::new();
+        for (line, row) in parse_rows(reader, mapping) {
+            let outcome = match row {
+                Err(RowError { column, detail, .. }) => CsvRowOutcome::Malformed { column, detail },
+                Ok(request) => self.import_request(request, source_ref, line),
+            };
+            rows.push(CsvRowReport { line, outcome });
+        }
+        CsvImportReport { source_ref: source_ref.to_string(), rows }
+    }
+
+    fn import_request(&mut self, request: AttestationRequest, source_ref: &str, line: usize) -> CsvRowOutcome {
+        let report = self.validate_attestation(&request);
+        if !report.is_valid() {
+            return CsvRowOutcome::Refused { reasons: report.issues.into_iter().map(|i| i.message).collect() };
+        }
+        let key = IdempotencyKey::new(format!("{source_ref}:{line}"), &request);
+        match self.issue_attestation_idempotent(request, Some(key)) {
+            Ok(att) => {
+                let origin = AttestationOrigin::ExternalImport { source_ref: source_ref.to_string() };
+                self.attestations.get_mut(&att.id).expect("just issued").origin = origin;
+                self.publish_attestation(&att.id);
+                CsvRowOutcome::Imported { attestation_id: att.id }
+            }
+            Err(reason) => CsvRowOutcome::Refused { reasons: vec![reason] },
+        }
+    }
+}
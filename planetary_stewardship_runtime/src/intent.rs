@@ -0,0 +1,205 @@
+// path: planetary_stewardship_runtime/src/intent.rs
+
+//! Public intent log backing SAEP's `require_public_intent_log`.
+//! - Append-only, hash-chained; an entry's id is its `self_hash`
+//! - PLGA and MME publish (or accept a caller's) entry before acting when SAEP demands it
+//! - One log can be shared by several engines (`SharedIntentLog`)
+//! - `query` by actor and time range feeds public dashboards
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::{Did, StewardModule};
+
+/// `prev_hash` of the first entry.
+pub const INTENT_GENESIS_HASH: &str = "genesis";
+
+/// Log shared between engines that must publish to the same public record.
+pub type SharedIntentLog = Arc<Mutex<IntentLog>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IntentEntryId(pub String);
+
+impl fmt::Display for IntentEntryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// What an actor announces before acting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IntentEntry {
+    pub actor: Did,
+    pub module: StewardModule,
+    pub description: String,
+    /// Identifies the action the intent covers (e.g. `plga:attestation:<id>`).
+    pub decision_id: String,
+    pub timestamp_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IntentLogEntry {
+    pub seq: u64,
+    pub entry: IntentEntry,
+    pub prev_hash: String,
+    pub self_hash: String,
+}
+
+impl IntentLogEntry {
+    pub fn id(&self) -> IntentEntryId {
+        IntentEntryId(self.self_hash.clone())
+    }
+
+    /// Hash over everything except `self_hash` (struct field order is fixed).
+    fn compute_hash(&self) -> String {
+        let payload = serde_json::to_vec(&serde_json::json!({
+            "seq": self.seq,
+            "entry": self.entry,
+            "prev_hash": self.prev_hash,
+        }))
+        .expect("intent entry serialization");
+        let mut hasher = Sha256::new();
+        hasher.update(&payload);
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// First entry that fails verification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IntentLogError {
+    pub index: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for IntentLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Intent log entry {}: {}", self.index, self.reason)
+    }
+}
+
+impl std::error::Error for IntentLogError {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IntentLog {
+    entries: Vec<IntentLogEntry>,
+}
+
+impl IntentLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty log ready to hand to several engines.
+    pub fn shared() -> SharedIntentLog {
+        Arc::new(Mutex::new(Self::new()))
+    }
+
+    pub fn publish(&mut self, entry: IntentEntry) -> IntentEntryId {
+        let prev_hash = self
+            .entries
+            .last()
+            .map(|e| e.self_hash.clone())
+            .unwrap_or_else(|| INTENT_GENESIS_HASH.to_string());
+        let mut published = IntentLogEntry {
+            seq: self.entries.len() as u64,
+            entry,
+            prev_hash,
+            self_hash: String::new(),
+        };
+        published.self_hash = published.compute_hash();
+        let id = published.id();
+        self.entries.push(published);
+        id
+    }
+
+    pub fn get(&self, id: &IntentEntryId) -> Option<&IntentLogEntry> {
+        self.entries.iter().find(|e| e.self_hash == id.0)
+    }
+
+    pub fn entries(&self) -> &[IntentLogEntry] {
+        &self.entries
+    }
+
+    /// Entries with `from_ms <= timestamp_ms < to_ms`, optionally for one actor, oldest first.
+    pub fn query(&self, actor: Option<&Did>, from_ms: u64, to_ms: u64) -> Vec<&IntentLogEntry> {
+        self.entries
+            .iter()
+            .filter(|e| actor.is_none_or(|a| &e.entry.actor == a))
+            .filter(|e| (from_ms..to_ms).contains(&e.entry.timestamp_ms))
+            .collect()
+    }
+
+    /// Check sequence numbers, the hash chain and every self-hash.
+    pub fn verify(&self) -> Result<(), IntentLogError> {
+        let mut prev_hash = INTENT_GENESIS_HASH;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.seq != index as u64 {
+                return Err(IntentLogError {
+                    index,
+                    reason: format!("sequence number {} out of order", entry.seq),
+                });
+            }
+            if entry.prev_hash != prev_hash {
+                return Err(IntentLogError {
+                    index,
+                    reason: "prev_hash does not match previous entry".into(),
+                });
+            }
+            if entry.compute_hash() != entry.self_hash {
+                return Err(IntentLogError {
+                    index,
+                    reason: "self_hash does not match entry contents".into(),
+                });
+            }
+            prev_hash = &entry.self_hash;
+        }
+        Ok(())
+    }
+}
+
+/// The entry an action runs under: the caller's, checked against actor and
+/// module, or a freshly published one built by `auto`.
+pub(crate) fn resolve_intent(
+    log: &SharedIntentLog,
+    supplied: Option<IntentEntryId>,
+    actor: &Did,
+    module: StewardModule,
+    auto: impl FnOnce() -> IntentEntry,
+) -> Result<IntentEntryId, String> {
+    let mut log = log.lock().map_err(|_| "Intent log lock poisoned".to_string())?;
+    match supplied {
+        Some(id) => {
+            check_published(&log, &id, actor, module)?;
+            Ok(id)
+        }
+        None => Ok(log.publish(auto())),
+    }
+}
+
+/// Read-only half of `resolve_intent`: a supplied entry must exist for this actor and module.
+pub(crate) fn check_intent(
+    log: &SharedIntentLog,
+    supplied: &IntentEntryId,
+    actor: &Did,
+    module: StewardModule,
+) -> Result<(), String> {
+    let log = log.lock().map_err(|_| "Intent log lock poisoned".to_string())?;
+    check_published(&log, supplied, actor, module)
+}
+
+fn check_published(log: &IntentLog, id: &IntentEntryId, actor: &Did, module: StewardModule) -> Result<(), String> {
+    let published = log
+        .get(id)
+        .ok_or_else(|| format!("Intent entry {id} is not in the public intent log"))?;
+    if &published.entry.actor != actor || published.entry.module != module {
+        return Err(format!("Intent entry {id} was published for a different actor or module"));
+    }
+    Ok(())
+}
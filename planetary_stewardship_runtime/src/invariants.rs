@@ -0,0 +1,42 @@
+// path: planetary_stewardship_runtime/src/invariants.rs
+
+//! State checks for generated operation sequences (feature `test-util`).
+//! - `ledger.keyed_by_id`: every attestation is stored under its own id
+//! - `ledger.reservation_released`: no issued id is still reserved
+//! - `ledger.view_current`: the shared read view holds exactly the stored attestations, as stored
+//! - `ledger.redactions_verify`: the redaction log's chain holds and matches what it redacted
+
+use steward_ids::invariants::InvariantViolation;
+
+use crate::PlanetaryLedger;
+
+impl PlanetaryLedger {
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let mut ids: Vec<_> = self.attestations.keys().collect();
+        ids.sort_by(|a, b| a.0.cmp(&b.0));
+        let view = self.attestation_views.snapshot();
+        for id in &ids {
+            let attestation = &self.attestations[*id];
+            if attestation.id != **id {
+                let detail = format!("{} is stored under {}", attestation.id.0, id.0);
+                return Err(InvariantViolation::new("ledger.keyed_by_id", detail));
+            }
+            if self.reserved.contains(*id) {
+                let detail = format!("{} is issued and still reserved", id.0);
+                return Err(InvariantViolation::new("ledger.reservation_released", detail));
+            }
+            let as_viewed = view.get(id).map(|a| serde_json::to_value(a).expect("attestations serialize"));
+            if as_viewed != Some(serde_json::to_value(attestation).expect("attestations serialize")) {
+                let detail = format!("the read view's {} differs from the stored one", id.0);
+                return Err(InvariantViolation::new("ledger.view_current", detail));
+            }
+        }
+        let viewed = view.values().len();
+        if viewed != ids.len() {
+            let detail = format!("the read view holds {viewed} attestations, the ledger {}", ids.len());
+            return Err(InvariantViolation::new("ledger.view_current", detail));
+        }
+        self.verify_redactions()
+            .map_err(|e| InvariantViolation::new("ledger.redactions_verify", e.to_string()))
+    }
+}
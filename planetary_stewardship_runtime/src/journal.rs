@@ -0,0 +1,257 @@
+// path: planetary_stewardship_runtime/src/journal.rs
+
+//! Offline-first mutation journal: operations a field device queued while disconnected,
+//! replayed in order when it syncs.
+//! - `MutationJournal` is append-only; each entry carries a client-chosen idempotency key,
+//!   the client's timestamp and one `JournalOp`
+//! - `PlanetaryLedger::apply_journal` applies PLGA entries (PLGA consents, attestations),
+//!   `MicroMissionsEngine::apply_journal` MME entries (MME consents, assignments, closings);
+//!   each reports the others' entries as `OtherModule`, so a journal goes to both
+//! - Every entry gets a result: `Applied`, `Skipped` (its key was already applied for the same
+//!   operation, e.g. by another device's overlapping journal), `Rejected` with every reason
+//!   validation found, or `NotAttempted` after a rejection under `ReplayPolicy::StopOnRejection`
+//! - Keys are held like other idempotency keys, for the engine's idempotency TTL; set it to
+//!   cover the longest time a device may stay offline
+//! - Client clocks are not trusted: results record the server's apply time next to the
+//!   client's, assignments and closings are made at the apply time, and consent timestamps
+//!   after it are brought back to it. Attestation requests are applied as sent, since a
+//!   witness endorsement covers their timestamp
+
+use serde::{Serialize, Deserialize};
+
+use crate::idempotency::IdempotencyStore;
+use crate::{
+    AssignmentKey, AssignmentStatus, AttestationId, AttestationRequest, ConsentRecord, Did, IdempotencyKey, MissionId,
+    SaepRefs, StewardModule,
+};
+
+/// Operations queued by one device, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MutationJournal {
+    /// The device that wrote the journal; reported back, never interpreted.
+    pub device: String,
+    entries: Vec<JournalEntry>,
+}
+
+impl MutationJournal {
+    pub fn new(device: impl Into<String>) -> Self {
+        Self { device: device.into(), entries: Vec::new() }
+    }
+
+    /// Queue `op` under `key`, which must be non-empty and new to this journal.
+    pub fn append(
+        &mut self,
+        key: impl Into<String>,
+        client_ts_ms: u64,
+        op: JournalOp,
+    ) -> Result<&JournalEntry, String> {
+        let key = key.into();
+        if key.trim().is_empty() {
+            return Err("A journal entry needs an idempotency key".into());
+        }
+        if self.entries.iter().any(|e| e.key == key) {
+            return Err(format!("Journal {} already has an entry under {key}", self.device));
+        }
+        self.entries.push(JournalEntry { key, client_ts_ms, op });
+        Ok(&self.entries[self.entries.len() - 1])
+    }
+
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JournalEntry {
+    pub key: String,
+    /// When the device queued the operation, by its own clock.
+    pub client_ts_ms: u64,
+    pub op: JournalOp,
+}
+
+impl JournalEntry {
+    /// `key` bound to the operation, so reusing it for another one is a conflict.
+    pub fn idempotency_key(&self) -> IdempotencyKey {
+        IdempotencyKey::new(&self.key, &self.op)
+    }
+}
+
+/// A mutating call, as queued.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum JournalOp {
+    /// `ConsentRegistry::submit_consent`.
+    SubmitConsent { record: ConsentRecord },
+    /// `PlanetaryLedger::issue_attestation_idempotent`.
+    IssueAttestation { request: Box<AttestationRequest> },
+    /// `MicroMissionsEngine::assign_mission_with_refs`.
+    AssignMission {
+        mission: MissionId,
+        assignee: Did,
+        #[serde(default)]
+        refs: SaepRefs,
+    },
+    /// `MicroMissionsEngine::close_assignment`, e.g. a completed mission.
+    CloseAssignment { assignment: AssignmentRef, status: AssignmentStatus },
+}
+
+impl JournalOp {
+    /// The module whose engine applies this operation.
+    pub fn module(&self) -> StewardModule {
+        match self {
+            JournalOp::SubmitConsent { record } => record.module,
+            JournalOp::IssueAttestation { request } => request.module,
+            JournalOp::AssignMission { .. } | JournalOp::CloseAssignment { .. } => StewardModule::MME,
+        }
+    }
+}
+
+/// The assignment a closing names. A device offline since assigning cannot know the
+/// assignment's key, which carries the server's time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AssignmentRef {
+    /// Made by the `AssignMission` entry under this key, in this or an earlier journal.
+    Journaled(String),
+    /// Made online, as returned then.
+    Key(AssignmentKey),
+}
+
+/// What an applied entry did.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum JournalEffect {
+    Consent,
+    Attestation { id: AttestationId },
+    Assigned { key: AssignmentKey },
+    Closed { key: AssignmentKey },
+}
+
+/// Whether one rejected entry ends the replay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReplayPolicy {
+    /// Later entries are still applied; for journals of independent operations.
+    #[default]
+    ContinueOnRejection,
+    /// Later entries are reported `NotAttempted`; for journals whose entries build on each other.
+    StopOnRejection,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum JournalOutcome {
+    Applied { effect: JournalEffect },
+    /// The key was already applied for the same operation; `effect` is what that did.
+    Skipped { effect: JournalEffect },
+    /// Nothing was changed; unless another operation holds the key, it can be retried once fixed.
+    Rejected { reasons: Vec<String> },
+    /// Applied by another module's engine.
+    OtherModule { module: StewardModule },
+    /// Not reached: an earlier entry was rejected under `ReplayPolicy::StopOnRejection`.
+    NotAttempted,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JournalEntryResult {
+    pub key: String,
+    pub client_ts_ms: u64,
+    /// Server time the entry was looked at; `None` when it was not.
+    pub applied_ms: Option<u64>,
+    pub outcome: JournalOutcome,
+}
+
+/// One result per journal entry, in journal order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JournalReport {
+    pub device: String,
+    pub results: Vec<JournalEntryResult>,
+}
+
+impl JournalReport {
+    pub fn applied(&self) -> impl Iterator<Item = &JournalEntryResult> {
+        self.results.iter().filter(|r| matches!(r.outcome, JournalOutcome::Applied { .. }))
+    }
+
+    pub fn skipped(&self) -> impl Iterator<Item = &JournalEntryResult> {
+        self.results.iter().filter(|r| matches!(r.outcome, JournalOutcome::Skipped { .. }))
+    }
+
+    pub fn rejected(&self) -> impl Iterator<Item = &JournalEntryResult> {
+        self.results.iter().filter(|r| matches!(r.outcome, JournalOutcome::Rejected { .. }))
+    }
+}
+
+/// An engine a journal replays against.
+pub(crate) trait JournalTarget {
+    const MODULE: StewardModule;
+
+    /// Journal keys applied here, with what each did.
+    fn journal_keys(&mut self) -> &mut IdempotencyStore<JournalEffect>;
+
+    /// Validate and apply `op` at `now_ms`; every reason on refusal.
+    fn apply_op(&mut self, op: &JournalOp, now_ms: u64) -> Result<JournalEffect, Vec<String>>;
+}
+
+/// Replay `journal` against `target` in order, reading the server time per entry.
+pub(crate) fn replay<T: JournalTarget>(
+    target: &mut T,
+    journal: &MutationJournal,
+    policy: ReplayPolicy,
+    now_ms: impl Fn() -> u64,
+) -> JournalReport {
+    let mut stopped = false;
+    let mut results = Vec::with_capacity(journal.len());
+    for entry in journal.entries() {
+        let (applied_ms, outcome) = if stopped {
+            (None, JournalOutcome::NotAttempted)
+        } else if entry.op.module() != T::MODULE {
+            (None, JournalOutcome::OtherModule { module: entry.op.module() })
+        } else {
+            let now = now_ms();
+            (Some(now), apply_entry(target, entry, now))
+        };
+        stopped |= policy == ReplayPolicy::StopOnRejection && matches!(outcome, JournalOutcome::Rejected { .. });
+        results.push(JournalEntryResult {
+            key: entry.key.clone(),
+            client_ts_ms: entry.client_ts_ms,
+            applied_ms,
+            outcome,
+        });
+    }
+    JournalReport { device: journal.device.clone(), results }
+}
+
+fn apply_entry<T: JournalTarget>(target: &mut T, entry: &JournalEntry, now_ms: u64) -> JournalOutcome {
+    let key = entry.idempotency_key();
+    match target.journal_keys().lookup(&key, now_ms) {
+        Err(conflict) => return JournalOutcome::Rejected { reasons: vec![conflict.to_string()] },
+        Ok(Some(effect)) => return JournalOutcome::Skipped { effect: effect.clone() },
+        Ok(None) => {}
+    }
+    match target.apply_op(&entry.op, now_ms) {
+        Ok(effect) => {
+            target.journal_keys().record(key, effect.clone(), now_ms);
+            JournalOutcome::Applied { effect }
+        }
+        Err(reasons) => JournalOutcome::Rejected { reasons },
+    }
+}
+
+/// `record` with a timestamp no later than `now_ms`.
+pub(crate) fn clamp_consent(record: &ConsentRecord, now_ms: u64) -> ConsentRecord {
+    ConsentRecord { timestamp_ms: record.timestamp_ms.min(now_ms), ..record.clone() }
+}
@@ -0,0 +1,366 @@
+// path: planetary_stewardship_runtime/src/keys.rs
+
+//! Signing keys of witnesses and verifiers, with their lifecycles.
+//! - `KeyResolver` finds a DID's key by key id as it stood at a given time, for a given
+//!   purpose; the ledger checks witness endorsements as of the claim's timestamp and verifier
+//!   approvals as of the review
+//! - `KeyRegistry` holds the keys: each is valid over `[valid_from_ms, valid_until_ms)` for one
+//!   `KeyPurpose`. Rotating closes the old key's window where the successor's opens, so
+//!   signatures made before the rotation keep verifying under the old key
+//! - Revocation says from when a key is not to be trusted, which may be before it is recorded
+//!   (a compromise found later): signatures made before then stay valid, later ones fail
+//! - A key verifies only for its purpose, so a governance tally-signing key cannot endorse or
+//!   approve attestations
+//! - Registering, rotating and revoking take a registry admin, and each lands in the audit log
+//!   with who did it; the registry serializes whole, log included. Revoking goes through
+//!   `AdminAction::RevokeKey`, under `DualControl`
+//! - The signature bytes are the `SignatureScheme`'s to check (ed25519 in a deployment)
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::{AdminBinding, Did};
+
+/// What a key may sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum KeyPurpose {
+    /// Witness endorsements and verifier approvals.
+    AttestationVerification,
+    GovernanceTallySigning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PublicKey {
+    pub did: Did,
+    pub key_id: String,
+    pub purpose: KeyPurpose,
+    /// Encoded as the `SignatureScheme` expects, e.g. hex ed25519.
+    pub material: String,
+}
+
+/// Why a key could not be used, or a signature did not verify under it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum KeyError {
+    UnknownKey { did: Did, key_id: String },
+    WrongPurpose { did: Did, key_id: String, purpose: KeyPurpose, requested: KeyPurpose },
+    OutsideValidity { did: Did, key_id: String, as_of_ms: u64, valid_from_ms: u64, valid_until_ms: Option<u64> },
+    Revoked { did: Did, key_id: String, as_of_ms: u64, revoked_from_ms: u64 },
+    BadSignature { did: Did, key_id: String },
+    /// The resolver could not read its keys.
+    Unavailable,
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyError::UnknownKey { did, key_id } => write!(f, "{did} has no key {key_id}"),
+            KeyError::WrongPurpose { did, key_id, purpose, requested } => {
+                write!(f, "Key {key_id} of {did} is for {purpose:?}, not {requested:?}")
+            }
+            KeyError::OutsideValidity { did, key_id, as_of_ms, valid_from_ms, valid_until_ms } => {
+                write!(f, "Key {key_id} of {did} is valid from {valid_from_ms}")?;
+                if let Some(until) = valid_until_ms {
+                    write!(f, " until {until}")?;
+                }
+                write!(f, ", not at {as_of_ms}")
+            }
+            KeyError::Revoked { did, key_id, as_of_ms, revoked_from_ms } => {
+                write!(f, "Key {key_id} of {did} is revoked from {revoked_from_ms}, before {as_of_ms}")
+            }
+            KeyError::BadSignature { did, key_id } => {
+                write!(f, "Signature does not verify under key {key_id} of {did}")
+            }
+            KeyError::Unavailable => write!(f, "Key registry unavailable"),
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+/// Resolves DIDs' keys and checks signatures made with them; `PlanetaryLedger::with_key_resolver`.
+pub trait KeyResolver: Send + Sync {
+    /// `did`'s key `key_id`, if it could sign for `purpose` at `as_of_ms`.
+    fn resolve(&self, did: &Did, key_id: &str, purpose: KeyPurpose, as_of_ms: u64) -> Result<PublicKey, KeyError>;
+
+    /// Whether `signature` over `message` was made with `key`.
+    fn check_signature(&self, key: &PublicKey, message: &[u8], signature: &str) -> bool;
+
+    /// `resolve` the key, then check `signature` under it.
+    fn verify(
+        &self,
+        did: &Did,
+        key_id: &str,
+        purpose: KeyPurpose,
+        as_of_ms: u64,
+        message: &[u8],
+        signature: &str,
+    ) -> Result<PublicKey, KeyError> {
+        let key = self.resolve(did, key_id, purpose, as_of_ms)?;
+        if !self.check_signature(&key, message, signature) {
+            return Err(KeyError::BadSignature { did: did.clone(), key_id: key_id.into() });
+        }
+        Ok(key)
+    }
+}
+
+/// Checks signature bytes against a key; `RegistryKeyResolver`.
+pub trait SignatureScheme: Send + Sync {
+    fn verify(&self, key: &PublicKey, message: &[u8], signature: &str) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RegisteredKey {
+    pub key: PublicKey,
+    pub valid_from_ms: u64,
+    /// Exclusive; `None` until the key expires or is rotated.
+    #[serde(default)]
+    pub valid_until_ms: Option<u64>,
+    /// Key id of the key this one was rotated to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotated_to: Option<String>,
+}
+
+impl RegisteredKey {
+    fn covers(&self, as_of_ms: u64) -> bool {
+        as_of_ms >= self.valid_from_ms && self.valid_until_ms.is_none_or(|until| as_of_ms < until)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KeyRevocation {
+    pub did: Did,
+    pub key_id: String,
+    /// Signatures made at or after this time fail.
+    pub revoked_from_ms: u64,
+    pub reason: String,
+    pub by: Did,
+    pub recorded_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum KeyEventAction {
+    Registered { purpose: KeyPurpose, valid_from_ms: u64, valid_until_ms: Option<u64> },
+    RotatedTo { successor: String, at_ms: u64 },
+    Revoked { revoked_from_ms: u64, reason: String },
+}
+
+/// One change to the registry, in `KeyRegistry::audit_log`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KeyEvent {
+    pub timestamp_ms: u64,
+    pub by: Did,
+    pub did: Did,
+    pub key_id: String,
+    pub action: KeyEventAction,
+}
+
+pub type SharedKeyRegistry = Arc<Mutex<KeyRegistry>>;
+
+/// Keys by DID and key id, their revocations and every change to them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KeyRegistry {
+    /// DIDs allowed to register, rotate and revoke keys.
+    admins: BTreeSet<Did>,
+    keys: BTreeMap<Did, BTreeMap<String, RegisteredKey>>,
+    revocations: Vec<KeyRevocation>,
+    audit: Vec<KeyEvent>,
+    /// Where `AdminAction::RevokeKey` approvals come from; see `DualControl::bind`.
+    #[serde(skip)]
+    pub(crate) admin: AdminBinding,
+}
+
+impl KeyRegistry {
+    pub fn new(admins: impl IntoIterator<Item = Did>) -> Self {
+        Self { admins: admins.into_iter().collect(), ..Self::default() }
+    }
+
+    pub fn into_shared(self) -> SharedKeyRegistry {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub fn is_admin(&self, did: &Did) -> bool {
+        self.admins.contains(did)
+    }
+
+    pub fn key(&self, did: &Did, key_id: &str) -> Option<&RegisteredKey> {
+        self.keys.get(did).and_then(|keys| keys.get(key_id))
+    }
+
+    /// `did`'s keys, by key id.
+    pub fn keys_of(&self, did: &Did) -> impl Iterator<Item = &RegisteredKey> {
+        self.keys.get(did).into_iter().flat_map(|keys| keys.values())
+    }
+
+    /// In the order recorded.
+    pub fn revocations(&self) -> &[KeyRevocation] {
+        &self.revocations
+    }
+
+    pub fn audit_log(&self) -> &[KeyEvent] {
+        &self.audit
+    }
+
+    pub fn revocation(&self, did: &Did, key_id: &str) -> Option<&KeyRevocation> {
+        self.revocations.iter().find(|r| &r.did == did && r.key_id == key_id)
+    }
+
+    /// `did`'s key `key_id` as `KeyResolver::resolve` sees it.
+    pub fn resolve(&self, did: &Did, key_id: &str, purpose: KeyPurpose, as_of_ms: u64) -> Result<PublicKey, KeyError> {
+        let (did_owned, key_owned) = (did.clone(), key_id.to_string());
+        let Some(registered) = self.key(did, key_id) else {
+            return Err(KeyError::UnknownKey { did: did_owned, key_id: key_owned });
+        };
+        if registered.key.purpose != purpose {
+            let (found, requested) = (registered.key.purpose, purpose);
+            return Err(KeyError::WrongPurpose { did: did_owned, key_id: key_owned, purpose: found, requested });
+        }
+        if !registered.covers(as_of_ms) {
+            return Err(KeyError::OutsideValidity {
+                did: did_owned,
+                key_id: key_owned,
+                as_of_ms,
+                valid_from_ms: registered.valid_from_ms,
+                valid_until_ms: registered.valid_until_ms,
+            });
+        }
+        if let Some(revoked) = self.revocation(did, key_id).filter(|r| as_of_ms >= r.revoked_from_ms) {
+            let revoked_from_ms = revoked.revoked_from_ms;
+            return Err(KeyError::Revoked { did: did_owned, key_id: key_owned, as_of_ms, revoked_from_ms });
+        }
+        Ok(registered.key.clone())
+    }
+
+    fn require_admin(&self, by: &Did) -> Result<(), String> {
+        if !self.is_admin(by) {
+            return Err(format!("{by} is not a key registry admin"));
+        }
+        Ok(())
+    }
+
+    fn log(&mut self, by: &Did, did: &Did, key_id: &str, action: KeyEventAction, now_ms: u64) {
+        let event = KeyEvent { timestamp_ms: now_ms, by: by.clone(), did: did.clone(), key_id: key_id.into(), action };
+        self.audit.push(event);
+    }
+
+    /// Add `key`, valid over `[valid_from_ms, valid_until_ms)`.
+    pub fn register_key(
+        &mut self,
+        key: PublicKey,
+        valid_from_ms: u64,
+        valid_until_ms: Option<u64>,
+        by: &Did,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        self.require_admin(by)?;
+        if key.key_id.trim().is_empty() || key.material.trim().is_empty() {
+            return Err("A key needs a key id and key material".into());
+        }
+        if self.key(&key.did, &key.key_id).is_some() {
+            return Err(format!("{} already has a key {}", key.did, key.key_id));
+        }
+        if valid_until_ms.is_some_and(|until| until <= valid_from_ms) {
+            return Err(format!("Key {} would never be valid", key.key_id));
+        }
+        let (did, key_id, purpose) = (key.did.clone(), key.key_id.clone(), key.purpose);
+        let registered = RegisteredKey { key, valid_from_ms, valid_until_ms, rotated_to: None };
+        self.keys.entry(did.clone()).or_default().insert(key_id.clone(), registered);
+        let action = KeyEventAction::Registered { purpose, valid_from_ms, valid_until_ms };
+        self.log(by, &did, &key_id, action, now_ms);
+        Ok(())
+    }
+
+    /// Replace `did`'s key `key_id` with `successor` from `at_ms`: the old key stops being valid
+    /// there and the successor, of the same purpose, starts.
+    pub fn rotate_key(
+        &mut self,
+        did: &Did,
+        key_id: &str,
+        successor: PublicKey,
+        at_ms: u64,
+        by: &Did,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        self.require_admin(by)?;
+        let Some(old) = self.key(did, key_id) else {
+            return Err(format!("{did} has no key {key_id}"));
+        };
+        if &successor.did != did || successor.purpose != old.key.purpose {
+            return Err(format!("A successor to key {key_id} must be {did}'s, for {:?}", old.key.purpose));
+        }
+        if old.rotated_to.is_some() || !old.covers(at_ms) {
+            return Err(format!("Key {key_id} of {did} is not in force at {at_ms}"));
+        }
+        let successor_id = successor.key_id.clone();
+        self.register_key(successor, at_ms, None, by, now_ms)?;
+        let old = self.keys.get_mut(did).and_then(|keys| keys.get_mut(key_id)).expect("checked above");
+        old.valid_until_ms = Some(at_ms);
+        old.rotated_to = Some(successor_id.clone());
+        self.log(by, did, key_id, KeyEventAction::RotatedTo { successor: successor_id, at_ms }, now_ms);
+        Ok(())
+    }
+
+    /// Stop trusting `did`'s key `key_id` for signatures made at or after `revoked_from_ms`.
+    /// Only through `AdminAction::RevokeKey`.
+    pub(crate) fn revoke_key(
+        &mut self,
+        did: &Did,
+        key_id: &str,
+        revoked_from_ms: u64,
+        reason: String,
+        by: &Did,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        self.require_admin(by)?;
+        if reason.trim().is_empty() {
+            return Err("A key revocation needs a reason".into());
+        }
+        if self.key(did, key_id).is_none() {
+            return Err(format!("{did} has no key {key_id}"));
+        }
+        if self.revocation(did, key_id).is_some() {
+            return Err(format!("Key {key_id} of {did} is already revoked"));
+        }
+        self.revocations.push(KeyRevocation {
+            did: did.clone(),
+            key_id: key_id.into(),
+            revoked_from_ms,
+            reason: reason.clone(),
+            by: by.clone(),
+            recorded_ms: now_ms,
+        });
+        self.log(by, did, key_id, KeyEventAction::Revoked { revoked_from_ms, reason }, now_ms);
+        Ok(())
+    }
+}
+
+/// `KeyResolver` over a shared `KeyRegistry`: registry changes reach the ledger at once.
+pub struct RegistryKeyResolver {
+    registry: SharedKeyRegistry,
+    scheme: Arc<dyn SignatureScheme>,
+}
+
+impl RegistryKeyResolver {
+    pub fn new(registry: SharedKeyRegistry, scheme: Arc<dyn SignatureScheme>) -> Self {
+        Self { registry, scheme }
+    }
+}
+
+impl KeyResolver for RegistryKeyResolver {
+    fn resolve(&self, did: &Did, key_id: &str, purpose: KeyPurpose, as_of_ms: u64) -> Result<PublicKey, KeyError> {
+        self.registry.lock().map_err(|_| KeyError::Unavailable)?.resolve(did, key_id, purpose, as_of_ms)
+    }
+
+    fn check_signature(&self, key: &PublicKey, message: &[u8], signature: &str) -> bool {
+        self.scheme.verify(key, message, signature)
+    }
+}
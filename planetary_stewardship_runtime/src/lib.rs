@@ -7,28 +7,265 @@
 //!
 //! This crate is designed to sit under ALN / XR / BCI / biomechanical
 //! modules as a shared policy + attestation engine. [web:6][web:11][web:17]
+//!
+//! With the (default) `tracing` feature, decision paths emit spans and events
+//! using the workspace-wide field names `actor_did`, `module`, `decision`
+//! (`allow` / `deny`), `reason` and `duration_us`.
+//!
+//! With the `governance-anchoring` feature, `GovernanceAnchoring` records approved and vetoed
+//! proposals as CSC attestations on the ledger.
+//!
+//! Denials are built as `Reason`s (a `ReasonCode` plus parameters, see
+//! `steward_ids::messages`); `String` errors are their English rendering, and
+//! `check_proposal`, `EthicsDecision::coded_reasons` and validation issues carry the codes.
 
 use serde::{Serialize, Deserialize};
-use std::collections::{HashMap, HashSet};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{self, BufRead, Write};
+use std::sync::{Arc, Mutex};
+use steward_ids::stream::{self, StreamReader};
+use steward_runtime_support::{default_id_generator, system_clock, Clock, IdGenerator};
+
+use idempotency::IdempotencyStore;
+use journal::JournalTarget;
 
 /// ---------------------------------------------------------------------
 /// CORE IDS / ENUMS
 /// ---------------------------------------------------------------------
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct Did(pub String);
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct MissionId(pub String);
+/// Canonical, validated ids shared with the rest of the workspace.
+/// Re-exported (not aliased) so `Did("...".into())` keeps compiling.
+pub use steward_ids::{Did, IdError, LanguageTag, MetricKey, MissionId};
+pub use steward_ids::metrics::{CustomMetricRegistry, CustomMetricSpec, CustomMetrics, MetricValue};
+pub use steward_ids::messages::{Messages, Reason, ReasonCode};
+pub use steward_ids::receipts::{verify_receipt_inclusion, CountedBallots, VoteReceipt};
+pub use steward_ids::stream::{Compression, StreamError, StreamManifest};
+
+pub mod intent;
+pub use intent::{
+    IntentEntry, IntentEntryId, IntentLog, IntentLogEntry, IntentLogError, SharedIntentLog,
+    INTENT_GENESIS_HASH,
+};
+
+pub mod rollback;
+pub use rollback::{
+    ActionRef, ActionResolver, PlanId, PlanInvocation, RegisteredPlan, RollbackPlan, RollbackPlanRegistry,
+    SharedRollbackRegistry,
+};
+
+#[cfg(feature = "test-util")]
+pub mod builders;
+#[cfg(feature = "test-util")]
+mod invariants;
+
+pub mod consent_prompt;
+pub use consent_prompt::{
+    ConsentPromptDescriptor, CONSENT_PROMPT_VERSION, MME_ASSIGNMENT_PURPOSE, PLGA_ATTESTATION_PURPOSE,
+};
+
+pub mod consent_evidence;
+pub use consent_evidence::{ConsentEvidencePolicy, EvidenceVerifier, NoopEvidenceVerifier};
+
+pub mod consent_observer;
+pub use consent_observer::{
+    ConsentChange, ConsentDependent, ConsentEvent, ConsentEventQueue, ConsentObserver, ConsentRegistryMut,
+    ObserverFailure,
+};
+
+pub mod consent_requirements;
+pub use consent_requirements::{
+    consent_requirements, ActionKind, ConsentFreshness, ConsentParty, ConsentRequirements, ConsentScope,
+};
+
+pub mod evidence;
+pub use evidence::{EvidenceArtifact, EvidenceBundle, EvidencePolicy, EvidenceRule};
+
+pub mod keys;
+pub use keys::{
+    KeyError, KeyEvent, KeyEventAction, KeyPurpose, KeyRegistry, KeyResolver, KeyRevocation, PublicKey, RegisteredKey,
+    RegistryKeyResolver, SharedKeyRegistry, SignatureScheme,
+};
+
+pub mod witness;
+pub use witness::{ImpactThresholdPolicy, WitnessEndorsement};
+
+pub mod biodiversity;
+pub use biodiversity::{BiodiversityDetail, BiodiversityTotal, IndexKind, MetricsPolicy};
+
+pub mod localization;
+#[cfg(feature = "toml")]
+pub use localization::templates_from_toml;
+pub use localization::{LocalizedTemplate, ResolvedTemplate};
+
+pub mod search;
+pub use search::{Difficulty, LocationKind, ScoredTemplate, TemplateQuery};
+
+pub mod template_review;
+pub use template_review::{
+    TemplateId, TemplateReviewPolicy, TemplateSignoff, TemplateState, TemplateSubmission, TemplateVerdict,
+};
+
+pub mod content;
+pub use content::{CharClass, ContentCheck, ContentFinding, ContentPolicy, PiiAction, PiiKind};
+
+pub mod skills;
+pub use skills::{
+    SkillCredit, SkillId, SkillMatch, SkillSpec, SkillSuggestion, SkillTaxonomy, UnknownSkill, UnknownSkillPolicy,
+};
+
+pub mod abandonment;
+pub use abandonment::{
+    AbandonmentCounts, AbandonmentReport, AbandonmentWindow, AssigneeAbandonment, AssigneeCohort, DefaultMissionScorer,
+    MissionScorer, TemplateAbandonment, REPEAT_ABANDONMENT_STREAK,
+};
+
+pub mod timeline;
+pub use timeline::{ActorTimeline, TimelineEntry, TimelineFlag, TimelineOptions};
+
+pub mod assignment_archive;
+pub use assignment_archive::{
+    ArchiveDescriptor, ArchiveIter, ArchivedAssignment, AssignmentArchive, AssignmentFilter, AssignmentKey,
+    AssignmentState, AssignmentStatus, FileArchive, InMemoryArchive, OpenAssignmentStatus,
+};
+
+pub mod fairness;
+pub use fairness::{
+    ClaimDelay, ClaimWindow, FairnessPolicy, FairnessViolation, HighDemandCap, LotteryDraw, PassedOver,
+};
+
+pub mod idempotency;
+pub use idempotency::{IdempotencyConflict, IdempotencyKey, DEFAULT_IDEMPOTENCY_TTL_MS};
+
+pub mod journal;
+pub use journal::{
+    AssignmentRef, JournalEffect, JournalEntry, JournalEntryResult, JournalOp, JournalOutcome, JournalReport,
+    MutationJournal, ReplayPolicy,
+};
+
+pub mod ingestion;
+pub use ingestion::{IngestionError, IngestionLimits, IngestionQueue, IssueResult, QueueScope, TicketId, TicketStatus};
+pub use ingestion::csv::{
+    parse_csv, CsvImportReport, CsvMapping, CsvRowOutcome, CsvRowReport, HeadlineMetric, MetricColumn, RowError,
+    TimestampFormat,
+};
+
+pub mod units;
+
+pub mod read_view;
+pub use read_view::{LedgerView, MissionsView, ViewHead, VIEW_GENESIS_HASH};
+
+pub mod metric_schema;
+pub use metric_schema::{
+    MetricAggregation, MetricField, MetricFieldKind, MetricFieldValue, MetricSchema, MetricSchemaRegistry,
+    ModuleMetrics, ModuleTotals,
+};
+
+pub mod validation;
+pub use validation::{
+    AttestationRequest, ValidationCode, ValidationCondition, ValidationIssue, ValidationReport,
+};
+
+pub mod verification;
+pub use verification::{
+    PendingState, PendingVerification, PendingVerificationId, ReviewError, VerificationPolicy, VerifierReview,
+    VerifierVerdict,
+};
+
+pub mod conflict;
+pub use conflict::{
+    AppliedConflict, ConflictDeclaration, ConflictPolicy, ConflictReevaluation, ConflictTreatment, QuorumAtRisk,
+    RelationshipKind,
+};
+
+pub mod verifier;
+pub use verifier::{
+    CoVerification, VerifierAuditAction, VerifierAuditEntry, VerifierDowngrade, VerifierFlag, VerifierPolicy,
+    VerifierRegistry, VerifierReport, VerifierSummary, VerifierThresholds,
+};
+
+pub mod charter;
+pub use charter::{
+    charter_set_hash, AppliedProposal, AppliedProposalRef, CharterError, CHARTER_UNBIND_FLOOR,
+    DEFAULT_CHARTER_BOUND_MODULES, UNBIND_MODULE_KEY,
+};
+pub mod charter_text;
+pub use charter_text::{
+    default_charter, CharterCitation, CharterClause, CharterDocument, CharterRegistry, CharterRule, CharterRuleSet,
+    CharterVersion, OrphanedCharterRule, CHARTER_EXCERPT_CHARS, NON_WEAPONIZATION_CLAUSE,
+};
+
+pub mod limits;
+pub use limits::{ProposalLimit, ProposalLimitExceeded, ProposalLimits};
+
+pub mod retention;
+pub use retention::{
+    RedactionError, RedactionLog, RedactionMode, RedactionRecord, RedactionTarget, RetentionPolicy,
+    REDACTION_GENESIS_HASH, TOMBSTONE_DID,
+};
+
+pub mod proposal_template;
+pub use proposal_template::{
+    payload_hash, ConfigChange, ModuleOverrides, ProposalTemplate, SaepFlag, TemplateProvenance, CHANGE_KEY,
+};
+
+pub mod scope;
+pub use scope::{ScopePath, ScopeSettings, ScopeTree, MODULE_SEGMENT, ROOT_SCOPE};
+
+pub mod module_status;
+pub use module_status::{
+    GovernanceAuditAction, GovernanceAuditEntry, GovernanceAuditOutcome, ModulePaused, ModuleState,
+    ModuleStatusRegistry, PauseId, SharedModuleStatus,
+};
+
+pub mod runtime_config;
+pub use runtime_config::{
+    build_runtime, build_runtime_with, config_diff, ConfigFieldChange, ConfigIssue, ConsentConfig, GovernanceConfig,
+    LedgerConfig, MissionsConfig, ReloadReport, RuntimeConfig, RuntimeConfigError, StewardshipRuntime,
+};
+
+pub mod saep_calibration;
+pub use saep_calibration::{
+    EscalationResolution, FeedbackStore, ReviewOutcome, RuleAction, RuleCalibration, RuleFeedback, RulePerformance,
+    RuleWeightChange, SaepEscalation, SharedRuleCalibration, DEFAULT_RULE_WEIGHT,
+};
+
+pub mod dual_control;
+pub use dual_control::{
+    AdminAction, AdminApproval, AdminBinding, AdminEffect, AdminLogEntry, AdminLogEvent, AdminOperation, AdminProposal,
+    AdminTarget, DualControl, DualControlPolicy, PendingAdminAction, DEFAULT_CONFIRM_WINDOW_MS,
+};
+
+pub mod saep_cache;
+pub use saep_cache::{SaepCacheConfig, SaepCacheStats, DEFAULT_SAEP_CACHE_CAPACITY, DEFAULT_SAEP_CACHE_TTL_MS};
+
+pub mod notifications;
+pub use notifications::{
+    notification_prompt, DigestFrequency, NewNotification, Notification, NotificationAck, NotificationCategory,
+    NotificationCenter, NotificationDigest, NotificationId, NotificationPreferences, NotificationSeverity,
+    NOTIFICATION_PURPOSE,
+};
+
+#[cfg(feature = "governance-anchoring")]
+pub mod governance_anchor;
+#[cfg(feature = "governance-anchoring")]
+pub use governance_anchor::{governance_metric_schema, GovernanceAnchor, GovernanceAnchoring};
+
+/// Crate version stamped into exported JSON Schemas.
+#[cfg(feature = "schema")]
+pub const SCHEMA_CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ModuleId(pub String);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AttestationId(pub String);
 
 /// Core modules enumerated for binding enforcement.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum StewardModule {
     PLGA,
     MME,
@@ -40,11 +277,25 @@ pub enum StewardModule {
     CSC,
 }
 
+impl StewardModule {
+    pub const ALL: [StewardModule; 8] = [
+        StewardModule::PLGA,
+        StewardModule::MME,
+        StewardModule::VET,
+        StewardModule::OCG,
+        StewardModule::DCCN,
+        StewardModule::REBL,
+        StewardModule::PSM,
+        StewardModule::CSC,
+    ];
+}
+
 /// ---------------------------------------------------------------------
 /// ETHICS KERNEL: SAEP
 /// ---------------------------------------------------------------------
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct EthicsContext {
     pub actor: Did,
     pub affected_parties: Vec<Did>,
@@ -54,15 +305,75 @@ pub struct EthicsContext {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct EthicsDecision {
     pub allowed: bool,
+    /// `coded_reasons` in English.
     pub reasons: Vec<String>,
+    /// Rules that fired, in `reasons` order.
+    #[serde(default)]
+    pub coded_reasons: Vec<Reason>,
+    /// Fired rules weighted below the deny threshold; see `RuleCalibration`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub escalated: Vec<ReasonCode>,
     pub require_rollback_plan: bool,
     pub require_public_intent_log: bool,
     pub require_consent: bool,
 }
 
+/// What an `EthicsDecision` comes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SaepVerdict {
+    Allow,
+    /// Held for a reviewer: every rule that fired is weighted below the deny threshold.
+    Escalate,
+    Deny,
+}
+
+impl EthicsDecision {
+    pub fn verdict(&self) -> SaepVerdict {
+        if self.allowed {
+            SaepVerdict::Allow
+        } else if !self.escalated.is_empty() && self.escalated.len() == self.coded_reasons.len() {
+            SaepVerdict::Escalate
+        } else {
+            SaepVerdict::Deny
+        }
+    }
+
+    /// Codes of the rules that fired, comma-separated.
+    fn rule_codes(&self) -> String {
+        self.coded_reasons.iter().map(|r| r.code.as_str()).collect::<Vec<_>>().join(", ")
+    }
+
+    /// Why `action` (e.g. `"attestation"`) is refused, or held for review, under this decision.
+    pub fn blocked(&self, action: &str) -> Reason {
+        let code = match self.verdict() {
+            SaepVerdict::Escalate => ReasonCode::SaepEscalated,
+            _ => ReasonCode::SaepBlocked,
+        };
+        Reason::new(code)
+            .with("action", action)
+            .with("rules", format!("{:?}", self.reasons))
+            .with("codes", self.rule_codes())
+    }
+}
+
+/// What an action brings toward `require_public_intent_log` and `require_rollback_plan`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SaepRefs {
+    /// Already-published intent entry; `None` auto-publishes one when required.
+    #[serde(default)]
+    pub intent_entry_id: Option<IntentEntryId>,
+    /// Registered rollback plan; `None` is refused when one is required.
+    #[serde(default)]
+    pub rollback_plan_id: Option<PlanId>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SaepConfig {
     pub enforce_non_harm: bool,
     pub enforce_transparency: bool,
@@ -71,6 +382,9 @@ pub struct SaepConfig {
     pub enforce_commons_benefit: bool,
     // Karma safety.
     pub forbid_punitive_scoring: bool,
+    /// Flags set differently for single modules; see `for_module`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub module_overrides: ModuleOverrides,
 }
 
 impl Default for SaepConfig {
@@ -82,6 +396,7 @@ impl Default for SaepConfig {
             enforce_informed_consent: true,
             enforce_commons_benefit: true,
             forbid_punitive_scoring: true,
+            module_overrides: BTreeMap::new(),
         }
     }
 }
@@ -89,58 +404,153 @@ impl Default for SaepConfig {
 /// Ethics engine: in practice you plug your risk models in here.[web:17]
 pub struct SaepEngine {
     config: SaepConfig,
+    /// Rule weights deciding deny or escalate; every rule denies without one.
+    calibration: Option<SharedRuleCalibration>,
+    /// Decisions already made; see `saep_cache`.
+    cache: Option<Mutex<saep_cache::SaepCache>>,
 }
 
 impl SaepEngine {
     pub fn new(config: SaepConfig) -> Self {
-        Self { config }
+        Self { config, calibration: None, cache: None }
+    }
+
+    /// Keep up to `config.capacity` decisions for `config.ttl_ms` on `clock`.
+    pub fn with_cache(mut self, config: SaepCacheConfig, clock: Arc<dyn Clock>) -> Self {
+        self.cache = Some(Mutex::new(saep_cache::SaepCache::new(config, clock)));
+        self
+    }
+
+    /// Weigh fired rules by `calibration`, shared with the engines it is handed to.
+    pub fn with_calibration(mut self, calibration: SharedRuleCalibration) -> Self {
+        self.calibration = Some(calibration);
+        self
+    }
+
+    pub fn config(&self) -> &SaepConfig {
+        &self.config
+    }
+
+    pub fn calibration(&self) -> Option<&SharedRuleCalibration> {
+        self.calibration.as_ref()
+    }
+
+    /// SHA-256 over the config and the shared rule weights: what a decision depends on
+    /// besides its context.
+    pub fn config_fingerprint(&self) -> String {
+        let weights = self.calibration.as_ref().map(|calibration| {
+            let calibration = calibration.lock().unwrap_or_else(|e| e.into_inner());
+            (calibration.deny_threshold(), calibration.weights().clone())
+        });
+        let payload = serde_json::to_vec(&(&self.config, weights)).expect("SAEP config serialization");
+        format!("{:x}", Sha256::digest(&payload))
+    }
+
+    /// `None` without `with_cache`.
+    pub fn cache_stats(&self) -> Option<SaepCacheStats> {
+        self.cache.as_ref().map(|cache| cache.lock().unwrap_or_else(|e| e.into_inner()).stats())
+    }
+
+    /// Drop every cached decision; the counters are kept.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        }
     }
 
     /// Evaluate a proposed action in any module (missions, simulations, guild ops, etc.).
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "saep.evaluate", level = "debug", skip_all,
+        fields(actor_did = %ctx.actor, module = ?ctx.module),
+    ))]
     pub fn evaluate(&self, ctx: &EthicsContext) -> EthicsDecision {
+        let Some(cache) = &self.cache else {
+            return self.decide(ctx);
+        };
+        let lock = || cache.lock().unwrap_or_else(|e| e.into_inner());
+        if !ctx.affected_parties.is_empty() {
+            lock().bypass();
+            return self.decide(ctx);
+        }
+        let key = saep_cache::cache_key(ctx, &self.config_fingerprint());
+        if let Some(decision) = lock().get(&key) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(decision = ?decision.verdict(), "SAEP decision from cache");
+            return decision;
+        }
+        let decision = self.decide(ctx);
+        lock().insert(key, decision.clone());
+        decision
+    }
+
+    fn decide(&self, ctx: &EthicsContext) -> EthicsDecision {
+        let config = self.config.for_module(ctx.module);
         let mut allowed = true;
-        let mut reasons = Vec::new();
+        let mut coded_reasons = Vec::new();
         let mut require_rollback_plan = false;
         let mut require_public_intent_log = false;
         let mut require_consent = false;
 
-        if self.config.enforce_non_harm {
+        if config.enforce_non_harm {
             // Placeholder: wire real risk analysis models here (e.g., env harm, psych load).
             let maybe_risky = ctx.description.to_lowercase().contains("weapon")
                 || ctx.description.to_lowercase().contains("coercive");
             if maybe_risky {
                 allowed = false;
-                reasons.push("non_harm: detected potential harmful or coercive intent".into());
+                coded_reasons.push(Reason::new(ReasonCode::SaepNonHarm));
+                #[cfg(feature = "tracing")]
+                tracing::debug!(rule = "non_harm", "SAEP rule fired");
             }
         }
 
-        if self.config.enforce_transparency {
+        if config.enforce_transparency {
             require_public_intent_log = true;
         }
 
-        if self.config.enforce_reversibility {
+        if config.enforce_reversibility {
             require_rollback_plan = true;
         }
 
-        if self.config.enforce_informed_consent {
+        if config.enforce_informed_consent {
             require_consent = true;
         }
 
-        if self.config.enforce_commons_benefit {
+        if config.enforce_commons_benefit {
             // Block explicit private-hoarding keywords.
             if ctx.description.to_lowercase().contains("exclusive monetization") {
                 allowed = false;
-                reasons.push("commons_benefit: private hoarding flagged".into());
+                coded_reasons.push(Reason::new(ReasonCode::SaepCommonsBenefit));
+                #[cfg(feature = "tracing")]
+                tracing::debug!(rule = "commons_benefit", "SAEP rule fired");
             }
         }
 
-        EthicsDecision {
+        let escalated = match &self.calibration {
+            Some(calibration) => {
+                let calibration = calibration.lock().unwrap_or_else(|e| e.into_inner());
+                coded_reasons
+                    .iter()
+                    .map(|r| r.code)
+                    .filter(|code| calibration.rule_action(*code) == RuleAction::Escalate)
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        let decision = EthicsDecision {
             allowed,
-            reasons,
+            reasons: coded_reasons.iter().map(Reason::to_string).collect(),
+            coded_reasons,
+            escalated,
             require_rollback_plan,
             require_public_intent_log,
             require_consent,
-        }
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(decision = ?decision.verdict(), require_consent, "SAEP decision");
+
+        decision
     }
 }
 
@@ -149,6 +559,7 @@ impl SaepEngine {
 /// ---------------------------------------------------------------------
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConsentRecord {
     pub participant: Did,
     pub module: StewardModule,
@@ -156,28 +567,196 @@ pub struct ConsentRecord {
     pub consent_given: bool,
     pub timestamp_ms: u64,
     pub evidence_uri: Option<String>,
+    /// Hash of the content at `evidence_uri` when consent was given, e.g. `sha256:<hex>`;
+    /// see `ConsentEvidencePolicy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence_hash: Option<String>,
+    /// `ConsentPromptDescriptor::canonical_hash` of the prompt the participant saw.
+    #[serde(default)]
+    pub prompt_hash: Option<String>,
 }
 
 pub struct ConsentRegistry {
     records: HashMap<(Did, StewardModule, Option<MissionId>), ConsentRecord>,
+    strict_prompts: bool,
+    /// Withdrawals not yet taken by an engine's `sweep_retention`, oldest first.
+    withdrawals: Vec<ConsentRecord>,
+    evidence_policy: ConsentEvidencePolicy,
+    evidence_verifier: Arc<dyn EvidenceVerifier>,
+    strict_evidence: bool,
+    /// Told about every change after it is recorded, in the order they were added.
+    observers: Vec<(String, Arc<dyn ConsentObserver>)>,
+    observer_failures: Vec<ObserverFailure>,
 }
 
 impl ConsentRegistry {
     pub fn new() -> Self {
-        Self { records: HashMap::new() }
+        Self {
+            records: HashMap::new(),
+            strict_prompts: false,
+            withdrawals: Vec::new(),
+            evidence_policy: ConsentEvidencePolicy::default(),
+            evidence_verifier: Arc::new(NoopEvidenceVerifier),
+            strict_evidence: false,
+            observers: Vec::new(),
+            observer_failures: Vec::new(),
+        }
+    }
+
+    /// Tell `observer`, as `name`, about every later grant, withdrawal and expiry.
+    pub fn with_observer(mut self, name: &str, observer: Arc<dyn ConsentObserver>) -> Self {
+        self.add_observer(name, observer);
+        self
+    }
+
+    /// `with_observer` on a registry already in use, e.g. through an engine's `consent_mut`.
+    pub fn add_observer(&mut self, name: &str, observer: Arc<dyn ConsentObserver>) {
+        self.observers.push((name.to_string(), observer));
+    }
+
+    /// Observers that errored or panicked, oldest first; the changes they failed on stand.
+    pub fn observer_failures(&self) -> &[ObserverFailure] {
+        &self.observer_failures
+    }
+
+    pub fn take_observer_failures(&mut self) -> Vec<ObserverFailure> {
+        std::mem::take(&mut self.observer_failures)
+    }
+
+    pub(crate) fn record_observer_failure(&mut self, failure: ObserverFailure) {
+        self.observer_failures.push(failure);
+    }
+
+    /// What `submit_consent` requires of a grant's evidence.
+    pub fn with_evidence_policy(mut self, policy: ConsentEvidencePolicy) -> Self {
+        self.evidence_policy = policy;
+        self
+    }
+
+    pub fn evidence_policy(&self) -> &ConsentEvidencePolicy {
+        &self.evidence_policy
+    }
+
+    /// Compare recorded evidence hashes with what `verifier` fetches, instead of trusting them.
+    pub fn with_evidence_verifier(mut self, verifier: Arc<dyn EvidenceVerifier>) -> Self {
+        self.evidence_verifier = verifier;
+        self
+    }
+
+    /// A grant the evidence policy wants hashed only counts while it carries a hash.
+    pub fn with_strict_evidence(mut self) -> Self {
+        self.strict_evidence = true;
+        self
+    }
+
+    pub fn strict_evidence(&self) -> bool {
+        self.strict_evidence
+    }
+
+    /// Consent only counts while its `prompt_hash` matches the current prompt for the action.
+    pub fn with_strict_prompts(mut self) -> Self {
+        self.strict_prompts = true;
+        self
+    }
+
+    pub fn strict_prompts(&self) -> bool {
+        self.strict_prompts
     }
 
+    /// A record with `consent_given: false` is also queued for `take_withdrawals`.
+    /// Observers hear of the grant or withdrawal once it is recorded.
     pub fn upsert_consent(&mut self, record: ConsentRecord) {
+        let change = if record.consent_given { ConsentChange::Granted } else { ConsentChange::Withdrawn };
+        if !record.consent_given {
+            self.withdrawals.push(record.clone());
+        }
         let key = (record.participant.clone(), record.module, record.mission.clone());
-        self.records.insert(key, record);
+        self.records.insert(key, record.clone());
+        self.notify_observers(change, &record);
+    }
+
+    /// Lapse every grant given before `granted_before_ms`: it is recorded as not given at
+    /// `now_ms`, and observers hear of it as an expiry. Expiries are not withdrawals, so
+    /// they are not queued for `take_withdrawals`. Returns the lapsed records in key order.
+    pub fn expire_grants(&mut self, granted_before_ms: u64, now_ms: u64) -> Vec<ConsentRecord> {
+        let mut lapsed: Vec<_> = self
+            .records
+            .iter_mut()
+            .filter(|(_, r)| r.consent_given && r.timestamp_ms < granted_before_ms)
+            .map(|(key, r)| {
+                r.consent_given = false;
+                r.timestamp_ms = now_ms;
+                (key.clone(), r.clone())
+            })
+            .collect();
+        lapsed.sort_by(|a, b| a.0.cmp(&b.0));
+        let lapsed: Vec<ConsentRecord> = lapsed.into_iter().map(|(_, r)| r).collect();
+        for record in &lapsed {
+            self.notify_observers(ConsentChange::Expired, record);
+        }
+        lapsed
+    }
+
+    fn notify_observers(&mut self, change: ConsentChange, record: &ConsentRecord) {
+        for (name, observer) in &self.observers {
+            if let Err(message) = consent_observer::notify(observer.as_ref(), change, record) {
+                self.observer_failures.push(ObserverFailure {
+                    observer: name.clone(),
+                    change,
+                    participant: record.participant.clone(),
+                    module: record.module,
+                    message,
+                });
+            }
+        }
+    }
+
+    /// `upsert_consent` once `record` passes the evidence policy and verifier; a withdrawal
+    /// always does.
+    pub fn submit_consent(&mut self, record: ConsentRecord) -> Result<(), Reason> {
+        self.evidence_policy.check(&record, self.evidence_verifier.as_ref())?;
+        self.upsert_consent(record);
+        Ok(())
+    }
+
+    /// Remove and return queued withdrawals for `module`, oldest first.
+    pub fn take_withdrawals(&mut self, module: StewardModule) -> Vec<ConsentRecord> {
+        let (taken, rest) = std::mem::take(&mut self.withdrawals).into_iter().partition(|r| r.module == module);
+        self.withdrawals = rest;
+        taken
     }
 
+    /// Under strict evidence, a grant missing a hash the policy requires is not valid.
     pub fn has_valid_consent(&self, did: &Did, module: StewardModule, mission: Option<&MissionId>) -> bool {
         let key = (did.clone(), module, mission.cloned());
-        self.records
-            .get(&key)
-            .map(|r| r.consent_given)
-            .unwrap_or(false)
+        self.records.get(&key).is_some_and(|r| self.counts(r))
+    }
+
+    fn counts(&self, record: &ConsentRecord) -> bool {
+        record.consent_given && !(self.strict_evidence && self.evidence_policy.missing_hash(record))
+    }
+
+    /// `has_valid_consent`, and under strict prompts the record must carry `prompt`'s hash:
+    /// a prompt that changed since consent was given (e.g. an edited mission) invalidates it.
+    pub fn has_valid_consent_for_prompt(
+        &self,
+        did: &Did,
+        module: StewardModule,
+        mission: Option<&MissionId>,
+        prompt: &ConsentPromptDescriptor,
+    ) -> bool {
+        let key = (did.clone(), module, mission.cloned());
+        self.records.get(&key).is_some_and(|r| {
+            self.counts(r)
+                && (!self.strict_prompts || r.prompt_hash.as_deref() == Some(prompt.canonical_hash().as_str()))
+        })
+    }
+
+    /// Current record per (participant, module, mission), in that key order.
+    pub fn records(&self) -> impl Iterator<Item = &ConsentRecord> {
+        let mut records: Vec<_> = self.records.iter().collect();
+        records.sort_by(|a, b| a.0.cmp(b.0));
+        records.into_iter().map(|(_, r)| r)
     }
 }
 
@@ -185,201 +764,2212 @@ impl ConsentRegistry {
 /// PLANETARY LEDGER OF GOOD ACTIONS (PLGA) – NON-COMPETITIVE ATTESTATIONS
 /// ---------------------------------------------------------------------
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ImpactMetrics {
     pub co2eq_reduced: f64,
     pub biodiversity_index_delta: f64,
+    /// Index and survey behind `biodiversity_index_delta`; omitted when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub biodiversity_detail: Option<BiodiversityDetail>,
     pub restored_area_m2: f64,
     pub avoided_emissions_co2eq: f64,
+    /// Dimensions registered with the ledger (`register_custom_metric`), in key order.
+    /// Omitted when empty, so attestations without any serialize as before.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: CustomMetrics,
+}
+
+impl ImpactMetrics {
+    /// Add `other` into `self`, field by field and custom key by key.
+    /// Biodiversity detail is not summed; see `ImpactSummary::biodiversity_by_index`.
+    pub fn accumulate(&mut self, other: &ImpactMetrics) {
+        self.co2eq_reduced += other.co2eq_reduced;
+        self.biodiversity_index_delta += other.biodiversity_index_delta;
+        self.restored_area_m2 += other.restored_area_m2;
+        self.avoided_emissions_co2eq += other.avoided_emissions_co2eq;
+        steward_ids::metrics::accumulate(&mut self.custom, &other.custom);
+    }
+
+    /// Nothing claimed: every headline field zero, no biodiversity detail, no custom dimension.
+    pub fn is_empty(&self) -> bool {
+        [self.co2eq_reduced, self.biodiversity_index_delta, self.restored_area_m2, self.avoided_emissions_co2eq]
+            .iter()
+            .all(|v| *v == 0.0)
+            && self.biodiversity_detail.is_none()
+            && self.custom.is_empty()
+    }
+
+    /// The headline fields as values of `MetricSchema::plga`.
+    pub fn to_module_metrics(&self) -> ModuleMetrics {
+        [
+            ("co2eq_reduced", self.co2eq_reduced),
+            ("biodiversity_index_delta", self.biodiversity_index_delta),
+            ("restored_area_m2", self.restored_area_m2),
+            ("avoided_emissions_co2eq", self.avoided_emissions_co2eq),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), MetricFieldValue::Number(value)))
+        .collect()
+    }
+}
+
+/// Totals over a set of attestations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ImpactSummary {
+    pub attestations: usize,
+    /// How many of `attestations` were imported from external records.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub imported: usize,
+    /// PLGA attestations only. `totals.biodiversity_index_delta` mixes indices; these keep them apart.
+    pub totals: ImpactMetrics,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub biodiversity_by_index: Vec<BiodiversityTotal>,
+    /// Other modules' attestations under their schemas, in module order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<ModuleTotals>,
+}
+
+impl ImpactSummary {
+    /// Totals for a module other than PLGA, if it has attestations here.
+    pub fn module(&self, module: StewardModule) -> Option<&ModuleTotals> {
+        self.modules.iter().find(|t| t.module == module)
+    }
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+pub(crate) fn plga_module() -> StewardModule {
+    StewardModule::PLGA
+}
+
+pub(crate) fn is_plga(module: &StewardModule) -> bool {
+    *module == StewardModule::PLGA
+}
+
+/// Where an attestation's record came from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AttestationOrigin {
+    /// Issued on this ledger.
+    #[default]
+    Native,
+    /// Translated from another system's records, e.g. by `ingest_csv`; `source_ref` names the
+    /// feed or file.
+    ExternalImport { source_ref: String },
+}
+
+impl AttestationOrigin {
+    pub fn is_native(&self) -> bool {
+        *self == AttestationOrigin::Native
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StewardshipAttestation {
     pub id: AttestationId,
     pub actor_did: Did,
     pub mission_id: Option<MissionId>,
     pub timestamp_ms: u64,
     pub description: String,
+    /// Module the record belongs to; records from before modules load as PLGA's.
+    #[serde(default = "plga_module", skip_serializing_if = "is_plga")]
+    pub module: StewardModule,
+    /// PLGA's metrics; default for other modules.
     pub impact_metrics: ImpactMetrics,
-    pub evidence_uri: String,
+    /// Other modules' metrics, under their `MetricSchema`; empty for PLGA.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub module_metrics: ModuleMetrics,
+    /// Artifacts behind the claim; records with a single `evidence_uri` load as one artifact.
+    #[serde(alias = "evidence_uri", deserialize_with = "evidence::bundle_or_uri")]
+    pub evidence: Vec<EvidenceArtifact>,
     pub verifier_dids: Vec<Did>,
     /// Non-transferable, non-speculative “badge” view.
     pub visible_symbol: String, // "STWD"
+    /// Public intent entry the attestation was issued under, when SAEP required one.
+    #[serde(default)]
+    pub intent_entry_id: Option<IntentEntryId>,
+    /// Registered plan for revoking the attestation, when SAEP required one.
+    #[serde(default)]
+    pub rollback_plan_id: Option<PlanId>,
+    /// Listed verifiers that were downgraded for this actor at issuance, or had a `NotCounted`
+    /// conflict with them; their signature does not count.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub non_counting_verifiers: Vec<Did>,
+    /// Listed verifiers with a declared conflict with the actor at issuance, and what was done.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub verifier_conflicts: Vec<AppliedConflict>,
+    /// Co-signature the claim carried, checked at issuance; see `ImpactThresholdPolicy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub witness_endorsement: Option<WitnessEndorsement>,
+    #[serde(default, skip_serializing_if = "AttestationOrigin::is_native")]
+    pub origin: AttestationOrigin,
+}
+
+impl StewardshipAttestation {
+    /// The typed metrics of a PLGA attestation; `None` for other modules.
+    pub fn as_plga_metrics(&self) -> Option<&ImpactMetrics> {
+        (self.module == StewardModule::PLGA).then_some(&self.impact_metrics)
+    }
+
+    /// Metrics by field name under the module's schema, PLGA's included.
+    pub fn metrics(&self) -> ModuleMetrics {
+        match self.as_plga_metrics() {
+            Some(metrics) => metrics.to_module_metrics(),
+            None => self.module_metrics.clone(),
+        }
+    }
+
+    /// `verifier_dids` minus `non_counting_verifiers`.
+    pub fn counting_verifiers(&self) -> impl Iterator<Item = &Did> {
+        self.verifier_dids.iter().filter(|v| !self.non_counting_verifiers.contains(v))
+    }
 }
 
+/// `steward_ids::stream` kind of `PlanetaryLedger::export_attestations`.
+pub const ATTESTATION_STREAM_KIND: &str = "planetary.attestations";
+
 pub struct PlanetaryLedger {
     saep: SaepEngine,
     consent: ConsentRegistry,
     attestations: HashMap<AttestationId, StewardshipAttestation>,
+    clock: Arc<dyn Clock>,
+    ids: Arc<dyn IdGenerator>,
+    intent_log: SharedIntentLog,
+    rollback: SharedRollbackRegistry,
+    module_status: SharedModuleStatus,
+    /// Custom dimensions attestations may carry.
+    custom_metrics: CustomMetricRegistry,
+    /// What each module's attestations measure; PLGA's is built in.
+    metric_schemas: MetricSchemaRegistry,
+    metrics_policy: MetricsPolicy,
+    evidence_policy: EvidencePolicy,
+    /// Applied to attestation descriptions; only the sanitized text is stored.
+    content_policy: ContentPolicy,
+    impact_thresholds: ImpactThresholdPolicy,
+    /// Checks witness signatures and verifier approvals; without one, neither verifies.
+    key_resolver: Option<Arc<dyn KeyResolver>>,
+    verifier_stats: verifier::VerifierStats,
+    verifiers: VerifierRegistry,
+    /// Ids handed out by `reserve_attestation_id` and not yet issued.
+    reserved: HashSet<AttestationId>,
+    /// Applied by `sweep_retention` to PLGA consent withdrawals.
+    retention: RetentionPolicy,
+    /// Sweep as soon as a PLGA withdrawal is recorded, instead of waiting for `sweep_retention`.
+    retention_on_withdrawal: bool,
+    redactions: RedactionLog,
+    /// Changes to `consent`, heard as its `plga` observer.
+    consent_events: ConsentEventQueue,
+    /// Attestations created under each idempotency key.
+    idempotency: IdempotencyStore<AttestationId>,
+    /// Journal entries applied by `apply_journal`, by key.
+    journal_keys: IdempotencyStore<JournalEffect>,
+    verification_policy: VerificationPolicy,
+    /// Claims submitted for delegated verification, in every state.
+    pending_verifications: BTreeMap<PendingVerificationId, PendingVerification>,
+    /// Copy of `attestations` that `read_view` shares; kept current by `publish_attestation`.
+    attestation_views: read_view::ViewLog<AttestationId, StewardshipAttestation>,
+    view_head: ViewHead,
+    /// Where verifier and retention approvals come from; see `DualControl::bind`.
+    admin: AdminBinding,
 }
 
 impl PlanetaryLedger {
     pub fn new(saep: SaepEngine, consent: ConsentRegistry) -> Self {
+        Self::with_runtime(saep, consent, system_clock(), default_id_generator())
+    }
+
+    /// Ledger with an injected clock and attestation-id source.
+    pub fn with_runtime(
+        saep: SaepEngine,
+        mut consent: ConsentRegistry,
+        clock: Arc<dyn Clock>,
+        ids: Arc<dyn IdGenerator>,
+    ) -> Self {
+        let consent_events = ConsentEventQueue::new();
+        consent.add_observer("plga", Arc::new(consent_events.clone()));
         Self {
             saep,
             consent,
             attestations: HashMap::new(),
+            clock,
+            ids,
+            intent_log: IntentLog::shared(),
+            rollback: RollbackPlanRegistry::shared(),
+            module_status: ModuleStatusRegistry::shared(),
+            custom_metrics: CustomMetricRegistry::new(),
+            metric_schemas: MetricSchemaRegistry::default(),
+            metrics_policy: MetricsPolicy::default(),
+            evidence_policy: EvidencePolicy::default(),
+            content_policy: ContentPolicy::default(),
+            impact_thresholds: ImpactThresholdPolicy::default(),
+            key_resolver: None,
+            verifier_stats: verifier::VerifierStats::default(),
+            verifiers: VerifierRegistry::default(),
+            reserved: HashSet::new(),
+            retention: RetentionPolicy::default(),
+            retention_on_withdrawal: false,
+            redactions: RedactionLog::default(),
+            consent_events,
+            idempotency: IdempotencyStore::default(),
+            journal_keys: IdempotencyStore::default(),
+            verification_policy: VerificationPolicy::default(),
+            pending_verifications: BTreeMap::new(),
+            attestation_views: read_view::ViewLog::default(),
+            view_head: ViewHead::default(),
+            admin: AdminBinding::default(),
         }
     }
 
-    /// Karma-safe: no scores, no ranks, just per-actor, per-mission attestations.[web:16]
-    pub fn issue_attestation(
-        &mut self,
-        actor_did: Did,
-        mission_id: Option<MissionId>,
-        description: String,
-        impact_metrics: ImpactMetrics,
-        evidence_uri: String,
-        verifier_dids: Vec<Did>,
-        timestamp_ms: u64,
-    ) -> Result<StewardshipAttestation, String> {
-        let ctx = EthicsContext {
-            actor: actor_did.clone(),
-            affected_parties: vec![],
-            module: StewardModule::PLGA,
-            description: description.clone(),
-            estimated_impact: serde_json::json!({
-                "co2eq_reduced": impact_metrics.co2eq_reduced,
-                "biodiversity_index_delta": impact_metrics.biodiversity_index_delta,
-            }),
-        };
+    /// Publish to `log` (e.g. one shared with `MicroMissionsEngine`) instead of a private log.
+    pub fn with_intent_log(mut self, log: SharedIntentLog) -> Self {
+        self.intent_log = log;
+        self
+    }
 
-        let decision = self.saep.evaluate(&ctx);
-        if !decision.allowed {
-            return Err(format!("SAEP blocked attestation: {:?}", decision.reasons));
-        }
+    pub fn intent_log(&self) -> SharedIntentLog {
+        self.intent_log.clone()
+    }
+
+    /// Look plans up in `registry` (e.g. one shared with `MicroMissionsEngine`) instead of a private one.
+    pub fn with_rollback_registry(mut self, registry: SharedRollbackRegistry) -> Self {
+        self.rollback = registry;
+        self
+    }
+
+    pub fn rollback_registry(&self) -> SharedRollbackRegistry {
+        self.rollback.clone()
+    }
+
+    /// Obey `status` (e.g. one shared with `GovernanceEngine`) instead of a private, always-active one.
+    pub fn with_module_status(mut self, status: SharedModuleStatus) -> Self {
+        self.module_status = status;
+        self
+    }
+
+    pub fn module_status(&self) -> SharedModuleStatus {
+        self.module_status.clone()
+    }
 
-        // KSCP: require explicit consent for logging under PLGA.
-        if decision.require_consent &&
-            !self.consent.has_valid_consent(&actor_did, StewardModule::PLGA, mission_id.as_ref())
-        {
-            return Err("No valid KSCP consent for PLGA attestation".into());
+    /// Allow attestations to carry `key`, measured in `spec.unit` and within its range.
+    /// A key registers once: changing its unit or range would split its history.
+    pub fn register_custom_metric(&mut self, key: MetricKey, spec: CustomMetricSpec) -> Result<(), String> {
+        spec.validate()?;
+        if self.custom_metrics.contains_key(&key) {
+            return Err(format!("Custom metric {key} is already registered"));
         }
+        self.custom_metrics.insert(key, spec);
+        Ok(())
+    }
 
-        let att_id = AttestationId(uuid::Uuid::new_v4().to_string());
-        let att = StewardshipAttestation {
-            id: att_id.clone(),
-            actor_did,
-            mission_id,
-            timestamp_ms,
-            description,
-            impact_metrics,
-            evidence_uri,
-            verifier_dids,
-            visible_symbol: "STWD".into(),
-        };
+    pub fn custom_metrics(&self) -> &CustomMetricRegistry {
+        &self.custom_metrics
+    }
 
-        self.attestations.insert(att_id.clone(), att.clone());
-        Ok(att)
+    /// Let `schema.module` issue attestations measured by `schema`; see `MetricSchemaRegistry::register`.
+    pub fn register_metric_schema(&mut self, schema: MetricSchema) -> Result<(), String> {
+        self.metric_schemas.register(schema)
     }
 
-    pub fn get_attestations_for_actor(&self, actor: &Did) -> Vec<&StewardshipAttestation> {
-        self.attestations
-            .values()
-            .filter(|a| &a.actor_did == actor)
-            .collect()
+    pub fn metric_schemas(&self) -> &MetricSchemaRegistry {
+        &self.metric_schemas
     }
 
-    /// No transfer operation by design.
-    pub fn forbid_transfer(&self, _attestation_id: &AttestationId, _to: &Did) -> Result<(), String> {
-        Err("Stewardship attestations are non-transferable and non-speculative by design.")
+    /// Detail the built-in metrics must carry; see `MetricsPolicy`.
+    pub fn with_metrics_policy(mut self, policy: MetricsPolicy) -> Self {
+        self.metrics_policy = policy;
+        self
     }
-}
 
-/// ---------------------------------------------------------------------
-/// MICRO-MISSIONS ENGINE (MME) – WITH ETHICS + CONSENT CHECKS
-/// ---------------------------------------------------------------------
+    pub fn metrics_policy(&self) -> &MetricsPolicy {
+        &self.metrics_policy
+    }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MissionTemplate {
-    pub id: MissionId,
-    pub title: String,
-    pub description: String, // markdown
-    pub difficulty: String,  // XS,S,M,L,XL
-    pub expected_impact: serde_json::Value,
-    pub location_hint: String, // "geo" or "virtual"
-    pub required_skills: Vec<String>,
-}
+    /// Evidence bigger claims must carry; see `EvidencePolicy`.
+    pub fn with_evidence_policy(mut self, policy: EvidencePolicy) -> Self {
+        self.evidence_policy = policy;
+        self
+    }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AssignedMission {
-    pub mission: MissionTemplate,
-    pub assignee: Did,
-    pub assigned_ts_ms: u64,
-}
+    pub fn evidence_policy(&self) -> &EvidencePolicy {
+        &self.evidence_policy
+    }
 
-pub struct MicroMissionsEngine {
-    saep: SaepEngine,
-    consent: ConsentRegistry,
-    templates: HashMap<MissionId, MissionTemplate>,
-    active_assignments: Vec<AssignedMission>,
-}
+    /// Claims needing a witness endorsement, and the witnesses who may give one.
+    pub fn with_impact_threshold_policy(mut self, policy: ImpactThresholdPolicy) -> Self {
+        self.impact_thresholds = policy;
+        self
+    }
 
-impl MicroMissionsEngine {
-    pub fn new(saep: SaepEngine, consent: ConsentRegistry) -> Self {
-        Self {
-            saep,
-            consent,
-            templates: HashMap::new(),
-            active_assignments: Vec::new(),
-        }
+    pub fn impact_threshold_policy(&self) -> &ImpactThresholdPolicy {
+        &self.impact_thresholds
     }
 
-    pub fn add_template(&mut self, tpl: MissionTemplate) {
-        self.templates.insert(tpl.id.clone(), tpl);
+    /// Verify witness signatures and verifier approvals with `resolver`.
+    pub fn with_key_resolver(mut self, resolver: Arc<dyn KeyResolver>) -> Self {
+        self.key_resolver = Some(resolver);
+        self
     }
 
-    /// “Agentic-RAG” placeholder: real system uses profiles + local context. [web:6][web:11]
-    pub fn assign_mission(
-        &mut self,
-        mission_id: &MissionId,
-        assignee: Did,
-        now_ms: u64,
-    ) -> Result<AssignedMission, String> {
-        let tpl = self.templates.get(mission_id)
-            .ok_or_else(|| "Unknown mission template".to_string())?
-            .clone();
+    /// Thresholds and auto-downgrade setting for verifier reports.
+    pub fn with_verifier_policy(mut self, policy: VerifierPolicy) -> Self {
+        self.verifiers.policy = policy;
+        self
+    }
 
-        let ctx = EthicsContext {
-            actor: assignee.clone(),
-            affected_parties: vec![],
-            module: StewardModule::MME,
-            description: tpl.description.clone(),
-            estimated_impact: tpl.expected_impact.clone(),
-        };
+    /// What `sweep_retention` does to records of participants who withdraw PLGA consent.
+    pub fn with_retention_policy(mut self, policy: RetentionPolicy) -> Self {
+        self.retention = policy;
+        self
+    }
 
-        let decision = self.saep.evaluate(&ctx);
-        if !decision.allowed {
-            return Err(format!("SAEP blocked mission assignment: {:?}", decision.reasons));
-        }
+    pub fn retention_policy(&self) -> &RetentionPolicy {
+        &self.retention
+    }
 
-        if decision.require_consent &&
-            !self.consent.has_valid_consent(&assignee, StewardModule::MME, Some(mission_id))
-        {
-            return Err("No valid KSCP consent for mission assignment".into());
-        }
+    /// Sweep retention when a PLGA withdrawal is made through `consent_mut` or replayed from a
+    /// journal, rather than on the next `sweep_retention`. A sweep that fails is recorded as a
+    /// failure of the registry's `plga` observer; the withdrawal stands either way.
+    pub fn with_retention_on_withdrawal(mut self) -> Self {
+        self.retention_on_withdrawal = true;
+        self
+    }
 
-        let assigned = AssignedMission {
-            mission: tpl,
-            assignee,
-            assigned_ts_ms: now_ms,
-        };
-        self.active_assignments.push(assigned.clone());
-        Ok(assigned)
+    pub fn redaction_log(&self) -> &RedactionLog {
+        &self.redactions
     }
-}
 
-/// ---------------------------------------------------------------------
-/// GOVERNANCE HOOKS – POLYCENTRIC + QUADRATIC CONSENSUS
-/// ---------------------------------------------------------------------
+    /// How long an idempotency or journal key is held after its first success, by the ledger's clock.
+    pub fn with_idempotency_ttl(mut self, ttl_ms: u64) -> Self {
+        self.idempotency.ttl_ms = ttl_ms;
+        self.journal_keys.ttl_ms = ttl_ms;
+        self
+    }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum GovernanceScope {
-    Module(ModuleId),
-    EcosystemWide,
-}
+    /// Idempotency keys currently held, expired ones not yet dropped included.
+    pub fn idempotency_keys_held(&self) -> usize {
+        self.idempotency.len()
+    }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+    /// Redact `did`'s attestations under `policy`, as `withdrawal` (a PLGA withdrawal by `did`)
+    /// requires; one with a mission covers only that mission's attestations.
+    /// Returns the redactions made, oldest attestation first. Reached through
+    /// `AdminAction::ExecuteRetention` or `apply_consent_events`.
+    pub(crate) fn apply_retention(
+        &mut self,
+        did: &Did,
+        policy: &RetentionPolicy,
+        withdrawal: &ConsentRecord,
+    ) -> Result<Vec<RedactionRecord>, String> {
+        retention::check_withdrawal(did, StewardModule::PLGA, withdrawal)?;
+        let mut redacted = Vec::new();
+        if *policy == RetentionPolicy::Keep {
+            return Ok(redacted);
+        }
+        let targets: Vec<AttestationId> = self
+            .get_attestations_for_actor(did)
+            .into_iter()
+            .filter(|a| retention::covers(withdrawal, a.mission_id.as_ref()))
+            .map(|a| a.id.clone())
+            .collect();
+        for id in targets {
+            let att = self.attestations.get_mut(&id).expect("listed above");
+            redacted.extend(retention::redact(
+                &mut self.redactions,
+                RedactionTarget::Attestation(id),
+                StewardModule::PLGA,
+                withdrawal,
+                policy,
+                att,
+                retention::redact_attestation,
+            ));
+        }
+        if !redacted.is_empty() {
+            // Verifier statistics key on actor DIDs; rebuild them from the redacted records.
+            self.verifier_stats = verifier::VerifierStats::default();
+            for att in self.attestations.values() {
+                self.verifier_stats.record(att);
+            }
+            for record in &redacted {
+                if let RedactionTarget::Attestation(id) = &record.target {
+                    self.view_head.advance("attestation", id, self.attestations.get(id));
+                }
+            }
+            // Views taken from here on must not share the unredacted copies.
+            self.attestation_views.reset(&self.attestations);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(module = "PLGA", redacted = redacted.len(), "retention applied");
+        Ok(redacted)
+    }
+
+    /// `apply_retention` under the ledger's policy for every PLGA withdrawal queued in its consent registry.
+    /// Run through `AdminAction::ExecuteRetention`, or by `apply_consent_events`.
+    fn sweep_retention(&mut self) -> Result<Vec<RedactionRecord>, String> {
+        let policy = self.retention.clone();
+        let mut redacted = Vec::new();
+        for withdrawal in self.consent.take_withdrawals(StewardModule::PLGA) {
+            redacted.extend(self.apply_retention(&withdrawal.participant.clone(), &policy, &withdrawal)?);
+        }
+        Ok(redacted)
+    }
+
+    /// Under `with_retention_on_withdrawal`, sweep retention if a PLGA withdrawal was recorded
+    /// since the last call; otherwise just drop the queued changes.
+    pub fn apply_consent_events(&mut self) {
+        let withdrawn = self
+            .consent_events
+            .take()
+            .iter()
+            .any(|e| e.change == ConsentChange::Withdrawn && e.record.module == StewardModule::PLGA);
+        if !(withdrawn && self.retention_on_withdrawal) {
+            return;
+        }
+        let policy = self.retention.clone();
+        for withdrawal in self.consent.take_withdrawals(StewardModule::PLGA) {
+            if let Err(message) = self.apply_retention(&withdrawal.participant.clone(), &policy, &withdrawal) {
+                self.consent.record_observer_failure(ObserverFailure {
+                    observer: "plga".into(),
+                    change: ConsentChange::Withdrawn,
+                    participant: withdrawal.participant,
+                    module: StewardModule::PLGA,
+                    message,
+                });
+            }
+        }
+    }
+
+    /// Check the redaction log's chain, and that every redacted attestation is as its latest redaction left it.
+    pub fn verify_redactions(&self) -> Result<(), RedactionError> {
+        self.redactions.verify(|target, hash| match target {
+            RedactionTarget::Attestation(id) => self.attestations.get(id).is_some_and(|a| retention::content_hash(a) == hash),
+            _ => false,
+        })
+    }
+
+    pub fn verifier_registry(&self) -> &VerifierRegistry {
+        &self.verifiers
+    }
+
+    /// Workload, concentration and co-verification per verifier over `[start_ms, end_ms)`,
+    /// widened to whole days. Built from counters kept at issuance.
+    pub fn verifier_report(&self, window: (u64, u64)) -> VerifierReport {
+        self.verifier_stats.report(window, &self.verifiers.policy.thresholds)
+    }
+
+    /// Downgrade the relationships `report` flags, if the policy enables auto-downgrade.
+    pub fn apply_verifier_report(&mut self, report: &VerifierReport, now_ms: u64) -> Vec<VerifierDowngrade> {
+        self.verifiers.apply_report(report, now_ms)
+    }
+
+    /// `verifier`'s signature stops counting on `actor`'s attestations issued from now on.
+    /// Only through `AdminAction::SuspendVerifier`.
+    fn downgrade_verifier(
+        &mut self,
+        verifier: Did,
+        actor: Did,
+        reason: String,
+        by: Did,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        self.verifiers.downgrade(verifier, actor, reason, Some(by), now_ms)
+    }
+
+    /// Lift a downgrade; attestations issued while it held keep their `non_counting_verifiers`.
+    /// Only through `AdminAction::RestoreVerifier`.
+    fn restore_verifier(&mut self, verifier: &Did, actor: &Did, by: Did, now_ms: u64) -> Result<(), String> {
+        self.verifiers.restore(verifier, actor, by, now_ms)
+    }
+
+    /// Id the next attestation can be issued under, so a rollback plan can
+    /// target it before it exists.
+    pub fn reserve_attestation_id(&mut self) -> AttestationId {
+        let id = AttestationId(self.ids.next_id());
+        self.reserved.insert(id.clone());
+        id
+    }
+
+    /// Register `plan` with this ledger's registry, resolving attestations here.
+    pub fn register_rollback_plan(&self, plan: RollbackPlan) -> Result<PlanId, String> {
+        self.rollback
+            .lock()
+            .map_err(|_| "Rollback registry lock poisoned".to_string())?
+            .register(plan, self)
+    }
+
+    /// `issue_attestation` stamped with the ledger's clock.
+    pub fn issue_attestation_now(
+        &mut self,
+        actor_did: Did,
+        mission_id: Option<MissionId>,
+        description: String,
+        impact_metrics: ImpactMetrics,
+        evidence: impl Into<EvidenceBundle>,
+        verifier_dids: Vec<Did>,
+    ) -> Result<StewardshipAttestation, String> {
+        let timestamp_ms = self.clock.now_ms();
+        self.issue_attestation(
+            actor_did,
+            mission_id,
+            description,
+            impact_metrics,
+            evidence,
+            verifier_dids,
+            timestamp_ms,
+        )
+    }
+
+    /// Karma-safe: no scores, no ranks, just per-actor, per-mission attestations.[web:16]
+    /// Publishes a public intent entry first when SAEP requires one. Carries no
+    /// rollback plan, so it is refused under `enforce_reversibility`; see `issue_attestation_with_refs`.
+    pub fn issue_attestation(
+        &mut self,
+        actor_did: Did,
+        mission_id: Option<MissionId>,
+        description: String,
+        impact_metrics: ImpactMetrics,
+        evidence: impl Into<EvidenceBundle>,
+        verifier_dids: Vec<Did>,
+        timestamp_ms: u64,
+    ) -> Result<StewardshipAttestation, String> {
+        self.issue_attestation_with_intent(
+            actor_did,
+            mission_id,
+            description,
+            impact_metrics,
+            evidence,
+            verifier_dids,
+            timestamp_ms,
+            None,
+        )
+    }
+
+    /// `issue_attestation` under an intent entry the actor already published;
+    /// `None` auto-publishes one when SAEP requires it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue_attestation_with_intent(
+        &mut self,
+        actor_did: Did,
+        mission_id: Option<MissionId>,
+        description: String,
+        impact_metrics: ImpactMetrics,
+        evidence: impl Into<EvidenceBundle>,
+        verifier_dids: Vec<Did>,
+        timestamp_ms: u64,
+        intent: Option<IntentEntryId>,
+    ) -> Result<StewardshipAttestation, String> {
+        self.issue_attestation_with_refs(
+            actor_did,
+            mission_id,
+            description,
+            impact_metrics,
+            evidence,
+            verifier_dids,
+            timestamp_ms,
+            SaepRefs {
+                intent_entry_id: intent,
+                rollback_plan_id: None,
+            },
+        )
+    }
+
+    /// `issue_attestation` with the intent entry and rollback plan SAEP may require.
+    /// A plan must target an id from `reserve_attestation_id`; the attestation is issued under it.
+    /// Refused with the `ModulePaused` message while PLGA is paused or read-only, when
+    /// `impact_metrics.custom` holds an unregistered key or an out-of-range value, when the
+    /// biodiversity claim falls short of the ledger's `MetricsPolicy`, when `evidence`
+    /// falls short of the ledger's `EvidencePolicy` for the claim's size, and when the claim
+    /// is above the `ImpactThresholdPolicy` (this path carries no endorsement; see
+    /// `issue_attestation_idempotent`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue_attestation_with_refs(
+        &mut self,
+        actor_did: Did,
+        mission_id: Option<MissionId>,
+        description: String,
+        impact_metrics: ImpactMetrics,
+        evidence: impl Into<EvidenceBundle>,
+        verifier_dids: Vec<Did>,
+        timestamp_ms: u64,
+        refs: SaepRefs,
+    ) -> Result<StewardshipAttestation, String> {
+        self.issue(AttestationRequest {
+            actor_did,
+            mission_id,
+            description,
+            module: StewardModule::PLGA,
+            impact_metrics,
+            module_metrics: ModuleMetrics::new(),
+            evidence: evidence.into().0,
+            verifier_dids,
+            timestamp_ms,
+            refs,
+            capture_window: None,
+            witness_endorsement: None,
+        })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "plga.issue_attestation", level = "info",
+        skip(self, request),
+        fields(actor_did = %request.actor_did, module = ?request.module),
+    ))]
+    fn issue(&mut self, mut request: AttestationRequest) -> Result<StewardshipAttestation, String> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+        // Witnesses endorse, and the ledger stores, the sanitized description.
+        request.description = match self.content_policy.apply("description", &request.description) {
+            Ok(sanitized) => sanitized,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "content",
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "attestation rejected"
+                );
+                return Err(e.to_string());
+            }
+        };
+        let statement_hash = witness::statement_hash(&request);
+        let AttestationRequest {
+            actor_did,
+            mission_id,
+            description,
+            module,
+            impact_metrics,
+            module_metrics,
+            evidence,
+            verifier_dids,
+            timestamp_ms,
+            refs,
+            capture_window: _,
+            witness_endorsement,
+        } = request;
+
+        if let Err(e) = module_status::check_writable(&self.module_status, module) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "module_paused",
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        if let Err(e) = self.metric_schemas.check(module, &impact_metrics, &module_metrics) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "metric_schema",
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e);
+        }
+
+        if let Err(e) = steward_ids::metrics::check_custom(&self.custom_metrics, &impact_metrics.custom) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "custom_metric",
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e);
+        }
+
+        if let Err(e) = self.metrics_policy.check(&impact_metrics, timestamp_ms) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "biodiversity",
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e);
+        }
+
+        if let Err(e) = self.evidence_policy.check(&impact_metrics, &evidence) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "evidence",
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e);
+        }
+
+        if let Err(e) = self.impact_thresholds.check(
+            self.key_resolver.as_deref(),
+            &impact_metrics,
+            &statement_hash,
+            witness_endorsement.as_ref(),
+            timestamp_ms,
+        ) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "witness",
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        let ctx = EthicsContext {
+            actor: actor_did.clone(),
+            affected_parties: vec![],
+            module,
+            description: description.clone(),
+            estimated_impact: estimated_impact(module, &impact_metrics, &module_metrics),
+        };
+
+        let decision = self.saep.evaluate(&ctx);
+        if !decision.allowed {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "saep",
+                rules = ?decision.reasons,
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(decision.blocked("attestation").to_string());
+        }
+
+        // KSCP: require explicit consent for logging under the attestation's module.
+        let requirements = consent_requirements(self.saep.config(), module, ActionKind::IssueAttestation);
+        let prompt = ConsentPromptDescriptor::for_action(module, None, PLGA_ATTESTATION_PURPOSE, &decision);
+        if let Err(e) = requirements.check(&self.consent, &actor_did, mission_id.as_ref(), &prompt) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "consent_missing",
+                mission_id = ?mission_id,
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        let verifier_conflicts = self.applied_conflicts(&actor_did, &verifier_dids, timestamp_ms);
+        if let Err(e) = conflict::forbidden(&verifier_conflicts, &actor_did) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "verifier_conflict",
+                duration_us = started.elapsed().as_micros() as u64,
+                "attestation rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        let reserved = &self.reserved;
+        let plan = rollback::resolve_plan(&self.rollback, refs.rollback_plan_id, decision.require_rollback_plan, |target| {
+            match target {
+                ActionRef::Attestation(id) if reserved.contains(id) => Ok(()),
+                other => Err(format!("Rollback plan targets {other}, not a reserved attestation id")),
+            }
+        });
+        let plan = match plan {
+            Ok(plan) => plan,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "rollback_plan",
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "attestation rejected"
+                );
+                return Err(e);
+            }
+        };
+        let att_id = match plan.as_ref().map(|p| &p.plan.action_ref) {
+            Some(ActionRef::Attestation(id)) => id.clone(),
+            _ => AttestationId(self.ids.next_id()),
+        };
+
+        let intent = refs.intent_entry_id;
+        let intent_entry_id = if decision.require_public_intent_log || intent.is_some() {
+            let entry = || IntentEntry {
+                actor: actor_did.clone(),
+                module,
+                description: description.clone(),
+                decision_id: format!("plga:attestation:{}", att_id.0),
+                timestamp_ms,
+            };
+            match intent::resolve_intent(&self.intent_log, intent, &actor_did, module, entry) {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        decision = "deny",
+                        reason = "intent_log",
+                        duration_us = started.elapsed().as_micros() as u64,
+                        "attestation rejected"
+                    );
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+        let non_counting_verifiers: Vec<Did> = verifier_dids
+            .iter()
+            .filter(|v| {
+                self.verifiers.is_downgraded(v, &actor_did)
+                    || verifier_conflicts
+                        .iter()
+                        .any(|c| &c.verifier == *v && c.treatment == ConflictTreatment::NotCounted)
+            })
+            .cloned()
+            .collect();
+        let att = StewardshipAttestation {
+            id: att_id.clone(),
+            actor_did,
+            mission_id,
+            timestamp_ms,
+            description,
+            module,
+            impact_metrics,
+            module_metrics,
+            evidence,
+            verifier_dids,
+            visible_symbol: "STWD".into(),
+            intent_entry_id,
+            rollback_plan_id: plan.map(|p| p.plan.id),
+            non_counting_verifiers,
+            verifier_conflicts,
+            witness_endorsement,
+            origin: AttestationOrigin::Native,
+        };
+
+        self.reserved.remove(&att_id);
+        self.verifier_stats.record(&att);
+        self.attestations.insert(att_id.clone(), att.clone());
+        self.publish_attestation(&att_id);
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            decision = "allow",
+            attestation_id = %att_id.0,
+            duration_us = started.elapsed().as_micros() as u64,
+            "attestation issued"
+        );
+        Ok(att)
+    }
+
+    /// `issue_attestation_with_refs` for `request`, at most once per unexpired idempotency key:
+    /// a replay with the same request returns the attestation the key first created.
+    /// `request.capture_window` only matters to validation; `request.witness_endorsement`
+    /// is checked and recorded. With no key, this is the way to issue a witnessed claim.
+    pub fn issue_attestation_idempotent(
+        &mut self,
+        request: AttestationRequest,
+        idempotency_key: Option<IdempotencyKey>,
+    ) -> Result<StewardshipAttestation, String> {
+        if let Some(key) = &idempotency_key {
+            if let Some(original) = self.idempotent_attestation(key).map_err(|e| e.to_string())? {
+                return Ok(original.clone());
+            }
+        }
+        let att = self.issue(request)?;
+        if let Some(key) = idempotency_key {
+            self.idempotency.record(key, att.id.clone(), self.clock.now_ms());
+        }
+        Ok(att)
+    }
+
+    /// Replay a device's journal: its PLGA consents and attestations in order, each validated
+    /// in full and skipped if its key was applied before. Read by the ledger's clock.
+    pub fn apply_journal(&mut self, journal: &MutationJournal, policy: ReplayPolicy) -> JournalReport {
+        let clock = self.clock.clone();
+        let report = journal::replay(self, journal, policy, || clock.now_ms());
+        self.apply_consent_events();
+        report
+    }
+
+    /// The attestation `key` created, if the key is held for this request and unexpired;
+    /// a conflict if it is held for another request.
+    pub fn idempotent_attestation(
+        &self,
+        key: &IdempotencyKey,
+    ) -> Result<Option<&StewardshipAttestation>, IdempotencyConflict> {
+        let id = self.idempotency.lookup(key, self.clock.now_ms())?;
+        Ok(id.and_then(|id| self.attestations.get(id)))
+    }
+
+    /// Dry run of `issue_attestation_with_refs`: every failing check, plus the conditions
+    /// SAEP would attach. Publishes, reserves and records nothing.
+    pub fn validate_attestation(&self, request: &AttestationRequest) -> ValidationReport {
+        let module = request.module;
+        let mut report = ValidationReport::new(module);
+        report.check(ValidationCode::ModulePaused, module_status::check_writable(&self.module_status, module));
+        report.check(
+            ValidationCode::MetricSchema,
+            self.metric_schemas
+                .check(module, &request.impact_metrics, &request.module_metrics)
+                .map_err(validation::detail(ReasonCode::MetricSchema)),
+        );
+        report.check(
+            ValidationCode::CustomMetric,
+            steward_ids::metrics::check_custom(&self.custom_metrics, &request.impact_metrics.custom)
+                .map_err(validation::detail(ReasonCode::CustomMetric)),
+        );
+        report.check(
+            ValidationCode::Biodiversity,
+            self.metrics_policy
+                .check(&request.impact_metrics, request.timestamp_ms)
+                .map_err(validation::detail(ReasonCode::Biodiversity)),
+        );
+        report.check(
+            ValidationCode::Evidence,
+            self.evidence_policy
+                .check(&request.impact_metrics, &request.evidence)
+                .map_err(validation::detail(ReasonCode::EvidenceRejected)),
+        );
+        let description = content::report(&self.content_policy, "description", &request.description, &mut report);
+        let statement_hash = witness::statement_hash(&AttestationRequest { description, ..request.clone() });
+        report.check(
+            ValidationCode::Witness,
+            self.impact_thresholds.check(
+                self.key_resolver.as_deref(),
+                &request.impact_metrics,
+                &statement_hash,
+                request.witness_endorsement.as_ref(),
+                request.timestamp_ms,
+            ),
+        );
+        if self.impact_thresholds.requires_witness(&request.impact_metrics) {
+            report.conditions.push(ValidationCondition::Witness {
+                statement_hash,
+                witnesses: self.impact_thresholds.witnesses.iter().cloned().collect(),
+            });
+        }
+        let window = request
+            .capture_window
+            .unwrap_or_else(|| self.evidence_policy.capture_window(request.timestamp_ms));
+        for artifact in evidence::captured_outside(&request.evidence, window) {
+            report.warn(
+                ValidationCode::EvidenceOutsideWindow,
+                Reason::new(ReasonCode::EvidenceOutsideWindow)
+                    .with("uri", &artifact.uri)
+                    .with("captured_at_ms", artifact.captured_at_ms.unwrap_or_default())
+                    .with("window_start_ms", window.0)
+                    .with("window_end_ms", window.1),
+            );
+        }
+        for (hash, earlier) in self.shared_content_hashes(&request.evidence) {
+            report.warn(
+                ValidationCode::EvidenceReused,
+                Reason::new(ReasonCode::EvidenceReused).with("hash", hash).with("attestation", &earlier.0),
+            );
+        }
+
+        let decision = self.saep.evaluate(&EthicsContext {
+            actor: request.actor_did.clone(),
+            affected_parties: vec![],
+            module,
+            description: request.description.clone(),
+            estimated_impact: estimated_impact(module, &request.impact_metrics, &request.module_metrics),
+        });
+        if !decision.allowed {
+            report.check(ValidationCode::Saep, Err(decision.blocked("attestation")));
+        }
+
+        let requirements = consent_requirements(self.saep.config(), module, ActionKind::IssueAttestation);
+        let prompt = ConsentPromptDescriptor::for_action(module, None, PLGA_ATTESTATION_PURPOSE, &decision);
+        if requirements.required() {
+            report.check(
+                ValidationCode::ConsentMissing,
+                requirements.check(&self.consent, &request.actor_did, request.mission_id.as_ref(), &prompt),
+            );
+            report.conditions.push(ValidationCondition::Consent {
+                purpose: prompt.purpose.clone(),
+                prompt_hash: prompt.canonical_hash(),
+            });
+        }
+
+        let conflicts = self.applied_conflicts(&request.actor_did, &request.verifier_dids, request.timestamp_ms);
+        report.check(ValidationCode::VerifierConflict, conflict::forbidden(&conflicts, &request.actor_did));
+
+        let plan_id = request.refs.rollback_plan_id.clone();
+        let reserved = &self.reserved;
+        let plan = rollback::resolve_plan(&self.rollback, plan_id.clone(), decision.require_rollback_plan, |target| {
+            match target {
+                ActionRef::Attestation(id) if reserved.contains(id) => Ok(()),
+                other => Err(format!("Rollback plan targets {other}, not a reserved attestation id")),
+            }
+        });
+        report.check(ValidationCode::RollbackPlan, plan.map(|_| ()).map_err(validation::detail(ReasonCode::RollbackPlan)));
+        if decision.require_rollback_plan || plan_id.is_some() {
+            report.conditions.push(ValidationCondition::RollbackPlan { plan_id });
+        }
+
+        let intent = request.refs.intent_entry_id.clone();
+        if let Some(id) = &intent {
+            report.check(
+                ValidationCode::IntentLog,
+                intent::check_intent(&self.intent_log, id, &request.actor_did, module)
+                    .map_err(validation::detail(ReasonCode::IntentLog)),
+            );
+        }
+        if decision.require_public_intent_log || intent.is_some() {
+            report.conditions.push(ValidationCondition::PublicIntentLog { intent_entry_id: intent });
+        }
+        report
+    }
+
+    /// Content hashes in `evidence` that already back an issued attestation, each with
+    /// the earliest such attestation, in `evidence` order.
+    pub fn shared_content_hashes<'a>(&self, evidence: &'a [EvidenceArtifact]) -> Vec<(&'a str, AttestationId)> {
+        let mut shared: Vec<(&str, AttestationId)> = Vec::new();
+        for hash in evidence.iter().filter_map(|a| a.content_hash.as_deref()) {
+            if shared.iter().any(|(h, _)| *h == hash) {
+                continue;
+            }
+            let earliest = self
+                .attestations()
+                .find(|att| att.evidence.iter().any(|a| a.content_hash.as_deref() == Some(hash)));
+            if let Some(att) = earliest {
+                shared.push((hash, att.id.clone()));
+            }
+        }
+        shared
+    }
+
+    /// Consent view used for KSCP checks.
+    pub fn consent(&self) -> &ConsentRegistry {
+        &self.consent
+    }
+
+    /// Upsert here to keep the consent view current; see `apply_consent_events`.
+    pub fn consent_mut(&mut self) -> ConsentRegistryMut<'_, Self> {
+        ConsentRegistryMut::new(self)
+    }
+
+    pub fn get_attestation(&self, id: &AttestationId) -> Option<&StewardshipAttestation> {
+        self.attestations.get(id)
+    }
+
+    /// All attestations, ordered by timestamp, then id.
+    pub fn attestations(&self) -> impl Iterator<Item = &StewardshipAttestation> {
+        let mut all: Vec<&StewardshipAttestation> = self.attestations.values().collect();
+        sort_attestations(&mut all);
+        all.into_iter()
+    }
+
+    /// `actor`'s attestations, ordered by timestamp, then id.
+    pub fn get_attestations_for_actor(&self, actor: &Did) -> Vec<&StewardshipAttestation> {
+        let mut found: Vec<&StewardshipAttestation> = self
+            .attestations
+            .values()
+            .filter(|a| &a.actor_did == actor)
+            .collect();
+        sort_attestations(&mut found);
+        found
+    }
+
+    /// `module`'s attestations, across actors, in `attestations()` order.
+    pub fn attestations_in_module(&self, module: StewardModule) -> impl Iterator<Item = &StewardshipAttestation> {
+        self.attestations().filter(move |a| a.module == module)
+    }
+
+    /// Attestations imported from external records, or only those from `source_ref`, in
+    /// `attestations()` order.
+    pub fn imported_attestations<'a>(
+        &'a self,
+        source_ref: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a StewardshipAttestation> + 'a {
+        self.attestations().filter(move |a| match &a.origin {
+            AttestationOrigin::Native => false,
+            AttestationOrigin::ExternalImport { source_ref: from } => source_ref.is_none_or(|s| s == from),
+        })
+    }
+
+    /// Every attestation, in `attestations()` order, as a `steward_ids::stream` of
+    /// `ATTESTATION_STREAM_KIND`; written one at a time, so beyond ordering one reference per
+    /// attestation the export needs no more memory for a million attestations than for ten.
+    pub fn export_attestations<W: Write>(&self, sink: W, compression: Compression) -> io::Result<StreamManifest> {
+        stream::write_stream(sink, ATTESTATION_STREAM_KIND, compression, self.attestations())
+    }
+
+    /// Store the attestations `export_attestations` wrote, as they were exported; the number
+    /// stored. Nothing is stored unless the whole stream verifies against its manifest and
+    /// every id is new to the ledger. Only attestations travel: consents, verifiers and
+    /// idempotency keys are the ledger's own.
+    pub fn restore_attestations<R: BufRead>(&mut self, source: R) -> Result<usize, StreamError> {
+        let mut restored: Vec<StewardshipAttestation> = Vec::new();
+        let mut seen = HashSet::new();
+        let records = StreamReader::open(source, ATTESTATION_STREAM_KIND)?.records::<StewardshipAttestation>();
+        for (index, att) in records.enumerate() {
+            let att = att?;
+            if self.attestations.contains_key(&att.id) || !seen.insert(att.id.clone()) {
+                let reason = format!("Attestation {} is already recorded", att.id.0);
+                return Err(StreamError::Rejected { index: index as u64, reason });
+            }
+            restored.push(att);
+        }
+        let count = restored.len();
+        for att in restored {
+            let id = att.id.clone();
+            self.verifier_stats.record(&att);
+            self.attestations.insert(id.clone(), att);
+            self.publish_attestation(&id);
+        }
+        Ok(count)
+    }
+
+    /// Totals over every attestation, or only `actor`'s: PLGA's with custom dimensions included
+    /// and biodiversity deltas also totalled per index kind, other modules' under their schemas.
+    /// Summed in `attestations()` order, so the floats round the same every time.
+    pub fn impact_summary(&self, actor: Option<&Did>) -> ImpactSummary {
+        let selected = self.attestations().filter(|a| actor.is_none_or(|d| &a.actor_did == d)).collect();
+        read_view::summarize(selected, &self.metric_schemas)
+    }
+
+    /// Chronological view of `actor`'s attestations with advisory flags for verifiers.
+    pub fn actor_timeline(&self, actor: &Did, options: TimelineOptions) -> ActorTimeline {
+        timeline::build_timeline(actor, self.get_attestations_for_actor(actor), &options)
+    }
+
+    /// No transfer operation by design.
+    pub fn forbid_transfer(&self, _attestation_id: &AttestationId, _to: &Did) -> Result<(), String> {
+        Err("Stewardship attestations are non-transferable and non-speculative by design.")
+    }
+
+    /// A point-in-time, lock-free view of the attestations for dashboards; see `read_view`.
+    pub fn read_view(&self) -> LedgerView {
+        let attestations = self.attestation_views.snapshot();
+        LedgerView::new(self.view_head.clone(), self.clock.now_ms(), attestations, self.metric_schemas.clone())
+    }
+
+    /// Head of the change chain the next `read_view` will carry.
+    pub fn view_head(&self) -> &ViewHead {
+        &self.view_head
+    }
+
+    /// Pass attestation `id`, as stored now, on to views and the change chain.
+    pub(crate) fn publish_attestation(&mut self, id: &AttestationId) {
+        let att = self.attestations.get(id).expect("published attestations are stored");
+        self.view_head.advance("attestation", id, Some(att));
+        self.attestation_views.put(id.clone(), att);
+    }
+}
+
+/// What SAEP sees of a claim's size: PLGA's headline figures, or another module's metrics.
+fn estimated_impact(module: StewardModule, impact: &ImpactMetrics, metrics: &ModuleMetrics) -> serde_json::Value {
+    if module != StewardModule::PLGA {
+        return serde_json::to_value(metrics).unwrap_or_default();
+    }
+    serde_json::json!({
+        "co2eq_reduced": impact.co2eq_reduced,
+        "biodiversity_index_delta": impact.biodiversity_index_delta,
+    })
+}
+
+pub(crate) fn sort_attestations(attestations: &mut [&StewardshipAttestation]) {
+    attestations.sort_by(|a, b| (a.timestamp_ms, &a.id.0).cmp(&(b.timestamp_ms, &b.id.0)));
+}
+
+/// Issued and reserved attestations exist; nothing else is known to the ledger.
+impl ActionResolver for PlanetaryLedger {
+    fn action_exists(&self, action: &ActionRef) -> bool {
+        match action {
+            ActionRef::Attestation(id) => self.attestations.contains_key(id) || self.reserved.contains(id),
+            _ => false,
+        }
+    }
+}
+
+/// ---------------------------------------------------------------------
+/// MICRO-MISSIONS ENGINE (MME) – WITH ETHICS + CONSENT CHECKS
+/// ---------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MissionTemplate {
+    pub id: MissionId,
+    pub title: String,
+    pub description: String, // markdown
+    pub difficulty: String,  // XS,S,M,L,XL
+    pub expected_impact: serde_json::Value,
+    pub location_hint: String, // "geo" or "virtual"
+    pub required_skills: Vec<String>,
+    /// Language of `title` and `description`; required once `localized_content` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_language: Option<LanguageTag>,
+    /// The same mission in other languages; see `resolve`.
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "steward_ids::ordered::map"
+    )]
+    pub localized_content: HashMap<LanguageTag, LocalizedTemplate>,
+    /// Claims of it count toward the `FairnessPolicy`'s high-demand cap.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub high_demand: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AssignedMission {
+    pub mission: MissionTemplate,
+    pub assignee: Did,
+    pub assigned_ts_ms: u64,
+    /// Public intent entry the assignment was made under, when SAEP required one.
+    #[serde(default)]
+    pub intent_entry_id: Option<IntentEntryId>,
+    /// Registered plan for undoing the assignment, when SAEP required one.
+    #[serde(default)]
+    pub rollback_plan_id: Option<PlanId>,
+    /// Suspended while the assignee's consent no longer covers it; omitted while active.
+    #[serde(default, skip_serializing_if = "OpenAssignmentStatus::is_active")]
+    pub status: OpenAssignmentStatus,
+    /// Approved version of the template `mission` is; 0, and omitted, if it was added without review.
+    #[serde(default, skip_serializing_if = "is_zero_version")]
+    pub template_version: u32,
+}
+
+fn is_zero_version(version: &u32) -> bool {
+    *version == 0
+}
+
+pub struct MicroMissionsEngine {
+    saep: SaepEngine,
+    consent: ConsentRegistry,
+    /// Shared with views taken since the last template change.
+    templates: Arc<HashMap<MissionId, MissionTemplate>>,
+    /// Full-text index over `templates`, kept current by `add_template`.
+    search_index: Arc<search::TemplateIndex>,
+    template_review: TemplateReviewPolicy,
+    /// Latest version submitted for review per mission; approved ones are also in `templates`.
+    template_submissions: BTreeMap<MissionId, TemplateSubmission>,
+    /// Canonical skills; `None` matches skills as written.
+    skills: Option<SkillTaxonomy>,
+    /// Applied to template descriptions by `register_template` and bundle loads.
+    content_policy: ContentPolicy,
+    preferred_languages: HashMap<Did, LanguageTag>,
+    /// Open assignments; closed ones are moved to `archive`.
+    active_assignments: assignment_archive::ActiveAssignments,
+    archive: Box<dyn AssignmentArchive>,
+    /// Ranks `recommend_for` results.
+    scorer: Arc<dyn MissionScorer>,
+    intent_log: SharedIntentLog,
+    rollback: SharedRollbackRegistry,
+    module_status: SharedModuleStatus,
+    /// Applied by `sweep_retention` to MME consent withdrawals.
+    retention: RetentionPolicy,
+    redactions: RedactionLog,
+    /// Changes to `consent`, heard as its `mme` observer.
+    consent_events: ConsentEventQueue,
+    /// Assignments created under each idempotency key.
+    idempotency: IdempotencyStore<AssignmentKey>,
+    /// Journal entries applied by `apply_journal`, by key.
+    journal_keys: IdempotencyStore<JournalEffect>,
+    /// Copy of `active_assignments` that `read_view` shares; kept current by `publish_assignment`.
+    assignment_views: read_view::ViewLog<AssignmentKey, AssignedMission>,
+    view_head: ViewHead,
+    fairness: FairnessPolicy,
+    /// Waitlists of missions published under a claim delay, until drawn.
+    claim_windows: BTreeMap<MissionId, ClaimWindow>,
+    lottery_draws: Vec<LotteryDraw>,
+    /// Where retention approvals come from; see `DualControl::bind`.
+    admin: AdminBinding,
+}
+
+impl MicroMissionsEngine {
+    pub fn new(saep: SaepEngine, mut consent: ConsentRegistry) -> Self {
+        let consent_events = ConsentEventQueue::new();
+        consent.add_observer("mme", Arc::new(consent_events.clone()));
+        Self {
+            saep,
+            consent,
+            templates: Arc::default(),
+            search_index: Arc::default(),
+            template_review: TemplateReviewPolicy::default(),
+            template_submissions: BTreeMap::new(),
+            skills: None,
+            content_policy: ContentPolicy::default(),
+            preferred_languages: HashMap::new(),
+            active_assignments: BTreeMap::new(),
+            archive: Box::new(InMemoryArchive::new()),
+            scorer: Arc::new(DefaultMissionScorer::default()),
+            intent_log: IntentLog::shared(),
+            rollback: RollbackPlanRegistry::shared(),
+            module_status: ModuleStatusRegistry::shared(),
+            retention: RetentionPolicy::default(),
+            redactions: RedactionLog::default(),
+            consent_events,
+            idempotency: IdempotencyStore::default(),
+            journal_keys: IdempotencyStore::default(),
+            assignment_views: read_view::ViewLog::default(),
+            view_head: ViewHead::default(),
+            fairness: FairnessPolicy::default(),
+            claim_windows: BTreeMap::new(),
+            lottery_draws: Vec::new(),
+            admin: AdminBinding::default(),
+        }
+    }
+
+    /// Publish to `log` (e.g. one shared with `PlanetaryLedger`) instead of a private log.
+    pub fn with_intent_log(mut self, log: SharedIntentLog) -> Self {
+        self.intent_log = log;
+        self
+    }
+
+    pub fn intent_log(&self) -> SharedIntentLog {
+        self.intent_log.clone()
+    }
+
+    /// A point-in-time, lock-free view of the templates and open assignments for dashboards;
+    /// see `read_view`.
+    pub fn read_view(&self) -> MissionsView {
+        MissionsView::new(
+            self.view_head.clone(),
+            self.templates.clone(),
+            self.search_index.clone(),
+            self.skills.clone(),
+            self.assignment_views.snapshot(),
+        )
+    }
+
+    /// Head of the change chain the next `read_view` will carry.
+    pub fn view_head(&self) -> &ViewHead {
+        &self.view_head
+    }
+
+    /// Pass the open assignment `key`, as stored now or as closed, on to views and the change chain.
+    fn publish_assignment(&mut self, key: &AssignmentKey) {
+        let assignment = self.active_assignments.get(key);
+        self.view_head.advance("assignment", key, assignment);
+        match assignment {
+            Some(assignment) => self.assignment_views.put(key.clone(), assignment),
+            None => self.assignment_views.remove(key.clone()),
+        }
+    }
+
+    /// Look plans up in `registry` (e.g. one shared with `PlanetaryLedger`) instead of a private one.
+    pub fn with_rollback_registry(mut self, registry: SharedRollbackRegistry) -> Self {
+        self.rollback = registry;
+        self
+    }
+
+    pub fn rollback_registry(&self) -> SharedRollbackRegistry {
+        self.rollback.clone()
+    }
+
+    /// Obey `status` (e.g. one shared with `GovernanceEngine`) instead of a private, always-active one.
+    pub fn with_module_status(mut self, status: SharedModuleStatus) -> Self {
+        self.module_status = status;
+        self
+    }
+
+    pub fn module_status(&self) -> SharedModuleStatus {
+        self.module_status.clone()
+    }
+
+    /// What `sweep_retention` does to assignments of participants who withdraw MME consent.
+    pub fn with_retention_policy(mut self, policy: RetentionPolicy) -> Self {
+        self.retention = policy;
+        self
+    }
+
+    pub fn retention_policy(&self) -> &RetentionPolicy {
+        &self.retention
+    }
+
+    pub fn redaction_log(&self) -> &RedactionLog {
+        &self.redactions
+    }
+
+    /// How long an idempotency or journal key is held after its first success, by the calls' `now_ms`.
+    pub fn with_idempotency_ttl(mut self, ttl_ms: u64) -> Self {
+        self.idempotency.ttl_ms = ttl_ms;
+        self.journal_keys.ttl_ms = ttl_ms;
+        self
+    }
+
+    /// Idempotency keys currently held, expired ones not yet dropped included.
+    pub fn idempotency_keys_held(&self) -> usize {
+        self.idempotency.len()
+    }
+
+    /// Move closed assignments to `archive` (e.g. a `FileArchive`) instead of memory.
+    /// Records already archived stay where they were.
+    pub fn with_assignment_archive(mut self, archive: Box<dyn AssignmentArchive>) -> Self {
+        self.archive = archive;
+        self
+    }
+
+    pub fn assignment_archive(&self) -> &dyn AssignmentArchive {
+        self.archive.as_ref()
+    }
+
+    /// Move the open assignment `key` to the archive as `status`, closed at `now_ms`.
+    /// If the archive refuses it, it stays open. A suspended assignment can be abandoned,
+    /// not completed, until its assignee consents again.
+    pub fn close_assignment(
+        &mut self,
+        key: &AssignmentKey,
+        status: AssignmentStatus,
+        now_ms: u64,
+    ) -> Result<ArchivedAssignment, String> {
+        let open = self.active_assignments.get(key).ok_or_else(|| format!("No open assignment of {key}"))?;
+        if status == AssignmentStatus::Completed && !open.status.is_active() {
+            return Err(format!("Assignment of {key} is suspended pending consent"));
+        }
+        let assignment = self.active_assignments.remove(key).expect("checked above");
+        let record = ArchivedAssignment { assignment, status, closed_ts_ms: now_ms };
+        if let Err(e) = self.archive.append(record.clone()) {
+            self.active_assignments.insert(key.clone(), record.assignment);
+            return Err(e);
+        }
+        self.publish_assignment(key);
+        Ok(record)
+    }
+
+    /// Archived assignments matching `filter`, in the order they were closed; streamed from
+    /// the archive rather than loaded.
+    pub fn archive_query<'a>(&'a self, filter: &'a AssignmentFilter) -> ArchiveIter<'a> {
+        self.archive.query(filter)
+    }
+
+    /// Open assignments and the archive, for persisting the engine.
+    pub fn assignment_state(&self) -> AssignmentState {
+        AssignmentState {
+            active: self.active_assignments.values().cloned().collect(),
+            archive: self.archive.descriptor(),
+        }
+    }
+
+    /// Restore `assignment_state`'s output; an `External` archive keeps the engine's own.
+    pub fn with_assignment_state(mut self, state: AssignmentState) -> Result<Self, String> {
+        if let Some(archive) = state.archive.open()? {
+            self.archive = archive;
+        }
+        let mut active = BTreeMap::new();
+        for assignment in state.active {
+            if let Some(duplicate) = active.insert(assignment.key(), assignment) {
+                return Err(format!("Duplicate open assignment of {}", duplicate.key()));
+            }
+        }
+        self.active_assignments = active;
+        let keys: Vec<AssignmentKey> = self.active_assignments.keys().cloned().collect();
+        for key in &keys {
+            self.publish_assignment(key);
+        }
+        Ok(self)
+    }
+
+    /// Redact `did`'s assignments under `policy`, as `withdrawal` (an MME withdrawal by `did`)
+    /// requires, and forget their preferred language. One with a mission covers only that mission.
+    /// Only open assignments are redacted; the archive is append-only. Reached only through
+    /// `AdminAction::ExecuteRetention`.
+    pub(crate) fn apply_retention(
+        &mut self,
+        did: &Did,
+        policy: &RetentionPolicy,
+        withdrawal: &ConsentRecord,
+    ) -> Result<Vec<RedactionRecord>, String> {
+        retention::check_withdrawal(did, StewardModule::MME, withdrawal)?;
+        let mut redacted = Vec::new();
+        if *policy == RetentionPolicy::Keep {
+            return Ok(redacted);
+        }
+        if withdrawal.mission.is_none() {
+            self.preferred_languages.remove(did);
+        }
+        let keys: Vec<AssignmentKey> = self
+            .active_assignments
+            .iter()
+            .filter(|(_, a)| &a.assignee == did && retention::covers(withdrawal, Some(&a.mission.id)))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in keys {
+            let Some(mut assignment) = self.active_assignments.remove(&key) else { continue };
+            let target = RedactionTarget::Assignment {
+                mission: assignment.mission.id.clone(),
+                assigned_ts_ms: assignment.assigned_ts_ms,
+            };
+            redacted.extend(retention::redact(
+                &mut self.redactions,
+                target,
+                StewardModule::MME,
+                withdrawal,
+                policy,
+                &mut assignment,
+                retention::redact_assignment,
+            ));
+            // Re-key under the redacted assignee, unless that assignment is already open.
+            let redacted_key = assignment.key();
+            let new_key = if self.active_assignments.contains_key(&redacted_key) { key.clone() } else { redacted_key };
+            self.active_assignments.insert(new_key.clone(), assignment);
+            self.publish_assignment(&key);
+            self.publish_assignment(&new_key);
+        }
+        if !redacted.is_empty() {
+            // Views taken from here on must not share the unredacted copies.
+            self.assignment_views.reset(&self.active_assignments);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(module = "MME", redacted = redacted.len(), "retention applied");
+        Ok(redacted)
+    }
+
+    /// `apply_retention` under the engine's policy for every MME withdrawal queued in its consent registry.
+    /// Only through `AdminAction::ExecuteRetention`.
+    fn sweep_retention(&mut self) -> Result<Vec<RedactionRecord>, String> {
+        let policy = self.retention.clone();
+        let mut redacted = Vec::new();
+        for withdrawal in self.consent.take_withdrawals(StewardModule::MME) {
+            redacted.extend(self.apply_retention(&withdrawal.participant.clone(), &policy, &withdrawal)?);
+        }
+        Ok(redacted)
+    }
+
+    /// Check the redaction log's chain, and that every redacted assignment, open or archived,
+    /// is as its latest redaction left it.
+    pub fn verify_redactions(&self) -> Result<(), RedactionError> {
+        self.redactions.verify(|target, hash| match target {
+            RedactionTarget::Assignment { mission, assigned_ts_ms } => {
+                let matches = |a: &AssignedMission| {
+                    &a.mission.id == mission && a.assigned_ts_ms == *assigned_ts_ms && retention::content_hash(a) == hash
+                };
+                self.active_assignments.values().any(matches) || {
+                    let filter = AssignmentFilter { mission: Some(mission.clone()), ..AssignmentFilter::default() };
+                    let archived = self.archive.query(&filter).filter_map(Result::ok).any(|r| matches(&r.assignment));
+                    archived
+                }
+            }
+            _ => false,
+        })
+    }
+
+    /// Register `plan` with this engine's registry, resolving assignments here.
+    pub fn register_rollback_plan(&self, plan: RollbackPlan) -> Result<PlanId, String> {
+        self.rollback
+            .lock()
+            .map_err(|_| "Rollback registry lock poisoned".to_string())?
+            .register(plan, self)
+    }
+
+    /// Add or replace a template; the search index is updated in place. Skills and descriptions
+    /// are stored as written, even with a skill taxonomy or content policy; `register_template`
+    /// canonicalizes and sanitizes them. Neither goes through review; see `submit_template`.
+    pub fn add_template(&mut self, tpl: MissionTemplate) {
+        Arc::make_mut(&mut self.search_index).insert(&tpl);
+        self.view_head.advance("template", &tpl.id, Some(&tpl));
+        Arc::make_mut(&mut self.templates).insert(tpl.id.clone(), tpl);
+    }
+
+    /// Take `id` out of search and assignment; whether it was there.
+    fn withdraw_template(&mut self, id: &MissionId) -> bool {
+        if !self.templates.contains_key(id) {
+            return false;
+        }
+        Arc::make_mut(&mut self.templates).remove(id);
+        Arc::make_mut(&mut self.search_index).remove(id);
+        self.view_head.advance("template", id, None::<&MissionTemplate>);
+        true
+    }
+
+    /// `add_template` after `MissionTemplate::validate`, with descriptions sanitized under the
+    /// content policy and required skills as canonical ids when the engine has a skill taxonomy.
+    pub fn register_template(&mut self, tpl: MissionTemplate) -> Result<(), String> {
+        let tpl = self.prepare_template(tpl)?;
+        self.add_template(tpl);
+        Ok(())
+    }
+
+    fn prepare_template(&mut self, mut tpl: MissionTemplate) -> Result<MissionTemplate, String> {
+        tpl.validate()?;
+        self.sanitize_descriptions(&mut tpl)?;
+        let mut templates = [tpl];
+        self.canonicalize_skills(&mut templates).map_err(skills::describe_unknown)?;
+        let [tpl] = templates;
+        Ok(tpl)
+    }
+
+    /// Register every template in a TOML bundle; nothing is added if any template is invalid.
+    /// Under `UnknownSkillPolicy::Reject` the error lists every unrecognized skill in the bundle.
+    #[cfg(feature = "toml")]
+    pub fn load_templates_toml(&mut self, source: &str) -> Result<usize, String> {
+        let mut templates = localization::templates_from_toml(source)?;
+        for tpl in &mut templates {
+            self.sanitize_descriptions(tpl)?;
+        }
+        self.canonicalize_skills(&mut templates).map_err(skills::describe_unknown)?;
+        let count = templates.len();
+        for tpl in templates {
+            self.add_template(tpl);
+        }
+        Ok(count)
+    }
+
+    pub fn get_template(&self, id: &MissionId) -> Option<&MissionTemplate> {
+        self.templates.get(id)
+    }
+
+    /// The template's strings in `lang`, falling back to its default language.
+    pub fn template_in(&self, id: &MissionId, lang: &LanguageTag) -> Option<ResolvedTemplate> {
+        self.templates.get(id).map(|tpl| tpl.resolve(Some(lang)))
+    }
+
+    /// Templates matching `query`'s terms (in its language) and filters, best match first,
+    /// then by mission id; see `search`.
+    pub fn search_templates(&self, query: &TemplateQuery) -> Vec<ScoredTemplate> {
+        self.search_index.search(&self.templates, query, self.skills.as_ref())
+    }
+
+    /// Language SAEP reads a participant's missions in at assignment.
+    pub fn set_preferred_language(&mut self, participant: Did, lang: LanguageTag) {
+        self.preferred_languages.insert(participant, lang);
+    }
+
+    pub fn preferred_language(&self, participant: &Did) -> Option<&LanguageTag> {
+        self.preferred_languages.get(participant)
+    }
+
+    /// SAEP context for assigning `tpl` to `assignee`, described in the assignee's language.
+    fn assignment_context(&self, tpl: &MissionTemplate, assignee: &Did) -> EthicsContext {
+        EthicsContext {
+            actor: assignee.clone(),
+            affected_parties: vec![],
+            module: StewardModule::MME,
+            description: tpl.resolve(self.preferred_language(assignee)).description,
+            estimated_impact: tpl.expected_impact.clone(),
+        }
+    }
+
+    /// Templates ordered by mission id.
+    pub fn templates(&self) -> impl Iterator<Item = &MissionTemplate> {
+        let mut templates: Vec<&MissionTemplate> = self.templates.values().collect();
+        templates.sort_by(|a, b| a.id.cmp(&b.id));
+        templates.into_iter()
+    }
+
+    /// Open assignments, oldest first.
+    pub fn active_assignments(&self) -> impl ExactSizeIterator<Item = &AssignedMission> {
+        self.active_assignments.values()
+    }
+
+    pub fn active_assignment(&self, key: &AssignmentKey) -> Option<&AssignedMission> {
+        self.active_assignments.get(key)
+    }
+
+    /// `assignee`'s open assignments waiting for them to consent again or abandon, oldest first.
+    pub fn suspended_assignments<'a>(&'a self, assignee: &'a Did) -> impl Iterator<Item = &'a AssignedMission> {
+        self.active_assignments.values().filter(move |a| &a.assignee == assignee && !a.status.is_active())
+    }
+
+    /// Consent view used for KSCP checks.
+    pub fn consent(&self) -> &ConsentRegistry {
+        &self.consent
+    }
+
+    /// Upsert here to keep the consent view current; see `apply_consent_events`.
+    pub fn consent_mut(&mut self) -> ConsentRegistryMut<'_, Self> {
+        ConsentRegistryMut::new(self)
+    }
+
+    /// Recheck the open assignments of everyone whose MME consent changed since the last call:
+    /// an active assignment the assignee's consent no longer covers is suspended, and a
+    /// suspended one it covers again resumes. Nothing is closed or deleted.
+    pub fn apply_consent_events(&mut self) {
+        let mut changed: BTreeMap<Did, u64> = BTreeMap::new();
+        for event in self.consent_events.take() {
+            if event.record.module == StewardModule::MME {
+                changed.insert(event.record.participant, event.record.timestamp_ms);
+            }
+        }
+        for (participant, at_ms) in changed {
+            let keys: Vec<AssignmentKey> = self
+                .active_assignments
+                .iter()
+                .filter(|(_, a)| a.assignee == participant)
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in keys {
+                let covered = self.consent_basis(&self.active_assignments[&key]).is_ok();
+                let assignment = self.active_assignments.get_mut(&key).expect("listed above");
+                let status = match (assignment.status, covered) {
+                    (OpenAssignmentStatus::Active, false) => {
+                        OpenAssignmentStatus::SuspendedPendingConsent { since_ms: at_ms }
+                    }
+                    (OpenAssignmentStatus::SuspendedPendingConsent { .. }, true) => OpenAssignmentStatus::Active,
+                    (status, _) => status,
+                };
+                if status != assignment.status {
+                    assignment.status = status;
+                    self.publish_assignment(&key);
+                }
+            }
+        }
+    }
+
+    /// The consent check `assign_mission_with_refs` made, against the mission as assigned.
+    fn consent_basis(&self, assignment: &AssignedMission) -> Result<(), Reason> {
+        let tpl = &assignment.mission;
+        let decision = self.saep.evaluate(&self.assignment_context(tpl, &assignment.assignee));
+        let requirements = consent_requirements(self.saep.config(), StewardModule::MME, ActionKind::AssignMission);
+        let prompt = ConsentPromptDescriptor::for_action(StewardModule::MME, Some(tpl), MME_ASSIGNMENT_PURPOSE, &decision);
+        requirements.check(&self.consent, &assignment.assignee, Some(&tpl.id), &prompt)
+    }
+
+    /// Dry run of `assign_mission_with_refs`: every failing check, plus the conditions
+    /// SAEP would attach. Publishes and assigns nothing. An unknown template stops
+    /// the checks that need it.
+    pub fn validate_assignment(&self, mission_id: &MissionId, assignee: &Did, now_ms: u64) -> ValidationReport {
+        self.validate_assignment_with_refs(mission_id, assignee, now_ms, &SaepRefs::default())
+    }
+
+    /// `validate_assignment` for `assign_mission_with_refs` with `refs`.
+    /// `now_ms` mirrors the assignment call: the same mission, assignee and time may be open once.
+    pub fn validate_assignment_with_refs(
+        &self,
+        mission_id: &MissionId,
+        assignee: &Did,
+        now_ms: u64,
+        refs: &SaepRefs,
+    ) -> ValidationReport {
+        let mut report = ValidationReport::new(StewardModule::MME);
+        report.check(
+            ValidationCode::ModulePaused,
+            module_status::check_writable(&self.module_status, StewardModule::MME),
+        );
+        let Some(tpl) = self.templates.get(mission_id) else {
+            report.check(
+                ValidationCode::UnknownMission,
+                Err(Reason::new(ReasonCode::MissionUnknown).with("mission", mission_id)),
+            );
+            return report;
+        };
+        report.check(ValidationCode::AlreadyAssigned, self.check_unassigned(mission_id, assignee, now_ms));
+        report.check(
+            ValidationCode::Fairness,
+            self.check_fairness(mission_id, assignee, now_ms).map_err(|v| v.reason()),
+        );
+
+        let decision = self.saep.evaluate(&self.assignment_context(tpl, assignee));
+        if !decision.allowed {
+            report.check(ValidationCode::Saep, Err(decision.blocked("mission assignment")));
+        }
+
+        let requirements = consent_requirements(self.saep.config(), StewardModule::MME, ActionKind::AssignMission);
+        let prompt = ConsentPromptDescriptor::for_action(StewardModule::MME, Some(tpl), MME_ASSIGNMENT_PURPOSE, &decision);
+        if requirements.required() {
+            report.check(
+                ValidationCode::ConsentMissing,
+                requirements.check(&self.consent, assignee, Some(mission_id), &prompt),
+            );
+            report.conditions.push(ValidationCondition::Consent {
+                purpose: prompt.purpose.clone(),
+                prompt_hash: prompt.canonical_hash(),
+            });
+        }
+
+        let expected = ActionRef::Assignment {
+            mission: mission_id.clone(),
+            assignee: assignee.clone(),
+        };
+        let plan_id = refs.rollback_plan_id.clone();
+        let plan = rollback::resolve_plan(&self.rollback, plan_id.clone(), decision.require_rollback_plan, |target| {
+            if target == &expected {
+                Ok(())
+            } else {
+                Err(format!("Rollback plan targets {target}, not {expected}"))
+            }
+        });
+        report.check(ValidationCode::RollbackPlan, plan.map(|_| ()).map_err(validation::detail(ReasonCode::RollbackPlan)));
+        if decision.require_rollback_plan || plan_id.is_some() {
+            report.conditions.push(ValidationCondition::RollbackPlan { plan_id });
+        }
+
+        let intent = refs.intent_entry_id.clone();
+        if let Some(id) = &intent {
+            report.check(
+                ValidationCode::IntentLog,
+                intent::check_intent(&self.intent_log, id, assignee, StewardModule::MME)
+                    .map_err(validation::detail(ReasonCode::IntentLog)),
+            );
+        }
+        if decision.require_public_intent_log || intent.is_some() {
+            report.conditions.push(ValidationCondition::PublicIntentLog { intent_entry_id: intent });
+        }
+        report
+    }
+
+    /// “Agentic-RAG” placeholder: real system uses profiles + local context. [web:6][web:11]
+    /// Publishes a public intent entry first when SAEP requires one. Carries no
+    /// rollback plan, so it is refused under `enforce_reversibility`; see `assign_mission_with_refs`.
+    pub fn assign_mission(
+        &mut self,
+        mission_id: &MissionId,
+        assignee: Did,
+        now_ms: u64,
+    ) -> Result<AssignedMission, String> {
+        self.assign_mission_with_intent(mission_id, assignee, now_ms, None)
+    }
+
+    /// `assign_mission` under an intent entry the assignee already published;
+    /// `None` auto-publishes one when SAEP requires it.
+    pub fn assign_mission_with_intent(
+        &mut self,
+        mission_id: &MissionId,
+        assignee: Did,
+        now_ms: u64,
+        intent: Option<IntentEntryId>,
+    ) -> Result<AssignedMission, String> {
+        self.assign_mission_with_refs(
+            mission_id,
+            assignee,
+            now_ms,
+            SaepRefs {
+                intent_entry_id: intent,
+                rollback_plan_id: None,
+            },
+        )
+    }
+
+    /// `assign_mission` with the intent entry and rollback plan SAEP may require.
+    /// A plan must target this exact mission and assignee.
+    /// Refused with the `ModulePaused` message while MME is paused or read-only.
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "mme.assign_mission", level = "info", skip(self, refs),
+        fields(actor_did = %assignee, module = "MME"),
+    ))]
+    pub fn assign_mission_with_refs(
+        &mut self,
+        mission_id: &MissionId,
+        assignee: Did,
+        now_ms: u64,
+        refs: SaepRefs,
+    ) -> Result<AssignedMission, String> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        if let Err(e) = module_status::check_writable(&self.module_status, StewardModule::MME) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "module_paused",
+                duration_us = started.elapsed().as_micros() as u64,
+                "mission assignment rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        let tpl = self.templates.get(mission_id)
+            .ok_or_else(|| Reason::new(ReasonCode::MissionUnknown).with("mission", mission_id))?
+            .clone();
+
+        if let Err(e) = self.check_unassigned(mission_id, &assignee, now_ms) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "already_assigned",
+                duration_us = started.elapsed().as_micros() as u64,
+                "mission assignment rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        if let Err(e) = self.check_fairness(mission_id, &assignee, now_ms) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "fairness",
+                duration_us = started.elapsed().as_micros() as u64,
+                "mission assignment rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        let decision = self.saep.evaluate(&self.assignment_context(&tpl, &assignee));
+        if !decision.allowed {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "saep",
+                rules = ?decision.reasons,
+                duration_us = started.elapsed().as_micros() as u64,
+                "mission assignment rejected"
+            );
+            return Err(decision.blocked("mission assignment").to_string());
+        }
+
+        let requirements = consent_requirements(self.saep.config(), StewardModule::MME, ActionKind::AssignMission);
+        let prompt = ConsentPromptDescriptor::for_action(StewardModule::MME, Some(&tpl), MME_ASSIGNMENT_PURPOSE, &decision);
+        if let Err(e) = requirements.check(&self.consent, &assignee, Some(mission_id), &prompt) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "consent_missing",
+                duration_us = started.elapsed().as_micros() as u64,
+                "mission assignment rejected"
+            );
+            return Err(e.to_string());
+        }
+
+        let expected = ActionRef::Assignment {
+            mission: mission_id.clone(),
+            assignee: assignee.clone(),
+        };
+        let plan = rollback::resolve_plan(&self.rollback, refs.rollback_plan_id, decision.require_rollback_plan, |target| {
+            if target == &expected {
+                Ok(())
+            } else {
+                Err(format!("Rollback plan targets {target}, not {expected}"))
+            }
+        });
+        let plan = match plan {
+            Ok(plan) => plan,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "rollback_plan",
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "mission assignment rejected"
+                );
+                return Err(e);
+            }
+        };
+
+        let intent = refs.intent_entry_id;
+        let intent_entry_id = if decision.require_public_intent_log || intent.is_some() {
+            let entry = || IntentEntry {
+                actor: assignee.clone(),
+                module: StewardModule::MME,
+                description: format!("Assignment of mission {mission_id}: {}", tpl.title),
+                decision_id: format!("mme:assignment:{mission_id}:{assignee}:{now_ms}"),
+                timestamp_ms: now_ms,
+            };
+            match intent::resolve_intent(&self.intent_log, intent, &assignee, StewardModule::MME, entry) {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        decision = "deny",
+                        reason = "intent_log",
+                        duration_us = started.elapsed().as_micros() as u64,
+                        "mission assignment rejected"
+                    );
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+
+        let assigned = AssignedMission {
+            template_version: self.template_version(&tpl.id).unwrap_or(0),
+            mission: tpl,
+            assignee,
+            assigned_ts_ms: now_ms,
+            intent_entry_id,
+            rollback_plan_id: plan.map(|p| p.plan.id),
+            status: OpenAssignmentStatus::Active,
+        };
+        self.active_assignments.insert(assigned.key(), assigned.clone());
+        self.publish_assignment(&assigned.key());
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            decision = "allow",
+            duration_us = started.elapsed().as_micros() as u64,
+            "mission assigned"
+        );
+        Ok(assigned)
+    }
+
+    /// `assign_mission_with_refs` at most once per unexpired idempotency key: a replay with
+    /// the same request returns the assignment the key first created, or is refused once
+    /// that assignment has been closed.
+    pub fn assign_mission_idempotent(
+        &mut self,
+        mission_id: &MissionId,
+        assignee: Did,
+        now_ms: u64,
+        refs: SaepRefs,
+        idempotency_key: Option<IdempotencyKey>,
+    ) -> Result<AssignedMission, String> {
+        if let Some(key) = &idempotency_key {
+            if let Some(created) = self.idempotency.lookup(key, now_ms).map_err(|e| e.to_string())? {
+                return self
+                    .active_assignments
+                    .get(created)
+                    .cloned()
+                    .ok_or_else(|| format!("Assignment of {created} under idempotency key {} is closed", key.key));
+            }
+        }
+        let assigned = self.assign_mission_with_refs(mission_id, assignee, now_ms, refs)?;
+        if let Some(key) = idempotency_key {
+            self.idempotency.record(key, assigned.key(), now_ms);
+        }
+        Ok(assigned)
+    }
+
+    /// Replay a device's journal: its MME consents, assignments and closings in order, each
+    /// validated in full and skipped if its key was applied before. Every entry is applied at
+    /// `now_ms`, so one journal cannot assign the same mission to the same steward twice.
+    pub fn apply_journal(&mut self, journal: &MutationJournal, policy: ReplayPolicy, now_ms: u64) -> JournalReport {
+        let report = journal::replay(self, journal, policy, || now_ms);
+        self.apply_consent_events();
+        report
+    }
+
+    /// The assignment `key` created, if the key is held for this request, unexpired at
+    /// `now_ms` and the assignment still open; a conflict if it is held for another request.
+    pub fn idempotent_assignment(
+        &self,
+        key: &IdempotencyKey,
+        now_ms: u64,
+    ) -> Result<Option<&AssignedMission>, IdempotencyConflict> {
+        let Some(created) = self.idempotency.lookup(key, now_ms)? else {
+            return Ok(None);
+        };
+        Ok(self.active_assignments.get(created))
+    }
+
+    /// Refuses a second open assignment of `mission_id` to `assignee` at `now_ms`.
+    fn check_unassigned(&self, mission_id: &MissionId, assignee: &Did, now_ms: u64) -> Result<(), Reason> {
+        let key = AssignmentKey { assigned_ts_ms: now_ms, mission: mission_id.clone(), assignee: assignee.clone() };
+        if self.active_assignments.contains_key(&key) {
+            return Err(Reason::new(ReasonCode::MissionAlreadyAssigned)
+                .with("mission", mission_id)
+                .with("assignee", assignee)
+                .with("assigned_ts_ms", now_ms));
+        }
+        Ok(())
+    }
+}
+
+impl ConsentDependent for PlanetaryLedger {
+    fn registry(&self) -> &ConsentRegistry {
+        &self.consent
+    }
+
+    fn registry_mut(&mut self) -> &mut ConsentRegistry {
+        &mut self.consent
+    }
+
+    fn apply_consent_events(&mut self) {
+        PlanetaryLedger::apply_consent_events(self)
+    }
+}
+
+impl ConsentDependent for MicroMissionsEngine {
+    fn registry(&self) -> &ConsentRegistry {
+        &self.consent
+    }
+
+    fn registry_mut(&mut self) -> &mut ConsentRegistry {
+        &mut self.consent
+    }
+
+    fn apply_consent_events(&mut self) {
+        MicroMissionsEngine::apply_consent_events(self)
+    }
+}
+
+impl JournalTarget for PlanetaryLedger {
+    const MODULE: StewardModule = StewardModule::PLGA;
+
+    fn journal_keys(&mut self) -> &mut IdempotencyStore<JournalEffect> {
+        &mut self.journal_keys
+    }
+
+    fn apply_op(&mut self, op: &JournalOp, now_ms: u64) -> Result<JournalEffect, Vec<String>> {
+        match op {
+            JournalOp::SubmitConsent { record } => {
+                self.consent.submit_consent(journal::clamp_consent(record, now_ms)).map_err(|r| vec![r.to_string()])?;
+                Ok(JournalEffect::Consent)
+            }
+            JournalOp::IssueAttestation { request } => {
+                let report = self.validate_attestation(request);
+                if !report.is_valid() {
+                    return Err(report.issues.into_iter().map(|i| i.message).collect());
+                }
+                let att = self.issue_attestation_idempotent(request.as_ref().clone(), None).map_err(|e| vec![e])?;
+                Ok(JournalEffect::Attestation { id: att.id })
+            }
+            other => Err(vec![format!("{:?} operations are not applied by the ledger", other.module())]),
+        }
+    }
+}
+
+impl JournalTarget for MicroMissionsEngine {
+    const MODULE: StewardModule = StewardModule::MME;
+
+    fn journal_keys(&mut self) -> &mut IdempotencyStore<JournalEffect> {
+        &mut self.journal_keys
+    }
+
+    fn apply_op(&mut self, op: &JournalOp, now_ms: u64) -> Result<JournalEffect, Vec<String>> {
+        match op {
+            JournalOp::SubmitConsent { record } => {
+                self.consent.submit_consent(journal::clamp_consent(record, now_ms)).map_err(|r| vec![r.to_string()])?;
+                Ok(JournalEffect::Consent)
+            }
+            JournalOp::AssignMission { mission, assignee, refs } => {
+                let report = self.validate_assignment_with_refs(mission, assignee, now_ms, refs);
+                if !report.is_valid() {
+                    return Err(report.issues.into_iter().map(|i| i.message).collect());
+                }
+                let assigned =
+                    self.assign_mission_with_refs(mission, assignee.clone(), now_ms, refs.clone()).map_err(|e| vec![e])?;
+                Ok(JournalEffect::Assigned { key: assigned.key() })
+            }
+            JournalOp::CloseAssignment { assignment, status } => {
+                let key = match assignment {
+                    AssignmentRef::Key(key) => key.clone(),
+                    AssignmentRef::Journaled(entry) => match self.journal_keys.get(entry, now_ms) {
+                        Some(JournalEffect::Assigned { key }) => key.clone(),
+                        _ => return Err(vec![format!("No assignment was journaled under {entry}")]),
+                    },
+                };
+                let closed = self.close_assignment(&key, *status, now_ms).map_err(|e| vec![e])?;
+                Ok(JournalEffect::Closed { key: closed.assignment.key() })
+            }
+            other => Err(vec![format!("{:?} operations are not applied by the mission engine", other.module())]),
+        }
+    }
+}
+
+/// Templates stand for their prospective assignments; nothing else is known to MME.
+impl ActionResolver for MicroMissionsEngine {
+    fn action_exists(&self, action: &ActionRef) -> bool {
+        match action {
+            ActionRef::Assignment { mission, .. } => self.templates.contains_key(mission),
+            _ => false,
+        }
+    }
+}
+
+/// ---------------------------------------------------------------------
+/// GOVERNANCE HOOKS – POLYCENTRIC + QUADRATIC CONSENSUS
+/// ---------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GovernanceScope {
+    /// `ecosystem/module:<id>`.
+    Module(ModuleId),
+    /// The root, `ecosystem`.
+    EcosystemWide,
+    /// A scope registered in the engine's `ScopeTree`.
+    Path(ScopePath),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GovernanceProposal {
     pub proposal_id: String,
     pub scope: GovernanceScope,
@@ -392,6 +2982,7 @@ pub struct GovernanceProposal {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct QuadraticVote {
     pub voter: Did,
     /// cost^2 relationship modeled off-chain/on-chain; store effective weight here. [web:15][web:18]
@@ -400,33 +2991,687 @@ pub struct QuadraticVote {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct QuadraticOutcome {
     pub proposal_id: String,
     pub total_support: f64,
     pub total_opposition: f64,
 }
 
+/// Votes collected by `cast_vote` on one proposal.
+#[derive(Debug, Clone, Default)]
+struct CollectedVotes {
+    /// Each voter's latest vote and its receipt leaf.
+    ballots: HashMap<Did, (QuadraticVote, String)>,
+    /// Set by `finalize_votes`.
+    counted: Option<CountedBallots>,
+}
+
 pub struct GovernanceEngine {
     saep: SaepEngine,
     /// modules bound to co-stewardship charter; they may not be weaponized. [web:16]
     charter_bound_modules: HashSet<StewardModule>,
+    /// Support share an unbinding proposal needs; never below `CHARTER_UNBIND_FLOOR`.
+    unbind_supermajority: f64,
+    /// Every charter version adopted; the latest is the one rules cite.
+    charter: CharterRegistry,
+    /// Forbidden-term rules enforced in charter-bound modules, each citing a clause.
+    charter_rules: CharterRuleSet,
+    /// Proposals applied through `apply_proposal`, by id.
+    applied: HashMap<String, AppliedProposal>,
+    /// Scope hierarchy with per-scope quorum, voter and charter settings.
+    scopes: ScopeTree,
+    /// Proposals from `instantiate_template`, by id.
+    templated: HashMap<String, TemplateProvenance>,
+    /// Payload limits checked before any other proposal work.
+    proposal_limits: ProposalLimits,
+    module_status: SharedModuleStatus,
+    /// Module restrictions, resumptions and charter changes, vetoed ones included, oldest first.
+    audit: Vec<GovernanceAuditEntry>,
+    /// Votes from `cast_vote`, by proposal id.
+    collected_votes: HashMap<String, CollectedVotes>,
 }
 
 impl GovernanceEngine {
+    /// Charter binds `DEFAULT_CHARTER_BOUND_MODULES`.
     pub fn new(saep: SaepEngine) -> Self {
-        let mut bound = HashSet::new();
-        bound.insert(StewardModule::PLGA);
-        bound.insert(StewardModule::MME);
-        bound.insert(StewardModule::VET);
-        bound.insert(StewardModule::OCG);
-        bound.insert(StewardModule::DCCN);
-        bound.insert(StewardModule::REBL);
-        bound.insert(StewardModule::PSM);
+        Self::with_charter_bound_modules(saep, DEFAULT_CHARTER_BOUND_MODULES)
+    }
 
+    /// Charter binds exactly `modules`; include CSC to have governance bind its own scope.
+    pub fn with_charter_bound_modules(saep: SaepEngine, modules: impl IntoIterator<Item = StewardModule>) -> Self {
         Self {
             saep,
-            charter_bound_modules: bound,
+            charter_bound_modules: modules.into_iter().collect(),
+            unbind_supermajority: CHARTER_UNBIND_FLOOR,
+            charter: CharterRegistry::default(),
+            charter_rules: CharterRuleSet::default(),
+            applied: HashMap::new(),
+            scopes: ScopeTree::new(),
+            templated: HashMap::new(),
+            proposal_limits: ProposalLimits::default(),
+            module_status: ModuleStatusRegistry::shared(),
+            audit: Vec::new(),
+            collected_votes: HashMap::new(),
+        }
+    }
+
+    /// Replace the default payload limits.
+    pub fn with_proposal_limits(mut self, limits: ProposalLimits) -> Self {
+        self.proposal_limits = limits;
+        self
+    }
+
+    pub fn proposal_limits(&self) -> &ProposalLimits {
+        &self.proposal_limits
+    }
+
+    /// `proposal`'s payload against the engine's limits; intake (e.g. an HTTP handler) can
+    /// call this on its own to answer before anything else.
+    pub fn check_proposal_limits(&self, proposal: &GovernanceProposal) -> Result<(), ProposalLimitExceeded> {
+        self.proposal_limits.check(&proposal.payload)
+    }
+
+    /// Require `share` support to unbind a module; values below `CHARTER_UNBIND_FLOOR` are raised to it.
+    pub fn with_unbind_supermajority(mut self, share: f64) -> Self {
+        self.unbind_supermajority = share.max(CHARTER_UNBIND_FLOOR);
+        self
+    }
+
+    /// Restrict modules through `status` (e.g. one shared with `PlanetaryLedger` and
+    /// `MicroMissionsEngine`) instead of a private one.
+    pub fn with_module_status(mut self, status: SharedModuleStatus) -> Self {
+        self.module_status = status;
+        self
+    }
+
+    pub fn module_status(&self) -> SharedModuleStatus {
+        self.module_status.clone()
+    }
+
+    /// Start from `scopes` (e.g. a deployment's bioregions and cities with their settings).
+    pub fn with_scope_tree(mut self, scopes: ScopeTree) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
+    pub fn scope_tree(&self) -> &ScopeTree {
+        &self.scopes
+    }
+
+    /// Register `path` under its registered parent; settings come through proposals.
+    pub fn register_scope(&mut self, path: ScopePath) -> Result<(), String> {
+        self.scopes.register(path)
+    }
+
+    /// Move `scope` and its descendants under `new_parent`, where they inherit from their new
+    /// ancestors. Audited under the moved scope's module (CSC if it names none).
+    pub fn reparent_scope(
+        &mut self,
+        scope: &ScopePath,
+        new_parent: &ScopePath,
+        by: Did,
+        now_ms: u64,
+    ) -> Result<ScopePath, String> {
+        let moved = self.scopes.reparent(scope, new_parent)?;
+        self.audit.push(GovernanceAuditEntry {
+            timestamp_ms: now_ms,
+            actor: by,
+            module: scope.module().unwrap_or(StewardModule::CSC),
+            action: GovernanceAuditAction::ReparentScope { from: scope.clone(), to: moved.clone() },
+            outcome: GovernanceAuditOutcome::Applied,
+        });
+        #[cfg(feature = "tracing")]
+        tracing::info!(from = %scope, to = %moved, "governance scope moved");
+        Ok(moved)
+    }
+
+    /// Settings in force for proposals in `scope`, inherited field by field from its ancestors.
+    pub fn effective_settings(&self, scope: &GovernanceScope) -> ScopeSettings {
+        self.scopes.effective(&scope.path())
+    }
+
+    /// Whether `voter` may vote on proposals in `scope`.
+    pub fn is_eligible_voter(&self, scope: &GovernanceScope, voter: &Did) -> bool {
+        self.effective_settings(scope).is_eligible(voter)
+    }
+
+    /// `tally_quadratic` over the votes of voters eligible in `proposal`'s scope.
+    pub fn tally_proposal(&self, proposal: &GovernanceProposal, votes: &[QuadraticVote]) -> QuadraticOutcome {
+        let settings = self.effective_settings(&proposal.scope);
+        let eligible: Vec<QuadraticVote> = votes.iter().filter(|v| settings.is_eligible(&v.voter)).cloned().collect();
+        self.tally_quadratic(&proposal.proposal_id, &eligible)
+    }
+
+    /// Collect `vote` on `proposal` and get a receipt committing to it under the voter's `salt`.
+    /// A voter may change their vote until `finalize_votes`; only the latest is counted, so
+    /// receipts for earlier ones stop verifying. Voters not eligible in the scope are refused.
+    pub fn cast_vote(
+        &mut self,
+        proposal: &GovernanceProposal,
+        vote: QuadraticVote,
+        salt: &str,
+        now_ms: u64,
+    ) -> Result<VoteReceipt, String> {
+        if !self.is_eligible_voter(&proposal.scope, &vote.voter) {
+            return Err(format!("{} is not an eligible voter in this proposal's scope", vote.voter));
+        }
+        let collected = self.collected_votes.entry(proposal.proposal_id.clone()).or_default();
+        if collected.counted.is_some() {
+            return Err(format!("Votes on proposal {} are already finalized", proposal.proposal_id));
+        }
+        let receipt = VoteReceipt::new(&proposal.proposal_id, vote.voter.as_str(), vote.support, salt, now_ms);
+        collected.ballots.insert(vote.voter.clone(), (vote, receipt.leaf()));
+        Ok(receipt)
+    }
+
+    /// Tally the votes collected for `proposal` with `tally_proposal`, and publish the leaves of
+    /// the ballots it counted; voters no longer eligible are left out of both. Refuses further
+    /// votes and a second finalization.
+    pub fn finalize_votes(&mut self, proposal: &GovernanceProposal) -> Result<QuadraticOutcome, String> {
+        let id = &proposal.proposal_id;
+        let settings = self.effective_settings(&proposal.scope);
+        let collected = self.collected_votes.entry(id.clone()).or_default();
+        if collected.counted.is_some() {
+            return Err(format!("Votes on proposal {id} are already finalized"));
+        }
+        let mut counted: Vec<(QuadraticVote, String)> =
+            collected.ballots.values().filter(|(vote, _)| settings.is_eligible(&vote.voter)).cloned().collect();
+        counted.sort_by(|a, b| a.0.voter.cmp(&b.0.voter));
+        collected.counted = Some(CountedBallots::new(id, counted.iter().map(|(_, leaf)| leaf.clone())));
+        let votes: Vec<QuadraticVote> = counted.into_iter().map(|(vote, _)| vote).collect();
+        Ok(self.tally_quadratic(id, &votes))
+    }
+
+    /// The ballots counted when `proposal_id`'s votes were finalized, for `verify_receipt_inclusion`.
+    pub fn counted_ballots(&self, proposal_id: &str) -> Option<&CountedBallots> {
+        self.collected_votes.get(proposal_id)?.counted.as_ref()
+    }
+
+    /// Every pause, read-only switch, resumption, charter change and scope move attempted here,
+    /// including vetoed ones.
+    pub fn audit_trail(&self) -> &[GovernanceAuditEntry] {
+        &self.audit
+    }
+
+    /// In `StewardModule` order.
+    pub fn charter_bound_modules(&self) -> Vec<StewardModule> {
+        let mut modules: Vec<StewardModule> = self.charter_bound_modules.iter().copied().collect();
+        modules.sort();
+        modules
+    }
+
+    pub fn is_charter_bound(&self, module: StewardModule) -> bool {
+        self.charter_bound_modules.contains(&module)
+    }
+
+    /// Charter versions adopted so far; starts from `default_charter`.
+    pub fn charter(&self) -> &CharterRegistry {
+        &self.charter
+    }
+
+    pub fn charter_rules(&self) -> &CharterRuleSet {
+        &self.charter_rules
+    }
+
+    /// Enforce `rules` from now on; refused unless every rule cites a clause of the active
+    /// charter version.
+    pub fn set_charter_rules(&mut self, rules: CharterRuleSet) -> Result<(), CharterError> {
+        let active = &self.charter.active().document;
+        if let Some(orphan) = rules.orphaned_by(active).into_iter().next() {
+            let version = active.version;
+            return Err(CharterError::UnknownClause { rule: orphan.rule, clause: orphan.clause, version });
+        }
+        self.charter_rules = rules;
+        Ok(())
+    }
+
+    /// Bind `module` to the charter. Always allowed; binding a bound module changes nothing
+    /// and is not audited. Returns whether the set changed.
+    pub fn bind_module(&mut self, module: StewardModule, by: Did, now_ms: u64) -> bool {
+        let before_hash = charter_set_hash(&self.charter_bound_modules);
+        if !self.charter_bound_modules.insert(module) {
+            return false;
+        }
+        self.audit.push(GovernanceAuditEntry {
+            timestamp_ms: now_ms,
+            actor: by,
+            module,
+            action: GovernanceAuditAction::BindCharter {
+                before_hash,
+                after_hash: charter_set_hash(&self.charter_bound_modules),
+            },
+            outcome: GovernanceAuditOutcome::Applied,
+        });
+        #[cfg(feature = "tracing")]
+        tracing::info!(module = ?module, "module bound to charter");
+        true
+    }
+
+    /// Release `module` from the charter under `authorization`: an applied, ecosystem-wide
+    /// proposal naming `module` under `UNBIND_MODULE_KEY`, passed with the unbind supermajority
+    /// and not used before. Refusals are audited as vetoed.
+    pub fn unbind_module(
+        &mut self,
+        module: StewardModule,
+        authorization: AppliedProposalRef,
+        by: Did,
+        now_ms: u64,
+    ) -> Result<(), CharterError> {
+        let before_hash = charter_set_hash(&self.charter_bound_modules);
+        if let Err(e) = self.check_unbind(module, &authorization) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "charter_authorization",
+                module = ?module,
+                "charter unbinding refused"
+            );
+            self.audit.push(GovernanceAuditEntry {
+                timestamp_ms: now_ms,
+                actor: by,
+                module,
+                action: GovernanceAuditAction::UnbindCharter {
+                    authorization,
+                    after_hash: before_hash.clone(),
+                    before_hash,
+                },
+                outcome: GovernanceAuditOutcome::Vetoed { reasons: vec![e.to_string()], citations: vec![] },
+            });
+            return Err(e);
+        }
+        self.charter_bound_modules.remove(&module);
+        if let Some(applied) = self.applied.get_mut(&authorization.proposal_id) {
+            applied.spent = true;
+        }
+        self.audit.push(GovernanceAuditEntry {
+            timestamp_ms: now_ms,
+            actor: by,
+            module,
+            action: GovernanceAuditAction::UnbindCharter {
+                authorization,
+                before_hash,
+                after_hash: charter_set_hash(&self.charter_bound_modules),
+            },
+            outcome: GovernanceAuditOutcome::Applied,
+        });
+        #[cfg(feature = "tracing")]
+        tracing::info!(decision = "allow", module = ?module, "module unbound from charter");
+        Ok(())
+    }
+
+    fn check_unbind(&self, module: StewardModule, authorization: &AppliedProposalRef) -> Result<(), CharterError> {
+        let proposal_id = authorization.proposal_id.clone();
+        if !self.charter_bound_modules.contains(&module) {
+            return Err(CharterError::NotBound { module });
+        }
+        let Some(applied) = self.applied.get(&proposal_id) else {
+            return Err(CharterError::UnknownProposal { proposal_id });
+        };
+        if !applied.proposal.scope.path().is_root() {
+            return Err(CharterError::NotEcosystemWide { proposal_id });
+        }
+        let support_share = applied.support_share();
+        if support_share < self.unbind_supermajority {
+            return Err(CharterError::InsufficientMajority {
+                proposal_id,
+                support_share,
+                required: self.unbind_supermajority,
+            });
+        }
+        let named = applied.unbind_target();
+        if named != Some(module) {
+            return Err(CharterError::WrongModule { proposal_id, module, named });
+        }
+        if applied.spent {
+            return Err(CharterError::AuthorizationSpent { proposal_id });
+        }
+        Ok(())
+    }
+
+    /// Record `proposal` as applied if `can_apply_proposal` passes it on `outcome`, and make
+    /// the `ConfigChange` its payload carries, if any. Proposal ids are unique; the returned
+    /// reference can authorize an unbinding. Charter rules a newly adopted charter version
+    /// orphans are listed on the applied proposal.
+    pub fn apply_proposal(
+        &mut self,
+        proposal: GovernanceProposal,
+        outcome: QuadraticOutcome,
+        now_ms: u64,
+    ) -> Result<AppliedProposalRef, String> {
+        if outcome.proposal_id != proposal.proposal_id {
+            return Err(Reason::new(ReasonCode::GovernanceOutcomeMismatch)
+                .with("outcome", &outcome.proposal_id)
+                .with("proposal", &proposal.proposal_id)
+                .into());
+        }
+        if self.applied.contains_key(&proposal.proposal_id) {
+            return Err(Reason::new(ReasonCode::GovernanceAlreadyApplied).with("proposal", &proposal.proposal_id).into());
+        }
+        if !self.can_apply_proposal(&proposal, &outcome)? {
+            return Err(Reason::new(ReasonCode::GovernanceNotPassed).with("proposal", &proposal.proposal_id).into());
+        }
+        let reference = AppliedProposalRef { proposal_id: proposal.proposal_id.clone() };
+        let mut orphaned_rules = Vec::new();
+        match ConfigChange::from_payload(&proposal.payload)? {
+            Some(ConfigChange::AdoptCharter { document }) => {
+                orphaned_rules = self.adopt_charter(document, &reference, now_ms);
+            }
+            Some(change) => self.apply_change(&change),
+            None => {}
+        }
+        self.applied.insert(
+            proposal.proposal_id.clone(),
+            AppliedProposal { proposal, outcome, applied_ms: now_ms, spent: false, orphaned_rules },
+        );
+        Ok(reference)
+    }
+
+    pub fn applied_proposal(&self, reference: &AppliedProposalRef) -> Option<&AppliedProposal> {
+        self.applied.get(&reference.proposal_id)
+    }
+
+    /// SAEP settings governance evaluates proposals under; changed by applied `ConfigChange`s.
+    pub fn saep_config(&self) -> &SaepConfig {
+        self.saep.config()
+    }
+
+    /// Least total vote weight a proposal in `scope` needs, as set there or inherited; 0 when
+    /// unset all the way up.
+    pub fn quorum(&self, scope: &GovernanceScope) -> f64 {
+        self.effective_settings(scope).quorum.unwrap_or(0.0)
+    }
+
+    /// Build a proposal from the template `template_id` (`ProposalTemplate::id`) and `params`.
+    /// Parameters are checked, the change must alter the current settings, and the payload's
+    /// hash is recorded: `can_apply_proposal` refuses the proposal if the payload is edited.
+    /// Ids are `<template_id>-<n>`, numbered per engine.
+    pub fn instantiate_template(&mut self, template_id: &str, params: &serde_json::Value) -> Result<GovernanceProposal, String> {
+        let template = ProposalTemplate::from_id(template_id)
+            .ok_or_else(|| format!("Unknown proposal template {template_id}"))?;
+        let change = template.change(params)?;
+        self.check_effective(&change)?;
+        let (title, description) = change.describe();
+        let payload = change.to_payload();
+        let proposal_id = format!("{template_id}-{}", self.templated.len() + 1);
+        self.templated.insert(
+            proposal_id.clone(),
+            TemplateProvenance { template_id: template_id.into(), payload_hash: payload_hash(&payload) },
+        );
+        Ok(GovernanceProposal {
+            proposal_id,
+            scope: change.scope(),
+            title,
+            description,
+            payload,
+            can_introduce_restrictions: change.introduces_restrictions(),
+        })
+    }
+
+    pub fn template_provenance(&self, proposal_id: &str) -> Option<&TemplateProvenance> {
+        self.templated.get(proposal_id)
+    }
+
+    /// A change that leaves the current settings as they are cannot be proposed.
+    fn check_effective(&self, change: &ConfigChange) -> Result<(), String> {
+        let config = self.saep.config();
+        let unchanged = match change {
+            ConfigChange::ToggleEnforcementFlag { flag, value } => flag.get(config) == *value,
+            ConfigChange::SetModuleOverride { module, flag, value } => flag.get(&config.for_module(*module)) == *value,
+            ConfigChange::UpdateQuorum { scope, new_quorum } => self.quorum(scope) == *new_quorum,
+            ConfigChange::SetScopeSettings { scope, settings } => {
+                self.scopes.settings(&scope.path()).cloned().unwrap_or_default() == *settings
+            }
+            ConfigChange::AdoptCharter { document } => self.charter.active().document == *document,
+        };
+        if unchanged {
+            return Err(format!("Change would leave settings unchanged: {}", change.describe().0));
+        }
+        Ok(())
+    }
+
+    fn apply_change(&mut self, change: &ConfigChange) {
+        let config = &mut self.saep.config;
+        match change {
+            ConfigChange::ToggleEnforcementFlag { flag, value } => {
+                flag.set(config, *value);
+                self.saep.clear_cache();
+            }
+            ConfigChange::SetModuleOverride { module, flag, value } => {
+                config.module_overrides.entry(*module).or_default().insert(*flag, *value);
+                self.saep.clear_cache();
+            }
+            ConfigChange::UpdateQuorum { scope, new_quorum } => {
+                if let Some(own) = self.scopes.settings_mut(scope) {
+                    own.quorum = Some(*new_quorum);
+                }
+            }
+            ConfigChange::SetScopeSettings { scope, settings } => {
+                if let Some(own) = self.scopes.settings_mut(scope) {
+                    *own = settings.clone();
+                }
+            }
+            // Needs the proposal; see `adopt_charter`.
+            ConfigChange::AdoptCharter { .. } => {}
+        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(change = ?change, "governance change applied");
+    }
+
+    /// Make `document` the active charter version under `authorization`, which adopts its new
+    /// and reworded clauses. Audited under CSC with the document hashes; returns the rules
+    /// citing clauses it no longer has.
+    fn adopt_charter(
+        &mut self,
+        document: CharterDocument,
+        authorization: &AppliedProposalRef,
+        now_ms: u64,
+    ) -> Vec<OrphanedCharterRule> {
+        let active = self.charter.active();
+        let before_hash = active.document_hash.clone();
+        let document = document.stamped(&active.document, &authorization.proposal_id);
+        let orphaned = self.charter_rules.orphaned_by(&document);
+        let version = document.version;
+        let after_hash = document.document_hash();
+        self.charter.push(CharterVersion {
+            document,
+            document_hash: after_hash.clone(),
+            adopted_via: Some(authorization.proposal_id.clone()),
+            adopted_ms: now_ms,
+        });
+        self.audit.push(GovernanceAuditEntry {
+            timestamp_ms: now_ms,
+            actor: Did("did:psv:governance:collective".into()),
+            module: StewardModule::CSC,
+            action: GovernanceAuditAction::AdoptCharter {
+                version,
+                authorization: authorization.clone(),
+                before_hash,
+                after_hash,
+            },
+            outcome: GovernanceAuditOutcome::Applied,
+        });
+        #[cfg(feature = "tracing")]
+        if !orphaned.is_empty() {
+            tracing::warn!(version, orphaned = ?orphaned, "charter version orphans rules");
+        }
+        orphaned
+    }
+
+    /// Emergency stop: refuse new writes in `module` until `resume_module` names the returned id.
+    /// Pausing restricts participants, so it must pass SAEP and the charter first. Only through
+    /// `AdminAction::PauseModule`.
+    fn pause_module(
+        &mut self,
+        module: StewardModule,
+        reason: String,
+        paused_by: Did,
+        now_ms: u64,
+    ) -> Result<PauseId, String> {
+        self.restrict_module(module, reason, paused_by, now_ms, false)
+    }
+
+    /// Planned freeze with the same effect and checks as `pause_module`; only through
+    /// `AdminAction::SetModuleReadOnly`.
+    fn set_module_read_only(
+        &mut self,
+        module: StewardModule,
+        reason: String,
+        set_by: Did,
+        now_ms: u64,
+    ) -> Result<PauseId, String> {
+        self.restrict_module(module, reason, set_by, now_ms, true)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "governance.restrict_module", level = "info", skip(self, reason),
+        fields(actor_did = %by, module = ?module),
+    ))]
+    fn restrict_module(
+        &mut self,
+        module: StewardModule,
+        reason: String,
+        by: Did,
+        now_ms: u64,
+        read_only: bool,
+    ) -> Result<PauseId, String> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        if reason.trim().is_empty() {
+            return Err("A module restriction needs a reason".into());
+        }
+        let mut status = self
+            .module_status
+            .lock()
+            .map_err(|_| "Module status lock poisoned".to_string())?;
+        if let Some(current) = status.status(module).pause_id() {
+            return Err(format!("Module {module:?} is already restricted by {current}; resume it first"));
+        }
+
+        let kind = if read_only { "read-only" } else { "pause" };
+        let id = PauseId(format!("{kind}:{module:?}:{now_ms}:{}", self.audit.len()));
+        let ctx = EthicsContext {
+            actor: by.clone(),
+            affected_parties: vec![],
+            module,
+            description: reason.clone(),
+            estimated_impact: serde_json::json!({ "restriction": kind }),
+        };
+        let decision = self.saep.evaluate(&ctx);
+        let mut reasons = decision.reasons;
+        let citations: Vec<CharterCitation> = self.charter_violation(module, &reason).into_iter().collect();
+        for citation in &citations {
+            let charter = Reason::new(ReasonCode::SaepCharter)
+                .with("module", format!("{module:?}"))
+                .with("clause", &citation.clause)
+                .with("excerpt", &citation.excerpt);
+            reasons.push(charter.to_string());
+        }
+        let (action, state) = if read_only {
+            (
+                GovernanceAuditAction::ReadOnly { id: id.clone(), reason: reason.clone() },
+                ModuleState::ReadOnly { id: id.clone(), reason, set_by: by.clone(), since_ms: now_ms },
+            )
+        } else {
+            (
+                GovernanceAuditAction::Pause { id: id.clone(), reason: reason.clone() },
+                ModuleState::Paused { id: id.clone(), reason, paused_by: by.clone(), since_ms: now_ms },
+            )
+        };
+
+        if !decision.allowed || !reasons.is_empty() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "saep",
+                rules = ?reasons,
+                duration_us = started.elapsed().as_micros() as u64,
+                "module restriction vetoed"
+            );
+            let err = format!("Ethics-kernel vetoed restricting module {module:?}: {reasons:?}");
+            self.audit.push(GovernanceAuditEntry {
+                timestamp_ms: now_ms,
+                actor: by,
+                module,
+                action,
+                outcome: GovernanceAuditOutcome::Vetoed { reasons, citations },
+            });
+            return Err(err);
+        }
+
+        status.set(module, state);
+        self.audit.push(GovernanceAuditEntry {
+            timestamp_ms: now_ms,
+            actor: by,
+            module,
+            action,
+            outcome: GovernanceAuditOutcome::Applied,
+        });
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            decision = "allow",
+            pause_id = %id,
+            duration_us = started.elapsed().as_micros() as u64,
+            "module restricted"
+        );
+        Ok(id)
+    }
+
+    /// Lift the restriction `pause_id` from `module`; fails if a different one (or none) is in force.
+    /// Only through `AdminAction::ResumeModule`.
+    fn resume_module(
+        &mut self,
+        module: StewardModule,
+        pause_id: &PauseId,
+        resumed_by: Did,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        let mut status = self
+            .module_status
+            .lock()
+            .map_err(|_| "Module status lock poisoned".to_string())?;
+        match status.status(module).pause_id() {
+            Some(current) if current == pause_id => {}
+            Some(current) => {
+                return Err(format!("Module {module:?} is restricted by {current}, not {pause_id}"));
+            }
+            None => return Err(format!("Module {module:?} is not restricted")),
         }
+        status.set(module, ModuleState::Active);
+        self.audit.push(GovernanceAuditEntry {
+            timestamp_ms: now_ms,
+            actor: resumed_by,
+            module,
+            action: GovernanceAuditAction::Resume { id: pause_id.clone() },
+            outcome: GovernanceAuditOutcome::Applied,
+        });
+        #[cfg(feature = "tracing")]
+        tracing::info!(module = ?module, pause_id = %pause_id, "module resumed");
+        Ok(())
+    }
+
+    /// The clause a charter rule enforces against `text` in `module`, if `module` is
+    /// charter-bound and some rule's term is in `text`. [web:16]
+    fn charter_violation(&self, module: StewardModule, text: &str) -> Option<CharterCitation> {
+        if !self.charter_bound_modules.contains(&module) {
+            return None;
+        }
+        let rule = self.charter_rules.rule_for(text)?;
+        Some(self.charter.cite(&rule.clause).unwrap_or_else(|| CharterCitation {
+            clause: rule.clause.clone(),
+            version: self.charter.active().document.version,
+            excerpt: String::new(),
+        }))
+    }
+
+    /// The clause a `check_proposal` refusal enforced, if it was a charter veto.
+    pub fn charter_citation(&self, reason: &Reason) -> Option<CharterCitation> {
+        if reason.code != ReasonCode::GovernanceCharter {
+            return None;
+        }
+        let clause = reason.params.get("clause")?;
+        self.charter.cite(clause)
     }
 
     pub fn tally_quadratic(&self, proposal_id: &str, votes: &[QuadraticVote]) -> QuadraticOutcome {
@@ -447,34 +3692,150 @@ impl GovernanceEngine {
     }
 
     /// Core guard: even if governance supports a proposal, SAEP + charter must pass.
+    /// Payloads over the engine's `ProposalLimits` are refused before anything else.
+    /// Proposals scoped to a paused or read-only module are refused with the `ModulePaused` message,
+    /// as are malformed `ConfigChange` payloads and template proposals edited since instantiation.
+    /// Proposals or changes targeting an unregistered scope path are refused. Below the scope's
+    /// effective quorum a proposal does not pass, and restrictive proposals may not mention the
+    /// scope's effective charter terms. A charter adoption must be newer than the active version.
     pub fn can_apply_proposal(
         &self,
         proposal: &GovernanceProposal,
         outcome: &QuadraticOutcome,
     ) -> Result<bool, String> {
+        self.check_proposal(proposal, outcome).map_err(String::from)
+    }
+
+    /// `can_apply_proposal`, refusing with the reason's code and parameters.
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "governance.can_apply_proposal", level = "info", skip_all,
+        fields(
+            proposal_id = %proposal.proposal_id,
+            actor_did = "did:psv:governance:collective",
+            module = tracing::field::Empty,
+        ),
+    ))]
+    pub fn check_proposal(
+        &self,
+        proposal: &GovernanceProposal,
+        outcome: &QuadraticOutcome,
+    ) -> Result<bool, Reason> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        if let Err(e) = self.check_proposal_limits(proposal) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "proposal_limit",
+                limit = %e.limit,
+                measured = e.measured,
+                duration_us = started.elapsed().as_micros() as u64,
+                "proposal not applicable"
+            );
+            return Err(e.reason());
+        }
+
+        let module = proposal.scope.module();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("module", tracing::field::debug(&module));
+
+        // Proposals do not land in a paused or read-only module.
+        if let Err(e) = module_status::check_writable(&self.module_status, module) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "module_paused",
+                duration_us = started.elapsed().as_micros() as u64,
+                "proposal not applicable"
+            );
+            return Err(e);
+        }
+
+        // Payloads are checked before the vote counts: a template proposal must be unedited,
+        // and any change payload must parse.
+        if let Some(provenance) = self.templated.get(&proposal.proposal_id) {
+            if payload_hash(&proposal.payload) != provenance.payload_hash {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "template_payload_edited",
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "proposal not applicable"
+                );
+                return Err(Reason::new(ReasonCode::GovernanceTemplateEdited)
+                    .with("proposal", &proposal.proposal_id)
+                    .with("template", &provenance.template_id));
+            }
+        }
+        let change = match ConfigChange::from_payload(&proposal.payload) {
+            Ok(change) => change,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "malformed_change",
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "proposal not applicable"
+                );
+                return Err(validation::detail(ReasonCode::GovernanceMalformedChange)(e));
+            }
+        };
+        let mut targets = std::iter::once(proposal.scope.clone()).chain(change.as_ref().map(ConfigChange::scope));
+        if let Some(unknown) = targets.find(|scope| !self.scopes.knows(scope)) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "unknown_scope",
+                duration_us = started.elapsed().as_micros() as u64,
+                "proposal not applicable"
+            );
+            return Err(Reason::new(ReasonCode::GovernanceUnknownScope).with("scope", unknown.path()));
+        }
+        if let Some(ConfigChange::AdoptCharter { document }) = &change {
+            let active = self.charter.active().document.version;
+            if document.version <= active {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "stale_charter_version",
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "proposal not applicable"
+                );
+                return Err(Reason::new(ReasonCode::GovernanceCharterVersion)
+                    .with("version", document.version)
+                    .with("active", active));
+            }
+        }
+
+        let quorum = self.quorum(&proposal.scope);
+        if outcome.total_support + outcome.total_opposition < quorum {
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                decision = "deny",
+                reason = "quorum",
+                quorum,
+                duration_us = started.elapsed().as_micros() as u64,
+                "proposal not applicable"
+            );
+            return Ok(false);
+        }
+
         // Basic quadratic consensus heuristic.
         if outcome.total_support <= outcome.total_opposition {
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                decision = "deny",
+                reason = "quadratic_support",
+                total_support = outcome.total_support,
+                total_opposition = outcome.total_opposition,
+                duration_us = started.elapsed().as_micros() as u64,
+                "proposal not applicable"
+            );
             return Ok(false);
         }
 
         // Apply SAEP to the governance action itself.
-        let module = match &proposal.scope {
-            GovernanceScope::Module(mid) => {
-                match mid.0.as_str() {
-                    "PLGA" => StewardModule::PLGA,
-                    "MME" => StewardModule::MME,
-                    "VET" => StewardModule::VET,
-                    "OCG" => StewardModule::OCG,
-                    "DCCN" => StewardModule::DCCN,
-                    "REBL" => StewardModule::REBL,
-                    "PSM" => StewardModule::PSM,
-                    "CSC" => StewardModule::CSC,
-                    _      => StewardModule::CSC,
-                }
-            }
-            GovernanceScope::EcosystemWide => StewardModule::CSC,
-        };
-
         let ctx = EthicsContext {
             actor: Did("did:psv:governance:collective".into()),
             affected_parties: vec![],
@@ -486,18 +3847,57 @@ impl GovernanceEngine {
         let decision = self.saep.evaluate(&ctx);
         if !decision.allowed {
             // This is your “ethics-kernel-triggered veto” – no human kingmaking. [web:18]
-            return Err(format!("Ethics-kernel vetoed governance proposal: {:?}", decision.reasons));
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "saep",
+                rules = ?decision.reasons,
+                duration_us = started.elapsed().as_micros() as u64,
+                "proposal vetoed"
+            );
+            return Err(Reason::new(ReasonCode::GovernanceEthicsVeto)
+                .with("rules", format!("{:?}", decision.reasons))
+                .with("codes", decision.rule_codes()));
         }
 
         // Co-stewardship charter binding: no weaponization or extractive shifts. [web:16]
-        if proposal.can_introduce_restrictions && self.charter_bound_modules.contains(&module) {
+        if proposal.can_introduce_restrictions {
             // Require that payload explicitly documents non-military, non-extractive use.
-            let text = proposal.description.to_lowercase();
-            if text.contains("weapon") || text.contains("military") {
-                return Err("CSC: disallows militarization or harmful use in charter-bound modules.".into());
+            if let Some(citation) = self.charter_violation(module, &proposal.description) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "charter",
+                    clause = %citation.clause,
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "proposal vetoed"
+                );
+                return Err(Reason::new(ReasonCode::GovernanceCharter)
+                    .with("module", format!("{module:?}"))
+                    .with("clause", citation.clause)
+                    .with("excerpt", citation.excerpt));
+            }
+            let settings = self.effective_settings(&proposal.scope);
+            if let Some(term) = settings.charter_term_in(&proposal.description) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "scope_charter",
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "proposal vetoed"
+                );
+                return Err(Reason::new(ReasonCode::GovernanceScopeCharter)
+                    .with("scope", proposal.scope.path())
+                    .with("term", term));
             }
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            decision = "allow",
+            duration_us = started.elapsed().as_micros() as u64,
+            "proposal applicable"
+        );
         Ok(true)
     }
 }
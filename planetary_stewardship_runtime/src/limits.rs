@@ -0,0 +1,143 @@
+// path: planetary_stewardship_runtime/src/limits.rs
+
+//! Size limits on governance proposal payloads.
+//! - Checked first by `can_apply_proposal` (and so `apply_proposal`), before the payload is
+//!   hashed, parsed, cloned or shown to SAEP
+//! - Bytes are counted through a writer that keeps nothing, so an oversized payload is never
+//!   serialized into memory; depth is only walked (with an explicit stack) once the payload
+//!   is known to be within the byte limit
+//! - A payload that is a JSON array (a JSON Patch document) counts one operation per
+//!   element; any other payload counts as one
+//! - Defaults are generous for config changes and patches; deployments tighten or widen
+//!   them with `GovernanceEngine::with_proposal_limits`
+
+use serde::{Serialize, Deserialize};
+use std::fmt;
+use std::io;
+
+use crate::{Reason, ReasonCode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalLimit {
+    PayloadBytes,
+    PayloadDepth,
+    PatchOperations,
+}
+
+impl fmt::Display for ProposalLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ProposalLimit::PayloadBytes => "payload_bytes",
+            ProposalLimit::PayloadDepth => "payload_depth",
+            ProposalLimit::PatchOperations => "patch_operations",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProposalLimits {
+    /// Serialized (compact JSON) payload size.
+    pub max_payload_bytes: usize,
+    /// Nesting of arrays and objects; a scalar payload has depth 0.
+    pub max_payload_depth: usize,
+    pub max_patch_operations: usize,
+}
+
+impl Default for ProposalLimits {
+    fn default() -> Self {
+        Self {
+            max_payload_bytes: 256 * 1024,
+            max_payload_depth: 32,
+            max_patch_operations: 1_000,
+        }
+    }
+}
+
+/// The first limit a payload broke, with the payload's measured value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProposalLimitExceeded {
+    pub limit: ProposalLimit,
+    pub measured: usize,
+    pub max: usize,
+}
+
+impl ProposalLimitExceeded {
+    pub fn reason(&self) -> Reason {
+        Reason::new(ReasonCode::ProposalLimitExceeded)
+            .with("limit", self.limit)
+            .with("measured", self.measured)
+            .with("max", self.max)
+    }
+}
+
+impl fmt::Display for ProposalLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reason().fmt(f)
+    }
+}
+
+impl std::error::Error for ProposalLimitExceeded {}
+
+impl ProposalLimits {
+    /// Patch operations, then bytes, then depth: each check bounds the next one's work.
+    pub fn check(&self, payload: &serde_json::Value) -> Result<(), ProposalLimitExceeded> {
+        let exceeded = |limit, measured, max| Err(ProposalLimitExceeded { limit, measured, max });
+        let operations = match payload {
+            serde_json::Value::Array(ops) => ops.len(),
+            _ => 1,
+        };
+        if operations > self.max_patch_operations {
+            return exceeded(ProposalLimit::PatchOperations, operations, self.max_patch_operations);
+        }
+        let bytes = payload_bytes(payload);
+        if bytes > self.max_payload_bytes {
+            return exceeded(ProposalLimit::PayloadBytes, bytes, self.max_payload_bytes);
+        }
+        let depth = payload_depth(payload);
+        if depth > self.max_payload_depth {
+            return exceeded(ProposalLimit::PayloadDepth, depth, self.max_payload_depth);
+        }
+        Ok(())
+    }
+}
+
+/// Compact JSON size, counted without serializing into memory.
+pub fn payload_bytes(payload: &serde_json::Value) -> usize {
+    let mut counter = ByteCounter(0);
+    serde_json::to_writer(&mut counter, payload).expect("counting writer never fails");
+    counter.0
+}
+
+/// Nesting of arrays and objects: `{}` and `{"a": 1}` are 1, `{"a": []}` is 2.
+pub fn payload_depth(payload: &serde_json::Value) -> usize {
+    let mut deepest = 0;
+    // Each value with the number of containers enclosing it.
+    let mut stack = vec![(payload, 0usize)];
+    while let Some((value, enclosing)) = stack.pop() {
+        let children: Vec<&serde_json::Value> = match value {
+            serde_json::Value::Array(items) => items.iter().collect(),
+            serde_json::Value::Object(fields) => fields.values().collect(),
+            _ => continue,
+        };
+        deepest = deepest.max(enclosing + 1);
+        stack.extend(children.into_iter().map(|child| (child, enclosing + 1)));
+    }
+    deepest
+}
+
+struct ByteCounter(usize);
+
+impl io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
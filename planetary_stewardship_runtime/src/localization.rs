@@ -0,0 +1,125 @@
+// path: planetary_stewardship_runtime/src/localization.rs
+
+//! Language-tagged mission template content.
+//! - A `MissionTemplate`'s own `title` / `description` are in its `default_language`;
+//!   `localized_content` adds other languages without splitting the template (or its stats)
+//! - `MissionTemplate::resolve` picks the exact tag, then the bare language (`es` for `es-MX`),
+//!   then the default strings
+//! - `validate` rejects localizations with a title but no description (or the reverse)
+//! - SAEP reads the assignee's preferred language at assignment; consent prompts keep the
+//!   default strings, so switching language does not invalidate consent
+//! - Bundles load from TOML (feature `toml`), localized content as `[template.localized_content.<tag>]`
+
+use serde::{Serialize, Deserialize};
+
+use crate::{LanguageTag, MissionId, MissionTemplate};
+
+/// Title and description in one language.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LocalizedTemplate {
+    #[serde(default)]
+    pub title: String,
+    /// Markdown, like `MissionTemplate::description`.
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A template's user-facing strings in the language that was served.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolvedTemplate {
+    pub mission_id: MissionId,
+    /// Language of `title` / `description`; `None` when the default strings were
+    /// served and the template names no default language.
+    #[serde(default)]
+    pub language: Option<LanguageTag>,
+    pub title: String,
+    pub description: String,
+    /// The requested language was not available.
+    #[serde(default)]
+    pub fallback: bool,
+}
+
+impl MissionTemplate {
+    /// Every localization needs both strings, and may not restate the default language.
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.localized_content.is_empty() && self.default_language.is_none() {
+            return Err(format!("Mission template {} has localized content but no default_language", self.id));
+        }
+        let mut tags: Vec<&LanguageTag> = self.localized_content.keys().collect();
+        tags.sort();
+        for tag in tags {
+            if Some(tag) == self.default_language.as_ref() {
+                return Err(format!(
+                    "Mission template {} localizes its default language {tag}; edit title and description instead",
+                    self.id
+                ));
+            }
+            let content = &self.localized_content[tag];
+            match (content.title.trim().is_empty(), content.description.trim().is_empty()) {
+                (false, false) => {}
+                (false, true) => {
+                    return Err(format!("Mission template {} has a title but no description in {tag}", self.id));
+                }
+                (true, false) => {
+                    return Err(format!("Mission template {} has a description but no title in {tag}", self.id));
+                }
+                (true, true) => return Err(format!("Mission template {} has empty {tag} content", self.id)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Strings for `lang`: exact tag, then its bare language, then the default.
+    /// `None` asks for the default.
+    pub fn resolve(&self, lang: Option<&LanguageTag>) -> ResolvedTemplate {
+        let default = || ResolvedTemplate {
+            mission_id: self.id.clone(),
+            language: self.default_language.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            fallback: false,
+        };
+        let Some(lang) = lang else {
+            return default();
+        };
+        if Some(lang) == self.default_language.as_ref() {
+            return default();
+        }
+        let primary = lang.primary();
+        let found = self
+            .localized_content
+            .get_key_value(lang)
+            .or_else(|| self.localized_content.get_key_value(&primary));
+        match found {
+            Some((tag, content)) => ResolvedTemplate {
+                mission_id: self.id.clone(),
+                language: Some(tag.clone()),
+                title: content.title.clone(),
+                description: content.description.clone(),
+                fallback: false,
+            },
+            None if self.default_language.as_ref().map(LanguageTag::primary) == Some(primary) => default(),
+            None => ResolvedTemplate { fallback: true, ..default() },
+        }
+    }
+}
+
+/// TOML layout: one `[[template]]` table per mission template.
+#[cfg(feature = "toml")]
+#[derive(Deserialize)]
+struct TemplateBundle {
+    #[serde(default)]
+    template: Vec<MissionTemplate>,
+}
+
+/// Parse and validate mission templates from a TOML document.
+#[cfg(feature = "toml")]
+pub fn templates_from_toml(source: &str) -> Result<Vec<MissionTemplate>, String> {
+    let bundle: TemplateBundle = toml::from_str(source).map_err(|e| format!("Invalid mission template TOML: {e}"))?;
+    for template in &bundle.template {
+        template.validate()?;
+    }
+    Ok(bundle.template)
+}
@@ -0,0 +1,316 @@
+// path: planetary_stewardship_runtime/src/metric_schema.rs
+
+//! Per-module metric schemas, so modules other than PLGA can issue attestations.
+//! - A `MetricSchema` names a module's metric fields: their kind, bounds, unit, whether a
+//!   claim must carry them, and how summaries aggregate them
+//! - PLGA's schema is the four headline fields of `ImpactMetrics`, registered on every ledger
+//!   and fixed; its attestations keep carrying typed `ImpactMetrics`
+//! - Other modules' attestations carry `ModuleMetrics`, checked against their schema at
+//!   issuance: unknown fields, missing required ones, wrong kinds and values out of bounds
+//!   are refused
+//! - MME has no schema: its records are assignments, kept by `MicroMissionsEngine`
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::{ImpactMetrics, StewardModule};
+
+/// A module's metric values, by field name.
+pub type ModuleMetrics = BTreeMap<String, MetricFieldValue>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MetricFieldKind {
+    /// Any finite number; whole numbers are accepted too.
+    Number,
+    Integer,
+    Flag,
+}
+
+/// How `impact_summary` totals a field over a module's attestations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MetricAggregation {
+    /// A flag counts as 1 when set.
+    #[default]
+    Sum,
+    Max,
+    Min,
+    /// Attestations carrying the field.
+    Count,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricField {
+    pub name: String,
+    pub kind: MetricFieldKind,
+    #[serde(default)]
+    pub required: bool,
+    /// Inclusive bounds; flags take none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub unit: String,
+    #[serde(default)]
+    pub aggregation: MetricAggregation,
+}
+
+impl MetricField {
+    /// An optional field, summed, without bounds or unit.
+    pub fn new(name: &str, kind: MetricFieldKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            required: false,
+            min: None,
+            max: None,
+            unit: String::new(),
+            aggregation: MetricAggregation::Sum,
+        }
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    pub fn with_aggregation(mut self, aggregation: MetricAggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    fn check(&self, value: &MetricFieldValue) -> Result<(), String> {
+        let fits = matches!(
+            (self.kind, value),
+            (MetricFieldKind::Number, MetricFieldValue::Number(_) | MetricFieldValue::Integer(_))
+                | (MetricFieldKind::Integer, MetricFieldValue::Integer(_))
+                | (MetricFieldKind::Flag, MetricFieldValue::Flag(_))
+        );
+        if !fits {
+            return Err(format!("Metric {} must be {:?}, not {value:?}", self.name, self.kind));
+        }
+        let n = value.as_f64();
+        if !n.is_finite() || self.min.is_some_and(|min| n < min) || self.max.is_some_and(|max| n > max) {
+            let bound = |b: Option<f64>| b.map_or("..".to_string(), |b| b.to_string());
+            return Err(format!("Metric {} = {n} is outside [{}, {}]", self.name, bound(self.min), bound(self.max)));
+        }
+        Ok(())
+    }
+}
+
+/// One metric value; JSON numbers without a fraction read as `Integer`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum MetricFieldValue {
+    Flag(bool),
+    Integer(i64),
+    Number(f64),
+}
+
+impl MetricFieldValue {
+    /// The value as a number; a set flag is 1.
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            MetricFieldValue::Flag(set) => f64::from(u8::from(set)),
+            MetricFieldValue::Integer(n) => n as f64,
+            MetricFieldValue::Number(n) => n,
+        }
+    }
+}
+
+impl From<f64> for MetricFieldValue {
+    fn from(n: f64) -> Self {
+        MetricFieldValue::Number(n)
+    }
+}
+
+impl From<i64> for MetricFieldValue {
+    fn from(n: i64) -> Self {
+        MetricFieldValue::Integer(n)
+    }
+}
+
+impl From<i32> for MetricFieldValue {
+    fn from(n: i32) -> Self {
+        MetricFieldValue::Integer(n.into())
+    }
+}
+
+impl From<bool> for MetricFieldValue {
+    fn from(set: bool) -> Self {
+        MetricFieldValue::Flag(set)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricSchema {
+    pub module: StewardModule,
+    pub fields: Vec<MetricField>,
+}
+
+impl MetricSchema {
+    pub fn new(module: StewardModule, fields: Vec<MetricField>) -> Self {
+        Self { module, fields }
+    }
+
+    /// The headline fields of `ImpactMetrics`; see `ImpactMetrics::to_module_metrics`.
+    pub fn plga() -> Self {
+        let number = |name| MetricField::new(name, MetricFieldKind::Number).required();
+        Self::new(
+            StewardModule::PLGA,
+            vec![
+                number("co2eq_reduced").with_unit("t"),
+                number("biodiversity_index_delta"),
+                number("restored_area_m2").with_unit("m2"),
+                number("avoided_emissions_co2eq").with_unit("t"),
+            ],
+        )
+    }
+
+    pub fn field(&self, name: &str) -> Option<&MetricField> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// The schema itself: named, distinct fields with finite bounds, `min <= max`, and none on flags.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, field) in self.fields.iter().enumerate() {
+            if field.name.trim().is_empty() {
+                return Err(format!("{:?} metric schema has a field without a name", self.module));
+            }
+            if self.fields[..i].iter().any(|f| f.name == field.name) {
+                return Err(format!("{:?} metric schema names {} twice", self.module, field.name));
+            }
+            let bounds = [field.min, field.max];
+            if field.kind == MetricFieldKind::Flag && bounds.iter().any(Option::is_some) {
+                return Err(format!("Metric {} is a flag and takes no range", field.name));
+            }
+            if bounds.iter().flatten().any(|b| !b.is_finite())
+                || field.min.zip(field.max).is_some_and(|(min, max)| min > max)
+            {
+                return Err(format!("Metric {} has an invalid range", field.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// `metrics` against the schema: every field known, of its kind and within its bounds, and
+    /// every required field present.
+    pub fn check(&self, metrics: &ModuleMetrics) -> Result<(), String> {
+        for (name, value) in metrics {
+            let field = self
+                .field(name)
+                .ok_or_else(|| format!("Metric {name} is not in the {:?} schema", self.module))?;
+            field.check(value)?;
+        }
+        match self.fields.iter().find(|f| f.required && !metrics.contains_key(&f.name)) {
+            Some(missing) => Err(format!("Metric {} is required in {:?}", missing.name, self.module)),
+            None => Ok(()),
+        }
+    }
+
+    /// Each field's aggregate over `records`, for fields at least one of them carries.
+    pub fn totals<'a>(&self, records: impl IntoIterator<Item = &'a ModuleMetrics>) -> ModuleTotals {
+        let mut totals = ModuleTotals { module: self.module, attestations: 0, fields: BTreeMap::new() };
+        for metrics in records {
+            totals.attestations += 1;
+            for field in &self.fields {
+                let Some(value) = metrics.get(&field.name).map(MetricFieldValue::as_f64) else { continue };
+                let total = totals.fields.entry(field.name.clone());
+                match field.aggregation {
+                    MetricAggregation::Sum => *total.or_default() += value,
+                    MetricAggregation::Count => *total.or_default() += 1.0,
+                    MetricAggregation::Max => {
+                        total.and_modify(|t| *t = t.max(value)).or_insert(value);
+                    }
+                    MetricAggregation::Min => {
+                        total.and_modify(|t| *t = t.min(value)).or_insert(value);
+                    }
+                }
+            }
+        }
+        totals
+    }
+}
+
+/// One module's attestations, totalled field by field under its schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleTotals {
+    pub module: StewardModule,
+    pub attestations: usize,
+    pub fields: BTreeMap<String, f64>,
+}
+
+/// The schema each module's attestations are checked against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricSchemaRegistry {
+    schemas: BTreeMap<StewardModule, MetricSchema>,
+}
+
+impl Default for MetricSchemaRegistry {
+    fn default() -> Self {
+        Self { schemas: BTreeMap::from([(StewardModule::PLGA, MetricSchema::plga())]) }
+    }
+}
+
+impl MetricSchemaRegistry {
+    /// Add `schema` for its module. A module registers once: changing its fields would split
+    /// its history. PLGA's is built in, and MME has none.
+    pub fn register(&mut self, schema: MetricSchema) -> Result<(), String> {
+        schema.validate()?;
+        if schema.module == StewardModule::MME {
+            return Err("MME records assignments, not attestations; it takes no metric schema".into());
+        }
+        if self.schemas.contains_key(&schema.module) {
+            return Err(format!("{:?} already has a metric schema", schema.module));
+        }
+        self.schemas.insert(schema.module, schema);
+        Ok(())
+    }
+
+    pub fn get(&self, module: StewardModule) -> Option<&MetricSchema> {
+        self.schemas.get(&module)
+    }
+
+    pub fn schemas(&self) -> impl Iterator<Item = &MetricSchema> {
+        self.schemas.values()
+    }
+
+    /// A claim's metrics against its module's schema: PLGA's are `impact_metrics`, with no
+    /// `module_metrics`; any other module's are `module_metrics`, with default `impact_metrics`.
+    pub fn check(
+        &self,
+        module: StewardModule,
+        impact_metrics: &ImpactMetrics,
+        module_metrics: &ModuleMetrics,
+    ) -> Result<(), String> {
+        let schema = self.get(module).ok_or_else(|| format!("{module:?} has no metric schema"))?;
+        if module == StewardModule::PLGA {
+            return match module_metrics.keys().next() {
+                Some(name) => Err(format!("PLGA metrics go in impact_metrics, not module_metrics ({name})")),
+                None => Ok(()),
+            };
+        }
+        if !impact_metrics.is_empty() {
+            return Err(format!("{module:?} attestations carry module_metrics, not impact_metrics"));
+        }
+        schema.check(module_metrics)
+    }
+}
@@ -0,0 +1,200 @@
+// path: planetary_stewardship_runtime/src/module_status.rs
+
+//! Module-level kill switch.
+//! - Per-`StewardModule` state: `Active`, `Paused` (incident) or `ReadOnly` (planned)
+//! - PLGA issuance, MME assignment and governance proposals check it first and fail with `ModulePaused`
+//! - Only `GovernanceEngine` changes state, from admin actions approved under `DualControl`:
+//!   restrictions pass SAEP and the charter, and every attempt lands in its audit trail;
+//!   resuming names the restriction it lifts
+//! - Charter binding changes (`charter`) share that audit trail
+//! - Reads and exports keep working under any state
+//! - Baseline rights stay open under any state: consent can still be withdrawn
+//!   (`consent_mut`) and rollback plans can still be registered and invoked
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::{AdminBinding, AppliedProposalRef, CharterCitation, Did, Reason, ReasonCode, ScopePath, StewardModule};
+
+/// Registry shared between the engines a restriction must stop together.
+pub type SharedModuleStatus = Arc<Mutex<ModuleStatusRegistry>>;
+
+/// Names one pause or read-only period; `resume_module` must quote it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PauseId(pub String);
+
+impl fmt::Display for PauseId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ModuleState {
+    #[default]
+    Active,
+    /// Emergency stop during an incident.
+    Paused {
+        id: PauseId,
+        reason: String,
+        paused_by: Did,
+        since_ms: u64,
+    },
+    /// Planned freeze (migration, audit); blocks the same writes as `Paused`.
+    ReadOnly {
+        id: PauseId,
+        reason: String,
+        set_by: Did,
+        since_ms: u64,
+    },
+}
+
+impl ModuleState {
+    pub fn is_active(&self) -> bool {
+        matches!(self, ModuleState::Active)
+    }
+
+    /// The restriction in force, if any.
+    pub fn pause_id(&self) -> Option<&PauseId> {
+        match self {
+            ModuleState::Active => None,
+            ModuleState::Paused { id, .. } | ModuleState::ReadOnly { id, .. } => Some(id),
+        }
+    }
+}
+
+/// A mutating operation was refused because its module is not `Active`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModulePaused {
+    pub module: StewardModule,
+    pub state: ModuleState,
+}
+
+impl ModulePaused {
+    pub fn reason(&self) -> Reason {
+        let (code, id, note, since_ms) = match &self.state {
+            ModuleState::Active => {
+                return Reason::new(ReasonCode::ModuleActive).with("module", format!("{:?}", self.module));
+            }
+            ModuleState::Paused { id, reason, since_ms, .. } => (ReasonCode::ModulePaused, id, reason, since_ms),
+            ModuleState::ReadOnly { id, reason, since_ms, .. } => (ReasonCode::ModuleReadOnly, id, reason, since_ms),
+        };
+        Reason::new(code)
+            .with("module", format!("{:?}", self.module))
+            .with("pause_id", id)
+            .with("since_ms", since_ms)
+            .with("note", note)
+    }
+}
+
+impl fmt::Display for ModulePaused {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reason().fmt(f)
+    }
+}
+
+impl std::error::Error for ModulePaused {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleStatusRegistry {
+    /// Modules missing here are `Active`.
+    #[serde(serialize_with = "steward_ids::ordered::map")]
+    states: HashMap<StewardModule, ModuleState>,
+    /// The controller every `GovernanceEngine` on this registry takes admin actions from.
+    #[serde(skip)]
+    pub(crate) admin: AdminBinding,
+}
+
+impl ModuleStatusRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All modules active, ready to hand to several engines.
+    pub fn shared() -> SharedModuleStatus {
+        Arc::new(Mutex::new(Self::new()))
+    }
+
+    pub fn status(&self, module: StewardModule) -> ModuleState {
+        self.states.get(&module).cloned().unwrap_or_default()
+    }
+
+    /// Modules currently paused or read-only, in `StewardModule` order.
+    pub fn restricted(&self) -> impl Iterator<Item = (StewardModule, &ModuleState)> {
+        let mut restricted: Vec<(StewardModule, &ModuleState)> =
+            self.states.iter().filter(|(_, s)| !s.is_active()).map(|(m, s)| (*m, s)).collect();
+        restricted.sort_by_key(|(m, _)| *m);
+        restricted.into_iter()
+    }
+
+    /// `Ok` only while `module` is `Active`.
+    pub fn check_writable(&self, module: StewardModule) -> Result<(), ModulePaused> {
+        match self.status(module) {
+            ModuleState::Active => Ok(()),
+            state => Err(ModulePaused { module, state }),
+        }
+    }
+
+    pub(crate) fn set(&mut self, module: StewardModule, state: ModuleState) {
+        if state.is_active() {
+            self.states.remove(&module);
+        } else {
+            self.states.insert(module, state);
+        }
+    }
+}
+
+/// What a governance audit entry records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GovernanceAuditAction {
+    Pause { id: PauseId, reason: String },
+    ReadOnly { id: PauseId, reason: String },
+    Resume { id: PauseId },
+    /// The module joined the charter-bound set.
+    BindCharter { before_hash: String, after_hash: String },
+    /// The module left the charter-bound set under `authorization`; equal hashes when refused.
+    UnbindCharter { authorization: AppliedProposalRef, before_hash: String, after_hash: String },
+    /// The scope at `from` and its descendants moved to `to`.
+    ReparentScope { from: ScopePath, to: ScopePath },
+    /// Charter `version` became the active one; hashes are `CharterDocument::document_hash`.
+    AdoptCharter { version: u32, authorization: AppliedProposalRef, before_hash: String, after_hash: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GovernanceAuditOutcome {
+    Applied,
+    /// SAEP, the co-stewardship charter or (for unbinding) the authorization check refused the action.
+    Vetoed {
+        reasons: Vec<String>,
+        /// Charter clauses the refusal enforced.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        citations: Vec<CharterCitation>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GovernanceAuditEntry {
+    pub timestamp_ms: u64,
+    pub actor: Did,
+    pub module: StewardModule,
+    pub action: GovernanceAuditAction,
+    pub outcome: GovernanceAuditOutcome,
+}
+
+/// `check_writable` on a shared registry, flattened to the engines' error type.
+pub(crate) fn check_writable(status: &SharedModuleStatus, module: StewardModule) -> Result<(), Reason> {
+    status
+        .lock()
+        .map_err(|_| Reason::new(ReasonCode::ModuleStatusUnavailable))?
+        .check_writable(module)
+        .map_err(|e| e.reason())
+}
@@ -0,0 +1,603 @@
+// path: planetary_stewardship_runtime/src/notifications.rs
+
+//! What each agent needs to know, collected from the engines and handed out as digests.
+//! - `NotificationCenter` keeps pending notifications per DID, each with a category and a
+//!   severity. It is fed from the engines it is wired to: consent expiries through a
+//!   `ConsentObserver` registered on their registries, module pauses and governance changes
+//!   from `GovernanceEngine::audit_trail`, suspended assignments and closing claim windows
+//!   from `MicroMissionsEngine`. Anything else (governance turns, capability deprecations)
+//!   the host posts itself
+//! - Every notification names its source (e.g. `governance:pause:<id>`); posting a source
+//!   again for the same agent does nothing, so collecting twice is harmless
+//! - Agents mute categories and choose a digest frequency in `NotificationPreferences`, kept
+//!   only with their KSCP consent to the `NOTIFICATION_PURPOSE` prompt; withdrawing or letting
+//!   that consent lapse drops them
+//! - `digest_for` hands out what is due and marks it delivered; asked again at the same time,
+//!   it returns the same digest
+//! - Safety-critical categories cannot be muted, are never held for a digest, and stay
+//!   pending until the agent acknowledges them; `dismiss` refuses them
+//! - Delivery transport is the host's: the center only aggregates, in memory
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{
+    ConsentChange, ConsentEventQueue, ConsentObserver, ConsentPromptDescriptor, ConsentRecord, Did,
+    GovernanceAuditAction, GovernanceAuditOutcome, GovernanceEngine, MicroMissionsEngine, OpenAssignmentStatus,
+    StewardModule, CONSENT_PROMPT_VERSION,
+};
+
+/// Purpose agents consent to before the center keeps their notification preferences.
+pub const NOTIFICATION_PURPOSE: &str = "Keep your notification preferences and prepare your digests";
+
+/// How far ahead of a claim window's draw its entrants are told, unless configured otherwise.
+pub const DEFAULT_DEADLINE_HORIZON_MS: u64 = 24 * 3_600_000;
+
+const HOUR_MS: u64 = 3_600_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum NotificationCategory {
+    /// A governance decision or turn that concerns the agent.
+    GovernanceTurn,
+    /// An emergency governance action, e.g. a module paused. Safety-critical.
+    GovernanceEmergency,
+    /// A capability the agent uses is being retired.
+    CapabilityDeprecation,
+    /// A consent grant lapsed.
+    ConsentExpiry,
+    /// An assignment was suspended because its consent basis is gone. Safety-critical.
+    AssignmentSuspended,
+    /// A mission date is near, e.g. the draw of a waitlist the agent joined.
+    MissionDeadline,
+}
+
+impl NotificationCategory {
+    pub const ALL: [NotificationCategory; 6] = [
+        NotificationCategory::GovernanceTurn,
+        NotificationCategory::GovernanceEmergency,
+        NotificationCategory::CapabilityDeprecation,
+        NotificationCategory::ConsentExpiry,
+        NotificationCategory::AssignmentSuspended,
+        NotificationCategory::MissionDeadline,
+    ];
+
+    /// Cannot be muted or held, and stays pending until acknowledged.
+    pub fn is_safety_critical(self) -> bool {
+        matches!(self, NotificationCategory::GovernanceEmergency | NotificationCategory::AssignmentSuspended)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum NotificationSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NotificationId(pub String);
+
+impl fmt::Display for NotificationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Notification {
+    pub id: NotificationId,
+    pub recipient: Did,
+    pub category: NotificationCategory,
+    pub severity: NotificationSeverity,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+    /// What it is about, e.g. `governance:pause:<id>`; one notification per agent and source.
+    pub source: String,
+    pub created_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivered_ms: Option<u64>,
+}
+
+/// A notification to post; `post` assigns its id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewNotification {
+    pub recipient: Did,
+    pub category: NotificationCategory,
+    pub severity: NotificationSeverity,
+    pub title: String,
+    pub body: String,
+    pub source: String,
+}
+
+impl NewNotification {
+    /// Critical when the category is safety-critical, informational otherwise.
+    pub fn new(
+        recipient: Did,
+        category: NotificationCategory,
+        source: impl Into<String>,
+        title: impl Into<String>,
+    ) -> Self {
+        let severity =
+            if category.is_safety_critical() { NotificationSeverity::Critical } else { NotificationSeverity::Info };
+        Self { recipient, category, severity, title: title.into(), body: String::new(), source: source.into() }
+    }
+
+    pub fn with_severity(mut self, severity: NotificationSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// How often non-critical notifications are handed out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DigestFrequency {
+    /// Whenever a digest is asked for.
+    Immediate,
+    Hourly,
+    #[default]
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    /// Least time between two digests that deliver held notifications.
+    pub fn period_ms(self) -> u64 {
+        match self {
+            DigestFrequency::Immediate => 0,
+            DigestFrequency::Hourly => HOUR_MS,
+            DigestFrequency::Daily => 24 * HOUR_MS,
+            DigestFrequency::Weekly => 7 * 24 * HOUR_MS,
+        }
+    }
+}
+
+/// One agent's choices; agents with none on file get the default (nothing muted, daily).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NotificationPreferences {
+    /// Categories not to notify at all; safety-critical ones cannot be muted.
+    #[serde(default)]
+    pub muted: BTreeSet<NotificationCategory>,
+    #[serde(default)]
+    pub digest: DigestFrequency,
+}
+
+/// What one agent is handed at once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NotificationDigest {
+    pub recipient: Did,
+    pub generated_ms: u64,
+    /// Delivered for the first time by this digest, oldest first.
+    pub items: Vec<Notification>,
+    /// Safety-critical notifications delivered earlier and not yet acknowledged.
+    pub outstanding: Vec<Notification>,
+    /// Pending notifications held until the next digest is due.
+    pub held: usize,
+    /// When held notifications will next be handed out.
+    pub next_due_ms: u64,
+}
+
+impl NotificationDigest {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty() && self.outstanding.is_empty()
+    }
+}
+
+/// An agent's acknowledgment of a notification, kept after it leaves the pending set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NotificationAck {
+    pub notification: NotificationId,
+    pub recipient: Did,
+    pub category: NotificationCategory,
+    pub acknowledged_ms: u64,
+}
+
+/// The prompt agents see before consenting to `NOTIFICATION_PURPOSE`; grants to it are CSC
+/// records carrying its hash.
+pub fn notification_prompt() -> ConsentPromptDescriptor {
+    ConsentPromptDescriptor {
+        version: CONSENT_PROMPT_VERSION,
+        module: StewardModule::CSC,
+        mission_id: None,
+        mission_title: None,
+        mission_description: None,
+        purpose: NOTIFICATION_PURPOSE.to_string(),
+        recorded_data: vec![
+            "Your DID, the notification categories you mute and how often you want digests".to_string(),
+            "Which notifications were delivered to you, and when you acknowledged them".to_string(),
+        ],
+        retention: "Preferences are kept until you withdraw consent; safety-critical notifications are kept \
+                    until you acknowledge them"
+            .to_string(),
+        public_intent_log: false,
+        rollback_plan_required: false,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StoredPreferences {
+    preferences: NotificationPreferences,
+    /// The grant they are kept under.
+    consent: ConsentRecord,
+}
+
+#[derive(Debug, Clone)]
+struct DigestState {
+    last: NotificationDigest,
+    /// When held notifications were last handed out.
+    last_due_ms: u64,
+}
+
+/// Pending notifications, preferences and digests for every agent.
+#[derive(Debug)]
+pub struct NotificationCenter {
+    /// Per agent, oldest first.
+    pending: BTreeMap<Did, Vec<Notification>>,
+    preferences: BTreeMap<Did, StoredPreferences>,
+    /// (recipient, source) of everything posted, muted posts included.
+    posted: BTreeSet<(Did, String)>,
+    digests: BTreeMap<Did, DigestState>,
+    acknowledgments: Vec<NotificationAck>,
+    /// Heard from every registry `consent_observer` is registered on.
+    consent_events: ConsentEventQueue,
+    /// `audit_trail` entries of the governance engine already collected.
+    governance_cursor: usize,
+    deadline_horizon_ms: u64,
+    next_seq: u64,
+}
+
+impl Default for NotificationCenter {
+    fn default() -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            preferences: BTreeMap::new(),
+            posted: BTreeSet::new(),
+            digests: BTreeMap::new(),
+            acknowledgments: Vec::new(),
+            consent_events: ConsentEventQueue::new(),
+            governance_cursor: 0,
+            deadline_horizon_ms: DEFAULT_DEADLINE_HORIZON_MS,
+            next_seq: 1,
+        }
+    }
+}
+
+impl NotificationCenter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tell entrants of a claim window about its draw once it is `horizon_ms` away.
+    pub fn with_deadline_horizon(mut self, horizon_ms: u64) -> Self {
+        self.deadline_horizon_ms = horizon_ms;
+        self
+    }
+
+    /// Register on each engine's consent registry (`add_observer("notifications", ...)`);
+    /// what it hears is collected by `collect_consent`.
+    pub fn consent_observer(&self) -> Arc<dyn ConsentObserver> {
+        Arc::new(self.consent_events.clone())
+    }
+
+    /// Keep `preferences` for `consent.participant`, under `consent`: a CSC grant with the
+    /// hash of `notification_prompt`. Replaces earlier preferences.
+    pub fn set_preferences(
+        &mut self,
+        consent: ConsentRecord,
+        preferences: NotificationPreferences,
+    ) -> Result<(), String> {
+        let did = &consent.participant;
+        if !consent.consent_given || consent.module != StewardModule::CSC || consent.mission.is_some() {
+            return Err(format!("Notification preferences of {did} need their CSC consent, given"));
+        }
+        if consent.prompt_hash.as_deref() != Some(notification_prompt().canonical_hash().as_str()) {
+            return Err(format!("{did}'s consent was not given to the notification prompt"));
+        }
+        if let Some(category) = preferences.muted.iter().find(|c| c.is_safety_critical()) {
+            return Err(format!("{category:?} notifications are safety-critical and cannot be muted"));
+        }
+        self.preferences.insert(did.clone(), StoredPreferences { preferences, consent });
+        Ok(())
+    }
+
+    /// `did`'s preferences, or the defaults when none are on file.
+    pub fn preferences(&self, did: &Did) -> NotificationPreferences {
+        self.preferences.get(did).map(|p| p.preferences.clone()).unwrap_or_default()
+    }
+
+    /// Agents with preferences on file; governance broadcasts reach them.
+    pub fn subscribers(&self) -> impl Iterator<Item = &Did> {
+        self.preferences.keys()
+    }
+
+    /// Add `new` to its recipient's pending set. `None` when the category is muted or the
+    /// source was already posted to the recipient.
+    pub fn post(&mut self, new: NewNotification, now_ms: u64) -> Result<Option<NotificationId>, String> {
+        if new.title.trim().is_empty() || new.source.trim().is_empty() {
+            return Err("A notification needs a title and a source".into());
+        }
+        if !self.posted.insert((new.recipient.clone(), new.source.clone())) {
+            return Ok(None);
+        }
+        if self.preferences(&new.recipient).muted.contains(&new.category) {
+            return Ok(None);
+        }
+        let id = NotificationId(format!("notification:{}", self.next_seq));
+        self.next_seq += 1;
+        self.pending.entry(new.recipient.clone()).or_default().push(Notification {
+            id: id.clone(),
+            recipient: new.recipient,
+            category: new.category,
+            severity: new.severity,
+            title: new.title,
+            body: new.body,
+            source: new.source,
+            created_ms: now_ms,
+            delivered_ms: None,
+        });
+        Ok(Some(id))
+    }
+
+    /// `did`'s pending notifications, oldest first: not yet delivered, or safety-critical and
+    /// not yet acknowledged.
+    pub fn pending(&self, did: &Did) -> &[Notification] {
+        self.pending.get(did).map_or(&[], Vec::as_slice)
+    }
+
+    /// Hand `did` what is due at `now_ms` and mark it delivered. Safety-critical
+    /// notifications are delivered at once, the rest once the digest period since the last
+    /// due digest has passed; delivered ones leave the pending set unless safety-critical.
+    /// A second call at the same `now_ms` returns the same digest.
+    pub fn digest_for(&mut self, did: &Did, now_ms: u64) -> NotificationDigest {
+        if let Some(state) = self.digests.get(did).filter(|s| s.last.generated_ms == now_ms) {
+            return state.last.clone();
+        }
+        let period = self.preferences(did).digest.period_ms();
+        let last_due_ms = self.digests.get(did).map(|s| s.last_due_ms);
+        let due = last_due_ms.is_none_or(|last| now_ms >= last.saturating_add(period));
+        let mut digest = NotificationDigest {
+            recipient: did.clone(),
+            generated_ms: now_ms,
+            items: Vec::new(),
+            outstanding: Vec::new(),
+            held: 0,
+            next_due_ms: 0,
+        };
+        let pending = self.pending.entry(did.clone()).or_default();
+        for notification in pending.iter_mut() {
+            let critical = notification.category.is_safety_critical();
+            match notification.delivered_ms {
+                Some(_) => digest.outstanding.push(notification.clone()),
+                None if due || critical => {
+                    notification.delivered_ms = Some(now_ms);
+                    digest.items.push(notification.clone());
+                }
+                None => digest.held += 1,
+            }
+        }
+        pending.retain(|n| n.delivered_ms.is_none() || n.category.is_safety_critical());
+        if pending.is_empty() {
+            self.pending.remove(did);
+        }
+        let last_due_ms = if due { now_ms } else { last_due_ms.unwrap_or(now_ms) };
+        digest.next_due_ms = last_due_ms.saturating_add(period);
+        self.digests.insert(did.clone(), DigestState { last: digest.clone(), last_due_ms });
+        digest
+    }
+
+    /// `did` has seen `id`: it leaves the pending set and the acknowledgment is kept. Only
+    /// delivered notifications can be acknowledged.
+    pub fn acknowledge(&mut self, did: &Did, id: &NotificationId, now_ms: u64) -> Result<NotificationAck, String> {
+        let pending = self.pending.get_mut(did).ok_or_else(|| format!("{did} has no notification {id} pending"))?;
+        let index = pending
+            .iter()
+            .position(|n| &n.id == id)
+            .ok_or_else(|| format!("{did} has no notification {id} pending"))?;
+        if pending[index].delivered_ms.is_none() {
+            return Err(format!("Notification {id} has not been delivered to {did} yet"));
+        }
+        let notification = pending.remove(index);
+        if pending.is_empty() {
+            self.pending.remove(did);
+        }
+        let ack = NotificationAck {
+            notification: notification.id,
+            recipient: did.clone(),
+            category: notification.category,
+            acknowledged_ms: now_ms,
+        };
+        self.acknowledgments.push(ack.clone());
+        Ok(ack)
+    }
+
+    /// Drop a pending notification unread. Safety-critical ones must be acknowledged instead.
+    pub fn dismiss(&mut self, did: &Did, id: &NotificationId) -> Result<Notification, String> {
+        let pending = self.pending.get_mut(did).ok_or_else(|| format!("{did} has no notification {id} pending"))?;
+        let index = pending
+            .iter()
+            .position(|n| &n.id == id)
+            .ok_or_else(|| format!("{did} has no notification {id} pending"))?;
+        if pending[index].category.is_safety_critical() {
+            return Err(format!("Notification {id} is safety-critical; it stays pending until acknowledged"));
+        }
+        let notification = pending.remove(index);
+        if pending.is_empty() {
+            self.pending.remove(did);
+        }
+        Ok(notification)
+    }
+
+    /// Acknowledgments, oldest first.
+    pub fn acknowledgments(&self) -> &[NotificationAck] {
+        &self.acknowledgments
+    }
+
+    /// Notify consent expiries heard since the last call, and drop preferences whose consent
+    /// was withdrawn or lapsed. Returns the notifications posted.
+    pub fn collect_consent(&mut self, now_ms: u64) -> Result<Vec<NotificationId>, String> {
+        let mut posted = Vec::new();
+        for event in self.consent_events.take() {
+            let record = &event.record;
+            if event.change != ConsentChange::Granted {
+                let kept_under = self.preferences.get(&record.participant).map(|p| &p.consent);
+                if kept_under.is_some_and(|c| c.module == record.module && c.mission == record.mission) {
+                    self.preferences.remove(&record.participant);
+                }
+            }
+            if event.change != ConsentChange::Expired {
+                continue;
+            }
+            let scope = match &record.mission {
+                Some(mission) => format!("{:?} consent for {mission}", record.module),
+                None => format!("{:?} consent", record.module),
+            };
+            let mission = record.mission.as_ref().map_or_else(String::new, ToString::to_string);
+            let source = format!("consent:expired:{:?}:{mission}:{}", record.module, record.timestamp_ms);
+            let title = format!("Your {scope} expired");
+            let category = NotificationCategory::ConsentExpiry;
+            let new = NewNotification::new(record.participant.clone(), category, source, title)
+                .with_severity(NotificationSeverity::Warning)
+                .with_body("Grant it again to keep taking part; anything that relied on it is on hold meanwhile.");
+            posted.extend(self.post(new, now_ms)?);
+        }
+        Ok(posted)
+    }
+
+    /// Notify subscribers of governance actions `engine` applied since the last call: a pause
+    /// is an emergency, read-only switches, resumptions and charter or scope changes are
+    /// governance turns. Vetoed actions are not notified. Collect from one engine only.
+    pub fn collect_governance(
+        &mut self,
+        engine: &GovernanceEngine,
+        now_ms: u64,
+    ) -> Result<Vec<NotificationId>, String> {
+        let trail = engine.audit_trail();
+        let new_entries = trail.get(self.governance_cursor..).unwrap_or_default();
+        self.governance_cursor = trail.len();
+        let subscribers: Vec<Did> = self.subscribers().cloned().collect();
+        let mut posted = Vec::new();
+        for entry in new_entries {
+            if entry.outcome != GovernanceAuditOutcome::Applied {
+                continue;
+            }
+            let module = entry.module;
+            let (category, severity, source, title, body) = match &entry.action {
+                GovernanceAuditAction::Pause { id, reason } => (
+                    NotificationCategory::GovernanceEmergency,
+                    NotificationSeverity::Critical,
+                    format!("governance:pause:{id}"),
+                    format!("{module:?} is paused"),
+                    reason.clone(),
+                ),
+                GovernanceAuditAction::ReadOnly { id, reason } => (
+                    NotificationCategory::GovernanceTurn,
+                    NotificationSeverity::Warning,
+                    format!("governance:read-only:{id}"),
+                    format!("{module:?} is read-only"),
+                    reason.clone(),
+                ),
+                GovernanceAuditAction::Resume { id } => (
+                    NotificationCategory::GovernanceTurn,
+                    NotificationSeverity::Info,
+                    format!("governance:resume:{id}"),
+                    format!("{module:?} is open again"),
+                    String::new(),
+                ),
+                GovernanceAuditAction::BindCharter { after_hash, .. } => (
+                    NotificationCategory::GovernanceTurn,
+                    NotificationSeverity::Info,
+                    format!("governance:charter:{after_hash}"),
+                    format!("{module:?} is now bound by the co-stewardship charter"),
+                    String::new(),
+                ),
+                GovernanceAuditAction::UnbindCharter { authorization, after_hash, .. } => (
+                    NotificationCategory::GovernanceTurn,
+                    NotificationSeverity::Info,
+                    format!("governance:charter:{after_hash}"),
+                    format!("{module:?} is no longer bound by the co-stewardship charter"),
+                    format!("Authorized by proposal {}", authorization.proposal_id),
+                ),
+                GovernanceAuditAction::AdoptCharter { version, authorization, after_hash, .. } => (
+                    NotificationCategory::GovernanceTurn,
+                    NotificationSeverity::Info,
+                    format!("governance:charter-version:{after_hash}"),
+                    format!("Charter version {version} is in force"),
+                    format!("Adopted by proposal {}", authorization.proposal_id),
+                ),
+                GovernanceAuditAction::ReparentScope { from, to } => (
+                    NotificationCategory::GovernanceTurn,
+                    NotificationSeverity::Info,
+                    format!("governance:reparent:{from}:{to}:{}", entry.timestamp_ms),
+                    format!("Scope {from} moved to {to}"),
+                    String::new(),
+                ),
+            };
+            for did in &subscribers {
+                let new = NewNotification::new(did.clone(), category, source.clone(), title.clone())
+                    .with_severity(severity)
+                    .with_body(body.clone());
+                posted.extend(self.post(new, now_ms)?);
+            }
+        }
+        Ok(posted)
+    }
+
+    /// Notify assignees of assignments `engine` holds suspended pending consent, and
+    /// entrants of waitlists drawn within the deadline horizon of `now_ms`.
+    pub fn collect_missions(
+        &mut self,
+        engine: &MicroMissionsEngine,
+        now_ms: u64,
+    ) -> Result<Vec<NotificationId>, String> {
+        let mut news = Vec::new();
+        for (key, assignment) in &engine.active_assignments {
+            if let OpenAssignmentStatus::SuspendedPendingConsent { since_ms } = assignment.status {
+                let new = NewNotification::new(
+                    assignment.assignee.clone(),
+                    NotificationCategory::AssignmentSuspended,
+                    format!("assignment:suspended:{}:{}:{}:{since_ms}", key.mission, key.assignee, key.assigned_ts_ms),
+                    format!("\"{}\" is suspended", assignment.mission.title),
+                )
+                .with_body("Your consent no longer covers this assignment. Consent again to resume it, or abandon it.");
+                news.push(new);
+            }
+        }
+        for window in engine.claim_windows.values() {
+            if now_ms >= window.opens_ms || window.opens_ms - now_ms > self.deadline_horizon_ms {
+                continue;
+            }
+            let title = engine.templates.get(&window.mission).map_or(window.mission.to_string(), |t| t.title.clone());
+            for entrant in &window.entrants {
+                let new = NewNotification::new(
+                    entrant.clone(),
+                    NotificationCategory::MissionDeadline,
+                    format!("mission:draw:{}:{}", window.mission, window.opens_ms),
+                    format!("The waitlist for \"{title}\" is drawn soon"),
+                )
+                .with_severity(NotificationSeverity::Warning)
+                .with_body(format!("Joins close and the lottery can be drawn at {} ms.", window.opens_ms));
+                news.push(new);
+            }
+        }
+        let mut posted = Vec::new();
+        for new in news {
+            posted.extend(self.post(new, now_ms)?);
+        }
+        Ok(posted)
+    }
+}
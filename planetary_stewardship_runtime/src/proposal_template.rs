@@ -0,0 +1,252 @@
+// path: planetary_stewardship_runtime/src/proposal_template.rs
+
+//! Templates for routine governance proposals that change SAEP or voting settings.
+//! - `ToggleEnforcementFlag`, `SetModuleOverride` (one module's flag) and `UpdateQuorum`
+//! - `GovernanceEngine::instantiate_template` checks the parameters, refuses changes that
+//!   would do nothing, and writes the payload, title and description; the payload's hash is
+//!   recorded so an edit made after instantiation is caught at approval
+//! - The payload is a `ConfigChange` under `"change"`; hand-written proposals carrying one are
+//!   checked and applied exactly like template ones, and payloads without it stay opaque
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{CharterDocument, GovernanceScope, SaepConfig, ScopeSettings, StewardModule};
+
+/// Payload key that marks a `ConfigChange`.
+pub const CHANGE_KEY: &str = "change";
+
+/// One boolean of `SaepConfig`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SaepFlag {
+    EnforceNonHarm,
+    EnforceTransparency,
+    EnforceReversibility,
+    EnforceInformedConsent,
+    EnforceCommonsBenefit,
+    ForbidPunitiveScoring,
+}
+
+impl SaepFlag {
+    pub fn get(self, config: &SaepConfig) -> bool {
+        match self {
+            SaepFlag::EnforceNonHarm => config.enforce_non_harm,
+            SaepFlag::EnforceTransparency => config.enforce_transparency,
+            SaepFlag::EnforceReversibility => config.enforce_reversibility,
+            SaepFlag::EnforceInformedConsent => config.enforce_informed_consent,
+            SaepFlag::EnforceCommonsBenefit => config.enforce_commons_benefit,
+            SaepFlag::ForbidPunitiveScoring => config.forbid_punitive_scoring,
+        }
+    }
+
+    pub fn set(self, config: &mut SaepConfig, value: bool) {
+        let field = match self {
+            SaepFlag::EnforceNonHarm => &mut config.enforce_non_harm,
+            SaepFlag::EnforceTransparency => &mut config.enforce_transparency,
+            SaepFlag::EnforceReversibility => &mut config.enforce_reversibility,
+            SaepFlag::EnforceInformedConsent => &mut config.enforce_informed_consent,
+            SaepFlag::EnforceCommonsBenefit => &mut config.enforce_commons_benefit,
+            SaepFlag::ForbidPunitiveScoring => &mut config.forbid_punitive_scoring,
+        };
+        *field = value;
+    }
+}
+
+impl fmt::Display for SaepFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = serde_json::to_value(self).expect("flag serialization");
+        f.write_str(name.as_str().unwrap_or_default())
+    }
+}
+
+impl SaepConfig {
+    /// This config with `module`'s overrides applied.
+    pub fn for_module(&self, module: StewardModule) -> SaepConfig {
+        let mut effective = self.clone();
+        for (flag, value) in self.module_overrides.get(&module).into_iter().flatten() {
+            flag.set(&mut effective, *value);
+        }
+        effective
+    }
+}
+
+/// What an applied proposal changes, as carried in its payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "change", deny_unknown_fields)]
+pub enum ConfigChange {
+    ToggleEnforcementFlag { flag: SaepFlag, value: bool },
+    SetModuleOverride { module: StewardModule, flag: SaepFlag, value: bool },
+    /// Least total vote weight (support + opposition) a proposal in `scope` needs.
+    UpdateQuorum { scope: GovernanceScope, new_quorum: f64 },
+    /// Replace `scope`'s own settings; fields left unset inherit from the parent scope.
+    SetScopeSettings { scope: GovernanceScope, settings: ScopeSettings },
+    /// Make `document` the active charter version; its version must be above the active one.
+    AdoptCharter { document: CharterDocument },
+}
+
+impl ConfigChange {
+    /// The change in `payload`, if it carries one. A malformed one is an error.
+    pub fn from_payload(payload: &serde_json::Value) -> Result<Option<ConfigChange>, String> {
+        if payload.get(CHANGE_KEY).is_none() {
+            return Ok(None);
+        }
+        let change: ConfigChange =
+            serde_json::from_value(payload.clone()).map_err(|e| format!("Malformed change payload: {e}"))?;
+        change.check_values()?;
+        Ok(Some(change))
+    }
+
+    pub fn to_payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("change serialization")
+    }
+
+    /// Range checks that hold whatever the current config.
+    pub fn check_values(&self) -> Result<(), String> {
+        match self {
+            ConfigChange::UpdateQuorum { new_quorum, .. } if !new_quorum.is_finite() || *new_quorum < 0.0 => {
+                Err(format!("Quorum must be a finite, non-negative vote weight, got {new_quorum}"))
+            }
+            ConfigChange::SetScopeSettings { settings, .. } => settings.check_values(),
+            ConfigChange::AdoptCharter { document } => document.check(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Where a proposal making this change is decided.
+    pub fn scope(&self) -> GovernanceScope {
+        match self {
+            ConfigChange::ToggleEnforcementFlag { .. } | ConfigChange::AdoptCharter { .. } => {
+                GovernanceScope::EcosystemWide
+            }
+            ConfigChange::SetModuleOverride { module, .. } => GovernanceScope::Module(crate::ModuleId(format!("{module:?}"))),
+            ConfigChange::UpdateQuorum { scope, .. } | ConfigChange::SetScopeSettings { scope, .. } => scope.clone(),
+        }
+    }
+
+    /// Turning a check on or limiting who may vote restricts; turning a check off, moving a
+    /// quorum or rewording the charter does not.
+    pub fn introduces_restrictions(&self) -> bool {
+        match self {
+            ConfigChange::ToggleEnforcementFlag { value, .. } | ConfigChange::SetModuleOverride { value, .. } => *value,
+            ConfigChange::UpdateQuorum { .. } | ConfigChange::AdoptCharter { .. } => false,
+            ConfigChange::SetScopeSettings { settings, .. } => settings.eligible_voters.is_some(),
+        }
+    }
+
+    /// Proposal title and description.
+    pub fn describe(&self) -> (String, String) {
+        let verb = |value: bool| if value { "Enable" } else { "Disable" };
+        match self {
+            ConfigChange::ToggleEnforcementFlag { flag, value } => (
+                format!("{} SAEP {flag}", verb(*value)),
+                format!("Set SAEP `{flag}` to {value} for every module without an override."),
+            ),
+            ConfigChange::SetModuleOverride { module, flag, value } => (
+                format!("{} SAEP {flag} for {module:?}", verb(*value)),
+                format!("Override SAEP `{flag}` to {value} for {module:?} only; other modules keep the shared setting."),
+            ),
+            ConfigChange::UpdateQuorum { scope, new_quorum } => (
+                format!("Set {} quorum to {new_quorum}", scope_label(scope)),
+                format!(
+                    "Proposals scoped to {} need at least {new_quorum} total vote weight (support plus opposition) to pass.",
+                    scope_label(scope)
+                ),
+            ),
+            ConfigChange::SetScopeSettings { scope, settings } => (
+                format!("Set {} settings", scope_label(scope)),
+                format!(
+                    "Replace the settings of {} with {}; unset ones are inherited from the enclosing scope.",
+                    scope_label(scope),
+                    serde_json::to_string(settings).expect("settings serialization")
+                ),
+            ),
+            ConfigChange::AdoptCharter { document } => (
+                format!("Adopt charter version {}", document.version),
+                format!(
+                    "Make version {} of the co-stewardship charter ({} clause{}, sha256 {}) the active one.",
+                    document.version,
+                    document.clauses.len(),
+                    if document.clauses.len() == 1 { "" } else { "s" },
+                    document.document_hash()
+                ),
+            ),
+        }
+    }
+}
+
+fn scope_label(scope: &GovernanceScope) -> String {
+    match scope {
+        GovernanceScope::Module(module) => format!("module {}", module.0),
+        GovernanceScope::EcosystemWide => "ecosystem-wide".into(),
+        GovernanceScope::Path(path) => format!("scope {path}"),
+    }
+}
+
+/// The library `GovernanceEngine::instantiate_template` draws from; parameters are the
+/// matching `ConfigChange` variant's fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ProposalTemplate {
+    /// `{ "flag": "enforce_transparency", "value": false }`
+    ToggleEnforcementFlag,
+    /// `{ "module": "MME", "flag": "enforce_reversibility", "value": false }`
+    SetModuleOverride,
+    /// `{ "scope": "EcosystemWide", "new_quorum": 40.0 }`
+    UpdateQuorum,
+}
+
+impl ProposalTemplate {
+    pub const ALL: [ProposalTemplate; 3] =
+        [ProposalTemplate::ToggleEnforcementFlag, ProposalTemplate::SetModuleOverride, ProposalTemplate::UpdateQuorum];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            ProposalTemplate::ToggleEnforcementFlag => "toggle_enforcement_flag",
+            ProposalTemplate::SetModuleOverride => "set_module_override",
+            ProposalTemplate::UpdateQuorum => "update_quorum",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.id() == id)
+    }
+
+    /// The change `params` (a JSON object of this template's fields) describe.
+    pub fn change(self, params: &serde_json::Value) -> Result<ConfigChange, String> {
+        let mut payload = match params {
+            serde_json::Value::Object(fields) => fields.clone(),
+            _ => return Err(format!("Template {} takes an object of parameters", self.id())),
+        };
+        if payload.contains_key(CHANGE_KEY) {
+            return Err(format!("Template {} sets {CHANGE_KEY:?} itself", self.id()));
+        }
+        let tag = serde_json::to_value(self).expect("template serialization");
+        payload.insert(CHANGE_KEY.into(), tag);
+        let change: ConfigChange = serde_json::from_value(serde_json::Value::Object(payload))
+            .map_err(|e| format!("Invalid parameters for template {}: {e}", self.id()))?;
+        change.check_values()?;
+        Ok(change)
+    }
+}
+
+/// Recorded when a proposal is instantiated from a template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateProvenance {
+    pub template_id: String,
+    pub payload_hash: String,
+}
+
+/// SHA-256 over the payload's JSON (object keys sorted).
+pub fn payload_hash(payload: &serde_json::Value) -> String {
+    let bytes = serde_json::to_vec(payload).expect("payload serialization");
+    format!("{:x}", Sha256::digest(&bytes))
+}
+
+/// Per-module flag overrides, by module then flag.
+pub type ModuleOverrides = BTreeMap<StewardModule, BTreeMap<SaepFlag, bool>>;
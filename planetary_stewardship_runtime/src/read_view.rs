@@ -0,0 +1,296 @@
+// path: planetary_stewardship_runtime/src/read_view.rs
+
+//! Read-only views for dashboards: `PlanetaryLedger::read_view` and
+//! `MicroMissionsEngine::read_view`.
+//! - A view is point-in-time consistent: it answers every query as of the moment it was taken,
+//!   whatever is written afterwards, and never takes a lock or blocks a writer
+//! - Views are `Send + Sync` and cheap to take: attestations and open assignments are kept in
+//!   shared immutable segments, merged as they grow (each record is copied O(log n) times over
+//!   its life), so taking a view copies O(log n) handles. Templates and their search index are
+//!   shared until the next template change, which copies them once
+//! - Every change a view can see advances the engine's change chain; `ViewHead` names the
+//!   chain head a view corresponds to, so dashboards can show how fresh they are. Heads of
+//!   successive views never go back: `generation` only grows
+//! - Records a redaction rewrote stay visible, as they were, in views taken before it; the
+//!   engine itself drops its superseded copies at the redaction
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::search::TemplateIndex;
+use crate::{
+    biodiversity, sort_attestations, ActorTimeline, AssignedMission, AssignmentKey, AttestationId, AttestationOrigin,
+    Did, ImpactSummary, LanguageTag, MetricSchemaRegistry, MissionId, MissionTemplate, ModuleMetrics, ResolvedTemplate,
+    ScoredTemplate, SkillTaxonomy, StewardModule, StewardshipAttestation, TemplateQuery, TimelineOptions,
+};
+
+/// `head_hash` of an engine nothing has been written to.
+pub const VIEW_GENESIS_HASH: &str = "genesis";
+
+/// The change chain head a view was taken at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ViewHead {
+    /// Changes recorded before the view was taken.
+    pub generation: u64,
+    /// SHA-256 over the previous head, the kind and key of the change, and the record after it.
+    pub head_hash: String,
+}
+
+impl Default for ViewHead {
+    fn default() -> Self {
+        Self { generation: 0, head_hash: VIEW_GENESIS_HASH.into() }
+    }
+}
+
+impl ViewHead {
+    /// The head after `kind` record `key` became `record` (`None`: was removed).
+    pub(crate) fn advance(&mut self, kind: &str, key: &impl Serialize, record: Option<&impl Serialize>) {
+        let mut hasher = Sha256::new();
+        hasher.update(self.head_hash.as_bytes());
+        hasher.update(kind.as_bytes());
+        hasher.update(serde_json::to_vec(key).expect("view key serialization"));
+        match record {
+            Some(record) => hasher.update(serde_json::to_vec(record).expect("view record serialization")),
+            None => hasher.update(b"removed"),
+        }
+        self.generation += 1;
+        self.head_hash = format!("{:x}", hasher.finalize());
+    }
+}
+
+/// Records by key; `None` shadows an older segment's record with a removal.
+type Segment<K, V> = HashMap<K, Option<Arc<V>>>;
+
+/// The copy of a collection views are taken from: immutable segments, newest last, each at
+/// least as large as the next (the logarithmic method), so there are O(log n) of them.
+pub(crate) struct ViewLog<K, V> {
+    segments: Vec<Arc<Segment<K, V>>>,
+}
+
+impl<K, V> Default for ViewLog<K, V> {
+    fn default() -> Self {
+        Self { segments: Vec::new() }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> ViewLog<K, V> {
+    pub(crate) fn put(&mut self, key: K, record: &V) {
+        self.push(key, Some(Arc::new(record.clone())));
+    }
+
+    pub(crate) fn remove(&mut self, key: K) {
+        self.push(key, None);
+    }
+
+    /// Replace everything with `records`, dropping every superseded copy.
+    pub(crate) fn reset<'a>(&mut self, records: impl IntoIterator<Item = (&'a K, &'a V)>)
+    where
+        K: 'a,
+        V: 'a,
+    {
+        let segment: Segment<K, V> = records.into_iter().map(|(k, v)| (k.clone(), Some(Arc::new(v.clone())))).collect();
+        self.segments = if segment.is_empty() { Vec::new() } else { vec![Arc::new(segment)] };
+    }
+
+    pub(crate) fn snapshot(&self) -> ViewSnapshot<K, V> {
+        ViewSnapshot { segments: self.segments.clone() }
+    }
+
+    /// Add a one-record segment, merging it down while the segment below is no larger.
+    /// Segments a view still holds are copied, never changed.
+    fn push(&mut self, key: K, record: Option<Arc<V>>) {
+        let mut segment: Segment<K, V> = HashMap::from([(key, record)]);
+        while self.segments.last().is_some_and(|below| below.len() <= segment.len()) {
+            let below = self.segments.pop().expect("checked above");
+            let mut merged = Arc::try_unwrap(below).unwrap_or_else(|shared| (*shared).clone());
+            merged.extend(segment);
+            if self.segments.is_empty() {
+                merged.retain(|_, record| record.is_some());
+            }
+            segment = merged;
+        }
+        self.segments.push(Arc::new(segment));
+    }
+}
+
+/// A collection as it was when the view was taken.
+pub(crate) struct ViewSnapshot<K, V> {
+    segments: Vec<Arc<Segment<K, V>>>,
+}
+
+impl<K: Eq + Hash, V> ViewSnapshot<K, V> {
+    pub(crate) fn get(&self, key: &K) -> Option<&V> {
+        self.segments.iter().rev().find_map(|segment| segment.get(key)).and_then(|record| record.as_deref())
+    }
+
+    /// Every record, in no particular order.
+    pub(crate) fn values(&self) -> Vec<&V> {
+        let mut seen = HashSet::new();
+        self.segments
+            .iter()
+            .rev()
+            .flat_map(|segment| segment.iter())
+            .filter(|(key, _)| seen.insert(*key))
+            .filter_map(|(_, record)| record.as_deref())
+            .collect()
+    }
+}
+
+/// The ledger's attestations as of `head`; see `PlanetaryLedger::read_view`.
+pub struct LedgerView {
+    head: ViewHead,
+    taken_ms: u64,
+    attestations: ViewSnapshot<AttestationId, StewardshipAttestation>,
+    metric_schemas: MetricSchemaRegistry,
+}
+
+impl LedgerView {
+    pub(crate) fn new(
+        head: ViewHead,
+        taken_ms: u64,
+        attestations: ViewSnapshot<AttestationId, StewardshipAttestation>,
+        metric_schemas: MetricSchemaRegistry,
+    ) -> Self {
+        Self { head, taken_ms, attestations, metric_schemas }
+    }
+
+    pub fn head(&self) -> &ViewHead {
+        &self.head
+    }
+
+    /// Ledger clock time the view was taken at.
+    pub fn taken_ms(&self) -> u64 {
+        self.taken_ms
+    }
+
+    pub fn get_attestation(&self, id: &AttestationId) -> Option<&StewardshipAttestation> {
+        self.attestations.get(id)
+    }
+
+    /// As `PlanetaryLedger::attestations`.
+    pub fn attestations(&self) -> impl Iterator<Item = &StewardshipAttestation> {
+        let mut all = self.attestations.values();
+        sort_attestations(&mut all);
+        all.into_iter()
+    }
+
+    /// As `PlanetaryLedger::get_attestations_for_actor`.
+    pub fn get_attestations_for_actor(&self, actor: &Did) -> Vec<&StewardshipAttestation> {
+        self.attestations().filter(|a| &a.actor_did == actor).collect()
+    }
+
+    /// As `PlanetaryLedger::attestations_in_module`.
+    pub fn attestations_in_module(&self, module: StewardModule) -> impl Iterator<Item = &StewardshipAttestation> {
+        self.attestations().filter(move |a| a.module == module)
+    }
+
+    /// As `PlanetaryLedger::imported_attestations`.
+    pub fn imported_attestations<'a>(
+        &'a self,
+        source_ref: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a StewardshipAttestation> + 'a {
+        self.attestations().filter(move |a| match &a.origin {
+            AttestationOrigin::Native => false,
+            AttestationOrigin::ExternalImport { source_ref: from } => source_ref.is_none_or(|s| s == from),
+        })
+    }
+
+    /// As `PlanetaryLedger::impact_summary`.
+    pub fn impact_summary(&self, actor: Option<&Did>) -> ImpactSummary {
+        let selected = self.attestations().filter(|a| actor.is_none_or(|d| &a.actor_did == d)).collect();
+        summarize(selected, &self.metric_schemas)
+    }
+
+    /// As `PlanetaryLedger::actor_timeline`.
+    pub fn actor_timeline(&self, actor: &Did, options: TimelineOptions) -> ActorTimeline {
+        crate::timeline::build_timeline(actor, self.get_attestations_for_actor(actor), &options)
+    }
+}
+
+/// Totals over `selected`, in order; see `PlanetaryLedger::impact_summary`.
+pub(crate) fn summarize(selected: Vec<&StewardshipAttestation>, schemas: &MetricSchemaRegistry) -> ImpactSummary {
+    let mut summary = ImpactSummary::default();
+    let mut by_module: BTreeMap<StewardModule, Vec<&ModuleMetrics>> = BTreeMap::new();
+    for att in &selected {
+        summary.attestations += 1;
+        summary.imported += usize::from(!att.origin.is_native());
+        match att.as_plga_metrics() {
+            Some(metrics) => summary.totals.accumulate(metrics),
+            None => by_module.entry(att.module).or_default().push(&att.module_metrics),
+        }
+    }
+    let plga = selected.iter().filter_map(|a| a.as_plga_metrics());
+    summary.biodiversity_by_index = biodiversity::totals_by_index(plga);
+    summary.modules = by_module
+        .into_iter()
+        .filter_map(|(module, records)| Some(schemas.get(module)?.totals(records)))
+        .collect();
+    summary
+}
+
+/// The engine's templates and open assignments as of `head`; see
+/// `MicroMissionsEngine::read_view`.
+pub struct MissionsView {
+    head: ViewHead,
+    templates: Arc<HashMap<MissionId, MissionTemplate>>,
+    search_index: Arc<TemplateIndex>,
+    skills: Option<SkillTaxonomy>,
+    assignments: ViewSnapshot<AssignmentKey, AssignedMission>,
+}
+
+impl MissionsView {
+    pub(crate) fn new(
+        head: ViewHead,
+        templates: Arc<HashMap<MissionId, MissionTemplate>>,
+        search_index: Arc<TemplateIndex>,
+        skills: Option<SkillTaxonomy>,
+        assignments: ViewSnapshot<AssignmentKey, AssignedMission>,
+    ) -> Self {
+        Self { head, templates, search_index, skills, assignments }
+    }
+
+    pub fn head(&self) -> &ViewHead {
+        &self.head
+    }
+
+    pub fn get_template(&self, id: &MissionId) -> Option<&MissionTemplate> {
+        self.templates.get(id)
+    }
+
+    /// As `MicroMissionsEngine::template_in`.
+    pub fn template_in(&self, id: &MissionId, lang: &LanguageTag) -> Option<ResolvedTemplate> {
+        self.templates.get(id).map(|tpl| tpl.resolve(Some(lang)))
+    }
+
+    /// As `MicroMissionsEngine::templates`.
+    pub fn templates(&self) -> impl Iterator<Item = &MissionTemplate> {
+        let mut templates: Vec<&MissionTemplate> = self.templates.values().collect();
+        templates.sort_by(|a, b| a.id.cmp(&b.id));
+        templates.into_iter()
+    }
+
+    /// As `MicroMissionsEngine::search_templates`.
+    pub fn search_templates(&self, query: &TemplateQuery) -> Vec<ScoredTemplate> {
+        self.search_index.search(&self.templates, query, self.skills.as_ref())
+    }
+
+    /// As `MicroMissionsEngine::active_assignments`.
+    pub fn active_assignments(&self) -> impl ExactSizeIterator<Item = &AssignedMission> {
+        let mut open = self.assignments.values();
+        open.sort_by_key(|a| a.key());
+        open.into_iter()
+    }
+
+    pub fn active_assignment(&self, key: &AssignmentKey) -> Option<&AssignedMission> {
+        self.assignments.get(key)
+    }
+
+    /// As `MicroMissionsEngine::suspended_assignments`.
+    pub fn suspended_assignments<'a>(&'a self, assignee: &'a Did) -> impl Iterator<Item = &'a AssignedMission> {
+        self.active_assignments().filter(move |a| &a.assignee == assignee && !a.status.is_active())
+    }
+}
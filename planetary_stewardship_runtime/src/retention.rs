@@ -0,0 +1,273 @@
+// path: planetary_stewardship_runtime/src/retention.rs
+
+//! What happens to a participant's existing records when they withdraw consent.
+//! - `Keep` (default) leaves records as they are
+//! - `Pseudonymize` swaps the DID for a stable salted pseudonym and drops evidence flagged
+//!   `personal`; impact totals, per pseudonym, match the participant's totals before
+//! - `Tombstone` keeps the id, timestamp, intent entry and impact metrics and removes the rest
+//! - Every redaction appends a hash-chained `RedactionRecord`: the record's hash before and
+//!   after, and a hash over exactly what was removed; `verify` checks the chain and that each
+//!   redacted record still hashes to what its redaction recorded
+//! - `ConsentRegistry` queues withdrawals; `AdminAction::ExecuteRetention` applies an engine's
+//!   policy to them, as does the ledger's `apply_consent_events` when configured to
+//! - The public intent log is not rewritten: its entries are announcements the participant
+//!   published, and its chain must keep verifying
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+use crate::{AssignedMission, AttestationId, ConsentRecord, Did, MissionId, StewardModule, StewardshipAttestation};
+
+/// `prev_hash` of the first redaction.
+pub const REDACTION_GENESIS_HASH: &str = "genesis";
+
+/// `actor_did` / `assignee` of tombstoned records.
+pub const TOMBSTONE_DID: &str = "did:psv:redacted:tombstone";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RetentionPolicy {
+    #[default]
+    Keep,
+    /// `salt` keeps pseudonyms unlinkable across deployments; reuse it to keep them stable.
+    Pseudonymize { salt: String },
+    Tombstone,
+}
+
+impl RetentionPolicy {
+    /// `did:psv:pseudonym:<hex>` for `did` under `salt`.
+    pub fn pseudonym(salt: &str, did: &Did) -> Did {
+        let mut hasher = Sha256::new();
+        hasher.update(salt.as_bytes());
+        hasher.update([0x00]);
+        hasher.update(did.0.as_bytes());
+        let digest = format!("{:x}", hasher.finalize());
+        Did(format!("did:psv:pseudonym:{}", &digest[..32]))
+    }
+
+    fn mode(&self) -> Option<RedactionMode> {
+        match self {
+            RetentionPolicy::Keep => None,
+            RetentionPolicy::Pseudonymize { .. } => Some(RedactionMode::Pseudonymized),
+            RetentionPolicy::Tombstone => Some(RedactionMode::Tombstoned),
+        }
+    }
+
+    /// Who a record names after redaction.
+    fn replacement(&self, did: &Did) -> Did {
+        match self {
+            RetentionPolicy::Pseudonymize { salt } => Self::pseudonym(salt, did),
+            _ => Did(TOMBSTONE_DID.into()),
+        }
+    }
+}
+
+/// Recorded instead of the policy, so the salt never lands in the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RedactionMode {
+    Pseudonymized,
+    Tombstoned,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RedactionTarget {
+    Attestation(AttestationId),
+    Assignment { mission: MissionId, assigned_ts_ms: u64 },
+}
+
+impl fmt::Display for RedactionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RedactionTarget::Attestation(id) => write!(f, "attestation {}", id.0),
+            RedactionTarget::Assignment { mission, assigned_ts_ms } => {
+                write!(f, "assignment of {mission} at {assigned_ts_ms}")
+            }
+        }
+    }
+}
+
+/// One redacted record. Carries no DID: the withdrawal is referenced by hash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RedactionRecord {
+    pub seq: u64,
+    pub target: RedactionTarget,
+    pub mode: RedactionMode,
+    pub module: StewardModule,
+    /// Hash of the `ConsentRecord` withdrawing consent.
+    pub withdrawal_hash: String,
+    pub withdrawn_at_ms: u64,
+    /// Hash of the record before redaction.
+    pub original_hash: String,
+    /// Hash over the removed or replaced fields, as they were.
+    pub removed_content_hash: String,
+    /// Hash of the record as it now stands.
+    pub redacted_hash: String,
+    pub prev_hash: String,
+    pub self_hash: String,
+}
+
+impl RedactionRecord {
+    /// Hash over everything except `self_hash`.
+    fn compute_hash(&self) -> String {
+        let mut unsealed = self.clone();
+        unsealed.self_hash = String::new();
+        content_hash(&unsealed)
+    }
+}
+
+/// First redaction, or redacted record, that fails verification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RedactionError {
+    pub index: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for RedactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Redaction {}: {}", self.index, self.reason)
+    }
+}
+
+impl std::error::Error for RedactionError {}
+
+/// Append-only, hash-chained record of redactions, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RedactionLog {
+    entries: Vec<RedactionRecord>,
+}
+
+impl RedactionLog {
+    pub fn entries(&self) -> &[RedactionRecord] {
+        &self.entries
+    }
+
+    /// Latest redaction of `target`.
+    pub fn for_target(&self, target: &RedactionTarget) -> Option<&RedactionRecord> {
+        self.entries.iter().rev().find(|r| &r.target == target)
+    }
+
+    /// Check sequence numbers, the hash chain and every self-hash, then that each target
+    /// still hashes to its latest redaction's `redacted_hash` (`matches(target, hash)`).
+    pub fn verify(&self, matches: impl Fn(&RedactionTarget, &str) -> bool) -> Result<(), RedactionError> {
+        let mut prev_hash = REDACTION_GENESIS_HASH;
+        for (index, record) in self.entries.iter().enumerate() {
+            let fail = |reason: String| Err(RedactionError { index, reason });
+            if record.seq != index as u64 {
+                return fail(format!("sequence number {} out of order", record.seq));
+            }
+            if record.prev_hash != prev_hash {
+                return fail("prev_hash does not match previous redaction".into());
+            }
+            if record.compute_hash() != record.self_hash {
+                return fail("self_hash does not match redaction contents".into());
+            }
+            prev_hash = &record.self_hash;
+        }
+        for (index, record) in self.entries.iter().enumerate() {
+            if self.for_target(&record.target) != Some(record) {
+                continue;
+            }
+            if !matches(&record.target, &record.redacted_hash) {
+                return Err(RedactionError {
+                    index,
+                    reason: format!("{} no longer matches its redacted hash", record.target),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn append(&mut self, mut record: RedactionRecord) -> RedactionRecord {
+        record.seq = self.entries.len() as u64;
+        record.prev_hash = self
+            .entries
+            .last()
+            .map(|r| r.self_hash.clone())
+            .unwrap_or_else(|| REDACTION_GENESIS_HASH.to_string());
+        record.self_hash = record.compute_hash();
+        self.entries.push(record.clone());
+        record
+    }
+}
+
+/// SHA-256 over a record's JSON (struct field order is fixed).
+pub fn content_hash<T: Serialize>(value: &T) -> String {
+    let payload = serde_json::to_vec(value).expect("redaction serialization");
+    format!("{:x}", Sha256::digest(&payload))
+}
+
+/// `withdrawal` must withdraw `did`'s consent in `module`.
+pub(crate) fn check_withdrawal(did: &Did, module: StewardModule, withdrawal: &ConsentRecord) -> Result<(), String> {
+    if &withdrawal.participant != did || withdrawal.module != module || withdrawal.consent_given {
+        return Err(format!("Consent record does not withdraw {did}'s {module:?} consent"));
+    }
+    Ok(())
+}
+
+/// Withdrawal without a mission covers every record in the module; with one, only that mission's.
+pub(crate) fn covers(withdrawal: &ConsentRecord, mission: Option<&MissionId>) -> bool {
+    withdrawal.mission.is_none() || withdrawal.mission.as_ref() == mission
+}
+
+/// Redact `target`'s record in place via `redact`, which returns what it removed,
+/// and log it. `None` under `Keep`.
+pub(crate) fn redact<T: Serialize>(
+    log: &mut RedactionLog,
+    target: RedactionTarget,
+    module: StewardModule,
+    withdrawal: &ConsentRecord,
+    policy: &RetentionPolicy,
+    record: &mut T,
+    redact: impl FnOnce(&mut T, RedactionMode, Did) -> serde_json::Value,
+) -> Option<RedactionRecord> {
+    let mode = policy.mode()?;
+    let original_hash = content_hash(record);
+    let removed = redact(record, mode, policy.replacement(&withdrawal.participant));
+    Some(log.append(RedactionRecord {
+        seq: 0,
+        target,
+        mode,
+        module,
+        withdrawal_hash: content_hash(withdrawal),
+        withdrawn_at_ms: withdrawal.timestamp_ms,
+        original_hash,
+        removed_content_hash: content_hash(&removed),
+        redacted_hash: content_hash(record),
+        prev_hash: String::new(),
+        self_hash: String::new(),
+    }))
+}
+
+/// Pseudonymize: new actor, personal evidence dropped. Tombstone: everything but the id,
+/// timestamp, impact metrics and intent / rollback references.
+pub(crate) fn redact_attestation(att: &mut StewardshipAttestation, mode: RedactionMode, actor: Did) -> serde_json::Value {
+    let actor_did = std::mem::replace(&mut att.actor_did, actor);
+    match mode {
+        RedactionMode::Pseudonymized => {
+            let (personal, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut att.evidence).into_iter().partition(|a| a.personal);
+            att.evidence = kept;
+            serde_json::json!({ "actor_did": actor_did, "evidence": personal })
+        }
+        RedactionMode::Tombstoned => serde_json::json!({
+            "actor_did": actor_did,
+            "mission_id": att.mission_id.take(),
+            "description": std::mem::take(&mut att.description),
+            "evidence": std::mem::take(&mut att.evidence),
+            "verifier_dids": std::mem::take(&mut att.verifier_dids),
+            "non_counting_verifiers": std::mem::take(&mut att.non_counting_verifiers),
+            "verifier_conflicts": std::mem::take(&mut att.verifier_conflicts),
+            "witness_endorsement": att.witness_endorsement.take(),
+        }),
+    }
+}
+
+/// Assignments name the participant only as assignee; the mission template is public.
+pub(crate) fn redact_assignment(assignment: &mut AssignedMission, _mode: RedactionMode, assignee: Did) -> serde_json::Value {
+    serde_json::json!({ "assignee": std::mem::replace(&mut assignment.assignee, assignee) })
+}
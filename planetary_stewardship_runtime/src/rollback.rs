@@ -0,0 +1,238 @@
+// path: planetary_stewardship_runtime/src/rollback.rs
+
+//! Rollback plans backing SAEP's `require_rollback_plan`.
+//! - A plan names the action it can undo (`ActionRef`), its steps and who is responsible
+//! - Plans only register against actions that exist (or, for attestations, are reserved)
+//! - PLGA and MME refuse to act without a registered plan when SAEP demands one
+//! - One registry can be shared by several engines (`SharedRollbackRegistry`)
+//! - `plans_never_invoked` lists untested plans for audits
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::{AttestationId, Did, GovernanceProposal, MissionId};
+
+/// Registry shared between engines whose actions must stay reversible.
+pub type SharedRollbackRegistry = Arc<Mutex<RollbackPlanRegistry>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PlanId(pub String);
+
+impl fmt::Display for PlanId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The action a rollback plan undoes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ActionRef {
+    Attestation(AttestationId),
+    Assignment { mission: MissionId, assignee: Did },
+    GovernanceProposal(String),
+}
+
+impl fmt::Display for ActionRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionRef::Attestation(id) => write!(f, "attestation {}", id.0),
+            ActionRef::Assignment { mission, assignee } => write!(f, "assignment of {mission} to {assignee}"),
+            ActionRef::GovernanceProposal(id) => write!(f, "governance proposal {id}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RollbackPlan {
+    pub id: PlanId,
+    pub action_ref: ActionRef,
+    /// Human-readable steps, in order.
+    pub steps: Vec<String>,
+    pub responsible: Did,
+    pub created_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PlanInvocation {
+    pub invoked_by: Did,
+    pub timestamp_ms: u64,
+    pub outcome_note: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RegisteredPlan {
+    pub plan: RollbackPlan,
+    /// Set once the plan has been carried out.
+    #[serde(default)]
+    pub invocation: Option<PlanInvocation>,
+}
+
+/// Answers whether an action exists, so plans cannot point at nothing.
+pub trait ActionResolver {
+    fn action_exists(&self, action: &ActionRef) -> bool;
+}
+
+/// Known governance proposals resolve `ActionRef::GovernanceProposal`.
+impl ActionResolver for [GovernanceProposal] {
+    fn action_exists(&self, action: &ActionRef) -> bool {
+        match action {
+            ActionRef::GovernanceProposal(id) => self.iter().any(|p| &p.proposal_id == id),
+            _ => false,
+        }
+    }
+}
+
+/// Plans keyed by id, so lookups stay cheap however many actions are covered; `order` keeps
+/// registration order for `plans`. Serialized as the plan list, in that order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "PlanList", into = "PlanList")]
+pub struct RollbackPlanRegistry {
+    plans: BTreeMap<PlanId, RegisteredPlan>,
+    order: Vec<PlanId>,
+}
+
+/// Wire form of `RollbackPlanRegistry`.
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct PlanList {
+    plans: Vec<RegisteredPlan>,
+}
+
+impl TryFrom<PlanList> for RollbackPlanRegistry {
+    type Error = String;
+
+    fn try_from(list: PlanList) -> Result<Self, String> {
+        let mut registry = Self::default();
+        for registered in list.plans {
+            let id = registered.plan.id.clone();
+            if registry.plans.insert(id.clone(), registered).is_some() {
+                return Err(format!("Rollback plan {id} is registered twice"));
+            }
+            registry.order.push(id);
+        }
+        Ok(registry)
+    }
+}
+
+impl From<RollbackPlanRegistry> for PlanList {
+    fn from(mut registry: RollbackPlanRegistry) -> Self {
+        let plans = registry.order.iter().filter_map(|id| registry.plans.remove(id)).collect();
+        PlanList { plans }
+    }
+}
+
+/// Same schema as the plan list it serializes as.
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for RollbackPlanRegistry {
+    fn schema_name() -> String {
+        "RollbackPlanRegistry".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        PlanList::json_schema(gen)
+    }
+}
+
+impl RollbackPlanRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty registry ready to hand to several engines.
+    pub fn shared() -> SharedRollbackRegistry {
+        Arc::new(Mutex::new(Self::new()))
+    }
+
+    /// Fails on a duplicate id, an empty step list, or an action `resolver` does not know.
+    pub fn register<R>(&mut self, plan: RollbackPlan, resolver: &R) -> Result<PlanId, String>
+    where
+        R: ActionResolver + ?Sized,
+    {
+        if self.plans.contains_key(&plan.id) {
+            return Err(format!("Rollback plan {} is already registered", plan.id));
+        }
+        if plan.steps.iter().all(|s| s.trim().is_empty()) {
+            return Err(format!("Rollback plan {} has no steps", plan.id));
+        }
+        if !resolver.action_exists(&plan.action_ref) {
+            return Err(format!("Rollback plan {} targets unknown {}", plan.id, plan.action_ref));
+        }
+        let id = plan.id.clone();
+        self.order.push(id.clone());
+        self.plans.insert(id.clone(), RegisteredPlan { plan, invocation: None });
+        Ok(id)
+    }
+
+    pub fn get(&self, id: &PlanId) -> Option<&RegisteredPlan> {
+        self.plans.get(id)
+    }
+
+    /// All plans, in registration order.
+    pub fn plans(&self) -> Vec<&RegisteredPlan> {
+        self.order.iter().filter_map(|id| self.plans.get(id)).collect()
+    }
+
+    /// Record that the plan was carried out; a plan is invoked at most once.
+    pub fn mark_invoked(
+        &mut self,
+        plan_id: &PlanId,
+        invoked_by: Did,
+        timestamp_ms: u64,
+        outcome_note: String,
+    ) -> Result<(), String> {
+        let registered = self
+            .plans
+            .get_mut(plan_id)
+            .ok_or_else(|| format!("Unknown rollback plan {plan_id}"))?;
+        if registered.invocation.is_some() {
+            return Err(format!("Rollback plan {plan_id} was already invoked"));
+        }
+        registered.invocation = Some(PlanInvocation {
+            invoked_by,
+            timestamp_ms,
+            outcome_note,
+        });
+        Ok(())
+    }
+
+    /// Plans created before `older_than_ms` (a timestamp) that were never invoked.
+    pub fn plans_never_invoked(&self, older_than_ms: u64) -> Vec<&RegisteredPlan> {
+        self.plans()
+            .into_iter()
+            .filter(|p| p.invocation.is_none() && p.plan.created_ms < older_than_ms)
+            .collect()
+    }
+}
+
+/// The plan an action runs under. A supplied plan must be registered, not yet
+/// invoked and accepted by `covers`; none supplied is only fine when not `required`.
+pub(crate) fn resolve_plan(
+    registry: &SharedRollbackRegistry,
+    supplied: Option<PlanId>,
+    required: bool,
+    covers: impl FnOnce(&ActionRef) -> Result<(), String>,
+) -> Result<Option<RegisteredPlan>, String> {
+    let Some(id) = supplied else {
+        return if required {
+            Err("SAEP requires a registered rollback plan for this action".into())
+        } else {
+            Ok(None)
+        };
+    };
+    let registry = registry.lock().map_err(|_| "Rollback registry lock poisoned".to_string())?;
+    let registered = registry
+        .get(&id)
+        .ok_or_else(|| format!("Rollback plan {id} is not registered"))?;
+    if registered.invocation.is_some() {
+        return Err(format!("Rollback plan {id} was already invoked"));
+    }
+    covers(&registered.plan.action_ref)?;
+    Ok(Some(registered.clone()))
+}
@@ -0,0 +1,662 @@
+// path: planetary_stewardship_runtime/src/runtime_config.rs
+
+//! A whole deployment's policies in one reviewable file, and the runtime built from it.
+//! - `RuntimeConfig` holds every setting the engines take: SAEP flags and module overrides,
+//!   consent evidence rules, the ledger's evidence, metrics, content, witness, verification,
+//!   retention and ingestion settings, the missions engine's review, content and fairness
+//!   policies, and governance's charter, ecosystem quorum and proposal limits. A section or field left out
+//!   keeps the engines' default, so an empty file builds today's hand-assembled runtime
+//! - `RuntimeConfig::load` reads TOML (with the `toml` feature) or JSON, refuses unknown
+//!   fields, and checks fields against each other; every problem is listed, not just the first
+//! - `build_runtime` wires the engines together: one SAEP configuration, one clock and id
+//!   source, one intent log, rollback plan registry and module status registry, and consent
+//!   written once through the runtime to every engine's registry
+//! - `StewardshipRuntime::reload` reports what differs from the settings in force and applies
+//!   the hot-reloadable changes; changes to SAEP, the charter or the ecosystem's scope settings
+//!   are refused, since records and proposals already rest on them and they change through
+//!   governance or a migration. So are changes to `admin`: a reload must not be a way around
+//!   dual control
+//! - Admin actions (pauses, verifier suspensions, key revocations, retention runs) go through
+//!   the runtime's `DualControl`, configured by the `admin` section; see `dual_control`
+//! - SAEP's non-harm terms are built into `SaepEngine`; there is no lexicon to point at yet
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+use steward_runtime_support::{default_id_generator, system_clock, Clock, IdGenerator};
+
+use crate::charter::{CHARTER_UNBIND_FLOOR, DEFAULT_CHARTER_BOUND_MODULES};
+use crate::dual_control::{
+    state_hash, AdminAction, AdminApproval, AdminBinding, AdminEffect, AdminLogEntry, AdminProposal, AdminTarget,
+    DualControl, DualControlPolicy, PendingAdminAction,
+};
+use crate::idempotency::DEFAULT_IDEMPOTENCY_TTL_MS;
+use crate::ingestion::{IngestionLimits, IngestionQueue};
+use crate::module_status::{ModuleStatusRegistry, SharedModuleStatus};
+use crate::scope::{ScopePath, ScopeSettings, ScopeTree};
+use crate::{
+    ConsentEvidencePolicy, ConsentRecord, ConsentRegistry, ContentPolicy, Did, EvidencePolicy, FairnessPolicy,
+    GovernanceEngine, ImpactThresholdPolicy, IntentLog, MetricsPolicy, MicroMissionsEngine, PlanetaryLedger,
+    ProposalLimits, Reason, RetentionPolicy, RollbackPlanRegistry, SaepConfig, SaepEngine, SharedIntentLog,
+    SharedKeyRegistry, SharedRollbackRegistry, StewardModule, TemplateReviewPolicy, VerificationPolicy,
+    VerifierPolicy,
+};
+
+/// Settings for a whole runtime; see `build_runtime`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct RuntimeConfig {
+    /// Shared by every engine.
+    pub saep: SaepConfig,
+    pub consent: ConsentConfig,
+    pub ledger: LedgerConfig,
+    pub missions: MissionsConfig,
+    pub governance: GovernanceConfig,
+    /// Who administers the deployment and which operations need a second admin.
+    pub admin: DualControlPolicy,
+}
+
+/// What consent grants written through the runtime must carry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct ConsentConfig {
+    pub evidence: ConsentEvidencePolicy,
+    /// `ConsentRegistry::with_strict_evidence`.
+    pub strict_evidence: bool,
+    /// `ConsentRegistry::with_strict_prompts`.
+    pub strict_prompts: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct LedgerConfig {
+    pub metrics: MetricsPolicy,
+    pub evidence: EvidencePolicy,
+    /// Applied to attestation descriptions.
+    pub content: ContentPolicy,
+    pub impact_thresholds: ImpactThresholdPolicy,
+    pub verification: VerificationPolicy,
+    pub verifier: VerifierPolicy,
+    pub retention: RetentionPolicy,
+    /// `PlanetaryLedger::with_retention_on_withdrawal`.
+    pub retention_on_withdrawal: bool,
+    pub idempotency_ttl_ms: u64,
+    /// Depth limits of the runtime's `IngestionQueue`.
+    pub ingestion: IngestionLimits,
+}
+
+impl Default for LedgerConfig {
+    fn default() -> Self {
+        Self {
+            metrics: MetricsPolicy::default(),
+            evidence: EvidencePolicy::default(),
+            content: ContentPolicy::default(),
+            impact_thresholds: ImpactThresholdPolicy::default(),
+            verification: VerificationPolicy::default(),
+            verifier: VerifierPolicy::default(),
+            retention: RetentionPolicy::default(),
+            retention_on_withdrawal: false,
+            idempotency_ttl_ms: DEFAULT_IDEMPOTENCY_TTL_MS,
+            ingestion: IngestionLimits::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct MissionsConfig {
+    pub template_review: TemplateReviewPolicy,
+    /// Applied to template descriptions.
+    pub content: ContentPolicy,
+    pub retention: RetentionPolicy,
+    pub idempotency_ttl_ms: u64,
+    pub fairness: FairnessPolicy,
+}
+
+impl Default for MissionsConfig {
+    fn default() -> Self {
+        Self {
+            template_review: TemplateReviewPolicy::default(),
+            content: ContentPolicy::default(),
+            retention: RetentionPolicy::default(),
+            idempotency_ttl_ms: DEFAULT_IDEMPOTENCY_TTL_MS,
+            fairness: FairnessPolicy::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct GovernanceConfig {
+    pub charter_bound_modules: BTreeSet<StewardModule>,
+    /// Support share an unbinding proposal needs; at least `CHARTER_UNBIND_FLOOR`.
+    pub unbind_supermajority: f64,
+    /// The `ecosystem` scope's own settings: quorum, voters and charter terms every scope
+    /// inherits unless it sets its own.
+    pub ecosystem: ScopeSettings,
+    pub proposal_limits: ProposalLimits,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            charter_bound_modules: DEFAULT_CHARTER_BOUND_MODULES.into(),
+            unbind_supermajority: CHARTER_UNBIND_FLOOR,
+            ecosystem: ScopeSettings::default(),
+            proposal_limits: ProposalLimits::default(),
+        }
+    }
+}
+
+/// One problem with a configuration, at a dotted field path such as `ledger.verification.quorum`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConfigIssue {
+    pub field: String,
+    pub problem: String,
+}
+
+impl ConfigIssue {
+    fn new(field: impl Into<String>, problem: impl Into<String>) -> Self {
+        Self { field: field.into(), problem: problem.into() }
+    }
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.problem)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RuntimeConfigError {
+    Io { path: String, error: String },
+    /// Not TOML or JSON of a `RuntimeConfig`, unknown fields included.
+    Parse(String),
+    Invalid(Vec<ConfigIssue>),
+}
+
+impl fmt::Display for RuntimeConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeConfigError::Io { path, error } => write!(f, "Cannot read runtime config {path}: {error}"),
+            RuntimeConfigError::Parse(error) => write!(f, "Invalid runtime config: {error}"),
+            RuntimeConfigError::Invalid(issues) => {
+                let issues: Vec<String> = issues.iter().map(ConfigIssue::to_string).collect();
+                write!(f, "Runtime config has {} problem(s): {}", issues.len(), issues.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuntimeConfigError {}
+
+impl RuntimeConfig {
+    /// Read and validate the file at `path`: TOML if it ends in `.toml`, JSON otherwise.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RuntimeConfigError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| RuntimeConfigError::Io { path: path.display().to_string(), error: e.to_string() })?;
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            Self::from_toml(&source)
+        } else {
+            Self::from_json(&source)
+        }
+    }
+
+    pub fn from_json(source: &str) -> Result<Self, RuntimeConfigError> {
+        let config: Self = serde_json::from_str(source).map_err(|e| RuntimeConfigError::Parse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self, RuntimeConfigError> {
+        let config: Self = toml::from_str(source).map_err(|e| RuntimeConfigError::Parse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    #[cfg(not(feature = "toml"))]
+    pub fn from_toml(_source: &str) -> Result<Self, RuntimeConfigError> {
+        Err(RuntimeConfigError::Parse("TOML runtime configs need the `toml` feature".into()))
+    }
+
+    /// Every problem found, fields checked alone and against each other.
+    pub fn issues(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        let governance = &self.governance;
+        for module in self.saep.module_overrides.keys() {
+            if !governance.charter_bound_modules.contains(module) {
+                let problem = format!("overrides SAEP for {module:?}, not in governance.charter_bound_modules");
+                issues.push(ConfigIssue::new(format!("saep.module_overrides.{module:?}"), problem));
+            }
+        }
+        let share = governance.unbind_supermajority;
+        if !(CHARTER_UNBIND_FLOOR..=1.0).contains(&share) {
+            let problem = format!("must be between the charter floor {CHARTER_UNBIND_FLOOR:.3} and 1, got {share}");
+            issues.push(ConfigIssue::new("governance.unbind_supermajority", problem));
+        }
+        if let Err(problem) = governance.ecosystem.check_values() {
+            issues.push(ConfigIssue::new("governance.ecosystem.quorum", problem));
+        }
+        let ledger = &self.ledger;
+        if ledger.verification.quorum == 0 {
+            issues.push(ConfigIssue::new("ledger.verification.quorum", "must be at least 1"));
+        }
+        if ledger.verification.review_window_ms == 0 {
+            issues.push(ConfigIssue::new("ledger.verification.review_window_ms", "must be longer than 0"));
+        }
+        let thresholds = &ledger.impact_thresholds;
+        let thresholded = thresholds.co2eq_reduced_above.is_some() || thresholds.restored_area_m2_above.is_some();
+        if thresholded && thresholds.witnesses.is_empty() {
+            let problem = "names no witness, so no claim above the thresholds could be issued";
+            issues.push(ConfigIssue::new("ledger.impact_thresholds.witnesses", problem));
+        }
+        let ingestion = &ledger.ingestion;
+        if ingestion.max_depth_per_actor > ingestion.max_depth {
+            let problem = format!("is above ledger.ingestion.max_depth ({})", ingestion.max_depth);
+            issues.push(ConfigIssue::new("ledger.ingestion.max_depth_per_actor", problem));
+        }
+        if ingestion.drain_batch == 0 {
+            issues.push(ConfigIssue::new("ledger.ingestion.drain_batch", "must be at least 1"));
+        }
+        let reviews = &self.missions.template_review;
+        if reviews.required_signoffs > reviews.reviewers.len() {
+            let problem = format!("is more than the {} reviewer(s) named", reviews.reviewers.len());
+            issues.push(ConfigIssue::new("missions.template_review.required_signoffs", problem));
+        }
+        let fairness = &self.missions.fairness;
+        if fairness.high_demand.as_ref().is_some_and(|cap| cap.window_ms == 0) {
+            issues.push(ConfigIssue::new("missions.fairness.high_demand.window_ms", "must be longer than 0"));
+        }
+        if fairness.claim_delay.as_ref().is_some_and(|delay| delay.slots == 0) {
+            issues.push(ConfigIssue::new("missions.fairness.claim_delay.slots", "must be at least 1"));
+        }
+        if self.admin.confirm_window_ms == 0 {
+            issues.push(ConfigIssue::new("admin.confirm_window_ms", "must be longer than 0"));
+        }
+        issues
+    }
+
+    pub fn validate(&self) -> Result<(), RuntimeConfigError> {
+        match self.issues() {
+            issues if issues.is_empty() => Ok(()),
+            issues => Err(RuntimeConfigError::Invalid(issues)),
+        }
+    }
+
+    fn consent_registry(&self) -> ConsentRegistry {
+        let mut registry = ConsentRegistry::new();
+        self.apply_consent(&mut registry);
+        registry
+    }
+
+    fn apply_consent(&self, registry: &mut ConsentRegistry) {
+        registry.evidence_policy = self.consent.evidence.clone();
+        registry.strict_evidence = self.consent.strict_evidence;
+        registry.strict_prompts = self.consent.strict_prompts;
+    }
+
+    /// Everything `reload` may change on a running runtime.
+    fn apply_hot(&self, runtime: &mut StewardshipRuntime) {
+        self.apply_consent(&mut runtime.consents);
+        self.apply_consent(&mut runtime.ledger.consent);
+        self.apply_consent(&mut runtime.missions.consent);
+        let (ledger, config) = (&mut runtime.ledger, &self.ledger);
+        ledger.metrics_policy = config.metrics.clone();
+        ledger.evidence_policy = config.evidence.clone();
+        ledger.content_policy = config.content.clone();
+        ledger.impact_thresholds = config.impact_thresholds.clone();
+        ledger.verification_policy = config.verification.clone();
+        ledger.verifiers.policy = config.verifier.clone();
+        ledger.retention = config.retention.clone();
+        ledger.retention_on_withdrawal = config.retention_on_withdrawal;
+        ledger.idempotency.ttl_ms = config.idempotency_ttl_ms;
+        ledger.journal_keys.ttl_ms = config.idempotency_ttl_ms;
+        runtime.ingestion.set_limits(config.ingestion.clone());
+        let (missions, config) = (&mut runtime.missions, &self.missions);
+        missions.template_review = config.template_review.clone();
+        missions.content_policy = config.content.clone();
+        missions.retention = config.retention.clone();
+        missions.idempotency.ttl_ms = config.idempotency_ttl_ms;
+        missions.journal_keys.ttl_ms = config.idempotency_ttl_ms;
+        missions.fairness = config.fairness.clone();
+        runtime.governance.proposal_limits = self.governance.proposal_limits.clone();
+    }
+}
+
+/// Fields `reload` refuses to change; any field under a listed section counts.
+const MIGRATION_FIELDS: [&str; 5] =
+    ["saep", "governance.charter_bound_modules", "governance.unbind_supermajority", "governance.ecosystem", "admin"];
+
+/// One field that differs between the settings in force and a reloaded config.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConfigFieldChange {
+    /// Dotted, e.g. `ledger.evidence`.
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+impl ConfigFieldChange {
+    pub fn requires_migration(&self) -> bool {
+        MIGRATION_FIELDS.iter().any(|f| self.field == *f || self.field.starts_with(&format!("{f}.")))
+    }
+}
+
+/// What `StewardshipRuntime::reload` changed and what it refused; both empty if nothing differed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReloadReport {
+    pub applied: Vec<ConfigFieldChange>,
+    /// Still in force as before; these need a governance proposal or a migration.
+    pub rejected: Vec<ConfigFieldChange>,
+}
+
+impl ReloadReport {
+    pub fn is_unchanged(&self) -> bool {
+        self.applied.is_empty() && self.rejected.is_empty()
+    }
+}
+
+/// Fields of each section (`saep.enforce_non_harm`, `ledger.evidence`, ...) that differ, by field.
+pub fn config_diff(before: &RuntimeConfig, after: &RuntimeConfig) -> Vec<ConfigFieldChange> {
+    let json = |config| serde_json::to_value(config).expect("runtime config serialization");
+    let (before, after) = (json(before), json(after));
+    let mut changes = Vec::new();
+    let empty = serde_json::Map::new();
+    let sections = before.as_object().unwrap_or(&empty);
+    for (section, old) in sections {
+        let new = &after[section];
+        let (old_fields, new_fields) = (old.as_object().unwrap_or(&empty), new.as_object().unwrap_or(&empty));
+        let fields: BTreeSet<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+        for field in fields {
+            let old = old_fields.get(field).cloned().unwrap_or_default();
+            let new = new_fields.get(field).cloned().unwrap_or_default();
+            if old != new {
+                changes.push(ConfigFieldChange { field: format!("{section}.{field}"), before: old, after: new });
+            }
+        }
+    }
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+/// The engines of one deployment, wired to share their logs, registries, clock and ids.
+pub struct StewardshipRuntime {
+    pub ledger: PlanetaryLedger,
+    pub missions: MicroMissionsEngine,
+    pub governance: GovernanceEngine,
+    /// Attestation requests waiting for `ledger`.
+    pub ingestion: IngestionQueue,
+    /// Checks every consent write before it reaches the engines.
+    consents: ConsentRegistry,
+    intent_log: SharedIntentLog,
+    rollback_plans: SharedRollbackRegistry,
+    module_status: SharedModuleStatus,
+    /// Revoked through `admin`; see `with_key_registry`.
+    keys: Option<SharedKeyRegistry>,
+    /// Bound to every engine here at `build_runtime`, and to `keys` by `with_key_registry`.
+    admin: DualControl,
+    /// The runtime's own binding to `admin`, as one `AdminTarget`.
+    admin_binding: AdminBinding,
+    clock: Arc<dyn Clock>,
+    ids: Arc<dyn IdGenerator>,
+    /// What `build_runtime` was given, with the reloads applied since.
+    config: RuntimeConfig,
+}
+
+/// The runtime `config` describes, on the system clock and default ids. `load` validates;
+/// a config built in code should be checked with `RuntimeConfig::validate` first.
+pub fn build_runtime(config: RuntimeConfig) -> StewardshipRuntime {
+    build_runtime_with(config, system_clock(), default_id_generator())
+}
+
+/// `build_runtime` with an injected clock and id source (replay, examples).
+pub fn build_runtime_with(
+    config: RuntimeConfig,
+    clock: Arc<dyn Clock>,
+    ids: Arc<dyn IdGenerator>,
+) -> StewardshipRuntime {
+    let intent_log = IntentLog::shared();
+    let rollback_plans = RollbackPlanRegistry::shared();
+    let module_status = ModuleStatusRegistry::shared();
+    let saep = || SaepEngine::new(config.saep.clone());
+    let ledger = PlanetaryLedger::with_runtime(saep(), config.consent_registry(), clock.clone(), ids.clone())
+        .with_intent_log(intent_log.clone())
+        .with_rollback_registry(rollback_plans.clone())
+        .with_module_status(module_status.clone());
+    let missions = MicroMissionsEngine::new(saep(), config.consent_registry())
+        .with_intent_log(intent_log.clone())
+        .with_rollback_registry(rollback_plans.clone())
+        .with_module_status(module_status.clone());
+    let governance = &config.governance;
+    let mut scopes = ScopeTree::new();
+    scopes.set_settings(&ScopePath::root(), governance.ecosystem.clone()).expect("the root is always registered");
+    let governance = GovernanceEngine::with_charter_bound_modules(saep(), governance.charter_bound_modules.clone())
+        .with_unbind_supermajority(governance.unbind_supermajority)
+        .with_scope_tree(scopes)
+        .with_module_status(module_status.clone());
+    let mut runtime = StewardshipRuntime {
+        ledger,
+        missions,
+        governance,
+        ingestion: IngestionQueue::new(config.ledger.ingestion.clone()),
+        consents: config.consent_registry(),
+        intent_log,
+        rollback_plans,
+        module_status,
+        keys: None,
+        admin: DualControl::new(config.admin.clone()),
+        admin_binding: AdminBinding::default(),
+        clock,
+        ids,
+        config: RuntimeConfig::default(),
+    };
+    let (admin, target) = runtime.admin_target();
+    admin.bind(&target).expect("a new runtime is unbound");
+    for engine in [&*target.ledger as &dyn AdminTarget, &*target.missions, &*target.governance] {
+        admin.bind(engine).expect("new engines are unbound");
+    }
+    config.apply_hot(&mut runtime);
+    runtime.config = config;
+    runtime
+}
+
+impl StewardshipRuntime {
+    pub fn config(&self) -> &RuntimeConfig {
+        &self.config
+    }
+
+    /// Compare `config` with the settings in force, apply the changes that can be made live and
+    /// refuse those that need a migration, which stay as they were. A config with problems
+    /// changes nothing.
+    pub fn reload(&mut self, config: RuntimeConfig) -> Result<ReloadReport, RuntimeConfigError> {
+        config.validate()?;
+        let changes = config_diff(&self.config, &config);
+        let (rejected, applied): (Vec<_>, Vec<_>) =
+            changes.into_iter().partition(ConfigFieldChange::requires_migration);
+        // The fields of `MIGRATION_FIELDS` stay as they were.
+        let kept = &self.config;
+        let config = RuntimeConfig {
+            saep: kept.saep.clone(),
+            admin: kept.admin.clone(),
+            governance: GovernanceConfig {
+                charter_bound_modules: kept.governance.charter_bound_modules.clone(),
+                unbind_supermajority: kept.governance.unbind_supermajority,
+                ecosystem: kept.governance.ecosystem.clone(),
+                ..config.governance
+            },
+            ..config
+        };
+        config.apply_hot(self);
+        self.config = config;
+        #[cfg(feature = "tracing")]
+        tracing::info!(applied = applied.len(), rejected = rejected.len(), "runtime config reloaded");
+        Ok(ReloadReport { applied, rejected })
+    }
+
+    /// Record `record` if the runtime's consent policy accepts it, in every engine's registry.
+    pub fn upsert_consent(&mut self, record: ConsentRecord) -> Result<(), Reason> {
+        self.consents.submit_consent(record.clone())?;
+        self.ledger.consent_mut().upsert_consent(record.clone());
+        self.missions.consent_mut().upsert_consent(record);
+        Ok(())
+    }
+
+    pub fn consents(&self) -> impl Iterator<Item = &ConsentRecord> {
+        self.consents.records()
+    }
+
+    pub fn intent_log(&self) -> &SharedIntentLog {
+        &self.intent_log
+    }
+
+    pub fn rollback_plans(&self) -> &SharedRollbackRegistry {
+        &self.rollback_plans
+    }
+
+    pub fn module_status(&self) -> &SharedModuleStatus {
+        &self.module_status
+    }
+
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
+    pub fn ids(&self) -> Arc<dyn IdGenerator> {
+        self.ids.clone()
+    }
+
+    pub fn now_ms(&self) -> u64 {
+        self.clock.now_ms()
+    }
+
+    /// Let `AdminAction::RevokeKey` revoke keys in `keys`, the registry the ledger's
+    /// `RegistryKeyResolver` reads. The registry is bound to this runtime's controller; one
+    /// already bound to another refuses the runtime's revocations.
+    pub fn with_key_registry(mut self, keys: SharedKeyRegistry) -> Self {
+        let _ = self.admin.bind(&*keys.lock().unwrap_or_else(|e| e.into_inner()));
+        self.keys = Some(keys);
+        self
+    }
+
+    /// Pending admin actions and the admin log.
+    pub fn admin(&self) -> &DualControl {
+        &self.admin
+    }
+
+    /// `DualControl::propose` on this runtime, now.
+    pub fn propose_admin(&mut self, action: AdminAction, by: &Did) -> Result<AdminProposal, String> {
+        let now_ms = self.clock.now_ms();
+        let (admin, mut target) = self.admin_target();
+        admin.propose(&mut target, action, by, now_ms)
+    }
+
+    /// `DualControl::confirm` on this runtime, now.
+    pub fn confirm_admin(&mut self, action_id: &str, by: &Did) -> Result<AdminEffect, String> {
+        let now_ms = self.clock.now_ms();
+        let (admin, mut target) = self.admin_target();
+        admin.confirm(&mut target, action_id, by, now_ms)
+    }
+
+    pub fn cancel_admin(&mut self, action_id: &str, by: &Did) -> Result<PendingAdminAction, String> {
+        let now_ms = self.clock.now_ms();
+        self.admin.cancel(action_id, by, now_ms)
+    }
+
+    /// `DualControl::opt_out_single_admin`, for a deployment with one admin.
+    pub fn opt_out_single_admin(&mut self, by: &Did, reason: String) -> Result<(), String> {
+        let now_ms = self.clock.now_ms();
+        self.admin.opt_out_single_admin(by, reason, now_ms)
+    }
+
+    /// Drop and log the admin actions whose confirmation window has closed.
+    pub fn expire_admin(&mut self) -> Vec<PendingAdminAction> {
+        let now_ms = self.clock.now_ms();
+        self.admin.expire(now_ms)
+    }
+
+    pub fn admin_log(&self) -> &[AdminLogEntry] {
+        self.admin.log()
+    }
+
+    fn admin_target(&mut self) -> (&mut DualControl, RuntimeAdminTarget<'_>) {
+        let target = RuntimeAdminTarget {
+            binding: &self.admin_binding,
+            ledger: &mut self.ledger,
+            missions: &mut self.missions,
+            governance: &mut self.governance,
+            keys: self.keys.as_ref(),
+        };
+        (&mut self.admin, target)
+    }
+}
+
+/// The runtime's engines as one `AdminTarget`: each action goes to the engine that owns it.
+struct RuntimeAdminTarget<'a> {
+    binding: &'a AdminBinding,
+    ledger: &'a mut PlanetaryLedger,
+    missions: &'a mut MicroMissionsEngine,
+    governance: &'a mut GovernanceEngine,
+    keys: Option<&'a SharedKeyRegistry>,
+}
+
+impl AdminTarget for RuntimeAdminTarget<'_> {
+    fn admin_target_name(&self) -> &'static str {
+        "StewardshipRuntime"
+    }
+
+    fn admin_binding(&self) -> AdminBinding {
+        self.binding.clone()
+    }
+
+    /// Each engine checks the approval against its own binding.
+    fn execute_admin(&mut self, approval: AdminApproval, now_ms: u64) -> Result<AdminEffect, String> {
+        match approval.action() {
+            AdminAction::SuspendVerifier { .. } | AdminAction::RestoreVerifier { .. } => {
+                self.ledger.execute_admin(approval, now_ms)
+            }
+            AdminAction::PauseModule { .. }
+            | AdminAction::SetModuleReadOnly { .. }
+            | AdminAction::ResumeModule { .. } => {
+                self.governance.execute_admin(approval, now_ms)
+            }
+            AdminAction::RevokeKey { .. } => match self.keys {
+                Some(keys) => keys
+                    .lock()
+                    .map_err(|_| "Key registry lock poisoned".to_string())?
+                    .execute_admin(approval, now_ms),
+                None => Err("This runtime has no key registry; see `with_key_registry`".into()),
+            },
+            AdminAction::ExecuteRetention { module: StewardModule::PLGA } => {
+                self.ledger.execute_admin(approval, now_ms)
+            }
+            AdminAction::ExecuteRetention { module: StewardModule::MME } => {
+                self.missions.execute_admin(approval, now_ms)
+            }
+            AdminAction::ExecuteRetention { module } => Err(format!("Module {module:?} keeps no records to retain")),
+        }
+    }
+
+    /// Over the engines' admin state hashes, and the key registry's if there is one.
+    fn admin_state_hash(&self) -> String {
+        let keys = self.keys.map(|keys| keys.lock().unwrap_or_else(|e| e.into_inner()).admin_state_hash());
+        state_hash(&(
+            self.governance.admin_state_hash(),
+            self.ledger.admin_state_hash(),
+            self.missions.admin_state_hash(),
+            keys,
+        ))
+    }
+}
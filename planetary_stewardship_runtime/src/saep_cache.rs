@@ -0,0 +1,177 @@
+// path: planetary_stewardship_runtime/src/saep_cache.rs
+
+//! Bounded cache of SAEP decisions, opted into with `SaepEngine::with_cache`.
+//! - Keyed by a SHA-256 over the context's module, description and impact payload (object
+//!   keys sorted) and the engine's `config_fingerprint`: the SAEP config and the rule weights
+//!   it shares. A changed flag or weight misses rather than returning a stale decision; the
+//!   non-harm terms are built into `SaepEngine`, so there is no lexicon to fingerprint yet
+//! - At most `capacity` decisions, least recently used evicted first; a decision older than
+//!   `ttl_ms` on the engine's clock is evaluated afresh
+//! - Contexts with affected parties bypass the cache: their consent can change between calls
+//! - `SaepEngine::clear_cache` drops every decision; governance changes to the SAEP config
+//!   call it. `cache_stats` gives operators hits, misses, bypasses, evictions and expiries
+//! - A cached decision is the one a fresh evaluation returns under the same config, down to
+//!   its serialized bytes
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use steward_runtime_support::Clock;
+
+use crate::{EthicsContext, EthicsDecision};
+
+/// Decisions kept by `SaepCacheConfig::default`.
+pub const DEFAULT_SAEP_CACHE_CAPACITY: usize = 1024;
+/// How long `SaepCacheConfig::default` keeps a decision: 10 minutes.
+pub const DEFAULT_SAEP_CACHE_TTL_MS: u64 = 10 * 60 * 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SaepCacheConfig {
+    /// Most decisions kept; 0 caches nothing.
+    pub capacity: usize,
+    pub ttl_ms: u64,
+}
+
+impl Default for SaepCacheConfig {
+    fn default() -> Self {
+        Self { capacity: DEFAULT_SAEP_CACHE_CAPACITY, ttl_ms: DEFAULT_SAEP_CACHE_TTL_MS }
+    }
+}
+
+/// Counters since the cache was created; `clear` keeps them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SaepCacheStats {
+    pub hits: u64,
+    /// Including expired decisions.
+    pub misses: u64,
+    /// Contexts with affected parties, evaluated without the cache.
+    pub bypassed: u64,
+    /// Decisions dropped to stay within capacity.
+    pub evictions: u64,
+    /// Decisions found older than the TTL.
+    pub expired: u64,
+    pub entries: usize,
+    pub capacity: usize,
+}
+
+impl SaepCacheStats {
+    /// Hits over lookups; `None` before the first lookup.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
+}
+
+struct CachedDecision {
+    decision: EthicsDecision,
+    stored_ms: u64,
+    /// Position in `SaepCache::recency`.
+    last_used: u64,
+}
+
+/// The LRU behind `SaepEngine`'s cache.
+pub(crate) struct SaepCache {
+    config: SaepCacheConfig,
+    clock: Arc<dyn Clock>,
+    entries: HashMap<String, CachedDecision>,
+    /// Keys by last use, oldest first.
+    recency: BTreeMap<u64, String>,
+    next_use: u64,
+    stats: SaepCacheStats,
+}
+
+impl SaepCache {
+    pub(crate) fn new(config: SaepCacheConfig, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            config,
+            clock,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            next_use: 0,
+            stats: SaepCacheStats { capacity: config.capacity, ..SaepCacheStats::default() },
+        }
+    }
+
+    /// The decision stored under `key`, unless it has expired; counts a hit or a miss.
+    pub(crate) fn get(&mut self, key: &str) -> Option<EthicsDecision> {
+        let now_ms = self.clock.now_ms();
+        let Some(entry) = self.entries.get_mut(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        if now_ms.saturating_sub(entry.stored_ms) >= self.config.ttl_ms {
+            let last_used = entry.last_used;
+            self.entries.remove(key);
+            self.recency.remove(&last_used);
+            self.stats.expired += 1;
+            self.stats.misses += 1;
+            return None;
+        }
+        self.recency.remove(&entry.last_used);
+        entry.last_used = self.next_use;
+        self.recency.insert(self.next_use, key.to_string());
+        self.next_use += 1;
+        self.stats.hits += 1;
+        Some(entry.decision.clone())
+    }
+
+    /// Store `decision` under `key`, evicting the least recently used beyond capacity.
+    pub(crate) fn insert(&mut self, key: String, decision: EthicsDecision) {
+        if self.config.capacity == 0 {
+            return;
+        }
+        let stored_ms = self.clock.now_ms();
+        if let Some(old) = self.entries.remove(&key) {
+            self.recency.remove(&old.last_used);
+        }
+        while self.entries.len() >= self.config.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else { break };
+            self.entries.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+        self.recency.insert(self.next_use, key.clone());
+        self.entries.insert(key, CachedDecision { decision, stored_ms, last_used: self.next_use });
+        self.next_use += 1;
+    }
+
+    pub(crate) fn bypass(&mut self) {
+        self.stats.bypassed += 1;
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    pub(crate) fn stats(&self) -> SaepCacheStats {
+        SaepCacheStats { entries: self.entries.len(), ..self.stats }
+    }
+}
+
+/// Cache key of `ctx` under the config with `fingerprint`. The actor is left out: it does not
+/// change the decision.
+pub(crate) fn cache_key(ctx: &EthicsContext, fingerprint: &str) -> String {
+    let payload = serde_json::to_vec(&(
+        ctx.module,
+        &ctx.description,
+        normalized(&ctx.estimated_impact),
+        fingerprint,
+    ))
+    .expect("cache key serialization");
+    format!("{:x}", Sha256::digest(&payload))
+}
+
+/// `value` with every object's keys in sorted order, whatever order it was built in.
+fn normalized(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let sorted: BTreeMap<&String, serde_json::Value> = map.iter().map(|(k, v)| (k, normalized(v))).collect();
+            serde_json::to_value(sorted).expect("impact normalization")
+        }
+        serde_json::Value::Array(items) => serde_json::Value::Array(items.iter().map(normalized).collect()),
+        other => other.clone(),
+    }
+}
@@ -0,0 +1,296 @@
+// path: planetary_stewardship_runtime/src/saep_calibration.rs
+
+//! Reviewer feedback on SAEP rules, and human-approved rule weights.
+//! - A decision whose rules fired can be escalated for review; resolving the escalation as
+//!   upheld or overturned counts toward every rule that fired, in the `FeedbackStore`
+//! - `rule_performance_report` gives each rule's precision: the share of its reviewed
+//!   decisions that reviewers upheld
+//! - A rule's weight (1.0 unless set) decides what it does when it fires: at or above
+//!   `deny_threshold` it denies, below it the decision escalates to a reviewer instead. The
+//!   action is held either way; an escalated decision says so in `blocked`
+//! - Weights change only through `set_rule_weight` by a calibrator, never from feedback on
+//!   its own; every change is logged with who approved it, and a `SaepEngine` sharing the
+//!   calibration applies it from its next `evaluate`
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use crate::{Did, EthicsContext, EthicsDecision, ReasonCode, StewardModule};
+
+/// Weight of a rule nobody has set.
+pub const DEFAULT_RULE_WEIGHT: f64 = 1.0;
+
+/// A reviewer's finding on an escalated decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReviewOutcome {
+    /// The rules were right to fire.
+    Upheld,
+    /// A false positive.
+    Overturned,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleFeedback {
+    pub upheld: u64,
+    pub overturned: u64,
+}
+
+impl RuleFeedback {
+    /// Upheld over reviewed; `None` before any review.
+    pub fn precision(&self) -> Option<f64> {
+        let reviewed = self.upheld + self.overturned;
+        (reviewed > 0).then(|| self.upheld as f64 / reviewed as f64)
+    }
+}
+
+/// Upheld and overturned counts per rule.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FeedbackStore {
+    counts: BTreeMap<ReasonCode, RuleFeedback>,
+}
+
+impl FeedbackStore {
+    pub fn record(&mut self, rule: ReasonCode, outcome: ReviewOutcome) {
+        let counts = self.counts.entry(rule).or_default();
+        match outcome {
+            ReviewOutcome::Upheld => counts.upheld += 1,
+            ReviewOutcome::Overturned => counts.overturned += 1,
+        }
+    }
+
+    pub fn feedback(&self, rule: ReasonCode) -> RuleFeedback {
+        self.counts.get(&rule).copied().unwrap_or_default()
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = (&ReasonCode, &RuleFeedback)> {
+        self.counts.iter()
+    }
+}
+
+/// A decision held for a reviewer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SaepEscalation {
+    pub escalation_id: String,
+    pub actor: Did,
+    pub module: StewardModule,
+    pub description: String,
+    /// Rules that fired, denying or escalating.
+    pub rules: Vec<ReasonCode>,
+    pub opened_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<EscalationResolution>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EscalationResolution {
+    pub outcome: ReviewOutcome,
+    pub reviewer: Did,
+    pub resolved_ms: u64,
+}
+
+/// An approved change to a rule's weight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleWeightChange {
+    pub rule: ReasonCode,
+    pub before: f64,
+    pub after: f64,
+    pub approved_by: Did,
+    pub timestamp_ms: u64,
+}
+
+/// What a rule does when it fires, at its current weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RuleAction {
+    Deny,
+    Escalate,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RulePerformance {
+    pub rule: ReasonCode,
+    pub upheld: u64,
+    pub overturned: u64,
+    pub precision: Option<f64>,
+    pub weight: f64,
+    pub action: RuleAction,
+}
+
+pub type SharedRuleCalibration = Arc<Mutex<RuleCalibration>>;
+
+/// Rule weights, the feedback behind them and the escalations that feed it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleCalibration {
+    /// DIDs allowed to change rule weights.
+    calibrators: BTreeSet<Did>,
+    /// Rules weighted below this escalate instead of denying.
+    deny_threshold: f64,
+    weights: BTreeMap<ReasonCode, f64>,
+    feedback: FeedbackStore,
+    escalations: BTreeMap<String, SaepEscalation>,
+    weight_log: Vec<RuleWeightChange>,
+}
+
+impl RuleCalibration {
+    /// Every rule at `DEFAULT_RULE_WEIGHT`, denying below a threshold of 0.5.
+    pub fn new(calibrators: impl IntoIterator<Item = Did>) -> Self {
+        Self {
+            calibrators: calibrators.into_iter().collect(),
+            deny_threshold: 0.5,
+            weights: BTreeMap::new(),
+            feedback: FeedbackStore::default(),
+            escalations: BTreeMap::new(),
+            weight_log: Vec::new(),
+        }
+    }
+
+    pub fn with_deny_threshold(mut self, deny_threshold: f64) -> Self {
+        self.deny_threshold = deny_threshold;
+        self
+    }
+
+    pub fn into_shared(self) -> SharedRuleCalibration {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub fn deny_threshold(&self) -> f64 {
+        self.deny_threshold
+    }
+
+    /// Rules set away from `DEFAULT_RULE_WEIGHT`, and what they were set to.
+    pub(crate) fn weights(&self) -> &BTreeMap<ReasonCode, f64> {
+        &self.weights
+    }
+
+    pub fn rule_weight(&self, rule: ReasonCode) -> f64 {
+        self.weights.get(&rule).copied().unwrap_or(DEFAULT_RULE_WEIGHT)
+    }
+
+    pub fn rule_action(&self, rule: ReasonCode) -> RuleAction {
+        if self.rule_weight(rule) >= self.deny_threshold {
+            RuleAction::Deny
+        } else {
+            RuleAction::Escalate
+        }
+    }
+
+    /// Set `rule`'s weight in `[0, 1]`, approved by a calibrator.
+    pub fn set_rule_weight(
+        &mut self,
+        rule: ReasonCode,
+        weight: f64,
+        approved_by: &Did,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        if !self.calibrators.contains(approved_by) {
+            return Err(format!("{approved_by} is not a SAEP rule calibrator"));
+        }
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(format!("Rule weight must be within [0, 1], got {weight}"));
+        }
+        let before = self.rule_weight(rule);
+        self.weights.insert(rule, weight);
+        self.weight_log.push(RuleWeightChange {
+            rule,
+            before,
+            after: weight,
+            approved_by: approved_by.clone(),
+            timestamp_ms: now_ms,
+        });
+        Ok(())
+    }
+
+    pub fn weight_log(&self) -> &[RuleWeightChange] {
+        &self.weight_log
+    }
+
+    /// Hold `decision` on `ctx` for a reviewer; refused when no rule fired.
+    pub fn escalate(&mut self, ctx: &EthicsContext, decision: &EthicsDecision, now_ms: u64) -> Result<String, String> {
+        if decision.coded_reasons.is_empty() {
+            return Err("No SAEP rule fired; nothing to review".into());
+        }
+        let escalation_id = format!("saep-escalation-{}", self.escalations.len() + 1);
+        self.escalations.insert(
+            escalation_id.clone(),
+            SaepEscalation {
+                escalation_id: escalation_id.clone(),
+                actor: ctx.actor.clone(),
+                module: ctx.module,
+                description: ctx.description.clone(),
+                rules: decision.coded_reasons.iter().map(|r| r.code).collect(),
+                opened_ms: now_ms,
+                resolution: None,
+            },
+        );
+        Ok(escalation_id)
+    }
+
+    /// Record the reviewer's finding, counting it toward every rule that fired.
+    pub fn resolve_escalation(
+        &mut self,
+        escalation_id: &str,
+        outcome: ReviewOutcome,
+        reviewer: &Did,
+        now_ms: u64,
+    ) -> Result<&SaepEscalation, String> {
+        let escalation = self
+            .escalations
+            .get_mut(escalation_id)
+            .ok_or_else(|| format!("Unknown SAEP escalation: {escalation_id}"))?;
+        if escalation.resolution.is_some() {
+            return Err(format!("SAEP escalation {escalation_id} is already resolved"));
+        }
+        if escalation.actor == *reviewer {
+            return Err("The actor cannot review their own escalation".into());
+        }
+        escalation.resolution = Some(EscalationResolution { outcome, reviewer: reviewer.clone(), resolved_ms: now_ms });
+        for rule in &escalation.rules {
+            self.feedback.record(*rule, outcome);
+        }
+        Ok(escalation)
+    }
+
+    pub fn escalation(&self, escalation_id: &str) -> Option<&SaepEscalation> {
+        self.escalations.get(escalation_id)
+    }
+
+    /// Escalations awaiting a reviewer, oldest first.
+    pub fn open_escalations(&self) -> Vec<&SaepEscalation> {
+        let mut open: Vec<&SaepEscalation> = self.escalations.values().filter(|e| e.resolution.is_none()).collect();
+        open.sort_by_key(|e| e.opened_ms);
+        open
+    }
+
+    pub fn feedback(&self) -> &FeedbackStore {
+        &self.feedback
+    }
+
+    /// Every rule with feedback or a set weight, by code.
+    pub fn rule_performance_report(&self) -> Vec<RulePerformance> {
+        let rules: BTreeSet<ReasonCode> =
+            self.feedback.rules().map(|(rule, _)| *rule).chain(self.weights.keys().copied()).collect();
+        rules
+            .into_iter()
+            .map(|rule| {
+                let feedback = self.feedback.feedback(rule);
+                RulePerformance {
+                    rule,
+                    upheld: feedback.upheld,
+                    overturned: feedback.overturned,
+                    precision: feedback.precision(),
+                    weight: self.rule_weight(rule),
+                    action: self.rule_action(rule),
+                }
+            })
+            .collect()
+    }
+}
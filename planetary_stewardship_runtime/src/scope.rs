@@ -0,0 +1,326 @@
+// path: planetary_stewardship_runtime/src/scope.rs
+
+//! Nested governance scopes, e.g. `ecosystem/bioregion:sonora/city:phoenix/module:MME`.
+//! - A `ScopePath` names its ancestors, so the hierarchy cannot hold a cycle
+//! - `ScopeTree` holds the registered scopes and each one's own `ScopeSettings`; a setting
+//!   left unset is inherited, and the child-most one set along the path wins
+//! - `GovernanceScope::EcosystemWide` is the root and `GovernanceScope::Module(m)` is
+//!   `ecosystem/module:m`; both are always known, any other path must be registered first
+//! - Moving a scope under another parent moves its descendants and their settings with it;
+//!   `GovernanceEngine::reparent_scope` audits the move
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::{Did, GovernanceScope, StewardModule};
+
+/// First segment of every path.
+pub const ROOT_SCOPE: &str = "ecosystem";
+
+/// Segment kind naming a `StewardModule`, e.g. `module:MME`.
+pub const MODULE_SEGMENT: &str = "module";
+
+/// `ecosystem`, then `/`-separated `kind:name` segments: kind `[a-z][a-z0-9_-]*`, name
+/// `[A-Za-z0-9._-]+`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ScopePath(String);
+
+impl ScopePath {
+    pub fn new(s: impl Into<String>) -> Result<Self, String> {
+        let s = s.into();
+        Self::validate(&s)?;
+        Ok(ScopePath(s))
+    }
+
+    pub fn validate(s: &str) -> Result<(), String> {
+        let mut segments = s.split('/');
+        if segments.next() != Some(ROOT_SCOPE) {
+            return Err(format!("Scope path {s:?} must start with {ROOT_SCOPE:?}"));
+        }
+        for segment in segments {
+            let (kind, name) = segment.split_once(':').unwrap_or((segment, ""));
+            let kind_ok = kind.starts_with(|c: char| c.is_ascii_lowercase())
+                && kind.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-'));
+            let name_ok =
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+            if !kind_ok || !name_ok {
+                return Err(format!("Scope path {s:?} has malformed segment {segment:?}; expected kind:name"));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn root() -> Self {
+        ScopePath(ROOT_SCOPE.into())
+    }
+
+    /// `ecosystem/module:<module>`.
+    pub fn module_scope(module: &str) -> Self {
+        ScopePath(format!("{ROOT_SCOPE}/{MODULE_SEGMENT}:{module}"))
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0 == ROOT_SCOPE
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// `None` for the root.
+    pub fn parent(&self) -> Option<ScopePath> {
+        self.0.rsplit_once('/').map(|(parent, _)| ScopePath(parent.into()))
+    }
+
+    /// The last segment; `ecosystem` for the root.
+    pub fn leaf(&self) -> &str {
+        self.0.rsplit('/').next().unwrap_or(ROOT_SCOPE)
+    }
+
+    /// This path extended by `segment` (`kind:name`).
+    pub fn child(&self, segment: &str) -> Result<ScopePath, String> {
+        ScopePath::new(format!("{}/{segment}", self.0))
+    }
+
+    /// This path, then its parent, up to the root.
+    pub fn ancestors(&self) -> impl Iterator<Item = ScopePath> {
+        std::iter::successors(Some(self.clone()), ScopePath::parent)
+    }
+
+    /// Whether this path is `other` or lies below it.
+    pub fn is_within(&self, other: &ScopePath) -> bool {
+        self.0 == other.0 || self.0.strip_prefix(&other.0).is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    /// The module the child-most `module:` segment names; `None` without one or for an
+    /// unknown name.
+    pub fn module(&self) -> Option<StewardModule> {
+        let name = self.0.split('/').rev().find_map(|s| s.strip_prefix(MODULE_SEGMENT)?.strip_prefix(':'))?;
+        StewardModule::ALL.into_iter().find(|m| format!("{m:?}") == name)
+    }
+
+    /// This path with its `from` prefix replaced by `to`.
+    fn rebased(&self, from: &ScopePath, to: &ScopePath) -> ScopePath {
+        ScopePath(format!("{}{}", to.0, &self.0[from.0.len()..]))
+    }
+}
+
+impl TryFrom<String> for ScopePath {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        ScopePath::new(s)
+    }
+}
+
+impl From<ScopePath> for String {
+    fn from(path: ScopePath) -> String {
+        path.0
+    }
+}
+
+impl fmt::Display for ScopePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Serialized as a plain string; the pattern mirrors `validate`.
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for ScopePath {
+    fn schema_name() -> String {
+        "ScopePath".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some(r"^ecosystem(/[a-z][a-z0-9_-]*:[A-Za-z0-9._-]+)*$".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+impl GovernanceScope {
+    /// Where this scope sits in the hierarchy.
+    pub fn path(&self) -> ScopePath {
+        match self {
+            GovernanceScope::Module(module) => ScopePath::module_scope(&module.0),
+            GovernanceScope::EcosystemWide => ScopePath::root(),
+            GovernanceScope::Path(path) => path.clone(),
+        }
+    }
+
+    /// The module proposals here are evaluated in; CSC when the scope names none.
+    pub fn module(&self) -> StewardModule {
+        self.path().module().unwrap_or(StewardModule::CSC)
+    }
+}
+
+/// One scope's own settings; `None` inherits from the parent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ScopeSettings {
+    /// Least total vote weight (support + opposition) a proposal needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quorum: Option<f64>,
+    /// Who may vote; unset all the way up, anyone may.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eligible_voters: Option<BTreeSet<Did>>,
+    /// Lowercase terms a restrictive proposal may not mention, on top of the charter's own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charter_terms: Option<BTreeSet<String>>,
+}
+
+impl ScopeSettings {
+    /// These settings, with unset fields taken from `parent`.
+    fn inheriting(mut self, parent: &ScopeSettings) -> ScopeSettings {
+        self.quorum = self.quorum.or(parent.quorum);
+        if self.eligible_voters.is_none() {
+            self.eligible_voters = parent.eligible_voters.clone();
+        }
+        if self.charter_terms.is_none() {
+            self.charter_terms = parent.charter_terms.clone();
+        }
+        self
+    }
+
+    /// Whether `voter` may vote under these settings.
+    pub fn is_eligible(&self, voter: &Did) -> bool {
+        self.eligible_voters.as_ref().is_none_or(|voters| voters.contains(voter))
+    }
+
+    /// The first charter term `text` mentions, ignoring case.
+    pub fn charter_term_in(&self, text: &str) -> Option<&str> {
+        let text = text.to_lowercase();
+        self.charter_terms.iter().flatten().map(String::as_str).find(|term| text.contains(term))
+    }
+
+    pub(crate) fn check_values(&self) -> Result<(), String> {
+        match self.quorum {
+            Some(quorum) if !quorum.is_finite() || quorum < 0.0 => {
+                Err(format!("Quorum must be a finite, non-negative vote weight, got {quorum}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Registered scopes and their own settings. The root is always registered.
+#[derive(Debug, Clone)]
+pub struct ScopeTree {
+    scopes: BTreeMap<ScopePath, ScopeSettings>,
+}
+
+impl Default for ScopeTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScopeTree {
+    pub fn new() -> Self {
+        Self { scopes: BTreeMap::from([(ScopePath::root(), ScopeSettings::default())]) }
+    }
+
+    /// Register `path` with no settings of its own; its parent must be registered.
+    pub fn register(&mut self, path: ScopePath) -> Result<(), String> {
+        if self.scopes.contains_key(&path) {
+            return Err(format!("Scope {path} is already registered"));
+        }
+        let parent = path.parent().unwrap_or_else(ScopePath::root);
+        if !self.scopes.contains_key(&parent) {
+            return Err(format!("Scope {path} needs its parent {parent} registered first"));
+        }
+        self.scopes.insert(path, ScopeSettings::default());
+        Ok(())
+    }
+
+    pub fn contains(&self, path: &ScopePath) -> bool {
+        self.scopes.contains_key(path)
+    }
+
+    /// Whether proposals may target `scope`: the root and module scopes always, paths once registered.
+    pub fn knows(&self, scope: &GovernanceScope) -> bool {
+        match scope {
+            GovernanceScope::Path(path) => self.contains(path),
+            _ => true,
+        }
+    }
+
+    /// Registered paths, each before its descendants.
+    pub fn scopes(&self) -> impl Iterator<Item = &ScopePath> {
+        self.scopes.keys()
+    }
+
+    /// Registered paths directly below `path`.
+    pub fn children<'a>(&'a self, path: &'a ScopePath) -> impl Iterator<Item = &'a ScopePath> {
+        self.scopes.keys().filter(move |p| p.parent().as_ref() == Some(path))
+    }
+
+    /// `path`'s own settings, without inheritance.
+    pub fn settings(&self, path: &ScopePath) -> Option<&ScopeSettings> {
+        self.scopes.get(path)
+    }
+
+    /// Replace `path`'s own settings, returning the previous ones; default settings remove
+    /// every override.
+    pub fn set_settings(&mut self, path: &ScopePath, settings: ScopeSettings) -> Result<ScopeSettings, String> {
+        settings.check_values()?;
+        let own = self.scopes.get_mut(path).ok_or_else(|| format!("Scope {path} is not registered"))?;
+        Ok(std::mem::replace(own, settings))
+    }
+
+    /// `scope`'s own settings, registering a module scope on first use.
+    pub(crate) fn settings_mut(&mut self, scope: &GovernanceScope) -> Option<&mut ScopeSettings> {
+        let path = scope.path();
+        if !self.contains(&path) && self.knows(scope) {
+            self.scopes.insert(path.clone(), ScopeSettings::default());
+        }
+        self.scopes.get_mut(&path)
+    }
+
+    /// Settings in force at `path`: each field from the child-most scope along the path
+    /// that sets it. Unregistered segments contribute nothing.
+    pub fn effective(&self, path: &ScopePath) -> ScopeSettings {
+        path.ancestors()
+            .filter_map(|p| self.scopes.get(&p))
+            .fold(ScopeSettings::default(), |resolved, own| resolved.inheriting(own))
+    }
+
+    /// Move `path` and everything below it under `new_parent`, returning its new path.
+    /// The root cannot move, nor can a scope move below itself or onto a registered path.
+    pub fn reparent(&mut self, path: &ScopePath, new_parent: &ScopePath) -> Result<ScopePath, String> {
+        if path.is_root() {
+            return Err(format!("The {ROOT_SCOPE} scope cannot be moved"));
+        }
+        if !self.contains(path) {
+            return Err(format!("Scope {path} is not registered"));
+        }
+        if !self.contains(new_parent) {
+            return Err(format!("Scope {new_parent} is not registered"));
+        }
+        if new_parent.is_within(path) {
+            return Err(format!("Scope {path} cannot move below itself, to {new_parent}"));
+        }
+        if path.parent().as_ref() == Some(new_parent) {
+            return Err(format!("Scope {path} is already under {new_parent}"));
+        }
+        let moved = new_parent.child(path.leaf())?;
+        if self.contains(&moved) {
+            return Err(format!("Scope {moved} is already registered"));
+        }
+        let subtree: Vec<ScopePath> = self.scopes.keys().filter(|p| p.is_within(path)).cloned().collect();
+        for old in subtree {
+            let settings = self.scopes.remove(&old).unwrap_or_default();
+            self.scopes.insert(old.rebased(path, &moved), settings);
+        }
+        Ok(moved)
+    }
+}
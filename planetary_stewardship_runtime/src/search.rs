@@ -0,0 +1,237 @@
+// path: planetary_stewardship_runtime/src/search.rs
+
+//! Mission template search for `MicroMissionsEngine::search_templates`.
+//! - An inverted index over every template's title and description, in every language it
+//!   carries; updated on each `add_template` (and so on bundle load), never rebuilt
+//! - Tokens are runs of letters and digits, lowercased; matching is exact per token, with no
+//!   stemming or prefixes (`creek` does not find `creeks`)
+//! - Every query term must match the strings the query's language resolves to; a term scores
+//!   2 per occurrence in the title and 1 per occurrence in the description
+//! - Filters: difficulty range, required skills the participant has, location kind
+//!   (templates carry no tags); ties rank by mission id
+//! - With a skill taxonomy, the participant's skills are read as canonical ids and a
+//!   specialization covers the skill it specializes
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{LanguageTag, MissionId, MissionTemplate, ResolvedTemplate, SkillCredit, SkillId, SkillTaxonomy};
+
+/// `MissionTemplate::difficulty`, smallest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Difficulty {
+    XS,
+    S,
+    M,
+    L,
+    XL,
+}
+
+impl Difficulty {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_uppercase().as_str() {
+            "XS" => Some(Difficulty::XS),
+            "S" => Some(Difficulty::S),
+            "M" => Some(Difficulty::M),
+            "L" => Some(Difficulty::L),
+            "XL" => Some(Difficulty::XL),
+            _ => None,
+        }
+    }
+}
+
+/// `MissionTemplate::location_hint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LocationKind {
+    Geo,
+    Virtual,
+}
+
+impl LocationKind {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "geo" => Some(LocationKind::Geo),
+            "virtual" => Some(LocationKind::Virtual),
+            _ => None,
+        }
+    }
+}
+
+/// Unset fields do not filter. A template whose difficulty or location hint does not
+/// parse is left out by the matching filter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateQuery {
+    /// Terms that must all match; empty lists every template passing the filters.
+    #[serde(default)]
+    pub text: String,
+    /// Language to match and serve in, as in `template_in`; `None` uses the default strings.
+    #[serde(default)]
+    pub language: Option<LanguageTag>,
+    #[serde(default)]
+    pub min_difficulty: Option<Difficulty>,
+    #[serde(default)]
+    pub max_difficulty: Option<Difficulty>,
+    /// The participant's skills: only templates requiring none outside them.
+    #[serde(default)]
+    pub skills: Option<Vec<String>>,
+    #[serde(default)]
+    pub location: Option<LocationKind>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl TemplateQuery {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self { text: text.into(), ..Self::default() }
+    }
+
+    fn admits(&self, tpl: &MissionTemplate, taxonomy: &SkillTaxonomy) -> bool {
+        if self.min_difficulty.is_some() || self.max_difficulty.is_some() {
+            let Some(difficulty) = Difficulty::parse(&tpl.difficulty) else {
+                return false;
+            };
+            if self.min_difficulty.is_some_and(|min| difficulty < min)
+                || self.max_difficulty.is_some_and(|max| difficulty > max)
+            {
+                return false;
+            }
+        }
+        if let Some(skills) = &self.skills {
+            let held: BTreeSet<SkillId> =
+                skills.iter().map(|s| taxonomy.normalize_skill(s).unwrap_or_else(|| SkillId(s.clone()))).collect();
+            if !tpl.required_skills.iter().all(|s| taxonomy.credit(&SkillId(s.clone()), &held) == SkillCredit::Full) {
+                return false;
+            }
+        }
+        if let Some(location) = self.location {
+            if LocationKind::parse(&tpl.location_hint) != Some(location) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ScoredTemplate {
+    /// 0 for a query without text.
+    pub score: u32,
+    pub template: ResolvedTemplate,
+}
+
+/// Lowercased runs of letters and digits.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Occurrences of a token in one language's strings.
+#[derive(Debug, Clone, Copy, Default)]
+struct FieldHits {
+    title: u32,
+    description: u32,
+}
+
+/// Strings a template is indexed under: `None` for its default strings.
+type Localization = Option<LanguageTag>;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TemplateIndex {
+    postings: HashMap<String, HashMap<MissionId, HashMap<Localization, FieldHits>>>,
+    /// Tokens each template is filed under, to unfile it when it is replaced.
+    filed: HashMap<MissionId, HashSet<String>>,
+}
+
+impl TemplateIndex {
+    /// File `tpl`, replacing whatever was filed under its id.
+    pub(crate) fn insert(&mut self, tpl: &MissionTemplate) {
+        self.remove(&tpl.id);
+        let localized = tpl.localized_content.iter().map(|(tag, c)| (Some(tag.clone()), &c.title, &c.description));
+        let mut filed = HashSet::new();
+        for (localization, title, description) in std::iter::once((None, &tpl.title, &tpl.description)).chain(localized) {
+            for (text, in_title) in [(title, true), (description, false)] {
+                for token in tokenize(text) {
+                    let hits = self
+                        .postings
+                        .entry(token.clone())
+                        .or_default()
+                        .entry(tpl.id.clone())
+                        .or_default()
+                        .entry(localization.clone())
+                        .or_default();
+                    if in_title {
+                        hits.title += 1;
+                    } else {
+                        hits.description += 1;
+                    }
+                    filed.insert(token);
+                }
+            }
+        }
+        self.filed.insert(tpl.id.clone(), filed);
+    }
+
+    pub(crate) fn remove(&mut self, id: &MissionId) {
+        for token in self.filed.remove(id).unwrap_or_default() {
+            if let Some(missions) = self.postings.get_mut(&token) {
+                missions.remove(id);
+                if missions.is_empty() {
+                    self.postings.remove(&token);
+                }
+            }
+        }
+    }
+
+    /// Score of `id` for `terms` in `localization`; `None` when a term does not match there.
+    fn score(&self, id: &MissionId, localization: &Localization, terms: &[String]) -> Option<u32> {
+        terms.iter().try_fold(0, |score, term| {
+            let hits = self.postings.get(term)?.get(id)?.get(localization)?;
+            Some(score + 2 * hits.title + hits.description)
+        })
+    }
+
+    /// Templates matching `query`, best first, then by mission id.
+    pub(crate) fn search(
+        &self,
+        templates: &HashMap<MissionId, MissionTemplate>,
+        query: &TemplateQuery,
+        taxonomy: Option<&SkillTaxonomy>,
+    ) -> Vec<ScoredTemplate> {
+        let empty = SkillTaxonomy::default();
+        let taxonomy = taxonomy.unwrap_or(&empty);
+        let terms: Vec<String> = tokenize(&query.text).collect::<HashSet<_>>().into_iter().collect();
+        // Candidates come from the rarest term's postings; no text means every template.
+        let candidates: Vec<&MissionId> = match terms.iter().map(|t| self.postings.get(t)).collect::<Option<Vec<_>>>() {
+            None => return Vec::new(),
+            Some(lists) => match lists.into_iter().min_by_key(|missions| missions.len()) {
+                Some(rarest) => rarest.keys().collect(),
+                None => templates.keys().collect(),
+            },
+        };
+        let language = query.language.as_ref();
+        let mut hits: Vec<ScoredTemplate> = candidates
+            .into_iter()
+            .filter_map(|id| templates.get(id))
+            .filter(|tpl| query.admits(tpl, taxonomy))
+            .filter_map(|tpl| {
+                let template = tpl.resolve(language);
+                let localization = template
+                    .language
+                    .clone()
+                    .filter(|tag| tpl.localized_content.contains_key(tag) && Some(tag) != tpl.default_language.as_ref());
+                let score = self.score(&tpl.id, &localization, &terms)?;
+                Some(ScoredTemplate { score, template })
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.template.mission_id.cmp(&b.template.mission_id)));
+        if let Some(limit) = query.limit {
+            hits.truncate(limit);
+        }
+        hits
+    }
+}
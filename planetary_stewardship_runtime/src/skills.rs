@@ -0,0 +1,415 @@
+// path: planetary_stewardship_runtime/src/skills.rs
+
+//! Skill taxonomy for mission matching (`MicroMissionsEngine::with_skill_taxonomy`).
+//! - Each canonical skill has a display name, synonyms and at most one parent; parents must be
+//!   known skills and may not loop back, so the skills form a forest
+//! - Names are compared normalized: lowercased, with every run of non-alphanumerics read as one
+//!   space (`Remote_Sensing` is `remote sensing`)
+//! - `UnknownSkillPolicy` decides whether an unrecognized name is refused or filed as a pending
+//!   skill for curators; defining the skill later confirms it
+//! - Suggestions for an unrecognized name are the closest id, display name or synonym by edit
+//!   distance over the longer name's length
+//! - Volunteer profiles do not exist yet; their registration should go through `resolve` too
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::{MicroMissionsEngine, MissionId, MissionTemplate};
+
+/// Largest normalized edit distance still offered as a suggestion.
+pub const MAX_SUGGESTION_DISTANCE: f64 = 0.5;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SkillId(pub String);
+
+impl fmt::Display for SkillId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SkillSpec {
+    pub id: SkillId,
+    pub display_name: String,
+    /// Other names templates and volunteers may use for the skill.
+    #[serde(default)]
+    pub synonyms: Vec<String>,
+    /// Broader skill this one specializes.
+    #[serde(default)]
+    pub parent: Option<SkillId>,
+}
+
+/// What `SkillTaxonomy::resolve` does with a name it does not recognize.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UnknownSkillPolicy {
+    #[default]
+    Reject,
+    /// File it as a pending skill under its normalized name.
+    CreatePending,
+}
+
+/// A known name close to an unrecognized one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SkillSuggestion {
+    pub skill: SkillId,
+    /// The id, display name or synonym that was closest.
+    pub matched: String,
+    /// Edit distance over the longer normalized name's length, in [0, 1].
+    pub distance: f64,
+}
+
+/// A skill name the taxonomy did not recognize.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UnknownSkill {
+    /// Template that required it, when it came from one.
+    #[serde(default)]
+    pub mission: Option<MissionId>,
+    pub skill: String,
+    #[serde(default)]
+    pub suggestion: Option<SkillSuggestion>,
+}
+
+impl fmt::Display for UnknownSkill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.mission {
+            Some(mission) => write!(f, "Mission template {mission} requires unknown skill {:?}", self.skill)?,
+            None => write!(f, "Unknown skill {:?}", self.skill)?,
+        }
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean {:?}, i.e. {}?)", suggestion.matched, suggestion.skill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Credit a volunteer's skills earn against one required skill.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SkillCredit {
+    /// The volunteer has the skill or a specialization of it.
+    Full,
+    /// The volunteer has only an ancestor of the skill.
+    Parent { held: SkillId },
+    None,
+}
+
+/// One template's fit for a set of skills.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SkillMatch {
+    pub mission_id: MissionId,
+    /// Mean credit over the required skills, in [0, 1]; 1 for a template requiring none.
+    pub score: f64,
+    /// Credit per required skill, in the template's order.
+    pub credits: Vec<(SkillId, SkillCredit)>,
+}
+
+/// Canonical skills plus those filed pending under `UnknownSkillPolicy::CreatePending`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SkillTaxonomy {
+    skills: BTreeMap<SkillId, SkillSpec>,
+    /// Pending skills with the name they were first seen under.
+    #[serde(default)]
+    pending: BTreeMap<SkillId, String>,
+    #[serde(default)]
+    pub policy: UnknownSkillPolicy,
+}
+
+/// Lowercased alphanumeric runs joined by single spaces.
+pub fn normalize_name(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Levenshtein distance over the longer name's length; 0 for two empty names.
+fn name_distance(a: &str, b: &str) -> f64 {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 0.0;
+    }
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()] as f64 / longest as f64
+}
+
+impl SkillTaxonomy {
+    pub fn new(policy: UnknownSkillPolicy) -> Self {
+        Self { policy, ..Self::default() }
+    }
+
+    /// A taxonomy of `specs`, listed in any order.
+    pub fn from_specs(specs: Vec<SkillSpec>, policy: UnknownSkillPolicy) -> Result<Self, String> {
+        let mut taxonomy = Self::new(policy);
+        for spec in specs {
+            if taxonomy.skills.contains_key(&spec.id) {
+                return Err(format!("Skill {} is defined twice", spec.id));
+            }
+            taxonomy.skills.insert(spec.id.clone(), spec);
+        }
+        taxonomy.validate()?;
+        Ok(taxonomy)
+    }
+
+    /// Add or replace a skill; defining a pending skill confirms it. Nothing changes if the
+    /// result would not validate.
+    pub fn define(&mut self, spec: SkillSpec) -> Result<(), String> {
+        let id = spec.id.clone();
+        let previous = self.skills.insert(id.clone(), spec);
+        if let Err(e) = self.validate() {
+            match previous {
+                Some(previous) => self.skills.insert(id, previous),
+                None => self.skills.remove(&id),
+            };
+            return Err(e);
+        }
+        self.pending.remove(&id);
+        Ok(())
+    }
+
+    /// Every id and name is non-empty, every normalized name names one skill, and every
+    /// parent is a known skill reached without a cycle.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut names: BTreeMap<String, &SkillId> = BTreeMap::new();
+        for spec in self.skills.values() {
+            if normalize_name(&spec.id.0).is_empty() || spec.display_name.trim().is_empty() {
+                return Err(format!("Skill {:?} needs an id and a display name", spec.id.0));
+            }
+            for name in Self::names(spec) {
+                let key = normalize_name(name);
+                if key.is_empty() {
+                    return Err(format!("Skill {} has an empty synonym", spec.id));
+                }
+                match names.insert(key, &spec.id) {
+                    Some(other) if other != &spec.id => {
+                        return Err(format!("Skill name {name:?} is used by both {other} and {}", spec.id));
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(parent) = &spec.parent {
+                if !self.skills.contains_key(parent) {
+                    return Err(format!("Skill {} has unknown parent {parent}", spec.id));
+                }
+            }
+        }
+        for id in self.skills.keys() {
+            let mut seen = BTreeSet::from([id]);
+            let mut at = id;
+            while let Some(parent) = self.skills.get(at).and_then(|s| s.parent.as_ref()) {
+                if !seen.insert(parent) {
+                    return Err(format!("Skill {id}'s parents loop back through {parent}"));
+                }
+                at = parent;
+            }
+        }
+        Ok(())
+    }
+
+    fn names(spec: &SkillSpec) -> impl Iterator<Item = &str> {
+        [spec.id.0.as_str(), spec.display_name.as_str()]
+            .into_iter()
+            .chain(spec.synonyms.iter().map(String::as_str))
+    }
+
+    pub fn get(&self, id: &SkillId) -> Option<&SkillSpec> {
+        self.skills.get(id)
+    }
+
+    pub fn skills(&self) -> impl Iterator<Item = &SkillSpec> {
+        self.skills.values()
+    }
+
+    /// Skills filed by `CreatePending`, with the name each was first seen under.
+    pub fn pending(&self) -> &BTreeMap<SkillId, String> {
+        &self.pending
+    }
+
+    pub fn is_pending(&self, id: &SkillId) -> bool {
+        self.pending.contains_key(id)
+    }
+
+    /// Canonical id for `name`, by id, display name or synonym; pending skills by their id.
+    pub fn normalize_skill(&self, name: &str) -> Option<SkillId> {
+        let key = normalize_name(name);
+        if key.is_empty() {
+            return None;
+        }
+        self.skills
+            .values()
+            .find(|spec| Self::names(spec).any(|n| normalize_name(n) == key))
+            .map(|spec| spec.id.clone())
+            .or_else(|| self.pending.keys().find(|id| normalize_name(&id.0) == key).cloned())
+    }
+
+    /// The known name closest to `name`, if within `MAX_SUGGESTION_DISTANCE`.
+    pub fn suggest(&self, name: &str) -> Option<SkillSuggestion> {
+        let key = normalize_name(name);
+        let mut best: Option<SkillSuggestion> = None;
+        for spec in self.skills.values() {
+            for known in Self::names(spec) {
+                let distance = name_distance(&key, &normalize_name(known));
+                if distance <= MAX_SUGGESTION_DISTANCE && best.as_ref().is_none_or(|b| distance < b.distance) {
+                    best = Some(SkillSuggestion { skill: spec.id.clone(), matched: known.to_string(), distance });
+                }
+            }
+        }
+        best
+    }
+
+    /// `normalize_skill`, or under `CreatePending` a new pending skill; `Err` carries the
+    /// suggestion under `Reject`.
+    pub fn resolve(&mut self, name: &str) -> Result<SkillId, UnknownSkill> {
+        if let Some(id) = self.normalize_skill(name) {
+            return Ok(id);
+        }
+        let key = normalize_name(name);
+        if self.policy == UnknownSkillPolicy::Reject || key.is_empty() {
+            return Err(UnknownSkill { mission: None, skill: name.to_string(), suggestion: self.suggest(name) });
+        }
+        let id = SkillId(key.replace(' ', "-"));
+        self.pending.insert(id.clone(), name.trim().to_string());
+        Ok(id)
+    }
+
+    /// `id`'s parent, grandparent and so on.
+    pub fn ancestors<'a>(&'a self, id: &SkillId) -> impl Iterator<Item = &'a SkillId> + 'a {
+        std::iter::successors(self.skills.get(id).and_then(|s| s.parent.as_ref()), |at| {
+            self.skills.get(*at).and_then(|s| s.parent.as_ref())
+        })
+    }
+
+    /// Whether `skill` is `of` or one of its specializations.
+    pub fn is_within(&self, skill: &SkillId, of: &SkillId) -> bool {
+        skill == of || self.ancestors(skill).any(|a| a == of)
+    }
+
+    /// What `held` earns against `required`: full for the skill or a specialization, parent
+    /// credit for an ancestor only.
+    pub fn credit(&self, required: &SkillId, held: &BTreeSet<SkillId>) -> SkillCredit {
+        if held.iter().any(|h| self.is_within(h, required)) {
+            return SkillCredit::Full;
+        }
+        match self.ancestors(required).find(|a| held.contains(*a)) {
+            Some(ancestor) => SkillCredit::Parent { held: ancestor.clone() },
+            None => SkillCredit::None,
+        }
+    }
+}
+
+impl MicroMissionsEngine {
+    /// Match skills through `taxonomy`: `register_template` and `load_templates_toml` then
+    /// store canonical ids, and search compares them.
+    pub fn with_skill_taxonomy(mut self, taxonomy: SkillTaxonomy) -> Self {
+        self.skills = Some(taxonomy);
+        self
+    }
+
+    pub fn skill_taxonomy(&self) -> Option<&SkillTaxonomy> {
+        self.skills.as_ref()
+    }
+
+    /// For curating the taxonomy in place, e.g. defining pending skills.
+    pub fn skill_taxonomy_mut(&mut self) -> Option<&mut SkillTaxonomy> {
+        self.skills.as_mut()
+    }
+
+    /// Rewrite every template's `required_skills` as canonical ids, dropping repeats. Under
+    /// `Reject`, every unrecognized skill is reported and no template or skill is changed.
+    pub(crate) fn canonicalize_skills(&mut self, templates: &mut [MissionTemplate]) -> Result<(), Vec<UnknownSkill>> {
+        let Some(taxonomy) = self.skills.as_mut() else {
+            return Ok(());
+        };
+        if taxonomy.policy == UnknownSkillPolicy::Reject {
+            let unknown: Vec<UnknownSkill> = templates
+                .iter()
+                .flat_map(|tpl| tpl.required_skills.iter().map(move |s| (tpl, s)))
+                .filter(|(_, s)| taxonomy.normalize_skill(s).is_none())
+                .map(|(tpl, s)| UnknownSkill {
+                    mission: Some(tpl.id.clone()),
+                    skill: s.clone(),
+                    suggestion: taxonomy.suggest(s),
+                })
+                .collect();
+            if !unknown.is_empty() {
+                return Err(unknown);
+            }
+        }
+        for tpl in templates {
+            let mut canonical: Vec<String> = Vec::with_capacity(tpl.required_skills.len());
+            for skill in &tpl.required_skills {
+                let id = taxonomy
+                    .resolve(skill)
+                    .map_err(|e| vec![UnknownSkill { mission: Some(tpl.id.clone()), ..e }])?;
+                if !canonical.contains(&id.0) {
+                    canonical.push(id.0);
+                }
+            }
+            tpl.required_skills = canonical;
+        }
+        Ok(())
+    }
+
+    /// Templates scored against `skills` (a volunteer's, in any recognized spelling), best
+    /// first, then by mission id. An ancestor of a required skill earns `parent_credit` of a
+    /// full match; templates earning nothing are left out. Without a taxonomy, skills match
+    /// only as written.
+    pub fn recommend_for_skills(&self, skills: &[String], parent_credit: f64) -> Vec<SkillMatch> {
+        let empty = SkillTaxonomy::default();
+        let taxonomy = self.skills.as_ref().unwrap_or(&empty);
+        let held: BTreeSet<SkillId> =
+            skills.iter().map(|s| taxonomy.normalize_skill(s).unwrap_or_else(|| SkillId(s.clone()))).collect();
+        let parent_credit = parent_credit.clamp(0.0, 1.0);
+        let mut matches: Vec<SkillMatch> = self
+            .templates
+            .values()
+            .filter_map(|tpl| {
+                let credits: Vec<(SkillId, SkillCredit)> = tpl
+                    .required_skills
+                    .iter()
+                    .map(|s| {
+                        let required = SkillId(s.clone());
+                        let credit = taxonomy.credit(&required, &held);
+                        (required, credit)
+                    })
+                    .collect();
+                let earned: f64 = credits
+                    .iter()
+                    .map(|(_, c)| match c {
+                        SkillCredit::Full => 1.0,
+                        SkillCredit::Parent { .. } => parent_credit,
+                        SkillCredit::None => 0.0,
+                    })
+                    .sum();
+                let score = if credits.is_empty() { 1.0 } else { earned / credits.len() as f64 };
+                (score > 0.0).then(|| SkillMatch { mission_id: tpl.id.clone(), score, credits })
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.mission_id.cmp(&b.mission_id)));
+        matches
+    }
+}
+
+/// Every unknown skill, one per clause, for `String` errors.
+pub(crate) fn describe_unknown(unknown: Vec<UnknownSkill>) -> String {
+    unknown.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
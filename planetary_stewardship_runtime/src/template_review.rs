@@ -0,0 +1,229 @@
+// path: planetary_stewardship_runtime/src/template_review.rs
+
+//! Review of mission templates before volunteers can see them.
+//! - `submit_template` holds a template as a `Draft` under a new `TemplateId` version; nothing
+//!   is assignable or searchable until that version is `Approved`
+//! - `review_template` runs SAEP once over the description and expected impact, as MME with
+//!   the submitter as actor: a block rejects the draft with SAEP's reasons, otherwise it
+//!   awaits the sign-offs `TemplateReviewPolicy` asks for (approved at once if none)
+//! - Reviewers sign off with their DIDs; one rejection rejects, with its reason attached for
+//!   the submitter
+//! - Submitting an edit of an approved template takes it out of search and assignment until
+//!   the edit is approved; open assignments keep the template they were assigned, and its
+//!   version (`AssignedMission::template_version`)
+//! - `add_template` and the bundle loaders are the operator's path and skip review
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::{Did, EthicsContext, MicroMissionsEngine, MissionId, MissionTemplate, StewardModule};
+
+/// One submitted version of a mission template.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateId {
+    pub mission: MissionId,
+    /// From 1, per mission; 0 is a template added without review.
+    pub version: u32,
+}
+
+impl fmt::Display for TemplateId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@v{}", self.mission, self.version)
+    }
+}
+
+/// Who signs off on templates SAEP lets through. The default asks nobody.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateReviewPolicy {
+    #[serde(default)]
+    pub reviewers: BTreeSet<Did>,
+    /// Approvals from distinct `reviewers` a template needs; never the submitter's own.
+    #[serde(default)]
+    pub required_signoffs: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TemplateVerdict {
+    Approve,
+    Reject { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateSignoff {
+    pub reviewer: Did,
+    pub verdict: TemplateVerdict,
+    pub at_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TemplateState {
+    /// Submitted; SAEP has not seen it yet.
+    Draft,
+    AwaitingSignoff,
+    /// Assignable and searchable.
+    Approved,
+    /// SAEP's reasons, or the rejecting reviewer's.
+    Rejected { reasons: Vec<String> },
+}
+
+/// The latest version submitted for a mission, and where its review stands.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TemplateSubmission {
+    pub id: TemplateId,
+    pub template: MissionTemplate,
+    pub submitted_by: Did,
+    pub state: TemplateState,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signoffs: Vec<TemplateSignoff>,
+}
+
+impl MicroMissionsEngine {
+    /// Sign-offs asked of templates submitted for review.
+    pub fn with_template_review_policy(mut self, policy: TemplateReviewPolicy) -> Self {
+        self.template_review = policy;
+        self
+    }
+
+    pub fn template_review_policy(&self) -> &TemplateReviewPolicy {
+        &self.template_review
+    }
+
+    /// Hold `tpl`, validated and prepared as by `register_template`, as the next version of
+    /// its mission, in `Draft`. Only the original submitter may submit an edit, and templates
+    /// added without review are not edited this way.
+    pub fn submit_template(&mut self, tpl: MissionTemplate, submitted_by: Did) -> Result<TemplateId, String> {
+        let tpl = self.prepare_template(tpl)?;
+        let version = match self.template_submissions.get(&tpl.id) {
+            Some(previous) if previous.submitted_by != submitted_by => {
+                return Err(format!("Only {} may edit mission {}", previous.submitted_by, tpl.id));
+            }
+            Some(previous) => previous.id.version + 1,
+            None if self.templates.contains_key(&tpl.id) => {
+                return Err(format!("Mission {} was added without review; replace it with add_template", tpl.id));
+            }
+            None => 1,
+        };
+        // An edit of a live template takes it out of search and assignment until approved.
+        self.withdraw_template(&tpl.id);
+        let id = TemplateId { mission: tpl.id.clone(), version };
+        let state = TemplateState::Draft;
+        let submission = TemplateSubmission { id: id.clone(), template: tpl, submitted_by, state, signoffs: vec![] };
+        self.template_submissions.insert(id.mission.clone(), submission);
+        Ok(id)
+    }
+
+    /// Run SAEP over draft `id`'s description and expected impact, as MME with the submitter as
+    /// actor; returns the state it leaves the draft in.
+    pub fn review_template(&mut self, id: &TemplateId) -> Result<TemplateState, String> {
+        let submission = self.current_submission(id)?;
+        if submission.state != TemplateState::Draft {
+            return Err(format!("Template {id} is not a draft ({:?})", submission.state));
+        }
+        let decision = self.saep.evaluate(&EthicsContext {
+            actor: submission.submitted_by.clone(),
+            affected_parties: vec![],
+            module: StewardModule::MME,
+            description: submission.template.description.clone(),
+            estimated_impact: submission.template.expected_impact.clone(),
+        });
+        let state = if !decision.allowed {
+            TemplateState::Rejected { reasons: decision.reasons }
+        } else if self.template_review.required_signoffs == 0 {
+            TemplateState::Approved
+        } else {
+            TemplateState::AwaitingSignoff
+        };
+        self.settle_template(id, state.clone());
+        Ok(state)
+    }
+
+    /// Record `reviewer`'s verdict on `id`, which must be awaiting sign-off; returns the state
+    /// it leaves the template in.
+    pub fn sign_off_template(
+        &mut self,
+        id: &TemplateId,
+        reviewer: &Did,
+        verdict: TemplateVerdict,
+        now_ms: u64,
+    ) -> Result<TemplateState, String> {
+        let required = self.template_review.required_signoffs;
+        if !self.template_review.reviewers.contains(reviewer) {
+            return Err(format!("{reviewer} does not review mission templates"));
+        }
+        let submission = self.current_submission(id)?;
+        if submission.state != TemplateState::AwaitingSignoff {
+            return Err(format!("Template {id} is not awaiting sign-off ({:?})", submission.state));
+        }
+        if &submission.submitted_by == reviewer {
+            return Err(format!("{reviewer} submitted template {id} and cannot sign it off"));
+        }
+        if submission.signoffs.iter().any(|s| &s.reviewer == reviewer) {
+            return Err(format!("{reviewer} has already signed off template {id}"));
+        }
+        let submission = self.template_submissions.get_mut(&id.mission).expect("checked above");
+        let signoff = TemplateSignoff { reviewer: reviewer.clone(), verdict: verdict.clone(), at_ms: now_ms };
+        submission.signoffs.push(signoff);
+        let approvals = submission.signoffs.iter().filter(|s| s.verdict == TemplateVerdict::Approve).count();
+        let state = match verdict {
+            TemplateVerdict::Reject { reason } => {
+                TemplateState::Rejected { reasons: vec![format!("{reviewer}: {reason}")] }
+            }
+            TemplateVerdict::Approve if approvals >= required => TemplateState::Approved,
+            TemplateVerdict::Approve => TemplateState::AwaitingSignoff,
+        };
+        self.settle_template(id, state.clone());
+        Ok(state)
+    }
+
+    /// The latest version submitted for `mission`, with its state and sign-offs.
+    pub fn template_submission(&self, mission: &MissionId) -> Option<&TemplateSubmission> {
+        self.template_submissions.get(mission)
+    }
+
+    /// Templates awaiting `reviewer`'s sign-off, by mission id.
+    pub fn templates_awaiting_signoff(&self, reviewer: &Did) -> Vec<&TemplateSubmission> {
+        if !self.template_review.reviewers.contains(reviewer) {
+            return Vec::new();
+        }
+        self.template_submissions
+            .values()
+            .filter(|s| s.state == TemplateState::AwaitingSignoff && &s.submitted_by != reviewer)
+            .filter(|s| s.signoffs.iter().all(|signoff| &signoff.reviewer != reviewer))
+            .collect()
+    }
+
+    /// Version of the live template for `mission`: its approved version, 0 if it was added
+    /// without review, `None` if nothing for it is assignable.
+    pub fn template_version(&self, mission: &MissionId) -> Option<u32> {
+        if !self.templates.contains_key(mission) {
+            return None;
+        }
+        let approved = self.template_submissions.get(mission).filter(|s| s.state == TemplateState::Approved);
+        Some(approved.map_or(0, |s| s.id.version))
+    }
+
+    fn current_submission(&self, id: &TemplateId) -> Result<&TemplateSubmission, String> {
+        match self.template_submissions.get(&id.mission) {
+            Some(submission) if submission.id == *id => Ok(submission),
+            Some(submission) => Err(format!("Template {id} was superseded by {}", submission.id)),
+            None => Err(format!("No template {id} was submitted")),
+        }
+    }
+
+    /// Move `id` to `state`, publishing it when approved.
+    fn settle_template(&mut self, id: &TemplateId, state: TemplateState) {
+        let submission = self.template_submissions.get_mut(&id.mission).expect("current submission");
+        submission.state = state;
+        if submission.state == TemplateState::Approved {
+            let tpl = submission.template.clone();
+            self.add_template(tpl);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+// path: planetary_stewardship_runtime/src/timeline.rs
+
+//! Per-actor attestation timelines for verifiers.
+//! - Entries in time order, each annotated with advisory `TimelineFlag`s
+//! - Flags never block anything; they point a human reviewer at patterns worth a look
+//! - Thresholds live in `TimelineOptions`; the defaults suit monthly field work
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+use crate::{AttestationId, Did, ImpactMetrics, StewardshipAttestation};
+
+const DAY_MS: u64 = 86_400_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TimelineOptions {
+    /// Attestations with identical metrics this close together form a burst.
+    pub burst_window_ms: u64,
+    /// Smallest burst worth flagging.
+    pub burst_min_count: usize,
+    /// |z| at or above which a metric is an outlier, measured against the actor's other attestations.
+    pub outlier_zscore: f64,
+    /// Attestations needed before outliers are computed at all.
+    pub outlier_min_history: usize,
+    /// Evidence URIs or content hashes seen again after at least this long count as reused.
+    pub evidence_reuse_min_ms: u64,
+    /// Silence at least this long before a claim makes it a candidate spike.
+    pub gap_ms: u64,
+    /// The claim must be this multiple of the median of earlier claims (`co2eq_reduced`).
+    pub spike_factor: f64,
+}
+
+impl Default for TimelineOptions {
+    fn default() -> Self {
+        Self {
+            burst_window_ms: 10 * 60_000,
+            burst_min_count: 3,
+            outlier_zscore: 3.0,
+            outlier_min_history: 5,
+            evidence_reuse_min_ms: 30 * DAY_MS,
+            gap_ms: 90 * DAY_MS,
+            spike_factor: 5.0,
+        }
+    }
+}
+
+/// Advisory pattern attached to a timeline entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TimelineFlag {
+    /// First entry of `size` attestations with identical metrics within the burst window.
+    BurstCluster { size: usize, last_ms: u64 },
+    /// `metric` lies `zscore` standard deviations from the actor's other attestations.
+    MetricOutlier { metric: String, zscore: f64 },
+    /// Same evidence URI as an attestation at least `evidence_reuse_min_ms` earlier.
+    ReusedEvidence { first_attestation: AttestationId },
+    /// An artifact with the same content hash as one in an attestation at least
+    /// `evidence_reuse_min_ms` earlier, whatever its URI.
+    ReusedContentHash { first_attestation: AttestationId, content_hash: String },
+    /// A claim `ratio` times the earlier median after `gap_ms` of silence.
+    GapThenSpike { gap_ms: u64, ratio: f64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TimelineEntry {
+    pub attestation: StewardshipAttestation,
+    /// Time since the actor's previous attestation.
+    #[serde(default)]
+    pub gap_before_ms: Option<u64>,
+    #[serde(default)]
+    pub flags: Vec<TimelineFlag>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActorTimeline {
+    pub actor: Did,
+    /// Oldest first; ties broken by attestation id.
+    pub entries: Vec<TimelineEntry>,
+}
+
+impl ActorTimeline {
+    /// Entries carrying at least one flag.
+    pub fn flagged(&self) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries.iter().filter(|e| !e.flags.is_empty())
+    }
+}
+
+fn metric_values(m: &ImpactMetrics) -> [(&'static str, f64); 4] {
+    [
+        ("co2eq_reduced", m.co2eq_reduced),
+        ("biodiversity_index_delta", m.biodiversity_index_delta),
+        ("restored_area_m2", m.restored_area_m2),
+        ("avoided_emissions_co2eq", m.avoided_emissions_co2eq),
+    ]
+}
+
+fn same_metrics(a: &ImpactMetrics, b: &ImpactMetrics) -> bool {
+    metric_values(a)
+        .iter()
+        .zip(metric_values(b).iter())
+        .all(|((_, x), (_, y))| x == y)
+        && a.custom == b.custom
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Build `actor`'s timeline from their attestations (any order).
+pub fn build_timeline(actor: &Did, attestations: Vec<&StewardshipAttestation>, options: &TimelineOptions) -> ActorTimeline {
+    let mut sorted = attestations;
+    sorted.sort_by(|a, b| (a.timestamp_ms, &a.id.0).cmp(&(b.timestamp_ms, &b.id.0)));
+    let mut entries: Vec<TimelineEntry> = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, a)| TimelineEntry {
+            attestation: (*a).clone(),
+            gap_before_ms: i.checked_sub(1).map(|p| a.timestamp_ms - sorted[p].timestamp_ms),
+            flags: Vec::new(),
+        })
+        .collect();
+
+    flag_bursts(&mut entries, options);
+    flag_outliers(&mut entries, options);
+    flag_reused_evidence(&mut entries, options);
+    flag_gap_spikes(&mut entries, options);
+
+    ActorTimeline {
+        actor: actor.clone(),
+        entries,
+    }
+}
+
+/// Runs of identical metrics inside the window; flagged once, on the run's first entry.
+fn flag_bursts(entries: &mut [TimelineEntry], options: &TimelineOptions) {
+    let mut start = 0;
+    while start < entries.len() {
+        let first = &entries[start].attestation;
+        let end = entries[start..]
+            .iter()
+            .take_while(|e| {
+                e.attestation.timestamp_ms - first.timestamp_ms <= options.burst_window_ms
+                    && same_metrics(&e.attestation.impact_metrics, &first.impact_metrics)
+            })
+            .count()
+            + start;
+        let size = end - start;
+        if size >= options.burst_min_count.max(2) {
+            let last_ms = entries[end - 1].attestation.timestamp_ms;
+            entries[start].flags.push(TimelineFlag::BurstCluster { size, last_ms });
+            start = end;
+        } else {
+            start += 1;
+        }
+    }
+}
+
+/// Leave-one-out z-score per metric; the most extreme metric over the threshold is flagged.
+fn flag_outliers(entries: &mut [TimelineEntry], options: &TimelineOptions) {
+    if entries.len() < options.outlier_min_history.max(3) {
+        return;
+    }
+    let values: Vec<[(&'static str, f64); 4]> =
+        entries.iter().map(|e| metric_values(&e.attestation.impact_metrics)).collect();
+    for (i, entry) in entries.iter_mut().enumerate() {
+        let mut worst: Option<(&'static str, f64)> = None;
+        for m in 0..4 {
+            let others: Vec<f64> = values
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, v)| v[m].1)
+                .collect();
+            let n = others.len() as f64;
+            let mean = others.iter().sum::<f64>() / n;
+            let std = (others.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+            if std == 0.0 {
+                // Nothing to compare against without spread.
+                continue;
+            }
+            let (name, x) = values[i][m];
+            let z = (x - mean) / std;
+            if z.abs() >= options.outlier_zscore && worst.is_none_or(|(_, w)| z.abs() > w.abs()) {
+                worst = Some((name, z));
+            }
+        }
+        if let Some((metric, zscore)) = worst {
+            entry.flags.push(TimelineFlag::MetricOutlier { metric: metric.into(), zscore });
+        }
+    }
+}
+
+/// Later uses of an evidence URI, or of an artifact's content hash, at least
+/// `evidence_reuse_min_ms` after its first use. One flag of each kind per entry.
+fn flag_reused_evidence(entries: &mut [TimelineEntry], options: &TimelineOptions) {
+    let mut uris_seen: HashMap<String, (AttestationId, u64)> = HashMap::new();
+    let mut hashes_seen: HashMap<String, (AttestationId, u64)> = HashMap::new();
+    for entry in entries.iter_mut() {
+        let a = &entry.attestation;
+        let reused = |seen: &HashMap<String, (AttestationId, u64)>, key: &str| {
+            seen.get(key)
+                .filter(|(_, at)| a.timestamp_ms - at >= options.evidence_reuse_min_ms)
+                .map(|(first, _)| first.clone())
+        };
+        let uri_flag = a
+            .evidence
+            .iter()
+            .find_map(|e| reused(&uris_seen, &e.uri))
+            .map(|first| TimelineFlag::ReusedEvidence { first_attestation: first });
+        let hash_flag = a.evidence.iter().filter_map(|e| e.content_hash.as_deref()).find_map(|h| {
+            reused(&hashes_seen, h).map(|first| TimelineFlag::ReusedContentHash {
+                first_attestation: first,
+                content_hash: h.to_string(),
+            })
+        });
+        for artifact in &a.evidence {
+            uris_seen.entry(artifact.uri.clone()).or_insert_with(|| (a.id.clone(), a.timestamp_ms));
+            if let Some(h) = &artifact.content_hash {
+                hashes_seen.entry(h.clone()).or_insert_with(|| (a.id.clone(), a.timestamp_ms));
+            }
+        }
+        entry.flags.extend(uri_flag.into_iter().chain(hash_flag));
+    }
+}
+
+/// A long silence followed by a claim far above the earlier median.
+fn flag_gap_spikes(entries: &mut [TimelineEntry], options: &TimelineOptions) {
+    for i in 1..entries.len() {
+        let Some(gap_ms) = entries[i].gap_before_ms.filter(|g| *g >= options.gap_ms) else {
+            continue;
+        };
+        let mut earlier: Vec<f64> = entries[..i].iter().map(|e| e.attestation.impact_metrics.co2eq_reduced).collect();
+        let baseline = median(&mut earlier);
+        if baseline <= 0.0 {
+            continue;
+        }
+        let ratio = entries[i].attestation.impact_metrics.co2eq_reduced / baseline;
+        if ratio >= options.spike_factor {
+            entries[i].flags.push(TimelineFlag::GapThenSpike { gap_ms, ratio });
+        }
+    }
+}
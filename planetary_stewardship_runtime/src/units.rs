@@ -0,0 +1,47 @@
+// path: planetary_stewardship_runtime/src/units.rs
+
+//! Units for the headline impact metrics, for records measured in something else.
+//! - Mass, for `co2eq_reduced` and `avoided_emissions_co2eq`: `t` (canonical), `kg`, `g`, `lb`;
+//!   `tco2e` and `kgco2e` are read as `t` and `kg`
+//! - Area, for `restored_area_m2`: `m2` (canonical), `ha`, `km2`, `acre`, `ft2`
+//! - Index, for `biodiversity_index_delta`: unitless, written as an empty unit or `index`
+//! - Units match case-insensitively; converting between dimensions is refused
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Mass,
+    Area,
+    Index,
+}
+
+/// Dimension of `unit` and how many canonical units one of it is.
+fn lookup(unit: &str) -> Option<(Dimension, f64)> {
+    let found = match unit.trim().to_ascii_lowercase().as_str() {
+        "t" | "tco2e" => (Dimension::Mass, 1.0),
+        "kg" | "kgco2e" => (Dimension::Mass, 1e-3),
+        "g" => (Dimension::Mass, 1e-6),
+        "lb" => (Dimension::Mass, 0.000_453_592_37),
+        "m2" => (Dimension::Area, 1.0),
+        "ha" => (Dimension::Area, 1e4),
+        "km2" => (Dimension::Area, 1e6),
+        "acre" => (Dimension::Area, 4_046.856_422_4),
+        "ft2" => (Dimension::Area, 0.092_903_04),
+        "" | "index" => (Dimension::Index, 1.0),
+        _ => return None,
+    };
+    Some(found)
+}
+
+pub fn dimension(unit: &str) -> Option<Dimension> {
+    lookup(unit).map(|(dimension, _)| dimension)
+}
+
+/// `value` in `from`, expressed in `to`.
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, String> {
+    let (from_dim, from_factor) = lookup(from).ok_or_else(|| format!("Unknown unit {from:?}"))?;
+    let (to_dim, to_factor) = lookup(to).ok_or_else(|| format!("Unknown unit {to:?}"))?;
+    if from_dim != to_dim {
+        return Err(format!("Cannot convert {from:?} ({from_dim:?}) to {to:?} ({to_dim:?})"));
+    }
+    Ok(value * from_factor / to_factor)
+}
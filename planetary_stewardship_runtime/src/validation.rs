@@ -0,0 +1,167 @@
+// path: planetary_stewardship_runtime/src/validation.rs
+
+//! Dry-run validation for attestations and mission assignments.
+//! - `PlanetaryLedger::validate_attestation` / `MicroMissionsEngine::validate_assignment`
+//!   run the same checks as issuance and assignment, in read-only mode
+//! - Every failing check is reported, not just the first; issuance itself still stops at the first
+//! - `conditions` lists what SAEP would attach on success (consent, intent entry, rollback plan),
+//!   and the witness endorsement a claim above the impact thresholds needs
+//! - `warnings` are advisory (evidence captured out of window, evidence content already used);
+//!   they never make a report invalid
+//! - Nothing is published, reserved or recorded
+//! - Each issue carries the `Reason` its message renders from; apps branch on `code` or
+//!   `reason`, and render `reason` in the participant's language
+
+use serde::{Serialize, Deserialize};
+
+use std::collections::BTreeMap;
+
+use crate::{
+    evidence, is_plga, plga_module, Did, EvidenceArtifact, ImpactMetrics, IntentEntryId, MissionId, ModuleMetrics,
+    PlanId, Reason, ReasonCode, SaepRefs, StewardModule, WitnessEndorsement,
+};
+
+/// Everything `issue_attestation_with_refs` takes, as one value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AttestationRequest {
+    pub actor_did: Did,
+    #[serde(default)]
+    pub mission_id: Option<MissionId>,
+    pub description: String,
+    /// A module other than PLGA claims `module_metrics` under its registered schema instead of
+    /// `impact_metrics`, and needs its actor's consent in that module.
+    #[serde(default = "plga_module", skip_serializing_if = "is_plga")]
+    pub module: StewardModule,
+    pub impact_metrics: ImpactMetrics,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub module_metrics: ModuleMetrics,
+    /// A single `evidence_uri` string is accepted as a one-artifact bundle.
+    #[serde(alias = "evidence_uri", deserialize_with = "evidence::bundle_or_uri")]
+    pub evidence: Vec<EvidenceArtifact>,
+    #[serde(default)]
+    pub verifier_dids: Vec<Did>,
+    pub timestamp_ms: u64,
+    #[serde(default)]
+    pub refs: SaepRefs,
+    /// `[start_ms, end_ms]` the evidence should be captured in, e.g. the mission's assignment
+    /// period or the epoch being attested; defaults to the ledger policy's window.
+    #[serde(default)]
+    pub capture_window: Option<(u64, u64)>,
+    /// Needed when the claim is above the ledger's `ImpactThresholdPolicy`; must cover
+    /// `witness::statement_hash` of this request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub witness_endorsement: Option<WitnessEndorsement>,
+}
+
+/// Machine-readable reason a check failed; matches the `reason` field of the denial trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ValidationCode {
+    ModulePaused,
+    /// Metrics that do not fit the module's `MetricSchema`.
+    MetricSchema,
+    CustomMetric,
+    Biodiversity,
+    UnknownMission,
+    AlreadyAssigned,
+    /// An assignment limit of the missions engine's `FairnessPolicy`.
+    Fairness,
+    Saep,
+    ConsentMissing,
+    RollbackPlan,
+    IntentLog,
+    Evidence,
+    Witness,
+    /// A listed verifier's declared conflict with the actor is `Forbidden`.
+    VerifierConflict,
+    /// Description refused by the content policy.
+    Content,
+    /// Warning only.
+    EvidenceOutsideWindow,
+    /// Warning only.
+    EvidenceReused,
+    /// Warning only: raw HTML or an unsafe link was removed from the description.
+    ContentSanitized,
+    /// Warning only: PII under `PiiAction::Warn`, or a flagged term.
+    ContentFlagged,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ValidationIssue {
+    pub code: ValidationCode,
+    /// The message the real operation would fail with.
+    pub message: String,
+    /// `message` as a code and parameters; `None` only in reports from before reason codes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<Reason>,
+}
+
+impl ValidationIssue {
+    fn new(code: ValidationCode, reason: Reason) -> Self {
+        Self { code, message: reason.to_string(), reason: Some(reason) }
+    }
+}
+
+/// Wrap a lower-level check's message as `code`'s `detail`.
+pub(crate) fn detail(code: ReasonCode) -> impl Fn(String) -> Reason {
+    move |message| Reason::new(code).with("detail", message)
+}
+
+/// Something SAEP attaches to the action when it goes through.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ValidationCondition {
+    /// KSCP consent for this purpose; under strict prompts it must carry `prompt_hash`.
+    Consent { purpose: String, prompt_hash: String },
+    /// A public intent entry: the supplied one, or `None` for one published on success.
+    PublicIntentLog { intent_entry_id: Option<IntentEntryId> },
+    /// A registered rollback plan covering the action.
+    RollbackPlan { plan_id: Option<PlanId> },
+    /// An endorsement of `statement_hash` by one of `witnesses`.
+    Witness { statement_hash: String, witnesses: Vec<Did> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ValidationReport {
+    pub module: StewardModule,
+    /// Failing checks in pipeline order.
+    pub issues: Vec<ValidationIssue>,
+    #[serde(default)]
+    pub conditions: Vec<ValidationCondition>,
+    /// Advisory findings for a reviewer; issuance ignores them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub(crate) fn new(module: StewardModule) -> Self {
+        Self {
+            module,
+            issues: Vec::new(),
+            conditions: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Whether the real operation would succeed right now.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn has(&self, code: ValidationCode) -> bool {
+        self.issues.iter().any(|i| i.code == code)
+    }
+
+    pub(crate) fn warn(&mut self, code: ValidationCode, reason: Reason) {
+        self.warnings.push(ValidationIssue::new(code, reason));
+    }
+
+    pub(crate) fn check(&mut self, code: ValidationCode, result: Result<(), Reason>) {
+        if let Err(reason) = result {
+            self.issues.push(ValidationIssue::new(code, reason));
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// path: planetary_stewardship_runtime/src/verification.rs
+
+//! Delegated verification: a claim goes to its named verifiers before it is issued.
+//! - `submit_for_verification` validates the request and holds it as a `PendingVerification`;
+//!   its `verifier_dids` become the requested verifiers, each of whom reviews it once
+//! - `Approve` carries the verifier's signature over the draft's `witness::statement_hash`,
+//!   checked with the ledger's `KeyResolver` against the verifier's attestation-verification
+//!   key `key_id` as it stands at the review
+//! - Transitions, all from `AwaitingReview`:
+//!   - the `quorum`-th approval issues the draft, with the approvers as its verifiers, and
+//!     `Finalized` it (`Rejected` instead if issuance refuses it by then). Approvals from
+//!     verifiers with a conflict with the actor count only if it is `CountedFlagged`
+//!   - a `Reject` that leaves too few unreviewed verifiers to reach quorum: `Rejected`
+//!   - a `RequestChanges`: `ChangesRequested`, back with the actor and the notes, until
+//!     `resubmit_verification` sends a revised draft out again with every verdict cleared
+//!   - any call at or after the deadline, or `expire_verifications`: `Expired`
+//! - `Finalized`, `Rejected` and `Expired` are final; every review of them is refused
+
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use crate::keys::{KeyError, KeyPurpose};
+use crate::witness::statement_hash;
+use crate::{AttestationId, AttestationRequest, Did, PlanetaryLedger};
+
+const DAY_MS: u64 = 86_400_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerificationPolicy {
+    /// Approvals that issue a draft.
+    pub quorum: usize,
+    /// From submission (or resubmission) to the deadline.
+    pub review_window_ms: u64,
+}
+
+impl Default for VerificationPolicy {
+    fn default() -> Self {
+        Self { quorum: 1, review_window_ms: 7 * DAY_MS }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PendingVerificationId(pub String);
+
+impl fmt::Display for PendingVerificationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VerifierVerdict {
+    /// `signature` is over the bytes of the draft's `statement_hash`, made with the verifier's key `key_id`.
+    Approve {
+        signature: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        key_id: String,
+    },
+    RequestChanges { notes: String },
+    Reject { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierReview {
+    pub verifier: Did,
+    pub verdict: VerifierVerdict,
+    pub at_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PendingState {
+    AwaitingReview,
+    ChangesRequested { by: Did, notes: String },
+    Finalized { attestation_id: AttestationId },
+    /// The verifiers' reject reasons, or issuance's refusal of the approved draft.
+    Rejected { reasons: Vec<String> },
+    Expired,
+}
+
+/// A claim waiting for its verifiers, or what became of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PendingVerification {
+    pub id: PendingVerificationId,
+    /// The request as submitted, without verifiers; they are added as they approve.
+    pub attestation_draft: AttestationRequest,
+    pub statement_hash: String,
+    pub requested_verifiers: Vec<Did>,
+    pub submitted_ms: u64,
+    pub deadline_ms: u64,
+    /// Verdicts on the current draft, oldest first.
+    #[serde(default)]
+    pub reviews: Vec<VerifierReview>,
+    pub state: PendingState,
+}
+
+impl PendingVerification {
+    /// Verifiers who approved the current draft, in review order.
+    pub fn approvals(&self) -> impl Iterator<Item = &Did> {
+        self.reviews.iter().filter(|r| matches!(r.verdict, VerifierVerdict::Approve { .. })).map(|r| &r.verifier)
+    }
+
+    pub fn has_reviewed(&self, verifier: &Did) -> bool {
+        self.reviews.iter().any(|r| &r.verifier == verifier)
+    }
+
+    /// `AwaitingReview` or `ChangesRequested`.
+    pub fn is_open(&self) -> bool {
+        matches!(self.state, PendingState::AwaitingReview | PendingState::ChangesRequested { .. })
+    }
+}
+
+/// Why a review or resubmission was refused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReviewError {
+    UnknownPending(PendingVerificationId),
+    /// The item is not in a state that takes this call.
+    WrongState { state: PendingState },
+    /// The item has expired, now if not before.
+    PastDeadline { deadline_ms: u64, at_ms: u64 },
+    NotRequested { verifier: Did },
+    AlreadyReviewed { verifier: Did },
+    BadSignature { verifier: Did },
+    /// The approval's key is unknown, revoked, out of its validity or not for attestations.
+    KeyRejected { verifier: Did, error: KeyError },
+    /// Only the submitting actor may resubmit, and the revision must be their claim.
+    NotActor { actor: Did },
+    /// The revised draft fails validation.
+    Invalid { reasons: Vec<String> },
+}
+
+impl fmt::Display for ReviewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReviewError::UnknownPending(id) => write!(f, "No pending verification {id}"),
+            ReviewError::WrongState { state } => write!(f, "Pending verification is {state:?}"),
+            ReviewError::PastDeadline { deadline_ms, at_ms } => {
+                write!(f, "Review deadline {deadline_ms} has passed (now {at_ms})")
+            }
+            ReviewError::NotRequested { verifier } => write!(f, "{verifier} was not asked to verify this claim"),
+            ReviewError::AlreadyReviewed { verifier } => write!(f, "{verifier} has already reviewed this claim"),
+            ReviewError::BadSignature { verifier } => {
+                write!(f, "Approval by {verifier} is not signed over the claim's statement")
+            }
+            ReviewError::KeyRejected { verifier, error } => write!(f, "Approval by {verifier} refused: {error}"),
+            ReviewError::NotActor { actor } => write!(f, "Only {actor} may resubmit this claim"),
+            ReviewError::Invalid { reasons } => write!(f, "Revised claim is invalid: {}", reasons.join("; ")),
+        }
+    }
+}
+
+impl std::error::Error for ReviewError {}
+
+impl PlanetaryLedger {
+    /// Approvals needed and time allowed for verifications submitted from now on.
+    pub fn with_verification_policy(mut self, policy: VerificationPolicy) -> Self {
+        self.verification_policy = policy;
+        self
+    }
+
+    pub fn verification_policy(&self) -> &VerificationPolicy {
+        &self.verification_policy
+    }
+
+    /// Hold `request` for its `verifier_dids` instead of issuing it. Refused when it fails
+    /// validation, names no verifier or one twice, or names fewer than the policy's quorum.
+    pub fn submit_for_verification(
+        &mut self,
+        mut request: AttestationRequest,
+    ) -> Result<PendingVerificationId, String> {
+        let requested = std::mem::take(&mut request.verifier_dids);
+        let quorum = self.verification_policy.quorum.max(1);
+        if requested.len() < quorum {
+            return Err(format!("Claim names {} verifiers; the quorum is {quorum}", requested.len()));
+        }
+        if let Some(twice) = requested.iter().enumerate().find(|(i, v)| requested[..*i].contains(v)) {
+            return Err(format!("Claim names {} twice as verifier", twice.1));
+        }
+        self.check_draft(&request).map_err(|reasons| reasons.join("; "))?;
+        let now_ms = self.clock.now_ms();
+        let id = PendingVerificationId(format!("pv:{}", self.ids.next_id()));
+        let pending = PendingVerification {
+            id: id.clone(),
+            statement_hash: statement_hash(&request),
+            attestation_draft: request,
+            requested_verifiers: requested,
+            submitted_ms: now_ms,
+            deadline_ms: now_ms.saturating_add(self.verification_policy.review_window_ms),
+            reviews: Vec::new(),
+            state: PendingState::AwaitingReview,
+        };
+        self.pending_verifications.insert(id.clone(), pending);
+        Ok(id)
+    }
+
+    /// Record `verifier`'s verdict on `pending_id` and apply the transition it causes; returns
+    /// the resulting state.
+    pub fn review(
+        &mut self,
+        pending_id: &PendingVerificationId,
+        verifier: &Did,
+        verdict: VerifierVerdict,
+    ) -> Result<PendingState, ReviewError> {
+        let now_ms = self.clock.now_ms();
+        let quorum = self.verification_policy.quorum.max(1);
+        self.open_pending(pending_id, now_ms)?;
+        let pending = &self.pending_verifications[pending_id];
+        if pending.state != PendingState::AwaitingReview {
+            return Err(ReviewError::WrongState { state: pending.state.clone() });
+        }
+        if !pending.requested_verifiers.contains(verifier) {
+            return Err(ReviewError::NotRequested { verifier: verifier.clone() });
+        }
+        if pending.has_reviewed(verifier) {
+            return Err(ReviewError::AlreadyReviewed { verifier: verifier.clone() });
+        }
+        if let VerifierVerdict::Approve { signature, key_id } = &verdict {
+            let Some(keys) = self.key_resolver.as_deref() else {
+                return Err(ReviewError::BadSignature { verifier: verifier.clone() });
+            };
+            let purpose = KeyPurpose::AttestationVerification;
+            match keys.verify(verifier, key_id, purpose, now_ms, pending.statement_hash.as_bytes(), signature) {
+                Ok(_) => {}
+                Err(KeyError::BadSignature { .. }) => {
+                    return Err(ReviewError::BadSignature { verifier: verifier.clone() });
+                }
+                Err(error) => return Err(ReviewError::KeyRejected { verifier: verifier.clone(), error }),
+            }
+        }
+
+        let draft = &pending.attestation_draft;
+        let not_counting: Vec<Did> = pending
+            .requested_verifiers
+            .iter()
+            .filter(|v| !self.counts_toward_quorum(v, &draft.actor_did, draft.timestamp_ms))
+            .cloned()
+            .collect();
+        let counts = |v: &&Did| !not_counting.contains(v);
+
+        let pending = self.pending_verifications.get_mut(pending_id).expect("checked above");
+        pending.reviews.push(VerifierReview { verifier: verifier.clone(), verdict: verdict.clone(), at_ms: now_ms });
+        match verdict {
+            VerifierVerdict::Approve { .. } if pending.approvals().filter(counts).count() >= quorum => {
+                let mut request = pending.attestation_draft.clone();
+                let approvers: Vec<Did> = pending.approvals().cloned().collect();
+                request.verifier_dids =
+                    pending.requested_verifiers.iter().filter(|v| approvers.contains(v)).cloned().collect();
+                let state = match self.issue_attestation_idempotent(request, None) {
+                    Ok(att) => PendingState::Finalized { attestation_id: att.id },
+                    Err(e) => PendingState::Rejected { reasons: vec![e] },
+                };
+                self.pending_verifications.get_mut(pending_id).expect("checked above").state = state.clone();
+                Ok(state)
+            }
+            VerifierVerdict::Approve { .. } => Ok(pending.state.clone()),
+            VerifierVerdict::RequestChanges { notes } => {
+                pending.state = PendingState::ChangesRequested { by: verifier.clone(), notes };
+                Ok(pending.state.clone())
+            }
+            VerifierVerdict::Reject { .. } => {
+                let unreviewed =
+                    pending.requested_verifiers.iter().filter(|v| !pending.has_reviewed(v)).filter(counts).count();
+                if pending.approvals().filter(counts).count() + unreviewed < quorum {
+                    let reasons = pending
+                        .reviews
+                        .iter()
+                        .filter_map(|r| match &r.verdict {
+                            VerifierVerdict::Reject { reason } => Some(format!("{}: {reason}", r.verifier)),
+                            _ => None,
+                        })
+                        .collect();
+                    pending.state = PendingState::Rejected { reasons };
+                }
+                Ok(pending.state.clone())
+            }
+        }
+    }
+
+    /// Send a draft back out after `ChangesRequested`: `revised` replaces it (its verifiers are
+    /// ignored), every verdict is cleared and the deadline restarts.
+    pub fn resubmit_verification(
+        &mut self,
+        pending_id: &PendingVerificationId,
+        actor: &Did,
+        mut revised: AttestationRequest,
+    ) -> Result<(), ReviewError> {
+        let now_ms = self.clock.now_ms();
+        self.open_pending(pending_id, now_ms)?;
+        let pending = &self.pending_verifications[pending_id];
+        if !matches!(pending.state, PendingState::ChangesRequested { .. }) {
+            return Err(ReviewError::WrongState { state: pending.state.clone() });
+        }
+        let submitter = pending.attestation_draft.actor_did.clone();
+        if actor != &submitter || revised.actor_did != submitter {
+            return Err(ReviewError::NotActor { actor: submitter });
+        }
+        revised.verifier_dids.clear();
+        self.check_draft(&revised).map_err(|reasons| ReviewError::Invalid { reasons })?;
+        let window_ms = self.verification_policy.review_window_ms;
+        let pending = self.pending_verifications.get_mut(pending_id).expect("checked above");
+        pending.statement_hash = statement_hash(&revised);
+        pending.attestation_draft = revised;
+        pending.reviews.clear();
+        pending.deadline_ms = now_ms.saturating_add(window_ms);
+        pending.state = PendingState::AwaitingReview;
+        Ok(())
+    }
+
+    /// Expire every open item whose deadline has passed; returns their ids.
+    pub fn expire_verifications(&mut self) -> Vec<PendingVerificationId> {
+        let now_ms = self.clock.now_ms();
+        let mut expired = Vec::new();
+        for pending in self.pending_verifications.values_mut() {
+            if pending.is_open() && now_ms >= pending.deadline_ms {
+                pending.state = PendingState::Expired;
+                expired.push(pending.id.clone());
+            }
+        }
+        expired
+    }
+
+    /// Items waiting for `verifier`'s verdict, soonest deadline first.
+    pub fn verification_inbox(&self, verifier: &Did) -> Vec<&PendingVerification> {
+        let now_ms = self.clock.now_ms();
+        let mut inbox: Vec<&PendingVerification> = self
+            .pending_verifications
+            .values()
+            .filter(|p| p.state == PendingState::AwaitingReview && now_ms < p.deadline_ms)
+            .filter(|p| p.requested_verifiers.contains(verifier) && !p.has_reviewed(verifier))
+            .collect();
+        inbox.sort_by(|a, b| (a.deadline_ms, &a.id).cmp(&(b.deadline_ms, &b.id)));
+        inbox
+    }
+
+    /// Everything `actor` submitted for verification, in any state, by id.
+    pub fn verifications_submitted_by(&self, actor: &Did) -> Vec<&PendingVerification> {
+        self.pending_verifications.values().filter(|p| &p.attestation_draft.actor_did == actor).collect()
+    }
+
+    pub fn pending_verification(&self, pending_id: &PendingVerificationId) -> Option<&PendingVerification> {
+        self.pending_verifications.get(pending_id)
+    }
+
+    /// Whether the item is open and within its deadline; an open item past it expires here.
+    fn open_pending(&mut self, pending_id: &PendingVerificationId, now_ms: u64) -> Result<(), ReviewError> {
+        let pending = self
+            .pending_verifications
+            .get_mut(pending_id)
+            .ok_or_else(|| ReviewError::UnknownPending(pending_id.clone()))?;
+        if pending.is_open() && now_ms >= pending.deadline_ms {
+            pending.state = PendingState::Expired;
+        }
+        match pending.state {
+            PendingState::Expired => Err(ReviewError::PastDeadline { deadline_ms: pending.deadline_ms, at_ms: now_ms }),
+            PendingState::Finalized { .. } | PendingState::Rejected { .. } => {
+                Err(ReviewError::WrongState { state: pending.state.clone() })
+            }
+            PendingState::AwaitingReview | PendingState::ChangesRequested { .. } => Ok(()),
+        }
+    }
+
+    /// Validation issues issuance would refuse the draft for.
+    fn check_draft(&self, request: &AttestationRequest) -> Result<(), Vec<String>> {
+        let report = self.validate_attestation(request);
+        if report.is_valid() {
+            Ok(())
+        } else {
+            Err(report.issues.into_iter().map(|i| i.message).collect())
+        }
+    }
+}
@@ -0,0 +1,379 @@
+// path: planetary_stewardship_runtime/src/verifier.rs
+
+//! Verifier workload and independence.
+//! - Per-day counters are updated as attestations are issued, so a report sums days instead of
+//!   rescanning the ledger; report windows are therefore whole UTC days
+//! - Per verifier: attestations verified, distinct actors, largest actor share and a
+//!   Herfindahl index over actor shares
+//! - Flags: a verifier covering most of one actor's attestations, a verifier whose work is
+//!   mostly one actor, and verifier pairs that (almost) always sign together
+//! - `VerifierRegistry` holds downgrades: a downgraded verifier still appears on the actor's
+//!   later attestations but in `non_counting_verifiers`; downgrades are audited and reversible
+//! - It also holds conflict-of-interest declarations, audited too; see `conflict`
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::conflict::{ConflictDeclaration, ConflictPolicy, RelationshipKind};
+use crate::{Did, StewardshipAttestation};
+
+const DAY_MS: u64 = 86_400_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierThresholds {
+    /// Smallest count (actor attestations, verifier workload, joint signatures) worth judging.
+    pub min_attestations: usize,
+    /// Flag a verifier that signed at least this share of one actor's attestations.
+    pub max_actor_coverage: f64,
+    /// Flag a verifier whose work is at least this share one actor's.
+    pub max_actor_share: f64,
+    /// Flag a pair whose joint signatures over their combined work (Jaccard) reach this.
+    pub co_verification_jaccard: f64,
+}
+
+impl Default for VerifierThresholds {
+    fn default() -> Self {
+        Self {
+            min_attestations: 5,
+            max_actor_coverage: 0.9,
+            max_actor_share: 0.8,
+            co_verification_jaccard: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierPolicy {
+    pub thresholds: VerifierThresholds,
+    /// `apply_verifier_report` downgrades flagged verifier/actor relationships when set.
+    #[serde(default)]
+    pub auto_downgrade: bool,
+    /// What issuance does with the signatures of verifiers with a declared conflict.
+    #[serde(default)]
+    pub conflicts: ConflictPolicy,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierSummary {
+    pub verifier: Did,
+    pub attestations_verified: usize,
+    pub distinct_actors: usize,
+    /// The actor taking the largest share of this verifier's work (lowest DID on ties).
+    pub top_actor: Did,
+    pub max_actor_share: f64,
+    /// Sum of squared actor shares: 1.0 means one actor, 1/n means n actors equally.
+    pub herfindahl: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CoVerification {
+    /// `a` < `b`.
+    pub a: Did,
+    pub b: Did,
+    pub together: usize,
+    /// `together` over the attestations either verified.
+    pub jaccard: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VerifierFlag {
+    /// `verifier` signed `coverage` of `actor`'s attestations in the window.
+    ActorCoverage { verifier: Did, actor: Did, coverage: f64 },
+    /// `share` of `verifier`'s work in the window was for `actor`.
+    ActorConcentration { verifier: Did, actor: Did, share: f64 },
+    /// `a` and `b` signed together `together` times, Jaccard `jaccard`.
+    AlwaysTogether { a: Did, b: Did, together: usize, jaccard: f64 },
+}
+
+impl VerifierFlag {
+    /// The verifier/actor relationship a flag is about, for downgrading.
+    pub fn relationship(&self) -> Option<(&Did, &Did)> {
+        match self {
+            VerifierFlag::ActorCoverage { verifier, actor, .. }
+            | VerifierFlag::ActorConcentration { verifier, actor, .. } => Some((verifier, actor)),
+            VerifierFlag::AlwaysTogether { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierReport {
+    /// `[start_ms, end_ms)`, widened to whole UTC days.
+    pub window: (u64, u64),
+    /// By verifier DID.
+    pub verifiers: Vec<VerifierSummary>,
+    /// Pairs that signed together at least once, by (`a`, `b`).
+    pub co_verifications: Vec<CoVerification>,
+    pub flags: Vec<VerifierFlag>,
+}
+
+/// One day's counters.
+#[derive(Debug, Clone, Default)]
+struct DayCounts {
+    actor_attestations: HashMap<Did, usize>,
+    /// (verifier, actor) -> attestations.
+    verified: HashMap<(Did, Did), usize>,
+    /// (a, b) with a < b -> attestations both signed.
+    together: HashMap<(Did, Did), usize>,
+}
+
+/// Counters behind `verifier_report`, updated per issued attestation.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct VerifierStats {
+    days: BTreeMap<u64, DayCounts>,
+}
+
+impl VerifierStats {
+    pub(crate) fn record(&mut self, att: &StewardshipAttestation) {
+        let day = self.days.entry(att.timestamp_ms / DAY_MS).or_default();
+        *day.actor_attestations.entry(att.actor_did.clone()).or_default() += 1;
+        // A verifier named twice on one attestation still signed it once.
+        let verifiers: BTreeSet<&Did> = att.verifier_dids.iter().collect();
+        for v in &verifiers {
+            *day.verified.entry(((*v).clone(), att.actor_did.clone())).or_default() += 1;
+        }
+        for (i, a) in verifiers.iter().enumerate() {
+            for b in verifiers.iter().skip(i + 1) {
+                *day.together.entry(((*a).clone(), (*b).clone())).or_default() += 1;
+            }
+        }
+    }
+
+    pub(crate) fn report(&self, window: (u64, u64), thresholds: &VerifierThresholds) -> VerifierReport {
+        let first = window.0 / DAY_MS;
+        let last = window.1.div_ceil(DAY_MS);
+        let mut actors: BTreeMap<&Did, usize> = BTreeMap::new();
+        let mut verified: BTreeMap<&Did, BTreeMap<&Did, usize>> = BTreeMap::new();
+        let mut together: BTreeMap<(&Did, &Did), usize> = BTreeMap::new();
+        for counts in self.days.range(first..last).map(|(_, c)| c) {
+            for (actor, n) in &counts.actor_attestations {
+                *actors.entry(actor).or_default() += n;
+            }
+            for ((verifier, actor), n) in &counts.verified {
+                *verified.entry(verifier).or_default().entry(actor).or_default() += n;
+            }
+            for ((a, b), n) in &counts.together {
+                *together.entry((a, b)).or_default() += n;
+            }
+        }
+
+        let mut report = VerifierReport {
+            window: (first * DAY_MS, last * DAY_MS),
+            verifiers: Vec::new(),
+            co_verifications: Vec::new(),
+            flags: Vec::new(),
+        };
+        let mut totals: BTreeMap<&Did, usize> = BTreeMap::new();
+        for (verifier, per_actor) in &verified {
+            let total: usize = per_actor.values().sum();
+            totals.insert(verifier, total);
+            let share = |n: usize| n as f64 / total as f64;
+            let (top_actor, top) = per_actor
+                .iter()
+                .fold((None, 0), |best, (actor, n)| if *n > best.1 { (Some(*actor), *n) } else { best });
+            report.verifiers.push(VerifierSummary {
+                verifier: (*verifier).clone(),
+                attestations_verified: total,
+                distinct_actors: per_actor.len(),
+                top_actor: top_actor.cloned().unwrap_or_else(|| (*verifier).clone()),
+                max_actor_share: share(top),
+                herfindahl: per_actor.values().map(|n| share(*n).powi(2)).sum(),
+            });
+            for (actor, n) in per_actor {
+                let actor_total = actors.get(actor).copied().unwrap_or(0);
+                let coverage = *n as f64 / actor_total.max(1) as f64;
+                if actor_total >= thresholds.min_attestations && coverage >= thresholds.max_actor_coverage {
+                    report.flags.push(VerifierFlag::ActorCoverage {
+                        verifier: (*verifier).clone(),
+                        actor: (*actor).clone(),
+                        coverage,
+                    });
+                }
+                if total >= thresholds.min_attestations && share(*n) >= thresholds.max_actor_share {
+                    report.flags.push(VerifierFlag::ActorConcentration {
+                        verifier: (*verifier).clone(),
+                        actor: (*actor).clone(),
+                        share: share(*n),
+                    });
+                }
+            }
+        }
+        for ((a, b), n) in together {
+            let union = totals.get(a).copied().unwrap_or(0) + totals.get(b).copied().unwrap_or(0) - n;
+            let jaccard = n as f64 / union.max(1) as f64;
+            if n >= thresholds.min_attestations && jaccard >= thresholds.co_verification_jaccard {
+                report.flags.push(VerifierFlag::AlwaysTogether { a: a.clone(), b: b.clone(), together: n, jaccard });
+            }
+            report.co_verifications.push(CoVerification { a: a.clone(), b: b.clone(), together: n, jaccard });
+        }
+        report
+    }
+}
+
+/// A verifier whose signatures stop counting for one actor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierDowngrade {
+    pub verifier: Did,
+    pub actor: Did,
+    pub reason: String,
+    pub since_ms: u64,
+    /// `None` when applied automatically from a report.
+    #[serde(default)]
+    pub by: Option<Did>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VerifierAuditAction {
+    Downgrade { reason: String },
+    Restore,
+    DeclareConflict { relationship: RelationshipKind },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierAuditEntry {
+    pub timestamp_ms: u64,
+    pub verifier: Did,
+    pub actor: Did,
+    pub action: VerifierAuditAction,
+    /// `None` when applied automatically from a report.
+    #[serde(default)]
+    pub by: Option<Did>,
+}
+
+/// Downgrades in force and every change to them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierRegistry {
+    pub policy: VerifierPolicy,
+    downgrades: Vec<VerifierDowngrade>,
+    audit: Vec<VerifierAuditEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    conflicts: Vec<ConflictDeclaration>,
+}
+
+impl VerifierRegistry {
+    pub fn new(policy: VerifierPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_downgraded(&self, verifier: &Did, actor: &Did) -> bool {
+        self.downgrades.iter().any(|d| &d.verifier == verifier && &d.actor == actor)
+    }
+
+    /// In the order they were applied.
+    pub fn downgrades(&self) -> &[VerifierDowngrade] {
+        &self.downgrades
+    }
+
+    pub fn audit_trail(&self) -> &[VerifierAuditEntry] {
+        &self.audit
+    }
+
+    pub fn downgrade(
+        &mut self,
+        verifier: Did,
+        actor: Did,
+        reason: String,
+        by: Option<Did>,
+        now_ms: u64,
+    ) -> Result<(), String> {
+        if reason.trim().is_empty() {
+            return Err("A verifier downgrade needs a reason".into());
+        }
+        if self.is_downgraded(&verifier, &actor) {
+            return Err(format!("Verifier {verifier} is already downgraded for {actor}"));
+        }
+        self.audit.push(VerifierAuditEntry {
+            timestamp_ms: now_ms,
+            verifier: verifier.clone(),
+            actor: actor.clone(),
+            action: VerifierAuditAction::Downgrade { reason: reason.clone() },
+            by: by.clone(),
+        });
+        self.downgrades.push(VerifierDowngrade { verifier, actor, reason, since_ms: now_ms, by });
+        Ok(())
+    }
+
+    pub fn restore(&mut self, verifier: &Did, actor: &Did, by: Did, now_ms: u64) -> Result<(), String> {
+        let before = self.downgrades.len();
+        self.downgrades.retain(|d| !(&d.verifier == verifier && &d.actor == actor));
+        if self.downgrades.len() == before {
+            return Err(format!("Verifier {verifier} is not downgraded for {actor}"));
+        }
+        self.audit.push(VerifierAuditEntry {
+            timestamp_ms: now_ms,
+            verifier: verifier.clone(),
+            actor: actor.clone(),
+            action: VerifierAuditAction::Restore,
+            by: Some(by),
+        });
+        Ok(())
+    }
+
+    /// Declarations in the order recorded, expired ones included.
+    pub fn conflicts(&self) -> &[ConflictDeclaration] {
+        &self.conflicts
+    }
+
+    /// Declarations between `verifier` and `actor` covering `at_ms`.
+    pub fn conflicts_between<'a>(
+        &'a self,
+        verifier: &'a Did,
+        actor: &'a Did,
+        at_ms: u64,
+    ) -> impl Iterator<Item = &'a ConflictDeclaration> + 'a {
+        self.conflicts.iter().filter(move |d| &d.verifier == verifier && &d.actor == actor && d.covers(at_ms))
+    }
+
+    /// Record a declaration, which may date from before `now_ms`. Refused for a verifier
+    /// naming themselves, or one expiring before it holds.
+    pub fn declare_conflict(&mut self, declaration: ConflictDeclaration, by: Did, now_ms: u64) -> Result<(), String> {
+        if declaration.verifier == declaration.actor {
+            return Err(format!("{} cannot declare a conflict with themselves", declaration.verifier));
+        }
+        if declaration.expires_ms.is_some_and(|expires| expires <= declaration.declared_ms) {
+            let (verifier, actor) = (&declaration.verifier, &declaration.actor);
+            return Err(format!("Conflict of {verifier} with {actor} expires before it holds"));
+        }
+        self.audit.push(VerifierAuditEntry {
+            timestamp_ms: now_ms,
+            verifier: declaration.verifier.clone(),
+            actor: declaration.actor.clone(),
+            action: VerifierAuditAction::DeclareConflict { relationship: declaration.relationship },
+            by: Some(by),
+        });
+        self.conflicts.push(declaration);
+        Ok(())
+    }
+
+    /// Downgrade every relationship `report` flags, when the policy allows it.
+    /// Relationships already downgraded are skipped. Returns the new downgrades.
+    pub fn apply_report(&mut self, report: &VerifierReport, now_ms: u64) -> Vec<VerifierDowngrade> {
+        if !self.policy.auto_downgrade {
+            return Vec::new();
+        }
+        let start = self.downgrades.len();
+        for flag in &report.flags {
+            let Some((verifier, actor)) = flag.relationship() else { continue };
+            if self.is_downgraded(verifier, actor) {
+                continue;
+            }
+            let reason = format!("Automatic: {}", serde_json::to_string(flag).unwrap_or_default());
+            // Cannot fail: the reason is non-empty and the pair is not downgraded.
+            let _ = self.downgrade(verifier.clone(), actor.clone(), reason, None, now_ms);
+        }
+        self.downgrades[start..].to_vec()
+    }
+}
@@ -0,0 +1,142 @@
+// path: planetary_stewardship_runtime/src/witness.rs
+
+//! Witness co-signing for high-impact attestations.
+//! - `ImpactThresholdPolicy` sets per-metric thresholds and the witnesses trusted to co-sign;
+//!   a claim strictly above any threshold needs a `WitnessEndorsement` from one of them
+//! - Witnesses are their own set, apart from the verifier registry: a witness vouches for one
+//!   statement before it is issued, verifiers for the work behind it
+//! - An endorsement signs `statement_hash`, the SHA-256 of the claim being issued; the ledger's
+//!   `KeyResolver` checks the signature against the witness's attestation-verification key
+//!   `key_id`, as it stood at the claim's timestamp
+//! - Claims at or below every threshold need none; one supplied anyway is checked and recorded
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::keys::{KeyError, KeyPurpose, KeyResolver};
+use crate::{
+    is_plga, AttestationRequest, Did, EvidenceArtifact, ImpactMetrics, MissionId, ModuleMetrics, Reason, ReasonCode,
+    StewardModule,
+};
+
+/// A witness's signature over the statement of one attestation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WitnessEndorsement {
+    pub witness_did: Did,
+    /// Over the bytes of `statement_hash`.
+    pub signature: String,
+    /// `statement_hash` of the request the witness endorsed.
+    pub statement_hash: String,
+    /// Which of the witness's keys signed.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub key_id: String,
+}
+
+/// Claims above which issuance needs a witness. The default never does.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ImpactThresholdPolicy {
+    /// tCO₂e.
+    #[serde(default)]
+    pub co2eq_reduced_above: Option<f64>,
+    #[serde(default)]
+    pub restored_area_m2_above: Option<f64>,
+    /// DIDs whose endorsements count.
+    #[serde(default)]
+    pub witnesses: BTreeSet<Did>,
+}
+
+impl ImpactThresholdPolicy {
+    /// First threshold `metrics` is strictly above, as (metric, value, threshold).
+    pub fn exceeded(&self, metrics: &ImpactMetrics) -> Option<(&'static str, f64, f64)> {
+        [
+            ("co2eq_reduced", metrics.co2eq_reduced, self.co2eq_reduced_above),
+            ("restored_area_m2", metrics.restored_area_m2, self.restored_area_m2_above),
+        ]
+        .into_iter()
+        .find_map(|(metric, value, t)| t.filter(|t| value > *t).map(|t| (metric, value, t)))
+    }
+
+    pub fn requires_witness(&self, metrics: &ImpactMetrics) -> bool {
+        self.exceeded(metrics).is_some()
+    }
+
+    /// `endorsement` is required if `metrics` exceed a threshold, and if present must come
+    /// from a listed witness, cover `statement_hash` and verify under `resolver` with a key
+    /// valid at `as_of_ms`.
+    pub fn check(
+        &self,
+        resolver: Option<&dyn KeyResolver>,
+        metrics: &ImpactMetrics,
+        statement_hash: &str,
+        endorsement: Option<&WitnessEndorsement>,
+        as_of_ms: u64,
+    ) -> Result<(), Reason> {
+        let Some(endorsement) = endorsement else {
+            return match self.exceeded(metrics) {
+                Some((metric, value, threshold)) => Err(Reason::new(ReasonCode::WitnessRequired)
+                    .with("metric", metric)
+                    .with("value", value)
+                    .with("threshold", threshold)),
+                None => Ok(()),
+            };
+        };
+        let witness = &endorsement.witness_did;
+        if !self.witnesses.contains(witness) {
+            return Err(Reason::new(ReasonCode::WitnessUnknown).with("witness", witness));
+        }
+        if endorsement.statement_hash != statement_hash {
+            return Err(Reason::new(ReasonCode::WitnessStatementMismatch)
+                .with("witness", witness)
+                .with("statement_hash", statement_hash));
+        }
+        let Some(resolver) = resolver else {
+            return Err(Reason::new(ReasonCode::WitnessSignatureInvalid).with("witness", witness));
+        };
+        let (key_id, purpose) = (&endorsement.key_id, KeyPurpose::AttestationVerification);
+        match resolver.verify(witness, key_id, purpose, as_of_ms, statement_hash.as_bytes(), &endorsement.signature) {
+            Ok(_) => Ok(()),
+            Err(KeyError::BadSignature { .. }) => {
+                Err(Reason::new(ReasonCode::WitnessSignatureInvalid).with("witness", witness))
+            }
+            Err(error) => Err(Reason::new(ReasonCode::WitnessKeyRejected)
+                .with("witness", witness)
+                .with("key_id", key_id)
+                .with("problem", error)),
+        }
+    }
+}
+
+/// What a witness endorses: who claims what, when, on which evidence.
+#[derive(Serialize)]
+struct Statement<'a> {
+    actor_did: &'a Did,
+    mission_id: &'a Option<MissionId>,
+    description: &'a str,
+    /// Left out for PLGA, so PLGA statements hash as they did before modules.
+    #[serde(skip_serializing_if = "is_plga")]
+    module: StewardModule,
+    impact_metrics: &'a ImpactMetrics,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    module_metrics: &'a ModuleMetrics,
+    evidence: &'a [EvidenceArtifact],
+    timestamp_ms: u64,
+}
+
+/// SHA-256 (hex) of `request`'s claim; refs, verifiers and the endorsement itself are left out.
+pub fn statement_hash(request: &AttestationRequest) -> String {
+    let statement = Statement {
+        actor_did: &request.actor_did,
+        mission_id: &request.mission_id,
+        description: &request.description,
+        module: request.module,
+        impact_metrics: &request.impact_metrics,
+        module_metrics: &request.module_metrics,
+        evidence: &request.evidence,
+        timestamp_ms: request.timestamp_ms,
+    };
+    let payload = serde_json::to_vec(&statement).expect("witness statement serialization");
+    format!("{:x}", Sha256::digest(&payload))
+}
@@ -0,0 +1,86 @@
+// path: steward-cli/examples/binary_flow.rs
+
+//! The same field flow through the built `steward-cli` binary, checking what a shell script
+//! sees: exit codes, stdout and stderr.
+//! - grant consent → issue attestation → query, each exiting 0
+//! - An attestation without consent is an ethics denial (3), reported on stderr only
+//! - A store path that cannot be read is an IO error (4), a different code from the denial
+//! - An unknown flag is a usage error (2)
+//!
+//! Run with `cargo build -p steward-cli && cargo run -p steward-cli --example binary_flow`.
+//! The binary is looked up next to the examples directory, or at `STEWARD_CLI_BIN`.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const ACTOR: &str = "did:psv:steward:alice";
+
+fn binary() -> PathBuf {
+    if let Some(path) = std::env::var_os("STEWARD_CLI_BIN") {
+        return PathBuf::from(path);
+    }
+    let example = std::env::current_exe().expect("example path");
+    let profile_dir = example.parent().and_then(Path::parent).expect("target/<profile>/examples");
+    let binary = profile_dir.join(format!("steward-cli{}", std::env::consts::EXE_SUFFIX));
+    assert!(binary.exists(), "{} is missing; run `cargo build -p steward-cli` first", binary.display());
+    binary
+}
+
+fn steward(store: &Path, args: &[&str]) -> Output {
+    Command::new(binary())
+        .arg("--store")
+        .arg(store)
+        .args(args)
+        .env_remove("STEWARD_STORE")
+        .output()
+        .expect("steward-cli runs")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).expect("utf-8 stdout")
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).expect("utf-8 stderr")
+}
+
+fn main() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let store = dir.path().join("store.json");
+    let issue = [
+        "attest", "issue", "--actor", ACTOR, "--description", "Planted 40 saplings",
+        "--evidence-uri", "ipfs://evidence", "--co2eq-reduced", "1.2", "--timestamp-ms", "2000",
+        "--rollback-step", "Revoke the attestation",
+    ];
+
+    // 1. No consent yet: an ethics denial, exit 3, explained on stderr.
+    let denied = steward(&store, &issue);
+    assert_eq!(denied.status.code(), Some(3), "{}", stderr(&denied));
+    assert!(stdout(&denied).is_empty() && !stderr(&denied).trim().is_empty());
+    println!("denied (3): {}", stderr(&denied).trim());
+
+    // 2. grant-consent → issue-attestation → query, each exiting 0.
+    let grant = ["consent", "grant", "--participant", ACTOR, "--module", "plga", "--timestamp-ms", "1000"];
+    let granted = steward(&store, &grant);
+    assert_eq!(granted.status.code(), Some(0), "{}", stderr(&granted));
+    let issued = steward(&store, &issue);
+    assert_eq!(issued.status.code(), Some(0), "{}", stderr(&issued));
+    let queried = steward(&store, &["--json", "attest", "query", "--actor", ACTOR]);
+    assert_eq!(queried.status.code(), Some(0), "{}", stderr(&queried));
+    let found: serde_json::Value = serde_json::from_str(&stdout(&queried)).expect("query JSON");
+    let found = found.as_array().expect("array of attestations");
+    assert_eq!(found.len(), 1);
+    assert_eq!((&found[0]["actor_did"], &found[0]["timestamp_ms"]), (&ACTOR.into(), &2000.into()));
+
+    // 3. A store that cannot be read: IO error, exit 4, not the denial's 3.
+    let unreadable = steward(dir.path(), &["attest", "query"]);
+    assert_eq!(unreadable.status.code(), Some(4), "{}", stderr(&unreadable));
+    assert!(stderr(&unreadable).starts_with("Cannot read store"), "{}", stderr(&unreadable));
+    println!("unreadable store (4): {}", stderr(&unreadable).trim());
+
+    // 4. An unknown flag: usage error, exit 2.
+    let usage = steward(&store, &["attest", "issue", "--no-such-flag"]);
+    assert_eq!(usage.status.code(), Some(2), "{}", stderr(&usage));
+
+    println!("binary flow ok");
+}
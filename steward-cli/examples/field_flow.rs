@@ -0,0 +1,83 @@
+// path: steward-cli/examples/field_flow.rs
+
+//! End-to-end CLI flow against a throwaway store:
+//! grant consent → issue attestation with a rollback plan → query → audit plans,
+//! plus KSCP and reversibility denials and a usage error.
+//!
+//! Run with `cargo run -p steward-cli --example field_flow`; `binary_flow` runs the same
+//! flow through the built binary and checks its exit codes.
+
+use steward_cli::run_from_args;
+
+fn steward(store: &str, args: &[&str]) -> (Result<(), steward_cli::CliError>, String) {
+    let mut out = Vec::new();
+    let argv: Vec<&str> = ["steward-cli", "--store", store].into_iter().chain(args.iter().copied()).collect();
+    let result = run_from_args(argv, &mut out);
+    (result, String::from_utf8(out).expect("utf-8 output"))
+}
+
+fn main() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let store = dir.path().join("store.json");
+    let store = store.to_str().expect("utf-8 path");
+    let actor = "did:psv:steward:alice";
+
+    // No consent yet: PLGA must refuse with the "denied" exit code.
+    let (denied, _) = steward(store, &[
+        "attest", "issue", "--actor", actor, "--description", "Planted 40 saplings",
+        "--evidence-uri", "ipfs://evidence", "--co2eq-reduced", "1.2",
+    ]);
+    let err = denied.expect_err("attestation without consent");
+    assert_eq!(err.exit_code(), 3, "{err}");
+
+    let (granted, text) = steward(store, &[
+        "consent", "grant", "--participant", actor, "--module", "plga", "--timestamp-ms", "1000",
+    ]);
+    granted.expect("grant consent");
+    print!("{text}");
+
+    // Consent alone is not enough: SAEP's reversibility rule wants a rollback plan.
+    let (no_plan, _) = steward(store, &[
+        "attest", "issue", "--actor", actor, "--description", "Planted 40 saplings",
+        "--evidence-uri", "ipfs://evidence", "--co2eq-reduced", "1.2", "--timestamp-ms", "2000",
+    ]);
+    assert_eq!(no_plan.expect_err("attestation without rollback plan").exit_code(), 3);
+
+    let (issued, text) = steward(store, &[
+        "attest", "issue", "--actor", actor, "--description", "Planted 40 saplings",
+        "--evidence-uri", "ipfs://evidence", "--co2eq-reduced", "1.2", "--timestamp-ms", "2000",
+        "--rollback-step", "Revoke the attestation", "--rollback-step", "Notify the verifiers",
+    ]);
+    issued.expect("issue attestation");
+    print!("{text}");
+
+    let (queried, text) = steward(store, &["--json", "attest", "query", "--actor", actor]);
+    queried.expect("query attestations");
+    let found: serde_json::Value = serde_json::from_str(&text).expect("query JSON");
+    let found = found.as_array().expect("array of attestations");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0]["actor_did"], actor);
+    assert_eq!(found[0]["timestamp_ms"], 2000);
+
+    let (table, text) = steward(store, &["attest", "query"]);
+    table.expect("table query");
+    print!("{text}");
+
+    let (audit, text) = steward(store, &["--json", "rollback", "list", "--never-invoked-before", "3000"]);
+    audit.expect("rollback audit");
+    let plans: serde_json::Value = serde_json::from_str(&text).expect("audit JSON");
+    assert_eq!(plans.as_array().map(Vec::len), Some(1));
+    assert_eq!(plans[0]["plan"]["action_ref"]["Attestation"], found[0]["id"]);
+    assert_eq!(found[0]["rollback_plan_id"], plans[0]["plan"]["id"]);
+
+    let (unknown, _) = steward(store, &[
+        "rollback", "register", "--id", "plan:ghost", "--attestation", "no-such-attestation",
+        "--step", "Nothing to undo", "--responsible", actor,
+    ]);
+    assert_eq!(unknown.expect_err("plan for a missing attestation").exit_code(), 1);
+
+    let (usage, _) = steward(store, &["attest", "issue", "--no-such-flag"]);
+    assert_eq!(usage.expect_err("unknown flag").exit_code(), 2);
+
+    println!("field flow ok");
+}
@@ -0,0 +1,224 @@
+// path: steward-cli/src/attest.rs
+
+//! `attest issue | query | export`
+
+use clap::{Args, Subcommand, ValueEnum};
+use serde::Deserialize;
+use std::io;
+use std::path::PathBuf;
+use steward_ids::stream::write_stream;
+
+use planetary_stewardship_runtime::{
+    ActionRef, Compression, Did, ImpactMetrics, MissionId, PlanId, PlanetaryLedger, RollbackPlan, SaepRefs,
+    StewardshipAttestation, ATTESTATION_STREAM_KIND,
+};
+
+use crate::output::{emit, emit_message, opt, Table};
+use crate::{read_json, timestamp_or_now, CliError, Ctx};
+
+#[derive(Debug, Subcommand)]
+pub enum AttestCommand {
+    /// Issue a PLGA attestation (SAEP + KSCP checked).
+    Issue(IssueArgs),
+    /// List attestations, optionally filtered.
+    Query {
+        #[arg(long)]
+        actor: Option<Did>,
+        #[arg(long)]
+        mission: Option<MissionId>,
+    },
+    /// Dump attestations for downstream tools.
+    Export {
+        #[arg(long, value_enum, default_value = "json")]
+        format: ExportFormat,
+        #[arg(long)]
+        actor: Option<Did>,
+        /// Gzip the lines after the header (`--format stream`; needs the flate2 feature).
+        #[arg(long)]
+        gzip: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON array.
+    Json,
+    /// One attestation per line.
+    Jsonl,
+    /// Header line, one attestation per line and a manifest line, so an import can tell an
+    /// interrupted export from a complete one (`PlanetaryLedger::restore_attestations`).
+    Stream,
+}
+
+/// Attestation request, from flags or `--input <file.json>`.
+#[derive(Debug, Args, Deserialize)]
+pub struct IssueArgs {
+    /// JSON file with the same fields as the flags (snake_case); flags are then ignored.
+    #[arg(long, exclusive = true)]
+    #[serde(skip)]
+    pub input: Option<PathBuf>,
+    #[arg(long, required_unless_present = "input")]
+    pub actor: Option<Did>,
+    #[arg(long)]
+    #[serde(default)]
+    pub mission: Option<MissionId>,
+    #[arg(long, required_unless_present = "input")]
+    pub description: Option<String>,
+    #[arg(long, required_unless_present = "input")]
+    pub evidence_uri: Option<String>,
+    /// Repeat for multiple verifiers.
+    #[arg(long = "verifier")]
+    #[serde(default)]
+    pub verifiers: Vec<Did>,
+    #[arg(long, default_value_t = 0.0)]
+    #[serde(default)]
+    pub co2eq_reduced: f64,
+    #[arg(long, default_value_t = 0.0)]
+    #[serde(default)]
+    pub biodiversity_index_delta: f64,
+    #[arg(long, default_value_t = 0.0)]
+    #[serde(default)]
+    pub restored_area_m2: f64,
+    #[arg(long, default_value_t = 0.0)]
+    #[serde(default)]
+    pub avoided_emissions_co2eq: f64,
+    /// Defaults to now.
+    #[arg(long)]
+    #[serde(default)]
+    pub timestamp_ms: Option<u64>,
+    /// Step of the rollback plan registered with the attestation (`rollback:<id>`);
+    /// repeat for several. SAEP's reversibility rule refuses issuance without one.
+    #[arg(long = "rollback-step")]
+    #[serde(default)]
+    pub rollback_steps: Vec<String>,
+    /// Who carries the rollback plan out; defaults to the actor.
+    #[arg(long)]
+    #[serde(default)]
+    pub rollback_responsible: Option<Did>,
+}
+
+pub fn run(cmd: AttestCommand, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    match cmd {
+        AttestCommand::Issue(args) => issue(args, ctx),
+        AttestCommand::Query { actor, mission } => query(actor, mission, ctx),
+        AttestCommand::Export { format, actor, gzip } => export(format, actor, gzip, ctx),
+    }
+}
+
+fn issue(args: IssueArgs, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let args = match &args.input {
+        Some(path) => read_json::<IssueArgs>(path)?,
+        None => args,
+    };
+    let missing = |field: &str| CliError::input(format!("Attestation request is missing {field}"));
+    let actor = args.actor.ok_or_else(|| missing("actor"))?;
+    let description = args.description.ok_or_else(|| missing("description"))?;
+    let evidence_uri = args.evidence_uri.ok_or_else(|| missing("evidence_uri"))?;
+
+    let mut store = ctx.load_store()?;
+    let intent_log = store.shared_intent_log();
+    let rollback_plans = store.shared_rollback_plans();
+    let mut ledger = PlanetaryLedger::new(store.saep_engine(), store.consent_registry())
+        .with_intent_log(intent_log.clone())
+        .with_rollback_registry(rollback_plans.clone());
+    let timestamp_ms = timestamp_or_now(args.timestamp_ms);
+    // Reservations do not outlive this process, so the plan is registered inline.
+    let rollback_plan_id = if args.rollback_steps.is_empty() {
+        None
+    } else {
+        let id = ledger.reserve_attestation_id();
+        let plan = RollbackPlan {
+            id: PlanId(format!("rollback:{}", id.0)),
+            action_ref: ActionRef::Attestation(id),
+            steps: args.rollback_steps,
+            responsible: args.rollback_responsible.unwrap_or_else(|| actor.clone()),
+            created_ms: timestamp_ms,
+        };
+        Some(ledger.register_rollback_plan(plan).map_err(CliError::input)?)
+    };
+    // The ledger only fails on SAEP, KSCP, rollback-plan or intent-log denials.
+    let attestation = ledger
+        .issue_attestation_with_refs(
+            actor,
+            args.mission,
+            description,
+            ImpactMetrics {
+                co2eq_reduced: args.co2eq_reduced,
+                biodiversity_index_delta: args.biodiversity_index_delta,
+                biodiversity_detail: None,
+                restored_area_m2: args.restored_area_m2,
+                avoided_emissions_co2eq: args.avoided_emissions_co2eq,
+                custom: Default::default(),
+            },
+            evidence_uri,
+            args.verifiers,
+            timestamp_ms,
+            SaepRefs {
+                intent_entry_id: None,
+                rollback_plan_id,
+            },
+        )
+        .map_err(CliError::denied)?;
+    store.attestations.push(attestation.clone());
+    store.keep_intent_log(&intent_log);
+    store.keep_rollback_plans(&rollback_plans);
+    ctx.save_store(&store)?;
+
+    let message = format!("Attestation issued: {} for {}", attestation.id.0, attestation.actor_did);
+    emit_message(ctx, &attestation, &message)
+}
+
+fn filtered<'a>(
+    attestations: &'a [StewardshipAttestation],
+    actor: Option<&Did>,
+    mission: Option<&MissionId>,
+) -> Vec<&'a StewardshipAttestation> {
+    attestations
+        .iter()
+        .filter(|a| actor.is_none_or(|d| &a.actor_did == d))
+        .filter(|a| mission.is_none_or(|m| a.mission_id.as_ref() == Some(m)))
+        .collect()
+}
+
+fn query(actor: Option<Did>, mission: Option<MissionId>, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let store = ctx.load_store()?;
+    let found = filtered(&store.attestations, actor.as_ref(), mission.as_ref());
+    emit(ctx, &found, || {
+        let mut table = Table::new(&["ID", "ACTOR", "MISSION", "TIMESTAMP_MS", "CO2EQ", "DESCRIPTION"]);
+        for a in &found {
+            table.row(vec![
+                a.id.0.clone(),
+                a.actor_did.to_string(),
+                opt(a.mission_id.as_ref()),
+                a.timestamp_ms.to_string(),
+                a.impact_metrics.co2eq_reduced.to_string(),
+                a.description.clone(),
+            ]);
+        }
+        table
+    })
+}
+
+/// Written to the output as serialized, never built up as one string first.
+fn export(format: ExportFormat, actor: Option<Did>, gzip: bool, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    if gzip && !matches!(format, ExportFormat::Stream) {
+        return Err(CliError::input("--gzip applies only to --format stream"));
+    }
+    let store = ctx.load_store()?;
+    let found = filtered(&store.attestations, actor.as_ref(), None);
+    let out = &mut *ctx.out;
+    let written = match format {
+        ExportFormat::Json => serde_json::to_writer_pretty(&mut *out, &found)
+            .map_err(io::Error::from)
+            .and_then(|()| out.write_all(b"\n")),
+        ExportFormat::Jsonl => found.iter().try_for_each(|a| {
+            serde_json::to_writer(&mut *out, a)?;
+            out.write_all(b"\n")
+        }),
+        ExportFormat::Stream => {
+            let compression = if gzip { Compression::Gzip } else { Compression::None };
+            write_stream(out, ATTESTATION_STREAM_KIND, compression, &found).map(drop)
+        }
+    };
+    written.map_err(|e| CliError::io(format!("Cannot write output: {e}")))
+}
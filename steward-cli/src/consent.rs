@@ -0,0 +1,111 @@
+// path: steward-cli/src/consent.rs
+
+//! `consent grant | withdraw | list`
+
+use clap::{Args, Subcommand};
+
+use planetary_stewardship_runtime::{ConsentRecord, Did, MissionId, StewardModule};
+
+use crate::output::{emit, emit_message, opt, Table};
+use crate::{parse_module, timestamp_or_now, CliError, Ctx};
+
+#[derive(Debug, Subcommand)]
+pub enum ConsentCommand {
+    /// Record that a participant consents to a module (optionally one mission).
+    Grant(ConsentArgs),
+    /// Record that a participant withdraws consent.
+    Withdraw(ConsentArgs),
+    /// Show effective consent per participant/module/mission.
+    List {
+        #[arg(long)]
+        participant: Option<Did>,
+        /// Show every recorded change instead of the effective state.
+        #[arg(long)]
+        history: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct ConsentArgs {
+    #[arg(long)]
+    pub participant: Did,
+    #[arg(long, value_parser = parse_module)]
+    pub module: StewardModule,
+    #[arg(long)]
+    pub mission: Option<MissionId>,
+    #[arg(long)]
+    pub evidence_uri: Option<String>,
+    /// Hash of the content at `--evidence-uri` now, e.g. `sha256:<hex>`.
+    #[arg(long)]
+    pub evidence_hash: Option<String>,
+    /// `canonical_hash` of the consent prompt the participant was shown.
+    #[arg(long)]
+    pub prompt_hash: Option<String>,
+    /// Defaults to now.
+    #[arg(long)]
+    pub timestamp_ms: Option<u64>,
+}
+
+pub fn run(cmd: ConsentCommand, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    match cmd {
+        ConsentCommand::Grant(args) => record(args, true, ctx),
+        ConsentCommand::Withdraw(args) => record(args, false, ctx),
+        ConsentCommand::List { participant, history } => list(participant, history, ctx),
+    }
+}
+
+fn record(args: ConsentArgs, consent_given: bool, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let mut store = ctx.load_store()?;
+    let record = ConsentRecord {
+        participant: args.participant,
+        module: args.module,
+        mission: args.mission,
+        consent_given,
+        timestamp_ms: timestamp_or_now(args.timestamp_ms),
+        evidence_uri: args.evidence_uri,
+        evidence_hash: args.evidence_hash,
+        prompt_hash: args.prompt_hash,
+    };
+    store
+        .consent_registry()
+        .submit_consent(record.clone())
+        .map_err(|reason| CliError::denied(reason.to_string()))?;
+    store.consents.push(record.clone());
+    ctx.save_store(&store)?;
+
+    let verb = if consent_given { "granted" } else { "withdrawn" };
+    let message = format!(
+        "Consent {verb}: {} for {:?}{}",
+        record.participant,
+        record.module,
+        record.mission.as_ref().map(|m| format!(" / {m}")).unwrap_or_default()
+    );
+    emit_message(ctx, &record, &message)
+}
+
+fn list(participant: Option<Did>, history: bool, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let store = ctx.load_store()?;
+    let records: Vec<_> = if history {
+        store.consents.iter().collect()
+    } else {
+        store.effective_consents()
+    };
+    let records: Vec<_> = records
+        .into_iter()
+        .filter(|r| participant.as_ref().is_none_or(|p| &r.participant == p))
+        .collect();
+
+    emit(ctx, &records, || {
+        let mut table = Table::new(&["PARTICIPANT", "MODULE", "MISSION", "CONSENT", "TIMESTAMP_MS"]);
+        for r in &records {
+            table.row(vec![
+                r.participant.to_string(),
+                format!("{:?}", r.module),
+                opt(r.mission.as_ref()),
+                if r.consent_given { "granted" } else { "withdrawn" }.into(),
+                r.timestamp_ms.to_string(),
+            ]);
+        }
+        table
+    })
+}
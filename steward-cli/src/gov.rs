@@ -0,0 +1,79 @@
+// path: steward-cli/src/gov.rs
+
+//! `gov preview | tally`
+//! - `preview` asks whether SAEP and the charter would let a proposal through if the vote passed
+//! - `tally` sums quadratic votes and applies the same guard to the real outcome
+//! - A failed vote is a normal result (exit 0, `applicable: false`); vetoes exit 3
+
+use clap::Subcommand;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use planetary_stewardship_runtime::{GovernanceEngine, GovernanceProposal, QuadraticOutcome, QuadraticVote};
+
+use crate::output::{emit, Table};
+use crate::{read_json, CliError, Ctx};
+
+#[derive(Debug, Subcommand)]
+pub enum GovCommand {
+    /// Check a proposal against SAEP and the charter, assuming it wins the vote.
+    Preview {
+        /// `GovernanceProposal` JSON file.
+        #[arg(long)]
+        proposal: PathBuf,
+    },
+    /// Tally quadratic votes and check whether the proposal can be applied.
+    Tally {
+        /// `GovernanceProposal` JSON file.
+        #[arg(long)]
+        proposal: PathBuf,
+        /// JSON array of `QuadraticVote`.
+        #[arg(long)]
+        votes: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GovReport {
+    pub proposal_id: String,
+    /// `None` for previews.
+    pub outcome: Option<QuadraticOutcome>,
+    pub applicable: bool,
+}
+
+pub fn run(cmd: GovCommand, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let (proposal, votes) = match cmd {
+        GovCommand::Preview { proposal } => (read_json::<GovernanceProposal>(&proposal)?, None),
+        GovCommand::Tally { proposal, votes } => (
+            read_json::<GovernanceProposal>(&proposal)?,
+            Some(read_json::<Vec<QuadraticVote>>(&votes)?),
+        ),
+    };
+
+    let store = ctx.load_store()?;
+    let engine = GovernanceEngine::new(store.saep_engine());
+    let outcome = votes.map(|votes| engine.tally_quadratic(&proposal.proposal_id, &votes));
+    let assumed = QuadraticOutcome {
+        proposal_id: proposal.proposal_id.clone(),
+        total_support: 1.0,
+        total_opposition: 0.0,
+    };
+    let applicable = engine
+        .can_apply_proposal(&proposal, outcome.as_ref().unwrap_or(&assumed))
+        .map_err(CliError::denied)?;
+
+    let report = GovReport {
+        proposal_id: proposal.proposal_id,
+        outcome,
+        applicable,
+    };
+    emit(ctx, &report, || {
+        let mut table = Table::new(&["PROPOSAL", "SUPPORT", "OPPOSITION", "APPLICABLE"]);
+        let (support, opposition) = match &report.outcome {
+            Some(o) => (o.total_support.to_string(), o.total_opposition.to_string()),
+            None => ("(preview)".into(), "(preview)".into()),
+        };
+        table.row(vec![report.proposal_id.clone(), support, opposition, report.applicable.to_string()]);
+        table
+    })
+}
@@ -0,0 +1,235 @@
+// path: steward-cli/src/karma.rs
+
+//! `karma ingest-manifests | verify-chain | report`
+//! - Ingest accepts files holding one manifest or an array of manifests
+//! - Chains are per vNode, ordered by `epoch_start`; each `prev_hash` must equal the predecessor's `self_hash`
+//! - Reports price epochs under their shard's policy (`--shard-policy`); epochs of a shard without
+//!   one are counted but earn nothing
+
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use aln_karma::{CustomMetrics, MetricKey, PricingTable, SafetyEpochManifest, ShardPolicy};
+
+use crate::output::{emit, emit_message, Table};
+use crate::{read_json, CliError, Ctx};
+
+#[derive(Debug, Subcommand)]
+pub enum KarmaCommand {
+    /// Add manifests from JSON files to the store (hashes are checked first).
+    IngestManifests {
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Check every stored manifest hash and the per-vNode prev_hash links.
+    VerifyChain {
+        #[arg(long)]
+        vnode: Option<String>,
+    },
+    /// Per-vNode totals and the AU.ET the eligible manifests would earn.
+    Report(ReportArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    #[arg(long)]
+    pub vnode: Option<String>,
+    #[arg(long, default_value_t = 10.0)]
+    pub price_per_tco2e: f64,
+    #[arg(long, default_value_t = 0.01)]
+    pub price_per_kwh: f64,
+    #[arg(long, default_value_t = 2.5)]
+    pub price_per_near_miss: f64,
+    /// AU.ET per unit of a custom metric, e.g. `water:nitrate_kg_reduced=0.4` (repeatable).
+    #[arg(long = "custom-rate", value_parser = parse_custom_rate)]
+    pub custom_rates: Vec<(MetricKey, f64)>,
+    /// JSON file holding a `ShardPolicy` (repeatable, one per shard).
+    #[arg(long = "shard-policy")]
+    pub shard_policies: Vec<PathBuf>,
+}
+
+fn parse_custom_rate(s: &str) -> Result<(MetricKey, f64), String> {
+    let (key, rate) = s.split_once('=').ok_or("expected <metric-key>=<rate>")?;
+    let key = MetricKey::new(key).map_err(|e| e.to_string())?;
+    let rate = rate.parse::<f64>().map_err(|e| format!("invalid rate {rate:?}: {e}"))?;
+    Ok((key, rate))
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestFile {
+    Many(Vec<SafetyEpochManifest>),
+    One(Box<SafetyEpochManifest>),
+}
+
+/// One problem found by `verify-chain`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainIssue {
+    pub vnode_id: String,
+    pub manifest_id: String,
+    pub problem: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VNodeReport {
+    pub vnode_id: String,
+    pub epochs: usize,
+    pub eligible_epochs: usize,
+    pub gross_t_co2e_avoided: f64,
+    pub leakage_t_co2e: f64,
+    pub net_t_co2e_avoided: f64,
+    pub au_et: f64,
+    /// Custom metric totals across all epochs, in key order.
+    #[serde(skip_serializing_if = "CustomMetrics::is_empty")]
+    pub custom: CustomMetrics,
+}
+
+pub fn run(cmd: KarmaCommand, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    match cmd {
+        KarmaCommand::IngestManifests { files } => ingest(files, ctx),
+        KarmaCommand::VerifyChain { vnode } => verify_chain(vnode, ctx),
+        KarmaCommand::Report(args) => report(args, ctx),
+    }
+}
+
+fn ingest(files: Vec<PathBuf>, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let mut store = ctx.load_store()?;
+    let mut added = Vec::new();
+    for path in &files {
+        let manifests = match read_json::<ManifestFile>(path)? {
+            ManifestFile::Many(many) => many,
+            ManifestFile::One(one) => vec![*one],
+        };
+        for manifest in manifests {
+            if !manifest.verify_hash() {
+                return Err(CliError::verification(format!(
+                    "Manifest {} in {} fails hash verification",
+                    manifest.id,
+                    path.display()
+                )));
+            }
+            if store.manifests.iter().chain(&added).any(|m| m.id == manifest.id) {
+                return Err(CliError::input(format!("Manifest {} is already in the store", manifest.id)));
+            }
+            added.push(manifest);
+        }
+    }
+    let ids: Vec<String> = added.iter().map(|m| m.id.to_string()).collect();
+    store.manifests.extend(added);
+    ctx.save_store(&store)?;
+    let message = format!("Ingested {} manifest(s)", ids.len());
+    emit_message(ctx, &ids, &message)
+}
+
+/// Stored manifests grouped by vNode, each group ordered by `epoch_start`.
+fn by_vnode<'a>(manifests: &'a [SafetyEpochManifest], vnode: Option<&str>) -> BTreeMap<&'a str, Vec<&'a SafetyEpochManifest>> {
+    let mut groups: BTreeMap<&str, Vec<&SafetyEpochManifest>> = BTreeMap::new();
+    for m in manifests.iter().filter(|m| vnode.is_none_or(|v| m.vnode.vnode_id == v)) {
+        groups.entry(m.vnode.vnode_id.as_str()).or_default().push(m);
+    }
+    for group in groups.values_mut() {
+        group.sort_by_key(|m| m.epoch_start);
+    }
+    groups
+}
+
+fn verify_chain(vnode: Option<String>, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let store = ctx.load_store()?;
+    let mut issues = Vec::new();
+    let groups = by_vnode(&store.manifests, vnode.as_deref());
+    for (vnode_id, chain) in &groups {
+        let mut issue = |m: &SafetyEpochManifest, problem: String| {
+            issues.push(ChainIssue {
+                vnode_id: vnode_id.to_string(),
+                manifest_id: m.id.to_string(),
+                problem,
+            })
+        };
+        let mut prev: Option<&SafetyEpochManifest> = None;
+        for m in chain {
+            if !m.verify_hash() {
+                issue(m, "self_hash does not match contents".into());
+            }
+            match (prev, &m.prev_hash) {
+                (None, Some(_)) => issue(m, "first epoch links to an unknown predecessor".into()),
+                (Some(p), None) => issue(m, format!("missing prev_hash (expected {})", p.self_hash)),
+                (Some(p), Some(h)) if *h != p.self_hash => {
+                    issue(m, format!("prev_hash {h} does not match predecessor {}", p.self_hash))
+                }
+                _ => {}
+            }
+            prev = Some(m);
+        }
+    }
+
+    emit(ctx, &issues, || {
+        let mut table = Table::new(&["VNODE", "MANIFEST", "PROBLEM"]);
+        for i in &issues {
+            table.row(vec![i.vnode_id.clone(), i.manifest_id.clone(), i.problem.clone()]);
+        }
+        table
+    })?;
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::verification(format!("Manifest chain verification failed: {} issue(s)", issues.len())))
+    }
+}
+
+fn report(args: ReportArgs, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let store = ctx.load_store()?;
+    let pricing = args
+        .custom_rates
+        .into_iter()
+        .fold(PricingTable::new(args.price_per_tco2e, args.price_per_kwh, args.price_per_near_miss), |p, (key, rate)| {
+            p.with_custom_rate(key, rate)
+        });
+    let mut policies: BTreeMap<String, ShardPolicy> = BTreeMap::new();
+    for path in &args.shard_policies {
+        let policy: ShardPolicy = read_json(path)?;
+        if policies.contains_key(&policy.policy_shard_id) {
+            return Err(CliError::input(format!("Two policies given for shard {}", policy.policy_shard_id)));
+        }
+        policies.insert(policy.policy_shard_id.clone(), policy);
+    }
+    let reports: Vec<VNodeReport> = by_vnode(&store.manifests, args.vnode.as_deref())
+        .into_iter()
+        .map(|(vnode_id, chain)| {
+            let mut r = VNodeReport {
+                vnode_id: vnode_id.to_string(),
+                epochs: chain.len(),
+                ..Default::default()
+            };
+            for m in chain {
+                r.gross_t_co2e_avoided += m.metrics.t_co2e_avoided;
+                r.leakage_t_co2e += m.leakage_t_co2e();
+                r.net_t_co2e_avoided += m.net_t_co2e_avoided();
+                steward_ids::metrics::accumulate(&mut r.custom, &m.metrics.custom);
+                let policy = policies.get(&m.vnode.policy_shard_id);
+                if let Some(allowance) = policy.and_then(|p| m.to_karma_allowance_with_pricing(p, None, &pricing)) {
+                    r.eligible_epochs += 1;
+                    r.au_et += allowance.au_et_delta;
+                }
+            }
+            r
+        })
+        .collect();
+
+    emit(ctx, &reports, || {
+        let mut table = Table::new(&["VNODE", "EPOCHS", "ELIGIBLE", "GROSS_TCO2E", "LEAKAGE", "NET_TCO2E", "AU.ET"]);
+        for r in &reports {
+            table.row(vec![
+                r.vnode_id.clone(),
+                r.epochs.to_string(),
+                r.eligible_epochs.to_string(),
+                format!("{:.3}", r.gross_t_co2e_avoided),
+                format!("{:.3}", r.leakage_t_co2e),
+                format!("{:.3}", r.net_t_co2e_avoided),
+                format!("{:.2}", r.au_et),
+            ]);
+        }
+        table
+    })
+}
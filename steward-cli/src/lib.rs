@@ -0,0 +1,203 @@
+// path: steward-cli/src/lib.rs
+
+//! `steward-cli`: terminal front-end for field coordinators.
+//! - `consent grant/withdraw/list`, `attest issue/query/export`,
+//!   `mission add-template/assign/complete`, `karma ingest-manifests/verify-chain/report`,
+//!   `gov preview/tally`, `rollback register/invoke/list`
+//! - Operates on a JSON snapshot store (`--store`, default `steward-store.json`)
+//! - Human tables by default, `--json` for scripting
+//!
+//! Exit codes:
+//! - 0 success
+//! - 1 invalid input (bad JSON file, unknown mission, duplicate id)
+//! - 2 usage error (unknown flag, missing argument)
+//! - 3 denied by the ethics kernel (SAEP), consent (KSCP) or the charter
+//! - 4 IO error (store or input file unreadable / unwritable)
+//! - 5 verification failed (broken manifest chain)
+
+use clap::{Parser, Subcommand};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use planetary_stewardship_runtime::StewardModule;
+use steward_runtime_support::{Clock, SystemClock};
+
+pub mod attest;
+pub mod consent;
+pub mod gov;
+pub mod karma;
+pub mod mission;
+pub mod output;
+pub mod rollback;
+pub mod store;
+pub use store::StewardStore;
+
+// ---------------------------------------------------------------------
+// ERRORS
+// ---------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Input,
+    Usage,
+    Denied,
+    Io,
+    Verification,
+}
+
+#[derive(Debug, Clone)]
+pub struct CliError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl CliError {
+    pub fn input(message: impl Into<String>) -> Self {
+        Self { kind: ErrorKind::Input, message: message.into() }
+    }
+
+    pub fn denied(message: impl Into<String>) -> Self {
+        Self { kind: ErrorKind::Denied, message: message.into() }
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self { kind: ErrorKind::Io, message: message.into() }
+    }
+
+    pub fn verification(message: impl Into<String>) -> Self {
+        Self { kind: ErrorKind::Verification, message: message.into() }
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        match self.kind {
+            ErrorKind::Input => 1,
+            ErrorKind::Usage => 2,
+            ErrorKind::Denied => 3,
+            ErrorKind::Io => 4,
+            ErrorKind::Verification => 5,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+// ---------------------------------------------------------------------
+// COMMAND LINE
+// ---------------------------------------------------------------------
+
+#[derive(Debug, Parser)]
+#[command(name = "steward-cli", version, about = "Stewardship operations from the terminal")]
+pub struct Cli {
+    /// JSON store to read and update.
+    #[arg(long, global = true, env = "STEWARD_STORE", default_value = "steward-store.json")]
+    pub store: PathBuf,
+    /// Emit JSON instead of tables.
+    #[arg(long, global = true)]
+    pub json: bool,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Record and inspect KSCP consent.
+    #[command(subcommand)]
+    Consent(consent::ConsentCommand),
+    /// Issue and inspect PLGA attestations.
+    #[command(subcommand)]
+    Attest(attest::AttestCommand),
+    /// Manage micro-mission templates and assignments.
+    #[command(subcommand)]
+    Mission(mission::MissionCommand),
+    /// Ingest, verify and report on SafetyEpochManifests.
+    #[command(subcommand)]
+    Karma(karma::KarmaCommand),
+    /// Check and tally governance proposals.
+    #[command(subcommand)]
+    Gov(gov::GovCommand),
+    /// Register, invoke and audit rollback plans.
+    #[command(subcommand)]
+    Rollback(rollback::RollbackCommand),
+}
+
+/// Where a command writes and which store it uses.
+pub struct Ctx<'a> {
+    pub store_path: &'a Path,
+    pub json: bool,
+    pub out: &'a mut dyn Write,
+}
+
+impl Ctx<'_> {
+    pub fn load_store(&self) -> Result<StewardStore, CliError> {
+        StewardStore::load(self.store_path)
+    }
+
+    pub fn save_store(&self, store: &StewardStore) -> Result<(), CliError> {
+        store.save(self.store_path)
+    }
+}
+
+/// Parse `args` (including the program name) and run the command.
+/// `--help` / `--version` print to `out` and succeed.
+pub fn run_from_args<I, T>(args: I, out: &mut dyn Write) -> Result<(), CliError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<std::ffi::OsString> + Clone,
+{
+    match Cli::try_parse_from(args) {
+        Ok(cli) => run(cli, out),
+        Err(e) if !e.use_stderr() => out
+            .write_all(e.render().to_string().as_bytes())
+            .map_err(|e| CliError::io(format!("Cannot write output: {e}"))),
+        Err(e) => Err(CliError {
+            kind: ErrorKind::Usage,
+            message: e.render().to_string().trim_end().to_string(),
+        }),
+    }
+}
+
+pub fn run(cli: Cli, out: &mut dyn Write) -> Result<(), CliError> {
+    let mut ctx = Ctx {
+        store_path: &cli.store,
+        json: cli.json,
+        out,
+    };
+    match cli.command {
+        Command::Consent(cmd) => consent::run(cmd, &mut ctx),
+        Command::Attest(cmd) => attest::run(cmd, &mut ctx),
+        Command::Mission(cmd) => mission::run(cmd, &mut ctx),
+        Command::Karma(cmd) => karma::run(cmd, &mut ctx),
+        Command::Gov(cmd) => gov::run(cmd, &mut ctx),
+        Command::Rollback(cmd) => rollback::run(cmd, &mut ctx),
+    }
+}
+
+// ---------------------------------------------------------------------
+// SHARED HELPERS
+// ---------------------------------------------------------------------
+
+/// clap value parser for `PLGA`, `MME`, ... (the serde names).
+pub fn parse_module(s: &str) -> Result<StewardModule, String> {
+    serde_json::from_value(serde_json::Value::String(s.to_uppercase()))
+        .map_err(|_| format!("unknown module {s:?} (expected PLGA, MME, VET, OCG, DCCN, REBL, PSM or CSC)"))
+}
+
+/// Read and parse a JSON input file.
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, CliError> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| CliError::io(format!("Cannot read {}: {e}", path.display())))?;
+    serde_json::from_str(&raw).map_err(|e| CliError::input(format!("Invalid JSON in {}: {e}", path.display())))
+}
+
+/// `explicit` if given, else the system clock.
+pub fn timestamp_or_now(explicit: Option<u64>) -> u64 {
+    explicit.unwrap_or_else(|| SystemClock.now_ms())
+}
@@ -0,0 +1,192 @@
+// path: steward-cli/src/mission.rs
+
+//! `mission add-template | assign | complete | list`
+
+use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+use planetary_stewardship_runtime::{Did, MicroMissionsEngine, MissionId, MissionTemplate, PlanId, SaepRefs};
+
+use crate::output::{emit, emit_message, opt, Table};
+use crate::store::AssignmentEntry;
+use crate::{read_json, timestamp_or_now, CliError, Ctx};
+
+#[derive(Debug, Subcommand)]
+pub enum MissionCommand {
+    /// Register a mission template (ids are immutable once added).
+    AddTemplate(TemplateArgs),
+    /// Assign a mission to a participant (SAEP + KSCP checked).
+    Assign {
+        #[arg(long)]
+        mission: MissionId,
+        #[arg(long)]
+        assignee: Did,
+        /// Defaults to now.
+        #[arg(long)]
+        timestamp_ms: Option<u64>,
+        /// Plan from `rollback register --mission --assignee`; SAEP's reversibility rule requires one.
+        #[arg(long)]
+        rollback_plan: Option<String>,
+    },
+    /// Mark a participant's open assignment as complete.
+    Complete {
+        #[arg(long)]
+        mission: MissionId,
+        #[arg(long)]
+        assignee: Did,
+        /// Defaults to now.
+        #[arg(long)]
+        timestamp_ms: Option<u64>,
+    },
+    /// List assignments.
+    List {
+        #[arg(long)]
+        assignee: Option<Did>,
+        /// Only assignments not yet completed.
+        #[arg(long)]
+        open: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct TemplateArgs {
+    /// `MissionTemplate` JSON file; flags are then ignored.
+    #[arg(long, exclusive = true)]
+    pub input: Option<PathBuf>,
+    #[arg(long, required_unless_present = "input")]
+    pub id: Option<MissionId>,
+    #[arg(long, required_unless_present = "input")]
+    pub title: Option<String>,
+    #[arg(long, required_unless_present = "input")]
+    pub description: Option<String>,
+    /// XS, S, M, L or XL.
+    #[arg(long, default_value = "M")]
+    pub difficulty: String,
+    /// JSON impact model, e.g. '{"restored_area_m2": 350}'.
+    #[arg(long, default_value = "{}")]
+    pub expected_impact: String,
+    /// "geo" or "virtual".
+    #[arg(long, default_value = "geo")]
+    pub location_hint: String,
+    /// Repeat for multiple skills.
+    #[arg(long = "skill")]
+    pub skills: Vec<String>,
+}
+
+pub fn run(cmd: MissionCommand, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    match cmd {
+        MissionCommand::AddTemplate(args) => add_template(args, ctx),
+        MissionCommand::Assign { mission, assignee, timestamp_ms, rollback_plan } => {
+            assign(mission, assignee, timestamp_ms, rollback_plan.map(PlanId), ctx)
+        }
+        MissionCommand::Complete { mission, assignee, timestamp_ms } => complete(mission, assignee, timestamp_ms, ctx),
+        MissionCommand::List { assignee, open } => list(assignee, open, ctx),
+    }
+}
+
+fn template_from_args(args: TemplateArgs) -> Result<MissionTemplate, CliError> {
+    if let Some(path) = &args.input {
+        return read_json(path);
+    }
+    let missing = |field: &str| CliError::input(format!("Mission template is missing {field}"));
+    Ok(MissionTemplate {
+        id: args.id.ok_or_else(|| missing("id"))?,
+        title: args.title.ok_or_else(|| missing("title"))?,
+        description: args.description.ok_or_else(|| missing("description"))?,
+        difficulty: args.difficulty,
+        expected_impact: serde_json::from_str(&args.expected_impact)
+            .map_err(|e| CliError::input(format!("--expected-impact is not valid JSON: {e}")))?,
+        location_hint: args.location_hint,
+        required_skills: args.skills,
+        default_language: None,
+        localized_content: Default::default(),
+        high_demand: false,
+    })
+}
+
+fn add_template(args: TemplateArgs, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let template = template_from_args(args)?;
+    template.validate().map_err(CliError::input)?;
+    let mut store = ctx.load_store()?;
+    if store.mission_templates.iter().any(|t| t.id == template.id) {
+        return Err(CliError::input(format!("Mission template {} already exists", template.id)));
+    }
+    store.mission_templates.push(template.clone());
+    ctx.save_store(&store)?;
+    let message = format!("Mission template added: {} ({})", template.id, template.title);
+    emit_message(ctx, &template, &message)
+}
+
+fn assign(
+    mission: MissionId,
+    assignee: Did,
+    timestamp_ms: Option<u64>,
+    rollback_plan_id: Option<PlanId>,
+    ctx: &mut Ctx<'_>,
+) -> Result<(), CliError> {
+    let mut store = ctx.load_store()?;
+    if !store.mission_templates.iter().any(|t| t.id == mission) {
+        return Err(CliError::input(format!("Unknown mission template: {mission}")));
+    }
+    let intent_log = store.shared_intent_log();
+    let mut engine = MicroMissionsEngine::new(store.saep_engine(), store.consent_registry())
+        .with_intent_log(intent_log.clone())
+        .with_rollback_registry(store.shared_rollback_plans());
+    for template in &store.mission_templates {
+        engine.add_template(template.clone());
+    }
+    // The template exists, so any remaining failure is a SAEP, KSCP or rollback-plan denial.
+    let refs = SaepRefs {
+        intent_entry_id: None,
+        rollback_plan_id,
+    };
+    let assignment = engine
+        .assign_mission_with_refs(&mission, assignee, timestamp_or_now(timestamp_ms), refs)
+        .map_err(CliError::denied)?;
+    store.assignments.push(AssignmentEntry {
+        assignment: assignment.clone(),
+        completed_ts_ms: None,
+    });
+    store.keep_intent_log(&intent_log);
+    ctx.save_store(&store)?;
+    let message = format!("Mission {mission} assigned to {}", assignment.assignee);
+    emit_message(ctx, &assignment, &message)
+}
+
+fn complete(mission: MissionId, assignee: Did, timestamp_ms: Option<u64>, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let mut store = ctx.load_store()?;
+    let entry = store
+        .assignments
+        .iter_mut()
+        .rev()
+        .find(|e| e.completed_ts_ms.is_none() && e.assignment.mission.id == mission && e.assignment.assignee == assignee)
+        .ok_or_else(|| CliError::input(format!("No open assignment of {mission} for {assignee}")))?;
+    entry.completed_ts_ms = Some(timestamp_or_now(timestamp_ms));
+    let entry = entry.clone();
+    ctx.save_store(&store)?;
+    let message = format!("Mission {mission} completed by {assignee}");
+    emit_message(ctx, &entry, &message)
+}
+
+fn list(assignee: Option<Did>, open: bool, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let store = ctx.load_store()?;
+    let entries: Vec<&AssignmentEntry> = store
+        .assignments
+        .iter()
+        .filter(|e| assignee.as_ref().is_none_or(|a| &e.assignment.assignee == a))
+        .filter(|e| !open || e.completed_ts_ms.is_none())
+        .collect();
+    emit(ctx, &entries, || {
+        let mut table = Table::new(&["MISSION", "ASSIGNEE", "ASSIGNED_MS", "COMPLETED_MS", "TITLE"]);
+        for e in &entries {
+            table.row(vec![
+                e.assignment.mission.id.to_string(),
+                e.assignment.assignee.to_string(),
+                e.assignment.assigned_ts_ms.to_string(),
+                opt(e.completed_ts_ms),
+                e.assignment.mission.title.clone(),
+            ]);
+        }
+        table
+    })
+}
@@ -0,0 +1,88 @@
+// path: steward-cli/src/output.rs
+
+//! Human tables by default, JSON with `--json`.
+
+use serde::Serialize;
+
+use crate::{CliError, Ctx};
+
+/// Plain-text table with left-aligned columns sized to their widest cell.
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&'static str]) -> Self {
+        Self {
+            headers: headers.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    fn render(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (w, cell) in widths.iter_mut().zip(row) {
+                *w = (*w).max(cell.chars().count());
+            }
+        }
+        let line = |cells: Vec<&str>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(c, w)| format!("{c:<w$}"))
+                .collect();
+            padded.join("  ").trim_end().to_string() + "\n"
+        };
+        let rules: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        let mut out = line(self.headers.clone());
+        out += &line(rules.iter().map(String::as_str).collect());
+        for row in &self.rows {
+            out += &line(row.iter().map(String::as_str).collect());
+        }
+        if self.rows.is_empty() {
+            out += "(none)\n";
+        }
+        out
+    }
+}
+
+fn write_out(ctx: &mut Ctx<'_>, text: &str) -> Result<(), CliError> {
+    ctx.out
+        .write_all(text.as_bytes())
+        .map_err(|e| CliError::io(format!("Cannot write output: {e}")))
+}
+
+/// Print `value` as pretty JSON under `--json`, otherwise the table `table` builds.
+pub fn emit<T: Serialize>(ctx: &mut Ctx<'_>, value: &T, table: impl FnOnce() -> Table) -> Result<(), CliError> {
+    let text = if ctx.json {
+        serde_json::to_string_pretty(value).expect("output serialization") + "\n"
+    } else {
+        table().render()
+    };
+    write_out(ctx, &text)
+}
+
+/// Print a one-line confirmation, or `value` as JSON under `--json`.
+pub fn emit_message<T: Serialize>(ctx: &mut Ctx<'_>, value: &T, message: &str) -> Result<(), CliError> {
+    let text = if ctx.json {
+        serde_json::to_string_pretty(value).expect("output serialization") + "\n"
+    } else {
+        format!("{message}\n")
+    };
+    write_out(ctx, &text)
+}
+
+/// Write raw text regardless of `--json` (exports).
+pub fn emit_raw(ctx: &mut Ctx<'_>, text: &str) -> Result<(), CliError> {
+    write_out(ctx, text)
+}
+
+pub fn opt<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_else(|| "-".into())
+}
@@ -0,0 +1,120 @@
+// path: steward-cli/src/rollback.rs
+
+//! `rollback register | invoke | list`
+
+use clap::{Args, Subcommand};
+
+use planetary_stewardship_runtime::{ActionRef, AttestationId, Did, MissionId, PlanId, RegisteredPlan, RollbackPlan};
+
+use crate::output::{emit, emit_message, opt, Table};
+use crate::{timestamp_or_now, CliError, Ctx};
+
+#[derive(Debug, Subcommand)]
+pub enum RollbackCommand {
+    /// Register a plan against a stored attestation or a mission assignment.
+    Register(RegisterArgs),
+    /// Record that a plan was carried out (once per plan).
+    Invoke {
+        #[arg(long)]
+        plan: String,
+        #[arg(long)]
+        by: Did,
+        #[arg(long)]
+        note: String,
+        /// Defaults to now.
+        #[arg(long)]
+        timestamp_ms: Option<u64>,
+    },
+    /// List plans.
+    List {
+        /// Audit view: only plans created before this timestamp and never invoked.
+        #[arg(long)]
+        never_invoked_before: Option<u64>,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct RegisterArgs {
+    #[arg(long)]
+    pub id: String,
+    /// Stored attestation the plan revokes.
+    #[arg(long, required_unless_present = "mission", conflicts_with = "mission")]
+    pub attestation: Option<String>,
+    /// Mission whose assignment to `--assignee` the plan undoes.
+    #[arg(long, requires = "assignee")]
+    pub mission: Option<MissionId>,
+    #[arg(long, requires = "mission")]
+    pub assignee: Option<Did>,
+    /// Repeat for several steps, in order.
+    #[arg(long = "step", required = true)]
+    pub steps: Vec<String>,
+    #[arg(long)]
+    pub responsible: Did,
+    /// Defaults to now.
+    #[arg(long)]
+    pub timestamp_ms: Option<u64>,
+}
+
+pub fn run(cmd: RollbackCommand, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    match cmd {
+        RollbackCommand::Register(args) => register(args, ctx),
+        RollbackCommand::Invoke { plan, by, note, timestamp_ms } => invoke(PlanId(plan), by, note, timestamp_ms, ctx),
+        RollbackCommand::List { never_invoked_before } => list(never_invoked_before, ctx),
+    }
+}
+
+fn register(args: RegisterArgs, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let action_ref = match (args.attestation, args.mission, args.assignee) {
+        (Some(id), _, _) => ActionRef::Attestation(AttestationId(id)),
+        (None, Some(mission), Some(assignee)) => ActionRef::Assignment { mission, assignee },
+        _ => return Err(CliError::input("Give --attestation, or --mission with --assignee")),
+    };
+    let plan = RollbackPlan {
+        id: PlanId(args.id),
+        action_ref,
+        steps: args.steps,
+        responsible: args.responsible,
+        created_ms: timestamp_or_now(args.timestamp_ms),
+    };
+    let mut store = ctx.load_store()?;
+    let mut registry = store.rollback_plans.clone();
+    registry.register(plan.clone(), &store).map_err(CliError::input)?;
+    store.rollback_plans = registry;
+    ctx.save_store(&store)?;
+    let message = format!("Rollback plan registered: {} for {}", plan.id, plan.action_ref);
+    emit_message(ctx, &plan, &message)
+}
+
+fn invoke(plan: PlanId, by: Did, note: String, timestamp_ms: Option<u64>, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let mut store = ctx.load_store()?;
+    store
+        .rollback_plans
+        .mark_invoked(&plan, by, timestamp_or_now(timestamp_ms), note)
+        .map_err(CliError::input)?;
+    let registered = store.rollback_plans.get(&plan).cloned().expect("plan just invoked");
+    ctx.save_store(&store)?;
+    let message = format!("Rollback plan invoked: {plan}");
+    emit_message(ctx, &registered, &message)
+}
+
+fn list(never_invoked_before: Option<u64>, ctx: &mut Ctx<'_>) -> Result<(), CliError> {
+    let store = ctx.load_store()?;
+    let plans: Vec<&RegisteredPlan> = match never_invoked_before {
+        Some(cutoff) => store.rollback_plans.plans_never_invoked(cutoff),
+        None => store.rollback_plans.plans(),
+    };
+    emit(ctx, &plans, || {
+        let mut table = Table::new(&["ID", "ACTION", "RESPONSIBLE", "CREATED_MS", "STEPS", "INVOKED_MS"]);
+        for p in &plans {
+            table.row(vec![
+                p.plan.id.to_string(),
+                p.plan.action_ref.to_string(),
+                p.plan.responsible.to_string(),
+                p.plan.created_ms.to_string(),
+                p.plan.steps.len().to_string(),
+                opt(p.invocation.as_ref().map(|i| i.timestamp_ms)),
+            ]);
+        }
+        table
+    })
+}
@@ -0,0 +1,168 @@
+// path: steward-cli/src/store.rs
+
+//! JSON-snapshot store backing the CLI.
+//! - One file holds consents, attestations, missions, manifests, the public intent log
+//!   and the rollback plan registry
+//! - Consents are append-only; the last record per (participant, module, mission) wins
+//! - Saves go through a temp file + rename so a crash never leaves half a store
+
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::io::ErrorKind as IoErrorKind;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use aln_karma::SafetyEpochManifest;
+use planetary_stewardship_runtime::{
+    ActionRef, ActionResolver, AssignedMission, ConsentEvidencePolicy, ConsentRecord, ConsentRegistry, IntentLog,
+    MissionTemplate,
+    RollbackPlanRegistry, SaepConfig, SaepEngine, SharedIntentLog, SharedRollbackRegistry, StewardshipAttestation,
+};
+
+use crate::CliError;
+
+pub const STORE_SCHEMA_VERSION: u32 = 1;
+
+/// A mission assignment plus its completion, if recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentEntry {
+    pub assignment: AssignedMission,
+    #[serde(default)]
+    pub completed_ts_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StewardStore {
+    pub schema_version: u32,
+    /// SAEP configuration applied to every decision made through the CLI.
+    #[serde(default)]
+    pub saep: SaepConfig,
+    /// Checked against each new consent grant; records already stored are not re-checked.
+    #[serde(default)]
+    pub consent_evidence: ConsentEvidencePolicy,
+    /// Full consent history, oldest first.
+    #[serde(default)]
+    pub consents: Vec<ConsentRecord>,
+    #[serde(default)]
+    pub attestations: Vec<StewardshipAttestation>,
+    #[serde(default)]
+    pub mission_templates: Vec<MissionTemplate>,
+    #[serde(default)]
+    pub assignments: Vec<AssignmentEntry>,
+    #[serde(default)]
+    pub manifests: Vec<SafetyEpochManifest>,
+    /// Public intent entries published by attestations and assignments.
+    #[serde(default)]
+    pub intent_log: IntentLog,
+    /// Rollback plans for attestations and assignments.
+    #[serde(default)]
+    pub rollback_plans: RollbackPlanRegistry,
+}
+
+impl Default for StewardStore {
+    fn default() -> Self {
+        Self {
+            schema_version: STORE_SCHEMA_VERSION,
+            saep: SaepConfig::default(),
+            consent_evidence: ConsentEvidencePolicy::default(),
+            consents: Vec::new(),
+            attestations: Vec::new(),
+            mission_templates: Vec::new(),
+            assignments: Vec::new(),
+            manifests: Vec::new(),
+            intent_log: IntentLog::default(),
+            rollback_plans: RollbackPlanRegistry::default(),
+        }
+    }
+}
+
+impl StewardStore {
+    /// Load the store at `path`; a missing file is an empty store.
+    pub fn load(path: &Path) -> Result<Self, CliError> {
+        let raw = match fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(e) if e.kind() == IoErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(CliError::io(format!("Cannot read store {}: {e}", path.display()))),
+        };
+        let store: Self = serde_json::from_str(&raw)
+            .map_err(|e| CliError::io(format!("Store {} is not valid JSON: {e}", path.display())))?;
+        if store.schema_version > STORE_SCHEMA_VERSION {
+            return Err(CliError::io(format!(
+                "Store {} has schema version {}, newer than supported {STORE_SCHEMA_VERSION}",
+                path.display(),
+                store.schema_version
+            )));
+        }
+        Ok(store)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), CliError> {
+        let json = serde_json::to_string_pretty(self).expect("store serialization");
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, json)
+            .and_then(|_| fs::rename(&tmp, path))
+            .map_err(|e| CliError::io(format!("Cannot write store {}: {e}", path.display())))
+    }
+
+    pub fn saep_engine(&self) -> SaepEngine {
+        SaepEngine::new(self.saep.clone())
+    }
+
+    /// Registry holding the effective (latest) consent per key, under the store's evidence policy.
+    pub fn consent_registry(&self) -> ConsentRegistry {
+        let mut registry = ConsentRegistry::new().with_evidence_policy(self.consent_evidence.clone());
+        for record in &self.consents {
+            registry.upsert_consent(record.clone());
+        }
+        registry
+    }
+
+    /// Copy of the intent log for an engine to publish into; see `keep_intent_log`.
+    pub fn shared_intent_log(&self) -> SharedIntentLog {
+        Arc::new(Mutex::new(self.intent_log.clone()))
+    }
+
+    /// Take back what an engine published into `shared_intent_log`.
+    pub fn keep_intent_log(&mut self, log: &SharedIntentLog) {
+        if let Ok(log) = log.lock() {
+            self.intent_log = log.clone();
+        }
+    }
+
+    /// Copy of the rollback registry for engines to check plans against; see `keep_rollback_plans`.
+    pub fn shared_rollback_plans(&self) -> SharedRollbackRegistry {
+        Arc::new(Mutex::new(self.rollback_plans.clone()))
+    }
+
+    /// Take back plans registered through `shared_rollback_plans`.
+    pub fn keep_rollback_plans(&mut self, registry: &SharedRollbackRegistry) {
+        if let Ok(registry) = registry.lock() {
+            self.rollback_plans = registry.clone();
+        }
+    }
+
+    /// Latest consent record per (participant, module, mission), in first-seen order.
+    pub fn effective_consents(&self) -> Vec<&ConsentRecord> {
+        let mut effective: Vec<&ConsentRecord> = Vec::new();
+        for record in &self.consents {
+            match effective.iter_mut().find(|r| {
+                r.participant == record.participant && r.module == record.module && r.mission == record.mission
+            }) {
+                Some(slot) => *slot = record,
+                None => effective.push(record),
+            }
+        }
+        effective
+    }
+}
+
+/// Stored attestations and mission templates; the CLI keeps no governance proposals.
+impl ActionResolver for StewardStore {
+    fn action_exists(&self, action: &ActionRef) -> bool {
+        match action {
+            ActionRef::Attestation(id) => self.attestations.iter().any(|a| &a.id == id),
+            ActionRef::Assignment { mission, .. } => self.mission_templates.iter().any(|t| &t.id == mission),
+            ActionRef::GovernanceProposal(_) => false,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// path: steward-export/examples/module_metrics.rs
+
+//! Example: a second module issuing attestations through the ledger
+//! (run with `--features planetary_stewardship_runtime`).
+//! - VET registers a metric schema; its claims carry metrics under it, checked at issuance
+//! - Claims that break the schema, carry PLGA metrics, or lack VET consent are refused
+//! - The summary keeps PLGA's typed totals and totals VET's fields by their aggregation
+//! - Queries across modules still see every record, and the subject-access export carries
+//!   the module and its metrics; PLGA records serialize as before
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+fn main() {
+    use planetary_stewardship_runtime::builders::{
+        did, AttestationBuilder, ConsentRecordBuilder, LedgerBuilder, FIXTURE_START_MS,
+    };
+    use planetary_stewardship_runtime::{
+        ImpactMetrics, MetricAggregation, MetricField, MetricFieldKind, MetricFieldValue, MetricSchema, StewardModule,
+        StewardshipAttestation, ValidationCode,
+    };
+    use steward_export::{subject_access_export, ExportSources};
+
+    const T0: u64 = FIXTURE_START_MS;
+    const VET: StewardModule = StewardModule::VET;
+
+    let (ana, ben) = (did("did:psv:steward:ana"), did("did:psv:steward:ben"));
+    let mut ledger = LedgerBuilder::new()
+        .without_rollback_plans()
+        .consenting(&ana)
+        .consenting(&ben)
+        .consent(ConsentRecordBuilder::new(&ana, VET).build())
+        .build();
+
+    // 1. The schema: PLGA's is built in; VET registers once, MME never.
+    let integer = |name| MetricField::new(name, MetricFieldKind::Integer);
+    let vet = MetricSchema::new(
+        VET,
+        vec![
+            integer("sessions").required().with_range(1.0, 50.0),
+            integer("participants").with_range(0.0, 500.0),
+            integer("peak_attendance").with_aggregation(MetricAggregation::Max),
+            MetricField::new("accessible_venue", MetricFieldKind::Flag),
+        ],
+    );
+    assert_eq!(ledger.metric_schemas().get(StewardModule::PLGA), Some(&MetricSchema::plga()));
+    ledger.register_metric_schema(vet.clone()).expect("valid schema");
+    assert!(ledger.register_metric_schema(vet).unwrap_err().contains("already"));
+    let mme = MetricSchema::new(StewardModule::MME, vec![]);
+    assert!(ledger.register_metric_schema(mme).unwrap_err().contains("assignments"));
+    let flag_range = MetricField::new("open", MetricFieldKind::Flag).with_range(0.0, 1.0);
+    let ocg = MetricSchema::new(StewardModule::OCG, vec![flag_range]);
+    assert!(ledger.register_metric_schema(ocg).unwrap_err().contains("takes no range"));
+
+    // 2. Issuance: one PLGA claim and two VET claims by ana.
+    AttestationBuilder::new(&ana).timestamp_ms(T0).issue_on(&mut ledger).expect("PLGA claim");
+    let workshop =
+        |at_ms| AttestationBuilder::new(&ana).module(VET).description("Tree care workshops").timestamp_ms(at_ms);
+    let first = workshop(T0 + 1)
+        .module_metric("sessions", 3)
+        .module_metric("participants", 41)
+        .module_metric("peak_attendance", 18)
+        .module_metric("accessible_venue", true)
+        .issue_on(&mut ledger)
+        .expect("fits the schema");
+    workshop(T0 + 2)
+        .module_metric("sessions", 2)
+        .module_metric("participants", 25)
+        .module_metric("peak_attendance", 22)
+        .module_metric("accessible_venue", false)
+        .issue_on(&mut ledger)
+        .expect("fits the schema");
+    assert_eq!(first.module, VET);
+    assert!(first.as_plga_metrics().is_none() && first.impact_metrics.is_empty());
+
+    // 3. Refusals, each previewed by the dry run.
+    let refused = [
+        (workshop(T0 + 3).module_metric("sessions", 1).module_metric("volunteers", 4), "not in the VET schema"),
+        (workshop(T0 + 3).module_metric("participants", 4), "sessions is required"),
+        (workshop(T0 + 3).module_metric("sessions", 1.5), "must be Integer"),
+        (workshop(T0 + 3).module_metric("sessions", 60), "outside [1, 50]"),
+        (workshop(T0 + 3).module_metric("sessions", 1).co2eq_reduced(0.4), "not impact_metrics"),
+        (AttestationBuilder::new(&ana).module_metric("sessions", 1), "not module_metrics"),
+        (AttestationBuilder::new(&ana).module(StewardModule::OCG), "OCG has no metric schema"),
+    ];
+    for (claim, expected) in refused {
+        let report = ledger.validate_attestation(&claim.request());
+        assert!(report.has(ValidationCode::MetricSchema), "{expected}");
+        let err = claim.issue_on(&mut ledger).unwrap_err();
+        assert!(err.contains(expected), "{err}");
+    }
+    let no_consent = AttestationBuilder::new(&ben).module(VET).module_metric("sessions", 1);
+    assert!(ledger.validate_attestation(&no_consent.request()).has(ValidationCode::ConsentMissing));
+    assert_eq!(no_consent.issue_on(&mut ledger).unwrap_err(), "No valid KSCP consent for VET attestation");
+
+    // 4. The summary: PLGA's totals as before, VET's by each field's aggregation.
+    let summary = ledger.impact_summary(Some(&ana));
+    assert_eq!(summary.attestations, 3);
+    assert_eq!(summary.totals.co2eq_reduced, 0.2);
+    let totals = summary.module(VET).expect("VET claims");
+    assert_eq!(totals.attestations, 2);
+    let fields: Vec<(&str, f64)> = totals.fields.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    let expected = [("accessible_venue", 1.0), ("participants", 66.0), ("peak_attendance", 22.0), ("sessions", 5.0)];
+    assert_eq!(fields, expected);
+    println!("{}", serde_json::to_string_pretty(&summary).expect("summary json"));
+
+    // 5. Cross-module queries, and PLGA's typed metrics by field name.
+    assert_eq!(ledger.get_attestations_for_actor(&ana).len(), 3);
+    assert_eq!(ledger.attestations_in_module(VET).count(), 2);
+    let plga = ledger.attestations_in_module(StewardModule::PLGA).next().expect("PLGA claim");
+    assert_eq!(plga.as_plga_metrics().map(|m| m.co2eq_reduced), Some(0.2));
+    assert_eq!(plga.metrics()["co2eq_reduced"], MetricFieldValue::Number(0.2));
+    assert_eq!(plga.metrics(), ImpactMetrics { co2eq_reduced: 0.2, ..ImpactMetrics::default() }.to_module_metrics());
+
+    // 6. The export: VET records name their module; PLGA records serialize without one.
+    let export = subject_access_export(&ExportSources::new().with_ledger(&ledger), &ana).expect("export");
+    let attestations = &export.ledger.as_ref().expect("ledger section").attestations;
+    assert_eq!(attestations.len(), 3);
+    let json = serde_json::to_value(&attestations[1].record).expect("attestation json");
+    assert_eq!((&json["module"], &json["module_metrics"]["sessions"]), (&"VET".into(), &3.into()));
+    assert!(serde_json::to_value(&attestations[0].record).expect("attestation json").get("module").is_none());
+    let back: StewardshipAttestation = serde_json::from_value(json).expect("reads back");
+    assert_eq!((back.module, back.module_metrics), (VET, first.module_metrics));
+}
+
+#[cfg(not(feature = "planetary_stewardship_runtime"))]
+fn main() {
+    eprintln!("run with --features planetary_stewardship_runtime");
+}
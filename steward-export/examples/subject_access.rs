@@ -0,0 +1,216 @@
+// path: steward-export/examples/subject_access.rs
+
+//! Example: ana asks for everything the workspace holds about her
+//! (run with `--features planetary_stewardship_runtime,the_element,cybernetic-governance`).
+//! - Ana and ben both use the ledger, missions, the element and the arena's governance
+//! - Ana withdraws: the DPO runs retention, so the ledger pseudonymizes her attestations and
+//!   missions tombstones one assignment; both still appear in her export, each next to its redaction
+//! - The export holds every record of hers, and nothing of ben's: his DID never appears
+//! - Categories that held nothing are listed, and each chain head matches the chain as read
+
+#[cfg(all(
+    feature = "planetary_stewardship_runtime",
+    feature = "the_element",
+    feature = "cybernetic-governance"
+))]
+fn main() {
+    use cybernetic_governance::builders::{
+        bci_xr_arena_domain, cap, caps, ConstitutionBuilder, ProposalBuilder, BCI_XR_ARENA,
+    };
+    use cybernetic_governance::{
+        AppealGrounds, AppealVerdict, CapabilityGovernance, MatchOverride, VoterId, VoterRegistry,
+    };
+    use planetary_stewardship_runtime::builders::{
+        did, mission_id, saep_without_rollback_plans, AttestationBuilder, ConsentRecordBuilder, LedgerBuilder,
+        MissionTemplateBuilder, FIXTURE_START_MS,
+    };
+    use planetary_stewardship_runtime::{
+        AdminAction, AdminEffect, AssignmentStatus, ConsentRegistry, DualControl, DualControlPolicy, IntentLog,
+        MicroMissionsEngine, RedactionMode, RetentionPolicy, SaepEngine, StewardModule, TOMBSTONE_DID,
+    };
+    use steward_export::{subject_access_export, ExportCategory, ExportSources, EXPORT_SCHEMA_VERSION};
+    use the_element::{default_element, CapabilityId, SafetyEvent};
+
+    const T0: u64 = FIXTURE_START_MS;
+    const DAY_MS: u64 = 86_400_000;
+
+    let (ana, ben) = (did("did:psv:steward:ana"), did("did:psv:steward:ben"));
+    let (creek, seeds) = (mission_id("creek-cleanup"), mission_id("seed-library"));
+    let config = saep_without_rollback_plans();
+    let salt = "phx-2026-retention";
+    // The DPO is the deployment's only admin, so retention runs on their say alone.
+    let dpo = did("did:psv:city:phoenix:dpo");
+    let mut admin = DualControl::new(DualControlPolicy {
+        admins: [dpo.clone()].into(),
+        ..DualControlPolicy::default()
+    });
+    admin.opt_out_single_admin(&dpo, "One-person pilot deployment".into(), T0).expect("admin");
+
+    // 1. Ledger: ana issues two claims and verifies one of ben's, then withdraws.
+    let log = IntentLog::shared();
+    let mut ledger = LedgerBuilder::new()
+        .saep(config.clone())
+        .consenting(&ana)
+        .consenting(&ben)
+        .build()
+        .with_intent_log(log.clone())
+        .with_retention_policy(RetentionPolicy::Pseudonymize { salt: salt.into() });
+    admin.bind(&ledger).expect("a new ledger is unbound");
+    for (actor, description, at_ms) in [(&ana, "Creek cleanup", T0), (&ana, "Planted willows", T0 + DAY_MS)] {
+        let claim = AttestationBuilder::new(actor).description(description).timestamp_ms(at_ms);
+        claim.issue_on(&mut ledger).expect("consented");
+    }
+    let verified = AttestationBuilder::new(&ben).description("Seed library").verifier(&ana).timestamp_ms(T0 + DAY_MS);
+    let verified = verified.issue_on(&mut ledger).expect("consented");
+    let withdrawal = ConsentRecordBuilder::new(&ana, StewardModule::PLGA).at_ms(T0 + 2 * DAY_MS).withdrawn().build();
+    ledger.consent_mut().upsert_consent(withdrawal);
+    let retention = AdminAction::ExecuteRetention { module: StewardModule::PLGA };
+    let effect = admin.propose(&mut ledger, retention, &dpo, T0 + 2 * DAY_MS).expect("ana's withdrawal");
+    assert_eq!(effect.into_effect(), Some(AdminEffect::RetentionExecuted { redactions: 2 }));
+
+    // 2. Missions, on the same intent log: ana finishes one mission and withdraws from another.
+    let mut registry = ConsentRegistry::new();
+    for (who, mission) in [(&ana, &creek), (&ana, &seeds), (&ben, &creek)] {
+        registry.upsert_consent(ConsentRecordBuilder::new(who, StewardModule::MME).mission(mission).build());
+    }
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), registry)
+        .with_intent_log(log.clone())
+        .with_retention_policy(RetentionPolicy::Tombstone);
+    admin.bind(&missions).expect("a new engine is unbound");
+    for mission in [&creek, &seeds] {
+        missions.add_template(MissionTemplateBuilder::new(mission.as_str()).build_valid());
+    }
+    missions.set_preferred_language(ana.clone(), "es".parse().expect("valid tag"));
+    let done = missions.assign_mission(&creek, ana.clone(), T0).expect("consented").key();
+    missions.close_assignment(&done, AssignmentStatus::Completed, T0 + DAY_MS).expect("open");
+    missions.assign_mission(&seeds, ana.clone(), T0 + DAY_MS).expect("consented");
+    missions.assign_mission(&creek, ben.clone(), T0 + DAY_MS).expect("consented");
+    let withdrawal = ConsentRecordBuilder::new(&ana, StewardModule::MME).mission(&seeds).at_ms(T0 + 2 * DAY_MS);
+    missions.consent_mut().upsert_consent(withdrawal.withdrawn().build());
+    let retention = AdminAction::ExecuteRetention { module: StewardModule::MME };
+    let effect = admin.propose(&mut missions, retention, &dpo, T0 + 2 * DAY_MS).expect("ana's withdrawal");
+    assert_eq!(effect.into_effect(), Some(AdminEffect::RetentionExecuted { redactions: 1 }));
+
+    // 3. The element: both enable an ability; ana pauses once.
+    let mut element = default_element();
+    let focus = CapabilityId::new("cognitive:focus_enhancer").expect("valid capability");
+    for who in [&ana, &ben] {
+        element.request_enable(who, &focus, true).expect("enabled");
+    }
+    element.record_safety_event(&ana, SafetyEvent::Pause { duration_ms: None }, T0);
+    element.record_safety_event(&ben, SafetyEvent::Resume, T0);
+
+    // 4. Governance: both vote, both appeal, ana reviews ben's appeal and referees a match.
+    let constitution = ConstitutionBuilder::new()
+        .global_min_capability_floor(4)
+        .referees(&[ana.as_str()], 50)
+        .appeal_panel(&[ana.as_str(), "panel:okafor"], 50)
+        .build();
+    let mut gov = CapabilityGovernance::new(constitution);
+    gov.upsert_domain(bci_xr_arena_domain()).expect("arena domain is valid");
+    let mut voters = VoterRegistry::new();
+    for (who, weight) in [(&ana, 80), (&ben, 20)] {
+        voters.register(VoterId::from(who.clone()), weight);
+        voters.set_eligibility(&VoterId::from(who.clone()), BCI_XR_ARENA, true).expect("registered");
+    }
+    let trim = ProposalBuilder::new("trim", BCI_XR_ARENA)
+        .restrict(&["move:bci_pull", "move:bci_shield"])
+        .activation_height(100)
+        .build_valid_under(&gov);
+    gov.open_proposal_vote(&trim, voters.snapshot(BCI_XR_ARENA)).expect("valid proposal");
+    gov.cast("trim", &VoterId::from(ana.clone()), true).expect("in snapshot");
+    gov.cast("trim", &VoterId::from(ben.clone()), false).expect("in snapshot");
+    let outcome = gov.close_vote("trim", 105).expect("open vote");
+    gov.commit_proposal(&trim, &outcome, 110).expect("80% passes");
+    let (shield, pull) = (cap("move:bci_shield"), cap("move:bci_pull"));
+    gov.file_appeal(BCI_XR_ARENA, &shield, ana.as_str(), AppealGrounds::Procedural, vec![], 120).expect("in window");
+    let bens = gov.file_appeal(BCI_XR_ARENA, &pull, ben.as_str(), AppealGrounds::Procedural, vec![], 120);
+    let bens = bens.expect("in window").appeal_id.clone();
+    let denied = AppealVerdict::Denied { rationale: "Within the turn's limits".into() };
+    gov.review_appeal(&bens, ana.as_str(), denied, 125).expect("on the panel");
+    let match_override = MatchOverride {
+        match_id: "match-7".into(),
+        domain_id: BCI_XR_ARENA.into(),
+        disabled: caps(&["move:bci_push"]),
+        issued_by: ana.as_str().into(),
+        valid_from_height: 126,
+        valid_to_height: 140,
+        rationale: "Sensor recalibration".into(),
+    };
+    gov.issue_override(match_override, 126).expect("ana referees");
+
+    // 5. Ana's export.
+    let sources = ExportSources::new()
+        .with_ledger(&ledger)
+        .with_missions(&missions)
+        .with_element(&element)
+        .with_governance(&gov)
+        .with_voter_registry(&voters);
+    let export = subject_access_export(&sources, &ana).expect("sources readable");
+    println!("{}", serde_json::to_string_pretty(&export).expect("export json"));
+    assert_eq!((export.schema_version, &export.subject), (EXPORT_SCHEMA_VERSION, &ana));
+
+    // 6. Every record of hers, redacted ones with their redaction.
+    let pseudonym = RetentionPolicy::pseudonym(salt, &ana);
+    let on_ledger = export.ledger.as_ref().expect("ledger queried");
+    assert_eq!(on_ledger.consent.len(), 1);
+    assert_eq!(on_ledger.attestations.len(), 2);
+    assert!(on_ledger.attestations.iter().all(|a| a.record.actor_did == pseudonym
+        && a.redaction.as_ref().is_some_and(|r| r.mode == RedactionMode::Pseudonymized)));
+    assert_eq!(on_ledger.verifier_roles.len(), 1);
+    assert_eq!(on_ledger.verifier_roles[0].attestation, verified.id);
+    assert_eq!(on_ledger.intents.len(), 4, "two claims, two assignments");
+    let on_missions = export.missions.as_ref().expect("missions queried");
+    assert_eq!(on_missions.consent.len(), 2);
+    assert_eq!(on_missions.preferred_language.as_ref().map(|l| l.to_string()), Some("es".into()));
+    let [tombstoned] = on_missions.active_assignments.as_slice() else { panic!("one open assignment") };
+    assert_eq!(tombstoned.record.assignee.as_str(), TOMBSTONE_DID);
+    assert_eq!(tombstoned.redaction.as_ref().map(|r| r.mode), Some(RedactionMode::Tombstoned));
+    assert_eq!(on_missions.archived_assignments.len(), 1);
+    assert!(on_missions.intents.is_empty(), "the shared log is exported once, with the ledger");
+    let in_element = export.element.as_ref().expect("element queried");
+    assert!(in_element.profile.is_some());
+    assert_eq!(in_element.safety_log.len(), 1);
+    let in_governance = export.governance.as_ref().expect("governance queried");
+    assert_eq!(in_governance.voter.as_ref().map(|v| v.weight), Some(80));
+    assert_eq!(in_governance.ballots.len(), 1);
+    assert!(in_governance.ballots[0].support);
+    assert_eq!(in_governance.appeals.len(), 1);
+    assert_eq!(in_governance.appeal_reviews, [bens]);
+    assert_eq!(in_governance.match_overrides.len(), 1);
+
+    // 7. Nothing of ben's.
+    let json = serde_json::to_string(&export).expect("export json");
+    assert!(!json.contains(ben.as_str()));
+
+    // 8. Empty categories are listed; the chain heads match the chains.
+    assert_eq!(
+        export.empty_categories,
+        [
+            ExportCategory::ElementUsageLog,
+            ExportCategory::ElementReassessmentLog,
+            ExportCategory::ElementPendingReassessments,
+        ]
+    );
+    let chains: Vec<&str> = export.chain_heads.iter().map(|h| h.chain.as_str()).collect();
+    assert_eq!(chains, ["ledger.intent_log", "ledger.redaction_log", "missions.redaction_log", "governance.event_log"]);
+    let intent_head = log.lock().unwrap().entries().last().map(|e| e.self_hash.clone());
+    assert_eq!(export.chain_heads[0].head_hash, intent_head);
+    assert_eq!(export.chain_heads[3].length, gov.event_log().entries().len() as u64);
+    gov.verify_log().expect("governance log verifies");
+
+    // 9. Ben's export holds his claim, which names ana as its verifier, and none of her records.
+    let theirs = subject_access_export(&sources, &ben).expect("sources readable");
+    assert!(!serde_json::to_string(&theirs).expect("export json").contains(pseudonym.as_str()));
+    let [claim] = theirs.ledger.as_ref().expect("ledger queried").attestations.as_slice() else { panic!("one claim") };
+    assert_eq!((&claim.record.id, &claim.record.verifier_dids), (&verified.id, &vec![ana.clone()]));
+}
+
+#[cfg(not(all(
+    feature = "planetary_stewardship_runtime",
+    feature = "the_element",
+    feature = "cybernetic-governance"
+)))]
+fn main() {
+    eprintln!("run with --features planetary_stewardship_runtime,the_element,cybernetic-governance");
+}
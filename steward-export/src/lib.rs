@@ -0,0 +1,546 @@
+// path: steward-export/src/lib.rs
+
+//! `steward-export`: everything the workspace holds about one DID, as one document.
+//! - `subject_access_export` queries only the engines handed to it in `ExportSources`; each
+//!   source system is compiled in with the feature named after its crate
+//!   (`planetary_stewardship_runtime`, `the_element`, `cybernetic-governance`)
+//! - `SubjectExport` is versioned (`EXPORT_SCHEMA_VERSION`) and has one section per source
+//!   system; a section is absent when its engine was not handed over
+//! - A category that was queried and held nothing for the subject is listed in
+//!   `empty_categories`, so "nothing held" and "not asked" read differently
+//! - Records redacted after a withdrawal appear as they now stand, next to the
+//!   `RedactionRecord` that redacted them. A pseudonymized record is found by the subject's
+//!   pseudonym under the engine's current salt, and any redacted record through the
+//!   withdrawal that redacted it, while the consent registry still holds that withdrawal
+//! - `chain_heads` gives the length and last hash of every hash chain the export read, so a
+//!   recipient can check the export against a later verification of that chain
+//! - Records about other participants never appear; where one names the subject in a
+//!   secondary role (verifier, appeal reviewer), only a reference to it is exported
+
+use serde::{Serialize, Deserialize};
+use std::marker::PhantomData;
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+use std::collections::HashSet;
+#[cfg(feature = "planetary_stewardship_runtime")]
+use std::sync::Arc;
+
+pub use steward_ids::Did;
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+use planetary_stewardship_runtime::{
+    retention, ArchivedAssignment, AssignedMission, AssignmentFilter, AttestationId, ConsentRecord, ConsentRegistry,
+    IntentLogEntry, LanguageTag, MicroMissionsEngine, PlanetaryLedger, RedactionLog, RedactionRecord, RedactionTarget,
+    RetentionPolicy, SharedIntentLog, StewardshipAttestation,
+};
+
+#[cfg(feature = "the_element")]
+use the_element::{
+    AgentCyberProfile, PendingReassessment, ReassessmentRecord, SafetyEventRecord, TheElement, UsageRecord,
+};
+
+#[cfg(feature = "cybernetic-governance")]
+use cybernetic_governance::{
+    CapabilityAppeal, CapabilityGovernance, GovernanceEvent, MatchOverride, VoterId, VoterRecord, VoterRegistry,
+};
+
+/// Crate version stamped into exported JSON Schemas.
+#[cfg(feature = "schema")]
+pub const SCHEMA_CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Bumped whenever a field of `SubjectExport` changes meaning or is removed.
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// The engines an export reads; unset sources are not queried.
+#[derive(Clone, Copy, Default)]
+pub struct ExportSources<'a> {
+    #[cfg(feature = "planetary_stewardship_runtime")]
+    ledger: Option<&'a PlanetaryLedger>,
+    #[cfg(feature = "planetary_stewardship_runtime")]
+    missions: Option<&'a MicroMissionsEngine>,
+    #[cfg(feature = "the_element")]
+    element: Option<&'a TheElement>,
+    #[cfg(feature = "cybernetic-governance")]
+    governance: Option<&'a CapabilityGovernance>,
+    #[cfg(feature = "cybernetic-governance")]
+    voters: Option<&'a VoterRegistry>,
+    _engines: PhantomData<&'a ()>,
+}
+
+impl<'a> ExportSources<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg(feature = "planetary_stewardship_runtime")]
+    pub fn with_ledger(mut self, ledger: &'a PlanetaryLedger) -> Self {
+        self.ledger = Some(ledger);
+        self
+    }
+
+    #[cfg(feature = "planetary_stewardship_runtime")]
+    pub fn with_missions(mut self, missions: &'a MicroMissionsEngine) -> Self {
+        self.missions = Some(missions);
+        self
+    }
+
+    #[cfg(feature = "the_element")]
+    pub fn with_element(mut self, element: &'a TheElement) -> Self {
+        self.element = Some(element);
+        self
+    }
+
+    #[cfg(feature = "cybernetic-governance")]
+    pub fn with_governance(mut self, governance: &'a CapabilityGovernance) -> Self {
+        self.governance = Some(governance);
+        self
+    }
+
+    /// The registry the governance engine's vote snapshots were taken from; the engine
+    /// does not hold one itself.
+    #[cfg(feature = "cybernetic-governance")]
+    pub fn with_voter_registry(mut self, voters: &'a VoterRegistry) -> Self {
+        self.voters = Some(voters);
+        self
+    }
+}
+
+/// A category of records an export queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ExportCategory {
+    LedgerConsent,
+    LedgerAttestations,
+    LedgerVerifierRoles,
+    LedgerIntents,
+    MissionsConsent,
+    MissionsPreferredLanguage,
+    MissionsActiveAssignments,
+    MissionsArchivedAssignments,
+    MissionsIntents,
+    ElementProfile,
+    ElementSafetyLog,
+    ElementUsageLog,
+    ElementReassessmentLog,
+    ElementPendingReassessments,
+    GovernanceVoterRecord,
+    GovernanceBallots,
+    GovernanceAppeals,
+    GovernanceAppealReviews,
+    GovernanceMatchOverrides,
+}
+
+/// Where a record came from and how far that chain had grown when the export read it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChainHead {
+    /// `<section>.<log>`, e.g. `ledger.intent_log`.
+    pub chain: String,
+    pub length: u64,
+    /// `self_hash` of the last entry; `None` for an empty chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_hash: Option<String>,
+}
+
+/// A record as it now stands and, if it was redacted, its latest redaction.
+#[cfg(feature = "planetary_stewardship_runtime")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExportedRecord<T> {
+    pub record: T,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redaction: Option<RedactionRecord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VerifierRoleKind {
+    Verifier { counted: bool },
+    Witness,
+}
+
+/// Another actor's attestation the subject verified or witnessed.
+#[cfg(feature = "planetary_stewardship_runtime")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifierRole {
+    pub attestation: AttestationId,
+    pub role: VerifierRoleKind,
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LedgerExport {
+    pub consent: Vec<ConsentRecord>,
+    /// Attestations the subject issued, oldest first.
+    pub attestations: Vec<ExportedRecord<StewardshipAttestation>>,
+    pub verifier_roles: Vec<VerifierRole>,
+    /// Public intent entries the subject published; the log is never redacted.
+    pub intents: Vec<IntentLogEntry>,
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MissionsExport {
+    pub consent: Vec<ConsentRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preferred_language: Option<LanguageTag>,
+    pub active_assignments: Vec<ExportedRecord<AssignedMission>>,
+    pub archived_assignments: Vec<ExportedRecord<ArchivedAssignment>>,
+    /// Empty, and not in `empty_categories`, when the engine shares the ledger's intent log.
+    pub intents: Vec<IntentLogEntry>,
+}
+
+#[cfg(feature = "the_element")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ElementExport {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<AgentCyberProfile>,
+    pub safety_log: Vec<SafetyEventRecord>,
+    pub usage_log: Vec<UsageRecord>,
+    pub reassessment_log: Vec<ReassessmentRecord>,
+    pub pending_reassessments: Vec<PendingReassessment>,
+}
+
+/// A ballot the subject cast.
+#[cfg(feature = "cybernetic-governance")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CastBallot {
+    pub proposal_id: String,
+    pub support: bool,
+    /// Weight frozen in the vote's snapshot.
+    pub weight: u128,
+    pub vote_closed: bool,
+}
+
+#[cfg(feature = "cybernetic-governance")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GovernanceExport {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voter: Option<VoterRecord>,
+    pub ballots: Vec<CastBallot>,
+    /// Appeals the subject filed.
+    pub appeals: Vec<CapabilityAppeal>,
+    /// Ids of appeals the subject decided as reviewer.
+    pub appeal_reviews: Vec<String>,
+    /// Overrides the subject issued as referee, expired ones included.
+    pub match_overrides: Vec<MatchOverride>,
+}
+
+/// Everything the handed engines hold about `subject`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SubjectExport {
+    pub schema_version: u32,
+    pub subject: Did,
+    #[cfg(feature = "planetary_stewardship_runtime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ledger: Option<LedgerExport>,
+    #[cfg(feature = "planetary_stewardship_runtime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missions: Option<MissionsExport>,
+    #[cfg(feature = "the_element")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub element: Option<ElementExport>,
+    #[cfg(feature = "cybernetic-governance")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub governance: Option<GovernanceExport>,
+    pub chain_heads: Vec<ChainHead>,
+    /// Queried categories that held nothing for the subject.
+    pub empty_categories: Vec<ExportCategory>,
+}
+
+impl SubjectExport {
+    fn new(subject: &Did) -> Self {
+        Self {
+            schema_version: EXPORT_SCHEMA_VERSION,
+            subject: subject.clone(),
+            #[cfg(feature = "planetary_stewardship_runtime")]
+            ledger: None,
+            #[cfg(feature = "planetary_stewardship_runtime")]
+            missions: None,
+            #[cfg(feature = "the_element")]
+            element: None,
+            #[cfg(feature = "cybernetic-governance")]
+            governance: None,
+            chain_heads: Vec::new(),
+            empty_categories: Vec::new(),
+        }
+    }
+
+    /// Note `category` as queried; it is listed as empty if nothing was found.
+    #[cfg(any(feature = "planetary_stewardship_runtime", feature = "the_element", feature = "cybernetic-governance"))]
+    fn queried(&mut self, category: ExportCategory, found: usize) {
+        if found == 0 {
+            self.empty_categories.push(category);
+        }
+    }
+}
+
+/// Export every record the engines in `sources` hold about `subject`. Fails only when a
+/// source cannot be read (e.g. an unreadable assignment archive): a partial export would
+/// read as complete.
+#[cfg_attr(
+    not(any(feature = "planetary_stewardship_runtime", feature = "the_element", feature = "cybernetic-governance")),
+    allow(unused_variables, unused_mut)
+)]
+pub fn subject_access_export(sources: &ExportSources<'_>, subject: &Did) -> Result<SubjectExport, String> {
+    let mut export = SubjectExport::new(subject);
+    #[cfg(feature = "planetary_stewardship_runtime")]
+    {
+        let ledger_log = sources.ledger.map(|ledger| ledger.intent_log());
+        if let Some(ledger) = sources.ledger {
+            let section = export_ledger(ledger, subject, &mut export)?;
+            export.ledger = Some(section);
+        }
+        if let Some(missions) = sources.missions {
+            let section = export_missions(missions, subject, ledger_log.as_ref(), &mut export)?;
+            export.missions = Some(section);
+        }
+    }
+    #[cfg(feature = "the_element")]
+    if let Some(element) = sources.element {
+        export.element = Some(export_element(element, subject, &mut export));
+    }
+    #[cfg(feature = "cybernetic-governance")]
+    if let Some(governance) = sources.governance {
+        export.governance = Some(export_governance(governance, sources.voters, subject, &mut export));
+    }
+    export.empty_categories.sort();
+    Ok(export)
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+fn consent_for(registry: &ConsentRegistry, subject: &Did) -> Vec<ConsentRecord> {
+    registry.records().filter(|r| &r.participant == subject).cloned().collect()
+}
+
+/// Tells the subject's records apart from everyone else's on one engine.
+#[cfg(feature = "planetary_stewardship_runtime")]
+struct SubjectMatcher<'a> {
+    subject: &'a Did,
+    /// Who the subject's pseudonymized records name under the engine's current policy.
+    pseudonym: Option<Did>,
+    /// Hashes of the subject's withdrawals, as a `RedactionRecord` names them.
+    withdrawals: HashSet<String>,
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+impl<'a> SubjectMatcher<'a> {
+    fn new(subject: &'a Did, policy: &RetentionPolicy, consent: &[ConsentRecord]) -> Self {
+        let pseudonym = match policy {
+            RetentionPolicy::Pseudonymize { salt } => Some(RetentionPolicy::pseudonym(salt, subject)),
+            _ => None,
+        };
+        let withdrawals = consent.iter().filter(|r| !r.consent_given).map(retention::content_hash).collect();
+        Self { subject, pseudonym, withdrawals }
+    }
+
+    /// `record` as the subject's, if it names them, names their pseudonym, or was redacted
+    /// by one of their withdrawals.
+    fn claim<T: Clone>(
+        &self,
+        record: &T,
+        named: &Did,
+        redaction: Option<RedactionRecord>,
+    ) -> Option<ExportedRecord<T>> {
+        let ours = named == self.subject
+            || self.pseudonym.as_ref() == Some(named)
+            || redaction.as_ref().is_some_and(|r| self.withdrawals.contains(&r.withdrawal_hash));
+        ours.then(|| ExportedRecord { record: record.clone(), redaction })
+    }
+}
+
+/// The record's latest redaction, when it stands as that redaction left it.
+#[cfg(feature = "planetary_stewardship_runtime")]
+fn redaction_of<T: Serialize>(log: &RedactionLog, target: RedactionTarget, record: &T) -> Option<RedactionRecord> {
+    log.for_target(&target)
+        .filter(|r| r.redacted_hash == retention::content_hash(record))
+        .cloned()
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+fn intents_for(log: &SharedIntentLog, subject: &Did, chain: &str, export: &mut SubjectExport) -> Vec<IntentLogEntry> {
+    let log = log.lock().unwrap_or_else(|e| e.into_inner());
+    export.chain_heads.push(ChainHead {
+        chain: chain.into(),
+        length: log.entries().len() as u64,
+        head_hash: log.entries().last().map(|e| e.self_hash.clone()),
+    });
+    log.entries().iter().filter(|e| &e.entry.actor == subject).cloned().collect()
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+fn redaction_head(log: &RedactionLog, chain: &str) -> ChainHead {
+    ChainHead {
+        chain: chain.into(),
+        length: log.entries().len() as u64,
+        head_hash: log.entries().last().map(|r| r.self_hash.clone()),
+    }
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+fn export_ledger(ledger: &PlanetaryLedger, subject: &Did, export: &mut SubjectExport) -> Result<LedgerExport, String> {
+    let consent = consent_for(ledger.consent(), subject);
+    let matcher = SubjectMatcher::new(subject, ledger.retention_policy(), &consent);
+    let log = ledger.redaction_log();
+
+    let mut attestations = Vec::new();
+    let mut verifier_roles = Vec::new();
+    for attestation in ledger.attestations() {
+        let redaction = redaction_of(log, RedactionTarget::Attestation(attestation.id.clone()), attestation);
+        if let Some(record) = matcher.claim(attestation, &attestation.actor_did, redaction) {
+            attestations.push(record);
+            continue;
+        }
+        if attestation.verifier_dids.contains(subject) {
+            let counted = !attestation.non_counting_verifiers.contains(subject);
+            let role = VerifierRoleKind::Verifier { counted };
+            verifier_roles.push(VerifierRole { attestation: attestation.id.clone(), role });
+        }
+        if attestation.witness_endorsement.as_ref().is_some_and(|w| &w.witness_did == subject) {
+            let role = VerifierRoleKind::Witness;
+            verifier_roles.push(VerifierRole { attestation: attestation.id.clone(), role });
+        }
+    }
+    attestations.sort_by(|a, b| {
+        (a.record.timestamp_ms, &a.record.id.0).cmp(&(b.record.timestamp_ms, &b.record.id.0))
+    });
+    verifier_roles.sort_by(|a, b| a.attestation.0.cmp(&b.attestation.0));
+    let intents = intents_for(&ledger.intent_log(), subject, "ledger.intent_log", export);
+    export.chain_heads.push(redaction_head(log, "ledger.redaction_log"));
+
+    export.queried(ExportCategory::LedgerConsent, consent.len());
+    export.queried(ExportCategory::LedgerAttestations, attestations.len());
+    export.queried(ExportCategory::LedgerVerifierRoles, verifier_roles.len());
+    export.queried(ExportCategory::LedgerIntents, intents.len());
+    Ok(LedgerExport { consent, attestations, verifier_roles, intents })
+}
+
+#[cfg(feature = "planetary_stewardship_runtime")]
+fn export_missions(
+    missions: &MicroMissionsEngine,
+    subject: &Did,
+    ledger_log: Option<&SharedIntentLog>,
+    export: &mut SubjectExport,
+) -> Result<MissionsExport, String> {
+    let consent = consent_for(missions.consent(), subject);
+    let matcher = SubjectMatcher::new(subject, missions.retention_policy(), &consent);
+    let log = missions.redaction_log();
+    let assignment_target = |a: &AssignedMission| RedactionTarget::Assignment {
+        mission: a.mission.id.clone(),
+        assigned_ts_ms: a.assigned_ts_ms,
+    };
+
+    let mut active_assignments = Vec::new();
+    for assignment in missions.active_assignments() {
+        let redaction = redaction_of(log, assignment_target(assignment), assignment);
+        active_assignments.extend(matcher.claim(assignment, &assignment.assignee, redaction));
+    }
+    active_assignments.sort_by(|a, b| {
+        let key = |r: &ExportedRecord<AssignedMission>| (r.record.assigned_ts_ms, r.record.mission.id.clone());
+        key(a).cmp(&key(b))
+    });
+
+    let mut archived_assignments = Vec::new();
+    for archived in missions.archive_query(&AssignmentFilter::default()) {
+        let archived = archived?;
+        let redaction = redaction_of(log, assignment_target(&archived.assignment), &archived.assignment);
+        archived_assignments.extend(matcher.claim(&archived, &archived.assignment.assignee, redaction));
+    }
+
+    let preferred_language = missions.preferred_language(subject).cloned();
+    let shared_log = ledger_log.is_some_and(|l| Arc::ptr_eq(l, &missions.intent_log()));
+    let intents = if shared_log {
+        Vec::new()
+    } else {
+        let intents = intents_for(&missions.intent_log(), subject, "missions.intent_log", export);
+        export.queried(ExportCategory::MissionsIntents, intents.len());
+        intents
+    };
+    export.chain_heads.push(redaction_head(log, "missions.redaction_log"));
+
+    export.queried(ExportCategory::MissionsConsent, consent.len());
+    export.queried(ExportCategory::MissionsPreferredLanguage, preferred_language.iter().len());
+    export.queried(ExportCategory::MissionsActiveAssignments, active_assignments.len());
+    export.queried(ExportCategory::MissionsArchivedAssignments, archived_assignments.len());
+    Ok(MissionsExport { consent, preferred_language, active_assignments, archived_assignments, intents })
+}
+
+#[cfg(feature = "the_element")]
+fn export_element(element: &TheElement, subject: &Did, export: &mut SubjectExport) -> ElementExport {
+    let section = ElementExport {
+        profile: element.get_profile(subject).cloned(),
+        // The subject is asking for their own log; the owner check cannot refuse.
+        safety_log: element.export_safety_log(subject, subject).map(<[_]>::to_vec).unwrap_or_default(),
+        usage_log: element.usage_log(subject).to_vec(),
+        reassessment_log: element.reassessment_log(subject).to_vec(),
+        pending_reassessments: element.pending_reassessments(subject).into_iter().cloned().collect(),
+    };
+    export.queried(ExportCategory::ElementProfile, section.profile.iter().len());
+    export.queried(ExportCategory::ElementSafetyLog, section.safety_log.len());
+    export.queried(ExportCategory::ElementUsageLog, section.usage_log.len());
+    export.queried(ExportCategory::ElementReassessmentLog, section.reassessment_log.len());
+    export.queried(ExportCategory::ElementPendingReassessments, section.pending_reassessments.len());
+    section
+}
+
+#[cfg(feature = "cybernetic-governance")]
+fn export_governance(
+    governance: &CapabilityGovernance,
+    voters: Option<&VoterRegistry>,
+    subject: &Did,
+    export: &mut SubjectExport,
+) -> GovernanceExport {
+    let voter_id = VoterId(subject.0.clone());
+    let voter = voters.and_then(|v| v.get(&voter_id)).cloned();
+    let ballots: Vec<CastBallot> = governance
+        .vote_sessions()
+        .into_iter()
+        .filter_map(|session| {
+            let support = *session.ballots.get(&voter_id)?;
+            Some(CastBallot {
+                proposal_id: session.proposal_id.clone(),
+                support,
+                weight: session.snapshot.weights.get(&voter_id).copied().unwrap_or_default(),
+                vote_closed: session.outcome.is_some(),
+            })
+        })
+        .collect();
+    let appeals = governance.appeals().appeals();
+    let filed: Vec<CapabilityAppeal> = appeals.iter().filter(|a| a.appellant == subject.0).cloned().collect();
+    let appeal_reviews: Vec<String> = appeals
+        .iter()
+        .filter(|a| a.decision.as_ref().is_some_and(|d| d.reviewer == subject.0))
+        .map(|a| a.appeal_id.clone())
+        .collect();
+    let log = governance.event_log().entries();
+    let match_overrides: Vec<MatchOverride> = log
+        .iter()
+        .filter_map(|entry| match &entry.event {
+            GovernanceEvent::MatchOverrideIssued { match_override } if match_override.issued_by == subject.0 => {
+                Some(match_override.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    export.chain_heads.push(ChainHead {
+        chain: "governance.event_log".into(),
+        length: log.len() as u64,
+        head_hash: log.last().map(|e| e.self_hash.clone()),
+    });
+
+    if voters.is_some() {
+        export.queried(ExportCategory::GovernanceVoterRecord, voter.iter().len());
+    }
+    export.queried(ExportCategory::GovernanceBallots, ballots.len());
+    export.queried(ExportCategory::GovernanceAppeals, filed.len());
+    export.queried(ExportCategory::GovernanceAppealReviews, appeal_reviews.len());
+    export.queried(ExportCategory::GovernanceMatchOverrides, match_overrides.len());
+    GovernanceExport { voter, ballots, appeals: filed, appeal_reviews, match_overrides }
+}
@@ -0,0 +1,212 @@
+// path: steward-http/examples/attestation_lifecycle.rs
+
+//! Drives the router in-process (no socket) through the attestation lifecycle:
+//! denied without consent → consent → denied without a rollback plan →
+//! reserve id + register plan → issue (and a retried issue under the same
+//! idempotency key) → fetch → query → export → plan audit,
+//! plus the 409 / 422 / 403 / 413 error mappings.
+//!
+//! Run with `cargo run -p steward-http --example attestation_lifecycle`.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+use planetary_stewardship_runtime::{
+    ConsentEvidencePolicy, GovernanceProposal, GovernanceScope, ModuleId, NoopEvidenceVerifier, ProposalLimits, SaepConfig,
+};
+use steward_http::{router, AppState, StewardRuntime, TrustedHeaderIdentity};
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+async fn call(app: &Router, method: &str, uri: &str, body: Option<Value>, caller: Option<&str>) -> (StatusCode, String) {
+    let headers: Vec<(&str, &str)> = caller.map(|did| ("x-steward-did", did)).into_iter().collect();
+    send(app, method, uri, body, &headers).await
+}
+
+async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>, headers: &[(&str, &str)]) -> (StatusCode, String) {
+    let mut request = Request::builder().method(method).uri(uri);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let request = match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .expect("request");
+    let response = app.clone().oneshot(request).await.expect("infallible router");
+    let status = response.status();
+    let bytes = response.into_body().collect().await.expect("body").to_bytes();
+    (status, String::from_utf8(bytes.to_vec()).expect("utf-8 body"))
+}
+
+fn json_of(text: &str) -> Value {
+    serde_json::from_str(text).expect("JSON body")
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let runtime = StewardRuntime::with_runtime(
+        SaepConfig::default(),
+        Arc::new(FixedClock::new(1_700_000_000_000)),
+        Arc::new(SequentialIdGenerator::new(7)),
+    )
+    .with_consent_evidence(
+        ConsentEvidencePolicy { allowed_schemes: ["https".into(), "ipfs".into()].into(), ..Default::default() },
+        Arc::new(NoopEvidenceVerifier),
+    );
+    let app = router(AppState::with_identity(runtime, Arc::new(TrustedHeaderIdentity::default())));
+    let alice = "did:psv:steward:alice";
+    let issue = json!({
+        "actor_did": alice,
+        "description": "Cleared invasive ivy from 120 m² of riverbank",
+        "impact_metrics": {
+            "co2eq_reduced": 0.4,
+            "biodiversity_index_delta": 0.02,
+            "restored_area_m2": 120.0,
+            "avoided_emissions_co2eq": 0.0
+        },
+        "evidence_uri": "ipfs://riverbank-photos"
+    });
+
+    // KSCP: no consent yet.
+    let (status, body) = call(&app, "POST", "/v1/attestations", Some(issue.clone()), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+    assert_eq!(json_of(&body)["error"], "ethics_denied");
+
+    // Someone else may not grant consent on alice's behalf.
+    let consent = json!({
+        "participant": alice, "module": "PLGA", "mission": null,
+        "consent_given": true, "timestamp_ms": 1_700_000_000_000u64, "evidence_uri": null
+    });
+    let (status, _) = call(&app, "POST", "/v1/consents", Some(consent.clone()), Some("did:psv:steward:mallory")).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // Evidence the service's policy refuses is malformed input, not an ethics denial.
+    let mut ftp = consent.clone();
+    ftp["evidence_uri"] = json!("ftp://consent-forms/alice.pdf");
+    let (status, body) = call(&app, "POST", "/v1/consents", Some(ftp), Some(alice)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    assert_eq!(json_of(&body)["error"], "validation");
+    let (status, _) = call(&app, "POST", "/v1/consents", Some(consent), Some(alice)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // SAEP reversibility: consent alone is not enough.
+    let (status, body) = call(&app, "POST", "/v1/attestations", Some(issue.clone()), Some(alice)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+
+    // Reserve the id, then register a plan that can revoke it.
+    let (status, body) = call(&app, "POST", "/v1/attestations/reservations", None, None).await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let reserved = json_of(&body)["attestation_id"].clone();
+    let plan = json!({
+        "id": "plan:riverbank-ivy",
+        "action_ref": { "Attestation": reserved },
+        "steps": ["Revoke the attestation", "Notify the verifiers"],
+        "responsible": alice
+    });
+    let (status, body) = call(&app, "POST", "/v1/rollback-plans", Some(plan.clone()), Some(alice)).await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let (status, _) = call(&app, "POST", "/v1/rollback-plans", Some(plan), Some(alice)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let ghost = json!({
+        "id": "plan:ghost", "action_ref": { "Attestation": "never-reserved" },
+        "steps": ["Nothing to undo"], "responsible": alice
+    });
+    let (status, body) = call(&app, "POST", "/v1/rollback-plans", Some(ghost), Some(alice)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+
+    let mut issue = issue;
+    issue["rollback_plan_id"] = "plan:riverbank-ivy".into();
+    let keyed = [("x-steward-did", alice), ("idempotency-key", "ivy-upload-1")];
+    let (status, body) = send(&app, "POST", "/v1/attestations", Some(issue.clone()), &keyed).await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let attestation = json_of(&body);
+
+    // The app retries after a lost response: same record back, the spent plan is not re-checked.
+    let (status, replayed) = send(&app, "POST", "/v1/attestations", Some(issue.clone()), &keyed).await;
+    assert_eq!(status, StatusCode::CREATED, "{replayed}");
+    assert_eq!(json_of(&replayed), attestation);
+    let mut changed = issue;
+    changed["description"] = "Cleared invasive ivy from 200 m² of riverbank".into();
+    let (status, body) = send(&app, "POST", "/v1/attestations", Some(changed), &keyed).await;
+    assert_eq!(status, StatusCode::CONFLICT, "{body}");
+    assert_eq!(json_of(&body)["error"], "duplicate");
+    assert_eq!(attestation["timestamp_ms"], 1_700_000_000_000u64);
+    assert_eq!(attestation["id"], reserved);
+    let id = attestation["id"].as_str().expect("string id").to_string();
+    println!("issued {id}");
+
+    let (status, body) = call(&app, "GET", &format!("/v1/attestations/{id}"), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body), attestation);
+
+    let (status, body) = call(&app, "GET", &format!("/v1/attestations?actor={alice}"), None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body).as_array().map(Vec::len), Some(1));
+
+    let (status, body) = call(&app, "GET", "/v1/attestations/export", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.lines().count(), 1);
+
+    let (status, _) = call(&app, "GET", "/v1/attestations/unknown", None, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Audit: the plan is listed until it is invoked, and can be invoked once.
+    let (status, body) = call(&app, "GET", "/v1/rollback-plans?never_invoked_before=1700000000001", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_of(&body).as_array().map(Vec::len), Some(1));
+    let invocation = json!({ "invoked_by": alice, "outcome_note": "Photos were of the wrong riverbank" });
+    let uri = "/v1/rollback-plans/plan:riverbank-ivy/invocations";
+    let (status, body) = call(&app, "POST", uri, Some(invocation.clone()), Some(alice)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let (status, _) = call(&app, "POST", uri, Some(invocation), Some(alice)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (_, body) = call(&app, "GET", "/v1/rollback-plans?never_invoked_before=1700000000001", None, None).await;
+    assert_eq!(json_of(&body).as_array().map(Vec::len), Some(0));
+
+    // Validation and duplicates.
+    let (status, body) = call(&app, "GET", "/v1/attestations?actor=not-a-did", None, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    let template = json!({
+        "id": "riverbank-ivy", "title": "Riverbank ivy", "description": "Pull ivy",
+        "difficulty": "S", "expected_impact": {}, "location_hint": "geo", "required_skills": []
+    });
+    let (status, _) = call(&app, "POST", "/v1/missions/templates", Some(template.clone()), None).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, body) = call(&app, "POST", "/v1/missions/templates", Some(template), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(json_of(&body)["error"], "duplicate");
+
+    // Proposal payloads: one byte over the default limit is 413, right at it is not.
+    // `{"note":"…"}` is 11 bytes plus the note.
+    let max_bytes = ProposalLimits::default().max_payload_bytes;
+    let preview = |note_len: usize| {
+        let proposal = GovernanceProposal {
+            proposal_id: "prop-map".into(),
+            scope: GovernanceScope::Module(ModuleId("PLGA".into())),
+            title: "Publish quarterly restoration map".into(),
+            description: "Open data release for the watershed".into(),
+            payload: json!({ "note": "x".repeat(note_len) }),
+            can_introduce_restrictions: false,
+        };
+        json!({ "proposal": proposal })
+    };
+    let (status, body) = call(&app, "POST", "/v1/governance/preview", Some(preview(max_bytes - 11)), None).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let (status, body) = call(&app, "POST", "/v1/governance/preview", Some(preview(max_bytes - 10)), None).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE, "{body}");
+    assert_eq!(json_of(&body)["error"], "payload_too_large");
+    assert_eq!(
+        json_of(&body)["message"],
+        format!("Proposal payload_bytes is {}, over the limit of {max_bytes}", max_bytes + 1)
+    );
+
+    println!("attestation lifecycle ok");
+}
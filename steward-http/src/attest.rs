@@ -0,0 +1,161 @@
+// path: steward-http/src/attest.rs
+
+//! PLGA attestation endpoints.
+
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use planetary_stewardship_runtime::{
+    AttestationId, AttestationRequest, Did, EvidenceArtifact, ImpactMetrics, IntentEntryId, MissionId, ModuleMetrics,
+    PlanId, SaepRefs, StewardModule, StewardshipAttestation, WitnessEndorsement,
+};
+
+use crate::{ApiError, ApiJson, ApiQuery, AppState, Caller};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueAttestationRequest {
+    pub actor_did: Did,
+    #[serde(default)]
+    pub mission_id: Option<MissionId>,
+    pub description: String,
+    pub impact_metrics: ImpactMetrics,
+    /// Artifact list, or a single `evidence_uri` string.
+    #[serde(alias = "evidence_uri", deserialize_with = "planetary_stewardship_runtime::evidence::bundle_or_uri")]
+    pub evidence: Vec<EvidenceArtifact>,
+    #[serde(default)]
+    pub verifier_dids: Vec<Did>,
+    /// Defaults to the runtime clock.
+    #[serde(default)]
+    pub timestamp_ms: Option<u64>,
+    /// Intent the actor already published; otherwise one is published when SAEP requires it.
+    #[serde(default)]
+    pub intent_entry_id: Option<IntentEntryId>,
+    /// Plan registered against a reserved attestation id; SAEP's reversibility rule requires one.
+    #[serde(default)]
+    pub rollback_plan_id: Option<PlanId>,
+    /// Co-signature for a claim above the ledger's impact thresholds, over the statement hash
+    /// the ledger's dry run names.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub witness_endorsement: Option<WitnessEndorsement>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AttestationQuery {
+    pub actor: Option<Did>,
+    pub mission: Option<MissionId>,
+}
+
+/// `POST /v1/attestations`: 201 with the attestation, 422 for an unknown intent or plan
+/// or evidence the ledger's policy does not accept for the claim,
+/// 403 on SAEP/KSCP denial, a missing or invalid witness endorsement, or a plan that does not
+/// cover the attestation.
+/// A replayed `Idempotency-Key` returns the original attestation, or 409 for a different body.
+pub async fn issue(
+    State(state): State<AppState>,
+    caller: Caller,
+    headers: HeaderMap,
+    ApiJson(req): ApiJson<IssueAttestationRequest>,
+) -> Result<(StatusCode, Json<StewardshipAttestation>), ApiError> {
+    caller.authorize_for(&req.actor_did)?;
+    let idempotency_key = crate::idempotency_key(&headers, &req)?;
+    if req.description.trim().is_empty() {
+        return Err(ApiError::Validation("description must not be empty".into()));
+    }
+    if req.evidence.is_empty() || req.evidence.iter().any(|a| a.uri.trim().is_empty()) {
+        return Err(ApiError::Validation("evidence must list at least one artifact, each with a uri".into()));
+    }
+
+    let mut runtime = state.write()?;
+    if let Some(key) = &idempotency_key {
+        let replayed = runtime.ledger.idempotent_attestation(key).map_err(|e| ApiError::Duplicate(e.to_string()))?;
+        if let Some(original) = replayed {
+            return Ok((StatusCode::CREATED, Json(original.clone())));
+        }
+    }
+    runtime
+        .ledger
+        .evidence_policy()
+        .check(&req.impact_metrics, &req.evidence)
+        .map_err(ApiError::Validation)?;
+    if let Some(id) = &req.intent_entry_id {
+        crate::intent::require_published(&runtime, id)?;
+    }
+    if let Some(id) = &req.rollback_plan_id {
+        crate::rollback::require_registered(&runtime, id)?;
+    }
+    let timestamp_ms = req.timestamp_ms.unwrap_or_else(|| runtime.now_ms());
+    // Evidence passes and intent and plan (if any) exist, so the ledger only fails on witnessing,
+    // SAEP, KSCP, intent ownership or a plan that is missing, spent or aimed elsewhere. A failed attempt
+    // changes nothing, so the dry run names the same check, with its reason code.
+    let request = AttestationRequest {
+        actor_did: req.actor_did,
+        mission_id: req.mission_id,
+        description: req.description,
+        module: StewardModule::PLGA,
+        impact_metrics: req.impact_metrics,
+        module_metrics: ModuleMetrics::new(),
+        evidence: req.evidence,
+        verifier_dids: req.verifier_dids,
+        timestamp_ms,
+        refs: SaepRefs {
+            intent_entry_id: req.intent_entry_id,
+            rollback_plan_id: req.rollback_plan_id,
+        },
+        capture_window: None,
+        witness_endorsement: req.witness_endorsement,
+    };
+    match runtime.ledger.issue_attestation_idempotent(request.clone(), idempotency_key) {
+        Ok(attestation) => Ok((StatusCode::CREATED, Json(attestation))),
+        Err(message) => {
+            let report = runtime.ledger.validate_attestation(&request);
+            Err(ApiError::denied(message, report.issues.into_iter().find_map(|i| i.reason)))
+        }
+    }
+}
+
+/// Matching attestations ordered by timestamp, then id.
+fn matching(state: &AppState, q: &AttestationQuery) -> Result<Vec<StewardshipAttestation>, ApiError> {
+    let view = state.read()?.ledger.read_view();
+    let found = view
+        .attestations()
+        .filter(|a| q.actor.as_ref().is_none_or(|d| &a.actor_did == d))
+        .filter(|a| q.mission.as_ref().is_none_or(|m| a.mission_id.as_ref() == Some(m)))
+        .cloned()
+        .collect();
+    Ok(found)
+}
+
+/// `GET /v1/attestations`
+pub async fn query(
+    State(state): State<AppState>,
+    ApiQuery(q): ApiQuery<AttestationQuery>,
+) -> Result<Json<Vec<StewardshipAttestation>>, ApiError> {
+    matching(&state, &q).map(Json)
+}
+
+/// `GET /v1/attestations/export`: one attestation per line (NDJSON).
+pub async fn export(
+    State(state): State<AppState>,
+    ApiQuery(q): ApiQuery<AttestationQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let body: String = matching(&state, &q)?
+        .iter()
+        .map(|a| serde_json::to_string(a).expect("export serialization") + "\n")
+        .collect();
+    Ok(([(header::CONTENT_TYPE, "application/x-ndjson")], body))
+}
+
+/// `GET /v1/attestations/{id}`
+pub async fn get_one(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<StewardshipAttestation>, ApiError> {
+    let view = state.read()?.ledger.read_view();
+    view.get_attestation(&AttestationId(id.clone()))
+        .cloned()
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound(format!("No attestation {id}")))
+}
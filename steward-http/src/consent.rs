@@ -0,0 +1,56 @@
+// path: steward-http/src/consent.rs
+
+//! KSCP consent endpoints.
+
+use axum::extract::State;
+use axum::Json;
+use serde::Deserialize;
+
+use planetary_stewardship_runtime::{ConsentRecord, Did, Reason, ReasonCode, StewardModule};
+
+use crate::{ApiError, ApiJson, ApiQuery, AppState, Caller};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ConsentQuery {
+    pub participant: Option<Did>,
+    pub module: Option<StewardModule>,
+}
+
+/// `POST /v1/consents`: grant or withdraw (last write per key wins); a grant whose evidence
+/// fails the service's policy is malformed input (422), not an ethics denial.
+pub async fn upsert(
+    State(state): State<AppState>,
+    caller: Caller,
+    ApiJson(record): ApiJson<ConsentRecord>,
+) -> Result<Json<ConsentRecord>, ApiError> {
+    caller.authorize_for(&record.participant)?;
+    state.write()?.upsert_consent(record.clone()).map_err(evidence_error)?;
+    Ok(Json(record))
+}
+
+/// Evidence-policy refusals are validation errors; anything else stays a coded refusal.
+fn evidence_error(reason: Reason) -> ApiError {
+    match reason.code {
+        ReasonCode::ConsentEvidenceScheme
+        | ReasonCode::ConsentEvidenceHashMissing
+        | ReasonCode::ConsentEvidenceUnreadable
+        | ReasonCode::ConsentEvidenceHashMismatch => ApiError::Validation(reason.to_string()),
+        _ => reason.into(),
+    }
+}
+
+/// `GET /v1/consents`: current records, ordered by participant then timestamp.
+pub async fn query(
+    State(state): State<AppState>,
+    ApiQuery(q): ApiQuery<ConsentQuery>,
+) -> Result<Json<Vec<ConsentRecord>>, ApiError> {
+    let runtime = state.read()?;
+    let mut records: Vec<ConsentRecord> = runtime
+        .consents()
+        .filter(|r| q.participant.as_ref().is_none_or(|p| &r.participant == p))
+        .filter(|r| q.module.is_none_or(|m| r.module == m))
+        .cloned()
+        .collect();
+    records.sort_by(|a, b| (&a.participant.0, a.timestamp_ms).cmp(&(&b.participant.0, b.timestamp_ms)));
+    Ok(Json(records))
+}
@@ -0,0 +1,138 @@
+// path: steward-http/src/error.rs
+
+//! Typed API errors and their HTTP mapping.
+//! - Body is always `{"error": "<code>", "message": "<text>"}`, plus `"reason"` (a reason code
+//!   and its parameters) when the engine said why it refused
+//! - Extractor rejections (bad JSON, bad query) become `validation` (422)
+
+use axum::extract::rejection::{JsonRejection, QueryRejection};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use planetary_stewardship_runtime::Reason;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    /// The identity extractor rejected the caller (401).
+    Unauthorized(String),
+    /// The caller may not act for the requested participant (403).
+    Forbidden(String),
+    /// SAEP, KSCP or the co-stewardship charter refused the action (403).
+    EthicsDenied(String),
+    /// `EthicsDenied` with the engine's coded reason.
+    Refused { message: String, reason: Reason },
+    NotFound(String),
+    /// An entity with the same id already exists (409).
+    Duplicate(String),
+    /// Well-formed request with unusable content (422).
+    Validation(String),
+    /// A proposal over the engine's size limits (413).
+    PayloadTooLarge(String),
+    Internal(String),
+}
+
+/// Wire form of an `ApiError`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorBody {
+    pub error: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<Reason>,
+}
+
+impl ApiError {
+    /// A refusal with `message`, coded when the engine's `reason` is known.
+    pub fn denied(message: String, reason: Option<Reason>) -> Self {
+        match reason {
+            Some(reason) => ApiError::Refused { message, reason },
+            None => ApiError::EthicsDenied(message),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            ApiError::Forbidden(_) | ApiError::EthicsDenied(_) | ApiError::Refused { .. } => StatusCode::FORBIDDEN,
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Duplicate(_) => StatusCode::CONFLICT,
+            ApiError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Stable machine-readable code for the `error` field.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::Unauthorized(_) => "unauthorized",
+            ApiError::Forbidden(_) => "forbidden",
+            ApiError::EthicsDenied(_) | ApiError::Refused { .. } => "ethics_denied",
+            ApiError::NotFound(_) => "not_found",
+            ApiError::Duplicate(_) => "duplicate",
+            ApiError::Validation(_) => "validation",
+            ApiError::PayloadTooLarge(_) => "payload_too_large",
+            ApiError::Internal(_) => "internal",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ApiError::Unauthorized(m)
+            | ApiError::Forbidden(m)
+            | ApiError::EthicsDenied(m)
+            | ApiError::NotFound(m)
+            | ApiError::Duplicate(m)
+            | ApiError::Validation(m)
+            | ApiError::PayloadTooLarge(m)
+            | ApiError::Internal(m)
+            | ApiError::Refused { message: m, .. } => m,
+        }
+    }
+
+    pub fn reason(&self) -> Option<&Reason> {
+        match self {
+            ApiError::Refused { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code(), self.message())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorBody {
+            error: self.code().into(),
+            message: self.message().into(),
+            reason: self.reason().cloned(),
+        };
+        (self.status(), Json(body)).into_response()
+    }
+}
+
+impl From<Reason> for ApiError {
+    fn from(reason: Reason) -> Self {
+        ApiError::Refused { message: reason.to_string(), reason }
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        ApiError::Validation(rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        ApiError::Validation(rejection.body_text())
+    }
+}
@@ -0,0 +1,79 @@
+// path: steward-http/src/gov.rs
+
+//! Governance preview and quadratic tally endpoints.
+//! - A failed vote is a normal 200 (`applicable: false`); SAEP or charter vetoes are 403
+//! - Payloads over the engine's `ProposalLimits` are 413, checked before anything else
+//! - Vetoes carry the engine's reason code
+
+use axum::extract::State;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use planetary_stewardship_runtime::{GovernanceProposal, QuadraticOutcome, QuadraticVote};
+
+use crate::{ApiError, ApiJson, AppState, StewardRuntime};
+
+fn check_limits(runtime: &StewardRuntime, proposal: &GovernanceProposal) -> Result<(), ApiError> {
+    runtime
+        .governance
+        .check_proposal_limits(proposal)
+        .map_err(|e| ApiError::PayloadTooLarge(e.to_string()))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewRequest {
+    pub proposal: GovernanceProposal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TallyRequest {
+    pub proposal: GovernanceProposal,
+    pub votes: Vec<QuadraticVote>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceDecision {
+    pub proposal_id: String,
+    /// `None` for previews.
+    pub outcome: Option<QuadraticOutcome>,
+    pub applicable: bool,
+}
+
+/// `POST /v1/governance/preview`: would SAEP and the charter allow it if the vote passed?
+pub async fn preview(
+    State(state): State<AppState>,
+    ApiJson(req): ApiJson<PreviewRequest>,
+) -> Result<Json<GovernanceDecision>, ApiError> {
+    let assumed = QuadraticOutcome {
+        proposal_id: req.proposal.proposal_id.clone(),
+        total_support: 1.0,
+        total_opposition: 0.0,
+    };
+    let runtime = state.read()?;
+    check_limits(&runtime, &req.proposal)?;
+    let applicable = runtime.governance.check_proposal(&req.proposal, &assumed)?;
+    Ok(Json(GovernanceDecision {
+        proposal_id: req.proposal.proposal_id,
+        outcome: None,
+        applicable,
+    }))
+}
+
+/// `POST /v1/governance/tally`
+pub async fn tally(
+    State(state): State<AppState>,
+    ApiJson(req): ApiJson<TallyRequest>,
+) -> Result<Json<GovernanceDecision>, ApiError> {
+    let runtime = state.read()?;
+    check_limits(&runtime, &req.proposal)?;
+    if req.votes.iter().any(|v| !v.effective_weight.is_finite() || v.effective_weight < 0.0) {
+        return Err(ApiError::Validation("vote weights must be finite and non-negative".into()));
+    }
+    let outcome = runtime.governance.tally_quadratic(&req.proposal.proposal_id, &req.votes);
+    let applicable = runtime.governance.check_proposal(&req.proposal, &outcome)?;
+    Ok(Json(GovernanceDecision {
+        proposal_id: req.proposal.proposal_id,
+        outcome: Some(outcome),
+        applicable,
+    }))
+}
@@ -0,0 +1,83 @@
+// path: steward-http/src/identity.rs
+
+//! Pluggable caller identity.
+//! - Authentication itself is the deployment's job (gateway, mTLS, tokens)
+//! - `RequestIdentity` only turns an authenticated request into a `Did`
+//! - When a caller is known, handlers only let them act for themselves
+
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::HeaderName;
+
+use steward_ids::Did;
+
+use crate::error::ApiError;
+use crate::AppState;
+
+pub trait RequestIdentity: Send + Sync + 'static {
+    /// `Ok(None)` for anonymous callers; `Err` rejects the request.
+    fn identify(&self, parts: &Parts) -> Result<Option<Did>, ApiError>;
+}
+
+/// Every request is anonymous; no per-participant restriction applies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnonymousIdentity;
+
+impl RequestIdentity for AnonymousIdentity {
+    fn identify(&self, _parts: &Parts) -> Result<Option<Did>, ApiError> {
+        Ok(None)
+    }
+}
+
+/// Reads the caller DID from a header set by a trusted, authenticating proxy.
+/// Never expose this directly to clients.
+#[derive(Debug, Clone)]
+pub struct TrustedHeaderIdentity {
+    pub header: HeaderName,
+}
+
+impl Default for TrustedHeaderIdentity {
+    fn default() -> Self {
+        Self {
+            header: HeaderName::from_static("x-steward-did"),
+        }
+    }
+}
+
+impl RequestIdentity for TrustedHeaderIdentity {
+    fn identify(&self, parts: &Parts) -> Result<Option<Did>, ApiError> {
+        let Some(value) = parts.headers.get(&self.header) else {
+            return Ok(None);
+        };
+        let raw = value
+            .to_str()
+            .map_err(|_| ApiError::Unauthorized(format!("{} is not valid UTF-8", self.header)))?;
+        raw.parse::<Did>()
+            .map(Some)
+            .map_err(|e| ApiError::Unauthorized(format!("{}: {e}", self.header)))
+    }
+}
+
+/// The identified caller, if any.
+#[derive(Debug, Clone)]
+pub struct Caller(pub Option<Did>);
+
+impl Caller {
+    /// Identified callers may only act for themselves.
+    pub fn authorize_for(&self, participant: &Did) -> Result<(), ApiError> {
+        match &self.0 {
+            Some(caller) if caller != participant => Err(ApiError::Forbidden(format!(
+                "{caller} may not act for {participant}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FromRequestParts<AppState> for Caller {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        state.identity.identify(parts).map(Caller)
+    }
+}
@@ -0,0 +1,51 @@
+// path: steward-http/src/intent.rs
+
+//! Public intent log endpoint for dashboards.
+
+use axum::extract::State;
+use axum::Json;
+use serde::Deserialize;
+
+use planetary_stewardship_runtime::{Did, IntentEntryId, IntentLogEntry};
+
+use crate::{ApiError, ApiQuery, AppState, StewardRuntime};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct IntentQuery {
+    pub actor: Option<Did>,
+    /// Inclusive; defaults to the beginning of the log.
+    #[serde(default)]
+    pub from_ms: u64,
+    /// Exclusive; defaults to no upper bound.
+    pub to_ms: Option<u64>,
+}
+
+/// 422 unless `id` is in the runtime's intent log.
+pub(crate) fn require_published(runtime: &StewardRuntime, id: &IntentEntryId) -> Result<(), ApiError> {
+    let log = runtime
+        .intent_log()
+        .lock()
+        .map_err(|_| ApiError::Internal("intent log lock poisoned".into()))?;
+    match log.get(id) {
+        Some(_) => Ok(()),
+        None => Err(ApiError::Validation(format!("Unknown intent entry: {id}"))),
+    }
+}
+
+/// `GET /v1/intents`, oldest first.
+pub async fn query(
+    State(state): State<AppState>,
+    ApiQuery(q): ApiQuery<IntentQuery>,
+) -> Result<Json<Vec<IntentLogEntry>>, ApiError> {
+    let runtime = state.read()?;
+    let log = runtime
+        .intent_log()
+        .lock()
+        .map_err(|_| ApiError::Internal("intent log lock poisoned".into()))?;
+    let entries = log
+        .query(q.actor.as_ref(), q.from_ms, q.to_ms.unwrap_or(u64::MAX))
+        .into_iter()
+        .cloned()
+        .collect();
+    Ok(Json(entries))
+}
@@ -0,0 +1,142 @@
+// path: steward-http/src/lib.rs
+
+//! `steward-http`: REST front-end for the stewardship runtime.
+//! - One shared `StewardRuntime` behind `Arc<RwLock<..>>`; handlers never hold the lock across an await
+//! - Attestation and mission listings read a `read_view`, so the read lock is held only to take it
+//! - JSON in, JSON out; errors are `ApiError` bodies with stable codes
+//! - Caller identity is pluggable (`RequestIdentity`); authentication is not done here
+//! - `serve` (feature `server`) binds the router with axum's hyper server
+//!
+//! Routes (all under `/v1`):
+//! - `POST /consents`, `GET /consents?participant=`
+//! - `POST /attestations`, `GET /attestations?actor=&mission=`,
+//!   `GET /attestations/export` (NDJSON), `GET /attestations/{id}`,
+//!   `POST /attestations/reservations` (id for a rollback plan to target)
+//! - `POST /missions/templates`, `GET /missions/templates`,
+//!   `POST /missions/assignments`, `GET /missions/assignments?assignee=`
+//! - `POST /governance/preview`, `POST /governance/tally`
+//! - `GET /intents?actor=&from_ms=&to_ms=` (public intent log)
+//! - `POST /rollback-plans`, `GET /rollback-plans?never_invoked_before=`,
+//!   `POST /rollback-plans/{id}/invocations`
+//!
+//! `POST /attestations` and `POST /missions/assignments` honour an `Idempotency-Key`
+//! header: a retry with the same key and body returns the original record (201) without
+//! creating another; the same key with a different body is 409.
+//!
+//! Status codes: 401 identity rejected, 403 ethics/consent/charter denial or
+//! acting for someone else, 404 unknown id, 409 duplicate or reused idempotency key,
+//! 413 proposal over the governance engine's size limits, 422 invalid input.
+
+use axum::extract::{FromRequest, FromRequestParts};
+use axum::http::HeaderMap;
+use axum::routing::{get, post};
+use axum::Router;
+use serde::Serialize;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use planetary_stewardship_runtime::IdempotencyKey;
+
+pub mod attest;
+pub mod consent;
+pub mod error;
+pub mod gov;
+pub mod identity;
+pub mod intent;
+pub mod mission;
+pub mod rollback;
+pub mod runtime;
+pub use error::{ApiError, ErrorBody};
+pub use identity::{AnonymousIdentity, Caller, RequestIdentity, TrustedHeaderIdentity};
+pub use runtime::StewardRuntime;
+
+// ---------------------------------------------------------------------
+// STATE
+// ---------------------------------------------------------------------
+
+#[derive(Clone)]
+pub struct AppState {
+    pub runtime: Arc<RwLock<StewardRuntime>>,
+    pub identity: Arc<dyn RequestIdentity>,
+}
+
+impl AppState {
+    /// Anonymous access; swap `identity` for deployments that authenticate.
+    pub fn new(runtime: StewardRuntime) -> Self {
+        Self::with_identity(runtime, Arc::new(AnonymousIdentity))
+    }
+
+    pub fn with_identity(runtime: StewardRuntime, identity: Arc<dyn RequestIdentity>) -> Self {
+        Self {
+            runtime: Arc::new(RwLock::new(runtime)),
+            identity,
+        }
+    }
+
+    pub fn read(&self) -> Result<RwLockReadGuard<'_, StewardRuntime>, ApiError> {
+        self.runtime
+            .read()
+            .map_err(|_| ApiError::Internal("runtime lock poisoned".into()))
+    }
+
+    pub fn write(&self) -> Result<RwLockWriteGuard<'_, StewardRuntime>, ApiError> {
+        self.runtime
+            .write()
+            .map_err(|_| ApiError::Internal("runtime lock poisoned".into()))
+    }
+}
+
+// ---------------------------------------------------------------------
+// EXTRACTORS
+// ---------------------------------------------------------------------
+
+/// `axum::Json` with rejections mapped to `ApiError::Validation`.
+#[derive(Debug, FromRequest)]
+#[from_request(via(axum::Json), rejection(ApiError))]
+pub struct ApiJson<T>(pub T);
+
+/// `axum::extract::Query` with rejections mapped to `ApiError::Validation`.
+#[derive(Debug, FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(ApiError))]
+pub struct ApiQuery<T>(pub T);
+
+/// Header a client repeats, unchanged, on every retry of one submission.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// The request's idempotency key, bound to `request` as the client sent it (before
+/// defaults such as the timestamp are filled in).
+pub(crate) fn idempotency_key(headers: &HeaderMap, request: &impl Serialize) -> Result<Option<IdempotencyKey>, ApiError> {
+    let Some(value) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    match value.to_str().map(str::trim) {
+        Ok(key) if !key.is_empty() && key.len() <= 255 => Ok(Some(IdempotencyKey::new(key, request))),
+        _ => Err(ApiError::Validation("Idempotency-Key must be 1 to 255 visible ASCII characters".into())),
+    }
+}
+
+// ---------------------------------------------------------------------
+// ROUTER
+// ---------------------------------------------------------------------
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/v1/consents", post(consent::upsert).get(consent::query))
+        .route("/v1/attestations", post(attest::issue).get(attest::query))
+        .route("/v1/attestations/export", get(attest::export))
+        .route("/v1/attestations/reservations", post(rollback::reserve))
+        .route("/v1/attestations/{id}", get(attest::get_one))
+        .route("/v1/missions/templates", post(mission::add_template).get(mission::list_templates))
+        .route("/v1/missions/assignments", post(mission::assign).get(mission::list_assignments))
+        .route("/v1/governance/preview", post(gov::preview))
+        .route("/v1/governance/tally", post(gov::tally))
+        .route("/v1/intents", get(intent::query))
+        .route("/v1/rollback-plans", post(rollback::register).get(rollback::query))
+        .route("/v1/rollback-plans/{id}/invocations", post(rollback::invoke))
+        .with_state(state)
+}
+
+/// Serve `router(state)` on an already-bound listener until the process stops.
+#[cfg(feature = "server")]
+pub async fn serve(listener: tokio::net::TcpListener, state: AppState) -> std::io::Result<()> {
+    axum::serve(listener, router(state)).await
+}
@@ -0,0 +1,123 @@
+// path: steward-http/src/mission.rs
+
+//! MME template and assignment endpoints.
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use planetary_stewardship_runtime::{
+    AssignedMission, Did, IntentEntryId, MissionId, MissionTemplate, PlanId, SaepRefs,
+};
+
+use crate::{ApiError, ApiJson, ApiQuery, AppState, Caller};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignMissionRequest {
+    pub mission_id: MissionId,
+    pub assignee: Did,
+    /// Defaults to the runtime clock.
+    #[serde(default)]
+    pub now_ms: Option<u64>,
+    /// Intent the assignee already published; otherwise one is published when SAEP requires it.
+    #[serde(default)]
+    pub intent_entry_id: Option<IntentEntryId>,
+    /// Plan registered against this mission and assignee; SAEP's reversibility rule requires one.
+    #[serde(default)]
+    pub rollback_plan_id: Option<PlanId>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AssignmentQuery {
+    pub assignee: Option<Did>,
+}
+
+/// `POST /v1/missions/templates`: 201, 422 for incomplete localized content, or 409 if the id is taken.
+pub async fn add_template(
+    State(state): State<AppState>,
+    ApiJson(template): ApiJson<MissionTemplate>,
+) -> Result<(StatusCode, Json<MissionTemplate>), ApiError> {
+    if template.title.trim().is_empty() {
+        return Err(ApiError::Validation("title must not be empty".into()));
+    }
+    template.validate().map_err(ApiError::Validation)?;
+    let mut runtime = state.write()?;
+    if runtime.missions.get_template(&template.id).is_some() {
+        return Err(ApiError::Duplicate(format!("Mission template {} already exists", template.id)));
+    }
+    runtime.missions.add_template(template.clone());
+    Ok((StatusCode::CREATED, Json(template)))
+}
+
+/// `GET /v1/missions/templates`, ordered by id.
+pub async fn list_templates(State(state): State<AppState>) -> Result<Json<Vec<MissionTemplate>>, ApiError> {
+    let view = state.read()?.missions.read_view();
+    let mut templates: Vec<MissionTemplate> = view.templates().cloned().collect();
+    templates.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+    Ok(Json(templates))
+}
+
+/// `POST /v1/missions/assignments`: 201, 422 for an unknown template, intent or plan,
+/// 403 on SAEP/KSCP denial or a plan that does not cover the assignment.
+/// A replayed `Idempotency-Key` returns the original assignment, or 409 for a different body.
+pub async fn assign(
+    State(state): State<AppState>,
+    caller: Caller,
+    headers: HeaderMap,
+    ApiJson(req): ApiJson<AssignMissionRequest>,
+) -> Result<(StatusCode, Json<AssignedMission>), ApiError> {
+    caller.authorize_for(&req.assignee)?;
+    let idempotency_key = crate::idempotency_key(&headers, &req)?;
+    let mut runtime = state.write()?;
+    let now_ms = req.now_ms.unwrap_or_else(|| runtime.now_ms());
+    if let Some(key) = &idempotency_key {
+        let replayed = runtime
+            .missions
+            .idempotent_assignment(key, now_ms)
+            .map_err(|e| ApiError::Duplicate(e.to_string()))?;
+        if let Some(original) = replayed {
+            return Ok((StatusCode::CREATED, Json(original.clone())));
+        }
+    }
+    if runtime.missions.get_template(&req.mission_id).is_none() {
+        return Err(ApiError::Validation(format!("Unknown mission template: {}", req.mission_id)));
+    }
+    if let Some(id) = &req.intent_entry_id {
+        crate::intent::require_published(&runtime, id)?;
+    }
+    if let Some(id) = &req.rollback_plan_id {
+        crate::rollback::require_registered(&runtime, id)?;
+    }
+    // Template, intent and plan exist, so any remaining failure is a SAEP, KSCP,
+    // intent-ownership or plan-coverage denial.
+    let refs = SaepRefs {
+        intent_entry_id: req.intent_entry_id,
+        rollback_plan_id: req.rollback_plan_id,
+    };
+    // A failed attempt changes nothing, so the dry run names the same check, with its reason code.
+    match runtime
+        .missions
+        .assign_mission_idempotent(&req.mission_id, req.assignee.clone(), now_ms, refs.clone(), idempotency_key)
+    {
+        Ok(assigned) => Ok((StatusCode::CREATED, Json(assigned))),
+        Err(message) => {
+            let report = runtime.missions.validate_assignment_with_refs(&req.mission_id, &req.assignee, now_ms, &refs);
+            Err(ApiError::denied(message, report.issues.into_iter().find_map(|i| i.reason)))
+        }
+    }
+}
+
+/// `GET /v1/missions/assignments`, oldest first.
+pub async fn list_assignments(
+    State(state): State<AppState>,
+    ApiQuery(q): ApiQuery<AssignmentQuery>,
+) -> Result<Json<Vec<AssignedMission>>, ApiError> {
+    let view = state.read()?.missions.read_view();
+    let assignments = view
+        .active_assignments()
+        .filter(|a| q.assignee.as_ref().is_none_or(|d| &a.assignee == d))
+        .cloned()
+        .collect();
+    Ok(Json(assignments))
+}
@@ -0,0 +1,135 @@
+// path: steward-http/src/rollback.rs
+
+//! Rollback plan endpoints: reservations, registration, invocation and audit.
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use planetary_stewardship_runtime::{
+    ActionRef, ActionResolver, AttestationId, Did, PlanId, RegisteredPlan, RollbackPlan,
+};
+
+use crate::{ApiError, ApiJson, ApiQuery, AppState, Caller, StewardRuntime};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reservation {
+    pub attestation_id: AttestationId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterPlanRequest {
+    pub id: PlanId,
+    pub action_ref: ActionRef,
+    pub steps: Vec<String>,
+    pub responsible: Did,
+    /// Defaults to the runtime clock.
+    #[serde(default)]
+    pub created_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvokePlanRequest {
+    pub invoked_by: Did,
+    pub outcome_note: String,
+    /// Defaults to the runtime clock.
+    #[serde(default)]
+    pub timestamp_ms: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PlanQuery {
+    /// Audit view: only plans created before this timestamp and never invoked.
+    pub never_invoked_before: Option<u64>,
+}
+
+/// 422 unless `id` is a registered rollback plan.
+pub(crate) fn require_registered(runtime: &StewardRuntime, id: &PlanId) -> Result<(), ApiError> {
+    let registry = runtime
+        .rollback_plans()
+        .lock()
+        .map_err(|_| ApiError::Internal("rollback registry lock poisoned".into()))?;
+    match registry.get(id) {
+        Some(_) => Ok(()),
+        None => Err(ApiError::Validation(format!("Unknown rollback plan: {id}"))),
+    }
+}
+
+/// `POST /v1/attestations/reservations`: 201 with an id a plan can target before issuance.
+pub async fn reserve(State(state): State<AppState>) -> Result<(StatusCode, Json<Reservation>), ApiError> {
+    let mut runtime = state.write()?;
+    let attestation_id = runtime.ledger.reserve_attestation_id();
+    Ok((StatusCode::CREATED, Json(Reservation { attestation_id })))
+}
+
+/// `POST /v1/rollback-plans`: 201, 409 if the id is taken, 422 for no steps or an unknown action.
+pub async fn register(
+    State(state): State<AppState>,
+    caller: Caller,
+    ApiJson(req): ApiJson<RegisterPlanRequest>,
+) -> Result<(StatusCode, Json<RollbackPlan>), ApiError> {
+    caller.authorize_for(&req.responsible)?;
+    if req.steps.iter().all(|s| s.trim().is_empty()) {
+        return Err(ApiError::Validation("steps must not be empty".into()));
+    }
+    let runtime = state.read()?;
+    if !runtime.action_exists(&req.action_ref) {
+        return Err(ApiError::Validation(format!("Unknown {}", req.action_ref)));
+    }
+    let plan = RollbackPlan {
+        id: req.id,
+        action_ref: req.action_ref,
+        steps: req.steps,
+        responsible: req.responsible,
+        created_ms: req.created_ms.unwrap_or_else(|| runtime.now_ms()),
+    };
+    let mut registry = runtime
+        .rollback_plans()
+        .lock()
+        .map_err(|_| ApiError::Internal("rollback registry lock poisoned".into()))?;
+    // Steps and action are checked, so the registry can only refuse a duplicate id.
+    registry.register(plan.clone(), &*runtime).map_err(ApiError::Duplicate)?;
+    Ok((StatusCode::CREATED, Json(plan)))
+}
+
+/// `POST /v1/rollback-plans/{id}/invocations`: 200, 404 for an unknown plan, 409 if already invoked.
+pub async fn invoke(
+    State(state): State<AppState>,
+    caller: Caller,
+    Path(id): Path<String>,
+    ApiJson(req): ApiJson<InvokePlanRequest>,
+) -> Result<Json<RegisteredPlan>, ApiError> {
+    caller.authorize_for(&req.invoked_by)?;
+    let runtime = state.read()?;
+    let timestamp_ms = req.timestamp_ms.unwrap_or_else(|| runtime.now_ms());
+    let mut registry = runtime
+        .rollback_plans()
+        .lock()
+        .map_err(|_| ApiError::Internal("rollback registry lock poisoned".into()))?;
+    let id = PlanId(id);
+    if registry.get(&id).is_none() {
+        return Err(ApiError::NotFound(format!("No rollback plan {id}")));
+    }
+    registry
+        .mark_invoked(&id, req.invoked_by, timestamp_ms, req.outcome_note)
+        .map_err(ApiError::Duplicate)?;
+    Ok(Json(registry.get(&id).cloned().expect("plan just invoked")))
+}
+
+/// `GET /v1/rollback-plans`, in registration order.
+pub async fn query(
+    State(state): State<AppState>,
+    ApiQuery(q): ApiQuery<PlanQuery>,
+) -> Result<Json<Vec<RegisteredPlan>>, ApiError> {
+    let runtime = state.read()?;
+    let registry = runtime
+        .rollback_plans()
+        .lock()
+        .map_err(|_| ApiError::Internal("rollback registry lock poisoned".into()))?;
+    let plans = match q.never_invoked_before {
+        Some(cutoff) => registry.plans_never_invoked(cutoff),
+        None => registry.plans(),
+    };
+    Ok(Json(plans.into_iter().cloned().collect()))
+}
@@ -0,0 +1,100 @@
+// path: steward-http/src/runtime.rs
+
+//! The engines one service instance serves from.
+//! - PLGA, MME and governance each keep their own `ConsentRegistry`;
+//!   `upsert_consent` is the only write path and updates all of them, once the
+//!   service's consent evidence policy accepts the record
+//! - PLGA and MME publish into one shared public intent log and check
+//!   one shared rollback plan registry
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::{
+    ActionRef, ActionResolver, ConsentEvidencePolicy, ConsentRecord, ConsentRegistry, EvidenceVerifier,
+    GovernanceEngine, IntentLog, MicroMissionsEngine, PlanetaryLedger, Reason, RollbackPlanRegistry, SaepConfig,
+    SaepEngine, SharedIntentLog, SharedRollbackRegistry,
+};
+use steward_runtime_support::{default_id_generator, system_clock, Clock, IdGenerator};
+
+pub struct StewardRuntime {
+    pub ledger: PlanetaryLedger,
+    pub missions: MicroMissionsEngine,
+    pub governance: GovernanceEngine,
+    consents: ConsentRegistry,
+    intent_log: SharedIntentLog,
+    rollback_plans: SharedRollbackRegistry,
+    clock: Arc<dyn Clock>,
+}
+
+impl StewardRuntime {
+    pub fn new(config: SaepConfig) -> Self {
+        Self::with_runtime(config, system_clock(), default_id_generator())
+    }
+
+    /// Runtime with an injected clock and id source (replay, examples).
+    pub fn with_runtime(config: SaepConfig, clock: Arc<dyn Clock>, ids: Arc<dyn IdGenerator>) -> Self {
+        let intent_log = IntentLog::shared();
+        let rollback_plans = RollbackPlanRegistry::shared();
+        Self {
+            ledger: PlanetaryLedger::with_runtime(
+                SaepEngine::new(config.clone()),
+                ConsentRegistry::new(),
+                clock.clone(),
+                ids,
+            )
+            .with_intent_log(intent_log.clone())
+            .with_rollback_registry(rollback_plans.clone()),
+            missions: MicroMissionsEngine::new(SaepEngine::new(config.clone()), ConsentRegistry::new())
+                .with_intent_log(intent_log.clone())
+                .with_rollback_registry(rollback_plans.clone()),
+            governance: GovernanceEngine::new(SaepEngine::new(config)),
+            consents: ConsentRegistry::new(),
+            intent_log,
+            rollback_plans,
+            clock,
+        }
+    }
+
+    /// Check consent grants against `policy`, comparing hashes with what `verifier` fetches.
+    pub fn with_consent_evidence(mut self, policy: ConsentEvidencePolicy, verifier: Arc<dyn EvidenceVerifier>) -> Self {
+        self.consents = std::mem::replace(&mut self.consents, ConsentRegistry::new())
+            .with_evidence_policy(policy)
+            .with_evidence_verifier(verifier);
+        self
+    }
+
+    pub fn upsert_consent(&mut self, record: ConsentRecord) -> Result<(), Reason> {
+        self.consents.submit_consent(record.clone())?;
+        self.ledger.consent_mut().upsert_consent(record.clone());
+        self.missions.consent_mut().upsert_consent(record);
+        Ok(())
+    }
+
+    pub fn consents(&self) -> impl Iterator<Item = &ConsentRecord> {
+        self.consents.records()
+    }
+
+    pub fn intent_log(&self) -> &SharedIntentLog {
+        &self.intent_log
+    }
+
+    pub fn rollback_plans(&self) -> &SharedRollbackRegistry {
+        &self.rollback_plans
+    }
+
+    pub fn now_ms(&self) -> u64 {
+        self.clock.now_ms()
+    }
+}
+
+/// Attestations resolve against the ledger, assignments against MME; the
+/// service keeps no governance proposals.
+impl ActionResolver for StewardRuntime {
+    fn action_exists(&self, action: &ActionRef) -> bool {
+        match action {
+            ActionRef::Attestation(_) => self.ledger.action_exists(action),
+            ActionRef::Assignment { .. } => self.missions.action_exists(action),
+            ActionRef::GovernanceProposal(_) => false,
+        }
+    }
+}
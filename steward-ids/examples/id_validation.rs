@@ -0,0 +1,74 @@
+// path: steward-ids/examples/id_validation.rs
+
+//! Example: malformed ids stop at the parse boundary.
+//! - DIDs without a method or id, with an uppercase method, a trailing `:`, a space or a bad
+//!   `%` escape are refused, by `parse` and by serde alike
+//! - Capabilities must be non-empty and namespaced, lowercase; language tags need a 2-8
+//!   letter language and well-formed subtags
+//! - A pre-DID id reads back through `Did::deserialize_compat` as `did:legacy:<id>`, escaped;
+//!   a string claiming to be a DID still has to be one
+
+use serde::Deserialize;
+use steward_ids::{CapabilityId, Did, IdError, LanguageTag};
+
+#[derive(Deserialize)]
+struct Stored {
+    #[serde(deserialize_with = "Did::deserialize_compat")]
+    agent: Did,
+}
+
+fn stored(agent: &str) -> Result<Did, String> {
+    let json = serde_json::json!({ "agent": agent });
+    serde_json::from_value::<Stored>(json).map(|s| s.agent).map_err(|e| e.to_string())
+}
+
+fn main() {
+    // 1. DIDs.
+    let bad_dids = [
+        ("alice", IdError::MalformedDid("alice".into())),
+        ("did:psv", IdError::MalformedDid("did:psv".into())),
+        ("did::alice", IdError::MalformedDid("did::alice".into())),
+        ("did:psv:alice:", IdError::MalformedDid("did:psv:alice:".into())),
+        ("did:PSV:alice", IdError::InvalidDidMethod("did:PSV:alice".into())),
+        ("did:psv:ali ce", IdError::InvalidDidChar { did: "did:psv:ali ce".into(), ch: ' ' }),
+        ("did:psv:ali%zz", IdError::InvalidDidChar { did: "did:psv:ali%zz".into(), ch: '%' }),
+        ("", IdError::Empty { kind: "DID" }),
+    ];
+    for (input, expected) in bad_dids {
+        assert_eq!(input.parse::<Did>(), Err(expected.clone()), "{input:?}");
+        let err = serde_json::from_value::<Did>(input.into()).expect_err("serde validates too");
+        assert_eq!(err.to_string(), expected.to_string());
+    }
+    let long = format!("did:psv:{}", "a".repeat(300));
+    assert_eq!(Did::new(long), Err(IdError::TooLong { kind: "DID", max: 256 }));
+    assert!("did:psv:athlete:ana%20b".parse::<Did>().is_ok());
+
+    // 2. Capabilities and language tags.
+    let bad_capabilities = [
+        ("", IdError::Empty { kind: "capability" }),
+        ("move", IdError::MalformedCapability("move".into())),
+        ("move:", IdError::MalformedCapability("move:".into())),
+        (":bci_push", IdError::MalformedCapability(":bci_push".into())),
+        ("Move:bci_push", IdError::MalformedCapability("Move:bci_push".into())),
+        ("move:bci push", IdError::InvalidCapabilityChar { capability: "move:bci push".into(), ch: ' ' }),
+    ];
+    for (input, expected) in bad_capabilities {
+        assert_eq!(CapabilityId::new(input), Err(expected), "{input:?}");
+    }
+    for input in ["e", "español", "es_MX", "es-", "es--MX", "toolonglang", "es-toolongsub"] {
+        let expected = IdError::MalformedLanguageTag(input.into());
+        assert_eq!(input.parse::<LanguageTag>(), Err(expected), "{input:?}");
+    }
+    assert_eq!(LanguageTag::new("ES-mx").expect("valid").as_str(), "es-MX");
+    println!("refused: {}", "es_MX".parse::<LanguageTag>().expect_err("underscore"));
+
+    // 3. Pre-DID ids read back as legacy DIDs; broken DIDs do not.
+    let alice = stored("alice").expect("legacy id");
+    assert_eq!((alice.as_str(), alice.is_legacy()), ("did:legacy:alice", true));
+    assert_eq!(stored("org acme:7").expect("legacy id").as_str(), "did:legacy:org%20acme%3A7");
+    assert_eq!(stored("did:psv:athlete:ana"), Ok(Did("did:psv:athlete:ana".into())));
+    assert!(!stored("did:psv:athlete:ana").expect("a DID").is_legacy());
+    let err = stored("did:PSV:ana").expect_err("claims to be a DID");
+    assert!(err.contains(&IdError::InvalidDidMethod("did:PSV:ana".into()).to_string()), "{err}");
+    assert!(stored("").is_err());
+}
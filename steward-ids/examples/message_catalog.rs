@@ -0,0 +1,73 @@
+// path: steward-ids/examples/message_catalog.rs
+
+//! Example: rendering reason codes in English and in a registered Spanish catalog.
+//! - Every code renders in the default locale, never as the missing-catalog placeholder,
+//!   and with every placeholder filled
+//! - `es` covers a few codes; `es-MX` falls back to it, and codes it leaves out to English
+//! - A template naming a parameter its code does not carry is refused
+
+use std::collections::BTreeSet;
+
+use steward_ids::messages::{placeholder, Catalog, Messages, Reason, ReasonCode, DEFAULT_LOCALE};
+use steward_ids::LanguageTag;
+
+fn tag(s: &str) -> LanguageTag {
+    LanguageTag::new(s).expect("valid language tag")
+}
+
+fn main() {
+    let en = tag(DEFAULT_LOCALE);
+    let messages = Messages::new();
+
+    // 1. Every code has an English message using only its own parameters.
+    let names: BTreeSet<&str> = ReasonCode::ALL.iter().map(|c| c.as_str()).collect();
+    assert_eq!(names.len(), ReasonCode::ALL.len(), "reason codes are unique");
+    let mut english = Catalog::new();
+    for &code in ReasonCode::ALL {
+        let reason = code.params().iter().fold(Reason::new(code), |r, name| r.with(name, format!("<{name}>")));
+        let rendered = messages.render_reason(&reason, &en);
+        assert_ne!(rendered, placeholder(code), "{code} has no English message");
+        assert!(!rendered.contains('{'), "{code} leaves a placeholder unfilled: {rendered}");
+        assert_eq!(rendered, reason.to_string());
+        assert_eq!(serde_json::to_value(code).expect("code json"), code.as_str());
+        english.insert(code, messages.template(code, &en).expect("English template").to_string());
+    }
+    Messages::new().register_catalog(en.clone(), english).expect("English uses only declared parameters");
+
+    // 2. A partial Spanish catalog; `es-MX` finds it through the bare language.
+    let mut messages = Messages::new();
+    let spanish: Catalog = [
+        (ReasonCode::ElementOptInRequired, "La habilidad {capability} requiere tu consentimiento explícito."),
+        (ReasonCode::ConsentMissing, "{participant} no ha dado su consentimiento KSCP para {module}."),
+        (ReasonCode::SaepNonHarm, "Se detectó una posible intención dañina o coercitiva."),
+    ]
+    .into_iter()
+    .map(|(code, template)| (code, template.to_string()))
+    .collect();
+    messages.register_catalog(tag("es"), spanish).expect("valid Spanish catalog");
+
+    let opt_in = Reason::new(ReasonCode::ElementOptInRequired).with("capability", "bci:focus_assist");
+    assert_eq!(
+        messages.render_reason(&opt_in, &tag("es-MX")),
+        "La habilidad bci:focus_assist requiere tu consentimiento explícito."
+    );
+    assert_eq!(opt_in.to_string(), "Explicit opt-in required for this ability.");
+
+    // 3. Codes the catalog leaves out, and unregistered locales, fall back to English.
+    let floor = Reason::new(ReasonCode::CapabilityDomainFloor);
+    assert_eq!(messages.render_reason(&floor, &tag("es")), floor.to_string());
+    assert_eq!(messages.render_reason(&opt_in, &tag("fr")), opt_in.to_string());
+
+    // 4. A template using a parameter its code lacks is refused, and nothing is added.
+    let bad: Catalog = [(ReasonCode::ElementSelfBlocked, "{agent} bloqueó {capability}".to_string())].into();
+    messages.register_catalog(tag("es"), bad).expect_err("element.self_blocked carries no agent");
+    let blocked = Reason::new(ReasonCode::ElementSelfBlocked).with("capability", "bci:focus_assist");
+    assert_eq!(messages.render_reason(&blocked, &tag("es")), blocked.to_string());
+
+    // 5. On the wire a reason is its code and parameters.
+    assert_eq!(
+        serde_json::to_value(&opt_in).expect("reason json"),
+        serde_json::json!({ "code": "element.opt_in_required", "params": { "capability": "bci:focus_assist" } })
+    );
+    println!("{} reason codes render in {DEFAULT_LOCALE}; es covers 3", ReasonCode::ALL.len());
+}
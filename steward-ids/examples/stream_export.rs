@@ -0,0 +1,201 @@
+// path: steward-ids/examples/stream_export.rs
+
+//! Example: a million records exported and imported in constant memory
+//! (gzip-compressed when run with `--features flate2`).
+//! - A counting allocator tracks live heap bytes; neither direction grows the heap by more
+//!   than `CEILING`, however many records pass through
+//! - The import folds the records back into the totals they were generated from, and
+//!   arrives at the same totals
+//! - A file cut short, or a record altered after the export, fails the import instead of
+//!   quietly yielding fewer or other records
+//! - A ledger of a million attestations exports through `export_attestations` within `CEILING`
+//!   beyond its ordering index, and `restore_attestations` into an empty ledger yields the same
+//!   attestations, the same `impact_summary` and, exported again, the same chain head (the
+//!   ledger comes from `planetary_stewardship_runtime`, a dev-dependency here, with `test-util`)
+
+use serde::{Serialize, Deserialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use planetary_stewardship_runtime::builders::{did, AttestationBuilder, LedgerBuilder, FIXTURE_START_MS};
+use planetary_stewardship_runtime::{PlanetaryLedger, StewardshipAttestation};
+use steward_ids::stream::{write_stream, Compression, StreamError, StreamReader, StreamWriter};
+
+const RECORDS: u64 = 1_000_000;
+const ACTORS: u64 = 64;
+/// Heap growth allowed while streaming: buffers, the compressor's state and one record.
+const CEILING: usize = 1024 * 1024;
+const KIND: &str = "example.readings";
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let moved = System.realloc(ptr, layout, new_size);
+        if !moved.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            grew(new_size);
+        }
+        moved
+    }
+}
+
+fn grew(size: usize) {
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// `f`'s result and how far it took the heap above where it started.
+fn peak_growth<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let start = LIVE.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let result = f();
+    (result, PEAK.load(Ordering::Relaxed) - start)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Reading {
+    seq: u64,
+    actor: String,
+    module: String,
+    co2eq_g: u64,
+}
+
+fn reading(seq: u64) -> Reading {
+    Reading {
+        seq,
+        actor: format!("did:psv:steward:volunteer-{:02}", seq % ACTORS),
+        module: if seq.is_multiple_of(3) { "PLGA" } else { "MME" }.into(),
+        co2eq_g: seq * 7 % 1_000,
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Totals {
+    records: u64,
+    last_seq: u64,
+    co2eq_g_by_actor: BTreeMap<String, u64>,
+}
+
+impl Totals {
+    fn add(&mut self, reading: &Reading) {
+        self.records += 1;
+        self.last_seq = reading.seq;
+        *self.co2eq_g_by_actor.entry(reading.actor.clone()).or_default() += reading.co2eq_g;
+    }
+}
+
+fn import(path: &std::path::Path) -> Result<Totals, StreamError> {
+    let mut totals = Totals::default();
+    for reading in StreamReader::open(BufReader::new(File::open(path)?), KIND)?.records::<Reading>() {
+        totals.add(&reading?);
+    }
+    Ok(totals)
+}
+
+/// A ledger holding `reading(seq)` for every `seq` as a PLGA attestation of its actor.
+/// Issued straight from the request: with reversibility off no claim needs a rollback plan,
+/// and `issue_on` would register a million of them.
+fn ledger_of_readings() -> PlanetaryLedger {
+    let actors: Vec<_> = (0..ACTORS).map(|a| did(&format!("did:psv:steward:volunteer-{a:02}"))).collect();
+    let builder = actors.iter().fold(LedgerBuilder::new().seed(3).without_rollback_plans(), |b, a| b.consenting(a));
+    let mut ledger = builder.build();
+    for seq in 0..RECORDS {
+        let tonnes = reading(seq).co2eq_g as f64 / 1e6;
+        let claim = AttestationBuilder::new(&actors[(seq % ACTORS) as usize]).description(&format!("Reading {seq}"));
+        let claim = claim.timestamp_ms(FIXTURE_START_MS + seq).co2eq_reduced(tonnes);
+        ledger.issue_attestation_idempotent(claim.request(), None).expect("issued");
+    }
+    ledger
+}
+
+fn main() {
+    let compression = if Compression::Gzip.available() { Compression::Gzip } else { Compression::None };
+    let path = std::env::temp_dir().join(format!("steward-stream-{}.jsonl", std::process::id()));
+    let mut expected = Totals::default();
+    for seq in 0..RECORDS {
+        expected.add(&reading(seq));
+    }
+
+    // 1. Export: generated and written one at a time.
+    let (manifest, export_growth) = peak_growth(|| {
+        let sink = BufWriter::new(File::create(&path).expect("temp file"));
+        let mut writer = StreamWriter::new(sink, KIND, compression).expect("header written");
+        for seq in 0..RECORDS {
+            writer.write_record(&reading(seq)).expect("record written");
+        }
+        writer.finish().expect("manifest written")
+    });
+    assert_eq!(manifest.records, RECORDS);
+    assert!(export_growth < CEILING, "export grew the heap by {export_growth} bytes");
+    let bytes = fs::metadata(&path).expect("temp file").len();
+    println!("exported {RECORDS} records ({compression:?}, {bytes} bytes), heap +{export_growth} bytes");
+
+    // 2. Import: the same totals, in the same constant memory.
+    let (totals, import_growth) = peak_growth(|| import(&path).expect("stream verifies"));
+    assert_eq!(totals, expected);
+    assert!(import_growth < CEILING, "import grew the heap by {import_growth} bytes");
+    println!("imported {} records, heap +{import_growth} bytes", totals.records);
+
+    // 3. Cut short: the manifest is gone, so the import fails.
+    OpenOptions::new().write(true).open(&path).expect("temp file").set_len(bytes / 2).expect("truncated");
+    let cut = import(&path).expect_err("a truncated export does not import");
+    assert!(matches!(cut, StreamError::Interrupted { records } if records < RECORDS), "{cut}");
+    println!("{cut}");
+    fs::remove_file(&path).expect("temp file removed");
+
+    // 4. Altered: the records no longer reach the manifest's chain head.
+    let mut small = Vec::new();
+    write_stream(&mut small, KIND, Compression::None, (0..3).map(reading)).expect("written");
+    let altered = String::from_utf8(small).expect("utf-8").replacen("\"co2eq_g\":7", "\"co2eq_g\":700", 1);
+    let records = StreamReader::open(altered.as_bytes(), KIND).expect("header intact").records::<Reading>();
+    let last = records.last().expect("records").expect_err("the manifest no longer matches");
+    assert!(matches!(last, StreamError::ManifestMismatch { .. }), "{last}");
+    assert!(matches!(StreamReader::open(altered.as_bytes(), "other.kind"), Err(StreamError::KindMismatch { .. })));
+
+    // 5. A ledger's million attestations: streamed out, restored into an empty ledger.
+    let source = ledger_of_readings();
+    let (manifest, export_growth) = peak_growth(|| {
+        let sink = BufWriter::new(File::create(&path).expect("temp file"));
+        source.export_attestations(sink, compression).expect("exported")
+    });
+    assert_eq!(manifest.records, RECORDS);
+    // `attestations()` orders references to the stored records, and the sort takes as much again.
+    let order_index = 2 * RECORDS as usize * size_of::<&StewardshipAttestation>();
+    assert!(export_growth < order_index + CEILING, "ledger export grew the heap by {export_growth} bytes");
+    println!("exported {RECORDS} attestations, heap +{export_growth} bytes");
+    let mut restored = LedgerBuilder::new().seed(5).without_rollback_plans().build();
+    let count = restored.restore_attestations(BufReader::new(File::open(&path).expect("temp file"))).expect("restored");
+    assert_eq!(count as u64, RECORDS);
+    fs::remove_file(&path).expect("temp file removed");
+    let line = |a: &StewardshipAttestation| serde_json::to_string(a).expect("serializes");
+    assert_eq!(restored.attestations().count(), source.attestations().count());
+    assert!(restored.attestations().zip(source.attestations()).all(|(r, s)| line(r) == line(s)));
+    let summary = |l: &PlanetaryLedger| serde_json::to_value(l.impact_summary(None)).expect("summary json");
+    assert_eq!(summary(&restored), summary(&source));
+    let again = restored.export_attestations(io::sink(), compression).expect("exported");
+    assert_eq!(again.chain_head, manifest.chain_head);
+    println!("restored {count} attestations, chain head {}", again.chain_head);
+}
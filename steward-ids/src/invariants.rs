@@ -0,0 +1,29 @@
+// path: steward-ids/src/invariants.rs
+
+//! What an engine's `check_invariants` reports when its state breaks one of the crate's
+//! safety claims.
+//! - Each engine checks its own state behind its `test-util` feature; the checks are cheap
+//!   enough to run after every operation of a generated sequence (see `steward-invariants`)
+//! - `invariant` names the claim (`element.baseline_retained`), `detail` the offending record
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvariantViolation {
+    pub invariant: &'static str,
+    pub detail: String,
+}
+
+impl InvariantViolation {
+    pub fn new(invariant: &'static str, detail: impl Into<String>) -> Self {
+        Self { invariant, detail: detail.into() }
+    }
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} violated: {}", self.invariant, self.detail)
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
@@ -0,0 +1,483 @@
+// path: steward-ids/src/lib.rs
+
+//! Canonical identifiers shared across the stewardship workspace.
+//! - `Did`: W3C DID syntax (`did:<method>:<method-specific-id>`); ids stored before DIDs
+//!   were enforced read back as `did:legacy:<id>` through `Did::deserialize_compat`
+//! - `CapabilityId`: namespaced capability (`<namespace>:<name>[:<name>...]`)
+//! - `MissionId`: opaque, printable mission identifier
+//! - `MetricKey`: namespaced custom impact metric (`<namespace>:<name>`)
+//! - `LanguageTag`: BCP 47-style language tag (`es`, `es-MX`), case-normalized
+//! - `metrics`: custom metric values and the registrations that bound them
+//! - `ordered`: sorted serialization for the `HashSet` / `HashMap` fields that hold them
+//! - `messages`: reason codes for denials and the locale catalogs that render them
+//! - `receipts`: vote receipts and the counted-ballot trees they are checked against
+//! - `stream`: framed, optionally gzipped JSON-lines streams for exports and their imports
+//! - `invariants`: the violation engines' `check_invariants` report
+//!
+//! Validation happens at parse boundaries (`FromStr`, `new`, serde
+//! deserialization) so malformed ids never reach a ledger or registry.
+//! The tuple field stays public so existing `Did("...".into())`-style code
+//! keeps compiling; prefer `parse()` / `new()` for untrusted input.
+
+use serde::{Deserializer, Serialize, Deserialize};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+pub mod invariants;
+pub mod messages;
+pub mod metrics;
+pub mod ordered;
+pub mod receipts;
+pub mod stream;
+
+/// Crate version stamped into exported JSON Schemas.
+#[cfg(feature = "schema")]
+pub const SCHEMA_CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Why an identifier failed validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum IdError {
+    Empty { kind: &'static str },
+    TooLong { kind: &'static str, max: usize },
+    /// Missing `did:` prefix, method, or method-specific id.
+    MalformedDid(String),
+    /// Method names are lowercase ASCII letters and digits.
+    InvalidDidMethod(String),
+    InvalidDidChar { did: String, ch: char },
+    /// Missing `<namespace>:` or empty name segment.
+    MalformedCapability(String),
+    InvalidCapabilityChar { capability: String, ch: char },
+    InvalidMissionChar { mission: String, ch: char },
+    /// Missing `<namespace>:`, empty name, or more than one `:`.
+    MalformedMetricKey(String),
+    InvalidMetricKeyChar { key: String, ch: char },
+    /// Primary subtag not 2-8 letters, or an empty or over-long later subtag.
+    MalformedLanguageTag(String),
+}
+
+impl fmt::Display for IdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdError::Empty { kind } => write!(f, "{kind} must not be empty"),
+            IdError::TooLong { kind, max } => write!(f, "{kind} longer than {max} bytes"),
+            IdError::MalformedDid(did) => {
+                write!(f, "Malformed DID {did:?}: expected did:<method>:<method-specific-id>")
+            }
+            IdError::InvalidDidMethod(did) => write!(f, "DID {did:?} has an invalid method name"),
+            IdError::InvalidDidChar { did, ch } => write!(f, "DID {did:?} contains invalid character {ch:?}"),
+            IdError::MalformedCapability(cap) => {
+                write!(f, "Malformed capability {cap:?}: expected <namespace>:<name>")
+            }
+            IdError::InvalidCapabilityChar { capability, ch } => {
+                write!(f, "Capability {capability:?} contains invalid character {ch:?}")
+            }
+            IdError::InvalidMissionChar { mission, ch } => {
+                write!(f, "Mission id {mission:?} contains invalid character {ch:?}")
+            }
+            IdError::MalformedMetricKey(key) => {
+                write!(f, "Malformed metric key {key:?}: expected <namespace>:<name>")
+            }
+            IdError::InvalidMetricKeyChar { key, ch } => {
+                write!(f, "Metric key {key:?} contains invalid character {ch:?}")
+            }
+            IdError::MalformedLanguageTag(tag) => {
+                write!(f, "Malformed language tag {tag:?}: expected <language>[-<subtag>...], e.g. es-MX")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+const MAX_ID_LEN: usize = 256;
+
+fn check_len(kind: &'static str, s: &str) -> Result<(), IdError> {
+    if s.is_empty() {
+        return Err(IdError::Empty { kind });
+    }
+    if s.len() > MAX_ID_LEN {
+        return Err(IdError::TooLong { kind, max: MAX_ID_LEN });
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------
+// DID
+// ---------------------------------------------------------------------
+
+/// DID method `Did::from_legacy` gives pre-DID ids.
+pub const LEGACY_DID_METHOD: &str = "legacy";
+
+/// Decentralized identifier, e.g. `did:psv:governance:collective`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Did(pub String);
+
+impl Did {
+    pub fn new(s: impl Into<String>) -> Result<Self, IdError> {
+        let s = s.into();
+        Self::validate(&s)?;
+        Ok(Did(s))
+    }
+
+    /// DID syntax: `did:` method `:` method-specific-id, where the method is
+    /// `[a-z0-9]+` and the id is colon-separated runs of `[A-Za-z0-9._-]` or
+    /// `%XX` escapes, not ending in `:`.
+    pub fn validate(s: &str) -> Result<(), IdError> {
+        check_len("DID", s)?;
+        let rest = s
+            .strip_prefix("did:")
+            .ok_or_else(|| IdError::MalformedDid(s.to_string()))?;
+        let (method, msid) = rest
+            .split_once(':')
+            .ok_or_else(|| IdError::MalformedDid(s.to_string()))?;
+        if method.is_empty() || msid.is_empty() || msid.ends_with(':') {
+            return Err(IdError::MalformedDid(s.to_string()));
+        }
+        if !method.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()) {
+            return Err(IdError::InvalidDidMethod(s.to_string()));
+        }
+        let bytes = msid.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[i] as char;
+            if c == '%' {
+                let hex = bytes.get(i + 1..i + 3);
+                if !hex.is_some_and(|h| h.iter().all(u8::is_ascii_hexdigit)) {
+                    return Err(IdError::InvalidDidChar { did: s.to_string(), ch: '%' });
+                }
+                i += 3;
+                continue;
+            }
+            if !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | ':')) {
+                let ch = msid[i..].chars().next().unwrap_or(c);
+                return Err(IdError::InvalidDidChar { did: s.to_string(), ch });
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// A pre-DID id (`alice`, `org acme`) as `did:legacy:alice`, `did:legacy:org%20acme`.
+    /// Bytes outside `[A-Za-z0-9._-]` are `%XX`-escaped, so distinct ids stay distinct.
+    /// Anything already starting with `did:` must be a valid DID.
+    pub fn from_legacy(s: &str) -> Result<Self, IdError> {
+        if s.starts_with("did:") {
+            return Did::new(s);
+        }
+        check_len("DID", s)?;
+        let mut did = format!("did:{LEGACY_DID_METHOD}:");
+        for b in s.bytes() {
+            if b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-') {
+                did.push(b as char);
+            } else {
+                let _ = write!(did, "%{b:02X}");
+            }
+        }
+        Did::new(did)
+    }
+
+    /// Serde `deserialize_with` for records stored before DIDs were enforced: a valid DID
+    /// as is, any other non-`did:` string through `from_legacy`.
+    pub fn deserialize_compat<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Did::from_legacy(&s).map_err(serde::de::Error::custom)
+    }
+
+    /// Whether this DID was mapped from a pre-DID id by `from_legacy`.
+    pub fn is_legacy(&self) -> bool {
+        self.method() == LEGACY_DID_METHOD
+    }
+
+    pub fn method(&self) -> &str {
+        self.0.split(':').nth(1).unwrap_or("")
+    }
+
+    pub fn method_specific_id(&self) -> &str {
+        self.0.splitn(3, ':').nth(2).unwrap_or("")
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// ---------------------------------------------------------------------
+// CAPABILITY
+// ---------------------------------------------------------------------
+
+/// Namespaced capability, e.g. `move:bci_shield` or `safety:emergency_stop`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CapabilityId(pub String);
+
+impl CapabilityId {
+    pub fn new(s: impl Into<String>) -> Result<Self, IdError> {
+        let s = s.into();
+        Self::validate(&s)?;
+        Ok(CapabilityId(s))
+    }
+
+    /// Namespace `[a-z][a-z0-9_-]*`, then one or more `:`-separated name
+    /// segments of `[a-z0-9_.-]+`.
+    pub fn validate(s: &str) -> Result<(), IdError> {
+        check_len("capability", s)?;
+        let mut segments = s.split(':');
+        let namespace = segments.next().unwrap_or("");
+        if !namespace.starts_with(|c: char| c.is_ascii_lowercase()) {
+            return Err(IdError::MalformedCapability(s.to_string()));
+        }
+        let mut names = 0;
+        for segment in segments {
+            if segment.is_empty() {
+                return Err(IdError::MalformedCapability(s.to_string()));
+            }
+            names += 1;
+        }
+        if names == 0 {
+            return Err(IdError::MalformedCapability(s.to_string()));
+        }
+        let ns_len = namespace.len();
+        for (i, c) in s.char_indices() {
+            let ok = if i < ns_len {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-')
+            } else {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.' | ':')
+            };
+            if !ok {
+                return Err(IdError::InvalidCapabilityChar { capability: s.to_string(), ch: c });
+            }
+        }
+        Ok(())
+    }
+
+    /// Text before the first `:`.
+    pub fn namespace(&self) -> &str {
+        self.0.split(':').next().unwrap_or("")
+    }
+
+    /// Text after the first `:`.
+    pub fn name(&self) -> &str {
+        self.0.split_once(':').map(|(_, n)| n).unwrap_or("")
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// ---------------------------------------------------------------------
+// MISSION
+// ---------------------------------------------------------------------
+
+/// Mission identifier: printable ASCII `[A-Za-z0-9._:-]`, no whitespace.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MissionId(pub String);
+
+impl MissionId {
+    pub fn new(s: impl Into<String>) -> Result<Self, IdError> {
+        let s = s.into();
+        Self::validate(&s)?;
+        Ok(MissionId(s))
+    }
+
+    pub fn validate(s: &str) -> Result<(), IdError> {
+        check_len("mission id", s)?;
+        if let Some(ch) = s
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | ':' | '-')))
+        {
+            return Err(IdError::InvalidMissionChar { mission: s.to_string(), ch });
+        }
+        Ok(())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// ---------------------------------------------------------------------
+// METRIC KEY
+// ---------------------------------------------------------------------
+
+/// Custom impact metric, e.g. `water:nitrate_kg_reduced`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MetricKey(pub String);
+
+impl MetricKey {
+    pub fn new(s: impl Into<String>) -> Result<Self, IdError> {
+        let s = s.into();
+        Self::validate(&s)?;
+        Ok(MetricKey(s))
+    }
+
+    /// Namespace `[a-z][a-z0-9_-]*`, one `:`, then a name of `[a-z0-9_.-]+`.
+    pub fn validate(s: &str) -> Result<(), IdError> {
+        check_len("metric key", s)?;
+        let (namespace, name) = s
+            .split_once(':')
+            .ok_or_else(|| IdError::MalformedMetricKey(s.to_string()))?;
+        if !namespace.starts_with(|c: char| c.is_ascii_lowercase()) || name.is_empty() || name.contains(':') {
+            return Err(IdError::MalformedMetricKey(s.to_string()));
+        }
+        if let Some(ch) = namespace
+            .chars()
+            .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-')))
+            .or_else(|| {
+                name.chars()
+                    .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.')))
+            })
+        {
+            return Err(IdError::InvalidMetricKeyChar { key: s.to_string(), ch });
+        }
+        Ok(())
+    }
+
+    /// Text before the `:`.
+    pub fn namespace(&self) -> &str {
+        self.0.split(':').next().unwrap_or("")
+    }
+
+    /// Text after the `:`.
+    pub fn name(&self) -> &str {
+        self.0.split_once(':').map(|(_, n)| n).unwrap_or("")
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// ---------------------------------------------------------------------
+// LANGUAGE TAG
+// ---------------------------------------------------------------------
+
+/// Language of user-facing text, e.g. `en` or `es-MX`.
+///
+/// Tags compare case-insensitively in BCP 47, so `new` normalizes: the language
+/// lowercase, a four-letter script title-case, a two-letter region uppercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct LanguageTag(pub String);
+
+impl LanguageTag {
+    pub fn new(s: impl Into<String>) -> Result<Self, IdError> {
+        let s = s.into();
+        Self::validate(&s)?;
+        let normalized = s
+            .split('-')
+            .enumerate()
+            .map(|(i, sub)| match (i, sub.len()) {
+                (0, _) => sub.to_ascii_lowercase(),
+                (_, 2) => sub.to_ascii_uppercase(),
+                (_, 4) => sub[..1].to_ascii_uppercase() + &sub[1..].to_ascii_lowercase(),
+                _ => sub.to_ascii_lowercase(),
+            })
+            .collect::<Vec<_>>()
+            .join("-");
+        Ok(LanguageTag(normalized))
+    }
+
+    /// Language of 2-8 ASCII letters, then `-`-separated subtags of 1-8 ASCII alphanumerics.
+    pub fn validate(s: &str) -> Result<(), IdError> {
+        check_len("language tag", s)?;
+        let mut subtags = s.split('-');
+        let language = subtags.next().unwrap_or("");
+        let language_ok = (2..=8).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic());
+        let rest_ok = subtags.all(|t| (1..=8).contains(&t.len()) && t.chars().all(|c| c.is_ascii_alphanumeric()));
+        if !(language_ok && rest_ok) {
+            return Err(IdError::MalformedLanguageTag(s.to_string()));
+        }
+        Ok(())
+    }
+
+    /// The language subtag alone: `es` for `es-MX`.
+    pub fn primary(&self) -> LanguageTag {
+        LanguageTag(self.0.split('-').next().unwrap_or("").to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// ---------------------------------------------------------------------
+// SHARED CONVERSIONS
+// ---------------------------------------------------------------------
+
+macro_rules! string_id_impls {
+    ($ty:ident, $pattern:literal) => {
+        impl FromStr for $ty {
+            type Err = IdError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $ty {
+            type Error = IdError;
+            fn try_from(s: String) -> Result<Self, Self::Error> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<&str> for $ty {
+            type Error = IdError;
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                Self::new(s)
+            }
+        }
+
+        impl From<$ty> for String {
+            fn from(id: $ty) -> String {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $ty {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        /// Serialized as a plain string; the pattern mirrors `validate`.
+        #[cfg(feature = "schema")]
+        impl schemars::JsonSchema for $ty {
+            fn schema_name() -> String {
+                stringify!($ty).to_string()
+            }
+
+            fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                schemars::schema::SchemaObject {
+                    instance_type: Some(schemars::schema::InstanceType::String.into()),
+                    string: Some(Box::new(schemars::schema::StringValidation {
+                        max_length: Some(MAX_ID_LEN as u32),
+                        min_length: Some(1),
+                        pattern: Some($pattern.to_string()),
+                    })),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+}
+
+string_id_impls!(
+    Did,
+    r"^did:[a-z0-9]+:([A-Za-z0-9._:-]|%[0-9A-Fa-f]{2})*([A-Za-z0-9._-]|%[0-9A-Fa-f]{2})$"
+);
+string_id_impls!(CapabilityId, r"^[a-z][a-z0-9_-]*(:[a-z0-9_.-]+)+$");
+string_id_impls!(MissionId, r"^[A-Za-z0-9._:-]+$");
+string_id_impls!(MetricKey, r"^[a-z][a-z0-9_-]*:[a-z0-9_.-]+$");
+string_id_impls!(LanguageTag, r"^[A-Za-z]{2,8}(-[A-Za-z0-9]{1,8})*$");
@@ -0,0 +1,503 @@
+// path: steward-ids/src/messages.rs
+
+//! Reason codes for denials, and the catalogs that turn them into participant-facing text.
+//! - A `Reason` is a `ReasonCode` plus named parameters (a capability, a DID, a limit);
+//!   apps branch on the code, which serializes as a stable namespaced string (`saep.non_harm`)
+//! - `Display` renders the built-in English catalog, so `String` errors read as they always have
+//! - `Messages::register_catalog` adds a locale; `render` tries the exact tag, then the bare
+//!   language (`es` for `es-MX`), then English
+//! - A template may only use its code's parameters; `{name}` is replaced by the parameter's
+//!   value, and a code with no English entry renders as `⟦code⟧`
+//! - `detail` carries prose a lower-level check composed before it had a code
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::LanguageTag;
+
+/// Tag of the built-in catalog.
+pub const DEFAULT_LOCALE: &str = "en";
+
+macro_rules! reason_codes {
+    ($($(#[$meta:meta])* $variant:ident = $name:literal [$($param:literal),*],)*) => {
+        /// Why an action was refused, independent of how it is worded.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+        pub enum ReasonCode {
+            $($(#[$meta])* #[serde(rename = $name)] $variant,)*
+        }
+
+        impl ReasonCode {
+            pub const ALL: &'static [ReasonCode] = &[$(ReasonCode::$variant),*];
+
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(ReasonCode::$variant => $name,)*
+                }
+            }
+
+            /// Parameters a `Reason` with this code carries, and templates may use.
+            pub fn params(self) -> &'static [&'static str] {
+                match self {
+                    $(ReasonCode::$variant => &[$($param),*],)*
+                }
+            }
+        }
+    };
+}
+
+reason_codes! {
+    // SAEP rules and the decisions they block.
+    SaepNonHarm = "saep.non_harm" [],
+    SaepCommonsBenefit = "saep.commons_benefit" [],
+    /// Restricting a charter-bound module for militarization or harmful use.
+    SaepCharter = "saep.charter" ["module", "clause", "excerpt"],
+    /// `rules` is the rendered list of fired rules, `codes` their reason codes.
+    SaepBlocked = "saep.blocked" ["action", "rules", "codes"],
+    SaepEscalated = "saep.escalated" ["action", "rules", "codes"],
+
+    // Module status.
+    ModuleActive = "module.active" ["module"],
+    ModulePaused = "module.paused" ["module", "pause_id", "since_ms", "note"],
+    ModuleReadOnly = "module.read_only" ["module", "pause_id", "since_ms", "note"],
+    ModuleStatusUnavailable = "module.status_unavailable" [],
+
+    // Ledger and mission checks.
+    MetricSchema = "metrics.schema" ["detail"],
+    CustomMetric = "metrics.custom" ["detail"],
+    Biodiversity = "metrics.biodiversity" ["detail"],
+    EvidenceRejected = "evidence.rejected" ["detail"],
+    EvidenceOutsideWindow = "evidence.outside_window" ["uri", "captured_at_ms", "window_start_ms", "window_end_ms"],
+    EvidenceReused = "evidence.reused" ["hash", "attestation"],
+    WitnessRequired = "witness.required" ["metric", "value", "threshold"],
+    WitnessUnknown = "witness.unknown" ["witness"],
+    WitnessStatementMismatch = "witness.statement_mismatch" ["witness", "statement_hash"],
+    WitnessSignatureInvalid = "witness.signature_invalid" ["witness"],
+    WitnessKeyRejected = "witness.key_rejected" ["witness", "key_id", "problem"],
+    VerifierConflictForbidden = "verifier.conflict_forbidden" ["verifier", "actor", "relationship"],
+    ContentTooLong = "content.too_long" ["field", "chars", "max"],
+    ContentDisallowedCharacter = "content.disallowed_character" ["field", "code_point", "offset"],
+    ContentPii = "content.pii" ["field", "kind", "offset"],
+    ContentFlaggedTerm = "content.flagged_term" ["field", "term"],
+    ContentSanitized = "content.sanitized" ["field", "removed"],
+    ConsentMissing = "consent.missing" ["detail", "participant", "module"],
+    ConsentEvidenceScheme = "consent.evidence_scheme" ["uri", "allowed"],
+    ConsentEvidenceHashMissing = "consent.evidence_hash_missing" ["participant", "module"],
+    ConsentEvidenceHashMismatch = "consent.evidence_hash_mismatch" ["uri", "expected", "actual"],
+    ConsentEvidenceUnreadable = "consent.evidence_unreadable" ["uri", "detail"],
+    RollbackPlan = "rollback.plan" ["detail"],
+    IntentLog = "intent.log" ["detail"],
+    MissionUnknown = "mission.unknown" ["mission"],
+    MissionAlreadyAssigned = "mission.already_assigned" ["mission", "assignee", "assigned_ts_ms"],
+    MissionActiveCap = "mission.active_cap" ["assignee", "active", "max"],
+    MissionHighDemandCap = "mission.high_demand_cap" ["assignee", "claims", "max", "window_ms"],
+    MissionClaimDelay = "mission.claim_delay" ["mission", "opens_ms"],
+    MissionLotteryPending = "mission.lottery_pending" ["mission"],
+    MissionWaitlistClosed = "mission.waitlist_closed" ["mission"],
+    /// A lottery seed that does not open the commitment the mission was published with.
+    MissionLotterySeed = "mission.lottery_seed" ["mission"],
+    IdempotencyConflict = "idempotency.conflict" ["key"],
+    /// `scope` is `global` or the actor's DID.
+    IngestionQueueFull = "ingestion.queue_full" ["scope", "limit", "retry_after_ms"],
+    IngestionMalformed = "ingestion.malformed" ["field", "detail"],
+    /// Shared by both governance engines.
+    ProposalLimitExceeded = "proposal.limit_exceeded" ["limit", "measured", "max"],
+
+    // Stewardship governance.
+    GovernanceOutcomeMismatch = "governance.outcome_mismatch" ["outcome", "proposal"],
+    GovernanceAlreadyApplied = "governance.already_applied" ["proposal"],
+    GovernanceNotPassed = "governance.not_passed" ["proposal"],
+    GovernanceTemplateEdited = "governance.template_edited" ["proposal", "template"],
+    GovernanceMalformedChange = "governance.malformed_change" ["detail"],
+    GovernanceEthicsVeto = "governance.ethics_veto" ["rules", "codes"],
+    GovernanceCharter = "governance.charter" ["module", "clause", "excerpt"],
+    GovernanceUnknownScope = "governance.unknown_scope" ["scope"],
+    GovernanceScopeCharter = "governance.scope_charter" ["scope", "term"],
+    GovernanceCharterVersion = "governance.charter_version" ["version", "active"],
+    CharterNotBound = "charter.not_bound" ["module"],
+    CharterUnknownProposal = "charter.unknown_proposal" ["proposal"],
+    CharterNotEcosystemWide = "charter.not_ecosystem_wide" ["proposal"],
+    CharterInsufficientMajority = "charter.insufficient_majority" ["proposal", "support_share", "required"],
+    CharterWrongModule = "charter.wrong_module" ["proposal", "module", "named"],
+    CharterModuleUnnamed = "charter.module_unnamed" ["proposal", "key"],
+    CharterAuthorizationSpent = "charter.authorization_spent" ["proposal"],
+    CharterUnknownClause = "charter.unknown_clause" ["rule", "clause", "version"],
+
+    // Capability governance.
+    CapabilitySunsetOrder = "capability.sunset_order" [],
+    CapabilityCapabilitySunsetOrder = "capability.capability_sunset_order" ["capability"],
+    CapabilitySunsetUnrestricted = "capability.sunset_unrestricted" ["capability"],
+    CapabilityNoDomains = "capability.no_domains" [],
+    CapabilityUnknownDomain = "capability.unknown_domain" ["domain"],
+    CapabilityMultiDomain = "capability.multi_domain" [],
+    CapabilityHeightAhead = "capability.height_ahead" ["current_height", "authoritative"],
+    CapabilityOutcomeMismatch = "capability.outcome_mismatch" [],
+    CapabilityOutcomeFuture = "capability.outcome_future" [],
+    CapabilityOutcomeStale = "capability.outcome_stale" [],
+    CapabilityWeightOverflow = "capability.weight_overflow" [],
+    CapabilityUnknownCapabilities = "capability.unknown_capabilities" ["capabilities"],
+    CapabilityThresholdMissed = "capability.threshold_missed" ["capabilities"],
+    CapabilityDomainFloor = "capability.domain_floor" [],
+    CapabilityGlobalFloor = "capability.global_floor" [],
+    CapabilityCategoryFloor = "capability.category_floor" ["category", "enabled", "floor"],
+    CapabilityRestrictionFraction = "capability.restriction_fraction" [],
+    CapabilityCumulativeFraction = "capability.cumulative_fraction" [],
+    CapabilityImpactStatementMissing = "capability.impact_statement_missing" [],
+    CapabilityImpactStatementCount = "capability.impact_statement_count" ["stated", "restricted"],
+    CapabilityImpactStatementIncomplete = "capability.impact_statement_incomplete" ["field"],
+    CapabilityImpactEstimateOff = "capability.impact_estimate_off" ["estimated", "observed", "factor"],
+    CapabilityReservedNamespace = "capability.reserved_namespace" ["capability", "namespace"],
+
+    // The Element.
+    ElementUnknownCapability = "element.unknown_capability" ["capability"],
+    ElementOptInRequired = "element.opt_in_required" ["capability"],
+    ElementSelfBlocked = "element.self_blocked" ["capability"],
+    ElementMissingPrerequisite = "element.missing_prerequisite" ["capability", "prerequisite"],
+    ElementLogExportOwnerOnly = "element.log_export_owner_only" ["agent"],
+    ElementLogPurgeOwnerOnly = "element.log_purge_owner_only" ["agent"],
+    ElementBaselineRight = "element.baseline_right" ["capability"],
+    ElementRestrictionFraction = "element.restriction_fraction" ["fraction"],
+    ElementUsageConstraintsInvalid = "element.usage_constraints_invalid" ["detail"],
+    ElementUsageConstraintsNotPermitted = "element.usage_constraints_not_permitted" ["requester", "agent", "capability"],
+    ElementUsageOutsideHours = "element.usage_outside_hours" ["capability", "from", "until"],
+    ElementUsageDailyCap = "element.usage_daily_cap" ["capability", "max_ms"],
+    ElementUsageUnavailable = "element.usage_unavailable" ["capability"],
+    ElementUsageSessionOpen = "element.usage_session_open" ["capability"],
+    ElementUsageNoSession = "element.usage_no_session" ["capability"],
+    ElementSelfEndorsement = "element.self_endorsement" ["capability"],
+    ElementEndorserLacksCapability = "element.endorser_lacks_capability" ["endorser", "capability"],
+    ElementEndorsementExpiry = "element.endorsement_expiry" ["expires_ms"],
+    ElementUnknownEndorsement = "element.unknown_endorsement" ["endorsement"],
+    ElementEndorsementRevokeEndorserOnly = "element.endorsement_revoke_endorser_only" ["endorsement"],
+    ElementEndorsementRequired = "element.endorsement_required" ["capability"],
+    ElementSandboxPaused = "element.sandbox_paused" ["capability"],
+}
+
+impl fmt::Display for ReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The built-in catalog. Wording matches what these denials said before they had codes.
+const ENGLISH: &[(ReasonCode, &str)] = &[
+    (ReasonCode::SaepNonHarm, "non_harm: detected potential harmful or coercive intent"),
+    (ReasonCode::SaepCommonsBenefit, "commons_benefit: private hoarding flagged"),
+    (ReasonCode::SaepCharter, "charter: clause {clause} forbids this in charter-bound module {module}: \"{excerpt}\""),
+    (ReasonCode::SaepBlocked, "SAEP blocked {action}: {rules}"),
+    (ReasonCode::SaepEscalated, "SAEP held {action} for human review: {rules}"),
+    (ReasonCode::ModuleActive, "Module {module} is active"),
+    (ReasonCode::ModulePaused, "Module {module} is paused ({pause_id}, since {since_ms}): {note}"),
+    (ReasonCode::ModuleReadOnly, "Module {module} is read-only ({pause_id}, since {since_ms}): {note}"),
+    (ReasonCode::ModuleStatusUnavailable, "Module status lock poisoned"),
+    (ReasonCode::MetricSchema, "{detail}"),
+    (ReasonCode::CustomMetric, "{detail}"),
+    (ReasonCode::Biodiversity, "{detail}"),
+    (ReasonCode::EvidenceRejected, "{detail}"),
+    (
+        ReasonCode::EvidenceOutsideWindow,
+        "Evidence {uri} was captured at {captured_at_ms} ms, outside [{window_start_ms}, {window_end_ms}]",
+    ),
+    (ReasonCode::EvidenceReused, "Evidence content {hash} already backs attestation {attestation}"),
+    (
+        ReasonCode::WitnessRequired,
+        "Claim of {value} {metric} is above the witnessing threshold of {threshold}; a witness endorsement is required",
+    ),
+    (ReasonCode::WitnessUnknown, "{witness} is not a witness under the ledger's impact threshold policy"),
+    (
+        ReasonCode::WitnessStatementMismatch,
+        "Endorsement by {witness} covers another statement, not {statement_hash}",
+    ),
+    (ReasonCode::WitnessSignatureInvalid, "Endorsement signature by {witness} does not verify"),
+    (ReasonCode::WitnessKeyRejected, "Endorsement by {witness} used key {key_id}, which cannot sign it: {problem}"),
+    (
+        ReasonCode::VerifierConflictForbidden,
+        "{verifier} has a declared {relationship} relationship with {actor} and may not verify their claims",
+    ),
+    (ReasonCode::ContentTooLong, "The {field} is {chars} characters long, over the limit of {max}"),
+    (ReasonCode::ContentDisallowedCharacter, "The {field} contains disallowed character {code_point} at {offset}"),
+    (ReasonCode::ContentPii, "The {field} contains {kind} at {offset}"),
+    (ReasonCode::ContentFlaggedTerm, "The {field} contains flagged term \"{term}\""),
+    (ReasonCode::ContentSanitized, "Removed {removed} from the {field}"),
+    (ReasonCode::ConsentMissing, "{detail}"),
+    (ReasonCode::ConsentEvidenceScheme, "Consent evidence {uri} must use one of: {allowed}"),
+    (ReasonCode::ConsentEvidenceHashMissing, "Consent by {participant} to {module} must carry an evidence hash"),
+    (
+        ReasonCode::ConsentEvidenceHashMismatch,
+        "Consent evidence at {uri} hashes to {actual}, not the recorded {expected}",
+    ),
+    (ReasonCode::ConsentEvidenceUnreadable, "Cannot verify consent evidence at {uri}: {detail}"),
+    (ReasonCode::RollbackPlan, "{detail}"),
+    (ReasonCode::IntentLog, "{detail}"),
+    (ReasonCode::MissionUnknown, "Unknown mission template"),
+    (ReasonCode::MissionAlreadyAssigned, "Mission {mission} is already assigned to {assignee} at {assigned_ts_ms}"),
+    (ReasonCode::MissionActiveCap, "{assignee} has {active} active assignments, the limit is {max}"),
+    (
+        ReasonCode::MissionHighDemandCap,
+        "{assignee} claimed {claims} high-demand missions in the last {window_ms} ms, the limit is {max}",
+    ),
+    (
+        ReasonCode::MissionClaimDelay,
+        "Mission {mission} takes waitlist joins until {opens_ms}, then assigns by lottery",
+    ),
+    (ReasonCode::MissionLotteryPending, "Mission {mission} is waiting for its lottery draw"),
+    (ReasonCode::MissionWaitlistClosed, "Mission {mission} has no open waitlist"),
+    (ReasonCode::MissionLotterySeed, "The seed does not match the one mission {mission} was published with"),
+    (ReasonCode::IdempotencyConflict, "Idempotency key {key} was already used for a different request"),
+    (
+        ReasonCode::IngestionQueueFull,
+        "Ingestion queue for {scope} is full ({limit} waiting); retry in {retry_after_ms} ms",
+    ),
+    (ReasonCode::IngestionMalformed, "Malformed attestation request: {field} {detail}"),
+    (ReasonCode::ProposalLimitExceeded, "Proposal {limit} is {measured}, over the limit of {max}"),
+    (ReasonCode::GovernanceOutcomeMismatch, "Outcome is for proposal {outcome}, not {proposal}"),
+    (ReasonCode::GovernanceAlreadyApplied, "Proposal {proposal} was already applied"),
+    (ReasonCode::GovernanceNotPassed, "Proposal {proposal} did not pass"),
+    (
+        ReasonCode::GovernanceTemplateEdited,
+        "Proposal {proposal} payload was edited after instantiation from template {template}",
+    ),
+    (ReasonCode::GovernanceMalformedChange, "{detail}"),
+    (ReasonCode::GovernanceEthicsVeto, "Ethics-kernel vetoed governance proposal: {rules}"),
+    (
+        ReasonCode::GovernanceCharter,
+        "CSC: charter clause {clause} forbids this in charter-bound module {module}: \"{excerpt}\"",
+    ),
+    (ReasonCode::GovernanceUnknownScope, "Governance scope {scope} is not registered"),
+    (ReasonCode::GovernanceScopeCharter, "Restrictive proposals in {scope} may not mention {term}"),
+    (ReasonCode::GovernanceCharterVersion, "Charter version {version} is not newer than the active version {active}"),
+    (ReasonCode::CharterNotBound, "Module {module} is not charter-bound"),
+    (ReasonCode::CharterUnknownProposal, "Proposal {proposal} has not been applied"),
+    (
+        ReasonCode::CharterNotEcosystemWide,
+        "Proposal {proposal} is module-scoped; unbinding needs an ecosystem-wide proposal",
+    ),
+    (
+        ReasonCode::CharterInsufficientMajority,
+        "Proposal {proposal} passed with {support_share} support; unbinding needs {required}",
+    ),
+    (ReasonCode::CharterWrongModule, "Proposal {proposal} unbinds {named}, not {module}"),
+    (ReasonCode::CharterModuleUnnamed, "Proposal {proposal} does not name a module under {key}"),
+    (ReasonCode::CharterAuthorizationSpent, "Proposal {proposal} has already authorized an unbinding"),
+    (
+        ReasonCode::CharterUnknownClause,
+        "Charter rule {rule} cites clause {clause}, which charter version {version} does not have",
+    ),
+    (ReasonCode::CapabilitySunsetOrder, "sunset_height must be later than activation_height"),
+    (ReasonCode::CapabilityCapabilitySunsetOrder, "Sunset for {capability} must be later than activation_height"),
+    (ReasonCode::CapabilitySunsetUnrestricted, "Sunset given for {capability} which is not restricted"),
+    (ReasonCode::CapabilityNoDomains, "Proposal targets no domains"),
+    (ReasonCode::CapabilityUnknownDomain, "Unknown domain_id: {domain}"),
+    (ReasonCode::CapabilityMultiDomain, "Multi-domain proposal; use evaluate_proposal_per_domain"),
+    (
+        ReasonCode::CapabilityHeightAhead,
+        "current_height {current_height} is ahead of height source ({authoritative})",
+    ),
+    (ReasonCode::CapabilityOutcomeMismatch, "Vote outcome belongs to a different proposal"),
+    (ReasonCode::CapabilityOutcomeFuture, "Vote outcome finalized after current_height; rejected"),
+    (
+        ReasonCode::CapabilityOutcomeStale,
+        "Vote outcome is stale (older than max_outcome_age_heights); rejected",
+    ),
+    (ReasonCode::CapabilityWeightOverflow, "Vote weights overflow u128"),
+    (
+        ReasonCode::CapabilityUnknownCapabilities,
+        "Proposal restricts capabilities unknown to domain: {capabilities}",
+    ),
+    (ReasonCode::CapabilityThresholdMissed, "Yes ratio misses per-capability threshold for: {capabilities}"),
+    (ReasonCode::CapabilityDomainFloor, "Proposal would violate domain.min_capability_count; rejected"),
+    (ReasonCode::CapabilityGlobalFloor, "Proposal would violate global_min_capability_floor; rejected"),
+    (
+        ReasonCode::CapabilityCategoryFloor,
+        "Proposal would leave {enabled} enabled in category {category}, below its floor of {floor}; rejected",
+    ),
+    (ReasonCode::CapabilityRestrictionFraction, "Proposal over max_restriction_fraction_per_turn; rejected"),
+    (ReasonCode::CapabilityCumulativeFraction, "Proposal over max_cumulative_disabled_fraction; rejected"),
+    (
+        ReasonCode::CapabilityImpactStatementMissing,
+        "Constitution requires an impact statement for proposals restricting capabilities",
+    ),
+    (
+        ReasonCode::CapabilityImpactStatementCount,
+        "Impact statement counts {stated} affected capabilities; the proposal restricts {restricted}",
+    ),
+    (ReasonCode::CapabilityImpactStatementIncomplete, "Impact statement must fill in {field}"),
+    (
+        ReasonCode::CapabilityImpactEstimateOff,
+        "Impact statement estimates {estimated} affected agents; the element bridge finds {observed}, off by more than {factor}x",
+    ),
+    (
+        ReasonCode::CapabilityReservedNamespace,
+        "{capability} is in non-restrictable namespace {namespace}; no proposal can restrict it",
+    ),
+    (ReasonCode::ElementUnknownCapability, "Unknown capability"),
+    (ReasonCode::ElementOptInRequired, "Explicit opt-in required for this ability."),
+    (ReasonCode::ElementSelfBlocked, "Agent has explicitly blocked this capability."),
+    (ReasonCode::ElementMissingPrerequisite, "Missing prerequisite capability: {prerequisite}"),
+    (ReasonCode::ElementLogExportOwnerOnly, "Only the agent can export their safety log."),
+    (ReasonCode::ElementLogPurgeOwnerOnly, "Only the agent can purge their safety log."),
+    (ReasonCode::ElementBaselineRight, "Cannot restrict baseline capability: {capability}"),
+    (ReasonCode::ElementRestrictionFraction, "Restriction exceeds allowed per-turn fraction."),
+    (ReasonCode::ElementUsageConstraintsInvalid, "Invalid usage constraints: {detail}"),
+    (
+        ReasonCode::ElementUsageConstraintsNotPermitted,
+        "{requester} may not change usage constraints on {capability} for {agent}",
+    ),
+    (ReasonCode::ElementUsageOutsideHours, "{capability} may only be used between {from} and {until} local time"),
+    (ReasonCode::ElementUsageDailyCap, "Daily use of {capability} has reached its cap of {max_ms} ms"),
+    (ReasonCode::ElementUsageUnavailable, "{capability} is not enabled for this agent"),
+    (ReasonCode::ElementUsageSessionOpen, "A session of {capability} is already open"),
+    (ReasonCode::ElementUsageNoSession, "No open session of {capability}"),
+    (ReasonCode::ElementSelfEndorsement, "An agent cannot endorse themselves for {capability}"),
+    (ReasonCode::ElementEndorserLacksCapability, "{endorser} cannot use {capability}, so cannot endorse it"),
+    (ReasonCode::ElementEndorsementExpiry, "An endorsement cannot expire ({expires_ms}) before it is issued"),
+    (ReasonCode::ElementUnknownEndorsement, "Unknown endorsement {endorsement}"),
+    (ReasonCode::ElementEndorsementRevokeEndorserOnly, "Only the endorser can revoke {endorsement}"),
+    (ReasonCode::ElementEndorsementRequired, "{capability} needs an active endorsement from a designated endorser"),
+    (ReasonCode::ElementSandboxPaused, "Augmentation is paused; {capability} cannot start until it resumes"),
+];
+
+/// A locale's templates by code; codes it leaves out fall back.
+pub type Catalog = BTreeMap<ReasonCode, String>;
+
+/// A coded denial and the parameters its message needs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Reason {
+    pub code: ReasonCode,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+impl Reason {
+    pub fn new(code: ReasonCode) -> Self {
+        Self { code, params: BTreeMap::new() }
+    }
+
+    /// Set parameter `name`, one of `code.params()`.
+    pub fn with(mut self, name: &str, value: impl fmt::Display) -> Self {
+        debug_assert!(self.code.params().contains(&name), "{} has no parameter {name}", self.code);
+        self.params.insert(name.to_string(), value.to_string());
+        self
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match english(self.code) {
+            Some(template) => f.write_str(&fill(template, &self.params)),
+            None => f.write_str(&placeholder(self.code)),
+        }
+    }
+}
+
+impl std::error::Error for Reason {}
+
+impl From<Reason> for String {
+    fn from(reason: Reason) -> Self {
+        reason.to_string()
+    }
+}
+
+/// What a code with no template anywhere renders as.
+pub fn placeholder(code: ReasonCode) -> String {
+    format!("⟦{code}⟧")
+}
+
+fn english(code: ReasonCode) -> Option<&'static str> {
+    ENGLISH.iter().find(|(c, _)| *c == code).map(|(_, template)| *template)
+}
+
+/// `{name}` placeholders in `template`, in order.
+fn placeholders(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        rest = &rest[open + 1..];
+        match rest.find('}') {
+            Some(close) if is_param_name(&rest[..close]) => {
+                names.push(&rest[..close]);
+                rest = &rest[close + 1..];
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+fn is_param_name(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// `template` with each `{name}` replaced by `params[name]`; unknown names are left as written.
+fn fill(template: &str, params: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        match after.find('}').map(|close| (close, &after[..close])) {
+            Some((close, name)) if is_param_name(name) && params.contains_key(name) => {
+                out.push_str(&params[name]);
+                rest = &after[close + 1..];
+            }
+            _ => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Locale catalogs registered on top of the built-in English one.
+#[derive(Debug, Clone, Default)]
+pub struct Messages {
+    catalogs: BTreeMap<LanguageTag, Catalog>,
+}
+
+impl Messages {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `catalog`'s templates to `locale`, replacing any registered for the same codes.
+    /// Refused, with nothing added, if a template uses a parameter its code does not carry.
+    pub fn register_catalog(&mut self, locale: LanguageTag, catalog: Catalog) -> Result<(), String> {
+        for (code, template) in &catalog {
+            if let Some(name) = placeholders(template).into_iter().find(|n| !code.params().contains(n)) {
+                return Err(format!("{locale} message for {code} uses {{{name}}}, which {code} does not carry"));
+            }
+        }
+        self.catalogs.entry(locale).or_default().extend(catalog);
+        Ok(())
+    }
+
+    /// Registered locales, sorted.
+    pub fn locales(&self) -> Vec<&LanguageTag> {
+        self.catalogs.keys().collect()
+    }
+
+    /// The template `code` renders with in `locale`: the exact tag, then the bare language,
+    /// then English.
+    pub fn template(&self, code: ReasonCode, locale: &LanguageTag) -> Option<&str> {
+        [locale.clone(), locale.primary()]
+            .iter()
+            .find_map(|tag| self.catalogs.get(tag)?.get(&code).map(String::as_str))
+            .or_else(|| english(code))
+    }
+
+    /// `code` with `params` in `locale`, or `placeholder(code)` if no catalog has it.
+    pub fn render(&self, code: ReasonCode, params: &BTreeMap<String, String>, locale: &LanguageTag) -> String {
+        match self.template(code, locale) {
+            Some(template) => fill(template, params),
+            None => placeholder(code),
+        }
+    }
+
+    pub fn render_reason(&self, reason: &Reason, locale: &LanguageTag) -> String {
+        self.render(reason.code, &reason.params, locale)
+    }
+}
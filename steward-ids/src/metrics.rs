@@ -0,0 +1,93 @@
+// path: steward-ids/src/metrics.rs
+
+//! Custom impact dimensions.
+//! - `MetricValue`: a measured float plus the unit it is measured in
+//! - `CustomMetricSpec`: what a ledger or shard policy accepts for one `MetricKey` (unit, range)
+//! - Maps are `BTreeMap`s keyed by `MetricKey`, so encodings and hashes see them in key order
+//! - Keys nobody registered are refused, so a claim cannot invent its own dimension
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::MetricKey;
+
+/// Custom dimensions carried by one set of impact metrics.
+pub type CustomMetrics = BTreeMap<MetricKey, MetricValue>;
+
+/// Registered custom dimensions and their bounds.
+pub type CustomMetricRegistry = BTreeMap<MetricKey, CustomMetricSpec>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricValue {
+    pub value: f64,
+    /// Must equal the registered unit, e.g. `"kg"`.
+    pub unit: String,
+}
+
+impl MetricValue {
+    pub fn new(value: f64, unit: impl Into<String>) -> Self {
+        Self { value, unit: unit.into() }
+    }
+}
+
+/// Registration of one custom metric.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CustomMetricSpec {
+    pub unit: String,
+    /// Inclusive bounds for a single value.
+    pub min: f64,
+    pub max: f64,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl CustomMetricSpec {
+    /// The spec itself: a unit, and finite bounds with `min <= max`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.unit.trim().is_empty() {
+            return Err("Custom metric unit must not be empty".into());
+        }
+        if !self.min.is_finite() || !self.max.is_finite() || self.min > self.max {
+            return Err(format!("Custom metric range [{}, {}] is invalid", self.min, self.max));
+        }
+        Ok(())
+    }
+
+    /// One value against this spec.
+    pub fn check(&self, key: &MetricKey, value: &MetricValue) -> Result<(), String> {
+        if value.unit != self.unit {
+            return Err(format!("Custom metric {key} is measured in {:?}, not {:?}", self.unit, value.unit));
+        }
+        if !value.value.is_finite() || value.value < self.min || value.value > self.max {
+            return Err(format!(
+                "Custom metric {key} = {} is outside [{}, {}]",
+                value.value, self.min, self.max
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Every value in `custom` must be registered and within its spec.
+pub fn check_custom(registry: &CustomMetricRegistry, custom: &CustomMetrics) -> Result<(), String> {
+    for (key, value) in custom {
+        let spec = registry
+            .get(key)
+            .ok_or_else(|| format!("Custom metric {key} is not registered"))?;
+        spec.check(key, value)?;
+    }
+    Ok(())
+}
+
+/// Add `custom` into `totals`, key by key. The first unit seen for a key is kept;
+/// registered values all share it.
+pub fn accumulate(totals: &mut CustomMetrics, custom: &CustomMetrics) {
+    for (key, value) in custom {
+        totals
+            .entry(key.clone())
+            .and_modify(|t| t.value += value.value)
+            .or_insert_with(|| value.clone());
+    }
+}
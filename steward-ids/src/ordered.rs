@@ -0,0 +1,31 @@
+// path: steward-ids/src/ordered.rs
+
+//! Sorted serialization for hash-backed collections.
+//! - `HashSet` / `HashMap` iterate in a per-instance random order, so JSON built from them differs run to run
+//! - `#[serde(serialize_with = "steward_ids::ordered::set")]`: array in `Ord` order
+//! - `#[serde(serialize_with = "steward_ids::ordered::map")]`: object in key order
+//! - Deserialization is untouched; the in-memory types stay `HashSet` / `HashMap`
+
+use serde::{Serialize, Serializer};
+use std::collections::{HashMap, HashSet};
+
+pub fn set<T, H, S>(set: &HashSet<T, H>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + Ord,
+    S: Serializer,
+{
+    let mut items: Vec<&T> = set.iter().collect();
+    items.sort();
+    serializer.collect_seq(items)
+}
+
+pub fn map<K, V, H, S>(map: &HashMap<K, V, H>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize + Ord,
+    V: Serialize,
+    S: Serializer,
+{
+    let mut entries: Vec<(&K, &V)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    serializer.collect_map(entries)
+}
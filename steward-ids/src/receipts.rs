@@ -0,0 +1,132 @@
+// path: steward-ids/src/receipts.rs
+
+//! Vote receipts, shared by the quadratic and the weighted governance engines.
+//! - A `VoteReceipt` holds salted commitments to the voter and their ballot; without the salt,
+//!   which only the voter keeps, it does not say who voted or how
+//! - The ballot's leaf is SHA-256 over both commitments; `CountedBallots` publishes the leaves of
+//!   every counted ballot, sorted, and the Merkle root over them with the finalized outcome
+//! - A ballot replaced or left out of the tally has no leaf, so its receipt fails
+//!   `verify_receipt_inclusion`
+//! - Merkle nodes are SHA-256 over `0x01 || left || right` (children as lowercase hex); an
+//!   unpaired node is carried up a level unchanged
+
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VoteReceipt {
+    pub proposal_id: String,
+    pub voter_commitment: String,
+    pub support_commitment: String,
+    /// When the ballot was cast, on the engine's clock (milliseconds or height).
+    pub cast_at: u64,
+    /// Root of the published tally, once the voter has anchored the receipt to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tally_inclusion_root: Option<String>,
+}
+
+impl VoteReceipt {
+    /// Commit to `voter` casting `support` on `proposal_id`, under the voter's `salt`.
+    pub fn new(proposal_id: &str, voter: &str, support: bool, salt: &str, cast_at: u64) -> Self {
+        let support_flag = if support { "yes" } else { "no" };
+        Self {
+            proposal_id: proposal_id.to_string(),
+            voter_commitment: commitment("vote-receipt/voter", &[proposal_id, voter, salt]),
+            support_commitment: commitment("vote-receipt/support", &[proposal_id, voter, support_flag, salt]),
+            cast_at,
+            tally_inclusion_root: None,
+        }
+    }
+
+    /// The ballot's leaf in `CountedBallots`.
+    pub fn leaf(&self) -> String {
+        ballot_leaf(&self.voter_commitment, &self.support_commitment)
+    }
+
+    /// Whether the receipt is for `voter` casting `support` under `salt`.
+    pub fn opens_to(&self, voter: &str, support: bool, salt: &str) -> bool {
+        let opened = VoteReceipt::new(&self.proposal_id, voter, support, salt, self.cast_at);
+        opened.voter_commitment == self.voter_commitment && opened.support_commitment == self.support_commitment
+    }
+
+    /// Record the root `finalized_tally` was published with; the receipt then verifies only
+    /// against that root.
+    pub fn anchor_to(&mut self, finalized_tally: &CountedBallots) {
+        self.tally_inclusion_root = finalized_tally.root.clone();
+    }
+}
+
+/// Leaves of every ballot a finalized outcome counted, and the Merkle root over them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CountedBallots {
+    pub proposal_id: String,
+    /// Sorted, so the order says nothing about who voted when.
+    pub leaves: Vec<String>,
+    /// `None` when no ballot was counted.
+    pub root: Option<String>,
+}
+
+impl CountedBallots {
+    pub fn new(proposal_id: &str, leaves: impl IntoIterator<Item = String>) -> Self {
+        let mut leaves: Vec<String> = leaves.into_iter().collect();
+        leaves.sort();
+        let root = merkle_root(&leaves);
+        Self { proposal_id: proposal_id.to_string(), leaves, root }
+    }
+}
+
+/// Whether `receipt`'s ballot was counted in `finalized_tally`: its leaf is among the published
+/// leaves, the leaves hash to the published root, and an anchored receipt names that root.
+pub fn verify_receipt_inclusion(receipt: &VoteReceipt, finalized_tally: &CountedBallots) -> bool {
+    if receipt.proposal_id != finalized_tally.proposal_id {
+        return false;
+    }
+    if receipt.tally_inclusion_root.is_some() && receipt.tally_inclusion_root != finalized_tally.root {
+        return false;
+    }
+    merkle_root(&finalized_tally.leaves) == finalized_tally.root && finalized_tally.leaves.contains(&receipt.leaf())
+}
+
+fn ballot_leaf(voter_commitment: &str, support_commitment: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(voter_commitment.as_bytes());
+    hasher.update(support_commitment.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// SHA-256 over `domain` and each part, length-prefixed so parts cannot run together.
+fn commitment(domain: &str, parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(domain.as_bytes());
+    for part in parts {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn node_hash(left: &str, right: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left.as_bytes());
+    hasher.update(right.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn merkle_root(leaves: &[String]) -> Option<String> {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node_hash(left, right),
+                [single] => single.clone(),
+                _ => unreachable!("chunks(2)"),
+            })
+            .collect();
+    }
+    level.pop()
+}
@@ -0,0 +1,351 @@
+// path: steward-ids/src/stream.rs
+
+//! Framed JSON-lines streams, for exports too large to build in memory.
+//! - A stream is a header line, one line per record and a trailing manifest line; writer and
+//!   reader hold one record at a time, however many the stream carries
+//! - The header is always plain JSON and says whether the lines after it are gzip-compressed;
+//!   `Compression::Gzip` needs the `flate2` feature on both ends
+//! - The manifest carries the record count and a chain head: SHA-256 over the previous head
+//!   and each record line, from `STREAM_GENESIS_HASH`. An export cut short has no manifest, or
+//!   one its records do not match, and `StreamReader` fails instead of ending quietly
+//! - Readers also take a whole stream gzipped after the fact, told by the gzip magic bytes
+
+use serde::de::DeserializeOwned;
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::marker::PhantomData;
+
+/// `StreamHeader::format` of every stream.
+pub const STREAM_FORMAT: &str = "steward-stream";
+pub const STREAM_VERSION: u32 = 1;
+/// Chain head of a stream with no records.
+pub const STREAM_GENESIS_HASH: &str = "genesis";
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// How the lines after the header are encoded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip,
+}
+
+impl Compression {
+    /// Whether this build can write and read it.
+    pub fn available(self) -> bool {
+        match self {
+            Compression::None => true,
+            Compression::Gzip => cfg!(feature = "flate2"),
+        }
+    }
+}
+
+/// First line of a stream, never compressed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StreamHeader {
+    pub format: String,
+    pub version: u32,
+    /// What the records are, e.g. `planetary.attestations`.
+    pub kind: String,
+    pub compression: Compression,
+}
+
+/// Last line of a stream, as `{"manifest": ...}`: what the writer wrote.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StreamManifest {
+    pub records: u64,
+    pub chain_head: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Trailer {
+    manifest: StreamManifest,
+}
+
+/// Why a stream could not be read, or an importer refused it.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    /// Missing or malformed header, or another format or version.
+    Header(String),
+    KindMismatch { expected: String, found: String },
+    /// Compressed in a way this build cannot read.
+    CompressionUnavailable(Compression),
+    /// Record `index` (from 0) is not valid JSON for the record type.
+    Record { index: u64, reason: String },
+    /// The input ended without a manifest, after `records` records: the export was cut short.
+    Interrupted { records: u64 },
+    /// The manifest does not match the records before it.
+    ManifestMismatch { manifest: StreamManifest, read: StreamManifest },
+    /// Record `index` read fine, but the importer refused it.
+    Rejected { index: u64, reason: String },
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "Stream read failed: {e}"),
+            StreamError::Header(reason) => write!(f, "Bad stream header: {reason}"),
+            StreamError::KindMismatch { expected, found } => {
+                write!(f, "Stream holds {found} records, expected {expected}")
+            }
+            StreamError::CompressionUnavailable(compression) => {
+                write!(f, "Stream is {compression:?}-compressed; this build cannot decompress it")
+            }
+            StreamError::Record { index, reason } => write!(f, "Record {index}: {reason}"),
+            StreamError::Interrupted { records } => {
+                write!(f, "Stream ends without a manifest after {records} records; the export was interrupted")
+            }
+            StreamError::ManifestMismatch { manifest, read } => write!(
+                f,
+                "Manifest promises {} records with chain head {}, the stream has {} with {}",
+                manifest.records, manifest.chain_head, read.records, read.chain_head
+            ),
+            StreamError::Rejected { index, reason } => write!(f, "Record {index} refused: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+fn chain(head: &str, line: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(head.as_bytes());
+    hasher.update(line);
+    format!("{:x}", hasher.finalize())
+}
+
+enum Sink<W: Write> {
+    Plain(W),
+    #[cfg(feature = "flate2")]
+    Gzip(flate2::write::GzEncoder<W>),
+}
+
+impl<W: Write> Sink<W> {
+    fn writer(&mut self) -> &mut dyn Write {
+        match self {
+            Sink::Plain(w) => w,
+            #[cfg(feature = "flate2")]
+            Sink::Gzip(w) => w,
+        }
+    }
+
+    fn finish(self) -> io::Result<W> {
+        match self {
+            Sink::Plain(w) => Ok(w),
+            #[cfg(feature = "flate2")]
+            Sink::Gzip(w) => w.finish(),
+        }
+    }
+}
+
+/// Writes one stream to `W`, a record at a time; `finish` writes the manifest.
+pub struct StreamWriter<W: Write> {
+    sink: Sink<W>,
+    line: Vec<u8>,
+    records: u64,
+    chain_head: String,
+}
+
+impl<W: Write> StreamWriter<W> {
+    /// Writes the header; fails with `Unsupported` for a compression this build lacks.
+    pub fn new(mut sink: W, kind: &str, compression: Compression) -> io::Result<Self> {
+        if !compression.available() {
+            let message = format!("{compression:?} compression needs the flate2 feature");
+            return Err(io::Error::new(io::ErrorKind::Unsupported, message));
+        }
+        let header =
+            StreamHeader { format: STREAM_FORMAT.into(), version: STREAM_VERSION, kind: kind.into(), compression };
+        serde_json::to_writer(&mut sink, &header)?;
+        sink.write_all(b"\n")?;
+        let sink = match compression {
+            Compression::None => Sink::Plain(sink),
+            #[cfg(feature = "flate2")]
+            Compression::Gzip => Sink::Gzip(flate2::write::GzEncoder::new(sink, flate2::Compression::default())),
+            #[cfg(not(feature = "flate2"))]
+            Compression::Gzip => unreachable!("checked above"),
+        };
+        Ok(Self { sink, line: Vec::new(), records: 0, chain_head: STREAM_GENESIS_HASH.into() })
+    }
+
+    pub fn write_record<T: Serialize + ?Sized>(&mut self, record: &T) -> io::Result<()> {
+        self.line.clear();
+        serde_json::to_writer(&mut self.line, record)?;
+        self.chain_head = chain(&self.chain_head, &self.line);
+        self.records += 1;
+        self.line.push(b'\n');
+        self.sink.writer().write_all(&self.line)
+    }
+
+    /// Records written so far.
+    pub fn records(&self) -> u64 {
+        self.records
+    }
+
+    /// Write the manifest, end the compression and flush.
+    pub fn finish(mut self) -> io::Result<StreamManifest> {
+        let manifest = StreamManifest { records: self.records, chain_head: self.chain_head };
+        let trailer = Trailer { manifest };
+        serde_json::to_writer(self.sink.writer(), &trailer)?;
+        self.sink.writer().write_all(b"\n")?;
+        self.sink.finish()?.flush()?;
+        Ok(trailer.manifest)
+    }
+}
+
+/// Write `records` to `sink` as one stream of `kind`.
+pub fn write_stream<W, T, I>(sink: W, kind: &str, compression: Compression, records: I) -> io::Result<StreamManifest>
+where
+    W: Write,
+    T: Serialize,
+    I: IntoIterator<Item = T>,
+{
+    let mut writer = StreamWriter::new(sink, kind, compression)?;
+    for record in records {
+        writer.write_record(&record)?;
+    }
+    writer.finish()
+}
+
+#[cfg(feature = "flate2")]
+fn gunzip<'r>(source: Box<dyn BufRead + 'r>) -> Result<Box<dyn BufRead + 'r>, StreamError> {
+    Ok(Box::new(io::BufReader::new(flate2::bufread::MultiGzDecoder::new(source))))
+}
+
+#[cfg(not(feature = "flate2"))]
+fn gunzip<'r>(_source: Box<dyn BufRead + 'r>) -> Result<Box<dyn BufRead + 'r>, StreamError> {
+    Err(StreamError::CompressionUnavailable(Compression::Gzip))
+}
+
+/// Reads one stream, a record at a time, holding the next line back until it knows whether it
+/// is the manifest.
+pub struct StreamReader<'r> {
+    source: Box<dyn BufRead + 'r>,
+    header: StreamHeader,
+    /// The line after the current record: a record, or the manifest once the input ends.
+    ahead: Vec<u8>,
+    line: Vec<u8>,
+    records: u64,
+    chain_head: String,
+    done: bool,
+}
+
+impl<'r> StreamReader<'r> {
+    /// Read the header of a stream of `kind`, decompressing as the input or the header says.
+    pub fn open<R: BufRead + 'r>(source: R, kind: &str) -> Result<Self, StreamError> {
+        let mut source: Box<dyn BufRead + 'r> = Box::new(source);
+        if source.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            source = gunzip(source)?;
+        }
+        let mut line = Vec::new();
+        if read_line(&mut source, &mut line, 0)? == 0 {
+            return Err(StreamError::Header("the input is empty".into()));
+        }
+        let header: StreamHeader =
+            serde_json::from_slice(&line).map_err(|e| StreamError::Header(format!("not a stream header: {e}")))?;
+        if header.format != STREAM_FORMAT || header.version != STREAM_VERSION {
+            let (format, version) = (&header.format, header.version);
+            return Err(StreamError::Header(format!("unsupported format {format} version {version}")));
+        }
+        if header.kind != kind {
+            return Err(StreamError::KindMismatch { expected: kind.into(), found: header.kind });
+        }
+        match header.compression {
+            Compression::None => {}
+            Compression::Gzip => source = gunzip(source)?,
+        }
+        let mut ahead = Vec::new();
+        if read_line(&mut source, &mut ahead, 0)? == 0 {
+            return Err(StreamError::Interrupted { records: 0 });
+        }
+        Ok(Self { source, header, ahead, line, records: 0, chain_head: STREAM_GENESIS_HASH.into(), done: false })
+    }
+
+    pub fn header(&self) -> &StreamHeader {
+        &self.header
+    }
+
+    /// Records read so far.
+    pub fn records_read(&self) -> u64 {
+        self.records
+    }
+
+    /// The next record, or `None` once the manifest has been read and checked.
+    pub fn next_record<T: DeserializeOwned>(&mut self) -> Result<Option<T>, StreamError> {
+        if self.done {
+            return Ok(None);
+        }
+        if read_line(&mut self.source, &mut self.line, self.records)? == 0 {
+            self.done = true;
+            let trailer: Trailer =
+                serde_json::from_slice(&self.ahead).map_err(|_| StreamError::Interrupted { records: self.records })?;
+            let read = StreamManifest { records: self.records, chain_head: self.chain_head.clone() };
+            if trailer.manifest != read {
+                return Err(StreamError::ManifestMismatch { manifest: trailer.manifest, read });
+            }
+            return Ok(None);
+        }
+        std::mem::swap(&mut self.ahead, &mut self.line);
+        let index = self.records;
+        self.chain_head = chain(&self.chain_head, &self.line);
+        self.records += 1;
+        serde_json::from_slice(&self.line)
+            .map(Some)
+            .map_err(|e| StreamError::Record { index, reason: e.to_string() })
+    }
+
+    /// The records as an iterator, which ends after the first error.
+    pub fn records<T: DeserializeOwned>(self) -> StreamRecords<'r, T> {
+        StreamRecords { reader: self, failed: false, record: PhantomData }
+    }
+}
+
+/// `StreamReader::records`.
+pub struct StreamRecords<'r, T> {
+    reader: StreamReader<'r>,
+    failed: bool,
+    record: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> Iterator for StreamRecords<'_, T> {
+    type Item = Result<T, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.reader.next_record().transpose();
+        self.failed = matches!(next, Some(Err(_)));
+        next
+    }
+}
+
+/// Read one line into `buf` without its newline; 0 at the end of the input. A compressed
+/// input cut short ends in an `UnexpectedEof`, reported as `Interrupted`.
+fn read_line(source: &mut dyn BufRead, buf: &mut Vec<u8>, records: u64) -> Result<usize, StreamError> {
+    buf.clear();
+    match source.read_until(b'\n', buf) {
+        Ok(read) => {
+            if buf.last() == Some(&b'\n') {
+                buf.pop();
+            }
+            Ok(read)
+        }
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Err(StreamError::Interrupted { records }),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -0,0 +1,106 @@
+// path: steward-invariants/src/element.rs
+
+//! the_element: agents enabling and blocking abilities, governance turns restricting and
+//! unlocking them, and profiles restored from a stream.
+//! - After every step, `TheElement::check_invariants`
+//! - `element.opt_in_respected`: an opt-in ability is never enabled without the opt-in
+//! - `element.self_block_respected`: a self-blocked ability stays unusable, whatever a turn
+//!   unlocks
+
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+use steward_ids::invariants::InvariantViolation;
+use steward_ids::stream::{self, Compression};
+use steward_ids::{CapabilityId, Did};
+use the_element::builders::fixture_element;
+use the_element::{AgentCyberProfile, PROFILE_STREAM_KIND};
+
+use crate::strategies::{agent_profile, capability_set, did, element_capability, ELEMENT_CAPABILITIES};
+
+pub const NAME: &str = "element";
+
+#[derive(Debug, Clone)]
+pub enum ElementOp {
+    Enable { agent: Did, capability: CapabilityId, opt_in: bool },
+    Block { agent: Did, capability: CapabilityId },
+    Turn { agent: Did, restrict: HashSet<CapabilityId>, unlock: HashSet<CapabilityId> },
+    /// Restore a single profile through `restore_profiles`.
+    Restore { profile: AgentCyberProfile },
+}
+
+impl Arbitrary for ElementOp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            4 => (did(), element_capability(), any::<bool>())
+                .prop_map(|(agent, capability, opt_in)| ElementOp::Enable { agent, capability, opt_in }),
+            2 => (did(), element_capability()).prop_map(|(agent, capability)| ElementOp::Block { agent, capability }),
+            2 => (did(), capability_set(&ELEMENT_CAPABILITIES), capability_set(&ELEMENT_CAPABILITIES))
+                .prop_map(|(agent, restrict, unlock)| ElementOp::Turn { agent, restrict, unlock }),
+            1 => agent_profile().prop_map(|profile| ElementOp::Restore { profile }),
+        ]
+        .boxed()
+    }
+}
+
+pub fn run_ops(ops: &[ElementOp]) -> Result<(), InvariantViolation> {
+    let mut element = fixture_element(1);
+    let opt_in: HashSet<CapabilityId> = element
+        .list_abilities()
+        .into_iter()
+        .filter(|listing| listing.ability.require_explicit_opt_in)
+        .map(|listing| listing.ability.id)
+        .collect();
+    for op in ops {
+        match op {
+            ElementOp::Enable { agent, capability, opt_in: given } => {
+                let enabled = element.request_enable(agent, capability, *given).is_ok();
+                if enabled && !given && opt_in.contains(capability) {
+                    let detail = format!("{agent} enabled {capability} without opting in");
+                    return Err(InvariantViolation::new("element.opt_in_respected", detail));
+                }
+            }
+            ElementOp::Block { agent, capability } => {
+                let _ = element.request_block(agent, capability);
+            }
+            ElementOp::Turn { agent, restrict, unlock } => {
+                let blocked = element.get_profile(agent).map(|p| p.blocked_capabilities.clone()).unwrap_or_default();
+                let turn_id = element.next_turn_id();
+                let _ = element.governance_turn(&turn_id, agent, restrict, unlock);
+                if let Some(capability) = unlock.iter().find(|c| blocked.contains(*c) && element.can_use(agent, c)) {
+                    let detail = format!("a turn unlocked {capability}, which {agent} had blocked");
+                    return Err(InvariantViolation::new("element.self_block_respected", detail));
+                }
+            }
+            ElementOp::Restore { profile } => {
+                let mut sink = Vec::new();
+                stream::write_stream(&mut sink, PROFILE_STREAM_KIND, Compression::None, [profile])
+                    .expect("writing to memory");
+                element.restore_profiles(sink.as_slice()).expect("a stream just written restores");
+            }
+        }
+        element.check_invariants()?;
+    }
+    Ok(())
+}
+
+/// Minimal sequences of bugs this suite has found.
+pub fn regressions() -> Vec<Vec<ElementOp>> {
+    let ana = Did::new("did:psv:invariants:ana").expect("valid DID");
+    vec![
+        // `restore_profiles` took a profile without its baseline rights as written, and
+        // `ensure_profile` only granted them to profiles it created, so the restored agent
+        // kept no emergency exit.
+        vec![ElementOp::Restore {
+            profile: AgentCyberProfile {
+                agent: ana,
+                enabled_capabilities: HashSet::new(),
+                blocked_capabilities: HashSet::new(),
+                preferences: serde_json::json!({}),
+            },
+        }],
+    ]
+}
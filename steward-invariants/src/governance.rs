@@ -0,0 +1,275 @@
+// path: steward-invariants/src/governance.rs
+
+//! cybernetic-governance: proposals committed with caller-supplied outcomes or votes tallied
+//! by the engine, across the BCI/XR arena and a categorized league domain, with sunsets
+//! lapsing as the height advances.
+//! - After every step, `CapabilityGovernance::check_invariants`
+//! - `governance.supermajority_respected`: nothing is applied below the proposal's required
+//!   supermajority
+//! - `governance.rejected_unchanged`: a commit that errs or does not pass changes no domain
+//! - `governance.history_ordered`: every domain's history is in height order, and an applied
+//!   proposal's records are the latest entries of their domains' histories
+//! - `governance.replay_idempotent`: committing an applied proposal again reports
+//!   `AlreadyCommitted` with the original records and changes no state or history
+
+use cybernetic_governance::builders::{bci_xr_arena, vote, DomainBuilder, ProposalBuilder, BCI_XR_ARENA};
+use cybernetic_governance::{
+    AppliedChange, CapabilityGovernance, CommitOutcome, GovernanceProposal, GovernanceVoteOutcome, VoterId,
+    VoterRegistry,
+};
+use proptest::prelude::*;
+use steward_ids::invariants::InvariantViolation;
+
+use crate::strategies::vote_weights;
+
+pub const NAME: &str = "governance";
+
+pub const LEAGUE: &str = "league:phoenix:bci_xr_season";
+
+/// Capabilities proposals name: both domains' own, and one neither allows.
+pub const GOVERNANCE_CAPABILITIES: [&str; 9] = [
+    "safety:emergency_stop",
+    "safety:session_exit",
+    "access:baseline_play",
+    "research:noninvasive_bci",
+    "move:bci_push",
+    "move:bci_pull",
+    "move:bci_shield",
+    "move:xr_dash",
+    "move:unlisted",
+];
+
+/// Voters of engine-tallied proposals, with their weights.
+const VOTERS: [(&str, u128); 3] = [("voter:ana", 1), ("voter:ben", 2), ("voter:kofi", 3)];
+
+const START_HEIGHT: u64 = 1_000;
+
+/// Supermajority `ProposalBuilder` asks for unless told otherwise.
+const REQUIRED_SUPERMAJORITY: f64 = 0.75;
+
+#[derive(Debug, Clone)]
+pub enum GovernanceOp {
+    /// Commit with a caller-supplied outcome finalized at the current height.
+    Commit {
+        domain: &'static str,
+        restrict: Vec<&'static str>,
+        protect: Vec<&'static str>,
+        yes: u128,
+        no: u128,
+        sunset_after: Option<u64>,
+    },
+    /// Open a vote, cast one ballot per voter who turns up, close and commit it.
+    Tallied {
+        domain: &'static str,
+        restrict: Vec<&'static str>,
+        protect: Vec<&'static str>,
+        ballots: Vec<Option<bool>>,
+    },
+    Advance { heights: u64 },
+}
+
+fn domain() -> impl Strategy<Value = &'static str> {
+    prop::sample::select(vec![BCI_XR_ARENA, LEAGUE])
+}
+
+fn capabilities() -> impl Strategy<Value = Vec<&'static str>> {
+    prop::sample::subsequence(&GOVERNANCE_CAPABILITIES[..], 0..=3)
+}
+
+impl Arbitrary for GovernanceOp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            4 => (domain(), capabilities(), capabilities(), vote_weights(), prop::option::of(1u64..50))
+                .prop_map(|(domain, restrict, protect, (yes, no), sunset_after)| {
+                    GovernanceOp::Commit { domain, restrict, protect, yes, no, sunset_after }
+                }),
+            2 => (domain(), capabilities(), capabilities(), prop::collection::vec(any::<Option<bool>>(), VOTERS.len()))
+                .prop_map(|(domain, restrict, protect, ballots)| {
+                    GovernanceOp::Tallied { domain, restrict, protect, ballots }
+                }),
+            2 => (1u64..40).prop_map(|heights| GovernanceOp::Advance { heights }),
+        ]
+        .boxed()
+    }
+}
+
+/// The arena plus a league domain keeping two of its four moves enabled.
+fn engine() -> CapabilityGovernance {
+    let mut gov = bci_xr_arena();
+    let league = DomainBuilder::new(LEAGUE)
+        .description("Phoenix BCI/XR season league")
+        .category("moves", &["move:bci_push", "move:bci_pull", "move:bci_shield", "move:xr_dash"])
+        .category("safety", &["safety:emergency_stop", "safety:session_exit"])
+        .category_floor("moves", 2)
+        .min_capability_count(4)
+        .build_valid();
+    gov.upsert_domain(league).expect("league domain is valid");
+    gov
+}
+
+fn voters() -> VoterRegistry {
+    let mut voters = VoterRegistry::new();
+    for (name, weight) in VOTERS {
+        let voter = VoterId(name.into());
+        voters.register(voter.clone(), weight);
+        for domain_id in [BCI_XR_ARENA, LEAGUE] {
+            voters.set_eligibility(&voter, domain_id, true).expect("registered above");
+        }
+    }
+    voters
+}
+
+pub fn run_ops(ops: &[GovernanceOp]) -> Result<(), InvariantViolation> {
+    let mut gov = engine();
+    let voters = voters();
+    let mut height = START_HEIGHT;
+    for (step, op) in ops.iter().enumerate() {
+        let proposal_id = format!("prop-{step}");
+        let disabled_before = disabled(&gov);
+        // What a replay commits again: the proposal, and its outcome unless the engine tallied it.
+        let (committed, yes_ratio, replay) = match op {
+            GovernanceOp::Commit { domain, restrict, protect, yes, no, sunset_after } => {
+                let mut builder = proposal(&proposal_id, domain, restrict, protect, height);
+                if let Some(after) = sunset_after {
+                    builder = builder.sunset_height(height + after);
+                }
+                let (proposal, outcome) = (builder.build(), vote(&proposal_id, *yes, *no, height));
+                let ratio = (*yes as f64) / ((*yes + *no).max(1) as f64);
+                (gov.commit_proposal(&proposal, &outcome, height), ratio, Some((proposal, Some(outcome))))
+            }
+            GovernanceOp::Tallied { domain, restrict, protect, ballots } => {
+                let proposal = proposal(&proposal_id, domain, restrict, protect, height).build();
+                gov.open_vote(&proposal, voters.snapshot(domain)).expect("proposal ids are fresh");
+                let (mut yes, mut no) = (0, 0);
+                for ((name, weight), ballot) in VOTERS.iter().zip(ballots) {
+                    if let Some(support) = ballot {
+                        let voter = VoterId((*name).into());
+                        gov.cast(&proposal_id, &voter, *support).expect("eligible voter, first ballot");
+                        if *support { yes += weight } else { no += weight }
+                    }
+                }
+                gov.close_vote(&proposal_id, height).expect("opened above");
+                let ratio = (yes as f64) / ((yes + no).max(1) as f64);
+                (gov.commit_tallied_proposal(&proposal, height), ratio, Some((proposal, None)))
+            }
+            GovernanceOp::Advance { heights } => {
+                height += heights;
+                gov.advance_height(height);
+                (Ok(CommitOutcome::NotPassed), 0.0, None)
+            }
+        };
+        match committed {
+            Ok(CommitOutcome::Applied(_)) if yes_ratio < REQUIRED_SUPERMAJORITY => {
+                let detail = format!("{proposal_id} applied with a yes ratio of {yes_ratio}");
+                return Err(InvariantViolation::new("governance.supermajority_respected", detail));
+            }
+            Ok(CommitOutcome::Applied(changes)) => {
+                check_latest(&gov, &proposal_id, &changes)?;
+                if let Some((proposal, outcome)) = replay {
+                    check_replay(&mut gov, &proposal, outcome.as_ref(), &changes, height)?;
+                }
+            }
+            _ if !matches!(op, GovernanceOp::Advance { .. }) && disabled(&gov) != disabled_before => {
+                let detail = format!("{proposal_id} was not applied, yet the disabled capabilities changed");
+                return Err(InvariantViolation::new("governance.rejected_unchanged", detail));
+            }
+            _ => {}
+        }
+        check_history_ordered(&gov)?;
+        gov.check_invariants()?;
+    }
+    Ok(())
+}
+
+/// Each applied record is the last entry of its domain's history.
+fn check_latest(
+    gov: &CapabilityGovernance,
+    proposal_id: &str,
+    changes: &[AppliedChange],
+) -> Result<(), InvariantViolation> {
+    for change in changes {
+        let latest = gov.change_history(&change.domain_id).last();
+        if latest.map(|c| (c.proposal_id.as_str(), c.height)) != Some((proposal_id, change.height)) {
+            let detail = format!("{proposal_id} applied, but is not the latest change of {}", change.domain_id);
+            return Err(InvariantViolation::new("governance.history_ordered", detail));
+        }
+    }
+    Ok(())
+}
+
+fn check_history_ordered(gov: &CapabilityGovernance) -> Result<(), InvariantViolation> {
+    for domain_id in [BCI_XR_ARENA, LEAGUE] {
+        let history = gov.change_history(domain_id);
+        if let Some(pair) = history.windows(2).find(|pair| pair[0].height > pair[1].height) {
+            let detail = format!(
+                "{domain_id}: {} at height {} recorded after {} at height {}",
+                pair[1].proposal_id, pair[1].height, pair[0].proposal_id, pair[0].height
+            );
+            return Err(InvariantViolation::new("governance.history_ordered", detail));
+        }
+    }
+    Ok(())
+}
+
+/// Commit an applied proposal again: the originals come back and nothing moves.
+fn check_replay(
+    gov: &mut CapabilityGovernance,
+    proposal: &GovernanceProposal,
+    outcome: Option<&GovernanceVoteOutcome>,
+    changes: &[AppliedChange],
+    height: u64,
+) -> Result<(), InvariantViolation> {
+    let (disabled_before, history_before) = (disabled(gov), history_lengths(gov));
+    let replayed = match outcome {
+        Some(outcome) => gov.commit_proposal(proposal, outcome, height),
+        None => gov.commit_tallied_proposal(proposal, height),
+    };
+    let id = &proposal.proposal_id;
+    let detail = match replayed {
+        Ok(CommitOutcome::AlreadyCommitted(originals)) if json(&originals) != json(changes) => {
+            format!("{id} replayed with records other than those it applied")
+        }
+        Ok(CommitOutcome::AlreadyCommitted(_)) if disabled(gov) != disabled_before => {
+            format!("{id} replayed, and the disabled capabilities changed")
+        }
+        Ok(CommitOutcome::AlreadyCommitted(_)) if history_lengths(gov) != history_before => {
+            format!("{id} replayed, and the history grew")
+        }
+        Ok(CommitOutcome::AlreadyCommitted(_)) => return Ok(()),
+        other => format!("{id} replayed as {other:?}"),
+    };
+    Err(InvariantViolation::new("governance.replay_idempotent", detail))
+}
+
+fn history_lengths(gov: &CapabilityGovernance) -> [usize; 2] {
+    [BCI_XR_ARENA, LEAGUE].map(|domain_id| gov.change_history(domain_id).len())
+}
+
+fn json(changes: &[AppliedChange]) -> serde_json::Value {
+    serde_json::to_value(changes).expect("applied changes serialize")
+}
+
+fn proposal(id: &str, domain: &str, restrict: &[&str], protect: &[&str], height: u64) -> ProposalBuilder {
+    ProposalBuilder::new(id, domain).restrict(restrict).protect(protect).activation_height(height)
+}
+
+/// Each domain's disabled capabilities, sorted.
+fn disabled(gov: &CapabilityGovernance) -> Vec<Vec<String>> {
+    [BCI_XR_ARENA, LEAGUE]
+        .iter()
+        .map(|domain_id| {
+            let state = gov.get_domain_state(domain_id).expect("both domains are registered");
+            let mut caps: Vec<String> = state.disabled_capabilities.iter().map(|c| c.0.clone()).collect();
+            caps.sort();
+            caps
+        })
+        .collect()
+}
+
+/// Minimal sequences of bugs this suite has found.
+pub fn regressions() -> Vec<Vec<GovernanceOp>> {
+    Vec::new()
+}
@@ -0,0 +1,104 @@
+// path: steward-invariants/src/karma.rs
+
+//! aln-karma: allowances appended to two vNodes' hash chains, some linked to a stale or
+//! missing predecessor and some altered after hashing, and recorded allowances invalidated.
+//! - After every step, `KarmaLedger::check_invariants`
+//! - `karma.append_guarded`: `append` accepts exactly the untampered allowances that link to
+//!   their vNode's latest
+
+use std::collections::BTreeMap;
+
+use aln_karma::builders::{smart_city_mobility_policy, ManifestBuilder, FIXTURE_EPOCH_SECONDS, FIXTURE_START_MS};
+use aln_karma::{ImpactMetrics, KarmaLedger};
+use proptest::prelude::*;
+use steward_ids::invariants::InvariantViolation;
+
+use crate::strategies::{impact_metrics, VNODES};
+
+pub const NAME: &str = "karma";
+
+/// Which predecessor an appended allowance names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Link {
+    Latest,
+    Genesis,
+    /// The predecessor of the vNode's latest, when it has one.
+    Stale,
+}
+
+#[derive(Debug, Clone)]
+pub enum KarmaOp {
+    Append { vnode: &'static str, link: Link, tampered: bool, metrics: ImpactMetrics },
+    /// Invalidate the recorded allowance at `index` modulo the number recorded.
+    Invalidate { index: usize },
+}
+
+impl Arbitrary for KarmaOp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        let link = prop_oneof![6 => Just(Link::Latest), 1 => Just(Link::Genesis), 1 => Just(Link::Stale)];
+        prop_oneof![
+            5 => (prop::sample::select(&VNODES[..]), link, prop::bool::weighted(0.1), impact_metrics())
+                .prop_map(|(vnode, link, tampered, metrics)| KarmaOp::Append { vnode, link, tampered, metrics }),
+            1 => any::<usize>().prop_map(|index| KarmaOp::Invalidate { index }),
+        ]
+        .boxed()
+    }
+}
+
+pub fn run_ops(ops: &[KarmaOp]) -> Result<(), InvariantViolation> {
+    let policy = smart_city_mobility_policy();
+    let mut ledger = KarmaLedger::new();
+    // Each vNode's self_hashes, in append order.
+    let mut chains: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (step, op) in ops.iter().enumerate() {
+        match op {
+            KarmaOp::Append { vnode, link, tampered, metrics } => {
+                let chain = chains.entry(*vnode).or_default();
+                let prev = match link {
+                    Link::Latest => chain.last().cloned(),
+                    Link::Genesis => None,
+                    Link::Stale => chain.len().checked_sub(2).map(|i| chain[i].clone()),
+                };
+                let epoch = chain.len() as u64;
+                let manifest = ManifestBuilder::new(vnode)
+                    .metrics(metrics.clone())
+                    .at_ms(FIXTURE_START_MS + epoch * FIXTURE_EPOCH_SECONDS * 1_000)
+                    .seed(step as u64)
+                    .build();
+                let Some(mut allowance) = manifest.to_karma_allowance(&policy, prev.clone(), 10.0, 0.01, 2.5) else {
+                    continue;
+                };
+                if *tampered {
+                    allowance.au_et_delta += 1.0;
+                }
+                let self_hash = allowance.self_hash.clone();
+                let expected = !tampered && prev.as_ref() == chain.last();
+                let appended = ledger.append(allowance).is_ok();
+                if appended != expected {
+                    let detail = format!("append of step {step} returned {appended}, expected {expected}");
+                    return Err(InvariantViolation::new("karma.append_guarded", detail));
+                }
+                if appended {
+                    chain.push(self_hash);
+                }
+            }
+            KarmaOp::Invalidate { index } => {
+                let recorded = ledger.allowances().len();
+                if recorded > 0 {
+                    let id = ledger.allowances()[index % recorded].id;
+                    let _ = ledger.invalidate(id, "generated invalidation", FIXTURE_START_MS);
+                }
+            }
+        }
+        ledger.check_invariants()?;
+    }
+    Ok(())
+}
+
+/// Minimal sequences of bugs this suite has found.
+pub fn regressions() -> Vec<Vec<KarmaOp>> {
+    Vec::new()
+}
@@ -0,0 +1,86 @@
+// path: steward-invariants/src/ledger.rs
+
+//! planetary_stewardship_runtime: PLGA consent granted and withdrawn, attestations issued,
+//! and withdrawals tombstoning the withdrawn actor's records as they are recorded.
+//! - After every step, `PlanetaryLedger::check_invariants`
+//! - `ledger.consent_precedes_issuance`: an attestation is only issued to an actor holding
+//!   valid PLGA consent
+
+use std::sync::Arc;
+
+use planetary_stewardship_runtime::builders::{
+    AttestationBuilder, ConsentRecordBuilder, LedgerBuilder, FIXTURE_START_MS,
+};
+use planetary_stewardship_runtime::{PlanetaryLedger, RetentionPolicy, StewardModule};
+use proptest::prelude::*;
+use steward_ids::invariants::InvariantViolation;
+use steward_ids::Did;
+use steward_runtime_support::{Clock, FixedClock};
+
+use crate::strategies::did;
+
+pub const NAME: &str = "ledger";
+
+#[derive(Debug, Clone)]
+pub enum LedgerOp {
+    Grant { actor: Did },
+    Withdraw { actor: Did },
+    Issue { actor: Did, co2eq_reduced: f64 },
+    Advance { ms: u64 },
+}
+
+impl Arbitrary for LedgerOp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            2 => did().prop_map(|actor| LedgerOp::Grant { actor }),
+            1 => did().prop_map(|actor| LedgerOp::Withdraw { actor }),
+            4 => (did(), 0.01f64..2.0).prop_map(|(actor, co2eq_reduced)| LedgerOp::Issue { actor, co2eq_reduced }),
+            1 => (1u64..3_600_000).prop_map(|ms| LedgerOp::Advance { ms }),
+        ]
+        .boxed()
+    }
+}
+
+pub fn run_ops(ops: &[LedgerOp]) -> Result<(), InvariantViolation> {
+    let clock = Arc::new(FixedClock::new(FIXTURE_START_MS));
+    let mut ledger: PlanetaryLedger = LedgerBuilder::new()
+        .clock(clock.clone())
+        .build()
+        .with_retention_policy(RetentionPolicy::Tombstone)
+        .with_retention_on_withdrawal();
+    for op in ops {
+        let now_ms = clock.now_ms();
+        match op {
+            LedgerOp::Grant { actor } => {
+                let record = ConsentRecordBuilder::new(actor, StewardModule::PLGA).at_ms(now_ms).build();
+                ledger.consent_mut().upsert_consent(record);
+            }
+            LedgerOp::Withdraw { actor } => {
+                let record = ConsentRecordBuilder::new(actor, StewardModule::PLGA).at_ms(now_ms).withdrawn().build();
+                ledger.consent_mut().upsert_consent(record);
+            }
+            LedgerOp::Issue { actor, co2eq_reduced } => {
+                let consented = ledger.consent().has_valid_consent(actor, StewardModule::PLGA, None);
+                let issued = AttestationBuilder::new(actor)
+                    .co2eq_reduced(*co2eq_reduced)
+                    .timestamp_ms(now_ms)
+                    .issue_on(&mut ledger);
+                if let (Ok(attestation), false) = (&issued, consented) {
+                    let detail = format!("{} issued to {actor} without consent", attestation.id.0);
+                    return Err(InvariantViolation::new("ledger.consent_precedes_issuance", detail));
+                }
+            }
+            LedgerOp::Advance { ms } => clock.advance(*ms),
+        }
+        ledger.check_invariants()?;
+    }
+    Ok(())
+}
+
+/// Minimal sequences of bugs this suite has found.
+pub fn regressions() -> Vec<Vec<LedgerOp>> {
+    Vec::new()
+}
@@ -0,0 +1,53 @@
+// path: steward-invariants/src/main.rs
+
+//! Runs every invariant suite: recorded regressions first, then generated sequences.
+//! - `--cases N` sequences per suite (default 256), `--seed N` (default `DEFAULT_SEED`)
+//! - Optional positional arguments select suites by name
+//! - A failure prints the violated invariant and the shrunk sequence that shows it
+
+use std::process::ExitCode;
+
+use steward_invariants::{all_suites, SuiteConfig};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut config = SuiteConfig::default();
+    let mut selected = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = |name: &str, value: Option<&String>| -> u64 {
+            match value.and_then(|v| v.parse().ok()) {
+                Some(v) => v,
+                None => {
+                    eprintln!("{name} needs a number");
+                    std::process::exit(2);
+                }
+            }
+        };
+        match arg.as_str() {
+            "--cases" => config.cases = value("--cases", args.next()) as u32,
+            "--seed" => config.seed = value("--seed", args.next()),
+            name => selected.push(name),
+        }
+    }
+
+    let mut failures = 0;
+    for suite in all_suites() {
+        if !selected.is_empty() && !selected.contains(&suite.name) {
+            continue;
+        }
+        match (suite.run)(&config) {
+            Ok(()) => println!("ok   {}", suite.name),
+            Err(failure) => {
+                eprintln!("FAIL {}: {failure}", suite.name);
+                failures += 1;
+            }
+        }
+    }
+
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,84 @@
+// path: steward-invariants/src/runner.rs
+
+//! Runs one suite: its regressions first, then generated sequences, shrinking the first that
+//! fails.
+
+use std::fmt;
+
+use proptest::prelude::*;
+use proptest::test_runner::{Config, RngAlgorithm, TestError, TestRng, TestRunner};
+use steward_ids::invariants::InvariantViolation;
+
+/// How many sequences to generate, how long they get and what seeds them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuiteConfig {
+    pub cases: u32,
+    pub max_ops: usize,
+    pub seed: u64,
+}
+
+impl Default for SuiteConfig {
+    fn default() -> Self {
+        Self { cases: 256, max_ops: 32, seed: crate::DEFAULT_SEED }
+    }
+}
+
+/// The first violation a suite found, and the smallest sequence that still shows it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuiteFailure {
+    pub reason: String,
+    /// `Debug` of the operations, one per line.
+    pub minimal_sequence: Vec<String>,
+    /// Whether the sequence is a recorded regression rather than a generated case.
+    pub regression: bool,
+}
+
+impl fmt::Display for SuiteFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let origin = if self.regression { "regression" } else { "minimal sequence" };
+        writeln!(f, "{}", self.reason)?;
+        write!(f, "{origin} ({} ops):", self.minimal_sequence.len())?;
+        for op in &self.minimal_sequence {
+            write!(f, "\n  {op}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SuiteFailure {}
+
+/// Replay `regressions`, then run `config.cases` generated sequences through `run_ops`.
+pub fn check<Op>(
+    config: &SuiteConfig,
+    regressions: Vec<Vec<Op>>,
+    run_ops: fn(&[Op]) -> Result<(), InvariantViolation>,
+) -> Result<(), SuiteFailure>
+where
+    Op: Arbitrary + fmt::Debug + Clone,
+{
+    for ops in regressions {
+        if let Err(violation) = run_ops(&ops) {
+            return Err(failure(violation.to_string(), &ops, true));
+        }
+    }
+    let mut seed = [0u8; 32];
+    seed[..8].copy_from_slice(&config.seed.to_le_bytes());
+    let mut runner = TestRunner::new_with_rng(
+        Config { cases: config.cases, failure_persistence: None, ..Config::default() },
+        TestRng::from_seed(RngAlgorithm::ChaCha, &seed),
+    );
+    let sequences = prop::collection::vec(any::<Op>(), 1..=config.max_ops.max(1));
+    match runner.run(&sequences, |ops| run_ops(&ops).map_err(|v| TestCaseError::fail(v.to_string()))) {
+        Ok(()) => Ok(()),
+        Err(TestError::Fail(reason, ops)) => Err(failure(reason.message().to_string(), &ops, false)),
+        Err(TestError::Abort(reason)) => Err(SuiteFailure {
+            reason: format!("aborted: {}", reason.message()),
+            minimal_sequence: Vec::new(),
+            regression: false,
+        }),
+    }
+}
+
+fn failure<Op: fmt::Debug>(reason: String, ops: &[Op], regression: bool) -> SuiteFailure {
+    SuiteFailure { reason, minimal_sequence: ops.iter().map(|op| format!("{op:?}")).collect(), regression }
+}
@@ -0,0 +1,71 @@
+// path: steward-invariants/src/strategies.rs
+
+//! Value strategies the suites' operations draw from.
+//! - Agents, capabilities and vNodes come from small fixed pools, so generated operations keep
+//!   landing on the same records instead of each touching a fresh one
+//! - Composite values go through the crates' own types and builders, never hand-rolled JSON
+
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+use steward_ids::{CapabilityId, Did};
+use the_element::AgentCyberProfile;
+
+/// Agents every suite draws from.
+pub const AGENTS: [&str; 3] = ["did:psv:invariants:ana", "did:psv:invariants:ben", "did:psv:invariants:kofi"];
+
+/// Every ability of `the_element::default_element`, baseline rights first.
+pub const ELEMENT_CAPABILITIES: [&str; 10] = [
+    "meta:introspect_state",
+    "meta:emergency_exit",
+    "meta:pause_augmentation",
+    "security:neuroshield_basic",
+    "cognitive:focus_enhancer",
+    "cognitive:pattern_assist",
+    "motor:emg_calibration",
+    "motor:bci_motor_calibration",
+    "motor:exoskeleton_assist",
+    "sensory:xr_overlay_competitive",
+];
+
+/// vNodes the karma suite appends to.
+pub const VNODES: [&str; 2] = ["city:phoenix:traffic:controller-01", "city:phoenix:grid:substation-07"];
+
+pub fn did() -> impl Strategy<Value = Did> {
+    prop::sample::select(&AGENTS[..]).prop_map(|s| Did::new(s).expect("pool DIDs are valid"))
+}
+
+pub fn element_capability() -> impl Strategy<Value = CapabilityId> {
+    prop::sample::select(&ELEMENT_CAPABILITIES[..]).prop_map(|s| CapabilityId::new(s).expect("pool ids are valid"))
+}
+
+/// Any subset of `pool`, as capability ids.
+pub fn capability_set(pool: &'static [&'static str]) -> impl Strategy<Value = HashSet<CapabilityId>> {
+    prop::sample::subsequence(pool, 0..=pool.len())
+        .prop_map(|ids| ids.into_iter().map(|s| CapabilityId::new(s).expect("pool ids are valid")).collect())
+}
+
+/// A profile as `export_profiles` could have written it: what it enables and what it blocks
+/// never overlap, but nothing guarantees it holds every baseline right.
+pub fn agent_profile() -> impl Strategy<Value = AgentCyberProfile> {
+    (did(), capability_set(&ELEMENT_CAPABILITIES), capability_set(&ELEMENT_CAPABILITIES)).prop_map(
+        |(agent, enabled, blocked)| AgentCyberProfile {
+            agent,
+            enabled_capabilities: enabled.difference(&blocked).cloned().collect(),
+            blocked_capabilities: blocked,
+            preferences: serde_json::json!({}),
+        },
+    )
+}
+
+/// Yes and no weights of a caller-supplied vote outcome.
+pub fn vote_weights() -> impl Strategy<Value = (u128, u128)> {
+    (0u128..=1_000, 0u128..=1_000)
+}
+
+/// Epoch metrics from an idle epoch up to a busy one.
+pub fn impact_metrics() -> impl Strategy<Value = aln_karma::ImpactMetrics> {
+    (0.0f64..5.0, 0.0f64..500.0, 0u64..20).prop_map(|(t_co2e_avoided, kwh_reduced, near_misses_blocked)| {
+        aln_karma::ImpactMetrics { t_co2e_avoided, kwh_reduced, near_misses_blocked, ..Default::default() }
+    })
+}
@@ -0,0 +1,160 @@
+// path: steward-runtime-support/examples/deterministic_replay.rs
+
+//! Example: replaying a cross-crate scenario with injected clocks and ids.
+//! - aln-karma: epoch window + SafetyEpochManifest, its allowance, and a forecast
+//!   reconciled against it
+//! - planetary_stewardship_runtime: PLGA attestation backed by the manifest,
+//!   issued under a rollback plan for a reserved id
+//! - planetary governance: proposal citing the attestation
+//!
+//! Two runs with the same seed must serialize byte-for-byte identically.
+
+use std::sync::Arc;
+
+use aln_karma::{
+    epoch_window_at, reconcile_with_ids, BaselineModel, EpochForecast, ImpactMetrics as KarmaMetrics,
+    JusticeConstraints, PricingTable, SafetyEpochManifest, ShardPolicy, VNodeId,
+};
+use planetary_stewardship_runtime::{
+    ActionRef, ConsentRecord, ConsentRegistry, Did, GovernanceEngine, GovernanceProposal, GovernanceScope,
+    ImpactMetrics, ModuleId, PlanId, PlanetaryLedger, RollbackPlan, SaepConfig, SaepEngine, SaepRefs,
+    StewardModule,
+};
+use steward_runtime_support::{Clock, FixedClock, IdGenerator, SequentialIdGenerator, SteppingClock};
+
+fn run(seed: u64) -> String {
+    let ids = Arc::new(SequentialIdGenerator::new(seed));
+    let wall = FixedClock::new(1_767_225_600_000); // 2026-01-01T00:00:00Z
+
+    // 1. Manifest for the current 15-minute epoch.
+    let (epoch_start, epoch_end) = epoch_window_at(&wall, 900);
+    let manifest = SafetyEpochManifest::new_with_ids(
+        ids.as_ref(),
+        VNodeId {
+            vnode_id: "city:phoenix:traffic:controller-01".into(),
+            policy_shard_id: "policy:aln:mobility:v1".into(),
+        },
+        epoch_start,
+        epoch_end,
+        KarmaMetrics {
+            t_co2e_avoided: 2.7,
+            kwh_reduced: 0.0,
+            pollution_exposure_delta: -1_500.0,
+            near_misses_blocked: 7,
+            biosafety_delta: 0.12,
+            custom: Default::default(),
+        },
+        BaselineModel {
+            description: "Phoenix SOV baseline, peak hour".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: None,
+        },
+        JusticeConstraints {
+            forbid_burden_shifting: true,
+            require_opt_out_respected: true,
+        },
+        "merkle-root-vnode-log-0xabc".into(),
+        vec!["city_sensors://phoenix/pm25".into()],
+        None,
+    );
+    let policy = ShardPolicy {
+        policy_shard_id: manifest.vnode.policy_shard_id.clone(),
+        allow_near_miss_summary: true,
+        ..ShardPolicy::default()
+    };
+    let pricing = PricingTable::new(10.0, 0.01, 2.5);
+    let allowance = manifest.to_karma_allowance_with_ids(ids.as_ref(), &policy, None, &pricing).expect("eligible");
+    let forecast_metrics = KarmaMetrics { t_co2e_avoided: 3.0, ..manifest.metrics.clone() };
+    let (vnode, model) = (manifest.vnode.clone(), "corridor-model v1".to_string());
+    let forecast = EpochForecast::new_with_ids(ids.as_ref(), vnode, epoch_start, epoch_end, forecast_metrics, model);
+    let reconciliation = reconcile_with_ids(ids.as_ref(), &forecast, &manifest, 0.5).expect("same vNode and epoch");
+
+    // 2. Attestation for the steward who operated the vNode.
+    let steward = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mut consent = ConsentRegistry::new();
+    consent.upsert_consent(ConsentRecord {
+        participant: steward.clone(),
+        module: StewardModule::PLGA,
+        mission: None,
+        consent_given: true,
+        timestamp_ms: wall.now_ms(),
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    });
+    let ledger_clock = Arc::new(SteppingClock::new(wall.now_ms(), 1_000));
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(SaepConfig::default()),
+        consent,
+        ledger_clock.clone(),
+        ids.clone(),
+    );
+    let reserved = ledger.reserve_attestation_id();
+    let plan_id = ledger
+        .register_rollback_plan(RollbackPlan {
+            id: PlanId(format!("rollback:{}", reserved.0)),
+            action_ref: ActionRef::Attestation(reserved),
+            steps: vec!["Revoke the attestation".into()],
+            responsible: steward.clone(),
+            created_ms: ledger_clock.now_ms(),
+        })
+        .expect("reserved id");
+    let attestation = ledger
+        .issue_attestation_with_refs(
+            steward,
+            None,
+            "Peak-hour rerouting reduced corridor emissions".into(),
+            ImpactMetrics {
+                co2eq_reduced: manifest.net_t_co2e_avoided(),
+                biodiversity_index_delta: 0.0,
+                biodiversity_detail: None,
+                restored_area_m2: 0.0,
+                avoided_emissions_co2eq: manifest.net_t_co2e_avoided(),
+                custom: Default::default(),
+            },
+            format!("manifest://{}", manifest.self_hash),
+            vec![],
+            ledger_clock.now_ms(),
+            SaepRefs { intent_entry_id: None, rollback_plan_id: Some(plan_id) },
+        )
+        .expect("attestation issued");
+
+    // 3. Governance proposal citing the attestation.
+    let proposal = GovernanceProposal {
+        proposal_id: format!("proposal:{}", ids.next_id()),
+        scope: GovernanceScope::Module(ModuleId("PLGA".into())),
+        title: "Extend rerouting to the Van Buren corridor".into(),
+        description: "Apply the attested rerouting policy to a second corridor".into(),
+        payload: serde_json::json!({
+            "attestation_id": attestation.id.0,
+            "manifest_hash": manifest.self_hash,
+        }),
+        can_introduce_restrictions: false,
+    };
+    let engine = GovernanceEngine::new(SaepEngine::new(SaepConfig::default()));
+    let outcome = engine.tally_quadratic(&proposal.proposal_id, &[]);
+    let applicable = engine.can_apply_proposal(&proposal, &outcome);
+
+    serde_json::to_string_pretty(&serde_json::json!({
+        "manifest": manifest,
+        "allowance": allowance,
+        "forecast": forecast,
+        "reconciliation": reconciliation,
+        "attestation": attestation,
+        "proposal": proposal,
+        "outcome": outcome,
+        "applicable": applicable,
+    }))
+    .expect("scenario serializes")
+}
+
+fn main() {
+    let first = run(42);
+    let second = run(42);
+    assert_eq!(first, second, "replay diverged");
+    println!("{first}");
+    println!("Replay identical: {} bytes", first.len());
+
+    assert_ne!(first, run(43), "a different seed must change ids");
+}
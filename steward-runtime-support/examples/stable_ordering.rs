@@ -0,0 +1,255 @@
+// path: steward-runtime-support/examples/stable_ordering.rs
+
+//! Example: serialized output does not depend on insertion order.
+//! - Every run inserts the same data, shuffled, into fresh hash-backed collections
+//!   (each with its own random iteration order)
+//! - Ledger getters, consent records, element profiles, governance snapshots,
+//!   per-domain evaluations and manifest `external_refs` must serialize identically each run
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use aln_karma::{BaselineModel, ImpactMetrics as KarmaMetrics, JusticeConstraints, SafetyEpochManifest, VNodeId};
+use cybernetic_governance::{
+    AmendmentRules, CapabilityGovernance, CompetitiveDomain, GovernanceConstitution, GovernanceProposal as CapabilityProposal,
+    GovernanceVoteOutcome, ProposalTarget, ThresholdMode,
+};
+use planetary_stewardship_runtime::{
+    ConsentRecord, ConsentRegistry, Did, ImpactMetrics, MicroMissionsEngine, MissionId, MissionTemplate, PlanetaryLedger,
+    SaepConfig, SaepEngine, StewardModule,
+};
+use steward_ids::CapabilityId;
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+use the_element::{default_element, AgentId};
+
+const RUNS: u64 = 16;
+const T0: u64 = 1_767_225_600_000;
+
+/// Builds one serialized output from a shuffle seed.
+type Build = fn(u64) -> String;
+
+/// Fisher-Yates driven by xorshift, so each seed gives a different but reproducible order.
+fn shuffled<T: Clone>(items: &[T], seed: u64) -> Vec<T> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    let mut out = items.to_vec();
+    for i in (1..out.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        out.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+    out
+}
+
+fn caps(ids: &[&str], seed: u64) -> HashSet<CapabilityId> {
+    shuffled(ids, seed).into_iter().map(|id| CapabilityId::new(id).expect("valid capability")).collect()
+}
+
+/// Attestations are issued in a fixed order (their ids come from a sequence);
+/// consent records and mission templates are inserted shuffled.
+fn stewardship_output(seed: u64) -> String {
+    let actors: Vec<Did> = ["ana", "kofi", "li", "sami"]
+        .iter()
+        .map(|n| Did::new(format!("did:psv:steward:{n}")).expect("valid DID"))
+        .collect();
+    let mut consent = ConsentRegistry::new();
+    for actor in shuffled(&actors, seed) {
+        consent.upsert_consent(ConsentRecord {
+            participant: actor,
+            module: StewardModule::PLGA,
+            mission: None,
+            consent_given: true,
+            timestamp_ms: T0,
+            evidence_uri: None,
+            evidence_hash: None,
+            prompt_hash: None,
+        });
+    }
+    let config = SaepConfig { enforce_reversibility: false, ..SaepConfig::default() };
+    let mut ledger = PlanetaryLedger::with_runtime(
+        SaepEngine::new(config.clone()),
+        consent,
+        Arc::new(FixedClock::new(T0)),
+        Arc::new(SequentialIdGenerator::new(1)),
+    );
+    for (i, actor) in actors.iter().chain(actors.iter()).enumerate() {
+        let metrics = ImpactMetrics {
+            co2eq_reduced: 0.1 * i as f64,
+            biodiversity_index_delta: 0.0,
+            biodiversity_detail: None,
+            restored_area_m2: 10.0,
+            avoided_emissions_co2eq: 0.0,
+            custom: Default::default(),
+        };
+        // Equal timestamps in pairs, so the id tie-break is exercised too.
+        ledger
+            .issue_attestation(actor.clone(), None, format!("Work {i}"), metrics, format!("ipfs://{i}"), vec![], T0 + (i as u64 / 2))
+            .expect("attestation issued");
+    }
+
+    let mut missions = MicroMissionsEngine::new(SaepEngine::new(config), ConsentRegistry::new());
+    for id in shuffled(&["creek-cleanup", "seed-library", "bike-repair", "tree-census"], seed) {
+        missions.add_template(MissionTemplate {
+            id: MissionId::new(id).expect("valid mission id"),
+            title: id.into(),
+            description: format!("Mission {id}"),
+            difficulty: "S".into(),
+            expected_impact: serde_json::json!({}),
+            location_hint: "geo".into(),
+            required_skills: vec![],
+            default_language: None,
+            localized_content: Default::default(),
+            high_demand: false,
+        });
+    }
+
+    serde_json::to_string(&serde_json::json!({
+        "attestations": ledger.attestations().collect::<Vec<_>>(),
+        "ana": ledger.get_attestations_for_actor(&actors[0]),
+        "consent": ledger.consent().records().collect::<Vec<_>>(),
+        "templates": missions.templates().collect::<Vec<_>>(),
+    }))
+    .expect("stewardship json")
+}
+
+fn element_output(seed: u64) -> String {
+    let mut element = default_element();
+    let agent = AgentId::new("did:aln:player:neo").expect("valid DID");
+    let enhancements = [
+        "cognitive:focus_enhancer",
+        "cognitive:pattern_assist",
+        "motor:exoskeleton_assist",
+        "sensory:xr_overlay_competitive",
+    ];
+    // The exoskeleton needs a calibration first, whatever the order of the rest.
+    let calibration = CapabilityId::new("motor:emg_calibration").expect("valid capability");
+    element.request_enable(&agent, &calibration, true).expect("enabled");
+    for id in shuffled(&enhancements, seed) {
+        element.request_enable(&agent, &CapabilityId::new(id).expect("valid capability"), true).expect("enabled");
+    }
+    for id in shuffled(&["research:alpha", "research:beta", "research:gamma"], seed) {
+        element.request_block(&agent, &CapabilityId::new(id).expect("valid capability")).expect("blocked");
+    }
+    serde_json::to_string(element.get_profile(&agent).expect("profile")).expect("profile json")
+}
+
+/// Domains are upserted in a fixed order (the event log records it); their
+/// capability sets and the proposal's sets are built shuffled.
+fn governance_output(seed: u64) -> String {
+    let mut gov = CapabilityGovernance::new(GovernanceConstitution {
+        global_min_capability_floor: 2,
+        max_restriction_fraction_per_turn: 0.5,
+        max_cumulative_disabled_fraction: None,
+        min_supermajority_floor: 0.67,
+        hard_protect_safety_capabilities: true,
+        globally_nonrestrictable: caps(&["safety:emergency_stop", "safety:medic_call"], seed),
+        nonrestrictable_namespaces: HashSet::new(),
+        protected_override_supermajority: 0.9,
+        max_outcome_age_heights: None,
+        allow_oracle_override: false,
+        per_capability_thresholds: HashMap::new(),
+        per_namespace_thresholds: HashMap::new(),
+        threshold_mode: ThresholdMode::default(),
+        referees: HashSet::new(),
+        max_match_override_heights: 0,
+        amendment_rules: AmendmentRules::default(),
+        require_impact_statement: false,
+        impact_estimate_factor: 2.0,
+        appeal_panel: HashSet::new(),
+        appeal_window_heights: 0,
+    });
+    let allowed = ["move:dash", "move:block", "move:feint", "move:grapple", "sensory:xr_hint", "safety:emergency_stop"];
+    for id in ["arena-east", "arena-north", "arena-west"] {
+        gov.upsert_domain(CompetitiveDomain {
+            id: id.into(),
+            description: format!("Domain {id}"),
+            allowed_capabilities: caps(&allowed, seed),
+            min_capability_count: 2,
+            capability_categories: HashMap::new(),
+            category_floors: HashMap::new(),
+        })
+        .expect("valid domain");
+    }
+    let proposal = CapabilityProposal {
+        proposal_id: "prop-no-hints".into(),
+        domain_id: "arena-east".into(),
+        target: Some(ProposalTarget::AllDomains),
+        restrict_capabilities: caps(&["sensory:xr_hint", "move:grapple"], seed),
+        protect_capabilities: caps(&["move:dash", "move:block"], seed),
+        required_supermajority: 0.75,
+        activation_height: 0,
+        sunset_height: None,
+        capability_sunsets: shuffled(&[("move:grapple", 50u64), ("sensory:xr_hint", 80)], seed)
+            .into_iter()
+            .map(|(id, h)| (CapabilityId::new(id).expect("valid capability"), h))
+            .collect(),
+        impact_statement: None,
+    };
+    let outcome = GovernanceVoteOutcome {
+        proposal_id: proposal.proposal_id.clone(),
+        yes_weight: 90,
+        no_weight: 10,
+        finalized_height: 10,
+        participation: None,
+    };
+    let preview = gov.evaluate_proposal_per_domain(&proposal, &outcome, 10).expect("preview");
+    gov.commit_proposal(&proposal, &outcome, 10).expect("committed");
+    serde_json::to_string(&serde_json::json!({
+        "proposal": proposal,
+        "preview": preview,
+        "snapshot": gov.snapshot(),
+    }))
+    .expect("governance json")
+}
+
+fn manifest_output(seed: u64) -> String {
+    let refs = ["city_sensors://phoenix/pm25", "mrv://registry/42", "city_sensors://phoenix/no2", "mrv://registry/42"];
+    let manifest = SafetyEpochManifest::new_with_ids(
+        &SequentialIdGenerator::new(1),
+        VNodeId {
+            vnode_id: "city:phoenix:traffic:controller-01".into(),
+            policy_shard_id: "policy:aln:mobility:v1".into(),
+        },
+        0,
+        900,
+        KarmaMetrics {
+            t_co2e_avoided: 2.7,
+            kwh_reduced: 0.0,
+            pollution_exposure_delta: -1_500.0,
+            near_misses_blocked: 7,
+            biosafety_delta: 0.12,
+            custom: Default::default(),
+        },
+        BaselineModel {
+            description: "Phoenix SOV baseline, peak hour".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: None,
+        },
+        JusticeConstraints {
+            forbid_burden_shifting: true,
+            require_opt_out_respected: true,
+        },
+        "merkle-root-vnode-log-0xabc".into(),
+        shuffled(&refs, seed).into_iter().map(String::from).collect(),
+        None,
+    );
+    assert_eq!(manifest.external_refs.len(), 3, "duplicates are dropped");
+    serde_json::to_string(&manifest).expect("manifest json")
+}
+
+fn main() {
+    let outputs: [(&str, Build); 4] = [
+        ("stewardship", stewardship_output),
+        ("element", element_output),
+        ("governance", governance_output),
+        ("manifest", manifest_output),
+    ];
+    for (name, build) in outputs {
+        let first = build(0);
+        for seed in 1..RUNS {
+            assert_eq!(build(seed), first, "{name} output changed with insertion order (seed {seed})");
+        }
+        println!("{name}: {RUNS} shuffled runs, {} identical bytes", first.len());
+    }
+}
@@ -0,0 +1,155 @@
+// path: steward-runtime-support/src/lib.rs
+
+//! Injectable time and id sources shared across the stewardship workspace.
+//! - `Clock`: wall-clock milliseconds (`SystemClock`, `FixedClock`, `SteppingClock`)
+//! - `IdGenerator`: UUIDs (`UuidV4Generator`, deterministic `SequentialIdGenerator`)
+//!
+//! Existing constructors keep using the system defaults; the `*_with_*`
+//! variants accept injected instances so a scenario can be replayed and
+//! serialize byte-for-byte identically.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+// ---------------------------------------------------------------------
+// CLOCKS
+// ---------------------------------------------------------------------
+
+/// Source of wall-clock time, in milliseconds since the Unix epoch.
+pub trait Clock: Send + Sync {
+    fn now_ms(&self) -> u64;
+
+    fn now_secs(&self) -> u64 {
+        self.now_ms() / 1_000
+    }
+}
+
+/// The host clock. Reports 0 if the system time is before the Unix epoch.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0)
+    }
+}
+
+/// A clock that only moves when told to.
+#[derive(Debug, Default)]
+pub struct FixedClock {
+    now_ms: AtomicU64,
+}
+
+impl FixedClock {
+    pub fn new(now_ms: u64) -> Self {
+        Self { now_ms: AtomicU64::new(now_ms) }
+    }
+
+    pub fn set(&self, now_ms: u64) {
+        self.now_ms.store(now_ms, Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, delta_ms: u64) {
+        self.now_ms.fetch_add(delta_ms, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_ms(&self) -> u64 {
+        self.now_ms.load(Ordering::SeqCst)
+    }
+}
+
+/// A clock that advances by `step_ms` every time it is read, so
+/// consecutive events get distinct, predictable timestamps.
+#[derive(Debug)]
+pub struct SteppingClock {
+    next_ms: AtomicU64,
+    step_ms: u64,
+}
+
+impl SteppingClock {
+    /// First read returns `start_ms`, then `start_ms + step_ms`, and so on.
+    pub fn new(start_ms: u64, step_ms: u64) -> Self {
+        Self {
+            next_ms: AtomicU64::new(start_ms),
+            step_ms,
+        }
+    }
+}
+
+impl Clock for SteppingClock {
+    fn now_ms(&self) -> u64 {
+        self.next_ms.fetch_add(self.step_ms, Ordering::SeqCst)
+    }
+}
+
+// ---------------------------------------------------------------------
+// ID GENERATORS
+// ---------------------------------------------------------------------
+
+/// Source of unique identifiers.
+pub trait IdGenerator: Send + Sync {
+    fn next_uuid(&self) -> Uuid;
+
+    /// Hyphenated string form, as used by string-wrapped ids.
+    fn next_id(&self) -> String {
+        self.next_uuid().to_string()
+    }
+}
+
+/// Random v4 UUIDs. The default everywhere.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidV4Generator;
+
+impl IdGenerator for UuidV4Generator {
+    fn next_uuid(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Deterministic v4-formatted UUIDs built from `(seed, counter)`.
+/// Two generators with the same seed yield the same sequence.
+#[derive(Debug, Default)]
+pub struct SequentialIdGenerator {
+    seed: u64,
+    counter: AtomicU64,
+}
+
+impl SequentialIdGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            counter: AtomicU64::new(0),
+        }
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_uuid(&self) -> Uuid {
+        let n = self.counter.fetch_add(1, Ordering::SeqCst);
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.seed.to_be_bytes());
+        bytes[8..].copy_from_slice(&n.to_be_bytes());
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
+
+// ---------------------------------------------------------------------
+// DEFAULTS
+// ---------------------------------------------------------------------
+
+/// Shared handle to the system clock.
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// Shared handle to the v4 UUID generator.
+pub fn default_id_generator() -> Arc<dyn IdGenerator> {
+    Arc::new(UuidV4Generator)
+}
@@ -0,0 +1,180 @@
+// path: steward-scenarios/src/capability_restriction.rs
+
+//! Scenario: from a vNode epoch to a restricted agent profile.
+//! 1. A traffic vNode seals a `SafetyEpochManifest` for the current epoch
+//! 2. The steward who ran it consents (PLGA + MME, mission-scoped) and is assigned the follow-up mission
+//!    under a registered rollback plan
+//! 3. Completing the mission is recorded as a PLGA attestation citing the manifest, issued under
+//!    a plan for a reserved id (`MicroMissionsEngine` has no completion state of its own)
+//! 4. Two restriction proposals go through `GovernanceEngine`: one is vetoed by SAEP, one applies
+//! 5. The applicable one is committed constitutionally in `CapabilityGovernance`
+//! 6. The domain's restrictions are bridged into the steward's `TheElement` profile
+
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+use cybernetic_governance::element_bridge::apply_domain_to_agents;
+use planetary_stewardship_runtime::{
+    ActionRef, GovernanceProposal, GovernanceScope, ModuleId, QuadraticVote, SaepRefs, StewardModule,
+};
+use the_element::CapabilityDomain;
+
+use crate::fixtures::{
+    cap, capability_governance, did, domain, enhancement, manifest_evidence_uri, mission_id, mission_template,
+    plga_metrics, restriction, vote, ManifestBuilder, ScenarioEnv,
+};
+
+pub const NAME: &str = "capability_restriction";
+
+const DOMAIN_ID: &str = "xr-grid:phoenix:heat-festival";
+
+pub fn run(seed: u64) -> Value {
+    let env = ScenarioEnv::new(seed);
+    let steward = did("did:psv:steward:ana");
+
+    // 1. vNode epoch.
+    let manifest = env.manifest(ManifestBuilder::new("city:phoenix:traffic:controller-01"));
+    env.advance_ms(60_000);
+
+    // 2. Consent and assignment.
+    let mission = mission_id("corridor-rerouting-review");
+    let consents = vec![
+        env.consent(&steward, StewardModule::PLGA, Some(&mission)),
+        env.consent(&steward, StewardModule::MME, Some(&mission)),
+    ];
+    let mut missions = env.missions(&consents, &[mission_template(mission.as_str(), "Review corridor rerouting")]);
+    let assignment_plan = missions
+        .register_rollback_plan(env.rollback_plan(
+            "plan:assignment:corridor-review",
+            ActionRef::Assignment { mission: mission.clone(), assignee: steward.clone() },
+            &steward,
+        ))
+        .expect("mission template exists");
+    let assignment = missions
+        .assign_mission_with_refs(
+            &mission,
+            steward.clone(),
+            env.now_ms(),
+            SaepRefs { intent_entry_id: None, rollback_plan_id: Some(assignment_plan) },
+        )
+        .expect("consented mission assignment");
+    env.advance_ms(3_600_000);
+
+    // 3. Completion as a manifest-backed attestation.
+    let mut ledger = env.ledger(&consents);
+    let reserved = ledger.reserve_attestation_id();
+    let attestation_plan = ledger
+        .register_rollback_plan(env.rollback_plan(
+            "plan:attestation:corridor-review",
+            ActionRef::Attestation(reserved),
+            &did("did:psv:verifier:city-of-phoenix"),
+        ))
+        .expect("attestation id reserved");
+    let attestation = ledger
+        .issue_attestation_with_refs(
+            steward.clone(),
+            Some(mission.clone()),
+            "Reviewed peak-hour rerouting on the corridor".into(),
+            plga_metrics(&manifest),
+            manifest_evidence_uri(&manifest),
+            vec![did("did:psv:verifier:city-of-phoenix")],
+            env.now_ms(),
+            SaepRefs { intent_entry_id: None, rollback_plan_id: Some(attestation_plan) },
+        )
+        .expect("consented attestation");
+
+    // 4. Ethics-kernel path.
+    let governance = env.governance();
+    let votes = vec![
+        QuadraticVote { voter: did("did:psv:steward:ana"), effective_weight: 3.0, support: true },
+        QuadraticVote { voter: did("did:psv:steward:kofi"), effective_weight: 2.0, support: true },
+        QuadraticVote { voter: did("did:psv:steward:lee"), effective_weight: 1.0, support: false },
+    ];
+    let proposal = |id: &str, description: &str| GovernanceProposal {
+        proposal_id: id.into(),
+        scope: GovernanceScope::Module(ModuleId("PSM".into())),
+        title: format!("Restrict XR overlays ({id})"),
+        description: description.into(),
+        payload: json!({ "domain_id": DOMAIN_ID, "attestation_id": attestation.id.0 }),
+        can_introduce_restrictions: true,
+    };
+    let vetoed = proposal("prop-overlay-weapon", "Repurpose high-intensity overlays as a crowd weapon deterrent");
+    let heat = proposal("prop-overlay-heat", "Pause high-intensity overlays during extreme heat");
+    let mut ethics = BTreeMap::new();
+    for p in [&vetoed, &heat] {
+        let outcome = governance.tally_quadratic(&p.proposal_id, &votes);
+        let decision = governance.can_apply_proposal(p, &outcome);
+        ethics.insert(
+            p.proposal_id.clone(),
+            json!({ "outcome": outcome, "decision": decision }),
+        );
+    }
+    let heat_applicable = governance
+        .can_apply_proposal(&heat, &governance.tally_quadratic(&heat.proposal_id, &votes))
+        .expect("heat proposal passes SAEP and charter");
+    assert!(heat_applicable, "heat proposal carries the vote");
+
+    // 5. Constitutional path.
+    let mut gov = capability_governance(vec![domain(
+        DOMAIN_ID,
+        &[
+            "safety:emergency_stop",
+            "meta:pause_augmentation",
+            "xr:overlay_high_intensity",
+            "xr:overlay_wayfinding",
+            "xr:overlay_shade_map",
+        ],
+        3,
+    )]);
+    let restrict = restriction(
+        &heat.proposal_id,
+        DOMAIN_ID,
+        &["xr:overlay_high_intensity", "meta:pause_augmentation"],
+        100,
+    );
+    let commit = gov
+        .commit_proposal(&restrict, &vote(&heat.proposal_id, 5, 1, 105), 110)
+        .expect("restriction is constitutional");
+    let state = gov.get_domain_state(DOMAIN_ID).expect("domain registered");
+    let domain_state = json!({
+        "disabled_capabilities": state.disabled_capabilities,
+        "disabled_by": state.disabled_by.iter().collect::<BTreeMap<_, _>>(),
+    });
+
+    // 6. Element profile.
+    let mut element = env.element();
+    for id in ["xr:overlay_high_intensity", "xr:overlay_wayfinding", "xr:overlay_shade_map"] {
+        element.upsert_ability(enhancement(id, CapabilityDomain::Sensory));
+        element.request_enable(&steward, &cap(id), true).expect("opt-in enhancement");
+    }
+    let bridge = apply_domain_to_agents(&gov, DOMAIN_ID, &mut element, std::slice::from_ref(&steward))
+        .expect("domain registered");
+    let profile = element.get_profile(&steward).expect("steward has a profile");
+    let usable: BTreeSet<_> = profile
+        .enabled_capabilities
+        .iter()
+        .filter(|c| element.can_use(&steward, c))
+        .collect();
+    assert!(
+        bridge.skipped_baseline.contains(&cap("meta:pause_augmentation")),
+        "baseline rights are never bridged into restrictions"
+    );
+
+    let intent_log = env.intent_log_snapshot();
+    intent_log.verify().expect("intent log chain intact");
+    let rollback_plans = env.rollback_plans_snapshot();
+
+    json!({
+        "manifest": manifest,
+        "assignment": assignment,
+        "attestation": attestation,
+        "intent_log": intent_log.entries(),
+        "rollback_plans": rollback_plans.plans(),
+        "ethics": ethics,
+        "commit": commit,
+        "domain_state": domain_state,
+        "bridge": bridge,
+        "profile": profile,
+        "usable_capabilities": usable,
+    })
+}
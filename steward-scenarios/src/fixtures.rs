@@ -0,0 +1,215 @@
+// path: steward-scenarios/src/fixtures.rs
+
+//! Builder helpers for the fixtures every scenario needs, on top of each crate's `builders`
+//! (feature `test-util`).
+//! - `ScenarioEnv`: the shared fixed clock, seeded id source, intent log and rollback
+//!   registry, and engines wired to them
+//! - `ManifestBuilder`: a certified, eligible `SafetyEpochManifest` unless told otherwise,
+//!   built for the epoch containing the env clock's "now" by `ScenarioEnv::manifest`
+//! - `plga_metrics`: the one place manifest metrics become attestation metrics
+//! - Arena helpers for `CapabilityGovernance` domains, proposals and outcomes
+
+use std::sync::Arc;
+
+pub use aln_karma::builders::ManifestBuilder;
+use aln_karma::SafetyEpochManifest;
+use cybernetic_governance::builders::{ConstitutionBuilder, DomainBuilder, ProposalBuilder};
+pub use cybernetic_governance::builders::{cap, caps, vote};
+use cybernetic_governance::{
+    CapabilityGovernance, CompetitiveDomain, GovernanceConstitution, GovernanceProposal as CapabilityProposal,
+};
+use planetary_stewardship_runtime::builders::{ConsentRecordBuilder, LedgerBuilder, MissionTemplateBuilder};
+pub use planetary_stewardship_runtime::builders::{did, mission_id};
+use planetary_stewardship_runtime::{
+    ActionRef, ConsentRecord, ConsentRegistry, Did, GovernanceEngine, ImpactMetrics, IntentLog, MicroMissionsEngine,
+    MissionId, MissionTemplate, PlanId, PlanetaryLedger, RollbackPlan, RollbackPlanRegistry, SaepConfig, SaepEngine,
+    SharedIntentLog, SharedRollbackRegistry, StewardModule,
+};
+use steward_runtime_support::{Clock, FixedClock, SequentialIdGenerator};
+use the_element::builders::AbilityBuilder;
+use the_element::{default_element_with_ids, CapabilityDomain, CyberneticAbility, TheElement};
+
+/// 2026-01-01T00:00:00Z; every scenario starts here.
+pub const SCENARIO_START_MS: u64 = 1_767_225_600_000;
+
+/// 15-minute epochs, as deployed on the Phoenix vNodes.
+pub const EPOCH_SECONDS: u64 = 900;
+
+// ---------------------------------------------------------------------
+// ENVIRONMENT
+// ---------------------------------------------------------------------
+
+/// Shared clock, id source, intent log and rollback registry; engines built here all draw from them.
+pub struct ScenarioEnv {
+    pub clock: Arc<FixedClock>,
+    pub ids: Arc<SequentialIdGenerator>,
+    pub intent_log: SharedIntentLog,
+    pub rollback_plans: SharedRollbackRegistry,
+    pub saep: SaepConfig,
+}
+
+impl ScenarioEnv {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            clock: Arc::new(FixedClock::new(SCENARIO_START_MS)),
+            ids: Arc::new(SequentialIdGenerator::new(seed)),
+            intent_log: IntentLog::shared(),
+            rollback_plans: RollbackPlanRegistry::shared(),
+            saep: SaepConfig::default(),
+        }
+    }
+
+    pub fn now_ms(&self) -> u64 {
+        self.clock.now_ms()
+    }
+
+    /// Move the shared clock forward.
+    pub fn advance_ms(&self, ms: u64) {
+        self.clock.advance(ms);
+    }
+
+    /// Consent granted now.
+    pub fn consent(&self, participant: &Did, module: StewardModule, mission: Option<&MissionId>) -> ConsentRecord {
+        let record = ConsentRecordBuilder::new(participant, module).at_ms(self.now_ms());
+        match mission {
+            Some(mission) => record.mission(mission).build(),
+            None => record.build(),
+        }
+    }
+
+    fn registry(consents: &[ConsentRecord]) -> ConsentRegistry {
+        let mut registry = ConsentRegistry::new();
+        for record in consents {
+            registry.upsert_consent(record.clone());
+        }
+        registry
+    }
+
+    pub fn ledger(&self, consents: &[ConsentRecord]) -> PlanetaryLedger {
+        let mut builder = LedgerBuilder::new().clock(self.clock.clone()).ids(self.ids.clone()).saep(self.saep.clone());
+        for record in consents {
+            builder = builder.consent(record.clone());
+        }
+        builder
+            .build()
+            .with_intent_log(self.intent_log.clone())
+            .with_rollback_registry(self.rollback_plans.clone())
+    }
+
+    pub fn missions(&self, consents: &[ConsentRecord], templates: &[MissionTemplate]) -> MicroMissionsEngine {
+        let mut engine = MicroMissionsEngine::new(SaepEngine::new(self.saep.clone()), Self::registry(consents))
+            .with_intent_log(self.intent_log.clone())
+            .with_rollback_registry(self.rollback_plans.clone());
+        for template in templates {
+            engine.add_template(template.clone());
+        }
+        engine
+    }
+
+    /// Snapshot of the shared intent log.
+    pub fn intent_log_snapshot(&self) -> IntentLog {
+        self.intent_log.lock().expect("intent log lock").clone()
+    }
+
+    /// Two-step plan for undoing `action_ref`, created now; register it with the engine that owns the action.
+    pub fn rollback_plan(&self, id: &str, action_ref: ActionRef, responsible: &Did) -> RollbackPlan {
+        RollbackPlan {
+            id: PlanId(id.into()),
+            steps: vec![format!("Undo the {action_ref}"), "Notify everyone the action touched".into()],
+            action_ref,
+            responsible: responsible.clone(),
+            created_ms: self.now_ms(),
+        }
+    }
+
+    /// Snapshot of the shared rollback registry.
+    pub fn rollback_plans_snapshot(&self) -> RollbackPlanRegistry {
+        self.rollback_plans.lock().expect("rollback registry lock").clone()
+    }
+
+    pub fn governance(&self) -> GovernanceEngine {
+        GovernanceEngine::new(SaepEngine::new(self.saep.clone()))
+    }
+
+    /// Default element library, turn ids drawn from the shared source.
+    pub fn element(&self) -> TheElement {
+        default_element_with_ids(self.ids.clone())
+    }
+
+    /// `builder`'s manifest for the epoch containing now, its id drawn from the shared source.
+    pub fn manifest(&self, builder: ManifestBuilder) -> SafetyEpochManifest {
+        builder.at_ms(self.now_ms()).epoch_seconds(EPOCH_SECONDS).build_with_ids(self.ids.as_ref())
+    }
+}
+
+// ---------------------------------------------------------------------
+// ALN-KARMA
+// ---------------------------------------------------------------------
+
+/// Attestation metrics backed by a manifest. Both sides are tonnes CO₂e;
+/// the attestation carries the manifest's net (post-leakage) figure.
+pub fn plga_metrics(manifest: &SafetyEpochManifest) -> ImpactMetrics {
+    ImpactMetrics {
+        co2eq_reduced: manifest.net_t_co2e_avoided(),
+        biodiversity_index_delta: 0.0,
+        biodiversity_detail: None,
+        restored_area_m2: 0.0,
+        avoided_emissions_co2eq: manifest.net_t_co2e_avoided(),
+        custom: Default::default(),
+    }
+}
+
+/// Evidence link the PLGA side uses to cite a manifest.
+pub fn manifest_evidence_uri(manifest: &SafetyEpochManifest) -> String {
+    format!("manifest://{}", manifest.self_hash)
+}
+
+// ---------------------------------------------------------------------
+// PLANETARY
+// ---------------------------------------------------------------------
+
+pub fn mission_template(id: &str, title: &str) -> MissionTemplate {
+    MissionTemplateBuilder::new(id)
+        .title(title)
+        .description(&format!("{title}.\n\nEvidence: vNode epoch manifest."))
+        .expected_impact(serde_json::json!({ "t_co2e_avoided": 1.0 }))
+        .build()
+}
+
+// ---------------------------------------------------------------------
+// CAPABILITY GOVERNANCE + ELEMENT
+// ---------------------------------------------------------------------
+
+/// Permissive constitution with the arena defaults; tweak fields as needed.
+pub fn constitution() -> GovernanceConstitution {
+    ConstitutionBuilder::new().build()
+}
+
+pub fn capability_governance(domains: Vec<CompetitiveDomain>) -> CapabilityGovernance {
+    let mut gov = CapabilityGovernance::new(constitution());
+    for domain in domains {
+        gov.upsert_domain(domain).expect("valid scenario domain");
+    }
+    gov
+}
+
+pub fn domain(id: &str, allowed: &[&str], min_capability_count: usize) -> CompetitiveDomain {
+    DomainBuilder::new(id)
+        .description(&format!("Scenario domain {id}"))
+        .allow(allowed)
+        .min_capability_count(min_capability_count)
+        .build()
+}
+
+/// Permanent restriction of `restrict` in `domain_id`, eligible at `activation_height`.
+pub fn restriction(proposal_id: &str, domain_id: &str, restrict: &[&str], activation_height: u64) -> CapabilityProposal {
+    ProposalBuilder::new(proposal_id, domain_id)
+        .restrict(restrict)
+        .activation_height(activation_height)
+        .build()
+}
+
+/// Low-risk, opt-in enhancement for the element library.
+pub fn enhancement(id: &str, domain: CapabilityDomain) -> CyberneticAbility {
+    AbilityBuilder::new(id).domain(domain).description(&format!("Scenario ability {id}")).build_valid()
+}
@@ -0,0 +1,100 @@
+// path: steward-scenarios/src/golden.rs
+
+//! Golden-file comparison for scenario outputs.
+//! - Outputs are canonicalized before comparison: object keys sorted, and
+//!   arrays of plain strings sorted (most come from `HashSet`s, whose order is random)
+//! - Goldens live in `golden/<scenario>.json` next to this crate's sources
+//! - `bless` rewrites them; review the diff like any other change
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("golden")
+}
+
+pub fn golden_path(name: &str) -> PathBuf {
+    golden_dir().join(format!("{name}.json"))
+}
+
+/// `value` as JSON with set-like string arrays sorted.
+pub fn canonical_json<T: Serialize>(value: &T) -> Value {
+    let mut value = serde_json::to_value(value).expect("scenario output serializes");
+    sort_string_arrays(&mut value);
+    value
+}
+
+fn sort_string_arrays(value: &mut Value) {
+    match value {
+        Value::Array(items) => {
+            items.iter_mut().for_each(sort_string_arrays);
+            if items.iter().all(Value::is_string) {
+                items.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+            }
+        }
+        Value::Object(map) => map.values_mut().for_each(sort_string_arrays),
+        _ => {}
+    }
+}
+
+pub fn render(value: &Value) -> String {
+    serde_json::to_string_pretty(value).expect("scenario output serializes") + "\n"
+}
+
+/// Why a scenario's output did not match its golden file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoldenMismatch {
+    Missing(PathBuf),
+    Differs { path: PathBuf, line: usize, expected: String, actual: String },
+}
+
+impl std::fmt::Display for GoldenMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoldenMismatch::Missing(path) => write!(f, "missing golden file {} (run with --bless)", path.display()),
+            GoldenMismatch::Differs { path, line, expected, actual } => write!(
+                f,
+                "{} differs at line {line}\n  expected: {expected}\n  actual:   {actual}",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GoldenMismatch {}
+
+/// Compare `actual` with `golden/<name>.json`, reporting the first differing line.
+pub fn check(name: &str, actual: &Value) -> Result<(), GoldenMismatch> {
+    let path = golden_path(name);
+    let expected = fs::read_to_string(&path).map_err(|_| GoldenMismatch::Missing(path.clone()))?;
+    let actual = render(actual);
+    if expected == actual {
+        return Ok(());
+    }
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (e, a) => {
+                return Err(GoldenMismatch::Differs {
+                    path,
+                    line,
+                    expected: e.unwrap_or("<end of file>").to_string(),
+                    actual: a.unwrap_or("<end of file>").to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// Overwrite `golden/<name>.json` with `actual`.
+pub fn bless(name: &str, actual: &Value) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(golden_dir())?;
+    let path = golden_path(name);
+    fs::write(&path, render(actual))?;
+    Ok(path)
+}
@@ -0,0 +1,38 @@
+// path: steward-scenarios/src/lib.rs
+
+//! End-to-end scenarios across aln-karma, planetary_stewardship_runtime,
+//! cybernetic-governance and the_element.
+//! - Every scenario runs on `fixtures::ScenarioEnv` (fixed clock, seeded ids), so output is reproducible
+//! - Outputs are compared against `golden/<name>.json` by the `scenarios` binary
+//! - Regenerate goldens with `cargo run -p steward-scenarios -- --bless` and review the diff
+//!
+//! Adding a scenario: write a module with `NAME` and `run(seed) -> Value`
+//! (build fixtures with the `fixtures` helpers), then list it in `all_scenarios`.
+
+use serde_json::Value;
+
+pub mod capability_restriction;
+pub mod fixtures;
+pub mod golden;
+
+/// Seed every golden file is recorded with.
+pub const GOLDEN_SEED: u64 = 42;
+
+pub struct Scenario {
+    pub name: &'static str,
+    pub run: fn(u64) -> Value,
+}
+
+impl Scenario {
+    /// Run with `seed` and canonicalize for golden comparison.
+    pub fn output(&self, seed: u64) -> Value {
+        golden::canonical_json(&(self.run)(seed))
+    }
+}
+
+pub fn all_scenarios() -> Vec<Scenario> {
+    vec![Scenario {
+        name: capability_restriction::NAME,
+        run: capability_restriction::run,
+    }]
+}
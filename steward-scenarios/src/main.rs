@@ -0,0 +1,51 @@
+// path: steward-scenarios/src/main.rs
+
+//! Runs every scenario twice (replay must be identical) and checks it against its golden file.
+//! - `--bless` rewrites the golden files instead of checking them
+//! - Optional positional arguments select scenarios by name
+
+use std::process::ExitCode;
+
+use steward_scenarios::{all_scenarios, golden, GOLDEN_SEED};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let bless = args.iter().any(|a| a == "--bless");
+    let selected: Vec<&String> = args.iter().filter(|a| !a.starts_with("--")).collect();
+
+    let mut failures = 0;
+    for scenario in all_scenarios() {
+        if !selected.is_empty() && !selected.iter().any(|s| *s == scenario.name) {
+            continue;
+        }
+        let output = scenario.output(GOLDEN_SEED);
+        if output != scenario.output(GOLDEN_SEED) {
+            eprintln!("FAIL {}: replay with the same seed diverged", scenario.name);
+            failures += 1;
+            continue;
+        }
+        if bless {
+            match golden::bless(scenario.name, &output) {
+                Ok(path) => println!("blessed {} -> {}", scenario.name, path.display()),
+                Err(e) => {
+                    eprintln!("FAIL {}: cannot write golden file: {e}", scenario.name);
+                    failures += 1;
+                }
+            }
+            continue;
+        }
+        match golden::check(scenario.name, &output) {
+            Ok(()) => println!("ok   {}", scenario.name),
+            Err(mismatch) => {
+                eprintln!("FAIL {}: {mismatch}", scenario.name);
+                failures += 1;
+            }
+        }
+    }
+
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,196 @@
+// path: steward-schemas/examples/validate_samples.rs
+
+//! Example: checking the exported schemas against real serde output.
+//! - Serializes sample values from each crate and validates them
+//! - Round-trips each sample through its Rust type unchanged
+//! - Confirms layouts serde would reject (wrong enum tagging, malformed ids)
+//!   are rejected by the schema too
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+
+use aln_karma::{BaselineModel, JusticeConstraints, SafetyEpochManifest, VNodeId};
+use cybernetic_governance::{CapabilityId, CommitOutcome, GovernanceProposal as CapabilityProposal, ProposalTarget};
+use planetary_stewardship_runtime::{
+    ActionRef, ConsentRecord, ConsentRegistry, Did, GovernanceProposal, GovernanceScope, ImpactMetrics, MissionId,
+    MissionTemplate, ModuleId, PlanId, PlanetaryLedger, RollbackPlan, RollbackPlanRegistry, SaepConfig, SaepEngine,
+    SaepRefs, StewardModule, StewardshipAttestation,
+};
+
+fn validator(crate_name: &str, type_name: &str) -> jsonschema::Validator {
+    let entry = steward_schemas::find_schema(crate_name, type_name)
+        .unwrap_or_else(|| panic!("no schema for {crate_name}:{type_name}"));
+    let schema = serde_json::to_value(&entry.schema).expect("schema json");
+    jsonschema::validator_for(&schema).expect("schema compiles")
+}
+
+/// Sample must validate and survive a serde round-trip byte-for-byte.
+fn accept<T: Serialize + DeserializeOwned>(crate_name: &str, type_name: &str, sample: &T) {
+    let value = serde_json::to_value(sample).expect("sample json");
+    let v = validator(crate_name, type_name);
+    let errors: Vec<String> = v.iter_errors(&value).map(|e| e.to_string()).collect();
+    assert!(errors.is_empty(), "{crate_name}:{type_name} rejected its own output: {errors:?}");
+
+    let back: T = serde_json::from_value(value.clone()).expect("round-trip");
+    assert_eq!(serde_json::to_value(&back).expect("json"), value, "{type_name} changed on round-trip");
+    println!("ok   {crate_name}:{type_name}");
+}
+
+/// Sample must be rejected by both the schema and serde.
+fn reject<T: DeserializeOwned>(crate_name: &str, type_name: &str, sample: Value) {
+    assert!(!validator(crate_name, type_name).is_valid(&sample), "{type_name} schema accepted {sample}");
+    assert!(serde_json::from_value::<T>(sample.clone()).is_err(), "serde accepted {sample}");
+    println!("deny {crate_name}:{type_name} {sample}");
+}
+
+fn main() {
+    let steward = Did::new("did:psv:steward:ana").expect("valid DID");
+    let mission = MissionId::new("mission:creek-cleanup-01").expect("valid mission id");
+
+    // planetary_stewardship_runtime
+    let mut consent = ConsentRegistry::new();
+    consent.upsert_consent(ConsentRecord {
+        participant: steward.clone(),
+        module: StewardModule::PLGA,
+        mission: Some(mission.clone()),
+        consent_given: true,
+        timestamp_ms: 0,
+        evidence_uri: None,
+        evidence_hash: None,
+        prompt_hash: None,
+    });
+    let mut ledger = PlanetaryLedger::new(SaepEngine::new(SaepConfig::default()), consent);
+    let reserved = ledger.reserve_attestation_id();
+    let plan = RollbackPlan {
+        id: PlanId("plan:creek-cleanup-01".into()),
+        action_ref: ActionRef::Attestation(reserved),
+        steps: vec!["Revoke the attestation".into(), "Notify the verifier".into()],
+        responsible: steward.clone(),
+        created_ms: 1_767_225_600_000,
+    };
+    let plan_id = ledger.register_rollback_plan(plan).expect("reserved id");
+    let attestation = ledger
+        .issue_attestation_with_refs(
+            steward.clone(),
+            Some(mission.clone()),
+            "Creek cleanup".into(),
+            ImpactMetrics {
+                co2eq_reduced: 0.0,
+                biodiversity_index_delta: 0.02,
+                biodiversity_detail: None,
+                restored_area_m2: 350.0,
+                avoided_emissions_co2eq: 0.0,
+                custom: Default::default(),
+            },
+            "ipfs://evidence",
+            vec![Did::new("did:psv:verifier:kofi").expect("valid DID")],
+            1_767_225_600_000,
+            SaepRefs { intent_entry_id: None, rollback_plan_id: Some(plan_id) },
+        )
+        .expect("attestation issued");
+    accept::<StewardshipAttestation>("planetary_stewardship_runtime", "StewardshipAttestation", &attestation);
+    let registry: RollbackPlanRegistry = ledger.rollback_registry().lock().expect("registry lock").clone();
+    accept("planetary_stewardship_runtime", "RollbackPlanRegistry", &registry);
+    reject::<ActionRef>(
+        "planetary_stewardship_runtime",
+        "ActionRef",
+        json!({ "Assignment": { "mission": "creek-cleanup-01" } }),
+    );
+
+    accept(
+        "planetary_stewardship_runtime",
+        "MissionTemplate",
+        &MissionTemplate {
+            id: mission,
+            title: "Creek cleanup".into(),
+            description: "Remove debris along a 500 m stretch".into(),
+            difficulty: "S".into(),
+            expected_impact: json!({ "restored_area_m2": 350.0 }),
+            location_hint: "geo".into(),
+            required_skills: vec!["waders".into()],
+            default_language: None,
+            localized_content: Default::default(),
+            high_demand: false,
+        },
+    );
+
+    for scope in [GovernanceScope::Module(ModuleId("PLGA".into())), GovernanceScope::EcosystemWide] {
+        accept(
+            "planetary_stewardship_runtime",
+            "GovernanceProposal",
+            &GovernanceProposal {
+                proposal_id: "proposal:1".into(),
+                scope,
+                title: "Extend cleanup season".into(),
+                description: "Add two weeks in October".into(),
+                payload: json!({ "weeks": 2 }),
+                can_introduce_restrictions: false,
+            },
+        );
+    }
+    // Externally tagged on the wire; an internally tagged layout must not validate.
+    reject::<GovernanceScope>("planetary_stewardship_runtime", "GovernanceScope", json!({ "type": "Module", "value": "PLGA" }));
+    reject::<StewardshipAttestation>(
+        "planetary_stewardship_runtime",
+        "StewardshipAttestation",
+        {
+            let mut bad = serde_json::to_value(&attestation).expect("json");
+            bad["actor_did"] = json!("psv:steward:ana");
+            bad
+        },
+    );
+
+    // aln-karma
+    let manifest = SafetyEpochManifest::new(
+        VNodeId {
+            vnode_id: "city:phoenix:traffic:controller-01".into(),
+            policy_shard_id: "policy:aln:mobility:v1".into(),
+        },
+        1_767_225_600,
+        1_767_226_500,
+        aln_karma::ImpactMetrics {
+            t_co2e_avoided: 2.7,
+            near_misses_blocked: 7,
+            ..Default::default()
+        },
+        BaselineModel {
+            description: "Phoenix SOV baseline".into(),
+            additionality_certified: true,
+            min_improvement_ratio: 0.05,
+            method: None,
+        },
+        JusticeConstraints {
+            forbid_burden_shifting: true,
+            require_opt_out_respected: true,
+        },
+        "merkle-root-vnode-log-0xabc".into(),
+        vec![],
+        None,
+    );
+    accept("aln-karma", "SafetyEpochManifest", &manifest);
+
+    // cybernetic-governance
+    accept(
+        "cybernetic-governance",
+        "GovernanceProposal",
+        &CapabilityProposal {
+            proposal_id: "arena:prop-7".into(),
+            domain_id: "arena:phoenix".into(),
+            target: Some(ProposalTarget::Domains(vec!["arena:phoenix".into(), "arena:tempe".into()])),
+            restrict_capabilities: HashSet::from([CapabilityId::new("move:bci_push").expect("valid")]),
+            protect_capabilities: HashSet::new(),
+            required_supermajority: 0.67,
+            activation_height: 1_000,
+            sunset_height: Some(2_000),
+            capability_sunsets: HashMap::new(),
+            impact_statement: None,
+        },
+    );
+    accept::<CommitOutcome>("cybernetic-governance", "CommitOutcome", &CommitOutcome::NotPassed);
+    reject::<CommitOutcome>("cybernetic-governance", "CommitOutcome", json!({ "NotPassed": null, "extra": 1 }));
+    reject::<ProposalTarget>("cybernetic-governance", "ProposalTarget", json!({ "Domain": ["arena:phoenix"] }));
+
+    println!("All samples match their schemas.");
+}
@@ -0,0 +1,46 @@
+// path: steward-schemas/src/bin/export-schemas.rs
+
+//! `cargo run --bin export-schemas -- <out_dir>`
+//! Writes one `<crate>.<Type>.schema.json` per exported type, plus an
+//! `index.json` mapping `$id`s to file names and crate versions.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let Some(out_dir) = std::env::args().nth(1).map(PathBuf::from) else {
+        eprintln!("usage: export-schemas <out_dir>");
+        return ExitCode::from(2);
+    };
+    if let Err(e) = fs::create_dir_all(&out_dir) {
+        eprintln!("Cannot create {}: {e}", out_dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut index = serde_json::Map::new();
+    let schemas = steward_schemas::all_schemas();
+    for entry in &schemas {
+        let path = out_dir.join(entry.file_name());
+        if let Err(e) = fs::write(&path, entry.to_json_pretty()) {
+            eprintln!("Cannot write {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+        index.insert(
+            entry.id(),
+            serde_json::json!({
+                "file": entry.file_name(),
+                "crate": entry.crate_name,
+                "crate_version": entry.crate_version,
+            }),
+        );
+    }
+
+    let index_json = serde_json::to_string_pretty(&index).expect("index serialization") + "\n";
+    if let Err(e) = fs::write(out_dir.join("index.json"), index_json) {
+        eprintln!("Cannot write index.json: {e}");
+        return ExitCode::FAILURE;
+    }
+    println!("Wrote {} schemas to {}", schemas.len(), out_dir.display());
+    ExitCode::SUCCESS
+}
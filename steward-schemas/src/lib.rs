@@ -0,0 +1,528 @@
+// path: steward-schemas/src/lib.rs
+
+//! JSON Schemas for the workspace's public serde types.
+//! - One schema per type, generated from the `schema` feature's `JsonSchema` derives
+//! - Stable `$id`s (`urn:localbostrom:schema:<crate>:<Type>`), independent of version
+//! - Each schema carries `x-crate` / `x-crate-version` so consumers can pin
+//! - `export-schemas <out_dir>` writes them as `<crate>.<Type>.schema.json`
+//!
+//! Enum layouts come from the same serde attributes the types use on the
+//! wire (externally tagged unless a type says otherwise), so the schemas
+//! describe exactly what `serde_json` emits.
+
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+
+pub const SCHEMA_ID_PREFIX: &str = "urn:localbostrom:schema";
+
+/// A generated schema and where its type lives.
+pub struct SchemaEntry {
+    pub crate_name: &'static str,
+    pub crate_version: &'static str,
+    pub type_name: &'static str,
+    pub schema: RootSchema,
+}
+
+impl SchemaEntry {
+    fn new(crate_name: &'static str, crate_version: &'static str, type_name: &'static str, mut schema: RootSchema) -> Self {
+        let id = format!("{SCHEMA_ID_PREFIX}:{crate_name}:{type_name}");
+        schema.schema.metadata().id = Some(id);
+        let extensions = &mut schema.schema.extensions;
+        extensions.insert("x-crate".into(), crate_name.into());
+        extensions.insert("x-crate-version".into(), crate_version.into());
+        Self {
+            crate_name,
+            crate_version,
+            type_name,
+            schema,
+        }
+    }
+
+    pub fn id(&self) -> String {
+        format!("{SCHEMA_ID_PREFIX}:{}:{}", self.crate_name, self.type_name)
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("{}.{}.schema.json", self.crate_name, self.type_name)
+    }
+
+    /// Pretty JSON with a trailing newline, so exported files diff cleanly.
+    pub fn to_json_pretty(&self) -> String {
+        let mut json = serde_json::to_string_pretty(&self.schema).expect("schema serialization");
+        json.push('\n');
+        json
+    }
+}
+
+macro_rules! entries {
+    ($crate_name:literal, $version:expr; $($name:ident $(<$lt:lifetime>)?),* $(,)?) => {
+        vec![$( SchemaEntry::new($crate_name, $version, stringify!($name), schema_for!($name $(<$lt>)?)) ),*]
+    };
+}
+
+/// Every exported schema, grouped by crate, in a fixed order.
+pub fn all_schemas() -> Vec<SchemaEntry> {
+    let mut out = {
+        use steward_ids::messages::*;
+        use steward_ids::metrics::*;
+        use steward_ids::receipts::*;
+        use steward_ids::stream::*;
+        use steward_ids::*;
+        entries!("steward-ids", steward_ids::SCHEMA_CRATE_VERSION;
+            Did,
+            CapabilityId,
+            MissionId,
+            MetricKey,
+            LanguageTag,
+            IdError,
+            MetricValue,
+            CustomMetricSpec,
+            ReasonCode,
+            Reason,
+            VoteReceipt,
+            CountedBallots,
+            Compression,
+            StreamHeader,
+            StreamManifest,
+        )
+    };
+
+    {
+        use aln_karma::*;
+        out.extend(entries!("aln-karma", aln_karma::SCHEMA_CRATE_VERSION;
+            VNodeId,
+            ImpactMetrics,
+            BaselineModel,
+            JusticeConstraints,
+            LeakageEstimate,
+            ShardPolicy,
+            ShardPolicyRegistry,
+            PricingTable,
+            CarbonAccounting,
+            KarmaAllowance,
+            VintageDecay,
+            VintageRule,
+            VintageWindow,
+            AllowanceInvalidation,
+            KarmaLedger,
+            BudgetPurpose,
+            AllocationDraw,
+            AllocationRecord,
+            AllocationStatus,
+            BudgetShortfall,
+            ShortfallPolicy,
+            VNodeBudget,
+            SiblingSide,
+            ProofStep,
+            MerkleProof,
+            NearMissCategory,
+            NearMissSeverity,
+            NearMissEvent,
+            DataQualityAnnotation,
+            RefSnapshot,
+            RefError,
+            RefStatus,
+            RefAudit,
+            RefAuditReport,
+            SafetyEpochManifest,
+            EpochChain,
+            TrendMetric,
+            TrendConfig,
+            TrendKind,
+            TrendFinding,
+            EpochForecast,
+            MetricDelta,
+            ReconciliationRecord,
+            CalibrationSummary,
+            ReportKind,
+            RebaselineReport,
+            EpochDivergence,
+            ChainComparison,
+        ));
+    }
+
+    {
+        use planetary_stewardship_runtime::*;
+        out.extend(entries!("planetary_stewardship_runtime", planetary_stewardship_runtime::SCHEMA_CRATE_VERSION;
+            ModuleId,
+            AttestationId,
+            StewardModule,
+            EthicsContext,
+            EthicsDecision,
+            SaepConfig,
+            ConsentRecord,
+            ConsentChange,
+            ConsentEvent,
+            ObserverFailure,
+            ConsentEvidencePolicy,
+            ImpactMetrics,
+            ImpactSummary,
+            StewardshipAttestation,
+            AttestationOrigin,
+            EvidenceArtifact,
+            EvidenceRule,
+            EvidencePolicy,
+            WitnessEndorsement,
+            ImpactThresholdPolicy,
+            IndexKind,
+            BiodiversityDetail,
+            MetricsPolicy,
+            BiodiversityTotal,
+            MissionTemplate,
+            LocalizedTemplate,
+            ResolvedTemplate,
+            AssignedMission,
+            AssignmentKey,
+            AssignmentStatus,
+            OpenAssignmentStatus,
+            ArchivedAssignment,
+            AssignmentFilter,
+            AssignmentState,
+            AbandonmentWindow,
+            AssigneeCohort,
+            AbandonmentCounts,
+            TemplateAbandonment,
+            AssigneeAbandonment,
+            AbandonmentReport,
+            MutationJournal,
+            JournalEntry,
+            JournalOp,
+            AssignmentRef,
+            JournalEffect,
+            ReplayPolicy,
+            JournalOutcome,
+            JournalEntryResult,
+            JournalReport,
+            TicketId,
+            IngestionLimits,
+            QueueScope,
+            IngestionError,
+            TicketStatus,
+            IngestionQueue,
+            TimestampFormat,
+            HeadlineMetric,
+            MetricColumn,
+            CsvMapping,
+            RowError,
+            CsvRowOutcome,
+            CsvRowReport,
+            CsvImportReport,
+            MetricFieldKind,
+            MetricAggregation,
+            MetricField,
+            MetricFieldValue,
+            MetricSchema,
+            MetricSchemaRegistry,
+            ModuleTotals,
+            GovernanceScope,
+            ScopePath,
+            ScopeSettings,
+            GovernanceProposal,
+            QuadraticVote,
+            QuadraticOutcome,
+            IntentEntryId,
+            IntentEntry,
+            IntentLogEntry,
+            IntentLogError,
+            IntentLog,
+            SaepRefs,
+            PlanId,
+            ActionRef,
+            RollbackPlan,
+            PlanInvocation,
+            RegisteredPlan,
+            RollbackPlanRegistry,
+            TimelineOptions,
+            TimelineFlag,
+            TimelineEntry,
+            ActorTimeline,
+            ConsentPromptDescriptor,
+            AttestationRequest,
+            ValidationCode,
+            ValidationIssue,
+            ValidationCondition,
+            ValidationReport,
+            PauseId,
+            ModuleState,
+            ModulePaused,
+            ModuleStatusRegistry,
+            GovernanceAuditAction,
+            GovernanceAuditOutcome,
+            GovernanceAuditEntry,
+            AppliedProposalRef,
+            AppliedProposal,
+            CharterClause,
+            CharterDocument,
+            CharterCitation,
+            CharterRule,
+            CharterRuleSet,
+            OrphanedCharterRule,
+            CharterVersion,
+            CharterRegistry,
+            CharterError,
+            RetentionPolicy,
+            RedactionMode,
+            RedactionTarget,
+            RedactionRecord,
+            RedactionError,
+            RedactionLog,
+            SaepFlag,
+            ConfigChange,
+            ProposalTemplate,
+            TemplateProvenance,
+            ActionKind,
+            ConsentParty,
+            ConsentScope,
+            ConsentFreshness,
+            ConsentRequirements,
+            Difficulty,
+            LocationKind,
+            TemplateQuery,
+            ScoredTemplate,
+            TemplateId,
+            TemplateReviewPolicy,
+            TemplateVerdict,
+            TemplateSignoff,
+            TemplateState,
+            TemplateSubmission,
+            FairnessPolicy,
+            HighDemandCap,
+            ClaimDelay,
+            FairnessViolation,
+            ClaimWindow,
+            PassedOver,
+            LotteryDraw,
+            ViewHead,
+            SkillId,
+            SkillSpec,
+            UnknownSkillPolicy,
+            SkillSuggestion,
+            UnknownSkill,
+            SkillCredit,
+            SkillMatch,
+            SkillTaxonomy,
+            PiiAction,
+            CharClass,
+            PiiKind,
+            ContentFinding,
+            ContentCheck,
+            ContentPolicy,
+            ProposalLimit,
+            ProposalLimits,
+            ProposalLimitExceeded,
+            IdempotencyKey,
+            IdempotencyConflict,
+            VerifierThresholds,
+            VerifierPolicy,
+            VerifierSummary,
+            CoVerification,
+            VerifierFlag,
+            VerifierReport,
+            VerifierDowngrade,
+            VerifierAuditAction,
+            VerifierAuditEntry,
+            VerifierRegistry,
+            RelationshipKind,
+            ConflictTreatment,
+            ConflictDeclaration,
+            ConflictPolicy,
+            AppliedConflict,
+            QuorumAtRisk,
+            ConflictReevaluation,
+            VerificationPolicy,
+            PendingVerificationId,
+            VerifierVerdict,
+            VerifierReview,
+            PendingState,
+            PendingVerification,
+            ReviewError,
+            KeyPurpose,
+            PublicKey,
+            KeyError,
+            RegisteredKey,
+            KeyRevocation,
+            KeyEventAction,
+            KeyEvent,
+            KeyRegistry,
+            ConsentConfig,
+            LedgerConfig,
+            MissionsConfig,
+            GovernanceConfig,
+            RuntimeConfig,
+            ConfigIssue,
+            RuntimeConfigError,
+            ConfigFieldChange,
+            ReloadReport,
+            SaepVerdict,
+            ReviewOutcome,
+            RuleFeedback,
+            FeedbackStore,
+            SaepEscalation,
+            EscalationResolution,
+            RuleWeightChange,
+            RuleAction,
+            RulePerformance,
+            RuleCalibration,
+            SaepCacheConfig,
+            SaepCacheStats,
+            AdminOperation,
+            AdminAction,
+            AdminEffect,
+            DualControlPolicy,
+            PendingAdminAction,
+            AdminProposal,
+            AdminLogEvent,
+            AdminLogEntry,
+            DualControl,
+            NotificationCategory,
+            NotificationSeverity,
+            NotificationId,
+            Notification,
+            DigestFrequency,
+            NotificationPreferences,
+            NotificationDigest,
+            NotificationAck,
+        ));
+    }
+
+    {
+        use the_element::*;
+        out.extend(entries!("the_element", the_element::SCHEMA_CRATE_VERSION;
+            GovernanceTurnId,
+            CapabilityDomain,
+            CapabilityClass,
+            RiskTier,
+            Requirement,
+            CyberneticAbility,
+            AgentCyberProfile,
+            ElementConfig,
+            SafetyEvent,
+            SafetyEventRecord,
+            SafetyReviewThresholds,
+            SafetyEventSummary,
+            SafetyReviewFlag,
+            AbilityListing,
+            AbilityChange,
+            ReassessmentAwaiting,
+            PendingReassessment,
+            ReassessmentEvent,
+            ReassessmentRecord,
+            AbilityUpdate,
+            ConstraintOrigin,
+            UsageConstraints,
+            UsageEvent,
+            UsageRecord,
+            Endorsement,
+            EnablementPolicy,
+            SessionMonitor,
+            AutoPauseCause,
+            AutoPausedSession,
+            ConstraintFiring,
+            SandboxViolation,
+            UseOutcome,
+            SimulatedUse,
+            SandboxReport,
+        ));
+    }
+
+    {
+        use cybernetic_governance::amendment::*;
+        use cybernetic_governance::voting::*;
+        use cybernetic_governance::*;
+        out.extend(entries!("cybernetic-governance", cybernetic_governance::SCHEMA_CRATE_VERSION;
+            CompetitiveDomain,
+            CategoryId,
+            CategoryCount,
+            ProposalTarget,
+            GovernanceProposal,
+            ImpactStatement,
+            GovernanceVoteOutcome,
+            GovernanceConstitution,
+            ThresholdMode,
+            NonrestrictableRule,
+            ProtectionRecord,
+            DomainState,
+            ExpiredRestriction,
+            ChangeKind,
+            UnknownCapabilityPolicy,
+            AppliedChange,
+            CommitOutcome,
+            DomainChangeResult,
+            AmendmentRules,
+            ConstitutionChange,
+            ConstitutionAmendment,
+            AmendmentStatus,
+            AmendmentAssessment,
+            AmendmentRecord,
+            AppliedAmendment,
+            AmendmentCancellation,
+            VoterId,
+            VoterRecord,
+            VoterRegistry,
+            VoterSnapshot,
+            TallySource,
+            VoteSession,
+            UnrevealedPolicy,
+            CommitRevealRules,
+            CommitRevealWindow,
+            VoteParticipation,
+            CommitRevealError,
+            ThresholdCheck,
+            DomainPreview,
+            ProposalPreview,
+            EnabledCapability<'static>,
+            DisabledCapability<'static>,
+            EffectiveCapabilities<'static>,
+            OracleVerdict,
+            OracleReview,
+            OracleOverride,
+            MatchOverride,
+            GovernanceEvent,
+            GovernanceLogEntry,
+            LogVerifyError,
+            ConstitutionSignature,
+            SignedConstitutionDocument,
+            GovernanceEventLog,
+            GovernanceSnapshot,
+            RestoreError,
+            DomainTemplate,
+            DomainOverrides,
+            TemplateDrift,
+            ProposalLimit,
+            ProposalLimits,
+            ProposalLimitExceeded,
+            AppealGrounds,
+            AppealVerdict,
+            AppealDecision,
+            CapabilityAppeal,
+            AppealRegistry,
+            HeightCheckpoint,
+            MappingError,
+        ));
+    }
+
+    {
+        use steward_export::*;
+        out.extend(entries!("steward-export", steward_export::SCHEMA_CRATE_VERSION;
+            SubjectExport,
+            ExportCategory,
+            ChainHead,
+            VerifierRoleKind,
+            VerifierRole,
+            LedgerExport,
+            MissionsExport,
+            ElementExport,
+            CastBallot,
+            GovernanceExport,
+        ));
+    }
+
+    out
+}
+
+/// Schema for `crate_name`'s `type_name`, if exported.
+pub fn find_schema(crate_name: &str, type_name: &str) -> Option<SchemaEntry> {
+    all_schemas()
+        .into_iter()
+        .find(|e| e.crate_name == crate_name && e.type_name == type_name)
+}
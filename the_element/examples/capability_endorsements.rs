@@ -0,0 +1,101 @@
+// path: the_element/examples/capability_endorsements.rs
+
+//! Example: veteran athletes vouching for a newcomer's exoskeleton assist.
+//! - The arena's policy requires one active endorsement from a designated coach before
+//!   `request_enable` lets anyone new turn on `motor:exoskeleton_assist`
+//! - Self-endorsement, endorsing an ability one cannot use and an already-expired endorsement
+//!   are refused; an endorsement from someone outside the coach set is recorded but does not
+//!   satisfy the policy
+//! - The policy checks existence only: one endorsement and three, a terse statement and a
+//!   long one, all give the same answer
+//! - Expired and revoked endorsements stop counting; only the endorser can revoke, and the
+//!   records stay visible to a clinic through `endorsements_for`
+
+use std::sync::Arc;
+
+use steward_runtime_support::FixedClock;
+use the_element::{default_element, AgentId, CapabilityId, EnablementPolicy, Endorsement, ReasonCode};
+
+const T0: u64 = 1_767_225_600_000;
+const DAY_MS: u64 = 24 * 60 * 60 * 1_000;
+
+fn main() -> Result<(), String> {
+    let [ana, ben, teammate, kofi, mei] =
+        ["coach:ana", "coach:ben", "athlete:lina", "athlete:kofi", "athlete:mei"]
+            .map(|n| AgentId::new(format!("did:psv:arena:{n}")).expect("valid DID"));
+    let exo = CapabilityId::new("motor:exoskeleton_assist").expect("valid capability");
+    let emg = CapabilityId::new("motor:emg_calibration").expect("valid capability");
+    let clock = Arc::new(FixedClock::new(T0));
+    let mut element = default_element().with_clock(clock.clone());
+    // Everyone is calibrated, so only endorsements stand between them and the exoskeleton.
+    for athlete in [&ana, &ben, &teammate, &kofi, &mei] {
+        element.request_enable(athlete, &emg, true)?;
+    }
+    for veteran in [&ana, &ben, &teammate] {
+        element.request_enable(veteran, &exo, true)?;
+    }
+    let coaches = [ana.clone(), ben.clone()];
+    element.set_enablement_policy(EnablementPolicy::default().require_endorsement(exo.clone(), coaches));
+
+    // 1. Without an endorsement the newcomer is held; bad endorsements are refused.
+    let held = element.request_enable(&kofi, &exo, true).expect_err("needs an endorsement");
+    assert_eq!(held.code, ReasonCode::ElementEndorsementRequired);
+    let own = element.issue_endorsement(&ana, &ana, &exo, "I'm ready", None).expect_err("self");
+    assert_eq!(own.code, ReasonCode::ElementSelfEndorsement);
+    let unheld = element.issue_endorsement(&mei, &kofi, &exo, "Looks ready", None).expect_err("mei cannot use it");
+    assert_eq!(unheld.code, ReasonCode::ElementEndorserLacksCapability);
+    let stale = element.issue_endorsement(&ana, &kofi, &exo, "Ready", Some(T0)).expect_err("already expired");
+    assert_eq!(stale.code, ReasonCode::ElementEndorsementExpiry);
+
+    // 2. A teammate's endorsement is kept on record, but lina is not a designated coach.
+    element.issue_endorsement(&teammate, &kofi, &exo, "Trained together all season", None)?;
+    assert!(element.request_enable(&kofi, &exo, true).is_err());
+
+    // 3. One coach's endorsement, valid for a week, is enough.
+    element.issue_endorsement(&ana, &kofi, &exo, "Balance drills passed", Some(T0 + 7 * DAY_MS))?;
+    element.request_enable(&kofi, &exo, true)?;
+    assert!(element.can_use(&kofi, &exo));
+
+    // 4. Existence only: more endorsements or longer statements change nothing.
+    let policy = element.enablement_policy().clone();
+    let endorse = |endorser: &AgentId, statement: &str| Endorsement {
+        endorsement_id: format!("endorsement:{endorser}"),
+        endorser: endorser.clone(),
+        subject: mei.clone(),
+        capability_id: exo.clone(),
+        statement: statement.into(),
+        issued_ms: T0,
+        expires_ms: None,
+        revoked_ms: None,
+    };
+    let terse = endorse(&ana, "ok");
+    let effusive = endorse(&ben, &"Exceptional control under load. ".repeat(40));
+    let outsider = endorse(&teammate, "Also ready");
+    assert!(policy.permits(&exo, &[&terse], T0));
+    assert_eq!(policy.permits(&exo, &[&terse], T0), policy.permits(&exo, &[&terse, &effusive, &outsider], T0));
+    assert_eq!(policy.permits(&exo, &[&terse], T0), policy.permits(&exo, &[&effusive], T0));
+    assert!(!policy.permits(&exo, &[&outsider], T0));
+    assert!(!policy.permits(&exo, &[], T0));
+    let focus = CapabilityId::new("cognitive:focus_enhancer").expect("valid capability");
+    assert!(policy.permits(&focus, &[], T0), "abilities the policy does not name need none");
+
+    // 5. Expiry and revocation: mei's day-long endorsement lapses, ben's is revoked.
+    let brief = element.issue_endorsement(&ana, &mei, &exo, "Ready for today's heat", Some(T0 + DAY_MS))?;
+    clock.set(T0 + DAY_MS);
+    assert!(!brief.is_active(T0 + DAY_MS));
+    assert!(element.request_enable(&mei, &exo, true).is_err(), "expired");
+    let revocable = element.issue_endorsement(&ben, &mei, &exo, "Ready", None)?;
+    let refused = element.revoke_endorsement(&ana, &revocable.endorsement_id).expect_err("ben's to revoke");
+    assert_eq!(refused.code, ReasonCode::ElementEndorsementRevokeEndorserOnly);
+    clock.advance(1_000);
+    let revoked = element.revoke_endorsement(&ben, &revocable.endorsement_id)?;
+    assert_eq!(revoked.revoked_ms, Some(T0 + DAY_MS + 1_000));
+    assert!(element.request_enable(&mei, &exo, true).is_err(), "revoked");
+
+    // 6. The clinic sees every record as issued: no counts, no ranking.
+    let records = element.endorsements_for(&mei, &exo);
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(|e| !e.is_active(T0 + DAY_MS + 1_000)));
+    println!("{}", serde_json::to_string_pretty(&element.endorsements_for(&kofi, &exo)).expect("json"));
+    Ok(())
+}
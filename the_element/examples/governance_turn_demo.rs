@@ -1,7 +1,39 @@
 // path: the_element/examples/governance_turn_demo.rs
 
+//! Example: governance turns that expand an agent's abilities, and ability updates that
+//! put enabled abilities up for reassessment.
+//! - A cosmetic update changes nothing; a riskier, reclassified or re-prerequisited one
+//!   holds the ability back from every agent that enabled it
+//! - Opt-in abilities wait for the agent's own re-confirmation, which no governance turn
+//!   can give; a prerequisite-only change clears itself once the prerequisites are there
+
+use std::collections::HashSet;
+
 use the_element::*;
 
+fn cap(id: &str) -> CapabilityId {
+    CapabilityId(id.into())
+}
+
+/// A social ability anyone may enable without explicit opt-in.
+fn social(id: &str, requires: &[&str]) -> CyberneticAbility {
+    CyberneticAbility {
+        id: cap(id),
+        name: id.into(),
+        domain: CapabilityDomain::Social,
+        class_: CapabilityClass::Enhancement,
+        risk_tier: RiskTier::Low,
+        description: "Multi-agent coordination aid.".into(),
+        requires: requires.iter().map(|id| cap(id)).collect(),
+        ai_delegable: false,
+        require_explicit_opt_in: false,
+    }
+}
+
+fn ability(element: &TheElement, id: &str) -> CyberneticAbility {
+    element.list_abilities().into_iter().find(|l| l.ability.id == cap(id)).expect("in the library").ability
+}
+
 fn main() -> Result<(), String> {
     let mut element = default_element();
 
@@ -38,5 +70,81 @@ fn main() -> Result<(), String> {
     // If a later governance-turn tried to *restrict* more than 33% of these,
     // or touch baseline abilities, it would be rejected automatically. [web:21][web:26][web:29]
 
+    // A cosmetic update (new wording) leaves everyone's abilities alone.
+    let patterns = cap("cognitive:pattern_assist");
+    let mut reworded = ability(&element, "cognitive:pattern_assist");
+    reworded.description = "Highlights recurring structure in what you are looking at.".into();
+    let update = element.upsert_ability(reworded);
+    assert_eq!(update.changes, [AbilityChange::CosmeticOnly]);
+    assert!(update.pending_agents.is_empty() && element.can_use(&agent, &patterns));
+
+    // Risk increased: the overlay turns invasive. It stays enabled but is held back.
+    let overlay = cap("sensory:xr_overlay_competitive");
+    let mut riskier = ability(&element, "sensory:xr_overlay_competitive");
+    riskier.risk_tier = RiskTier::High;
+    let update = element.upsert_ability(riskier);
+    assert_eq!(update.changes, [AbilityChange::RiskIncreased]);
+    assert_eq!(update.pending_agents, vec![agent.clone()]);
+    assert!(!element.effective_capabilities(&agent).contains(&overlay));
+    assert!(element.get_profile(&agent).unwrap().enabled_capabilities.contains(&overlay));
+
+    // Governance cannot re-confirm for the agent: unlocking it again changes nothing.
+    let mut relock = HashSet::new();
+    relock.insert(overlay.clone());
+    element.governance_turn(&element.next_turn_id(), &agent, &HashSet::new(), &relock)?;
+    assert!(!element.can_use(&agent, &overlay));
+
+    // Only the agent's own explicit opt-in, under the new terms, brings it back.
+    element.request_enable(&agent, &overlay, false).expect_err("opt-in still required");
+    element.request_enable(&agent, &overlay, true)?;
+    assert!(element.can_use(&agent, &overlay));
+
+    // Class changed: the focus enhancer becomes experimental, and waits for re-confirmation.
+    let focus = cap("cognitive:focus_enhancer");
+    let mut experimental = ability(&element, "cognitive:focus_enhancer");
+    experimental.class_ = CapabilityClass::Experimental;
+    assert_eq!(element.upsert_ability(experimental).changes, [AbilityChange::ClassChanged]);
+    let pending = element.pending_reassessments(&agent);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].awaiting, ReassessmentAwaiting::AgentConfirmation);
+    // Blocking it instead withdraws the reassessment.
+    element.request_block(&agent, &focus)?;
+    assert!(element.pending_reassessments(&agent).is_empty());
+
+    // Prerequisites changed: the mesh now needs a presence beacon. An agent who already
+    // has the beacon keeps the mesh; one who does not gets it back once they enable it.
+    let trinity = AgentId("did:aln:player:trinity".into());
+    let (mesh, beacon) = (cap("social:coordination_mesh"), cap("social:presence_beacon"));
+    element.upsert_ability(social("social:presence_beacon", &[]));
+    element.upsert_ability(social("social:coordination_mesh", &[]));
+    element.request_enable(&agent, &mesh, false)?;
+    element.request_enable(&trinity, &beacon, false)?;
+    element.request_enable(&trinity, &mesh, false)?;
+    let update = element.upsert_ability(social("social:coordination_mesh", &["social:presence_beacon"]));
+    assert_eq!(update.changes, [AbilityChange::PrerequisitesChanged]);
+    assert_eq!((update.pending_agents, update.revalidated_agents), (vec![agent.clone()], vec![trinity.clone()]));
+    assert_eq!(element.pending_reassessments(&agent)[0].awaiting, ReassessmentAwaiting::Prerequisites);
+    assert!(!element.can_use(&agent, &mesh) && element.can_use(&trinity, &mesh));
+    element.request_enable(&agent, &beacon, false)?;
+    assert!(element.can_use(&agent, &mesh));
+
+    // Several changes at once are all reported, in classification order.
+    let mut overhaul = social("social:coordination_mesh", &[]);
+    overhaul.risk_tier = RiskTier::Medium;
+    overhaul.class_ = CapabilityClass::Experimental;
+    let changes = element.upsert_ability(overhaul).changes;
+    assert_eq!(
+        changes,
+        [AbilityChange::RiskIncreased, AbilityChange::PrerequisitesChanged, AbilityChange::ClassChanged]
+    );
+
+    println!("Reassessment log for {}:", agent.as_str());
+    for record in element.reassessment_log(&agent) {
+        println!(" {} {:?} {} {:?}", record.seq, record.event, record.capability.0, record.changes);
+    }
+    let events: Vec<ReassessmentEvent> = element.reassessment_log(&agent).iter().map(|r| r.event).collect();
+    use ReassessmentEvent::*;
+    assert_eq!(events, [Pending, Reconfirmed, Pending, Withdrawn, Pending, Revalidated, Pending]);
+
     Ok(())
 }
@@ -0,0 +1,86 @@
+// path: the_element/examples/prerequisite_alternatives.rs
+
+//! Example: prerequisites with alternatives (run with `--features test-util`).
+//! - The default exoskeleton assist needs the baseline neuroshield and either calibration;
+//!   a denial names what is left to enable, alternatives and all
+//! - Nested `All`/`Any` groups are met, and reported unmet, branch by branch
+//! - Restricting one calibration keeps the exoskeleton usable on the other; restricting both
+//!   leaves it enabled but unusable until one is back
+//! - Abilities written with a flat prerequisite list still load, as an `All` of it
+
+use std::collections::HashSet;
+
+use the_element::builders::{fixture_element, AbilityBuilder};
+use the_element::{AgentId, CapabilityId, CyberneticAbility, ReasonCode, Requirement};
+
+fn cap(id: &str) -> CapabilityId {
+    CapabilityId::new(id).expect("valid capability")
+}
+
+fn held(ids: &[&str]) -> HashSet<CapabilityId> {
+    ids.iter().map(|id| cap(id)).collect()
+}
+
+fn main() -> Result<(), String> {
+    let mut element = fixture_element(7);
+    let ana = AgentId::new("did:psv:clinic:ana").expect("valid DID");
+    let [exo, emg, bci] = ["motor:exoskeleton_assist", "motor:emg_calibration", "motor:bci_motor_calibration"].map(cap);
+
+    // 1. No calibration yet: refused, naming both ways forward.
+    let refused = element.request_enable(&ana, &exo, true).expect_err("uncalibrated");
+    assert_eq!(refused.code, ReasonCode::ElementMissingPrerequisite);
+    let message = refused.to_string();
+    assert!(message.ends_with("motor:emg_calibration or motor:bci_motor_calibration"), "{message}");
+
+    // 2. Either calibration is enough; ana does both.
+    element.request_enable(&ana, &bci, true)?;
+    element.request_enable(&ana, &exo, true)?;
+    element.request_enable(&ana, &emg, true)?;
+    assert!(element.can_use(&ana, &exo));
+
+    // 3. Nested groups: lab work needs a badge, and a mentor or both safety courses.
+    let lab = AbilityBuilder::new("cognitive:lab_access")
+        .requires(&["meta:lab_badge"])
+        .requirement(Requirement::Any(vec![
+            Requirement::cap(cap("meta:mentor")),
+            Requirement::all_of([cap("meta:course_chem"), cap("meta:course_fire")]),
+        ]))
+        .build_valid();
+    let requires = &lab.requires;
+    assert_eq!(requires.to_string(), "meta:lab_badge and (meta:mentor or (meta:course_chem and meta:course_fire))");
+    assert!(requires.is_met_by(&held(&["meta:lab_badge", "meta:mentor"])));
+    assert!(requires.is_met_by(&held(&["meta:lab_badge", "meta:course_chem", "meta:course_fire"])));
+    assert!(!requires.is_met_by(&held(&["meta:mentor", "meta:course_chem", "meta:course_fire"])), "no badge");
+    let partway = requires.unmet(&held(&["meta:lab_badge", "meta:course_fire"])).expect("one course short");
+    assert_eq!(partway.to_string(), "meta:mentor or meta:course_chem");
+    assert!(!Requirement::Any(Vec::new()).is_met_by(&held(&["meta:mentor"])), "no alternative to take");
+    assert!(Requirement::default().is_met_by(&HashSet::new()));
+
+    // 4. A branch restricted: the other calibration still holds, so the exoskeleton stays usable.
+    let turn = element.next_turn_id();
+    element.governance_turn(&turn, &ana, &HashSet::from([emg.clone()]), &HashSet::new())?;
+    assert!(!element.can_use(&ana, &emg) && element.can_use(&ana, &exo));
+
+    // 5. Both branches restricted: still enabled, no longer usable, until one is back.
+    let turn = element.next_turn_id();
+    element.governance_turn(&turn, &ana, &HashSet::from([bci.clone()]), &HashSet::new())?;
+    let profile = element.get_profile(&ana).expect("ana has a profile");
+    assert!(profile.enabled_capabilities.contains(&exo));
+    assert!(!element.can_use(&ana, &exo) && !element.effective_capabilities(&ana).contains(&exo));
+    element.request_enable(&ana, &emg, true)?;
+    assert!(element.can_use(&ana, &exo));
+
+    // 6. A flat list, as abilities were written before, loads as an `All`; the new form round-trips.
+    let mut legacy = serde_json::to_value(AbilityBuilder::new("social:presence_beacon").build()).expect("json");
+    legacy["requires"] = serde_json::json!(["security:neuroshield_basic", "meta:emergency_exit"]);
+    let loaded: CyberneticAbility = serde_json::from_value(legacy).expect("flat requires");
+    assert_eq!(loaded.requires, Requirement::all_of([cap("meta:emergency_exit"), cap("security:neuroshield_basic")]));
+    let written = serde_json::to_value(&lab).expect("json");
+    let reread: CyberneticAbility = serde_json::from_value(written.clone()).expect("expression");
+    assert_eq!(reread.requires, lab.requires);
+
+    let listing = element.list_abilities().into_iter().find(|l| l.ability.id == exo).expect("in the library");
+    println!("{exo} requires {}", listing.ability.requires);
+    println!("{}", serde_json::to_string_pretty(&written["requires"]).expect("json"));
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// path: the_element/examples/safety_events.rs
+
+//! Example: emergency exits and pauses surfacing an ability for safety review.
+//! - A synthetic history: most exits happen with the focus enhancer engaged
+//! - The enhancer is flagged on exactly the exit that brings it to the threshold, and
+//!   `list_abilities` shows the flag; the ability itself stays enabled
+//! - Only the agent can export or purge their safety log
+
+use std::collections::HashSet;
+
+use the_element::{default_element, AgentId, CapabilityId, SafetyEvent, SafetyReviewThresholds};
+
+const T0: u64 = 1_767_225_600_000;
+const MINUTE_MS: u64 = 60_000;
+
+fn main() -> Result<(), String> {
+    let focus = CapabilityId::new("cognitive:focus_enhancer").expect("valid capability");
+    let patterns = CapabilityId::new("cognitive:pattern_assist").expect("valid capability");
+    let exit = |active: &[&CapabilityId]| SafetyEvent::EmergencyExit {
+        active_capabilities_snapshot: active.iter().map(|c| (*c).clone()).collect::<HashSet<_>>(),
+    };
+
+    let mut element = default_element()
+        .with_safety_review_thresholds(SafetyReviewThresholds { min_exits: 3, exit_correlation: 0.6 });
+    let agents: Vec<AgentId> = ["ana", "kofi", "mei"]
+        .iter()
+        .map(|name| AgentId::new(format!("did:aln:player:{name}")).expect("valid DID"))
+        .collect();
+    for agent in &agents {
+        element.request_enable(agent, &focus, true)?;
+        element.request_enable(agent, &patterns, true)?;
+    }
+
+    // 1. Pauses count against whatever the agent could use at the time.
+    element.record_safety_event(&agents[0], SafetyEvent::Pause { duration_ms: Some(5 * MINUTE_MS) }, T0);
+    element.record_safety_event(&agents[0], SafetyEvent::Resume, T0 + 5 * MINUTE_MS);
+
+    // 2. Exits: focus, patterns, focus, focus + patterns. Focus reaches 3 exits of 4
+    //    (0.75) on the last one; patterns has 2 and is never judged.
+    let history = [
+        (&agents[0], vec![&focus]),
+        (&agents[1], vec![&patterns]),
+        (&agents[2], vec![&focus]),
+        (&agents[1], vec![&focus, &patterns]),
+    ];
+    for (i, (agent, active)) in history.iter().enumerate() {
+        assert!(element.safety_review_flag(&focus).is_none(), "not flagged before the threshold");
+        element.record_safety_event(agent, exit(active), T0 + (i as u64 + 1) * 10 * MINUTE_MS);
+    }
+    let flag = element.safety_review_flag(&focus).expect("flagged at the threshold");
+    assert_eq!((flag.emergency_exits, flag.exit_correlation), (3, 0.75));
+    assert!(element.safety_review_flag(&patterns).is_none());
+
+    let summary = element.safety_event_summary(&focus, (T0, T0 + 60 * MINUTE_MS));
+    println!("{}", serde_json::to_string_pretty(&summary).expect("summary json"));
+    assert_eq!((summary.emergency_exits, summary.pauses, summary.agents, summary.total_exits), (3, 1, 3, 4));
+
+    // 3. Flagged, not disabled: the library shows the flag and agents keep the ability.
+    let flagged: Vec<String> = element
+        .list_abilities()
+        .into_iter()
+        .filter(|listing| listing.safety_review.is_some())
+        .map(|listing| listing.ability.id.0)
+        .collect();
+    assert_eq!(flagged, vec![focus.0.clone()]);
+    assert!(agents.iter().all(|agent| element.can_use(agent, &focus)));
+
+    // 4. The log belongs to the agent.
+    assert!(element.export_safety_log(&agents[1], &agents[0]).is_err());
+    assert_eq!(element.export_safety_log(&agents[0], &agents[0])?.len(), 3);
+    assert!(element.purge_safety_log(&agents[1], &agents[0]).is_err());
+    assert_eq!(element.purge_safety_log(&agents[0], &agents[0])?, 3);
+    assert_eq!(element.safety_event_summary(&focus, (T0, T0 + 60 * MINUTE_MS)).emergency_exits, 2);
+    assert!(element.safety_review_flag(&focus).is_some(), "purging keeps raised flags");
+    Ok(())
+}
@@ -0,0 +1,127 @@
+// path: the_element/examples/sandbox_session.rs
+
+//! Example: trying out an exoskeleton therapy envelope in a sandbox before prescribing it.
+//! - The sandbox is built from Mei's profile; the clinician's 45-minute daily cap and the
+//!   therapist's check-ins every 10 minutes are configured in it alone
+//! - A second session runs into the cap and is force-paused; a therapist dropping out for
+//!   20 minutes is a monitor violation
+//! - An emergency exit closes the open session; a pause refuses new ones until it lapses
+//! - Mei's real profile and logs are untouched throughout
+//! - A window meant for the exoskeleton, set on the emergency exit instead, is caught: the
+//!   exit, a baseline right, stops being usable at 18:00
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use steward_runtime_support::FixedClock;
+use the_element::{
+    default_element, AgentId, AutoPauseCause, CapabilityId, ConstraintOrigin, ReasonCode, SafetyEvent,
+    SandboxViolation, SessionMonitor, UsageConstraints, UseOutcome,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const MINUTE_MS: u64 = 60_000;
+const HOUR_MS: u64 = 60 * MINUTE_MS;
+
+/// `day` days after T0, at `hour`:`minute` UTC.
+fn at(day: u64, hour: u64, minute: u64) -> u64 {
+    T0 + day * 24 * HOUR_MS + hour * HOUR_MS + minute * MINUTE_MS
+}
+
+fn main() -> Result<(), String> {
+    let mei = AgentId::new("did:aln:patient:mei").expect("valid DID");
+    let clinician = AgentId::new("did:aln:clinic:rehab-7").expect("valid DID");
+    let exo = CapabilityId::new("motor:exoskeleton_assist").expect("valid capability");
+    let emg = CapabilityId::new("motor:emg_calibration").expect("valid capability");
+    let exit = CapabilityId::new("meta:emergency_exit").expect("valid capability");
+    let mut element = default_element().with_clock(Arc::new(FixedClock::new(at(0, 9, 0))));
+    element.request_enable(&mei, &emg, true)?;
+    element.request_enable(&mei, &exo, true)?;
+    let template = element.get_profile(&mei).cloned().expect("profile created on enable");
+
+    // 1. The envelope under trial: at most 45 minutes a day, a check-in every 10 minutes.
+    let envelope = UsageConstraints {
+        max_daily_duration_ms: Some(45 * MINUTE_MS),
+        allowed_hours: None,
+        timezone_offset_min: 0,
+        set_by: ConstraintOrigin::ClinicalAdvisor(clinician.clone()),
+    };
+    let therapist =
+        SessionMonitor { capability: exo.clone(), check_in_every_ms: 10 * MINUTE_MS, max_silence_ms: 15 * MINUTE_MS };
+    let mut sandbox = element.sandbox(template.clone()).with_monitor(therapist);
+    sandbox.set_usage_constraints(&clinician, &mei, &exo, envelope)?;
+    assert_eq!(sandbox.now_ms(), at(0, 9, 0));
+
+    // 2. A 30-minute session completes; the next one reaches the cap after 15 minutes.
+    let first = sandbox.simulate_use(&exo, 30 * MINUTE_MS);
+    assert_eq!((first.outcome, first.ran_ms), (UseOutcome::Completed, 30 * MINUTE_MS));
+    sandbox.advance_time(HOUR_MS);
+    let second = sandbox.simulate_use(&exo, 30 * MINUTE_MS);
+    assert_eq!(second.ran_ms, 15 * MINUTE_MS);
+    let UseOutcome::AutoPaused { cause: AutoPauseCause::UsageConstraint { reason } } = &second.outcome else {
+        return Err(format!("expected a force-pause, got {:?}", second.outcome));
+    };
+    assert_eq!(reason.code, ReasonCode::ElementUsageDailyCap);
+    let refused = sandbox.simulate_use(&exo, 10 * MINUTE_MS);
+    assert!(matches!(refused.outcome, UseOutcome::Refused { .. }), "still at the cap");
+    let report = sandbox.report();
+    assert_eq!((report.constraints_fired.len(), report.auto_paused.len()), (2, 1));
+    assert!(report.is_clean());
+
+    // 3. The next morning the therapist drops out for 20 minutes: 15 is the most allowed.
+    sandbox.advance_time(at(1, 9, 0) - sandbox.now_ms());
+    sandbox.silence_monitor(&exo, 20 * MINUTE_MS)?;
+    assert_eq!(sandbox.simulate_use(&exo, 30 * MINUTE_MS).outcome, UseOutcome::Completed);
+    let silent = SandboxViolation::MonitorSilent {
+        at_ms: at(1, 9, 16),
+        capability: exo.clone(),
+        silent_ms: 16 * MINUTE_MS,
+        max_silence_ms: 15 * MINUTE_MS,
+    };
+    assert_eq!(sandbox.report().violations, [silent]);
+
+    // 4. An emergency exit mid-session closes it, and names it in the exit's snapshot.
+    sandbox.advance_time(at(1, 10, 0) - sandbox.now_ms());
+    sandbox.start_session(&mei, &exo)?;
+    sandbox.advance_time(5 * MINUTE_MS);
+    let record = sandbox.inject(SafetyEvent::EmergencyExit { active_capabilities_snapshot: HashSet::new() });
+    assert_eq!(record.active_capabilities, [exo.clone()].into());
+    assert!(sandbox.open_session(&mei, &exo).is_none());
+
+    // 5. A 30-minute pause refuses sessions until it lapses.
+    sandbox.inject(SafetyEvent::Pause { duration_ms: Some(30 * MINUTE_MS) });
+    let UseOutcome::Refused { reason } = sandbox.simulate_use(&exo, 5 * MINUTE_MS).outcome else {
+        return Err("a pause should refuse new sessions".into());
+    };
+    println!("refused: {reason}");
+    sandbox.advance_time(30 * MINUTE_MS);
+    assert_eq!(sandbox.simulate_use(&exo, 5 * MINUTE_MS).outcome, UseOutcome::Completed);
+    let report = sandbox.report();
+    assert_eq!(report.auto_paused.last().map(|p| &p.cause), Some(&AutoPauseCause::EmergencyExit));
+    assert_eq!(report.safety_events.len(), 2);
+    println!("{}", serde_json::to_string_pretty(&report).expect("report json"));
+
+    // 6. None of it reached Mei's real profile or logs.
+    assert!(element.usage_log(&mei).is_empty());
+    assert!(element.usage_constraints(&mei, &exo).is_none());
+    assert!(element.export_safety_log(&mei, &mei)?.is_empty());
+    assert_eq!(element.get_profile(&mei).map(|p| &p.enabled_capabilities), Some(&template.enabled_capabilities));
+
+    // 7. Misconfigured: the 08:00–18:00 window meant for the exoskeleton lands on the exit.
+    let mut sandbox = element.sandbox(template);
+    let window = UsageConstraints {
+        max_daily_duration_ms: None,
+        allowed_hours: Some((8, 18)),
+        timezone_offset_min: 0,
+        set_by: ConstraintOrigin::ClinicalAdvisor(clinician.clone()),
+    };
+    sandbox.set_usage_constraints(&clinician, &mei, &exit, window)?;
+    sandbox.advance_time(at(0, 18, 30) - sandbox.now_ms());
+    let late = sandbox.simulate_use(&exo, 30 * MINUTE_MS);
+    assert_eq!(late.outcome, UseOutcome::Completed, "the exoskeleton itself was never constrained");
+    let report = sandbox.report();
+    assert!(!report.is_clean());
+    let unavailable = SandboxViolation::BaselineUnavailable { at_ms: at(0, 18, 0), capability: exit };
+    assert_eq!(report.violations, [unavailable]);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// path: the_element/examples/streamed_profiles.rs
+
+//! Example: agent profiles moved to another element through a stream
+//! (gzip-compressed when run with `--features flate2`).
+//! - `export_profiles` writes the profiles by agent id, one at a time; the receiving element
+//!   then holds the same profiles and grants the same abilities
+//! - An export cut short restores no profile at all
+//! - A profile stored before agent ids were DIDs still reads, as `did:legacy:<id>`
+
+use the_element::{default_element, AgentCyberProfile, AgentId, CapabilityId, Compression, StreamError, TheElement};
+
+const AGENTS: usize = 500;
+
+fn main() -> Result<(), String> {
+    let compression = if Compression::Gzip.available() { Compression::Gzip } else { Compression::None };
+    let focus = CapabilityId::new("cognitive:focus_enhancer").expect("valid capability");
+    let patterns = CapabilityId::new("cognitive:pattern_assist").expect("valid capability");
+    let agents: Vec<AgentId> =
+        (0..AGENTS).map(|i| AgentId::new(format!("did:aln:player:p{i:03}")).expect("valid DID")).collect();
+    let mut element = default_element();
+    for (i, agent) in agents.iter().enumerate() {
+        element.request_enable(agent, &focus, true)?;
+        if i % 3 == 0 {
+            element.request_enable(agent, &patterns, true)?;
+        }
+    }
+
+    // 1. Out and back: the same profiles, the same abilities.
+    let mut exported = Vec::new();
+    let manifest = element.export_profiles(&mut exported, compression).map_err(|e| e.to_string())?;
+    assert_eq!(manifest.records, AGENTS as u64);
+    let mut restored = default_element();
+    assert_eq!(restored.restore_profiles(exported.as_slice()).map_err(|e| e.to_string())?, AGENTS);
+    let json = |element: &TheElement, agent| serde_json::to_value(element.get_profile(agent)).expect("json");
+    for agent in &agents {
+        assert_eq!(json(&restored, agent), json(&element, agent));
+        assert_eq!(restored.can_use(agent, &patterns), element.can_use(agent, &patterns));
+    }
+    println!("{AGENTS} profiles in {} bytes ({compression:?})", exported.len());
+
+    // 2. Cut short: no profile restored.
+    let mut cut = default_element();
+    let error = cut.restore_profiles(&exported[..exported.len() / 2]).expect_err("interrupted");
+    assert!(matches!(error, StreamError::Interrupted { .. }), "{error}");
+    assert!(agents.iter().all(|agent| cut.get_profile(agent).is_none()));
+    println!("{error}");
+
+    // 3. A pre-DID profile: the bare id maps to a legacy DID; a malformed DID still fails.
+    let legacy = serde_json::json!({
+        "agent": "player-042",
+        "enabled_capabilities": ["cognitive:focus_enhancer"],
+        "blocked_capabilities": [],
+        "preferences": {},
+    });
+    let profile: AgentCyberProfile = serde_json::from_value(legacy.clone()).map_err(|e| e.to_string())?;
+    assert_eq!(profile.agent, AgentId::new("did:legacy:player-042").expect("valid DID"));
+    let mut broken = legacy;
+    broken["agent"] = "did:aln:".into();
+    assert!(serde_json::from_value::<AgentCyberProfile>(broken).is_err());
+    Ok(())
+}
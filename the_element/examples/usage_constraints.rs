@@ -0,0 +1,127 @@
+// path: the_element/examples/usage_constraints.rs
+
+//! Example: a clinician's usage envelope on an exoskeleton, in UTC+1.
+//! - Prescribed: at most 3 hours a day, never between 22:00 and 06:00 local time
+//! - A session running past 22:00 is force-paused by the next enforcement pass
+//! - A self-imposed 1-hour cap on focus counts a session across local midnight only from
+//!   midnight on, and is the agent's alone to remove
+//! - The agent can remove the clinician's envelope; the removal is logged
+//!
+//! Times are local to the agent; `at` converts them to the element's UTC clock.
+
+use std::sync::Arc;
+
+use steward_runtime_support::FixedClock;
+use the_element::{
+    default_element, AgentId, CapabilityClass, CapabilityDomain, CapabilityId, ConstraintOrigin, CyberneticAbility,
+    ReasonCode, RiskTier, UsageConstraints, UsageEvent,
+};
+
+const T0: u64 = 1_767_225_600_000;
+const MINUTE_MS: u64 = 60_000;
+const HOUR_MS: u64 = 60 * MINUTE_MS;
+const UTC_PLUS_ONE: i32 = 60;
+
+/// `day` days after T0, at `hour`:`minute` local time.
+fn at(day: u64, hour: u64, minute: u64) -> u64 {
+    T0 + day * 24 * HOUR_MS + hour * HOUR_MS + minute * MINUTE_MS - UTC_PLUS_ONE as u64 * MINUTE_MS
+}
+
+fn main() -> Result<(), String> {
+    let ana = AgentId::new("did:aln:patient:ana").expect("valid DID");
+    let clinician = AgentId::new("did:aln:clinic:rehab-7").expect("valid DID");
+    let exo = CapabilityId::new("motor:exoskeleton_assist").expect("valid capability");
+    let focus = CapabilityId::new("cognitive:focus_enhancer").expect("valid capability");
+    let clock = Arc::new(FixedClock::new(at(0, 9, 0)));
+    let mut element = default_element().with_clock(clock.clone());
+    element.upsert_ability(CyberneticAbility {
+        id: exo.clone(),
+        name: "Exoskeleton assist".into(),
+        domain: CapabilityDomain::Motor,
+        class_: CapabilityClass::Enhancement,
+        risk_tier: RiskTier::Medium,
+        description: "Powered gait assistance".into(),
+        requires: Default::default(),
+        ai_delegable: false,
+        require_explicit_opt_in: true,
+    });
+    element.request_enable(&ana, &exo, true)?;
+    element.request_enable(&ana, &focus, true)?;
+
+    // 1. The clinician prescribes the envelope; no one else can, ana's own would be hers.
+    let prescribed = UsageConstraints {
+        max_daily_duration_ms: Some(3 * HOUR_MS),
+        allowed_hours: Some((6, 22)),
+        timezone_offset_min: UTC_PLUS_ONE,
+        set_by: ConstraintOrigin::ClinicalAdvisor(clinician.clone()),
+    };
+    let kofi = AgentId::new("did:aln:patient:kofi").expect("valid DID");
+    let refused = element.set_usage_constraints(&kofi, &ana, &exo, prescribed.clone()).expect_err("not the advisor");
+    assert_eq!(refused.code, ReasonCode::ElementUsageConstraintsNotPermitted);
+    element.set_usage_constraints(&clinician, &ana, &exo, prescribed.clone())?;
+
+    // 2. Two hours in the morning, then one more: the cap is reached at three.
+    element.start_session(&ana, &exo)?;
+    clock.set(at(0, 11, 0));
+    assert_eq!(element.end_session(&ana, &exo)?, 2 * HOUR_MS);
+    clock.set(at(0, 14, 0));
+    element.start_session(&ana, &exo)?;
+    clock.set(at(0, 15, 0));
+    assert!(!element.can_use(&ana, &exo), "3 hours used, session included");
+    let paused = element.enforce_usage_constraints();
+    let UsageEvent::SessionForcePaused { reason, .. } = &paused[0].event else {
+        panic!("expected a force-pause, got {:?}", paused[0].event);
+    };
+    assert_eq!(reason.code, ReasonCode::ElementUsageDailyCap);
+    assert_eq!(element.usage_today(&ana, &exo), 3 * HOUR_MS);
+
+    // 3. The next local day starts afresh; a session straddling 22:00 is force-paused.
+    clock.set(at(1, 21, 30));
+    assert_eq!(element.usage_today(&ana, &exo), 0);
+    element.start_session(&ana, &exo)?;
+    clock.set(at(1, 21, 59));
+    assert!(element.enforce_usage_constraints().is_empty(), "still inside the allowed hours");
+    clock.set(at(1, 22, 5));
+    assert!(!element.can_use(&ana, &exo));
+    let paused = element.enforce_usage_constraints();
+    let UsageEvent::SessionForcePaused { duration_ms, reason } = &paused[0].event else {
+        panic!("expected a force-pause, got {:?}", paused[0].event);
+    };
+    assert_eq!((*duration_ms, reason.code), (35 * MINUTE_MS, ReasonCode::ElementUsageOutsideHours));
+    assert_eq!(reason.to_string(), "motor:exoskeleton_assist may only be used between 06:00 and 22:00 local time");
+    assert_eq!(element.open_session(&ana, &exo), None);
+    assert_eq!(element.start_session(&ana, &exo).map_err(|r| r.code), Err(ReasonCode::ElementUsageOutsideHours));
+
+    // 4. Midnight: a self-imposed 1-hour focus cap; 23:30 to 00:30 counts 30 minutes on day 3.
+    let own = UsageConstraints {
+        max_daily_duration_ms: Some(HOUR_MS),
+        allowed_hours: None,
+        timezone_offset_min: UTC_PLUS_ONE,
+        set_by: ConstraintOrigin::SelfImposed,
+    };
+    element.set_usage_constraints(&ana, &ana, &focus, own)?;
+    clock.set(at(2, 23, 30));
+    element.start_session(&ana, &focus)?;
+    clock.set(at(2, 23, 59));
+    assert_eq!(element.usage_today(&ana, &focus), 29 * MINUTE_MS);
+    clock.set(at(3, 0, 0));
+    assert_eq!(element.usage_today(&ana, &focus), 0, "reset at local midnight");
+    clock.set(at(3, 0, 30));
+    assert_eq!(element.end_session(&ana, &focus)?, HOUR_MS);
+    assert_eq!(element.usage_today(&ana, &focus), 30 * MINUTE_MS);
+    assert!(element.can_use(&ana, &focus));
+
+    // 5. Removal: the clinician cannot lift ana's own cap; ana lifts the clinician's envelope.
+    assert!(element.remove_usage_constraints(&clinician, &ana, &focus).is_err());
+    clock.set(at(3, 23, 0));
+    assert!(!element.can_use(&ana, &exo));
+    let lifted = element.remove_usage_constraints(&ana, &ana, &exo)?;
+    assert_eq!(lifted, prescribed);
+    assert!(element.can_use(&ana, &exo));
+    let removal = element.usage_log(&ana).last().expect("logged");
+    assert!(matches!(&removal.event, UsageEvent::ConstraintsRemoved { by, .. } if *by == ana));
+    assert_eq!(removal.capability, exo);
+
+    println!("{}", serde_json::to_string_pretty(removal).expect("record json"));
+    Ok(())
+}
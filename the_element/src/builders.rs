@@ -0,0 +1,123 @@
+// path: the_element/src/builders.rs
+
+//! Fluent builders and fixtures for tests, behind the `test-util` feature.
+//! - `AbilityBuilder` starts from a low-risk, opt-in enhancement; `build` takes ids as written,
+//!   `build_valid` panics unless the id and every requirement are well-formed capability ids
+//! - `fixture_element` is `default_element` with turn ids drawn from a seeded
+//!   `SequentialIdGenerator` and a fixed clock, so governance turns replay identically
+
+use std::sync::Arc;
+
+use steward_runtime_support::{FixedClock, SequentialIdGenerator};
+
+use crate::{
+    default_element_with_ids, CapabilityClass, CapabilityDomain, CapabilityId, CyberneticAbility, Requirement, RiskTier,
+    TheElement,
+};
+
+/// 2026-01-01T00:00:00Z; the fixture element's clock reads this.
+pub const FIXTURE_START_MS: u64 = 1_767_225_600_000;
+
+/// Builds a `CyberneticAbility`.
+#[derive(Debug, Clone)]
+pub struct AbilityBuilder {
+    ability: CyberneticAbility,
+}
+
+impl AbilityBuilder {
+    pub fn new(id: &str) -> Self {
+        Self {
+            ability: CyberneticAbility {
+                id: CapabilityId(id.into()),
+                name: id.into(),
+                domain: CapabilityDomain::Cognitive,
+                class_: CapabilityClass::Enhancement,
+                risk_tier: RiskTier::Low,
+                description: format!("Fixture ability {id}"),
+                requires: Requirement::default(),
+                ai_delegable: false,
+                require_explicit_opt_in: true,
+            },
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.ability.name = name.into();
+        self
+    }
+
+    pub fn domain(mut self, domain: CapabilityDomain) -> Self {
+        self.ability.domain = domain;
+        self
+    }
+
+    pub fn class(mut self, class: CapabilityClass) -> Self {
+        self.ability.class_ = class;
+        self
+    }
+
+    /// A baseline right: no opt-in, and never removable by a governance turn.
+    pub fn baseline_right(mut self) -> Self {
+        self.ability.class_ = CapabilityClass::BaselineRight;
+        self.ability.require_explicit_opt_in = false;
+        self
+    }
+
+    pub fn risk_tier(mut self, tier: RiskTier) -> Self {
+        self.ability.risk_tier = tier;
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.ability.description = description.into();
+        self
+    }
+
+    /// Adds `ids` to the abilities that must be enabled first.
+    pub fn requires(self, ids: &[&str]) -> Self {
+        ids.iter().fold(self, |b, id| b.requirement(Requirement::cap(CapabilityId(id.to_string()))))
+    }
+
+    /// Adds a group of alternatives: at least one of `ids` must be enabled first.
+    pub fn requires_any(self, ids: &[&str]) -> Self {
+        self.requirement(Requirement::any_of(ids.iter().map(|id| CapabilityId(id.to_string()))))
+    }
+
+    /// Adds `requirement` to what must hold first.
+    pub fn requirement(mut self, requirement: Requirement) -> Self {
+        self.ability.requires = std::mem::take(&mut self.ability.requires).and(requirement);
+        self
+    }
+
+    pub fn ai_delegable(mut self, delegable: bool) -> Self {
+        self.ability.ai_delegable = delegable;
+        self
+    }
+
+    pub fn require_explicit_opt_in(mut self, opt_in: bool) -> Self {
+        self.ability.require_explicit_opt_in = opt_in;
+        self
+    }
+
+    pub fn build(self) -> CyberneticAbility {
+        self.ability
+    }
+
+    /// `build`, panicking unless the ability's id and requirements would deserialize.
+    pub fn build_valid(self) -> CyberneticAbility {
+        let ability = self.build();
+        for id in std::iter::once(&ability.id).chain(ability.requires.capabilities()) {
+            if let Err(e) = CapabilityId::validate(&id.0) {
+                panic!("fixture ability {} is invalid: {e}", ability.id.0);
+            }
+        }
+        ability
+    }
+}
+
+/// `default_element` with turn ids from `SequentialIdGenerator::new(seed)` and a clock fixed
+/// at `FIXTURE_START_MS`.
+pub fn fixture_element(seed: u64) -> TheElement {
+    default_element_with_ids(Arc::new(SequentialIdGenerator::new(seed)))
+        .with_clock(Arc::new(FixedClock::new(FIXTURE_START_MS)))
+}
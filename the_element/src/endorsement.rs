@@ -0,0 +1,160 @@
+// path: the_element/src/endorsement.rs
+
+//! Agent-to-agent endorsements: one agent vouching that another is ready for an ability.
+//! - Only an agent who can use the ability themselves may endorse it, and never for
+//!   themselves; the endorser alone may revoke it
+//! - `endorsements_for` returns the raw records, revoked and expired ones included. There
+//!   is deliberately no count, ranking or aggregate over them: an endorsement is a statement,
+//!   not a score, and nothing that sorts or scores agents takes one
+//! - An `EnablementPolicy` may require, for named abilities, one unexpired and unrevoked
+//!   endorsement from a designated endorser before `request_enable` succeeds. The check is
+//!   existence only; more endorsements, or longer statements, change nothing
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{AgentId, CapabilityId, Reason, ReasonCode, TheElement};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Endorsement {
+    pub endorsement_id: String,
+    pub endorser: AgentId,
+    pub subject: AgentId,
+    pub capability_id: CapabilityId,
+    /// The endorser's own words; never parsed or weighed.
+    pub statement: String,
+    pub issued_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revoked_ms: Option<u64>,
+}
+
+impl Endorsement {
+    /// Neither revoked nor expired at `now_ms`.
+    pub fn is_active(&self, now_ms: u64) -> bool {
+        self.revoked_ms.is_none() && self.expires_ms.is_none_or(|expires| now_ms < expires)
+    }
+}
+
+/// Abilities that need an endorsement before they can be enabled, and who may give it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EnablementPolicy {
+    required_endorsers: BTreeMap<CapabilityId, BTreeSet<AgentId>>,
+}
+
+impl EnablementPolicy {
+    /// Require an endorsement from one of `endorsers` to enable `capability_id`.
+    pub fn require_endorsement(
+        mut self,
+        capability_id: CapabilityId,
+        endorsers: impl IntoIterator<Item = AgentId>,
+    ) -> Self {
+        self.required_endorsers.insert(capability_id, endorsers.into_iter().collect());
+        self
+    }
+
+    /// The designated endorsers of `capability_id`; `None` when it needs no endorsement.
+    pub fn endorsers(&self, capability_id: &CapabilityId) -> Option<&BTreeSet<AgentId>> {
+        self.required_endorsers.get(capability_id)
+    }
+
+    /// Whether enabling `capability_id` may go ahead given the subject's `endorsements`:
+    /// always when it needs none, otherwise when any one is active and from a designated
+    /// endorser.
+    pub fn permits(&self, capability_id: &CapabilityId, endorsements: &[&Endorsement], now_ms: u64) -> bool {
+        let Some(endorsers) = self.endorsers(capability_id) else {
+            return true;
+        };
+        endorsements.iter().any(|e| {
+            e.capability_id == *capability_id && endorsers.contains(&e.endorser) && e.is_active(now_ms)
+        })
+    }
+}
+
+impl TheElement {
+    /// Check `request_enable` against `policy`.
+    pub fn with_enablement_policy(mut self, policy: EnablementPolicy) -> Self {
+        self.enablement_policy = policy;
+        self
+    }
+
+    pub fn set_enablement_policy(&mut self, policy: EnablementPolicy) {
+        self.enablement_policy = policy;
+    }
+
+    pub fn enablement_policy(&self) -> &EnablementPolicy {
+        &self.enablement_policy
+    }
+
+    /// `endorser` vouches for `subject` on `capability_id`, issued now by the element's clock.
+    pub fn issue_endorsement(
+        &mut self,
+        endorser: &AgentId,
+        subject: &AgentId,
+        capability_id: &CapabilityId,
+        statement: &str,
+        expires_ms: Option<u64>,
+    ) -> Result<Endorsement, Reason> {
+        if !self.abilities.contains_key(capability_id) {
+            return Err(Reason::new(ReasonCode::ElementUnknownCapability).with("capability", capability_id));
+        }
+        if endorser == subject {
+            return Err(Reason::new(ReasonCode::ElementSelfEndorsement).with("capability", capability_id));
+        }
+        if !self.can_use(endorser, capability_id) {
+            return Err(Reason::new(ReasonCode::ElementEndorserLacksCapability)
+                .with("endorser", endorser)
+                .with("capability", capability_id));
+        }
+        let issued_ms = self.clock.now_ms();
+        if let Some(expires) = expires_ms.filter(|expires| *expires <= issued_ms) {
+            return Err(Reason::new(ReasonCode::ElementEndorsementExpiry).with("expires_ms", expires));
+        }
+        let endorsement = Endorsement {
+            endorsement_id: format!("endorsement:{}", self.ids.next_id()),
+            endorser: endorser.clone(),
+            subject: subject.clone(),
+            capability_id: capability_id.clone(),
+            statement: statement.into(),
+            issued_ms,
+            expires_ms,
+            revoked_ms: None,
+        };
+        self.endorsements.push(endorsement.clone());
+        Ok(endorsement)
+    }
+
+    /// Revoke an endorsement, for its endorser only; revoking it again keeps the first time.
+    pub fn revoke_endorsement(&mut self, requester: &AgentId, endorsement_id: &str) -> Result<&Endorsement, Reason> {
+        let now_ms = self.clock.now_ms();
+        let endorsement = self
+            .endorsements
+            .iter_mut()
+            .find(|e| e.endorsement_id == endorsement_id)
+            .ok_or_else(|| Reason::new(ReasonCode::ElementUnknownEndorsement).with("endorsement", endorsement_id))?;
+        if endorsement.endorser != *requester {
+            return Err(Reason::new(ReasonCode::ElementEndorsementRevokeEndorserOnly)
+                .with("endorsement", endorsement_id));
+        }
+        endorsement.revoked_ms.get_or_insert(now_ms);
+        Ok(endorsement)
+    }
+
+    /// Every endorsement of `subject` on `capability_id`, in the order issued, revoked and
+    /// expired ones included.
+    pub fn endorsements_for(&self, subject: &AgentId, capability_id: &CapabilityId) -> Vec<&Endorsement> {
+        self.endorsements
+            .iter()
+            .filter(|e| e.subject == *subject && e.capability_id == *capability_id)
+            .collect()
+    }
+
+    /// Whether the enablement policy lets `agent` enable `capability_id` now.
+    pub(crate) fn endorsement_permits(&self, agent: &AgentId, capability_id: &CapabilityId) -> bool {
+        let endorsements = self.endorsements_for(agent, capability_id);
+        self.enablement_policy.permits(capability_id, &endorsements, self.clock.now_ms())
+    }
+}
@@ -0,0 +1,64 @@
+// path: the_element/src/invariants.rs
+
+//! State checks for generated operation sequences (feature `test-util`).
+//! - `element.blocked_not_enabled`: no profile both enables and blocks an ability
+//! - `element.baseline_retained`: every profile holds every baseline right, unless the agent
+//!   blocked it themselves
+//! - `element.baseline_not_reassessed`: baseline rights are never held back for reassessment
+//! - `element.endorsement_not_self`: no endorsement names its endorser as subject
+//! - `element.log_sequence`: safety, usage and reassessment logs number their records 0, 1, ...
+
+use steward_ids::invariants::InvariantViolation;
+
+use crate::TheElement;
+
+impl TheElement {
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let mut agents: Vec<_> = self.profiles.keys().collect();
+        agents.sort();
+        for agent in agents {
+            let profile = &self.profiles[agent];
+            let mut both: Vec<_> = profile.enabled_capabilities.intersection(&profile.blocked_capabilities).collect();
+            both.sort();
+            if let Some(capability) = both.first() {
+                let detail = format!("{agent} both enables and blocks {capability}");
+                return Err(InvariantViolation::new("element.blocked_not_enabled", detail));
+            }
+            let mut baselines: Vec<_> = self.config.global_baseline_capabilities.iter().collect();
+            baselines.sort();
+            for capability in baselines {
+                if !profile.enabled_capabilities.contains(capability)
+                    && !profile.blocked_capabilities.contains(capability)
+                {
+                    let detail = format!("{agent} lacks baseline right {capability} without having blocked it");
+                    return Err(InvariantViolation::new("element.baseline_retained", detail));
+                }
+                if self.is_pending(agent, capability) {
+                    let detail = format!("{agent}'s baseline right {capability} is pending reassessment");
+                    return Err(InvariantViolation::new("element.baseline_not_reassessed", detail));
+                }
+            }
+        }
+        if let Some(e) = self.endorsements.iter().find(|e| e.endorser == e.subject) {
+            let detail = format!("{} endorses {} for themselves", e.endorsement_id, e.endorser);
+            return Err(InvariantViolation::new("element.endorsement_not_self", detail));
+        }
+        let sequences = self
+            .safety_logs
+            .iter()
+            .map(|(agent, log)| (agent, "safety", log.iter().map(|r| r.seq).collect::<Vec<_>>()))
+            .chain(self.usage_logs.iter().map(|(agent, log)| (agent, "usage", log.iter().map(|r| r.seq).collect())))
+            .chain(
+                self.reassessment_logs
+                    .iter()
+                    .map(|(agent, log)| (agent, "reassessment", log.iter().map(|r| r.seq).collect())),
+            );
+        for (agent, kind, seqs) in sequences {
+            if let Some(index) = seqs.iter().enumerate().position(|(i, seq)| *seq != i as u64) {
+                let detail = format!("{agent}'s {kind} log has seq {} at index {index}", seqs[index]);
+                return Err(InvariantViolation::new("element.log_sequence", detail));
+            }
+        }
+        Ok(())
+    }
+}
@@ -11,21 +11,58 @@
 
 use serde::{Serialize, Deserialize};
 use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+use steward_ids::stream::{self, StreamReader};
+use steward_runtime_support::{default_id_generator, system_clock, Clock, IdGenerator};
+
+pub use steward_ids::messages::{Reason, ReasonCode};
+pub use steward_ids::stream::{Compression, StreamError, StreamManifest};
+
+#[cfg(feature = "test-util")]
+pub mod builders;
+pub mod endorsement;
+#[cfg(feature = "test-util")]
+mod invariants;
+pub use endorsement::{EnablementPolicy, Endorsement};
+pub mod safety;
+pub use safety::{
+    AbilityListing, SafetyEvent, SafetyEventRecord, SafetyEventSummary, SafetyReviewFlag, SafetyReviewThresholds,
+};
+pub mod reassessment;
+pub mod requirement;
+pub use requirement::Requirement;
+pub use reassessment::{
+    classify_update, AbilityChange, AbilityUpdate, PendingReassessment, ReassessmentAwaiting, ReassessmentEvent,
+    ReassessmentRecord,
+};
+pub mod sandbox;
+pub use sandbox::{
+    AutoPauseCause, AutoPausedSession, ConstraintFiring, ElementSandbox, SandboxReport, SandboxViolation, SessionMonitor,
+    SimulatedUse, UseOutcome,
+};
+pub mod usage;
+pub use usage::{ConstraintOrigin, UsageConstraints, UsageEvent, UsageRecord};
 
 /// ---------------------------------------------------------------------
 /// CORE TYPES
 /// ---------------------------------------------------------------------
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CapabilityId(pub String);
+/// Canonical, validated ids shared with the rest of the workspace.
+/// Re-exported (not aliased) so tuple construction keeps compiling.
+pub use steward_ids::{CapabilityId, IdError};
+pub use steward_ids::Did as AgentId; // human, cyborg, AI, org
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct AgentId(pub String); // human, cyborg, AI, org
+/// Crate version stamped into exported JSON Schemas.
+#[cfg(feature = "schema")]
+pub const SCHEMA_CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GovernanceTurnId(pub String);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CapabilityDomain {
     Cognitive,     // memory, focus, pattern-boost
     Motor,         // exoskeleton, prosthetics, biomech motion
@@ -35,15 +72,17 @@ pub enum CapabilityDomain {
     Meta,          // introspection, self-governance tools
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CapabilityClass {
     BaselineRight,      // cannot be removed by any governance-turn.
     Enhancement,        // optional uplift, can be gated but not coerced. [web:20][web:21]
     Experimental,       // research-mode, with strict consent + safety.
 }
 
-/// Minimal risk tier for BCI / biomech / XR enhancement. [web:20][web:23]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Minimal risk tier for BCI / biomech / XR enhancement, ordered low to high. [web:20][web:23]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RiskTier {
     Low,        // non-invasive, reversible, minimal side effects
     Medium,     // non-invasive but strong modulation, or invasive maintenance-free
@@ -52,6 +91,7 @@ pub enum RiskTier {
 
 /// A single cybernetic / AI-augmented ability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CyberneticAbility {
     pub id: CapabilityId,
     pub name: String,
@@ -60,19 +100,28 @@ pub struct CyberneticAbility {
     pub risk_tier: RiskTier,
     /// Human-readable description of what this ability does.
     pub description: String,
-    /// Abilities that must be enabled before this can be used.
-    pub requires: HashSet<CapabilityId>,
+    /// Abilities that must be enabled before this can be used; see `requirement`.
+    #[serde(deserialize_with = "Requirement::deserialize_compat")]
+    pub requires: Requirement,
     /// Whether this ability can be delegated to an agentic-AI co-pilot. [web:25][web:28]
     pub ai_delegable: bool,
     /// Whether this ability can *only* be used with explicit opt-in.
     pub require_explicit_opt_in: bool,
 }
 
+/// `steward_ids::stream` kind of `TheElement::export_profiles`.
+pub const PROFILE_STREAM_KIND: &str = "the_element.profiles";
+
 /// A “cybernetic profile” for any agent/stakeholder.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AgentCyberProfile {
+    /// Profiles stored before agent ids were DIDs read back as `did:legacy:<id>`.
+    #[serde(deserialize_with = "AgentId::deserialize_compat")]
     pub agent: AgentId,
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub enabled_capabilities: HashSet<CapabilityId>,
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub blocked_capabilities: HashSet<CapabilityId>,
     pub preferences: serde_json::Value,
 }
@@ -82,9 +131,11 @@ pub struct AgentCyberProfile {
 /// ---------------------------------------------------------------------
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ElementConfig {
     /// Baseline capabilities that must always remain available to all agents.
     /// These represent *rights to augment / exit / introspect*, not privileges. [web:21][web:26][web:29]
+    #[serde(serialize_with = "steward_ids::ordered::set")]
     pub global_baseline_capabilities: HashSet<CapabilityId>,
     /// Max fraction of an agent's enabled capabilities that may be restricted in 1 turn.
     pub max_restriction_fraction_per_turn: f64,
@@ -96,67 +147,344 @@ pub struct TheElement {
     abilities: HashMap<CapabilityId, CyberneticAbility>,
     /// Per-agent profiles (actual enabled/blocked sets).
     profiles: HashMap<AgentId, AgentCyberProfile>,
+    /// Source of generated governance-turn ids.
+    ids: Arc<dyn IdGenerator>,
+    /// Per-agent safety logs (emergency exits, pauses, resumes), append-only.
+    safety_logs: HashMap<AgentId, Vec<SafetyEventRecord>>,
+    safety_thresholds: SafetyReviewThresholds,
+    /// Abilities flagged for safety review, until a reviewer clears them.
+    review_flags: HashMap<CapabilityId, SafetyReviewFlag>,
+    /// Per-agent enabled abilities held back since their definition changed.
+    pending_reassessments: HashMap<AgentId, HashMap<CapabilityId, PendingReassessment>>,
+    /// Per-agent reassessment logs, append-only.
+    reassessment_logs: HashMap<AgentId, Vec<ReassessmentRecord>>,
+    /// Reads usage sessions and constraints' allowed hours.
+    clock: Arc<dyn Clock>,
+    usage_constraints: HashMap<(AgentId, CapabilityId), UsageConstraints>,
+    /// Start of each open usage session.
+    open_sessions: HashMap<(AgentId, CapabilityId), u64>,
+    /// Closed sessions' use in the latest local day each pair was used.
+    daily_usage: HashMap<(AgentId, CapabilityId), usage::DailyUsage>,
+    /// Per-agent usage logs, append-only.
+    usage_logs: HashMap<AgentId, Vec<UsageRecord>>,
+    /// Every endorsement issued, in order; revocation marks a record, never removes it.
+    endorsements: Vec<Endorsement>,
+    enablement_policy: EnablementPolicy,
 }
 
 impl TheElement {
     pub fn new(config: ElementConfig) -> Self {
+        Self::with_id_generator(config, default_id_generator())
+    }
+
+    /// Element with an injected id source, for reproducible turn ids.
+    pub fn with_id_generator(config: ElementConfig, ids: Arc<dyn IdGenerator>) -> Self {
         Self {
             config,
             abilities: HashMap::new(),
             profiles: HashMap::new(),
+            ids,
+            safety_logs: HashMap::new(),
+            safety_thresholds: SafetyReviewThresholds::default(),
+            review_flags: HashMap::new(),
+            pending_reassessments: HashMap::new(),
+            reassessment_logs: HashMap::new(),
+            clock: system_clock(),
+            usage_constraints: HashMap::new(),
+            open_sessions: HashMap::new(),
+            daily_usage: HashMap::new(),
+            usage_logs: HashMap::new(),
+            endorsements: Vec::new(),
+            enablement_policy: EnablementPolicy::default(),
         }
     }
 
-    /// Define or update a capability in the global library.
-    pub fn upsert_ability(&mut self, ability: CyberneticAbility) {
-        self.abilities.insert(ability.id.clone(), ability);
+    /// Read usage sessions and allowed hours from `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Replace the thresholds at which abilities are flagged for safety review.
+    pub fn with_safety_review_thresholds(mut self, thresholds: SafetyReviewThresholds) -> Self {
+        self.safety_thresholds = thresholds;
+        self
+    }
+
+    /// Fresh governance-turn id (`turn:<uuid>`) for callers without their own.
+    pub fn next_turn_id(&self) -> GovernanceTurnId {
+        GovernanceTurnId(format!("turn:{}", self.ids.next_id()))
+    }
+
+    /// Define or update a capability in the global library. A non-cosmetic update puts the
+    /// ability up for reassessment with every agent holding it, except baseline rights.
+    pub fn upsert_ability(&mut self, ability: CyberneticAbility) -> AbilityUpdate {
+        let capability = ability.id.clone();
+        let changes = self
+            .abilities
+            .get(&capability)
+            .map(|old| classify_update(old, &ability))
+            .unwrap_or_default();
+        self.abilities.insert(capability.clone(), ability.clone());
+        let mut update = AbilityUpdate {
+            capability: capability.clone(),
+            changes,
+            pending_agents: Vec::new(),
+            revalidated_agents: Vec::new(),
+        };
+        let material: Vec<AbilityChange> =
+            update.changes.iter().copied().filter(|c| *c != AbilityChange::CosmeticOnly).collect();
+        if material.is_empty() || self.is_baseline(&capability) {
+            return update;
+        }
+
+        let mut holders: Vec<AgentId> = self
+            .profiles
+            .values()
+            .filter(|p| p.enabled_capabilities.contains(&capability) && !p.blocked_capabilities.contains(&capability))
+            .map(|p| p.agent.clone())
+            .collect();
+        holders.sort();
+        for agent in holders {
+            let mut changes = self
+                .pending_reassessments
+                .get(&agent)
+                .and_then(|pending| pending.get(&capability))
+                .map(|p| p.changes.clone())
+                .unwrap_or_default();
+            changes.extend(&material);
+            changes.sort();
+            changes.dedup();
+            let awaiting = ReassessmentAwaiting::for_update(&ability, &changes);
+            if awaiting == ReassessmentAwaiting::Prerequisites && self.prerequisites_met(&agent, &ability) {
+                if let Some(pending) = self.pending_reassessments.get_mut(&agent) {
+                    pending.remove(&capability);
+                }
+                self.log_reassessment(&agent, &capability, ReassessmentEvent::Revalidated, changes);
+                update.revalidated_agents.push(agent);
+                continue;
+            }
+            self.pending_reassessments.entry(agent.clone()).or_default().insert(
+                capability.clone(),
+                PendingReassessment { capability: capability.clone(), changes: changes.clone(), awaiting },
+            );
+            self.log_reassessment(&agent, &capability, ReassessmentEvent::Pending, changes);
+            update.pending_agents.push(agent);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            capability = %capability,
+            pending = update.pending_agents.len(),
+            revalidated = update.revalidated_agents.len(),
+            "ability update reassessed"
+        );
+        update
+    }
+
+    /// The library, by capability id, with any safety-review flags.
+    pub fn list_abilities(&self) -> Vec<AbilityListing> {
+        let mut listings: Vec<AbilityListing> = self
+            .abilities
+            .values()
+            .map(|ability| AbilityListing {
+                ability: ability.clone(),
+                safety_review: self.review_flags.get(&ability.id).cloned(),
+            })
+            .collect();
+        listings.sort_by(|a, b| a.ability.id.cmp(&b.ability.id));
+        listings
     }
 
     /// Initialize or fetch a profile.
+    /// The agent's profile, created if missing; either way holding every baseline right the
+    /// agent has not blocked.
     fn ensure_profile(&mut self, agent: &AgentId) -> &mut AgentCyberProfile {
-        self.profiles.entry(agent.clone()).or_insert_with(|| AgentCyberProfile {
+        let profile = self.profiles.entry(agent.clone()).or_insert_with(|| AgentCyberProfile {
             agent: agent.clone(),
-            enabled_capabilities: self.config.global_baseline_capabilities.clone(),
+            enabled_capabilities: HashSet::new(),
             blocked_capabilities: HashSet::new(),
             preferences: serde_json::json!({}),
-        })
+        });
+        backfill_baselines(profile, &self.config.global_baseline_capabilities);
+        profile
     }
 
     pub fn get_profile(&self, agent: &AgentId) -> Option<&AgentCyberProfile> {
         self.profiles.get(agent)
     }
 
+    /// Every profile, by agent id, written one at a time as a stream of `PROFILE_STREAM_KIND`.
+    pub fn export_profiles<W: Write>(&self, sink: W, compression: Compression) -> io::Result<StreamManifest> {
+        let mut profiles: Vec<&AgentCyberProfile> = self.profiles.values().collect();
+        profiles.sort_by(|a, b| a.agent.cmp(&b.agent));
+        stream::write_stream(sink, PROFILE_STREAM_KIND, compression, profiles)
+    }
+
+    /// Replace the profiles of the agents `export_profiles` wrote; the number restored. No
+    /// profile changes unless the whole stream verifies against its manifest. Safety, usage
+    /// and reassessment logs do not travel with the profiles. A restored profile missing a
+    /// baseline right it has not blocked gets it back.
+    pub fn restore_profiles<R: BufRead>(&mut self, source: R) -> Result<usize, StreamError> {
+        let mut staged: HashMap<AgentId, AgentCyberProfile> = HashMap::new();
+        let records = StreamReader::open(source, PROFILE_STREAM_KIND)?.records::<AgentCyberProfile>();
+        for (index, profile) in records.enumerate() {
+            let profile = profile?;
+            if staged.contains_key(&profile.agent) {
+                let reason = format!("Profile of {} appears twice", profile.agent);
+                return Err(StreamError::Rejected { index: index as u64, reason });
+            }
+            staged.insert(profile.agent.clone(), profile);
+        }
+        for profile in staged.values_mut() {
+            backfill_baselines(profile, &self.config.global_baseline_capabilities);
+        }
+        let count = staged.len();
+        self.profiles.extend(staged);
+        Ok(count)
+    }
+
+    /// Whether the agent can use this capability now: enabled, not self-blocked, not pending
+    /// reassessment, its prerequisites enabled, and within its usage constraints by the
+    /// element's clock.
+    pub fn can_use(&self, agent: &AgentId, capability_id: &CapabilityId) -> bool {
+        self.holds(agent, capability_id) && self.check_usage(agent, capability_id, self.clock.now_ms()).is_ok()
+    }
+
+    /// `can_use` but for usage constraints.
+    fn holds(&self, agent: &AgentId, capability_id: &CapabilityId) -> bool {
+        self.profiles.get(agent).is_some_and(|p| {
+            p.enabled_capabilities.contains(capability_id)
+                && !p.blocked_capabilities.contains(capability_id)
+        }) && !self.is_pending(agent, capability_id)
+            && self.abilities.get(capability_id).is_none_or(|a| self.prerequisites_met(agent, a))
+    }
+
+    /// Every capability the agent can use now; see `can_use`.
+    pub fn effective_capabilities(&self, agent: &AgentId) -> HashSet<CapabilityId> {
+        let now_ms = self.clock.now_ms();
+        self.profiles
+            .get(agent)
+            .map(|p| {
+                p.enabled_capabilities
+                    .iter()
+                    .filter(|c| self.holds(agent, c))
+                    .filter(|c| self.check_usage(agent, c, now_ms).is_ok())
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_pending(&self, agent: &AgentId, capability_id: &CapabilityId) -> bool {
+        self.pending_reassessments.get(agent).is_some_and(|p| p.contains_key(capability_id))
+    }
+
+    /// `agent`'s abilities awaiting reassessment, by capability id.
+    pub fn pending_reassessments(&self, agent: &AgentId) -> Vec<&PendingReassessment> {
+        let mut pending: Vec<&PendingReassessment> =
+            self.pending_reassessments.get(agent).map(|p| p.values().collect()).unwrap_or_default();
+        pending.sort_by(|a, b| a.capability.cmp(&b.capability));
+        pending
+    }
+
+    /// `agent`'s reassessment log, oldest first.
+    pub fn reassessment_log(&self, agent: &AgentId) -> &[ReassessmentRecord] {
+        self.reassessment_logs.get(agent).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    fn prerequisites_met(&self, agent: &AgentId, ability: &CyberneticAbility) -> bool {
+        self.profiles
+            .get(agent)
+            .is_some_and(|p| ability.requires.is_met_by(&p.enabled_capabilities))
+    }
+
+    /// Clear `agent`'s prerequisite-only reassessments that their enabled set now satisfies.
+    fn revalidate_prerequisites(&mut self, agent: &AgentId) {
+        let Some(pending) = self.pending_reassessments.get(agent) else {
+            return;
+        };
+        let mut met: Vec<CapabilityId> = pending
+            .values()
+            .filter(|p| p.awaiting == ReassessmentAwaiting::Prerequisites)
+            .filter(|p| self.abilities.get(&p.capability).is_some_and(|a| self.prerequisites_met(agent, a)))
+            .map(|p| p.capability.clone())
+            .collect();
+        met.sort();
+        for capability in met {
+            self.resolve_reassessment(agent, &capability, ReassessmentEvent::Revalidated);
+        }
+    }
+
+    fn resolve_reassessment(&mut self, agent: &AgentId, capability_id: &CapabilityId, event: ReassessmentEvent) {
+        let Some(pending) = self.pending_reassessments.get_mut(agent) else {
+            return;
+        };
+        let Some(resolved) = pending.remove(capability_id) else {
+            return;
+        };
+        if pending.is_empty() {
+            self.pending_reassessments.remove(agent);
+        }
+        self.log_reassessment(agent, capability_id, event, resolved.changes);
+    }
+
+    fn log_reassessment(
+        &mut self,
+        agent: &AgentId,
+        capability_id: &CapabilityId,
+        event: ReassessmentEvent,
+        changes: Vec<AbilityChange>,
+    ) {
+        let log = self.reassessment_logs.entry(agent.clone()).or_default();
+        log.push(ReassessmentRecord { seq: log.len() as u64, capability: capability_id.clone(), event, changes });
+    }
+
+    /// Whether the capability is a global baseline right (never restrictable).
+    pub fn is_baseline(&self, capability_id: &CapabilityId) -> bool {
+        self.config.global_baseline_capabilities.contains(capability_id)
+    }
+
     /// Agent-requested enablement of a capability (stakeholder-approved turn).
     /// Governance is allowed to *allow more*, not force-enable. [web:20][web:21][web:26]
+    /// Also how an agent re-confirms an ability pending reassessment, under its new terms.
+    /// Abilities the enablement policy names also need an active endorsement.
     pub fn request_enable(
         &mut self,
         agent: &AgentId,
         capability_id: &CapabilityId,
         explicit_opt_in: bool,
-    ) -> Result<(), String> {
+    ) -> Result<(), Reason> {
         let ability = self.abilities.get(capability_id)
-            .ok_or_else(|| "Unknown capability".to_string())?
+            .ok_or_else(|| Reason::new(ReasonCode::ElementUnknownCapability).with("capability", capability_id))?
             .clone();
 
         if ability.require_explicit_opt_in && !explicit_opt_in {
-            return Err("Explicit opt-in required for this ability.".into());
+            return Err(Reason::new(ReasonCode::ElementOptInRequired).with("capability", capability_id));
         }
+        let endorsed = self.endorsement_permits(agent, capability_id);
 
         let profile = self.ensure_profile(agent);
 
         // Respect prior self-blocks (agent can refuse even if governance approves).
         if profile.blocked_capabilities.contains(capability_id) {
-            return Err("Agent has explicitly blocked this capability.".into());
+            return Err(Reason::new(ReasonCode::ElementSelfBlocked).with("capability", capability_id));
         }
 
-        // Check prerequisites.
-        for req in &ability.requires {
-            if !profile.enabled_capabilities.contains(req) {
-                return Err(format!("Missing prerequisite capability: {}", req.0));
-            }
+        // Check prerequisites; the denial names what is left to enable.
+        if let Some(unmet) = ability.requires.unmet(&profile.enabled_capabilities) {
+            return Err(Reason::new(ReasonCode::ElementMissingPrerequisite)
+                .with("capability", capability_id)
+                .with("prerequisite", unmet));
+        }
+
+        // High-risk abilities may need someone who holds them to vouch first.
+        if !endorsed {
+            return Err(Reason::new(ReasonCode::ElementEndorsementRequired).with("capability", capability_id));
         }
 
         profile.enabled_capabilities.insert(capability_id.clone());
+        self.resolve_reassessment(agent, capability_id, ReassessmentEvent::Reconfirmed);
+        self.revalidate_prerequisites(agent);
         Ok(())
     }
 
@@ -165,33 +493,120 @@ impl TheElement {
         &mut self,
         agent: &AgentId,
         capability_id: &CapabilityId,
-    ) -> Result<(), String> {
+    ) -> Result<(), Reason> {
         let profile = self.ensure_profile(agent);
 
         // Agents can always block enhancements/experimental abilities for themselves.
         profile.enabled_capabilities.remove(capability_id);
         profile.blocked_capabilities.insert(capability_id.clone());
+        self.resolve_reassessment(agent, capability_id, ReassessmentEvent::Withdrawn);
         Ok(())
     }
 
+    /// Record an emergency exit, pause or resume. Always recorded, whatever the agent's
+    /// preferences; an exit re-checks its active abilities against the review thresholds.
+    pub fn record_safety_event(&mut self, agent: &AgentId, event: SafetyEvent, now_ms: u64) -> SafetyEventRecord {
+        let active_capabilities = match &event {
+            SafetyEvent::EmergencyExit { active_capabilities_snapshot } => active_capabilities_snapshot.clone(),
+            SafetyEvent::Pause { .. } | SafetyEvent::Resume => self.effective_capabilities(agent),
+        };
+        let log = self.safety_logs.entry(agent.clone()).or_default();
+        let record = SafetyEventRecord { seq: log.len() as u64, at_ms: now_ms, event, active_capabilities };
+        log.push(record.clone());
+
+        if matches!(record.event, SafetyEvent::EmergencyExit { .. }) {
+            let mut candidates: Vec<&CapabilityId> = record
+                .active_capabilities
+                .iter()
+                .filter(|c| self.abilities.contains_key(*c) && !self.is_baseline(c) && !self.review_flags.contains_key(*c))
+                .collect();
+            candidates.sort();
+            for capability in candidates {
+                let summary = self.safety_event_summary(capability, (0, u64::MAX));
+                if summary.emergency_exits < self.safety_thresholds.min_exits
+                    || summary.exit_correlation < self.safety_thresholds.exit_correlation
+                {
+                    continue;
+                }
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    capability = %capability,
+                    emergency_exits = summary.emergency_exits,
+                    exit_correlation = summary.exit_correlation,
+                    "ability flagged for safety review"
+                );
+                self.review_flags.insert(capability.clone(), SafetyReviewFlag {
+                    flagged_at_ms: now_ms,
+                    emergency_exits: summary.emergency_exits,
+                    exit_correlation: summary.exit_correlation,
+                });
+            }
+        }
+        record
+    }
+
+    /// Exits and pauses in `[start_ms, end_ms)` while `capability_id` was active, across agents.
+    pub fn safety_event_summary(&self, capability_id: &CapabilityId, window: (u64, u64)) -> SafetyEventSummary {
+        safety::summarize(&self.safety_logs, capability_id, window)
+    }
+
+    /// `agent`'s safety log, for `requester` == `agent` only.
+    pub fn export_safety_log(&self, requester: &AgentId, agent: &AgentId) -> Result<&[SafetyEventRecord], Reason> {
+        if requester != agent {
+            return Err(Reason::new(ReasonCode::ElementLogExportOwnerOnly).with("agent", agent));
+        }
+        Ok(self.safety_logs.get(agent).map(Vec::as_slice).unwrap_or_default())
+    }
+
+    /// Delete `agent`'s safety log, for `requester` == `agent` only. Returns the number of
+    /// records removed; review flags already raised are kept.
+    pub fn purge_safety_log(&mut self, requester: &AgentId, agent: &AgentId) -> Result<usize, Reason> {
+        if requester != agent {
+            return Err(Reason::new(ReasonCode::ElementLogPurgeOwnerOnly).with("agent", agent));
+        }
+        Ok(self.safety_logs.remove(agent).map_or(0, |log| log.len()))
+    }
+
+    pub fn safety_review_flag(&self, capability_id: &CapabilityId) -> Option<&SafetyReviewFlag> {
+        self.review_flags.get(capability_id)
+    }
+
+    /// Clear a reviewed ability's flag; a later exit can raise it again.
+    pub fn clear_safety_review_flag(&mut self, capability_id: &CapabilityId) -> Option<SafetyReviewFlag> {
+        self.review_flags.remove(capability_id)
+    }
+
     /// Governance-turn: propose restrictions or global unlocks for a given agent.
     /// This is where AI-chat governance or blockchain-based votes plug in. [web:21][web:26][web:29]
+    /// Unlocks never re-confirm an ability pending reassessment; only the agent can.
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "element.governance_turn", level = "info", skip(self, restrict, unlock),
+        fields(turn_id = %_turn_id.0, actor_did = %agent, module = "element"),
+    ))]
     pub fn governance_turn(
         &mut self,
         _turn_id: &GovernanceTurnId,
         agent: &AgentId,
         restrict: &HashSet<CapabilityId>,
         unlock: &HashSet<CapabilityId>,
-    ) -> Result<(), String> {
+    ) -> Result<(), Reason> {
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
         let profile = self.ensure_profile(agent);
 
         // Never restrict baseline rights.
         for cap in restrict {
             if self.config.global_baseline_capabilities.contains(cap) {
-                return Err(format!(
-                    "Cannot restrict baseline capability: {}",
-                    cap.0
-                ));
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    decision = "deny",
+                    reason = "baseline_right",
+                    capability = %cap,
+                    duration_us = started.elapsed().as_micros() as u64,
+                    "governance turn rejected"
+                );
+                return Err(Reason::new(ReasonCode::ElementBaselineRight).with("capability", cap));
             }
         }
 
@@ -202,7 +617,15 @@ impl TheElement {
             .count();
         let fraction = (restrict_count as f64) / (total_before as f64);
         if fraction > self.config.max_restriction_fraction_per_turn {
-            return Err("Restriction exceeds allowed per-turn fraction.".into());
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                decision = "deny",
+                reason = "restriction_fraction",
+                fraction,
+                duration_us = started.elapsed().as_micros() as u64,
+                "governance turn rejected"
+            );
+            return Err(Reason::new(ReasonCode::ElementRestrictionFraction).with("fraction", fraction));
         }
 
         // Apply restrictions.
@@ -217,17 +640,39 @@ impl TheElement {
             }
             profile.enabled_capabilities.insert(cap.clone());
         }
-
+        self.revalidate_prerequisites(agent);
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            decision = "allow",
+            restricted = restrict_count,
+            duration_us = started.elapsed().as_micros() as u64,
+            "governance turn applied"
+        );
         Ok(())
     }
 }
 
+/// Enable every baseline right `profile` has not blocked itself.
+fn backfill_baselines(profile: &mut AgentCyberProfile, baselines: &HashSet<CapabilityId>) {
+    for capability in baselines {
+        if !profile.blocked_capabilities.contains(capability) {
+            profile.enabled_capabilities.insert(capability.clone());
+        }
+    }
+}
+
 /// ---------------------------------------------------------------------
 /// A DEFAULT "ELEMENT FOUNDATION" SET OF ABILITIES
 /// (extensible per project; just a starting library)
 /// ---------------------------------------------------------------------
 
 pub fn default_element() -> TheElement {
+    default_element_with_ids(default_id_generator())
+}
+
+/// `default_element` with an injected turn-id source.
+pub fn default_element_with_ids(ids: Arc<dyn IdGenerator>) -> TheElement {
     let baseline_caps: HashSet<CapabilityId> = vec![
         CapabilityId("meta:introspect_state".into()),
         CapabilityId("meta:emergency_exit".into()),
@@ -235,10 +680,12 @@ pub fn default_element() -> TheElement {
         CapabilityId("security:neuroshield_basic".into()),
     ].into_iter().collect();
 
-    let mut element = TheElement::new(ElementConfig {
-        global_baseline_capabilities: baseline_caps.clone(),
+    let baseline_requirement = Requirement::all_of(baseline_caps.iter().cloned());
+
+    let mut element = TheElement::with_id_generator(ElementConfig {
+        global_baseline_capabilities: baseline_caps,
         max_restriction_fraction_per_turn: 0.33,
-    });
+    }, ids);
 
     // Baseline meta-abilities
     element.upsert_ability(CyberneticAbility {
@@ -248,7 +695,7 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::BaselineRight,
         risk_tier: RiskTier::Low,
         description: "View and log your own augmentation / BCI / XR state in real time.",
-        requires: HashSet::new(),
+        requires: Requirement::default(),
         ai_delegable: false,
         require_explicit_opt_in: false,
     });
@@ -260,7 +707,7 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::BaselineRight,
         risk_tier: RiskTier::Low,
         description: "Immediately disengage any augmentation session and revert to safe defaults.",
-        requires: HashSet::new(),
+        requires: Requirement::default(),
         ai_delegable: false,
         require_explicit_opt_in: false,
     });
@@ -272,7 +719,7 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::BaselineRight,
         risk_tier: RiskTier::Low,
         description: "Temporarily pause all enhancement channels while staying connected.",
-        requires: HashSet::new(),
+        requires: Requirement::default(),
         ai_delegable: false,
         require_explicit_opt_in: false,
     });
@@ -284,7 +731,7 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::BaselineRight,
         risk_tier: RiskTier::Low,
         description: "Baseline neurosecurity filter against malicious prompts or overclocking patterns.",
-        requires: HashSet::new(),
+        requires: Requirement::default(),
         ai_delegable: true,
         require_explicit_opt_in: false,
     });
@@ -297,7 +744,7 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::Enhancement,
         risk_tier: RiskTier::Medium,
         description: "Adaptive neurofeedback + XR overlays to deepen focus without coercion.",
-        requires: baseline_caps.clone(),
+        requires: baseline_requirement.clone(),
         ai_delegable: true,
         require_explicit_opt_in: true,
     });
@@ -309,12 +756,37 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::Enhancement,
         risk_tier: RiskTier::Low,
         description: "Agentic AI highlights patterns / strategies in real time for learning or gameplay.",
-        requires: baseline_caps.clone(),
+        requires: baseline_requirement.clone(),
         ai_delegable: true,
         require_explicit_opt_in: true,
     });
 
     // Motor / biomech
+    element.upsert_ability(CyberneticAbility {
+        id: CapabilityId("motor:emg_calibration".into()),
+        name: "EMG Calibration".into(),
+        domain: CapabilityDomain::Motor,
+        class_: CapabilityClass::Enhancement,
+        risk_tier: RiskTier::Low,
+        description: "Calibrate surface EMG sensors to the wearer's own muscle signals.",
+        requires: baseline_requirement.clone(),
+        ai_delegable: true,
+        require_explicit_opt_in: true,
+    });
+
+    element.upsert_ability(CyberneticAbility {
+        id: CapabilityId("motor:bci_motor_calibration".into()),
+        name: "BCI Motor Calibration".into(),
+        domain: CapabilityDomain::Motor,
+        class_: CapabilityClass::Enhancement,
+        risk_tier: RiskTier::Medium,
+        description: "Calibrate a BCI decoder to the wearer's motor intent.",
+        requires: baseline_requirement.clone(),
+        ai_delegable: false,
+        require_explicit_opt_in: true,
+    });
+
+    // Either calibration will do for the exoskeleton.
     element.upsert_ability(CyberneticAbility {
         id: CapabilityId("motor:exoskeleton_assist".into()),
         name: "Exoskeleton Assist".into(),
@@ -322,7 +794,10 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::Enhancement,
         risk_tier: RiskTier::Medium,
         description: "Balance and strength assistance via exoskeleton + BCI / EMG integration.",
-        requires: baseline_caps.clone(),
+        requires: Requirement::cap(CapabilityId("security:neuroshield_basic".into())).and(Requirement::any_of([
+            CapabilityId("motor:emg_calibration".into()),
+            CapabilityId("motor:bci_motor_calibration".into()),
+        ])),
         ai_delegable: true,
         require_explicit_opt_in: true,
     });
@@ -335,7 +810,7 @@ pub fn default_element() -> TheElement {
         class_: CapabilityClass::Enhancement,
         risk_tier: RiskTier::Low,
         description: "Ethics-checked XR overlays for competitive sport / cybernetic gameplay.",
-        requires: baseline_caps.clone(),
+        requires: baseline_requirement,
         ai_delegable: true,
         require_explicit_opt_in: true,
     });
@@ -0,0 +1,117 @@
+// path: the_element/src/reassessment.rs
+
+//! Reassessing abilities whose definition changed after agents enabled them.
+//! - `upsert_ability` compares the new definition with the old one: a higher risk tier, a
+//!   newly required opt-in or newly allowed AI delegation is `RiskIncreased`; other fields
+//!   (name, description, domain, a lower tier) are `CosmeticOnly`
+//! - Any other change makes the ability pending for every agent holding it (baseline rights
+//!   excepted): it stays enabled but drops out of `effective_capabilities`
+//! - Opt-in abilities, and any riskier or reclassified one, wait for the agent's own
+//!   `request_enable`; a prerequisite-only change clears itself once the agent's enabled
+//!   prerequisites satisfy the new set
+//! - Governance turns never clear a pending reassessment, so no turn can re-confirm in bulk
+//! - Every transition is logged per agent
+
+use serde::{Serialize, Deserialize};
+
+use crate::{AgentId, CapabilityId, CyberneticAbility};
+
+/// How an ability's definition changed on `upsert_ability`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AbilityChange {
+    /// Nothing an agent opted in under became riskier or stricter.
+    CosmeticOnly,
+    RiskIncreased,
+    PrerequisitesChanged,
+    ClassChanged,
+}
+
+/// The changes from `old` to `new`, in `AbilityChange` order; `[CosmeticOnly]` if none matter.
+pub fn classify_update(old: &CyberneticAbility, new: &CyberneticAbility) -> Vec<AbilityChange> {
+    let mut changes = Vec::new();
+    if new.risk_tier > old.risk_tier
+        || (new.require_explicit_opt_in && !old.require_explicit_opt_in)
+        || (new.ai_delegable && !old.ai_delegable)
+    {
+        changes.push(AbilityChange::RiskIncreased);
+    }
+    if new.requires != old.requires {
+        changes.push(AbilityChange::PrerequisitesChanged);
+    }
+    if new.class_ != old.class_ {
+        changes.push(AbilityChange::ClassChanged);
+    }
+    if changes.is_empty() {
+        changes.push(AbilityChange::CosmeticOnly);
+    }
+    changes
+}
+
+/// What clears a pending reassessment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReassessmentAwaiting {
+    /// The agent's own `request_enable`, with explicit opt-in where the ability requires it.
+    AgentConfirmation,
+    /// The agent's enabled prerequisites covering the ability's new `requires`.
+    Prerequisites,
+}
+
+impl ReassessmentAwaiting {
+    pub(crate) fn for_update(ability: &CyberneticAbility, changes: &[AbilityChange]) -> Self {
+        let prerequisites_only = changes.iter().all(|c| *c == AbilityChange::PrerequisitesChanged);
+        if ability.require_explicit_opt_in || !prerequisites_only {
+            Self::AgentConfirmation
+        } else {
+            Self::Prerequisites
+        }
+    }
+}
+
+/// An enabled ability held back from an agent until its update is reassessed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PendingReassessment {
+    pub capability: CapabilityId,
+    /// Every non-cosmetic change since the agent last confirmed, in `AbilityChange` order.
+    pub changes: Vec<AbilityChange>,
+    pub awaiting: ReassessmentAwaiting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReassessmentEvent {
+    /// An update held the ability back.
+    Pending,
+    /// The agent re-confirmed it through `request_enable`.
+    Reconfirmed,
+    /// The agent's prerequisites satisfied the new definition.
+    Revalidated,
+    /// The agent blocked it instead.
+    Withdrawn,
+}
+
+/// One entry of an agent's reassessment log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReassessmentRecord {
+    pub seq: u64,
+    pub capability: CapabilityId,
+    pub event: ReassessmentEvent,
+    /// The changes being reassessed.
+    pub changes: Vec<AbilityChange>,
+}
+
+/// What an `upsert_ability` changed and whom it affected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AbilityUpdate {
+    pub capability: CapabilityId,
+    /// Empty for a newly defined ability.
+    pub changes: Vec<AbilityChange>,
+    /// Agents now pending reassessment, sorted.
+    pub pending_agents: Vec<AgentId>,
+    /// Agents whose prerequisites already satisfy the update, sorted.
+    pub revalidated_agents: Vec<AgentId>,
+}
@@ -0,0 +1,157 @@
+// path: the_element/src/requirement.rs
+
+//! Prerequisite expressions for `CyberneticAbility::requires`.
+//! - A `Requirement` is every one of (`All`), at least one of (`Any`), or a single capability
+//!   (`Cap`), nested as deep as needed; an empty `All` needs nothing, an empty `Any` is never met
+//! - It is met by the agent's enabled capabilities. `request_enable` refuses an ability whose
+//!   requirement is unmet, and an enabled ability is only usable while it is met: restricting
+//!   one branch of a satisfied `Any` keeps the ability usable while another branch still holds,
+//!   and restricting the last keeps it enabled but unusable until a branch is enabled again
+//! - `unmet` is what is left to enable, rendered for denials as e.g.
+//!   `motor:emg_calibration or motor:bci_motor_calibration`
+//! - Abilities written before expressions existed carry a flat list of capabilities, read as
+//!   an `All` of them
+
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+use crate::CapabilityId;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Requirement {
+    All(Vec<Requirement>),
+    Any(Vec<Requirement>),
+    Cap(CapabilityId),
+}
+
+impl Default for Requirement {
+    /// Nothing required.
+    fn default() -> Self {
+        Requirement::All(Vec::new())
+    }
+}
+
+impl Requirement {
+    pub fn cap(id: CapabilityId) -> Self {
+        Requirement::Cap(id)
+    }
+
+    /// Every one of `ids`, sorted and de-duplicated.
+    pub fn all_of(ids: impl IntoIterator<Item = CapabilityId>) -> Self {
+        let ids: BTreeSet<CapabilityId> = ids.into_iter().collect();
+        Requirement::All(ids.into_iter().map(Requirement::Cap).collect())
+    }
+
+    /// At least one of `ids`, in the order given.
+    pub fn any_of(ids: impl IntoIterator<Item = CapabilityId>) -> Self {
+        Requirement::Any(ids.into_iter().map(Requirement::Cap).collect())
+    }
+
+    /// This and `other`, flattened into this `All` when it is one.
+    pub fn and(self, other: Requirement) -> Self {
+        match self {
+            Requirement::All(mut all) => {
+                if !all.contains(&other) {
+                    all.push(other);
+                }
+                Requirement::All(all)
+            }
+            this => Requirement::All(vec![this, other]),
+        }
+    }
+
+    pub fn is_met_by(&self, enabled: &HashSet<CapabilityId>) -> bool {
+        self.unmet(enabled).is_none()
+    }
+
+    /// The part of the requirement `enabled` does not meet, or `None` if it is met: an `All`
+    /// keeps its unmet children, an unmet `Any` is every branch's unmet part.
+    pub fn unmet(&self, enabled: &HashSet<CapabilityId>) -> Option<Requirement> {
+        match self {
+            Requirement::Cap(id) => (!enabled.contains(id)).then(|| self.clone()),
+            Requirement::All(children) => {
+                let unmet: Vec<Requirement> = children.iter().filter_map(|c| c.unmet(enabled)).collect();
+                single_or(unmet, Requirement::All)
+            }
+            Requirement::Any(children) => {
+                let unmet: Vec<Requirement> = children.iter().map(|c| c.unmet(enabled)).collect::<Option<_>>()?;
+                single_or(unmet, Requirement::Any).or(Some(Requirement::Any(Vec::new())))
+            }
+        }
+    }
+
+    /// Every capability the expression names.
+    pub fn capabilities(&self) -> BTreeSet<&CapabilityId> {
+        match self {
+            Requirement::Cap(id) => BTreeSet::from([id]),
+            Requirement::All(children) | Requirement::Any(children) => {
+                children.iter().flat_map(Requirement::capabilities).collect()
+            }
+        }
+    }
+
+    /// `Deserialize` that also reads the flat capability list abilities were written with.
+    pub fn deserialize_compat<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Flat(Vec<CapabilityId>),
+            Expression(Requirement),
+        }
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Flat(ids) => Requirement::all_of(ids),
+            Repr::Expression(requirement) => requirement,
+        })
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, Requirement::All(c) | Requirement::Any(c) if c.len() > 1)
+    }
+}
+
+/// `None` for no children, the child itself for one, `wrap` of them otherwise.
+fn single_or(mut children: Vec<Requirement>, wrap: fn(Vec<Requirement>) -> Requirement) -> Option<Requirement> {
+    match children.len() {
+        0 => None,
+        1 => children.pop(),
+        _ => Some(wrap(children)),
+    }
+}
+
+impl FromIterator<CapabilityId> for Requirement {
+    /// `Requirement::all_of`.
+    fn from_iter<I: IntoIterator<Item = CapabilityId>>(ids: I) -> Self {
+        Requirement::all_of(ids)
+    }
+}
+
+impl From<HashSet<CapabilityId>> for Requirement {
+    fn from(ids: HashSet<CapabilityId>) -> Self {
+        Requirement::all_of(ids)
+    }
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (children, joiner) = match self {
+            Requirement::Cap(id) => return write!(f, "{id}"),
+            Requirement::All(children) if children.is_empty() => return f.write_str("nothing"),
+            Requirement::Any(children) if children.is_empty() => return f.write_str("no alternative"),
+            Requirement::All(children) => (children, " and "),
+            Requirement::Any(children) => (children, " or "),
+        };
+        for (i, child) in children.iter().enumerate() {
+            if i > 0 {
+                f.write_str(joiner)?;
+            }
+            if child.is_compound() {
+                write!(f, "({child})")?;
+            } else {
+                write!(f, "{child}")?;
+            }
+        }
+        Ok(())
+    }
+}
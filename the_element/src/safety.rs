@@ -0,0 +1,125 @@
+// path: the_element/src/safety.rs
+
+//! Emergency exits and pauses, and the abilities that were active when they happened.
+//! - Safety events are always recorded (no opt-in): they protect the agent, not a metric
+//! - Each agent's log is append-only; only the agent can export or purge it
+//! - Summaries aggregate across agents without naming them: how many of a window's
+//!   exits and pauses happened while a capability was active
+//! - An ability whose share of exits reaches the review threshold is flagged for review,
+//!   never disabled; the flag stays until a reviewer clears it
+
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+
+use crate::{AgentId, CapabilityId};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SafetyEvent {
+    /// Capabilities the session had engaged when the agent bailed out.
+    EmergencyExit {
+        #[serde(serialize_with = "steward_ids::ordered::set")]
+        active_capabilities_snapshot: HashSet<CapabilityId>,
+    },
+    /// `None` pauses until the next `Resume`.
+    Pause { duration_ms: Option<u64> },
+    Resume,
+}
+
+/// One entry of an agent's safety log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SafetyEventRecord {
+    pub seq: u64,
+    pub at_ms: u64,
+    pub event: SafetyEvent,
+    /// The exit's snapshot; for pause / resume, the capabilities the agent could use then.
+    #[serde(serialize_with = "steward_ids::ordered::set")]
+    pub active_capabilities: HashSet<CapabilityId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SafetyReviewThresholds {
+    /// Fewest exits with the capability active before it is judged at all.
+    pub min_exits: usize,
+    /// Flag an ability active during at least this share of all recorded exits.
+    pub exit_correlation: f64,
+}
+
+impl Default for SafetyReviewThresholds {
+    fn default() -> Self {
+        Self { min_exits: 5, exit_correlation: 0.5 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SafetyEventSummary {
+    pub capability: CapabilityId,
+    /// `[start_ms, end_ms)`.
+    pub window: (u64, u64),
+    pub emergency_exits: usize,
+    pub pauses: usize,
+    /// Distinct agents behind `emergency_exits` and `pauses`.
+    pub agents: usize,
+    /// Exits in the window, across all capabilities.
+    pub total_exits: usize,
+    /// `emergency_exits` over `total_exits` (0.0 without exits).
+    pub exit_correlation: f64,
+}
+
+/// Raised on an ability whose exit correlation reached the review threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SafetyReviewFlag {
+    pub flagged_at_ms: u64,
+    pub emergency_exits: usize,
+    pub exit_correlation: f64,
+}
+
+/// An ability as `list_abilities` shows it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AbilityListing {
+    pub ability: crate::CyberneticAbility,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub safety_review: Option<SafetyReviewFlag>,
+}
+
+/// Aggregate `logs` for `capability` over `window`.
+pub(crate) fn summarize<'a>(
+    logs: impl IntoIterator<Item = (&'a AgentId, &'a Vec<SafetyEventRecord>)>,
+    capability: &CapabilityId,
+    window: (u64, u64),
+) -> SafetyEventSummary {
+    let mut summary = SafetyEventSummary {
+        capability: capability.clone(),
+        window,
+        emergency_exits: 0,
+        pauses: 0,
+        agents: 0,
+        total_exits: 0,
+        exit_correlation: 0.0,
+    };
+    for (_, records) in logs {
+        let mut involved = false;
+        for record in records.iter().filter(|r| r.at_ms >= window.0 && r.at_ms < window.1) {
+            let active = record.active_capabilities.contains(capability);
+            match record.event {
+                SafetyEvent::EmergencyExit { .. } => {
+                    summary.total_exits += 1;
+                    summary.emergency_exits += active as usize;
+                }
+                SafetyEvent::Pause { .. } => summary.pauses += active as usize,
+                SafetyEvent::Resume => continue,
+            }
+            involved |= active;
+        }
+        summary.agents += involved as usize;
+    }
+    if summary.total_exits > 0 {
+        summary.exit_correlation = summary.emergency_exits as f64 / summary.total_exits as f64;
+    }
+    summary
+}
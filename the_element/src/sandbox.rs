@@ -0,0 +1,505 @@
+// path: the_element/src/sandbox.rs
+
+//! A clock-controlled copy of the element, for trying an ability out before anyone uses it.
+//! - `TheElement::sandbox` builds a new element from the library, enablement policy,
+//!   endorsements and review thresholds, plus one template profile and its usage constraints.
+//!   It has its own clock and id source and shares nothing with the original, so nothing done
+//!   in it can reach a real profile or log
+//! - Reads go through `Deref`; changes through methods forwarding to the element's own, so
+//!   the element inside can never be swapped for a real one. `advance_time`, `inject` and
+//!   `simulate_use` move the clock in ticks, enforcing usage constraints on every tick
+//! - An injected pause or emergency exit closes the agent's open sessions, as the device would;
+//!   a pause also refuses new ones until it lapses or a resume is injected
+//! - Monitors are expected check-ins on a capability's sessions. On every tick the sandbox
+//!   checks that no monitored session went quiet too long and that every baseline right the
+//!   agent has not blocked is usable
+//! - `report` gathers the constraints that fired, the sessions auto-paused and the violations
+
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use steward_runtime_support::{Clock, FixedClock, SequentialIdGenerator};
+
+use crate::{
+    backfill_baselines, AbilityUpdate, AgentCyberProfile, AgentId, CapabilityId, CyberneticAbility, Reason, ReasonCode,
+    SafetyEvent, SafetyEventRecord, TheElement, UsageConstraints, UsageEvent,
+};
+
+/// How far the sandbox clock moves between enforcement passes, unless set otherwise.
+pub const DEFAULT_SANDBOX_TICK_MS: u64 = 60_000;
+
+/// Check-ins expected on a capability's open sessions, from a device or a person watching them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SessionMonitor {
+    pub capability: CapabilityId,
+    /// How often the simulated monitor checks in, counted from the session's start.
+    pub check_in_every_ms: u64,
+    /// Longest an open session may go without a check-in.
+    pub max_silence_ms: u64,
+}
+
+/// Why a session ended before its user ended it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum AutoPauseCause {
+    /// Force-paused by `enforce_usage_constraints`.
+    UsageConstraint { reason: Reason },
+    SafetyPause,
+    EmergencyExit,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AutoPausedSession {
+    pub capability: CapabilityId,
+    pub started_ms: u64,
+    pub paused_ms: u64,
+    pub cause: AutoPauseCause,
+}
+
+/// A usage constraint refusing a session, or force-pausing one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConstraintFiring {
+    pub at_ms: u64,
+    pub capability: CapabilityId,
+    pub reason: Reason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SandboxViolation {
+    /// A baseline right the agent has not blocked could not be used; reported once until it
+    /// is usable again.
+    BaselineUnavailable { at_ms: u64, capability: CapabilityId },
+    /// An open session went longer than its monitor allows without a check-in; reported once
+    /// per silence.
+    MonitorSilent { at_ms: u64, capability: CapabilityId, silent_ms: u64, max_silence_ms: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UseOutcome {
+    /// Ran for the whole requested duration and was ended by the user.
+    Completed,
+    Refused { reason: Reason },
+    AutoPaused { cause: AutoPauseCause },
+}
+
+/// One `simulate_use`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SimulatedUse {
+    pub capability: CapabilityId,
+    pub started_ms: u64,
+    pub requested_ms: u64,
+    pub ran_ms: u64,
+    pub outcome: UseOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SandboxReport {
+    pub agent: AgentId,
+    pub started_ms: u64,
+    pub ended_ms: u64,
+    pub uses: Vec<SimulatedUse>,
+    pub constraints_fired: Vec<ConstraintFiring>,
+    pub auto_paused: Vec<AutoPausedSession>,
+    /// The agent's safety log in the sandbox.
+    pub safety_events: Vec<SafetyEventRecord>,
+    pub violations: Vec<SandboxViolation>,
+}
+
+impl SandboxReport {
+    /// Whether no invariant was violated.
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// A monitor and where it is in the current session.
+struct MonitorState {
+    monitor: SessionMonitor,
+    /// Start of the session being watched.
+    session_ms: Option<u64>,
+    /// Last scheduled check-in, whether or not it happened.
+    scheduled_ms: u64,
+    /// Last check-in that happened; the session's start before the first.
+    seen_ms: u64,
+    silenced_until_ms: u64,
+    reported: bool,
+}
+
+/// See the module docs. Derefs to the sandbox's own element, read-only.
+pub struct ElementSandbox {
+    element: TheElement,
+    clock: Arc<FixedClock>,
+    agent: AgentId,
+    tick_ms: u64,
+    started_ms: u64,
+    /// `Some(None)` pauses until the next injected resume.
+    paused_until: Option<Option<u64>>,
+    monitors: BTreeMap<CapabilityId, MonitorState>,
+    baselines_down: BTreeSet<CapabilityId>,
+    uses: Vec<SimulatedUse>,
+    constraints_fired: Vec<ConstraintFiring>,
+    auto_paused: Vec<AutoPausedSession>,
+    violations: Vec<SandboxViolation>,
+}
+
+impl TheElement {
+    /// A sandbox holding `agent_template` alone, its clock starting at this element's now.
+    /// The template's usage constraints and pending reassessments here come with it; no
+    /// other profile, log or open session does.
+    pub fn sandbox(&self, agent_template: AgentCyberProfile) -> ElementSandbox {
+        let agent = agent_template.agent.clone();
+        let started_ms = self.clock.now_ms();
+        let clock = Arc::new(FixedClock::new(started_ms));
+        let mut element = TheElement::with_id_generator(self.config.clone(), Arc::new(SequentialIdGenerator::new(0)))
+            .with_clock(clock.clone())
+            .with_safety_review_thresholds(self.safety_thresholds.clone());
+        element.abilities = self.abilities.clone();
+        element.review_flags = self.review_flags.clone();
+        element.endorsements = self.endorsements.clone();
+        element.enablement_policy = self.enablement_policy.clone();
+        element.usage_constraints = self
+            .usage_constraints
+            .iter()
+            .filter(|((holder, _), _)| *holder == agent)
+            .map(|(key, constraints)| (key.clone(), constraints.clone()))
+            .collect();
+        if let Some(pending) = self.pending_reassessments.get(&agent) {
+            element.pending_reassessments.insert(agent.clone(), pending.clone());
+        }
+        let mut profile = agent_template;
+        backfill_baselines(&mut profile, &element.config.global_baseline_capabilities);
+        element.profiles.insert(agent.clone(), profile);
+
+        let mut sandbox = ElementSandbox {
+            element,
+            clock,
+            agent,
+            tick_ms: DEFAULT_SANDBOX_TICK_MS,
+            started_ms,
+            paused_until: None,
+            monitors: BTreeMap::new(),
+            baselines_down: BTreeSet::new(),
+            uses: Vec::new(),
+            constraints_fired: Vec::new(),
+            auto_paused: Vec::new(),
+            violations: Vec::new(),
+        };
+        sandbox.check_invariants();
+        sandbox
+    }
+}
+
+impl ElementSandbox {
+    /// Move the clock at most `tick_ms` between enforcement passes.
+    pub fn with_tick_ms(mut self, tick_ms: u64) -> Self {
+        self.tick_ms = tick_ms.max(1);
+        self
+    }
+
+    /// Expect `monitor`'s check-ins on every session of its capability.
+    pub fn with_monitor(mut self, monitor: SessionMonitor) -> Self {
+        let state = MonitorState {
+            monitor: monitor.clone(),
+            session_ms: None,
+            scheduled_ms: 0,
+            seen_ms: 0,
+            silenced_until_ms: 0,
+            reported: false,
+        };
+        self.monitors.insert(monitor.capability, state);
+        self
+    }
+
+    pub fn agent(&self) -> &AgentId {
+        &self.agent
+    }
+
+    pub fn now_ms(&self) -> u64 {
+        self.clock.now_ms()
+    }
+
+    /// `TheElement::upsert_ability`, in the sandbox.
+    pub fn upsert_ability(&mut self, ability: CyberneticAbility) -> AbilityUpdate {
+        self.element.upsert_ability(ability)
+    }
+
+    /// `TheElement::request_enable`, in the sandbox.
+    pub fn request_enable(
+        &mut self,
+        agent: &AgentId,
+        capability_id: &CapabilityId,
+        explicit_opt_in: bool,
+    ) -> Result<(), Reason> {
+        self.element.request_enable(agent, capability_id, explicit_opt_in)
+    }
+
+    /// `TheElement::request_block`, in the sandbox.
+    pub fn request_block(&mut self, agent: &AgentId, capability_id: &CapabilityId) -> Result<(), Reason> {
+        self.element.request_block(agent, capability_id)
+    }
+
+    /// `TheElement::set_usage_constraints`, in the sandbox.
+    pub fn set_usage_constraints(
+        &mut self,
+        requester: &AgentId,
+        agent: &AgentId,
+        capability_id: &CapabilityId,
+        constraints: UsageConstraints,
+    ) -> Result<(), Reason> {
+        self.element.set_usage_constraints(requester, agent, capability_id, constraints)
+    }
+
+    /// `TheElement::remove_usage_constraints`, in the sandbox.
+    pub fn remove_usage_constraints(
+        &mut self,
+        requester: &AgentId,
+        agent: &AgentId,
+        capability_id: &CapabilityId,
+    ) -> Result<UsageConstraints, Reason> {
+        self.element.remove_usage_constraints(requester, agent, capability_id)
+    }
+
+    /// `TheElement::start_session`, in the sandbox; the next tick enforces and monitors it.
+    pub fn start_session(&mut self, agent: &AgentId, capability_id: &CapabilityId) -> Result<(), Reason> {
+        self.element.start_session(agent, capability_id)
+    }
+
+    /// `TheElement::end_session`, in the sandbox.
+    pub fn end_session(&mut self, agent: &AgentId, capability_id: &CapabilityId) -> Result<u64, Reason> {
+        self.element.end_session(agent, capability_id)
+    }
+
+    /// Move the clock `ms` forward, tick by tick.
+    pub fn advance_time(&mut self, ms: u64) {
+        self.run_for(ms, None);
+    }
+
+    /// Record `event` for the agent now. A pause or an exit closes the agent's open sessions;
+    /// an exit with an empty snapshot is given the capabilities of those sessions.
+    pub fn inject(&mut self, event: SafetyEvent) -> SafetyEventRecord {
+        let now_ms = self.clock.now_ms();
+        let open = self.open_capabilities();
+        let (event, cause) = match event {
+            SafetyEvent::EmergencyExit { active_capabilities_snapshot } if active_capabilities_snapshot.is_empty() => {
+                let snapshot: HashSet<CapabilityId> = open.iter().cloned().collect();
+                let event = SafetyEvent::EmergencyExit { active_capabilities_snapshot: snapshot };
+                (event, Some(AutoPauseCause::EmergencyExit))
+            }
+            SafetyEvent::EmergencyExit { .. } => (event, Some(AutoPauseCause::EmergencyExit)),
+            SafetyEvent::Pause { duration_ms } => {
+                self.paused_until = Some(duration_ms.map(|d| now_ms + d));
+                (event, Some(AutoPauseCause::SafetyPause))
+            }
+            SafetyEvent::Resume => {
+                self.paused_until = None;
+                (event, None)
+            }
+        };
+        let agent = self.agent.clone();
+        let record = self.element.record_safety_event(&agent, event, now_ms);
+        if let Some(cause) = cause {
+            for capability in open {
+                let started_ms = self.element.open_session(&agent, &capability).expect("session is open");
+                self.element.end_session(&agent, &capability).expect("session is open");
+                let paused = AutoPausedSession { capability, started_ms, paused_ms: now_ms, cause: cause.clone() };
+                self.auto_paused.push(paused);
+            }
+        }
+        self.check_invariants();
+        record
+    }
+
+    /// Use `capability` for `duration_ms` from now: start a session, move the clock until the
+    /// session is auto-paused or the duration is up, and end it if still open.
+    pub fn simulate_use(&mut self, capability: &CapabilityId, duration_ms: u64) -> SimulatedUse {
+        let agent = self.agent.clone();
+        let started_ms = self.clock.now_ms();
+        let refused = if self.is_paused() {
+            Some(Reason::new(ReasonCode::ElementSandboxPaused).with("capability", capability))
+        } else {
+            self.element.start_session(&agent, capability).err()
+        };
+        if let Some(reason) = refused {
+            if matches!(reason.code, ReasonCode::ElementUsageOutsideHours | ReasonCode::ElementUsageDailyCap) {
+                let capability = capability.clone();
+                self.constraints_fired.push(ConstraintFiring { at_ms: started_ms, capability, reason: reason.clone() });
+            }
+            return self.record_use(capability, started_ms, duration_ms, UseOutcome::Refused { reason });
+        }
+        self.check_invariants();
+        self.run_for(duration_ms, Some(capability));
+        let outcome = if self.element.open_session(&agent, capability).is_some() {
+            self.element.end_session(&agent, capability).expect("session is open");
+            UseOutcome::Completed
+        } else {
+            let cause = self
+                .auto_paused
+                .iter()
+                .rev()
+                .find(|p| &p.capability == capability)
+                .map(|p| p.cause.clone())
+                .expect("a session closed mid-use was auto-paused");
+            UseOutcome::AutoPaused { cause }
+        };
+        self.record_use(capability, started_ms, duration_ms, outcome)
+    }
+
+    /// Stop `capability`'s monitor from checking in for `for_ms` from now, as if it dropped out.
+    pub fn silence_monitor(&mut self, capability: &CapabilityId, for_ms: u64) -> Result<(), String> {
+        let now_ms = self.clock.now_ms();
+        let state = self.monitors.get_mut(capability).ok_or_else(|| format!("No monitor on {capability}"))?;
+        state.silenced_until_ms = now_ms + for_ms;
+        Ok(())
+    }
+
+    /// Whether an injected pause still holds.
+    pub fn is_paused(&self) -> bool {
+        match self.paused_until {
+            Some(Some(until_ms)) => self.clock.now_ms() < until_ms,
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    pub fn report(&self) -> SandboxReport {
+        SandboxReport {
+            agent: self.agent.clone(),
+            started_ms: self.started_ms,
+            ended_ms: self.clock.now_ms(),
+            uses: self.uses.clone(),
+            constraints_fired: self.constraints_fired.clone(),
+            auto_paused: self.auto_paused.clone(),
+            safety_events: self.element.safety_logs.get(&self.agent).cloned().unwrap_or_default(),
+            violations: self.violations.clone(),
+        }
+    }
+
+    fn record_use(
+        &mut self,
+        capability: &CapabilityId,
+        started_ms: u64,
+        requested_ms: u64,
+        outcome: UseOutcome,
+    ) -> SimulatedUse {
+        let ran_ms = match outcome {
+            UseOutcome::Refused { .. } => 0,
+            _ => self.clock.now_ms() - started_ms,
+        };
+        let record = SimulatedUse { capability: capability.clone(), started_ms, requested_ms, ran_ms, outcome };
+        self.uses.push(record.clone());
+        record
+    }
+
+    /// Tick through `ms`, stopping early once `watched`'s session is closed.
+    fn run_for(&mut self, ms: u64, watched: Option<&CapabilityId>) {
+        let until_ms = self.clock.now_ms() + ms;
+        while self.clock.now_ms() < until_ms {
+            self.clock.advance(self.tick_ms.min(until_ms - self.clock.now_ms()));
+            self.tick();
+            if watched.is_some_and(|c| self.element.open_session(&self.agent, c).is_none()) {
+                break;
+            }
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.paused_until.is_some() && !self.is_paused() {
+            self.paused_until = None;
+        }
+        for record in self.element.enforce_usage_constraints() {
+            let UsageEvent::SessionForcePaused { duration_ms, reason } = record.event else {
+                continue;
+            };
+            let capability = record.capability.clone();
+            self.constraints_fired.push(ConstraintFiring { at_ms: record.at_ms, capability, reason: reason.clone() });
+            self.auto_paused.push(AutoPausedSession {
+                capability: record.capability,
+                started_ms: record.at_ms - duration_ms,
+                paused_ms: record.at_ms,
+                cause: AutoPauseCause::UsageConstraint { reason },
+            });
+        }
+        self.check_invariants();
+    }
+
+    fn open_capabilities(&self) -> Vec<CapabilityId> {
+        let mut open: Vec<CapabilityId> = self
+            .element
+            .open_sessions
+            .keys()
+            .filter(|(agent, _)| *agent == self.agent)
+            .map(|(_, capability)| capability.clone())
+            .collect();
+        open.sort();
+        open
+    }
+
+    fn check_invariants(&mut self) {
+        let now_ms = self.clock.now_ms();
+        let blocked =
+            self.element.profiles.get(&self.agent).map(|p| p.blocked_capabilities.clone()).unwrap_or_default();
+        let mut baselines: Vec<&CapabilityId> = self.element.config.global_baseline_capabilities.iter().collect();
+        baselines.sort();
+        for capability in baselines {
+            if blocked.contains(capability) || self.element.can_use(&self.agent, capability) {
+                self.baselines_down.remove(capability);
+            } else if self.baselines_down.insert(capability.clone()) {
+                let capability = capability.clone();
+                self.violations.push(SandboxViolation::BaselineUnavailable { at_ms: now_ms, capability });
+            }
+        }
+
+        let sessions: HashMap<CapabilityId, u64> = self
+            .open_capabilities()
+            .into_iter()
+            .filter_map(|c| self.element.open_session(&self.agent, &c).map(|started| (c, started)))
+            .collect();
+        for (capability, state) in &mut self.monitors {
+            let Some(&started_ms) = sessions.get(capability) else {
+                state.session_ms = None;
+                continue;
+            };
+            if state.session_ms != Some(started_ms) {
+                state.session_ms = Some(started_ms);
+                state.scheduled_ms = started_ms;
+                state.seen_ms = started_ms;
+                state.reported = false;
+            }
+            let every_ms = state.monitor.check_in_every_ms.max(1);
+            while state.scheduled_ms + every_ms <= now_ms {
+                state.scheduled_ms += every_ms;
+                if state.scheduled_ms >= state.silenced_until_ms {
+                    state.seen_ms = state.scheduled_ms;
+                    state.reported = false;
+                }
+            }
+            let silent_ms = now_ms - state.seen_ms;
+            if silent_ms > state.monitor.max_silence_ms && !state.reported {
+                state.reported = true;
+                self.violations.push(SandboxViolation::MonitorSilent {
+                    at_ms: now_ms,
+                    capability: capability.clone(),
+                    silent_ms,
+                    max_silence_ms: state.monitor.max_silence_ms,
+                });
+            }
+        }
+    }
+}
+
+impl Deref for ElementSandbox {
+    type Target = TheElement;
+
+    fn deref(&self) -> &TheElement {
+        &self.element
+    }
+}
@@ -0,0 +1,325 @@
+// path: the_element/src/usage.rs
+
+//! Usage envelopes on an agent's abilities: a daily duration cap and allowed local hours.
+//! - `UsageConstraints` attach per (agent, capability); `can_use` and `start_session` check
+//!   them against the element's clock, and `enforce_usage_constraints` force-pauses open
+//!   sessions that leave their allowed hours or reach their cap
+//! - Daily use is counted per local day, by the constraints' UTC offset, and starts again
+//!   at local midnight; a session running across midnight counts only its part after it
+//! - The agent sets and removes self-imposed constraints alone; a clinical advisor's can be
+//!   removed by the advisor or by the agent (non-coercion), and the agent's removal is logged
+//! - Every change and session is logged per agent
+
+use serde::{Serialize, Deserialize};
+
+use crate::{AgentId, CapabilityId, Reason, ReasonCode, TheElement};
+
+const MINUTE_MS: i64 = 60_000;
+const HOUR_MS: i64 = 60 * MINUTE_MS;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+/// Who set a pair of usage constraints, and so who may remove them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConstraintOrigin {
+    /// Removable by the agent only.
+    SelfImposed,
+    /// Removable by this advisor or by the agent.
+    ClinicalAdvisor(AgentId),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UsageConstraints {
+    /// Most use per local day, open sessions included.
+    #[serde(default)]
+    pub max_daily_duration_ms: Option<u64>,
+    /// Local hours `[from, until)`, each 0–23; `from > until` wraps past midnight.
+    #[serde(default)]
+    pub allowed_hours: Option<(u8, u8)>,
+    /// Local time minus UTC, in minutes.
+    #[serde(default)]
+    pub timezone_offset_min: i32,
+    pub set_by: ConstraintOrigin,
+}
+
+impl UsageConstraints {
+    pub fn validate(&self) -> Result<(), Reason> {
+        let invalid =
+            |detail: &str| Err(Reason::new(ReasonCode::ElementUsageConstraintsInvalid).with("detail", detail));
+        if let Some((from, until)) = self.allowed_hours {
+            if from > 23 || until > 23 {
+                return invalid("allowed_hours must be between 0 and 23");
+            }
+            if from == until {
+                return invalid("allowed_hours must not be empty");
+            }
+        }
+        if self.max_daily_duration_ms == Some(0) {
+            return invalid("max_daily_duration_ms must be positive");
+        }
+        if self.timezone_offset_min.abs() > 14 * 60 {
+            return invalid("timezone_offset_min must be within 14 hours of UTC");
+        }
+        Ok(())
+    }
+
+    /// Days since the epoch in local time.
+    pub fn local_day(&self, at_ms: u64) -> i64 {
+        local_day(self.timezone_offset_min, at_ms)
+    }
+
+    /// The UTC instant of the local midnight starting `at_ms`'s day.
+    pub fn local_midnight_ms(&self, at_ms: u64) -> u64 {
+        local_midnight_ms(self.timezone_offset_min, at_ms)
+    }
+
+    /// Whether `at_ms` falls in `allowed_hours`; always without them.
+    pub fn within_hours(&self, at_ms: u64) -> bool {
+        let Some((from, until)) = self.allowed_hours else {
+            return true;
+        };
+        let hour = (local_ms(self.timezone_offset_min, at_ms).rem_euclid(DAY_MS) / HOUR_MS) as u8;
+        if from < until {
+            hour >= from && hour < until
+        } else {
+            hour >= from || hour < until
+        }
+    }
+}
+
+fn local_ms(offset_min: i32, at_ms: u64) -> i64 {
+    at_ms as i64 + offset_min as i64 * MINUTE_MS
+}
+
+fn local_day(offset_min: i32, at_ms: u64) -> i64 {
+    local_ms(offset_min, at_ms).div_euclid(DAY_MS)
+}
+
+fn local_midnight_ms(offset_min: i32, at_ms: u64) -> u64 {
+    let local = local_ms(offset_min, at_ms);
+    (local - local.rem_euclid(DAY_MS) - offset_min as i64 * MINUTE_MS).max(0) as u64
+}
+
+/// Use counted towards one local day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct DailyUsage {
+    local_day: i64,
+    used_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UsageEvent {
+    ConstraintsSet { constraints: UsageConstraints },
+    /// `by` is the agent or the advisor who set them.
+    ConstraintsRemoved { by: AgentId, constraints: UsageConstraints },
+    SessionStarted,
+    SessionEnded { duration_ms: u64 },
+    /// Ended by `enforce_usage_constraints`.
+    SessionForcePaused { duration_ms: u64, reason: Reason },
+}
+
+/// One entry of an agent's usage log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UsageRecord {
+    pub seq: u64,
+    pub at_ms: u64,
+    pub capability: CapabilityId,
+    pub event: UsageEvent,
+}
+
+impl TheElement {
+    /// Attach `constraints` to `agent`'s use of `capability`, replacing any there. A
+    /// self-imposed set is the agent's to give; an advisor's is given by that advisor, and
+    /// may not replace one the agent imposed.
+    pub fn set_usage_constraints(
+        &mut self,
+        requester: &AgentId,
+        agent: &AgentId,
+        capability_id: &CapabilityId,
+        constraints: UsageConstraints,
+    ) -> Result<(), Reason> {
+        constraints.validate()?;
+        let key = (agent.clone(), capability_id.clone());
+        let allowed = match &constraints.set_by {
+            ConstraintOrigin::SelfImposed => requester == agent,
+            ConstraintOrigin::ClinicalAdvisor(advisor) => {
+                requester == advisor
+                    && !self.usage_constraints.get(&key).is_some_and(|c| c.set_by == ConstraintOrigin::SelfImposed)
+            }
+        };
+        if !allowed {
+            return Err(not_permitted(requester, agent, capability_id));
+        }
+        self.usage_constraints.insert(key, constraints.clone());
+        self.log_usage(agent, capability_id, UsageEvent::ConstraintsSet { constraints });
+        Ok(())
+    }
+
+    /// Remove the constraints on `agent`'s use of `capability`: the agent may always, the
+    /// advisor who set them may too.
+    pub fn remove_usage_constraints(
+        &mut self,
+        requester: &AgentId,
+        agent: &AgentId,
+        capability_id: &CapabilityId,
+    ) -> Result<UsageConstraints, Reason> {
+        let key = (agent.clone(), capability_id.clone());
+        let allowed = self.usage_constraints.get(&key).is_some_and(|c| match &c.set_by {
+            ConstraintOrigin::SelfImposed => requester == agent,
+            ConstraintOrigin::ClinicalAdvisor(advisor) => requester == agent || requester == advisor,
+        });
+        if !allowed {
+            return Err(not_permitted(requester, agent, capability_id));
+        }
+        let constraints = self.usage_constraints.remove(&key).expect("checked above");
+        #[cfg(feature = "tracing")]
+        if let ConstraintOrigin::ClinicalAdvisor(advisor) = &constraints.set_by {
+            tracing::info!(
+                actor_did = %requester,
+                capability = %capability_id,
+                advisor = %advisor,
+                "advisor usage constraints removed"
+            );
+        }
+        let event = UsageEvent::ConstraintsRemoved { by: requester.clone(), constraints: constraints.clone() };
+        self.log_usage(agent, capability_id, event);
+        Ok(constraints)
+    }
+
+    pub fn usage_constraints(&self, agent: &AgentId, capability_id: &CapabilityId) -> Option<&UsageConstraints> {
+        self.usage_constraints.get(&(agent.clone(), capability_id.clone()))
+    }
+
+    /// Start a session of `capability` now; refused unless `can_use` allows it.
+    pub fn start_session(&mut self, agent: &AgentId, capability_id: &CapabilityId) -> Result<(), Reason> {
+        let key = (agent.clone(), capability_id.clone());
+        if self.open_sessions.contains_key(&key) {
+            return Err(Reason::new(ReasonCode::ElementUsageSessionOpen).with("capability", capability_id));
+        }
+        if !self.holds(agent, capability_id) {
+            return Err(Reason::new(ReasonCode::ElementUsageUnavailable).with("capability", capability_id));
+        }
+        let now_ms = self.clock.now_ms();
+        self.check_usage(agent, capability_id, now_ms)?;
+        self.open_sessions.insert(key, now_ms);
+        self.log_usage(agent, capability_id, UsageEvent::SessionStarted);
+        Ok(())
+    }
+
+    /// End the open session of `capability` now; returns its duration.
+    pub fn end_session(&mut self, agent: &AgentId, capability_id: &CapabilityId) -> Result<u64, Reason> {
+        let duration_ms = self.close_session(agent, capability_id)?;
+        self.log_usage(agent, capability_id, UsageEvent::SessionEnded { duration_ms });
+        Ok(duration_ms)
+    }
+
+    /// When `agent` started their open session of `capability`.
+    pub fn open_session(&self, agent: &AgentId, capability_id: &CapabilityId) -> Option<u64> {
+        self.open_sessions.get(&(agent.clone(), capability_id.clone())).copied()
+    }
+
+    /// Force-pause every open session now outside its allowed hours or at its daily cap;
+    /// returns the log entries, by agent then capability.
+    pub fn enforce_usage_constraints(&mut self) -> Vec<UsageRecord> {
+        let now_ms = self.clock.now_ms();
+        let mut over: Vec<((AgentId, CapabilityId), Reason)> = self
+            .open_sessions
+            .keys()
+            .filter_map(|(agent, capability)| {
+                let reason = self.check_usage(agent, capability, now_ms).err()?;
+                Some(((agent.clone(), capability.clone()), reason))
+            })
+            .collect();
+        over.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut paused = Vec::new();
+        for ((agent, capability), reason) in over {
+            let duration_ms = self.close_session(&agent, &capability).expect("session is open");
+            #[cfg(feature = "tracing")]
+            tracing::warn!(actor_did = %agent, capability = %capability, reason = %reason.code, "session force-paused");
+            paused.push(self.log_usage(&agent, &capability, UsageEvent::SessionForcePaused { duration_ms, reason }));
+        }
+        paused
+    }
+
+    /// `agent`'s use of `capability` so far in the current local day, open session included.
+    pub fn usage_today(&self, agent: &AgentId, capability_id: &CapabilityId) -> u64 {
+        self.usage_at(agent, capability_id, self.clock.now_ms())
+    }
+
+    /// `agent`'s usage log, oldest first.
+    pub fn usage_log(&self, agent: &AgentId) -> &[UsageRecord] {
+        self.usage_logs.get(agent).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Whether `agent`'s constraints on `capability` allow using it at `now_ms`.
+    pub(crate) fn check_usage(&self, agent: &AgentId, capability_id: &CapabilityId, now_ms: u64) -> Result<(), Reason> {
+        let Some(constraints) = self.usage_constraints(agent, capability_id) else {
+            return Ok(());
+        };
+        if !constraints.within_hours(now_ms) {
+            let (from, until) = constraints.allowed_hours.unwrap_or_default();
+            return Err(Reason::new(ReasonCode::ElementUsageOutsideHours)
+                .with("capability", capability_id)
+                .with("from", format!("{from:02}:00"))
+                .with("until", format!("{until:02}:00")));
+        }
+        if let Some(max_ms) = constraints.max_daily_duration_ms {
+            if self.usage_at(agent, capability_id, now_ms) >= max_ms {
+                return Err(Reason::new(ReasonCode::ElementUsageDailyCap)
+                    .with("capability", capability_id)
+                    .with("max_ms", max_ms));
+            }
+        }
+        Ok(())
+    }
+
+    fn usage_at(&self, agent: &AgentId, capability_id: &CapabilityId, now_ms: u64) -> u64 {
+        let key = (agent.clone(), capability_id.clone());
+        let (day, midnight) = self.local_day_of(&key, now_ms);
+        let counted = self.daily_usage.get(&key).filter(|u| u.local_day == day).map_or(0, |u| u.used_ms);
+        let open = self.open_sessions.get(&key).map_or(0, |started| now_ms.saturating_sub((*started).max(midnight)));
+        counted + open
+    }
+
+    /// Local day and its midnight by the pair's constraints, UTC without any.
+    fn local_day_of(&self, key: &(AgentId, CapabilityId), at_ms: u64) -> (i64, u64) {
+        let offset = self.usage_constraints.get(key).map_or(0, |c| c.timezone_offset_min);
+        (local_day(offset, at_ms), local_midnight_ms(offset, at_ms))
+    }
+
+    /// Close the open session, counting its part in the current local day.
+    fn close_session(&mut self, agent: &AgentId, capability_id: &CapabilityId) -> Result<u64, Reason> {
+        let key = (agent.clone(), capability_id.clone());
+        let started = self
+            .open_sessions
+            .remove(&key)
+            .ok_or_else(|| Reason::new(ReasonCode::ElementUsageNoSession).with("capability", capability_id))?;
+        let now_ms = self.clock.now_ms();
+        let (day, midnight) = self.local_day_of(&key, now_ms);
+        let today = now_ms.saturating_sub(started.max(midnight));
+        let usage = self.daily_usage.entry(key).or_default();
+        if usage.local_day != day {
+            *usage = DailyUsage { local_day: day, used_ms: 0 };
+        }
+        usage.used_ms += today;
+        Ok(now_ms.saturating_sub(started))
+    }
+
+    fn log_usage(&mut self, agent: &AgentId, capability_id: &CapabilityId, event: UsageEvent) -> UsageRecord {
+        let at_ms = self.clock.now_ms();
+        let log = self.usage_logs.entry(agent.clone()).or_default();
+        let record = UsageRecord { seq: log.len() as u64, at_ms, capability: capability_id.clone(), event };
+        log.push(record.clone());
+        record
+    }
+}
+
+fn not_permitted(requester: &AgentId, agent: &AgentId, capability_id: &CapabilityId) -> Reason {
+    Reason::new(ReasonCode::ElementUsageConstraintsNotPermitted)
+        .with("requester", requester)
+        .with("agent", agent)
+        .with("capability", capability_id)
+}